@@ -5,6 +5,7 @@ use crate::comparison::traits::Min;
 use crate::num::random::{random_unsigned_inclusive_range, RandomUnsignedInclusiveRange};
 use crate::random::Seed;
 use crate::vecs::{random_values_from_vec, RandomValuesFromVec};
+use alloc::vec::Vec;
 
 /// Uniformly generates random [`char`]s in a closed interval.
 ///
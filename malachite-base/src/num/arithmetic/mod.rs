@@ -874,6 +874,93 @@ pub mod extended_gcd;
 /// assert_eq!(u32::checked_subfactorial(100), None);
 /// ```
 pub mod factorial;
+/// Traits for computing Fibonacci and Lucas numbers. There is a trait whose implementations panic
+/// if the result cannot be represented, a checked trait whose implementations return `None` in that
+/// case, and a trait for computing Fibonacci numbers modulo another number:
+/// [`Fibonacci`](traits::Fibonacci), [`CheckedFibonacci`](traits::CheckedFibonacci), and
+/// [`FibonacciMod`](traits::FibonacciMod).
+///
+/// # fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::Fibonacci;
+///
+/// assert_eq!(u8::fibonacci(0), 0);
+/// assert_eq!(u8::fibonacci(1), 1);
+/// assert_eq!(u8::fibonacci(2), 1);
+/// assert_eq!(u8::fibonacci(3), 2);
+/// assert_eq!(u8::fibonacci(4), 3);
+/// assert_eq!(u8::fibonacci(5), 5);
+/// assert_eq!(u32::fibonacci(40), 102334155);
+/// assert_eq!(u64::fibonacci(93), 12200160415121876738);
+/// ```
+///
+/// # fibonacci2
+/// ```
+/// use malachite_base::num::arithmetic::traits::Fibonacci;
+///
+/// assert_eq!(u8::fibonacci2(0), (0, 1));
+/// assert_eq!(u8::fibonacci2(1), (1, 0));
+/// assert_eq!(u8::fibonacci2(2), (1, 1));
+/// assert_eq!(u8::fibonacci2(5), (5, 3));
+/// assert_eq!(u32::fibonacci2(40), (102334155, 63245986));
+/// ```
+///
+/// # lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::Fibonacci;
+///
+/// assert_eq!(u8::lucas(0), 2);
+/// assert_eq!(u8::lucas(1), 1);
+/// assert_eq!(u8::lucas(2), 3);
+/// assert_eq!(u8::lucas(3), 4);
+/// assert_eq!(u8::lucas(4), 7);
+/// assert_eq!(u8::lucas(5), 11);
+/// assert_eq!(u32::lucas(30), 1860498);
+/// ```
+///
+/// # checked_fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacci;
+///
+/// assert_eq!(u8::checked_fibonacci(0), Some(0));
+/// assert_eq!(u8::checked_fibonacci(5), Some(5));
+/// assert_eq!(u8::checked_fibonacci(13), Some(233));
+/// assert_eq!(u8::checked_fibonacci(14), None);
+/// assert_eq!(u64::checked_fibonacci(93), Some(12200160415121876738));
+/// assert_eq!(u64::checked_fibonacci(94), None);
+/// ```
+///
+/// # checked_fibonacci2
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacci;
+///
+/// assert_eq!(u8::checked_fibonacci2(0), Some((0, 1)));
+/// assert_eq!(u8::checked_fibonacci2(5), Some((5, 3)));
+/// assert_eq!(u8::checked_fibonacci2(13), Some((233, 144)));
+/// assert_eq!(u8::checked_fibonacci2(14), None);
+/// ```
+///
+/// # checked_lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacci;
+///
+/// assert_eq!(u8::checked_lucas(0), Some(2));
+/// assert_eq!(u8::checked_lucas(5), Some(11));
+/// assert_eq!(u8::checked_lucas(11), Some(199));
+/// assert_eq!(u8::checked_lucas(12), None);
+/// assert_eq!(u32::checked_lucas(30), Some(1860498));
+/// ```
+///
+/// # fibonacci_mod
+/// ```
+/// use malachite_base::num::arithmetic::traits::FibonacciMod;
+///
+/// assert_eq!(u8::fibonacci_mod(10, 7), 6);
+/// assert_eq!(u32::fibonacci_mod(100, 1000), 75);
+/// assert_eq!(u64::fibonacci_mod(100, 1000000007), 687995182);
+/// assert_eq!(u64::fibonacci_mod(1000000000000000000, 1000000007), 209783453);
+/// ```
+pub mod fibonacci;
 /// [`Floor`](traits::Floor) and [`FloorAssign`](traits::FloorAssign), traits for computing the
 /// floor of a number.
 ///
@@ -1997,6 +2084,43 @@ pub mod mod_shl;
 /// assert_eq!(n, 2);
 /// ```
 pub mod mod_shr;
+/// [`ModSqrt`](traits::ModSqrt), [`ModPrimePowerSqrt`](traits::ModPrimePowerSqrt), and
+/// [`ModFactoredSqrt`](traits::ModFactoredSqrt), traits for finding square roots modulo a prime, a
+/// prime power, or a number with known factorization.
+///
+/// # mod_sqrt
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModSqrt;
+///
+/// assert_eq!(2u8.mod_sqrt(7), Some(3));
+/// assert_eq!(3u8.mod_sqrt(7), None);
+/// assert_eq!(0u8.mod_sqrt(7), Some(0));
+/// assert_eq!(10u32.mod_sqrt(13), Some(6));
+/// assert_eq!(5u64.mod_sqrt(1000000007), None);
+/// assert_eq!(2u64.mod_sqrt(1000000007), Some(59713600));
+/// ```
+///
+/// # mod_prime_power_sqrt
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+///
+/// assert_eq!(2u8.mod_prime_power_sqrt(7, 2), Some(10));
+/// assert_eq!(17u16.mod_prime_power_sqrt(2, 10), Some(233));
+/// assert_eq!(3u16.mod_prime_power_sqrt(2, 10), None);
+/// assert_eq!(18u32.mod_prime_power_sqrt(3, 4), None);
+/// assert_eq!(36u32.mod_prime_power_sqrt(3, 4), Some(21));
+/// ```
+///
+/// # mod_factored_sqrt
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModFactoredSqrt;
+///
+/// // 100 = 2^2 * 5^2
+/// assert_eq!(41u8.mod_factored_sqrt(&[(2, 2), (5, 2)]), Some(29));
+/// assert_eq!(2u8.mod_factored_sqrt(&[(2, 2), (5, 2)]), None);
+/// assert_eq!(4u32.mod_factored_sqrt(&[(3, 1), (5, 1), (7, 1)]), Some(37));
+/// ```
+pub mod mod_sqrt;
 /// Traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](traits::ModSquare), [`ModSquareAssign`](traits::ModSquareAssign),
@@ -2511,8 +2635,9 @@ pub mod primorial;
 ///
 /// The traits are [`FloorRoot`](traits::FloorRoot), [`FloorRootAssign`](traits::FloorRootAssign),
 /// [`CeilingRoot`](traits::CeilingRoot), [`CeilingRootAssign`](traits::CeilingRootAssign),
-/// [`CheckedRoot`](traits::CheckedRoot), [`RootRem`](traits::RootRem), and
-/// [`RootAssignRem`](traits::RootAssignRem).
+/// [`CheckedRoot`](traits::CheckedRoot), [`RootRem`](traits::RootRem),
+/// [`RootAssignRem`](traits::RootAssignRem), [`IsPerfectPower`](traits::IsPerfectPower), and
+/// [`PerfectPowerDecomposition`](traits::PerfectPowerDecomposition).
 ///
 /// # floor_root
 /// ```
@@ -2629,6 +2754,37 @@ pub mod primorial;
 /// assert_eq!(x.root_assign_rem(5), 1534195232);
 /// assert_eq!(x, 158);
 /// ```
+///
+/// # is_perfect_power
+/// ```
+/// use malachite_base::num::arithmetic::traits::IsPerfectPower;
+///
+/// assert_eq!(0u8.is_perfect_power(), true);
+/// assert_eq!(1u8.is_perfect_power(), true);
+/// assert_eq!(2u8.is_perfect_power(), false);
+/// assert_eq!(8u8.is_perfect_power(), true);
+/// assert_eq!(12u8.is_perfect_power(), false);
+/// assert_eq!(1000u16.is_perfect_power(), true);
+/// assert_eq!(1001u16.is_perfect_power(), false);
+/// assert_eq!((-1i8).is_perfect_power(), true);
+/// assert_eq!((-4i32).is_perfect_power(), false);
+/// assert_eq!((-64i32).is_perfect_power(), true);
+/// ```
+///
+/// # perfect_power_decomposition
+/// ```
+/// use malachite_base::num::arithmetic::traits::PerfectPowerDecomposition;
+///
+/// assert_eq!(0u8.perfect_power_decomposition(), (0, 1));
+/// assert_eq!(1u8.perfect_power_decomposition(), (1, 1));
+/// assert_eq!(12u8.perfect_power_decomposition(), (12, 1));
+/// assert_eq!(64u8.perfect_power_decomposition(), (2, 6));
+/// assert_eq!(1296u16.perfect_power_decomposition(), (6, 4));
+/// assert_eq!((-16i32).perfect_power_decomposition(), (-16, 1));
+/// assert_eq!((-64i32).perfect_power_decomposition(), (-4, 3));
+/// assert_eq!((-128i8).perfect_power_decomposition(), (-2, 7));
+/// assert_eq!((-729i16).perfect_power_decomposition(), (-9, 3));
+/// ```
 pub mod root;
 /// [`RotateLeft`](traits::RotateLeft), [`RotateLeftAssign`](traits::RotateLeftAssign),
 /// [`RotateRight`](traits::RotateRight), and [`RotateRightAssign`](traits::RotateRightAssign),
@@ -3382,8 +3538,9 @@ pub mod sign;
 ///
 /// The traits are [`FloorSqrt`](traits::FloorSqrt), [`FloorSqrtAssign`](traits::FloorSqrtAssign),
 /// [`CeilingSqrt`](traits::CeilingSqrt), [`CeilingSqrtAssign`](traits::CeilingSqrtAssign),
-/// [`CheckedSqrt`](traits::CheckedSqrt), [`SqrtRem`](traits::SqrtRem),
-/// [`SqrtAssignRem`](traits::SqrtAssignRem), and [`SqrtAssign`](traits::SqrtAssign).
+/// [`CheckedSqrt`](traits::CheckedSqrt), [`IsSquare`](traits::IsSquare),
+/// [`SqrtRem`](traits::SqrtRem), [`SqrtAssignRem`](traits::SqrtAssignRem), and
+/// [`SqrtAssign`](traits::SqrtAssign).
 ///
 /// # floor_sqrt
 /// ```
@@ -3468,6 +3625,19 @@ pub mod sign;
 /// assert_eq!(10000000000i64.checked_sqrt(), Some(100000));
 /// ```
 ///
+/// # is_square
+/// ```
+/// use malachite_base::num::arithmetic::traits::IsSquare;
+///
+/// assert_eq!(0u8.is_square(), true);
+/// assert_eq!(1u8.is_square(), true);
+/// assert_eq!(2u8.is_square(), false);
+/// assert_eq!(100u8.is_square(), true);
+/// assert_eq!(999999u32.is_square(), false);
+/// assert_eq!(1000000u32.is_square(), true);
+/// assert_eq!((-4i32).is_square(), false);
+/// ```
+///
 /// # sqrt_rem
 /// ```
 /// use malachite_base::num::arithmetic::traits::SqrtRem;
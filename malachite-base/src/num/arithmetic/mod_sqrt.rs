@@ -0,0 +1,327 @@
+use crate::num::arithmetic::traits::{ModFactoredSqrt, ModPrimePowerSqrt, ModSqrt, Parity};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::is_prime::mod_pow_unsigned;
+use core::cmp::min;
+
+// Finds a square root of `x` modulo `p` using Cipolla's algorithm, where `p` is an odd prime and
+// `x` is a nonzero quadratic residue modulo `p`. Returns `None` if `p` turns out not to be prime.
+fn mod_sqrt_cipolla<T: PrimitiveUnsigned>(x: T, p: T) -> Option<T> {
+    // Find a such that w = a ^ 2 - x is a quadratic nonresidue
+    let mut a = T::ONE;
+    let w = loop {
+        let w = a.mod_square(p).mod_sub(x, p);
+        match w.jacobi_symbol(p) {
+            -1 => break w,
+            0 => return Some(a),
+            _ => {}
+        }
+        a += T::ONE;
+        if a == p {
+            return None;
+        }
+    };
+    // Compute (a + ω) ^ ((p + 1) / 2) in F_p(ω), where ω ^ 2 = w. The result lies in F_p.
+    let exp: T = (p >> 1) + T::ONE;
+    let mut u = T::ONE;
+    let mut v = T::ZERO;
+    for i in (0..exp.significant_bits()).rev() {
+        let uv = u.mod_mul(v, p);
+        u = u.mod_square(p).mod_add(v.mod_square(p).mod_mul(w, p), p);
+        v = uv.mod_add(uv, p);
+        if exp.get_bit(i) {
+            (u, v) = (
+                u.mod_mul(a, p).mod_add(v.mod_mul(w, p), p),
+                u.mod_add(v.mod_mul(a, p), p),
+            );
+        }
+    }
+    Some(u)
+}
+
+// Finds a square root of `x` modulo `p` using the Tonelli-Shanks algorithm, where `p` is an odd
+// prime, `x` is a nonzero quadratic residue modulo `p`, and `s` is the number of trailing zeros of
+// `p - 1`. Returns `None` if `p` turns out not to be prime.
+fn mod_sqrt_tonelli_shanks<T: PrimitiveUnsigned>(x: T, p: T, s: u64) -> Option<T> {
+    let q = p >> s;
+    let mut z = T::TWO;
+    loop {
+        match z.jacobi_symbol(p) {
+            -1 => break,
+            0 => return None,
+            _ => {}
+        }
+        z += T::ONE;
+    }
+    let mut c = mod_pow_unsigned(z, q, p);
+    let mut r = mod_pow_unsigned(x, (q >> 1) + T::ONE, p);
+    let mut t = mod_pow_unsigned(x, q, p);
+    let mut m = s;
+    while t != T::ONE {
+        // Find the least i such that t ^ (2 ^ i) = 1
+        let mut i = 0;
+        let mut t_pow = t;
+        while t_pow != T::ONE {
+            t_pow.mod_square_assign(p);
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+        let mut b = c;
+        for _ in i + 1..m {
+            b.mod_square_assign(p);
+        }
+        r.mod_mul_assign(b, p);
+        c = b.mod_square(p);
+        t.mod_mul_assign(c, p);
+        m = i;
+    }
+    Some(r)
+}
+
+pub_test! {mod_sqrt_prime<T: PrimitiveUnsigned>(x: T, p: T) -> Option<T> {
+    assert!(x < p, "x must be reduced mod p, but {x} >= {p}");
+    if x == T::ZERO || p == T::TWO {
+        return Some(x);
+    }
+    if x.jacobi_symbol(p) == -1 {
+        return None;
+    }
+    let s = (p - T::ONE).trailing_zeros();
+    let r = match s {
+        // p = 3 mod 4: x ^ ((p + 1) / 4)
+        1 => mod_pow_unsigned(x, (p >> 2) + T::ONE, p),
+        // p = 5 mod 8: Atkin's algorithm
+        2 => {
+            let x_2 = x.mod_add(x, p);
+            let v = mod_pow_unsigned(x_2, p >> 3, p);
+            let i = x_2.mod_mul(v.mod_square(p), p);
+            x.mod_mul(v, p).mod_mul(i.mod_sub(T::ONE, p), p)
+        }
+        // Cipolla's algorithm is faster than Tonelli-Shanks when s(s - 1) > 8n + 20, where n is the
+        // bit length of p
+        _ if s * (s - 1) > (p.significant_bits() << 3) + 20 => mod_sqrt_cipolla(x, p)?,
+        _ => mod_sqrt_tonelli_shanks(x, p, s)?,
+    };
+    // If p is not prime, r may not be a square root
+    if r.mod_square(p) == x {
+        Some(min(r, p - r))
+    } else {
+        None
+    }
+}}
+
+// Finds a square root of `x` modulo `2 ^ k`, where `x` is odd and less than `2 ^ k`.
+fn mod_power_of_2_sqrt_odd<T: PrimitiveUnsigned>(x: T, k: u64) -> Option<T> {
+    match k {
+        1 => Some(T::ONE),
+        2 => {
+            if x.mod_power_of_2(2) == T::ONE {
+                Some(T::ONE)
+            } else {
+                None
+            }
+        }
+        _ => {
+            if x.mod_power_of_2(3) != T::ONE {
+                return None;
+            }
+            // If r ^ 2 = x mod 2 ^ i, then either r or r + 2 ^ (i - 1) is a square root of x mod
+            // 2 ^ (i + 1)
+            let mut r = T::ONE;
+            for i in 3..k {
+                if r.wrapping_square().wrapping_sub(x).mod_power_of_2(i + 1) != T::ZERO {
+                    r.set_bit(i - 1);
+                }
+            }
+            Some(r)
+        }
+    }
+}
+
+// Finds a square root of `x` modulo `p ^ k`, where `p` is an odd prime and `x` is less than `p ^ k`
+// and not divisible by `p`. The square root modulo `p` is lifted using Hensel's lemma, doubling the
+// exponent at each step.
+fn mod_odd_prime_power_sqrt_unit<T: PrimitiveUnsigned>(x: T, p: T, k: u64) -> Option<T> {
+    let mut r = mod_sqrt_prime(x % p, p)?;
+    let mut j = 1;
+    while j < k {
+        j = min(j << 1, k);
+        let m = p.pow(j);
+        let x_m = x % m;
+        let f = r.mod_square(m).mod_sub(x_m, m);
+        let inverse = r.mod_add(r, m).mod_inverse(m)?;
+        r.mod_sub_assign(f.mod_mul(inverse, m), m);
+    }
+    Some(r)
+}
+
+fn mod_prime_power_sqrt<T: PrimitiveUnsigned>(x: T, p: T, k: u64) -> Option<T> {
+    let m = p
+        .checked_pow(k)
+        .expect("The modulus is too large to represent");
+    assert!(x < m, "x must be reduced mod p^k, but {x} >= {m}");
+    if x == T::ZERO {
+        return Some(x);
+    }
+    // Write x = p ^ e * u, where u is not divisible by p. Since x < p ^ k, e < k.
+    let (e, u) = if p == T::TWO {
+        let e = x.trailing_zeros();
+        (e, x >> e)
+    } else {
+        let mut e = 0;
+        let mut u = x;
+        while (u % p) == T::ZERO {
+            u /= p;
+            e += 1;
+        }
+        (e, u)
+    };
+    if e.odd() {
+        return None;
+    }
+    // If y ^ 2 = u mod p ^ (k - e), then (p ^ (e / 2) * y) ^ 2 = x mod p ^ k
+    let y = if p == T::TWO {
+        mod_power_of_2_sqrt_odd(u, k - e)?
+    } else {
+        mod_odd_prime_power_sqrt_unit(u, p, k - e)?
+    };
+    let r = y * p.pow(e >> 1);
+    Some(min(r, m - r))
+}
+
+fn mod_factored_sqrt<T: PrimitiveUnsigned>(x: T, factors: &[(T, u64)]) -> Option<T> {
+    let mut m = T::ONE;
+    for &(p, k) in factors {
+        m = p
+            .checked_pow(k)
+            .and_then(|q| m.checked_mul(q))
+            .expect("The modulus is too large to represent");
+    }
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    // Combine the square roots modulo each prime power using the Chinese remainder theorem
+    let mut r = T::ZERO;
+    let mut m = T::ONE;
+    for &(p, k) in factors {
+        if k == 0 {
+            continue;
+        }
+        let q = p.pow(k);
+        let r_q = mod_prime_power_sqrt(x % q, p, k)?;
+        let d = r_q
+            .mod_sub(r % q, q)
+            .mod_mul((m % q).mod_inverse(q).unwrap(), q);
+        r += m * d;
+        m *= q;
+    }
+    Some(min(r, m - r))
+}
+
+macro_rules! impl_mod_sqrt {
+    ($t:ident) => {
+        impl ModSqrt<$t> for $t {
+            type Output = $t;
+
+            /// Finds a square root of a number modulo a prime $p$. The input must be already
+            /// reduced modulo $p$.
+            ///
+            /// Returns `None` if `self` is not a quadratic residue modulo $p$. Otherwise, of the
+            /// two square roots $r$ and $p - r$, the smaller one is returned. If $p$ is not prime,
+            /// `None` may be returned even if a square root exists.
+            ///
+            /// $f(x, p) = y$, where $x, y < p$, $y \leq p - y$, and $y^2 \equiv x \mod p$.
+            ///
+            /// The square root is found using the Tonelli-Shanks algorithm, or Cipolla's algorithm
+            /// if $p - 1$ is divisible by a large power of 2.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `p`, or if `p` is even and not 2.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_sqrt).
+            #[inline]
+            fn mod_sqrt(self, p: $t) -> Option<$t> {
+                mod_sqrt_prime(self, p)
+            }
+        }
+
+        impl ModPrimePowerSqrt<$t> for $t {
+            type Output = $t;
+
+            /// Finds a square root of a number modulo a prime power $p^k$. The input must be
+            /// already reduced modulo $p^k$.
+            ///
+            /// Returns `None` if `self` is not a square modulo $p^k$. Otherwise, a square root $r$
+            /// with $r \leq p^k - r$ is returned. If $p$ is not prime, `None` may be returned even
+            /// if a square root exists.
+            ///
+            /// $f(x, p, k) = y$, where $x, y < p^k$, $y \leq p^k - y$, and $y^2 \equiv x \mod p^k$.
+            ///
+            /// A square root modulo $p$ is lifted to a square root modulo $p^k$ using Hensel's
+            /// lemma.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `Self::WIDTH`.
+            ///
+            /// # Panics
+            /// Panics if $p^k$ is too large to represent, if `self` is greater than or equal to
+            /// $p^k$, or if `p` is even and not 2.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_prime_power_sqrt).
+            #[inline]
+            fn mod_prime_power_sqrt(self, p: $t, k: u64) -> Option<$t> {
+                mod_prime_power_sqrt(self, p, k)
+            }
+        }
+
+        impl ModFactoredSqrt<$t> for $t {
+            type Output = $t;
+
+            /// Finds a square root of a number modulo $m$, given the factorization of $m$. The
+            /// input must be already reduced modulo $m$.
+            ///
+            /// The factorization is given as a list of distinct primes and their exponents, as
+            /// returned by [`factor`](crate::num::factorization::traits::Factor::factor). Returns
+            /// `None` if `self` is not a square modulo $m$. Otherwise, a square root $r$ with $r
+            /// \leq m - r$ is returned.
+            ///
+            /// $f(x, ((p_1, k_1), \ldots, (p_n, k_n))) = y$, where $m = \prod_{i=1}^n p_i^{k_i}$,
+            /// $x, y < m$, $y \leq m - y$, and $y^2 \equiv x \mod m$.
+            ///
+            /// Square roots modulo each prime power are found with
+            /// [`mod_prime_power_sqrt`](ModPrimePowerSqrt::mod_prime_power_sqrt) and combined
+            /// using the Chinese remainder theorem.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `Self::WIDTH`.
+            ///
+            /// # Panics
+            /// Panics if $m$ is too large to represent, if `self` is greater than or equal to $m$,
+            /// or if the primes are not distinct.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_factored_sqrt).
+            #[inline]
+            fn mod_factored_sqrt(self, factors: &[($t, u64)]) -> Option<$t> {
+                mod_factored_sqrt(self, factors)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_sqrt);
@@ -4,7 +4,8 @@ use crate::num::arithmetic::sqrt::floor_inverse_checked_binary;
 use crate::num::arithmetic::traits::DivRound;
 use crate::num::arithmetic::traits::{
     CeilingRoot, CeilingRootAssign, CeilingSqrt, CheckedRoot, CheckedSqrt, DivMod, FloorRoot,
-    FloorRootAssign, FloorSqrt, Parity, Pow, PowerOf2, RootAssignRem, RootRem, SqrtRem, XMulYToZZ,
+    FloorRootAssign, FloorSqrt, IsPerfectPower, Parity, PerfectPowerDecomposition, Pow, PowerOf2,
+    RootAssignRem, RootRem, SqrtRem, XMulYToZZ,
 };
 use crate::num::basic::floats::PrimitiveFloat;
 use crate::num::basic::integers::PrimitiveInt;
@@ -12,6 +13,7 @@ use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use crate::num::conversion::traits::{
     RawMantissaAndExponent, RoundingFrom, SaturatingFrom, WrappingFrom,
 };
+use crate::num::factorization::traits::Primes;
 use crate::num::logic::traits::{LowMask, SignificantBits};
 use crate::rounding_modes::RoundingMode;
 use core::cmp::Ordering;
@@ -1008,7 +1010,7 @@ pub_test! {fast_floor_root_u32(n: u32, exp: u64) -> u32 {
     let x = root_estimate_32(f64::from(n), exp_usize);
     // one round of Newton iteration
     let mut root = u32::rounding_from(
-        (f64::from(n / x.pow(exp - 1)) - f64::from(x)) * INV_TABLE[exp_usize],
+        (f64::from(n / x.saturating_pow(exp - 1)) - f64::from(x)) * INV_TABLE[exp_usize],
         RoundingMode::Down,
     ).0;
     if root >= upper_limit {
@@ -1099,7 +1101,7 @@ pub_test! {fast_ceiling_root_u32(n: u32, exp: u64) -> u32 {
     let x = root_estimate_32(f64::from(n), exp_usize);
     // one round of Newton iteration
     let mut root = u32::rounding_from(
-        (f64::from(n / x.pow(exp - 1)) - f64::from(x)) * INV_TABLE[exp_usize],
+        (f64::from(n / x.saturating_pow(exp - 1)) - f64::from(x)) * INV_TABLE[exp_usize],
         RoundingMode::Down,
     ).0;
     if root >= upper_limit {
@@ -1148,7 +1150,7 @@ pub_test! {fast_ceiling_root_u64(n: u64, exp: u64) -> u64 {
     let x = root_estimate_64(n as f64, exp_usize);
     // one round of Newton iteration
     let mut root = u64::rounding_from(
-        (((n / x.pow(exp - 1)) as f64) - x as f64) * INV_TABLE[exp_usize],
+        (((n / x.saturating_pow(exp - 1)) as f64) - x as f64) * INV_TABLE[exp_usize],
         RoundingMode::Down,
     ).0;
     if root >= upper_limit {
@@ -1197,7 +1199,7 @@ pub_test! {fast_checked_root_u32(n: u32, exp: u64) -> Option<u32> {
     let x = root_estimate_32(f64::from(n), exp_usize);
     // one round of Newton iteration
     let mut root = u32::rounding_from(
-        (f64::from(n / x.pow(exp - 1)) - f64::from(x)) * INV_TABLE[exp_usize],
+        (f64::from(n / x.saturating_pow(exp - 1)) - f64::from(x)) * INV_TABLE[exp_usize],
         RoundingMode::Down,
     ).0;
     if root >= upper_limit {
@@ -1246,7 +1248,7 @@ pub_test! {fast_checked_root_u64(n: u64, exp: u64) -> Option<u64> {
     let x = root_estimate_64(n as f64, exp_usize);
     // one round of Newton iteration
     let mut root = u64::rounding_from(
-        (((n / x.pow(exp - 1)) as f64) - x as f64) * INV_TABLE[exp_usize],
+        (((n / x.saturating_pow(exp - 1)) as f64) - x as f64) * INV_TABLE[exp_usize],
         RoundingMode::Down,
     ).0;
     if root >= upper_limit {
@@ -1296,7 +1298,7 @@ pub_test! {fast_root_rem_u32(n: u32, exp: u64) -> (u32, u32) {
     let x = root_estimate_32(f64::from(n), exp_usize);
     // one round of Newton iteration
     let mut root = u32::rounding_from(
-        (f64::from(n / x.pow(exp - 1)) - f64::from(x)) * INV_TABLE[exp_usize],
+        (f64::from(n / x.saturating_pow(exp - 1)) - f64::from(x)) * INV_TABLE[exp_usize],
         RoundingMode::Down,
     ).0;
     if root >= upper_limit {
@@ -1342,7 +1344,7 @@ pub_test! {fast_root_rem_u64(n: u64, exp: u64) -> (u64, u64) {
     let x = root_estimate_64(n as f64, exp_usize);
     // one round of Newton iteration
     let mut root = u64::rounding_from(
-        (((n / x.pow(exp - 1)) as f64) - x as f64) * INV_TABLE[exp_usize],
+        (((n / x.saturating_pow(exp - 1)) as f64) - x as f64) * INV_TABLE[exp_usize],
         RoundingMode::Down,
     ).0;
     if root >= upper_limit {
@@ -2323,3 +2325,143 @@ macro_rules! impl_root_primitive_int {
     };
 }
 apply_to_primitive_ints!(impl_root_primitive_int);
+
+fn is_perfect_power_unsigned<T: PrimitiveUnsigned>(x: T) -> bool {
+    if x <= T::ONE {
+        return true;
+    }
+    // If x = a^k with a >= 2, then k < x.significant_bits(). It suffices to check prime k.
+    let bits = x.significant_bits();
+    u64::primes()
+        .take_while(|&p| p < bits)
+        .any(|p| x.checked_root(p).is_some())
+}
+
+fn perfect_power_decomposition_unsigned<T: PrimitiveUnsigned>(x: T) -> (T, u64) {
+    if x <= T::ONE {
+        return (x, 1);
+    }
+    // Take prime roots, smallest prime first, for as long as possible. What remains is not a
+    // perfect power, and the product of the primes is the largest possible exponent.
+    let mut base = x;
+    let mut exp = 1;
+    for p in u64::primes() {
+        if p >= base.significant_bits() {
+            break;
+        }
+        while let Some(root) = base.checked_root(p) {
+            base = root;
+            exp *= p;
+        }
+    }
+    (base, exp)
+}
+
+macro_rules! impl_perfect_power_unsigned {
+    ($t: ident) => {
+        impl IsPerfectPower for $t {
+            /// Determines whether an integer is a perfect power; that is, whether it is equal to
+            /// $a^k$ for some integers $a$ and $k \geq 2$.
+            ///
+            /// 0 and 1 are considered perfect powers.
+            ///
+            /// $f(x) = (\exists a, k \in \N : k \geq 2 \land x = a^k)$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::root#is_perfect_power).
+            #[inline]
+            fn is_perfect_power(&self) -> bool {
+                is_perfect_power_unsigned(*self)
+            }
+        }
+
+        impl PerfectPowerDecomposition for $t {
+            type Output = $t;
+
+            /// Writes an integer as $a^k$, where $k$ is as large as possible, and returns
+            /// $(a, k)$.
+            ///
+            /// If the integer is not a perfect power, $k$ is 1. Since 0 and 1 are $k$th powers for
+            /// every $k$, they are returned with an exponent of 1.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::root#perfect_power_decomposition).
+            #[inline]
+            fn perfect_power_decomposition(self) -> ($t, u64) {
+                perfect_power_decomposition_unsigned(self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_perfect_power_unsigned);
+
+macro_rules! impl_perfect_power_signed {
+    ($t: ident) => {
+        impl IsPerfectPower for $t {
+            /// Determines whether an integer is a perfect power; that is, whether it is equal to
+            /// $a^k$ for some integers $a$ and $k \geq 2$.
+            ///
+            /// 0, 1, and -1 are considered perfect powers. A negative integer can only be an odd
+            /// power.
+            ///
+            /// $f(x) = (\exists a \in \Z, k \in \N : k \geq 2 \land x = a^k)$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::root#is_perfect_power).
+            #[inline]
+            fn is_perfect_power(&self) -> bool {
+                if *self >= -1 {
+                    self.unsigned_abs().is_perfect_power()
+                } else {
+                    let exp = self.unsigned_abs().perfect_power_decomposition().1;
+                    exp >> exp.trailing_zeros() != 1
+                }
+            }
+        }
+
+        impl PerfectPowerDecomposition for $t {
+            type Output = $t;
+
+            /// Writes an integer as $a^k$, where $k$ is as large as possible, and returns
+            /// $(a, k)$.
+            ///
+            /// If the integer is not a perfect power, $k$ is 1. Since 0, 1, and -1 are $k$th
+            /// powers for infinitely many $k$, they are returned with an exponent of 1. If the
+            /// integer is negative, $k$ is odd.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::root#perfect_power_decomposition).
+            fn perfect_power_decomposition(self) -> ($t, u64) {
+                let (base, exp) = self.unsigned_abs().perfect_power_decomposition();
+                if self >= 0 {
+                    ($t::wrapping_from(base), exp)
+                } else {
+                    // -a^k = (-a^(2^s))^m, where k = 2^s * m and m is odd.
+                    let zeros = exp.trailing_zeros();
+                    let odd_exp = exp >> zeros;
+                    if odd_exp == 1 {
+                        (self, 1)
+                    } else {
+                        (
+                            $t::wrapping_from(base.pow(1 << zeros)).wrapping_neg(),
+                            odd_exp,
+                        )
+                    }
+                }
+            }
+        }
+    };
+}
+apply_to_signeds!(impl_perfect_power_signed);
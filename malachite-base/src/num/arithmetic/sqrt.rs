@@ -1,12 +1,12 @@
 use crate::num::arithmetic::traits::{
-    CeilingSqrt, CeilingSqrtAssign, CheckedSqrt, FloorSqrt, FloorSqrtAssign,
+    CeilingSqrt, CeilingSqrtAssign, CheckedSqrt, FloorSqrt, FloorSqrtAssign, IsSquare,
     RoundToMultipleOfPowerOf2, ShrRound, Sqrt, SqrtAssign, SqrtAssignRem, SqrtRem,
 };
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::basic::signeds::PrimitiveSigned;
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use crate::num::conversion::traits::WrappingFrom;
-use crate::num::logic::traits::SignificantBits;
+use crate::num::logic::traits::{BitAccess, SignificantBits};
 use crate::rounding_modes::RoundingMode;
 use core::cmp::Ordering;
 
@@ -1120,6 +1120,53 @@ macro_rules! impl_sqrt_assign_rem_unsigned {
 }
 apply_to_unsigneds!(impl_sqrt_assign_rem_unsigned);
 
+// Bit r of this mask is set if and only if r is a square mod 64. Only 12 of the 64 residues are
+// squares, so checking the low bits rejects most non-squares before any root is taken.
+const SQUARES_MOD_64: u64 = 0x202021202030213;
+
+macro_rules! impl_is_square_unsigned {
+    ($t: ident) => {
+        impl IsSquare for $t {
+            /// Determines whether an integer is a perfect square.
+            ///
+            /// $f(x) = (\exists k \in \N : x = k^2)$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::sqrt#is_square).
+            #[inline]
+            fn is_square(&self) -> bool {
+                SQUARES_MOD_64.get_bit(u64::wrapping_from(*self) & 63)
+                    && self.checked_sqrt().is_some()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_is_square_unsigned);
+
+macro_rules! impl_is_square_signed {
+    ($t: ident) => {
+        impl IsSquare for $t {
+            /// Determines whether an integer is a perfect square.
+            ///
+            /// $f(x) = (\exists k \in \N : x = k^2)$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::sqrt#is_square).
+            #[inline]
+            fn is_square(&self) -> bool {
+                *self >= 0 && self.unsigned_abs().is_square()
+            }
+        }
+    };
+}
+apply_to_signeds!(impl_is_square_signed);
+
 macro_rules! impl_sqrt_assign {
     ($t: ident) => {
         impl FloorSqrtAssign for $t {
@@ -361,6 +361,31 @@ pub trait CheckedSubfactorial: Sized {
     fn checked_subfactorial(n: u64) -> Option<Self>;
 }
 
+/// Computes Fibonacci and Lucas numbers.
+pub trait Fibonacci: Sized {
+    fn fibonacci(n: u64) -> Self;
+
+    fn fibonacci2(n: u64) -> (Self, Self);
+
+    fn lucas(n: u64) -> Self;
+}
+
+/// Computes Fibonacci and Lucas numbers, returning `None` if the result is not representable.
+pub trait CheckedFibonacci: Sized {
+    fn checked_fibonacci(n: u64) -> Option<Self>;
+
+    fn checked_fibonacci2(n: u64) -> Option<(Self, Self)>;
+
+    fn checked_lucas(n: u64) -> Option<Self>;
+}
+
+/// Computes a Fibonacci number modulo another number.
+pub trait FibonacciMod<M = Self> {
+    type Output;
+
+    fn fibonacci_mod(n: u64, m: M) -> Self::Output;
+}
+
 /// Takes the floor of a number.
 pub trait Floor {
     type Output;
@@ -932,6 +957,31 @@ pub trait ModSquarePrecomputedAssign<RHS = Self, M = Self>: ModPowPrecomputed<RH
     fn mod_square_precomputed_assign(&mut self, m: M, data: &Self::Data);
 }
 
+/// Finds a square root of a number modulo a prime $p$, or returns `None` if there is none. The
+/// input must be already reduced modulo $p$.
+pub trait ModSqrt<M = Self> {
+    type Output;
+
+    fn mod_sqrt(self, p: M) -> Option<Self::Output>;
+}
+
+/// Finds a square root of a number modulo a prime power $p^k$, or returns `None` if there is none.
+/// The input must be already reduced modulo $p^k$.
+pub trait ModPrimePowerSqrt<M = Self> {
+    type Output;
+
+    fn mod_prime_power_sqrt(self, p: M, k: u64) -> Option<Self::Output>;
+}
+
+/// Finds a square root of a number modulo $m$, given the factorization of $m$ as a list of distinct
+/// primes and their exponents. Returns `None` if there is no square root. The input must be already
+/// reduced modulo $m$.
+pub trait ModFactoredSqrt<M = Self> {
+    type Output;
+
+    fn mod_factored_sqrt(self, factors: &[(M, u64)]) -> Option<Self::Output>;
+}
+
 /// Adds two numbers modulo a third number $m$. The inputs must be already reduced modulo $m$.
 pub trait ModSub<RHS = Self, M = Self> {
     type Output;
@@ -1225,6 +1275,19 @@ pub trait CheckedRoot<POW> {
     fn checked_root(self, pow: POW) -> Option<Self::Output>;
 }
 
+/// Determines whether a number is a perfect power; that is, whether it is equal to $a^k$ for some
+/// integers $a$ and $k \geq 2$.
+pub trait IsPerfectPower {
+    fn is_perfect_power(&self) -> bool;
+}
+
+/// Writes a number as $a^k$, where $k$ is as large as possible.
+pub trait PerfectPowerDecomposition {
+    type Output;
+
+    fn perfect_power_decomposition(self) -> (Self::Output, u64);
+}
+
 /// Finds the floor of the $n$th root of a number, returning both the root and the remainder.
 pub trait RootRem<POW> {
     type RootOutput;
@@ -1505,6 +1568,11 @@ pub trait CheckedSqrt {
     fn checked_sqrt(self) -> Option<Self::Output>;
 }
 
+/// Determines whether a number is a perfect square.
+pub trait IsSquare {
+    fn is_square(&self) -> bool;
+}
+
 /// Finds the floor of the square root of a number, returning both the root and the remainder.
 pub trait SqrtRem {
     type SqrtOutput;
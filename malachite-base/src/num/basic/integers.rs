@@ -7,12 +7,13 @@ use crate::num::arithmetic::traits::{
     CheckedSqrt, CheckedSquare, CheckedSub, CheckedSubMul, DivAssignMod, DivAssignRem, DivExact,
     DivExactAssign, DivMod, DivRem, DivRound, DivRoundAssign, DivisibleBy, DivisibleByPowerOf2,
     EqMod, EqModPowerOf2, ExtendedGcd, FloorRoot, FloorRootAssign, FloorSqrt, FloorSqrtAssign,
-    JacobiSymbol, KroneckerSymbol, LegendreSymbol, Mod, ModAssign, ModPowerOf2, ModPowerOf2Assign,
-    OverflowingAdd, OverflowingAddAssign, OverflowingAddMul, OverflowingAddMulAssign,
-    OverflowingDiv, OverflowingDivAssign, OverflowingMul, OverflowingMulAssign, OverflowingNeg,
-    OverflowingNegAssign, OverflowingPow, OverflowingPowAssign, OverflowingSquare,
-    OverflowingSquareAssign, OverflowingSub, OverflowingSubAssign, OverflowingSubMul,
-    OverflowingSubMulAssign, Parity, Pow, PowAssign, PowerOf2, RemPowerOf2, RemPowerOf2Assign,
+    IsPerfectPower, IsSquare, JacobiSymbol, KroneckerSymbol, LegendreSymbol, Mod, ModAssign,
+    ModPowerOf2, ModPowerOf2Assign, OverflowingAdd, OverflowingAddAssign, OverflowingAddMul,
+    OverflowingAddMulAssign, OverflowingDiv, OverflowingDivAssign, OverflowingMul,
+    OverflowingMulAssign, OverflowingNeg, OverflowingNegAssign, OverflowingPow,
+    OverflowingPowAssign, OverflowingSquare, OverflowingSquareAssign, OverflowingSub,
+    OverflowingSubAssign, OverflowingSubMul, OverflowingSubMulAssign, Parity,
+    PerfectPowerDecomposition, Pow, PowAssign, PowerOf2, RemPowerOf2, RemPowerOf2Assign,
     RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign, RoundToMultiple,
     RoundToMultipleAssign, RoundToMultipleOfPowerOf2, RoundToMultipleOfPowerOf2Assign,
     SaturatingAdd, SaturatingAddAssign, SaturatingAddMul, SaturatingAddMulAssign, SaturatingMul,
@@ -184,6 +185,8 @@ pub trait PrimitiveInt:
     + HasRandomPrimitiveInts
     + Hash
     + IsInteger
+    + IsPerfectPower
+    + IsSquare
     + JacobiSymbol<Self>
     + KroneckerSymbol<Self>
     + LeadingZeros
@@ -251,6 +254,7 @@ pub trait PrimitiveInt:
     + PartialEq<Self>
     + PartialOrd<Self>
     + PartialOrdAbs<Self>
+    + PerfectPowerDecomposition<Output = Self>
     + Pow<u64, Output = Self>
     + PowAssign<u64>
     + PowerOf2<u64>
@@ -657,6 +661,8 @@ pub trait PrimitiveInt:
     + FromStringBase
     + Hash
     + IsInteger
+    + IsPerfectPower
+    + IsSquare
     + JacobiSymbol<Self>
     + KroneckerSymbol<Self>
     + LeadingZeros
@@ -724,6 +730,7 @@ pub trait PrimitiveInt:
     + PartialEq<Self>
     + PartialOrd<Self>
     + PartialOrdAbs<Self>
+    + PerfectPowerDecomposition<Output = Self>
     + Pow<u64, Output = Self>
     + PowAssign<u64>
     + PowerOf2<u64>
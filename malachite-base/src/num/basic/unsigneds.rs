@@ -1,20 +1,22 @@
 use crate::num::arithmetic::traits::{
     CeilingDivAssignNegMod, CeilingDivNegMod, CeilingLogBase, CeilingLogBase2,
-    CeilingLogBasePowerOf2, CheckedDoubleFactorial, CheckedFactorial, CheckedLcm, CheckedLogBase,
-    CheckedLogBase2, CheckedLogBasePowerOf2, CheckedMultifactorial, CheckedNextPowerOf2,
-    CheckedPrimorial, CheckedSubfactorial, CoprimeWith, DoubleFactorial, ExtendedGcd, Factorial,
-    FloorLogBase, FloorLogBase2, FloorLogBasePowerOf2, Gcd, GcdAssign, IsPowerOf2, Lcm, LcmAssign,
-    ModAdd, ModAddAssign, ModInverse, ModIsReduced, ModMul, ModMulAssign, ModMulPrecomputed,
+    CeilingLogBasePowerOf2, CheckedDoubleFactorial, CheckedFactorial, CheckedFibonacci, CheckedLcm,
+    CheckedLogBase, CheckedLogBase2, CheckedLogBasePowerOf2, CheckedMultifactorial,
+    CheckedNextPowerOf2, CheckedPrimorial, CheckedSubfactorial, CoprimeWith, DoubleFactorial,
+    ExtendedGcd, Factorial, Fibonacci, FibonacciMod, FloorLogBase, FloorLogBase2,
+    FloorLogBasePowerOf2, Gcd, GcdAssign, IsPowerOf2, Lcm, LcmAssign, ModAdd, ModAddAssign,
+    ModFactoredSqrt, ModInverse, ModIsReduced, ModMul, ModMulAssign, ModMulPrecomputed,
     ModMulPrecomputedAssign, ModNeg, ModNegAssign, ModPow, ModPowAssign, ModPowPrecomputed,
     ModPowPrecomputedAssign, ModPowerOf2, ModPowerOf2Add, ModPowerOf2AddAssign, ModPowerOf2Inverse,
     ModPowerOf2IsReduced, ModPowerOf2Mul, ModPowerOf2MulAssign, ModPowerOf2Neg,
     ModPowerOf2NegAssign, ModPowerOf2Pow, ModPowerOf2PowAssign, ModPowerOf2Shl,
     ModPowerOf2ShlAssign, ModPowerOf2Shr, ModPowerOf2ShrAssign, ModPowerOf2Square,
-    ModPowerOf2SquareAssign, ModPowerOf2Sub, ModPowerOf2SubAssign, ModSquare, ModSquareAssign,
-    ModSquarePrecomputed, ModSquarePrecomputedAssign, ModSub, ModSubAssign, Multifactorial, NegMod,
-    NegModAssign, NegModPowerOf2, NegModPowerOf2Assign, NextPowerOf2, NextPowerOf2Assign,
-    Primorial, RootAssignRem, RootRem, SqrtAssignRem, SqrtRem, Subfactorial, XMulYToZZ,
-    XXAddYYToZZ, XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ, XXXSubYYYToZZZ, XXXXAddYYYYToZZZZ,
+    ModPowerOf2SquareAssign, ModPowerOf2Sub, ModPowerOf2SubAssign, ModPrimePowerSqrt, ModSqrt,
+    ModSquare, ModSquareAssign, ModSquarePrecomputed, ModSquarePrecomputedAssign, ModSub,
+    ModSubAssign, Multifactorial, NegMod, NegModAssign, NegModPowerOf2, NegModPowerOf2Assign,
+    NextPowerOf2, NextPowerOf2Assign, Primorial, RootAssignRem, RootRem, SqrtAssignRem, SqrtRem,
+    Subfactorial, XMulYToZZ, XXAddYYToZZ, XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ,
+    XXXSubYYYToZZZ, XXXXAddYYYYToZZZZ,
 };
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::conversion::traits::{
@@ -22,7 +24,7 @@ use crate::num::conversion::traits::{
     SciMantissaAndExponent, VecFromOtherType, VecFromOtherTypeSlice,
 };
 use crate::num::factorization::primes::{PrimesIterator, PrimesLessThanIterator};
-use crate::num::factorization::traits::Primes;
+use crate::num::factorization::traits::{Factor, IsPrime, NextPrime, PrevPrime, Primes};
 use crate::num::logic::traits::{BitBlockAccess, HammingDistance};
 
 /// Defines functions on primitive unsigned integer types: uxx and usize.
@@ -35,6 +37,7 @@ pub trait PrimitiveUnsigned:
     + CeilingDivNegMod<Self, DivOutput = Self, ModOutput = Self>
     + CheckedDoubleFactorial
     + CheckedFactorial
+    + CheckedFibonacci
     + CheckedMultifactorial
     + CheckedPrimorial
     + CheckedSubfactorial
@@ -52,7 +55,10 @@ pub trait PrimitiveUnsigned:
     + Digits<u128>
     + Digits<usize>
     + ExtendedGcd<Self, Gcd = Self>
+    + Factor
     + Factorial
+    + Fibonacci
+    + FibonacciMod<Self, Output = Self>
     + FloorLogBase<Output = u64>
     + FloorLogBase2<Output = u64>
     + FloorLogBasePowerOf2<u64, Output = u64>
@@ -68,11 +74,13 @@ pub trait PrimitiveUnsigned:
     + HammingDistance
     + IntegerMantissaAndExponent<Self, u64>
     + IsPowerOf2
+    + IsPrime
     + Lcm<Self, Output = Self>
     + LcmAssign<Self>
     + ModIsReduced<Self>
     + ModAdd<Self, Self, Output = Self>
     + ModAddAssign<Self, Self>
+    + ModFactoredSqrt<Self, Output = Self>
     + ModInverse<Self, Output = Self>
     + ModMul<Self, Self, Output = Self>
     + ModMulAssign<Self, Self>
@@ -132,6 +140,8 @@ pub trait PrimitiveUnsigned:
     + ModPowerOf2SubAssign<Self>
     + ModPowPrecomputed<u64, Self, Output = Self>
     + ModPowPrecomputedAssign<u64, Self>
+    + ModPrimePowerSqrt<Self, Output = Self>
+    + ModSqrt<Self, Output = Self>
     + ModSquare<Self, Output = Self>
     + ModSquareAssign<Self>
     + ModSquarePrecomputed<u64, Self, Output = Self>
@@ -145,6 +155,7 @@ pub trait PrimitiveUnsigned:
     + NegModPowerOf2Assign
     + NextPowerOf2<Output = Self>
     + NextPowerOf2Assign
+    + NextPrime
     + PowerOf2Digits<u8>
     + PowerOf2Digits<u16>
     + PowerOf2Digits<u32>
@@ -157,6 +168,7 @@ pub trait PrimitiveUnsigned:
     + PowerOf2DigitIterable<u64>
     + PowerOf2DigitIterable<u128>
     + PowerOf2DigitIterable<usize>
+    + PrevPrime
     + Primes<I = PrimesIterator<Self>, LI = PrimesLessThanIterator<Self>>
     + PrimitiveInt
     + Primorial
@@ -0,0 +1,161 @@
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::SaturatingInto;
+use crate::num::factorization::traits::Factor;
+use alloc::vec::Vec;
+use core::cmp::min;
+
+// The primes less than 1000.
+const SMALL_PRIMES: [u16; 168] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251, 257, 263, 269, 271, 277, 281, 283, 293, 307,
+    311, 313, 317, 331, 337, 347, 349, 353, 359, 367, 373, 379, 383, 389, 397, 401, 409, 419, 421,
+    431, 433, 439, 443, 449, 457, 461, 463, 467, 479, 487, 491, 499, 503, 509, 521, 523, 541, 547,
+    557, 563, 569, 571, 577, 587, 593, 599, 601, 607, 613, 617, 619, 631, 641, 643, 647, 653, 659,
+    661, 673, 677, 683, 691, 701, 709, 719, 727, 733, 739, 743, 751, 757, 761, 769, 773, 787, 797,
+    809, 811, 821, 823, 827, 829, 839, 853, 857, 859, 863, 877, 881, 883, 887, 907, 911, 919, 929,
+    937, 941, 947, 953, 967, 971, 977, 983, 991, 997,
+];
+
+const RHO_BATCH_SIZE: u64 = 128;
+
+// Finds a nontrivial factor of `n` using Brent's variant of Pollard's rho algorithm, iterating
+// x -> x ^ 2 + c. `n` must be odd and composite, and `c` must be less than `n`. Returns `None` if
+// the sequence cycles modulo `n` before a factor is found, in which case another `c` should be
+// tried.
+pub_test! {pollard_brent_rho<T: PrimitiveUnsigned>(n: T, c: T) -> Option<T> {
+    let data = T::precompute_mod_mul_data(&n);
+    let f = |x: T| x.mod_mul_precomputed(x, n, &data).mod_add(c, n);
+    let mut x;
+    let mut y = T::TWO;
+    let mut ys = y;
+    let mut q = T::ONE;
+    let mut g = T::ONE;
+    let mut r = 1u64;
+    loop {
+        x = y;
+        for _ in 0..r {
+            y = f(y);
+        }
+        let mut k = 0;
+        while k < r && g == T::ONE {
+            ys = y;
+            // Accumulate a batch of differences, so that only one gcd needs to be computed
+            for _ in 0..min(RHO_BATCH_SIZE, r - k) {
+                y = f(y);
+                q.mod_mul_precomputed_assign(x.mod_sub(y, n), n, &data);
+            }
+            g = q.gcd(n);
+            k += RHO_BATCH_SIZE;
+        }
+        if g != T::ONE {
+            break;
+        }
+        r <<= 1;
+    }
+    if g == n {
+        // The batch overshot; step through it one difference at a time
+        loop {
+            ys = f(ys);
+            g = x.mod_sub(ys, n).gcd(n);
+            if g != T::ONE {
+                break;
+            }
+        }
+    }
+    if g == n {
+        None
+    } else {
+        Some(g)
+    }
+}}
+
+// Pushes the prime factors of `n`, with multiplicity, onto `primes`. `n` must be odd.
+fn split_unsigned<T: PrimitiveUnsigned>(n: T, primes: &mut Vec<T>) {
+    if n == T::ONE {
+        return;
+    } else if n.is_prime() {
+        primes.push(n);
+        return;
+    } else if T::WIDTH > u64::WIDTH && n.significant_bits() <= u64::WIDTH {
+        // Arithmetic is faster with a narrower type
+        let mut narrow_primes = Vec::new();
+        split_unsigned::<u64>(n.saturating_into(), &mut narrow_primes);
+        primes.extend(narrow_primes.into_iter().map(T::wrapping_from));
+        return;
+    }
+    let mut c = T::ONE;
+    let d = loop {
+        if let Some(d) = pollard_brent_rho(n, c) {
+            break d;
+        }
+        c += T::ONE;
+    };
+    split_unsigned(d, primes);
+    split_unsigned(n / d, primes);
+}
+
+fn factor_unsigned<T: PrimitiveUnsigned>(mut n: T) -> Vec<(T, u64)> {
+    assert_ne!(n, T::ZERO, "Cannot factor 0");
+    let mut factors = Vec::new();
+    for p in SMALL_PRIMES {
+        let p = u64::from(p);
+        if p * p > SaturatingInto::<u64>::saturating_into(n) {
+            // Whatever remains has no factors less than or equal to its square root
+            if n != T::ONE {
+                factors.push((n, 1));
+            }
+            return factors;
+        }
+        let p = T::wrapping_from(p);
+        let mut exponent = 0;
+        while n.divisible_by(p) {
+            n /= p;
+            exponent += 1;
+        }
+        if exponent != 0 {
+            factors.push((p, exponent));
+        }
+    }
+    let mut primes = Vec::new();
+    split_unsigned(n, &mut primes);
+    primes.sort_unstable();
+    for p in primes {
+        match factors.last_mut() {
+            Some((q, exponent)) if *q == p => *exponent += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+macro_rules! impl_factor {
+    ($t:ident) => {
+        impl Factor for $t {
+            /// Returns the prime factorization of a number, as a list of pairs of primes and
+            /// exponents, sorted by prime.
+            ///
+            /// Factors less than 1000 are found by trial division. Any remaining composite
+            /// cofactor is split using Brent's variant of Pollard's rho algorithm.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{1/4})$
+            ///
+            /// $M(n) = O(\log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::factor#factor).
+            #[inline]
+            fn factor(&self) -> Vec<($t, u64)> {
+                factor_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_factor);
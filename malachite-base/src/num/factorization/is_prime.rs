@@ -0,0 +1,191 @@
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::{SaturatingInto, WrappingFrom};
+use crate::num::factorization::traits::{IsPrime, Primes};
+
+// The odd primes used for trial division. Any odd composite number with no factor in this list is
+// at least 59 ^ 2.
+const SMALL_ODD_PRIMES: [u8; 15] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+const TRIAL_DIVISION_LIMIT: u64 = 59 * 59;
+
+// Computes `x ^ exp` mod `m`. `ModPow` takes a `u64` exponent, so an exponent that doesn't fit
+// (which only happens when `T` is `u128`) is split into its high and low 64 bits.
+pub(crate) fn mod_pow_unsigned<T: PrimitiveUnsigned>(x: T, exp: T, m: T) -> T {
+    if exp.significant_bits() <= u64::WIDTH {
+        return x.mod_pow(exp.wrapping_into(), m);
+    }
+    let mut out = x.mod_pow((exp >> u64::WIDTH).wrapping_into(), m);
+    for _ in 0..u64::WIDTH {
+        out.mod_square_assign(m);
+    }
+    out.mod_mul(x.mod_pow(exp.wrapping_into(), m), m)
+}
+
+// Returns (x / 2) mod n, where x < n and n is odd.
+fn mod_half<T: PrimitiveUnsigned>(x: T, n: T) -> T {
+    if x.even() {
+        x >> 1
+    } else {
+        (x >> 1) + (n >> 1) + T::ONE
+    }
+}
+
+// Determines whether `n` is a strong probable prime to base `base`, where `n` is odd and greater
+// than `base`.
+pub_test! {is_strong_probable_prime<T: PrimitiveUnsigned>(n: T, base: T) -> bool {
+    let n_minus_1 = n - T::ONE;
+    let s = n_minus_1.trailing_zeros();
+    let mut x = mod_pow_unsigned(base, n_minus_1 >> s, n);
+    if x == T::ONE || x == n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x.mod_square_assign(n);
+        if x == n_minus_1 {
+            return true;
+        } else if x == T::ONE {
+            return false;
+        }
+    }
+    false
+}}
+
+// Determines whether `n` is a strong Lucas probable prime, with parameters chosen using Selfridge's
+// method A. `n` must be odd, not a perfect square, and greater than `TRIAL_DIVISION_LIMIT`.
+pub_test! {is_strong_lucas_probable_prime<T: PrimitiveUnsigned>(n: T) -> bool {
+    // Find the first D in 5, -7, 9, -11, ... with Jacobi symbol (D / n) = -1.
+    let mut d_abs = 5u64;
+    let mut d_negative = false;
+    let d = loop {
+        let d = T::wrapping_from(d_abs);
+        let d = if d_negative { n - d } else { d };
+        match d.jacobi_symbol(n) {
+            -1 => break d,
+            // n shares a factor with D but is larger than D, so it is composite
+            0 => return false,
+            _ => {}
+        }
+        d_abs += 2;
+        d_negative = !d_negative;
+    };
+    // P = 1 and Q = (1 - D) / 4
+    let q = if d_negative {
+        T::wrapping_from((d_abs + 1) >> 2)
+    } else {
+        n - T::wrapping_from((d_abs - 1) >> 2)
+    };
+    // n + 1 = k * 2 ^ s, with k odd. n + 1 cannot overflow, since 2 ^ W - 1 is divisible by 3 and
+    // has already been eliminated by trial division.
+    let n_plus_1 = n + T::ONE;
+    let s = n_plus_1.trailing_zeros();
+    let k = n_plus_1 >> s;
+    // Compute U_k, V_k, and Q ^ k, starting from U_1 = 1, V_1 = P = 1.
+    let mut u = T::ONE;
+    let mut v = T::ONE;
+    let mut q_k = q;
+    for i in (0..k.significant_bits() - 1).rev() {
+        u.mod_mul_assign(v, n);
+        v = v.mod_square(n).mod_sub(q_k.mod_add(q_k, n), n);
+        q_k.mod_square_assign(n);
+        if k.get_bit(i) {
+            let new_u = mod_half(u.mod_add(v, n), n);
+            v = mod_half(d.mod_mul(u, n).mod_add(v, n), n);
+            u = new_u;
+            q_k.mod_mul_assign(q, n);
+        }
+    }
+    if u == T::ZERO || v == T::ZERO {
+        return true;
+    }
+    for _ in 1..s {
+        v = v.mod_square(n).mod_sub(q_k.mod_add(q_k, n), n);
+        if v == T::ZERO {
+            return true;
+        }
+        q_k.mod_square_assign(n);
+    }
+    false
+}}
+
+fn is_prime_unsigned<T: PrimitiveUnsigned>(n: T) -> bool {
+    if n < T::TWO {
+        return false;
+    } else if n.even() {
+        return n == T::TWO;
+    }
+    for p in SMALL_ODD_PRIMES {
+        let p = T::from(p);
+        if n == p {
+            return true;
+        } else if n.divisible_by(p) {
+            return false;
+        }
+    }
+    if SaturatingInto::<u64>::saturating_into(n) < TRIAL_DIVISION_LIMIT {
+        return true;
+    }
+    is_strong_probable_prime(n, T::TWO)
+        && n.checked_sqrt().is_none()
+        && is_strong_lucas_probable_prime(n)
+}
+
+fn is_probable_prime_unsigned<T: PrimitiveUnsigned>(n: T, reps: u64) -> bool {
+    if !is_prime_unsigned(n) {
+        return false;
+    } else if n.significant_bits() <= u64::WIDTH {
+        // BPSW is known to be correct below 2 ^ 64
+        return true;
+    }
+    u64::primes()
+        .skip(1)
+        .take(usize::wrapping_from(reps))
+        .all(|p| is_strong_probable_prime(n, T::wrapping_from(p)))
+}
+
+macro_rules! impl_is_prime {
+    ($t:ident) => {
+        impl IsPrime for $t {
+            /// Determines whether a number is prime.
+            ///
+            /// Small factors are removed by trial division, and then the Baillie-PSW test is
+            /// applied: a strong Fermat test to base 2, followed by a strong Lucas test. This is
+            /// known to be correct for all numbers less than $2^{64}$. For larger numbers it is
+            /// probabilistic, although no counterexample is known.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_prime#is_prime).
+            #[inline]
+            fn is_prime(&self) -> bool {
+                is_prime_unsigned(*self)
+            }
+
+            /// Determines whether a number is probably prime, running `reps` additional strong
+            /// Fermat tests after the Baillie-PSW test.
+            ///
+            /// The bases are fixed rather than random, as they are in GMP: the additional tests
+            /// use the odd primes $3, 5, 7, \ldots$, so the result is reproducible, but a
+            /// composite number that passes them could be constructed deliberately. Since the
+            /// Baillie-PSW test is known to be correct for all numbers less than $2^{64}$, the
+            /// additional tests are only run for larger numbers.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `reps`.
+            ///
+            /// # Examples
+            /// See [here](super::is_prime#is_probable_prime).
+            #[inline]
+            fn is_probable_prime(&self, reps: u64) -> bool {
+                is_probable_prime_unsigned(*self, reps)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_is_prime);
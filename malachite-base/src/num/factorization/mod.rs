@@ -1,3 +1,102 @@
+/// [`Factor`](traits::Factor), a trait for computing the prime factorization of a number.
+///
+/// # factor
+/// ```
+/// use malachite_base::num::factorization::traits::Factor;
+///
+/// assert_eq!(1u8.factor(), &[]);
+/// assert_eq!(12u8.factor(), &[(2, 2), (3, 1)]);
+/// assert_eq!(65535u16.factor(), &[(3, 1), (5, 1), (17, 1), (257, 1)]);
+/// assert_eq!(4294967297u64.factor(), &[(641, 1), (6700417, 1)]);
+/// assert_eq!(
+///     u64::MAX.factor(),
+///     &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]
+/// );
+/// assert_eq!(
+///     18446744073709551617u128.factor(),
+///     &[(274177, 1), (67280421310721, 1)]
+/// );
+/// ```
+pub mod factor;
+/// [`IsPrime`](traits::IsPrime), a trait for testing whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::factorization::traits::IsPrime;
+///
+/// assert_eq!(0u8.is_prime(), false);
+/// assert_eq!(1u16.is_prime(), false);
+/// assert_eq!(2u32.is_prime(), true);
+/// assert_eq!(97u8.is_prime(), true);
+/// assert_eq!(2047u16.is_prime(), false);
+/// assert_eq!(5459u32.is_prime(), false);
+/// assert_eq!(u64::MAX.is_prime(), false);
+/// assert_eq!(18446744073709551557u64.is_prime(), true);
+/// assert_eq!(170141183460469231731687303715884105727u128.is_prime(), true);
+/// ```
+///
+/// # is_probable_prime
+/// ```
+/// use malachite_base::num::factorization::traits::IsPrime;
+///
+/// assert_eq!(10u8.is_probable_prime(5), false);
+/// assert_eq!(65521u16.is_probable_prime(5), true);
+/// assert_eq!(18446744073709551557u64.is_probable_prime(0), true);
+/// assert_eq!(170141183460469231731687303715884105727u128.is_probable_prime(10), true);
+/// assert_eq!(170141183460469231731687303715884105729u128.is_probable_prime(10), false);
+/// ```
+pub mod is_prime;
+/// [`NextPrime`](traits::NextPrime) and [`PrevPrime`](traits::PrevPrime), traits for finding the
+/// primes adjacent to a number.
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NextPrime;
+///
+/// assert_eq!(0u8.next_prime(), 2);
+/// assert_eq!(2u8.next_prime(), 3);
+/// assert_eq!(100u16.next_prime(), 101);
+/// assert_eq!(1000000u32.next_prime(), 1000003);
+/// assert_eq!(10000000000000000000u64.next_prime(), 10000000000000000051);
+/// assert_eq!((u64::MAX as u128).next_prime(), 18446744073709551629);
+/// ```
+///
+/// # prev_prime
+/// ```
+/// use malachite_base::num::factorization::traits::PrevPrime;
+///
+/// assert_eq!(3u8.prev_prime(), 2);
+/// assert_eq!(u8::MAX.prev_prime(), 251);
+/// assert_eq!(100u16.prev_prime(), 97);
+/// assert_eq!(1000000u32.prev_prime(), 999983);
+/// assert_eq!(u64::MAX.prev_prime(), 18446744073709551557);
+/// ```
+pub mod next_prime;
+/// [`PrimePi`](traits::PrimePi) and [`NthPrime`](traits::NthPrime), traits for counting primes
+/// and finding the $n$th prime.
+///
+/// # prime_pi
+/// ```
+/// use malachite_base::num::factorization::traits::PrimePi;
+///
+/// assert_eq!(0u8.prime_pi(), 0);
+/// assert_eq!(10u8.prime_pi(), 4);
+/// assert_eq!(u16::MAX.prime_pi(), 6542);
+/// assert_eq!(1000000000u32.prime_pi(), 50847534);
+/// assert_eq!(10000000000u64.prime_pi(), 455052511);
+/// ```
+///
+/// # nth_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NthPrime;
+///
+/// assert_eq!(u8::nth_prime(1), 2);
+/// assert_eq!(u8::nth_prime(54), 251);
+/// assert_eq!(u16::nth_prime(1000), 7919);
+/// assert_eq!(u32::nth_prime(1000000), 15485863);
+/// assert_eq!(u64::nth_prime(1000000000), 22801763489);
+/// ```
+pub mod prime_pi;
 /// An efficient prime sieve.
 pub mod prime_sieve;
 /// [`Primes`](traits::Primes), a trait for generating prime numbers.
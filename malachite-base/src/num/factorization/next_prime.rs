@@ -0,0 +1,79 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{NextPrime, PrevPrime};
+
+fn next_prime_unsigned<T: PrimitiveUnsigned>(n: T) -> T {
+    if n < T::TWO {
+        return T::TWO;
+    }
+    // Start at the smallest odd number greater than `n`
+    let mut p = n
+        .checked_add(T::ONE)
+        .expect("Next prime is too large to represent")
+        | T::ONE;
+    loop {
+        if p.is_prime() {
+            return p;
+        }
+        p = p
+            .checked_add(T::TWO)
+            .expect("Next prime is too large to represent");
+    }
+}
+
+fn prev_prime_unsigned<T: PrimitiveUnsigned>(n: T) -> T {
+    assert!(n > T::TWO, "There are no primes less than {n}");
+    if n == T::from(3u8) {
+        return T::TWO;
+    }
+    // Start at the largest odd number less than `n`
+    let mut p = (n - T::TWO) | T::ONE;
+    while !p.is_prime() {
+        p -= T::TWO;
+    }
+    p
+}
+
+macro_rules! impl_next_prime {
+    ($t:ident) => {
+        impl NextPrime for $t {
+            /// Finds the smallest prime greater than a number.
+            ///
+            /// Odd numbers greater than `self` are tested in turn with
+            /// [`is_prime`](crate::num::factorization::traits::IsPrime::is_prime).
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the next prime is greater than the type's maximum value.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#next_prime).
+            #[inline]
+            fn next_prime(&self) -> $t {
+                next_prime_unsigned(*self)
+            }
+        }
+
+        impl PrevPrime for $t {
+            /// Finds the largest prime less than a number.
+            ///
+            /// Odd numbers less than `self` are tested in turn with
+            /// [`is_prime`](crate::num::factorization::traits::IsPrime::is_prime).
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is less than or equal to 2.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#prev_prime).
+            #[inline]
+            fn prev_prime(&self) -> $t {
+                prev_prime_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_next_prime);
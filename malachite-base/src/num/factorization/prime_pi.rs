@@ -0,0 +1,280 @@
+use crate::num::arithmetic::traits::{FloorRoot, FloorSqrt};
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::conversion::traits::{ExactFrom, RoundingFrom, WrappingFrom};
+use crate::num::factorization::prime_sieve::{
+    limbs_prime_sieve_size, limbs_prime_sieve_u64, n_to_bit,
+};
+use crate::num::factorization::traits::{IsPrime, NextPrime, NthPrime, PrevPrime, PrimePi, Primes};
+use crate::num::logic::traits::CountOnes;
+use crate::rounding_modes::RoundingMode::*;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+
+// Primes up to this limit are counted directly, by sieving.
+const SIEVE_THRESHOLD: u64 = 1 << 16;
+
+// The largest sieve used by the Meissel-Lehmer method. Larger sieves make counting faster, at the
+// cost of memory.
+const MAX_TABLE_LIMIT: u64 = 1 << 27;
+
+// phi(x, a) is computed directly when a is `PHI_TABLE_A`, using its periodicity modulo the product
+// of the first `PHI_TABLE_A` primes.
+const PHI_TABLE_A: usize = 6;
+
+const PHI_TABLE_MODULUS: u64 = 2 * 3 * 5 * 7 * 11 * 13;
+
+const PHI_TABLE_TOTIENT: u64 = 2 * 4 * 6 * 10 * 12;
+
+// When finding the nth prime, the estimate is refined until there are at most this many primes
+// between it and the answer.
+const NTH_PRIME_STEP_LIMIT: u64 = 256;
+
+// The number of primes less than or equal to the maximum value of each unsigned type.
+const PRIME_PI_U8_MAX: u64 = 54;
+
+const PRIME_PI_U16_MAX: u64 = 6542;
+
+const PRIME_PI_U32_MAX: u64 = 203280221;
+
+const PRIME_PI_U64_MAX: u64 = 425656284035217743;
+
+const PRIME_PI_USIZE_MAX: u64 = if usize::WIDTH == u32::WIDTH {
+    PRIME_PI_U32_MAX
+} else {
+    PRIME_PI_U64_MAX
+};
+
+// Holds the data needed to count the primes less than or equal to some `x` using the Meissel-Lehmer
+// method: a sieve, from which the primes up to `limit` can be counted in constant time; the primes
+// up to twice the square root of `x`; and a table of phi(n, `PHI_TABLE_A`) for n less than
+// `PHI_TABLE_MODULUS`.
+struct PrimeCounter {
+    limit: u64,
+    sieve: Vec<u64>,
+    // `counts[i]` is the number of primes represented by `sieve[..i]`.
+    counts: Vec<u32>,
+    // `primes[i]` is the (i + 1)th prime.
+    primes: Vec<u64>,
+    phi_table: Vec<u16>,
+}
+
+impl PrimeCounter {
+    fn new(x: u64) -> PrimeCounter {
+        let sqrt = x.floor_sqrt();
+        // By Bertrand's postulate, there is a prime between the square root of `x` and twice the
+        // square root, so the table always contains the prime following the square root.
+        let limit = max(
+            max(sqrt << 1, SIEVE_THRESHOLD),
+            min(x.floor_root(3).pow(2), MAX_TABLE_LIMIT),
+        );
+        let mut sieve = alloc::vec![0; limbs_prime_sieve_size::<u64>(limit)];
+        limbs_prime_sieve_u64(&mut sieve, limit);
+        let mut counts = Vec::with_capacity(sieve.len());
+        let mut count = 0;
+        for &word in &sieve {
+            counts.push(count);
+            count += (!word).count_ones();
+        }
+        let mut phi_table = Vec::with_capacity(usize::exact_from(PHI_TABLE_MODULUS));
+        let mut phi = 0;
+        for n in 0..PHI_TABLE_MODULUS {
+            if [2, 3, 5, 7, 11, 13].iter().all(|&p| n % p != 0) {
+                phi += 1;
+            }
+            phi_table.push(phi);
+        }
+        PrimeCounter {
+            limit,
+            sieve,
+            counts,
+            primes: u64::primes_less_than_or_equal_to(&limit.min(sqrt << 1)).collect(),
+            phi_table,
+        }
+    }
+
+    // Counts the primes less than or equal to `x`, where `x` is at most `self.limit`.
+    fn pi_table(&self, x: u64) -> u64 {
+        match x {
+            0 | 1 => 0,
+            2 => 1,
+            3 | 4 => 2,
+            _ => {
+                // Bit `i` of the sieve represents the (i + 1)th number greater than 3 that is not
+                // divisible by 2 or 3.
+                let bit = n_to_bit(x);
+                let i = usize::exact_from(bit >> u64::LOG_WIDTH);
+                let mask = u64::MAX >> (u64::WIDTH_MASK - (bit & u64::WIDTH_MASK));
+                2 + u64::from(self.counts[i]) + CountOnes::count_ones(!self.sieve[i] & mask)
+            }
+        }
+    }
+
+    // Counts the positive integers less than or equal to `x` that are not divisible by any of the
+    // first `a` primes. If `a` is greater than `PHI_TABLE_A`, `x` must be positive.
+    fn phi(&self, x: u64, a: usize) -> u64 {
+        if a == 0 {
+            return x;
+        } else if a < PHI_TABLE_A {
+            return self.phi(x, a - 1) - self.phi(x / self.primes[a - 1], a - 1);
+        } else if a == PHI_TABLE_A {
+            return x / PHI_TABLE_MODULUS * PHI_TABLE_TOTIENT
+                + u64::from(self.phi_table[usize::exact_from(x % PHI_TABLE_MODULUS)]);
+        }
+        let next_p = self.primes[a];
+        if x < next_p {
+            // Only 1 is left
+            return 1;
+        } else if x <= self.limit && x / next_p < next_p {
+            // Only 1 and the primes greater than the ath prime are left
+            return self.pi_table(x) + 1 - u64::exact_from(a);
+        }
+        // Use phi(x, a) = phi(x, a - 1) - phi(x / p_a, a - 1), repeatedly
+        let mut phi = self.phi(x, PHI_TABLE_A);
+        for i in PHI_TABLE_A..a {
+            let p = self.primes[i];
+            let y = x / p;
+            if y < p {
+                // phi(y, i) is 1 for this and all remaining terms
+                return phi - u64::exact_from(a - i);
+            }
+            phi -= self.phi(y, i);
+        }
+        phi
+    }
+
+    // Counts the primes less than or equal to `x` using Lehmer's formula. The square root of `x`
+    // must be no greater than the square root of the value that `self` was created with.
+    fn pi(&self, x: u64) -> u64 {
+        if x <= self.limit {
+            return self.pi_table(x);
+        }
+        let a = self.pi_table(x.floor_root(4));
+        let b = self.pi_table(x.floor_sqrt());
+        let c = self.pi_table(x.floor_root(3));
+        let mut sum = self.phi(x, usize::exact_from(a)) + (b + a - 2) * (b - a + 1) / 2;
+        for i in a..b {
+            let w = x / self.primes[usize::exact_from(i)];
+            sum -= self.pi(w);
+            if i < c {
+                let b_i = self.pi_table(w.floor_sqrt());
+                for j in i..b_i {
+                    sum -= self.pi(w / self.primes[usize::exact_from(j)]) - j;
+                }
+            }
+        }
+        sum
+    }
+}
+
+pub_test! {prime_pi_u64(x: u64) -> u64 {
+    if x <= SIEVE_THRESHOLD {
+        u64::exact_from(u64::primes_less_than_or_equal_to(&x).count())
+    } else {
+        PrimeCounter::new(x).pi(x)
+    }
+}}
+
+pub_test! {nth_prime_u64(n: u64) -> u64 {
+    assert_ne!(n, 0, "There is no 0th prime");
+    assert!(n <= PRIME_PI_U64_MAX, "The {}th prime is too large for a u64", n);
+    if n <= 1000 {
+        return u64::primes().nth(usize::exact_from(n - 1)).unwrap();
+    }
+    // Start with Cipolla's asymptotic estimate, and refine it using the prime number theorem until
+    // it is within a few hundred primes of the answer
+    let n_f = n as f64;
+    let log_n = libm::log(n_f);
+    let log_log_n = libm::log(log_n);
+    let estimate = n_f * (log_n + log_log_n - 1.0 + (log_log_n - 2.0) / log_n);
+    let mut x = u64::rounding_from(estimate, Floor).0;
+    let mut count = prime_pi_u64(x);
+    while count.abs_diff(n) > NTH_PRIME_STEP_LIMIT {
+        let gap = libm::log(x as f64);
+        let step = u64::rounding_from(count.abs_diff(n) as f64 * gap, Floor).0;
+        x = if count > n {
+            x.saturating_sub(step)
+        } else {
+            x.saturating_add(step)
+        };
+        count = prime_pi_u64(x);
+    }
+    if count >= n {
+        let mut p = if x.is_prime() { x } else { x.prev_prime() };
+        for _ in n..count {
+            p = p.prev_prime();
+        }
+        p
+    } else {
+        let mut p = x;
+        for _ in count..n {
+            p = p.next_prime();
+        }
+        p
+    }
+}}
+
+macro_rules! impl_prime_pi {
+    ($t:ident, $pi_max:ident) => {
+        impl PrimePi for $t {
+            /// Counts the primes less than or equal to a number.
+            ///
+            /// Small numbers are handled with a prime sieve, and larger numbers with the
+            /// Meissel-Lehmer method.
+            ///
+            /// $f(x) = \pi(x)$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{3/4})$
+            ///
+            /// $M(n) = O(n^{2/3})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+            ///
+            /// # Examples
+            /// See [here](super::prime_pi#prime_pi).
+            #[inline]
+            fn prime_pi(&self) -> u64 {
+                prime_pi_u64(u64::wrapping_from(*self))
+            }
+        }
+
+        impl NthPrime for $t {
+            /// Finds the $n$th prime, where the first prime is 2.
+            ///
+            /// The primes up to an estimate of the $n$th prime are counted using
+            /// [`prime_pi`](PrimePi::prime_pi), and the answer is reached by stepping through the
+            /// primes near the estimate.
+            ///
+            /// $f(n) = p_n$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O((n \log n)^{3/4})$
+            ///
+            /// $M(n) = O((n \log n)^{2/3})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Panics
+            /// Panics if `n` is 0, or if the $n$th prime is greater than the type's maximum
+            /// value.
+            ///
+            /// # Examples
+            /// See [here](super::prime_pi#nth_prime).
+            #[inline]
+            fn nth_prime(n: u64) -> $t {
+                assert!(
+                    n <= $pi_max,
+                    "The {}th prime is too large for a {}",
+                    n,
+                    stringify!($t)
+                );
+                $t::exact_from(nth_prime_u64(n))
+            }
+        }
+    };
+}
+impl_prime_pi!(u8, PRIME_PI_U8_MAX);
+impl_prime_pi!(u16, PRIME_PI_U16_MAX);
+impl_prime_pi!(u32, PRIME_PI_U32_MAX);
+impl_prime_pi!(u64, PRIME_PI_U64_MAX);
+impl_prime_pi!(usize, PRIME_PI_USIZE_MAX);
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 pub trait Primes {
     type I: Iterator<Item = Self>;
     type LI: Iterator<Item = Self>;
@@ -8,3 +10,40 @@ pub trait Primes {
 
     fn primes() -> Self::I;
 }
+
+/// Determines whether a number is prime.
+///
+/// `is_prime` uses the Baillie-PSW test, which is known to be correct for all numbers less than
+/// $2^{64}$. For larger numbers it is probabilistic: no composite number that passes it is known,
+/// but none has been ruled out. `is_probable_prime` runs additional strong Fermat tests with fixed
+/// bases.
+pub trait IsPrime {
+    fn is_prime(&self) -> bool;
+
+    fn is_probable_prime(&self, reps: u64) -> bool;
+}
+
+/// Finds the prime factorization of a number.
+pub trait Factor: Sized {
+    fn factor(&self) -> Vec<(Self, u64)>;
+}
+
+/// Finds the smallest prime greater than a number.
+pub trait NextPrime {
+    fn next_prime(&self) -> Self;
+}
+
+/// Finds the largest prime less than a number.
+pub trait PrevPrime {
+    fn prev_prime(&self) -> Self;
+}
+
+/// Counts the primes less than or equal to a number.
+pub trait PrimePi {
+    fn prime_pi(&self) -> u64;
+}
+
+/// Finds the $n$th prime, where the first prime is 2.
+pub trait NthPrime {
+    fn nth_prime(n: u64) -> Self;
+}
@@ -19,13 +19,16 @@ use crate::num::random::geometric::{
 use crate::random::{Seed, EXAMPLE_SEED};
 use crate::rounding_modes::RoundingMode;
 use crate::vecs::{random_values_from_vec, RandomValuesFromVec};
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::convert::identity;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use hashbrown::HashMap;
 use itertools::Itertools;
 use rand::Rng;
 use rand_chacha::ChaCha20Rng;
-use std::collections::HashMap;
-use std::convert::identity;
-use std::fmt::Debug;
-use std::marker::PhantomData;
 
 // Uniformly generates random primitive integers.
 #[doc(hidden)]
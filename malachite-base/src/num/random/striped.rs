@@ -12,9 +12,11 @@ use crate::num::random::{
     RandomUnsignedRange,
 };
 use crate::random::Seed;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::iter::{repeat, Repeat};
+use core::marker::PhantomData;
 use itertools::Itertools;
-use std::iter::{repeat, Repeat};
-use std::marker::PhantomData;
 
 /// Generates bits from a striped random sequence.
 ///
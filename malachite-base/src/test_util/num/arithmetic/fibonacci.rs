@@ -0,0 +1,31 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+fn checked_lucas_sequence_naive<T: PrimitiveUnsigned>(n: u64, x_0: T, x_1: T) -> Option<T> {
+    let mut x = x_0;
+    let mut x_next = Some(x_1);
+    for _ in 0..n {
+        let next = x_next?;
+        x_next = x.checked_add(next);
+        x = next;
+    }
+    Some(x)
+}
+
+pub fn checked_fibonacci_naive<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    checked_lucas_sequence_naive(n, T::ZERO, T::ONE)
+}
+
+pub fn checked_lucas_naive<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    checked_lucas_sequence_naive(n, T::TWO, T::ONE)
+}
+
+pub fn fibonacci_mod_naive<T: PrimitiveUnsigned>(n: u64, m: T) -> T {
+    let mut f = T::ZERO;
+    let mut f_next = T::ONE % m;
+    for _ in 0..n {
+        let next = f.mod_add(f_next, m);
+        f = f_next;
+        f_next = next;
+    }
+    f
+}
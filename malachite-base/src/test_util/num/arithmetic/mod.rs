@@ -1,5 +1,6 @@
 pub mod extended_gcd;
 pub mod factorial;
+pub mod fibonacci;
 pub mod kronecker_symbol;
 pub mod mod_inverse;
 pub mod mod_mul;
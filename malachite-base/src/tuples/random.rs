@@ -1,6 +1,6 @@
 use crate::random::Seed;
-use std::cmp::Ordering;
-use std::iter::{repeat, Repeat};
+use core::cmp::Ordering;
+use core::iter::{repeat, Repeat};
 
 /// Generates random units; repeats `()`.
 ///
@@ -686,7 +686,7 @@ where
 /// # #[macro_use]
 /// # extern crate malachite_base;
 /// # fn main() {
-/// use std::collections::HashMap;
+/// use hashbrown::HashMap;
 /// use std::hash::Hash;
 ///
 /// random_unique_tuples!(
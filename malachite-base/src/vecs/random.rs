@@ -13,10 +13,11 @@ use crate::sets::random::{
     RandomBTreeSetsFixedLength,
 };
 use crate::vecs::exhaustive::validate_oi_map;
-use std::cmp::Ordering;
-use std::collections::HashMap;
-use std::hash::Hash;
-use std::iter::{repeat, Repeat};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::Hash;
+use core::iter::{repeat, Repeat};
+use hashbrown::HashMap;
 
 /// Generates random [`Vec`]s of a given length using elements from a single iterator.
 ///
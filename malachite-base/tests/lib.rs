@@ -195,6 +195,7 @@ pub mod num {
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod factorial;
+        pub mod fibonacci;
         pub mod floor;
         pub mod gcd;
         pub mod is_power_of_2;
@@ -223,6 +224,7 @@ pub mod num {
         pub mod mod_power_of_2_sub;
         pub mod mod_shl;
         pub mod mod_shr;
+        pub mod mod_sqrt;
         pub mod mod_square;
         pub mod mod_sub;
         pub mod neg;
@@ -373,6 +375,10 @@ pub mod num {
         pub mod primitive_int_increasing_range;
     }
     pub mod factorization {
+        pub mod factor;
+        pub mod is_prime;
+        pub mod next_prime;
+        pub mod prime_pi;
         pub mod prime_sieve;
         pub mod primes;
     }
@@ -0,0 +1,216 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_pair_gen_var_37};
+use malachite_base::test_util::num::arithmetic::fibonacci::{
+    checked_fibonacci_naive, checked_lucas_naive, fibonacci_mod_naive,
+};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::fibonacci(n), out);
+    }
+    test::<u8>(0, 0);
+    test::<u8>(1, 1);
+    test::<u8>(2, 1);
+    test::<u8>(3, 2);
+    test::<u8>(4, 3);
+    test::<u8>(5, 5);
+    test::<u8>(13, 233);
+    test::<u32>(40, 102334155);
+    test::<u64>(93, 12200160415121876738);
+    test::<u128>(186, 332825110087067562321196029789634457848);
+}
+
+fn fibonacci_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci(200));
+}
+
+#[test]
+fn fibonacci_fail() {
+    apply_fn_to_unsigneds!(fibonacci_fail_helper);
+}
+
+#[test]
+fn test_fibonacci2() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: (T, T)) {
+        assert_eq!(T::fibonacci2(n), out);
+    }
+    test::<u8>(0, (0, 1));
+    test::<u8>(1, (1, 0));
+    test::<u8>(2, (1, 1));
+    test::<u8>(3, (2, 1));
+    test::<u8>(5, (5, 3));
+    test::<u8>(13, (233, 144));
+    test::<u32>(40, (102334155, 63245986));
+}
+
+fn fibonacci2_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci2(200));
+}
+
+#[test]
+fn fibonacci2_fail() {
+    apply_fn_to_unsigneds!(fibonacci2_fail_helper);
+}
+
+#[test]
+fn test_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::lucas(n), out);
+    }
+    test::<u8>(0, 2);
+    test::<u8>(1, 1);
+    test::<u8>(2, 3);
+    test::<u8>(3, 4);
+    test::<u8>(4, 7);
+    test::<u8>(5, 11);
+    test::<u8>(11, 199);
+    test::<u32>(30, 1860498);
+}
+
+fn lucas_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::lucas(200));
+}
+
+#[test]
+fn lucas_fail() {
+    apply_fn_to_unsigneds!(lucas_fail_helper);
+}
+
+#[test]
+fn test_checked_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_fibonacci(n), out);
+        assert_eq!(checked_fibonacci_naive(n), out);
+    }
+    test::<u8>(0, Some(0));
+    test::<u8>(1, Some(1));
+    test::<u8>(5, Some(5));
+    test::<u8>(13, Some(233));
+    test::<u64>(93, Some(12200160415121876738));
+
+    test::<u8>(14, None);
+    test::<u64>(94, None);
+    test::<u128>(187, None);
+}
+
+#[test]
+fn test_checked_fibonacci2() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<(T, T)>) {
+        assert_eq!(T::checked_fibonacci2(n), out);
+    }
+    test::<u8>(0, Some((0, 1)));
+    test::<u8>(1, Some((1, 0)));
+    test::<u8>(5, Some((5, 3)));
+    test::<u8>(13, Some((233, 144)));
+
+    test::<u8>(14, None);
+    test::<u64>(94, None);
+}
+
+#[test]
+fn test_checked_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_lucas(n), out);
+        assert_eq!(checked_lucas_naive(n), out);
+    }
+    test::<u8>(0, Some(2));
+    test::<u8>(1, Some(1));
+    test::<u8>(5, Some(11));
+    test::<u8>(11, Some(199));
+    test::<u32>(30, Some(1860498));
+
+    test::<u8>(12, None);
+    test::<u32>(100, None);
+}
+
+#[test]
+fn test_fibonacci_mod() {
+    fn test<T: PrimitiveUnsigned>(n: u64, m: T, out: T) {
+        assert_eq!(T::fibonacci_mod(n, m), out);
+    }
+    test::<u8>(0, 1, 0);
+    test::<u8>(10, 1, 0);
+    test::<u8>(0, 7, 0);
+    test::<u8>(1, 7, 1);
+    test::<u8>(10, 7, 6);
+    test::<u32>(100, 1000, 75);
+    test::<u64>(100, 1000000007, 687995182);
+    test::<u64>(1000000000000000000, 1000000007, 209783453);
+}
+
+fn fibonacci_mod_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci_mod(10, T::ZERO));
+}
+
+#[test]
+fn fibonacci_mod_fail() {
+    apply_fn_to_unsigneds!(fibonacci_mod_fail_helper);
+}
+
+fn checked_fibonacci_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let of = T::checked_fibonacci(n);
+        assert_eq!(checked_fibonacci_naive(n), of);
+        if let Some(f) = of {
+            assert_eq!(T::fibonacci(n), f);
+            let (f_alt, f_prev) = T::fibonacci2(n);
+            assert_eq!(f_alt, f);
+            if n == 0 {
+                assert_eq!(f_prev, T::ONE);
+            } else {
+                assert_eq!(T::fibonacci(n - 1), f_prev);
+            }
+            if n > 1 {
+                assert_eq!(f_prev + T::fibonacci(n - 2), f);
+            }
+        } else {
+            assert!(T::checked_fibonacci2(n).is_none());
+            if n != u64::MAX {
+                assert!(T::checked_fibonacci(n + 1).is_none());
+            }
+        }
+    });
+}
+
+#[test]
+fn checked_fibonacci_properties() {
+    apply_fn_to_unsigneds!(checked_fibonacci_properties_helper);
+}
+
+fn checked_lucas_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let ol = T::checked_lucas(n);
+        assert_eq!(checked_lucas_naive(n), ol);
+        if let Some(l) = ol {
+            assert_eq!(T::lucas(n), l);
+            // L_n = F_n + 2F_{n - 1}
+            let (f, f_prev) = T::fibonacci2(n);
+            assert_eq!(f + (f_prev << 1), l);
+        } else if n > 1 && n != u64::MAX {
+            assert!(T::checked_lucas(n + 1).is_none());
+        }
+    });
+}
+
+#[test]
+fn checked_lucas_properties() {
+    apply_fn_to_unsigneds!(checked_lucas_properties_helper);
+}
+
+fn fibonacci_mod_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_37::<T, u64>().test_properties(|(m, n)| {
+        let f = T::fibonacci_mod(n, m);
+        assert!(f < m);
+        assert_eq!(fibonacci_mod_naive(n, m), f);
+        if let Some(f_alt) = T::checked_fibonacci(n) {
+            assert_eq!(f_alt % m, f);
+        }
+    });
+}
+
+#[test]
+fn fibonacci_mod_properties() {
+    apply_fn_to_unsigneds!(fibonacci_mod_properties_helper);
+}
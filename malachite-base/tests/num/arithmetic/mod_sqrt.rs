@@ -0,0 +1,235 @@
+use malachite_base::num::arithmetic::traits::{ModFactoredSqrt, ModPrimePowerSqrt, ModSqrt};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+use std::panic::catch_unwind;
+
+fn test_mod_sqrt_helper<T: PrimitiveUnsigned>() {
+    let test = |x: u8, p: u8, out: Option<u8>| {
+        assert_eq!(T::from(x).mod_sqrt(T::from(p)), out.map(T::from));
+    };
+    test(0, 2, Some(0));
+    test(1, 2, Some(1));
+    test(0, 7, Some(0));
+    test(1, 7, Some(1));
+    test(2, 7, Some(3));
+    test(3, 7, None);
+    test(4, 7, Some(2));
+    test(10, 13, Some(6));
+    test(5, 13, None);
+    test(2, 17, Some(6));
+    test(3, 17, None);
+    test(3, 97, Some(10));
+    test(5, 97, None);
+    test(2, 193, Some(52));
+    test(5, 193, None);
+}
+
+#[test]
+fn test_mod_sqrt() {
+    apply_fn_to_unsigneds!(test_mod_sqrt_helper);
+
+    let test = |x: u64, p: u64, out: Option<u64>| {
+        assert_eq!(x.mod_sqrt(p), out);
+        assert_eq!(u128::from(x).mod_sqrt(u128::from(p)), out.map(u128::from));
+    };
+    test(2, 1000000007, Some(59713600));
+    test(5, 1000000007, None);
+    // 998244353 - 1 = 2^23 * 7 * 17
+    test(3, 998244353, None);
+    test(2, 998244353, Some(116195171));
+    // 2^64 - 2^32 + 1
+    test(7, 18446744069414584321, None);
+    test(3, 18446744069414584321, Some(281474976579584));
+    test(2, 18446744069414584321, Some(1099494850304));
+
+    let test = |x: u128, p: u128, out: Option<u128>| {
+        assert_eq!(x.mod_sqrt(p), out);
+    };
+    test(
+        2,
+        170141183460469231731687303715884105727,
+        Some(18446744073709551616),
+    );
+    test(3, 170141183460469231731687303715884105727, None);
+}
+
+fn mod_sqrt_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.mod_sqrt(T::ZERO));
+    assert_panic!(T::from(7u8).mod_sqrt(T::from(7u8)));
+    assert_panic!(T::from(3u8).mod_sqrt(T::from(10u8)));
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    apply_fn_to_unsigneds!(mod_sqrt_fail_helper);
+}
+
+fn test_mod_prime_power_sqrt_helper<T: PrimitiveUnsigned>() {
+    let test = |x: u8, p: u8, k: u64, out: Option<u8>| {
+        assert_eq!(
+            T::from(x).mod_prime_power_sqrt(T::from(p), k),
+            out.map(T::from)
+        );
+    };
+    test(0, 2, 0, Some(0));
+    test(0, 7, 1, Some(0));
+    test(2, 7, 1, Some(3));
+    test(2, 7, 2, Some(10));
+    test(3, 7, 2, None);
+    test(7, 7, 2, None);
+    test(0, 7, 2, Some(0));
+    test(1, 2, 1, Some(1));
+    test(1, 2, 2, Some(1));
+    test(3, 2, 2, None);
+    test(1, 2, 3, Some(1));
+    test(5, 2, 3, None);
+    test(17, 2, 7, Some(41));
+    test(4, 2, 7, Some(2));
+    test(8, 2, 7, None);
+    test(36, 3, 4, Some(21));
+    test(18, 3, 4, None);
+    test(9, 3, 4, Some(3));
+}
+
+#[test]
+fn test_mod_prime_power_sqrt() {
+    apply_fn_to_unsigneds!(test_mod_prime_power_sqrt_helper);
+
+    let test = |x: u64, p: u64, k: u64, out: Option<u64>| {
+        assert_eq!(x.mod_prime_power_sqrt(p, k), out);
+        assert_eq!(
+            u128::from(x).mod_prime_power_sqrt(u128::from(p), k),
+            out.map(u128::from)
+        );
+    };
+    test(17, 2, 63, Some(405959429219100393));
+    test(2, 7, 22, Some(1830612359265707720));
+    test(5, 7, 22, None);
+    test(2, 1000000007, 2, Some(404504992771821330));
+
+    let test = |x: u128, p: u128, k: u64, out: Option<u128>| {
+        assert_eq!(x.mod_prime_power_sqrt(p, k), out);
+    };
+    test(17, 2, 127, Some(60714889673454602250791861189942126313));
+    test(2, 7, 45, Some(41386911860381949936026538973328234639));
+}
+
+fn mod_prime_power_sqrt_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::from(49u8).mod_prime_power_sqrt(T::from(7u8), 2));
+    assert_panic!(T::ONE.mod_prime_power_sqrt(T::TWO, 0));
+    assert_panic!(T::ONE.mod_prime_power_sqrt(T::TWO, T::WIDTH));
+}
+
+#[test]
+fn mod_prime_power_sqrt_fail() {
+    apply_fn_to_unsigneds!(mod_prime_power_sqrt_fail_helper);
+}
+
+fn test_mod_factored_sqrt_helper<T: PrimitiveUnsigned>() {
+    let test = |x: u8, factors: &[(u8, u64)], out: Option<u8>| {
+        let factors: Vec<(T, u64)> = factors.iter().map(|&(p, k)| (T::from(p), k)).collect();
+        assert_eq!(T::from(x).mod_factored_sqrt(&factors), out.map(T::from));
+    };
+    test(0, &[], Some(0));
+    test(0, &[(2, 2), (5, 2)], Some(0));
+    test(41, &[(2, 2), (5, 2)], Some(29));
+    test(2, &[(2, 2), (5, 2)], None);
+    test(4, &[(3, 1), (5, 1), (7, 1)], Some(37));
+    test(11, &[(3, 1), (5, 1), (7, 1)], None);
+    test(9, &[(2, 3), (3, 2)], Some(9));
+    test(1, &[(2, 3), (3, 1), (5, 1)], Some(1));
+}
+
+#[test]
+fn test_mod_factored_sqrt() {
+    apply_fn_to_unsigneds!(test_mod_factored_sqrt_helper);
+
+    let test = |x: u64, factors: &[(u64, u64)], out: Option<u64>| {
+        assert_eq!(x.mod_factored_sqrt(factors), out);
+    };
+    test(
+        2,
+        &[(998244353, 1), (1000000007, 1)],
+        Some(392789308689811542),
+    );
+    test(3, &[(998244353, 1), (1000000007, 1)], None);
+}
+
+fn mod_factored_sqrt_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::from(10u8).mod_factored_sqrt(&[(T::TWO, 1), (T::from(3u8), 1)]));
+    assert_panic!(T::ONE.mod_factored_sqrt(&[(T::from(3u8), 1), (T::from(3u8), 1)]));
+    assert_panic!(T::ZERO.mod_factored_sqrt(&[(T::TWO, T::WIDTH)]));
+}
+
+#[test]
+fn mod_factored_sqrt_fail() {
+    apply_fn_to_unsigneds!(mod_factored_sqrt_fail_helper);
+}
+
+fn mod_sqrt_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_27::<T>().test_properties(|(x, p)| {
+        if p < T::TWO {
+            return;
+        }
+        let p = if p.is_prime() { p } else { p.prev_prime() };
+        let x = x % p;
+        let sqrt = x.mod_sqrt(p);
+        assert_eq!(sqrt.is_some(), p == T::TWO || x.legendre_symbol(p) != -1);
+        if let Some(sqrt) = sqrt {
+            assert!(sqrt <= p - sqrt);
+            assert_eq!(sqrt.mod_square(p), x);
+            assert_eq!(x.mod_prime_power_sqrt(p, 1), Some(sqrt));
+            assert_eq!(x.mod_factored_sqrt(&[(p, 1)]), Some(sqrt));
+        }
+        let square = x.mod_square(p);
+        let sqrt = square.mod_sqrt(p).unwrap();
+        assert!(sqrt == x || sqrt == p - x);
+    });
+}
+
+fn mod_factored_sqrt_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_27::<T>().test_properties(|(x, m)| {
+        if m == T::ZERO {
+            return;
+        }
+        let x = x % m;
+        let factors = m.factor();
+        let square = x.mod_square(m);
+        let sqrt = square.mod_factored_sqrt(&factors).unwrap();
+        assert!(sqrt <= m - sqrt);
+        assert_eq!(sqrt.mod_square(m), square);
+        if let &[(p, k)] = factors.as_slice() {
+            assert_eq!(square.mod_prime_power_sqrt(p, k), Some(sqrt));
+        }
+        if let Some(sqrt) = x.mod_factored_sqrt(&factors) {
+            assert!(sqrt <= m - sqrt);
+            assert_eq!(sqrt.mod_square(m), x);
+        }
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|m| {
+        assert_eq!(T::ZERO.mod_factored_sqrt(&m.factor()), Some(T::ZERO));
+        if m > T::ONE {
+            let sqrt = T::ONE.mod_factored_sqrt(&m.factor()).unwrap();
+            assert_eq!(sqrt.mod_square(m), T::ONE);
+        }
+    });
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    mod_sqrt_properties_helper::<u8>();
+    mod_sqrt_properties_helper::<u16>();
+    mod_sqrt_properties_helper::<u32>();
+    mod_sqrt_properties_helper::<u64>();
+    mod_sqrt_properties_helper::<usize>();
+}
+
+#[test]
+fn mod_factored_sqrt_properties() {
+    mod_factored_sqrt_properties_helper::<u8>();
+    mod_factored_sqrt_properties_helper::<u16>();
+    mod_factored_sqrt_properties_helper::<u32>();
+    mod_factored_sqrt_properties_helper::<u64>();
+    mod_factored_sqrt_properties_helper::<usize>();
+}
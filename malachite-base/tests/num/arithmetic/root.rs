@@ -8,7 +8,7 @@ use malachite_base::num::arithmetic::root::{
     ceiling_root_binary, checked_root_binary, floor_root_binary, root_rem_binary,
 };
 use malachite_base::num::arithmetic::traits::{
-    CeilingRoot, CheckedRoot, FloorRoot, Parity, RootRem,
+    CeilingRoot, CheckedRoot, DivisibleBy, FloorRoot, Parity, RootRem,
 };
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
@@ -744,3 +744,140 @@ fn root_rem_properties() {
         assert_eq!(fast_root_rem_u64(n, exp), n.root_rem(exp));
     });
 }
+
+#[test]
+fn test_is_perfect_power() {
+    fn test_u<T: PrimitiveUnsigned>(n: T, out: bool) {
+        assert_eq!(n.is_perfect_power(), out);
+    }
+    test_u::<u8>(0, true);
+    test_u::<u8>(1, true);
+    test_u::<u8>(2, false);
+    test_u::<u8>(4, true);
+    test_u::<u8>(8, true);
+    test_u::<u8>(12, false);
+    test_u::<u8>(128, true);
+    test_u::<u8>(255, false);
+    test_u::<u16>(1000, true);
+    test_u::<u16>(1001, false);
+    test_u::<u32>(3486784401, true);
+    test_u::<u64>(u64::MAX, false);
+    test_u::<u64>(1 << 63, true);
+    test_u::<u128>(3u128.pow(80), true);
+    test_u::<u128>(3u128.pow(80) + 1, false);
+
+    fn test_i<T: PrimitiveSigned>(n: T, out: bool) {
+        assert_eq!(n.is_perfect_power(), out);
+    }
+    test_i::<i8>(0, true);
+    test_i::<i8>(1, true);
+    test_i::<i8>(-1, true);
+    test_i::<i8>(-2, false);
+    test_i::<i8>(4, true);
+    test_i::<i8>(-4, false);
+    test_i::<i8>(-8, true);
+    test_i::<i8>(-128, true);
+    test_i::<i32>(-64, true);
+    test_i::<i32>(-16, false);
+    test_i::<i64>(i64::MIN, true);
+    test_i::<i64>(-(1 << 62), true);
+    test_i::<i64>(-(1 << 32), false);
+}
+
+#[test]
+fn test_perfect_power_decomposition() {
+    fn test_u<T: PrimitiveUnsigned>(n: T, base: T, exp: u64) {
+        assert_eq!(n.perfect_power_decomposition(), (base, exp));
+    }
+    test_u::<u8>(0, 0, 1);
+    test_u::<u8>(1, 1, 1);
+    test_u::<u8>(2, 2, 1);
+    test_u::<u8>(12, 12, 1);
+    test_u::<u8>(64, 2, 6);
+    test_u::<u8>(128, 2, 7);
+    test_u::<u8>(144, 12, 2);
+    test_u::<u16>(1000, 10, 3);
+    test_u::<u16>(1296, 6, 4);
+    test_u::<u32>(3486784401, 3, 20);
+    test_u::<u64>(1 << 63, 2, 63);
+    test_u::<u64>(u64::MAX, u64::MAX, 1);
+    test_u::<u128>(3u128.pow(80), 3, 80);
+    test_u::<u128>(36u128.pow(24), 6, 48);
+
+    fn test_i<T: PrimitiveSigned>(n: T, base: T, exp: u64) {
+        assert_eq!(n.perfect_power_decomposition(), (base, exp));
+    }
+    test_i::<i8>(0, 0, 1);
+    test_i::<i8>(1, 1, 1);
+    test_i::<i8>(-1, -1, 1);
+    test_i::<i8>(64, 2, 6);
+    test_i::<i8>(-8, -2, 3);
+    test_i::<i8>(-128, -2, 7);
+    test_i::<i16>(729, 3, 6);
+    test_i::<i16>(-729, -9, 3);
+    test_i::<i32>(-16, -16, 1);
+    test_i::<i32>(-64, -4, 3);
+    test_i::<i64>(i64::MIN, -2, 63);
+    test_i::<i64>(-(1 << 62), -4, 31);
+    test_i::<i64>(-(1 << 32), -(1 << 32), 1);
+}
+
+fn is_perfect_power_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        let is_perfect_power = n.is_perfect_power();
+        let (base, exp) = n.perfect_power_decomposition();
+        assert_eq!(base.pow(exp), n);
+        assert_eq!(is_perfect_power, n <= T::ONE || exp > 1);
+        assert_eq!(
+            is_perfect_power,
+            n <= T::ONE || (2..n.significant_bits()).any(|k| n.checked_root(k).is_some())
+        );
+        if n > T::ONE {
+            assert!(!base.is_perfect_power());
+            if n.is_square() {
+                assert!(exp.even());
+            }
+        }
+    });
+
+    unsigned_pair_gen_var_32::<T, u64>().test_properties(|(n, exp)| {
+        if n > T::ONE {
+            if let Some(power) = n.checked_pow(exp) {
+                let (power_base, power_exp) = power.perfect_power_decomposition();
+                assert!(power_exp.divisible_by(exp));
+                assert_eq!(power_base.pow(power_exp), power);
+                if exp > 1 {
+                    assert!(power.is_perfect_power());
+                }
+            }
+        }
+    });
+}
+
+fn is_perfect_power_properties_helper_signed<T: PrimitiveSigned>() {
+    signed_gen::<T>().test_properties(|n| {
+        let is_perfect_power = n.is_perfect_power();
+        let (base, exp) = n.perfect_power_decomposition();
+        assert_eq!(base.pow(exp), n);
+        let trivial = n >= T::NEGATIVE_ONE && n <= T::ONE;
+        assert_eq!(is_perfect_power, trivial || exp > 1);
+        if n >= T::ZERO {
+            assert_eq!(
+                is_perfect_power,
+                trivial || (2..n.significant_bits()).any(|k| n.checked_root(k).is_some())
+            );
+        } else {
+            assert!(exp.odd());
+            assert!(base < T::ZERO);
+        }
+        if !trivial {
+            assert!(!base.is_perfect_power());
+        }
+    });
+}
+
+#[test]
+fn is_perfect_power_properties() {
+    apply_fn_to_unsigneds!(is_perfect_power_properties_helper_unsigned);
+    apply_fn_to_signeds!(is_perfect_power_properties_helper_signed);
+}
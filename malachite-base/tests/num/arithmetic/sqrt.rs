@@ -8,7 +8,7 @@ use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::num::conversion::traits::WrappingFrom;
 use malachite_base::num::float::NiceFloat;
 use malachite_base::test_util::generators::{
-    primitive_float_gen, signed_gen_var_2, unsigned_gen, unsigned_gen_var_17,
+    primitive_float_gen, signed_gen, signed_gen_var_2, unsigned_gen, unsigned_gen_var_17,
 };
 use std::panic::catch_unwind;
 
@@ -409,3 +409,60 @@ fn sqrt_assign_properties_helper<T: PrimitiveFloat>() {
 fn sqrt_assign_properties() {
     apply_fn_to_primitive_floats!(sqrt_assign_properties_helper);
 }
+
+#[test]
+fn test_is_square() {
+    fn test_u<T: PrimitiveUnsigned>(n: T, out: bool) {
+        assert_eq!(n.is_square(), out);
+    }
+    test_u::<u8>(0, true);
+    test_u::<u8>(1, true);
+    test_u::<u8>(2, false);
+    test_u::<u8>(4, true);
+    test_u::<u8>(99, false);
+    test_u::<u8>(100, true);
+    test_u::<u8>(225, true);
+    test_u::<u8>(255, false);
+    test_u::<u32>(999999, false);
+    test_u::<u32>(1000000, true);
+    test_u::<u64>(u64::MAX, false);
+    test_u::<u64>(0xfffffffe00000001, true);
+    test_u::<u128>(0xfffffffffffffffe0000000000000001, true);
+    test_u::<u128>(0xfffffffffffffffe0000000000000002, false);
+
+    fn test_i<T: PrimitiveSigned>(n: T, out: bool) {
+        assert_eq!(n.is_square(), out);
+    }
+    test_i::<i8>(0, true);
+    test_i::<i8>(1, true);
+    test_i::<i8>(-1, false);
+    test_i::<i8>(100, true);
+    test_i::<i8>(-100, false);
+    test_i::<i64>(10000000000, true);
+    test_i::<i64>(i64::MIN, false);
+}
+
+fn is_square_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        assert_eq!(n.is_square(), n.checked_sqrt().is_some());
+        let sqrt = n.floor_sqrt();
+        assert!((sqrt * sqrt).is_square());
+    });
+}
+
+fn is_square_properties_helper_signed<T: PrimitiveSigned>() {
+    signed_gen::<T>().test_properties(|n| {
+        let is_square = n.is_square();
+        assert_eq!(is_square, n >= T::ZERO && n.checked_sqrt().is_some());
+        if n >= T::ZERO {
+            let sqrt = n.floor_sqrt();
+            assert!((sqrt * sqrt).is_square());
+        }
+    });
+}
+
+#[test]
+fn is_square_properties() {
+    apply_fn_to_unsigneds!(is_square_properties_helper_unsigned);
+    apply_fn_to_signeds!(is_square_properties_helper_signed);
+}
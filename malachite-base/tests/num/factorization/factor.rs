@@ -0,0 +1,153 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::factor::pollard_brent_rho;
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_gen_var_5};
+use std::panic::catch_unwind;
+
+fn test_factor_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: &[(u8, u64)]| {
+        let out = out
+            .iter()
+            .map(|&(p, e)| (T::from(p), e))
+            .collect::<Vec<_>>();
+        assert_eq!(T::from(n).factor(), out);
+    };
+    test(1, &[]);
+    test(2, &[(2, 1)]);
+    test(4, &[(2, 2)]);
+    test(12, &[(2, 2), (3, 1)]);
+    test(97, &[(97, 1)]);
+    test(128, &[(2, 7)]);
+    test(210, &[(2, 1), (3, 1), (5, 1), (7, 1)]);
+    test(255, &[(3, 1), (5, 1), (17, 1)]);
+}
+
+#[test]
+fn test_factor() {
+    apply_fn_to_unsigneds!(test_factor_helper);
+
+    let test = |n: u64, out: &[(u64, u64)]| {
+        assert_eq!(n.factor(), out);
+        assert_eq!(
+            u128::from(n).factor(),
+            out.iter()
+                .map(|&(p, e)| (u128::from(p), e))
+                .collect::<Vec<_>>()
+        );
+    };
+    test(65535, &[(3, 1), (5, 1), (17, 1), (257, 1)]);
+    test(994009, &[(997, 2)]);
+    test(1018081, &[(1009, 2)]);
+    test(4294967297, &[(641, 1), (6700417, 1)]);
+    test(1000000016000000063, &[(1000000007, 1), (1000000009, 1)]);
+    test(18446744073709551557, &[(18446744073709551557, 1)]);
+    test(
+        u64::MAX,
+        &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)],
+    );
+
+    let test = |n: u128, out: &[(u128, u64)]| {
+        assert_eq!(n.factor(), out);
+    };
+    test(18446744073709551617, &[(274177, 1), (67280421310721, 1)]);
+    test(
+        1000000000000000006000000000000000009,
+        &[(1000000000000000003, 2)],
+    );
+    test(
+        170141183460469231731687303715884105727,
+        &[(170141183460469231731687303715884105727, 1)],
+    );
+    test(
+        u128::MAX,
+        &[
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (274177, 1),
+            (6700417, 1),
+            (67280421310721, 1),
+        ],
+    );
+}
+
+fn factor_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.factor());
+}
+
+#[test]
+fn factor_fail() {
+    apply_fn_to_unsigneds!(factor_fail_helper);
+}
+
+#[test]
+fn test_pollard_brent_rho() {
+    let test = |n: u64, c: u64, out: Option<u64>| {
+        assert_eq!(pollard_brent_rho(n, c), out);
+    };
+    test(9, 1, Some(3));
+    test(9, 2, None);
+    test(25, 1, None);
+    test(25, 2, Some(5));
+    test(8051, 1, Some(97));
+    test(10403, 1, Some(101));
+    test(4294967297, 1, Some(641));
+    test(1000000016000000063, 1, Some(1000000009));
+}
+
+fn factor_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let factors = n.factor();
+        assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+        let mut product = T::ONE;
+        for &(p, e) in &factors {
+            assert!(p.is_prime());
+            assert_ne!(e, 0);
+            product *= p.pow(e);
+        }
+        assert_eq!(product, n);
+        assert_eq!(n.is_prime(), factors == [(n, 1)]);
+    });
+
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n == T::ZERO {
+            return;
+        }
+        let mut factors = Vec::new();
+        let mut m = n;
+        for p in T::primes_less_than_or_equal_to(&n) {
+            let mut e = 0;
+            while m.divisible_by(p) {
+                m /= p;
+                e += 1;
+            }
+            if e != 0 {
+                factors.push((p, e));
+            }
+        }
+        assert_eq!(n.factor(), factors);
+    });
+}
+
+#[test]
+fn factor_properties() {
+    factor_properties_helper::<u8>();
+    factor_properties_helper::<u16>();
+    factor_properties_helper::<u32>();
+    factor_properties_helper::<u64>();
+    factor_properties_helper::<usize>();
+
+    // Random u128s may have two large prime factors, which makes factoring them slow
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(
+            u128::from(n).factor(),
+            n.factor()
+                .into_iter()
+                .map(|(p, e)| (u128::from(p), e))
+                .collect::<Vec<_>>()
+        );
+    });
+}
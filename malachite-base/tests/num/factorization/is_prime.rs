@@ -0,0 +1,135 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::is_prime::{
+    is_strong_lucas_probable_prime, is_strong_probable_prime,
+};
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_5};
+
+fn test_is_prime_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out| {
+        let n = T::from(n);
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.is_probable_prime(0), out);
+        assert_eq!(n.is_probable_prime(10), out);
+    };
+    test(0, false);
+    test(1, false);
+    test(2, true);
+    test(3, true);
+    test(4, false);
+    test(5, true);
+    test(9, false);
+    test(53, true);
+    test(59, true);
+    test(97, true);
+    test(121, false);
+    test(251, true);
+    test(255, false);
+
+    let primes = T::primes_less_than(&T::from(u8::MAX)).collect::<Vec<_>>();
+    for n in 0..u8::MAX {
+        assert_eq!(T::from(n).is_prime(), primes.contains(&T::from(n)));
+    }
+}
+
+#[test]
+fn test_is_prime() {
+    apply_fn_to_unsigneds!(test_is_prime_helper);
+
+    let test = |n: u64, out| {
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.is_probable_prime(5), out);
+        assert_eq!(u128::from(n).is_prime(), out);
+    };
+    test(3481, false);
+    test(3491, true);
+    test(65521, true);
+    test(65535, false);
+    // strong pseudoprimes to base 2
+    test(2047, false);
+    test(3277, false);
+    test(4033, false);
+    test(3825123056546413051, false);
+    // strong Lucas pseudoprimes
+    test(5459, false);
+    test(5777, false);
+    test(10877, false);
+    // Carmichael numbers
+    test(561, false);
+    test(41041, false);
+    test(4294967291, true);
+    test(4294967297, false);
+    test(2305843009213693951, true);
+    test(18446744073709551557, true);
+    test(u64::MAX, false);
+
+    let test = |n: u128, out| {
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.is_probable_prime(5), out);
+    };
+    test(18446744073709551629, true);
+    test(618970019642690137449562111, true);
+    test(170141183460469231731687303715884105727, true);
+    test(170141183460469231731687303715884105729, false);
+    test(318665857834031151167461, false);
+    test(3317044064679887385961981, false);
+    test(340282366920938463463374607431768211297, true);
+    test(u128::MAX, false);
+}
+
+#[test]
+fn test_is_strong_probable_prime() {
+    let test = |n: u64, base, out| {
+        assert_eq!(is_strong_probable_prime(n, base), out);
+    };
+    test(97, 2, true);
+    test(2047, 2, true);
+    test(3277, 2, true);
+    test(2047, 3, false);
+    test(5459, 2, false);
+    test(3825123056546413051, 2, true);
+    test(3825123056546413051, 37, false);
+}
+
+#[test]
+fn test_is_strong_lucas_probable_prime() {
+    let test = |n: u64, out| {
+        assert_eq!(is_strong_lucas_probable_prime(n), out);
+    };
+    test(3491, true);
+    test(5459, true);
+    test(5777, true);
+    test(10877, true);
+    test(16109, true);
+    test(18971, true);
+    test(4033, false);
+    test(3825123056546413051, false);
+    test(18446744073709551557, true);
+}
+
+fn is_prime_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(n.is_probable_prime(2), is_prime);
+        if is_prime && n != T::TWO {
+            assert!(n.odd());
+        }
+        if n > T::ONE {
+            if let Some(square) = n.checked_square() {
+                assert!(!square.is_prime());
+            }
+        }
+    });
+
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        assert_eq!(
+            n.is_prime(),
+            T::primes_less_than_or_equal_to(&n).last() == Some(n)
+        );
+    });
+}
+
+#[test]
+fn is_prime_properties() {
+    apply_fn_to_unsigneds!(is_prime_properties_helper);
+}
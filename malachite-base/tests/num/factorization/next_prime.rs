@@ -0,0 +1,177 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{NextPrime, PrevPrime};
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_5};
+use std::panic::catch_unwind;
+
+fn test_next_prime_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u8| {
+        assert_eq!(T::from(n).next_prime(), T::from(out));
+    };
+    test(0, 2);
+    test(1, 2);
+    test(2, 3);
+    test(3, 5);
+    test(4, 5);
+    test(5, 7);
+    test(89, 97);
+    test(97, 101);
+    test(250, 251);
+}
+
+#[test]
+fn test_next_prime() {
+    apply_fn_to_unsigneds!(test_next_prime_helper);
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(n.next_prime(), out);
+        assert_eq!(u128::from(n).next_prime(), u128::from(out));
+    };
+    test(251, 257);
+    test(65521, 65537);
+    test(1000000, 1000003);
+    test(4294967291, 4294967311);
+    test(1000000000000, 1000000000039);
+    test(10000000000000000000, 10000000000000000051);
+    test(18446744073709551556, 18446744073709551557);
+
+    let test = |n: u128, out: u128| {
+        assert_eq!(n.next_prime(), out);
+    };
+    test(18446744073709551557, 18446744073709551629);
+    test(u128::from(u64::MAX), 18446744073709551629);
+    test(
+        170141183460469231731687303715884105727,
+        170141183460469231731687303715884105757,
+    );
+}
+
+#[test]
+fn next_prime_fail() {
+    assert_panic!(251u8.next_prime());
+    assert_panic!(u8::MAX.next_prime());
+    assert_panic!(65521u16.next_prime());
+    assert_panic!(4294967291u32.next_prime());
+    assert_panic!(18446744073709551557u64.next_prime());
+    assert_panic!(u128::MAX.next_prime());
+}
+
+fn test_prev_prime_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u8| {
+        assert_eq!(T::from(n).prev_prime(), T::from(out));
+    };
+    test(3, 2);
+    test(4, 3);
+    test(5, 3);
+    test(6, 5);
+    test(97, 89);
+    test(100, 97);
+    test(255, 251);
+}
+
+#[test]
+fn test_prev_prime() {
+    apply_fn_to_unsigneds!(test_prev_prime_helper);
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(n.prev_prime(), out);
+        assert_eq!(u128::from(n).prev_prime(), u128::from(out));
+    };
+    test(257, 251);
+    test(65537, 65521);
+    test(1000000, 999983);
+    test(4294967296, 4294967291);
+    test(1000000000000, 999999999989);
+    test(u64::MAX, 18446744073709551557);
+
+    let test = |n: u128, out: u128| {
+        assert_eq!(n.prev_prime(), out);
+    };
+    test(18446744073709551629, 18446744073709551557);
+    test(u128::MAX, 340282366920938463463374607431768211297);
+}
+
+fn prev_prime_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.prev_prime());
+    assert_panic!(T::ONE.prev_prime());
+    assert_panic!(T::TWO.prev_prime());
+}
+
+#[test]
+fn prev_prime_fail() {
+    apply_fn_to_unsigneds!(prev_prime_fail_helper);
+}
+
+fn next_prime_properties_helper<T: PrimitiveUnsigned>() {
+    let largest_prime = T::MAX.prev_prime();
+    unsigned_gen::<T>().test_properties(|n| {
+        if n >= largest_prime {
+            return;
+        }
+        let p = n.next_prime();
+        assert!(p > n);
+        assert!(p.is_prime());
+        if p > T::TWO {
+            assert!(p.prev_prime() <= n);
+        }
+    });
+
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n >= largest_prime {
+            return;
+        }
+        assert_eq!(Some(n.next_prime()), T::primes().find(|&p| p > n));
+    });
+}
+
+#[test]
+fn next_prime_properties() {
+    next_prime_properties_helper::<u8>();
+    next_prime_properties_helper::<u16>();
+    next_prime_properties_helper::<u32>();
+    next_prime_properties_helper::<u64>();
+    next_prime_properties_helper::<usize>();
+
+    let largest_prime = u64::MAX.prev_prime();
+    unsigned_gen::<u64>().test_properties(|n| {
+        if n < largest_prime {
+            assert_eq!(u128::from(n).next_prime(), u128::from(n.next_prime()));
+        }
+    });
+}
+
+fn prev_prime_properties_helper<T: PrimitiveUnsigned>() {
+    let largest_prime = T::MAX.prev_prime();
+    unsigned_gen::<T>().test_properties(|n| {
+        if n <= T::TWO {
+            return;
+        }
+        let p = n.prev_prime();
+        assert!(p < n);
+        assert!(p.is_prime());
+        if p < largest_prime {
+            assert!(p.next_prime() >= n);
+        }
+    });
+
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n <= T::TWO {
+            return;
+        }
+        assert_eq!(Some(n.prev_prime()), T::primes_less_than(&n).last());
+    });
+}
+
+#[test]
+fn prev_prime_properties() {
+    prev_prime_properties_helper::<u8>();
+    prev_prime_properties_helper::<u16>();
+    prev_prime_properties_helper::<u32>();
+    prev_prime_properties_helper::<u64>();
+    prev_prime_properties_helper::<usize>();
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        if n > 2 {
+            assert_eq!(u128::from(n).prev_prime(), u128::from(n.prev_prime()));
+        }
+    });
+}
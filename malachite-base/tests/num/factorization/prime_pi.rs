@@ -0,0 +1,101 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::prime_pi::{nth_prime_u64, prime_pi_u64};
+use malachite_base::num::factorization::traits::{NthPrime, PrimePi};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_prime_pi() {
+    let test = |n: u64, out: u64| {
+        assert_eq!(prime_pi_u64(n), out);
+        assert_eq!(n.prime_pi(), out);
+        if let Ok(n) = u32::try_from(n) {
+            assert_eq!(n.prime_pi(), out);
+        }
+    };
+    test(0, 0);
+    test(1, 0);
+    test(2, 1);
+    test(3, 2);
+    test(4, 2);
+    test(10, 4);
+    test(100, 25);
+    test(255, 54);
+    test(1000, 168);
+    test(65535, 6542);
+    test(65536, 6542);
+    test(65537, 6543);
+    test(100000, 9592);
+    test(1000000, 78498);
+    test(10000000, 664579);
+    test(100000000, 5761455);
+    test(1000000000, 50847534);
+    test(u64::from(u32::MAX), 203280221);
+    test(10000000000, 455052511);
+}
+
+#[test]
+fn test_nth_prime() {
+    let test = |n: u64, out: u64| {
+        assert_eq!(nth_prime_u64(n), out);
+        assert_eq!(u64::nth_prime(n), out);
+        if let Ok(out) = u32::try_from(out) {
+            assert_eq!(u32::nth_prime(n), out);
+        }
+    };
+    test(1, 2);
+    test(2, 3);
+    test(3, 5);
+    test(25, 97);
+    test(54, 251);
+    test(168, 997);
+    test(1000, 7919);
+    test(1001, 7927);
+    test(6542, 65521);
+    test(10000, 104729);
+    test(100000, 1299709);
+    test(1000000, 15485863);
+    test(10000000, 179424673);
+    test(203280221, 4294967291);
+    test(1000000000, 22801763489);
+}
+
+#[test]
+fn nth_prime_fail() {
+    assert_panic!(u64::nth_prime(0));
+    assert_panic!(u8::nth_prime(55));
+    assert_panic!(u16::nth_prime(6543));
+    assert_panic!(u32::nth_prime(203280222));
+    assert_panic!(u64::nth_prime(425656284035217744));
+    assert_panic!(u64::nth_prime(u64::MAX));
+    assert_panic!(nth_prime_u64(u64::MAX));
+}
+
+fn prime_pi_properties_helper<T: PrimitiveUnsigned + PrimePi + NthPrime>() {
+    let largest_prime = T::MAX.prev_prime();
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        let pi = n.prime_pi();
+        assert_eq!(
+            pi,
+            u64::exact_from(T::primes_less_than_or_equal_to(&n).count())
+        );
+        if n.is_prime() {
+            assert_eq!(T::nth_prime(pi), n);
+        } else if pi != 0 {
+            assert!(T::nth_prime(pi) < n);
+        }
+        if n < largest_prime {
+            assert_eq!(n.next_prime().prime_pi(), pi + 1);
+        }
+    });
+}
+
+#[test]
+fn prime_pi_properties() {
+    prime_pi_properties_helper::<u8>();
+    prime_pi_properties_helper::<u16>();
+    prime_pi_properties_helper::<u32>();
+    prime_pi_properties_helper::<u64>();
+    prime_pi_properties_helper::<usize>();
+}
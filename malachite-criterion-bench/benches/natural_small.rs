@@ -0,0 +1,136 @@
+extern crate criterion;
+extern crate malachite_base;
+extern crate malachite_base_baseline;
+extern crate malachite_nz;
+extern crate malachite_nz_baseline;
+extern crate num;
+extern crate rug;
+
+use criterion::*;
+use malachite_base::num::arithmetic::traits::DivMod;
+use malachite_base::num::random::random_primitive_ints;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_base_baseline::num::arithmetic::traits::DivMod as BaselineDivMod;
+use malachite_nz::natural::random::get_random_natural_with_bits;
+use malachite_nz::natural::Natural;
+use num::{BigUint, Integer};
+use std::str::FromStr;
+
+// These benchmarks cover `Natural`s of 2 to 4 64-bit limbs, which are stored inline rather than on
+// the heap. Each is compared against malachite 0.4.4, which stored them on the heap, to check that
+// avoiding the allocation is worth the larger `Natural` (40 bytes rather than 24).
+const SIZES: [u64; 3] = [128, 192, 256];
+
+pub fn natural_to_biguint(n: &Natural) -> BigUint {
+    BigUint::from_str(n.to_string().as_ref()).unwrap()
+}
+
+pub fn natural_to_baseline_natural(n: &Natural) -> malachite_nz_baseline::natural::Natural {
+    malachite_nz_baseline::natural::Natural::from_str(n.to_string().as_ref()).unwrap()
+}
+
+pub fn natural_to_rug_integer(n: &Natural) -> rug::Integer {
+    rug::Integer::from_str(n.to_string().as_ref()).unwrap()
+}
+
+fn random_natural_pair(x_bits: u64, y_bits: u64) -> (Natural, Natural) {
+    (
+        get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("a")), x_bits),
+        get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("b")), y_bits),
+    )
+}
+
+fn bench_add(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Natural + Natural, small");
+    for &i in SIZES.iter() {
+        let (x, y) = random_natural_pair(i, i);
+        let x_baseline = natural_to_baseline_natural(&x);
+        let y_baseline = natural_to_baseline_natural(&y);
+        let x_num = natural_to_biguint(&x);
+        let y_num = natural_to_biguint(&y);
+        let x_rug = natural_to_rug_integer(&x);
+        let y_rug = natural_to_rug_integer(&y);
+        group.bench_function(BenchmarkId::new("malachite", i), |b| {
+            b.iter_with_setup(|| (x.clone(), y.clone()), |(x, y)| x + y)
+        });
+        group.bench_function(BenchmarkId::new("malachite 0.4.4", i), |b| {
+            b.iter_with_setup(|| (x_baseline.clone(), y_baseline.clone()), |(x, y)| x + y)
+        });
+        group.bench_function(BenchmarkId::new("num", i), |b| {
+            b.iter_with_setup(|| (x_num.clone(), y_num.clone()), |(x, y)| x + y)
+        });
+        group.bench_function(BenchmarkId::new("rug", i), |b| {
+            b.iter_with_setup(|| (x_rug.clone(), y_rug.clone()), |(x, y)| x + y)
+        });
+    }
+    group.finish();
+}
+
+fn bench_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Natural * Natural, small");
+    for &i in SIZES.iter() {
+        let (x, y) = random_natural_pair(i, i);
+        let x_baseline = natural_to_baseline_natural(&x);
+        let y_baseline = natural_to_baseline_natural(&y);
+        let x_num = natural_to_biguint(&x);
+        let y_num = natural_to_biguint(&y);
+        let x_rug = natural_to_rug_integer(&x);
+        let y_rug = natural_to_rug_integer(&y);
+        group.bench_function(BenchmarkId::new("malachite", i), |b| {
+            b.iter_with_setup(|| (x.clone(), y.clone()), |(x, y)| x * y)
+        });
+        group.bench_function(BenchmarkId::new("malachite 0.4.4", i), |b| {
+            b.iter_with_setup(|| (x_baseline.clone(), y_baseline.clone()), |(x, y)| x * y)
+        });
+        group.bench_function(BenchmarkId::new("num", i), |b| {
+            b.iter_with_setup(|| (x_num.clone(), y_num.clone()), |(x, y)| x * y)
+        });
+        group.bench_function(BenchmarkId::new("rug", i), |b| {
+            b.iter_with_setup(|| (x_rug.clone(), y_rug.clone()), |(x, y)| x * y)
+        });
+    }
+    group.finish();
+}
+
+fn bench_div_mod(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Natural.div_mod(Natural), small");
+    for &i in SIZES.iter() {
+        // The divisor has at least two limbs, so that the general division algorithm is used.
+        let (x, y) = random_natural_pair(i, (i >> 1) + 1);
+        let x_baseline = natural_to_baseline_natural(&x);
+        let y_baseline = natural_to_baseline_natural(&y);
+        let x_num = natural_to_biguint(&x);
+        let y_num = natural_to_biguint(&y);
+        let x_rug = natural_to_rug_integer(&x);
+        let y_rug = natural_to_rug_integer(&y);
+        group.bench_function(BenchmarkId::new("malachite", i), |b| {
+            b.iter_with_setup(|| (x.clone(), y.clone()), |(x, y)| x.div_mod(y))
+        });
+        group.bench_function(BenchmarkId::new("malachite 0.4.4", i), |b| {
+            b.iter_with_setup(
+                || (x_baseline.clone(), y_baseline.clone()),
+                |(x, y)| x.div_mod(y),
+            )
+        });
+        group.bench_function(BenchmarkId::new("num", i), |b| {
+            b.iter_with_setup(
+                || (x_num.clone(), y_num.clone()),
+                |(x, y)| x.div_mod_floor(&y),
+            )
+        });
+        group.bench_function(BenchmarkId::new("rug", i), |b| {
+            b.iter_with_setup(
+                || (x_rug.clone(), y_rug.clone()),
+                |(x, y)| x.div_rem_floor(y),
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().significance_level(0.1).sample_size(100);
+    targets = bench_add, bench_mul, bench_div_mod
+}
+criterion_main!(benches);
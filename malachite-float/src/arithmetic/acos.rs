@@ -0,0 +1,660 @@
+use crate::arithmetic::asin::one_minus_square;
+use crate::arithmetic::atan::{atan_enclosure, pi_multiple_prec_round};
+use crate::arithmetic::exp::{integer_and_pow, ziv_round};
+use crate::constants::pi::pi_fixed_point_bounds;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{CeilingSqrt, FloorSqrt, UnsignedAbs};
+use malachite_base::num::basic::traits::{One, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+// Computes $\arccos x$, where $0 < |x| < 1$, rounding to `prec` bits.
+fn acos_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    let (m, pow) = integer_and_pow(x);
+    let negative = m < 0u32;
+    let m = m.unsigned_abs();
+    let mut d = None;
+    ziv_round(prec, rm, |working_prec| {
+        let g = working_prec + 8;
+        if exponent < -i64::exact_from(g) {
+            // $|\arccos x - \pi/2| < 2|x| \leq 2^{-g}$.
+            let (lo, hi) = pi_fixed_point_bounds(g - 1);
+            return (
+                Integer::from(lo) - Integer::ONE,
+                Integer::from(hi) + Integer::ONE,
+                -i64::exact_from(g),
+            );
+        }
+        // $\arccos |x| = \arctan(\sqrt{1-x^2}/|x|) = \arctan(\sqrt{D}/m)$.
+        let d = d.get_or_insert_with(|| one_minus_square(&m, pow));
+        let g = if negative {
+            g
+        } else {
+            // $\arccos x > \sqrt{1-x^2} = \sqrt{D}2^p \geq 2^e$.
+            let e = (i64::exact_from(d.significant_bits()) - 1 + (pow << 1)) >> 1;
+            g + u64::exact_from((1 - e).max(0))
+        };
+        let h = g + 8;
+        let n = &m << h;
+        let d = &*d << (h << 1);
+        let (lo, hi) = atan_enclosure(&(&d).floor_sqrt(), &d.ceiling_sqrt(), &n, &n, 0, g);
+        let pow = -i64::exact_from(g);
+        if negative {
+            // $\arccos x = \pi - \arccos |x|$.
+            let (pi_lo, pi_hi) = pi_fixed_point_bounds(g);
+            (Integer::from(pi_lo) - hi, Integer::from(pi_hi) - lo, pow)
+        } else {
+            (lo, hi, pow)
+        }
+    })
+}
+
+// Computes $\arccos x$, rounding to `prec` bits.
+fn acos_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { .. }) => (float_nan!(), Ordering::Equal),
+        Float(Zero { .. }) => pi_multiple_prec_round(1, -1, prec, rm),
+        _ => match x.get_exponent().unwrap().cmp(&1) {
+            Ordering::Equal if *x == 1u32 => (Float::ZERO, Ordering::Equal),
+            Ordering::Equal if *x == -1i32 => pi_multiple_prec_round(1, 0, prec, rm),
+            Ordering::Greater | Ordering::Equal => (float_nan!(), Ordering::Equal),
+            Ordering::Less => acos_finite(x, prec, rm),
+        },
+    }
+}
+
+impl Float {
+    /// Computes the arccosine of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arccosine is less than, equal to, or greater than
+    /// the exact arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arccos x+\epsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.470629");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.470631");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.470629");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acos_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        acos_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded arccosine is less than, equal to, or greater
+    /// than the exact arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arccos x+\epsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.470629");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.470631");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.470629");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acos_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        acos_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arccosine is less than, equal to, or greater than the exact
+    /// arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the arccosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arccos x+\epsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec(5);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec(20);
+    /// assert_eq!(x.to_string(), "1.470629");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acos_prec(self, prec: u64) -> (Float, Ordering) {
+        self.acos_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arccosine is less than, equal to, or greater than the exact
+    /// arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the arccosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arccos x+\epsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.470629");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acos_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.acos_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arccosine is less than, equal to, or greater than the exact arccosine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arccos x+\epsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).acos_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.4706289056333368");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.470628905633337");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4706289056333368");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acos_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.acos_prec_round(prec, rm)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arccosine is less than, equal to, or greater than the exact arccosine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arccos x+\epsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).acos_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.4706289056333368");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.470628905633337");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).acos_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4706289056333368");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acos_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.acos_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its arccosine, rounding the result to the specified precision and
+    /// with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded arccosine is less than, equal to, or greater than the exact arccosine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \arccos x+\epsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    ///
+    /// See the [`Float::acos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.acos_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.acos_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.5");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.acos_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.5");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.acos_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.470629");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.acos_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.470631");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.acos_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.470629");
+    /// ```
+    #[inline]
+    pub fn acos_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (acos, o) = acos_prec_round_ref_helper(self, prec, rm);
+        *self = acos;
+        o
+    }
+
+    /// Replaces a [`Float`] with its arccosine, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded arccosine
+    /// is less than, equal to, or greater than the exact arccosine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \arccos x+\epsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$.
+    ///
+    /// See the [`Float::acos_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.5");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.470629");
+    /// ```
+    #[inline]
+    pub fn acos_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.acos_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its arccosine, rounding the result with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded arccosine is less than,
+    /// equal to, or greater than the exact arccosine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \arccos x+\epsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::acos_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.4706289056333368");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.acos_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.470628905633337");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.acos_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.4706289056333368");
+    /// ```
+    #[inline]
+    pub fn acos_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.acos_prec_round_assign(prec, rm)
+    }
+}
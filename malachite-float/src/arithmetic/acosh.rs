@@ -0,0 +1,691 @@
+use crate::arithmetic::exp::{integer_and_pow, ziv_round};
+use crate::arithmetic::ln::{ln_enclosure, ln_interval_enclosure};
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_zero, Float};
+use malachite_base::num::arithmetic::traits::{
+    CeilingSqrt, FloorSqrt, PowerOf2, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Returns an enclosure of $\operatorname{arcosh} x = \log(x + \sqrt{x^2-1})$, where $x = m2^p > 1$.
+fn acosh_enclosure(m: &Natural, pow: i64, working_prec: u64) -> (Integer, Integer, u64) {
+    // When $x$ is close to 1, $\operatorname{arcosh} x$ is close to $\sqrt{2(x-1)}$, so the
+    // precision is increased by the number of leading zeros of $x - 1$. Using a scale at least as
+    // fine as $2^p$ also makes $x$ exact, so that $x^2 - 1$ suffers no cancellation error.
+    let (g, x) = if pow >= 0 {
+        (
+            working_prec + 8,
+            m << (working_prec + 8 + u64::exact_from(pow)),
+        )
+    } else {
+        let shift = pow.unsigned_abs();
+        let x_minus_one_exponent =
+            i64::exact_from((m - Natural::power_of_2(shift)).significant_bits()) + pow;
+        let g = (working_prec + 8 + u64::exact_from((-x_minus_one_exponent).max(0))).max(shift);
+        (g, m << (g - shift))
+    };
+    let d = (&x).square() - Natural::power_of_2(g << 1);
+    let a_lo = &x + (&d).floor_sqrt();
+    let a_hi = x + d.ceiling_sqrt();
+    ln_interval_enclosure(&a_lo, &a_hi, -i64::exact_from(g), working_prec)
+}
+
+// Computes $\operatorname{arcosh} x$, where $x > 1$ is finite, rounding to `prec` bits.
+fn acosh_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    let (m, pow) = integer_and_pow(x);
+    let m = m.unsigned_abs();
+    ziv_round(prec, rm, |working_prec| {
+        if exponent > 1 {
+            // $\log 2x - 1/(4x^2) < \operatorname{arcosh} x < \log 2x$.
+            let enclosure = ln_enclosure(&m, pow + 1, working_prec);
+            let f = enclosure.f;
+            if (exponent - 1) << 1 >= i64::exact_from(f) + 2 {
+                let (lo, hi) = enclosure.ln();
+                return (lo - Integer::ONE, hi, -i64::exact_from(f));
+            }
+        }
+        let (lo, hi, f) = acosh_enclosure(&m, pow, working_prec);
+        (lo, hi, -i64::exact_from(f))
+    })
+}
+
+// Computes $\operatorname{arcosh} x$, rounding to `prec` bits.
+fn acosh_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        Float(Infinity { sign: true }) => (float_infinity!(), Ordering::Equal),
+        float_nan!()
+        | Float(Infinity { sign: false } | Zero { .. } | Finite { sign: false, .. }) => {
+            (float_nan!(), Ordering::Equal)
+        }
+        _ => match x.partial_cmp(&1u32).unwrap() {
+            Ordering::Less => (float_nan!(), Ordering::Equal),
+            Ordering::Equal => (float_zero!(), Ordering::Equal),
+            Ordering::Greater => acosh_finite(x, prec, rm),
+        },
+    }
+}
+
+impl Float {
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic cosine is
+    /// less than, equal to, or greater than the exact inverse hyperbolic cosine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{arcosh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acosh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::acosh_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.811527");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        acosh_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic cosine is
+    /// less than, equal to, or greater than the exact inverse hyperbolic cosine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{arcosh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acosh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::acosh_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.811527");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        acosh_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic cosine is less than, equal to,
+    /// or greater than the exact inverse hyperbolic cosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the inverse hyperbolic cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{arcosh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec(5);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec(20);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.acosh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded inverse hyperbolic cosine is less than, equal
+    /// to, or greater than the exact inverse hyperbolic cosine. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the inverse hyperbolic cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{arcosh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.acosh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic cosine is less than, equal to, or greater
+    /// than the exact inverse hyperbolic cosine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{arcosh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acosh_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.8115262724608532");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.acosh_prec_round(prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic cosine is less than, equal to,
+    /// or greater than the exact inverse hyperbolic cosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{arcosh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acosh_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.8115262724608532");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.acosh_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its inverse hyperbolic cosine, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded inverse hyperbolic cosine is less than, equal to, or greater
+    /// than the exact inverse hyperbolic cosine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \operatorname{arcosh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acosh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::acosh_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.acosh_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.75");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.acosh_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.81");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.acosh_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.81");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.acosh_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.811525");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.acosh_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.811527");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.acosh_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// ```
+    #[inline]
+    pub fn acosh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (acosh, o) = acosh_prec_round_ref_helper(self, prec, rm);
+        *self = acosh;
+        o
+    }
+
+    /// Replaces a [`Float`] with its inverse hyperbolic cosine, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded inverse hyperbolic cosine is less than, equal to, or greater than the exact inverse
+    /// hyperbolic cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \operatorname{arcosh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// See the [`Float::acosh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.81");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// ```
+    #[inline]
+    pub fn acosh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.acosh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its inverse hyperbolic cosine, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded inverse
+    /// hyperbolic cosine is less than, equal to, or greater than the exact inverse hyperbolic
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \operatorname{arcosh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::acosh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::acosh_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.acosh_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.8115262724608532");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    /// ```
+    #[inline]
+    pub fn acosh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.acosh_prec_round_assign(prec, rm)
+    }
+}
@@ -0,0 +1,4109 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{
+    float_either_zero, float_infinity, float_nan, float_negative_infinity, float_negative_zero,
+    float_zero, significand_bits, Float,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::{max, min, Ordering};
+use std::ops::{Add, AddAssign};
+
+// The zero that results from adding two numbers whose exact sum is zero, or from adding two zeros
+// of opposite signs. This follows IEEE 754 and MPFR: the result is positive zero, unless the
+// rounding mode is `Floor`, in which case it is negative zero.
+pub(crate) const fn exact_zero_sum(rm: RoundingMode) -> Float {
+    if let RoundingMode::Floor = rm {
+        float_negative_zero!()
+    } else {
+        float_zero!()
+    }
+}
+
+// Adds two finite, nonzero `Float`s, given as sign-exponent-precision-significand tuples, and
+// rounds the result to `prec` bits.
+//
+// If `y` is so much smaller than `x` that it only affects the rounding of the sum, it is replaced
+// by a single bit that lies strictly between the lowest bit that can matter for rounding and the
+// highest bit of `y`. This keeps the exact sum from growing with the difference in exponents.
+#[allow(clippy::too_many_arguments)]
+fn add_finite_prec_round(
+    x_sign: bool,
+    x_exponent: i64,
+    x_precision: u64,
+    x_significand: &Natural,
+    y_sign: bool,
+    y_exponent: i64,
+    y_precision: u64,
+    y_significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if x_exponent < y_exponent {
+        return add_finite_prec_round(
+            y_sign,
+            y_exponent,
+            y_precision,
+            y_significand,
+            x_sign,
+            x_exponent,
+            x_precision,
+            x_significand,
+            prec,
+            rm,
+        );
+    }
+    let x_shift = x_exponent - i64::exact_from(significand_bits(x_significand));
+    let working_prec = i64::exact_from(max(prec, x_precision));
+    let sticky_exponent = x_exponent - working_prec - 3;
+    let x = Integer::from_sign_and_abs_ref(x_sign, x_significand);
+    let (y, y_shift) = if y_exponent <= sticky_exponent {
+        (
+            Integer::from_sign_and_abs(y_sign, Natural::from(1u32)),
+            sticky_exponent - 1,
+        )
+    } else {
+        (
+            Integer::from_sign_and_abs_ref(y_sign, y_significand),
+            y_exponent - i64::exact_from(significand_bits(y_significand)),
+        )
+    };
+    let shift = min(x_shift, y_shift);
+    let sum = (x << (x_shift - shift)) + (y << (y_shift - shift));
+    if sum == 0u32 {
+        (exact_zero_sum(rm), Ordering::Equal)
+    } else {
+        Float::from_integer_times_power_of_2_prec_round(sum, shift, prec, rm)
+    }
+}
+
+// Adds `x` and `y` (or `x` and `-y`, if `negate_y` is `true`), rounding to `prec` bits. This is the
+// shared core of addition and subtraction.
+pub(crate) fn add_prec_round_ref_ref_helper(
+    x: &Float,
+    y: &Float,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (float_nan!(), _) | (_, float_nan!()) => (float_nan!(), Ordering::Equal),
+        (Float(Infinity { sign: x_sign }), Float(Infinity { sign: y_sign })) => {
+            if *x_sign == (*y_sign != negate_y) {
+                (x.clone(), Ordering::Equal)
+            } else {
+                (float_nan!(), Ordering::Equal)
+            }
+        }
+        (Float(Infinity { .. }), _) => (x.clone(), Ordering::Equal),
+        (_, Float(Infinity { sign })) => {
+            if *sign != negate_y {
+                (float_infinity!(), Ordering::Equal)
+            } else {
+                (float_negative_infinity!(), Ordering::Equal)
+            }
+        }
+        (Float(Zero { sign: x_sign }), Float(Zero { sign: y_sign })) => {
+            if *x_sign == (*y_sign != negate_y) {
+                (x.clone(), Ordering::Equal)
+            } else {
+                (exact_zero_sum(rm), Ordering::Equal)
+            }
+        }
+        (float_either_zero!(), _) => {
+            let mut sum = if negate_y { -y } else { y.clone() };
+            let o = sum.set_prec_round(prec, rm);
+            (sum, o)
+        }
+        (_, float_either_zero!()) => {
+            let mut sum = x.clone();
+            let o = sum.set_prec_round(prec, rm);
+            (sum, o)
+        }
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                precision: x_precision,
+                significand: x_significand,
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                precision: y_precision,
+                significand: y_significand,
+            }),
+        ) => add_finite_prec_round(
+            *x_sign,
+            *x_exponent,
+            *x_precision,
+            x_significand,
+            *y_sign != negate_y,
+            *y_exponent,
+            *y_precision,
+            y_significand,
+            prec,
+            rm,
+        ),
+    }
+}
+
+// Like `add_prec_round_ref_ref_helper`, but takes `x` by value, so that it can be reused when `y` is
+// zero.
+pub(crate) fn add_prec_round_val_ref_helper(
+    x: Float,
+    y: &Float,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match (&x, y) {
+        (Float(Finite { .. }), float_either_zero!()) => {
+            assert_ne!(prec, 0);
+            let mut sum = x;
+            let o = sum.set_prec_round(prec, rm);
+            (sum, o)
+        }
+        _ => add_prec_round_ref_ref_helper(&x, y, negate_y, prec, rm),
+    }
+}
+
+// Like `add_prec_round_ref_ref_helper`, but takes `y` by value, so that it can be reused when `x` is
+// zero.
+pub(crate) fn add_prec_round_ref_val_helper(
+    x: &Float,
+    y: Float,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match (x, &y) {
+        (float_either_zero!(), Float(Finite { .. })) => {
+            assert_ne!(prec, 0);
+            let mut sum = if negate_y { -y } else { y };
+            let o = sum.set_prec_round(prec, rm);
+            (sum, o)
+        }
+        _ => add_prec_round_ref_ref_helper(x, &y, negate_y, prec, rm),
+    }
+}
+
+// Like `add_prec_round_ref_ref_helper`, but takes both `x` and `y` by value, so that either can be
+// reused when the other is zero.
+pub(crate) fn add_prec_round_val_val_helper(
+    x: Float,
+    y: Float,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match (&x, &y) {
+        (Float(Finite { .. }), float_either_zero!()) => {
+            add_prec_round_val_ref_helper(x, &y, negate_y, prec, rm)
+        }
+        _ => add_prec_round_ref_val_helper(&x, y, negate_y, prec, rm),
+    }
+}
+
+// Rounds the exact sum of a `Float` and a `Rational` to `prec` bits, giving an exact zero the sign
+// that `exact_zero_sum` prescribes.
+fn round_exact_sum(sum: Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if sum == 0u32 {
+        (exact_zero_sum(rm), Ordering::Equal)
+    } else {
+        Float::from_rational_prec_round(sum, prec, rm)
+    }
+}
+
+// Adds `x` and `y` (or `x` and `-y`, if `negate_y` is `true`), where `y` is a `Rational`, rounding
+// to `prec` bits. This is the shared core of `Float`-`Rational` addition and subtraction.
+pub(crate) fn add_rational_prec_round_ref_ref_helper(
+    x: &Float,
+    y: &Rational,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() => (float_nan!(), Ordering::Equal),
+        Float(Infinity { .. }) => (x.clone(), Ordering::Equal),
+        float_either_zero!() => {
+            if *y == 0u32 {
+                (x.clone(), Ordering::Equal)
+            } else if negate_y {
+                Float::from_rational_prec_round(-y, prec, rm)
+            } else {
+                Float::from_rational_prec_round_ref(y, prec, rm)
+            }
+        }
+        _ => {
+            if *y == 0u32 {
+                let mut sum = x.clone();
+                let o = sum.set_prec_round(prec, rm);
+                return (sum, o);
+            }
+            let x = Rational::exact_from(x);
+            round_exact_sum(if negate_y { x - y } else { x + y }, prec, rm)
+        }
+    }
+}
+
+// Like `add_rational_prec_round_ref_ref_helper`, but takes `x` by value.
+pub(crate) fn add_rational_prec_round_val_ref_helper(
+    x: Float,
+    y: &Rational,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match x {
+        Float(Finite { .. }) => {
+            assert_ne!(prec, 0);
+            let mut x = x;
+            if *y == 0u32 {
+                let o = x.set_prec_round(prec, rm);
+                return (x, o);
+            }
+            let x = Rational::exact_from(x);
+            round_exact_sum(if negate_y { x - y } else { x + y }, prec, rm)
+        }
+        _ => add_rational_prec_round_ref_ref_helper(&x, y, negate_y, prec, rm),
+    }
+}
+
+// Like `add_rational_prec_round_ref_ref_helper`, but takes `y` by value.
+pub(crate) fn add_rational_prec_round_ref_val_helper(
+    x: &Float,
+    y: Rational,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_either_zero!() if y != 0u32 => {
+            Float::from_rational_prec_round(if negate_y { -y } else { y }, prec, rm)
+        }
+        Float(Finite { .. }) if y != 0u32 => {
+            let x = Rational::exact_from(x);
+            round_exact_sum(if negate_y { x - y } else { x + y }, prec, rm)
+        }
+        _ => add_rational_prec_round_ref_ref_helper(x, &y, negate_y, prec, rm),
+    }
+}
+
+// Like `add_rational_prec_round_ref_ref_helper`, but takes both `x` and `y` by value.
+pub(crate) fn add_rational_prec_round_val_val_helper(
+    x: Float,
+    y: Rational,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match x {
+        Float(Finite { .. }) if y != 0u32 => {
+            assert_ne!(prec, 0);
+            let x = Rational::exact_from(x);
+            round_exact_sum(if negate_y { x - y } else { x + y }, prec, rm)
+        }
+        Float(Finite { .. }) => add_rational_prec_round_val_ref_helper(x, &y, negate_y, prec, rm),
+        _ => add_rational_prec_round_ref_val_helper(&x, y, negate_y, prec, rm),
+    }
+}
+
+impl Float {
+    /// Adds two [`Float`]s, rounding the result to the specified precision and with the specified
+    /// rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\infty,-\infty,p,m)=
+    ///   f(-\infty,\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p,m)=0.0$
+    /// - $f(-0.0,-0.0,p,m)=-0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::add_round`] instead. If both of these things are true, consider using `+`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "6.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "5.85988");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        add_prec_round_val_val_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the specified precision and with the specified
+    /// rounding mode. The first [`Float`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\infty,-\infty,p,m)=
+    ///   f(-\infty,\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p,m)=0.0$
+    /// - $f(-0.0,-0.0,p,m)=-0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::add_round_val_ref`] instead. If both of these things are true,
+    /// consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "6.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "5.85988");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_prec_round_val_ref_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the specified precision and with the specified
+    /// rounding mode. The first [`Float`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\infty,-\infty,p,m)=
+    ///   f(-\infty,\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p,m)=0.0$
+    /// - $f(-0.0,-0.0,p,m)=-0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::add_round_ref_val`] instead. If both of these things are true,
+    /// consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_val(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_val(
+    ///     Float::from(E),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "6.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_val(
+    ///     Float::from(E),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_val(
+    ///     Float::from(E),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_val(
+    ///     Float::from(E),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "5.85988");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_val(
+    ///     Float::from(E),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_prec_round_ref_val_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the specified precision and with the specified
+    /// rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\infty,-\infty,p,m)=
+    ///   f(-\infty,\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p,m)=0.0$
+    /// - $f(-0.0,-0.0,p,m)=-0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::add_round_ref_ref`] instead. If both of these things are true,
+    /// consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "6.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "5.85988");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_prec_round_ref_ref_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sum is less than, equal to, or greater than the exact sum. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\infty,-\infty,p)= f(-\infty,\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p)=0.0$
+    /// - $f(-0.0,-0.0,p)=-0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=0.0$ if $m$ is not `Floor`
+    /// - $f(x,-x,p)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round`] instead. If you know that your target precision is the maximum of
+    /// the precisions of the two inputs, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_prec(Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec(Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.add_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// The first [`Float`] is taken by value and the second by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\infty,-\infty,p)= f(-\infty,\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p)=0.0$
+    /// - $f(-0.0,-0.0,p)=-0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=0.0$ if $m$ is not `Floor`
+    /// - $f(x,-x,p)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round_val_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.add_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// The first [`Float`] is taken by reference and the second by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\infty,-\infty,p)= f(-\infty,\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p)=0.0$
+    /// - $f(-0.0,-0.0,p)=-0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=0.0$ if $m$ is not `Floor`
+    /// - $f(x,-x,p)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round_ref_val`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.add_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded sum is less than, equal to, or greater than the exact sum. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\infty,-\infty,p)= f(-\infty,\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p)=0.0$
+    /// - $f(-0.0,-0.0,p)=-0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=0.0$ if $m$ is not `Floor`
+    /// - $f(x,-x,p)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round_ref_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.add_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds two [`Float`]s, rounding the result with the specified rounding mode. Both [`Float`]s
+    /// are taken by value. An [`Ordering`] is also returned, indicating whether the rounded sum is
+    /// less than, equal to, or greater than the exact sum. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\infty,-\infty,m)= f(-\infty,\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,m)=0.0$
+    /// - $f(-0.0,-0.0,m)=-0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::add_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "5.859874482048839");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round(other, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result with the specified rounding mode. The first
+    /// [`Float`] is taken by value and the second by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\infty,-\infty,m)= f(-\infty,\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,m)=0.0$
+    /// - $f(-0.0,-0.0,m)=-0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::add_prec_round_val_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_round_val_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_round_val_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "5.859874482048839");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_round_val_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result with the specified rounding mode. The first
+    /// [`Float`] is taken by reference and the second by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\infty,-\infty,m)= f(-\infty,\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,m)=0.0$
+    /// - $f(-0.0,-0.0,m)=-0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::add_prec_round_ref_val`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_round_ref_val(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_round_ref_val(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "5.859874482048839");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_round_ref_val(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result with the specified rounding mode. Both [`Float`]s
+    /// are taken by reference. An [`Ordering`] is also returned, indicating whether the rounded sum
+    /// is less than, equal to, or greater than the exact sum. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\infty,-\infty,m)= f(-\infty,\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,m)=0.0$
+    /// - $f(-0.0,-0.0,m)=-0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::add_prec_round_ref_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "5.859874482048839");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// See the [`Float::add_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_assign`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::add_round_assign`] instead. If both of these things are true,
+    /// consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_prec_round_assign(Float::from(E), 5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign(Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign(Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign(Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.85987");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign(Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "5.85988");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign(Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// ```
+    #[inline]
+    pub fn add_prec_round_assign(&mut self, other: Float, prec: u64, rm: RoundingMode) -> Ordering {
+        let (sum, o) = add_prec_round_ref_val_helper(self, other, false, prec, rm);
+        *self = sum;
+        o
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// reference. An [`Ordering`] is returned, indicating whether the rounded sum is less than,
+    /// equal to, or greater than the exact sum. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// See the [`Float::add_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::add_round_assign_ref`] instead. If both of these things are
+    /// true, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.85987");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "5.85988");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// ```
+    #[inline]
+    pub fn add_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (sum, o) = add_prec_round_ref_ref_helper(self, other, false, prec, rm);
+        *self = sum;
+        o
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] on the right-hand side is taken by value. An [`Ordering`]
+    /// is returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// See the [`Float::add_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round_assign`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_prec_assign(Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_prec_assign(Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// ```
+    #[inline]
+    pub fn add_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.add_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// See the [`Float::add_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round_assign_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_prec_assign_ref(&Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_prec_assign_ref(&Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// ```
+    #[inline]
+    pub fn add_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.add_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// See the [`Float::add_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::add_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+=`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_round_assign(Float::from(E), RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_round_assign(Float::from(E), RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "5.859874482048839");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_round_assign(Float::from(E), RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// ```
+    #[inline]
+    pub fn add_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_assign(other, prec, rm)
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. The [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// See the [`Float::add_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_round_assign_ref(&Float::from(E), RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_round_assign_ref(&Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "5.859874482048839");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_round_assign_ref(&Float::from(E), RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// ```
+    #[inline]
+    pub fn add_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_assign_ref(other, prec, rm)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] and the [`Rational`] are both taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$
+    /// - $f(-\infty,x,p,m)=-\infty$
+    /// - $f(0.0,0,p,m)=0.0$
+    /// - $f(-0.0,0,p,m)=-0.0$
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_rational_prec`] instead.
+    /// If you know that your target precision is the precision of the [`Float`] input, consider
+    /// using [`Float::add_rational_round`] instead. If both of these things are true, consider
+    /// using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474922");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_prec_round(
+        self,
+        other: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_rational_prec_round_val_val_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value and the [`Rational`] by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded sum is less
+    /// than, equal to, or greater than the exact sum. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$
+    /// - $f(-\infty,x,p,m)=-\infty$
+    /// - $f(0.0,0,p,m)=0.0$
+    /// - $f(-0.0,0,p,m)=-0.0$
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_rational_prec_val_ref`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::add_rational_round_val_ref`] instead. If both of these things are
+    /// true, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474922");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_prec_round_val_ref(
+        self,
+        other: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_rational_prec_round_val_ref_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference and the [`Rational`] by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded sum is less than,
+    /// equal to, or greater than the exact sum. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$
+    /// - $f(-\infty,x,p,m)=-\infty$
+    /// - $f(0.0,0,p,m)=0.0$
+    /// - $f(-0.0,0,p,m)=-0.0$
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_rational_prec_ref_val`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::add_rational_round_ref_val`] instead. If both of these things are
+    /// true, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474922");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_prec_round_ref_val(
+        &self,
+        other: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_rational_prec_round_ref_val_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] and the [`Rational`] are both taken by reference.
+    /// An [`Ordering`] is also returned, indicating whether the rounded sum is less than, equal to,
+    /// or greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$
+    /// - $f(-\infty,x,p,m)=-\infty$
+    /// - $f(0.0,0,p,m)=0.0$
+    /// - $f(-0.0,0,p,m)=-0.0$
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_rational_prec_ref_ref`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::add_rational_round_ref_ref`] instead. If both of these things are
+    /// true, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474922");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_prec_round_ref_ref(
+        &self,
+        other: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_rational_prec_round_ref_ref_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] and the [`Rational`] are both taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$
+    /// - $f(-\infty,x,p)=-\infty$
+    /// - $f(0.0,0,p)=0.0$
+    /// - $f(-0.0,0,p)=-0.0$
+    /// - $f(x,-x,p)=0.0$ if $x$ is nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_rational_prec_round`] instead. If you know that your target precision is the
+    /// precision of the [`Float`] input, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_prec(self, other: Rational, prec: u64) -> (Float, Ordering) {
+        self.add_rational_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value and the [`Rational`] by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$
+    /// - $f(-\infty,x,p)=-\infty$
+    /// - $f(0.0,0,p)=0.0$
+    /// - $f(-0.0,0,p)=-0.0$
+    /// - $f(x,-x,p)=0.0$ if $x$ is nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_rational_prec_round_val_ref`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    /// );
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_prec_val_ref(self, other: &Rational, prec: u64) -> (Float, Ordering) {
+        self.add_rational_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference and the [`Rational`] by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$
+    /// - $f(-\infty,x,p)=-\infty$
+    /// - $f(0.0,0,p)=0.0$
+    /// - $f(-0.0,0,p)=-0.0$
+    /// - $f(x,-x,p)=0.0$ if $x$ is nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_rational_prec_round_ref_val`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_ref_val(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_prec_ref_val(&self, other: Rational, prec: u64) -> (Float, Ordering) {
+        self.add_rational_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] and the [`Rational`] are both taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$
+    /// - $f(-\infty,x,p)=-\infty$
+    /// - $f(0.0,0,p)=0.0$
+    /// - $f(-0.0,0,p)=-0.0$
+    /// - $f(x,-x,p)=0.0$ if $x$ is nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_rational_prec_round_ref_ref`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    /// );
+    /// assert_eq!(s.to_string(), "3.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_prec_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    /// );
+    /// assert_eq!(s.to_string(), "3.474926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_prec_ref_ref(&self, other: &Rational, prec: u64) -> (Float, Ordering) {
+        self.add_rational_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result with the specified rounding mode.
+    /// The [`Float`] and the [`Rational`] are both taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$
+    /// - $f(-\infty,x,m)=-\infty$
+    /// - $f(0.0,0,m)=0.0$
+    /// - $f(-0.0,0,m)=-0.0$
+    /// - $f(x,-x,m)=0.0$ if $x$ is nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_rational_prec_round`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231262");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231266");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231266");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_round(self, other: Rational, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round(other, prec, rm)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value and the [`Rational`] by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$
+    /// - $f(-\infty,x,m)=-\infty$
+    /// - $f(0.0,0,m)=0.0$
+    /// - $f(-0.0,0,m)=-0.0$
+    /// - $f(x,-x,m)=0.0$ if $x$ is nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_rational_prec_round_val_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231262");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231266");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231266");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_round_val_ref(
+        self,
+        other: &Rational,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference and the [`Rational`] by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$
+    /// - $f(-\infty,x,m)=-\infty$
+    /// - $f(0.0,0,m)=0.0$
+    /// - $f(-0.0,0,m)=-0.0$
+    /// - $f(x,-x,m)=0.0$ if $x$ is nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_rational_prec_round_ref_val`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231262");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231266");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231266");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_round_ref_val(
+        &self,
+        other: Rational,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Adds a [`Float`] and a [`Rational`], rounding the result with the specified rounding mode.
+    /// The [`Float`] and the [`Rational`] are both taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$
+    /// - $f(-\infty,x,m)=-\infty$
+    /// - $f(0.0,0,m)=0.0$
+    /// - $f(-0.0,0,m)=-0.0$
+    /// - $f(x,-x,m)=0.0$ if $x$ is nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_rational_prec_round_ref_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231262");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231266");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).add_rational_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "3.4749259869231266");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_rational_round_ref_ref(
+        &self,
+        other: &Rational,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Adds a [`Rational`] to a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Rational`] on the right-hand side is taken by
+    /// value. An [`Ordering`] is returned, indicating whether the rounded sum is less than, equal
+    /// to, or greater than the exact sum. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// See the [`Float::add_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_rational_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::add_rational_round_assign`] instead. If both of these things are
+    /// true, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Floor,
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "3.4");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Ceiling,
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Nearest,
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Floor,
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "3.474922");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Ceiling,
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.474926");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Nearest,
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.474926");
+    /// ```
+    #[inline]
+    pub fn add_rational_prec_round_assign(
+        &mut self,
+        other: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (sum, o) = add_rational_prec_round_ref_val_helper(self, other, false, prec, rm);
+        *self = sum;
+        o
+    }
+
+    /// Adds a [`Rational`] to a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Rational`] on the right-hand side is taken by
+    /// reference. An [`Ordering`] is returned, indicating whether the rounded sum is less than,
+    /// equal to, or greater than the exact sum. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    ///
+    /// See the [`Float::add_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::add_rational_prec_assign_ref`] instead. If you know that your target precision is
+    /// the precision of the [`Float`] input, consider using
+    /// [`Float::add_rational_round_assign_ref`] instead. If both of these things are true, consider
+    /// using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Floor,
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "3.4");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Ceiling,
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Nearest,
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Floor,
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "3.474922");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Ceiling,
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.474926");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Nearest,
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.474926");
+    /// ```
+    #[inline]
+    pub fn add_rational_prec_round_assign_ref(
+        &mut self,
+        other: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (sum, o) = add_rational_prec_round_ref_ref_helper(self, other, false, prec, rm);
+        *self = sum;
+        o
+    }
+
+    /// Adds a [`Rational`] to a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. The [`Rational`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// See the [`Float::add_rational_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_rational_prec_round_assign`] instead. If you know that your target precision is
+    /// the precision of the [`Float`] input, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_assign(Rational::from_unsigneds(1u8, 3), 5),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_assign(Rational::from_unsigneds(1u8, 3), 20),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.474926");
+    /// ```
+    #[inline]
+    pub fn add_rational_prec_assign(&mut self, other: Rational, prec: u64) -> Ordering {
+        self.add_rational_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Rational`] to a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. The [`Rational`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// See the [`Float::add_rational_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_rational_prec_round_assign_ref`] instead. If you know that your target
+    /// precision is the precision of the [`Float`] input, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_assign_ref(&Rational::from_unsigneds(1u8, 3), 5),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_prec_assign_ref(&Rational::from_unsigneds(1u8, 3), 20),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.474926");
+    /// ```
+    #[inline]
+    pub fn add_rational_prec_assign_ref(&mut self, other: &Rational, prec: u64) -> Ordering {
+        self.add_rational_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Rational`] to a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. The [`Rational`] on the right-hand side is taken by value. An [`Ordering`] is
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] on the left-hand side.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// See the [`Float::add_rational_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_rational_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "3.4749259869231262");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.4749259869231266");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.4749259869231266");
+    /// ```
+    #[inline]
+    pub fn add_rational_round_assign(&mut self, other: Rational, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round_assign(other, prec, rm)
+    }
+
+    /// Adds a [`Rational`] to a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. The [`Rational`] on the right-hand side is taken by reference. An [`Ordering`] is
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] on the left-hand side.
+    ///
+    /// $$
+    /// x \gets x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x+y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// See the [`Float::add_rational_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_rational_prec_round_assign_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact addition.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_round_assign_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "3.4749259869231262");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         RoundingMode::Ceiling,
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.4749259869231266");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_rational_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         RoundingMode::Nearest,
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "3.4749259869231266");
+    /// ```
+    #[inline]
+    pub fn add_rational_round_assign_ref(
+        &mut self,
+        other: &Rational,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round_assign_ref(other, prec, rm)
+    }
+}
+
+impl Add<Float> for Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s, taking both by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::add_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::add_round`].
+    /// If you want both of these things, consider using [`Float::add_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) + Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) + Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(Float::from(1.5) + Float::NEGATIVE_INFINITY, Float::NEGATIVE_INFINITY);
+    /// assert!((Float::INFINITY + Float::NEGATIVE_INFINITY).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) + Float::from(2.5), 4.0);
+    /// assert_eq!(Float::from(1.5) + Float::from(-2.5), -1.0);
+    /// assert_eq!(Float::from(-1.5) + Float::from(2.5), 1.0);
+    /// assert_eq!(Float::from(-1.5) + Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round(other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Add<&'a Float> for Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s, taking the first by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) + &Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) + &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(Float::from(1.5) + &Float::NEGATIVE_INFINITY, Float::NEGATIVE_INFINITY);
+    /// assert!((Float::INFINITY + &Float::NEGATIVE_INFINITY).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) + &Float::from(2.5), 4.0);
+    /// assert_eq!(Float::from(1.5) + &Float::from(-2.5), -1.0);
+    /// assert_eq!(Float::from(-1.5) + &Float::from(2.5), 1.0);
+    /// assert_eq!(Float::from(-1.5) + &Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Add<Float> for &'a Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s, taking the first by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) + Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) + Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(&Float::from(1.5) + Float::NEGATIVE_INFINITY, Float::NEGATIVE_INFINITY);
+    /// assert!((&Float::INFINITY + Float::NEGATIVE_INFINITY).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) + Float::from(2.5), 4.0);
+    /// assert_eq!(&Float::from(1.5) + Float::from(-2.5), -1.0);
+    /// assert_eq!(&Float::from(-1.5) + Float::from(2.5), 1.0);
+    /// assert_eq!(&Float::from(-1.5) + Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Add<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s, taking both by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) + &Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) + &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(&Float::from(1.5) + &Float::NEGATIVE_INFINITY, Float::NEGATIVE_INFINITY);
+    /// assert!((&Float::INFINITY + &Float::NEGATIVE_INFINITY).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) + &Float::from(2.5), 4.0);
+    /// assert_eq!(&Float::from(1.5) + &Float::from(-2.5), -1.0);
+    /// assert_eq!(&Float::from(-1.5) + &Float::from(2.5), 1.0);
+    /// assert_eq!(&Float::from(-1.5) + &Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl AddAssign<Float> for Float {
+    /// Adds a [`Float`] to a [`Float`] in place, taking the [`Float`] on the right-hand side by
+    /// value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += Float::INFINITY;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += Float::NEGATIVE_INFINITY;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x += Float::NEGATIVE_INFINITY;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += Float::from(2.5);
+    /// assert_eq!(x, 4.0);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += Float::from(-2.5);
+    /// assert_eq!(x, -1.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x += Float::from(2.5);
+    /// assert_eq!(x, 1.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x += Float::from(-2.5);
+    /// assert_eq!(x, -4.0);
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_assign(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> AddAssign<&'a Float> for Float {
+    /// Adds a [`Float`] to a [`Float`] in place, taking the [`Float`] on the right-hand side by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += &Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += &Float::INFINITY;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += &Float::NEGATIVE_INFINITY;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x += &Float::NEGATIVE_INFINITY;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += &Float::from(2.5);
+    /// assert_eq!(x, 4.0);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += &Float::from(-2.5);
+    /// assert_eq!(x, -1.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x += &Float::from(2.5);
+    /// assert_eq!(x, 1.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x += &Float::from(-2.5);
+    /// assert_eq!(x, -4.0);
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &'a Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_assign_ref(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl Add<Rational> for Float {
+    type Output = Float;
+
+    /// Adds a [`Float`] and a [`Rational`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the sum is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$
+    /// - $f(-\infty,x)=-\infty$
+    /// - $f(0.0,0)=0.0$
+    /// - $f(-0.0,0)=-0.0$
+    /// - $f(x,-x)=0.0$ if $x$ is nonzero
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Float::NAN + Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(Float::INFINITY + Rational::exact_from(1.5), Float::INFINITY);
+    /// assert_eq!(Float::NEGATIVE_INFINITY + Rational::exact_from(1.5), Float::NEGATIVE_INFINITY);
+    ///
+    /// assert_eq!(Float::from(2.5) + Rational::exact_from(1.5), 4.0);
+    /// assert_eq!(Float::from(2.5) + Rational::exact_from(-1.5), 1.0);
+    /// assert_eq!(Float::from(-2.5) + Rational::exact_from(1.5), -1.0);
+    /// assert_eq!(Float::from(-2.5) + Rational::exact_from(-1.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: Rational) -> Float {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Add<&'a Rational> for Float {
+    type Output = Float;
+
+    /// Adds a [`Float`] and a [`Rational`], taking the [`Float`] by value and the [`Rational`] by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the sum is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Float::NAN + &Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(Float::INFINITY + &Rational::exact_from(1.5), Float::INFINITY);
+    /// assert_eq!(Float::NEGATIVE_INFINITY + &Rational::exact_from(1.5), Float::NEGATIVE_INFINITY);
+    ///
+    /// assert_eq!(Float::from(2.5) + &Rational::exact_from(1.5), 4.0);
+    /// assert_eq!(Float::from(2.5) + &Rational::exact_from(-1.5), 1.0);
+    /// assert_eq!(Float::from(-2.5) + &Rational::exact_from(1.5), -1.0);
+    /// assert_eq!(Float::from(-2.5) + &Rational::exact_from(-1.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Rational) -> Float {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Add<Rational> for &'a Float {
+    type Output = Float;
+
+    /// Adds a [`Float`] and a [`Rational`], taking the [`Float`] by reference and the [`Rational`]
+    /// by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the sum is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Float::NAN + Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(&Float::INFINITY + Rational::exact_from(1.5), Float::INFINITY);
+    /// assert_eq!(&Float::NEGATIVE_INFINITY + Rational::exact_from(1.5), Float::NEGATIVE_INFINITY);
+    ///
+    /// assert_eq!(&Float::from(2.5) + Rational::exact_from(1.5), 4.0);
+    /// assert_eq!(&Float::from(2.5) + Rational::exact_from(-1.5), 1.0);
+    /// assert_eq!(&Float::from(-2.5) + Rational::exact_from(1.5), -1.0);
+    /// assert_eq!(&Float::from(-2.5) + Rational::exact_from(-1.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: Rational) -> Float {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Add<&'a Rational> for &'b Float {
+    type Output = Float;
+
+    /// Adds a [`Float`] and a [`Rational`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the sum is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Float::NAN + &Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(&Float::INFINITY + &Rational::exact_from(1.5), Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::NEGATIVE_INFINITY + &Rational::exact_from(1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    ///
+    /// assert_eq!(&Float::from(2.5) + &Rational::exact_from(1.5), 4.0);
+    /// assert_eq!(&Float::from(2.5) + &Rational::exact_from(-1.5), 1.0);
+    /// assert_eq!(&Float::from(-2.5) + &Rational::exact_from(1.5), -1.0);
+    /// assert_eq!(&Float::from(-2.5) + &Rational::exact_from(-1.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Rational) -> Float {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl AddAssign<Rational> for Float {
+    /// Adds a [`Rational`] to a [`Float`] in place, taking the [`Rational`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the sum is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Float::NAN;
+    /// x += Rational::exact_from(1.5);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x += Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x += Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x += Rational::exact_from(1.5);
+    /// assert_eq!(x, 4.0);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x += Rational::exact_from(-1.5);
+    /// assert_eq!(x, 1.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x += Rational::exact_from(1.5);
+    /// assert_eq!(x, -1.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x += Rational::exact_from(-1.5);
+    /// assert_eq!(x, -4.0);
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: Rational) {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round_assign(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> AddAssign<&'a Rational> for Float {
+    /// Adds a [`Rational`] to a [`Float`] in place, taking the [`Rational`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the sum is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Float::NAN;
+    /// x += &Rational::exact_from(1.5);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x += &Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x += &Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x += &Rational::exact_from(1.5);
+    /// assert_eq!(x, 4.0);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x += &Rational::exact_from(-1.5);
+    /// assert_eq!(x, 1.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x += &Rational::exact_from(1.5);
+    /// assert_eq!(x, -1.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x += &Rational::exact_from(-1.5);
+    /// assert_eq!(x, -4.0);
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &'a Rational) {
+        let prec = self.significant_bits();
+        self.add_rational_prec_round_assign_ref(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl Add<Float> for Rational {
+    type Output = Float;
+
+    /// Adds a [`Rational`] and a [`Float`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the sum is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=\text{NaN}$
+    /// - $f(x,\infty)=\infty$
+    /// - $f(x,-\infty)=-\infty$
+    /// - $f(0,0.0)=0.0$
+    /// - $f(0,-0.0)=-0.0$
+    /// - $f(x,-x)=0.0$ if $x$ is nonzero
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Rational::exact_from(1.5) + Float::NAN).is_nan());
+    /// assert_eq!(Rational::exact_from(1.5) + Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(Rational::exact_from(1.5) + Float::NEGATIVE_INFINITY, Float::NEGATIVE_INFINITY);
+    ///
+    /// assert_eq!(Rational::exact_from(1.5) + Float::from(2.5), 4.0);
+    /// assert_eq!(Rational::exact_from(1.5) + Float::from(-2.5), -1.0);
+    /// assert_eq!(Rational::exact_from(-1.5) + Float::from(2.5), 1.0);
+    /// assert_eq!(Rational::exact_from(-1.5) + Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .add_rational_prec_round(self, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Add<&'a Float> for Rational {
+    type Output = Float;
+
+    /// Adds a [`Rational`] and a [`Float`], taking the [`Rational`] by value and the [`Float`] by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the sum is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Rational::exact_from(1.5) + &Float::NAN).is_nan());
+    /// assert_eq!(Rational::exact_from(1.5) + &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(Rational::exact_from(1.5) + &Float::NEGATIVE_INFINITY, Float::NEGATIVE_INFINITY);
+    ///
+    /// assert_eq!(Rational::exact_from(1.5) + &Float::from(2.5), 4.0);
+    /// assert_eq!(Rational::exact_from(1.5) + &Float::from(-2.5), -1.0);
+    /// assert_eq!(Rational::exact_from(-1.5) + &Float::from(2.5), 1.0);
+    /// assert_eq!(Rational::exact_from(-1.5) + &Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .add_rational_prec_round_ref_val(self, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Add<Float> for &'a Rational {
+    type Output = Float;
+
+    /// Adds a [`Rational`] and a [`Float`], taking the [`Rational`] by reference and the [`Float`]
+    /// by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the sum is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Rational::exact_from(1.5) + Float::NAN).is_nan());
+    /// assert_eq!(&Rational::exact_from(1.5) + Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(&Rational::exact_from(1.5) + Float::NEGATIVE_INFINITY, Float::NEGATIVE_INFINITY);
+    ///
+    /// assert_eq!(&Rational::exact_from(1.5) + Float::from(2.5), 4.0);
+    /// assert_eq!(&Rational::exact_from(1.5) + Float::from(-2.5), -1.0);
+    /// assert_eq!(&Rational::exact_from(-1.5) + Float::from(2.5), 1.0);
+    /// assert_eq!(&Rational::exact_from(-1.5) + Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .add_rational_prec_round_val_ref(self, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Add<&'a Float> for &'b Rational {
+    type Output = Float;
+
+    /// Adds a [`Rational`] and a [`Float`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the sum is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\epsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Rational::exact_from(1.5) + &Float::NAN).is_nan());
+    /// assert_eq!(&Rational::exact_from(1.5) + &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Rational::exact_from(1.5) + &Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    ///
+    /// assert_eq!(&Rational::exact_from(1.5) + &Float::from(2.5), 4.0);
+    /// assert_eq!(&Rational::exact_from(1.5) + &Float::from(-2.5), -1.0);
+    /// assert_eq!(&Rational::exact_from(-1.5) + &Float::from(2.5), 1.0);
+    /// assert_eq!(&Rational::exact_from(-1.5) + &Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .add_rational_prec_round_ref_ref(self, prec, RoundingMode::Nearest)
+            .0
+    }
+}
@@ -0,0 +1,660 @@
+use crate::arithmetic::atan::{atan_enclosure, pi_multiple_prec_round};
+use crate::arithmetic::exp::{cubic_term_is_negligible, integer_and_pow, round_nudged, ziv_round};
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{
+    CeilingSqrt, FloorSqrt, PowerOf2, Square, UnsignedAbs,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Given $x = m2^p$ with $0 < |m|2^p < 1$, returns $D = 2^{-2p} - m^2$, so that
+// $\sqrt{1-x^2} = \sqrt{D}2^p$.
+pub(crate) fn one_minus_square(m: &Natural, pow: i64) -> Natural {
+    Natural::power_of_2(u64::exact_from(-pow) << 1) - m.square()
+}
+
+// Computes $\arcsin x$, where $0 < |x| < 1$, rounding to `prec` bits.
+fn asin_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    let (m, pow) = integer_and_pow(x);
+    if cubic_term_is_negligible(&m, exponent, prec) {
+        // $|\arcsin x| > |x|$, and the difference is too small to affect the rounding.
+        let direction = if *x > 0u32 {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+        return round_nudged(m, pow, direction, prec, rm);
+    }
+    let negative = *x < 0u32;
+    let m = m.unsigned_abs();
+    // $\arcsin x = \arctan(x/\sqrt{1-x^2}) = \arctan(m/\sqrt{D})$.
+    let d = one_minus_square(&m, pow);
+    ziv_round(prec, rm, |working_prec| {
+        let g = working_prec + u64::exact_from((-exponent).max(0)) + 8;
+        let h = g + 8;
+        let n = &m << h;
+        let d = &d << (h << 1);
+        let (lo, hi) = atan_enclosure(&n, &n, &(&d).floor_sqrt(), &d.ceiling_sqrt(), 0, g);
+        let pow = -i64::exact_from(g);
+        if negative {
+            (-hi, -lo, pow)
+        } else {
+            (lo, hi, pow)
+        }
+    })
+}
+
+// Computes $\arcsin x$, rounding to `prec` bits.
+fn asin_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { .. }) => (float_nan!(), Ordering::Equal),
+        Float(Zero { .. }) => (x.clone(), Ordering::Equal),
+        _ => match x.get_exponent().unwrap().cmp(&1) {
+            Ordering::Equal if *x == 1u32 => pi_multiple_prec_round(1, -1, prec, rm),
+            Ordering::Equal if *x == -1i32 => pi_multiple_prec_round(-1, -1, prec, rm),
+            Ordering::Greater | Ordering::Equal => (float_nan!(), Ordering::Equal),
+            Ordering::Less => asin_finite(x, prec, rm),
+        },
+    }
+}
+
+impl Float {
+    /// Computes the arcsine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arcsine is less than, equal to, or greater than the
+    /// exact arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arcsin x+\epsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.098");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.1001674");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.1001675");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.1001674");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        asin_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arcsine is less than, equal to, or greater than the
+    /// exact arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arcsin x+\epsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.098");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.1001674");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.1001675");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.1001674");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        asin_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arcsine is less than, equal to, or greater than the exact
+    /// arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arcsin x+\epsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec(5);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec(20);
+    /// assert_eq!(x.to_string(), "0.1001674");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec(self, prec: u64) -> (Float, Ordering) {
+        self.asin_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arcsine is less than, equal to, or greater than the exact
+    /// arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arcsin x+\epsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_ref(5);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.1001674");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.asin_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arcsine is less than, equal to, or greater than the exact arcsine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arcsin x+\epsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).asin_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.1001674211615598");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.10016742116155981");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.1001674211615598");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asin_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.asin_prec_round(prec, rm)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arcsine is less than, equal to, or greater than the exact arcsine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arcsin x+\epsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).asin_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.1001674211615598");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.10016742116155981");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).asin_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.1001674211615598");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asin_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.asin_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its arcsine, rounding the result to the specified precision and
+    /// with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded arcsine is less than, equal to, or greater than the exact arcsine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \arcsin x+\epsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.asin_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.098");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.asin_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.1");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.asin_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.1");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.asin_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.1001674");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.asin_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.1001675");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.asin_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.1001674");
+    /// ```
+    #[inline]
+    pub fn asin_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (asin, o) = asin_prec_round_ref_helper(self, prec, rm);
+        *self = asin;
+        o
+    }
+
+    /// Replaces a [`Float`] with its arcsine, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded arcsine is
+    /// less than, equal to, or greater than the exact arcsine. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \arcsin x+\epsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// See the [`Float::asin_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.1");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.1001674");
+    /// ```
+    #[inline]
+    pub fn asin_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.asin_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its arcsine, rounding the result with the specified rounding mode.
+    /// An [`Ordering`] is returned, indicating whether the rounded arcsine is less than, equal to,
+    /// or greater than the exact arcsine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \arcsin x+\epsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::asin_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.1001674211615598");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.asin_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.10016742116155981");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.asin_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.1001674211615598");
+    /// ```
+    #[inline]
+    pub fn asin_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.asin_prec_round_assign(prec, rm)
+    }
+}
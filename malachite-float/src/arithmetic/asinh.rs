@@ -0,0 +1,699 @@
+use crate::arithmetic::exp::{
+    cubic_term_is_negligible, integer_and_pow, round_nudged, to_fixed_point, ziv_round,
+};
+use crate::arithmetic::ln::{ln_enclosure, ln_interval_enclosure};
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{CeilingSqrt, FloorSqrt, PowerOf2, Square};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Computes $\operatorname{arsinh} x$, where $x$ is finite and nonzero, rounding to `prec` bits.
+fn asinh_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    let (m, pow) = integer_and_pow(x);
+    let negative = *x < 0u32;
+    if cubic_term_is_negligible(&m, exponent, prec) {
+        // $|\operatorname{arsinh} x| < |x|$, and the difference is too small to affect the
+        // rounding.
+        let direction = if negative {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+        return round_nudged(m, pow, direction, prec, rm);
+    }
+    let m = Integer::from(m.unsigned_abs_ref());
+    ziv_round(prec, rm, |working_prec| {
+        let (lo, hi, f) = if exponent > 1 {
+            // $\log 2|x| < \operatorname{arsinh} |x| < \log 2|x| + 1/(4x^2)$.
+            let enclosure = ln_enclosure(m.unsigned_abs_ref(), pow + 1, working_prec);
+            let f = enclosure.f;
+            if (exponent - 1) << 1 >= i64::exact_from(f) + 2 {
+                let (lo, hi) = enclosure.ln();
+                (lo, hi + Integer::ONE, f)
+            } else {
+                asinh_enclosure(&m, pow, exponent, working_prec)
+            }
+        } else {
+            asinh_enclosure(&m, pow, exponent, working_prec)
+        };
+        let pow = -i64::exact_from(f);
+        if negative {
+            (-hi, -lo, pow)
+        } else {
+            (lo, hi, pow)
+        }
+    })
+}
+
+// Returns an enclosure of $\operatorname{arsinh} x = \log(x + \sqrt{x^2+1})$, where $x = m2^p$ is
+// positive and has exponent $e$.
+fn asinh_enclosure(
+    m: &Integer,
+    pow: i64,
+    exponent: i64,
+    working_prec: u64,
+) -> (Integer, Integer, u64) {
+    // When $x$ is small, $\operatorname{arsinh} x$ is about as large as $x$.
+    let g = working_prec + u64::exact_from((-exponent).max(0)) + 8;
+    let one = Natural::power_of_2(g << 1);
+    let x_lo = Natural::exact_from(to_fixed_point(m, pow, g, RoundingMode::Floor));
+    let x_hi = Natural::exact_from(to_fixed_point(m, pow, g, RoundingMode::Ceiling));
+    let a_lo = (&one + (&x_lo).square()).floor_sqrt() + x_lo;
+    let a_hi = (one + (&x_hi).square()).ceiling_sqrt() + x_hi;
+    ln_interval_enclosure(&a_lo, &a_hi, -i64::exact_from(g), working_prec)
+}
+
+// Computes $\operatorname{arsinh} x$, rounding to `prec` bits.
+fn asinh_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { .. }) | Float(Zero { .. }) => (x.clone(), Ordering::Equal),
+        _ => asinh_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic sine is
+    /// less than, equal to, or greater than the exact inverse hyperbolic sine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{arsinh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-\infty$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asinh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::asinh_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.862297");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asinh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        asinh_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic sine is
+    /// less than, equal to, or greater than the exact inverse hyperbolic sine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{arsinh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-\infty$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asinh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::asinh_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.862297");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asinh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        asinh_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic sine is less than, equal to, or
+    /// greater than the exact inverse hyperbolic sine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the inverse hyperbolic sine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{arsinh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-\infty$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec(5);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec(20);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asinh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.asinh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded inverse hyperbolic sine is less than, equal
+    /// to, or greater than the exact inverse hyperbolic sine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the inverse hyperbolic sine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{arsinh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-\infty$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asinh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.asinh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded inverse hyperbolic sine is less than, equal to, or greater than the
+    /// exact inverse hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{arsinh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-\infty$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asinh_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.862295743310848");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn asinh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.asinh_prec_round(prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic sine is less than, equal to, or greater
+    /// than the exact inverse hyperbolic sine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{arsinh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-\infty$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asinh_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.862295743310848");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn asinh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.asinh_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its inverse hyperbolic sine, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded inverse hyperbolic sine is less than, equal to, or greater than the
+    /// exact inverse hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \operatorname{arsinh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asinh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::asinh_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.asinh_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.81");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.asinh_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.asinh_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.asinh_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.862295");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.asinh_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.862297");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.asinh_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// ```
+    #[inline]
+    pub fn asinh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (asinh, o) = asinh_prec_round_ref_helper(self, prec, rm);
+        *self = asinh;
+        o
+    }
+
+    /// Replaces a [`Float`] with its inverse hyperbolic sine, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded inverse hyperbolic sine is less than, equal to, or greater than the exact inverse
+    /// hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \operatorname{arsinh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// See the [`Float::asinh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// ```
+    #[inline]
+    pub fn asinh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.asinh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its inverse hyperbolic sine, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded inverse
+    /// hyperbolic sine is less than, equal to, or greater than the exact inverse hyperbolic sine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \operatorname{arsinh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::asinh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::asinh_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.862295743310848");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.asinh_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.asinh_round_assign(RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    /// ```
+    #[inline]
+    pub fn asinh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.asinh_prec_round_assign(prec, rm)
+    }
+}
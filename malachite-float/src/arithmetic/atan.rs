@@ -0,0 +1,817 @@
+use crate::arithmetic::exp::{cubic_term_is_negligible, integer_and_pow, round_nudged, ziv_round};
+use crate::constants::pi::pi_fixed_point_bounds;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{
+    CeilingSqrt, DivRound, FloorSqrt, PowerOf2, ShrRound, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, SaturatingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Returns $n2^e/d$, rounded with `rm`, which must be `Floor` or `Ceiling`. `n` and `d` must be
+// positive.
+pub(crate) fn div_pow_round(n: &Natural, d: &Natural, e: i64, rm: RoundingMode) -> Natural {
+    if e >= 0 {
+        (n << u64::exact_from(e)).div_round(d, rm).0
+    } else if n.significant_bits() <= e.unsigned_abs() {
+        // $0 < n2^e/d < 1$.
+        if rm == RoundingMode::Floor {
+            Natural::ZERO
+        } else {
+            Natural::ONE
+        }
+    } else {
+        n.div_round(d << e.unsigned_abs(), rm).0
+    }
+}
+
+// Returns a lower or upper bound, depending on whether `rm` is `Floor` or `Ceiling`, for the sum
+// $\sum_{k=0}^\infty (-1)^k a_k$, where $a_k = \lfloor p_k/(2k+1) \rfloor$ or
+// $\lceil p_k/(2k+1) \rceil$ and `next_power` maps lower and upper bounds for $p_k$ to lower and
+// upper bounds for $p_{k+1}$. The true terms must decrease, and the sum is stopped once they are
+// less than 1.
+fn alternating_odd_series<F: FnMut(Natural, Natural) -> (Natural, Natural)>(
+    mut power_lo: Natural,
+    mut power_hi: Natural,
+    rm: RoundingMode,
+    mut next_power: F,
+) -> Natural {
+    let mut sum = Integer::ZERO;
+    let mut denominator = 1u64;
+    let mut positive = true;
+    loop {
+        // Positive terms are rounded in the direction of `rm`, negative terms in the opposite
+        // direction.
+        let term = if positive == (rm == RoundingMode::Floor) {
+            (&power_lo)
+                .div_round(Natural::from(denominator), RoundingMode::Floor)
+                .0
+        } else {
+            (&power_hi)
+                .div_round(Natural::from(denominator), RoundingMode::Ceiling)
+                .0
+        };
+        if positive {
+            sum += Integer::from(term);
+        } else {
+            sum -= Integer::from(term);
+        }
+        (power_lo, power_hi) = next_power(power_lo, power_hi);
+        if power_hi <= 1u32 {
+            // The remainder of an alternating series with decreasing terms is smaller than its
+            // first term.
+            if rm == RoundingMode::Floor {
+                sum -= Integer::ONE;
+            } else {
+                sum += Integer::ONE;
+            }
+            return Natural::saturating_from(sum);
+        }
+        denominator += 2;
+        positive = !positive;
+    }
+}
+
+// Rounds $c\pi2^p$ to `prec` bits, where $c$ is a nonzero integer.
+pub(crate) fn pi_multiple_prec_round(
+    c: i32,
+    pow: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let c = Integer::from(c);
+    ziv_round(prec, rm, |working_prec| {
+        let f = working_prec + 4;
+        let (lo, hi) = pi_fixed_point_bounds(f);
+        let (lo, hi) = (Integer::from(lo) * &c, Integer::from(hi) * &c);
+        let pow = pow - i64::exact_from(f);
+        if c > 0u32 {
+            (lo, hi, pow)
+        } else {
+            (hi, lo, pow)
+        }
+    })
+}
+
+// Returns a lower or upper bound, depending on whether `rm` is `Floor` or `Ceiling`, for
+// $\arctan(t2^{-g})2^g$, where $0 \leq t2^{-g} \leq 4$.
+//
+// The argument is replaced $s$ times by $t/(1+\sqrt{1+t^2})$, with $s$ around $\sqrt{g}/2$, which
+// halves its arctangent each time; then the Taylor series is summed and the result is multiplied by
+// $2^s$. Since the map is increasing, rounding each step in the direction of `rm` keeps the result
+// a bound.
+fn atan_fixed_point(t: &Natural, g: u64, rm: RoundingMode) -> Natural {
+    let s = (g.floor_sqrt() >> 1) + 1;
+    let h = g + s + g.significant_bits() + 8;
+    let one = Natural::power_of_2(h);
+    let one_squared = Natural::power_of_2(h << 1);
+    let mut t = t << (h - g);
+    for _ in 0..s {
+        let square = &one_squared + (&t).square();
+        let root = if rm == RoundingMode::Floor {
+            square.ceiling_sqrt()
+        } else {
+            square.floor_sqrt()
+        };
+        t = (t << h).div_round(&one + root, rm).0;
+    }
+    let t_squared_lo = (&t).square().shr_round(h, RoundingMode::Floor).0;
+    let t_squared_hi = (&t).square().shr_round(h, RoundingMode::Ceiling).0;
+    let sum = alternating_odd_series(t.clone(), t, rm, |lo, hi| {
+        (
+            (lo * &t_squared_lo).shr_round(h, RoundingMode::Floor).0,
+            (hi * &t_squared_hi).shr_round(h, RoundingMode::Ceiling).0,
+        )
+    });
+    (sum << s).shr_round(h - g, rm).0
+}
+
+// Given bounds `n_lo` $\leq n \leq$ `n_hi` and `d_lo` $\leq d \leq$ `d_hi` on positive numbers $n$
+// and $d$, returns `lo` and `hi` such that `lo`$2^{-g} \leq \arctan(n2^e/d) \leq$ `hi`$2^{-g}$.
+//
+// If $n2^e/d$ is large, $\arctan(n2^e/d) = \pi/2 - \arctan(d2^{-e}/n)$ is used instead.
+pub(crate) fn atan_enclosure(
+    n_lo: &Natural,
+    n_hi: &Natural,
+    d_lo: &Natural,
+    d_hi: &Natural,
+    e: i64,
+    g: u64,
+) -> (Integer, Integer) {
+    let ig = i64::exact_from(g);
+    if i64::exact_from(n_hi.significant_bits()) + e <= i64::exact_from(d_lo.significant_bits()) {
+        // $n2^e/d < 2$.
+        let t_lo = div_pow_round(n_lo, d_hi, e + ig, RoundingMode::Floor);
+        let t_hi = div_pow_round(n_hi, d_lo, e + ig, RoundingMode::Ceiling);
+        (
+            Integer::from(atan_fixed_point(&t_lo, g, RoundingMode::Floor)),
+            Integer::from(atan_fixed_point(&t_hi, g, RoundingMode::Ceiling)),
+        )
+    } else {
+        // $d2^{-e}/n < 2$, up to the widths of the bounds.
+        let r_lo = div_pow_round(d_lo, n_hi, ig - e, RoundingMode::Floor);
+        let r_hi = div_pow_round(d_hi, n_lo, ig - e, RoundingMode::Ceiling);
+        // $\pi2^{g-1} = (\pi/2)2^g$.
+        let (half_pi_lo, half_pi_hi) = pi_fixed_point_bounds(g - 1);
+        (
+            Integer::from(half_pi_lo)
+                - Integer::from(atan_fixed_point(&r_hi, g, RoundingMode::Ceiling)),
+            Integer::from(half_pi_hi)
+                - Integer::from(atan_fixed_point(&r_lo, g, RoundingMode::Floor)),
+        )
+    }
+}
+
+// Computes $\arctan x$, where $x$ is finite and nonzero, rounding to `prec` bits.
+fn atan_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    let (m, pow) = integer_and_pow(x);
+    if cubic_term_is_negligible(&m, exponent, prec) {
+        // $|\arctan x| < |x|$, and the difference is too small to affect the rounding.
+        let direction = if *x > 0u32 {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        return round_nudged(m, pow, direction, prec, rm);
+    }
+    let negative = *x < 0u32;
+    let m = m.unsigned_abs();
+    ziv_round(prec, rm, |working_prec| {
+        // $\arctan x$ is about as large as $x$ when $x$ is small.
+        let g = working_prec + u64::exact_from((-exponent).max(0)) + 8;
+        let (lo, hi) = atan_enclosure(&m, &m, &Natural::ONE, &Natural::ONE, pow, g);
+        let pow = -i64::exact_from(g);
+        if negative {
+            (-hi, -lo, pow)
+        } else {
+            (lo, hi, pow)
+        }
+    })
+}
+
+// Computes $\arctan x$, rounding to `prec` bits.
+fn atan_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() => (float_nan!(), Ordering::Equal),
+        Float(Infinity { sign }) => {
+            pi_multiple_prec_round(if *sign { 1 } else { -1 }, -1, prec, rm)
+        }
+        Float(Zero { .. }) => (x.clone(), Ordering::Equal),
+        _ => atan_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the arctangent of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arctan x+\epsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// The arctangent of $\pm\infty$ is $\pm\pi/2$, rounded as described above.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.262629");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        atan_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded arctangent is less than, equal to, or greater
+    /// than the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arctan x+\epsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// The arctangent of $\pm\infty$ is $\pm\pi/2$, rounded as described above.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.262629");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        atan_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arctan x+\epsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// The arctangent of $\pm\infty$ is $\pm\pi/2$, rounded as described above.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec(5);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec(20);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec(self, prec: u64) -> (Float, Ordering) {
+        self.atan_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arctan x+\epsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// The arctangent of $\pm\infty$ is $\pm\pi/2$, rounded as described above.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.atan_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arctan x+\epsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// The arctangent of $\pm\infty$ is $\pm\pi/2$, rounded as described above.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).atan_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.2626272556789115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn atan_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.atan_prec_round(prec, rm)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arctan x+\epsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// The arctangent of $\pm\infty$ is $\pm\pi/2$, rounded as described above.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).atan_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.2626272556789115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn atan_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.atan_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its arctangent, rounding the result to the specified precision and
+    /// with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded arctangent is less than, equal to, or greater than the exact arctangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \arctan x+\epsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.262627");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.262629");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// ```
+    #[inline]
+    pub fn atan_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (atan, o) = atan_prec_round_ref_helper(self, prec, rm);
+        *self = atan;
+        o
+    }
+
+    /// Replaces a [`Float`] with its arctangent, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded arctangent
+    /// is less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \arctan x+\epsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// See the [`Float::atan_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// ```
+    #[inline]
+    pub fn atan_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.atan_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its arctangent, rounding the result with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded arctangent is less than,
+    /// equal to, or greater than the exact arctangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \arctan x+\epsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::atan_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.2626272556789115");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan_round_assign(RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    /// ```
+    #[inline]
+    pub fn atan_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.atan_prec_round_assign(prec, rm)
+    }
+}
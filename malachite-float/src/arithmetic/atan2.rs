@@ -0,0 +1,1455 @@
+use crate::arithmetic::atan::{atan_enclosure, pi_multiple_prec_round};
+use crate::arithmetic::exp::{integer_and_pow, ziv_round};
+use crate::constants::pi::pi_fixed_point_bounds;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, float_negative_zero, float_zero, Float};
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::{max, Ordering};
+
+// Computes $\operatorname{atan2}(y, x)$, where $x$ and $y$ are finite and nonzero, rounding to
+// `prec` bits.
+fn atan2_finite(y: &Float, x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let (my, py) = integer_and_pow(y);
+    let (mx, px) = integer_and_pow(x);
+    let y_negative = my < 0u32;
+    let x_negative = mx < 0u32;
+    let (my, mx) = (my.unsigned_abs(), mx.unsigned_abs());
+    // $|y/x| > 2^{e_y-e_x-1}$.
+    let extra = u64::exact_from((x.get_exponent().unwrap() - y.get_exponent().unwrap() + 1).max(0));
+    ziv_round(prec, rm, |working_prec| {
+        // If $x < 0$, the result is close to $\pm\pi$ when $|y/x|$ is small, so no extra
+        // precision is needed.
+        let g = working_prec + 8 + if x_negative { 0 } else { extra };
+        let (lo, hi) = atan_enclosure(&my, &my, &mx, &mx, py - px, g);
+        let (lo, hi) = if x_negative {
+            let (pi_lo, pi_hi) = pi_fixed_point_bounds(g);
+            (Integer::from(pi_lo) - hi, Integer::from(pi_hi) - lo)
+        } else {
+            (lo, hi)
+        };
+        let pow = -i64::exact_from(g);
+        if y_negative {
+            (-hi, -lo, pow)
+        } else {
+            (lo, hi, pow)
+        }
+    })
+}
+
+// Computes $\operatorname{atan2}(y, x)$, rounding to `prec` bits.
+fn atan2_prec_round_ref_ref_helper(
+    y: &Float,
+    x: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    let sign = if y.is_sign_positive() { 1 } else { -1 };
+    match (y, x) {
+        (float_nan!(), _) | (_, float_nan!()) => (float_nan!(), Ordering::Equal),
+        (Float(Zero { .. }), _) => {
+            if x.is_sign_positive() {
+                (y.clone(), Ordering::Equal)
+            } else {
+                pi_multiple_prec_round(sign, 0, prec, rm)
+            }
+        }
+        (Float(Infinity { .. }), Float(Infinity { sign: x_sign })) => {
+            // $\pm\pi/4$ or $\pm3\pi/4$.
+            pi_multiple_prec_round(if *x_sign { sign } else { 3 * sign }, -2, prec, rm)
+        }
+        (Float(Infinity { .. }), _) | (_, Float(Zero { .. })) => {
+            pi_multiple_prec_round(sign, -1, prec, rm)
+        }
+        (_, Float(Infinity { sign: x_sign })) => {
+            if *x_sign {
+                (
+                    if sign > 0 {
+                        float_zero!()
+                    } else {
+                        float_negative_zero!()
+                    },
+                    Ordering::Equal,
+                )
+            } else {
+                pi_multiple_prec_round(sign, 0, prec, rm)
+            }
+        }
+        _ => atan2_finite(y, x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result to the specified precision and
+    /// with the specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded arctangent is less than, equal to, or greater
+    /// than the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,p,m)=f(x,-0.0,p,m)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=f(x,-0.0,p,m)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::atan2_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.8575125");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        atan2_prec_round_ref_ref_helper(&self, &other, prec, rm)
+    }
+
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result to the specified precision and
+    /// with the specified rounding mode. The first [`Float`] is taken by value and the second by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded arctangent is
+    /// less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,p,m)=f(x,-0.0,p,m)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=f(x,-0.0,p,m)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.8575125");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        atan2_prec_round_ref_ref_helper(&self, other, prec, rm)
+    }
+
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result to the specified precision and
+    /// with the specified rounding mode. The first [`Float`] is taken by reference and the second
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded arctangent is
+    /// less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,p,m)=f(x,-0.0,p,m)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=f(x,-0.0,p,m)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.8575125");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        atan2_prec_round_ref_ref_helper(self, &other, prec, rm)
+    }
+
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result to the specified precision and
+    /// with the specified rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded arctangent is less than, equal to, or greater
+    /// than the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,p,m)=f(x,-0.0,p,m)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=f(x,-0.0,p,m)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.8575125");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        atan2_prec_round_ref_ref_helper(self, other, prec, rm)
+    }
+
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result to the nearest value of the
+    /// specified precision. Both [`Float`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,p)=f(x,-0.0,p)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,p)=f(x,-0.0,p)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec(Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec(Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result to the nearest value of the
+    /// specified precision. The first [`Float`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arctangent is less than, equal
+    /// to, or greater than the exact arctangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,p)=f(x,-0.0,p)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,p)=f(x,-0.0,p)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result to the nearest value of the
+    /// specified precision. The first [`Float`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arctangent is less than, equal
+    /// to, or greater than the exact arctangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,p)=f(x,-0.0,p)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,p)=f(x,-0.0,p)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result to the nearest value of the
+    /// specified precision. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,p)=f(x,-0.0,p)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,p)=f(x,-0.0,p)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result with the specified rounding mode.
+    /// Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arctangent is less than, equal to, or greater than the exact arctangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,m)=f(x,-0.0,m)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,m)=f(x,-0.0,m)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan2_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round(other, prec, rm)
+    }
+
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result with the specified rounding mode.
+    /// The first [`Float`] is taken by value and the second by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,m)=f(x,-0.0,m)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,m)=f(x,-0.0,m)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result with the specified rounding mode.
+    /// The first [`Float`] is taken by reference and the second by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,m)=f(x,-0.0,m)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,m)=f(x,-0.0,m)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Computes the arctangent of the quotient of two [`Float`]s, using the signs of both to
+    /// determine the quadrant of the result, rounding the result with the specified rounding mode.
+    /// Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// The result is the angle, in $(-\pi, \pi]$, between the positive $x$-axis and the point $(y,
+    /// x)$; if $y > 0$, it is equal to $\arctan(x/y)$. If $x$ and $y$ are both infinite, the result
+    /// is an odd multiple of $\pm\pi/4$, rounded as described above.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$ or $x=0.0$
+    /// - $f(x,0.0,m)=f(x,-0.0,m)=\pi/2$ if $x>0.0$
+    /// - $f(x,0.0,m)=f(x,-0.0,m)=-\pi/2$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Replaces a [`Float`] with the arctangent of its quotient by another [`Float`], using the
+    /// signs of both to determine the quadrant of the result, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] on the right-hand side is
+    /// taken by value. An [`Ordering`] is returned, indicating whether the rounded arctangent is
+    /// less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_assign`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575115");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575125");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_round_assign(
+        &mut self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (atan2, o) = atan2_prec_round_ref_ref_helper(self, &other, prec, rm);
+        *self = atan2;
+        o
+    }
+
+    /// Replaces a [`Float`] with the arctangent of its quotient by another [`Float`], using the
+    /// signs of both to determine the quadrant of the result, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] on the right-hand side is
+    /// taken by reference. An [`Ordering`] is returned, indicating whether the rounded arctangent
+    /// is less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::atan2_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact arctangent, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575115");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575125");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (atan2, o) = atan2_prec_round_ref_ref_helper(self, other, prec, rm);
+        *self = atan2;
+        o
+    }
+
+    /// Replaces a [`Float`] with the arctangent of its quotient by another [`Float`], using the
+    /// signs of both to determine the quadrant of the result, rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded arctangent is less than, equal to,
+    /// or greater than the exact arctangent. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// See the [`Float::atan2_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign(Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign(Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.atan2_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with the arctangent of its quotient by another [`Float`], using the
+    /// signs of both to determine the quadrant of the result, rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] on the right-hand side is taken by
+    /// reference. An [`Ordering`] is returned, indicating whether the rounded arctangent is less
+    /// than, equal to, or greater than the exact arctangent. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    ///
+    /// See the [`Float::atan2_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign_ref(&Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign_ref(&Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.atan2_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with the arctangent of its quotient by another [`Float`], using the
+    /// signs of both to determine the quadrant of the result, rounding the result with the
+    /// specified rounding mode. The [`Float`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded arctangent is less than, equal to,
+    /// or greater than the exact arctangent. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs.
+    ///
+    /// $$
+    /// x \gets \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// See the [`Float::atan2_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_round_assign(Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_round_assign(Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575117863558464");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_round_assign(Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// ```
+    #[inline]
+    pub fn atan2_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_assign(other, prec, rm)
+    }
+
+    /// Replaces a [`Float`] with the arctangent of its quotient by another [`Float`], using the
+    /// signs of both to determine the quadrant of the result, rounding the result with the
+    /// specified rounding mode. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded arctangent is less than, equal to,
+    /// or greater than the exact arctangent. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs.
+    ///
+    /// $$
+    /// x \gets \operatorname{atan2}(x,y)+\epsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(x,y)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(x,y)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(x,y)|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// See the [`Float::atan2_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact arctangent.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_round_assign_ref(&Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_round_assign_ref(&Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575117863558464");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_round_assign_ref(&Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// ```
+    #[inline]
+    pub fn atan2_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_assign_ref(other, prec, rm)
+    }
+}
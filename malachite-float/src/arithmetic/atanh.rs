@@ -0,0 +1,686 @@
+use crate::arithmetic::atan::div_pow_round;
+use crate::arithmetic::exp::{cubic_term_is_negligible, integer_and_pow, round_nudged, ziv_round};
+use crate::arithmetic::ln::ln_interval_enclosure;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_negative_infinity, Float};
+use malachite_base::num::arithmetic::traits::{PowerOf2, UnsignedAbs};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Computes $\operatorname{artanh} x$, where $0 < |x| < 1$, rounding to `prec` bits.
+fn atanh_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    let (m, pow) = integer_and_pow(x);
+    let negative = *x < 0u32;
+    if cubic_term_is_negligible(&m, exponent, prec) {
+        // $|\operatorname{artanh} x| > |x|$, and the difference is too small to affect the
+        // rounding.
+        let direction = if negative {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        return round_nudged(m, pow, direction, prec, rm);
+    }
+    // $\operatorname{artanh} |x| = \log((1+|x|)/(1-|x|))/2 = \log(P/Q)/2$, where
+    // $P = 2^{-p} + |m|$ and $Q = 2^{-p} - |m|$.
+    let m = m.unsigned_abs();
+    let one = Natural::power_of_2(pow.unsigned_abs());
+    let p = &one + &m;
+    let q = one - m;
+    ziv_round(prec, rm, |working_prec| {
+        // When $x$ is small, $\operatorname{artanh} x$ is about as large as $x$.
+        let g = working_prec + u64::exact_from((-exponent).max(0)) + 8;
+        let ig = i64::exact_from(g);
+        let (lo, hi, f) = ln_interval_enclosure(
+            &div_pow_round(&p, &q, ig, RoundingMode::Floor),
+            &div_pow_round(&p, &q, ig, RoundingMode::Ceiling),
+            -ig,
+            working_prec,
+        );
+        let pow = -i64::exact_from(f) - 1;
+        if negative {
+            (-hi, -lo, pow)
+        } else {
+            (lo, hi, pow)
+        }
+    })
+}
+
+// Computes $\operatorname{artanh} x$, rounding to `prec` bits.
+fn atanh_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { .. }) => (float_nan!(), Ordering::Equal),
+        Float(Zero { .. }) => (x.clone(), Ordering::Equal),
+        _ => match x.get_exponent().unwrap().cmp(&1) {
+            Ordering::Equal if *x == 1u32 => (float_infinity!(), Ordering::Equal),
+            Ordering::Equal if *x == -1i32 => (float_negative_infinity!(), Ordering::Equal),
+            Ordering::Greater | Ordering::Equal => (float_nan!(), Ordering::Equal),
+            Ordering::Less => atanh_finite(x, prec, rm),
+        },
+    }
+}
+
+impl Float {
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic tangent is
+    /// less than, equal to, or greater than the exact inverse hyperbolic tangent. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{artanh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(1.0,p,m)=\infty$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atanh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::atanh_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.098");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.1003352");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.1003354");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.1003354");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn atanh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        atanh_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic tangent is
+    /// less than, equal to, or greater than the exact inverse hyperbolic tangent. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{artanh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(1.0,p,m)=\infty$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atanh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::atanh_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.098");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.1003352");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.1003354");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.1003354");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn atanh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        atanh_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic tangent is less than, equal to,
+    /// or greater than the exact inverse hyperbolic tangent. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the inverse hyperbolic tangent is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{artanh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(1.0,p)=\infty$
+    /// - $f(-1.0,p)=-\infty$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec(5);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec(20);
+    /// assert_eq!(x.to_string(), "0.1003354");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn atanh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.atanh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded inverse hyperbolic tangent is less than, equal
+    /// to, or greater than the exact inverse hyperbolic tangent. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the inverse hyperbolic tangent is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{artanh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(1.0,p)=\infty$
+    /// - $f(-1.0,p)=-\infty$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.1003354");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn atanh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.atanh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic tangent is less than, equal to, or greater
+    /// than the exact inverse hyperbolic tangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{artanh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(1.0,m)=\infty$
+    /// - $f(-1.0,m)=-\infty$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atanh_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.10033534773107558");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.10033534773107559");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.10033534773107558");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atanh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.atanh_prec_round(prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic tangent is less than, equal to,
+    /// or greater than the exact inverse hyperbolic tangent. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{artanh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(1.0,m)=\infty$
+    /// - $f(-1.0,m)=-\infty$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atanh_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.10033534773107558");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.10033534773107559");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(0.1).atanh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.10033534773107558");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atanh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.atanh_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its inverse hyperbolic tangent, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded inverse hyperbolic tangent is less than, equal to, or greater
+    /// than the exact inverse hyperbolic tangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \operatorname{artanh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atanh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::atanh_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.atanh_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.098");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.atanh_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.1");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.atanh_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.1");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.atanh_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.1003352");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.atanh_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.1003354");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.atanh_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.1003354");
+    /// ```
+    #[inline]
+    pub fn atanh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (atanh, o) = atanh_prec_round_ref_helper(self, prec, rm);
+        *self = atanh;
+        o
+    }
+
+    /// Replaces a [`Float`] with its inverse hyperbolic tangent, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded inverse hyperbolic tangent is less than, equal to, or greater than the exact inverse
+    /// hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \operatorname{artanh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// See the [`Float::atanh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.atanh_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.1");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.atanh_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.1003354");
+    /// ```
+    #[inline]
+    pub fn atanh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.atanh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its inverse hyperbolic tangent, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded inverse
+    /// hyperbolic tangent is less than, equal to, or greater than the exact inverse hyperbolic
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \operatorname{artanh} x+\epsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon|
+    ///   < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::atanh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atanh_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.atanh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.10033534773107558");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.atanh_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.10033534773107559");
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(x.atanh_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.10033534773107558");
+    /// ```
+    #[inline]
+    pub fn atanh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.atanh_prec_round_assign(prec, rm)
+    }
+}
@@ -0,0 +1,626 @@
+use crate::arithmetic::exp::{integer_and_pow, round_nudged, ziv_round};
+use crate::arithmetic::sin::sin_cos_enclosure;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+// Computes $\cos x$, where $x$ is finite and nonzero, rounding to `prec` bits.
+fn cos_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    if exponent.saturating_mul(2) < -i64::exact_from(prec + 2) {
+        // $1 - x^2/2 < \cos x < 1$, and $x^2/2 < 2^{-p-3}$, so $\cos x$ rounds like
+        // $1 - \varepsilon$.
+        return round_nudged(Integer::ONE, 0, Ordering::Less, prec, rm);
+    }
+    let (m, pow) = integer_and_pow(x);
+    ziv_round(prec, rm, |working_prec| {
+        let enclosure = sin_cos_enclosure(&m, pow, working_prec);
+        let f = enclosure.f;
+        let (lo, hi) = enclosure.sin_cos().1;
+        (lo, hi, -i64::exact_from(f))
+    })
+}
+
+// Computes $\cos x$, rounding to `prec` bits.
+fn cos_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { .. }) => (float_nan!(), Ordering::Equal),
+        Float(Zero { .. }) => (Float::one_prec(prec), Ordering::Equal),
+        _ => cos_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the cosine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded cosine is less than, equal to, or greater than the
+    /// exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cos x+\epsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.97");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.999999");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        cos_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded cosine is less than, equal to, or greater than the
+    /// exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cos x+\epsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.97");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.999999");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        cos_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded cosine is less than, equal to, or greater than the exact
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cos x+\epsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec(5);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec(20);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec(self, prec: u64) -> (Float, Ordering) {
+        self.cos_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded cosine is less than, equal to, or greater than the exact
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cos x+\epsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_ref(5);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_ref(20);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.cos_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cos x+\epsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cos_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.9999999999999999");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.cos_prec_round(prec, rm)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cos x+\epsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cos_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.9999999999999999");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.cos_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its cosine, rounding the result to the specified precision and
+    /// with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \cos x+\epsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cos_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cos_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.97");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cos_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cos_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cos_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.999999");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cos_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    pub fn cos_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (cos, o) = cos_prec_round_ref_helper(self, prec, rm);
+        *self = cos;
+        o
+    }
+
+    /// Replaces a [`Float`] with its cosine, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded cosine is
+    /// less than, equal to, or greater than the exact cosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \cos x+\epsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// See the [`Float::cos_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    pub fn cos_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.cos_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its cosine, rounding the result with the specified rounding mode.
+    /// An [`Ordering`] is returned, indicating whether the rounded cosine is less than, equal to,
+    /// or greater than the exact cosine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \cos x+\epsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::cos_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "-0.9999999999999999");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    pub fn cos_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.cos_prec_round_assign(prec, rm)
+    }
+}
@@ -0,0 +1,654 @@
+use crate::arithmetic::exp::{exp_scale, integer_and_pow, overflow, round_nudged, ziv_round};
+use crate::arithmetic::sinh::exp_abs_enclosure;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, Float};
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+// Computes $\cosh x$, where $x$ is finite and nonzero, rounding to `prec` bits.
+fn cosh_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    if exponent >= 63 {
+        // $|x| \geq 2^{62}$, so $\cosh x$ is treated as overflowing.
+        assert_ne!(rm, RoundingMode::Exact, "Inexact cosh");
+        return overflow(true, prec, rm);
+    }
+    if exponent.saturating_mul(2) < -i64::exact_from(prec + 2) {
+        // $1 < \cosh x < 1 + x^2$, and $x^2 < 2^{-p-2}$, so $\cosh x$ rounds like
+        // $1 + \varepsilon$.
+        return round_nudged(Integer::ONE, 0, Ordering::Greater, prec, rm);
+    }
+    let (m, pow) = integer_and_pow(x);
+    let m = m.unsigned_abs();
+    ziv_round(prec, rm, |working_prec| {
+        let f = exp_scale(working_prec, exponent);
+        let e = exp_abs_enclosure(&m, pow, f);
+        // $\cosh x = (E + 1/E)/2$.
+        (e.e_lo + e.inverse_lo, e.e_hi + e.inverse_hi, e.pow - 1)
+    })
+}
+
+// Computes $\cosh x$, rounding to `prec` bits.
+fn cosh_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() => (float_nan!(), Ordering::Equal),
+        Float(Infinity { .. }) => (float_infinity!(), Ordering::Equal),
+        Float(Zero { .. }) => (Float::one_prec(prec), Ordering::Equal),
+        _ => cosh_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded hyperbolic cosine is less
+    /// than, equal to, or greater than the exact hyperbolic cosine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cosh x+\epsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\infty$
+    /// - $f(\pm0.0,p,m)=1.0$
+    /// - $f(x,p,m)=\infty$ if $|x|\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,p,m)$ is the largest finite [`Float`] with precision $p$ if $|x|\geq 2^{62}$ and $m$
+    ///   is `Floor` or `Down`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cosh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::cosh_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with `prec`
+    /// bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "12.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.59196");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        cosh_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded hyperbolic cosine is less
+    /// than, equal to, or greater than the exact hyperbolic cosine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cosh x+\epsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\infty$
+    /// - $f(\pm0.0,p,m)=1.0$
+    /// - $f(x,p,m)=\infty$ if $|x|\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,p,m)$ is the largest finite [`Float`] with precision $p$ if $|x|\geq 2^{62}$ and $m$
+    ///   is `Floor` or `Down`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cosh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cosh_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with `prec`
+    /// bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "12.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.59196");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        cosh_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic cosine is less than, equal to, or greater than the
+    /// exact hyperbolic cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the hyperbolic cosine is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cosh x+\epsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\cosh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\infty$
+    /// - $f(\pm0.0,p)=1.0$
+    /// - $f(x,p)=\infty$ if $|x|\geq 2^{62}$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cosh_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec(5);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec(20);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.cosh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded hyperbolic cosine is less than, equal to, or
+    /// greater than the exact hyperbolic cosine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the hyperbolic cosine is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cosh x+\epsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\cosh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\infty$
+    /// - $f(\pm0.0,p)=1.0$
+    /// - $f(x,p)=\infty$ if $|x|\geq 2^{62}$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cosh_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.cosh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded hyperbolic cosine is less than, equal to, or greater than the exact
+    /// hyperbolic cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cosh x+\epsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\infty$
+    /// - $f(\pm0.0,m)=1.0$
+    /// - $f(x,m)=\infty$ if $|x|\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,m)$ is the largest finite [`Float`] with precision $p$ if $|x|\geq 2^{62}$ and $m$
+    ///   is `Floor` or `Down`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cosh_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.59195327552152");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.cosh_prec_round(prec, rm)
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic cosine is less than, equal to, or greater than the
+    /// exact hyperbolic cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cosh x+\epsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\infty$
+    /// - $f(\pm0.0,m)=1.0$
+    /// - $f(x,m)=\infty$ if $|x|\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,m)$ is the largest finite [`Float`] with precision $p$ if $|x|\geq 2^{62}$ and $m$
+    ///   is `Floor` or `Down`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cosh_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.59195327552152");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.cosh_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its hyperbolic cosine, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded hyperbolic cosine is less than, equal to, or greater than the exact
+    /// hyperbolic cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \cosh x+\epsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$.
+    ///
+    /// See the [`Float::cosh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cosh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::cosh_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with `prec`
+    /// bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cosh_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cosh_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "12.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cosh_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cosh_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "11.59195");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cosh_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "11.59196");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cosh_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// ```
+    #[inline]
+    pub fn cosh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (cosh, o) = cosh_prec_round_ref_helper(self, prec, rm);
+        *self = cosh;
+        o
+    }
+
+    /// Replaces a [`Float`] with its hyperbolic cosine, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// hyperbolic cosine is less than, equal to, or greater than the exact hyperbolic cosine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \cosh x+\epsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\cosh
+    ///   x|\rfloor-p}$.
+    ///
+    /// See the [`Float::cosh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cosh_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// ```
+    #[inline]
+    pub fn cosh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.cosh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its hyperbolic cosine, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded hyperbolic cosine
+    /// is less than, equal to, or greater than the exact hyperbolic cosine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \cosh x+\epsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::cosh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cosh_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.cosh_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "11.59195327552152");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    /// ```
+    #[inline]
+    pub fn cosh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.cosh_prec_round_assign(prec, rm)
+    }
+}
@@ -0,0 +1,7093 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_either_zero, float_nan, significand_bits, Float};
+use malachite_base::num::arithmetic::traits::DivMod;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::ops::{Div, DivAssign};
+
+// Divides $x2^{p_x}$ by $y2^{p_y}$, where $x$ and $y$ are positive, and rounds the quotient to
+// `prec` bits, giving it a positive sign if `sign` is `true` and a negative sign otherwise.
+//
+// $x$ is shifted left so that the truncated quotient has at least `prec + 2` significant bits. If
+// the division is inexact, a 1 bit is appended below the truncated quotient. This sticky bit lies
+// strictly below the rounding position, so rounding the result gives the same value and `Ordering`
+// as rounding the exact quotient would.
+pub(crate) fn div_naturals_times_powers_of_2_prec_round(
+    sign: bool,
+    x: Natural,
+    x_pow: i64,
+    y: Natural,
+    y_pow: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let shift = (prec + 2 + y.significant_bits()).saturating_sub(x.significant_bits());
+    let (mut quotient, remainder) = (x << shift).div_mod(y);
+    let mut pow = x_pow - y_pow - i64::exact_from(shift);
+    if remainder != 0u32 {
+        quotient <<= 1;
+        quotient += Natural::ONE;
+        pow -= 1;
+    }
+    Float::from_integer_times_power_of_2_prec_round(
+        Integer::from_sign_and_abs(sign, quotient),
+        pow,
+        prec,
+        rm,
+    )
+}
+
+// Divides `x` by `y`, rounding to `prec` bits.
+pub(crate) fn div_prec_round_ref_ref_helper(
+    x: &Float,
+    y: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (float_nan!(), _)
+        | (_, float_nan!())
+        | (Float(Infinity { .. }), Float(Infinity { .. }))
+        | (float_either_zero!(), float_either_zero!()) => (float_nan!(), Ordering::Equal),
+        (
+            Float(Infinity { sign: x_sign }),
+            Float(Finite { sign: y_sign, .. } | Zero { sign: y_sign }),
+        )
+        | (Float(Finite { sign: x_sign, .. }), Float(Zero { sign: y_sign })) => (
+            Float(Infinity {
+                sign: x_sign == y_sign,
+            }),
+            Ordering::Equal,
+        ),
+        (
+            Float(Finite { sign: x_sign, .. } | Zero { sign: x_sign }),
+            Float(Infinity { sign: y_sign }),
+        )
+        | (Float(Zero { sign: x_sign }), Float(Finite { sign: y_sign, .. })) => (
+            Float(Zero {
+                sign: x_sign == y_sign,
+            }),
+            Ordering::Equal,
+        ),
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => div_naturals_times_powers_of_2_prec_round(
+            x_sign == y_sign,
+            x_significand.clone(),
+            x_exponent - i64::exact_from(significand_bits(x_significand)),
+            y_significand.clone(),
+            y_exponent - i64::exact_from(significand_bits(y_significand)),
+            prec,
+            rm,
+        ),
+    }
+}
+
+// Like `div_prec_round_ref_ref_helper`, but takes `x` by value, so that its significand can be
+// reused for the quotient.
+pub(crate) fn div_prec_round_val_ref_helper(
+    x: Float,
+    y: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            assert_ne!(prec, 0);
+            let x_pow = x_exponent - i64::exact_from(significand_bits(&x_significand));
+            div_naturals_times_powers_of_2_prec_round(
+                x_sign == *y_sign,
+                x_significand,
+                x_pow,
+                y_significand.clone(),
+                y_exponent - i64::exact_from(significand_bits(y_significand)),
+                prec,
+                rm,
+            )
+        }
+        (x, y) => div_prec_round_ref_ref_helper(&x, y, prec, rm),
+    }
+}
+
+// Like `div_prec_round_ref_ref_helper`, but takes `y` by value, so that its significand can be
+// reused as the divisor.
+pub(crate) fn div_prec_round_ref_val_helper(
+    x: &Float,
+    y: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            assert_ne!(prec, 0);
+            let y_pow = y_exponent - i64::exact_from(significand_bits(&y_significand));
+            div_naturals_times_powers_of_2_prec_round(
+                *x_sign == y_sign,
+                x_significand.clone(),
+                x_exponent - i64::exact_from(significand_bits(x_significand)),
+                y_significand,
+                y_pow,
+                prec,
+                rm,
+            )
+        }
+        (x, y) => div_prec_round_ref_ref_helper(x, &y, prec, rm),
+    }
+}
+
+// Like `div_prec_round_ref_ref_helper`, but takes both `x` and `y` by value, so that both
+// significands can be reused.
+pub(crate) fn div_prec_round_val_val_helper(
+    x: Float,
+    y: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            assert_ne!(prec, 0);
+            let x_pow = x_exponent - i64::exact_from(significand_bits(&x_significand));
+            let y_pow = y_exponent - i64::exact_from(significand_bits(&y_significand));
+            div_naturals_times_powers_of_2_prec_round(
+                x_sign == y_sign,
+                x_significand,
+                x_pow,
+                y_significand,
+                y_pow,
+                prec,
+                rm,
+            )
+        }
+        (x, y) => div_prec_round_ref_ref_helper(&x, &y, prec, rm),
+    }
+}
+
+// Divides `x` by `y`, where `y` is a `Rational`, rounding to `prec` bits.
+pub(crate) fn div_rational_prec_round_ref_ref_helper(
+    x: &Float,
+    y: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() => (float_nan!(), Ordering::Equal),
+        Float(Infinity { sign }) => (
+            Float(Infinity {
+                sign: *sign == (*y >= 0u32),
+            }),
+            Ordering::Equal,
+        ),
+        Float(Zero { sign }) => {
+            if *y == 0u32 {
+                (float_nan!(), Ordering::Equal)
+            } else {
+                (
+                    Float(Zero {
+                        sign: *sign == (*y > 0u32),
+                    }),
+                    Ordering::Equal,
+                )
+            }
+        }
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) => {
+            if *y == 0u32 {
+                return (Float(Infinity { sign: *sign }), Ordering::Equal);
+            }
+            let (numerator, denominator) = y.numerator_and_denominator_ref();
+            div_naturals_times_powers_of_2_prec_round(
+                *sign == (*y > 0u32),
+                significand * denominator,
+                exponent - i64::exact_from(significand_bits(significand)),
+                numerator.clone(),
+                0,
+                prec,
+                rm,
+            )
+        }
+    }
+}
+
+// Like `div_rational_prec_round_ref_ref_helper`, but takes `x` by value, so that its significand
+// can be reused.
+pub(crate) fn div_rational_prec_round_val_ref_helper(
+    x: Float,
+    y: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match x {
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) if *y != 0u32 => {
+            assert_ne!(prec, 0);
+            let (numerator, denominator) = y.numerator_and_denominator_ref();
+            let pow = exponent - i64::exact_from(significand_bits(&significand));
+            div_naturals_times_powers_of_2_prec_round(
+                sign == (*y > 0u32),
+                significand * denominator,
+                pow,
+                numerator.clone(),
+                0,
+                prec,
+                rm,
+            )
+        }
+        x => div_rational_prec_round_ref_ref_helper(&x, y, prec, rm),
+    }
+}
+
+// Like `div_rational_prec_round_ref_ref_helper`, but takes `y` by value, so that its numerator and
+// denominator can be reused.
+pub(crate) fn div_rational_prec_round_ref_val_helper(
+    x: &Float,
+    y: Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match x {
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) if y != 0u32 => {
+            assert_ne!(prec, 0);
+            let sign = *sign == (y > 0u32);
+            let (numerator, denominator) = y.into_numerator_and_denominator();
+            div_naturals_times_powers_of_2_prec_round(
+                sign,
+                significand * denominator,
+                exponent - i64::exact_from(significand_bits(significand)),
+                numerator,
+                0,
+                prec,
+                rm,
+            )
+        }
+        _ => div_rational_prec_round_ref_ref_helper(x, &y, prec, rm),
+    }
+}
+
+// Like `div_rational_prec_round_ref_ref_helper`, but takes both `x` and `y` by value.
+pub(crate) fn div_rational_prec_round_val_val_helper(
+    x: Float,
+    y: Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match x {
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) if y != 0u32 => {
+            assert_ne!(prec, 0);
+            let sign = sign == (y > 0u32);
+            let pow = exponent - i64::exact_from(significand_bits(&significand));
+            let (numerator, denominator) = y.into_numerator_and_denominator();
+            div_naturals_times_powers_of_2_prec_round(
+                sign,
+                significand * denominator,
+                pow,
+                numerator,
+                0,
+                prec,
+                rm,
+            )
+        }
+        x => div_rational_prec_round_ref_val_helper(&x, y, prec, rm),
+    }
+}
+
+// Divides `x` by `y`, where `x` is a `Rational`, rounding to `prec` bits.
+pub(crate) fn rational_div_float_prec_round_ref_ref_helper(
+    x: &Rational,
+    y: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match y {
+        float_nan!() => (float_nan!(), Ordering::Equal),
+        Float(Infinity { sign }) => (
+            Float(Zero {
+                sign: *sign == (*x >= 0u32),
+            }),
+            Ordering::Equal,
+        ),
+        Float(Zero { sign }) => {
+            if *x == 0u32 {
+                (float_nan!(), Ordering::Equal)
+            } else {
+                (
+                    Float(Infinity {
+                        sign: *sign == (*x > 0u32),
+                    }),
+                    Ordering::Equal,
+                )
+            }
+        }
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) => {
+            if *x == 0u32 {
+                return (Float(Zero { sign: *sign }), Ordering::Equal);
+            }
+            let (numerator, denominator) = x.numerator_and_denominator_ref();
+            div_naturals_times_powers_of_2_prec_round(
+                *sign == (*x > 0u32),
+                numerator.clone(),
+                0,
+                denominator * significand,
+                exponent - i64::exact_from(significand_bits(significand)),
+                prec,
+                rm,
+            )
+        }
+    }
+}
+
+// Like `rational_div_float_prec_round_ref_ref_helper`, but takes `x` by value, so that its
+// numerator and denominator can be reused.
+pub(crate) fn rational_div_float_prec_round_val_ref_helper(
+    x: Rational,
+    y: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match y {
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) if x != 0u32 => {
+            assert_ne!(prec, 0);
+            let sign = *sign == (x > 0u32);
+            let (numerator, denominator) = x.into_numerator_and_denominator();
+            div_naturals_times_powers_of_2_prec_round(
+                sign,
+                numerator,
+                0,
+                denominator * significand,
+                exponent - i64::exact_from(significand_bits(significand)),
+                prec,
+                rm,
+            )
+        }
+        _ => rational_div_float_prec_round_ref_ref_helper(&x, y, prec, rm),
+    }
+}
+
+// Like `rational_div_float_prec_round_ref_ref_helper`, but takes `y` by value, so that its
+// significand can be reused.
+pub(crate) fn rational_div_float_prec_round_ref_val_helper(
+    x: &Rational,
+    y: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match y {
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) if *x != 0u32 => {
+            assert_ne!(prec, 0);
+            let (numerator, denominator) = x.numerator_and_denominator_ref();
+            let pow = exponent - i64::exact_from(significand_bits(&significand));
+            div_naturals_times_powers_of_2_prec_round(
+                sign == (*x > 0u32),
+                numerator.clone(),
+                0,
+                denominator * significand,
+                pow,
+                prec,
+                rm,
+            )
+        }
+        y => rational_div_float_prec_round_ref_ref_helper(x, &y, prec, rm),
+    }
+}
+
+// Like `rational_div_float_prec_round_ref_ref_helper`, but takes both `x` and `y` by value.
+pub(crate) fn rational_div_float_prec_round_val_val_helper(
+    x: Rational,
+    y: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match y {
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) if x != 0u32 => {
+            assert_ne!(prec, 0);
+            let sign = sign == (x > 0u32);
+            let pow = exponent - i64::exact_from(significand_bits(&significand));
+            let (numerator, denominator) = x.into_numerator_and_denominator();
+            div_naturals_times_powers_of_2_prec_round(
+                sign,
+                numerator,
+                0,
+                denominator * significand,
+                pow,
+                prec,
+                rm,
+            )
+        }
+        y => rational_div_float_prec_round_val_ref_helper(x, &y, prec, rm),
+    }
+}
+
+impl Float {
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded quotient is less than, equal to, or greater
+    /// than the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\pm\infty,\pm\infty,p,m)=f(\pm0.0,\pm0.0,p,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,p,m)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,p,m)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,p,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,p,m)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::div_round`] instead. If both of these things are true, consider using `/`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.155725");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        div_prec_round_val_val_helper(self, other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The first [`Float`] is taken by value and the second by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\pm\infty,\pm\infty,p,m)=f(\pm0.0,\pm0.0,p,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,p,m)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,p,m)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,p,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,p,m)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::div_round_val_ref`] instead. If both of these things are true,
+    /// consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.155725");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        div_prec_round_val_ref_helper(self, other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The first [`Float`] is taken by reference and the second
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\pm\infty,\pm\infty,p,m)=f(\pm0.0,\pm0.0,p,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,p,m)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,p,m)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,p,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,p,m)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::div_round_ref_val`] instead. If both of these things are true,
+    /// consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_val(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_val(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_val(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_val(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.155725");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_val(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_val(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        div_prec_round_ref_val_helper(self, other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded quotient is less than, equal to, or greater
+    /// than the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\pm\infty,\pm\infty,p,m)=f(\pm0.0,\pm0.0,p,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,p,m)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,p,m)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,p,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,p,m)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::div_round_ref_ref`] instead. If both of these things are true,
+    /// consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.155725");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        div_prec_round_ref_ref_helper(self, other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the nearest value of the
+    /// specified precision. Both [`Float`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded quotient is less than, equal to, or greater than the exact
+    /// quotient. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\pm\infty,\pm\infty,p)=f(\pm0.0,\pm0.0,p) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,p)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,p)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,p)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,p)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,p)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round`] instead. If you know that your target precision is the maximum of
+    /// the precisions of the two inputs, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_prec(Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec(Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.div_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The first [`Float`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded quotient is less than, equal
+    /// to, or greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\pm\infty,\pm\infty,p)=f(\pm0.0,\pm0.0,p) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,p)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,p)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,p)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,p)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,p)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round_val_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.div_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The first [`Float`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded quotient is less than, equal
+    /// to, or greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\pm\infty,\pm\infty,p)=f(\pm0.0,\pm0.0,p) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,p)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,p)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,p)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,p)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,p)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round_ref_val`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.div_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result to the nearest value of the
+    /// specified precision. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\pm\infty,\pm\infty,p)=f(\pm0.0,\pm0.0,p) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,p)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,p)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,p)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,p)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,p)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,p)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round_ref_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.div_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result with the specified rounding
+    /// mode. Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded quotient is less than, equal to, or greater than the exact quotient.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\pm\infty,\pm\infty,m)=f(\pm0.0,\pm0.0,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,m)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,m)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,m)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::div_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.155727349790922");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result with the specified rounding
+    /// mode. The first [`Float`] is taken by value and the second by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded quotient is less than, equal to, or greater
+    /// than the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\pm\infty,\pm\infty,m)=f(\pm0.0,\pm0.0,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,m)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,m)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,m)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::div_prec_round_val_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_round_val_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_round_val_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.155727349790922");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_round_val_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result with the specified rounding
+    /// mode. The first [`Float`] is taken by reference and the second by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded quotient is less than, equal to, or greater
+    /// than the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\pm\infty,\pm\infty,m)=f(\pm0.0,\pm0.0,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,m)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,m)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,m)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::div_prec_round_ref_val`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_round_ref_val(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_round_ref_val(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.155727349790922");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_round_ref_val(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by another [`Float`], rounding the result with the specified rounding
+    /// mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded quotient is less than, equal to, or greater than the exact quotient.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\pm\infty,\pm\infty,m)=f(\pm0.0,\pm0.0,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x,m)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x,m)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty,m)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::div_prec_round_ref_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.155727349790922");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// value. An [`Ordering`] is returned, indicating whether the rounded quotient is less than,
+    /// equal to, or greater than the exact quotient. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// See the [`Float::div_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_assign`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::div_round_assign`] instead. If both of these things are true,
+    /// consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.19");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.155725");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// ```
+    #[inline]
+    pub fn div_prec_round_assign(&mut self, other: Float, prec: u64, rm: RoundingMode) -> Ordering {
+        let (quotient, o) = div_prec_round_ref_val_helper(self, other, prec, rm);
+        *self = quotient;
+        o
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// reference. An [`Ordering`] is returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// See the [`Float::div_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::div_round_assign_ref`] instead. If both of these things are
+    /// true, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.19");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.155725");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// ```
+    #[inline]
+    pub fn div_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (quotient, o) = div_prec_round_ref_ref_helper(self, other, prec, rm);
+        *self = quotient;
+        o
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] on the right-hand side is taken by value. An [`Ordering`]
+    /// is returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// See the [`Float::div_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round_assign`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.div_prec_assign(Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.div_prec_assign(Float::from(E), 20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// ```
+    #[inline]
+    pub fn div_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.div_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded quotient is less than, equal to, or
+    /// greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// See the [`Float::div_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round_assign_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.div_prec_assign_ref(&Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_assign_ref(&Float::from(E), 20),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// ```
+    #[inline]
+    pub fn div_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.div_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is returned,
+    /// indicating whether the rounded quotient is less than, equal to, or greater than the exact
+    /// quotient. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// See the [`Float::div_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::div_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/=`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign(Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign(Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727349790922");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign(Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// ```
+    #[inline]
+    pub fn div_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_assign(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. The [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// See the [`Float::div_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::div_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign_ref(&Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign_ref(&Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727349790922");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign_ref(&Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// ```
+    #[inline]
+    pub fn div_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_assign_ref(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] and the [`Rational`] are both taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(\pm0.0,0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,p,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,p,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
+    /// - $f(x,0,p,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,p,m)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_rational_prec`] instead.
+    /// If you know that your target precision is the precision of the [`Float`] input, consider
+    /// using [`Float::div_rational_round`] instead. If both of these things are true, consider
+    /// using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42479");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_rational_prec_round(
+        self,
+        other: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        div_rational_prec_round_val_val_helper(self, other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value and the [`Rational`] by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(\pm0.0,0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,p,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,p,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
+    /// - $f(x,0,p,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,p,m)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_rational_prec_val_ref`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::div_rational_round_val_ref`] instead. If both of these things are
+    /// true, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42479");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_rational_prec_round_val_ref(
+        self,
+        other: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        div_rational_prec_round_val_ref_helper(self, other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference and the [`Rational`]
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(\pm0.0,0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,p,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,p,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
+    /// - $f(x,0,p,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,p,m)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_rational_prec_ref_val`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::div_rational_round_ref_val`] instead. If both of these things are
+    /// true, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42479");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_rational_prec_round_ref_val(
+        &self,
+        other: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        div_rational_prec_round_ref_val_helper(self, other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] and the [`Rational`] are both taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(\pm0.0,0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,p,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,p,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
+    /// - $f(x,0,p,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,p,m)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_rational_prec_ref_ref`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::div_rational_round_ref_ref`] instead. If both of these things are
+    /// true, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42479");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_rational_prec_round_ref_ref(
+        &self,
+        other: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        div_rational_prec_round_ref_ref_helper(self, other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] and the [`Rational`] are both taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded quotient is less than, equal
+    /// to, or greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(\pm0.0,0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,p)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,p)=\infty$ if $x<0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0$
+    /// - $f(x,0,p)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,p)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_rational_prec_round`] instead. If you know that your target precision is the
+    /// precision of the [`Float`] input, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_rational_prec(self, other: Rational, prec: u64) -> (Float, Ordering) {
+        self.div_rational_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value and the [`Rational`] by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded quotient is less than, equal
+    /// to, or greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(\pm0.0,0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,p)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,p)=\infty$ if $x<0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0$
+    /// - $f(x,0,p)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,p)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_rational_prec_round_val_ref`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_val_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_val_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_rational_prec_val_ref(self, other: &Rational, prec: u64) -> (Float, Ordering) {
+        self.div_rational_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference and the [`Rational`] by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded quotient is less than, equal
+    /// to, or greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(\pm0.0,0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,p)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,p)=\infty$ if $x<0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0$
+    /// - $f(x,0,p)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,p)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_rational_prec_round_ref_val`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_ref_val(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_ref_val(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_rational_prec_ref_val(&self, other: Rational, prec: u64) -> (Float, Ordering) {
+        self.div_rational_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] and the [`Rational`] are both taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded quotient is less than, equal
+    /// to, or greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(\pm0.0,0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,p)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,p)=\infty$ if $x<0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0$
+    /// - $f(x,0,p)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,p)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_rational_prec_round_ref_ref`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_ref_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).div_rational_prec_ref_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(s.to_string(), "9.42477");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_rational_prec_ref_ref(&self, other: &Rational, prec: u64) -> (Float, Ordering) {
+        self.div_rational_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result with the specified rounding mode.
+    /// The [`Float`] and the [`Rational`] are both taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(\pm0.0,0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,m)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0$
+    /// - $f(x,0,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,m)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::div_rational_prec_round`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).div_rational_round(Rational::from_unsigneds(1u8, 3), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).div_rational_round(Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).div_rational_round(Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn div_rational_round(self, other: Rational, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value and the [`Rational`] by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(\pm0.0,0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,m)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0$
+    /// - $f(x,0,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,m)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::div_rational_prec_round_val_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .div_rational_round_val_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .div_rational_round_val_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .div_rational_round_val_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn div_rational_round_val_ref(
+        self,
+        other: &Rational,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference and the [`Rational`] by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(\pm0.0,0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,m)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0$
+    /// - $f(x,0,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,m)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::div_rational_prec_round_ref_val`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .div_rational_round_ref_val(Rational::from_unsigneds(1u8, 3), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .div_rational_round_ref_val(Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .div_rational_round_ref_val(Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn div_rational_round_ref_val(
+        &self,
+        other: Rational,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`], rounding the result with the specified rounding mode.
+    /// The [`Float`] and the [`Rational`] are both taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(\pm0.0,0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x\geq0$
+    /// - $f(\infty,x,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,m)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0$
+    /// - $f(x,0,m)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0,m)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::div_rational_prec_round_ref_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .div_rational_round_ref_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .div_rational_round_ref_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .div_rational_round_ref_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "9.424777960769379");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn div_rational_round_ref_ref(
+        &self,
+        other: &Rational,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Rational`] on the right-hand side is
+    /// taken by value. An [`Ordering`] is returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// See the [`Float::div_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_rational_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::div_rational_round_assign`] instead. If both of these things are
+    /// true, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign(Rational::from_unsigneds(1u8, 3), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "9.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign(Rational::from_unsigneds(1u8, 3), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "9.42477");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.42479");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "9.42477");
+    /// ```
+    #[inline]
+    pub fn div_rational_prec_round_assign(
+        &mut self,
+        other: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (quotient, o) = div_rational_prec_round_ref_val_helper(self, other, prec, rm);
+        *self = quotient;
+        o
+    }
+
+    /// Divides a [`Float`] by a [`Rational`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Rational`] on the right-hand side is
+    /// taken by reference. An [`Ordering`] is returned, indicating whether the rounded quotient is
+    /// less than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// See the [`Float::div_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::div_rational_prec_assign_ref`] instead. If you know that your target precision is
+    /// the precision of the [`Float`] input, consider using
+    /// [`Float::div_rational_round_assign_ref`] instead. If both of these things are true, consider
+    /// using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "9.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "9.42477");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.42479");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "9.42477");
+    /// ```
+    #[inline]
+    pub fn div_rational_prec_round_assign_ref(
+        &mut self,
+        other: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (quotient, o) = div_rational_prec_round_ref_ref_helper(self, other, prec, rm);
+        *self = quotient;
+        o
+    }
+
+    /// Divides a [`Float`] by a [`Rational`] in place, rounding the result to the nearest value of
+    /// the specified precision. The [`Rational`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded quotient is less than, equal to, or
+    /// greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// See the [`Float::div_rational_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_rational_prec_round_assign`] instead. If you know that your target precision is
+    /// the precision of the [`Float`] input, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_assign(Rational::from_unsigneds(1u8, 3), 5),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_assign(Rational::from_unsigneds(1u8, 3), 20),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "9.42477");
+    /// ```
+    #[inline]
+    pub fn div_rational_prec_assign(&mut self, other: Rational, prec: u64) -> Ordering {
+        self.div_rational_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`] in place, rounding the result to the nearest value of
+    /// the specified precision. The [`Rational`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded quotient is less than, equal to, or
+    /// greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// See the [`Float::div_rational_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_rational_prec_round_assign_ref`] instead. If you know that your target
+    /// precision is the precision of the [`Float`] input, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_assign_ref(&Rational::from_unsigneds(1u8, 3), 5),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_prec_assign_ref(&Rational::from_unsigneds(1u8, 3), 20),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "9.42477");
+    /// ```
+    #[inline]
+    pub fn div_rational_prec_assign_ref(&mut self, other: &Rational, prec: u64) -> Ordering {
+        self.div_rational_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`] in place, rounding the result with the specified
+    /// rounding mode. The [`Rational`] on the right-hand side is taken by value. An [`Ordering`] is
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] on the left-hand side.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// See the [`Float::div_rational_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::div_rational_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Floor),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "9.424777960769379");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "9.424777960769379");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "9.424777960769379");
+    /// ```
+    #[inline]
+    pub fn div_rational_round_assign(&mut self, other: Rational, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round_assign(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Rational`] in place, rounding the result with the specified
+    /// rounding mode. The [`Rational`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded quotient is less than, equal to, or
+    /// greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] on the left-hand side.
+    ///
+    /// $$
+    /// x \gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// See the [`Float::div_rational_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::div_rational_prec_round_assign_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_round_assign_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Floor),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "9.424777960769379");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_round_assign_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "9.424777960769379");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_rational_round_assign_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "9.424777960769379");
+    /// ```
+    #[inline]
+    pub fn div_rational_round_assign_ref(
+        &mut self,
+        other: &Rational,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round_assign_ref(other, prec, rm)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Rational`] and the [`Float`] are both taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},p,m)=f(0,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(x,\infty,p,m)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,p,m)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,p,m)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,p,m)=0.0$ if $x<0$
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0$
+    /// - $f(0,x,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::rational_div_float_prec`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::rational_div_float_round`] instead. If both of these things are
+    /// true, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.109");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_prec_round(
+        x: Rational,
+        y: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        rational_div_float_prec_round_val_val_helper(x, y, prec, rm)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Rational`] is taken by value and the [`Float`] by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},p,m)=f(0,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(x,\infty,p,m)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,p,m)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,p,m)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,p,m)=0.0$ if $x<0$
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0$
+    /// - $f(0,x,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::rational_div_float_prec_val_ref`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using
+    /// [`Float::rational_div_float_round_val_ref`] instead. If both of these things are true,
+    /// consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.109");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_prec_round_val_ref(
+        x: Rational,
+        y: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        rational_div_float_prec_round_val_ref_helper(x, y, prec, rm)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Rational`] is taken by reference and the [`Float`]
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},p,m)=f(0,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(x,\infty,p,m)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,p,m)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,p,m)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,p,m)=0.0$ if $x<0$
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0$
+    /// - $f(0,x,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::rational_div_float_prec_ref_val`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using
+    /// [`Float::rational_div_float_round_ref_val`] instead. If both of these things are true,
+    /// consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.109");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_prec_round_ref_val(
+        x: &Rational,
+        y: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        rational_div_float_prec_round_ref_val_helper(x, y, prec, rm)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Rational`] and the [`Float`] are both taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},p,m)=f(0,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(x,\infty,p,m)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,p,m)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,p,m)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,p,m)=0.0$ if $x<0$
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0$
+    /// - $f(0,x,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::rational_div_float_prec_ref_ref`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using
+    /// [`Float::rational_div_float_round_ref_ref`] instead. If both of these things are true,
+    /// consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.109");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_prec_round_ref_ref(
+        x: &Rational,
+        y: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        rational_div_float_prec_round_ref_ref_helper(x, y, prec, rm)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Rational`] and the [`Float`] are both taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded quotient is less than, equal
+    /// to, or greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},p)=f(0,\pm0.0,p)=\text{NaN}$
+    /// - $f(x,\infty,p)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,p)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,p)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,p)=0.0$ if $x<0$
+    /// - $f(x,0.0,p)=\infty$ if $x>0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0$
+    /// - $f(0,x,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::rational_div_float_prec_round`] instead. If you know that your target precision is
+    /// the precision of the [`Float`] input, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) =
+    ///     Float::rational_div_float_prec(Rational::from_unsigneds(1u8, 3), Float::from(PI), 5);
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) =
+    ///     Float::rational_div_float_prec(Rational::from_unsigneds(1u8, 3), Float::from(PI), 20);
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_prec(x: Rational, y: Float, prec: u64) -> (Float, Ordering) {
+        Float::rational_div_float_prec_round(x, y, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Rational`] is taken by value and the [`Float`] by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded quotient is less than, equal
+    /// to, or greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},p)=f(0,\pm0.0,p)=\text{NaN}$
+    /// - $f(x,\infty,p)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,p)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,p)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,p)=0.0$ if $x<0$
+    /// - $f(x,0.0,p)=\infty$ if $x>0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0$
+    /// - $f(0,x,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::rational_div_float_prec_round_val_ref`] instead. If you know that your target
+    /// precision is the precision of the [`Float`] input, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     5,
+    /// );
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     20,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_prec_val_ref(x: Rational, y: &Float, prec: u64) -> (Float, Ordering) {
+        Float::rational_div_float_prec_round_val_ref(x, y, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Rational`] is taken by reference and the [`Float`] by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded quotient is less than, equal
+    /// to, or greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},p)=f(0,\pm0.0,p)=\text{NaN}$
+    /// - $f(x,\infty,p)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,p)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,p)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,p)=0.0$ if $x<0$
+    /// - $f(x,0.0,p)=\infty$ if $x>0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0$
+    /// - $f(0,x,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::rational_div_float_prec_round_ref_val`] instead. If you know that your target
+    /// precision is the precision of the [`Float`] input, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     5,
+    /// );
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     20,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_prec_ref_val(x: &Rational, y: Float, prec: u64) -> (Float, Ordering) {
+        Float::rational_div_float_prec_round_ref_val(x, y, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Rational`] and the [`Float`] are both taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded quotient is less than, equal
+    /// to, or greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},p)=f(0,\pm0.0,p)=\text{NaN}$
+    /// - $f(x,\infty,p)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,p)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,p)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,p)=0.0$ if $x<0$
+    /// - $f(x,0.0,p)=\infty$ if $x>0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0$
+    /// - $f(0,x,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::rational_div_float_prec_round_ref_ref`] instead. If you know that your target
+    /// precision is the precision of the [`Float`] input, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     5,
+    /// );
+    /// assert_eq!(s.to_string(), "0.105");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_prec_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     20,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061033");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_prec_ref_ref(
+        x: &Rational,
+        y: &Float,
+        prec: u64,
+    ) -> (Float, Ordering) {
+        Float::rational_div_float_prec_round_ref_ref(x, y, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Rational`] and the [`Float`] are both taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the precision of $y$, or 1 if $y$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},m)=f(0,\pm0.0,m)=\text{NaN}$
+    /// - $f(x,\infty,m)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,m)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,m)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,m)=0.0$ if $x<0$
+    /// - $f(x,0.0,m)=\infty$ if $x>0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0$
+    /// - $f(0,x,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::rational_div_float_prec_round`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::rational_div_float_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.10610329539459688");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032953945969");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032953945969");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_round(x: Rational, y: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = y.significant_bits();
+        Float::rational_div_float_prec_round(x, y, prec, rm)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Rational`] is taken by value and the [`Float`] by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the precision of $y$, or 1 if $y$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},m)=f(0,\pm0.0,m)=\text{NaN}$
+    /// - $f(x,\infty,m)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,m)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,m)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,m)=0.0$ if $x<0$
+    /// - $f(x,0.0,m)=\infty$ if $x>0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0$
+    /// - $f(0,x,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::rational_div_float_prec_round_val_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::rational_div_float_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.10610329539459688");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032953945969");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032953945969");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_round_val_ref(
+        x: Rational,
+        y: &Float,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = y.significant_bits();
+        Float::rational_div_float_prec_round_val_ref(x, y, prec, rm)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Rational`] is taken by reference and the [`Float`] by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the precision of $y$, or 1 if $y$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},m)=f(0,\pm0.0,m)=\text{NaN}$
+    /// - $f(x,\infty,m)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,m)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,m)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,m)=0.0$ if $x<0$
+    /// - $f(x,0.0,m)=\infty$ if $x>0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0$
+    /// - $f(0,x,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::rational_div_float_prec_round_ref_val`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::rational_div_float_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.10610329539459688");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032953945969");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Float::from(PI),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032953945969");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_round_ref_val(
+        x: &Rational,
+        y: Float,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = y.significant_bits();
+        Float::rational_div_float_prec_round_ref_val(x, y, prec, rm)
+    }
+
+    /// Divides a [`Rational`] by a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Rational`] and the [`Float`] are both taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |x/y|\rfloor-p}$.
+    /// - $p$ is the precision of $y$, or 1 if $y$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN},m)=f(0,\pm0.0,m)=\text{NaN}$
+    /// - $f(x,\infty,m)=0.0$ if $x\geq0$
+    /// - $f(x,\infty,m)=-0.0$ if $x<0$
+    /// - $f(x,-\infty,m)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty,m)=0.0$ if $x<0$
+    /// - $f(x,0.0,m)=\infty$ if $x>0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0$
+    /// - $f(0,x,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::rational_div_float_prec_round_ref_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact division.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::rational_div_float_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "0.10610329539459688");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::rational_div_float_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032953945969");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::rational_div_float_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Float::from(PI),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "0.1061032953945969");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn rational_div_float_round_ref_ref(
+        x: &Rational,
+        y: &Float,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = y.significant_bits();
+        Float::rational_div_float_prec_round_ref_ref(x, y, prec, rm)
+    }
+}
+
+impl Div<Float> for Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by another [`Float`], taking both by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0) = \text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0)=\infty$ if $x>0.0$
+    /// - $f(x,0.0)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=0.0$ if $x>0.0$
+    /// - $f(0.0,x)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::div_round`]
+    /// instead. If you want to specify the output precision, consider using [`Float::div_prec`]. If
+    /// you want both of these things, consider using [`Float::div_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) / Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) / Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::from(1.5) / Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(Float::from(-1.5) / Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert_eq!(Float::from(-1.5) / Float::NEGATIVE_ZERO, Float::INFINITY);
+    /// assert!((Float::ZERO / Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) / Float::from(0.5), 3.0);
+    /// assert_eq!(Float::from(1.5) / Float::from(-0.5), -3.0);
+    /// assert_eq!(Float::from(-1.5) / Float::from(0.5), -3.0);
+    /// assert_eq!(Float::from(-1.5) / Float::from(-0.5), 3.0);
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round(other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Div<&'a Float> for Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by another [`Float`], taking the first by value and the second by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0) = \text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0)=\infty$ if $x>0.0$
+    /// - $f(x,0.0)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=0.0$ if $x>0.0$
+    /// - $f(0.0,x)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::div_round`]
+    /// instead. If you want to specify the output precision, consider using [`Float::div_prec`]. If
+    /// you want both of these things, consider using [`Float::div_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) / &Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) / &Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::from(1.5) / &Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(Float::from(-1.5) / &Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert_eq!(Float::from(-1.5) / &Float::NEGATIVE_ZERO, Float::INFINITY);
+    /// assert!((Float::ZERO / &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) / &Float::from(0.5), 3.0);
+    /// assert_eq!(Float::from(1.5) / &Float::from(-0.5), -3.0);
+    /// assert_eq!(Float::from(-1.5) / &Float::from(0.5), -3.0);
+    /// assert_eq!(Float::from(-1.5) / &Float::from(-0.5), 3.0);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<Float> for &'a Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by another [`Float`], taking the first by reference and the second by
+    /// value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0) = \text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0)=\infty$ if $x>0.0$
+    /// - $f(x,0.0)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=0.0$ if $x>0.0$
+    /// - $f(0.0,x)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::div_round`]
+    /// instead. If you want to specify the output precision, consider using [`Float::div_prec`]. If
+    /// you want both of these things, consider using [`Float::div_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) / Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) / Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::from(1.5) / Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(&Float::from(-1.5) / Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert_eq!(&Float::from(-1.5) / Float::NEGATIVE_ZERO, Float::INFINITY);
+    /// assert!((&Float::ZERO / Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) / Float::from(0.5), 3.0);
+    /// assert_eq!(&Float::from(1.5) / Float::from(-0.5), -3.0);
+    /// assert_eq!(&Float::from(-1.5) / Float::from(0.5), -3.0);
+    /// assert_eq!(&Float::from(-1.5) / Float::from(-0.5), 3.0);
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Div<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by another [`Float`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0) = \text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $0.0<x<\infty$
+    /// - $f(\infty,x)=-\infty$ if $-\infty<x<0.0$
+    /// - $f(x,0.0)=\infty$ if $x>0.0$
+    /// - $f(x,0.0)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=-\infty$ if $0.0<x<\infty$
+    /// - $f(-\infty,x)=\infty$ if $-\infty<x<0.0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=0.0$ if $x>0.0$
+    /// - $f(0.0,x)=-0.0$ if $x<0.0$
+    /// - $f(x,\infty)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,\infty)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x)=0.0$ if $x<0.0$
+    /// - $f(x,-\infty)=-0.0$ if $0.0<x<\infty$
+    /// - $f(x,-\infty)=0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::div_round`]
+    /// instead. If you want to specify the output precision, consider using [`Float::div_prec`]. If
+    /// you want both of these things, consider using [`Float::div_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) / &Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) / &Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::from(1.5) / &Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(&Float::from(-1.5) / &Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert_eq!(&Float::from(-1.5) / &Float::NEGATIVE_ZERO, Float::INFINITY);
+    /// assert!((&Float::ZERO / &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) / &Float::from(0.5), 3.0);
+    /// assert_eq!(&Float::from(1.5) / &Float::from(-0.5), -3.0);
+    /// assert_eq!(&Float::from(-1.5) / &Float::from(0.5), -3.0);
+    /// assert_eq!(&Float::from(-1.5) / &Float::from(-0.5), 3.0);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl DivAssign<Float> for Float {
+    /// Divides a [`Float`] by a [`Float`] in place, taking the [`Float`] on the right-hand side by
+    /// value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `/` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Float::ZERO;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Float::NEGATIVE_ZERO;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x /= Float::ZERO;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x /= Float::NEGATIVE_ZERO;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::ZERO;
+    /// x /= Float::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Float::from(0.5);
+    /// assert_eq!(x, 3.0);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Float::from(-0.5);
+    /// assert_eq!(x, -3.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x /= Float::from(0.5);
+    /// assert_eq!(x, -3.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x /= Float::from(-0.5);
+    /// assert_eq!(x, 3.0);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_assign(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> DivAssign<&'a Float> for Float {
+    /// Divides a [`Float`] by a [`Float`] in place, taking the [`Float`] on the right-hand side by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `/` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Float::ZERO;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Float::NEGATIVE_ZERO;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x /= &Float::ZERO;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x /= &Float::NEGATIVE_ZERO;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::ZERO;
+    /// x /= &Float::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Float::from(0.5);
+    /// assert_eq!(x, 3.0);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Float::from(-0.5);
+    /// assert_eq!(x, -3.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x /= &Float::from(0.5);
+    /// assert_eq!(x, -3.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x /= &Float::from(-0.5);
+    /// assert_eq!(x, 3.0);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_assign_ref(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl Div<Rational> for Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by a [`Rational`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_rational_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::div_rational_prec`]. If you want both of these things, consider using
+    /// [`Float::div_rational_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Float::NAN / Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(Float::INFINITY / Rational::exact_from(1.5), Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::NEGATIVE_INFINITY / Rational::exact_from(1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Float::INFINITY / Rational::exact_from(-1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(Float::from(1.5) / Rational::ZERO, Float::INFINITY);
+    /// assert!((Float::ZERO / Rational::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) / Rational::exact_from(0.5), 5.0);
+    /// assert_eq!(Float::from(2.5) / Rational::exact_from(-0.5), -5.0);
+    /// assert_eq!(Float::from(-2.5) / Rational::exact_from(0.5), -5.0);
+    /// assert_eq!(Float::from(-2.5) / Rational::exact_from(-0.5), 5.0);
+    /// ```
+    #[inline]
+    fn div(self, other: Rational) -> Float {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<&'a Rational> for Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by a [`Rational`], taking the [`Float`] by value and the [`Rational`] by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_rational_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::div_rational_prec`]. If you want both of these things, consider using
+    /// [`Float::div_rational_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Float::NAN / &Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(
+    ///     Float::INFINITY / &Rational::exact_from(1.5),
+    ///     Float::INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Float::NEGATIVE_INFINITY / &Rational::exact_from(1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Float::INFINITY / &Rational::exact_from(-1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(Float::from(1.5) / &Rational::ZERO, Float::INFINITY);
+    /// assert!((Float::ZERO / &Rational::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) / &Rational::exact_from(0.5), 5.0);
+    /// assert_eq!(Float::from(2.5) / &Rational::exact_from(-0.5), -5.0);
+    /// assert_eq!(Float::from(-2.5) / &Rational::exact_from(0.5), -5.0);
+    /// assert_eq!(Float::from(-2.5) / &Rational::exact_from(-0.5), 5.0);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Rational) -> Float {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<Rational> for &'a Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by a [`Rational`], taking the [`Float`] by reference and the
+    /// [`Rational`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_rational_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::div_rational_prec`]. If you want both of these things, consider using
+    /// [`Float::div_rational_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Float::NAN / Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(
+    ///     &Float::INFINITY / Rational::exact_from(1.5),
+    ///     Float::INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Float::NEGATIVE_INFINITY / Rational::exact_from(1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Float::INFINITY / Rational::exact_from(-1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(&Float::from(1.5) / Rational::ZERO, Float::INFINITY);
+    /// assert!((&Float::ZERO / Rational::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) / Rational::exact_from(0.5), 5.0);
+    /// assert_eq!(&Float::from(2.5) / Rational::exact_from(-0.5), -5.0);
+    /// assert_eq!(&Float::from(-2.5) / Rational::exact_from(0.5), -5.0);
+    /// assert_eq!(&Float::from(-2.5) / Rational::exact_from(-0.5), 5.0);
+    /// ```
+    #[inline]
+    fn div(self, other: Rational) -> Float {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Div<&'a Rational> for &'b Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by a [`Rational`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_rational_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::div_rational_prec`]. If you want both of these things, consider using
+    /// [`Float::div_rational_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Float::NAN / &Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(
+    ///     &Float::INFINITY / &Rational::exact_from(1.5),
+    ///     Float::INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Float::NEGATIVE_INFINITY / &Rational::exact_from(1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Float::INFINITY / &Rational::exact_from(-1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(&Float::from(1.5) / &Rational::ZERO, Float::INFINITY);
+    /// assert!((&Float::ZERO / &Rational::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) / &Rational::exact_from(0.5), 5.0);
+    /// assert_eq!(&Float::from(2.5) / &Rational::exact_from(-0.5), -5.0);
+    /// assert_eq!(&Float::from(-2.5) / &Rational::exact_from(0.5), -5.0);
+    /// assert_eq!(&Float::from(-2.5) / &Rational::exact_from(-0.5), 5.0);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Rational) -> Float {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl DivAssign<Rational> for Float {
+    /// Divides a [`Float`] by a [`Rational`] in place, taking the [`Rational`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `/` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Float::NAN;
+    /// x /= Rational::exact_from(1.5);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x /= Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x /= Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x /= Rational::exact_from(-1.5);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Rational::ZERO;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::ZERO;
+    /// x /= Rational::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= Rational::exact_from(0.5);
+    /// assert_eq!(x, 5.0);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= Rational::exact_from(-0.5);
+    /// assert_eq!(x, -5.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x /= Rational::exact_from(0.5);
+    /// assert_eq!(x, -5.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x /= Rational::exact_from(-0.5);
+    /// assert_eq!(x, 5.0);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Rational) {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round_assign(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> DivAssign<&'a Rational> for Float {
+    /// Divides a [`Float`] by a [`Rational`] in place, taking the [`Rational`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `/` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Float::NAN;
+    /// x /= &Rational::exact_from(1.5);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x /= &Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x /= &Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x /= &Rational::exact_from(-1.5);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Rational::ZERO;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::ZERO;
+    /// x /= &Rational::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= &Rational::exact_from(0.5);
+    /// assert_eq!(x, 5.0);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= &Rational::exact_from(-0.5);
+    /// assert_eq!(x, -5.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x /= &Rational::exact_from(0.5);
+    /// assert_eq!(x, -5.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x /= &Rational::exact_from(-0.5);
+    /// assert_eq!(x, 5.0);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a Rational) {
+        let prec = self.significant_bits();
+        self.div_rational_prec_round_assign_ref(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl Div<Float> for Rational {
+    type Output = Float;
+
+    /// Divides a [`Rational`] by a [`Float`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,\infty)=-0.0$ if $x<0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=0.0$ if $x<0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,0.0)=-\infty$ if $x<0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(x,-0.0)=\infty$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::rational_div_float_round`] instead. If you want to specify the output precision,
+    /// consider using [`Float::rational_div_float_prec`]. If you want both of these things,
+    /// consider using [`Float::rational_div_float_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Rational::exact_from(1.5) / Float::NAN).is_nan());
+    /// assert_eq!(Rational::exact_from(1.5) / Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     Rational::exact_from(1.5) / Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Rational::exact_from(-1.5) / Float::ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Rational::ZERO / Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Rational::exact_from(4.5) / Float::from(1.5), 3.0);
+    /// assert_eq!(Rational::exact_from(4.5) / Float::from(-1.5), -3.0);
+    /// assert_eq!(Rational::exact_from(-4.5) / Float::from(1.5), -3.0);
+    /// assert_eq!(Rational::exact_from(-4.5) / Float::from(-1.5), 3.0);
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        Float::rational_div_float_prec_round(self, other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Div<&'a Float> for Rational {
+    type Output = Float;
+
+    /// Divides a [`Rational`] by a [`Float`], taking the [`Rational`] by value and the [`Float`] by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,\infty)=-0.0$ if $x<0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=0.0$ if $x<0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,0.0)=-\infty$ if $x<0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(x,-0.0)=\infty$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::rational_div_float_round`] instead. If you want to specify the output precision,
+    /// consider using [`Float::rational_div_float_prec`]. If you want both of these things,
+    /// consider using [`Float::rational_div_float_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Rational::exact_from(1.5) / &Float::NAN).is_nan());
+    /// assert_eq!(Rational::exact_from(1.5) / &Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     Rational::exact_from(1.5) / &Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Rational::exact_from(-1.5) / &Float::ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Rational::ZERO / &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Rational::exact_from(4.5) / &Float::from(1.5), 3.0);
+    /// assert_eq!(Rational::exact_from(4.5) / &Float::from(-1.5), -3.0);
+    /// assert_eq!(Rational::exact_from(-4.5) / &Float::from(1.5), -3.0);
+    /// assert_eq!(Rational::exact_from(-4.5) / &Float::from(-1.5), 3.0);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        Float::rational_div_float_prec_round_val_ref(self, other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Div<Float> for &'a Rational {
+    type Output = Float;
+
+    /// Divides a [`Rational`] by a [`Float`], taking the [`Rational`] by reference and the
+    /// [`Float`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,\infty)=-0.0$ if $x<0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=0.0$ if $x<0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,0.0)=-\infty$ if $x<0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(x,-0.0)=\infty$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::rational_div_float_round`] instead. If you want to specify the output precision,
+    /// consider using [`Float::rational_div_float_prec`]. If you want both of these things,
+    /// consider using [`Float::rational_div_float_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Rational::exact_from(1.5) / Float::NAN).is_nan());
+    /// assert_eq!(&Rational::exact_from(1.5) / Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Rational::exact_from(1.5) / Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Rational::exact_from(-1.5) / Float::ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Rational::ZERO / Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Rational::exact_from(4.5) / Float::from(1.5), 3.0);
+    /// assert_eq!(&Rational::exact_from(4.5) / Float::from(-1.5), -3.0);
+    /// assert_eq!(&Rational::exact_from(-4.5) / Float::from(1.5), -3.0);
+    /// assert_eq!(&Rational::exact_from(-4.5) / Float::from(-1.5), 3.0);
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        Float::rational_div_float_prec_round_ref_val(self, other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a, 'b> Div<&'a Float> for &'b Rational {
+    type Output = Float;
+
+    /// Divides a [`Rational`] by a [`Float`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,\infty)=-0.0$ if $x<0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=0.0$ if $x<0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,0.0)=-\infty$ if $x<0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(x,-0.0)=\infty$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::rational_div_float_round`] instead. If you want to specify the output precision,
+    /// consider using [`Float::rational_div_float_prec`]. If you want both of these things,
+    /// consider using [`Float::rational_div_float_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Rational::exact_from(1.5) / &Float::NAN).is_nan());
+    /// assert_eq!(&Rational::exact_from(1.5) / &Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Rational::exact_from(1.5) / &Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Rational::exact_from(-1.5) / &Float::ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Rational::ZERO / &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Rational::exact_from(4.5) / &Float::from(1.5), 3.0);
+    /// assert_eq!(&Rational::exact_from(4.5) / &Float::from(-1.5), -3.0);
+    /// assert_eq!(&Rational::exact_from(-4.5) / &Float::from(1.5), -3.0);
+    /// assert_eq!(&Rational::exact_from(-4.5) / &Float::from(-1.5), 3.0);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        Float::rational_div_float_prec_round_ref_ref(self, other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl Div<Natural> for Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by a [`Natural`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((Float::NAN / Natural::from(3u32)).is_nan());
+    /// assert_eq!(Float::INFINITY / Natural::from(3u32), Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::NEGATIVE_INFINITY / Natural::from(3u32),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(Float::from(2.5) / Natural::ZERO, Float::INFINITY);
+    /// assert!((Float::ZERO / Natural::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) / Natural::from(2u32), 1.25);
+    /// assert_eq!(Float::from(-2.5) / Natural::from(2u32), -1.25);
+    /// ```
+    #[inline]
+    fn div(self, other: Natural) -> Float {
+        let prec = self.significant_bits();
+        self.div_prec_round(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<&'a Natural> for Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by a [`Natural`], taking the [`Float`] by value and the [`Natural`] by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((Float::NAN / &Natural::from(3u32)).is_nan());
+    /// assert_eq!(Float::INFINITY / &Natural::from(3u32), Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::NEGATIVE_INFINITY / &Natural::from(3u32),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(Float::from(2.5) / &Natural::ZERO, Float::INFINITY);
+    /// assert!((Float::ZERO / &Natural::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) / &Natural::from(2u32), 1.25);
+    /// assert_eq!(Float::from(-2.5) / &Natural::from(2u32), -1.25);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Natural) -> Float {
+        let prec = self.significant_bits();
+        self.div_prec_round(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<Natural> for &'a Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by a [`Natural`], taking the [`Float`] by reference and the [`Natural`]
+    /// by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((&Float::NAN / Natural::from(3u32)).is_nan());
+    /// assert_eq!(&Float::INFINITY / Natural::from(3u32), Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::NEGATIVE_INFINITY / Natural::from(3u32),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(&Float::from(2.5) / Natural::ZERO, Float::INFINITY);
+    /// assert!((&Float::ZERO / Natural::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) / Natural::from(2u32), 1.25);
+    /// assert_eq!(&Float::from(-2.5) / Natural::from(2u32), -1.25);
+    /// ```
+    #[inline]
+    fn div(self, other: Natural) -> Float {
+        let prec = self.significant_bits();
+        self.div_prec_round_ref_val(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Div<&'a Natural> for &'b Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by a [`Natural`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((&Float::NAN / &Natural::from(3u32)).is_nan());
+    /// assert_eq!(&Float::INFINITY / &Natural::from(3u32), Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::NEGATIVE_INFINITY / &Natural::from(3u32),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(&Float::from(2.5) / &Natural::ZERO, Float::INFINITY);
+    /// assert!((&Float::ZERO / &Natural::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) / &Natural::from(2u32), 1.25);
+    /// assert_eq!(&Float::from(-2.5) / &Natural::from(2u32), -1.25);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Natural) -> Float {
+        let prec = self.significant_bits();
+        self.div_prec_round_ref_val(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl DivAssign<Natural> for Float {
+    /// Divides a [`Float`] by a [`Natural`] in place, taking the [`Natural`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `/` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Float::NAN;
+    /// x /= Natural::from(3u32);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x /= Natural::from(3u32);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x /= Natural::from(3u32);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= Natural::ZERO;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::ZERO;
+    /// x /= Natural::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= Natural::from(2u32);
+    /// assert_eq!(x, 1.25);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x /= Natural::from(2u32);
+    /// assert_eq!(x, -1.25);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Natural) {
+        let prec = self.significant_bits();
+        self.div_prec_round_assign(Float::from(other), prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> DivAssign<&'a Natural> for Float {
+    /// Divides a [`Float`] by a [`Natural`] in place, taking the [`Natural`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `/` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Float::NAN;
+    /// x /= &Natural::from(3u32);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x /= &Natural::from(3u32);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x /= &Natural::from(3u32);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= &Natural::ZERO;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::ZERO;
+    /// x /= &Natural::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= &Natural::from(2u32);
+    /// assert_eq!(x, 1.25);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x /= &Natural::from(2u32);
+    /// assert_eq!(x, -1.25);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a Natural) {
+        let prec = self.significant_bits();
+        self.div_prec_round_assign(Float::from(other), prec, RoundingMode::Nearest);
+    }
+}
+
+impl Div<Float> for Natural {
+    type Output = Float;
+
+    /// Divides a [`Natural`] by a [`Float`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((Natural::from(3u32) / Float::NAN).is_nan());
+    /// assert_eq!(Natural::from(3u32) / Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     Natural::from(3u32) / Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Natural::ZERO / Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Natural::from(3u32) / Float::from(1.5), 2.0);
+    /// assert_eq!(Natural::from(3u32) / Float::from(-1.5), -2.0);
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        Float::from(self)
+            .div_prec_round(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<&'a Float> for Natural {
+    type Output = Float;
+
+    /// Divides a [`Natural`] by a [`Float`], taking the [`Natural`] by value and the [`Float`] by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((Natural::from(3u32) / &Float::NAN).is_nan());
+    /// assert_eq!(Natural::from(3u32) / &Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     Natural::from(3u32) / &Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Natural::ZERO / &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Natural::from(3u32) / &Float::from(1.5), 2.0);
+    /// assert_eq!(Natural::from(3u32) / &Float::from(-1.5), -2.0);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        Float::from(self)
+            .div_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<Float> for &'a Natural {
+    type Output = Float;
+
+    /// Divides a [`Natural`] by a [`Float`], taking the [`Natural`] by reference and the [`Float`]
+    /// by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((&Natural::from(3u32) / Float::NAN).is_nan());
+    /// assert_eq!(&Natural::from(3u32) / Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Natural::from(3u32) / Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Natural::ZERO / Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Natural::from(3u32) / Float::from(1.5), 2.0);
+    /// assert_eq!(&Natural::from(3u32) / Float::from(-1.5), -2.0);
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        Float::from(self)
+            .div_prec_round(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Div<&'a Float> for &'b Natural {
+    type Output = Float;
+
+    /// Divides a [`Natural`] by a [`Float`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((&Natural::from(3u32) / &Float::NAN).is_nan());
+    /// assert_eq!(&Natural::from(3u32) / &Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Natural::from(3u32) / &Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Natural::ZERO / &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Natural::from(3u32) / &Float::from(1.5), 2.0);
+    /// assert_eq!(&Natural::from(3u32) / &Float::from(-1.5), -2.0);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        Float::from(self)
+            .div_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl Div<Integer> for Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by an [`Integer`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((Float::NAN / Integer::from(3)).is_nan());
+    /// assert_eq!(Float::INFINITY / Integer::from(3), Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::INFINITY / Integer::from(-3),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::ZERO / Integer::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) / Integer::from(2), 1.25);
+    /// assert_eq!(Float::from(2.5) / Integer::from(-2), -1.25);
+    /// assert_eq!(Float::from(-2.5) / Integer::from(2), -1.25);
+    /// assert_eq!(Float::from(-2.5) / Integer::from(-2), 1.25);
+    /// ```
+    #[inline]
+    fn div(self, other: Integer) -> Float {
+        let prec = self.significant_bits();
+        self.div_prec_round(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<&'a Integer> for Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by an [`Integer`], taking the [`Float`] by value and the [`Integer`] by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((Float::NAN / &Integer::from(3)).is_nan());
+    /// assert_eq!(Float::INFINITY / &Integer::from(3), Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::INFINITY / &Integer::from(-3),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::ZERO / &Integer::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) / &Integer::from(2), 1.25);
+    /// assert_eq!(Float::from(2.5) / &Integer::from(-2), -1.25);
+    /// assert_eq!(Float::from(-2.5) / &Integer::from(2), -1.25);
+    /// assert_eq!(Float::from(-2.5) / &Integer::from(-2), 1.25);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Integer) -> Float {
+        let prec = self.significant_bits();
+        self.div_prec_round(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<Integer> for &'a Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by an [`Integer`], taking the [`Float`] by reference and the [`Integer`]
+    /// by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((&Float::NAN / Integer::from(3)).is_nan());
+    /// assert_eq!(&Float::INFINITY / Integer::from(3), Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::INFINITY / Integer::from(-3),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::ZERO / Integer::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) / Integer::from(2), 1.25);
+    /// assert_eq!(&Float::from(2.5) / Integer::from(-2), -1.25);
+    /// assert_eq!(&Float::from(-2.5) / Integer::from(2), -1.25);
+    /// assert_eq!(&Float::from(-2.5) / Integer::from(-2), 1.25);
+    /// ```
+    #[inline]
+    fn div(self, other: Integer) -> Float {
+        let prec = self.significant_bits();
+        self.div_prec_round_ref_val(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Div<&'a Integer> for &'b Float {
+    type Output = Float;
+
+    /// Divides a [`Float`] by an [`Integer`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm0.0,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x\geq0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x>0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=\infty$ if $0.0<x<\infty$
+    /// - $f(x,0)=-\infty$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((&Float::NAN / &Integer::from(3)).is_nan());
+    /// assert_eq!(&Float::INFINITY / &Integer::from(3), Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::INFINITY / &Integer::from(-3),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::ZERO / &Integer::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) / &Integer::from(2), 1.25);
+    /// assert_eq!(&Float::from(2.5) / &Integer::from(-2), -1.25);
+    /// assert_eq!(&Float::from(-2.5) / &Integer::from(2), -1.25);
+    /// assert_eq!(&Float::from(-2.5) / &Integer::from(-2), 1.25);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Integer) -> Float {
+        let prec = self.significant_bits();
+        self.div_prec_round_ref_val(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl DivAssign<Integer> for Float {
+    /// Divides a [`Float`] by an [`Integer`] in place, taking the [`Integer`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `/` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Float::NAN;
+    /// x /= Integer::from(3);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x /= Integer::from(3);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x /= Integer::from(-3);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::ZERO;
+    /// x /= Integer::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= Integer::from(2);
+    /// assert_eq!(x, 1.25);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= Integer::from(-2);
+    /// assert_eq!(x, -1.25);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x /= Integer::from(2);
+    /// assert_eq!(x, -1.25);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x /= Integer::from(-2);
+    /// assert_eq!(x, 1.25);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Integer) {
+        let prec = self.significant_bits();
+        self.div_prec_round_assign(Float::from(other), prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> DivAssign<&'a Integer> for Float {
+    /// Divides a [`Float`] by an [`Integer`] in place, taking the [`Integer`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `/` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Float::NAN;
+    /// x /= &Integer::from(3);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x /= &Integer::from(3);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x /= &Integer::from(-3);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::ZERO;
+    /// x /= &Integer::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= &Integer::from(2);
+    /// assert_eq!(x, 1.25);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x /= &Integer::from(-2);
+    /// assert_eq!(x, -1.25);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x /= &Integer::from(2);
+    /// assert_eq!(x, -1.25);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x /= &Integer::from(-2);
+    /// assert_eq!(x, 1.25);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a Integer) {
+        let prec = self.significant_bits();
+        self.div_prec_round_assign(Float::from(other), prec, RoundingMode::Nearest);
+    }
+}
+
+impl Div<Float> for Integer {
+    type Output = Float;
+
+    /// Divides an [`Integer`] by a [`Float`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,\infty)=-0.0$ if $x<0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=0.0$ if $x<0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,0.0)=-\infty$ if $x<0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(x,-0.0)=\infty$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((Integer::from(3) / Float::NAN).is_nan());
+    /// assert_eq!(Integer::from(3) / Float::ZERO, Float::INFINITY);
+    /// assert_eq!(Integer::from(-3) / Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert!((Integer::ZERO / Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Integer::from(3) / Float::from(1.5), 2.0);
+    /// assert_eq!(Integer::from(-3) / Float::from(1.5), -2.0);
+    /// assert_eq!(Integer::from(3) / Float::from(-1.5), -2.0);
+    /// assert_eq!(Integer::from(-3) / Float::from(-1.5), 2.0);
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        Float::from(self)
+            .div_prec_round(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<&'a Float> for Integer {
+    type Output = Float;
+
+    /// Divides an [`Integer`] by a [`Float`], taking the [`Integer`] by value and the [`Float`] by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,\infty)=-0.0$ if $x<0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=0.0$ if $x<0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,0.0)=-\infty$ if $x<0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(x,-0.0)=\infty$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((Integer::from(3) / &Float::NAN).is_nan());
+    /// assert_eq!(Integer::from(3) / &Float::ZERO, Float::INFINITY);
+    /// assert_eq!(Integer::from(-3) / &Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert!((Integer::ZERO / &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Integer::from(3) / &Float::from(1.5), 2.0);
+    /// assert_eq!(Integer::from(-3) / &Float::from(1.5), -2.0);
+    /// assert_eq!(Integer::from(3) / &Float::from(-1.5), -2.0);
+    /// assert_eq!(Integer::from(-3) / &Float::from(-1.5), 2.0);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        Float::from(self)
+            .div_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<Float> for &'a Integer {
+    type Output = Float;
+
+    /// Divides an [`Integer`] by a [`Float`], taking the [`Integer`] by reference and the [`Float`]
+    /// by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,\infty)=-0.0$ if $x<0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=0.0$ if $x<0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,0.0)=-\infty$ if $x<0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(x,-0.0)=\infty$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((&Integer::from(3) / Float::NAN).is_nan());
+    /// assert_eq!(&Integer::from(3) / Float::ZERO, Float::INFINITY);
+    /// assert_eq!(&Integer::from(-3) / Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert!((&Integer::ZERO / Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Integer::from(3) / Float::from(1.5), 2.0);
+    /// assert_eq!(&Integer::from(-3) / Float::from(1.5), -2.0);
+    /// assert_eq!(&Integer::from(3) / Float::from(-1.5), -2.0);
+    /// assert_eq!(&Integer::from(-3) / Float::from(-1.5), 2.0);
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        Float::from(self)
+            .div_prec_round(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Div<&'a Float> for &'b Integer {
+    type Output = Float;
+
+    /// Divides an [`Integer`] by a [`Float`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the quotient
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\epsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm0.0)=\text{NaN}$
+    /// - $f(x,\infty)=0.0$ if $x\geq0$
+    /// - $f(x,\infty)=-0.0$ if $x<0$
+    /// - $f(x,-\infty)=-0.0$ if $x\geq0$
+    /// - $f(x,-\infty)=0.0$ if $x<0$
+    /// - $f(x,0.0)=\infty$ if $x>0$
+    /// - $f(x,0.0)=-\infty$ if $x<0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0$
+    /// - $f(x,-0.0)=\infty$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((&Integer::from(3) / &Float::NAN).is_nan());
+    /// assert_eq!(&Integer::from(3) / &Float::ZERO, Float::INFINITY);
+    /// assert_eq!(&Integer::from(-3) / &Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert!((&Integer::ZERO / &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Integer::from(3) / &Float::from(1.5), 2.0);
+    /// assert_eq!(&Integer::from(-3) / &Float::from(1.5), -2.0);
+    /// assert_eq!(&Integer::from(3) / &Float::from(-1.5), -2.0);
+    /// assert_eq!(&Integer::from(-3) / &Float::from(-1.5), 2.0);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        Float::from(self)
+            .div_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
@@ -0,0 +1,957 @@
+use crate::constants::ln_2::ln_2_fixed_point_bounds;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{
+    float_infinity, float_nan, float_negative_infinity, float_zero, significand_bits, Float,
+};
+use malachite_base::num::arithmetic::traits::{
+    DivRound, FloorSqrt, PowerOf2, RoundToMultipleOfPowerOf2, ShrRound, Square,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{LowMask, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use std::cmp::Ordering;
+
+// The transcendental functions in this module and its neighbors are computed with interval
+// arithmetic on fixed-point numbers. A pair of integers `lo` and `hi`, together with a scale $f$,
+// encloses a real number $y$ if `lo`$2^{-f} \leq y \leq$ `hi`$2^{-f}$. Lower bounds are always
+// computed with `Floor` and upper bounds with `Ceiling`, so every enclosure is rigorous, no matter
+// how many roundings went into it. The enclosure is then handed to `round_enclosure`, and if both
+// of its ends round to the same `Float`, that `Float` is the correctly-rounded result; otherwise,
+// the working precision is increased and the enclosure is recomputed (Ziv's strategy).
+
+// Returns $m$ and $p$ such that $x = m2^p$, where `x` is finite and nonzero.
+pub(crate) fn integer_and_pow(x: &Float) -> (Integer, i64) {
+    match x {
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) => (
+            Integer::from_sign_and_abs_ref(*sign, significand),
+            exponent - i64::exact_from(significand_bits(significand)),
+        ),
+        _ => panic!("{x} is not finite and nonzero"),
+    }
+}
+
+// Returns $\lfloor m2^{p+f} \rfloor$ or $\lceil m2^{p+f} \rceil$, depending on whether `rm` is
+// `Floor` or `Ceiling`.
+pub(crate) fn to_fixed_point(m: &Integer, pow: i64, f: u64, rm: RoundingMode) -> Integer {
+    let shift = pow + i64::exact_from(f);
+    if shift >= 0 {
+        m << shift
+    } else {
+        m.shr_round(shift.unsigned_abs(), rm).0
+    }
+}
+
+// Given $x = m2^p$ and an enclosure `c_lo`$2^{-f} \leq c \leq$ `c_hi`$2^{-f}$ of a positive
+// constant $c$, returns an enclosure of $xc$ with the same scale.
+pub(crate) fn mul_by_constant_enclosure(
+    m: &Integer,
+    pow: i64,
+    c_lo: &Natural,
+    c_hi: &Natural,
+) -> (Integer, Integer) {
+    let (c_lo, c_hi) = if *m >= 0 { (c_lo, c_hi) } else { (c_hi, c_lo) };
+    (
+        to_fixed_point(&(m * Integer::from(c_lo)), pow, 0, RoundingMode::Floor),
+        to_fixed_point(&(m * Integer::from(c_hi)), pow, 0, RoundingMode::Ceiling),
+    )
+}
+
+// Given an enclosure `lo`$2^p < y <$ `hi`$2^p$ of a real number $y$ which is known not to be
+// exactly representable with `prec` bits, returns $y$ rounded to `prec` bits, along with the
+// `Ordering` of the rounded value relative to $y$. If the ends of the enclosure don't determine the
+// result, `None` is returned.
+pub(crate) fn round_enclosure(
+    lo: Integer,
+    hi: Integer,
+    pow: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    let (y_lo, o_lo) = Float::from_integer_times_power_of_2_prec_round(lo, pow, prec, rm);
+    let (y_hi, o_hi) = Float::from_integer_times_power_of_2_prec_round(hi, pow, prec, rm);
+    if y_lo != y_hi || y_lo.is_sign_positive() != y_hi.is_sign_positive() {
+        None
+    } else if o_lo != Ordering::Greater {
+        Some((y_lo, Ordering::Less))
+    } else if o_hi != Ordering::Less {
+        Some((y_lo, Ordering::Greater))
+    } else {
+        None
+    }
+}
+
+// Rounds a real number $y$, which is known not to be exactly representable with `prec` bits, to
+// `prec` bits. `enclosure` is called with increasing working precisions $w$ and must return `lo`,
+// `hi` and $p$ such that `lo`$2^p < y <$ `hi`$2^p$, with the width of the enclosure roughly
+// $2^{-w}|y|$.
+pub(crate) fn ziv_round<F: FnMut(u64) -> (Integer, Integer, i64)>(
+    prec: u64,
+    rm: RoundingMode,
+    mut enclosure: F,
+) -> (Float, Ordering) {
+    assert_ne!(rm, RoundingMode::Exact, "Inexact transcendental function");
+    let mut working_prec = prec + prec.significant_bits() + 10;
+    loop {
+        let (lo, hi, pow) = enclosure(working_prec);
+        if let Some(result) = round_enclosure(lo, hi, pow, prec, rm) {
+            return result;
+        }
+        working_prec += working_prec >> 1;
+    }
+}
+
+// Rounds $m2^p+\varepsilon$ to `prec` bits, where $m$ is nonzero and $\varepsilon$ is an
+// infinitesimal whose sign is given by `direction`. This is used when a function's value is so
+// close to $m2^p$ that only the side on which it lies matters. Appending a 1 bit far enough below
+// both the last bit of $m$ and the rounding position gives a number that rounds the same way.
+pub(crate) fn round_nudged(
+    m: Integer,
+    pow: i64,
+    direction: Ordering,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(rm, RoundingMode::Exact, "Inexact transcendental function");
+    let bits = m.significant_bits();
+    let shift = bits.max(prec + 2) - bits + 1;
+    let mut m = m << shift;
+    if direction == Ordering::Greater {
+        m += Integer::ONE;
+    } else {
+        m -= Integer::ONE;
+    }
+    Float::from_integer_times_power_of_2_prec_round(m, pow - i64::exact_from(shift), prec, rm)
+}
+
+// Returns the largest finite `Float` with precision `prec`: the one with the largest exponent and
+// all significand bits set.
+fn max_finite(prec: u64) -> Float {
+    let bits = prec
+        .round_to_multiple_of_power_of_2(Limb::LOG_WIDTH, RoundingMode::Ceiling)
+        .0;
+    Float(Finite {
+        sign: true,
+        exponent: i64::MAX,
+        precision: prec,
+        significand: Natural::low_mask(prec) << (bits - prec),
+    })
+}
+
+// Returns the smallest positive `Float` with precision `prec`: the power of 2 with the smallest
+// exponent.
+fn min_positive(prec: u64) -> Float {
+    let bits = prec
+        .round_to_multiple_of_power_of_2(Limb::LOG_WIDTH, RoundingMode::Ceiling)
+        .0;
+    Float(Finite {
+        sign: true,
+        exponent: i64::MIN,
+        precision: prec,
+        significand: Natural::power_of_2(bits - 1),
+    })
+}
+
+// Rounds the value of a function that overflows, which is treated as larger in absolute value than
+// every finite `Float`, and which is positive if `sign` is `true` and negative otherwise. As in
+// MPFR, the result is an infinity, unless `rm` rounds toward zero, in which case it is the finite
+// `Float` with precision `prec` that is largest in absolute value.
+pub(crate) fn overflow(sign: bool, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    match (sign, rm) {
+        (_, RoundingMode::Exact) => panic!("Inexact float overflow"),
+        (true, RoundingMode::Floor | RoundingMode::Down) => (max_finite(prec), Ordering::Less),
+        (true, _) => (float_infinity!(), Ordering::Greater),
+        (false, RoundingMode::Ceiling | RoundingMode::Down) => {
+            (-max_finite(prec), Ordering::Greater)
+        }
+        (false, _) => (float_negative_infinity!(), Ordering::Less),
+    }
+}
+
+// Rounds the value of a positive function that underflows, which is treated as smaller than every
+// positive `Float`. As in MPFR, the result is zero, unless `rm` rounds away from zero, in which case
+// it is the smallest positive `Float` with precision `prec`.
+pub(crate) fn underflow(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    match rm {
+        RoundingMode::Exact => panic!("Inexact float underflow"),
+        RoundingMode::Ceiling | RoundingMode::Up => (min_positive(prec), Ordering::Greater),
+        _ => (float_zero!(), Ordering::Less),
+    }
+}
+
+// Returns whether a function $f$ with $|f(x) - x| < 2^{2e}|x|$, where $e$ is the exponent of $x$,
+// can be computed at $x = m2^p$ by passing $x$ to `round_nudged`.
+pub(crate) fn cubic_term_is_negligible(m: &Integer, exponent: i64, prec: u64) -> bool {
+    exponent.saturating_mul(2) < -i64::exact_from(m.significant_bits().max(prec + 2))
+}
+
+// Returns a lower or upper bound, depending on whether `rm` is `Floor` or `Ceiling`, for
+// $e^{t2^{-f}}2^f$, where $0 \leq t2^{-f} < 1$.
+//
+// The argument is divided by $2^s$, with $s$ around $\sqrt{f}/2$, the Taylor series is summed, and
+// the result is squared $s$ times.
+fn exp_fixed_point(t: &Natural, f: u64, rm: RoundingMode) -> Natural {
+    let s = (f.floor_sqrt() >> 1) + 1;
+    let g = f + s + f.significant_bits() + 8;
+    let u = t << (g - f - s);
+    let mut sum = Natural::power_of_2(g);
+    let mut term = sum.clone();
+    let mut j = 0u64;
+    loop {
+        j += 1;
+        term = (term * &u)
+            .shr_round(g, rm)
+            .0
+            .div_round(Natural::from(j), rm)
+            .0;
+        if rm == RoundingMode::Floor {
+            if term == 0u32 {
+                break;
+            }
+            sum += &term;
+        } else if term <= 1u32 {
+            // Since $u < 1/2$, the remaining terms sum to less than the current one.
+            sum += term + Natural::ONE;
+            break;
+        } else {
+            sum += &term;
+        }
+    }
+    for _ in 0..s {
+        sum = sum.square().shr_round(g, rm).0;
+    }
+    sum.shr_round(g - f, rm).0
+}
+
+// Returns a lower or upper bound, depending on whether `rm` is `Floor` or `Ceiling`, for
+// $e^{t2^{-f}}2^f$, where $|t2^{-f}| < 1$.
+fn exp_fixed_point_signed(t: &Integer, f: u64, rm: RoundingMode) -> Natural {
+    if *t >= 0 {
+        exp_fixed_point(t.unsigned_abs_ref(), f, rm)
+    } else {
+        Natural::power_of_2(f << 1)
+            .div_round(exp_fixed_point(t.unsigned_abs_ref(), f, -rm), rm)
+            .0
+    }
+}
+
+// Given an enclosure `y_lo`$2^{-f} \leq y \leq$ `y_hi`$2^{-f}$, where $|y| < 2^{62}$, returns
+// `lo`, `hi` and $p$ such that `lo`$2^p \leq e^y \leq$ `hi`$2^p$.
+//
+// The argument is reduced to $r = y - k\log 2$, with $k$ an integer close to $y/\log 2$, so that
+// $e^y = 2^ke^r$. The absolute error of the reduced argument is roughly $k2^{-f}$, so $f$ should
+// exceed the desired relative precision by the bit length of $k$, plus a few guard bits.
+pub(crate) fn exp_enclosure(y_lo: &Integer, y_hi: &Integer, f: u64) -> (Natural, Natural, i64) {
+    // $\lfloor 2^{70}\log 2 \rfloor$ is accurate enough to choose $k$ so that $0 \leq r < 3/4$, up
+    // to the tiny error of the enclosure.
+    let y_70 = if f >= 70 {
+        y_lo.shr_round(f - 70, RoundingMode::Floor).0
+    } else {
+        y_lo << (70 - f)
+    };
+    let k = y_70
+        .div_round(
+            Integer::from(ln_2_fixed_point_bounds(70).0),
+            RoundingMode::Floor,
+        )
+        .0;
+    let (ln_2_lo, ln_2_hi) = ln_2_fixed_point_bounds(f);
+    let (ln_2_lo, ln_2_hi) = (Integer::from(ln_2_lo), Integer::from(ln_2_hi));
+    let (r_lo, r_hi) = if k >= 0 {
+        (y_lo - &k * ln_2_hi, y_hi - &k * ln_2_lo)
+    } else {
+        (y_lo - &k * ln_2_lo, y_hi - &k * ln_2_hi)
+    };
+    (
+        exp_fixed_point_signed(&r_lo, f, RoundingMode::Floor),
+        exp_fixed_point_signed(&r_hi, f, RoundingMode::Ceiling),
+        i64::exact_from(&k) - i64::exact_from(f),
+    )
+}
+
+// Returns the fixed-point scale that `exp_enclosure` needs in order to compute $e^y$ with a
+// relative precision of about `working_prec` bits, where $y$ has exponent `y_exponent`.
+pub(crate) fn exp_scale(working_prec: u64, y_exponent: i64) -> u64 {
+    working_prec + u64::exact_from(y_exponent.max(0)) + 8
+}
+
+// Computes $e^x$, where $x$ is finite and nonzero, rounding to `prec` bits.
+fn exp_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    if exponent >= 63 {
+        // $|x| \geq 2^{62}$, so $e^x$ is treated as overflowing or underflowing.
+        assert_ne!(rm, RoundingMode::Exact, "Inexact exp");
+        return if *x > 0u32 {
+            overflow(true, prec, rm)
+        } else {
+            underflow(prec, rm)
+        };
+    }
+    if exponent <= -i64::exact_from(prec) - 3 {
+        // $|e^x - 1| < 2|x| \leq 2^{-p-2}$, so $e^x$ rounds like $1 \pm \varepsilon$.
+        let direction = if *x > 0u32 {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+        return round_nudged(Integer::ONE, 0, direction, prec, rm);
+    }
+    let (m, pow) = integer_and_pow(x);
+    ziv_round(prec, rm, |working_prec| {
+        let f = exp_scale(working_prec, exponent);
+        let (lo, hi, pow) = exp_enclosure(
+            &to_fixed_point(&m, pow, f, RoundingMode::Floor),
+            &to_fixed_point(&m, pow, f, RoundingMode::Ceiling),
+            f,
+        );
+        (Integer::from(lo), Integer::from(hi), pow)
+    })
+}
+
+// Computes $e^x$, rounding to `prec` bits.
+fn exp_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() => (float_nan!(), Ordering::Equal),
+        Float(Infinity { sign: true }) => (float_infinity!(), Ordering::Equal),
+        Float(Infinity { sign: false }) => (float_zero!(), Ordering::Equal),
+        Float(Zero { .. }) => (Float::one_prec(prec), Ordering::Equal),
+        _ => exp_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the exponential of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded exponential is less than, equal to, or greater than
+    /// the exact exponential. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x+\epsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    /// - $f(x,p,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,p,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,p,m)=0.0$ if $x\leq -2^{62}$ and $m$ is not `Ceiling` or `Up`
+    /// - $f(x,p,m)$ is the smallest positive [`Float`] with precision $p$ if $x\leq -2^{62}$ and
+    ///   $m$ is `Ceiling` or `Up`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "24.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.14072");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        exp_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded exponential is less than, equal to, or greater
+    /// than the exact exponential. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x+\epsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    /// - $f(x,p,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,p,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,p,m)=0.0$ if $x\leq -2^{62}$ and $m$ is not `Ceiling` or `Up`
+    /// - $f(x,p,m)$ is the smallest positive [`Float`] with precision $p$ if $x\leq -2^{62}$ and
+    ///   $m$ is `Ceiling` or `Up`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "24.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.14072");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        exp_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the exponential is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x+\epsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |e^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(0.0,p)=1.0$
+    /// - $f(-0.0,p)=1.0$
+    /// - $f(x,p)=\infty$ if $x\geq 2^{62}$
+    /// - $f(x,p)=0.0$ if $x\leq -2^{62}$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec(5);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec(20);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec(self, prec: u64) -> (Float, Ordering) {
+        self.exp_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the exponential is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x+\epsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |e^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(0.0,p)=1.0$
+    /// - $f(-0.0,p)=1.0$
+    /// - $f(x,p)=\infty$ if $x\geq 2^{62}$
+    /// - $f(x,p)=0.0$ if $x\leq -2^{62}$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_ref(5);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_ref(20);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.exp_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded exponential is less than, equal to, or greater than the exact exponential.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x+\epsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(0.0,m)=1.0$
+    /// - $f(-0.0,m)=1.0$
+    /// - $f(x,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,m)=0.0$ if $x\leq -2^{62}$ and $m$ is not `Ceiling` or `Up`
+    /// - $f(x,m)$ is the smallest positive [`Float`] with precision $p$ if $x\leq -2^{62}$ and $m$
+    ///   is `Ceiling` or `Up`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.140692632779263");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn exp_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp_prec_round(prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x+\epsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(0.0,m)=1.0$
+    /// - $f(-0.0,m)=1.0$
+    /// - $f(x,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,m)=0.0$ if $x\leq -2^{62}$ and $m$ is not `Ceiling` or `Up`
+    /// - $f(x,m)$ is the smallest positive [`Float`] with precision $p$ if $x\leq -2^{62}$ and $m$
+    ///   is `Ceiling` or `Up`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.140692632779263");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn exp_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its exponential, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded exponential is less than, equal to, or greater than the exact exponential. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets e^x+\epsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x|\rfloor-p}$.
+    ///
+    /// See the [`Float::exp_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "24.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "23.14069");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "23.14072");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// ```
+    #[inline]
+    pub fn exp_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (exp, o) = exp_prec_round_ref_helper(self, prec, rm);
+        *self = exp;
+        o
+    }
+
+    /// Replaces a [`Float`] with its exponential, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded exponential
+    /// is less than, equal to, or greater than the exact exponential. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets e^x+\epsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |e^x|\rfloor-p}$.
+    ///
+    /// See the [`Float::exp_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// ```
+    #[inline]
+    pub fn exp_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.exp_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its exponential, rounding the result with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded exponential is less than,
+    /// equal to, or greater than the exact exponential. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets e^x+\epsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::exp_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "23.140692632779263");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    /// ```
+    #[inline]
+    pub fn exp_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.exp_prec_round_assign(prec, rm)
+    }
+}
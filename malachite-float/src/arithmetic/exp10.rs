@@ -0,0 +1,696 @@
+use crate::arithmetic::exp::{
+    exp_enclosure, exp_scale, integer_and_pow, mul_by_constant_enclosure, overflow, round_nudged,
+    underflow, ziv_round,
+};
+use crate::arithmetic::ln::ln_10_fixed_point_bounds;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_zero, Float};
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Computes $10^x$, where $x$ is finite and nonzero, rounding to `prec` bits.
+fn exp10_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    if exponent >= 62 {
+        // $|x| \geq 2^{61}$, so $10^x$ is treated as overflowing or underflowing.
+        assert_ne!(rm, RoundingMode::Exact, "Inexact exp10");
+        return if *x > 0u32 {
+            overflow(true, prec, rm)
+        } else {
+            underflow(prec, rm)
+        };
+    }
+    if *x > 0u32 && x.is_integer() {
+        let n = u64::exact_from(x);
+        // If $n$ is larger than `prec`, $10^n$ is neither representable nor halfway between two
+        // representable values, so the general algorithm below terminates.
+        if n <= prec {
+            return Float::from_natural_prec_round(Natural::from(10u32).pow(n), prec, rm);
+        }
+    }
+    if exponent <= -i64::exact_from(prec) - 3 {
+        // $|10^x - 1| < 3|x| \leq 3 \cdot 2^{-p-3}$, so $10^x$ rounds like $1 \pm \varepsilon$.
+        let direction = if *x > 0u32 {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+        return round_nudged(Integer::ONE, 0, direction, prec, rm);
+    }
+    let (m, pow) = integer_and_pow(x);
+    ziv_round(prec, rm, |working_prec| {
+        let f = exp_scale(working_prec, exponent + 2);
+        let (ln_10_lo, ln_10_hi) = ln_10_fixed_point_bounds(f);
+        let (y_lo, y_hi) = mul_by_constant_enclosure(&m, pow, &ln_10_lo, &ln_10_hi);
+        let (lo, hi, pow) = exp_enclosure(&y_lo, &y_hi, f);
+        (Integer::from(lo), Integer::from(hi), pow)
+    })
+}
+
+// Computes $10^x$, rounding to `prec` bits.
+fn exp10_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() => (float_nan!(), Ordering::Equal),
+        Float(Infinity { sign: true }) => (float_infinity!(), Ordering::Equal),
+        Float(Infinity { sign: false }) => (float_zero!(), Ordering::Equal),
+        Float(Zero { .. }) => (Float::one_prec(prec), Ordering::Equal),
+        _ => exp10_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes 10 raised to the power of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded power is less than, equal to,
+    /// or greater than the exact power. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 10^x+\epsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |10^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    /// - $f(x,p,m)=\infty$ if $x\geq 2^{61}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,p,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{61}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,p,m)=0.0$ if $x\leq -2^{61}$ and $m$ is not `Ceiling` or `Up`
+    /// - $f(x,p,m)$ is the smallest positive [`Float`] with precision $p$ if $x\leq -2^{61}$ and
+    ///   $m$ is `Ceiling` or `Up`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp10_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::exp10_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with `prec` bits, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.34e3");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1385.457");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        exp10_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes 10 raised to the power of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded power is less than, equal to,
+    /// or greater than the exact power. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 10^x+\epsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |10^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    /// - $f(x,p,m)=\infty$ if $x\geq 2^{61}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,p,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{61}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,p,m)=0.0$ if $x\leq -2^{61}$ and $m$ is not `Ceiling` or `Up`
+    /// - $f(x,p,m)$ is the smallest positive [`Float`] with precision $p$ if $x\leq -2^{61}$ and
+    ///   $m$ is `Ceiling` or `Up`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp10_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp10_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with `prec` bits, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.34e3");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1385.457");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        exp10_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes 10 raised to the power of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 10^x+\epsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |10^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(0.0,p)=1.0$
+    /// - $f(-0.0,p)=1.0$
+    /// - $f(x,p)=\infty$ if $x\geq 2^{61}$
+    /// - $f(x,p)=0.0$ if $x\leq -2^{61}$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec(5);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec(20);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_prec(self, prec: u64) -> (Float, Ordering) {
+        self.exp10_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes 10 raised to the power of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded power is less than, equal to, or greater than the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 10^x+\epsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |10^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(0.0,p)=1.0$
+    /// - $f(-0.0,p)=1.0$
+    /// - $f(x,p)=\infty$ if $x\geq 2^{61}$
+    /// - $f(x,p)=0.0$ if $x\leq -2^{61}$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.exp10_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes 10 raised to the power of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded power is less than, equal to, or greater than the exact power. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 10^x+\epsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |10^x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(0.0,m)=1.0$
+    /// - $f(-0.0,m)=1.0$
+    /// - $f(x,m)=\infty$ if $x\geq 2^{61}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{61}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,m)=0.0$ if $x\leq -2^{61}$ and $m$ is not `Ceiling` or `Up`
+    /// - $f(x,m)$ is the smallest positive [`Float`] with precision $p$ if $x\leq -2^{61}$ and $m$
+    ///   is `Ceiling` or `Up`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp10_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1385.4557313670109");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp10_prec_round(prec, rm)
+    }
+
+    /// Computes 10 raised to the power of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 10^x+\epsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |10^x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(0.0,m)=1.0$
+    /// - $f(-0.0,m)=1.0$
+    /// - $f(x,m)=\infty$ if $x\geq 2^{61}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{61}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,m)=0.0$ if $x\leq -2^{61}$ and $m$ is not `Ceiling` or `Up`
+    /// - $f(x,m)$ is the smallest positive [`Float`] with precision $p$ if $x\leq -2^{61}$ and $m$
+    ///   is `Ceiling` or `Up`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp10_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1385.4557313670109");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp10_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with 10 raised to its power, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded power is less than, equal to, or greater than the exact power. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets 10^x+\epsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |10^x|\rfloor-p}$.
+    ///
+    /// See the [`Float::exp10_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp10_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp10_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with `prec` bits, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp10_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.34e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp10_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.4e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp10_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.4e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp10_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1385.455");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp10_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1385.457");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp10_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// ```
+    #[inline]
+    pub fn exp10_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (exp10, o) = exp10_prec_round_ref_helper(self, prec, rm);
+        *self = exp10;
+        o
+    }
+
+    /// Replaces a [`Float`] with 10 raised to its power, rounding the result to the nearest value
+    /// of the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// power is less than, equal to, or greater than the exact power. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets 10^x+\epsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |10^x|\rfloor-p}$.
+    ///
+    /// See the [`Float::exp10_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// ```
+    #[inline]
+    pub fn exp10_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.exp10_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with 10 raised to its power, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded power is less
+    /// than, equal to, or greater than the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets 10^x+\epsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |10^x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::exp10_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::exp10_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.exp10_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1385.4557313670109");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    /// ```
+    #[inline]
+    pub fn exp10_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.exp10_prec_round_assign(prec, rm)
+    }
+}
@@ -0,0 +1,682 @@
+use crate::arithmetic::exp::{
+    exp_enclosure, exp_scale, integer_and_pow, overflow, round_nudged, to_fixed_point, ziv_round,
+};
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, Float};
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::{NegativeOne, One};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+// Computes $e^x-1$, where $x$ is finite and nonzero, rounding to `prec` bits.
+fn expm1_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    if *x > 0u32 && exponent >= 63 {
+        // $x \geq 2^{62}$, so $e^x-1$ is treated as overflowing.
+        assert_ne!(rm, RoundingMode::Exact, "Inexact expm1");
+        return overflow(true, prec, rm);
+    }
+    if *x < 0u32 && (exponent >= 63 || *x <= -i64::exact_from(prec + 2)) {
+        // $0 < e^x < 2^{-p-2}$, so $e^x-1$ rounds like $-1 + \varepsilon$.
+        return round_nudged(Integer::NEGATIVE_ONE, 0, Ordering::Greater, prec, rm);
+    }
+    let (m, pow) = integer_and_pow(x);
+    if exponent < -i64::exact_from(m.significant_bits().max(prec + 2)) {
+        // $x < e^x-1 < x + x^2$, and $x^2$ is too small to affect the rounding.
+        return round_nudged(m, pow, Ordering::Greater, prec, rm);
+    }
+    ziv_round(prec, rm, |working_prec| {
+        // $e^x-1$ is about as large as $x$ when $x$ is small, so $e^x$ needs extra precision.
+        let f = exp_scale(
+            working_prec + u64::exact_from((-exponent).max(0)) + 2,
+            exponent,
+        );
+        let (lo, hi, pow) = exp_enclosure(
+            &to_fixed_point(&m, pow, f, RoundingMode::Floor),
+            &to_fixed_point(&m, pow, f, RoundingMode::Ceiling),
+            f,
+        );
+        let (lo, hi) = (Integer::from(lo), Integer::from(hi));
+        if pow <= 0 {
+            let one = Integer::power_of_2(pow.unsigned_abs());
+            (lo - &one, hi - one, pow)
+        } else {
+            // Subtracting 1 moves the lower bound by less than $2^p$.
+            (lo - Integer::ONE, hi, pow)
+        }
+    })
+}
+
+// Computes $e^x-1$, rounding to `prec` bits.
+fn expm1_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() => (float_nan!(), Ordering::Equal),
+        Float(Infinity { sign: true }) => (float_infinity!(), Ordering::Equal),
+        Float(Infinity { sign: false }) => (Float::negative_one_prec(prec), Ordering::Equal),
+        Float(Zero { .. }) => (x.clone(), Ordering::Equal),
+        _ => expm1_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x-1+\epsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,p,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::expm1_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::expm1_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "22.14072");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn expm1_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        expm1_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x-1+\epsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,p,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::expm1_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::expm1_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "22.14072");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn expm1_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        expm1_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result to the nearest value
+    /// of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x-1+\epsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-1.0$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\infty$ if $x\geq 2^{62}$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec(5);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec(20);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn expm1_prec(self, prec: u64) -> (Float, Ordering) {
+        self.expm1_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result to the nearest value
+    /// of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x-1+\epsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-1.0$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\infty$ if $x\geq 2^{62}$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_ref(5);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_ref(20);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn expm1_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.expm1_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x-1+\epsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-1.0$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::expm1_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.140692632779263");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn expm1_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.expm1_prec_round(prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x-1+\epsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-1.0$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::expm1_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.140692632779263");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn expm1_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.expm1_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its exponential minus 1, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets e^x-1+\epsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// See the [`Float::expm1_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::expm1_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::expm1_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.expm1_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.expm1_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.expm1_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.expm1_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "22.14069");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.expm1_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "22.14072");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.expm1_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// ```
+    #[inline]
+    pub fn expm1_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (expm1, o) = expm1_prec_round_ref_helper(self, prec, rm);
+        *self = expm1;
+        o
+    }
+
+    /// Replaces a [`Float`] with its exponential minus 1, rounding the result to the nearest value
+    /// of the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// result is less than, equal to, or greater than the exact result. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets e^x-1+\epsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// See the [`Float::expm1_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// ```
+    #[inline]
+    pub fn expm1_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.expm1_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its exponential minus 1, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets e^x-1+\epsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::expm1_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::expm1_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "22.140692632779263");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.expm1_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.expm1_round_assign(RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    /// ```
+    #[inline]
+    pub fn expm1_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.expm1_prec_round_assign(prec, rm)
+    }
+}
@@ -0,0 +1,839 @@
+use crate::arithmetic::exp::{integer_and_pow, ziv_round};
+use crate::constants::ln_2::ln_2_fixed_point_bounds;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_negative_infinity, float_zero, Float};
+use malachite_base::num::arithmetic::traits::{DivRound, PowerOf2, ShrRound, Square, UnsignedAbs};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+
+// Returns a lower or upper bound, depending on whether `rm` is `Floor` or `Ceiling`, for
+// $\operatorname{artanh}(t2^{-f})2^f$, where $0 \leq t2^{-f} \leq 1/3$.
+fn atanh_fixed_point(t: &Natural, f: u64, rm: RoundingMode) -> Natural {
+    let t_squared = t.square().shr_round(f, rm).0;
+    let mut power = t.clone();
+    let mut sum = t.clone();
+    let mut denominator = 1u64;
+    loop {
+        denominator += 2;
+        power = (power * &t_squared).shr_round(f, rm).0;
+        let term = (&power).div_round(Natural::from(denominator), rm).0;
+        if rm == RoundingMode::Floor {
+            if power == 0u32 {
+                break;
+            }
+            sum += term;
+        } else {
+            sum += term;
+            if power <= 1u32 {
+                // Since $t^2 \leq 1/9$, the remaining terms sum to less than 1.
+                sum += Natural::ONE;
+                break;
+            }
+        }
+    }
+    sum
+}
+
+// Returns a lower or upper bound, depending on whether `rm` is `Floor` or `Ceiling`, for
+// $2\operatorname{artanh}(2^f/d)$, where $d \geq 3$.
+fn atanh_reciprocal_times_2_fixed_point(d: u32, f: u64, rm: RoundingMode) -> Natural {
+    atanh_fixed_point(
+        &Natural::power_of_2(f).div_round(Natural::from(d), rm).0,
+        f,
+        rm,
+    ) << 1
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq \log 10 \leq$ `hi`$2^{-f}$, using
+// $\log 10 = 3\log 2 + 2\operatorname{artanh}(1/9)$.
+pub(crate) fn ln_10_fixed_point_bounds(f: u64) -> (Natural, Natural) {
+    let (ln_2_lo, ln_2_hi) = ln_2_fixed_point_bounds(f);
+    (
+        ln_2_lo * Natural::from(3u32)
+            + atanh_reciprocal_times_2_fixed_point(9, f, RoundingMode::Floor),
+        ln_2_hi * Natural::from(3u32)
+            + atanh_reciprocal_times_2_fixed_point(9, f, RoundingMode::Ceiling),
+    )
+}
+
+// Given an enclosure `lo`$2^{-f} \leq a \leq$ `hi`$2^{-f}$ and an enclosure
+// `c_lo`$2^{-f} \leq c \leq$ `c_hi`$2^{-f}$ with `c_lo` positive, returns an enclosure of $a/c$
+// with the same scale.
+pub(crate) fn div_enclosure_by_positive(
+    lo: Integer,
+    hi: Integer,
+    c_lo: &Natural,
+    c_hi: &Natural,
+    f: u64,
+) -> (Integer, Integer) {
+    let (c_lo, c_hi) = (Integer::from(c_lo), Integer::from(c_hi));
+    let lo_divisor = if lo >= 0 { &c_hi } else { &c_lo };
+    let hi_divisor = if hi >= 0 { &c_lo } else { &c_hi };
+    (
+        (lo << f).div_round(lo_divisor, RoundingMode::Floor).0,
+        (hi << f).div_round(hi_divisor, RoundingMode::Ceiling).0,
+    )
+}
+
+// An enclosure of $\log x$, split as $\log x = k\log 2 + a$, with
+// `a_lo`$2^{-f} \leq a \leq$ `a_hi`$2^{-f}$.
+pub(crate) struct LnEnclosure {
+    pub(crate) k: i64,
+    pub(crate) a_lo: Integer,
+    pub(crate) a_hi: Integer,
+    pub(crate) f: u64,
+}
+
+impl LnEnclosure {
+    // Returns an enclosure of $\log x$ itself, with scale `f`.
+    pub(crate) fn ln(self) -> (Integer, Integer) {
+        let (ln_2_lo, ln_2_hi) = ln_2_fixed_point_bounds(self.f);
+        let (ln_2_lo, ln_2_hi) = (Integer::from(ln_2_lo), Integer::from(ln_2_hi));
+        let k = Integer::from(self.k);
+        if k >= 0 {
+            (self.a_lo + &k * ln_2_lo, self.a_hi + k * ln_2_hi)
+        } else {
+            (self.a_lo + &k * ln_2_hi, self.a_hi + k * ln_2_lo)
+        }
+    }
+}
+
+// Returns an enclosure of $\log x$, where $x = m2^p$ and $m$ is positive and $x \neq 1$, whose
+// width is roughly $2^{-w}|\log x|$.
+//
+// $x$ is written as $y2^k$, with $3/4 \leq y < 3/2$, and $\log y$ is computed as
+// $2\operatorname{artanh}((y-1)/(y+1))$, where $|(y-1)/(y+1)| \leq 1/5$.
+pub(crate) fn ln_enclosure(m: &Natural, pow: i64, working_prec: u64) -> LnEnclosure {
+    let bits = m.significant_bits();
+    // If the two highest bits of $m$ are set, $y = m2^{-b} \in [3/4, 1)$, where $b$ is the bit
+    // length of $m$; otherwise, $y = m2^{1-b} \in [1, 3/2)$.
+    let y_shift = if bits > 1 && m.get_bit(bits - 2) {
+        bits
+    } else {
+        bits - 1
+    };
+    let k = pow + i64::exact_from(y_shift);
+    let power = Natural::power_of_2(y_shift);
+    let numerator = Integer::from(m) - Integer::from(&power);
+    let denominator = Integer::from(m + power);
+    let f = if k == 0 {
+        // $\log x = \log y$ is close to $y - 1$, which has exponent `numerator.significant_bits() -
+        // y_shift`.
+        working_prec + 4 + y_shift - numerator.significant_bits()
+    } else {
+        working_prec + 4 + k.unsigned_abs().significant_bits()
+    };
+    let z_lo = (&numerator << f)
+        .div_round(&denominator, RoundingMode::Floor)
+        .0;
+    let z_hi = (numerator << f)
+        .div_round(denominator, RoundingMode::Ceiling)
+        .0;
+    let (a_lo, a_hi) = if z_lo >= 0 {
+        (
+            Integer::from(atanh_fixed_point(
+                z_lo.unsigned_abs_ref(),
+                f,
+                RoundingMode::Floor,
+            )),
+            Integer::from(atanh_fixed_point(
+                z_hi.unsigned_abs_ref(),
+                f,
+                RoundingMode::Ceiling,
+            )),
+        )
+    } else if z_hi <= 0 {
+        (
+            -Integer::from(atanh_fixed_point(
+                z_lo.unsigned_abs_ref(),
+                f,
+                RoundingMode::Ceiling,
+            )),
+            -Integer::from(atanh_fixed_point(
+                z_hi.unsigned_abs_ref(),
+                f,
+                RoundingMode::Floor,
+            )),
+        )
+    } else {
+        (
+            -Integer::from(atanh_fixed_point(
+                z_lo.unsigned_abs_ref(),
+                f,
+                RoundingMode::Ceiling,
+            )),
+            Integer::from(atanh_fixed_point(
+                z_hi.unsigned_abs_ref(),
+                f,
+                RoundingMode::Ceiling,
+            )),
+        )
+    };
+    LnEnclosure {
+        k,
+        a_lo: a_lo << 1,
+        a_hi: a_hi << 1,
+        f,
+    }
+}
+
+// Given bounds `lo` $\leq m \leq$ `hi` on a positive $m$, returns `lo`, `hi` and $f$ such that
+// `lo`$2^{-f} \leq \log(m2^p) \leq$ `hi`$2^{-f}$, with a relative precision of about
+// `working_prec` bits at each end.
+pub(crate) fn ln_interval_enclosure(
+    lo: &Natural,
+    hi: &Natural,
+    pow: i64,
+    working_prec: u64,
+) -> (Integer, Integer, u64) {
+    let lo_enclosure = ln_enclosure(lo, pow, working_prec);
+    let hi_enclosure = ln_enclosure(hi, pow, working_prec);
+    let (lo_f, hi_f) = (lo_enclosure.f, hi_enclosure.f);
+    let f = max(lo_f, hi_f);
+    (
+        lo_enclosure.ln().0 << (f - lo_f),
+        hi_enclosure.ln().1 << (f - hi_f),
+        f,
+    )
+}
+
+// Computes $\log x$, where $x$ is positive, finite, and not equal to 1, rounding to `prec` bits.
+fn ln_positive(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let (m, pow) = integer_and_pow(x);
+    let m = m.unsigned_abs();
+    ziv_round(prec, rm, |working_prec| {
+        let enclosure = ln_enclosure(&m, pow, working_prec);
+        let f = enclosure.f;
+        let (lo, hi) = enclosure.ln();
+        (lo, hi, -i64::exact_from(f))
+    })
+}
+
+// Computes $\log x$, rounding to `prec` bits.
+fn ln_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { sign: false } | Finite { sign: false, .. }) => {
+            (float_nan!(), Ordering::Equal)
+        }
+        Float(Infinity { sign: true }) => (float_infinity!(), Ordering::Equal),
+        Float(Zero { .. }) => (float_negative_infinity!(), Ordering::Equal),
+        _ if *x == 1u32 => (float_zero!(), Ordering::Equal),
+        _ => ln_positive(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln x+\epsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=-\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.144732");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        ln_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln x+\epsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=-\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec_ref`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.144732");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        ln_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln x+\epsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=-\infty$
+    /// - $f(-0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec(5);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec(20);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec(self, prec: u64) -> (Float, Ordering) {
+        self.ln_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded logarithm is less than, equal to, or greater than
+    /// the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln x+\epsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=-\infty$
+    /// - $f(-0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.ln_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln x+\epsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\ln x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=-\infty$
+    /// - $f(-0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.1447298858493999");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn ln_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_prec_round(prec, rm)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln x+\epsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\ln x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=-\infty$
+    /// - $f(-0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.1447298858493999");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn ln_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its natural logarithm, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \ln x+\epsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\ln x|\rfloor-p}$.
+    ///
+    /// See the [`Float::ln_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.19");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.14473");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.144732");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// ```
+    #[inline]
+    pub fn ln_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (ln, o) = ln_prec_round_ref_helper(self, prec, rm);
+        *self = ln;
+        o
+    }
+
+    /// Replaces a [`Float`] with its natural logarithm, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// logarithm is less than, equal to, or greater than the exact logarithm. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \ln x+\epsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\ln x|\rfloor-p}$.
+    ///
+    /// See the [`Float::ln_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// ```
+    #[inline]
+    pub fn ln_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.ln_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its natural logarithm, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded logarithm is less
+    /// than, equal to, or greater than the exact logarithm. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \ln x+\epsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\ln x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::ln_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.1447298858493999");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    /// ```
+    #[inline]
+    pub fn ln_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.ln_prec_round_assign(prec, rm)
+    }
+}
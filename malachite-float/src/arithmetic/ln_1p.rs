@@ -0,0 +1,681 @@
+use crate::arithmetic::exp::{integer_and_pow, round_nudged, ziv_round};
+use crate::arithmetic::ln::ln_enclosure;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, float_negative_infinity, Float};
+use malachite_base::num::arithmetic::traits::{PowerOf2, UnsignedAbs};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+// Computes $\log(1+x)$, where $x$ is finite, nonzero, and greater than $-1$, rounding to `prec`
+// bits.
+fn ln_1p_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    let (m, pow) = integer_and_pow(x);
+    if exponent < -i64::exact_from(m.significant_bits().max(prec + 2)) {
+        // $x - x^2 < \log(1+x) < x$, and $x^2$ is too small to affect the rounding.
+        return round_nudged(m, pow, Ordering::Less, prec, rm);
+    }
+    ziv_round(prec, rm, |working_prec| {
+        if exponent > i64::exact_from(working_prec) + 8 {
+            // $\log x < \log(1+x) < \log x + 2^{1-e}$, where $e$ is the exponent of $x$. Computing
+            // $1+x$ exactly could take a huge number of bits.
+            let enclosure = ln_enclosure(m.unsigned_abs_ref(), pow, working_prec);
+            let f = enclosure.f;
+            let (lo, mut hi) = enclosure.ln();
+            let shift = i64::exact_from(f) + 1 - exponent;
+            if shift >= 0 {
+                hi += Integer::power_of_2(shift.unsigned_abs());
+            } else {
+                hi += Integer::ONE;
+            }
+            (lo, hi, -i64::exact_from(f))
+        } else {
+            // $1+x$ is computed exactly.
+            let (y, y_pow) = if pow >= 0 {
+                ((&m << pow) + Integer::ONE, 0)
+            } else {
+                (&m + Integer::power_of_2(pow.unsigned_abs()), pow)
+            };
+            let enclosure = ln_enclosure(&y.unsigned_abs(), y_pow, working_prec);
+            let f = enclosure.f;
+            let (lo, hi) = enclosure.ln();
+            (lo, hi, -i64::exact_from(f))
+        }
+    })
+}
+
+// Computes $\log(1+x)$, rounding to `prec` bits.
+fn ln_1p_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { sign: false }) => (float_nan!(), Ordering::Equal),
+        Float(Infinity { sign: true } | Zero { .. }) => (x.clone(), Ordering::Equal),
+        _ if *x < -1i32 => (float_nan!(), Ordering::Equal),
+        _ if *x == -1i32 => (float_negative_infinity!(), Ordering::Equal),
+        _ => ln_1p_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln(1+x)+\epsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_1p_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_1p_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.38");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.421082");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        ln_1p_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln(1+x)+\epsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_1p_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_1p_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.38");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.421082");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        ln_1p_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded logarithm is less than, equal to, or greater than
+    /// the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln(1+x)+\epsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(-1.0,p)=-\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec(5);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec(20);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec(self, prec: u64) -> (Float, Ordering) {
+        self.ln_1p_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded logarithm is less than, equal to, or greater
+    /// than the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln(1+x)+\epsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(-1.0,p)=-\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.ln_1p_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln(1+x)+\epsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(-1.0,m)=-\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_1p_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.4210804127942924");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn ln_1p_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_1p_prec_round(prec, rm)
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln(1+x)+\epsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(-1.0,m)=-\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_1p_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.4210804127942924");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn ln_1p_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_1p_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with the natural logarithm of 1 plus itself, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \ln(1+x)+\epsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// See the [`Float::ln_1p_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_1p_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_1p_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_1p_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.38");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_1p_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_1p_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_1p_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.42108");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_1p_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.421082");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_1p_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (ln_1p, o) = ln_1p_prec_round_ref_helper(self, prec, rm);
+        *self = ln_1p;
+        o
+    }
+
+    /// Replaces a [`Float`] with the natural logarithm of 1 plus itself, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded logarithm is less than, equal to, or greater than the exact logarithm. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \ln(1+x)+\epsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// See the [`Float::ln_1p_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.ln_1p_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with the natural logarithm of 1 plus itself, rounding the result with
+    /// the specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// logarithm is less than, equal to, or greater than the exact logarithm. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \ln(1+x)+\epsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::ln_1p_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::ln_1p_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.4210804127942924");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_1p_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.ln_1p_round_assign(RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    /// ```
+    #[inline]
+    pub fn ln_1p_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.ln_1p_prec_round_assign(prec, rm)
+    }
+}
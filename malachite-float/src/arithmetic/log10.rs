@@ -0,0 +1,687 @@
+use crate::arithmetic::exp::{integer_and_pow, ziv_round};
+use crate::arithmetic::ln::{div_enclosure_by_positive, ln_10_fixed_point_bounds, ln_enclosure};
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_negative_infinity, Float};
+use malachite_base::num::arithmetic::traits::{Pow, UnsignedAbs};
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// If $x = m2^p$, with $m$ positive, is $10^n$ for some nonnegative integer $n$, returns $n$.
+fn power_of_10_exponent(m: &Natural, pow: i64) -> Option<u64> {
+    let zeros = m.trailing_zeros().unwrap();
+    let twos = u64::exact_from(pow + i64::exact_from(zeros));
+    let odd = m >> zeros;
+    // $5^n$ has more than $2n$ bits, so larger $n$ can be ruled out without computing $5^n$.
+    if twos.checked_mul(2)? >= odd.significant_bits() {
+        return None;
+    }
+    if odd == Natural::from(5u32).pow(twos) {
+        Some(twos)
+    } else {
+        None
+    }
+}
+
+// Computes $\log_{10} x$, where $x$ is positive and finite, rounding to `prec` bits.
+fn log10_positive(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let (m, pow) = integer_and_pow(x);
+    let m = m.unsigned_abs();
+    if x.is_integer() {
+        if let Some(n) = power_of_10_exponent(&m, pow) {
+            return Float::from_unsigned_prec_round(n, prec, rm);
+        }
+    }
+    ziv_round(prec, rm, |working_prec| {
+        let enclosure = ln_enclosure(&m, pow, working_prec);
+        let f = enclosure.f;
+        let (lo, hi) = enclosure.ln();
+        let (ln_10_lo, ln_10_hi) = ln_10_fixed_point_bounds(f);
+        let (lo, hi) = div_enclosure_by_positive(lo, hi, &ln_10_lo, &ln_10_hi, f);
+        (lo, hi, -i64::exact_from(f))
+    })
+}
+
+// Computes $\log_{10} x$, rounding to `prec` bits.
+fn log10_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { sign: false } | Finite { sign: false, .. }) => {
+            (float_nan!(), Ordering::Equal)
+        }
+        Float(Infinity { sign: true }) => (float_infinity!(), Ordering::Equal),
+        Float(Zero { .. }) => (float_negative_infinity!(), Ordering::Equal),
+        _ => log10_positive(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log_{10} x+\epsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=-\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log10_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::log10_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.48");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4971495");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        log10_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log_{10} x+\epsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=-\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log10_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::log10_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.48");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4971495");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        log10_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log_{10} x+\epsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=-\infty$
+    /// - $f(-0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec(5);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec(20);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_prec(self, prec: u64) -> (Float, Ordering) {
+        self.log10_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded logarithm is less than, equal to, or greater than
+    /// the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log_{10} x+\epsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=-\infty$
+    /// - $f(-0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_ref(5);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.log10_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log_{10} x+\epsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=-\infty$
+    /// - $f(-0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::log10_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log10_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4971498726941338");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.log10_prec_round(prec, rm)
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log_{10} x+\epsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=-\infty$
+    /// - $f(-0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::log10_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log10_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4971498726941338");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.log10_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its base-10 logarithm, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \log_{10} x+\epsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    ///
+    /// See the [`Float::log10_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log10_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::log10_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log10_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.48");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log10_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log10_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log10_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.4971495");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log10_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.4971499");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log10_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// ```
+    #[inline]
+    pub fn log10_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (log10, o) = log10_prec_round_ref_helper(self, prec, rm);
+        *self = log10;
+        o
+    }
+
+    /// Replaces a [`Float`] with its base-10 logarithm, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// logarithm is less than, equal to, or greater than the exact logarithm. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \log_{10} x+\epsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$.
+    ///
+    /// See the [`Float::log10_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// ```
+    #[inline]
+    pub fn log10_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.log10_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its base-10 logarithm, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded logarithm is less
+    /// than, equal to, or greater than the exact logarithm. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \log_{10} x+\epsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::log10_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::log10_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.4971498726941338");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log10_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log10_round_assign(RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    /// ```
+    #[inline]
+    pub fn log10_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.log10_prec_round_assign(prec, rm)
+    }
+}
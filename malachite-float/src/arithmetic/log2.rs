@@ -0,0 +1,661 @@
+use crate::arithmetic::exp::{integer_and_pow, ziv_round};
+use crate::arithmetic::ln::{div_enclosure_by_positive, ln_enclosure};
+use crate::constants::ln_2::ln_2_fixed_point_bounds;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_negative_infinity, Float};
+use malachite_base::num::arithmetic::traits::{IsPowerOf2, UnsignedAbs};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+// Computes $\log_2 x$, where $x$ is positive, finite, and not a power of 2, rounding to `prec`
+// bits.
+fn log2_positive(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let (m, pow) = integer_and_pow(x);
+    let m = m.unsigned_abs();
+    ziv_round(prec, rm, |working_prec| {
+        // $\log_2 x = k + a/\log 2$, where $\log x = k\log 2 + a$.
+        let enclosure = ln_enclosure(&m, pow, working_prec);
+        let f = enclosure.f;
+        let (ln_2_lo, ln_2_hi) = ln_2_fixed_point_bounds(f);
+        let (lo, hi) =
+            div_enclosure_by_positive(enclosure.a_lo, enclosure.a_hi, &ln_2_lo, &ln_2_hi, f);
+        let k = Integer::from(enclosure.k) << f;
+        (lo + &k, hi + k, -i64::exact_from(f))
+    })
+}
+
+// Computes $\log_2 x$, rounding to `prec` bits.
+fn log2_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { sign: false } | Finite { sign: false, .. }) => {
+            (float_nan!(), Ordering::Equal)
+        }
+        Float(Infinity { sign: true }) => (float_infinity!(), Ordering::Equal),
+        Float(Zero { .. }) => (float_negative_infinity!(), Ordering::Equal),
+        _ if x.is_power_of_2() => {
+            Float::from_signed_prec_round(x.get_exponent().unwrap() - 1, prec, rm)
+        }
+        _ => log2_positive(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the base-2 logarithm of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded logarithm is less than, equal to, or greater
+    /// than the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log_2 x+\epsilon.
+    /// $$
+    /// - If $\log_2 x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p+1}$.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=-\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log2_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::log2_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.62");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.7");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.62");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.651495");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.651497");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.651497");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log2_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        log2_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the base-2 logarithm of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded logarithm is less than, equal to, or
+    /// greater than the exact logarithm. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log_2 x+\epsilon.
+    /// $$
+    /// - If $\log_2 x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p+1}$.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=-\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log2_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::log2_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.62");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.7");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.62");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.651495");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.651497");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.651497");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log2_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        log2_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the base-2 logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log_2 x+\epsilon.
+    /// $$
+    /// - If $\log_2 x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_2 x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\log_2
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=-\infty$
+    /// - $f(-0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log2_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec(5);
+    /// assert_eq!(x.to_string(), "1.62");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec(20);
+    /// assert_eq!(x.to_string(), "1.651497");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log2_prec(self, prec: u64) -> (Float, Ordering) {
+        self.log2_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the base-2 logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded logarithm is less than, equal to, or greater than
+    /// the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log_2 x+\epsilon.
+    /// $$
+    /// - If $\log_2 x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_2 x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\log_2
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=-\infty$
+    /// - $f(-0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log2_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.62");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log2_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.651497");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log2_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.log2_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the base-2 logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log_2 x+\epsilon.
+    /// $$
+    /// - If $\log_2 x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p+1}$.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=-\infty$
+    /// - $f(-0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::log2_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log2_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.6514961294723187");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log2_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.6514961294723189");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log2_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.6514961294723187");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn log2_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.log2_prec_round(prec, rm)
+    }
+
+    /// Computes the base-2 logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log_2 x+\epsilon.
+    /// $$
+    /// - If $\log_2 x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p+1}$.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=-\infty$
+    /// - $f(-0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::log2_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).log2_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.6514961294723187");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log2_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.6514961294723189");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log2_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.6514961294723187");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn log2_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.log2_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its base-2 logarithm, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \log_2 x+\epsilon.
+    /// $$
+    /// - If $\log_2 x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p+1}$.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p}$.
+    ///
+    /// See the [`Float::log2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log2_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::log2_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with `prec` bits,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log2_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.62");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log2_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.7");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log2_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.62");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log2_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.651495");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log2_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.651497");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log2_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.651497");
+    /// ```
+    #[inline]
+    pub fn log2_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (log2, o) = log2_prec_round_ref_helper(self, prec, rm);
+        *self = log2;
+        o
+    }
+
+    /// Replaces a [`Float`] with its base-2 logarithm, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// logarithm is less than, equal to, or greater than the exact logarithm. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \log_2 x+\epsilon.
+    /// $$
+    /// - If $\log_2 x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_2 x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\log_2
+    ///   x|\rfloor-p}$.
+    ///
+    /// See the [`Float::log2_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log2_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log2_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.62");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log2_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.651497");
+    /// ```
+    #[inline]
+    pub fn log2_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.log2_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its base-2 logarithm, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded logarithm is less
+    /// than, equal to, or greater than the exact logarithm. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \log_2 x+\epsilon.
+    /// $$
+    /// - If $\log_2 x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p+1}$.
+    /// - If $\log_2 x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\log_2 x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::log2_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::log2_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log2_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.6514961294723187");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.log2_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.6514961294723189");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log2_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.6514961294723187");
+    /// ```
+    #[inline]
+    pub fn log2_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.log2_prec_round_assign(prec, rm)
+    }
+}
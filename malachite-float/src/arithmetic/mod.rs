@@ -1,13 +1,88 @@
 /// Absolute value of [`Float`](super::Float)s.
 pub mod abs;
+/// Arccosines of [`Float`](super::Float)s.
+pub mod acos;
+/// Inverse hyperbolic cosines of [`Float`](super::Float)s.
+pub mod acosh;
+/// Addition of [`Float`](super::Float)s, and of [`Float`](super::Float)s with
+/// [`Rational`](malachite_q::Rational)s.
+pub mod add;
+/// Arcsines of [`Float`](super::Float)s.
+pub mod asin;
+/// Inverse hyperbolic sines of [`Float`](super::Float)s.
+pub mod asinh;
+/// Arctangents of [`Float`](super::Float)s.
+pub mod atan;
+/// Two-argument arctangents of [`Float`](super::Float)s.
+pub mod atan2;
+/// Inverse hyperbolic tangents of [`Float`](super::Float)s.
+pub mod atanh;
+/// Cosines of [`Float`](super::Float)s.
+pub mod cos;
+/// Hyperbolic cosines of [`Float`](super::Float)s.
+pub mod cosh;
+/// Division of [`Float`](super::Float)s, of [`Float`](super::Float)s by
+/// [`Rational`](malachite_q::Rational)s, and of [`Rational`](malachite_q::Rational)s by
+/// [`Float`](super::Float)s.
+pub mod div;
+/// Exponentials of [`Float`](super::Float)s.
+pub mod exp;
+/// Powers of 10 with [`Float`](super::Float) exponents.
+pub mod exp10;
+/// Powers of 2 with [`Float`](super::Float) exponents.
+pub mod exp2;
+/// Exponentials of [`Float`](super::Float)s, minus 1.
+pub mod expm1;
 /// An implementations of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a
 /// trait for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
+/// Natural logarithms of [`Float`](super::Float)s.
+pub mod ln;
+/// Natural logarithms of 1 plus [`Float`](super::Float)s.
+pub mod ln_1p;
+/// Base-10 logarithms of [`Float`](super::Float)s.
+pub mod log10;
+/// Base-2 logarithms of [`Float`](super::Float)s.
+pub mod log2;
+/// Multiplication of [`Float`](super::Float)s, and of [`Float`](super::Float)s with
+/// [`Rational`](malachite_q::Rational)s.
+pub mod mul;
+/// Fused multiply-adds of [`Float`](super::Float)s, and implementations of
+/// [`AddMul`](malachite_base::num::arithmetic::traits::AddMul) and
+/// [`AddMulAssign`](malachite_base::num::arithmetic::traits::AddMulAssign).
+pub mod mul_add;
 /// Negation of [`Float`](super::Float)s.
 pub mod neg;
 /// Implementations of [`PowerOf2`](malachite_base::num::arithmetic::traits::PowerOf2), a trait for
 /// computing a power of 2.
 pub mod power_of_2;
+/// Implementations of [`Reciprocal`](malachite_base::num::arithmetic::traits::Reciprocal) and
+/// [`ReciprocalAssign`](malachite_base::num::arithmetic::traits::ReciprocalAssign), traits for
+/// computing the reciprocal of a number.
+pub mod reciprocal;
+/// Reciprocal square roots of [`Float`](super::Float)s.
+pub mod reciprocal_sqrt;
+/// $n$th roots of [`Float`](super::Float)s.
+pub mod root;
 /// An implementation of [`Sign`](malachite_base::num::arithmetic::traits::Sign), a trait for
 /// determining the sign of a number.
 pub mod sign;
+/// Sines of [`Float`](super::Float)s.
+pub mod sin;
+/// Simultaneous sines and cosines of [`Float`](super::Float)s.
+pub mod sin_cos;
+/// Hyperbolic sines of [`Float`](super::Float)s.
+pub mod sinh;
+/// Implementations of [`Sqrt`](malachite_base::num::arithmetic::traits::Sqrt) and
+/// [`SqrtAssign`](malachite_base::num::arithmetic::traits::SqrtAssign), traits for computing the
+/// square root of a number.
+pub mod sqrt;
+/// Subtraction of [`Float`](super::Float)s, and of [`Float`](super::Float)s with
+/// [`Rational`](malachite_q::Rational)s.
+pub mod sub;
+/// Correctly-rounded sums and dot products of sequences of [`Float`](super::Float)s.
+pub mod sum;
+/// Tangents of [`Float`](super::Float)s.
+pub mod tan;
+/// Hyperbolic tangents of [`Float`](super::Float)s.
+pub mod tanh;
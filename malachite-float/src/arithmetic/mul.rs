@@ -0,0 +1,5647 @@
+use crate::arithmetic::div::div_naturals_times_powers_of_2_prec_round;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_either_zero, float_nan, significand_bits, Float};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::ops::{Mul, MulAssign};
+
+// Multiplies `x` and `y`, rounding to `prec` bits.
+//
+// The significands are multiplied exactly, using `Natural` multiplication (and therefore its
+// Toom-Cook and FFT algorithms), and the product is rounded once at the end.
+pub(crate) fn mul_prec_round_ref_ref_helper(
+    x: &Float,
+    y: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (float_nan!(), _)
+        | (_, float_nan!())
+        | (Float(Infinity { .. }), float_either_zero!())
+        | (float_either_zero!(), Float(Infinity { .. })) => (float_nan!(), Ordering::Equal),
+        (
+            Float(Infinity { sign: x_sign }),
+            Float(Finite { sign: y_sign, .. } | Infinity { sign: y_sign }),
+        )
+        | (Float(Finite { sign: x_sign, .. }), Float(Infinity { sign: y_sign })) => (
+            Float(Infinity {
+                sign: x_sign == y_sign,
+            }),
+            Ordering::Equal,
+        ),
+        (
+            Float(Zero { sign: x_sign }),
+            Float(Finite { sign: y_sign, .. } | Zero { sign: y_sign }),
+        )
+        | (Float(Finite { sign: x_sign, .. }), Float(Zero { sign: y_sign })) => (
+            Float(Zero {
+                sign: x_sign == y_sign,
+            }),
+            Ordering::Equal,
+        ),
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => Float::from_integer_times_power_of_2_prec_round(
+            Integer::from_sign_and_abs(x_sign == y_sign, x_significand * y_significand),
+            x_exponent + y_exponent
+                - i64::exact_from(
+                    significand_bits(x_significand) + significand_bits(y_significand),
+                ),
+            prec,
+            rm,
+        ),
+    }
+}
+
+// Like `mul_prec_round_ref_ref_helper`, but takes `x` by value, so that its significand can be
+// reused for the product.
+pub(crate) fn mul_prec_round_val_ref_helper(
+    x: Float,
+    y: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            assert_ne!(prec, 0);
+            let pow = x_exponent + y_exponent
+                - i64::exact_from(
+                    significand_bits(&x_significand) + significand_bits(y_significand),
+                );
+            Float::from_integer_times_power_of_2_prec_round(
+                Integer::from_sign_and_abs(x_sign == *y_sign, x_significand * y_significand),
+                pow,
+                prec,
+                rm,
+            )
+        }
+        (x, y) => mul_prec_round_ref_ref_helper(&x, y, prec, rm),
+    }
+}
+
+// Like `mul_prec_round_ref_ref_helper`, but takes both `x` and `y` by value, so that both
+// significands can be reused for the product.
+pub(crate) fn mul_prec_round_val_val_helper(
+    x: Float,
+    y: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            assert_ne!(prec, 0);
+            let pow = x_exponent + y_exponent
+                - i64::exact_from(
+                    significand_bits(&x_significand) + significand_bits(&y_significand),
+                );
+            Float::from_integer_times_power_of_2_prec_round(
+                Integer::from_sign_and_abs(x_sign == y_sign, x_significand * y_significand),
+                pow,
+                prec,
+                rm,
+            )
+        }
+        (x, y) => mul_prec_round_ref_ref_helper(&x, &y, prec, rm),
+    }
+}
+
+// Rounds $p2^e/d$ to `prec` bits, where $p$ is `product`, $e$ is `pow`, and $d$ is `denominator`,
+// giving the result a positive sign if `sign` is `true` and a negative sign otherwise.
+fn round_product_over_denominator(
+    sign: bool,
+    product: Natural,
+    pow: i64,
+    denominator: Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if denominator == 1u32 {
+        Float::from_integer_times_power_of_2_prec_round(
+            Integer::from_sign_and_abs(sign, product),
+            pow,
+            prec,
+            rm,
+        )
+    } else {
+        div_naturals_times_powers_of_2_prec_round(sign, product, pow, denominator, 0, prec, rm)
+    }
+}
+
+// Multiplies `x` by `y`, where `y` is a `Rational`, rounding to `prec` bits. The `Float`'s
+// significand is multiplied by the numerator, and if the denominator is not 1, the result is
+// divided by it.
+pub(crate) fn mul_rational_prec_round_ref_ref_helper(
+    x: &Float,
+    y: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() => (float_nan!(), Ordering::Equal),
+        Float(Infinity { sign }) => {
+            if *y == 0u32 {
+                (float_nan!(), Ordering::Equal)
+            } else {
+                (
+                    Float(Infinity {
+                        sign: *sign == (*y > 0u32),
+                    }),
+                    Ordering::Equal,
+                )
+            }
+        }
+        Float(Zero { sign }) => (
+            Float(Zero {
+                sign: *sign == (*y >= 0u32),
+            }),
+            Ordering::Equal,
+        ),
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) => {
+            if *y == 0u32 {
+                return (Float(Zero { sign: *sign }), Ordering::Equal);
+            }
+            let (numerator, denominator) = y.numerator_and_denominator_ref();
+            round_product_over_denominator(
+                *sign == (*y > 0u32),
+                significand * numerator,
+                exponent - i64::exact_from(significand_bits(significand)),
+                denominator.clone(),
+                prec,
+                rm,
+            )
+        }
+    }
+}
+
+// Like `mul_rational_prec_round_ref_ref_helper`, but takes `x` by value, so that its significand
+// can be reused for the product.
+pub(crate) fn mul_rational_prec_round_val_ref_helper(
+    x: Float,
+    y: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match x {
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) if *y != 0u32 => {
+            assert_ne!(prec, 0);
+            let (numerator, denominator) = y.numerator_and_denominator_ref();
+            let pow = exponent - i64::exact_from(significand_bits(&significand));
+            round_product_over_denominator(
+                sign == (*y > 0u32),
+                significand * numerator,
+                pow,
+                denominator.clone(),
+                prec,
+                rm,
+            )
+        }
+        x => mul_rational_prec_round_ref_ref_helper(&x, y, prec, rm),
+    }
+}
+
+// Like `mul_rational_prec_round_ref_ref_helper`, but takes `y` by value, so that its numerator and
+// denominator can be reused.
+pub(crate) fn mul_rational_prec_round_ref_val_helper(
+    x: &Float,
+    y: Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match x {
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) if y != 0u32 => {
+            assert_ne!(prec, 0);
+            let sign = *sign == (y > 0u32);
+            let (numerator, denominator) = y.into_numerator_and_denominator();
+            round_product_over_denominator(
+                sign,
+                significand * numerator,
+                exponent - i64::exact_from(significand_bits(significand)),
+                denominator,
+                prec,
+                rm,
+            )
+        }
+        _ => mul_rational_prec_round_ref_ref_helper(x, &y, prec, rm),
+    }
+}
+
+// Like `mul_rational_prec_round_ref_ref_helper`, but takes both `x` and `y` by value.
+pub(crate) fn mul_rational_prec_round_val_val_helper(
+    x: Float,
+    y: Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match x {
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) if y != 0u32 => {
+            assert_ne!(prec, 0);
+            let sign = sign == (y > 0u32);
+            let pow = exponent - i64::exact_from(significand_bits(&significand));
+            let (numerator, denominator) = y.into_numerator_and_denominator();
+            round_product_over_denominator(
+                sign,
+                significand * numerator,
+                pow,
+                denominator,
+                prec,
+                rm,
+            )
+        }
+        x => mul_rational_prec_round_ref_val_helper(&x, y, prec, rm),
+    }
+}
+
+impl Float {
+    /// Multiplies two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded product is less than, equal to, or greater than the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\pm\infty,\pm0.0,p,m)=f(\pm0.0,\pm\infty,p,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,p,m)=f(-0.0,-0.0,p,m)=0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::mul_round`] instead. If both of these things are true, consider using `*`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "8.53975");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        mul_prec_round_val_val_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. The first [`Float`] is taken by value and the second by reference.
+    /// An [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\pm\infty,\pm0.0,p,m)=f(\pm0.0,\pm\infty,p,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,p,m)=f(-0.0,-0.0,p,m)=0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::mul_round_val_ref`] instead. If both of these things are true,
+    /// consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "8.53975");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_prec_round_val_ref_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. The first [`Float`] is taken by reference and the second by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\pm\infty,\pm0.0,p,m)=f(\pm0.0,\pm\infty,p,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,p,m)=f(-0.0,-0.0,p,m)=0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::mul_round_ref_val`] instead. If both of these things are true,
+    /// consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_val(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_val(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_val(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_val(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_val(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "8.53975");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_val(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_prec_round_val_ref_helper(other, self, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded product is less than, equal to, or greater than the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=f(\pm\infty,\pm0.0,p,m)=f(\pm0.0,\pm\infty,p,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,p,m)=f(-0.0,-0.0,p,m)=0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::mul_round_ref_ref`] instead. If both of these things are true,
+    /// consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "8.53975");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_prec_round_ref_ref_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the nearest value of the specified
+    /// precision. Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded product is less than, equal to, or greater than the exact product.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\pm\infty,\pm0.0,p)=f(\pm0.0,\pm\infty,p) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,p)=f(-0.0,-0.0,p)=0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round`] instead. If you know that your target precision is the maximum of
+    /// the precisions of the two inputs, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec(Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec(Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.mul_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the nearest value of the specified
+    /// precision. The first [`Float`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\pm\infty,\pm0.0,p)=f(\pm0.0,\pm\infty,p) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,p)=f(-0.0,-0.0,p)=0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round_val_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.mul_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the nearest value of the specified
+    /// precision. The first [`Float`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\pm\infty,\pm0.0,p)=f(\pm0.0,\pm\infty,p) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,p)=f(-0.0,-0.0,p)=0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round_ref_val`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.mul_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the nearest value of the specified
+    /// precision. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded product is less than, equal to, or greater than the exact
+    /// product. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=f(\pm\infty,\pm0.0,p)=f(\pm0.0,\pm\infty,p) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,p)=f(-0.0,-0.0,p)=0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round_ref_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(s.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(s.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.mul_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result with the specified rounding mode. Both
+    /// [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded product is less than, equal to, or greater than the exact product. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\pm\infty,\pm0.0,m)=f(\pm0.0,\pm\infty,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,m)=f(-0.0,-0.0,m)=0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::mul_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `*`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "8.539734222673568");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round(other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result with the specified rounding mode. The first
+    /// [`Float`] is taken by value and the second by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded product is less than, equal to, or greater than the exact
+    /// product. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\pm\infty,\pm0.0,m)=f(\pm0.0,\pm\infty,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,m)=f(-0.0,-0.0,m)=0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::mul_prec_round_val_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `*`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_round_val_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_round_val_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "8.539734222673568");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_round_val_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result with the specified rounding mode. The first
+    /// [`Float`] is taken by reference and the second by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded product is less than, equal to, or greater than the exact
+    /// product. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\pm\infty,\pm0.0,m)=f(\pm0.0,\pm\infty,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,m)=f(-0.0,-0.0,m)=0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::mul_prec_round_ref_val`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `*`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_round_ref_val(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_round_ref_val(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "8.539734222673568");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_round_ref_val(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result with the specified rounding mode. Both
+    /// [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded product is less than, equal to, or greater than the exact product. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=f(\pm\infty,\pm0.0,m)=f(\pm0.0,\pm\infty,m) =
+    ///   \text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0,m)=f(-0.0,-0.0,m)=0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::mul_prec_round_ref_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `*`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "8.539734222673568");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] on the right-hand side is
+    /// taken by value. An [`Ordering`] is returned, indicating whether the rounded product is less
+    /// than, equal to, or greater than the exact product. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// See the [`Float::mul_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec_assign`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::mul_round_assign`] instead. If both of these things are true,
+    /// consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.53973");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "8.53975");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// ```
+    #[inline]
+    pub fn mul_prec_round_assign(&mut self, other: Float, prec: u64, rm: RoundingMode) -> Ordering {
+        let (product, o) = mul_prec_round_val_ref_helper(other, self, prec, rm);
+        *self = product;
+        o
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] on the right-hand side is
+    /// taken by reference. An [`Ordering`] is returned, indicating whether the rounded product is
+    /// less than, equal to, or greater than the exact product. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// See the [`Float::mul_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::mul_round_assign_ref`] instead. If both of these things are
+    /// true, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.53973");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "8.53975");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// ```
+    #[inline]
+    pub fn mul_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (product, o) = mul_prec_round_ref_ref_helper(self, other, prec, rm);
+        *self = product;
+        o
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded product is less than, equal to, or
+    /// greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// See the [`Float::mul_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round_assign`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.mul_prec_assign(Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.mul_prec_assign(Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// ```
+    #[inline]
+    pub fn mul_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.mul_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded product is less than, equal to, or
+    /// greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// See the [`Float::mul_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round_assign_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.mul_prec_assign_ref(&Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.mul_prec_assign_ref(&Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// ```
+    #[inline]
+    pub fn mul_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.mul_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is
+    /// returned, indicating whether the rounded product is less than, equal to, or greater than the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// See the [`Float::mul_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::mul_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `*=`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign(Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign(Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673568");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign(Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// ```
+    #[inline]
+    pub fn mul_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_assign(other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. The [`Float`] on the right-hand side is taken by reference. An [`Ordering`]
+    /// is returned, indicating whether the rounded product is less than, equal to, or greater than
+    /// the exact product. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the maximum precision of the inputs, or 1 if neither input has a precision.
+    ///
+    /// See the [`Float::mul_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::mul_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign_ref(&Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign_ref(&Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673568");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign_ref(&Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// ```
+    #[inline]
+    pub fn mul_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_assign_ref(other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] and the [`Rational`] are both taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded product is less
+    /// than, equal to, or greater than the exact product. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(\pm\infty,0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x>0$
+    /// - $f(\infty,x,p,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,p,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
+    /// - $f(x,0,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_rational_prec`] instead.
+    /// If you know that your target precision is the precision of the [`Float`] input, consider
+    /// using [`Float::mul_rational_round`] instead. If both of these things are true, consider
+    /// using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047199");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec_round(
+        self,
+        other: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_rational_prec_round_val_val_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value and the [`Rational`] by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded product is less
+    /// than, equal to, or greater than the exact product. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(\pm\infty,0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x>0$
+    /// - $f(\infty,x,p,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,p,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
+    /// - $f(x,0,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_rational_prec_val_ref`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::mul_rational_round_val_ref`] instead. If both of these things are
+    /// true, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047199");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_val_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec_round_val_ref(
+        self,
+        other: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_rational_prec_round_val_ref_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference and the [`Rational`]
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded product is less
+    /// than, equal to, or greater than the exact product. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(\pm\infty,0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x>0$
+    /// - $f(\infty,x,p,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,p,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
+    /// - $f(x,0,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_rational_prec_ref_val`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::mul_rational_round_ref_val`] instead. If both of these things are
+    /// true, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047199");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_val(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec_round_ref_val(
+        &self,
+        other: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_rational_prec_round_ref_val_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] and the [`Rational`] are both taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded product is less
+    /// than, equal to, or greater than the exact product. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(\pm\infty,0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x>0$
+    /// - $f(\infty,x,p,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,p,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
+    /// - $f(x,0,p,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,p,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_rational_prec_ref_ref`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::mul_rational_round_ref_ref`] instead. If both of these things are
+    /// true, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047199");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec_round_ref_ref(
+        &self,
+        other: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_rational_prec_round_ref_ref_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] and the [`Rational`] are both taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(\pm\infty,0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x>0$
+    /// - $f(\infty,x,p)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,p)=\infty$ if $x<0$
+    /// - $f(0.0,x,p)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0$
+    /// - $f(x,0,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_rational_prec_round`] instead. If you know that your target precision is the
+    /// precision of the [`Float`] input, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec(self, other: Rational, prec: u64) -> (Float, Ordering) {
+        self.mul_rational_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value and the [`Rational`] by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(\pm\infty,0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x>0$
+    /// - $f(\infty,x,p)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,p)=\infty$ if $x<0$
+    /// - $f(0.0,x,p)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0$
+    /// - $f(x,0,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_rational_prec_round_val_ref`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_val_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_val_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec_val_ref(self, other: &Rational, prec: u64) -> (Float, Ordering) {
+        self.mul_rational_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference and the [`Rational`] by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(\pm\infty,0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x>0$
+    /// - $f(\infty,x,p)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,p)=\infty$ if $x<0$
+    /// - $f(0.0,x,p)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0$
+    /// - $f(x,0,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_rational_prec_round_ref_val`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_ref_val(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_ref_val(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec_ref_val(&self, other: Rational, prec: u64) -> (Float, Ordering) {
+        self.mul_rational_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] and the [`Rational`] are both taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(\pm\infty,0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x>0$
+    /// - $f(\infty,x,p)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,p)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,p)=\infty$ if $x<0$
+    /// - $f(0.0,x,p)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0$
+    /// - $f(x,0,p)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,p)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_rational_prec_round_ref_ref`] instead. If you know that your target precision
+    /// is the precision of the [`Float`] input, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_ref_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(s.to_string(), "1.06");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI).mul_rational_prec_ref_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(s.to_string(), "1.047197");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec_ref_ref(&self, other: &Rational, prec: u64) -> (Float, Ordering) {
+        self.mul_rational_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result with the specified rounding
+    /// mode. The [`Float`] and the [`Rational`] are both taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded product is less than, equal to, or greater than the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(\pm\infty,0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x>0$
+    /// - $f(\infty,x,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,m)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,m)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0$
+    /// - $f(x,0,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::mul_rational_prec_round`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).mul_rational_round(Rational::from_unsigneds(1u8, 3), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.0471975511965976");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).mul_rational_round(Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.0471975511965979");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).mul_rational_round(Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.0471975511965976");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_round(self, other: Rational, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round(other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value and the [`Rational`] by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded product is less than, equal to, or greater
+    /// than the exact product. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(\pm\infty,0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x>0$
+    /// - $f(\infty,x,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,m)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,m)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0$
+    /// - $f(x,0,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::mul_rational_prec_round_val_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .mul_rational_round_val_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.0471975511965976");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .mul_rational_round_val_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.0471975511965979");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .mul_rational_round_val_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.0471975511965976");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_round_val_ref(
+        self,
+        other: &Rational,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference and the [`Rational`] by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded product is less than, equal to, or greater
+    /// than the exact product. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(\pm\infty,0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x>0$
+    /// - $f(\infty,x,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,m)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,m)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0$
+    /// - $f(x,0,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::mul_rational_prec_round_ref_val`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .mul_rational_round_ref_val(Rational::from_unsigneds(1u8, 3), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.0471975511965976");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .mul_rational_round_ref_val(Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.0471975511965979");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .mul_rational_round_ref_val(Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.0471975511965976");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_round_ref_val(
+        &self,
+        other: Rational,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`], rounding the result with the specified rounding
+    /// mode. The [`Float`] and the [`Rational`] are both taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded product is less than, equal to, or greater
+    /// than the exact product. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] input. See [`RoundingMode`]
+    /// for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(\pm\infty,0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x>0$
+    /// - $f(\infty,x,m)=-\infty$ if $x<0$
+    /// - $f(-\infty,x,m)=-\infty$ if $x>0$
+    /// - $f(-\infty,x,m)=\infty$ if $x<0$
+    /// - $f(0.0,x,m)=0.0$ if $x\geq0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0$
+    /// - $f(x,0,m)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0,m)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::mul_rational_prec_round_ref_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .mul_rational_round_ref_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.0471975511965976");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .mul_rational_round_ref_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.0471975511965979");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (s, o) = Float::from(PI)
+    ///     .mul_rational_round_ref_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.0471975511965976");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_rational_round_ref_ref(
+        &self,
+        other: &Rational,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Rational`] on the right-hand side is
+    /// taken by value. An [`Ordering`] is returned, indicating whether the rounded product is less
+    /// than, equal to, or greater than the exact product. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// See the [`Float::mul_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_rational_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the [`Float`] input,
+    /// consider using [`Float::mul_rational_round_assign`] instead. If both of these things are
+    /// true, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign(Rational::from_unsigneds(1u8, 3), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.06");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.06");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign(Rational::from_unsigneds(1u8, 3), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.047197");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.047199");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign(
+    ///         Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.047197");
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec_round_assign(
+        &mut self,
+        other: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (product, o) = mul_rational_prec_round_ref_val_helper(self, other, prec, rm);
+        *self = product;
+        o
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Rational`] on the right-hand side is
+    /// taken by reference. An [`Ordering`] is returned, indicating whether the rounded product is
+    /// less than, equal to, or greater than the exact product. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    ///
+    /// See the [`Float::mul_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::mul_rational_prec_assign_ref`] instead. If you know that your target precision is
+    /// the precision of the [`Float`] input, consider using
+    /// [`Float::mul_rational_round_assign_ref`] instead. If both of these things are true, consider
+    /// using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.06");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         5,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.06");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.047197");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.047199");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_round_assign_ref(
+    ///         &Rational::from_unsigneds(1u8, 3),
+    ///         20,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.047197");
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec_round_assign_ref(
+        &mut self,
+        other: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (product, o) = mul_rational_prec_round_ref_ref_helper(self, other, prec, rm);
+        *self = product;
+        o
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`] in place, rounding the result to the nearest value
+    /// of the specified precision. The [`Rational`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded product is less than, equal to, or
+    /// greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// See the [`Float::mul_rational_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_rational_prec_round_assign`] instead. If you know that your target precision is
+    /// the precision of the [`Float`] input, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_assign(Rational::from_unsigneds(1u8, 3), 5),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.06");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_assign(Rational::from_unsigneds(1u8, 3), 20),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.047197");
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec_assign(&mut self, other: Rational, prec: u64) -> Ordering {
+        self.mul_rational_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`] in place, rounding the result to the nearest value
+    /// of the specified precision. The [`Rational`] on the right-hand side is taken by reference.
+    /// An [`Ordering`] is returned, indicating whether the rounded product is less than, equal to,
+    /// or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$.
+    ///
+    /// See the [`Float::mul_rational_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_rational_prec_round_assign_ref`] instead. If you know that your target
+    /// precision is the precision of the [`Float`] input, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_assign_ref(&Rational::from_unsigneds(1u8, 3), 5),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.06");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_prec_assign_ref(&Rational::from_unsigneds(1u8, 3), 20),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.047197");
+    /// ```
+    #[inline]
+    pub fn mul_rational_prec_assign_ref(&mut self, other: &Rational, prec: u64) -> Ordering {
+        self.mul_rational_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`] in place, rounding the result with the specified
+    /// rounding mode. The [`Rational`] on the right-hand side is taken by value. An [`Ordering`] is
+    /// returned, indicating whether the rounded product is less than, equal to, or greater than the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] on the left-hand side.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// See the [`Float::mul_rational_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::mul_rational_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.0471975511965976");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.0471975511965979");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.0471975511965976");
+    /// ```
+    #[inline]
+    pub fn mul_rational_round_assign(&mut self, other: Rational, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round_assign(other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Rational`] in place, rounding the result with the specified
+    /// rounding mode. The [`Rational`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded product is less than, equal to, or
+    /// greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the [`Float`] on the left-hand side.
+    ///
+    /// $$
+    /// x \gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $xy$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |xy|\rfloor-p}$.
+    /// - $p$ is the precision of $x$, or 1 if $x$ has no precision.
+    ///
+    /// See the [`Float::mul_rational_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::mul_rational_prec_round_assign_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact multiplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_round_assign_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.0471975511965976");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_round_assign_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.0471975511965979");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_rational_round_assign_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.0471975511965976");
+    /// ```
+    #[inline]
+    pub fn mul_rational_round_assign_ref(
+        &mut self,
+        other: &Rational,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round_assign_ref(other, prec, rm)
+    }
+}
+
+impl Mul<Float> for Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s, taking both by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty) = \text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x>0.0$
+    /// - $f(\infty,x)=f(x,\infty)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=f(x,0.0)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x)=f(x,0.0)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x)=f(x,-0.0)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::mul_round`]
+    /// instead. If you want to specify the output precision, consider using [`Float::mul_prec`]. If
+    /// you want both of these things, consider using [`Float::mul_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) * Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) * Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::from(1.5) * Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Float::from(-1.5) * Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY * Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) * Float::from(2.0), 3.0);
+    /// assert_eq!(Float::from(1.5) * Float::from(-2.0), -3.0);
+    /// assert_eq!(Float::from(-1.5) * Float::from(2.0), -3.0);
+    /// assert_eq!(Float::from(-1.5) * Float::from(-2.0), 3.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round(other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Mul<&'a Float> for Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s, taking the first by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty) = \text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x>0.0$
+    /// - $f(\infty,x)=f(x,\infty)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=f(x,0.0)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x)=f(x,0.0)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x)=f(x,-0.0)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::mul_round`]
+    /// instead. If you want to specify the output precision, consider using [`Float::mul_prec`]. If
+    /// you want both of these things, consider using [`Float::mul_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) * &Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) * &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::from(1.5) * &Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Float::from(-1.5) * &Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY * &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) * &Float::from(2.0), 3.0);
+    /// assert_eq!(Float::from(1.5) * &Float::from(-2.0), -3.0);
+    /// assert_eq!(Float::from(-1.5) * &Float::from(2.0), -3.0);
+    /// assert_eq!(Float::from(-1.5) * &Float::from(-2.0), 3.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<Float> for &'a Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s, taking the first by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty) = \text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x>0.0$
+    /// - $f(\infty,x)=f(x,\infty)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=f(x,0.0)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x)=f(x,0.0)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x)=f(x,-0.0)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::mul_round`]
+    /// instead. If you want to specify the output precision, consider using [`Float::mul_prec`]. If
+    /// you want both of these things, consider using [`Float::mul_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) * Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) * Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::from(1.5) * Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Float::from(-1.5) * Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY * Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) * Float::from(2.0), 3.0);
+    /// assert_eq!(&Float::from(1.5) * Float::from(-2.0), -3.0);
+    /// assert_eq!(&Float::from(-1.5) * Float::from(2.0), -3.0);
+    /// assert_eq!(&Float::from(-1.5) * Float::from(-2.0), 3.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Mul<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s, taking both by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty) = \text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x>0.0$
+    /// - $f(\infty,x)=f(x,\infty)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=f(x,0.0)=0.0$ if $0.0<x<\infty$
+    /// - $f(0.0,x)=f(x,0.0)=-0.0$ if $-\infty<x<0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=-0.0$ if $0.0<x<\infty$
+    /// - $f(-0.0,x)=f(x,-0.0)=0.0$ if $-\infty<x<0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::mul_round`]
+    /// instead. If you want to specify the output precision, consider using [`Float::mul_prec`]. If
+    /// you want both of these things, consider using [`Float::mul_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) * &Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) * &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::from(1.5) * &Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Float::from(-1.5) * &Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY * &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) * &Float::from(2.0), 3.0);
+    /// assert_eq!(&Float::from(1.5) * &Float::from(-2.0), -3.0);
+    /// assert_eq!(&Float::from(-1.5) * &Float::from(2.0), -3.0);
+    /// assert_eq!(&Float::from(-1.5) * &Float::from(-2.0), 3.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl MulAssign<Float> for Float {
+    /// Multiplies a [`Float`] by a [`Float`] in place, taking the [`Float`] on the right-hand side
+    /// by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `*` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= Float::INFINITY;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= Float::NEGATIVE_INFINITY;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x *= Float::INFINITY;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= Float::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= Float::from(2.0);
+    /// assert_eq!(x, 3.0);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= Float::from(-2.0);
+    /// assert_eq!(x, -3.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x *= Float::from(2.0);
+    /// assert_eq!(x, -3.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x *= Float::from(-2.0);
+    /// assert_eq!(x, 3.0);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_assign(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> MulAssign<&'a Float> for Float {
+    /// Multiplies a [`Float`] by a [`Float`] in place, taking the [`Float`] on the right-hand side
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `*` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= &Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= &Float::INFINITY;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= &Float::NEGATIVE_INFINITY;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x *= &Float::INFINITY;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= &Float::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= &Float::from(2.0);
+    /// assert_eq!(x, 3.0);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= &Float::from(-2.0);
+    /// assert_eq!(x, -3.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x *= &Float::from(2.0);
+    /// assert_eq!(x, -3.0);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x *= &Float::from(-2.0);
+    /// assert_eq!(x, 3.0);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_assign_ref(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl Mul<Rational> for Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by a [`Rational`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_rational_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::mul_rational_prec`]. If you want both of these things, consider using
+    /// [`Float::mul_rational_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Float::NAN * Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(Float::INFINITY * Rational::exact_from(1.5), Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::NEGATIVE_INFINITY * Rational::exact_from(1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Float::INFINITY * Rational::exact_from(-1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY * Rational::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) * Rational::exact_from(0.5), 1.25);
+    /// assert_eq!(Float::from(2.5) * Rational::exact_from(-0.5), -1.25);
+    /// assert_eq!(Float::from(-2.5) * Rational::exact_from(0.5), -1.25);
+    /// assert_eq!(Float::from(-2.5) * Rational::exact_from(-0.5), 1.25);
+    /// ```
+    #[inline]
+    fn mul(self, other: Rational) -> Float {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<&'a Rational> for Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by a [`Rational`], taking the [`Float`] by value and the [`Rational`]
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_rational_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::mul_rational_prec`]. If you want both of these things, consider using
+    /// [`Float::mul_rational_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Float::NAN * &Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(
+    ///     Float::INFINITY * &Rational::exact_from(1.5),
+    ///     Float::INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Float::NEGATIVE_INFINITY * &Rational::exact_from(1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Float::INFINITY * &Rational::exact_from(-1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY * &Rational::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) * &Rational::exact_from(0.5), 1.25);
+    /// assert_eq!(Float::from(2.5) * &Rational::exact_from(-0.5), -1.25);
+    /// assert_eq!(Float::from(-2.5) * &Rational::exact_from(0.5), -1.25);
+    /// assert_eq!(Float::from(-2.5) * &Rational::exact_from(-0.5), 1.25);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Rational) -> Float {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<Rational> for &'a Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by a [`Rational`], taking the [`Float`] by reference and the
+    /// [`Rational`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_rational_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::mul_rational_prec`]. If you want both of these things, consider using
+    /// [`Float::mul_rational_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Float::NAN * Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(
+    ///     &Float::INFINITY * Rational::exact_from(1.5),
+    ///     Float::INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Float::NEGATIVE_INFINITY * Rational::exact_from(1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Float::INFINITY * Rational::exact_from(-1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY * Rational::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) * Rational::exact_from(0.5), 1.25);
+    /// assert_eq!(&Float::from(2.5) * Rational::exact_from(-0.5), -1.25);
+    /// assert_eq!(&Float::from(-2.5) * Rational::exact_from(0.5), -1.25);
+    /// assert_eq!(&Float::from(-2.5) * Rational::exact_from(-0.5), 1.25);
+    /// ```
+    #[inline]
+    fn mul(self, other: Rational) -> Float {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Mul<&'a Rational> for &'b Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by a [`Rational`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_rational_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::mul_rational_prec`]. If you want both of these things, consider using
+    /// [`Float::mul_rational_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Float::NAN * &Rational::exact_from(1.5)).is_nan());
+    /// assert_eq!(
+    ///     &Float::INFINITY * &Rational::exact_from(1.5),
+    ///     Float::INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Float::NEGATIVE_INFINITY * &Rational::exact_from(1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Float::INFINITY * &Rational::exact_from(-1.5),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY * &Rational::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) * &Rational::exact_from(0.5), 1.25);
+    /// assert_eq!(&Float::from(2.5) * &Rational::exact_from(-0.5), -1.25);
+    /// assert_eq!(&Float::from(-2.5) * &Rational::exact_from(0.5), -1.25);
+    /// assert_eq!(&Float::from(-2.5) * &Rational::exact_from(-0.5), 1.25);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Rational) -> Float {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl MulAssign<Rational> for Float {
+    /// Multiplies a [`Float`] by a [`Rational`] in place, taking the [`Rational`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `*` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Float::NAN;
+    /// x *= Rational::exact_from(1.5);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x *= Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= Rational::exact_from(-1.5);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= Rational::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x *= Rational::exact_from(0.5);
+    /// assert_eq!(x, 1.25);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x *= Rational::exact_from(-0.5);
+    /// assert_eq!(x, -1.25);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x *= Rational::exact_from(0.5);
+    /// assert_eq!(x, -1.25);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x *= Rational::exact_from(-0.5);
+    /// assert_eq!(x, 1.25);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Rational) {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round_assign(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> MulAssign<&'a Rational> for Float {
+    /// Multiplies a [`Float`] by a [`Rational`] in place, taking the [`Rational`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `*` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Float::NAN;
+    /// x *= &Rational::exact_from(1.5);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= &Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x *= &Rational::exact_from(1.5);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= &Rational::exact_from(-1.5);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= &Rational::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x *= &Rational::exact_from(0.5);
+    /// assert_eq!(x, 1.25);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x *= &Rational::exact_from(-0.5);
+    /// assert_eq!(x, -1.25);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x *= &Rational::exact_from(0.5);
+    /// assert_eq!(x, -1.25);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x *= &Rational::exact_from(-0.5);
+    /// assert_eq!(x, 1.25);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a Rational) {
+        let prec = self.significant_bits();
+        self.mul_rational_prec_round_assign_ref(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl Mul<Float> for Rational {
+    type Output = Float;
+
+    /// Multiplies a [`Rational`] by a [`Float`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,\infty)=-\infty$ if $x<0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,-\infty)=\infty$ if $x<0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,0.0)=-0.0$ if $x<0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=0.0$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Rational::exact_from(1.5) * Float::NAN).is_nan());
+    /// assert_eq!(Rational::exact_from(1.5) * Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     Rational::exact_from(1.5) * Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Rational::exact_from(-1.5) * Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Rational::ZERO * Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(Rational::exact_from(0.5) * Float::from(2.5), 1.25);
+    /// assert_eq!(Rational::exact_from(0.5) * Float::from(-2.5), -1.25);
+    /// assert_eq!(Rational::exact_from(-0.5) * Float::from(2.5), -1.25);
+    /// assert_eq!(Rational::exact_from(-0.5) * Float::from(-2.5), 1.25);
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_rational_prec_round(self, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<&'a Float> for Rational {
+    type Output = Float;
+
+    /// Multiplies a [`Rational`] by a [`Float`], taking the [`Rational`] by value and the [`Float`]
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,\infty)=-\infty$ if $x<0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,-\infty)=\infty$ if $x<0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,0.0)=-0.0$ if $x<0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=0.0$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((Rational::exact_from(1.5) * &Float::NAN).is_nan());
+    /// assert_eq!(
+    ///     Rational::exact_from(1.5) * &Float::INFINITY,
+    ///     Float::INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Rational::exact_from(1.5) * &Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     Rational::exact_from(-1.5) * &Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Rational::ZERO * &Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(Rational::exact_from(0.5) * &Float::from(2.5), 1.25);
+    /// assert_eq!(Rational::exact_from(0.5) * &Float::from(-2.5), -1.25);
+    /// assert_eq!(Rational::exact_from(-0.5) * &Float::from(2.5), -1.25);
+    /// assert_eq!(Rational::exact_from(-0.5) * &Float::from(-2.5), 1.25);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_rational_prec_round_ref_val(self, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<Float> for &'a Rational {
+    type Output = Float;
+
+    /// Multiplies a [`Rational`] by a [`Float`], taking the [`Rational`] by reference and the
+    /// [`Float`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,\infty)=-\infty$ if $x<0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,-\infty)=\infty$ if $x<0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,0.0)=-0.0$ if $x<0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=0.0$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Rational::exact_from(1.5) * Float::NAN).is_nan());
+    /// assert_eq!(
+    ///     &Rational::exact_from(1.5) * Float::INFINITY,
+    ///     Float::INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Rational::exact_from(1.5) * Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Rational::exact_from(-1.5) * Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Rational::ZERO * Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(&Rational::exact_from(0.5) * Float::from(2.5), 1.25);
+    /// assert_eq!(&Rational::exact_from(0.5) * Float::from(-2.5), -1.25);
+    /// assert_eq!(&Rational::exact_from(-0.5) * Float::from(2.5), -1.25);
+    /// assert_eq!(&Rational::exact_from(-0.5) * Float::from(-2.5), 1.25);
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_rational_prec_round_val_ref(self, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Mul<&'a Float> for &'b Rational {
+    type Output = Float;
+
+    /// Multiplies a [`Rational`] by a [`Float`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,\infty)=-\infty$ if $x<0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,-\infty)=\infty$ if $x<0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,0.0)=-0.0$ if $x<0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=0.0$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_base::num::conversion::traits::ExactFrom;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!((&Rational::exact_from(1.5) * &Float::NAN).is_nan());
+    /// assert_eq!(
+    ///     &Rational::exact_from(1.5) * &Float::INFINITY,
+    ///     Float::INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Rational::exact_from(1.5) * &Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(
+    ///     &Rational::exact_from(-1.5) * &Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Rational::ZERO * &Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(&Rational::exact_from(0.5) * &Float::from(2.5), 1.25);
+    /// assert_eq!(&Rational::exact_from(0.5) * &Float::from(-2.5), -1.25);
+    /// assert_eq!(&Rational::exact_from(-0.5) * &Float::from(2.5), -1.25);
+    /// assert_eq!(&Rational::exact_from(-0.5) * &Float::from(-2.5), 1.25);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_rational_prec_round_ref_ref(self, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl Mul<Natural> for Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by a [`Natural`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((Float::NAN * Natural::from(3u32)).is_nan());
+    /// assert_eq!(Float::INFINITY * Natural::from(3u32), Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::NEGATIVE_INFINITY * Natural::from(3u32),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY * Natural::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) * Natural::from(2u32), 5.0);
+    /// assert_eq!(Float::from(-2.5) * Natural::from(2u32), -5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: Natural) -> Float {
+        let prec = self.significant_bits();
+        self.mul_prec_round(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<&'a Natural> for Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by a [`Natural`], taking the [`Float`] by value and the [`Natural`]
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((Float::NAN * &Natural::from(3u32)).is_nan());
+    /// assert_eq!(Float::INFINITY * &Natural::from(3u32), Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::NEGATIVE_INFINITY * &Natural::from(3u32),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY * &Natural::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) * &Natural::from(2u32), 5.0);
+    /// assert_eq!(Float::from(-2.5) * &Natural::from(2u32), -5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Natural) -> Float {
+        let prec = self.significant_bits();
+        self.mul_prec_round(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<Natural> for &'a Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by a [`Natural`], taking the [`Float`] by reference and the
+    /// [`Natural`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((&Float::NAN * Natural::from(3u32)).is_nan());
+    /// assert_eq!(&Float::INFINITY * Natural::from(3u32), Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::NEGATIVE_INFINITY * Natural::from(3u32),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY * Natural::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) * Natural::from(2u32), 5.0);
+    /// assert_eq!(&Float::from(-2.5) * Natural::from(2u32), -5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: Natural) -> Float {
+        let prec = self.significant_bits();
+        self.mul_prec_round_ref_val(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Mul<&'a Natural> for &'b Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by a [`Natural`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((&Float::NAN * &Natural::from(3u32)).is_nan());
+    /// assert_eq!(&Float::INFINITY * &Natural::from(3u32), Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::NEGATIVE_INFINITY * &Natural::from(3u32),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY * &Natural::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) * &Natural::from(2u32), 5.0);
+    /// assert_eq!(&Float::from(-2.5) * &Natural::from(2u32), -5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Natural) -> Float {
+        let prec = self.significant_bits();
+        self.mul_prec_round_ref_val(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl MulAssign<Natural> for Float {
+    /// Multiplies a [`Float`] by a [`Natural`] in place, taking the [`Natural`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `*` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Float::NAN;
+    /// x *= Natural::from(3u32);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= Natural::from(3u32);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x *= Natural::from(3u32);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= Natural::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x *= Natural::from(2u32);
+    /// assert_eq!(x, 5.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x *= Natural::from(2u32);
+    /// assert_eq!(x, -5.0);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Natural) {
+        let prec = self.significant_bits();
+        self.mul_prec_round_assign(Float::from(other), prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> MulAssign<&'a Natural> for Float {
+    /// Multiplies a [`Float`] by a [`Natural`] in place, taking the [`Natural`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `*` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Float::NAN;
+    /// x *= &Natural::from(3u32);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= &Natural::from(3u32);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x *= &Natural::from(3u32);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= &Natural::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x *= &Natural::from(2u32);
+    /// assert_eq!(x, 5.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x *= &Natural::from(2u32);
+    /// assert_eq!(x, -5.0);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a Natural) {
+        let prec = self.significant_bits();
+        self.mul_prec_round_assign(Float::from(other), prec, RoundingMode::Nearest);
+    }
+}
+
+impl Mul<Float> for Natural {
+    type Output = Float;
+
+    /// Multiplies a [`Natural`] by a [`Float`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((Natural::from(3u32) * Float::NAN).is_nan());
+    /// assert_eq!(Natural::from(3u32) * Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     Natural::from(3u32) * Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Natural::ZERO * Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(Natural::from(2u32) * Float::from(2.5), 5.0);
+    /// assert_eq!(Natural::from(2u32) * Float::from(-2.5), -5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_prec_round(Float::from(self), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<&'a Float> for Natural {
+    type Output = Float;
+
+    /// Multiplies a [`Natural`] by a [`Float`], taking the [`Natural`] by value and the [`Float`]
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((Natural::from(3u32) * &Float::NAN).is_nan());
+    /// assert_eq!(Natural::from(3u32) * &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     Natural::from(3u32) * &Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Natural::ZERO * &Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(Natural::from(2u32) * &Float::from(2.5), 5.0);
+    /// assert_eq!(Natural::from(2u32) * &Float::from(-2.5), -5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_prec_round_ref_val(Float::from(self), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<Float> for &'a Natural {
+    type Output = Float;
+
+    /// Multiplies a [`Natural`] by a [`Float`], taking the [`Natural`] by reference and the
+    /// [`Float`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((&Natural::from(3u32) * Float::NAN).is_nan());
+    /// assert_eq!(&Natural::from(3u32) * Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Natural::from(3u32) * Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Natural::ZERO * Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(&Natural::from(2u32) * Float::from(2.5), 5.0);
+    /// assert_eq!(&Natural::from(2u32) * Float::from(-2.5), -5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_prec_round(Float::from(self), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Mul<&'a Float> for &'b Natural {
+    type Output = Float;
+
+    /// Multiplies a [`Natural`] by a [`Float`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!((&Natural::from(3u32) * &Float::NAN).is_nan());
+    /// assert_eq!(&Natural::from(3u32) * &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Natural::from(3u32) * &Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Natural::ZERO * &Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(&Natural::from(2u32) * &Float::from(2.5), 5.0);
+    /// assert_eq!(&Natural::from(2u32) * &Float::from(-2.5), -5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_prec_round_ref_val(Float::from(self), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl Mul<Integer> for Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by an [`Integer`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((Float::NAN * Integer::from(3)).is_nan());
+    /// assert_eq!(Float::INFINITY * Integer::from(3), Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::INFINITY * Integer::from(-3),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY * Integer::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) * Integer::from(2), 5.0);
+    /// assert_eq!(Float::from(2.5) * Integer::from(-2), -5.0);
+    /// assert_eq!(Float::from(-2.5) * Integer::from(2), -5.0);
+    /// assert_eq!(Float::from(-2.5) * Integer::from(-2), 5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: Integer) -> Float {
+        let prec = self.significant_bits();
+        self.mul_prec_round(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<&'a Integer> for Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by an [`Integer`], taking the [`Float`] by value and the [`Integer`]
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((Float::NAN * &Integer::from(3)).is_nan());
+    /// assert_eq!(Float::INFINITY * &Integer::from(3), Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::INFINITY * &Integer::from(-3),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY * &Integer::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(2.5) * &Integer::from(2), 5.0);
+    /// assert_eq!(Float::from(2.5) * &Integer::from(-2), -5.0);
+    /// assert_eq!(Float::from(-2.5) * &Integer::from(2), -5.0);
+    /// assert_eq!(Float::from(-2.5) * &Integer::from(-2), 5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Integer) -> Float {
+        let prec = self.significant_bits();
+        self.mul_prec_round(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<Integer> for &'a Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by an [`Integer`], taking the [`Float`] by reference and the
+    /// [`Integer`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((&Float::NAN * Integer::from(3)).is_nan());
+    /// assert_eq!(&Float::INFINITY * Integer::from(3), Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::INFINITY * Integer::from(-3),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY * Integer::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) * Integer::from(2), 5.0);
+    /// assert_eq!(&Float::from(2.5) * Integer::from(-2), -5.0);
+    /// assert_eq!(&Float::from(-2.5) * Integer::from(2), -5.0);
+    /// assert_eq!(&Float::from(-2.5) * Integer::from(-2), 5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: Integer) -> Float {
+        let prec = self.significant_bits();
+        self.mul_prec_round_ref_val(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Mul<&'a Integer> for &'b Float {
+    type Output = Float;
+
+    /// Multiplies a [`Float`] by an [`Integer`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(\pm\infty,0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x>0$
+    /// - $f(\infty,x)=-\infty$ if $x<0$
+    /// - $f(-\infty,x)=-\infty$ if $x>0$
+    /// - $f(-\infty,x)=\infty$ if $x<0$
+    /// - $f(0.0,x)=0.0$ if $x\geq0$
+    /// - $f(0.0,x)=-0.0$ if $x<0$
+    /// - $f(-0.0,x)=-0.0$ if $x\geq0$
+    /// - $f(-0.0,x)=0.0$ if $x<0$
+    /// - $f(x,0)=0.0$ if $0.0<x<\infty$
+    /// - $f(x,0)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((&Float::NAN * &Integer::from(3)).is_nan());
+    /// assert_eq!(&Float::INFINITY * &Integer::from(3), Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::INFINITY * &Integer::from(-3),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY * &Integer::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(2.5) * &Integer::from(2), 5.0);
+    /// assert_eq!(&Float::from(2.5) * &Integer::from(-2), -5.0);
+    /// assert_eq!(&Float::from(-2.5) * &Integer::from(2), -5.0);
+    /// assert_eq!(&Float::from(-2.5) * &Integer::from(-2), 5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Integer) -> Float {
+        let prec = self.significant_bits();
+        self.mul_prec_round_ref_val(Float::from(other), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl MulAssign<Integer> for Float {
+    /// Multiplies a [`Float`] by an [`Integer`] in place, taking the [`Integer`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `*` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Float::NAN;
+    /// x *= Integer::from(3);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= Integer::from(3);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= Integer::from(-3);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= Integer::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x *= Integer::from(2);
+    /// assert_eq!(x, 5.0);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x *= Integer::from(-2);
+    /// assert_eq!(x, -5.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x *= Integer::from(2);
+    /// assert_eq!(x, -5.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x *= Integer::from(-2);
+    /// assert_eq!(x, 5.0);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Integer) {
+        let prec = self.significant_bits();
+        self.mul_prec_round_assign(Float::from(other), prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> MulAssign<&'a Integer> for Float {
+    /// Multiplies a [`Float`] by an [`Integer`] in place, taking the [`Integer`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// See the `*` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Float::NAN;
+    /// x *= &Integer::from(3);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= &Integer::from(3);
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= &Integer::from(-3);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x *= &Integer::ZERO;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x *= &Integer::from(2);
+    /// assert_eq!(x, 5.0);
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x *= &Integer::from(-2);
+    /// assert_eq!(x, -5.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x *= &Integer::from(2);
+    /// assert_eq!(x, -5.0);
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x *= &Integer::from(-2);
+    /// assert_eq!(x, 5.0);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a Integer) {
+        let prec = self.significant_bits();
+        self.mul_prec_round_assign(Float::from(other), prec, RoundingMode::Nearest);
+    }
+}
+
+impl Mul<Float> for Integer {
+    type Output = Float;
+
+    /// Multiplies an [`Integer`] by a [`Float`], taking both by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,\infty)=-\infty$ if $x<0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,-\infty)=\infty$ if $x<0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,0.0)=-0.0$ if $x<0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=0.0$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((Integer::from(3) * Float::NAN).is_nan());
+    /// assert_eq!(Integer::from(3) * Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     Integer::from(-3) * Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Integer::ZERO * Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(Integer::from(2) * Float::from(2.5), 5.0);
+    /// assert_eq!(Integer::from(-2) * Float::from(2.5), -5.0);
+    /// assert_eq!(Integer::from(2) * Float::from(-2.5), -5.0);
+    /// assert_eq!(Integer::from(-2) * Float::from(-2.5), 5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_prec_round(Float::from(self), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<&'a Float> for Integer {
+    type Output = Float;
+
+    /// Multiplies an [`Integer`] by a [`Float`], taking the [`Integer`] by value and the [`Float`]
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,\infty)=-\infty$ if $x<0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,-\infty)=\infty$ if $x<0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,0.0)=-0.0$ if $x<0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=0.0$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((Integer::from(3) * &Float::NAN).is_nan());
+    /// assert_eq!(Integer::from(3) * &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     Integer::from(-3) * &Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Integer::ZERO * &Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(Integer::from(2) * &Float::from(2.5), 5.0);
+    /// assert_eq!(Integer::from(-2) * &Float::from(2.5), -5.0);
+    /// assert_eq!(Integer::from(2) * &Float::from(-2.5), -5.0);
+    /// assert_eq!(Integer::from(-2) * &Float::from(-2.5), 5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_prec_round_ref_val(Float::from(self), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<Float> for &'a Integer {
+    type Output = Float;
+
+    /// Multiplies an [`Integer`] by a [`Float`], taking the [`Integer`] by reference and the
+    /// [`Float`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,\infty)=-\infty$ if $x<0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,-\infty)=\infty$ if $x<0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,0.0)=-0.0$ if $x<0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=0.0$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((&Integer::from(3) * Float::NAN).is_nan());
+    /// assert_eq!(&Integer::from(3) * Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Integer::from(-3) * Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Integer::ZERO * Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(&Integer::from(2) * Float::from(2.5), 5.0);
+    /// assert_eq!(&Integer::from(-2) * Float::from(2.5), -5.0);
+    /// assert_eq!(&Integer::from(2) * Float::from(-2.5), -5.0);
+    /// assert_eq!(&Integer::from(-2) * Float::from(-2.5), 5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_prec_round(Float::from(self), prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Mul<&'a Float> for &'b Integer {
+    type Output = Float;
+
+    /// Multiplies an [`Integer`] by a [`Float`], taking both by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input [`Float`]. If the product is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = xy+\epsilon.
+    /// $$
+    /// - If $xy$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p}$, where
+    ///   $p$ is the precision of the input [`Float`].
+    ///
+    /// Special cases:
+    /// - $f(x,\text{NaN})=f(0,\pm\infty)=\text{NaN}$
+    /// - $f(x,\infty)=\infty$ if $x>0$
+    /// - $f(x,\infty)=-\infty$ if $x<0$
+    /// - $f(x,-\infty)=-\infty$ if $x>0$
+    /// - $f(x,-\infty)=\infty$ if $x<0$
+    /// - $f(x,0.0)=0.0$ if $x\geq0$
+    /// - $f(x,0.0)=-0.0$ if $x<0$
+    /// - $f(x,-0.0)=-0.0$ if $x\geq0$
+    /// - $f(x,-0.0)=0.0$ if $x<0$
+    /// - $f(0,x)=0.0$ if $0.0<x<\infty$
+    /// - $f(0,x)=-0.0$ if $-\infty<x<0.0$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!((&Integer::from(3) * &Float::NAN).is_nan());
+    /// assert_eq!(&Integer::from(3) * &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Integer::from(-3) * &Float::INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Integer::ZERO * &Float::INFINITY).is_nan());
+    ///
+    /// assert_eq!(&Integer::from(2) * &Float::from(2.5), 5.0);
+    /// assert_eq!(&Integer::from(-2) * &Float::from(2.5), -5.0);
+    /// assert_eq!(&Integer::from(2) * &Float::from(-2.5), -5.0);
+    /// assert_eq!(&Integer::from(-2) * &Float::from(-2.5), 5.0);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        let prec = other.significant_bits();
+        other
+            .mul_prec_round_ref_val(Float::from(self), prec, RoundingMode::Nearest)
+            .0
+    }
+}
@@ -0,0 +1,554 @@
+use crate::arithmetic::sum::{product_term, sum_terms_prec_round, term};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{AddMul, AddMulAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::{max, Ordering};
+
+// Computes $xy+z$, rounding to `prec` bits.
+fn mul_add_prec_round_ref_ref_ref_helper(
+    x: &Float,
+    y: &Float,
+    z: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    sum_terms_prec_round([product_term(x, y), term(z)].into_iter(), prec, rm)
+}
+
+impl Float {
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode. All three [`Float`]s are taken by
+    /// value. The product is not rounded before the addition, so only one rounding takes place.
+    /// An [`Ordering`] is also returned, indicating whether the rounded result is less than, equal
+    /// to, or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,p,m) = xy+z+\epsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p+1}$.
+    /// - If $xy+z$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,y,z,p,m)=\text{NaN}$ if $x$, $y$, or $z$ is NaN
+    /// - $f(\pm\infty,\pm0.0,z,p,m)=f(\pm0.0,\pm\infty,z,p,m)=\text{NaN}$
+    /// - $f(x,y,z,p,m)=\text{NaN}$ if $xy$ and $z$ are infinities of opposite signs
+    /// - $f(x,y,z,p,m)=\infty$ if $xy$ or $z$ is $\infty$ and the other is not NaN or $-\infty$
+    /// - $f(x,y,z,p,m)=-\infty$ if $xy$ or $z$ is $-\infty$ and the other is not NaN or $\infty$
+    /// - $f(x,y,z,p,m)$ is $0.0$ or $-0.0$ if $xy+z$ is zero, following the rules for adding zeros
+    ///   in [`Float::add_prec_round`], where $xy$ is $-0.0$ if it is zero and exactly one of $x$
+    ///   and $y$ is negative
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_add_prec`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the three inputs,
+    /// consider using [`Float::mul_add_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_add_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(1.0),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_add_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(1.0),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "10.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// // The product 0.1 * 10 is not exactly 1, and the difference survives because it is never
+    /// // rounded away.
+    /// let (s, o) = Float::from(0.1).mul_add_prec_round(
+    ///     Float::from(10.0),
+    ///     Float::from(-1.0),
+    ///     10,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(s.to_string(), "5.55e-17");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn mul_add_prec_round(
+        self,
+        y: Float,
+        z: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_add_prec_round_ref_ref_ref_helper(&self, &y, &z, prec, rm)
+    }
+
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode. All three [`Float`]s are taken by
+    /// reference. The product is not rounded before the addition, so only one rounding takes
+    /// place. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,p,m) = xy+z+\epsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p+1}$.
+    /// - If $xy+z$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See [`Float::mul_add_prec_round`] for the special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_add_prec_ref_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// three inputs, consider using [`Float::mul_add_round_ref_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_add_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(1.0),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_add_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(1.0),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "10.0");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn mul_add_prec_round_ref_ref_ref(
+        &self,
+        y: &Float,
+        z: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_add_prec_round_ref_ref_ref_helper(self, y, z, prec, rm)
+    }
+
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result to the nearest
+    /// value of the specified precision. All three [`Float`]s are taken by value. The product is
+    /// not rounded before the addition, so only one rounding takes place. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z,p) = xy+z+\epsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy+z|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See [`Float::mul_add_prec_round`] for the special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_add_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_add_prec(Float::from(E), Float::from(1.0), 5);
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::from(PI).mul_add_prec(Float::from(E), Float::from(1.0), 20);
+    /// assert_eq!(s.to_string(), "9.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_add_prec(self, y: Float, z: Float, prec: u64) -> (Float, Ordering) {
+        self.mul_add_prec_round(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result to the nearest
+    /// value of the specified precision. All three [`Float`]s are taken by reference. The product
+    /// is not rounded before the addition, so only one rounding takes place. An [`Ordering`] is
+    /// also returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z,p) = xy+z+\epsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |xy+z|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See [`Float::mul_add_prec_round`] for the special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_add_prec_round_ref_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) =
+    ///     Float::from(PI).mul_add_prec_ref_ref_ref(&Float::from(E), &Float::from(1.0), 5);
+    /// assert_eq!(s.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_add_prec_ref_ref_ref(&self, y: &Float, z: &Float, prec: u64) -> (Float, Ordering) {
+        self.mul_add_prec_round_ref_ref_ref(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result with the
+    /// specified rounding mode. All three [`Float`]s are taken by value. The product is not
+    /// rounded before the addition, so only one rounding takes place. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,m) = xy+z+\epsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $xy+z$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See [`Float::mul_add_prec_round`] for the special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::mul_add_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the maximum
+    /// precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_add_round(
+    ///     Float::from(E),
+    ///     Float::from(1.0),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(s.to_string(), "9.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_add_round(self, y: Float, z: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(
+            self.significant_bits(),
+            max(y.significant_bits(), z.significant_bits()),
+        );
+        self.mul_add_prec_round(y, z, prec, rm)
+    }
+
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result with the
+    /// specified rounding mode. All three [`Float`]s are taken by reference. The product is not
+    /// rounded before the addition, so only one rounding takes place. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,m) = xy+z+\epsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $xy+z$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See [`Float::mul_add_prec_round`] for the special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::mul_add_prec_round_ref_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the maximum
+    /// precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (s, o) = Float::from(PI).mul_add_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(1.0),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(s.to_string(), "9.539734222673568");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn mul_add_round_ref_ref_ref(
+        &self,
+        y: &Float,
+        z: &Float,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = max(
+            self.significant_bits(),
+            max(y.significant_bits(), z.significant_bits()),
+        );
+        self.mul_add_prec_round_ref_ref_ref(y, z, prec, rm)
+    }
+}
+
+impl AddMul<Float, Float> for Float {
+    type Output = Float;
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, taking all three by value. The
+    /// product is not rounded before the addition, so only one rounding takes place.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x+yz+\epsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See [`Float::mul_add_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMul;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(-1.0).add_mul(Float::from(0.1), Float::from(10.0)).to_string(),
+    ///     "5.551115123125783e-17"
+    /// );
+    /// ```
+    #[inline]
+    fn add_mul(self, y: Float, z: Float) -> Float {
+        y.mul_add_round(z, self, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a, 'b, 'c> AddMul<&'a Float, &'b Float> for &'c Float {
+    type Output = Float;
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, taking all three by reference.
+    /// The product is not rounded before the addition, so only one rounding takes place.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x+yz+\epsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See [`Float::mul_add_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMul;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     (&Float::from(-1.0)).add_mul(&Float::from(0.1), &Float::from(10.0)).to_string(),
+    ///     "5.551115123125783e-17"
+    /// );
+    /// ```
+    #[inline]
+    fn add_mul(self, y: &'a Float, z: &'b Float) -> Float {
+        y.mul_add_round_ref_ref_ref(z, self, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl AddMulAssign<Float, Float> for Float {
+    /// Adds the product of two other [`Float`]s to a [`Float`] in place, taking both [`Float`]s
+    /// on the right-hand side by value. The product is not rounded before the addition, so only
+    /// one rounding takes place.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x+yz+\epsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See [`Float::mul_add_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMulAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(-1.0);
+    /// x.add_mul_assign(Float::from(0.1), Float::from(10.0));
+    /// assert_eq!(x.to_string(), "5.551115123125783e-17");
+    /// ```
+    #[inline]
+    fn add_mul_assign(&mut self, y: Float, z: Float) {
+        *self = y
+            .mul_add_round_ref_ref_ref(&z, self, RoundingMode::Nearest)
+            .0;
+    }
+}
@@ -0,0 +1,685 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_zero, significand_bits, Float};
+use malachite_base::num::arithmetic::traits::{DivMod, Parity, PowerOf2, SqrtRem};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Takes the reciprocal of the square root of $x2^{p_x}$, where $x$ is positive, and rounds it to
+// `prec` bits.
+//
+// $2^s$ is divided by $x$, where $s$ is chosen so that the truncated square root of the quotient
+// has at least `prec + 2` significant bits and $s+p_x$ is even. Since
+// $\lfloor\sqrt{\lfloor y\rfloor}\rfloor = \lfloor\sqrt{y}\rfloor$, truncating the quotient doesn't
+// change the truncated root, and the root is exact only if both the division and the square root
+// are. If it is inexact, a 1 bit is appended below the truncated root, as in
+// `div_naturals_times_powers_of_2_prec_round`.
+fn reciprocal_sqrt_natural_times_power_of_2_prec_round(
+    x: &Natural,
+    x_pow: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let mut shift = x.significant_bits() + ((prec + 2) << 1);
+    if (i64::exact_from(shift) + x_pow).odd() {
+        shift += 1;
+    }
+    let (quotient, remainder) = Natural::power_of_2(shift).div_mod(x);
+    let (mut root, root_remainder) = quotient.sqrt_rem();
+    let mut pow = -((i64::exact_from(shift) + x_pow) >> 1);
+    if remainder != 0u32 || root_remainder != 0u32 {
+        root <<= 1;
+        root += Natural::ONE;
+        pow -= 1;
+    }
+    Float::from_natural_times_power_of_2_prec_round(root, pow, prec, rm)
+}
+
+// Takes the reciprocal of the square root of `x`, rounding to `prec` bits.
+fn reciprocal_sqrt_prec_round_ref_helper(
+    x: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { sign: false } | Finite { sign: false, .. }) => {
+            (float_nan!(), Ordering::Equal)
+        }
+        Float(Infinity { .. }) => (float_zero!(), Ordering::Equal),
+        Float(Zero { .. }) => (float_infinity!(), Ordering::Equal),
+        Float(Finite {
+            exponent,
+            significand,
+            ..
+        }) => reciprocal_sqrt_natural_times_power_of_2_prec_round(
+            significand,
+            exponent - i64::exact_from(significand_bits(significand)),
+            prec,
+            rm,
+        ),
+    }
+}
+
+impl Float {
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded reciprocal square root is less
+    /// than, equal to, or greater than the exact reciprocal square root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 1/\sqrt{x}+\epsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=0.0$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=\infty$
+    /// - $f(-0.0,p,m)=\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_sqrt_prec`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::reciprocal_sqrt_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact reciprocal square root, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.59");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.564189");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        reciprocal_sqrt_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded reciprocal square root is less
+    /// than, equal to, or greater than the exact reciprocal square root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 1/\sqrt{x}+\epsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=0.0$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=\infty$
+    /// - $f(-0.0,p,m)=\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_sqrt_prec_ref`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::reciprocal_sqrt_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact reciprocal square root, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.59");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.564189");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        reciprocal_sqrt_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded reciprocal square root is less than, equal to, or
+    /// greater than the exact reciprocal square root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the reciprocal square root is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 1/\sqrt{x}+\epsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=0.0$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=\infty$
+    /// - $f(-0.0,p)=\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec(5);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec(20);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec(self, prec: u64) -> (Float, Ordering) {
+        self.reciprocal_sqrt_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded reciprocal square root is less than, equal to,
+    /// or greater than the exact reciprocal square root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the reciprocal square root is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 1/\sqrt{x}+\epsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=0.0$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=\infty$
+    /// - $f(-0.0,p)=\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_ref(5);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.reciprocal_sqrt_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded reciprocal square root is less than, equal to, or greater
+    /// than the exact reciprocal square root. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 1/\sqrt{x}+\epsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=0.0$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=\infty$
+    /// - $f(-0.0,m)=\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::reciprocal_sqrt_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact reciprocal square
+    /// root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.5641895835477564");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round(prec, rm)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded reciprocal square root is less than, equal to, or
+    /// greater than the exact reciprocal square root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 1/\sqrt{x}+\epsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=0.0$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=\infty$
+    /// - $f(-0.0,m)=\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact reciprocal square
+    /// root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.5641895835477564");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round_ref(prec, rm)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`] in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded reciprocal square root is less than, equal to, or greater
+    /// than the exact reciprocal square root. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets 1/\sqrt{x}+\epsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// See the [`Float::reciprocal_sqrt_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_sqrt_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::reciprocal_sqrt_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact reciprocal square root, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_sqrt_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.56");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_sqrt_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.59");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_sqrt_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.56");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_sqrt_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.564189");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_sqrt_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.56419");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_sqrt_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (reciprocal_sqrt, o) = reciprocal_sqrt_prec_round_ref_helper(self, prec, rm);
+        *self = reciprocal_sqrt;
+        o
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`] in place, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded reciprocal square root is less than, equal to, or greater than the exact
+    /// reciprocal square root. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets 1/\sqrt{x}+\epsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// See the [`Float::reciprocal_sqrt_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.56");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.reciprocal_sqrt_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`] in place, rounding the result with
+    /// the specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// reciprocal square root is less than, equal to, or greater than the exact reciprocal square
+    /// root. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets 1/\sqrt{x}+\epsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::reciprocal_sqrt_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact reciprocal square
+    /// root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_sqrt_round_assign(RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_sqrt_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.5641895835477564");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_sqrt_round_assign(RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round_assign(prec, rm)
+    }
+}
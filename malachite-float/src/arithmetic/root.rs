@@ -0,0 +1,786 @@
+use crate::arithmetic::exp::{exp_enclosure, ziv_round};
+use crate::arithmetic::ln::{div_enclosure_by_positive, ln_enclosure};
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, float_zero, significand_bits, Float};
+use malachite_base::num::arithmetic::traits::{DivisibleBy, IsPowerOf2, Mod, Parity, RootRem};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Takes the `n`th root of $x2^{p_x}$, where $x$ is positive, and rounds it to `prec` bits, giving
+// it a positive sign if `sign` is `true` and a negative sign otherwise.
+//
+// If `n` exceeds the sum of `prec` and the bit length of $x$, the integer root below would need at
+// least $n(p+2)$ bits, so the root is computed as $e^{(\log x2^{p_x})/n}$ instead. In that case,
+// the root can only be exact if $x2^{p_x}$ is a power of 2: if the root were $m2^q$ with $m$ odd
+// and greater than 1, the odd part of $x$ would be $m^n$, which has more than `n` bits.
+//
+// Otherwise, $x$ is shifted left so that the truncated root has at least `prec + 2` significant
+// bits and the exponent of the shifted value is divisible by `n`. If the root is inexact, a 1 bit is
+// appended below the truncated root. As in `div_naturals_times_powers_of_2_prec_round`, this sticky
+// bit lies strictly below the rounding position, so rounding the result gives the same value and
+// `Ordering` as rounding the exact root would.
+pub(crate) fn root_natural_times_power_of_2_prec_round(
+    sign: bool,
+    x: Natural,
+    x_pow: i64,
+    n: u64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = x.significant_bits();
+    let root_bits = if n > bits.saturating_add(prec) {
+        None
+    } else {
+        n.checked_mul(prec + 2)
+    };
+    let root_bits = match root_bits {
+        Some(root_bits) => root_bits,
+        None => return root_by_exp_ln(sign, &x, x_pow, n, prec, rm),
+    };
+    let mut shift = root_bits.saturating_sub(bits);
+    shift += u64::wrapping_from((x_pow - i64::exact_from(shift)).mod_op(i64::exact_from(n)));
+    let (mut root, remainder) = (x << shift).root_rem(n);
+    let mut pow = (x_pow - i64::exact_from(shift)) / i64::exact_from(n);
+    if remainder != 0u32 {
+        root <<= 1;
+        root += Natural::ONE;
+        pow -= 1;
+    }
+    Float::from_integer_times_power_of_2_prec_round(
+        Integer::from_sign_and_abs(sign, root),
+        pow,
+        prec,
+        rm,
+    )
+}
+
+// Takes the `n`th root of $x2^{p_x}$ as $e^{(\log x2^{p_x})/n}$, where $x$ is positive and `n` is
+// greater than the bit length of $x$.
+fn root_by_exp_ln(
+    sign: bool,
+    x: &Natural,
+    x_pow: i64,
+    n: u64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if x.is_power_of_2() {
+        let log = x_pow + i64::exact_from(x.significant_bits() - 1);
+        if log.unsigned_abs().divisible_by(n) {
+            let root_log = i64::exact_from(log.unsigned_abs() / n);
+            return Float::from_integer_times_power_of_2_prec_round(
+                Integer::from_sign_and_abs(sign, Natural::ONE),
+                if log >= 0 { root_log } else { -root_log },
+                prec,
+                rm,
+            );
+        }
+    }
+    let n = Natural::from(n);
+    ziv_round(prec, rm, |working_prec| {
+        let enclosure = ln_enclosure(x, x_pow, working_prec);
+        let f = enclosure.f;
+        let (ln_lo, ln_hi) = enclosure.ln();
+        let (y_lo, y_hi) = div_enclosure_by_positive(ln_lo, ln_hi, &n, &n, 0);
+        let (lo, hi, pow) = exp_enclosure(&y_lo, &y_hi, f);
+        if sign {
+            (Integer::from(lo), Integer::from(hi), pow)
+        } else {
+            (-Integer::from(hi), -Integer::from(lo), pow)
+        }
+    })
+}
+
+// Takes the `n`th root of `x`, rounding to `prec` bits.
+fn root_prec_round_ref_helper(x: &Float, n: u64, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        _ if n == 0 => (float_nan!(), Ordering::Equal),
+        float_nan!() => (float_nan!(), Ordering::Equal),
+        Float(Infinity { sign }) => {
+            if *sign || n.odd() {
+                (x.clone(), Ordering::Equal)
+            } else {
+                (float_nan!(), Ordering::Equal)
+            }
+        }
+        Float(Zero { .. }) => {
+            if n.odd() {
+                (x.clone(), Ordering::Equal)
+            } else {
+                (float_zero!(), Ordering::Equal)
+            }
+        }
+        Float(Finite { sign: false, .. }) if n.even() => (float_nan!(), Ordering::Equal),
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) => root_natural_times_power_of_2_prec_round(
+            *sign,
+            significand.clone(),
+            exponent - i64::exact_from(significand_bits(significand)),
+            n,
+            prec,
+            rm,
+        ),
+    }
+}
+
+// Like `root_prec_round_ref_helper`, but takes `x` by value, so that its significand can be reused.
+fn root_prec_round_val_helper(x: Float, n: u64, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    match x {
+        Float(Finite {
+            sign,
+            exponent,
+            significand,
+            ..
+        }) if n != 0 && (sign || n.odd()) => {
+            assert_ne!(prec, 0);
+            let pow = exponent - i64::exact_from(significand_bits(&significand));
+            root_natural_times_power_of_2_prec_round(sign, significand, pow, n, prec, rm)
+        }
+        x => root_prec_round_ref_helper(&x, n, prec, rm),
+    }
+}
+
+impl Float {
+    /// Takes the $n$th root of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded root is less than, equal to, or greater than the
+    /// exact root. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = \sqrt[n]{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt[n]{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,0,p,m)=\text{NaN}$
+    /// - $f(\text{NaN},n,p,m)=\text{NaN}$
+    /// - $f(\infty,n,p,m)=\infty$
+    /// - $f(-\infty,n,p,m)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,p,m)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,p,m)=0.0$
+    /// - $f(-0.0,n,p,m)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,p,m)=0.0$ if $n$ is even
+    /// - $f(x,n,p,m)=\text{NaN}$ if $x<0$ and $n$ is even
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::root_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::root_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact root, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.46459");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn root_prec_round(self, n: u64, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        root_prec_round_val_helper(self, n, prec, rm)
+    }
+
+    /// Takes the $n$th root of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded root is less than, equal to, or greater than the
+    /// exact root. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = \sqrt[n]{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt[n]{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,0,p,m)=\text{NaN}$
+    /// - $f(\text{NaN},n,p,m)=\text{NaN}$
+    /// - $f(\infty,n,p,m)=\infty$
+    /// - $f(-\infty,n,p,m)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,p,m)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,p,m)=0.0$
+    /// - $f(-0.0,n,p,m)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,p,m)=0.0$ if $n$ is even
+    /// - $f(x,n,p,m)=\text{NaN}$ if $x<0$ and $n$ is even
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::root_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::root_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact root, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.46459");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn root_prec_round_ref(&self, n: u64, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        root_prec_round_ref_helper(self, n, prec, rm)
+    }
+
+    /// Takes the $n$th root of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded root is less than, equal to, or greater than the exact root.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// If the root is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n,p) = \sqrt[n]{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt[n]{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt[n]{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,0,p)=\text{NaN}$
+    /// - $f(\text{NaN},n,p)=\text{NaN}$
+    /// - $f(\infty,n,p)=\infty$
+    /// - $f(-\infty,n,p)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,p)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,p)=0.0$
+    /// - $f(-0.0,n,p)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,p)=0.0$ if $n$ is even
+    /// - $f(x,n,p)=\text{NaN}$ if $x<0$ and $n$ is even
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::root_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).root_prec(3, 5);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec(3, 20);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn root_prec(self, n: u64, prec: u64) -> (Float, Ordering) {
+        self.root_prec_round(n, prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the $n$th root of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded root is less than, equal to, or greater than the exact root.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// If the root is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n,p) = \sqrt[n]{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt[n]{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt[n]{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,0,p)=\text{NaN}$
+    /// - $f(\text{NaN},n,p)=\text{NaN}$
+    /// - $f(\infty,n,p)=\infty$
+    /// - $f(-\infty,n,p)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,p)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,p)=0.0$
+    /// - $f(-0.0,n,p)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,p)=0.0$ if $n$ is even
+    /// - $f(x,n,p)=\text{NaN}$ if $x<0$ and $n$ is even
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::root_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_ref(3, 5);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_ref(3, 20);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn root_prec_ref(&self, n: u64, prec: u64) -> (Float, Ordering) {
+        self.root_prec_round_ref(n, prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the $n$th root of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded root is less than, equal to, or greater than the exact root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,m) = \sqrt[n]{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt[n]{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(x,0,m)=\text{NaN}$
+    /// - $f(\text{NaN},n,m)=\text{NaN}$
+    /// - $f(\infty,n,m)=\infty$
+    /// - $f(-\infty,n,m)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,m)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,m)=0.0$
+    /// - $f(-0.0,n,m)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,m)=0.0$ if $n$ is even
+    /// - $f(x,n,m)=\text{NaN}$ if $x<0$ and $n$ is even
+    ///
+    /// If you want to specify an output precision, consider using [`Float::root_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).root_round(3, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_round(3, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4645918875615234");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_round(3, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn root_round(self, n: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.root_prec_round(n, prec, rm)
+    }
+
+    /// Takes the $n$th root of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded root is less than, equal to, or greater than the exact root. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,m) = \sqrt[n]{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt[n]{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(x,0,m)=\text{NaN}$
+    /// - $f(\text{NaN},n,m)=\text{NaN}$
+    /// - $f(\infty,n,m)=\infty$
+    /// - $f(-\infty,n,m)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,m)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,m)=0.0$
+    /// - $f(-0.0,n,m)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,m)=0.0$ if $n$ is even
+    /// - $f(x,n,m)=\text{NaN}$ if $x<0$ and $n$ is even
+    ///
+    /// If you want to specify an output precision, consider using [`Float::root_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).root_round_ref(3, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_round_ref(3, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4645918875615234");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_round_ref(3, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn root_round_ref(&self, n: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.root_prec_round_ref(n, prec, rm)
+    }
+
+    /// Takes the $n$th root of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded root is less than, equal to, or greater than the exact root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \sqrt[n]{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt[n]{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p}$.
+    ///
+    /// See the [`Float::root_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::root_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::root_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact root, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.root_prec_round_assign(3, 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.root_prec_round_assign(3, 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.root_prec_round_assign(3, 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.root_prec_round_assign(3, 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.46459");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.root_prec_round_assign(3, 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.464592");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.root_prec_round_assign(3, 20, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// ```
+    #[inline]
+    pub fn root_prec_round_assign(&mut self, n: u64, prec: u64, rm: RoundingMode) -> Ordering {
+        let (root, o) = root_prec_round_ref_helper(self, n, prec, rm);
+        *self = root;
+        o
+    }
+
+    /// Takes the $n$th root of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded root is
+    /// less than, equal to, or greater than the exact root. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \sqrt[n]{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt[n]{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt[n]{x}|\rfloor-p}$.
+    ///
+    /// See the [`Float::root_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::root_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_prec_assign(3, 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_prec_assign(3, 20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// ```
+    #[inline]
+    pub fn root_prec_assign(&mut self, n: u64, prec: u64) -> Ordering {
+        self.root_prec_round_assign(n, prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the $n$th root of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded root is less
+    /// than, equal to, or greater than the exact root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \sqrt[n]{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt[n]{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt[n]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt[n]{x}|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::root_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::root_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_round_assign(3, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.root_round_assign(3, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.4645918875615234");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.root_round_assign(3, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    /// ```
+    #[inline]
+    pub fn root_round_assign(&mut self, n: u64, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.root_prec_round_assign(n, prec, rm)
+    }
+}
@@ -0,0 +1,792 @@
+use crate::arithmetic::exp::{
+    cubic_term_is_negligible, integer_and_pow, round_nudged, to_fixed_point, ziv_round,
+};
+use crate::constants::pi::pi_fixed_point_bounds;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{
+    DivRound, FloorSqrt, ModPowerOf2, PowerOf2, ShrRound, Square,
+};
+use malachite_base::num::basic::traits::Zero as ZeroTrait;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Returns enclosures `sin_lo`$2^{-f} \leq \sin(t2^{-f}) \leq$ `sin_hi`$2^{-f}$ and
+// `cos_lo`$2^{-f} \leq \cos(t2^{-f}) \leq$ `cos_hi`$2^{-f}$, valid for all `t_lo` $\leq t \leq$
+// `t_hi`, where $|t2^{-f}| < 1$.
+//
+// The argument is divided by $2^s$, with $s$ around $\sqrt{f}/2$, both Taylor series are summed,
+// and the double-angle formulas $\sin 2a = 2\sin a\cos a$ and $\cos 2a = 1 - 2\sin^2 a$ are applied
+// $s$ times. Unlike the other fixed-point computations, which round every operation in a known
+// direction, this one tracks explicit error bounds, since the double-angle formulas are not
+// monotonic. Each doubling multiplies the errors by at most 6, which is paid for with $3s$ guard
+// bits.
+fn sin_cos_fixed_point(
+    t_lo: &Integer,
+    t_hi: &Integer,
+    f: u64,
+) -> (Integer, Integer, Integer, Integer) {
+    let s = (f.floor_sqrt() >> 1) + 1;
+    let g = f + 3 * s + f.significant_bits() + 10;
+    let u = t_lo << (g - f - s);
+    let u_abs = u.unsigned_abs_ref();
+    // Every computed term is within 4 of the true term $|u|^j2^{g(1-j)}/j!$.
+    let mut term = Natural::power_of_2(g);
+    let mut sin = Integer::ZERO;
+    let mut cos = Integer::from(&term);
+    let mut j = 0u64;
+    loop {
+        j += 1;
+        term = (term * u_abs)
+            .shr_round(g, RoundingMode::Floor)
+            .0
+            .div_round(Natural::from(j), RoundingMode::Floor)
+            .0;
+        if term == 0u32 {
+            break;
+        }
+        match j & 3 {
+            0 => cos += Integer::from(&term),
+            1 => sin += Integer::from(&term),
+            2 => cos -= Integer::from(&term),
+            _ => sin -= Integer::from(&term),
+        }
+    }
+    if u < 0u32 {
+        sin = -sin;
+    }
+    // Since $|u| < 1/2$, the omitted terms sum to less than 10.
+    let mut sin_error = Natural::from(4 * j + 10);
+    let mut cos_error = sin_error.clone();
+    let one = Integer::power_of_2(g);
+    for _ in 0..s {
+        let new_sin = (&sin * &cos).shr_round(g, RoundingMode::Floor).0 << 1;
+        cos = &one - ((&sin).square().shr_round(g, RoundingMode::Floor).0 << 1);
+        sin = new_sin;
+        let new_sin_error = (&cos_error << 2) + (&sin_error << 1) + Natural::from(2u32);
+        cos_error = sin_error * Natural::from(6u32) + Natural::from(2u32);
+        sin_error = new_sin_error;
+    }
+    // $\sin$ and $\cos$ are 1-Lipschitz.
+    let width = (t_hi - t_lo) << (g - f);
+    let sin_error = Integer::from(sin_error) + &width;
+    let cos_error = Integer::from(cos_error) + width;
+    (
+        (&sin - &sin_error).shr_round(g - f, RoundingMode::Floor).0,
+        (sin + sin_error).shr_round(g - f, RoundingMode::Ceiling).0,
+        (&cos - &cos_error).shr_round(g - f, RoundingMode::Floor).0,
+        (cos + cos_error).shr_round(g - f, RoundingMode::Ceiling).0,
+    )
+}
+
+// Enclosures of $\sin r$ and $\cos r$, where $x = k\pi/2 + r$ and $|r| \leq \pi/4$, at scale `f`.
+// `quadrant` is $k \bmod 4$.
+pub(crate) struct SinCosEnclosure {
+    pub(crate) quadrant: u64,
+    pub(crate) sin_lo: Integer,
+    pub(crate) sin_hi: Integer,
+    pub(crate) cos_lo: Integer,
+    pub(crate) cos_hi: Integer,
+    pub(crate) f: u64,
+}
+
+impl SinCosEnclosure {
+    // Returns enclosures of $\sin x$ and $\cos x$.
+    pub(crate) fn sin_cos(self) -> ((Integer, Integer), (Integer, Integer)) {
+        let sin = (self.sin_lo, self.sin_hi);
+        let cos = (self.cos_lo, self.cos_hi);
+        let negative_sin = (-&sin.1, -&sin.0);
+        let negative_cos = (-&cos.1, -&cos.0);
+        match self.quadrant {
+            0 => (sin, cos),
+            1 => (cos, negative_sin),
+            2 => (negative_sin, negative_cos),
+            _ => (negative_cos, sin),
+        }
+    }
+}
+
+// Returns enclosures of $\sin r$ and $\cos r$, where $x = m2^p$ is nonzero, $x = k\pi/2 + r$, and
+// $k$ is the integer closest to $2x/\pi$. The widths of the enclosures are roughly
+// $2^{-w}|\sin r|$.
+//
+// The reduction is exact: $\pi$ is computed with as many bits as the integer part of $x$ has, plus
+// the working precision, so even for huge $x$ no accuracy is lost. When $x$ is close to a multiple
+// of $\pi/2$, $r$ is small and the computation is repeated with enough extra precision to make up
+// for the cancellation; since $\pi$ is irrational, this always terminates.
+pub(crate) fn sin_cos_enclosure(m: &Integer, pow: i64, working_prec: u64) -> SinCosEnclosure {
+    let exponent = i64::exact_from(m.significant_bits()) + pow;
+    // $|r|$ is required to be at least $2^{-extra}$.
+    let mut extra = 0;
+    loop {
+        // $|k| < 2^e$, where $e$ is the exponent of $x$, so computing $x$ and $\pi/2$ with $e$ more
+        // bits than are needed for $r$ makes the error of $x - k\pi/2$ negligible.
+        let f = working_prec + extra + u64::exact_from(exponent.max(0)) + 14;
+        // $\pi2^{f-1} = (\pi/2)2^f$.
+        let (half_pi_lo, half_pi_hi) = pi_fixed_point_bounds(f - 1);
+        let (half_pi_lo, half_pi_hi) = (Integer::from(half_pi_lo), Integer::from(half_pi_hi));
+        let x_lo = to_fixed_point(m, pow, f, RoundingMode::Floor);
+        let x_hi = to_fixed_point(m, pow, f, RoundingMode::Ceiling);
+        let k: Integer = ((&x_lo << 1u32) + &half_pi_lo)
+            .div_round(&half_pi_lo << 1, RoundingMode::Floor)
+            .0;
+        let (r_lo, r_hi) = if k >= 0u32 {
+            (x_lo - &k * half_pi_hi, x_hi - &k * half_pi_lo)
+        } else {
+            (x_lo - &k * half_pi_lo, x_hi - &k * half_pi_hi)
+        };
+        if r_lo > 0u32 || r_hi < 0u32 {
+            // $|r| \geq 2^{b-1-f}$, where $b$ is the bit length of the end closer to zero.
+            let b = r_lo
+                .unsigned_abs_ref()
+                .min(r_hi.unsigned_abs_ref())
+                .significant_bits();
+            let needed = i64::exact_from(f) + 1 - i64::exact_from(b);
+            if i64::exact_from(extra) >= needed {
+                let (sin_lo, sin_hi, cos_lo, cos_hi) = sin_cos_fixed_point(&r_lo, &r_hi, f);
+                return SinCosEnclosure {
+                    quadrant: u64::exact_from(&k.mod_power_of_2(2)),
+                    sin_lo,
+                    sin_hi,
+                    cos_lo,
+                    cos_hi,
+                    f,
+                };
+            }
+            extra = u64::exact_from(needed);
+        } else {
+            // The enclosure of $r$ contains zero.
+            extra = (extra << 1).max(working_prec);
+        }
+    }
+}
+
+// Computes $\sin x$, where $x$ is finite and nonzero, rounding to `prec` bits.
+fn sin_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    let (m, pow) = integer_and_pow(x);
+    if cubic_term_is_negligible(&m, exponent, prec) {
+        // $|\sin x| < |x|$, and the difference is too small to affect the rounding.
+        let direction = if *x > 0u32 {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        return round_nudged(m, pow, direction, prec, rm);
+    }
+    ziv_round(prec, rm, |working_prec| {
+        let enclosure = sin_cos_enclosure(&m, pow, working_prec);
+        let f = enclosure.f;
+        let (lo, hi) = enclosure.sin_cos().0;
+        (lo, hi, -i64::exact_from(f))
+    })
+}
+
+// Computes $\sin x$, rounding to `prec` bits.
+fn sin_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { .. }) => (float_nan!(), Ordering::Equal),
+        Float(Zero { .. }) => (x.clone(), Ordering::Equal),
+        _ => sin_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the sine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sine is less than, equal to, or greater than the
+    /// exact sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sin x+\epsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with `prec` bits, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.18e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.224649e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        sin_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sine is less than, equal to, or greater than the
+    /// exact sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sin x+\epsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with `prec` bits, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.18e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.224649e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        sin_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded sine is less than, equal to, or greater than the exact sine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// If the sine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sin x+\epsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec(5);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec(20);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec(self, prec: u64) -> (Float, Ordering) {
+        self.sin_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded sine is less than, equal to, or greater than the exact sine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// If the sine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sin x+\epsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.sin_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sin x+\epsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sin_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn sin_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_prec_round(prec, rm)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sin x+\epsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sin_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn sin_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its sine, rounding the result to the specified precision and with
+    /// the specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \sin x+\epsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// See the [`Float::sin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with `prec` bits, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sin_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.18e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sin_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sin_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sin_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sin_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.224649e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sin_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// ```
+    #[inline]
+    pub fn sin_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (sin, o) = sin_prec_round_ref_helper(self, prec, rm);
+        *self = sin;
+        o
+    }
+
+    /// Replaces a [`Float`] with its sine, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded sine is
+    /// less than, equal to, or greater than the exact sine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \sin x+\epsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// See the [`Float::sin_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// ```
+    #[inline]
+    pub fn sin_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.sin_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its sine, rounding the result with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded sine is less than, equal to, or
+    /// greater than the exact sine. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \sin x+\epsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::sin_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.224646799147353e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    /// ```
+    #[inline]
+    pub fn sin_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.sin_prec_round_assign(prec, rm)
+    }
+}
@@ -0,0 +1,508 @@
+use crate::arithmetic::exp::{cubic_term_is_negligible, integer_and_pow, round_enclosure};
+use crate::arithmetic::sin::sin_cos_enclosure;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+
+// Computes $\sin x$ and $\cos x$, where $x$ is finite and nonzero, rounding both to `prec` bits.
+// The argument reduction, which is the expensive part when $x$ is large, is shared.
+fn sin_cos_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Float, Ordering, Ordering) {
+    let (m, pow) = integer_and_pow(x);
+    if cubic_term_is_negligible(&m, x.get_exponent().unwrap(), prec) {
+        // Both functions can be computed without an argument reduction.
+        let (sin, sin_o) = x.sin_prec_round_ref(prec, rm);
+        let (cos, cos_o) = x.cos_prec_round_ref(prec, rm);
+        return (sin, cos, sin_o, cos_o);
+    }
+    assert_ne!(rm, RoundingMode::Exact, "Inexact transcendental function");
+    let mut sin = None;
+    let mut cos = None;
+    let mut working_prec = prec + prec.significant_bits() + 10;
+    loop {
+        let enclosure = sin_cos_enclosure(&m, pow, working_prec);
+        let pow = -i64::exact_from(enclosure.f);
+        let ((sin_lo, sin_hi), (cos_lo, cos_hi)) = enclosure.sin_cos();
+        if sin.is_none() {
+            sin = round_enclosure(sin_lo, sin_hi, pow, prec, rm);
+        }
+        if cos.is_none() {
+            cos = round_enclosure(cos_lo, cos_hi, pow, prec, rm);
+        }
+        if let (Some((sin, sin_o)), Some((cos, cos_o))) = (&sin, &cos) {
+            return (sin.clone(), cos.clone(), *sin_o, *cos_o);
+        }
+        working_prec += working_prec >> 1;
+    }
+}
+
+// Computes $\sin x$ and $\cos x$, rounding both to `prec` bits.
+fn sin_cos_prec_round_ref_helper(
+    x: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Float, Ordering, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { .. }) => {
+            (float_nan!(), float_nan!(), Ordering::Equal, Ordering::Equal)
+        }
+        Float(Zero { .. }) => (
+            x.clone(),
+            Float::one_prec(prec),
+            Ordering::Equal,
+            Ordering::Equal,
+        ),
+        _ => sin_cos_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the sine and cosine of a [`Float`], rounding both results to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. Two
+    /// [`Ordering`]s are also returned, indicating whether the rounded sine and cosine are less
+    /// than, equal to, or greater than the exact sine and cosine, respectively. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument reduction
+    /// is only done once.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sin x+\epsilon_1, \cos x+\epsilon_2).
+    /// $$
+    /// The bounds on $\epsilon_1$ and $\epsilon_2$ are the same as the bounds on $\epsilon$ in
+    /// [`Float::sin_prec_round`] and [`Float::cos_prec_round`].
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(\pm\infty,p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p,m)=(0.0,1.0)$
+    /// - $f(-0.0,p,m)=(-0.0,1.0)$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_cos_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine or cosine is not exactly representable with `prec`
+    /// bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "0.098");
+    /// assert_eq!(cos.to_string(), "0.97");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "0.1");
+    /// assert_eq!(cos.to_string(), "1.0");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "0.1");
+    /// assert_eq!(cos.to_string(), "1.0");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "0.0998334");
+    /// assert_eq!(cos.to_string(), "0.995004");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "0.0998335");
+    /// assert_eq!(cos.to_string(), "0.995005");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "0.0998334");
+    /// assert_eq!(cos.to_string(), "0.995004");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_round(
+        self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Float, Ordering, Ordering) {
+        sin_cos_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the sine and cosine of a [`Float`], rounding both results to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. Two
+    /// [`Ordering`]s are also returned, indicating whether the rounded sine and cosine are less
+    /// than, equal to, or greater than the exact sine and cosine, respectively. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument reduction
+    /// is only done once.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sin x+\epsilon_1, \cos x+\epsilon_2).
+    /// $$
+    /// The bounds on $\epsilon_1$ and $\epsilon_2$ are the same as the bounds on $\epsilon$ in
+    /// [`Float::sin_prec_round_ref`] and [`Float::cos_prec_round_ref`].
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(\pm\infty,p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p,m)=(0.0,1.0)$
+    /// - $f(-0.0,p,m)=(-0.0,1.0)$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_cos_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine or cosine is not exactly representable with `prec`
+    /// bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "0.098");
+    /// assert_eq!(cos.to_string(), "0.97");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) =
+    ///     Float::from(0.1).sin_cos_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "0.1");
+    /// assert_eq!(cos.to_string(), "1.0");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) =
+    ///     Float::from(0.1).sin_cos_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "0.1");
+    /// assert_eq!(cos.to_string(), "1.0");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "0.0998334");
+    /// assert_eq!(cos.to_string(), "0.995004");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) =
+    ///     Float::from(0.1).sin_cos_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "0.0998335");
+    /// assert_eq!(cos.to_string(), "0.995005");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) =
+    ///     Float::from(0.1).sin_cos_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "0.0998334");
+    /// assert_eq!(cos.to_string(), "0.995004");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_round_ref(
+        &self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Float, Ordering, Ordering) {
+        sin_cos_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the sine and cosine of a [`Float`], rounding both results to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. Two [`Ordering`]s are also
+    /// returned, indicating whether the rounded sine and cosine are less than, equal to, or greater
+    /// than the exact sine and cosine, respectively. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument reduction
+    /// is only done once.
+    ///
+    /// If a result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = (\sin x+\epsilon_1, \cos x+\epsilon_2).
+    /// $$
+    /// The bounds on $\epsilon_1$ and $\epsilon_2$ are the same as the bounds on $\epsilon$ in
+    /// [`Float::sin_prec`] and [`Float::cos_prec`].
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=f(\pm\infty,p)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p)=(0.0,1.0)$
+    /// - $f(-0.0,p)=(-0.0,1.0)$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec(5);
+    /// assert_eq!(sin.to_string(), "0.1");
+    /// assert_eq!(cos.to_string(), "1.0");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec(20);
+    /// assert_eq!(sin.to_string(), "0.0998334");
+    /// assert_eq!(cos.to_string(), "0.995004");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec(self, prec: u64) -> (Float, Float, Ordering, Ordering) {
+        self.sin_cos_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the sine and cosine of a [`Float`], rounding both results to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. Two [`Ordering`]s are also
+    /// returned, indicating whether the rounded sine and cosine are less than, equal to, or greater
+    /// than the exact sine and cosine, respectively. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument reduction
+    /// is only done once.
+    ///
+    /// If a result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = (\sin x+\epsilon_1, \cos x+\epsilon_2).
+    /// $$
+    /// The bounds on $\epsilon_1$ and $\epsilon_2$ are the same as the bounds on $\epsilon$ in
+    /// [`Float::sin_prec_ref`] and [`Float::cos_prec_ref`].
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=f(\pm\infty,p)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p)=(0.0,1.0)$
+    /// - $f(-0.0,p)=(-0.0,1.0)$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec_ref(5);
+    /// assert_eq!(sin.to_string(), "0.1");
+    /// assert_eq!(cos.to_string(), "1.0");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_prec_ref(20);
+    /// assert_eq!(sin.to_string(), "0.0998334");
+    /// assert_eq!(cos.to_string(), "0.995004");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_ref(&self, prec: u64) -> (Float, Float, Ordering, Ordering) {
+        self.sin_cos_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the sine and cosine of a [`Float`], rounding both results with the specified
+    /// rounding mode. The [`Float`] is taken by value. Two [`Ordering`]s are also returned,
+    /// indicating whether the rounded sine and cosine are less than, equal to, or greater than the
+    /// exact sine and cosine, respectively. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument reduction
+    /// is only done once.
+    ///
+    /// The precision of the outputs is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = (\sin x+\epsilon_1, \cos x+\epsilon_2).
+    /// $$
+    /// The bounds on $\epsilon_1$ and $\epsilon_2$ are the same as the bounds on $\epsilon$ in
+    /// [`Float::sin_round`] and [`Float::cos_round`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=f(\pm\infty,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,m)=(0.0,1.0)$
+    /// - $f(-0.0,m)=(-0.0,1.0)$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_cos_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine or cosine is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_round(RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "0.09983341664682815");
+    /// assert_eq!(cos.to_string(), "0.9950041652780257");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_round(RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "0.09983341664682817");
+    /// assert_eq!(cos.to_string(), "0.9950041652780258");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_round(RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "0.09983341664682815");
+    /// assert_eq!(cos.to_string(), "0.9950041652780258");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn sin_cos_round(self, rm: RoundingMode) -> (Float, Float, Ordering, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_cos_prec_round(prec, rm)
+    }
+
+    /// Computes the sine and cosine of a [`Float`], rounding both results with the specified
+    /// rounding mode. The [`Float`] is taken by reference. Two [`Ordering`]s are also returned,
+    /// indicating whether the rounded sine and cosine are less than, equal to, or greater than the
+    /// exact sine and cosine, respectively. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument reduction
+    /// is only done once.
+    ///
+    /// The precision of the outputs is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = (\sin x+\epsilon_1, \cos x+\epsilon_2).
+    /// $$
+    /// The bounds on $\epsilon_1$ and $\epsilon_2$ are the same as the bounds on $\epsilon$ in
+    /// [`Float::sin_round_ref`] and [`Float::cos_round_ref`].
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=f(\pm\infty,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,m)=(0.0,1.0)$
+    /// - $f(-0.0,m)=(-0.0,1.0)$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_cos_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine or cosine is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_round_ref(RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "0.09983341664682815");
+    /// assert_eq!(cos.to_string(), "0.9950041652780257");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "0.09983341664682817");
+    /// assert_eq!(cos.to_string(), "0.9950041652780258");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(0.1).sin_cos_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "0.09983341664682815");
+    /// assert_eq!(cos.to_string(), "0.9950041652780258");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(o_cos, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn sin_cos_round_ref(&self, rm: RoundingMode) -> (Float, Float, Ordering, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_cos_prec_round_ref(prec, rm)
+    }
+}
@@ -0,0 +1,733 @@
+use crate::arithmetic::exp::{
+    cubic_term_is_negligible, exp_enclosure, exp_scale, integer_and_pow, overflow, round_nudged,
+    to_fixed_point, ziv_round,
+};
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{DivRound, PowerOf2, UnsignedAbs};
+use malachite_base::num::basic::traits::{One, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Enclosures of $E = e^{|x|}$ and $1/E$: `e_lo`$2^p \leq E \leq$ `e_hi`$2^p$ and
+// `inverse_lo`$2^p \leq 1/E \leq$ `inverse_hi`$2^p$.
+pub(crate) struct ExpAbsEnclosure {
+    pub(crate) e_lo: Integer,
+    pub(crate) e_hi: Integer,
+    pub(crate) inverse_lo: Integer,
+    pub(crate) inverse_hi: Integer,
+    pub(crate) pow: i64,
+}
+
+// Returns enclosures of $e^{|x|}$ and $e^{-|x|}$, where $x = m2^p$, $|x| < 2^{62}$, and the
+// fixed-point scale used for $|x|$ is `f`.
+pub(crate) fn exp_abs_enclosure(m: &Natural, pow: i64, f: u64) -> ExpAbsEnclosure {
+    let m = Integer::from(m);
+    let (e_lo, e_hi, pow) = exp_enclosure(
+        &to_fixed_point(&m, pow, f, RoundingMode::Floor),
+        &to_fixed_point(&m, pow, f, RoundingMode::Ceiling),
+        f,
+    );
+    let (inverse_lo, inverse_hi) = if pow <= 0 {
+        // $1/E = (2^{-2p}/E2^{-p})2^p$.
+        let numerator = Natural::power_of_2(pow.unsigned_abs() << 1);
+        (
+            (&numerator).div_round(&e_hi, RoundingMode::Floor).0,
+            numerator.div_round(&e_lo, RoundingMode::Ceiling).0,
+        )
+    } else {
+        // $0 < 1/E < 1 < 2^p$.
+        (Natural::ZERO, Natural::ONE)
+    };
+    ExpAbsEnclosure {
+        e_lo: Integer::from(e_lo),
+        e_hi: Integer::from(e_hi),
+        inverse_lo: Integer::from(inverse_lo),
+        inverse_hi: Integer::from(inverse_hi),
+        pow,
+    }
+}
+
+// Computes $\sinh x$, where $x$ is finite and nonzero, rounding to `prec` bits.
+fn sinh_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    let negative = *x < 0u32;
+    if exponent >= 63 {
+        // $|x| \geq 2^{62}$, so $\sinh x$ is treated as overflowing.
+        assert_ne!(rm, RoundingMode::Exact, "Inexact sinh");
+        return overflow(!negative, prec, rm);
+    }
+    let (m, pow) = integer_and_pow(x);
+    if cubic_term_is_negligible(&m, exponent, prec) {
+        // $|\sinh x| > |x|$, and the difference is too small to affect the rounding.
+        let direction = if negative {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        return round_nudged(m, pow, direction, prec, rm);
+    }
+    let m = m.unsigned_abs();
+    ziv_round(prec, rm, |working_prec| {
+        // When $|x|$ is small, $e^{|x|} - e^{-|x|}$ suffers from cancellation.
+        let f = exp_scale(
+            working_prec + u64::exact_from((-exponent).max(0)) + 2,
+            exponent,
+        );
+        let e = exp_abs_enclosure(&m, pow, f);
+        // $\sinh |x| = (E - 1/E)/2$.
+        let lo = e.e_lo - e.inverse_hi;
+        let hi = e.e_hi - e.inverse_lo;
+        if negative {
+            (-hi, -lo, e.pow - 1)
+        } else {
+            (lo, hi, e.pow - 1)
+        }
+    })
+}
+
+// Computes $\sinh x$, rounding to `prec` bits.
+fn sinh_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { .. }) | Float(Zero { .. }) => (x.clone(), Ordering::Equal),
+        _ => sinh_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded hyperbolic sine is less than, equal to, or
+    /// greater than the exact hyperbolic sine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sinh x+\epsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-\infty$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,p,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,p,m)=-\infty$ if $x\leq -2^{62}$ and $m$ is not `Ceiling` or `Down`
+    /// - $f(x,p,m)$ is the negative of the largest finite [`Float`] with precision $p$ if $x\leq
+    ///   -2^{62}$ and $m$ is `Ceiling` or `Down`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sinh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::sinh_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with `prec`
+    /// bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "12.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.54875");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        sinh_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded hyperbolic sine is less than, equal to, or
+    /// greater than the exact hyperbolic sine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sinh x+\epsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-\infty$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,p,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,p,m)=-\infty$ if $x\leq -2^{62}$ and $m$ is not `Ceiling` or `Down`
+    /// - $f(x,p,m)$ is the negative of the largest finite [`Float`] with precision $p$ if $x\leq
+    ///   -2^{62}$ and $m$ is `Ceiling` or `Down`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sinh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sinh_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with `prec`
+    /// bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "12.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.54875");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        sinh_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic sine is less than, equal to, or greater than the
+    /// exact hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the hyperbolic sine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sinh x+\epsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\sinh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-\infty$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\infty$ if $x\geq 2^{62}$
+    /// - $f(x,p)=-\infty$ if $x\leq -2^{62}$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sinh_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec(5);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec(20);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.sinh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic sine is less than, equal to, or greater than the
+    /// exact hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the hyperbolic sine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sinh x+\epsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\sinh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-\infty$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\infty$ if $x\geq 2^{62}$
+    /// - $f(x,p)=-\infty$ if $x\leq -2^{62}$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sinh_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.sinh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded hyperbolic sine is less than, equal to, or greater than the exact hyperbolic
+    /// sine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sinh x+\epsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-\infty$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,m)=-\infty$ if $x\leq -2^{62}$ and $m$ is not `Ceiling` or `Down`
+    /// - $f(x,m)$ is the negative of the largest finite [`Float`] with precision $p$ if $x\leq
+    ///   -2^{62}$ and $m$ is `Ceiling` or `Down`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sinh_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.548739357257748");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sinh_prec_round(prec, rm)
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded hyperbolic sine is less than, equal to, or greater than the exact
+    /// hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sinh x+\epsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-\infty$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\infty$ if $x\geq 2^{62}$ and $m$ is not `Floor` or `Down`
+    /// - $f(x,m)$ is the largest finite [`Float`] with precision $p$ if $x\geq 2^{62}$ and $m$ is
+    ///   `Floor` or `Down`
+    /// - $f(x,m)=-\infty$ if $x\leq -2^{62}$ and $m$ is not `Ceiling` or `Down`
+    /// - $f(x,m)$ is the negative of the largest finite [`Float`] with precision $p$ if $x\leq
+    ///   -2^{62}$ and $m$ is `Ceiling` or `Down`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sinh_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.548739357257748");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sinh_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its hyperbolic sine, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded hyperbolic sine is less than, equal to, or greater than the exact
+    /// hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \sinh x+\epsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$.
+    ///
+    /// See the [`Float::sinh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sinh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::sinh_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with `prec`
+    /// bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sinh_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sinh_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "12.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sinh_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sinh_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "11.54874");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sinh_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "11.54875");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sinh_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// ```
+    #[inline]
+    pub fn sinh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (sinh, o) = sinh_prec_round_ref_helper(self, prec, rm);
+        *self = sinh;
+        o
+    }
+
+    /// Replaces a [`Float`] with its hyperbolic sine, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// hyperbolic sine is less than, equal to, or greater than the exact hyperbolic sine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \sinh x+\epsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\sinh
+    ///   x|\rfloor-p}$.
+    ///
+    /// See the [`Float::sinh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sinh_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// ```
+    #[inline]
+    pub fn sinh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.sinh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its hyperbolic sine, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded hyperbolic sine
+    /// is less than, equal to, or greater than the exact hyperbolic sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \sinh x+\epsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::sinh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sinh_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sinh_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "11.548739357257748");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    /// ```
+    #[inline]
+    pub fn sinh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.sinh_prec_round_assign(prec, rm)
+    }
+}
@@ -0,0 +1,856 @@
+use crate::arithmetic::root::root_natural_times_power_of_2_prec_round;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, significand_bits, Float};
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+
+// Takes the square root of `x`, rounding to `prec` bits.
+fn sqrt_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { sign: false } | Finite { sign: false, .. }) => {
+            (float_nan!(), Ordering::Equal)
+        }
+        Float(Infinity { .. } | Zero { .. }) => (x.clone(), Ordering::Equal),
+        Float(Finite {
+            exponent,
+            significand,
+            ..
+        }) => root_natural_times_power_of_2_prec_round(
+            true,
+            significand.clone(),
+            exponent - i64::exact_from(significand_bits(significand)),
+            2,
+            prec,
+            rm,
+        ),
+    }
+}
+
+// Like `sqrt_prec_round_ref_helper`, but takes `x` by value, so that its significand can be reused.
+fn sqrt_prec_round_val_helper(x: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    match x {
+        Float(Finite {
+            sign: true,
+            exponent,
+            significand,
+            ..
+        }) => {
+            assert_ne!(prec, 0);
+            let pow = exponent - i64::exact_from(significand_bits(&significand));
+            root_natural_times_power_of_2_prec_round(true, significand, pow, 2, prec, rm)
+        }
+        x => sqrt_prec_round_ref_helper(&x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Takes the square root of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded square root is less than, equal to, or greater than
+    /// the exact square root. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p+1}$.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sqrt_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::sqrt_round`] instead. If both of these things are true, consider using
+    /// `Float::sqrt` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact square root, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.772453");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.772455");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.772453");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        sqrt_prec_round_val_helper(self, prec, rm)
+    }
+
+    /// Takes the square root of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded square root is less than, equal to, or greater
+    /// than the exact square root. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p+1}$.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sqrt_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sqrt_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).sqrt()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact square root, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.772453");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.772455");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.772453");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        sqrt_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Takes the square root of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded square root is less than, equal to, or greater than the exact
+    /// square root. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the square root is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using `Float::sqrt` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec(5);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec(20);
+    /// assert_eq!(x.to_string(), "1.772453");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec(self, prec: u64) -> (Float, Ordering) {
+        self.sqrt_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the square root of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded square root is less than, equal to, or greater than the exact
+    /// square root. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the square root is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).sqrt()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.772453");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.sqrt_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the square root of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded square root is less than, equal to, or greater than the exact square root. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p+1}$.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sqrt_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `Float::sqrt` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact square root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.7724538509055159");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.7724538509055161");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.7724538509055159");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sqrt_prec_round(prec, rm)
+    }
+
+    /// Takes the square root of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded square root is less than, equal to, or greater than the exact square root.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p+1}$.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sqrt_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).sqrt()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact square root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.7724538509055159");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.7724538509055161");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sqrt_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.7724538509055159");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sqrt_prec_round_ref(prec, rm)
+    }
+
+    /// Takes the square root of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded square root is less than, equal to, or greater than the exact square
+    /// root. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p+1}$.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p}$.
+    ///
+    /// See the [`Float::sqrt_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sqrt_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::sqrt_round_assign`] instead. If both of these things are true, consider using
+    /// `Float::sqrt_assign` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact square root, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sqrt_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.75");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sqrt_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.81");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sqrt_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.75");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sqrt_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.772453");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sqrt_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.772455");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sqrt_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.772453");
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (sqrt, o) = sqrt_prec_round_ref_helper(self, prec, rm);
+        *self = sqrt;
+        o
+    }
+
+    /// Takes the square root of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded square
+    /// root is less than, equal to, or greater than the exact square root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt{x}|\rfloor-p}$.
+    ///
+    /// See the [`Float::sqrt_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `Float::sqrt_assign` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.75");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.772453");
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.sqrt_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the square root of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded square root is
+    /// less than, equal to, or greater than the exact square root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p+1}$.
+    /// - If $\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt{x}|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::sqrt_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sqrt_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `Float::sqrt_assign` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision is too small for an exact square root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.7724538509055159");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sqrt_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.7724538509055161");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.7724538509055159");
+    /// ```
+    #[inline]
+    pub fn sqrt_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.sqrt_prec_round_assign(prec, rm)
+    }
+}
+
+impl Sqrt for Float {
+    type Output = Float;
+
+    /// Takes the square root of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square root is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_round`] instead. If you want to specify the output precision, consider using
+    /// [`Float::sqrt_prec`]. If you want both of these things, consider using
+    /// [`Float::sqrt_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.sqrt().is_nan());
+    /// assert_eq!(Float::INFINITY.sqrt(), Float::INFINITY);
+    /// assert!(Float::NEGATIVE_INFINITY.sqrt().is_nan());
+    /// assert_eq!(Float::ZERO.sqrt(), Float::ZERO);
+    /// assert_eq!(Float::NEGATIVE_ZERO.sqrt(), Float::NEGATIVE_ZERO);
+    /// assert_eq!(Float::from(PI).sqrt().to_string(), "1.7724538509055159");
+    /// assert_eq!(Float::from(0.25).sqrt(), 0.5);
+    /// assert!(Float::from(-4).sqrt().is_nan());
+    /// ```
+    #[inline]
+    fn sqrt(self) -> Float {
+        let prec = self.significant_bits();
+        self.sqrt_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Sqrt for &'a Float {
+    type Output = Float;
+
+    /// Takes the square root of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square root is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_round_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::sqrt_prec_ref`]. If you want both of these things, consider using
+    /// [`Float::sqrt_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).sqrt().is_nan());
+    /// assert_eq!((&Float::INFINITY).sqrt(), Float::INFINITY);
+    /// assert!((&Float::NEGATIVE_INFINITY).sqrt().is_nan());
+    /// assert_eq!((&Float::ZERO).sqrt(), Float::ZERO);
+    /// assert_eq!((&Float::NEGATIVE_ZERO).sqrt(), Float::NEGATIVE_ZERO);
+    /// assert_eq!((&Float::from(PI)).sqrt().to_string(), "1.7724538509055159");
+    /// assert_eq!((&Float::from(0.25)).sqrt(), 0.5);
+    /// assert!((&Float::from(-4)).sqrt().is_nan());
+    /// ```
+    #[inline]
+    fn sqrt(self) -> Float {
+        let prec = self.significant_bits();
+        self.sqrt_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl SqrtAssign for Float {
+    /// Takes the square root of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square root is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets \sqrt{x}+\epsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Sqrt`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_round_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::sqrt_prec_assign`]. If you want both of these things, consider using
+    /// [`Float::sqrt_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::SqrtAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.sqrt_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.sqrt_assign();
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.sqrt_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.sqrt_assign();
+    /// assert_eq!(x, Float::ZERO);
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.sqrt_assign();
+    /// assert_eq!(x, Float::NEGATIVE_ZERO);
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.sqrt_assign();
+    /// assert_eq!(x.to_string(), "1.7724538509055159");
+    ///
+    /// let mut x = Float::from(0.25);
+    /// x.sqrt_assign();
+    /// assert_eq!(x, 0.5);
+    /// ```
+    #[inline]
+    fn sqrt_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.sqrt_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
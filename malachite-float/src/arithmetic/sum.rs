@@ -0,0 +1,428 @@
+use crate::arithmetic::exp::{integer_and_pow, round_nudged};
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{
+    float_infinity, float_nan, float_negative_infinity, float_negative_zero, float_zero, Float,
+};
+use malachite_base::num::arithmetic::traits::Sign;
+use malachite_base::num::basic::traits::Zero as ZeroTrait;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use std::cmp::Ordering;
+
+// A summand of an exactly-accumulated sum: either a special value, or $m2^p$ with $m$ nonzero.
+pub(crate) enum Term {
+    NaN,
+    Infinity(bool),
+    Zero(bool),
+    Finite(Integer, i64),
+}
+
+pub(crate) fn term(x: &Float) -> Term {
+    match x {
+        Float(NaN) => Term::NaN,
+        Float(Infinity { sign }) => Term::Infinity(*sign),
+        Float(Zero { sign }) => Term::Zero(*sign),
+        _ => {
+            let (m, p) = integer_and_pow(x);
+            Term::Finite(m, p)
+        }
+    }
+}
+
+pub(crate) fn product_term(x: &Float, y: &Float) -> Term {
+    match (x, y) {
+        (Float(NaN), _)
+        | (_, Float(NaN))
+        | (Float(Infinity { .. }), Float(Zero { .. }))
+        | (Float(Zero { .. }), Float(Infinity { .. })) => Term::NaN,
+        (Float(Infinity { .. }), _) | (_, Float(Infinity { .. })) => {
+            Term::Infinity(x.is_sign_positive() == y.is_sign_positive())
+        }
+        (Float(Zero { .. }), _) | (_, Float(Zero { .. })) => {
+            Term::Zero(x.is_sign_positive() == y.is_sign_positive())
+        }
+        _ => {
+            let (mx, px) = integer_and_pow(x);
+            let (my, py) = integer_and_pow(y);
+            Term::Finite(mx * my, px + py)
+        }
+    }
+}
+
+// Rounds $\sum_i m_i2^{p_i}$, where each $m_i$ is nonzero, to `prec` bits. Returns `None` if the
+// sum is exactly zero.
+//
+// The terms are sorted by decreasing magnitude and split into clusters, where a new cluster starts
+// whenever there is a gap of more than $g$ bits between a term and the lowest bit of the current
+// cluster. Each cluster is summed exactly. A nonzero cluster sum $S2^q$ is at least $2^q$ in
+// absolute value, while all the later terms together are less than $2^{q-p-1}$; so the first
+// nonzero cluster sum determines the result, and the next one only determines the direction in
+// which it's nudged. This keeps memory use proportional to the sizes of the terms, even when their
+// exponents are far apart.
+fn round_finite_terms(
+    mut terms: Vec<(Integer, i64)>,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    let top = |(m, p): &(Integer, i64)| p + i64::exact_from(m.significant_bits());
+    terms.sort_unstable_by_key(|t| std::cmp::Reverse(top(t)));
+    let gap = i64::exact_from(prec + 2 + u64::exact_from(terms.len()).significant_bits());
+    let mut leading: Option<(Integer, i64)> = None;
+    let mut i = 0;
+    while i < terms.len() {
+        let mut q = terms[i].1;
+        let mut j = i + 1;
+        while j < terms.len() && top(&terms[j]) >= q.saturating_sub(gap) {
+            q = q.min(terms[j].1);
+            j += 1;
+        }
+        let mut sum = Integer::ZERO;
+        for (m, p) in &terms[i..j] {
+            sum += m << u64::exact_from(p - q);
+        }
+        i = j;
+        if sum != 0u32 {
+            if let Some((m, p)) = leading {
+                assert_ne!(rm, RoundingMode::Exact, "Inexact Float sum");
+                return Some(round_nudged(m, p, sum.sign(), prec, rm));
+            }
+            leading = Some((sum, q));
+        }
+    }
+    leading.map(|(m, p)| Float::from_integer_times_power_of_2_prec_round(m, p, prec, rm))
+}
+
+// Rounds the exact sum of the terms to `prec` bits. The special cases are those of repeated
+// addition: `NaN`s and infinities of both signs give `NaN`, an exactly zero sum of nonzero terms is
+// `0.0` (or `-0.0` when rounding with `Floor`), and a sum of zeros is `-0.0` only if all of them
+// are negative (or when rounding with `Floor` and some of them are).
+pub(crate) fn sum_terms_prec_round<I: Iterator<Item = Term>>(
+    terms: I,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    let mut nan = false;
+    let mut positive_infinity = false;
+    let mut negative_infinity = false;
+    let mut positive_zero = false;
+    let mut negative_zero = false;
+    let mut finite = Vec::new();
+    for t in terms {
+        match t {
+            Term::NaN => nan = true,
+            Term::Infinity(true) => positive_infinity = true,
+            Term::Infinity(false) => negative_infinity = true,
+            Term::Zero(true) => positive_zero = true,
+            Term::Zero(false) => negative_zero = true,
+            Term::Finite(m, p) => finite.push((m, p)),
+        }
+    }
+    if nan || positive_infinity && negative_infinity {
+        return (float_nan!(), Ordering::Equal);
+    } else if positive_infinity {
+        return (float_infinity!(), Ordering::Equal);
+    } else if negative_infinity {
+        return (float_negative_infinity!(), Ordering::Equal);
+    }
+    let zero_is_negative = if finite.is_empty() {
+        negative_zero && (!positive_zero || rm == RoundingMode::Floor)
+    } else {
+        rm == RoundingMode::Floor
+    };
+    round_finite_terms(finite, prec, rm).unwrap_or((
+        if zero_is_negative {
+            float_negative_zero!()
+        } else {
+            float_zero!()
+        },
+        Ordering::Equal,
+    ))
+}
+
+impl Float {
+    /// Adds up all the [`Float`]s in an iterator of [`Float`] references, rounding the result to
+    /// the specified precision and with the specified rounding mode. The sum is computed exactly
+    /// and rounded only once, so the result is correctly rounded no matter how many terms there
+    /// are or how much they cancel. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sum is less than, equal to, or greater than the exact sum. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1},p,m) = \sum_ {i=0}^{n-1} x_i+\epsilon.
+    /// $$
+    /// - If $\sum_ {i=0}^{n-1} x_i$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\sum_ {i=0}^{n-1} x_i$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\sum_ {i=0}^{n-1} x_i|\rfloor-p+1}$.
+    /// - If $\sum_ {i=0}^{n-1} x_i$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\sum_ {i=0}^{n-1} x_i|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f((),p,m)=0.0$
+    /// - $f((x_i)_ {i=0}^{n-1},p,m)=\text{NaN}$ if some $x_i$ is NaN, or if both $\infty$ and
+    ///   $-\infty$ are among the $x_i$
+    /// - $f((x_i)_ {i=0}^{n-1},p,m)=\infty$ if $\infty$ is among the $x_i$ and neither NaN nor
+    ///   $-\infty$ is
+    /// - $f((x_i)_ {i=0}^{n-1},p,m)=-\infty$ if $-\infty$ is among the $x_i$ and neither NaN nor
+    ///   $\infty$ is
+    /// - $f((x_i)_ {i=0}^{n-1},p,m)=0.0$ if the $x_i$ are finite, not all zero, and sum to zero,
+    ///   and $m$ is not `Floor`
+    /// - $f((x_i)_ {i=0}^{n-1},p,m)=-0.0$ if the $x_i$ are finite, not all zero, and sum to zero,
+    ///   and $m$ is `Floor`
+    /// - If all the $x_i$ are zero, the result is $-0.0$ if they are all $-0.0$, or if some of them
+    ///   are and $m$ is `Floor`; otherwise, it is $0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sum_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the `significant_bits`
+    /// of the [`Float`]s, plus `prec` times the number of [`Float`]s.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sum cannot be represented exactly with the given
+    /// precision, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// // Adding these one at a time with 53 bits of precision would give 0.
+    /// let xs = [Float::from(1.0e100), Float::from(1.0), Float::from(-1.0e100)];
+    /// let (s, o) = Float::sum_prec_round(&xs, 53, RoundingMode::Nearest);
+    /// assert_eq!(s.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let xs = [Float::from(1.0), Float::from(0.1), Float::from(0.01)];
+    /// let (s, o) = Float::sum_prec_round(&xs, 10, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "1.109");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (s, o) = Float::sum_prec_round(&xs, 10, RoundingMode::Ceiling);
+    /// assert_eq!(s.to_string(), "1.111");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let xs = [Float::from(2.0), Float::from(-2.0)];
+    /// let (s, o) = Float::sum_prec_round(&xs, 10, RoundingMode::Floor);
+    /// assert_eq!(s.to_string(), "-0.0");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    pub fn sum_prec_round<'a, I: IntoIterator<Item = &'a Float>>(
+        xs: I,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        sum_terms_prec_round(xs.into_iter().map(term), prec, rm)
+    }
+
+    /// Adds up all the [`Float`]s in an iterator of [`Float`] references, rounding the result to
+    /// the nearest value of the specified precision. The sum is computed exactly and rounded only
+    /// once. An [`Ordering`] is also returned, indicating whether the rounded sum is less than,
+    /// equal to, or greater than the exact sum. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1},p) = \sum_ {i=0}^{n-1} x_i+\epsilon.
+    /// $$
+    /// - If $\sum_ {i=0}^{n-1} x_i$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\sum_ {i=0}^{n-1} x_i$ is finite and nonzero, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\sum_ {i=0}^{n-1} x_i|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See [`Float::sum_prec_round`] for the special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sum_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the `significant_bits`
+    /// of the [`Float`]s, plus `prec` times the number of [`Float`]s.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let xs = [Float::from(1.0e100), Float::from(1.0), Float::from(-1.0e100)];
+    /// let (s, o) = Float::sum_prec(&xs, 10);
+    /// assert_eq!(s.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let xs = [Float::from(1.0), Float::from(0.1), Float::from(0.01)];
+    /// let (s, o) = Float::sum_prec(&xs, 10);
+    /// assert_eq!(s.to_string(), "1.109");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sum_prec<'a, I: IntoIterator<Item = &'a Float>>(xs: I, prec: u64) -> (Float, Ordering) {
+        Float::sum_prec_round(xs, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the dot product of two sequences of [`Float`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode. The products and their sum are
+    /// computed exactly and rounded only once, so the result is correctly rounded. An [`Ordering`]
+    /// is also returned, indicating whether the rounded dot product is less than, equal to, or
+    /// greater than the exact dot product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1},(y_i)_ {i=0}^{n-1},p,m) = \sum_ {i=0}^{n-1} x_iy_i+\epsilon.
+    /// $$
+    /// - If $\sum_ {i=0}^{n-1} x_iy_i$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\sum_ {i=0}^{n-1} x_iy_i$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\sum_ {i=0}^{n-1} x_iy_i|\rfloor-p+1}$.
+    /// - If $\sum_ {i=0}^{n-1} x_iy_i$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\sum_ {i=0}^{n-1} x_iy_i|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// The special cases are those of [`Float::sum_prec_round`] applied to the products
+    /// $x_iy_i$, where $0\infty$ is NaN and a zero product is negative if exactly one of its
+    /// factors is negative.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::dot_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the `significant_bits`
+    /// of the [`Float`]s, plus `prec` times the number of products.
+    ///
+    /// # Panics
+    /// Panics if `xs` and `ys` have different lengths, if `rm` is `Exact` but the dot product
+    /// cannot be represented exactly with the given precision, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// // (1 + 2^-30)(1 - 2^-30) - 1 = -2^-60, but rounding the product to 53 bits would give 0.
+    /// let xs = [Float::from(1.0 + 2.0f64.powi(-30)), Float::from(-1.0)];
+    /// let ys = [Float::from(1.0 - 2.0f64.powi(-30)), Float::from(1.0)];
+    /// let (d, o) = Float::dot_prec_round(&xs, &ys, 10, RoundingMode::Nearest);
+    /// assert_eq!(d.to_string(), "-8.67e-19");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let xs = [Float::from(1.0), Float::from(0.1)];
+    /// let ys = [Float::from(1.0), Float::from(0.1)];
+    /// let (d, o) = Float::dot_prec_round(&xs, &ys, 10, RoundingMode::Floor);
+    /// assert_eq!(d.to_string(), "1.01");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (d, o) = Float::dot_prec_round(&xs, &ys, 10, RoundingMode::Ceiling);
+    /// assert_eq!(d.to_string(), "1.012");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    pub fn dot_prec_round<
+        'a,
+        I: IntoIterator<Item = &'a Float>,
+        J: IntoIterator<Item = &'a Float>,
+    >(
+        xs: I,
+        ys: J,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let mut xs = xs.into_iter();
+        let mut ys = ys.into_iter();
+        let mut products = Vec::new();
+        loop {
+            match (xs.next(), ys.next()) {
+                (Some(x), Some(y)) => products.push(product_term(x, y)),
+                (None, None) => break,
+                _ => panic!("Dot product of sequences with different lengths"),
+            }
+        }
+        sum_terms_prec_round(products.into_iter(), prec, rm)
+    }
+
+    /// Computes the dot product of two sequences of [`Float`]s, rounding the result to the nearest
+    /// value of the specified precision. The products and their sum are computed exactly and
+    /// rounded only once. An [`Ordering`] is also returned, indicating whether the rounded dot
+    /// product is less than, equal to, or greater than the exact dot product. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// If the dot product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1},(y_i)_ {i=0}^{n-1},p) = \sum_ {i=0}^{n-1} x_iy_i+\epsilon.
+    /// $$
+    /// - If $\sum_ {i=0}^{n-1} x_iy_i$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\sum_ {i=0}^{n-1} x_iy_i$ is finite and nonzero, then
+    ///   $|\epsilon| < 2^{\lfloor\log_2 |\sum_ {i=0}^{n-1} x_iy_i|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See [`Float::dot_prec_round`] for the special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::dot_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the `significant_bits`
+    /// of the [`Float`]s, plus `prec` times the number of products.
+    ///
+    /// # Panics
+    /// Panics if `xs` and `ys` have different lengths, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let xs = [Float::from(3.0), Float::from(0.1)];
+    /// let ys = [Float::from(5.0), Float::from(-10.0)];
+    /// let (d, o) = Float::dot_prec(&xs, &ys, 10);
+    /// assert_eq!(d.to_string(), "14.0");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn dot_prec<'a, I: IntoIterator<Item = &'a Float>, J: IntoIterator<Item = &'a Float>>(
+        xs: I,
+        ys: J,
+        prec: u64,
+    ) -> (Float, Ordering) {
+        Float::dot_prec_round(xs, ys, prec, RoundingMode::Nearest)
+    }
+}
@@ -0,0 +1,683 @@
+use crate::arithmetic::exp::{cubic_term_is_negligible, integer_and_pow, round_nudged, ziv_round};
+use crate::arithmetic::ln::div_enclosure_by_positive;
+use crate::arithmetic::sin::sin_cos_enclosure;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Given enclosures `lo`$2^{-f} \leq a \leq$ `hi`$2^{-f}$ and `d_lo`$2^{-f} \leq d \leq$
+// `d_hi`$2^{-f}$, where $d$ is nonzero, returns an enclosure of $a/d$ with the same scale.
+fn div_enclosure(
+    lo: Integer,
+    hi: Integer,
+    d_lo: Integer,
+    d_hi: Integer,
+    f: u64,
+) -> (Integer, Integer) {
+    if d_lo > 0u32 {
+        div_enclosure_by_positive(
+            lo,
+            hi,
+            &Natural::exact_from(d_lo),
+            &Natural::exact_from(d_hi),
+            f,
+        )
+    } else {
+        div_enclosure_by_positive(
+            -hi,
+            -lo,
+            &Natural::exact_from(-d_hi),
+            &Natural::exact_from(-d_lo),
+            f,
+        )
+    }
+}
+
+// Computes $\tan x$, where $x$ is finite and nonzero, rounding to `prec` bits.
+fn tan_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = x.get_exponent().unwrap();
+    let (m, pow) = integer_and_pow(x);
+    if cubic_term_is_negligible(&m, exponent, prec) {
+        // $|\tan x| > |x|$, and the difference is too small to affect the rounding.
+        let direction = if *x > 0u32 {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+        return round_nudged(m, pow, direction, prec, rm);
+    }
+    ziv_round(prec, rm, |working_prec| {
+        let enclosure = sin_cos_enclosure(&m, pow, working_prec);
+        let f = enclosure.f;
+        // $\tan x = \tan r$ if $k$ is even and $-\cot r$ if $k$ is odd. `sin_cos_enclosure`
+        // guarantees that the enclosure of $\sin r$ doesn't contain zero.
+        let (lo, hi) = if enclosure.quadrant & 1 == 0 {
+            div_enclosure(
+                enclosure.sin_lo,
+                enclosure.sin_hi,
+                enclosure.cos_lo,
+                enclosure.cos_hi,
+                f,
+            )
+        } else {
+            div_enclosure(
+                -enclosure.cos_hi,
+                -enclosure.cos_lo,
+                enclosure.sin_lo,
+                enclosure.sin_hi,
+                f,
+            )
+        };
+        (lo, hi, -i64::exact_from(f))
+    })
+}
+
+// Computes $\tan x$, rounding to `prec` bits.
+fn tan_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() | Float(Infinity { .. }) => (float_nan!(), Ordering::Equal),
+        Float(Zero { .. }) => (x.clone(), Ordering::Equal),
+        _ => tan_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the tangent of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded tangent is less than, equal to, or greater than the
+    /// exact tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tan x+\epsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the tangent is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-1.18e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.224649e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tan_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        tan_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded tangent is less than, equal to, or greater than the
+    /// exact tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tan x+\epsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the tangent is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-1.18e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.224649e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tan_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        tan_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded tangent is less than, equal to, or greater than the exact
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \tan x+\epsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec(5);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec(20);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tan_prec(self, prec: u64) -> (Float, Ordering) {
+        self.tan_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded tangent is less than, equal to, or greater than the exact
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \tan x+\epsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_ref(5);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tan_prec_ref(20);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tan_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.tan_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded tangent is less than, equal to, or greater than the exact tangent. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \tan x+\epsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the tangent is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tan_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tan_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-1.224646799147353e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tan_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn tan_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.tan_prec_round(prec, rm)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded tangent is less than, equal to, or greater than the exact tangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \tan x+\epsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the tangent is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tan_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tan_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-1.224646799147353e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tan_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn tan_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.tan_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its tangent, rounding the result to the specified precision and
+    /// with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded tangent is less than, equal to, or greater than the exact tangent. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \tan x+\epsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the tangent is not exactly representable with `prec` bits, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tan_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tan_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-1.18e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tan_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tan_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-1.224649e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tan_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tan_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// ```
+    #[inline]
+    pub fn tan_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (tan, o) = tan_prec_round_ref_helper(self, prec, rm);
+        *self = tan;
+        o
+    }
+
+    /// Replaces a [`Float`] with its tangent, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded tangent is
+    /// less than, equal to, or greater than the exact tangent. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \tan x+\epsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$.
+    ///
+    /// See the [`Float::tan_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// ```
+    #[inline]
+    pub fn tan_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.tan_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its tangent, rounding the result with the specified rounding mode.
+    /// An [`Ordering`] is returned, indicating whether the rounded tangent is less than, equal to,
+    /// or greater than the exact tangent. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \tan x+\epsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::tan_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the tangent is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.2246467991473532e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "-1.224646799147353e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.2246467991473532e-16");
+    /// ```
+    #[inline]
+    pub fn tan_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.tan_prec_round_assign(prec, rm)
+    }
+}
@@ -0,0 +1,682 @@
+use crate::arithmetic::exp::{
+    cubic_term_is_negligible, exp_scale, integer_and_pow, round_nudged, ziv_round,
+};
+use crate::arithmetic::ln::div_enclosure_by_positive;
+use crate::arithmetic::sinh::exp_abs_enclosure;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::basic::traits::{NegativeOne, One};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+// Computes $\tanh x$, where $x$ is finite and nonzero, rounding to `prec` bits.
+fn tanh_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let negative = *x < 0u32;
+    let threshold = prec / 2 + 4;
+    if *x >= threshold || *x <= -i64::exact_from(threshold) {
+        // $1 - |\tanh x| < 2e^{-2|x|} < 2^{-p-3}$, so $\tanh x$ rounds like
+        // $\pm(1 - \varepsilon)$.
+        return if negative {
+            round_nudged(Integer::NEGATIVE_ONE, 0, Ordering::Greater, prec, rm)
+        } else {
+            round_nudged(Integer::ONE, 0, Ordering::Less, prec, rm)
+        };
+    }
+    let exponent = x.get_exponent().unwrap();
+    let (m, pow) = integer_and_pow(x);
+    if cubic_term_is_negligible(&m, exponent, prec) {
+        // $|\tanh x| < |x|$, and the difference is too small to affect the rounding.
+        let direction = if negative {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+        return round_nudged(m, pow, direction, prec, rm);
+    }
+    let m = m.unsigned_abs();
+    ziv_round(prec, rm, |working_prec| {
+        let g = working_prec + u64::exact_from((-exponent).max(0)) + 2;
+        let e = exp_abs_enclosure(&m, pow, exp_scale(g, exponent));
+        // $\tanh |x| = (E - 1/E)/(E + 1/E)$.
+        let (lo, hi) = div_enclosure_by_positive(
+            e.e_lo.clone() - &e.inverse_hi,
+            e.e_hi.clone() - &e.inverse_lo,
+            &Natural::exact_from(e.e_lo + e.inverse_lo),
+            &Natural::exact_from(e.e_hi + e.inverse_hi),
+            g,
+        );
+        let pow = -i64::exact_from(g);
+        if negative {
+            (-hi, -lo, pow)
+        } else {
+            (lo, hi, pow)
+        }
+    })
+}
+
+// Computes $\tanh x$, rounding to `prec` bits.
+fn tanh_prec_round_ref_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match x {
+        float_nan!() => (float_nan!(), Ordering::Equal),
+        Float(Infinity { sign: true }) => (Float::one_prec(prec), Ordering::Equal),
+        Float(Infinity { sign: false }) => (-Float::one_prec(prec), Ordering::Equal),
+        Float(Zero { .. }) => (x.clone(), Ordering::Equal),
+        _ => tanh_finite(x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded hyperbolic tangent is less
+    /// than, equal to, or greater than the exact hyperbolic tangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tanh x+\epsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=1.0$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tanh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::tanh_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with
+    /// `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.97");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.996271");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        tanh_prec_round_ref_helper(&self, prec, rm)
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded hyperbolic tangent is less
+    /// than, equal to, or greater than the exact hyperbolic tangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Ordering::Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tanh x+\epsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=1.0$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tanh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::tanh_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with
+    /// `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.97");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.996271");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        tanh_prec_round_ref_helper(self, prec, rm)
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic tangent is less than, equal to, or greater than
+    /// the exact hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the hyperbolic tangent is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \tanh x+\epsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\tanh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=1.0$
+    /// - $f(-\infty,p)=-1.0$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tanh_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec(5);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec(20);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.tanh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded hyperbolic tangent is less than, equal to, or
+    /// greater than the exact hyperbolic tangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// If the hyperbolic tangent is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \tanh x+\epsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\tanh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=1.0$
+    /// - $f(-\infty,p)=-1.0$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tanh_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.tanh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded hyperbolic tangent is less than, equal to, or greater than the exact
+    /// hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \tanh x+\epsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=1.0$
+    /// - $f(-\infty,m)=-1.0$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tanh_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.9962720762207499");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.tanh_prec_round(prec, rm)
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic tangent is less than, equal to, or greater than
+    /// the exact hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \tanh x+\epsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=1.0$
+    /// - $f(-\infty,m)=-1.0$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tanh_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.9962720762207499");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.tanh_prec_round_ref(prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its hyperbolic tangent, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded hyperbolic tangent is less than, equal to, or greater than the exact
+    /// hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \tanh x+\epsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$.
+    ///
+    /// See the [`Float::tanh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tanh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::tanh_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with
+    /// `prec` bits, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tanh_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.97");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tanh_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tanh_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tanh_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.996271");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tanh_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.996272");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tanh_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// ```
+    #[inline]
+    pub fn tanh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (tanh, o) = tanh_prec_round_ref_helper(self, prec, rm);
+        *self = tanh;
+        o
+    }
+
+    /// Replaces a [`Float`] with its hyperbolic tangent, rounding the result to the nearest value
+    /// of the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// hyperbolic tangent is less than, equal to, or greater than the exact hyperbolic tangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// $$
+    /// x \gets \tanh x+\epsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero, then $|\epsilon| < 2^{\lfloor\log_2 |\tanh
+    ///   x|\rfloor-p}$.
+    ///
+    /// See the [`Float::tanh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tanh_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// ```
+    #[inline]
+    pub fn tanh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.tanh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its hyperbolic tangent, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded hyperbolic
+    /// tangent is less than, equal to, or greater than the exact hyperbolic tangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Ordering::Equal`.
+    ///
+    /// The precision of the output is the precision of the input.
+    ///
+    /// $$
+    /// x \gets \tanh x+\epsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\epsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\epsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$.
+    /// - $p$ is the precision of the input.
+    ///
+    /// See the [`Float::tanh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tanh_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.9962720762207499");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tanh_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.tanh_round_assign(RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    /// ```
+    #[inline]
+    pub fn tanh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.tanh_prec_round_assign(prec, rm)
+    }
+}
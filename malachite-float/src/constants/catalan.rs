@@ -0,0 +1,186 @@
+use crate::arithmetic::ln::ln_interval_enclosure;
+use crate::constants::pi::pi_fixed_point_bounds;
+use crate::constants::{cached_fixed_point_bounds, constant_prec_round, FixedPointCache};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{CeilingSqrt, DivRound, FloorSqrt, ShrRound, Square};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static CATALAN_CACHE: FixedPointCache = const { RefCell::new(None) };
+}
+
+// Returns $P$, $Q$, $B$ and $T$ for the terms of
+// $\sum_{k=0}^\infty (k!)^2/((2k)!(2k+1)^2)$ with indices in $[a, b)$, where $a < b$. Writing
+// the $k$th term as $\prod_{j=0}^k (p_j/q_j)/b_k$, with $p_0 = q_0 = 1$, $p_j = j$,
+// $q_j = 2(2j-1)$, and $b_k = (2k+1)^2$, $P/Q = \prod_{k=a}^{b-1} p_k/q_k$,
+// $B = \prod_{k=a}^{b-1} b_k$, and the partial sum is $T/(BQ)$ times
+// $\prod_{j=0}^{a-1} p_j/q_j$.
+fn catalan_split(a: u64, b: u64) -> (Natural, Natural, Natural, Natural) {
+    if b - a == 1 {
+        let (p, q) = if a == 0 {
+            (Natural::ONE, Natural::ONE)
+        } else {
+            (Natural::from(a), Natural::from(((a << 1) - 1) << 1))
+        };
+        return (p.clone(), q, Natural::from((a << 1) + 1).square(), p);
+    }
+    let m = (a + b) >> 1;
+    let (p_left, q_left, b_left, t_left) = catalan_split(a, m);
+    let (p_right, q_right, b_right, t_right) = catalan_split(m, b);
+    let t = &b_right * &q_right * t_left + &b_left * &p_left * t_right;
+    (p_left * p_right, q_left * q_right, b_left * b_right, t)
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq G \leq$ `hi`$2^{-f}$, using
+// $$
+// G = \frac{\pi}{8}\log(2+\sqrt{3}) + \frac{3}{8}\sum_{k=0}^\infty
+// \frac{(k!)^2}{(2k)!(2k+1)^2}.
+// $$
+fn catalan_fixed_point_bounds_uncached(f: u64) -> (Natural, Natural) {
+    let g = f + 8;
+    // Each term is less than a quarter of the previous one, so the terms omitted after the first
+    // $N$ sum to less than $4^{1-N}/3 < 2^{-g}$.
+    let n = (g >> 1) + 1;
+    let (_, q, b, t) = catalan_split(0, n);
+    let denominator = b * q;
+    let t = t << g;
+    let sum_lo = (&t).div_round(&denominator, RoundingMode::Floor).0;
+    let sum_hi = t.div_round(denominator, RoundingMode::Ceiling).0 + Natural::ONE;
+    // $2 + \sqrt{3}$, at scale $g$.
+    let square = Natural::from(3u32) << (g << 1);
+    let two = Natural::from(2u32) << g;
+    let root_lo = &two + (&square).floor_sqrt();
+    let root_hi = two + square.ceiling_sqrt();
+    let (ln_lo, ln_hi, ln_f) = ln_interval_enclosure(&root_lo, &root_hi, -i64::exact_from(g), g);
+    // $\log(2 + \sqrt{3}) > 1$, so the bounds are positive.
+    let ln_lo = Natural::exact_from(ln_lo.shr_round(ln_f - g, RoundingMode::Floor).0);
+    let ln_hi = Natural::exact_from(ln_hi.shr_round(ln_f - g, RoundingMode::Ceiling).0);
+    let (pi_lo, pi_hi) = pi_fixed_point_bounds(g);
+    // `lo` and `hi` bound $8G$ at scale $2g$.
+    let lo = pi_lo * ln_lo + ((sum_lo * Natural::from(3u32)) << g);
+    let hi = pi_hi * ln_hi + ((sum_hi * Natural::from(3u32)) << g);
+    let shift = (g << 1) + 3 - f;
+    (
+        lo.shr_round(shift, RoundingMode::Floor).0,
+        hi.shr_round(shift, RoundingMode::Ceiling).0,
+    )
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq G \leq$ `hi`$2^{-f}$.
+pub(crate) fn catalan_fixed_point_bounds(f: u64) -> (Natural, Natural) {
+    cached_fixed_point_bounds(&CATALAN_CACHE, f, catalan_fixed_point_bounds_uncached)
+}
+
+impl Float {
+    /// Returns an approximation of Catalan's constant, $G$, with the given precision and rounded
+    /// using the given [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = G+\epsilon.
+    /// $$
+    /// - If $m$ is not `Nearest`, then $|\epsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest`, then $|\epsilon| < 2^{-p-1}$.
+    ///
+    /// The constant is computed using binary splitting, and the bounds computed at the highest
+    /// precision requested so far are cached, so subsequent calls with the same or lower
+    /// precisions are fast.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::catalan_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (catalan, o) = Float::catalan_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(catalan.to_string(), "0.91");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (catalan, o) = Float::catalan_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(catalan.to_string(), "0.94");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (catalan, o) = Float::catalan_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(catalan.to_string(), "0.91");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (catalan, o) = Float::catalan_prec_round(100, RoundingMode::Floor);
+    /// assert_eq!(catalan.to_string(), "0.915965594177219015054603514932");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (catalan, o) = Float::catalan_prec_round(100, RoundingMode::Ceiling);
+    /// assert_eq!(catalan.to_string(), "0.9159655941772190150546035149325");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (catalan, o) = Float::catalan_prec_round(100, RoundingMode::Nearest);
+    /// assert_eq!(catalan.to_string(), "0.9159655941772190150546035149325");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn catalan_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        constant_prec_round(catalan_fixed_point_bounds, prec, rm)
+    }
+
+    /// Returns an approximation of Catalan's constant, $G$, with the given precision and rounded to
+    /// the nearest [`Float`] of that precision. An [`Ordering`] is also returned, indicating
+    /// whether the rounded value is less than or greater than the exact value of the constant.
+    /// (Since the constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = G+\epsilon.
+    /// $$
+    /// - $|\epsilon| < 2^{-p-1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::catalan_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (catalan, o) = Float::catalan_prec(5);
+    /// assert_eq!(catalan.to_string(), "0.91");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (catalan, o) = Float::catalan_prec(100);
+    /// assert_eq!(catalan.to_string(), "0.9159655941772190150546035149325");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn catalan_prec(prec: u64) -> (Float, Ordering) {
+        Float::catalan_prec_round(prec, RoundingMode::Nearest)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::constants::{cached_fixed_point_bounds, constant_prec_round, FixedPointCache};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{DivRound, ShrRound};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static E_CACHE: FixedPointCache = const { RefCell::new(None) };
+}
+
+// Returns $Q$ and $T$ for the terms of $\sum_{k=1}^\infty 1/k!$ with indices in $(a, b]$, where
+// $a < b$. The partial sum $\sum_{k=a+1}^b 1/k!$ is $T/Q$ divided by $a!$, and $Q = b!/a!$.
+fn e_split(a: u64, b: u64) -> (Natural, Natural) {
+    if b - a == 1 {
+        return (Natural::from(b), Natural::ONE);
+    }
+    let m = (a + b) >> 1;
+    let (q_left, t_left) = e_split(a, m);
+    let (q_right, t_right) = e_split(m, b);
+    (q_left * &q_right, t_left * q_right + t_right)
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq e \leq$ `hi`$2^{-f}$, using
+// $e = \sum_{k=0}^\infty 1/k!$.
+fn e_fixed_point_bounds_uncached(f: u64) -> (Natural, Natural) {
+    let g = f + 2;
+    // Find an $N$ with $N! \geq 2^{g+1}$; then the terms omitted after $1/N!$ sum to
+    // less than $2/(N+1)! < 2^{-g}$.
+    let mut n = 1;
+    let mut log_factorial = 0;
+    while log_factorial <= g {
+        n += 1;
+        log_factorial += n.significant_bits() - 1;
+    }
+    let (q, t) = e_split(0, n);
+    let numerator = (&q + t) << g;
+    (
+        (&numerator)
+            .div_round(&q, RoundingMode::Floor)
+            .0
+            .shr_round(2, RoundingMode::Floor)
+            .0,
+        (numerator.div_round(q, RoundingMode::Ceiling).0 + Natural::ONE)
+            .shr_round(2, RoundingMode::Ceiling)
+            .0,
+    )
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq e \leq$ `hi`$2^{-f}$.
+pub(crate) fn e_fixed_point_bounds(f: u64) -> (Natural, Natural) {
+    cached_fixed_point_bounds(&E_CACHE, f, e_fixed_point_bounds_uncached)
+}
+
+impl Float {
+    /// Returns an approximation of $e$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = e+\epsilon.
+    /// $$
+    /// - If $m$ is not `Nearest`, then $|\epsilon| < 2^{2-p}$.
+    /// - If $m$ is `Nearest`, then $|\epsilon| < 2^{1-p}$.
+    ///
+    /// The constant is computed using binary splitting, and the bounds computed at the highest
+    /// precision requested so far are cached, so subsequent calls with the same or lower
+    /// precisions are fast.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::e_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (e, o) = Float::e_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(e.to_string(), "2.6");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (e, o) = Float::e_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(e.to_string(), "2.8");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (e, o) = Float::e_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(e.to_string(), "2.8");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (e, o) = Float::e_prec_round(100, RoundingMode::Floor);
+    /// assert_eq!(e.to_string(), "2.718281828459045235360287471351");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (e, o) = Float::e_prec_round(100, RoundingMode::Ceiling);
+    /// assert_eq!(e.to_string(), "2.718281828459045235360287471354");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (e, o) = Float::e_prec_round(100, RoundingMode::Nearest);
+    /// assert_eq!(e.to_string(), "2.718281828459045235360287471351");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn e_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        constant_prec_round(e_fixed_point_bounds, prec, rm)
+    }
+
+    /// Returns an approximation of $e$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = e+\epsilon.
+    /// $$
+    /// - $|\epsilon| < 2^{1-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::e_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (e, o) = Float::e_prec(5);
+    /// assert_eq!(e.to_string(), "2.8");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (e, o) = Float::e_prec(100);
+    /// assert_eq!(e.to_string(), "2.718281828459045235360287471351");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn e_prec(prec: u64) -> (Float, Ordering) {
+        Float::e_prec_round(prec, RoundingMode::Nearest)
+    }
+}
@@ -0,0 +1,207 @@
+use crate::constants::ln_2::ln_2_fixed_point_bounds;
+use crate::constants::{cached_fixed_point_bounds, constant_prec_round, FixedPointCache};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{DivRound, PowerOf2, ShrRound, Square};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static EULER_GAMMA_CACHE: FixedPointCache = const { RefCell::new(None) };
+}
+
+// The values computed by `euler_gamma_split` for an interval $[a, b)$.
+struct EulerGammaSplit {
+    q: Natural,
+    d: Natural,
+    c: Natural,
+    t: Natural,
+    v: Natural,
+}
+
+// Computes sums for the terms of the Brent–McMillan series with indices in $[a, b)$, where
+// $0 < a < b$. Let $n = 2^m$ and $t_k = (n^k/k!)^2$, and let $H_k$ be the $k$th harmonic number.
+// Then $Q = \prod_{k=a}^{b-1} k^2$, $D = \prod_{k=a}^{b-1} k$,
+// $C/D = \sum_{k=a}^{b-1} 1/k$,
+// $$
+// \frac{T}{Q} = \sum_{k=a}^{b-1} \frac{t_k}{t_{a-1}}, \quad \text{and} \quad
+// \frac{V}{DQ} = \sum_{k=a}^{b-1} \frac{t_k}{t_{a-1}}(H_k - H_{a-1}).
+// $$
+fn euler_gamma_split(m: u64, a: u64, b: u64) -> EulerGammaSplit {
+    if b - a == 1 {
+        let n_squared = Natural::power_of_2(m << 1);
+        return EulerGammaSplit {
+            q: Natural::from(a).square(),
+            d: Natural::from(a),
+            c: Natural::ONE,
+            t: n_squared.clone(),
+            v: n_squared,
+        };
+    }
+    let mid = (a + b) >> 1;
+    let left = euler_gamma_split(m, a, mid);
+    let right = euler_gamma_split(m, mid, b);
+    // $t_{mid-1}/t_{a-1} = 2^s/Q_l$.
+    let s = (m << 1) * (mid - a);
+    let v =
+        &left.v * &right.q * &right.d + ((&left.c * &right.d * &right.t + &left.d * right.v) << s);
+    EulerGammaSplit {
+        c: left.c * &right.d + right.c * &left.d,
+        t: left.t * &right.q + (right.t << s),
+        q: left.q * right.q,
+        d: left.d * right.d,
+        v,
+    }
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq \gamma \leq$ `hi`$2^{-f}$, using the
+// Brent–McMillan formula
+// $$
+// \gamma = \frac{A}{B} - \log n - \delta, \quad A = \sum_{k=0}^\infty t_kH_k, \quad
+// B = \sum_{k=0}^\infty t_k,
+// $$
+// where $t_k = (n^k/k!)^2$ and $0 < \delta < \pi e^{-4n}$. $n$ is chosen to be a power of 2, so
+// that $\log n$ is a multiple of $\log 2$.
+fn euler_gamma_fixed_point_bounds_uncached(f: u64) -> (Natural, Natural) {
+    // With $n > (g+8)/4$, both $\delta$ and the error from omitting the terms with $k \geq 5n$,
+    // which makes $A/B$ too small, are less than $2^{-g}$.
+    let m = ((f + 72) >> 2).significant_bits();
+    let g = f + m.significant_bits() + 4;
+    let split = euler_gamma_split(m, 1, 5 << m);
+    // $A/B = (V/(DQ))/(1 + T/Q) = V/(D(Q + T))$.
+    let numerator = split.v << g;
+    let denominator = split.d * (split.q + split.t);
+    let (ln_2_lo, ln_2_hi) = ln_2_fixed_point_bounds(g);
+    let m = Natural::from(m);
+    let lo =
+        (&numerator).div_round(&denominator, RoundingMode::Floor).0 - &m * ln_2_hi - Natural::ONE;
+    let hi = numerator.div_round(denominator, RoundingMode::Ceiling).0 - m * ln_2_lo + Natural::ONE;
+    let shift = g - f;
+    (
+        lo.shr_round(shift, RoundingMode::Floor).0,
+        hi.shr_round(shift, RoundingMode::Ceiling).0,
+    )
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq \gamma \leq$ `hi`$2^{-f}$.
+pub(crate) fn euler_gamma_fixed_point_bounds(f: u64) -> (Natural, Natural) {
+    cached_fixed_point_bounds(
+        &EULER_GAMMA_CACHE,
+        f,
+        euler_gamma_fixed_point_bounds_uncached,
+    )
+}
+
+impl Float {
+    /// Returns an approximation of $\gamma$, the Euler–Mascheroni constant, with the given
+    /// precision and rounded using the given [`RoundingMode`]. An [`Ordering`] is also returned,
+    /// indicating whether the rounded value is less than or greater than the exact value of the
+    /// constant. (Since the constant is irrational, the rounded value is never equal to the exact
+    /// value.)
+    ///
+    /// $$
+    /// x = \gamma+\epsilon.
+    /// $$
+    /// - If $m$ is not `Nearest`, then $|\epsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest`, then $|\epsilon| < 2^{-p-1}$.
+    ///
+    /// The constant is computed using binary splitting, and the bounds computed at the highest
+    /// precision requested so far are cached, so subsequent calls with the same or lower
+    /// precisions are fast.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::euler_gamma_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(gamma.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(gamma.to_string(), "0.59");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(gamma.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec_round(100, RoundingMode::Floor);
+    /// assert_eq!(gamma.to_string(), "0.5772156649015328606065120900823");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec_round(100, RoundingMode::Ceiling);
+    /// assert_eq!(gamma.to_string(), "0.577215664901532860606512090083");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec_round(100, RoundingMode::Nearest);
+    /// assert_eq!(gamma.to_string(), "0.5772156649015328606065120900823");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn euler_gamma_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        constant_prec_round(euler_gamma_fixed_point_bounds, prec, rm)
+    }
+
+    /// Returns an approximation of $\gamma$, the Euler–Mascheroni constant, with the given
+    /// precision and rounded to the nearest [`Float`] of that precision. An [`Ordering`] is also
+    /// returned, indicating whether the rounded value is less than or greater than the exact value
+    /// of the constant. (Since the constant is irrational, the rounded value is never equal to the
+    /// exact value.)
+    ///
+    /// $$
+    /// x = \gamma+\epsilon.
+    /// $$
+    /// - $|\epsilon| < 2^{-p-1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::euler_gamma_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec(5);
+    /// assert_eq!(gamma.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec(100);
+    /// assert_eq!(gamma.to_string(), "0.5772156649015328606065120900823");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn euler_gamma_prec(prec: u64) -> (Float, Ordering) {
+        Float::euler_gamma_prec_round(prec, RoundingMode::Nearest)
+    }
+}
@@ -0,0 +1,183 @@
+use crate::constants::{cached_fixed_point_bounds, constant_prec_round, FixedPointCache};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{DivRound, ShrRound, Square};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static LN_2_CACHE: FixedPointCache = const { RefCell::new(None) };
+}
+
+// Returns $Q$, $B$ and $T$ for the terms of $\operatorname{artanh}(1/q) = \sum_{k=0}^\infty
+// 1/((2k+1)q^{2k+1})$ with indices in $[a, b)$, where $a < b$. The partial sum
+// $\sum_{k=a}^{b-1} 1/((2k+1)q^{2k+1})$ is $T/(BQ)$ divided by $q^{2a-1}$ (or, if $a = 0$, by 1),
+// where $Q$ is $q^{2(b-a)}$ (or, if $a = 0$, $q^{2b-1}$) and $B = \prod_{k=a}^{b-1} (2k+1)$.
+fn atanh_reciprocal_split(
+    q: &Natural,
+    q_squared: &Natural,
+    a: u64,
+    b: u64,
+) -> (Natural, Natural, Natural) {
+    if b - a == 1 {
+        let leaf_q = if a == 0 { q.clone() } else { q_squared.clone() };
+        return (leaf_q, Natural::from((a << 1) + 1), Natural::ONE);
+    }
+    let m = (a + b) >> 1;
+    let (q_left, b_left, t_left) = atanh_reciprocal_split(q, q_squared, a, m);
+    let (q_right, b_right, t_right) = atanh_reciprocal_split(q, q_squared, m, b);
+    let t = &b_right * &q_right * t_left + &b_left * t_right;
+    (q_left * q_right, b_left * b_right, t)
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-g} \leq \operatorname{artanh}(1/q) \leq$ `hi`$2^{-g}$,
+// where $q \geq 2$, with an error of at most $2^{-g-8}$ from truncating the series.
+fn atanh_reciprocal_fixed_point_bounds(q: u32, g: u64) -> (Natural, Natural) {
+    let q = Natural::from(q);
+    // $q \geq 2^l$, so the terms omitted after the first $N$ sum to less than $2/q^{2N+1} \leq
+    // 2^{-g-8}$.
+    let l = q.significant_bits() - 1;
+    let n = (g + 9) / (l << 1) + 1;
+    let (q_product, b, t) = atanh_reciprocal_split(&q, &(&q).square(), 0, n);
+    let denominator = b * q_product;
+    let t = t << g;
+    (
+        (&t).div_round(&denominator, RoundingMode::Floor).0,
+        t.div_round(denominator, RoundingMode::Ceiling).0 + Natural::ONE,
+    )
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq \log 2 \leq$ `hi`$2^{-f}$, using
+// $$
+// \log 2 = 18\operatorname{artanh}\frac{1}{26} - 2\operatorname{artanh}\frac{1}{4801}
+// + 8\operatorname{artanh}\frac{1}{8749}.
+// $$
+fn ln_2_fixed_point_bounds_uncached(f: u64) -> (Natural, Natural) {
+    let g = f + 6;
+    let (a_lo, a_hi) = atanh_reciprocal_fixed_point_bounds(26, g);
+    let (b_lo, b_hi) = atanh_reciprocal_fixed_point_bounds(4801, g);
+    let (c_lo, c_hi) = atanh_reciprocal_fixed_point_bounds(8749, g);
+    let lo: Natural = a_lo * Natural::from(18u32) + (c_lo << 3) - (b_hi << 1);
+    let hi: Natural = a_hi * Natural::from(18u32) + (c_hi << 3) - (b_lo << 1);
+    (
+        lo.shr_round(6, RoundingMode::Floor).0,
+        hi.shr_round(6, RoundingMode::Ceiling).0,
+    )
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq \log 2 \leq$ `hi`$2^{-f}$.
+pub(crate) fn ln_2_fixed_point_bounds(f: u64) -> (Natural, Natural) {
+    cached_fixed_point_bounds(&LN_2_CACHE, f, ln_2_fixed_point_bounds_uncached)
+}
+
+impl Float {
+    /// Returns an approximation of $\log 2$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = \log 2+\epsilon.
+    /// $$
+    /// - If $m$ is not `Nearest`, then $|\epsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest`, then $|\epsilon| < 2^{-p-1}$.
+    ///
+    /// The constant is computed using binary splitting, and the bounds computed at the highest
+    /// precision requested so far are cached, so subsequent calls with the same or lower
+    /// precisions are fast.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_2_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(ln_2.to_string(), "0.69");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(ln_2.to_string(), "0.72");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(ln_2.to_string(), "0.69");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec_round(100, RoundingMode::Floor);
+    /// assert_eq!(ln_2.to_string(), "0.693147180559945309417232121458");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec_round(100, RoundingMode::Ceiling);
+    /// assert_eq!(ln_2.to_string(), "0.693147180559945309417232121459");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec_round(100, RoundingMode::Nearest);
+    /// assert_eq!(ln_2.to_string(), "0.693147180559945309417232121458");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_2_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        constant_prec_round(ln_2_fixed_point_bounds, prec, rm)
+    }
+
+    /// Returns an approximation of $\log 2$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = \log 2+\epsilon.
+    /// $$
+    /// - $|\epsilon| < 2^{-p-1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_2_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec(5);
+    /// assert_eq!(ln_2.to_string(), "0.69");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (ln_2, o) = Float::ln_2_prec(100);
+    /// assert_eq!(ln_2.to_string(), "0.693147180559945309417232121458");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_2_prec(prec: u64) -> (Float, Ordering) {
+        Float::ln_2_prec_round(prec, RoundingMode::Nearest)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::arithmetic::exp::ziv_round;
+use crate::Float;
+use malachite_base::num::arithmetic::traits::ShrRound;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::{max, Ordering};
+use std::thread::LocalKey;
+
+// Bounds `lo`$2^{-f} \leq c \leq$ `hi`$2^{-f}$ on a constant $c$, stored as `(lo, hi, f)`.
+pub(crate) type FixedPointCache = RefCell<Option<(Natural, Natural, u64)>>;
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq c \leq$ `hi`$2^{-f}$, where `compute` returns
+// such bounds for a constant $c$. The bounds with the largest $f$ computed so far are kept in
+// `cache`, so requesting $c$ at the same or a lower precision only takes a shift. When the cached
+// bounds are not precise enough, they are replaced by bounds with at least twice as many bits, so
+// that requesting $c$ at increasing precisions only recomputes it a logarithmic number of times.
+pub(crate) fn cached_fixed_point_bounds(
+    cache: &'static LocalKey<FixedPointCache>,
+    f: u64,
+    compute: fn(u64) -> (Natural, Natural),
+) -> (Natural, Natural) {
+    let cached_f = cache.with(|cache| cache.borrow().as_ref().map(|&(_, _, cached_f)| cached_f));
+    if cached_f.map_or(true, |cached_f| cached_f < f) {
+        let new_f = max(f, cached_f.unwrap_or(0) << 1);
+        let (lo, hi) = compute(new_f);
+        cache.with(|cache| *cache.borrow_mut() = Some((lo, hi, new_f)));
+    }
+    cache.with(|cache| {
+        let cache = cache.borrow();
+        let (lo, hi, cached_f) = cache.as_ref().unwrap();
+        let shift = cached_f - f;
+        (
+            lo.shr_round(shift, RoundingMode::Floor).0,
+            hi.shr_round(shift, RoundingMode::Ceiling).0,
+        )
+    })
+}
+
+// Rounds a positive irrational constant $c$ to `prec` bits, where `bounds` returns `lo` and `hi`
+// such that `lo`$2^{-f} \leq c \leq$ `hi`$2^{-f}$. $c$ must be at least 1/2.
+pub(crate) fn constant_prec_round(
+    bounds: fn(u64) -> (Natural, Natural),
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    ziv_round(prec, rm, |working_prec| {
+        let f = working_prec + 2;
+        let (lo, hi) = bounds(f);
+        (Integer::from(lo), Integer::from(hi), -i64::exact_from(f))
+    })
+}
+
+/// Catalan's constant.
+pub mod catalan;
+
+/// $e$, the base of the natural logarithm.
+pub mod e;
+
+/// The Euler–Mascheroni constant.
+pub mod euler_gamma;
+
+/// The natural logarithm of 2.
+pub mod ln_2;
+
+/// $\pi$.
+pub mod pi;
+
+/// The square root of 2.
+pub mod sqrt_2;
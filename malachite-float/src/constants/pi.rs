@@ -0,0 +1,184 @@
+use crate::constants::{cached_fixed_point_bounds, constant_prec_round, FixedPointCache};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{
+    CeilingSqrt, DivRound, FloorSqrt, Parity, Pow, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static PI_CACHE: FixedPointCache = const { RefCell::new(None) };
+}
+
+// $640320^3/24$.
+const CHUDNOVSKY_Q_FACTOR: u64 = 10939058860032000;
+
+// Returns $P$, $Q$ and $T$ for the terms of the Chudnovsky series with indices in $[a, b)$, where
+// $a < b$. The partial sum $\sum_{k=a}^{b-1} a_k$ is $T/Q$ times $\prod_{k=1}^{a-1} p_k/q_k$, with
+// $p_k = (6k-5)(2k-1)(6k-1)$ and $q_k = k^3 \cdot 640320^3/24$, and $P/Q$ is the ratio
+// $\prod_{k=a}^{b-1} p_k/q_k$.
+fn chudnovsky_split(a: u64, b: u64) -> (Natural, Natural, Integer) {
+    if b - a == 1 {
+        if a == 0 {
+            return (Natural::ONE, Natural::ONE, Integer::from(13591409u32));
+        }
+        let p = Natural::from(6 * a - 5) * Natural::from(2 * a - 1) * Natural::from(6 * a - 1);
+        let q = Natural::from(a).pow(3) * Natural::from(CHUDNOVSKY_Q_FACTOR);
+        let t = Integer::from(
+            &p * (Natural::from(545140134u32) * Natural::from(a) + Natural::from(13591409u32)),
+        );
+        return (p, q, if a.odd() { -t } else { t });
+    }
+    let m = (a + b) >> 1;
+    let (p_left, q_left, t_left) = chudnovsky_split(a, m);
+    let (p_right, q_right, t_right) = chudnovsky_split(m, b);
+    (
+        &p_left * p_right,
+        q_left * &q_right,
+        t_left * Integer::from(q_right) + Integer::from(p_left) * t_right,
+    )
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq \pi \leq$ `hi`$2^{-f}$, using the Chudnovsky
+// formula
+// $$
+// \frac{1}{\pi} = \frac{12}{640320^{3/2}} \sum_{k=0}^\infty
+// \frac{(-1)^k(6k)!(13591409+545140134k)}{(3k)!(k!)^3 640320^{3k}},
+// $$
+// or $\pi = 426880\sqrt{10005}/S$, where $S$ is the sum.
+fn pi_fixed_point_bounds_uncached(f: u64) -> (Natural, Natural) {
+    let g = f + 8;
+    // The $k$th term has absolute value less than $2^{30}(k+1)2^{-47k}$, so the terms omitted
+    // after the first $N$ sum to less than $2^{-g}$.
+    let n = (g + 32 + g.significant_bits()) / 47 + 1;
+    let (_, q, t) = chudnovsky_split(0, n);
+    let t = t.unsigned_abs();
+    let sum_lo = (&t << g).div_round(&q, RoundingMode::Floor).0 - Natural::ONE;
+    let sum_hi = (t << g).div_round(q, RoundingMode::Ceiling).0 + Natural::ONE;
+    let square = Natural::from(10005u32) << (g << 1);
+    let root_lo = (&square).floor_sqrt() * Natural::from(426880u32);
+    let root_hi = square.ceiling_sqrt() * Natural::from(426880u32);
+    (
+        (root_lo << f).div_round(sum_hi, RoundingMode::Floor).0,
+        (root_hi << f).div_round(sum_lo, RoundingMode::Ceiling).0,
+    )
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq \pi \leq$ `hi`$2^{-f}$.
+pub(crate) fn pi_fixed_point_bounds(f: u64) -> (Natural, Natural) {
+    cached_fixed_point_bounds(&PI_CACHE, f, pi_fixed_point_bounds_uncached)
+}
+
+impl Float {
+    /// Returns an approximation of $\pi$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = \pi+\epsilon.
+    /// $$
+    /// - If $m$ is not `Nearest`, then $|\epsilon| < 2^{2-p}$.
+    /// - If $m$ is `Nearest`, then $|\epsilon| < 2^{1-p}$.
+    ///
+    /// The constant is computed using binary splitting, and the bounds computed at the highest
+    /// precision requested so far are cached, so subsequent calls with the same or lower
+    /// precisions are fast.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pi_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (pi, o) = Float::pi_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(pi.to_string(), "3.1");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (pi, o) = Float::pi_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(pi.to_string(), "3.2");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (pi, o) = Float::pi_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(pi.to_string(), "3.1");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (pi, o) = Float::pi_prec_round(100, RoundingMode::Floor);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383279");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (pi, o) = Float::pi_prec_round(100, RoundingMode::Ceiling);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383282");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (pi, o) = Float::pi_prec_round(100, RoundingMode::Nearest);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383279");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn pi_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        constant_prec_round(pi_fixed_point_bounds, prec, rm)
+    }
+
+    /// Returns an approximation of $\pi$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = \pi+\epsilon.
+    /// $$
+    /// - $|\epsilon| < 2^{1-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pi_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (pi, o) = Float::pi_prec(5);
+    /// assert_eq!(pi.to_string(), "3.1");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (pi, o) = Float::pi_prec(100);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383279");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn pi_prec(prec: u64) -> (Float, Ordering) {
+        Float::pi_prec_round(prec, RoundingMode::Nearest)
+    }
+}
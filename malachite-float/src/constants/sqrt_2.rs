@@ -0,0 +1,130 @@
+use crate::constants::{cached_fixed_point_bounds, constant_prec_round, FixedPointCache};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{CeilingSqrt, FloorSqrt};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static SQRT_2_CACHE: FixedPointCache = const { RefCell::new(None) };
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq \sqrt{2} \leq$ `hi`$2^{-f}$.
+fn sqrt_2_fixed_point_bounds_uncached(f: u64) -> (Natural, Natural) {
+    let square = Natural::from(2u32) << (f << 1);
+    ((&square).floor_sqrt(), square.ceiling_sqrt())
+}
+
+// Returns `lo` and `hi` such that `lo`$2^{-f} \leq \sqrt{2} \leq$ `hi`$2^{-f}$.
+pub(crate) fn sqrt_2_fixed_point_bounds(f: u64) -> (Natural, Natural) {
+    cached_fixed_point_bounds(&SQRT_2_CACHE, f, sqrt_2_fixed_point_bounds_uncached)
+}
+
+impl Float {
+    /// Returns an approximation of $\sqrt{2}$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = \sqrt{2}+\epsilon.
+    /// $$
+    /// - If $m$ is not `Nearest`, then $|\epsilon| < 2^{1-p}$.
+    /// - If $m$ is `Nearest`, then $|\epsilon| < 2^{-p}$.
+    ///
+    /// The bounds computed at the highest precision requested so far are cached, so subsequent
+    /// calls with the same or lower precisions are fast.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sqrt_2_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sqrt_2, o) = Float::sqrt_2_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(sqrt_2.to_string(), "1.38");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sqrt_2, o) = Float::sqrt_2_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(sqrt_2.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt_2, o) = Float::sqrt_2_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(sqrt_2.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt_2, o) = Float::sqrt_2_prec_round(100, RoundingMode::Floor);
+    /// assert_eq!(sqrt_2.to_string(), "1.414213562373095048801688724209");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (sqrt_2, o) = Float::sqrt_2_prec_round(100, RoundingMode::Ceiling);
+    /// assert_eq!(sqrt_2.to_string(), "1.414213562373095048801688724211");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt_2, o) = Float::sqrt_2_prec_round(100, RoundingMode::Nearest);
+    /// assert_eq!(sqrt_2.to_string(), "1.414213562373095048801688724209");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_2_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        constant_prec_round(sqrt_2_fixed_point_bounds, prec, rm)
+    }
+
+    /// Returns an approximation of $\sqrt{2}$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = \sqrt{2}+\epsilon.
+    /// $$
+    /// - $|\epsilon| < 2^{-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_2_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (sqrt_2, o) = Float::sqrt_2_prec(5);
+    /// assert_eq!(sqrt_2.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (sqrt_2, o) = Float::sqrt_2_prec(100);
+    /// assert_eq!(sqrt_2.to_string(), "1.414213562373095048801688724209");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_2_prec(prec: u64) -> (Float, Ordering) {
+        Float::sqrt_2_prec_round(prec, RoundingMode::Nearest)
+    }
+}
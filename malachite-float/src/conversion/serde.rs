@@ -0,0 +1,109 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{significand_bits, ComparableFloatRef, Float};
+use malachite_base::num::arithmetic::traits::NegModPowerOf2;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::FromStringBase;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// The representation of a `Float` in binary formats. The sign is `true` for positive `Float`s.
+// The significand of a finite `Float` is stored with exactly `precision` significant bits, so that
+// it doesn't depend on the width of a `Limb`.
+#[derive(Serialize, Deserialize)]
+enum BinaryFloat {
+    NaN,
+    Infinity(bool),
+    Zero(bool),
+    Finite(bool, i64, u64, Natural),
+}
+
+impl Serialize for Float {
+    /// Serializes a [`Float`].
+    ///
+    /// Human-readable formats, like JSON, get a hexadecimal string that includes the precision,
+    /// such as `"0x1.8#3"`; this is the format produced by formatting a
+    /// [`ComparableFloat`](crate::ComparableFloat) with `{:#x}`. Binary formats get NaN, an
+    /// infinity, or a zero as a tagged sign, and a finite [`Float`] as its sign, exponent,
+    /// precision, and significand. The significand is serialized like a [`Natural`], with
+    /// exactly `precision` significant bits.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.get_prec()` in binary
+    /// formats. Human-readable formats take longer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.serialize_str(&format!("{:#x}", ComparableFloatRef(self)));
+        }
+        match self {
+            Float(NaN) => BinaryFloat::NaN,
+            Float(Infinity { sign }) => BinaryFloat::Infinity(*sign),
+            Float(Zero { sign }) => BinaryFloat::Zero(*sign),
+            Float(Finite {
+                sign,
+                exponent,
+                precision,
+                significand,
+            }) => BinaryFloat::Finite(
+                *sign,
+                *exponent,
+                *precision,
+                significand >> (significand_bits(significand) - precision),
+            ),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Float {
+    /// Deserializes a [`Float`] from the representation produced by its [`Serialize`]
+    /// implementation.
+    ///
+    /// In binary formats, a finite [`Float`] whose significand does not have exactly `precision`
+    /// significant bits is an error.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the length of the input in binary
+    /// formats. Human-readable formats take longer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Float, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            return if matches!(s.as_str(), "NaN" | "Infinity" | "-Infinity")
+                || s.strip_prefix('-').unwrap_or(&s).starts_with("0x")
+            {
+                Float::from_string_base(16, &s)
+            } else {
+                None
+            }
+            .ok_or_else(|| D::Error::custom(format!("Unrecognized Float string '{s}'")));
+        }
+        Ok(match BinaryFloat::deserialize(deserializer)? {
+            BinaryFloat::NaN => Float(NaN),
+            BinaryFloat::Infinity(sign) => Float(Infinity { sign }),
+            BinaryFloat::Zero(sign) => Float(Zero { sign }),
+            BinaryFloat::Finite(sign, exponent, precision, significand) => {
+                if precision == 0 || significand.significant_bits() != precision {
+                    return Err(D::Error::custom(format!(
+                        "Significand {significand} does not have precision {precision}"
+                    )));
+                }
+                Float(Finite {
+                    sign,
+                    exponent,
+                    precision,
+                    significand: significand << precision.neg_mod_power_of_2(Limb::LOG_WIDTH),
+                })
+            }
+        })
+    }
+}
@@ -0,0 +1,305 @@
+use crate::Float;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::string::from_sci_string::preprocess_sci_string;
+use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::traits::{ExactFrom, FromSciString, FromStringBase};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+fn special_float_from_string(s: &str) -> Option<Float> {
+    match s {
+        "NaN" => Some(Float::NAN),
+        "Infinity" => Some(Float::INFINITY),
+        "-Infinity" => Some(Float::NEGATIVE_INFINITY),
+        _ => None,
+    }
+}
+
+// Parses a finite number, possibly in scientific notation. Returns whether the string begins with
+// a minus sign, the absolute value of the number, and the number of significant digits, which
+// includes trailing zeros but not leading zeros.
+fn parse_finite_sci_string(
+    s: &str,
+    options: FromSciStringOptions,
+) -> Option<(bool, Rational, u64)> {
+    let (s, negative) = if let Some(s) = s.strip_prefix('-') {
+        (s, true)
+    } else {
+        (s.strip_prefix('+').unwrap_or(s), false)
+    };
+    if s.starts_with(['+', '-']) {
+        return None;
+    }
+    let (digits, exponent) = preprocess_sci_string(s, options)?;
+    let base = options.get_base();
+    let significand = Natural::from_string_base(base, std::str::from_utf8(&digits).ok()?)?;
+    let significant_digits =
+        u64::exact_from(digits.iter().skip_while(|&&digit| digit == b'0').count());
+    Some((
+        negative,
+        Rational::from(significand) * Rational::from(base).pow(exponent),
+        significant_digits,
+    ))
+}
+
+fn from_parsed_sci_string_prec_round(
+    negative: bool,
+    x: Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if x == 0u32 {
+        (
+            if negative {
+                Float::NEGATIVE_ZERO
+            } else {
+                Float::ZERO
+            },
+            Ordering::Equal,
+        )
+    } else {
+        Float::from_rational_prec_round(if negative { -x } else { x }, prec, rm)
+    }
+}
+
+impl Float {
+    /// Converts a string, possibly in scientific notation, to a [`Float`] with the given
+    /// precision, rounding using the given [`RoundingMode`]. An [`Ordering`] is also returned,
+    /// indicating whether the returned value is less than, equal to, or greater than the value
+    /// represented by the string.
+    ///
+    /// Use [`FromSciStringOptions`] to specify the base (from 2 to 36, inclusive). The rounding
+    /// mode option is ignored; `rm` is used instead.
+    ///
+    /// If the base is greater than 10, the higher digits are represented by the letters `'a'`
+    /// through `'z'` or `'A'` through `'Z'`; the case doesn't matter and doesn't need to be
+    /// consistent.
+    ///
+    /// Exponents are allowed, and are indicated using the character `'e'` or `'E'`. If the base is
+    /// 15 or greater, an ambiguity arises where it may not be clear whether `'e'` is a digit or
+    /// an exponent indicator. To resolve this ambiguity, always use a `'+'` or `'-'` sign after
+    /// the exponent indicator when the base is 15 or greater.
+    ///
+    /// The exponent itself is always parsed using base 10.
+    ///
+    /// Decimal (or other-base) points are allowed. The strings `"NaN"`, `"Infinity"`, and
+    /// `"-Infinity"` are also accepted, and a zero with a leading minus sign is converted to
+    /// negative zero.
+    ///
+    /// The value represented by the string is rounded exactly once, so the result is correctly
+    /// rounded.
+    ///
+    /// If the string is unparseable, `None` is returned.
+    ///
+    /// If the output is finite and nonzero, it has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::from_sci_string_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(m^n n \log m (\log n + \log\log m))$
+    ///
+    /// $M(n, m) = O(m^n n \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `s.len()`, and $m$ is `options.base`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the value represented by the string
+    /// cannot be represented exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let options = FromSciStringOptions::default();
+    ///
+    /// let (x, o) =
+    ///     Float::from_sci_string_prec_round("3.14159", options, 10, RoundingMode::Floor).unwrap();
+    /// assert_eq!(x.to_string(), "3.141");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from_sci_string_prec_round("3.14159", options, 10, RoundingMode::Ceiling)
+    ///         .unwrap();
+    /// assert_eq!(x.to_string(), "3.145");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from_sci_string_prec_round("3.14159e-20", options, 20, RoundingMode::Nearest)
+    ///         .unwrap();
+    /// assert_eq!(x.to_string(), "3.141591e-20");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from_sci_string_prec_round("-0.5", options, 1, RoundingMode::Exact).unwrap();
+    /// assert_eq!(x.to_string(), "-0.5");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let mut options = FromSciStringOptions::default();
+    /// options.set_base(16);
+    /// let (x, o) =
+    ///     Float::from_sci_string_prec_round("ff.8", options, 8, RoundingMode::Down).unwrap();
+    /// assert_eq!(x.to_string(), "255.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// assert!(
+    ///     Float::from_sci_string_prec_round("1.2.3", options, 10, RoundingMode::Floor).is_none()
+    /// );
+    /// ```
+    pub fn from_sci_string_prec_round(
+        s: &str,
+        options: FromSciStringOptions,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Option<(Float, Ordering)> {
+        assert_ne!(prec, 0);
+        if let Some(x) = special_float_from_string(s) {
+            return Some((x, Ordering::Equal));
+        }
+        let (negative, x, _) = parse_finite_sci_string(s, options)?;
+        Some(from_parsed_sci_string_prec_round(negative, x, prec, rm))
+    }
+
+    /// Converts a string, possibly in scientific notation, to a [`Float`] with the given
+    /// precision, rounding to the nearest [`Float`] of that precision. An [`Ordering`] is also
+    /// returned, indicating whether the returned value is less than, equal to, or greater than the
+    /// value represented by the string.
+    ///
+    /// Use [`FromSciStringOptions`] to specify the base (from 2 to 36, inclusive). The rounding
+    /// mode option is ignored. See [`Float::from_sci_string_prec_round`] for a description of the
+    /// accepted strings.
+    ///
+    /// If the string is unparseable, `None` is returned.
+    ///
+    /// If the output is finite and nonzero, it has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::from_sci_string_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(m^n n \log m (\log n + \log\log m))$
+    ///
+    /// $M(n, m) = O(m^n n \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `s.len()`, and $m$ is `options.base`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let options = FromSciStringOptions::default();
+    ///
+    /// let (x, o) = Float::from_sci_string_prec("3.14159", options, 10).unwrap();
+    /// assert_eq!(x.to_string(), "3.141");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from_sci_string_prec("0.1", options, 100).unwrap();
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from_sci_string_prec("-Infinity", options, 100).unwrap();
+    /// assert_eq!(x.to_string(), "-Infinity");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn from_sci_string_prec(
+        s: &str,
+        options: FromSciStringOptions,
+        prec: u64,
+    ) -> Option<(Float, Ordering)> {
+        Float::from_sci_string_prec_round(s, options, prec, RoundingMode::Nearest)
+    }
+}
+
+impl FromSciString for Float {
+    /// Converts a string, possibly in scientific notation, to a [`Float`].
+    ///
+    /// If the string has $n$ significant digits in base $b$, the precision of the output is the
+    /// smallest $p$ such that $2^{p-1} > b^n$. This is enough to guarantee that strings with $n$
+    /// significant digits that represent different values produce different [`Float`]s. For
+    /// example, in base 10, a string with 1
+    /// significant digit produces a [`Float`] with precision 5, and a string with 17 significant
+    /// digits produces a [`Float`] with precision 58. Trailing zeros count as significant digits;
+    /// leading zeros don't. If the string represents zero, the output is zero or negative zero.
+    ///
+    /// Use [`FromSciStringOptions`] to specify the base (from 2 to 36, inclusive) and the rounding
+    /// mode, in case rounding is necessary because the number can't be represented exactly with
+    /// that precision. If the rounding mode is `Exact` and rounding is necessary, `None` is
+    /// returned. See [`Float::from_sci_string_prec_round`] for a description of the accepted
+    /// strings.
+    ///
+    /// If the string is unparseable, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(m^n n \log m (\log n + \log\log m))$
+    ///
+    /// $M(n, m) = O(m^n n \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `s.len()`, and $m$ is `options.base`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_base::num::conversion::traits::FromSciString;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Float::from_sci_string("3.14159").unwrap();
+    /// assert_eq!(x.to_string(), "3.14159");
+    /// assert_eq!(x.get_prec(), Some(21));
+    ///
+    /// let x = Float::from_sci_string("1.5e-20").unwrap();
+    /// assert_eq!(x.to_string(), "1.5e-20");
+    /// assert_eq!(x.get_prec(), Some(8));
+    ///
+    /// assert_eq!(Float::from_sci_string("-0.000").unwrap().to_string(), "-0.0");
+    /// assert!(Float::from_sci_string("abc").is_none());
+    ///
+    /// let mut options = FromSciStringOptions::default();
+    /// options.set_rounding_mode(RoundingMode::Exact);
+    /// assert_eq!(Float::from_sci_string_with_options("0.5", options).unwrap().to_string(), "0.5");
+    /// assert!(Float::from_sci_string_with_options("0.1", options).is_none());
+    ///
+    /// let mut options = FromSciStringOptions::default();
+    /// options.set_base(16);
+    /// let x = Float::from_sci_string_with_options("ff.8", options).unwrap();
+    /// assert_eq!(x.to_string(), "255.5");
+    /// assert_eq!(x.get_prec(), Some(14));
+    /// ```
+    fn from_sci_string_with_options(s: &str, options: FromSciStringOptions) -> Option<Float> {
+        if let Some(x) = special_float_from_string(s) {
+            return Some(x);
+        }
+        let (negative, x, significant_digits) = parse_finite_sci_string(s, options)?;
+        if significant_digits == 0 {
+            return Some(from_parsed_sci_string_prec_round(negative, x, 1, RoundingMode::Exact).0);
+        }
+        let prec = Natural::from(options.get_base())
+            .pow(significant_digits)
+            .significant_bits()
+            + 1;
+        let rm = options.get_rounding_mode();
+        if rm == RoundingMode::Exact {
+            let (y, o) = from_parsed_sci_string_prec_round(negative, x, prec, RoundingMode::Floor);
+            if o == Ordering::Equal {
+                Some(y)
+            } else {
+                None
+            }
+        } else {
+            Some(from_parsed_sci_string_prec_round(negative, x, prec, rm).0)
+        }
+    }
+}
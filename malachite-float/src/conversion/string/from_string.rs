@@ -1,16 +1,14 @@
 use crate::Float;
-use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::basic::traits::{NegativeZero, Zero};
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
 use malachite_base::num::conversion::traits::{FromSciString, FromStringBase};
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_q::Rational;
+use std::cmp::Ordering;
 use std::str::FromStr;
 
-fn from_hex_string(s: &str) -> Float {
-    match s {
-        "NaN" => Float::NAN,
-        "Infinity" => Float::INFINITY,
-        "-Infinity" => Float::NEGATIVE_INFINITY,
+fn from_hex_string(s: &str) -> Option<Float> {
+    Some(match s {
         "0x0.0" => Float::ZERO,
         "-0x0.0" => Float::NEGATIVE_ZERO,
         s => {
@@ -19,31 +17,98 @@ fn from_hex_string(s: &str) -> Float {
             } else {
                 (s, true)
             };
-            let s = s.strip_prefix("0x").unwrap();
-            let hash_index = s.find('#').unwrap();
-            let precision = u64::from_str(&s[hash_index + 1..]).unwrap();
+            let s = s.strip_prefix("0x")?;
+            let hash_index = s.find('#')?;
+            let precision = u64::from_str(&s[hash_index + 1..])
+                .ok()
+                .filter(|&p| p != 0)?;
             let mut options = FromSciStringOptions::default();
             options.set_base(16);
-            let x = Float::from_rational_prec_round(
-                Rational::from_sci_string_with_options(&s[..hash_index], options).unwrap(),
-                precision,
-                RoundingMode::Exact,
-            )
-            .0;
+            let x = Rational::from_sci_string_with_options(&s[..hash_index], options)?;
+            let (x, o) = Float::from_rational_prec_round(x, precision, RoundingMode::Floor);
+            if o != Ordering::Equal {
+                return None;
+            }
             if sign {
                 x
             } else {
                 -x
             }
         }
+    })
+}
+
+impl FromStr for Float {
+    type Err = ();
+
+    /// Converts a string, possibly in scientific notation, to a [`Float`].
+    ///
+    /// The precision of the output is chosen from the number of significant decimal digits in the
+    /// string, so that strings representing different values produce different [`Float`]s, and the
+    /// value is rounded to the nearest [`Float`] of that precision. See
+    /// [`Float::from_sci_string_with_options`](FromSciString::from_sci_string_with_options) for
+    /// details. If the string is unparseable, `Err(())` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(10^n n (\log n)^2)$
+    ///
+    /// $M(n) = O(10^n n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Float::from_str("123.0").unwrap().to_string(), "123.0");
+    /// assert_eq!(Float::from_str("-3.14159e-20").unwrap().to_string(), "-3.141591e-20");
+    /// assert_eq!(Float::from_str("Infinity").unwrap().to_string(), "Infinity");
+    /// assert!(Float::from_str("1/2").is_err());
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Float, ()> {
+        Float::from_sci_string(s).ok_or(())
     }
 }
 
 impl FromStringBase for Float {
+    /// Converts a string, possibly in scientific notation and in a given base, to a [`Float`].
+    ///
+    /// In base 16, strings like `"0x1.8#3"`, where the number after the `'#'` is the precision,
+    /// are also accepted; this is the format produced by formatting a [`ComparableFloat`] with
+    /// `{:#x}`. Otherwise, see
+    /// [`Float::from_sci_string_with_options`](FromSciString::from_sci_string_with_options) for
+    /// details. If the string is unparseable, `None` is returned.
+    ///
+    /// [`ComparableFloat`]: crate::ComparableFloat
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(m^n n \log m (\log n + \log\log m))$
+    ///
+    /// $M(n, m) = O(m^n n \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `s.len()`, and $m$ is `base`.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2 or greater than 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::FromStringBase;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from_string_base(10, "0.25").unwrap().to_string(), "0.25");
+    /// assert_eq!(Float::from_string_base(2, "-1.1e3").unwrap().to_string(), "-12.0");
+    /// assert_eq!(Float::from_string_base(16, "0x1.8#3").unwrap().to_string(), "1.5");
+    /// assert!(Float::from_string_base(10, "ff").is_none());
+    /// ```
     fn from_string_base(base: u8, s: &str) -> Option<Self> {
-        if base != 16 {
-            panic!();
+        if base == 16 && s.strip_prefix('-').unwrap_or(s).starts_with("0x") {
+            return from_hex_string(s);
         }
-        Some(from_hex_string(s))
+        let mut options = FromSciStringOptions::default();
+        options.set_base(base);
+        Float::from_sci_string_with_options(s, options)
     }
 }
@@ -0,0 +1,405 @@
+use crate::Float;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use malachite_base::num::arithmetic::traits::{
+    CheckedLogBase2, DivRound, Parity, Pow, PowerOf2, ShrRound,
+};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::string::options::{SciSizeOptions, ToSciOptions};
+use malachite_base::num::conversion::string::to_sci::write_exponent;
+use malachite_base::num::conversion::traits::{
+    ExactFrom, IntegerMantissaAndExponent, RoundingFrom, ToSci, ToStringBase,
+};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::fmt::{Formatter, Write};
+
+// Compares $m2^e$ with $b^p$, where $m > 0$.
+fn cmp_with_power(m: &Natural, e: i64, base: &Natural, p: i64) -> Ordering {
+    if p >= 0 {
+        let power = base.pow(p.unsigned_abs());
+        if e >= 0 {
+            (m << e.unsigned_abs()).cmp(&power)
+        } else {
+            m.cmp(&(power << e.unsigned_abs()))
+        }
+    } else {
+        let x = m * base.pow(p.unsigned_abs());
+        if e >= 0 {
+            (x << e.unsigned_abs()).cmp(&Natural::ONE)
+        } else {
+            x.cmp(&Natural::power_of_2(e.unsigned_abs()))
+        }
+    }
+}
+
+// Returns $\lfloor\log_b m2^e\rfloor$, where $m > 0$ and $2 \leq b \leq 36$.
+pub(crate) fn floor_log_base_helper(m: &Natural, e: i64, base: u8) -> i64 {
+    let log_2 = i64::exact_from(m.significant_bits()) - 1 + e;
+    if let Some(log_base) = base.checked_log_base_2() {
+        return log_2.div_euclid(i64::exact_from(log_base));
+    }
+    // Estimate the logarithm using the top bits of `m`, and then correct the estimate.
+    let shift = m.significant_bits().saturating_sub(f64::MANTISSA_WIDTH + 1);
+    let top = f64::exact_from(&(m >> shift));
+    let approx = (top.log2() + (i64::exact_from(shift) + e) as f64) / f64::from(base).log2();
+    let mut log = i64::rounding_from(approx, RoundingMode::Floor).0;
+    let base = Natural::from(base);
+    while cmp_with_power(m, e, &base, log) == Ordering::Less {
+        log -= 1;
+    }
+    while cmp_with_power(m, e, &base, log + 1) != Ordering::Less {
+        log += 1;
+    }
+    log
+}
+
+// Rounds $m2^eb^s$ to an integer, where $s$ is `scale`, using the given `RoundingMode`.
+pub(crate) fn round_scaled_helper(
+    m: &Natural,
+    e: i64,
+    base: u8,
+    scale: i64,
+    rm: RoundingMode,
+) -> (Natural, Ordering) {
+    let base = Natural::from(base);
+    if scale >= 0 {
+        let n = m * base.pow(scale.unsigned_abs());
+        if e >= 0 {
+            (n << e.unsigned_abs(), Ordering::Equal)
+        } else {
+            n.shr_round(e.unsigned_abs(), rm)
+        }
+    } else {
+        let d = base.pow(scale.unsigned_abs());
+        if e >= 0 {
+            (m << e.unsigned_abs()).div_round(d, rm)
+        } else {
+            m.div_round(d << e.unsigned_abs(), rm)
+        }
+    }
+}
+
+fn fmt_zero(f: &mut Formatter, options: ToSciOptions) -> std::fmt::Result {
+    f.write_char('0')?;
+    let scale = if options.get_include_trailing_zeros() {
+        match options.get_size_options() {
+            SciSizeOptions::Complete => None,
+            SciSizeOptions::Scale(scale) => {
+                if scale == 0 {
+                    None
+                } else {
+                    Some(scale)
+                }
+            }
+            SciSizeOptions::Precision(precision) => {
+                if precision == 1 {
+                    None
+                } else {
+                    Some(precision - 1)
+                }
+            }
+        }
+    } else {
+        None
+    };
+    if let Some(scale) = scale {
+        f.write_char('.')?;
+        for _ in 0..scale {
+            f.write_char('0')?;
+        }
+    }
+    Ok(())
+}
+
+// Returns the number of digits after the point in the expansion of $m2^e$ in base $b$, or `None`
+// if the expansion is non-terminating. $m$ must be odd.
+fn length_after_point(e: i64, base: u8) -> Option<u64> {
+    if e >= 0 {
+        Some(0)
+    } else if base.odd() {
+        None
+    } else {
+        Some(
+            e.unsigned_abs()
+                .div_round(u64::from(base.trailing_zeros()), RoundingMode::Ceiling)
+                .0,
+        )
+    }
+}
+
+impl ToSci for Float {
+    /// Determines whether a [`Float`] can be converted to a string using
+    /// [`to_sci`](malachite_base::num::conversion::traits::ToSci::to_sci) and a particular set of
+    /// options.
+    ///
+    /// NaN, infinities, and zeros can always be converted. A finite, nonzero [`Float`] can be
+    /// converted unless `options` has `size_complete` specified and the [`Float`] has a
+    /// non-terminating expansion in the given base (which happens iff it is not an integer and the
+    /// base is odd), or the rounding mode is `Exact` and the size options require the [`Float`] to
+    /// be rounded.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), |self.get_exponent()|, s)`, where `s` depends on the size
+    /// type specified in `options`.
+    /// - If `options` has `scale` specified, then `s` is `options.scale`.
+    /// - If `options` has `precision` specified, then `s` is `options.precision`.
+    /// - If `options` has `size_complete` specified, then `s` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN};
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// assert!(Float::from(1.5).fmt_sci_valid(options));
+    /// assert!(Float::NAN.fmt_sci_valid(options));
+    /// assert!(Float::INFINITY.fmt_sci_valid(options));
+    ///
+    /// options.set_size_complete();
+    /// assert!(Float::from(1.5).fmt_sci_valid(options));
+    /// // 1.5 is 1.1111... in base 3
+    /// options.set_base(3);
+    /// assert!(!Float::from(1.5).fmt_sci_valid(options));
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// options.set_rounding_mode(RoundingMode::Exact);
+    /// options.set_precision(2);
+    /// assert!(Float::from(1.5).fmt_sci_valid(options));
+    /// assert!(!Float::from(1.25).fmt_sci_valid(options));
+    /// ```
+    fn fmt_sci_valid(&self, options: ToSciOptions) -> bool {
+        let (m, e) = match self {
+            Float(Finite { .. }) => self.integer_mantissa_and_exponent(),
+            _ => return true,
+        };
+        let base = options.get_base();
+        let scale = match options.get_size_options() {
+            SciSizeOptions::Complete => return length_after_point(e, base).is_some(),
+            _ if options.get_rounding_mode() != RoundingMode::Exact => return true,
+            SciSizeOptions::Precision(precision) => {
+                i64::exact_from(precision - 1) - floor_log_base_helper(&m, e, base)
+            }
+            SciSizeOptions::Scale(scale) => i64::exact_from(scale),
+        };
+        round_scaled_helper(&m, e, base, scale, RoundingMode::Floor).1 == Ordering::Equal
+    }
+
+    /// Converts a [`Float`] to a string using a specified base, possibly formatting the number
+    /// using scientific notation.
+    ///
+    /// See [`ToSciOptions`] for details on the available options. The value of the [`Float`] is
+    /// rounded exactly once, so the output is correctly rounded according to the options. NaN is
+    /// written as `"NaN"`, and the infinities as `"Infinity"` and `"-Infinity"`. Negative zero is
+    /// written with a leading minus sign.
+    ///
+    /// Unlike [`Display`](std::fmt::Display), this function does not take the precision of the
+    /// [`Float`] into account; it formats the exact value of the [`Float`] according to the
+    /// options.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), |self.get_exponent()|, s)`, where `s` depends on the size
+    /// type specified in `options`.
+    /// - If `options` has `scale` specified, then `s` is `options.scale`.
+    /// - If `options` has `precision` specified, then `s` is `options.precision`.
+    /// - If `options` has `size_complete` specified, then `s` is 0.
+    ///
+    /// # Panics
+    /// Panics if `options.rounding_mode` is `Exact`, but the size options are such that the input
+    /// must be rounded, or if `options` has `size_complete` specified but the [`Float`] has a
+    /// non-terminating expansion in the given base.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeInfinity, NegativeZero};
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Float::from(std::f64::consts::PI);
+    /// let mut options = ToSciOptions::default();
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.141592653589793");
+    ///
+    /// options.set_precision(3);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.14");
+    ///
+    /// options.set_rounding_mode(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.15");
+    ///
+    /// options.set_scale(6);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.141593");
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// options.set_base(16);
+    /// options.set_size_complete();
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.243f6a8885a3");
+    ///
+    /// options.set_uppercase();
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.243F6A8885A3");
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// options.set_size_complete();
+    /// assert_eq!(
+    ///     x.to_sci_with_options(options).to_string(),
+    ///     "3.141592653589793115997963468544185161590576171875"
+    /// );
+    ///
+    /// let x = Float::from(-1.0e-10);
+    /// let mut options = ToSciOptions::default();
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "-1e-10");
+    ///
+    /// options.set_neg_exp_threshold(-11);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "-0.0000000001");
+    ///
+    /// let x = Float::from(1.0e100);
+    /// let mut options = ToSciOptions::default();
+    /// options.set_precision(5);
+    /// options.set_e_uppercase();
+    /// options.set_force_exponent_plus_sign(true);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "1E+100");
+    ///
+    /// options.set_include_trailing_zeros(true);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "1.0000E+100");
+    ///
+    /// assert_eq!(Float::NEGATIVE_ZERO.to_sci().to_string(), "-0");
+    /// assert_eq!(Float::NEGATIVE_INFINITY.to_sci().to_string(), "-Infinity");
+    /// ```
+    fn fmt_sci(&self, f: &mut Formatter, options: ToSciOptions) -> std::fmt::Result {
+        match self {
+            float_nan!() => return f.write_str("NaN"),
+            float_infinity!() => return f.write_str("Infinity"),
+            float_negative_infinity!() => return f.write_str("-Infinity"),
+            float_zero!() => return fmt_zero(f, options),
+            float_negative_zero!() => {
+                f.write_char('-')?;
+                return fmt_zero(f, options);
+            }
+            _ => {}
+        }
+        let (m, e) = self.integer_mantissa_and_exponent();
+        let mut rm = options.get_rounding_mode();
+        if self.is_sign_negative() {
+            f.write_char('-')?;
+            rm = -rm;
+        }
+        let base = options.get_base();
+        let mut trim_zeros = !options.get_include_trailing_zeros();
+        let mut log = floor_log_base_helper(&m, e, base);
+        // Here, precision 0 means that we're rounding down to zero
+        let (mut scale, mut precision) = match options.get_size_options() {
+            SciSizeOptions::Complete => {
+                trim_zeros = false;
+                let scale = length_after_point(e, base).unwrap_or_else(|| {
+                    panic!("{self} has a non-terminating expansion in base {base}")
+                });
+                let precision = i64::exact_from(scale) + log + 1;
+                assert!(precision > 0);
+                (i64::exact_from(scale), precision)
+            }
+            SciSizeOptions::Scale(scale) => {
+                (i64::exact_from(scale), i64::exact_from(scale) + log + 1)
+            }
+            SciSizeOptions::Precision(precision) => (
+                i64::exact_from(precision - 1) - log,
+                i64::exact_from(precision),
+            ),
+        };
+        let n = round_scaled_helper(&m, e, base, scale, rm).0;
+        if precision <= 0 {
+            // e.g. we're in base 10, self is 0.01 or 0.000001, but scale is 1
+            if n == 0u32 {
+                return fmt_zero(f, options);
+            } else if n == 1u32 {
+                precision = 1;
+                log = -scale;
+            } else {
+                panic!("Bug: precision <= 0 must mean self.abs() rounds to 0 or 1");
+            };
+        }
+        let mut cs = if options.get_lowercase() {
+            n.to_string_base(base)
+        } else {
+            n.to_string_base_upper(base)
+        }
+        .into_bytes();
+        let mut precision = usize::exact_from(precision);
+        if cs.len() == precision + 1 {
+            // We rounded up to a power of the base, so precision is greater than we expected. If
+            // the options specify the precision, we need to adjust.
+            log += 1;
+            match options.get_size_options() {
+                SciSizeOptions::Complete => panic!(),
+                SciSizeOptions::Precision(_) => {
+                    scale -= 1;
+                    assert_eq!(cs.pop().unwrap(), b'0');
+                }
+                SciSizeOptions::Scale(_) => {
+                    precision += 1;
+                }
+            }
+        }
+        assert_eq!(cs.len(), precision);
+        if log <= options.get_neg_exp_threshold() || scale < 0 {
+            assert_ne!(log, 0);
+            // exponent
+            if trim_zeros {
+                let trailing_zeros = cs.iter().rev().take_while(|&&c| c == b'0').count();
+                precision -= trailing_zeros;
+                cs.truncate(precision);
+            }
+            if precision > 1 {
+                cs.push(0);
+                cs.copy_within(1..precision, 2);
+                cs[1] = b'.';
+            }
+            f.write_str(&String::from_utf8(cs).unwrap())?;
+            write_exponent(f, options, log)
+        } else if scale == 0 {
+            // no exponent or point
+            f.write_str(&String::from_utf8(cs).unwrap())
+        } else {
+            // no exponent
+            if trim_zeros {
+                let trailing_zeros = cs
+                    .iter()
+                    .rev()
+                    .take(usize::exact_from(scale))
+                    .take_while(|&&c| c == b'0')
+                    .count();
+                precision -= trailing_zeros;
+                cs.truncate(precision);
+            }
+            if log < 0 {
+                f.write_char('0')?;
+                f.write_char('.')?;
+                for _ in 0..-log - 1 {
+                    f.write_char('0')?;
+                }
+            } else {
+                let digits_before = usize::exact_from(log) + 1;
+                if precision > digits_before {
+                    cs.push(0);
+                    cs.copy_within(digits_before..precision, digits_before + 1);
+                    cs[digits_before] = b'.';
+                }
+            }
+            f.write_str(&String::from_utf8(cs).unwrap())
+        }
+    }
+}
@@ -1,12 +1,77 @@
+use crate::conversion::string::to_sci::{floor_log_base_helper, round_scaled_helper};
 use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
 use crate::{ComparableFloat, ComparableFloatRef, Float};
-use malachite_base::num::arithmetic::traits::{Abs, ModPowerOf2, ShrRound};
+use malachite_base::num::arithmetic::traits::{Abs, ModPowerOf2, Pow, ShrRound};
+use malachite_base::num::basic::traits::One;
 use malachite_base::num::conversion::string::options::ToSciOptions;
-use malachite_base::num::conversion::traits::{ExactFrom, ToSci};
+use malachite_base::num::conversion::traits::{ExactFrom, IntegerMantissaAndExponent, ToSci};
 use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
 use malachite_q::Rational;
+use std::cmp::max;
 use std::fmt::{Debug, Display, Formatter, LowerHex, Result, Write};
 
+// Rounds $x2^e$ to `precision` significant decimal digits, using `Nearest`, and returns the digits
+// and the decimal exponent. If $x = 0$, returns `None`.
+fn round_to_decimal_digits(
+    x: &Natural,
+    e: i64,
+    log: i64,
+    precision: u64,
+    power: &Natural,
+) -> Option<(Natural, i64)> {
+    if *x == 0u32 {
+        return None;
+    }
+    let scale = i64::exact_from(precision - 1) - log;
+    let n = round_scaled_helper(x, e, 10, scale, RoundingMode::Nearest).0;
+    Some(if n == *power {
+        (Natural::from(10u32).pow(precision - 1), log + 1)
+    } else {
+        (n, log)
+    })
+}
+
+// Returns the smallest number of significant decimal digits $d$ such that rounding a finite,
+// nonzero `Float` to $d$ digits gives a result that differs from the results of rounding both its
+// neighbors (the `Float`s of the same precision that are one ulp away) to $d$ digits. The search
+// is done directly on the significand and exponent.
+//
+// Two distinct $d$-digit roundings can only happen between values that are more than
+// $10^{-d}$ times the value apart, relatively; since the neighbors are within $2^{1-p}$ of each
+// other, no $d$ smaller than roughly $(p - 3)\log_{10} 2$ can work, so we start the search there.
+// Only a few candidates are then tried.
+fn shortest_distinguishing_precision(x: &Float) -> u64 {
+    let p = x.get_prec().unwrap();
+    let e = x.get_exponent().unwrap() - i64::exact_from(p);
+    let (m, m_e) = x.integer_mantissa_and_exponent();
+    let x = m << u64::exact_from(m_e - e);
+    let lower = &x - Natural::ONE;
+    let higher = &x + Natural::ONE;
+    let log = floor_log_base_helper(&x, e, 10);
+    let lower_log = if lower == 0u32 {
+        0
+    } else {
+        floor_log_base_helper(&lower, e, 10)
+    };
+    let higher_log = floor_log_base_helper(&higher, e, 10);
+    // floor((p - 3) * log_10(2)), approximated from below
+    let mut precision = max(
+        1,
+        u64::exact_from(u128::from(p.saturating_sub(3)) * 30102999 / 100000000),
+    );
+    loop {
+        let power = Natural::from(10u32).pow(precision);
+        let r = round_to_decimal_digits(&x, e, log, precision, &power);
+        if r != round_to_decimal_digits(&lower, e, lower_log, precision, &power)
+            && r != round_to_decimal_digits(&higher, e, higher_log, precision, &power)
+        {
+            return precision;
+        }
+        precision += 1;
+    }
+}
+
 impl Display for Float {
     fn fmt(&self, f: &mut Formatter) -> Result {
         match self {
@@ -16,30 +81,16 @@ impl Display for Float {
             float_zero!() => write!(f, "0.0"),
             float_negative_zero!() => write!(f, "-0.0"),
             _ => {
-                let mut lower = self.clone();
-                let mut higher = self.clone();
-                lower.decrement();
-                higher.increment();
-                let self_q = Rational::exact_from(self);
-                let lower_q = Rational::exact_from(lower);
-                let higher_q = Rational::exact_from(higher);
                 let mut options = ToSciOptions::default();
-                for precision in 1.. {
-                    options.set_precision(precision);
-                    let s = self_q.to_sci_with_options(options).to_string();
-                    let s_lower = lower_q.to_sci_with_options(options).to_string();
-                    let s_higher = higher_q.to_sci_with_options(options).to_string();
-                    if s != s_lower && s != s_higher {
-                        return if s.contains('.') {
-                            write!(f, "{s}")
-                        } else if let Some(i) = s.find('e') {
-                            write!(f, "{}.0e{}", &s[..i], &s[i + 1..])
-                        } else {
-                            write!(f, "{s}.0")
-                        };
-                    }
+                options.set_precision(shortest_distinguishing_precision(self));
+                let s = self.to_sci_with_options(options).to_string();
+                if s.contains('.') {
+                    write!(f, "{s}")
+                } else if let Some(i) = s.find('e') {
+                    write!(f, "{}.0e{}", &s[..i], &s[i + 1..])
+                } else {
+                    write!(f, "{s}.0")
                 }
-                panic!();
             }
         }
     }
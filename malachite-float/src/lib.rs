@@ -266,6 +266,8 @@ pub mod arithmetic;
 pub mod basic;
 /// Traits for comparing [`Float`]s for equality or order.
 pub mod comparison;
+/// Mathematical constants, such as $\pi$ and $e$, computed to arbitrary precision.
+pub mod constants;
 /// Traits for converting to and from [`Float`]s, including converting [`Float`]s to and from
 /// strings.
 pub mod conversion;
@@ -18,7 +18,7 @@ use malachite_base::num::exhaustive::{
 use malachite_base::num::logic::traits::SignificantBits;
 use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
 use malachite_base::rounding_modes::RoundingMode;
-use malachite_base::test_util::generators::common::{reshape_2_1_to_3, It};
+use malachite_base::test_util::generators::common::{reshape_2_1_to_3, reshape_2_2_to_4, It};
 use malachite_base::test_util::generators::exhaustive_pairs_big_tiny;
 use malachite_base::tuples::exhaustive::{
     exhaustive_pairs, exhaustive_pairs_from_single, exhaustive_triples_xyy, lex_pairs,
@@ -29,6 +29,7 @@ use malachite_nz::natural::exhaustive::exhaustive_naturals;
 use malachite_nz::natural::Natural;
 use malachite_q::exhaustive::exhaustive_rationals;
 use malachite_q::Rational;
+use std::cmp::Ordering;
 
 // -- Float --
 
@@ -118,6 +119,120 @@ pub fn exhaustive_float_float_unsigned_triple_gen<T: PrimitiveUnsigned>() -> It<
     ))
 }
 
+pub fn exhaustive_float_float_unsigned_triple_gen_var_1() -> It<(Float, Float, u64)> {
+    reshape_2_1_to_3(Box::new(exhaustive_pairs_big_tiny(
+        exhaustive_pairs_from_single(exhaustive_floats()),
+        exhaustive_positive_primitive_ints(),
+    )))
+}
+
+// -- (Float, Float, PrimitiveUnsigned, RoundingMode) --
+
+pub fn add_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.add_prec_round_ref_ref(y, prec, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn sub_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.sub_prec_round_ref_ref(y, prec, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn mul_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.mul_prec_round_ref_ref(y, prec, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn div_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.div_prec_round_ref_ref(y, prec, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn atan2_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.atan2_prec_round_ref_ref(y, prec, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        reshape_2_2_to_4(Box::new(exhaustive_pairs_big_tiny(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            lex_pairs(
+                exhaustive_positive_primitive_ints(),
+                exhaustive_rounding_modes(),
+            ),
+        )))
+        .filter(|(x, y, prec, rm)| add_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_2(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        reshape_2_2_to_4(Box::new(exhaustive_pairs_big_tiny(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            lex_pairs(
+                exhaustive_positive_primitive_ints(),
+                exhaustive_rounding_modes(),
+            ),
+        )))
+        .filter(|(x, y, prec, rm)| sub_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_3(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        reshape_2_2_to_4(Box::new(exhaustive_pairs_big_tiny(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            lex_pairs(
+                exhaustive_positive_primitive_ints(),
+                exhaustive_rounding_modes(),
+            ),
+        )))
+        .filter(|(x, y, prec, rm)| mul_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_4(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        reshape_2_2_to_4(Box::new(exhaustive_pairs_big_tiny(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            lex_pairs(
+                exhaustive_positive_primitive_ints(),
+                exhaustive_rounding_modes(),
+            ),
+        )))
+        .filter(|(x, y, prec, rm)| div_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_5(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        reshape_2_2_to_4(Box::new(exhaustive_pairs_big_tiny(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            lex_pairs(
+                exhaustive_positive_primitive_ints(),
+                exhaustive_rounding_modes(),
+            ),
+        )))
+        .filter(|(x, y, prec, rm)| atan2_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
 // -- (Float, Float, Rational) --
 
 pub fn exhaustive_float_float_rational_triple_gen() -> It<(Float, Float, Rational)> {
@@ -238,6 +353,36 @@ pub fn exhaustive_float_unsigned_unsigned_triple_gen<T: PrimitiveUnsigned>() ->
     ))
 }
 
+pub fn exhaustive_float_unsigned_unsigned_triple_gen_var_1() -> It<(Float, u64, u64)> {
+    reshape_2_1_to_3(Box::new(exhaustive_pairs_big_tiny(
+        exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+        exhaustive_positive_primitive_ints(),
+    )))
+}
+
+// -- (Float, PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode) --
+
+pub fn root_prec_round_valid(x: &Float, n: u64, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.root_prec_round_ref(n, p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn exhaustive_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> It<(Float, u64, u64, RoundingMode)> {
+    Box::new(
+        reshape_2_2_to_4(Box::new(exhaustive_pairs_big_tiny(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            lex_pairs(
+                exhaustive_positive_primitive_ints(),
+                exhaustive_rounding_modes(),
+            ),
+        )))
+        .filter(|(x, n, prec, rm)| root_prec_round_valid(x, *n, *prec, *rm)),
+    )
+}
+
 // -- (Float, PrimitiveUnsigned, RoundingMode) --
 
 pub fn set_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
@@ -247,6 +392,174 @@ pub fn set_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
     }
 }
 
+pub fn reciprocal_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.reciprocal_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn sqrt_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.sqrt_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn exp_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.exp_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn exp2_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.exp2_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn exp10_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.exp10_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn expm1_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.expm1_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn ln_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.ln_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn log2_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.log2_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn log10_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.log10_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn ln_1p_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.ln_1p_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn sin_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.sin_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn cos_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.cos_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn tan_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.tan_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn asin_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.asin_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn acos_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.acos_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn atan_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.atan_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn sinh_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.sinh_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn cosh_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.cosh_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn tanh_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.tanh_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn asinh_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.asinh_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn acosh_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.acosh_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn atanh_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.atanh_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn sin_cos_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, _, o_sin, o_cos) = x.sin_cos_prec_round_ref(p, RoundingMode::Floor);
+        o_sin == Ordering::Equal && o_cos == Ordering::Equal
+    }
+}
+
+pub fn reciprocal_sqrt_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.reciprocal_sqrt_prec_round_ref(p, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
 pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_1() -> It<(Float, u64, RoundingMode)>
 {
     reshape_2_1_to_3(Box::new(
@@ -258,6 +571,270 @@ pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_1() -> It<(Float,
     ))
 }
 
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_2() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| reciprocal_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_3() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| sqrt_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_4() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| reciprocal_sqrt_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_5() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| exp_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_6() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| exp2_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_7() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| exp10_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_8() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| expm1_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_9() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| ln_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_10() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| log2_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_11() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| log10_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_12() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| ln_1p_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_13() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| sin_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_14() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| cos_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_15() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| tan_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_16() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| asin_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_17() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| acos_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_18() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| atan_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_19() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| sinh_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_20() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| cosh_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_21() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| tanh_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_22() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| asinh_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_23() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| acosh_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_24() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| atanh_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_25() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| sin_cos_prec_round_valid(x, p, rm)),
+    ))
+}
+
 // -- (Float, Rational) --
 
 pub fn exhaustive_float_rational_pair_gen() -> It<(Float, Rational)> {
@@ -283,6 +860,127 @@ pub fn exhaustive_float_rational_rational_triple_gen() -> It<(Float, Rational, R
     ))
 }
 
+// -- (Float, Rational, PrimitiveUnsigned) --
+
+pub fn exhaustive_float_rational_unsigned_triple_gen_var_1() -> It<(Float, Rational, u64)> {
+    reshape_2_1_to_3(Box::new(exhaustive_pairs_big_tiny(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rationals()),
+        exhaustive_positive_primitive_ints(),
+    )))
+}
+
+// -- (Float, Rational, PrimitiveUnsigned, RoundingMode) --
+
+pub fn add_rational_prec_round_valid(x: &Float, y: &Rational, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.add_rational_prec_round_ref_ref(y, prec, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn sub_rational_prec_round_valid(x: &Float, y: &Rational, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.sub_rational_prec_round_ref_ref(y, prec, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn mul_rational_prec_round_valid(x: &Float, y: &Rational, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.mul_rational_prec_round_ref_ref(y, prec, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn div_rational_prec_round_valid(x: &Float, y: &Rational, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = x.div_rational_prec_round_ref_ref(y, prec, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn rational_div_float_prec_round_valid(
+    x: &Float,
+    y: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> bool {
+    rm != RoundingMode::Exact || {
+        let (_, o) = Float::rational_div_float_prec_round_ref_ref(y, x, prec, RoundingMode::Floor);
+        o == Ordering::Equal
+    }
+}
+
+pub fn exhaustive_float_rational_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> It<(Float, Rational, u64, RoundingMode)> {
+    Box::new(
+        reshape_2_2_to_4(Box::new(exhaustive_pairs_big_tiny(
+            exhaustive_pairs(exhaustive_floats(), exhaustive_rationals()),
+            lex_pairs(
+                exhaustive_positive_primitive_ints(),
+                exhaustive_rounding_modes(),
+            ),
+        )))
+        .filter(|(x, y, prec, rm)| add_rational_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rational_unsigned_rounding_mode_quadruple_gen_var_2(
+) -> It<(Float, Rational, u64, RoundingMode)> {
+    Box::new(
+        reshape_2_2_to_4(Box::new(exhaustive_pairs_big_tiny(
+            exhaustive_pairs(exhaustive_floats(), exhaustive_rationals()),
+            lex_pairs(
+                exhaustive_positive_primitive_ints(),
+                exhaustive_rounding_modes(),
+            ),
+        )))
+        .filter(|(x, y, prec, rm)| sub_rational_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rational_unsigned_rounding_mode_quadruple_gen_var_3(
+) -> It<(Float, Rational, u64, RoundingMode)> {
+    Box::new(
+        reshape_2_2_to_4(Box::new(exhaustive_pairs_big_tiny(
+            exhaustive_pairs(exhaustive_floats(), exhaustive_rationals()),
+            lex_pairs(
+                exhaustive_positive_primitive_ints(),
+                exhaustive_rounding_modes(),
+            ),
+        )))
+        .filter(|(x, y, prec, rm)| mul_rational_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rational_unsigned_rounding_mode_quadruple_gen_var_4(
+) -> It<(Float, Rational, u64, RoundingMode)> {
+    Box::new(
+        reshape_2_2_to_4(Box::new(exhaustive_pairs_big_tiny(
+            exhaustive_pairs(exhaustive_floats(), exhaustive_rationals()),
+            lex_pairs(
+                exhaustive_positive_primitive_ints(),
+                exhaustive_rounding_modes(),
+            ),
+        )))
+        .filter(|(x, y, prec, rm)| div_rational_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rational_unsigned_rounding_mode_quadruple_gen_var_5(
+) -> It<(Float, Rational, u64, RoundingMode)> {
+    Box::new(
+        reshape_2_2_to_4(Box::new(exhaustive_pairs_big_tiny(
+            exhaustive_pairs(exhaustive_floats(), exhaustive_rationals()),
+            lex_pairs(
+                exhaustive_positive_primitive_ints(),
+                exhaustive_rounding_modes(),
+            ),
+        )))
+        .filter(|(x, y, prec, rm)| rational_div_float_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
 // -- (Float, RoundingMode) --
 
 pub(crate) fn natural_rounding_from_float_valid(f: &Float, rm: RoundingMode) -> bool {
@@ -175,6 +175,67 @@ pub fn float_float_unsigned_triple_gen<T: PrimitiveUnsigned>() -> Generator<(Flo
     )
 }
 
+// All `(Float, Float, u64)` where the `u64` is positive.
+pub fn float_float_unsigned_triple_gen_var_1() -> Generator<(Float, Float, u64)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_triple_gen_var_1,
+        &random_float_float_unsigned_triple_gen_var_1,
+        &special_random_float_float_unsigned_triple_gen_var_1,
+    )
+}
+
+// -- (Float, Float, PrimitiveUnsigned, RoundingMode) --
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::add_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_1,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_1,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_1,
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::sub_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_2(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_2,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_2,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_2,
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::mul_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_3(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_3,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_3,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_3,
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::div_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_4(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_4,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_4,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_4,
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::atan2_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_5(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_5,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_5,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_5,
+    )
+}
+
 // -- (Float, Float, Rational) --
 
 pub fn float_float_rational_triple_gen() -> Generator<(Float, Float, Rational)> {
@@ -374,6 +435,15 @@ pub fn float_unsigned_unsigned_triple_gen<T: PrimitiveUnsigned>() -> Generator<(
     )
 }
 
+// All `(Float, u64, u64)` where both `u64`s are positive.
+pub fn float_unsigned_unsigned_triple_gen_var_1() -> Generator<(Float, u64, u64)> {
+    Generator::new(
+        &exhaustive_float_unsigned_unsigned_triple_gen_var_1,
+        &random_float_unsigned_unsigned_triple_gen_var_1,
+        &special_random_float_unsigned_unsigned_triple_gen_var_1,
+    )
+}
+
 // -- (Float, PrimitiveUnsigned, RoundingMode) --
 
 // All `(Float, u64, RoundingMode)` that are valid inputs to `Float.set_prec`.
@@ -408,6 +478,235 @@ pub fn float_unsigned_rounding_mode_triple_gen_var_1_rm() -> Generator<(
     )
 }
 
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::reciprocal_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_2() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_2,
+        &random_float_unsigned_rounding_mode_triple_gen_var_2,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_2,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::sqrt_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_3() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_3,
+        &random_float_unsigned_rounding_mode_triple_gen_var_3,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_3,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::reciprocal_sqrt_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_4() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_4,
+        &random_float_unsigned_rounding_mode_triple_gen_var_4,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_4,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::exp_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_5() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_5,
+        &random_float_unsigned_rounding_mode_triple_gen_var_5,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_5,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::exp2_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_6() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_6,
+        &random_float_unsigned_rounding_mode_triple_gen_var_6,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_6,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::exp10_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_7() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_7,
+        &random_float_unsigned_rounding_mode_triple_gen_var_7,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_7,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::expm1_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_8() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_8,
+        &random_float_unsigned_rounding_mode_triple_gen_var_8,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_8,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::ln_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_9() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_9,
+        &random_float_unsigned_rounding_mode_triple_gen_var_9,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_9,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::log2_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_10() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_10,
+        &random_float_unsigned_rounding_mode_triple_gen_var_10,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_10,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::log10_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_11() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_11,
+        &random_float_unsigned_rounding_mode_triple_gen_var_11,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_11,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::ln_1p_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_12() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_12,
+        &random_float_unsigned_rounding_mode_triple_gen_var_12,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_12,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::sin_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_13() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_13,
+        &random_float_unsigned_rounding_mode_triple_gen_var_13,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_13,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::cos_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_14() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_14,
+        &random_float_unsigned_rounding_mode_triple_gen_var_14,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_14,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::tan_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_15() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_15,
+        &random_float_unsigned_rounding_mode_triple_gen_var_15,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_15,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::asin_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_16() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_16,
+        &random_float_unsigned_rounding_mode_triple_gen_var_16,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_16,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::acos_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_17() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_17,
+        &random_float_unsigned_rounding_mode_triple_gen_var_17,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_17,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::atan_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_18() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_18,
+        &random_float_unsigned_rounding_mode_triple_gen_var_18,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_18,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::sinh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_19() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_19,
+        &random_float_unsigned_rounding_mode_triple_gen_var_19,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_19,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::cosh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_20() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_20,
+        &random_float_unsigned_rounding_mode_triple_gen_var_20,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_20,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::tanh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_21() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_21,
+        &random_float_unsigned_rounding_mode_triple_gen_var_21,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_21,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::asinh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_22() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_22,
+        &random_float_unsigned_rounding_mode_triple_gen_var_22,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_22,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::acosh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_23() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_23,
+        &random_float_unsigned_rounding_mode_triple_gen_var_23,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_23,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::atanh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_24() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_24,
+        &random_float_unsigned_rounding_mode_triple_gen_var_24,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_24,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::sin_cos_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_25() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_25,
+        &random_float_unsigned_rounding_mode_triple_gen_var_25,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_25,
+    )
+}
+
+// -- (Float, PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode) --
+
+// All `(Float, u64, u64, RoundingMode)` that are valid inputs to `Float::root_prec_round`, where
+// the first `u64` is positive.
+pub fn float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> Generator<(Float, u64, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1,
+        &random_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1,
+        &special_random_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1,
+    )
+}
+
 // -- (Float, Rational) --
 
 pub fn float_rational_pair_gen() -> Generator<(Float, Rational)> {
@@ -445,6 +744,71 @@ pub fn float_rational_rational_triple_gen() -> Generator<(Float, Rational, Ratio
     )
 }
 
+// -- (Float, Rational, PrimitiveUnsigned) --
+
+// All `(Float, Rational, u64)` where the `u64` is positive.
+pub fn float_rational_unsigned_triple_gen_var_1() -> Generator<(Float, Rational, u64)> {
+    Generator::new(
+        &exhaustive_float_rational_unsigned_triple_gen_var_1,
+        &random_float_rational_unsigned_triple_gen_var_1,
+        &special_random_float_rational_unsigned_triple_gen_var_1,
+    )
+}
+
+// -- (Float, Rational, PrimitiveUnsigned, RoundingMode) --
+
+// All `(Float, Rational, u64, RoundingMode)` that are valid inputs to
+// `Float::add_rational_prec_round`.
+pub fn float_rational_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> Generator<(Float, Rational, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rational_unsigned_rounding_mode_quadruple_gen_var_1,
+        &random_float_rational_unsigned_rounding_mode_quadruple_gen_var_1,
+        &special_random_float_rational_unsigned_rounding_mode_quadruple_gen_var_1,
+    )
+}
+
+// All `(Float, Rational, u64, RoundingMode)` that are valid inputs to
+// `Float::sub_rational_prec_round`.
+pub fn float_rational_unsigned_rounding_mode_quadruple_gen_var_2(
+) -> Generator<(Float, Rational, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rational_unsigned_rounding_mode_quadruple_gen_var_2,
+        &random_float_rational_unsigned_rounding_mode_quadruple_gen_var_2,
+        &special_random_float_rational_unsigned_rounding_mode_quadruple_gen_var_2,
+    )
+}
+
+// All `(Float, Rational, u64, RoundingMode)` that are valid inputs to `Float::mul_rational_prec_round`.
+pub fn float_rational_unsigned_rounding_mode_quadruple_gen_var_3(
+) -> Generator<(Float, Rational, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rational_unsigned_rounding_mode_quadruple_gen_var_3,
+        &random_float_rational_unsigned_rounding_mode_quadruple_gen_var_3,
+        &special_random_float_rational_unsigned_rounding_mode_quadruple_gen_var_3,
+    )
+}
+
+// All `(Float, Rational, u64, RoundingMode)` that are valid inputs to `Float::div_rational_prec_round`.
+pub fn float_rational_unsigned_rounding_mode_quadruple_gen_var_4(
+) -> Generator<(Float, Rational, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rational_unsigned_rounding_mode_quadruple_gen_var_4,
+        &random_float_rational_unsigned_rounding_mode_quadruple_gen_var_4,
+        &special_random_float_rational_unsigned_rounding_mode_quadruple_gen_var_4,
+    )
+}
+
+// All `(Float, Rational, u64, RoundingMode)` such that the swapped `(Rational, Float, u64, RoundingMode)` are valid inputs to `Float::rational_div_float_prec_round`.
+pub fn float_rational_unsigned_rounding_mode_quadruple_gen_var_5(
+) -> Generator<(Float, Rational, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rational_unsigned_rounding_mode_quadruple_gen_var_5,
+        &random_float_rational_unsigned_rounding_mode_quadruple_gen_var_5,
+        &special_random_float_rational_unsigned_rounding_mode_quadruple_gen_var_5,
+    )
+}
+
 // -- (Float, RoundingMode) --
 
 pub fn float_rounding_mode_pair_gen() -> Generator<(Float, RoundingMode)> {
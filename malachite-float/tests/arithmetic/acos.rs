@@ -0,0 +1,2332 @@
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_17,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_acos_prec_round(x: &rug::Float, prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.acos_ref(), rm)
+}
+
+#[test]
+fn test_acos_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_prec(prec);
+        assert!(acos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+
+        let (acos_alt, o_alt) = x.acos_prec_ref(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_prec_assign(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_acos, rug_o) = rug_acos_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("0.0", "0x0.0", 10, "1.57", "0x1.920#10", Ordering::Less);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Less,
+    );
+    test("-0.0", "-0x0.0", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("-0.0", "-0x0.0", 10, "1.57", "0x1.920#10", Ordering::Less);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Less,
+    );
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 1, "4.0", "0x4.0#1", Ordering::Greater);
+    test("-1.0", "-0x1.0#1", 10, "3.141", "0x3.24#10", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test("0.5", "0x0.8#1", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test("0.5", "0x0.8#1", 10, "1.047", "0x1.0c0#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.047197551196597746154214461094",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Ordering::Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "NaN", "NaN", Ordering::Equal);
+    test("123.0", "0x7b.0#7", 10, "NaN", "NaN", Ordering::Equal);
+    test("123.0", "0x7b.0#7", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "1.23",
+        "0x1.3b0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "1.230959417340774701761084911795",
+        "0x1.3b2028082e8d3d1abe83f2c2c#100",
+        Ordering::Greater,
+    );
+    test("1.0e30", "0x1.0E+25#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("1.0e30", "0x1.0E+25#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("1.0e30", "0x1.0E+25#1", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "1.23",
+        "0x1.3b0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "1.230959417340774682134929178248",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test("1.111", "0x1.1c8#10", 1, "NaN", "NaN", Ordering::Equal);
+    test("1.111", "0x1.1c8#10", 10, "NaN", "NaN", Ordering::Equal);
+    test("1.111", "0x1.1c8#10", 100, "NaN", "NaN", Ordering::Equal);
+    test("-0.5", "-0x0.8#1", 1, "2.0", "0x2.0#1", Ordering::Less);
+    test("-0.5", "-0x0.8#1", 10, "2.094", "0x2.18#10", Ordering::Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        "2.094395102393195492308428922187",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Ordering::Greater,
+    );
+    test("0.9", "0x0.e#3", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("0.9", "0x0.e#3", 10, "0.505", "0x0.814#10", Ordering::Less);
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        "0.505360510284157306971314873988",
+        "0x0.815f4e705c3e68f2e84ed1708#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn acos_prec_fail() {
+    assert_panic!(Float::NAN.acos_prec(0));
+    assert_panic!(Float::NAN.acos_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.acos_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_acos_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_round(rm);
+        assert!(acos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+
+        let (acos_alt, o_alt) = x.acos_round_ref(rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_round_assign(rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) =
+                rug_acos_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "1.2309594173407745",
+        "0x1.3b2028082e8d3#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "1.2309594173407747",
+        "0x1.3b2028082e8d4#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "1.2309594173407745",
+        "0x1.3b2028082e8d3#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "1.2309594173407747",
+        "0x1.3b2028082e8d4#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "1.2309594173407747",
+        "0x1.3b2028082e8d4#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "1.230959417340774682134929178247987375710340009355094839055547",
+        "0x1.3b2028082e8d3bb0b49d8acf01fe19505e6c599314e7d33b24#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "1.230959417340774682134929178247987375710340009355094839055549",
+        "0x1.3b2028082e8d3bb0b49d8acf01fe19505e6c599314e7d33b26#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "1.230959417340774682134929178247987375710340009355094839055547",
+        "0x1.3b2028082e8d3bb0b49d8acf01fe19505e6c599314e7d33b24#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "1.230959417340774682134929178247987375710340009355094839055549",
+        "0x1.3b2028082e8d3bb0b49d8acf01fe19505e6c599314e7d33b26#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "1.230959417340774682134929178247987375710340009355094839055549",
+        "0x1.3b2028082e8d3bb0b49d8acf01fe19505e6c599314e7d33b26#200",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#3",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Ceiling,
+        "0.6",
+        "0x0.a#3",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#3",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Up,
+        "0.6",
+        "0x0.a#3",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#3",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn acos_round_fail() {
+    assert_panic!(Float::from(0.5f32).acos_round(RoundingMode::Exact));
+    assert_panic!(Float::from(0.5f32).acos_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(0.5f32);
+        x.acos_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_acos_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_prec_round(prec, rm);
+        assert!(acos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+
+        let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_prec_round_assign(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "3.141592653589793238462643383282",
+        "0x3.243f6a8885a308d313198a2e4#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "1.23",
+        "0x1.3b0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "1.232",
+        "0x1.3b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "1.23",
+        "0x1.3b0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "1.230959417340774682134929178248",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "1.230959417340774682134929178249",
+        "0x1.3b2028082e8d3bb0b49d8acf2#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "1.230959417340774682134929178248",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "2.094",
+        "0x2.18#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.098",
+        "0x2.19#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "2.094",
+        "0x2.18#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        RoundingMode::Floor,
+        "2.094395102393195492308428922184",
+        "0x2.182a4705ae6cb08cb7665c1e8#100",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        RoundingMode::Ceiling,
+        "2.094395102393195492308428922187",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        RoundingMode::Nearest,
+        "2.094395102393195492308428922187",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        10,
+        RoundingMode::Floor,
+        "0.505",
+        "0x0.814#10",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        10,
+        RoundingMode::Ceiling,
+        "0.506",
+        "0x0.818#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        10,
+        RoundingMode::Nearest,
+        "0.505",
+        "0x0.814#10",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        RoundingMode::Floor,
+        "0.505360510284157306971314873987",
+        "0x0.815f4e705c3e68f2e84ed1707#100",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        RoundingMode::Ceiling,
+        "0.505360510284157306971314873988",
+        "0x0.815f4e705c3e68f2e84ed1708#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        RoundingMode::Nearest,
+        "0.505360510284157306971314873988",
+        "0x0.815f4e705c3e68f2e84ed1708#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn acos_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).acos_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).acos_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.acos_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(0.5f32).acos_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(0.5f32).acos_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(0.5f32);
+        x.acos_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn acos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_17().test_properties(|(x, prec, rm)| {
+        let (acos, o) = x.clone().acos_prec_round(prec, rm);
+        assert!(acos.is_valid());
+
+        let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.acos_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && acos.is_normal() {
+            assert_eq!(acos.get_prec(), Some(prec));
+            // $\arccos x$ is positive unless $x = 1$.
+            assert!(acos > 0u32);
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if acos > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if acos > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.acos_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.acos_prec_round_ref(prec, RoundingMode::Ceiling);
+                if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.acos_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(acos.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.acos_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn acos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (acos, o) = x.clone().acos_prec(prec);
+        assert!(acos.is_valid());
+
+        let (acos_alt, o_alt) = x.acos_prec_ref(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.acos_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        let (rug_acos, rug_o) = rug_acos_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn acos_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.acos_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.acos_round_ref(rm));
+                continue;
+            }
+
+            let (acos, o) = x.clone().acos_round(rm);
+            assert!(acos.is_valid());
+
+            let (acos_alt, o_alt) = x.acos_round_ref(rm);
+            assert!(acos_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.acos_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+            assert_eq!(o_alt, o);
+
+            let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_acos, rug_o) =
+                    rug_acos_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_acos)),
+                    ComparableFloatRef(&acos)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (acos, o) = x.acos_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(acos), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::NAN);
+    test_special(Float::NEGATIVE_INFINITY, Float::NAN);
+    test_special(Float::ONE, Float::ZERO);
+}
@@ -0,0 +1,2255 @@
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_23,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_acosh_prec_round(
+    x: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.acosh_ref(), rm)
+}
+
+#[test]
+fn test_acosh_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acosh, o) = x.clone().acosh_prec(prec);
+        assert!(acosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+
+        let (acosh_alt, o_alt) = x.acosh_prec_ref(prec);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acosh_alt = x.clone();
+        let o_alt = acosh_alt.acosh_prec_assign(prec);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_acosh, rug_o) = rug_acosh_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh)),
+            ComparableFloatRef(&acosh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 100, "NaN", "NaN", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "NaN", "NaN", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "NaN", "NaN", Ordering::Equal);
+    test("-0.0", "-0x0.0", 100, "NaN", "NaN", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.5", "0x0.8#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("0.5", "0x0.8#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("0.5", "0x0.8#1", 100, "NaN", "NaN", Ordering::Equal);
+    test("123.0", "0x7b.0#7", 1, "4.0", "0x4.0#1", Ordering::Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "5.51",
+        "0x5.82#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "5.505315010967266904805100520163",
+        "0x5.815c53164844554fd7eab6b10#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.812",
+        "0x1.d00#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.811526272460853065901319862625",
+        "0x1.cfc02f90106c17a3fd778845e#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.658",
+        "0x1.a88#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.657454454153077215400697256995",
+        "0x1.a84eef63326e764c4b7c9ea20#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "70.0",
+        "0x46.0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "70.0078652365544762511404442673",
+        "0x46.020374c5c6db00c6a6d5daf8#100",
+        Ordering::Less,
+    );
+    test("8.0e-31", "0x1.0E-25#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("8.0e-31", "0x1.0E-25#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("8.0e-31", "0x1.0E-25#1", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "0.4678",
+        "0x0.77c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "0.4675931556269121110066605243373",
+        "0x0.77b42f5f40433ba635ab7efe30#100",
+        Ordering::Greater,
+    );
+    test("2.0", "0x2.0#1", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test("2.0", "0x2.0#1", 10, "1.316", "0x1.510#10", Ordering::Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "1.316957896924816708625046347307",
+        "0x1.51242719804349be684bd0188#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn acosh_prec_fail() {
+    assert_panic!(Float::NAN.acosh_prec(0));
+    assert_panic!(Float::NAN.acosh_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.acosh_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_acosh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acosh, o) = x.clone().acosh_round(rm);
+        assert!(acosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+
+        let (acosh_alt, o_alt) = x.acosh_round_ref(rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acosh_alt = x.clone();
+        let o_alt = acosh_alt.acosh_round_assign(rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acosh, rug_o) =
+                rug_acosh_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acosh)),
+                ComparableFloatRef(&acosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "5.5",
+        "0x5.8#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "5.56",
+        "0x5.9#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "5.5",
+        "0x5.8#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "5.56",
+        "0x5.9#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "5.5",
+        "0x5.8#7",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.811526272460853",
+        "0x1.cfc02f90106c1#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.8115262724608532",
+        "0x1.cfc02f90106c2#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.811526272460853",
+        "0x1.cfc02f90106c1#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.8115262724608532",
+        "0x1.cfc02f90106c2#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.811526272460853",
+        "0x1.cfc02f90106c1#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "1.6574544541530771",
+        "0x1.a84eef63326e7#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "1.6574544541530774",
+        "0x1.a84eef63326e8#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "1.6574544541530771",
+        "0x1.a84eef63326e7#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "1.6574544541530774",
+        "0x1.a84eef63326e8#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "1.6574544541530771",
+        "0x1.a84eef63326e7#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "0.4673",
+        "0x0.77a#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "0.4678",
+        "0x0.77c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "0.4673",
+        "0x0.77a#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "0.4678",
+        "0x0.77c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "0.4678",
+        "0x0.77c#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn acosh_round_fail() {
+    assert_panic!(Float::from(3u32).acosh_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).acosh_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.acosh_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_acosh_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acosh, o) = x.clone().acosh_prec_round(prec, rm);
+        assert!(acosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+
+        let (acosh_alt, o_alt) = x.acosh_prec_round_ref(prec, rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acosh_alt = x.clone();
+        let o_alt = acosh_alt.acosh_prec_round_assign(prec, rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acosh, rug_o) = rug_acosh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acosh)),
+                ComparableFloatRef(&acosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.811",
+        "0x1.cf8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.812",
+        "0x1.d00#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.812",
+        "0x1.d00#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "1.811526272460853065901319862623",
+        "0x1.cfc02f90106c17a3fd778845c#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "1.811526272460853065901319862625",
+        "0x1.cfc02f90106c17a3fd778845e#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "1.811526272460853065901319862625",
+        "0x1.cfc02f90106c17a3fd778845e#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "0.4673",
+        "0x0.77a#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.4678",
+        "0x0.77c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "0.4678",
+        "0x0.77c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "0.4675931556269121110066605243369",
+        "0x0.77b42f5f40433ba635ab7efe28#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "0.4675931556269121110066605243373",
+        "0x0.77b42f5f40433ba635ab7efe30#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "0.4675931556269121110066605243373",
+        "0x0.77b42f5f40433ba635ab7efe30#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.316",
+        "0x1.510#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.318",
+        "0x1.518#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.316",
+        "0x1.510#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.316957896924816708625046347307",
+        "0x1.51242719804349be684bd0188#100",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.316957896924816708625046347309",
+        "0x1.51242719804349be684bd018a#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.316957896924816708625046347307",
+        "0x1.51242719804349be684bd0188#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn acosh_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).acosh_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).acosh_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.acosh_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).acosh_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).acosh_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.acosh_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn acosh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_23().test_properties(|(x, prec, rm)| {
+        let (acosh, o) = x.clone().acosh_prec_round(prec, rm);
+        assert!(acosh.is_valid());
+
+        let (acosh_alt, o_alt) = x.acosh_prec_round_ref(prec, rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.acosh_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && acosh.is_normal() {
+            assert_eq!(acosh.get_prec(), Some(prec));
+            // $\operatorname{arcosh} x$ is positive unless $x = 1$.
+            assert!(acosh > 0u32);
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if acosh > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if acosh > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.acosh_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.acosh_prec_round_ref(prec, RoundingMode::Ceiling);
+                if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acosh, rug_o) =
+                rug_acosh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acosh)),
+                ComparableFloatRef(&acosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.acosh_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(acosh.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.acosh_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn acosh_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (acosh, o) = x.clone().acosh_prec(prec);
+        assert!(acosh.is_valid());
+
+        let (acosh_alt, o_alt) = x.acosh_prec_ref(prec);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.acosh_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        let (acosh_alt, o_alt) = x.acosh_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        let (rug_acosh, rug_o) = rug_acosh_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh)),
+            ComparableFloatRef(&acosh)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn acosh_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.acosh_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.acosh_round_ref(rm));
+                continue;
+            }
+
+            let (acosh, o) = x.clone().acosh_round(rm);
+            assert!(acosh.is_valid());
+
+            let (acosh_alt, o_alt) = x.acosh_round_ref(rm);
+            assert!(acosh_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.acosh_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acosh));
+            assert_eq!(o_alt, o);
+
+            let (acosh_alt, o_alt) = x.acosh_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_acosh, rug_o) =
+                    rug_acosh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_acosh)),
+                    ComparableFloatRef(&acosh)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (acosh, o) = x.acosh_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(acosh), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::INFINITY);
+    test_special(Float::NEGATIVE_INFINITY, Float::NAN);
+    test_special(Float::ZERO, Float::NAN);
+    test_special(Float::NEGATIVE_ZERO, Float::NAN);
+    test_special(Float::ONE, Float::ZERO);
+    test_special(Float::NEGATIVE_ONE, Float::NAN);
+}
@@ -0,0 +1,8202 @@
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_unsigned_rounding_mode_quadruple_gen_var_1, float_float_unsigned_triple_gen_var_1,
+    float_gen, float_pair_gen, float_pair_gen_var_1, float_rational_pair_gen,
+    float_rational_pair_gen_var_1, float_rational_unsigned_rounding_mode_quadruple_gen_var_1,
+    float_rational_unsigned_triple_gen_var_1, float_triple_gen,
+    float_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn rug_add_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x + y, rm)
+}
+
+fn rug_add_rational_prec_round(
+    x: &rug::Float,
+    y: &rug::Rational,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x + y, rm)
+}
+
+#[test]
+fn test_add() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let sum = x.clone() + y.clone();
+        assert!(sum.is_valid());
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let sum_alt = x.clone() + &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let sum_alt = &x + y.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let sum_alt = &x + &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+
+        let mut sum_alt = x.clone();
+        sum_alt += y.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let mut sum_alt = x.clone();
+        sum_alt += &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+
+        let prec = max(x.significant_bits(), y.significant_bits());
+        let (rug_sum, _) = rug_add_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sum)),
+            ComparableFloatRef(&sum)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", "Infinity", "Infinity",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "123.0", "0x7b.0#7", "123.0", "0x7b.0#7");
+    test("123.0", "0x7b.0#7", "-0.0", "-0x0.0", "123.0", "0x7b.0#7");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "0.0", "0x0.0");
+    test("123.0", "0x7b.0#7", "1.0", "0x1.0#1", "124.0", "0x7c.0#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "5.859874482048838",
+        "0x5.dc20bbeb10900#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+    );
+    test("1.0", "0x1.0#1", "8.0e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+}
+
+#[test]
+fn test_add_prec() {
+    let test = |s, s_hex, t, t_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (sum, o) = x.clone().add_prec(y.clone(), prec);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_prec_val_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_prec_ref_val(y.clone(), prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_prec_ref_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_assign(y.clone(), prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_assign_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_sum, rug_o) = rug_add_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sum)),
+            ComparableFloatRef(&sum)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "124.0",
+        "0x7c.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "124.0",
+        "0x7c.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "5.86",
+        "0x5.dc#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "5.859874482048838206793561766972",
+        "0x5.dc20bbeb10902000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "1.727",
+        "0x1.ba0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "1.727379091216697970523341609805",
+        "0x1.ba35842091e63000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "3.141592653589793115997963468544",
+        "0x3.243f6a8885a30000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn add_prec_fail() {
+    assert_panic!(Float::ONE.add_prec(Float::TWO, 0));
+    assert_panic!(Float::ONE.add_prec_val_ref(&Float::TWO, 0));
+    assert_panic!(Float::ONE.add_prec_ref_val(Float::TWO, 0));
+    assert_panic!(Float::ONE.add_prec_ref_ref(&Float::TWO, 0));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_prec_assign(Float::TWO, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_prec_assign_ref(&Float::TWO, 0)
+    });
+}
+
+#[test]
+fn test_add_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (sum, o) = x.clone().add_round(y.clone(), rm);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_round_val_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_round_ref_val(y.clone(), rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_round_ref_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_round_assign(y.clone(), rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_round_assign_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sum, rug_o) = rug_add_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                max(x.significant_bits(), y.significant_bits()),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sum)),
+                ComparableFloatRef(&sum)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "124.0",
+        "0x7c.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "124.0",
+        "0x7c.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "124.0",
+        "0x7c.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "124.0",
+        "0x7c.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "124.0",
+        "0x7c.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "124.0",
+        "0x7c.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "5.859874482048838",
+        "0x5.dc20bbeb10900#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "5.859874482048839",
+        "0x5.dc20bbeb10904#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "5.859874482048838",
+        "0x5.dc20bbeb10900#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "5.859874482048839",
+        "0x5.dc20bbeb10904#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "5.859874482048838",
+        "0x5.dc20bbeb10900#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Exact,
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "1.2676506002282297e30",
+        "0x1.0000000000001E+25#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "1.2676506002282297e30",
+        "0x1.0000000000001E+25#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "3.1415926535897936",
+        "0x3.243f6a8885a32#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "3.1415926535897936",
+        "0x3.243f6a8885a32#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn add_round_fail() {
+    assert_panic!(Float::from(3u32).add_round(Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).add_round_val_ref(&Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).add_round_ref_val(Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).add_round_ref_ref(&Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.add_round_assign(Float::from(1.0e-100), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.add_round_assign_ref(&Float::from(1.0e-100), RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_add_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (sum, o) = x.clone().add_prec_round(y.clone(), prec, rm);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_prec_round_val_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_round_assign(y.clone(), prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_round_assign_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sum, rug_o) = rug_add_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sum)),
+                ComparableFloatRef(&sum)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Down,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Up,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Down,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Up,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "124.0",
+        "0x7c.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "124.0",
+        "0x7c.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "124.0",
+        "0x7c.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "124.0",
+        "0x7c.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "124.0",
+        "0x7c.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "124.0",
+        "0x7c.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Down,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Up,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "5.86",
+        "0x5.dc#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "5.87",
+        "0x5.de#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "5.86",
+        "0x5.dc#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "5.87",
+        "0x5.de#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "5.86",
+        "0x5.dc#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "1.727",
+        "0x1.ba0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.729",
+        "0x1.ba8#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Down,
+        "1.727",
+        "0x1.ba0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Up,
+        "1.729",
+        "0x1.ba8#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "1.727",
+        "0x1.ba0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Ceiling,
+        "3.0e30",
+        "0x2.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Down,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Up,
+        "3.0e30",
+        "0x2.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.27e30",
+        "0x1.008E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "1.27e30",
+        "0x1.008E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn add_prec_round_fail() {
+    assert_panic!(Float::ONE.add_prec_round(Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.add_prec_round_val_ref(&Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.add_prec_round_ref_val(Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.add_prec_round_ref_ref(&Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_prec_round_assign(Float::TWO, 0, RoundingMode::Floor)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_prec_round_assign_ref(&Float::TWO, 0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::ONE.add_prec_round(Float::from(1.0e-100), 1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.add_prec_round_val_ref(
+        &Float::from(1.0e-100),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.add_prec_round_ref_val(Float::from(1.0e-100), 1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.add_prec_round_ref_ref(
+        &Float::from(1.0e-100),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_prec_round_assign(Float::from(1.0e-100), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_prec_round_assign_ref(&Float::from(1.0e-100), 1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_add_rational() {
+    let test = |s, s_hex, t, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let sum = x.clone() + y.clone();
+        assert!(sum.is_valid());
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let sum_alt = x.clone() + &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let sum_alt = &x + y.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let sum_alt = &x + &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+
+        let sum_alt = y.clone() + x.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let sum_alt = y.clone() + &x;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let sum_alt = &y + x.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let sum_alt = &y + &x;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+
+        let mut sum_alt = x.clone();
+        sum_alt += y.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let mut sum_alt = x.clone();
+        sum_alt += &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+
+        let (rug_sum, _) = rug_add_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::from(&y),
+            x.significant_bits(),
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sum)),
+            ComparableFloatRef(&sum)
+        );
+    };
+    test("NaN", "NaN", "123", "NaN", "NaN");
+    test("Infinity", "Infinity", "-123", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "0", "-Infinity", "-Infinity");
+    test("0.0", "0x0.0", "0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "0", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "1/3", "0.2", "0x0.4#1");
+    test("-0.0", "-0x0.0", "-22/7", "-4.0", "-0x4.0#1");
+    test("1.0", "0x1.0#1", "0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "1", "2.0", "0x2.0#1");
+    test("1.0", "0x1.0#1", "-1", "0.0", "0x0.0");
+    test("123.0", "0x7b.0#7", "1/3", "123.0", "0x7b.0#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        "-0.0012644892673497412",
+        "-0x0.0052de9c0ca62494#53",
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        "-1.0808802290397619",
+        "-0x1.14b491129e678#53",
+    );
+    test("1.0e30", "0x1.0E+25#1", "1/3", "1.0e30", "0x1.0E+25#1");
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        "1.0e9",
+        "0x4.0E+7#1",
+    );
+}
+
+#[test]
+fn test_add_rational_prec() {
+    let test = |s, s_hex, t, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (sum, o) = x.clone().add_rational_prec(y.clone(), prec);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_rational_prec_val_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_rational_prec_ref_val(y.clone(), prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_rational_prec_ref_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_rational_prec_assign(y.clone(), prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_rational_prec_assign_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_sum, rug_o) = rug_add_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sum)),
+            ComparableFloatRef(&sum)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "123", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", "123", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", "123", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", "0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", "0", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", "0", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", "0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", "0", 10, "-0.0", "-0x0.0", Ordering::Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", "1/3", 1, "0.2", "0x0.4#1", Ordering::Less);
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        100,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        "-3.145",
+        "-0x3.25#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        100,
+        "-3.142857142857142857142857142858",
+        "-0x3.2492492492492492492492494#100",
+        Ordering::Less,
+    );
+    test("1.0", "0x1.0#1", "0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", "1", 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", "-1", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", "-1", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", "-1", 100, "0.0", "0x0.0", Ordering::Equal);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        "123.4",
+        "0x7b.6#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        100,
+        "123.3333333333333333333333333334",
+        "0x7b.555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        "-0.001",
+        "-0x0.004#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        "-0.001265",
+        "-0x0.0052e#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        100,
+        "-0.001264489267349741144893674312957",
+        "-0x0.0052de9c0ca62492492492492490#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        "-1.08",
+        "-0x1.148#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        100,
+        "-1.080880229039761812141288525405",
+        "-0x1.14b491129e677aaaaaaaaaaaa#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        100,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        "1.0e9",
+        "0x4.0E+7#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        "1.0e9",
+        "0x3.baE+7#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        100,
+        "1000000000.0",
+        "0x3b9aca00.000000000000000000#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn add_rational_prec_fail() {
+    assert_panic!(Float::ONE.add_rational_prec(Rational::ONE, 0));
+    assert_panic!(Float::ONE.add_rational_prec_val_ref(&Rational::ONE, 0));
+    assert_panic!(Float::ONE.add_rational_prec_ref_val(Rational::ONE, 0));
+    assert_panic!(Float::ONE.add_rational_prec_ref_ref(&Rational::ONE, 0));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_rational_prec_assign(Rational::ONE, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_rational_prec_assign_ref(&Rational::ONE, 0)
+    });
+}
+
+#[test]
+fn test_add_rational_round() {
+    let test = |s, s_hex, t, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (sum, o) = x.clone().add_rational_round(y.clone(), rm);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_rational_round_val_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_rational_round_ref_val(y.clone(), rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_rational_round_ref_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_rational_round_assign(y.clone(), rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_rational_round_assign_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sum, rug_o) = rug_add_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::from(&y),
+                x.significant_bits(),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sum)),
+                ComparableFloatRef(&sum)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        RoundingMode::Up,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        RoundingMode::Ceiling,
+        "124.0",
+        "0x7c.0#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        RoundingMode::Down,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        RoundingMode::Up,
+        "124.0",
+        "0x7c.0#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Floor,
+        "-0.0012644892673497412",
+        "-0x0.0052de9c0ca62494#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "-0.001264489267349741",
+        "-0x0.0052de9c0ca62490#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Down,
+        "-0.001264489267349741",
+        "-0x0.0052de9c0ca62490#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Up,
+        "-0.0012644892673497412",
+        "-0x0.0052de9c0ca62494#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Nearest,
+        "-0.0012644892673497412",
+        "-0x0.0052de9c0ca62494#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Floor,
+        "-1.0808802290397619",
+        "-0x1.14b491129e678#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Ceiling,
+        "-1.0808802290397617",
+        "-0x1.14b491129e677#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Down,
+        "-1.0808802290397617",
+        "-0x1.14b491129e677#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Up,
+        "-1.0808802290397619",
+        "-0x1.14b491129e678#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Nearest,
+        "-1.0808802290397619",
+        "-0x1.14b491129e678#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Floor,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Ceiling,
+        "3.0e30",
+        "0x2.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Down,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Up,
+        "3.0e30",
+        "0x2.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Nearest,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        RoundingMode::Floor,
+        "5.0e8",
+        "0x2.0E+7#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        RoundingMode::Ceiling,
+        "1.0e9",
+        "0x4.0E+7#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        RoundingMode::Down,
+        "5.0e8",
+        "0x2.0E+7#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        RoundingMode::Up,
+        "1.0e9",
+        "0x4.0E+7#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        RoundingMode::Nearest,
+        "1.0e9",
+        "0x4.0E+7#1",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn add_rational_round_fail() {
+    assert_panic!(
+        Float::ONE.add_rational_round(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    );
+    assert_panic!(Float::ONE
+        .add_rational_round_val_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!(Float::ONE
+        .add_rational_round_ref_val(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!(Float::ONE
+        .add_rational_round_ref_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_rational_round_assign_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_add_rational_prec_round() {
+    let test = |s, s_hex, t, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (sum, o) = x.clone().add_rational_prec_round(y.clone(), prec, rm);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_rational_prec_round_val_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_rational_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let (sum_alt, o_alt) = x.add_rational_prec_round_ref_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_rational_prec_round_assign(y.clone(), prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_rational_prec_round_assign_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sum, rug_o) = rug_add_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sum)),
+                ComparableFloatRef(&sum)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "0.333",
+        "0x0.554#10",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        RoundingMode::Down,
+        "0.333",
+        "0x0.554#10",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        RoundingMode::Up,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        RoundingMode::Up,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        RoundingMode::Floor,
+        "-3.145",
+        "-0x3.25#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        RoundingMode::Ceiling,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        RoundingMode::Down,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        RoundingMode::Up,
+        "-3.145",
+        "-0x3.25#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        RoundingMode::Nearest,
+        "-3.145",
+        "-0x3.25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "123.2",
+        "0x7b.4#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "123.4",
+        "0x7b.6#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        RoundingMode::Down,
+        "123.2",
+        "0x7b.4#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        RoundingMode::Up,
+        "123.4",
+        "0x7b.6#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "123.4",
+        "0x7b.6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Floor,
+        "-0.002",
+        "-0x0.008#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Ceiling,
+        "-0.001",
+        "-0x0.004#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Down,
+        "-0.001",
+        "-0x0.004#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Up,
+        "-0.002",
+        "-0x0.008#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Nearest,
+        "-0.001",
+        "-0x0.004#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Floor,
+        "-0.001265",
+        "-0x0.0052e#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Ceiling,
+        "-0.001263",
+        "-0x0.0052c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Down,
+        "-0.001263",
+        "-0x0.0052c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Up,
+        "-0.001265",
+        "-0x0.0052e#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Nearest,
+        "-0.001265",
+        "-0x0.0052e#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "-1.082",
+        "-0x1.150#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "-1.08",
+        "-0x1.148#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Down,
+        "-1.08",
+        "-0x1.148#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Up,
+        "-1.082",
+        "-0x1.150#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "-1.08",
+        "-0x1.148#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "3.0e30",
+        "0x2.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Down,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Up,
+        "3.0e30",
+        "0x2.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "1.27e30",
+        "0x1.008E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Down,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Up,
+        "1.27e30",
+        "0x1.008E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        RoundingMode::Floor,
+        "5.0e8",
+        "0x2.0E+7#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e9",
+        "0x4.0E+7#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        RoundingMode::Down,
+        "5.0e8",
+        "0x2.0E+7#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        RoundingMode::Up,
+        "1.0e9",
+        "0x4.0E+7#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        RoundingMode::Nearest,
+        "1.0e9",
+        "0x4.0E+7#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        RoundingMode::Floor,
+        "9.99e8",
+        "0x3.b9E+7#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        RoundingMode::Ceiling,
+        "1.0e9",
+        "0x3.baE+7#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        RoundingMode::Down,
+        "9.99e8",
+        "0x3.b9E+7#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        RoundingMode::Up,
+        "1.0e9",
+        "0x3.baE+7#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        RoundingMode::Nearest,
+        "1.0e9",
+        "0x3.baE+7#10",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn add_rational_prec_round_fail() {
+    assert_panic!(Float::ONE.add_rational_prec_round(Rational::ONE, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.add_rational_prec_round_val_ref(
+        &Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::ONE.add_rational_prec_round_ref_val(
+        Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::ONE.add_rational_prec_round_ref_ref(
+        &Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_rational_prec_round_assign(Rational::ONE, 0, RoundingMode::Floor)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_rational_prec_round_assign_ref(&Rational::ONE, 0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::ONE.add_rational_prec_round(
+        Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.add_rational_prec_round_val_ref(
+        &Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.add_rational_prec_round_ref_val(
+        Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.add_rational_prec_round_ref_ref(
+        &Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_rational_prec_round_assign(Rational::from_unsigneds(1u8, 3), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_rational_prec_round_assign_ref(
+            &Rational::from_unsigneds(1u8, 3),
+            1,
+            RoundingMode::Exact,
+        )
+    });
+}
+
+#[test]
+fn add_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_1().test_properties(|(x, y, prec, rm)| {
+        let (sum, o) = x.clone().add_prec_round(y.clone(), prec, rm);
+        assert!(sum.is_valid());
+
+        let (sum_alt, o_alt) = x.clone().add_prec_round_val_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_prec_round_assign(y.clone(), prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_prec_round_assign_ref(&y, prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        if sum.is_finite() && x.is_finite() && y.is_finite() {
+            if sum.is_normal() {
+                assert_eq!(sum.get_prec(), Some(prec));
+            }
+            let r_sum = Rational::exact_from(&x) + Rational::exact_from(&y);
+            assert_eq!(sum.partial_cmp(&r_sum), Some(o));
+            if o == Ordering::Less {
+                let mut next = sum.clone();
+                next.increment();
+                assert!(next > r_sum);
+            } else if o == Ordering::Greater {
+                let mut next = sum.clone();
+                next.decrement();
+                assert!(next < r_sum);
+            }
+            match (r_sum >= 0u32, rm) {
+                (_, RoundingMode::Floor)
+                | (true, RoundingMode::Down)
+                | (false, RoundingMode::Up) => {
+                    assert_ne!(o, Ordering::Greater)
+                }
+                (_, RoundingMode::Ceiling)
+                | (true, RoundingMode::Up)
+                | (false, RoundingMode::Down) => {
+                    assert_ne!(o, Ordering::Less)
+                }
+                (_, RoundingMode::Exact) => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sum, rug_o) = rug_add_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sum)),
+                ComparableFloatRef(&sum)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (sum_alt, o_alt) = y.add_prec_round_ref_ref(&x, prec, rm);
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (mut sum_alt, mut o_alt) = (-&x).add_prec_round(-&y, prec, -rm);
+        sum_alt.neg_assign();
+        o_alt = o_alt.reverse();
+        if sum_alt.is_zero() && x.is_finite() && y.is_finite() {
+            // The sign of an exact zero sum depends on the rounding mode, so it is not symmetric
+            // under negation.
+            assert!(sum.is_zero());
+        } else {
+            assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+            assert_eq!(o_alt, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.add_prec_round_ref_ref(&y, prec, rm);
+                assert_eq!(
+                    ComparableFloat(s.abs_negative_zero()),
+                    ComparableFloat(sum.abs_negative_zero_ref())
+                );
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.add_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+        }
+    });
+
+    float_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (sum, o) = x.add_prec_round_ref_val(Float::NAN, prec, rm);
+        assert!(sum.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (sum, o) = Float::NAN.add_prec_round_val_ref(&x, prec, rm);
+        assert!(sum.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        if !x.is_nan() {
+            if x != Float::NEGATIVE_INFINITY {
+                assert_eq!(
+                    x.add_prec_round_ref_val(Float::INFINITY, prec, rm),
+                    (Float::INFINITY, Ordering::Equal)
+                );
+                assert_eq!(
+                    Float::INFINITY.add_prec_round_val_ref(&x, prec, rm),
+                    (Float::INFINITY, Ordering::Equal)
+                );
+            }
+            if x != Float::INFINITY {
+                assert_eq!(
+                    x.add_prec_round_ref_val(Float::NEGATIVE_INFINITY, prec, rm),
+                    (Float::NEGATIVE_INFINITY, Ordering::Equal)
+                );
+                assert_eq!(
+                    Float::NEGATIVE_INFINITY.add_prec_round_val_ref(&x, prec, rm),
+                    (Float::NEGATIVE_INFINITY, Ordering::Equal)
+                );
+            }
+        }
+        if !x.is_negative_zero() {
+            let (sum, o) = x.add_prec_round_ref_val(Float::ZERO, prec, rm);
+            let mut sum_alt = x.clone();
+            let o_alt = sum_alt.set_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(sum), ComparableFloat(sum_alt));
+            assert_eq!(o, o_alt);
+        }
+        if rm != RoundingMode::Floor || !x.is_positive_zero() {
+            let (sum, o) = x.add_prec_round_ref_val(Float::NEGATIVE_ZERO, prec, rm);
+            let mut sum_alt = x.clone();
+            let o_alt = sum_alt.set_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(sum), ComparableFloat(sum_alt));
+            assert_eq!(o, o_alt);
+        }
+    });
+}
+
+#[test]
+fn add_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (sum, o) = x.clone().add_prec(y.clone(), prec);
+        assert!(sum.is_valid());
+
+        let (sum_alt, o_alt) = x.clone().add_prec_val_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_prec_ref_val(y.clone(), prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_prec_ref_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        if sum.is_finite() && x.is_finite() && y.is_finite() {
+            if sum.is_normal() {
+                assert_eq!(sum.get_prec(), Some(prec));
+            }
+            let r_sum = Rational::exact_from(&x) + Rational::exact_from(&y);
+            assert_eq!(sum.partial_cmp(&r_sum), Some(o));
+            if o == Ordering::Less {
+                let mut next = sum.clone();
+                next.increment();
+                assert!(next > r_sum);
+            } else if o == Ordering::Greater {
+                let mut next = sum.clone();
+                next.decrement();
+                assert!(next < r_sum);
+            }
+        }
+
+        let (rug_sum, rug_o) = rug_add_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sum)),
+            ComparableFloatRef(&sum)
+        );
+        assert_eq!(rug_o, o);
+
+        let (sum_alt, o_alt) = y.add_prec_ref_ref(&x, prec);
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        if (x != 0u32 && y != 0u32) || (x.is_sign_positive() && y.is_sign_positive()) {
+            let (mut sum_alt, mut o_alt) = (-&x).add_prec(-&y, prec);
+            sum_alt.neg_assign();
+            o_alt = o_alt.reverse();
+            assert_eq!(
+                ComparableFloat(sum_alt.abs_negative_zero()),
+                ComparableFloat(sum.abs_negative_zero_ref())
+            );
+            assert_eq!(o_alt, o);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_round_properties_helper(x: Float, y: Float) {
+    for rm in exhaustive_rounding_modes() {
+        let prec = max(x.significant_bits(), y.significant_bits());
+        let (_, floor_o) = x.add_prec_round_ref_ref(&y, prec, RoundingMode::Floor);
+        if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+            assert_panic!(x.add_round_ref_ref(&y, rm));
+            continue;
+        }
+
+        let (sum, o) = x.clone().add_round(y.clone(), rm);
+        assert!(sum.is_valid());
+
+        let (sum_alt, o_alt) = x.clone().add_round_val_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_round_ref_val(y.clone(), rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_round_ref_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sum, rug_o) = rug_add_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sum)),
+                ComparableFloatRef(&sum)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (sum_alt, o_alt) = y.add_round_ref_ref(&x, rm);
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+    }
+}
+
+#[test]
+fn add_round_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        add_round_properties_helper(x, y);
+    });
+
+    float_pair_gen_var_1().test_properties(|(x, y)| {
+        add_round_properties_helper(x, y);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_properties_helper(x: Float, y: Float) {
+    let sum = x.clone() + y.clone();
+    assert!(sum.is_valid());
+
+    let sum_alt = x.clone() + &y;
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let sum_alt = &x + y.clone();
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let sum_alt = &x + &y;
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let mut x_alt = x.clone();
+    x_alt += y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+
+    let mut x_alt = x.clone();
+    x_alt += &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+
+    let (sum_alt, _) = x.add_round_ref_ref(&y, RoundingMode::Nearest);
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let (rug_sum, _) = rug_add_prec_round(
+        &rug::Float::exact_from(&x),
+        &rug::Float::exact_from(&y),
+        max(x.significant_bits(), y.significant_bits()),
+        rug::float::Round::Nearest,
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sum)),
+        ComparableFloatRef(&sum)
+    );
+
+    let sum_alt = &y + &x;
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    if (x != 0u32 && y != 0u32) || (x.is_sign_positive() && y.is_sign_positive()) {
+        assert_eq!(
+            ComparableFloat((-(-&x - &y)).abs_negative_zero()),
+            ComparableFloat(sum.abs_negative_zero_ref())
+        );
+    }
+}
+
+#[test]
+fn add_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        add_properties_helper(x, y);
+    });
+
+    float_pair_gen_var_1().test_properties(|(x, y)| {
+        add_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        assert!((&x + Float::NAN).is_nan());
+        assert!((Float::NAN + &x).is_nan());
+        if !x.is_nan() {
+            if x != Float::NEGATIVE_INFINITY {
+                assert_eq!(&x + Float::INFINITY, Float::INFINITY);
+                assert_eq!(Float::INFINITY + &x, Float::INFINITY);
+            }
+            if x != Float::INFINITY {
+                assert_eq!(&x + Float::NEGATIVE_INFINITY, Float::NEGATIVE_INFINITY);
+                assert_eq!(Float::NEGATIVE_INFINITY + &x, Float::NEGATIVE_INFINITY);
+            }
+        }
+        assert_eq!(
+            ComparableFloatRef(&(&x + Float::NEGATIVE_ZERO)),
+            ComparableFloatRef(&x)
+        );
+        if !x.is_negative_zero() {
+            assert_eq!(
+                ComparableFloatRef(&(&x + Float::ZERO)),
+                ComparableFloatRef(&x)
+            );
+        }
+        if x.is_finite() {
+            assert_eq!(&x + -&x, 0u32);
+            assert!((&x + -&x).is_sign_positive());
+        }
+    });
+
+    float_triple_gen().test_properties(|(x, y, z)| {
+        if x.is_finite() && y.is_finite() && z.is_finite() {
+            // Addition with enough precision is exact, and therefore associative.
+            let prec = 4096;
+            let (xy, o) = x.add_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+            let (yz, o_alt) = y.add_prec_round_ref_ref(&z, prec, RoundingMode::Nearest);
+            if o == Ordering::Equal && o_alt == Ordering::Equal {
+                let (xy_z, o) = xy.add_prec_round(z, prec, RoundingMode::Nearest);
+                let (x_yz, o_alt) = x.add_prec_round(yz, prec, RoundingMode::Nearest);
+                if o == Ordering::Equal && o_alt == Ordering::Equal {
+                    assert_eq!(xy_z, x_yz);
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn add_rational_prec_round_properties() {
+    float_rational_unsigned_rounding_mode_quadruple_gen_var_1().test_properties(
+        |(x, y, prec, rm)| {
+            let (sum, o) = x.clone().add_rational_prec_round(y.clone(), prec, rm);
+            assert!(sum.is_valid());
+
+            let (sum_alt, o_alt) = x.clone().add_rational_prec_round_val_ref(&y, prec, rm);
+            assert!(sum_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+            assert_eq!(o_alt, o);
+
+            let (sum_alt, o_alt) = x.add_rational_prec_round_ref_val(y.clone(), prec, rm);
+            assert!(sum_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+            assert_eq!(o_alt, o);
+
+            let (sum_alt, o_alt) = x.add_rational_prec_round_ref_ref(&y, prec, rm);
+            assert!(sum_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.add_rational_prec_round_assign(y.clone(), prec, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.add_rational_prec_round_assign_ref(&y, prec, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+            assert_eq!(o_alt, o);
+
+            if sum.is_finite() && x.is_finite() {
+                if sum.is_normal() {
+                    assert_eq!(sum.get_prec(), Some(prec));
+                }
+                let r_sum = Rational::exact_from(&x) + &y;
+                assert_eq!(sum.partial_cmp(&r_sum), Some(o));
+                if o == Ordering::Less {
+                    let mut next = sum.clone();
+                    next.increment();
+                    assert!(next > r_sum);
+                } else if o == Ordering::Greater {
+                    let mut next = sum.clone();
+                    next.decrement();
+                    assert!(next < r_sum);
+                }
+                match (r_sum >= 0u32, rm) {
+                    (_, RoundingMode::Floor)
+                    | (true, RoundingMode::Down)
+                    | (false, RoundingMode::Up) => {
+                        assert_ne!(o, Ordering::Greater)
+                    }
+                    (_, RoundingMode::Ceiling)
+                    | (true, RoundingMode::Up)
+                    | (false, RoundingMode::Down) => {
+                        assert_ne!(o, Ordering::Less)
+                    }
+                    (_, RoundingMode::Exact) => assert_eq!(o, Ordering::Equal),
+                    _ => {}
+                }
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_sum, rug_o) = rug_add_rational_prec_round(
+                    &rug::Float::exact_from(&x),
+                    &rug::Rational::exact_from(&y),
+                    prec,
+                    rug_rm,
+                );
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_sum)),
+                    ComparableFloatRef(&sum)
+                );
+                assert_eq!(rug_o, o);
+            }
+
+            if x.is_finite() && y != 0u32 {
+                let (sum_alt, o_alt) = x.add_prec_round_ref_val(
+                    Float::from_rational_prec_round_ref(&y, 4096, RoundingMode::Floor).0,
+                    prec,
+                    rm,
+                );
+                if Rational::exact_from(
+                    &Float::from_rational_prec_round_ref(&y, 4096, RoundingMode::Floor).0,
+                ) == y
+                {
+                    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+                    assert_eq!(o_alt, o);
+                }
+            }
+
+            if o == Ordering::Equal {
+                for rm in exhaustive_rounding_modes() {
+                    let (s, oo) = x.add_rational_prec_round_ref_ref(&y, prec, rm);
+                    assert_eq!(
+                        ComparableFloat(s.abs_negative_zero()),
+                        ComparableFloat(sum.abs_negative_zero_ref())
+                    );
+                    assert_eq!(oo, Ordering::Equal);
+                }
+            } else {
+                assert_panic!(x.add_rational_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+            }
+        },
+    );
+}
+
+#[test]
+fn add_rational_prec_properties() {
+    float_rational_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (sum, o) = x.clone().add_rational_prec(y.clone(), prec);
+        assert!(sum.is_valid());
+
+        let (sum_alt, o_alt) = x.clone().add_rational_prec_val_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_rational_prec_ref_val(y.clone(), prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_rational_prec_ref_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_rational_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_rational_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_rational_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        if sum.is_finite() && x.is_finite() {
+            let r_sum = Rational::exact_from(&x) + &y;
+            assert_eq!(sum.partial_cmp(&r_sum), Some(o));
+        }
+
+        let (rug_sum, rug_o) = rug_add_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sum)),
+            ComparableFloatRef(&sum)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_rational_round_properties_helper(x: Float, y: Rational) {
+    for rm in exhaustive_rounding_modes() {
+        let prec = x.significant_bits();
+        let (_, floor_o) = x.add_rational_prec_round_ref_ref(&y, prec, RoundingMode::Floor);
+        if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+            assert_panic!(x.add_rational_round_ref_ref(&y, rm));
+            continue;
+        }
+
+        let (sum, o) = x.clone().add_rational_round(y.clone(), rm);
+        assert!(sum.is_valid());
+
+        let (sum_alt, o_alt) = x.clone().add_rational_round_val_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_rational_round_ref_val(y.clone(), rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_rational_round_ref_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_rational_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_rational_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_rational_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sum, rug_o) = rug_add_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sum)),
+                ComparableFloatRef(&sum)
+            );
+            assert_eq!(rug_o, o);
+        }
+    }
+}
+
+#[test]
+fn add_rational_round_properties() {
+    float_rational_pair_gen().test_properties(|(x, y)| {
+        add_rational_round_properties_helper(x, y);
+    });
+
+    float_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        add_rational_round_properties_helper(x, y);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_rational_properties_helper(x: Float, y: Rational) {
+    let sum = x.clone() + y.clone();
+    assert!(sum.is_valid());
+
+    let sum_alt = x.clone() + &y;
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let sum_alt = &x + y.clone();
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let sum_alt = &x + &y;
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let sum_alt = y.clone() + x.clone();
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let sum_alt = y.clone() + &x;
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let sum_alt = &y + x.clone();
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let sum_alt = &y + &x;
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let mut x_alt = x.clone();
+    x_alt += y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+
+    let mut x_alt = x.clone();
+    x_alt += &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+
+    let (sum_alt, _) = x.add_rational_round_ref_ref(&y, RoundingMode::Nearest);
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let (rug_sum, _) = rug_add_rational_prec_round(
+        &rug::Float::exact_from(&x),
+        &rug::Rational::exact_from(&y),
+        x.significant_bits(),
+        rug::float::Round::Nearest,
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sum)),
+        ComparableFloatRef(&sum)
+    );
+}
+
+#[test]
+fn add_rational_properties() {
+    float_rational_pair_gen().test_properties(|(x, y)| {
+        add_rational_properties_helper(x, y);
+    });
+
+    float_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        add_rational_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        assert_eq!(
+            ComparableFloatRef(&(&x + Rational::ZERO)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(
+            ComparableFloatRef(&(Rational::ZERO + &x)),
+            ComparableFloatRef(&x)
+        );
+        if x.is_finite() {
+            assert_eq!(&x + -Rational::exact_from(&x), 0u32);
+        }
+    });
+}
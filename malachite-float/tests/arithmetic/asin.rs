@@ -0,0 +1,2328 @@
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_16,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_asin_prec_round(x: &rug::Float, prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.asin_ref(), rm)
+}
+
+#[test]
+fn test_asin_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_prec(prec);
+        assert!(asin.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+
+        let (asin_alt, o_alt) = x.asin_prec_ref(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_prec_assign(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_asin, rug_o) = rug_asin_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("1.0", "0x1.0#1", 10, "1.57", "0x1.920#10", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.57079632679489661923132169164",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test("0.5", "0x0.8#1", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("0.5", "0x0.8#1", 10, "0.523", "0x0.860#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.523598775598298873077107230547",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Ordering::Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "NaN", "NaN", Ordering::Equal);
+    test("123.0", "0x7b.0#7", 10, "NaN", "NaN", Ordering::Equal);
+    test("123.0", "0x7b.0#7", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "0.3398",
+        "0x0.570#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "0.3398369094541219174702367798444",
+        "0x0.56ff8d3c1444474ecb08d25440#100",
+        Ordering::Less,
+    );
+    test("1.0e30", "0x1.0E+25#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("1.0e30", "0x1.0E+25#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("1.0e30", "0x1.0E+25#1", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "0.3398",
+        "0x0.570#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "0.3398369094541219370963925133918",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test("1.111", "0x1.1c8#10", 1, "NaN", "NaN", Ordering::Equal);
+    test("1.111", "0x1.1c8#10", 10, "NaN", "NaN", Ordering::Equal);
+    test("1.111", "0x1.1c8#10", 100, "NaN", "NaN", Ordering::Equal);
+    test("-0.5", "-0x0.8#1", 1, "-0.5", "-0x0.8#1", Ordering::Greater);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        "-0.523",
+        "-0x0.860#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        "-0.523598775598298873077107230547",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Ordering::Less,
+    );
+    test("0.9", "0x0.e#3", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test(
+        "0.9",
+        "0x0.e#3",
+        10,
+        "1.066",
+        "0x1.110#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        "1.065435816510739312260006817652",
+        "0x1.10c066d3e6931b76a13df3a68#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn asin_prec_fail() {
+    assert_panic!(Float::NAN.asin_prec(0));
+    assert_panic!(Float::NAN.asin_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.asin_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_asin_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_round(rm);
+        assert!(asin.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+
+        let (asin_alt, o_alt) = x.asin_round_ref(rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_round_assign(rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) =
+                rug_asin_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "0.33983690945412187",
+        "0x0.56ff8d3c144444#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "0.33983690945412193",
+        "0x0.56ff8d3c144448#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "0.33983690945412187",
+        "0x0.56ff8d3c144444#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "0.33983690945412193",
+        "0x0.56ff8d3c144448#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "0.33983690945412193",
+        "0x0.56ff8d3c144448#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "0.339836909454121937096392513391764066388244690332458071431924",
+        "0x0.56ff8d3c144448b8d4ef3a47ffba2051f398427dffe7c5acde8#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "0.3398369094541219370963925133917640663882446903324580714319243",
+        "0x0.56ff8d3c144448b8d4ef3a47ffba2051f398427dffe7c5acdf0#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "0.339836909454121937096392513391764066388244690332458071431924",
+        "0x0.56ff8d3c144448b8d4ef3a47ffba2051f398427dffe7c5acde8#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "0.3398369094541219370963925133917640663882446903324580714319243",
+        "0x0.56ff8d3c144448b8d4ef3a47ffba2051f398427dffe7c5acdf0#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "0.339836909454121937096392513391764066388244690332458071431924",
+        "0x0.56ff8d3c144448b8d4ef3a47ffba2051f398427dffe7c5acde8#200",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Ceiling,
+        "1.2",
+        "0x1.4#3",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Up,
+        "1.2",
+        "0x1.4#3",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn asin_round_fail() {
+    assert_panic!(Float::from(0.5f32).asin_round(RoundingMode::Exact));
+    assert_panic!(Float::from(0.5f32).asin_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(0.5f32);
+        x.asin_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_asin_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_prec_round(prec, rm);
+        assert!(asin.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+
+        let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_prec_round_assign(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.570796326794896619231321691641",
+        "0x1.921fb54442d18469898cc5172#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-1.572",
+        "-0x1.928#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.57",
+        "-0x1.920#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.57",
+        "-0x1.920#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "-1.570796326794896619231321691641",
+        "-0x1.921fb54442d18469898cc5172#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "-1.57079632679489661923132169164",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "-1.57079632679489661923132169164",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "0.3394",
+        "0x0.56e#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "0.3398",
+        "0x0.570#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "0.3398",
+        "0x0.570#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "0.3398369094541219370963925133914",
+        "0x0.56ff8d3c144448b8d4ef3a47f8#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "0.3398369094541219370963925133918",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "0.3398369094541219370963925133918",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        1,
+        RoundingMode::Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "-0.524",
+        "-0x0.864#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.523",
+        "-0x0.860#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.523",
+        "-0x0.860#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        RoundingMode::Floor,
+        "-0.523598775598298873077107230547",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        RoundingMode::Ceiling,
+        "-0.523598775598298873077107230546",
+        "-0x0.860a91c16b9b2c232dd99707a#100",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        RoundingMode::Nearest,
+        "-0.523598775598298873077107230547",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        10,
+        RoundingMode::Floor,
+        "1.064",
+        "0x1.108#10",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        10,
+        RoundingMode::Ceiling,
+        "1.066",
+        "0x1.110#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        10,
+        RoundingMode::Nearest,
+        "1.066",
+        "0x1.110#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        RoundingMode::Floor,
+        "1.065435816510739312260006817652",
+        "0x1.10c066d3e6931b76a13df3a68#100",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        RoundingMode::Ceiling,
+        "1.065435816510739312260006817653",
+        "0x1.10c066d3e6931b76a13df3a6a#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        RoundingMode::Nearest,
+        "1.065435816510739312260006817652",
+        "0x1.10c066d3e6931b76a13df3a68#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn asin_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).asin_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).asin_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.asin_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(0.5f32).asin_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(0.5f32).asin_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(0.5f32);
+        x.asin_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn asin_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_16().test_properties(|(x, prec, rm)| {
+        let (asin, o) = x.clone().asin_prec_round(prec, rm);
+        assert!(asin.is_valid());
+
+        let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.asin_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && asin.is_normal() {
+            assert_eq!(asin.get_prec(), Some(prec));
+            // $\arcsin x$ has the same sign as $x$.
+            assert!((x > 0u32) == (asin > 0u32));
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if asin > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if asin > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.asin_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.asin_prec_round_ref(prec, RoundingMode::Ceiling);
+                if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.asin_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(asin.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.asin_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn asin_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (asin, o) = x.clone().asin_prec(prec);
+        assert!(asin.is_valid());
+
+        let (asin_alt, o_alt) = x.asin_prec_ref(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.asin_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        let (rug_asin, rug_o) = rug_asin_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn asin_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.asin_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.asin_round_ref(rm));
+                continue;
+            }
+
+            let (asin, o) = x.clone().asin_round(rm);
+            assert!(asin.is_valid());
+
+            let (asin_alt, o_alt) = x.asin_round_ref(rm);
+            assert!(asin_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.asin_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+            assert_eq!(o_alt, o);
+
+            let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_asin, rug_o) =
+                    rug_asin_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_asin)),
+                    ComparableFloatRef(&asin)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (asin, o) = x.asin_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::NAN);
+    test_special(Float::NEGATIVE_INFINITY, Float::NAN);
+    test_special(Float::ZERO, Float::ZERO);
+    test_special(Float::NEGATIVE_ZERO, Float::NEGATIVE_ZERO);
+}
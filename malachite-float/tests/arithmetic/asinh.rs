@@ -0,0 +1,2163 @@
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_22,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_asinh_prec_round(
+    x: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.asinh_ref(), rm)
+}
+
+#[test]
+fn test_asinh_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asinh, o) = x.clone().asinh_prec(prec);
+        assert!(asinh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+
+        let (asinh_alt, o_alt) = x.asinh_prec_ref(prec);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asinh_alt = x.clone();
+        let o_alt = asinh_alt.asinh_prec_assign(prec);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_asinh, rug_o) = rug_asinh_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh)),
+            ComparableFloatRef(&asinh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Ordering::Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.8813735870195430252326093249797",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Ordering::Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-0.882",
+        "-0x0.e1c#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.8813735870195430252326093249797",
+        "-0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Ordering::Greater,
+    );
+    test("0.5", "0x0.8#1", 1, "0.5", "0x0.8#1", Ordering::Greater);
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        "0.4814",
+        "0x0.7b4#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.4812118250596034474977589134243",
+        "0x0.7b30b2bb14582652f810812a58#100",
+        Ordering::Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "4.0", "0x4.0#1", Ordering::Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "5.51",
+        "0x5.82#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "5.505348060078275900876077655164",
+        "0x5.815e7d8f221be5f7372cc2c08#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.861",
+        "0x1.dc8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.862295743310848182743051714127",
+        "0x1.dcbf69f10006cbe9c11ca9a5e#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.725",
+        "0x1.b98#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.725382558852315044033016964024",
+        "0x1.b9b2abdf5c076eabb258a2374#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "-1.146",
+        "-0x1.258#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "-1.146215834780588899714537624245",
+        "-0x1.256e66a48a3b62d71cb2341f6#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "0.3276",
+        "0x0.53e#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "0.3274501502372584257683679171047",
+        "0x0.53d3c5e656dd1fa8927e604358#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "70.0",
+        "0x46.0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "70.0078652365544762511404442673",
+        "0x46.020374c5c6db00c6a6d5daf8#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "0.3276",
+        "0x0.53e#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "0.3274501502372584433225352599881",
+        "0x0.53d3c5e656dd20ec63b484c7e8#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "-1.863",
+        "-0x1.dd0#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "-1.86267921113460998501900148295",
+        "-0x1.dcd88b76c3216f8eaa82640e6#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "0.958",
+        "0x0.f54#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "0.9579456160062332469952961633015",
+        "0x0.f53bec8417e584cbf18039e4d#100",
+        Ordering::Less,
+    );
+    test("2.0", "0x2.0#1", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test("2.0", "0x2.0#1", 10, "1.443", "0x1.718#10", Ordering::Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "1.443635475178810342493276740272",
+        "0x1.719218313d0872f8e831837f0#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn asinh_prec_fail() {
+    assert_panic!(Float::NAN.asinh_prec(0));
+    assert_panic!(Float::NAN.asinh_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.asinh_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_asinh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asinh, o) = x.clone().asinh_round(rm);
+        assert!(asinh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+
+        let (asinh_alt, o_alt) = x.asinh_round_ref(rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asinh_alt = x.clone();
+        let o_alt = asinh_alt.asinh_round_assign(rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asinh, rug_o) =
+                rug_asinh_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asinh)),
+                ComparableFloatRef(&asinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "5.5",
+        "0x5.8#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "5.56",
+        "0x5.9#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "5.5",
+        "0x5.8#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "5.56",
+        "0x5.9#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "5.5",
+        "0x5.8#7",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.862295743310848",
+        "0x1.dcbf69f10006c#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.8622957433108482",
+        "0x1.dcbf69f10006d#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.862295743310848",
+        "0x1.dcbf69f10006c#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.8622957433108482",
+        "0x1.dcbf69f10006d#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.8622957433108482",
+        "0x1.dcbf69f10006d#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "1.7253825588523148",
+        "0x1.b9b2abdf5c076#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "1.7253825588523151",
+        "0x1.b9b2abdf5c077#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "1.7253825588523148",
+        "0x1.b9b2abdf5c076#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "1.7253825588523151",
+        "0x1.b9b2abdf5c077#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "1.7253825588523151",
+        "0x1.b9b2abdf5c077#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "-1.1462158347805891",
+        "-0x1.256e66a48a3b7#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "-1.1462158347805889",
+        "-0x1.256e66a48a3b6#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "-1.1462158347805889",
+        "-0x1.256e66a48a3b6#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "-1.1462158347805891",
+        "-0x1.256e66a48a3b7#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "-1.1462158347805889",
+        "-0x1.256e66a48a3b6#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "0.32745015023725837",
+        "0x0.53d3c5e656dd1c#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "0.32745015023725843",
+        "0x0.53d3c5e656dd20#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "0.32745015023725837",
+        "0x0.53d3c5e656dd1c#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "0.32745015023725843",
+        "0x0.53d3c5e656dd20#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "0.32745015023725843",
+        "0x0.53d3c5e656dd20#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "0.3274501502372584433225352599882581277005245289907674512756294",
+        "0x0.53d3c5e656dd20ec63b484c7eaa4511e81f1357611d0b13b838#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "0.3274501502372584433225352599882581277005245289907674512756297",
+        "0x0.53d3c5e656dd20ec63b484c7eaa4511e81f1357611d0b13b840#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "0.3274501502372584433225352599882581277005245289907674512756294",
+        "0x0.53d3c5e656dd20ec63b484c7eaa4511e81f1357611d0b13b838#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "0.3274501502372584433225352599882581277005245289907674512756297",
+        "0x0.53d3c5e656dd20ec63b484c7eaa4511e81f1357611d0b13b840#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "0.3274501502372584433225352599882581277005245289907674512756297",
+        "0x0.53d3c5e656dd20ec63b484c7eaa4511e81f1357611d0b13b840#200",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "-1.862679211134609985019001482949627540444162659",
+        "-0x1.dcd88b76c3216f8eaa82640e54a6839dc532e0#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "-1.862679211134609985019001482949627540444162658",
+        "-0x1.dcd88b76c3216f8eaa82640e54a6839dc532d8#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "-1.862679211134609985019001482949627540444162658",
+        "-0x1.dcd88b76c3216f8eaa82640e54a6839dc532d8#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "-1.862679211134609985019001482949627540444162659",
+        "-0x1.dcd88b76c3216f8eaa82640e54a6839dc532e0#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "-1.862679211134609985019001482949627540444162658",
+        "-0x1.dcd88b76c3216f8eaa82640e54a6839dc532d8#150",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "0.957",
+        "0x0.f50#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "0.958",
+        "0x0.f54#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "0.957",
+        "0x0.f50#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "0.958",
+        "0x0.f54#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "0.958",
+        "0x0.f54#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn asinh_round_fail() {
+    assert_panic!(Float::from(3u32).asinh_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).asinh_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.asinh_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_asinh_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asinh, o) = x.clone().asinh_prec_round(prec, rm);
+        assert!(asinh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+
+        let (asinh_alt, o_alt) = x.asinh_prec_round_ref(prec, rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asinh_alt = x.clone();
+        let o_alt = asinh_alt.asinh_prec_round_assign(prec, rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asinh, rug_o) = rug_asinh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asinh)),
+                ComparableFloatRef(&asinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.881",
+        "0x0.e18#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.8813735870195430252326093249797",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.8813735870195430252326093249805",
+        "0x0.e1a1b30bcea13660d8f99e8de#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.8813735870195430252326093249797",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.882",
+        "-0x0.e1c#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.881",
+        "-0x0.e18#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.882",
+        "-0x0.e1c#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "-0.8813735870195430252326093249805",
+        "-0x0.e1a1b30bcea13660d8f99e8de#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "-0.8813735870195430252326093249797",
+        "-0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "-0.8813735870195430252326093249797",
+        "-0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.861",
+        "0x1.dc8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.863",
+        "0x1.dd0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.861",
+        "0x1.dc8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "1.862295743310848182743051714125",
+        "0x1.dcbf69f10006cbe9c11ca9a5c#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "1.862295743310848182743051714127",
+        "0x1.dcbf69f10006cbe9c11ca9a5e#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "1.862295743310848182743051714127",
+        "0x1.dcbf69f10006cbe9c11ca9a5e#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "-1.146",
+        "-0x1.258#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "-1.145",
+        "-0x1.250#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "-1.146",
+        "-0x1.258#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "-1.146215834780588899714537624247",
+        "-0x1.256e66a48a3b62d71cb2341f8#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "-1.146215834780588899714537624245",
+        "-0x1.256e66a48a3b62d71cb2341f6#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "-1.146215834780588899714537624245",
+        "-0x1.256e66a48a3b62d71cb2341f6#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "0.3271",
+        "0x0.53c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "0.3276",
+        "0x0.53e#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "0.3276",
+        "0x0.53e#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "0.3274501502372584433225352599881",
+        "0x0.53d3c5e656dd20ec63b484c7e8#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "0.3274501502372584433225352599885",
+        "0x0.53d3c5e656dd20ec63b484c7f0#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "0.3274501502372584433225352599881",
+        "0x0.53d3c5e656dd20ec63b484c7e8#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "0.957",
+        "0x0.f50#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.958",
+        "0x0.f54#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "0.958",
+        "0x0.f54#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "0.9579456160062332469952961633015",
+        "0x0.f53bec8417e584cbf18039e4d#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "0.957945616006233246995296163302",
+        "0x0.f53bec8417e584cbf18039e4e#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "0.9579456160062332469952961633015",
+        "0x0.f53bec8417e584cbf18039e4d#100",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.443",
+        "0x1.718#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.445",
+        "0x1.720#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.443",
+        "0x1.718#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.443635475178810342493276740272",
+        "0x1.719218313d0872f8e831837f0#100",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.443635475178810342493276740274",
+        "0x1.719218313d0872f8e831837f2#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.443635475178810342493276740272",
+        "0x1.719218313d0872f8e831837f0#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn asinh_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).asinh_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).asinh_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.asinh_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).asinh_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).asinh_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.asinh_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn asinh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_22().test_properties(|(x, prec, rm)| {
+        let (asinh, o) = x.clone().asinh_prec_round(prec, rm);
+        assert!(asinh.is_valid());
+
+        let (asinh_alt, o_alt) = x.asinh_prec_round_ref(prec, rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.asinh_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && asinh.is_normal() {
+            assert_eq!(asinh.get_prec(), Some(prec));
+            // $\operatorname{arsinh} x$ has the same sign as $x$.
+            assert!((x > 0u32) == (asinh > 0u32));
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if asinh > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if asinh > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.asinh_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.asinh_prec_round_ref(prec, RoundingMode::Ceiling);
+                if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asinh, rug_o) =
+                rug_asinh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asinh)),
+                ComparableFloatRef(&asinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.asinh_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(asinh.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.asinh_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn asinh_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (asinh, o) = x.clone().asinh_prec(prec);
+        assert!(asinh.is_valid());
+
+        let (asinh_alt, o_alt) = x.asinh_prec_ref(prec);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.asinh_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        let (asinh_alt, o_alt) = x.asinh_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        let (rug_asinh, rug_o) = rug_asinh_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh)),
+            ComparableFloatRef(&asinh)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn asinh_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.asinh_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.asinh_round_ref(rm));
+                continue;
+            }
+
+            let (asinh, o) = x.clone().asinh_round(rm);
+            assert!(asinh.is_valid());
+
+            let (asinh_alt, o_alt) = x.asinh_round_ref(rm);
+            assert!(asinh_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.asinh_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asinh));
+            assert_eq!(o_alt, o);
+
+            let (asinh_alt, o_alt) = x.asinh_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_asinh, rug_o) =
+                    rug_asinh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_asinh)),
+                    ComparableFloatRef(&asinh)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (asinh, o) = x.asinh_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(asinh), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::INFINITY);
+    test_special(Float::NEGATIVE_INFINITY, Float::NEGATIVE_INFINITY);
+    test_special(Float::ZERO, Float::ZERO);
+    test_special(Float::NEGATIVE_ZERO, Float::NEGATIVE_ZERO);
+}
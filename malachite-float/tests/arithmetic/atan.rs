@@ -0,0 +1,2141 @@
+use malachite_base::num::basic::traits::{NaN, NegativeZero, Zero};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_18,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_atan_prec_round(x: &rug::Float, prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.atan_ref(), rm)
+}
+
+#[test]
+fn test_atan_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_prec(prec);
+        assert!(atan.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+
+        let (atan_alt, o_alt) = x.atan_prec_ref(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_prec_assign(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_atan, rug_o) = rug_atan_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-1.57079632679489661923132169164",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Ordering::Greater);
+    test("1.0", "0x1.0#1", 10, "0.785", "0x0.c90#10", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-0.785",
+        "-0x0.c90#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.78539816339744830961566084582",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Greater,
+    );
+    test("0.5", "0x0.8#1", 1, "0.5", "0x0.8#1", Ordering::Greater);
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        "0.4639",
+        "0x0.76c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.4636476090008061162142562314613",
+        "0x0.76b19c1586ed3da2b7f222f660#100",
+        Ordering::Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.562",
+        "0x1.900#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.562666424614952707629342812471",
+        "0x1.900ae8244749e4ad7dc9fbdac#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.262",
+        "0x1.430#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.262627255678911672177612146816",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.219",
+        "0x1.380#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.218282905017277604527928398283",
+        "0x1.37e1637253388ca9ebdfe2c3e#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "-0.955",
+        "-0x0.f48#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "-0.9553166181245093103881681473584",
+        "-0x0.f48fa1402b8ae8e39e3fa3e28#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "0.3218",
+        "0x0.526#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "0.3217505543966421767480592449813",
+        "0x0.525e3e8c9a7b835ed9a10c61f0#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "0.3218",
+        "0x0.526#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "0.3217505543966421934014046143585",
+        "0x0.525e3e8c9a7b84920cd43f9520#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "-1.264",
+        "-0x1.438#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "-1.262743545771120214302132054235",
+        "-0x1.4343293852713db73e6a80f00#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "0.838",
+        "0x0.d68#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "0.838078331242305039545242997953",
+        "0x0.d68c4d302c0541795b576c3d4#100",
+        Ordering::Greater,
+    );
+    test("2.0", "0x2.0#1", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        "1.107",
+        "0x1.1b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "1.107148717794090503017065460178",
+        "0x1.1b6e192ebbe446c6d19aa220a#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn atan_prec_fail() {
+    assert_panic!(Float::NAN.atan_prec(0));
+    assert_panic!(Float::NAN.atan_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.atan_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_atan_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_round(rm);
+        assert!(atan.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+
+        let (atan_alt, o_alt) = x.atan_round_ref(rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_round_assign(rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) =
+                rug_atan_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "1.56",
+        "0x1.90#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "1.58",
+        "0x1.94#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "1.56",
+        "0x1.90#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "1.58",
+        "0x1.94#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "1.56",
+        "0x1.90#7",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.2626272556789115",
+        "0x1.433b8a322ddd2#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.2626272556789115",
+        "0x1.433b8a322ddd2#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "1.2182829050172774",
+        "0x1.37e1637253388#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "1.2182829050172774",
+        "0x1.37e1637253388#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "-0.9553166181245094",
+        "-0x0.f48fa1402b8af0#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "-0.9553166181245093",
+        "-0x0.f48fa1402b8ae8#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "-0.9553166181245093",
+        "-0x0.f48fa1402b8ae8#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "-0.9553166181245094",
+        "-0x0.f48fa1402b8af0#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "-0.9553166181245093",
+        "-0x0.f48fa1402b8ae8#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "0.32175055439664213",
+        "0x0.525e3e8c9a7b80#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "0.32175055439664219",
+        "0x0.525e3e8c9a7b84#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "0.32175055439664213",
+        "0x0.525e3e8c9a7b80#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "0.32175055439664219",
+        "0x0.525e3e8c9a7b84#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "0.32175055439664219",
+        "0x0.525e3e8c9a7b84#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "0.321750554396642193401404614358661319020755295557656191432803",
+        "0x0.525e3e8c9a7b84920cd43f9522bed64f71f84344f7595cf3908#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "0.3217505543966421934014046143586613190207552955576561914328033",
+        "0x0.525e3e8c9a7b84920cd43f9522bed64f71f84344f7595cf3910#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "0.321750554396642193401404614358661319020755295557656191432803",
+        "0x0.525e3e8c9a7b84920cd43f9522bed64f71f84344f7595cf3908#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "0.3217505543966421934014046143586613190207552955576561914328033",
+        "0x0.525e3e8c9a7b84920cd43f9522bed64f71f84344f7595cf3910#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "0.3217505543966421934014046143586613190207552955576561914328033",
+        "0x0.525e3e8c9a7b84920cd43f9522bed64f71f84344f7595cf3910#200",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "-1.262743545771120214302132054234899042675713502",
+        "-0x1.4343293852713db73e6a80effa406810286528#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "-1.2627435457711202143021320542348990426757135",
+        "-0x1.4343293852713db73e6a80effa406810286520#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "-1.2627435457711202143021320542348990426757135",
+        "-0x1.4343293852713db73e6a80effa406810286520#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "-1.262743545771120214302132054234899042675713502",
+        "-0x1.4343293852713db73e6a80effa406810286528#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "-1.2627435457711202143021320542348990426757135",
+        "-0x1.4343293852713db73e6a80effa406810286520#150",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "0.838",
+        "0x0.d68#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "0.839",
+        "0x0.d6c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "0.838",
+        "0x0.d68#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "0.839",
+        "0x0.d6c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "0.838",
+        "0x0.d68#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn atan_round_fail() {
+    assert_panic!(Float::from(3u32).atan_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).atan_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.atan_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_atan_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_prec_round(prec, rm);
+        assert!(atan.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+
+        let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_prec_round_assign(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.7853981633974483096156608458206",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.786",
+        "-0x0.c94#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.785",
+        "-0x0.c90#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.785",
+        "-0x0.c90#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "-0.7853981633974483096156608458206",
+        "-0x0.c90fdaa22168c234c4c6628b9#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "-0.78539816339744830961566084582",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "-0.78539816339744830961566084582",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.262",
+        "0x1.430#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.264",
+        "0x1.438#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.262",
+        "0x1.430#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "1.262627255678911672177612146814",
+        "0x1.433b8a322ddd29618168a21c8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "1.262627255678911672177612146816",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "1.262627255678911672177612146816",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "-0.956",
+        "-0x0.f4c#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.955",
+        "-0x0.f48#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.955",
+        "-0x0.f48#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "-0.955316618124509310388168147359",
+        "-0x0.f48fa1402b8ae8e39e3fa3e29#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "-0.9553166181245093103881681473584",
+        "-0x0.f48fa1402b8ae8e39e3fa3e28#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "-0.9553166181245093103881681473584",
+        "-0x0.f48fa1402b8ae8e39e3fa3e28#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "0.3213",
+        "0x0.524#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "0.3218",
+        "0x0.526#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "0.3218",
+        "0x0.526#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "0.3217505543966421934014046143585",
+        "0x0.525e3e8c9a7b84920cd43f9520#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "0.3217505543966421934014046143589",
+        "0x0.525e3e8c9a7b84920cd43f9528#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "0.3217505543966421934014046143585",
+        "0x0.525e3e8c9a7b84920cd43f9520#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "0.838",
+        "0x0.d68#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.839",
+        "0x0.d6c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "0.838",
+        "0x0.d68#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "0.8380783312423050395452429979523",
+        "0x0.d68c4d302c0541795b576c3d3#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "0.838078331242305039545242997953",
+        "0x0.d68c4d302c0541795b576c3d4#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "0.838078331242305039545242997953",
+        "0x0.d68c4d302c0541795b576c3d4#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.105",
+        "0x1.1b0#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.107",
+        "0x1.1b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.107",
+        "0x1.1b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.107148717794090503017065460178",
+        "0x1.1b6e192ebbe446c6d19aa220a#100",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.10714871779409050301706546018",
+        "0x1.1b6e192ebbe446c6d19aa220c#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.107148717794090503017065460178",
+        "0x1.1b6e192ebbe446c6d19aa220a#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn atan_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).atan_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).atan_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.atan_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).atan_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).atan_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.atan_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn atan_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_18().test_properties(|(x, prec, rm)| {
+        let (atan, o) = x.clone().atan_prec_round(prec, rm);
+        assert!(atan.is_valid());
+
+        let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atan_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && atan.is_normal() {
+            assert_eq!(atan.get_prec(), Some(prec));
+            // $\arctan x$ has the same sign as $x$, and is between $-\pi/2$ and $\pi/2$.
+            assert!((x > 0u32) == (atan > 0u32) && atan.le_abs(&2u32));
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if atan > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if atan > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.atan_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.atan_prec_round_ref(prec, RoundingMode::Ceiling);
+                if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.atan_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(atan.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.atan_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn atan_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (atan, o) = x.clone().atan_prec(prec);
+        assert!(atan.is_valid());
+
+        let (atan_alt, o_alt) = x.atan_prec_ref(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atan_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        let (rug_atan, rug_o) = rug_atan_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn atan_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.atan_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.atan_round_ref(rm));
+                continue;
+            }
+
+            let (atan, o) = x.clone().atan_round(rm);
+            assert!(atan.is_valid());
+
+            let (atan_alt, o_alt) = x.atan_round_ref(rm);
+            assert!(atan_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.atan_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+            assert_eq!(o_alt, o);
+
+            let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_atan, rug_o) =
+                    rug_atan_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_atan)),
+                    ComparableFloatRef(&atan)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (atan, o) = x.atan_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::ZERO, Float::ZERO);
+    test_special(Float::NEGATIVE_ZERO, Float::NEGATIVE_ZERO);
+}
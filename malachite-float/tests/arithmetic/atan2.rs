@@ -0,0 +1,5026 @@
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeOne, NegativeZero, One, Zero};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_unsigned_rounding_mode_quadruple_gen_var_5, float_float_unsigned_triple_gen_var_1,
+    float_pair_gen, float_unsigned_pair_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+
+fn rug_atan2_prec_round(
+    y: &rug::Float,
+    x: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), y.atan2_ref(x), rm)
+}
+
+#[test]
+fn test_atan2_prec() {
+    let test = |s, s_hex, t, t_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (atan2, o) = x.clone().atan2_prec(y.clone(), prec);
+        assert!(atan2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+
+        let (atan2_alt, o_alt) = x.clone().atan2_prec_val_ref(&y, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+        let (atan2_alt, o_alt) = x.atan2_prec_ref_val(y.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+        let (atan2_alt, o_alt) = x.atan2_prec_ref_ref(&y, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_prec_assign(y.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_prec_assign_ref(&y, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_atan2, rug_o) = rug_atan2_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-3.141592653589793238462643383279",
+        "-0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-3.141592653589793238462643383279",
+        "-0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "2.355",
+        "0x2.5b#10",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "2.356194490192344928846982537459",
+        "0x2.5b2f8fe6643a469e4e5327a28#100",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        "-0.785",
+        "-0x0.c90#10",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        "-0.78539816339744830961566084582",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-2.355",
+        "-0x2.5b#10",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-2.356194490192344928846982537459",
+        "-0x2.5b2f8fe6643a469e4e5327a28#100",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "-1.57079632679489661923132169164",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-1.57079632679489661923132169164",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-3.141592653589793238462643383279",
+        "-0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "2.355",
+        "0x2.5b#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "2.356194490192344928846982537459",
+        "0x2.5b2f8fe6643a469e4e5327a28#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-2.355",
+        "-0x2.5b#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-2.356194490192344928846982537459",
+        "-0x2.5b2f8fe6643a469e4e5327a28#100",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.562",
+        "0x1.900#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.562666424614952707629342812471",
+        "0x1.900ae8244749e4ad7dc9fbdac#100",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        "1.566",
+        "0x1.910#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.566731308535264715207796998011",
+        "0x1.91154d93c73b3c548df0b0fa8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.857",
+        "0x0.db8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.8575117863558462720334763011",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "1.994",
+        "0x1.fe8#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "1.99378176953278961895130326068",
+        "0x1.fe687b67811c776711b7e65b2#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "2.631e-31",
+        "0x5.56E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "2.629536350736705872070505183774e-31",
+        "0x5.5555555555554000000000000E-26#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "2.511e-31",
+        "0x5.18E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "2.511022249557423578941186476943e-31",
+        "0x5.17cc1b727220b79e27534dae8E-26#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "3.035",
+        "0x3.09#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "3.035927071489937468363907518402",
+        "0x3.0932843d172038d950fd89b0c#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "-1.23",
+        "-0x1.3b0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "-1.230914073342580026001184414948",
+        "-0x1.3b1d2f4931496c9f101a85584#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn atan2_prec_fail() {
+    assert_panic!(Float::NAN.atan2_prec(Float::NAN, 0));
+    assert_panic!(Float::NAN.atan2_prec_val_ref(&Float::NAN, 0));
+    assert_panic!(Float::NAN.atan2_prec_ref_val(Float::NAN, 0));
+    assert_panic!(Float::NAN.atan2_prec_ref_ref(&Float::NAN, 0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.atan2_prec_assign(Float::NAN, 0)
+    });
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.atan2_prec_assign_ref(&Float::NAN, 0)
+    });
+}
+
+#[test]
+fn test_atan2_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (atan2, o) = x.clone().atan2_round(y.clone(), rm);
+        assert!(atan2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+
+        let (atan2_alt, o_alt) = x.clone().atan2_round_val_ref(&y, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+        let (atan2_alt, o_alt) = x.atan2_round_ref_val(y.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+        let (atan2_alt, o_alt) = x.atan2_round_ref_ref(&y, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_round_assign(y.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_round_assign_ref(&y, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) = rug_atan2_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                max(x.significant_bits(), y.significant_bits()),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "-1.58",
+        "-0x1.94#7",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "-1.56",
+        "-0x1.90#7",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "-1.56",
+        "-0x1.90#7",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "-1.58",
+        "-0x1.94#7",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "-1.58",
+        "-0x1.94#7",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.56",
+        "0x1.90#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.58",
+        "0x1.94#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.56",
+        "0x1.90#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.58",
+        "0x1.94#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.56",
+        "0x1.90#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "1.56",
+        "0x1.90#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "1.58",
+        "0x1.94#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "1.56",
+        "0x1.90#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "1.58",
+        "0x1.94#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "1.56",
+        "0x1.90#7",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "0.8575117863558464",
+        "0x0.db85e4765538e0#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "0.8575117863558464",
+        "0x0.db85e4765538e0#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "1.9937817695327895",
+        "0x1.fe687b67811c7#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "1.9937817695327897",
+        "0x1.fe687b67811c8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "1.9937817695327895",
+        "0x1.fe687b67811c7#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "1.9937817695327897",
+        "0x1.fe687b67811c8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "1.9937817695327895",
+        "0x1.fe687b67811c7#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "2.6295363507367054e-31",
+        "0x5.5555555555550E-26#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "2.6295363507367059e-31",
+        "0x5.5555555555554E-26#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "2.6295363507367054e-31",
+        "0x5.5555555555550E-26#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "2.6295363507367059e-31",
+        "0x5.5555555555554E-26#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "2.6295363507367059e-31",
+        "0x5.5555555555554E-26#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "2.5110222495574232e-31",
+        "0x5.17cc1b7272208E-26#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "2.5110222495574236e-31",
+        "0x5.17cc1b727220cE-26#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "2.5110222495574232e-31",
+        "0x5.17cc1b7272208E-26#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "2.5110222495574236e-31",
+        "0x5.17cc1b727220cE-26#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "2.5110222495574236e-31",
+        "0x5.17cc1b727220cE-26#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "3.03592707148993746836390751840240325663648339393323737102428",
+        "0x3.0932843d172038d950fd89b0c4b1199fc8b25328c7a27ed720#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "3.035927071489937468363907518402403256636483393933237371024282",
+        "0x3.0932843d172038d950fd89b0c4b1199fc8b25328c7a27ed724#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "3.03592707148993746836390751840240325663648339393323737102428",
+        "0x3.0932843d172038d950fd89b0c4b1199fc8b25328c7a27ed720#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "3.035927071489937468363907518402403256636483393933237371024282",
+        "0x3.0932843d172038d950fd89b0c4b1199fc8b25328c7a27ed724#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "3.03592707148993746836390751840240325663648339393323737102428",
+        "0x3.0932843d172038d950fd89b0c4b1199fc8b25328c7a27ed720#200",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "-1.230914073342580026001184414948315048181612486",
+        "-0x1.3b1d2f4931496c9f101a85584622f850314c90#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "-1.230914073342580026001184414948315048181612484",
+        "-0x1.3b1d2f4931496c9f101a85584622f850314c88#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "-1.230914073342580026001184414948315048181612484",
+        "-0x1.3b1d2f4931496c9f101a85584622f850314c88#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "-1.230914073342580026001184414948315048181612486",
+        "-0x1.3b1d2f4931496c9f101a85584622f850314c90#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "-1.230914073342580026001184414948315048181612484",
+        "-0x1.3b1d2f4931496c9f101a85584622f850314c88#150",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn atan2_round_fail() {
+    assert_panic!(Float::ONE.atan2_round(Float::from(3u32), RoundingMode::Exact));
+    assert_panic!(Float::ONE.atan2_round_val_ref(&Float::from(3u32), RoundingMode::Exact));
+    assert_panic!(Float::ONE.atan2_round_ref_val(Float::from(3u32), RoundingMode::Exact));
+    assert_panic!(Float::ONE.atan2_round_ref_ref(&Float::from(3u32), RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_round_assign(Float::from(3u32), RoundingMode::Exact);
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_round_assign_ref(&Float::from(3u32), RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_atan2_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (atan2, o) = x.clone().atan2_prec_round(y.clone(), prec, rm);
+        assert!(atan2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+
+        let (atan2_alt, o_alt) = x.clone().atan2_prec_round_val_ref(&y, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+        let (atan2_alt, o_alt) = x.atan2_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+        let (atan2_alt, o_alt) = x.atan2_prec_round_ref_ref(&y, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_prec_round_assign(y.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_prec_round_assign_ref(&y, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) = rug_atan2_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.7853981633974483096156608458206",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "2.355",
+        "0x2.5b#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.359",
+        "0x2.5c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "2.355",
+        "0x2.5b#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "2.356194490192344928846982537459",
+        "0x2.5b2f8fe6643a469e4e5327a28#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "2.356194490192344928846982537463",
+        "0x2.5b2f8fe6643a469e4e5327a2c#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "2.356194490192344928846982537459",
+        "0x2.5b2f8fe6643a469e4e5327a28#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-2.359",
+        "-0x2.5c#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-2.355",
+        "-0x2.5b#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-2.355",
+        "-0x2.5b#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "-2.356194490192344928846982537463",
+        "-0x2.5b2f8fe6643a469e4e5327a2c#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "-2.356194490192344928846982537459",
+        "-0x2.5b2f8fe6643a469e4e5327a28#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "-2.356194490192344928846982537459",
+        "-0x2.5b2f8fe6643a469e4e5327a28#100",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.562",
+        "0x1.900#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.564",
+        "0x1.908#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.562",
+        "0x1.900#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.562666424614952707629342812471",
+        "0x1.900ae8244749e4ad7dc9fbdac#100",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.562666424614952707629342812473",
+        "0x1.900ae8244749e4ad7dc9fbdae#100",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.562666424614952707629342812471",
+        "0x1.900ae8244749e4ad7dc9fbdac#100",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "1.566",
+        "0x1.910#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.568",
+        "0x1.918#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "1.566",
+        "0x1.910#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Floor,
+        "1.566731308535264715207796998011",
+        "0x1.91154d93c73b3c548df0b0fa8#100",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.566731308535264715207796998013",
+        "0x1.91154d93c73b3c548df0b0faa#100",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Nearest,
+        "1.566731308535264715207796998011",
+        "0x1.91154d93c73b3c548df0b0fa8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "0.857",
+        "0x0.db8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.858",
+        "0x0.dbc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "0.857",
+        "0x0.db8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Floor,
+        "0.8575117863558462720334763011",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.8575117863558462720334763011007",
+        "0x0.db85e4765538da5947cfa3e55#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Nearest,
+        "0.8575117863558462720334763011",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "1.992",
+        "0x1.fe0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.994",
+        "0x1.fe8#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "1.994",
+        "0x1.fe8#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "1.99378176953278961895130326068",
+        "0x1.fe687b67811c776711b7e65b2#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "1.993781769532789618951303260681",
+        "0x1.fe687b67811c776711b7e65b4#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "1.99378176953278961895130326068",
+        "0x1.fe687b67811c776711b7e65b2#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Floor,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Ceiling,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "2.627e-31",
+        "0x5.54E-26#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.631e-31",
+        "0x5.56E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "2.631e-31",
+        "0x5.56E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Floor,
+        "2.629536350736705872070505183771e-31",
+        "0x5.5555555555553fffffffffff8E-26#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Ceiling,
+        "2.629536350736705872070505183774e-31",
+        "0x5.5555555555554000000000000E-26#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Nearest,
+        "2.629536350736705872070505183774e-31",
+        "0x5.5555555555554000000000000E-26#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "2.508e-31",
+        "0x5.16E-26#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "2.511e-31",
+        "0x5.18E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "2.511e-31",
+        "0x5.18E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "2.511022249557423578941186476943e-31",
+        "0x5.17cc1b727220b79e27534dae8E-26#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "2.511022249557423578941186476946e-31",
+        "0x5.17cc1b727220b79e27534daf0E-26#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "2.511022249557423578941186476943e-31",
+        "0x5.17cc1b727220b79e27534dae8E-26#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "1.570796326794896619231321691638",
+        "0x1.921fb54442d18469898cc516e#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "3.141592653589793238462643383276",
+        "0x3.243f6a8885a308d313198a2dc#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Floor,
+        "3.035",
+        "0x3.09#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Ceiling,
+        "3.039",
+        "0x3.0a#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Nearest,
+        "3.035",
+        "0x3.09#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Floor,
+        "3.035927071489937468363907518402",
+        "0x3.0932843d172038d950fd89b0c#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Ceiling,
+        "3.035927071489937468363907518405",
+        "0x3.0932843d172038d950fd89b10#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Nearest,
+        "3.035927071489937468363907518402",
+        "0x3.0932843d172038d950fd89b0c#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "-1.232",
+        "-0x1.3b8#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "-1.23",
+        "-0x1.3b0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "-1.23",
+        "-0x1.3b0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "-1.23091407334258002600118441495",
+        "-0x1.3b1d2f4931496c9f101a85586#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "-1.230914073342580026001184414948",
+        "-0x1.3b1d2f4931496c9f101a85584#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "-1.230914073342580026001184414948",
+        "-0x1.3b1d2f4931496c9f101a85584#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "0.7853981633974483096156608458206",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn atan2_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).atan2_prec_round(Float::one_prec(1), 0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).atan2_prec_round_val_ref(
+        &Float::one_prec(1),
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::one_prec(1).atan2_prec_round_ref_val(
+        Float::one_prec(1),
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::one_prec(1).atan2_prec_round_ref_ref(
+        &Float::one_prec(1),
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.atan2_prec_round_assign(Float::one_prec(1), 0, RoundingMode::Floor)
+    });
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.atan2_prec_round_assign_ref(&Float::one_prec(1), 0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::ONE.atan2_prec_round(Float::from(3u32), 1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.atan2_prec_round_val_ref(&Float::from(3u32), 1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.atan2_prec_round_ref_val(Float::from(3u32), 1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.atan2_prec_round_ref_ref(&Float::from(3u32), 1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_prec_round_assign(Float::from(3u32), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_prec_round_assign_ref(&Float::from(3u32), 1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn atan2_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_5().test_properties(|(x, y, prec, rm)| {
+        let (atan2, o) = x.clone().atan2_prec_round(y.clone(), prec, rm);
+        assert!(atan2.is_valid());
+
+        let (atan2_alt, o_alt) = x.clone().atan2_prec_round_val_ref(&y, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = x.atan2_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = x.atan2_prec_round_ref_ref(&y, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atan2_prec_round_assign(y.clone(), prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atan2_prec_round_assign_ref(&y, prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        if atan2.is_normal() {
+            assert_eq!(atan2.get_prec(), Some(prec));
+            // The result has the sign of $x$ and lies between $-\pi$ and $\pi$.
+            assert_eq!(atan2 > 0u32, x.is_sign_positive());
+            assert!(atan2.le_abs(&4u32));
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if atan2 > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if atan2 > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.atan2_prec_round_ref_ref(&y, prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.atan2_prec_round_ref_ref(&y, prec, RoundingMode::Ceiling);
+                if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) = rug_atan2_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.atan2_prec_round_ref_ref(&y, prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(atan2.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.atan2_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn atan2_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (atan2, o) = x.clone().atan2_prec(y.clone(), prec);
+        assert!(atan2.is_valid());
+
+        let (atan2_alt, o_alt) = x.clone().atan2_prec_val_ref(&y, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = x.atan2_prec_ref_val(y.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = x.atan2_prec_ref_ref(&y, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atan2_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atan2_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = x.atan2_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        // $\operatorname{atan2}(-x, y) = -\operatorname{atan2}(x, y)$.
+        let (atan2_alt, o_alt) = (-&x).atan2_prec_ref_ref(&y, prec);
+        assert_eq!(ComparableFloat(-atan2_alt), ComparableFloat(atan2.clone()));
+        assert_eq!(o_alt.reverse(), o);
+
+        let (rug_atan2, rug_o) = rug_atan2_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2)
+        );
+        assert_eq!(rug_o, o);
+    });
+
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        // $\operatorname{atan2}(x, 1) = \arctan x$.
+        let (atan2, o) = x.atan2_prec_ref_val(Float::ONE, prec);
+        let (atan, o_alt) = x.atan_prec_ref(prec);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(atan2));
+        assert_eq!(o_alt, o);
+    });
+}
+
+#[test]
+fn atan2_round_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = max(x.significant_bits(), y.significant_bits());
+            let (_, floor_o) = x.atan2_prec_round_ref_ref(&y, prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.atan2_round_ref_ref(&y, rm));
+                continue;
+            }
+
+            let (atan2, o) = x.clone().atan2_round(y.clone(), rm);
+            assert!(atan2.is_valid());
+
+            let (atan2_alt, o_alt) = x.clone().atan2_round_val_ref(&y, rm);
+            assert!(atan2_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+            assert_eq!(o_alt, o);
+
+            let (atan2_alt, o_alt) = x.atan2_round_ref_val(y.clone(), rm);
+            assert!(atan2_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+            assert_eq!(o_alt, o);
+
+            let (atan2_alt, o_alt) = x.atan2_round_ref_ref(&y, rm);
+            assert!(atan2_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.atan2_round_assign(y.clone(), rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan2));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.atan2_round_assign_ref(&y, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan2));
+            assert_eq!(o_alt, o);
+
+            let (atan2_alt, o_alt) = x.atan2_prec_round_ref_ref(&y, prec, rm);
+            assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_atan2, rug_o) = rug_atan2_prec_round(
+                    &rug::Float::exact_from(&x),
+                    &rug::Float::exact_from(&y),
+                    prec,
+                    rug_rm,
+                );
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_atan2)),
+                    ComparableFloatRef(&atan2)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    let test_special = |x: Float, y: Float, out: Float| {
+        let (atan2, o) = x.atan2_round(y, RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(atan2), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::ONE, Float::NAN);
+    test_special(Float::ONE, Float::NAN, Float::NAN);
+    test_special(Float::ZERO, Float::ONE, Float::ZERO);
+    test_special(Float::NEGATIVE_ZERO, Float::ONE, Float::NEGATIVE_ZERO);
+    test_special(Float::ZERO, Float::ZERO, Float::ZERO);
+    test_special(Float::NEGATIVE_ZERO, Float::ZERO, Float::NEGATIVE_ZERO);
+    test_special(Float::ONE, Float::INFINITY, Float::ZERO);
+    test_special(Float::NEGATIVE_ONE, Float::INFINITY, Float::NEGATIVE_ZERO);
+}
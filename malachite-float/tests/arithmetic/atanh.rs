@@ -0,0 +1,2414 @@
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_24,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_atanh_prec_round(
+    x: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.atanh_ref(), rm)
+}
+
+#[test]
+fn test_atanh_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atanh, o) = x.clone().atanh_prec(prec);
+        assert!(atanh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atanh.to_string(), out);
+        assert_eq!(to_hex_string(&atanh), out_hex);
+
+        let (atanh_alt, o_alt) = x.atanh_prec_ref(prec);
+        assert!(atanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atanh), ComparableFloatRef(&atanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atanh_alt = x.clone();
+        let o_alt = atanh_alt.atanh_prec_assign(prec);
+        assert!(atanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atanh), ComparableFloatRef(&atanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_atanh, rug_o) = rug_atanh_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atanh)),
+            ComparableFloatRef(&atanh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "Infinity", "Infinity", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("0.5", "0x0.8#1", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("0.5", "0x0.8#1", 10, "0.549", "0x0.8c8#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.549306144334054845697622618461",
+        "0x0.8c9f53d5681854bb520cc6aa8#100",
+        Ordering::Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "NaN", "NaN", Ordering::Equal);
+    test("123.0", "0x7b.0#7", 10, "NaN", "NaN", Ordering::Equal);
+    test("123.0", "0x7b.0#7", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "0.3467",
+        "0x0.58c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "0.3465735902799726338919343490073",
+        "0x0.58b90bfbe8e7bb55e4f1d9cc00#100",
+        Ordering::Less,
+    );
+    test("1.0e30", "0x1.0E+25#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("1.0e30", "0x1.0E+25#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("1.0e30", "0x1.0E+25#1", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "0.3467",
+        "0x0.58c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "0.346573590279972654708616060729",
+        "0x0.58b90bfbe8e7bcd5e4f1d9cc00#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test("1.111", "0x1.1c8#10", 1, "NaN", "NaN", Ordering::Equal);
+    test("1.111", "0x1.1c8#10", 10, "NaN", "NaN", Ordering::Equal);
+    test("1.111", "0x1.1c8#10", 100, "NaN", "NaN", Ordering::Equal);
+    test("-0.5", "-0x0.8#1", 1, "-0.5", "-0x0.8#1", Ordering::Greater);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        "-0.549",
+        "-0x0.8c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        "-0.549306144334054845697622618461",
+        "-0x0.8c9f53d5681854bb520cc6aa8#100",
+        Ordering::Greater,
+    );
+    test("0.9", "0x0.e#3", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test("0.9", "0x0.e#3", 10, "1.354", "0x1.5a8#10", Ordering::Less);
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        "1.354025100551105032998002285074",
+        "0x1.5aa16394d481f01341c899358#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn atanh_prec_fail() {
+    assert_panic!(Float::NAN.atanh_prec(0));
+    assert_panic!(Float::NAN.atanh_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.atanh_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_atanh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atanh, o) = x.clone().atanh_round(rm);
+        assert!(atanh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atanh.to_string(), out);
+        assert_eq!(to_hex_string(&atanh), out_hex);
+
+        let (atanh_alt, o_alt) = x.atanh_round_ref(rm);
+        assert!(atanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atanh), ComparableFloatRef(&atanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atanh_alt = x.clone();
+        let o_alt = atanh_alt.atanh_round_assign(rm);
+        assert!(atanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atanh), ComparableFloatRef(&atanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atanh, rug_o) =
+                rug_atanh_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atanh)),
+                ComparableFloatRef(&atanh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "0.34657359027997259",
+        "0x0.58b90bfbe8e7b8#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "0.34657359027997264",
+        "0x0.58b90bfbe8e7bc#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "0.34657359027997259",
+        "0x0.58b90bfbe8e7b8#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "0.34657359027997264",
+        "0x0.58b90bfbe8e7bc#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "0.34657359027997264",
+        "0x0.58b90bfbe8e7bc#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "0.34657359027997265470861606072908828403775006718012762706034",
+        "0x0.58b90bfbe8e7bcd5e4f1d9cc01f97b57a079a193394c5b16c50#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "0.3465735902799726547086160607290882840377500671801276270603403",
+        "0x0.58b90bfbe8e7bcd5e4f1d9cc01f97b57a079a193394c5b16c58#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "0.34657359027997265470861606072908828403775006718012762706034",
+        "0x0.58b90bfbe8e7bcd5e4f1d9cc01f97b57a079a193394c5b16c50#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "0.3465735902799726547086160607290882840377500671801276270603403",
+        "0x0.58b90bfbe8e7bcd5e4f1d9cc01f97b57a079a193394c5b16c58#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "0.34657359027997265470861606072908828403775006718012762706034",
+        "0x0.58b90bfbe8e7bcd5e4f1d9cc01f97b57a079a193394c5b16c50#200",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Floor,
+        "1.2",
+        "0x1.4#3",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.8#3",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Down,
+        "1.2",
+        "0x1.4#3",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Up,
+        "1.5",
+        "0x1.8#3",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        RoundingMode::Nearest,
+        "1.2",
+        "0x1.4#3",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn atanh_round_fail() {
+    assert_panic!(Float::from(0.5f32).atanh_round(RoundingMode::Exact));
+    assert_panic!(Float::from(0.5f32).atanh_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(0.5f32);
+        x.atanh_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_atanh_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atanh, o) = x.clone().atanh_prec_round(prec, rm);
+        assert!(atanh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atanh.to_string(), out);
+        assert_eq!(to_hex_string(&atanh), out_hex);
+
+        let (atanh_alt, o_alt) = x.atanh_prec_round_ref(prec, rm);
+        assert!(atanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atanh), ComparableFloatRef(&atanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atanh_alt = x.clone();
+        let o_alt = atanh_alt.atanh_prec_round_assign(prec, rm);
+        assert!(atanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atanh), ComparableFloatRef(&atanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atanh, rug_o) = rug_atanh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atanh)),
+                ComparableFloatRef(&atanh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "0.3462",
+        "0x0.58a#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "0.3467",
+        "0x0.58c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "0.3467",
+        "0x0.58c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "0.346573590279972654708616060729",
+        "0x0.58b90bfbe8e7bcd5e4f1d9cc00#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "0.3465735902799726547086160607294",
+        "0x0.58b90bfbe8e7bcd5e4f1d9cc08#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "0.346573590279972654708616060729",
+        "0x0.58b90bfbe8e7bcd5e4f1d9cc00#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        1,
+        RoundingMode::Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "-0.55",
+        "-0x0.8cc#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.549",
+        "-0x0.8c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.549",
+        "-0x0.8c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        RoundingMode::Floor,
+        "-0.549306144334054845697622618462",
+        "-0x0.8c9f53d5681854bb520cc6aa9#100",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        RoundingMode::Ceiling,
+        "-0.549306144334054845697622618461",
+        "-0x0.8c9f53d5681854bb520cc6aa8#100",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        RoundingMode::Nearest,
+        "-0.549306144334054845697622618461",
+        "-0x0.8c9f53d5681854bb520cc6aa8#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        10,
+        RoundingMode::Floor,
+        "1.354",
+        "0x1.5a8#10",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        10,
+        RoundingMode::Ceiling,
+        "1.355",
+        "0x1.5b0#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        10,
+        RoundingMode::Nearest,
+        "1.354",
+        "0x1.5a8#10",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        RoundingMode::Floor,
+        "1.354025100551105032998002285074",
+        "0x1.5aa16394d481f01341c899358#100",
+        Ordering::Less,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        RoundingMode::Ceiling,
+        "1.354025100551105032998002285076",
+        "0x1.5aa16394d481f01341c89935a#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.9",
+        "0x0.e#3",
+        100,
+        RoundingMode::Nearest,
+        "1.354025100551105032998002285074",
+        "0x1.5aa16394d481f01341c899358#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn atanh_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).atanh_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).atanh_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.atanh_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(0.5f32).atanh_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(0.5f32).atanh_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(0.5f32);
+        x.atanh_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn atanh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_24().test_properties(|(x, prec, rm)| {
+        let (atanh, o) = x.clone().atanh_prec_round(prec, rm);
+        assert!(atanh.is_valid());
+
+        let (atanh_alt, o_alt) = x.atanh_prec_round_ref(prec, rm);
+        assert!(atanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atanh_alt), ComparableFloatRef(&atanh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atanh_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atanh));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && atanh.is_normal() {
+            assert_eq!(atanh.get_prec(), Some(prec));
+            // $\operatorname{artanh} x$ has the same sign as $x$.
+            assert!((x > 0u32) == (atanh > 0u32));
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if atanh > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if atanh > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.atanh_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.atanh_prec_round_ref(prec, RoundingMode::Ceiling);
+                if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atanh, rug_o) =
+                rug_atanh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atanh)),
+                ComparableFloatRef(&atanh)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.atanh_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(atanh.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.atanh_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn atanh_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (atanh, o) = x.clone().atanh_prec(prec);
+        assert!(atanh.is_valid());
+
+        let (atanh_alt, o_alt) = x.atanh_prec_ref(prec);
+        assert!(atanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atanh_alt), ComparableFloatRef(&atanh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atanh_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atanh));
+        assert_eq!(o_alt, o);
+
+        let (atanh_alt, o_alt) = x.atanh_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&atanh_alt), ComparableFloatRef(&atanh));
+        assert_eq!(o_alt, o);
+
+        let (rug_atanh, rug_o) = rug_atanh_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atanh)),
+            ComparableFloatRef(&atanh)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn atanh_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.atanh_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.atanh_round_ref(rm));
+                continue;
+            }
+
+            let (atanh, o) = x.clone().atanh_round(rm);
+            assert!(atanh.is_valid());
+
+            let (atanh_alt, o_alt) = x.atanh_round_ref(rm);
+            assert!(atanh_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&atanh_alt), ComparableFloatRef(&atanh));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.atanh_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atanh));
+            assert_eq!(o_alt, o);
+
+            let (atanh_alt, o_alt) = x.atanh_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&atanh_alt), ComparableFloatRef(&atanh));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_atanh, rug_o) =
+                    rug_atanh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_atanh)),
+                    ComparableFloatRef(&atanh)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (atanh, o) = x.atanh_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(atanh), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::NAN);
+    test_special(Float::NEGATIVE_INFINITY, Float::NAN);
+    test_special(Float::ZERO, Float::ZERO);
+    test_special(Float::NEGATIVE_ZERO, Float::NEGATIVE_ZERO);
+    test_special(Float::ONE, Float::INFINITY);
+    test_special(Float::NEGATIVE_ONE, Float::NEGATIVE_INFINITY);
+}
@@ -0,0 +1,2133 @@
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_14,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_cos_prec_round(x: &rug::Float, prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.cos_ref(), rm)
+}
+
+#[test]
+fn test_cos_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_prec(prec);
+        assert!(cos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+
+        let (cos_alt, o_alt) = x.cos_prec_ref(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_prec_assign(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_cos, rug_o) = rug_cos_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("1.0", "0x1.0#1", 10, "0.54", "0x0.8a4#10", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("-1.0", "-0x1.0#1", 10, "0.54", "0x0.8a4#10", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+    );
+    test("0.5", "0x0.8#1", 1, "1.0", "0x1.0#1", Ordering::Greater);
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        "0.878",
+        "0x0.e0c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.877582561890372716116281582604",
+        "0x0.e0a94032dbea7cedbddd9da30#100",
+        Ordering::Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "-1.0", "-0x1.0#1", Ordering::Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "-0.888",
+        "-0x0.e34#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "-0.887968906691855428978322569443",
+        "-0x0.e351ee261b3cad7894677374e#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.912",
+        "-0x0.e98#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-0.9117339147869650385092477381996",
+        "-0x0.e96764d2aa0034ce60b62d755#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "0.156",
+        "0x0.27f#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "0.155943694765374377964416729388",
+        "0x0.27ebed0d08e166a8f789eb7528#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "0.9449569463147376704426021063145",
+        "0x0.f1e8b2cc8cc165db184e519cd#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "0.4893",
+        "0x0.7d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "0.4891786569747214499057893087514",
+        "0x0.7d3acffd9b8db34b71d86c7ff0#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "0.944956946314737664388284007676",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "-0.9999992005335529032683357396563",
+        "-0x0.fffff29651586c28bc01f9fb2#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "0.4434715251017858896674117237529",
+        "0x0.7187599104f847ef392b3b1268#100",
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        1,
+        "-2.0e-15",
+        "-0x8.0E-13#1",
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        10,
+        "-2.449e-15",
+        "-0xb.08E-13#10",
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        100,
+        "-2.449912578931294712152146931693e-15",
+        "-0xb.088e90c77fd12ea79f98632aE-13#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn cos_prec_fail() {
+    assert_panic!(Float::NAN.cos_prec(0));
+    assert_panic!(Float::NAN.cos_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.cos_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_cos_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_round(rm);
+        assert!(cos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+
+        let (cos_alt, o_alt) = x.cos_round_ref(rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_round_assign(rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) =
+                rug_cos_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "-0.89",
+        "-0x0.e4#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "-0.883",
+        "-0x0.e2#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "-0.883",
+        "-0x0.e2#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "-0.89",
+        "-0x0.e4#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "-0.89",
+        "-0x0.e4#7",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-0.911733914786965",
+        "-0x0.e96764d2aa0030#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-0.911733914786965",
+        "-0x0.e96764d2aa0030#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "0.1559436947653744",
+        "0x0.27ebed0d08e168#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "0.1559436947653744",
+        "0x0.27ebed0d08e168#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "0.9449569463147376",
+        "0x0.f1e8b2cc8cc160#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "0.9449569463147377",
+        "0x0.f1e8b2cc8cc168#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "0.9449569463147376",
+        "0x0.f1e8b2cc8cc160#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "0.9449569463147377",
+        "0x0.f1e8b2cc8cc168#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "0.9449569463147377",
+        "0x0.f1e8b2cc8cc168#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "0.9449569463147376643882840076758806078458526995651407376776453",
+        "0x0.f1e8b2cc8cc1656b6998d964d0f06c45113a7686fcc7f1d25d#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "0.944956946314737664388284007675880607845852699565140737677646",
+        "0x0.f1e8b2cc8cc1656b6998d964d0f06c45113a7686fcc7f1d25e#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "0.9449569463147376643882840076758806078458526995651407376776453",
+        "0x0.f1e8b2cc8cc1656b6998d964d0f06c45113a7686fcc7f1d25d#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "0.944956946314737664388284007675880607845852699565140737677646",
+        "0x0.f1e8b2cc8cc1656b6998d964d0f06c45113a7686fcc7f1d25e#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "0.944956946314737664388284007675880607845852699565140737677646",
+        "0x0.f1e8b2cc8cc1656b6998d964d0f06c45113a7686fcc7f1d25e#200",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "-0.999999200533552903268335739656574951575828821",
+        "-0x0.fffff29651586c28bc01f9fb24c20d43542f88#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "-0.9999992005335529032683357396565749515758288204",
+        "-0x0.fffff29651586c28bc01f9fb24c20d43542f84#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "-0.9999992005335529032683357396565749515758288204",
+        "-0x0.fffff29651586c28bc01f9fb24c20d43542f84#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "-0.999999200533552903268335739656574951575828821",
+        "-0x0.fffff29651586c28bc01f9fb24c20d43542f88#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "-0.9999992005335529032683357396565749515758288204",
+        "-0x0.fffff29651586c28bc01f9fb24c20d43542f84#150",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "0.4438",
+        "0x0.71a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "0.4438",
+        "0x0.71a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Less,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        RoundingMode::Floor,
+        "-2.449912578931295e-15",
+        "-0xb.088e90c77fd18E-13#53",
+        Ordering::Less,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        RoundingMode::Ceiling,
+        "-2.4499125789312946e-15",
+        "-0xb.088e90c77fd10E-13#53",
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        RoundingMode::Down,
+        "-2.4499125789312946e-15",
+        "-0xb.088e90c77fd10E-13#53",
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        RoundingMode::Up,
+        "-2.449912578931295e-15",
+        "-0xb.088e90c77fd18E-13#53",
+        Ordering::Less,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        RoundingMode::Nearest,
+        "-2.4499125789312946e-15",
+        "-0xb.088e90c77fd10E-13#53",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn cos_round_fail() {
+    assert_panic!(Float::from(3u32).cos_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).cos_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.cos_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_cos_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_prec_round(prec, rm);
+        assert!(cos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+
+        let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_prec_round_assign(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "-0.999999999999999999999999999999",
+        "-0x0.fffffffffffffffffffffffff#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "0.1558",
+        "0x0.27e#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.156",
+        "0x0.27f#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "0.156",
+        "0x0.27f#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "0.1559436947653743779644167293878",
+        "0x0.27ebed0d08e166a8f789eb7524#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.155943694765374377964416729388",
+        "0x0.27ebed0d08e166a8f789eb7528#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "0.155943694765374377964416729388",
+        "0x0.27ebed0d08e166a8f789eb7528#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "0.944",
+        "0x0.f1c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "0.944956946314737664388284007676",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "0.9449569463147376643882840076766",
+        "0x0.f1e8b2cc8cc1656b6998d964e#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "0.944956946314737664388284007676",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.4438",
+        "0x0.71a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "0.4434715251017858896674117237525",
+        "0x0.7187599104f847ef392b3b1260#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "0.4434715251017858896674117237529",
+        "0x0.7187599104f847ef392b3b1268#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "0.4434715251017858896674117237529",
+        "0x0.7187599104f847ef392b3b1268#100",
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        1,
+        RoundingMode::Floor,
+        "-4.0e-15",
+        "-0x1.0E-12#1",
+        Ordering::Less,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        1,
+        RoundingMode::Ceiling,
+        "-2.0e-15",
+        "-0x8.0E-13#1",
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        1,
+        RoundingMode::Nearest,
+        "-2.0e-15",
+        "-0x8.0E-13#1",
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        10,
+        RoundingMode::Floor,
+        "-2.453e-15",
+        "-0xb.0cE-13#10",
+        Ordering::Less,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        10,
+        RoundingMode::Ceiling,
+        "-2.449e-15",
+        "-0xb.08E-13#10",
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        10,
+        RoundingMode::Nearest,
+        "-2.449e-15",
+        "-0xb.08E-13#10",
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        100,
+        RoundingMode::Floor,
+        "-2.449912578931294712152146931696e-15",
+        "-0xb.088e90c77fd12ea79f98632bE-13#100",
+        Ordering::Less,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        100,
+        RoundingMode::Ceiling,
+        "-2.449912578931294712152146931693e-15",
+        "-0xb.088e90c77fd12ea79f98632aE-13#100",
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        100,
+        RoundingMode::Nearest,
+        "-2.449912578931294712152146931693e-15",
+        "-0xb.088e90c77fd12ea79f98632aE-13#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn cos_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).cos_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).cos_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.cos_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).cos_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).cos_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.cos_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn cos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_14().test_properties(|(x, prec, rm)| {
+        let (cos, o) = x.clone().cos_prec_round(prec, rm);
+        assert!(cos.is_valid());
+
+        let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.cos_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && cos.is_normal() {
+            assert_eq!(cos.get_prec(), Some(prec));
+            // $\cos x$ is between -1 and 1.
+            assert!(cos.le_abs(&1u32));
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if cos > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if cos > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.cos_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.cos_prec_round_ref(prec, RoundingMode::Ceiling);
+                if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.cos_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(cos.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.cos_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn cos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (cos, o) = x.clone().cos_prec(prec);
+        assert!(cos.is_valid());
+
+        let (cos_alt, o_alt) = x.cos_prec_ref(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.cos_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        let (rug_cos, rug_o) = rug_cos_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn cos_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.cos_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.cos_round_ref(rm));
+                continue;
+            }
+
+            let (cos, o) = x.clone().cos_round(rm);
+            assert!(cos.is_valid());
+
+            let (cos_alt, o_alt) = x.cos_round_ref(rm);
+            assert!(cos_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.cos_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+            assert_eq!(o_alt, o);
+
+            let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_cos, rug_o) =
+                    rug_cos_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_cos)),
+                    ComparableFloatRef(&cos)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (cos, o) = x.cos_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::NAN);
+    test_special(Float::NEGATIVE_INFINITY, Float::NAN);
+    test_special(Float::ZERO, Float::ONE);
+    test_special(Float::NEGATIVE_ZERO, Float::ONE);
+}
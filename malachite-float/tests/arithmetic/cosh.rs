@@ -0,0 +1,2175 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{CountOnes, SignificantBits};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_20,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_cosh_prec_round(x: &rug::Float, prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.cosh_ref(), rm)
+}
+
+// MPFR, with its default exponent range, overflows or underflows for larger inputs, and
+// Malachite's results are only compared against it when it doesn't.
+fn in_mpfr_range(x: &Float) -> bool {
+    x.get_exponent().map_or(true, |e| e <= 24)
+}
+
+#[test]
+fn test_cosh_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cosh, o) = x.clone().cosh_prec(prec);
+        assert!(cosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+
+        let (cosh_alt, o_alt) = x.cosh_prec_ref(prec);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cosh_alt = x.clone();
+        let o_alt = cosh_alt.cosh_prec_assign(prec);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            let (rug_cosh, rug_o) = rug_cosh_prec_round(
+                &rug::Float::exact_from(&x),
+                prec,
+                rug::float::Round::Nearest,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("1.0", "0x1.0#1", 10, "1.543", "0x1.8b0#10", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.543080634815243778477905620758",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Ordering::Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "1.543080634815243778477905620758",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Ordering::Greater,
+    );
+    test("0.5", "0x0.8#1", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test("0.5", "0x0.8#1", 10, "1.127", "0x1.208#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.127625965206380785226225161402",
+        "0x1.20ac1862ae8d0645823a4f060#100",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "1.0e53",
+        "0x1.0E+44#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.31e53",
+        "0x1.5e0E+44#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.309758659374531338094490512688e53",
+        "0x1.5e1152f9a8119393a111d9f34E+44#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "11.59",
+        "0xb.98#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "11.59195327552151921343908324729",
+        "0xb.978a3ff7200027b1b4b07db3#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "7.61",
+        "0x7.9c#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "7.610125138662287272802774883445",
+        "0x7.9c31293d05a112a04c47df6f8#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "2.18",
+        "0x2.2e#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "2.178183556608571051057807578727",
+        "0x2.2d9d7004519b643fa2e00fa84#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "1.057",
+        "0x1.0e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "1.0560718678299393832441023006",
+        "0x1.0e5ab9d68e232b563d00ac714#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "1.057",
+        "0x1.0e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "1.056071867829939389526864708264",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "11.61",
+        "0xb.9c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "11.60656580376196641440105294515",
+        "0xb.9b47e5820793d583f2840c93#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "1.684",
+        "0x1.af0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "1.683756317574335712402103214253",
+        "0x1.af0aa76e6a480d6fc0350789c#100",
+        Ordering::Less,
+    );
+    test("2.0", "0x2.0#1", 1, "4.0", "0x4.0#1", Ordering::Greater);
+    test("2.0", "0x2.0#1", 10, "3.762", "0x3.c3#10", Ordering::Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "3.762195691083631459562213477772",
+        "0x3.c31f41be5b3785264fee2eee8#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn cosh_prec_fail() {
+    assert_panic!(Float::NAN.cosh_prec(0));
+    assert_panic!(Float::NAN.cosh_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.cosh_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_cosh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cosh, o) = x.clone().cosh_round(rm);
+        assert!(cosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+
+        let (cosh_alt, o_alt) = x.cosh_round_ref(rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cosh_alt = x.clone();
+        let o_alt = cosh_alt.cosh_round_assign(rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_cosh, rug_o) =
+                    rug_cosh_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_cosh)),
+                    ComparableFloatRef(&cosh)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "1.3e53",
+        "0x1.5cE+44#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "1.32e53",
+        "0x1.60E+44#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "1.3e53",
+        "0x1.5cE+44#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "1.32e53",
+        "0x1.60E+44#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "1.32e53",
+        "0x1.60E+44#7",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "11.591953275521519",
+        "0xb.978a3ff720000#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "11.59195327552152",
+        "0xb.978a3ff720008#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "11.591953275521519",
+        "0xb.978a3ff720000#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "11.59195327552152",
+        "0xb.978a3ff720008#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "11.591953275521519",
+        "0xb.978a3ff720000#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "7.610125138662287",
+        "0x7.9c31293d05a10#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "7.610125138662288",
+        "0x7.9c31293d05a14#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "7.610125138662287",
+        "0x7.9c31293d05a10#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "7.610125138662288",
+        "0x7.9c31293d05a14#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "7.610125138662287",
+        "0x7.9c31293d05a10#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "2.178183556608571",
+        "0x2.2d9d7004519b6#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "2.1781835566085714",
+        "0x2.2d9d7004519b8#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "2.178183556608571",
+        "0x2.2d9d7004519b6#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "2.1781835566085714",
+        "0x2.2d9d7004519b8#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "2.178183556608571",
+        "0x2.2d9d7004519b6#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "1.0560718678299392",
+        "0x1.0e5ab9d68e232#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "1.0560718678299394",
+        "0x1.0e5ab9d68e233#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "1.0560718678299392",
+        "0x1.0e5ab9d68e232#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "1.0560718678299394",
+        "0x1.0e5ab9d68e233#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "1.0560718678299394",
+        "0x1.0e5ab9d68e233#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "1.056071867829939389526864708263983252525509287510443069879193",
+        "0x1.0e5ab9d68e232bca22825de223c5350517c944c8f14658cf4e#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "1.056071867829939389526864708263983252525509287510443069879194",
+        "0x1.0e5ab9d68e232bca22825de223c5350517c944c8f14658cf50#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "1.056071867829939389526864708263983252525509287510443069879193",
+        "0x1.0e5ab9d68e232bca22825de223c5350517c944c8f14658cf4e#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "1.056071867829939389526864708263983252525509287510443069879194",
+        "0x1.0e5ab9d68e232bca22825de223c5350517c944c8f14658cf50#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "1.056071867829939389526864708263983252525509287510443069879193",
+        "0x1.0e5ab9d68e232bca22825de223c5350517c944c8f14658cf4e#200",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "11.60656580376196641440105294515190289031076067",
+        "0xb.9b47e5820793d583f2840c92e7c42d5ff6964#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "11.60656580376196641440105294515190289031076068",
+        "0xb.9b47e5820793d583f2840c92e7c42d5ff6968#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "11.60656580376196641440105294515190289031076067",
+        "0xb.9b47e5820793d583f2840c92e7c42d5ff6964#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "11.60656580376196641440105294515190289031076068",
+        "0xb.9b47e5820793d583f2840c92e7c42d5ff6968#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "11.60656580376196641440105294515190289031076068",
+        "0xb.9b47e5820793d583f2840c92e7c42d5ff6968#150",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "1.684",
+        "0x1.af0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "1.686",
+        "0x1.af8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "1.684",
+        "0x1.af0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "1.686",
+        "0x1.af8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "1.684",
+        "0x1.af0#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn cosh_round_fail() {
+    assert_panic!(Float::from(3u32).cosh_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).cosh_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.cosh_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_cosh_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cosh, o) = x.clone().cosh_prec_round(prec, rm);
+        assert!(cosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+
+        let (cosh_alt, o_alt) = x.cosh_prec_round_ref(prec, rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cosh_alt = x.clone();
+        let o_alt = cosh_alt.cosh_prec_round_assign(prec, rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_cosh, rug_o) = rug_cosh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_cosh)),
+                    ComparableFloatRef(&cosh)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.545",
+        "0x1.8b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.543080634815243778477905620756",
+        "0x1.8b07551d9f5504c2bd2810018#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.543080634815243778477905620758",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.543080634815243778477905620758",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.545",
+        "0x1.8b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.543080634815243778477905620756",
+        "0x1.8b07551d9f5504c2bd2810018#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.543080634815243778477905620758",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.543080634815243778477905620758",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "11.58",
+        "0xb.94#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "11.59",
+        "0xb.98#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "11.59",
+        "0xb.98#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "11.59195327552151921343908324728",
+        "0xb.978a3ff7200027b1b4b07db2#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "11.59195327552151921343908324729",
+        "0xb.978a3ff7200027b1b4b07db3#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "11.59195327552151921343908324729",
+        "0xb.978a3ff7200027b1b4b07db3#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "2.176",
+        "0x2.2d#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "2.18",
+        "0x2.2e#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "2.18",
+        "0x2.2e#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "2.178183556608571051057807578727",
+        "0x2.2d9d7004519b643fa2e00fa84#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "2.178183556608571051057807578731",
+        "0x2.2d9d7004519b643fa2e00fa88#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "2.178183556608571051057807578727",
+        "0x2.2d9d7004519b643fa2e00fa84#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "1.055",
+        "0x1.0e0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "1.057",
+        "0x1.0e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "1.057",
+        "0x1.0e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "1.056071867829939389526864708264",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "1.056071867829939389526864708265",
+        "0x1.0e5ab9d68e232bca22825de24#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "1.056071867829939389526864708264",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "1.684",
+        "0x1.af0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "1.686",
+        "0x1.af8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "1.684",
+        "0x1.af0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "1.683756317574335712402103214253",
+        "0x1.af0aa76e6a480d6fc0350789c#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "1.683756317574335712402103214255",
+        "0x1.af0aa76e6a480d6fc0350789e#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "1.683756317574335712402103214253",
+        "0x1.af0aa76e6a480d6fc0350789c#100",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "3.762",
+        "0x3.c3#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "3.766",
+        "0x3.c4#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "3.762",
+        "0x3.c3#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Floor,
+        "3.762195691083631459562213477772",
+        "0x3.c31f41be5b3785264fee2eee8#100",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "3.762195691083631459562213477776",
+        "0x3.c31f41be5b3785264fee2eeec#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Nearest,
+        "3.762195691083631459562213477772",
+        "0x3.c31f41be5b3785264fee2eee8#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn cosh_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).cosh_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).cosh_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.cosh_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).cosh_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).cosh_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.cosh_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_cosh_prec_round_overflow() {
+    // $\cosh x$ is treated as overflowing for $|x| \geq 2^{62}$. Rounding toward zero gives the
+    // largest finite `Float` with the target precision.
+    let x = Float::power_of_2(62u64);
+    for rm in exhaustive_rounding_modes() {
+        if rm == RoundingMode::Exact {
+            assert_panic!(x.cosh_prec_round_ref(10, rm));
+            continue;
+        }
+        let (y, o) = x.cosh_prec_round_ref(10, rm);
+        assert!(y.is_valid());
+        let (y_alt, o_alt) = x.clone().cosh_prec_round(10, rm);
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&y));
+        assert_eq!(o_alt, o);
+        if rm == RoundingMode::Floor || rm == RoundingMode::Down {
+            assert_eq!(y.get_exponent(), Some(i64::MAX));
+            assert_eq!(y.get_prec(), Some(10));
+            assert_eq!(y.to_significand().unwrap().count_ones(), 10);
+            assert_eq!(o, Ordering::Less);
+        } else {
+            assert_eq!(ComparableFloat(y), ComparableFloat(Float::INFINITY));
+            assert_eq!(o, Ordering::Greater);
+        }
+    }
+
+    let x = -x;
+    for rm in exhaustive_rounding_modes() {
+        if rm == RoundingMode::Exact {
+            assert_panic!(x.cosh_prec_round_ref(10, rm));
+            continue;
+        }
+        let (y, o) = x.cosh_prec_round_ref(10, rm);
+        assert!(y.is_valid());
+        let (y_alt, o_alt) = x.clone().cosh_prec_round(10, rm);
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&y));
+        assert_eq!(o_alt, o);
+        if rm == RoundingMode::Floor || rm == RoundingMode::Down {
+            assert_eq!(y.get_exponent(), Some(i64::MAX));
+            assert_eq!(y.get_prec(), Some(10));
+            assert_eq!(y.to_significand().unwrap().count_ones(), 10);
+            assert_eq!(o, Ordering::Less);
+        } else {
+            assert_eq!(ComparableFloat(y), ComparableFloat(Float::INFINITY));
+            assert_eq!(o, Ordering::Greater);
+        }
+    }
+}
+
+#[test]
+fn cosh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_20().test_properties(|(x, prec, rm)| {
+        let (cosh, o) = x.clone().cosh_prec_round(prec, rm);
+        assert!(cosh.is_valid());
+
+        let (cosh_alt, o_alt) = x.cosh_prec_round_ref(prec, rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.cosh_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && cosh.is_normal() {
+            assert_eq!(cosh.get_prec(), Some(prec));
+            // $\cosh x$ is at least 1.
+            assert!(cosh >= 1u32);
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if cosh > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if cosh > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.cosh_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.cosh_prec_round_ref(prec, RoundingMode::Ceiling);
+                if ceiling.is_infinite() {
+                    // On overflow, `floor` is the largest finite `Float`.
+                    assert_eq!(floor.get_exponent(), Some(i64::MAX));
+                } else if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if in_mpfr_range(&x) {
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_cosh, rug_o) =
+                    rug_cosh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_cosh)),
+                    ComparableFloatRef(&cosh)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.cosh_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(cosh.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.cosh_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn cosh_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (cosh, o) = x.clone().cosh_prec(prec);
+        assert!(cosh.is_valid());
+
+        let (cosh_alt, o_alt) = x.cosh_prec_ref(prec);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.cosh_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        let (cosh_alt, o_alt) = x.cosh_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        if in_mpfr_range(&x) {
+            let (rug_cosh, rug_o) = rug_cosh_prec_round(
+                &rug::Float::exact_from(&x),
+                prec,
+                rug::float::Round::Nearest,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn cosh_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.cosh_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.cosh_round_ref(rm));
+                continue;
+            }
+
+            let (cosh, o) = x.clone().cosh_round(rm);
+            assert!(cosh.is_valid());
+
+            let (cosh_alt, o_alt) = x.cosh_round_ref(rm);
+            assert!(cosh_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.cosh_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cosh));
+            assert_eq!(o_alt, o);
+
+            let (cosh_alt, o_alt) = x.cosh_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+            assert_eq!(o_alt, o);
+
+            if in_mpfr_range(&x) {
+                if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                    let (rug_cosh, rug_o) =
+                        rug_cosh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                    assert_eq!(
+                        ComparableFloatRef(&Float::from(&rug_cosh)),
+                        ComparableFloatRef(&cosh)
+                    );
+                    assert_eq!(rug_o, o);
+                }
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (cosh, o) = x.cosh_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(cosh), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::INFINITY);
+    test_special(Float::NEGATIVE_INFINITY, Float::INFINITY);
+    test_special(Float::ZERO, Float::ONE);
+    test_special(Float::NEGATIVE_ZERO, Float::ONE);
+}
@@ -0,0 +1,12934 @@
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_unsigned_rounding_mode_quadruple_gen_var_4, float_float_unsigned_triple_gen_var_1,
+    float_gen, float_integer_pair_gen, float_natural_pair_gen, float_pair_gen,
+    float_pair_gen_var_1, float_rational_pair_gen, float_rational_pair_gen_var_1,
+    float_rational_unsigned_rounding_mode_quadruple_gen_var_4,
+    float_rational_unsigned_rounding_mode_quadruple_gen_var_5,
+    float_rational_unsigned_triple_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn rug_div_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x / y, rm)
+}
+
+fn rug_rational_div_float_prec_round(
+    x: &rug::Rational,
+    y: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x / y, rm)
+}
+
+fn rug_div_rational_prec_round(
+    x: &rug::Float,
+    y: &rug::Rational,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x / y, rm)
+}
+
+#[test]
+fn test_div() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let quotient = x.clone() / y.clone();
+        assert!(quotient.is_valid());
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let quotient_alt = x.clone() / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        let quotient_alt = &x / y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        let quotient_alt = &x / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+
+        let mut quotient_alt = x.clone();
+        quotient_alt /= y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        let mut quotient_alt = x.clone();
+        quotient_alt /= &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+
+        let prec = max(x.significant_bits(), y.significant_bits());
+        let (rug_quotient, _) = rug_div_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity", "NaN", "NaN");
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", "Infinity", "Infinity",
+    );
+    test("1.0", "0x1.0#1", "-Infinity", "-Infinity", "-0.0", "-0x0.0");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", "NaN", "NaN");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("0.0", "0x0.0", "123.0", "0x7b.0#7", "0.0", "0x0.0");
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", "Infinity", "Infinity");
+    test("-1.0", "-0x1.0#1", "-0.0", "-0x0.0", "Infinity", "Infinity");
+    test("0.0", "0x0.0", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test("123.0", "0x7b.0#7", "1.0", "0x1.0#1", "123.0", "0x7b.0#7");
+    test("123.0", "0x7b.0#7", "0.5", "0x0.8#1", "246.0", "0xf6.0#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-2.2214414690791831",
+        "-0x2.38b0635bac5c8#53",
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "2.6295363507367059e-31",
+        "0x5.5555555555554E-26#53",
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.5110222495574236e-31",
+        "0x5.17cc1b727220cE-26#53",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "-0.1060606060606060606060606060606060606060606060876283450420764",
+        "-0x0.1b26c9b26c9b26c9b26c9b26c9b26c9b26c9b29419637021da0#200",
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        "-2.828019081094652272156665829776550338940497111",
+        "-0x2.d3f90ef9c3f807b678b47aa218cf3777bdab8#150",
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        "1.0",
+        "0x1.000#10",
+    );
+}
+
+#[test]
+fn test_div_prec() {
+    let test = |s, s_hex, t, t_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = x.clone().div_prec(y.clone(), prec);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_prec_val_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_prec_ref_val(y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_prec_ref_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_assign(y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_assign_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_quotient, rug_o) = rug_div_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        "3.0e2",
+        "0x1.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        "246.0",
+        "0xf6.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        "246.0",
+        "0xf6.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.156",
+        "0x1.280#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.155727349790921734322182875179",
+        "0x1.27ddbf6271dbe02c839f8eb6a#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "-2.223",
+        "-0x2.39#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "-2.221441469079182885058846603273",
+        "-0x2.38b0635bac5c740ce07a5c754#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "2.631e-31",
+        "0x5.56E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "2.629536350736705872070505183774e-31",
+        "0x5.5555555555554000000000000E-26#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "2.511e-31",
+        "0x5.18E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "2.511022249557423578941186476943e-31",
+        "0x5.17cc1b727220b79e27534dae8E-26#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "-0.1",
+        "-0x0.2#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "-0.1061",
+        "-0x0.1b28#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "-0.1060606060606060606060606060606",
+        "-0x0.1b26c9b26c9b26c9b26c9b26ca#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "-2.828",
+        "-0x2.d4#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "-2.828019081094652272156665829775",
+        "-0x2.d3f90ef9c3f807b678b47aa20#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn div_prec_fail() {
+    assert_panic!(Float::ONE.div_prec(Float::TWO, 0));
+    assert_panic!(Float::ONE.div_prec_val_ref(&Float::TWO, 0));
+    assert_panic!(Float::ONE.div_prec_ref_val(Float::TWO, 0));
+    assert_panic!(Float::ONE.div_prec_ref_ref(&Float::TWO, 0));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_prec_assign(Float::TWO, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_prec_assign_ref(&Float::TWO, 0)
+    });
+}
+
+#[test]
+fn test_div_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = x.clone().div_round(y.clone(), rm);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_round_val_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_round_ref_val(y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_round_ref_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_round_assign(y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_round_assign_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) = rug_div_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                max(x.significant_bits(), y.significant_bits()),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "246.0",
+        "0xf6.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "246.0",
+        "0xf6.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "246.0",
+        "0xf6.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "246.0",
+        "0xf6.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "246.0",
+        "0xf6.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Exact,
+        "246.0",
+        "0xf6.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "1.155727349790922",
+        "0x1.27ddbf6271dbf#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "1.155727349790922",
+        "0x1.27ddbf6271dbf#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "-2.2214414690791831",
+        "-0x2.38b0635bac5c8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "-2.2214414690791826",
+        "-0x2.38b0635bac5c6#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "-2.2214414690791826",
+        "-0x2.38b0635bac5c6#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "-2.2214414690791831",
+        "-0x2.38b0635bac5c8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "-2.2214414690791831",
+        "-0x2.38b0635bac5c8#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "2.6295363507367059e-31",
+        "0x5.5555555555554E-26#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "2.6295363507367059e-31",
+        "0x5.5555555555554E-26#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "2.6295363507367059e-31",
+        "0x5.5555555555554E-26#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "2.6295363507367059e-31",
+        "0x5.5555555555554E-26#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "2.6295363507367059e-31",
+        "0x5.5555555555554E-26#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Exact,
+        "2.6295363507367059e-31",
+        "0x5.5555555555554E-26#53",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "2.5110222495574232e-31",
+        "0x5.17cc1b7272208E-26#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "2.5110222495574236e-31",
+        "0x5.17cc1b727220cE-26#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "2.5110222495574232e-31",
+        "0x5.17cc1b7272208E-26#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "2.5110222495574236e-31",
+        "0x5.17cc1b727220cE-26#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "2.5110222495574236e-31",
+        "0x5.17cc1b727220cE-26#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "-0.1060606060606060606060606060606060606060606060876283450420764",
+        "-0x0.1b26c9b26c9b26c9b26c9b26c9b26c9b26c9b29419637021da0#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "-0.10606060606060606060606060606060606060606060608762834504207634",
+        "-0x0.1b26c9b26c9b26c9b26c9b26c9b26c9b26c9b29419637021d9e#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "-0.10606060606060606060606060606060606060606060608762834504207634",
+        "-0x0.1b26c9b26c9b26c9b26c9b26c9b26c9b26c9b29419637021d9e#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "-0.1060606060606060606060606060606060606060606060876283450420764",
+        "-0x0.1b26c9b26c9b26c9b26c9b26c9b26c9b26c9b29419637021da0#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "-0.1060606060606060606060606060606060606060606060876283450420764",
+        "-0x0.1b26c9b26c9b26c9b26c9b26c9b26c9b26c9b29419637021da0#200",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "-2.828019081094652272156665829776550338940497114",
+        "-0x2.d3f90ef9c3f807b678b47aa218cf3777bdab9#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "-2.828019081094652272156665829776550338940497111",
+        "-0x2.d3f90ef9c3f807b678b47aa218cf3777bdab8#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "-2.828019081094652272156665829776550338940497111",
+        "-0x2.d3f90ef9c3f807b678b47aa218cf3777bdab8#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "-2.828019081094652272156665829776550338940497114",
+        "-0x2.d3f90ef9c3f807b678b47aa218cf3777bdab9#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "-2.828019081094652272156665829776550338940497111",
+        "-0x2.d3f90ef9c3f807b678b47aa218cf3777bdab8#150",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn div_round_fail() {
+    assert_panic!(Float::from(3u32).div_round(Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).div_round_val_ref(&Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).div_round_ref_val(Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).div_round_ref_ref(&Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.div_round_assign(Float::from(1.0e-100), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.div_round_assign_ref(&Float::from(1.0e-100), RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_div_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = x.clone().div_prec_round(y.clone(), prec, rm);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_prec_round_val_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_round_assign(y.clone(), prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_round_assign_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) = rug_div_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Floor,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Ceiling,
+        "3.0e2",
+        "0x1.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Nearest,
+        "3.0e2",
+        "0x1.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "246.0",
+        "0xf6.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "246.0",
+        "0xf6.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "246.0",
+        "0xf6.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Exact,
+        "246.0",
+        "0xf6.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Floor,
+        "246.0",
+        "0xf6.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Ceiling,
+        "246.0",
+        "0xf6.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Nearest,
+        "246.0",
+        "0xf6.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Exact,
+        "246.0",
+        "0xf6.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "1.154",
+        "0x1.278#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.156",
+        "0x1.280#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "1.156",
+        "0x1.280#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Floor,
+        "1.155727349790921734322182875177",
+        "0x1.27ddbf6271dbe02c839f8eb68#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Ceiling,
+        "1.155727349790921734322182875179",
+        "0x1.27ddbf6271dbe02c839f8eb6a#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Nearest,
+        "1.155727349790921734322182875179",
+        "0x1.27ddbf6271dbe02c839f8eb6a#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "-2.223",
+        "-0x2.39#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "-2.219",
+        "-0x2.38#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "-2.223",
+        "-0x2.39#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "-2.221441469079182885058846603276",
+        "-0x2.38b0635bac5c740ce07a5c758#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "-2.221441469079182885058846603273",
+        "-0x2.38b0635bac5c740ce07a5c754#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "-2.221441469079182885058846603273",
+        "-0x2.38b0635bac5c740ce07a5c754#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Floor,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Ceiling,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "2.627e-31",
+        "0x5.54E-26#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.631e-31",
+        "0x5.56E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "2.631e-31",
+        "0x5.56E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Floor,
+        "2.629536350736705872070505183774e-31",
+        "0x5.5555555555554000000000000E-26#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Ceiling,
+        "2.629536350736705872070505183774e-31",
+        "0x5.5555555555554000000000000E-26#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Nearest,
+        "2.629536350736705872070505183774e-31",
+        "0x5.5555555555554000000000000E-26#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Exact,
+        "2.629536350736705872070505183774e-31",
+        "0x5.5555555555554000000000000E-26#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "2.508e-31",
+        "0x5.16E-26#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "2.511e-31",
+        "0x5.18E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "2.511e-31",
+        "0x5.18E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "2.511022249557423578941186476943e-31",
+        "0x5.17cc1b727220b79e27534dae8E-26#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "2.511022249557423578941186476946e-31",
+        "0x5.17cc1b727220b79e27534daf0E-26#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "2.511022249557423578941186476943e-31",
+        "0x5.17cc1b727220b79e27534dae8E-26#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Exact,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Exact,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Floor,
+        "-0.1",
+        "-0x0.2#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Ceiling,
+        "-0.06",
+        "-0x0.1#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Nearest,
+        "-0.1",
+        "-0x0.2#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Floor,
+        "-0.1061",
+        "-0x0.1b28#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Ceiling,
+        "-0.106",
+        "-0x0.1b20#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Nearest,
+        "-0.1061",
+        "-0x0.1b28#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Floor,
+        "-0.1060606060606060606060606060606",
+        "-0x0.1b26c9b26c9b26c9b26c9b26ca#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Ceiling,
+        "-0.1060606060606060606060606060605",
+        "-0x0.1b26c9b26c9b26c9b26c9b26c8#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Nearest,
+        "-0.1060606060606060606060606060606",
+        "-0x0.1b26c9b26c9b26c9b26c9b26ca#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "-2.828",
+        "-0x2.d4#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "-2.824",
+        "-0x2.d3#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "-2.828",
+        "-0x2.d4#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "-2.828019081094652272156665829778",
+        "-0x2.d3f90ef9c3f807b678b47aa24#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "-2.828019081094652272156665829775",
+        "-0x2.d3f90ef9c3f807b678b47aa20#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "-2.828019081094652272156665829775",
+        "-0x2.d3f90ef9c3f807b678b47aa20#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn div_prec_round_fail() {
+    assert_panic!(Float::ONE.div_prec_round(Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.div_prec_round_val_ref(&Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.div_prec_round_ref_val(Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.div_prec_round_ref_ref(&Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_prec_round_assign(Float::TWO, 0, RoundingMode::Floor)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_prec_round_assign_ref(&Float::TWO, 0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::ONE.div_prec_round(Float::from(1.0e-100), 1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.div_prec_round_val_ref(
+        &Float::from(1.0e-100),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.div_prec_round_ref_val(Float::from(1.0e-100), 1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.div_prec_round_ref_ref(
+        &Float::from(1.0e-100),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_prec_round_assign(Float::from(1.0e-100), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_prec_round_assign_ref(&Float::from(1.0e-100), 1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_div_rational() {
+    let test = |s, s_hex, t, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let quotient = x.clone() / y.clone();
+        assert!(quotient.is_valid());
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let quotient_alt = x.clone() / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        let quotient_alt = &x / y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        let quotient_alt = &x / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+
+        let mut quotient_alt = x.clone();
+        quotient_alt /= y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        let mut quotient_alt = x.clone();
+        quotient_alt /= &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+
+        let (rug_quotient, _) = rug_div_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::from(&y),
+            x.significant_bits(),
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+    };
+    test("NaN", "NaN", "1/3", "NaN", "NaN");
+    test("Infinity", "Infinity", "1/3", "Infinity", "Infinity");
+    test("Infinity", "Infinity", "-22/7", "-Infinity", "-Infinity");
+    test("Infinity", "Infinity", "0", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "1/3", "-Infinity", "-Infinity");
+    test("0.0", "0x0.0", "1/3", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "1/3", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "-22/7", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "0", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "0", "Infinity", "Infinity");
+    test("-1.0", "-0x1.0#1", "0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "1/3", "4.0", "0x4.0#1");
+    test("1.0", "0x1.0#1", "123", "0.008", "0x0.02#1");
+    test("123.0", "0x7b.0#7", "-1", "-123.0", "-0x7b.0#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        "9.424777960769379",
+        "0x9.6cbe3f9990e90#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        "-0.9995976625058433",
+        "-0x0.ffe5a1e59ee268#53",
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        "-4.242640687119286",
+        "-0x4.3e1db337db368#53",
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        "0.11111111111111111111111111111111111111111111111111111111111115",
+        "0x0.1c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c8#200",
+    );
+    test("1.111", "0x1.1c8#10", "9/10", "1.234", "0x1.3c0#10");
+    test("1.0e30", "0x1.0E+25#1", "1/3", "5.0e30", "0x4.0E+25#1");
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        "-2.0e-31",
+        "-0x4.0E-26#1",
+    );
+}
+
+#[test]
+fn test_div_rational_prec() {
+    let test = |s, s_hex, t, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (quotient, o) = x.clone().div_rational_prec(y.clone(), prec);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_rational_prec_val_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_rational_prec_ref_val(y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_rational_prec_ref_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_rational_prec_assign(y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_rational_prec_assign_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_quotient, rug_o) = rug_div_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "1/3", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", "1/3", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", "1/3", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", "1/3", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", "1/3", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", "1/3", 100, "0.0", "0x0.0", Ordering::Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", "0", 1, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", "0", 10, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", "0", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        10,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        100,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        1,
+        "0.008",
+        "0x0.02#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        10,
+        "0.00813",
+        "0x0.0215#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        100,
+        "0.00813008130081300813008130081301",
+        "0x0.0214d0214d0214d0214d0214d04#100",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        1,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        10,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        100,
+        "-123.0",
+        "-0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        "9.42",
+        "0x9.6c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        100,
+        "9.42477796076937934799389040563",
+        "0x9.6cbe3f9990e9000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        100,
+        "-0.999597662505843264181170194537",
+        "-0x0.ffe5a1e59ee268ba2e8ba2e8c#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        "-4.24",
+        "-0x4.3e#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        100,
+        "-4.242640687119285436423865576216",
+        "-0x4.3e1db337db367000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        10,
+        "0.1111",
+        "0x0.1c70#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        100,
+        "0.1111111111111111111111111111111",
+        "0x0.1c71c71c71c71c71c71c71c71c#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        10,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        100,
+        "1.234809027777777777777777777777",
+        "0x1.3c1c71c71c71c71c71c71c71c#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        "5.0e30",
+        "0x4.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        "3.803e30",
+        "0x3.00E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        100,
+        "3802951800684688204490109616128.0",
+        "0x30000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        1,
+        "-2.0e-31",
+        "-0x4.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        10,
+        "-2.511e-31",
+        "-0x5.18E-26#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        100,
+        "-2.510011971157764835400954525901e-31",
+        "-0x5.1745d1745d1745d1745d17460E-26#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn div_rational_prec_fail() {
+    assert_panic!(Float::ONE.div_rational_prec(Rational::ONE, 0));
+    assert_panic!(Float::ONE.div_rational_prec_val_ref(&Rational::ONE, 0));
+    assert_panic!(Float::ONE.div_rational_prec_ref_val(Rational::ONE, 0));
+    assert_panic!(Float::ONE.div_rational_prec_ref_ref(&Rational::ONE, 0));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_rational_prec_assign(Rational::ONE, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_rational_prec_assign_ref(&Rational::ONE, 0)
+    });
+}
+
+#[test]
+fn test_div_rational_round() {
+    let test = |s, s_hex, t, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (quotient, o) = x.clone().div_rational_round(y.clone(), rm);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_rational_round_val_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_rational_round_ref_val(y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_rational_round_ref_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_rational_round_assign(y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_rational_round_assign_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) = rug_div_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::from(&y),
+                x.significant_bits(),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        RoundingMode::Floor,
+        "0.008",
+        "0x0.02#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        RoundingMode::Ceiling,
+        "0.02",
+        "0x0.04#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        RoundingMode::Down,
+        "0.008",
+        "0x0.02#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        RoundingMode::Up,
+        "0.02",
+        "0x0.04#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        RoundingMode::Nearest,
+        "0.008",
+        "0x0.02#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Floor,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Ceiling,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Down,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Up,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Nearest,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Exact,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        RoundingMode::Floor,
+        "9.424777960769379",
+        "0x9.6cbe3f9990e90#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        RoundingMode::Ceiling,
+        "9.424777960769379",
+        "0x9.6cbe3f9990e90#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        RoundingMode::Down,
+        "9.424777960769379",
+        "0x9.6cbe3f9990e90#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        RoundingMode::Up,
+        "9.424777960769379",
+        "0x9.6cbe3f9990e90#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        RoundingMode::Nearest,
+        "9.424777960769379",
+        "0x9.6cbe3f9990e90#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        RoundingMode::Exact,
+        "9.424777960769379",
+        "0x9.6cbe3f9990e90#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Floor,
+        "-0.9995976625058434",
+        "-0x0.ffe5a1e59ee270#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "-0.9995976625058433",
+        "-0x0.ffe5a1e59ee268#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Down,
+        "-0.9995976625058433",
+        "-0x0.ffe5a1e59ee268#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Up,
+        "-0.9995976625058434",
+        "-0x0.ffe5a1e59ee270#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Nearest,
+        "-0.9995976625058433",
+        "-0x0.ffe5a1e59ee268#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Floor,
+        "-4.242640687119286",
+        "-0x4.3e1db337db368#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Ceiling,
+        "-4.242640687119285",
+        "-0x4.3e1db337db364#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Down,
+        "-4.242640687119285",
+        "-0x4.3e1db337db364#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Up,
+        "-4.242640687119286",
+        "-0x4.3e1db337db368#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Nearest,
+        "-4.242640687119286",
+        "-0x4.3e1db337db368#53",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        RoundingMode::Floor,
+        "0.11111111111111111111111111111111111111111111111111111111111115",
+        "0x0.1c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c8#200",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        RoundingMode::Ceiling,
+        "0.11111111111111111111111111111111111111111111111111111111111115",
+        "0x0.1c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c8#200",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        RoundingMode::Down,
+        "0.11111111111111111111111111111111111111111111111111111111111115",
+        "0x0.1c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c8#200",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        RoundingMode::Up,
+        "0.11111111111111111111111111111111111111111111111111111111111115",
+        "0x0.1c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c8#200",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        RoundingMode::Nearest,
+        "0.11111111111111111111111111111111111111111111111111111111111115",
+        "0x0.1c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c8#200",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        RoundingMode::Exact,
+        "0.11111111111111111111111111111111111111111111111111111111111115",
+        "0x0.1c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c8#200",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        RoundingMode::Floor,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        RoundingMode::Ceiling,
+        "1.236",
+        "0x1.3c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        RoundingMode::Down,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        RoundingMode::Up,
+        "1.236",
+        "0x1.3c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        RoundingMode::Nearest,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Floor,
+        "3.0e30",
+        "0x2.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Ceiling,
+        "5.0e30",
+        "0x4.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Down,
+        "3.0e30",
+        "0x2.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Up,
+        "5.0e30",
+        "0x4.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Nearest,
+        "5.0e30",
+        "0x4.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        RoundingMode::Floor,
+        "-4.0e-31",
+        "-0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "-2.0e-31",
+        "-0x4.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        RoundingMode::Down,
+        "-2.0e-31",
+        "-0x4.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        RoundingMode::Up,
+        "-4.0e-31",
+        "-0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        RoundingMode::Nearest,
+        "-2.0e-31",
+        "-0x4.0E-26#1",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn div_rational_round_fail() {
+    assert_panic!(
+        Float::ONE.div_rational_round(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    );
+    assert_panic!(Float::ONE
+        .div_rational_round_val_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!(Float::ONE
+        .div_rational_round_ref_val(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!(Float::ONE
+        .div_rational_round_ref_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_rational_round_assign_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_div_rational_prec_round() {
+    let test = |s, s_hex, t, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (quotient, o) = x.clone().div_rational_prec_round(y.clone(), prec, rm);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_rational_prec_round_val_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_rational_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = x.div_rational_prec_round_ref_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_rational_prec_round_assign(y.clone(), prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_rational_prec_round_assign_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) = rug_div_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        1,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        100,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        1,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        1,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        1,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        10,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        10,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        10,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        100,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        100,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        100,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        100,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        10,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        100,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        1,
+        RoundingMode::Floor,
+        "0.008",
+        "0x0.02#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        1,
+        RoundingMode::Ceiling,
+        "0.02",
+        "0x0.04#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        1,
+        RoundingMode::Nearest,
+        "0.008",
+        "0x0.02#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        10,
+        RoundingMode::Floor,
+        "0.00812",
+        "0x0.0214#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        10,
+        RoundingMode::Ceiling,
+        "0.00813",
+        "0x0.0215#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        10,
+        RoundingMode::Nearest,
+        "0.00813",
+        "0x0.0215#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        100,
+        RoundingMode::Floor,
+        "0.008130081300813008130081300813",
+        "0x0.0214d0214d0214d0214d0214d00#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        100,
+        RoundingMode::Ceiling,
+        "0.00813008130081300813008130081301",
+        "0x0.0214d0214d0214d0214d0214d04#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        100,
+        RoundingMode::Nearest,
+        "0.00813008130081300813008130081301",
+        "0x0.0214d0214d0214d0214d0214d04#100",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        1,
+        RoundingMode::Floor,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        1,
+        RoundingMode::Ceiling,
+        "-6.0e1",
+        "-0x4.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        1,
+        RoundingMode::Nearest,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        10,
+        RoundingMode::Floor,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        10,
+        RoundingMode::Ceiling,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        10,
+        RoundingMode::Nearest,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        10,
+        RoundingMode::Exact,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        100,
+        RoundingMode::Floor,
+        "-123.0",
+        "-0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        100,
+        RoundingMode::Ceiling,
+        "-123.0",
+        "-0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        100,
+        RoundingMode::Nearest,
+        "-123.0",
+        "-0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        100,
+        RoundingMode::Exact,
+        "-123.0",
+        "-0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "9.42",
+        "0x9.6c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "9.44",
+        "0x9.70#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "9.42",
+        "0x9.6c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "9.42477796076937934799389040563",
+        "0x9.6cbe3f9990e9000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "9.42477796076937934799389040563",
+        "0x9.6cbe3f9990e9000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "9.42477796076937934799389040563",
+        "0x9.6cbe3f9990e9000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        100,
+        RoundingMode::Exact,
+        "9.42477796076937934799389040563",
+        "0x9.6cbe3f9990e9000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Ceiling,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        100,
+        RoundingMode::Floor,
+        "-0.999597662505843264181170194537",
+        "-0x0.ffe5a1e59ee268ba2e8ba2e8c#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        100,
+        RoundingMode::Ceiling,
+        "-0.999597662505843264181170194536",
+        "-0x0.ffe5a1e59ee268ba2e8ba2e8b#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        100,
+        RoundingMode::Nearest,
+        "-0.999597662505843264181170194537",
+        "-0x0.ffe5a1e59ee268ba2e8ba2e8c#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "-8.0",
+        "-0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "-4.25",
+        "-0x4.40#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "-4.24",
+        "-0x4.3e#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "-4.24",
+        "-0x4.3e#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "-4.242640687119285436423865576216",
+        "-0x4.3e1db337db367000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "-4.242640687119285436423865576216",
+        "-0x4.3e1db337db367000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "-4.242640687119285436423865576216",
+        "-0x4.3e1db337db367000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        100,
+        RoundingMode::Exact,
+        "-4.242640687119285436423865576216",
+        "-0x4.3e1db337db367000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        1,
+        RoundingMode::Floor,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        1,
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        1,
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        10,
+        RoundingMode::Floor,
+        "0.1111",
+        "0x0.1c70#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        10,
+        RoundingMode::Ceiling,
+        "0.1112",
+        "0x0.1c78#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        10,
+        RoundingMode::Nearest,
+        "0.1111",
+        "0x0.1c70#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        100,
+        RoundingMode::Floor,
+        "0.1111111111111111111111111111111",
+        "0x0.1c71c71c71c71c71c71c71c71c#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        100,
+        RoundingMode::Ceiling,
+        "0.1111111111111111111111111111112",
+        "0x0.1c71c71c71c71c71c71c71c71e#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        100,
+        RoundingMode::Nearest,
+        "0.1111111111111111111111111111111",
+        "0x0.1c71c71c71c71c71c71c71c71c#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        10,
+        RoundingMode::Floor,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        10,
+        RoundingMode::Ceiling,
+        "1.236",
+        "0x1.3c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        10,
+        RoundingMode::Nearest,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        100,
+        RoundingMode::Floor,
+        "1.234809027777777777777777777777",
+        "0x1.3c1c71c71c71c71c71c71c71c#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        100,
+        RoundingMode::Ceiling,
+        "1.234809027777777777777777777779",
+        "0x1.3c1c71c71c71c71c71c71c71e#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        100,
+        RoundingMode::Nearest,
+        "1.234809027777777777777777777777",
+        "0x1.3c1c71c71c71c71c71c71c71c#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "3.0e30",
+        "0x2.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "5.0e30",
+        "0x4.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "5.0e30",
+        "0x4.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "3.803e30",
+        "0x3.00E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "3.803e30",
+        "0x3.00E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "3.803e30",
+        "0x3.00E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Exact,
+        "3.803e30",
+        "0x3.00E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "3802951800684688204490109616128.0",
+        "0x30000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "3802951800684688204490109616128.0",
+        "0x30000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "3802951800684688204490109616128.0",
+        "0x30000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        100,
+        RoundingMode::Exact,
+        "3802951800684688204490109616128.0",
+        "0x30000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        1,
+        RoundingMode::Floor,
+        "-4.0e-31",
+        "-0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        1,
+        RoundingMode::Ceiling,
+        "-2.0e-31",
+        "-0x4.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        1,
+        RoundingMode::Nearest,
+        "-2.0e-31",
+        "-0x4.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        10,
+        RoundingMode::Floor,
+        "-2.511e-31",
+        "-0x5.18E-26#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        10,
+        RoundingMode::Ceiling,
+        "-2.508e-31",
+        "-0x5.16E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        10,
+        RoundingMode::Nearest,
+        "-2.511e-31",
+        "-0x5.18E-26#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        100,
+        RoundingMode::Floor,
+        "-2.510011971157764835400954525901e-31",
+        "-0x5.1745d1745d1745d1745d17460E-26#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        100,
+        RoundingMode::Ceiling,
+        "-2.510011971157764835400954525898e-31",
+        "-0x5.1745d1745d1745d1745d17458E-26#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        100,
+        RoundingMode::Nearest,
+        "-2.510011971157764835400954525901e-31",
+        "-0x5.1745d1745d1745d1745d17460E-26#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn div_rational_prec_round_fail() {
+    assert_panic!(Float::ONE.div_rational_prec_round(Rational::ONE, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.div_rational_prec_round_val_ref(
+        &Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::ONE.div_rational_prec_round_ref_val(
+        Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::ONE.div_rational_prec_round_ref_ref(
+        &Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_rational_prec_round_assign(Rational::ONE, 0, RoundingMode::Floor)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_rational_prec_round_assign_ref(&Rational::ONE, 0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::ONE.div_rational_prec_round(
+        Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.div_rational_prec_round_val_ref(
+        &Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.div_rational_prec_round_ref_val(
+        Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.div_rational_prec_round_ref_ref(
+        &Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_rational_prec_round_assign(Rational::from_unsigneds(1u8, 3), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_rational_prec_round_assign_ref(
+            &Rational::from_unsigneds(1u8, 3),
+            1,
+            RoundingMode::Exact,
+        )
+    });
+}
+
+#[test]
+fn test_rational_div_float() {
+    let test = |s, t, t_hex, out: &str, out_hex: &str| {
+        let x = Rational::from_str(s).unwrap();
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let quotient = x.clone() / y.clone();
+        assert!(quotient.is_valid());
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let quotient_alt = x.clone() / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        let quotient_alt = &x / y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        let quotient_alt = &x / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+
+        let (rug_quotient, _) = rug_rational_div_float_prec_round(
+            &rug::Rational::from(&x),
+            &rug::Float::exact_from(&y),
+            y.significant_bits(),
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+    };
+    test("1/3", "NaN", "NaN", "NaN", "NaN");
+    test("1/3", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("-22/7", "Infinity", "Infinity", "-0.0", "-0x0.0");
+    test("0", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("1/3", "-Infinity", "-Infinity", "-0.0", "-0x0.0");
+    test("1/3", "0.0", "0x0.0", "Infinity", "Infinity");
+    test("1/3", "-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("-22/7", "0.0", "0x0.0", "-Infinity", "-Infinity");
+    test("0", "0.0", "0x0.0", "NaN", "NaN");
+    test("0", "1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("0", "-1.0", "-0x1.0#1", "-0.0", "-0x0.0");
+    test("1/3", "1.0", "0x1.0#1", "0.2", "0x0.4#1");
+    test("123", "1.0", "0x1.0#1", "1.0e2", "0x8.0E+1#1");
+    test("-1", "123.0", "0x7b.0#7", "-0.0082", "-0x0.0218#7");
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "0.1061032953945969",
+        "0x0.1b2995e7b7b604#53",
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.0004024994347707",
+        "-0x1.001a60d1e8b42#53",
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-0.23570226039551584",
+        "-0x0.3c56fbbbfdf4cc#53",
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "9.0",
+        "0x9.0000000000000000000000000000000000000000000000000#200",
+    );
+    test("9/10", "1.111", "0x1.1c8#10", "0.81", "0x0.cf4#10");
+    test("1/3", "1.0e30", "0x1.0E+25#1", "2.0e-31", "0x4.0E-26#1");
+    test("-22/7", "8.0e-31", "0x1.0E-25#1", "-5.0e30", "-0x4.0E+25#1");
+    test("0", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("0", "-0.0", "-0x0.0", "NaN", "NaN");
+}
+
+#[test]
+fn test_rational_div_float_prec() {
+    let test = |s, t, t_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = Float::rational_div_float_prec(x.clone(), y.clone(), prec);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = Float::rational_div_float_prec_val_ref(x.clone(), &y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = Float::rational_div_float_prec_ref_val(&x, y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = Float::rational_div_float_prec_ref_ref(&x, &y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_quotient, rug_o) = rug_rational_div_float_prec_round(
+            &rug::Rational::from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("1/3", "NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("1/3", "NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("1/3", "NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "0.0",
+        "0x0.0",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "0.0",
+        "0x0.0",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "0.0",
+        "0x0.0",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "0.0",
+        "0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "0.0",
+        "0x0.0",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "0.0",
+        "0x0.0",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("0", "0.0", "0x0.0", 1, "NaN", "NaN", Ordering::Equal);
+    test("0", "0.0", "0x0.0", 10, "NaN", "NaN", Ordering::Equal);
+    test("0", "0.0", "0x0.0", 100, "NaN", "NaN", Ordering::Equal);
+    test("0", "1.0", "0x1.0#1", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0", "1.0", "0x1.0#1", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0", "1.0", "0x1.0#1", 100, "0.0", "0x0.0", Ordering::Equal);
+    test(
+        "0",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test("1/3", "1.0", "0x1.0#1", 1, "0.2", "0x0.4#1", Ordering::Less);
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "-0.008",
+        "-0x0.02#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "-0.00813",
+        "-0x0.0215#10",
+        Ordering::Less,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "-0.00813008130081300813008130081301",
+        "-0x0.0214d0214d0214d0214d0214d04#100",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "0.1061",
+        "0x0.1b28#10",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "0.1061032953945968946486779500287",
+        "0x0.1b2995e7b7b603d34b7c66f3a4#100",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "-1.000402499434770720973249243128",
+        "-0x1.001a60d1e8b424115a02a61dc#100",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "-0.2356",
+        "-0x0.3c5#10",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "-0.2357022603955158253547925982802",
+        "-0x0.3c56fbbbfdf4cb02e653b12250#100",
+        Ordering::Less,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "9.0",
+        "0x9.00#10",
+        Ordering::Greater,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "9.0",
+        "0x9.000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "0.81",
+        "0x0.cf4#10",
+        Ordering::Less,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "0.8098418277680140597539543058",
+        "0x0.cf51cb45324cd8515817c15f5#100",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "2.631e-31",
+        "0x5.56E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "2.62953635073670601803909521761e-31",
+        "0x5.5555555555555555555555558E-26#100",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "-5.0e30",
+        "-0x4.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "-3.986e30",
+        "-0x3.25E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "-3984044743574435261846781502612.0",
+        "-0x32492492492492492492492494.0#100",
+        Ordering::Less,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test("0", "-0.0", "-0x0.0", 1, "NaN", "NaN", Ordering::Equal);
+    test("0", "-0.0", "-0x0.0", 10, "NaN", "NaN", Ordering::Equal);
+    test("0", "-0.0", "-0x0.0", 100, "NaN", "NaN", Ordering::Equal);
+}
+
+#[test]
+fn rational_div_float_prec_fail() {
+    assert_panic!(Float::rational_div_float_prec(Rational::ONE, Float::TWO, 0));
+    assert_panic!(Float::rational_div_float_prec_val_ref(
+        Rational::ONE,
+        &Float::TWO,
+        0
+    ));
+    assert_panic!(Float::rational_div_float_prec_ref_val(
+        &Rational::ONE,
+        Float::TWO,
+        0
+    ));
+    assert_panic!(Float::rational_div_float_prec_ref_ref(
+        &Rational::ONE,
+        &Float::TWO,
+        0
+    ));
+}
+
+#[test]
+fn test_rational_div_float_round() {
+    let test = |s, t, t_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = Float::rational_div_float_round(x.clone(), y.clone(), rm);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = Float::rational_div_float_round_val_ref(x.clone(), &y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = Float::rational_div_float_round_ref_val(&x, y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = Float::rational_div_float_round_ref_ref(&x, &y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) = rug_rational_div_float_prec_round(
+                &rug::Rational::from(&x),
+                &rug::Float::exact_from(&y),
+                y.significant_bits(),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "-0.0082",
+        "-0x0.0218#7",
+        Ordering::Less,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "-0.0081",
+        "-0x0.0210#7",
+        Ordering::Greater,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "-0.0081",
+        "-0x0.0210#7",
+        Ordering::Greater,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "-0.0082",
+        "-0x0.0218#7",
+        Ordering::Less,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "-0.0082",
+        "-0x0.0218#7",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "0.10610329539459688",
+        "0x0.1b2995e7b7b603#53",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "0.1061032953945969",
+        "0x0.1b2995e7b7b604#53",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "0.10610329539459688",
+        "0x0.1b2995e7b7b603#53",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "0.1061032953945969",
+        "0x0.1b2995e7b7b604#53",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "0.1061032953945969",
+        "0x0.1b2995e7b7b604#53",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-1.0004024994347709",
+        "-0x1.001a60d1e8b43#53",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-1.0004024994347707",
+        "-0x1.001a60d1e8b42#53",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-1.0004024994347707",
+        "-0x1.001a60d1e8b42#53",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-1.0004024994347709",
+        "-0x1.001a60d1e8b43#53",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-1.0004024994347707",
+        "-0x1.001a60d1e8b42#53",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "-0.23570226039551584",
+        "-0x0.3c56fbbbfdf4cc#53",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "-0.23570226039551581",
+        "-0x0.3c56fbbbfdf4ca#53",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "-0.23570226039551581",
+        "-0x0.3c56fbbbfdf4ca#53",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "-0.23570226039551584",
+        "-0x0.3c56fbbbfdf4cc#53",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "-0.23570226039551584",
+        "-0x0.3c56fbbbfdf4cc#53",
+        Ordering::Less,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "8.99999999999999999999999999999999999999999999999999999999999",
+        "0x8.fffffffffffffffffffffffffffffffffffffffffffffffff#200",
+        Ordering::Less,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "9.0",
+        "0x9.0000000000000000000000000000000000000000000000000#200",
+        Ordering::Greater,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "8.99999999999999999999999999999999999999999999999999999999999",
+        "0x8.fffffffffffffffffffffffffffffffffffffffffffffffff#200",
+        Ordering::Less,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "9.0",
+        "0x9.0000000000000000000000000000000000000000000000000#200",
+        Ordering::Greater,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "9.0",
+        "0x9.0000000000000000000000000000000000000000000000000#200",
+        Ordering::Greater,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "0.81",
+        "0x0.cf4#10",
+        Ordering::Less,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "0.811",
+        "0x0.cf8#10",
+        Ordering::Greater,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "0.81",
+        "0x0.cf4#10",
+        Ordering::Less,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "0.811",
+        "0x0.cf8#10",
+        Ordering::Greater,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "0.81",
+        "0x0.cf4#10",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "-5.0e30",
+        "-0x4.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "-3.0e30",
+        "-0x2.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "-3.0e30",
+        "-0x2.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "-5.0e30",
+        "-0x4.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "-5.0e30",
+        "-0x4.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn rational_div_float_round_fail() {
+    assert_panic!(Float::rational_div_float_round(
+        Rational::from_unsigneds(1u8, 3),
+        Float::ONE,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::rational_div_float_round_val_ref(
+        Rational::from_unsigneds(1u8, 3),
+        &Float::ONE,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::rational_div_float_round_ref_val(
+        &Rational::from_unsigneds(1u8, 3),
+        Float::ONE,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::rational_div_float_round_ref_ref(
+        &Rational::from_unsigneds(1u8, 3),
+        &Float::ONE,
+        RoundingMode::Exact
+    ));
+}
+
+#[test]
+fn test_rational_div_float_prec_round() {
+    let test = |s, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = Float::rational_div_float_prec_round(x.clone(), y.clone(), prec, rm);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) =
+            Float::rational_div_float_prec_round_val_ref(x.clone(), &y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) =
+            Float::rational_div_float_prec_round_ref_val(&x, y.clone(), prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (quotient_alt, o_alt) = Float::rational_div_float_prec_round_ref_ref(&x, &y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) = rug_rational_div_float_prec_round(
+                &rug::Rational::from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-22/7",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.333",
+        "0x0.554#10",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.3333333333333333333333333333331",
+        "0x0.55555555555555555555555550#100",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Floor,
+        "-0.02",
+        "-0x0.04#1",
+        Ordering::Less,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Ceiling,
+        "-0.008",
+        "-0x0.02#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Nearest,
+        "-0.008",
+        "-0x0.02#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Floor,
+        "-0.00813",
+        "-0x0.0215#10",
+        Ordering::Less,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "-0.00812",
+        "-0x0.0214#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Nearest,
+        "-0.00813",
+        "-0x0.0215#10",
+        Ordering::Less,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        RoundingMode::Floor,
+        "-0.00813008130081300813008130081301",
+        "-0x0.0214d0214d0214d0214d0214d04#100",
+        Ordering::Less,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        RoundingMode::Ceiling,
+        "-0.008130081300813008130081300813",
+        "-0x0.0214d0214d0214d0214d0214d00#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        RoundingMode::Nearest,
+        "-0.00813008130081300813008130081301",
+        "-0x0.0214d0214d0214d0214d0214d04#100",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "0.1061",
+        "0x0.1b28#10",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.1062",
+        "0x0.1b30#10",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "0.1061",
+        "0x0.1b28#10",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "0.1061032953945968946486779500286",
+        "0x0.1b2995e7b7b603d34b7c66f3a2#100",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.1061032953945968946486779500287",
+        "0x0.1b2995e7b7b603d34b7c66f3a4#100",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "0.1061032953945968946486779500287",
+        "0x0.1b2995e7b7b603d34b7c66f3a4#100",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-1.002",
+        "-0x1.008#10",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "-1.000402499434770720973249243128",
+        "-0x1.001a60d1e8b424115a02a61dc#100",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "-1.000402499434770720973249243126",
+        "-0x1.001a60d1e8b424115a02a61da#100",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "-1.000402499434770720973249243128",
+        "-0x1.001a60d1e8b424115a02a61dc#100",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "-0.1",
+        "-0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "-0.2358",
+        "-0x0.3c6#10",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.2356",
+        "-0x0.3c5#10",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.2356",
+        "-0x0.3c5#10",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "-0.2357022603955158253547925982802",
+        "-0x0.3c56fbbbfdf4cb02e653b12250#100",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "-0.23570226039551582535479259828",
+        "-0x0.3c56fbbbfdf4cb02e653b1224c#100",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "-0.2357022603955158253547925982802",
+        "-0x0.3c56fbbbfdf4cb02e653b12250#100",
+        Ordering::Less,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "8.98",
+        "0x8.fc#10",
+        Ordering::Less,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "9.0",
+        "0x9.00#10",
+        Ordering::Greater,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "9.0",
+        "0x9.00#10",
+        Ordering::Greater,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "8.99999999999999999999999999999",
+        "0x8.ffffffffffffffffffffffff#100",
+        Ordering::Less,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "9.0",
+        "0x9.000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "3",
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "9.0",
+        "0x9.000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "0.81",
+        "0x0.cf4#10",
+        Ordering::Less,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.811",
+        "0x0.cf8#10",
+        Ordering::Greater,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "0.81",
+        "0x0.cf4#10",
+        Ordering::Less,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "0.809841827768014059753954305799",
+        "0x0.cf51cb45324cd8515817c15f4#100",
+        Ordering::Less,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "0.8098418277680140597539543058",
+        "0x0.cf51cb45324cd8515817c15f5#100",
+        Ordering::Greater,
+    );
+    test(
+        "9/10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "0.8098418277680140597539543058",
+        "0x0.cf51cb45324cd8515817c15f5#100",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Floor,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Ceiling,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "2.627e-31",
+        "0x5.54E-26#10",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.631e-31",
+        "0x5.56E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "2.631e-31",
+        "0x5.56E-26#10",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Floor,
+        "2.629536350736706018039095217607e-31",
+        "0x5.5555555555555555555555550E-26#100",
+        Ordering::Less,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Ceiling,
+        "2.62953635073670601803909521761e-31",
+        "0x5.5555555555555555555555558E-26#100",
+        Ordering::Greater,
+    );
+    test(
+        "1/3",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Nearest,
+        "2.62953635073670601803909521761e-31",
+        "0x5.5555555555555555555555558E-26#100",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "-5.0e30",
+        "-0x4.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "-3.0e30",
+        "-0x2.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "-5.0e30",
+        "-0x4.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "-3.986e30",
+        "-0x3.25E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "-3.981e30",
+        "-0x3.24E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "-3.986e30",
+        "-0x3.25E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "-3984044743574435261846781502612.0",
+        "-0x32492492492492492492492494.0#100",
+        Ordering::Less,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "-3984044743574435261846781502608.0",
+        "-0x32492492492492492492492490.0#100",
+        Ordering::Greater,
+    );
+    test(
+        "-22/7",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "-3984044743574435261846781502612.0",
+        "-0x32492492492492492492492494.0#100",
+        Ordering::Less,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn rational_div_float_prec_round_fail() {
+    assert_panic!(Float::rational_div_float_prec_round(
+        Rational::ONE,
+        Float::TWO,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::rational_div_float_prec_round_val_ref(
+        Rational::ONE,
+        &Float::TWO,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::rational_div_float_prec_round_ref_val(
+        &Rational::ONE,
+        Float::TWO,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::rational_div_float_prec_round_ref_ref(
+        &Rational::ONE,
+        &Float::TWO,
+        0,
+        RoundingMode::Floor
+    ));
+
+    assert_panic!(Float::rational_div_float_prec_round(
+        Rational::from_unsigneds(1u8, 3),
+        Float::ONE,
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::rational_div_float_prec_round_val_ref(
+        Rational::from_unsigneds(1u8, 3),
+        &Float::ONE,
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::rational_div_float_prec_round_ref_val(
+        &Rational::from_unsigneds(1u8, 3),
+        Float::ONE,
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::rational_div_float_prec_round_ref_ref(
+        &Rational::from_unsigneds(1u8, 3),
+        &Float::ONE,
+        1,
+        RoundingMode::Exact
+    ));
+}
+
+#[test]
+fn div_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_4().test_properties(|(x, y, prec, rm)| {
+        let (quotient, o) = x.clone().div_prec_round(y.clone(), prec, rm);
+        assert!(quotient.is_valid());
+
+        let (quotient_alt, o_alt) = x.clone().div_prec_round_val_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_prec_round_assign(y.clone(), prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_prec_round_assign_ref(&y, prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        if quotient.is_finite() && x.is_finite() && y.is_finite() {
+            if quotient.is_normal() {
+                assert_eq!(quotient.get_prec(), Some(prec));
+            }
+            let r_quotient = Rational::exact_from(&x) / Rational::exact_from(&y);
+            assert_eq!(quotient.partial_cmp(&r_quotient), Some(o));
+            if o == Ordering::Less {
+                let mut next = quotient.clone();
+                next.increment();
+                assert!(next > r_quotient);
+            } else if o == Ordering::Greater {
+                let mut next = quotient.clone();
+                next.decrement();
+                assert!(next < r_quotient);
+            }
+            match (r_quotient >= 0u32, rm) {
+                (_, RoundingMode::Floor)
+                | (true, RoundingMode::Down)
+                | (false, RoundingMode::Up) => {
+                    assert_ne!(o, Ordering::Greater)
+                }
+                (_, RoundingMode::Ceiling)
+                | (true, RoundingMode::Up)
+                | (false, RoundingMode::Down) => {
+                    assert_ne!(o, Ordering::Less)
+                }
+                (_, RoundingMode::Exact) => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) = rug_div_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (mut quotient_alt, mut o_alt) = (-&x).div_prec_round_val_ref(&y, prec, -rm);
+        quotient_alt.neg_assign();
+        o_alt = o_alt.reverse();
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (mut quotient_alt, mut o_alt) = x.div_prec_round_ref_val(-&y, prec, -rm);
+        quotient_alt.neg_assign();
+        o_alt = o_alt.reverse();
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.div_prec_round_ref_ref(&y, prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(quotient.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.div_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+        }
+    });
+
+    float_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (quotient, o) = x.div_prec_round_ref_val(Float::NAN, prec, rm);
+        assert!(quotient.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (quotient, o) = Float::NAN.div_prec_round_val_ref(&x, prec, rm);
+        assert!(quotient.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        if x.is_finite() {
+            let (quotient, o) = x.div_prec_round_ref_val(Float::INFINITY, prec, rm);
+            assert_eq!(
+                ComparableFloat(quotient),
+                ComparableFloat(if x.is_sign_positive() {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                })
+            );
+            assert_eq!(o, Ordering::Equal);
+        } else {
+            assert!(x
+                .div_prec_round_ref_val(Float::INFINITY, prec, rm)
+                .0
+                .is_nan());
+        }
+        if x.is_nan() || x == 0u32 {
+            assert!(x.div_prec_round_ref_val(Float::ZERO, prec, rm).0.is_nan());
+        } else if x > 0u32 {
+            assert_eq!(
+                x.div_prec_round_ref_val(Float::ZERO, prec, rm),
+                (Float::INFINITY, Ordering::Equal)
+            );
+            assert_eq!(
+                x.div_prec_round_ref_val(Float::NEGATIVE_ZERO, prec, rm),
+                (Float::NEGATIVE_INFINITY, Ordering::Equal)
+            );
+        } else {
+            assert_eq!(
+                x.div_prec_round_ref_val(Float::ZERO, prec, rm),
+                (Float::NEGATIVE_INFINITY, Ordering::Equal)
+            );
+            assert_eq!(
+                x.div_prec_round_ref_val(Float::NEGATIVE_ZERO, prec, rm),
+                (Float::INFINITY, Ordering::Equal)
+            );
+        }
+        if x.is_finite() && x != 0u32 {
+            let (quotient, o) = Float::ZERO.div_prec_round_val_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(quotient),
+                ComparableFloat(if x.is_sign_positive() {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                })
+            );
+            assert_eq!(o, Ordering::Equal);
+        }
+
+        let (quotient, o) = x.div_prec_round_ref_val(Float::ONE, prec, rm);
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.set_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(quotient), ComparableFloat(quotient_alt));
+        assert_eq!(o, o_alt);
+
+        let (quotient, o) = x.div_prec_round_ref_val(Float::NEGATIVE_ONE, prec, rm);
+        let mut quotient_alt = -&x;
+        let o_alt = quotient_alt.set_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(quotient), ComparableFloat(quotient_alt));
+        assert_eq!(o, o_alt);
+    });
+}
+
+#[test]
+fn div_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (quotient, o) = x.clone().div_prec(y.clone(), prec);
+        assert!(quotient.is_valid());
+
+        let (quotient_alt, o_alt) = x.clone().div_prec_val_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_prec_ref_val(y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_prec_ref_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        if quotient.is_finite() && x.is_finite() && y.is_finite() {
+            if quotient.is_normal() {
+                assert_eq!(quotient.get_prec(), Some(prec));
+            }
+            let r_quotient = Rational::exact_from(&x) / Rational::exact_from(&y);
+            assert_eq!(quotient.partial_cmp(&r_quotient), Some(o));
+            if o == Ordering::Less {
+                let mut next = quotient.clone();
+                next.increment();
+                assert!(next > r_quotient);
+            } else if o == Ordering::Greater {
+                let mut next = quotient.clone();
+                next.decrement();
+                assert!(next < r_quotient);
+            }
+        }
+
+        let (rug_quotient, rug_o) = rug_div_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(rug_o, o);
+
+        let (quotient_alt, o_alt) = (-&x).div_prec(-&y, prec);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn div_round_properties_helper(x: Float, y: Float) {
+    for rm in exhaustive_rounding_modes() {
+        let prec = max(x.significant_bits(), y.significant_bits());
+        let (_, floor_o) = x.div_prec_round_ref_ref(&y, prec, RoundingMode::Floor);
+        if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+            assert_panic!(x.div_round_ref_ref(&y, rm));
+            continue;
+        }
+
+        let (quotient, o) = x.clone().div_round(y.clone(), rm);
+        assert!(quotient.is_valid());
+
+        let (quotient_alt, o_alt) = x.clone().div_round_val_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_round_ref_val(y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_round_ref_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) = rug_div_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    }
+}
+
+#[test]
+fn div_round_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        div_round_properties_helper(x, y);
+    });
+
+    float_pair_gen_var_1().test_properties(|(x, y)| {
+        div_round_properties_helper(x, y);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn div_properties_helper(x: Float, y: Float) {
+    let quotient = x.clone() / y.clone();
+    assert!(quotient.is_valid());
+
+    let quotient_alt = x.clone() / &y;
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let quotient_alt = &x / y.clone();
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let quotient_alt = &x / &y;
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let mut x_alt = x.clone();
+    x_alt /= y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+
+    let mut x_alt = x.clone();
+    x_alt /= &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+
+    let (quotient_alt, _) = x.div_round_ref_ref(&y, RoundingMode::Nearest);
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let (rug_quotient, _) = rug_div_prec_round(
+        &rug::Float::exact_from(&x),
+        &rug::Float::exact_from(&y),
+        max(x.significant_bits(), y.significant_bits()),
+        rug::float::Round::Nearest,
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_quotient)),
+        ComparableFloatRef(&quotient)
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&-(-&x / &y)),
+        ComparableFloatRef(&quotient)
+    );
+    assert_eq!(
+        ComparableFloatRef(&-(&x / -&y)),
+        ComparableFloatRef(&quotient)
+    );
+}
+
+#[test]
+fn div_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        div_properties_helper(x, y);
+    });
+
+    float_pair_gen_var_1().test_properties(|(x, y)| {
+        div_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        assert!((&x / Float::NAN).is_nan());
+        assert!((Float::NAN / &x).is_nan());
+        if x.is_finite() {
+            assert_eq!(
+                ComparableFloat(&x / Float::INFINITY),
+                ComparableFloat(if x.is_sign_positive() {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                })
+            );
+            assert_eq!(
+                ComparableFloat(&x / Float::NEGATIVE_INFINITY),
+                ComparableFloat(if x.is_sign_positive() {
+                    Float::NEGATIVE_ZERO
+                } else {
+                    Float::ZERO
+                })
+            );
+        } else {
+            assert!((&x / Float::INFINITY).is_nan());
+            assert!((&x / Float::NEGATIVE_INFINITY).is_nan());
+        }
+        if x.is_nan() || x == 0u32 {
+            assert!((&x / Float::ZERO).is_nan());
+            assert!((&x / Float::NEGATIVE_ZERO).is_nan());
+        } else if x > 0u32 {
+            assert_eq!(&x / Float::ZERO, Float::INFINITY);
+            assert_eq!(&x / Float::NEGATIVE_ZERO, Float::NEGATIVE_INFINITY);
+        } else {
+            assert_eq!(&x / Float::ZERO, Float::NEGATIVE_INFINITY);
+            assert_eq!(&x / Float::NEGATIVE_ZERO, Float::INFINITY);
+        }
+        if x.is_finite() && x != 0u32 {
+            assert_eq!(
+                ComparableFloat(Float::ZERO / &x),
+                ComparableFloat(if x.is_sign_positive() {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                })
+            );
+            assert_eq!(&x / &x, 1u32);
+        }
+        assert_eq!(
+            ComparableFloatRef(&(&x / Float::ONE)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(
+            ComparableFloat(&x / Float::NEGATIVE_ONE),
+            ComparableFloat(-&x)
+        );
+    });
+
+    float_pair_gen().test_properties(|(x, y)| {
+        if x.is_finite() && y.is_finite() && y != 0u32 {
+            // A product whose precision is the sum of the factors' precisions is exact, so
+            // dividing it by one of the factors recovers the other factor exactly.
+            let (product, o) = x.mul_prec_round_ref_ref(
+                &y,
+                x.significant_bits() + y.significant_bits(),
+                RoundingMode::Nearest,
+            );
+            assert_eq!(o, Ordering::Equal);
+            let (quotient, o) =
+                product.div_prec_round(y, x.significant_bits(), RoundingMode::Nearest);
+            assert_eq!(o, Ordering::Equal);
+            assert_eq!(quotient, x);
+        }
+    });
+}
+
+#[test]
+fn div_rational_prec_round_properties() {
+    float_rational_unsigned_rounding_mode_quadruple_gen_var_4().test_properties(
+        |(x, y, prec, rm)| {
+            let (quotient, o) = x.clone().div_rational_prec_round(y.clone(), prec, rm);
+            assert!(quotient.is_valid());
+
+            let (quotient_alt, o_alt) = x.clone().div_rational_prec_round_val_ref(&y, prec, rm);
+            assert!(quotient_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&quotient_alt),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(o_alt, o);
+
+            let (quotient_alt, o_alt) = x.div_rational_prec_round_ref_val(y.clone(), prec, rm);
+            assert!(quotient_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&quotient_alt),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(o_alt, o);
+
+            let (quotient_alt, o_alt) = x.div_rational_prec_round_ref_ref(&y, prec, rm);
+            assert!(quotient_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&quotient_alt),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.div_rational_prec_round_assign(y.clone(), prec, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.div_rational_prec_round_assign_ref(&y, prec, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+            assert_eq!(o_alt, o);
+
+            if quotient.is_finite() && x.is_finite() {
+                if quotient.is_normal() {
+                    assert_eq!(quotient.get_prec(), Some(prec));
+                }
+                let r_quotient = Rational::exact_from(&x) / &y;
+                assert_eq!(quotient.partial_cmp(&r_quotient), Some(o));
+                if o == Ordering::Less {
+                    let mut next = quotient.clone();
+                    next.increment();
+                    assert!(next > r_quotient);
+                } else if o == Ordering::Greater {
+                    let mut next = quotient.clone();
+                    next.decrement();
+                    assert!(next < r_quotient);
+                }
+                match (r_quotient >= 0u32, rm) {
+                    (_, RoundingMode::Floor)
+                    | (true, RoundingMode::Down)
+                    | (false, RoundingMode::Up) => {
+                        assert_ne!(o, Ordering::Greater)
+                    }
+                    (_, RoundingMode::Ceiling)
+                    | (true, RoundingMode::Up)
+                    | (false, RoundingMode::Down) => {
+                        assert_ne!(o, Ordering::Less)
+                    }
+                    (_, RoundingMode::Exact) => assert_eq!(o, Ordering::Equal),
+                    _ => {}
+                }
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_quotient, rug_o) = rug_div_rational_prec_round(
+                    &rug::Float::exact_from(&x),
+                    &rug::Rational::exact_from(&y),
+                    prec,
+                    rug_rm,
+                );
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_quotient)),
+                    ComparableFloatRef(&quotient)
+                );
+                assert_eq!(rug_o, o);
+            }
+
+            if x.is_finite() && y != 0u32 {
+                let (y_alt, y_o) =
+                    Float::from_rational_prec_round_ref(&y, 4096, RoundingMode::Floor);
+                if y_o == Ordering::Equal {
+                    let (quotient_alt, o_alt) = x.div_prec_round_ref_val(y_alt, prec, rm);
+                    assert_eq!(
+                        ComparableFloatRef(&quotient_alt),
+                        ComparableFloatRef(&quotient)
+                    );
+                    assert_eq!(o_alt, o);
+                }
+            }
+
+            if o == Ordering::Equal {
+                for rm in exhaustive_rounding_modes() {
+                    let (s, oo) = x.div_rational_prec_round_ref_ref(&y, prec, rm);
+                    assert_eq!(
+                        ComparableFloat(s.abs_negative_zero()),
+                        ComparableFloat(quotient.abs_negative_zero_ref())
+                    );
+                    assert_eq!(oo, Ordering::Equal);
+                }
+            } else {
+                assert_panic!(x.div_rational_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+            }
+        },
+    );
+}
+
+#[test]
+fn div_rational_prec_properties() {
+    float_rational_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (quotient, o) = x.clone().div_rational_prec(y.clone(), prec);
+        assert!(quotient.is_valid());
+
+        let (quotient_alt, o_alt) = x.clone().div_rational_prec_val_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_rational_prec_ref_val(y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_rational_prec_ref_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_rational_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_rational_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) =
+            x.div_rational_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        if quotient.is_finite() && x.is_finite() {
+            let r_quotient = Rational::exact_from(&x) / &y;
+            assert_eq!(quotient.partial_cmp(&r_quotient), Some(o));
+        }
+
+        let (rug_quotient, rug_o) = rug_div_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn div_rational_round_properties_helper(x: Float, y: Rational) {
+    for rm in exhaustive_rounding_modes() {
+        let prec = x.significant_bits();
+        let (_, floor_o) = x.div_rational_prec_round_ref_ref(&y, prec, RoundingMode::Floor);
+        if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+            assert_panic!(x.div_rational_round_ref_ref(&y, rm));
+            continue;
+        }
+
+        let (quotient, o) = x.clone().div_rational_round(y.clone(), rm);
+        assert!(quotient.is_valid());
+
+        let (quotient_alt, o_alt) = x.clone().div_rational_round_val_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_rational_round_ref_val(y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_rational_round_ref_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_rational_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_rational_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_rational_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) = rug_div_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    }
+}
+
+#[test]
+fn div_rational_round_properties() {
+    float_rational_pair_gen().test_properties(|(x, y)| {
+        div_rational_round_properties_helper(x, y);
+    });
+
+    float_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        div_rational_round_properties_helper(x, y);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn div_rational_properties_helper(x: Float, y: Rational) {
+    let quotient = x.clone() / y.clone();
+    assert!(quotient.is_valid());
+
+    let quotient_alt = x.clone() / &y;
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let quotient_alt = &x / y.clone();
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let quotient_alt = &x / &y;
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let mut x_alt = x.clone();
+    x_alt /= y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+
+    let mut x_alt = x.clone();
+    x_alt /= &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+
+    let (quotient_alt, _) = x.div_rational_round_ref_ref(&y, RoundingMode::Nearest);
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let (rug_quotient, _) = rug_div_rational_prec_round(
+        &rug::Float::exact_from(&x),
+        &rug::Rational::exact_from(&y),
+        x.significant_bits(),
+        rug::float::Round::Nearest,
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_quotient)),
+        ComparableFloatRef(&quotient)
+    );
+}
+
+#[test]
+fn div_rational_properties() {
+    float_rational_pair_gen().test_properties(|(x, y)| {
+        div_rational_properties_helper(x, y);
+    });
+
+    float_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        div_rational_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        if x.is_nan() || x == 0u32 {
+            assert!((&x / Rational::ZERO).is_nan());
+        } else if x.is_sign_positive() {
+            assert_eq!(&x / Rational::ZERO, Float::INFINITY);
+        } else {
+            assert_eq!(&x / Rational::ZERO, Float::NEGATIVE_INFINITY);
+        }
+        assert_eq!(
+            ComparableFloatRef(&(&x / Rational::ONE)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(
+            ComparableFloat(&x / Rational::NEGATIVE_ONE),
+            ComparableFloat(-&x)
+        );
+    });
+
+    rational_gen().test_properties(|x| {
+        assert!((Float::NAN / x.clone()).is_nan());
+        assert!((x.clone() / Float::NAN).is_nan());
+        if x > 0u32 {
+            assert_eq!(Float::INFINITY / &x, Float::INFINITY);
+            assert_eq!(&x / Float::ZERO, Float::INFINITY);
+        } else if x < 0u32 {
+            assert_eq!(Float::INFINITY / &x, Float::NEGATIVE_INFINITY);
+            assert_eq!(&x / Float::ZERO, Float::NEGATIVE_INFINITY);
+        }
+    });
+}
+
+#[test]
+fn div_natural_properties() {
+    float_natural_pair_gen().test_properties(|(x, y)| {
+        let quotient = x.clone() / y.clone();
+        assert!(quotient.is_valid());
+
+        let quotient_alt = x.clone() / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let quotient_alt = &x / y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let quotient_alt = &x / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let mut x_alt = x.clone();
+        x_alt /= y.clone();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+
+        let mut x_alt = x.clone();
+        x_alt /= &y;
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+
+        let (quotient_alt, _) =
+            x.div_rational_round_ref_val(Rational::from(&y), RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let quotient = y.clone() / x.clone();
+        assert!(quotient.is_valid());
+
+        let quotient_alt = y.clone() / &x;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let quotient_alt = &y / x.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let quotient_alt = &y / &x;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let (quotient_alt, _) =
+            Float::rational_div_float_round(Rational::from(&y), x, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+    });
+}
+
+#[test]
+fn div_integer_properties() {
+    float_integer_pair_gen().test_properties(|(x, y)| {
+        let quotient = x.clone() / y.clone();
+        assert!(quotient.is_valid());
+
+        let quotient_alt = x.clone() / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let quotient_alt = &x / y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let quotient_alt = &x / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let mut x_alt = x.clone();
+        x_alt /= y.clone();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+
+        let mut x_alt = x.clone();
+        x_alt /= &y;
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+
+        let (quotient_alt, _) =
+            x.div_rational_round_ref_val(Rational::from(&y), RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let quotient = y.clone() / x.clone();
+        assert!(quotient.is_valid());
+
+        let quotient_alt = y.clone() / &x;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let quotient_alt = &y / x.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let quotient_alt = &y / &x;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+
+        let (quotient_alt, _) =
+            Float::rational_div_float_round(Rational::from(&y), x, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+    });
+}
+
+#[test]
+fn rational_div_float_prec_round_properties() {
+    float_rational_unsigned_rounding_mode_quadruple_gen_var_5().test_properties(
+        |(y, x, prec, rm)| {
+            let (quotient, o) =
+                Float::rational_div_float_prec_round(x.clone(), y.clone(), prec, rm);
+            assert!(quotient.is_valid());
+
+            let (quotient_alt, o_alt) =
+                Float::rational_div_float_prec_round_val_ref(x.clone(), &y, prec, rm);
+            assert!(quotient_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&quotient_alt),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(o_alt, o);
+
+            let (quotient_alt, o_alt) =
+                Float::rational_div_float_prec_round_ref_val(&x, y.clone(), prec, rm);
+            assert!(quotient_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&quotient_alt),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(o_alt, o);
+
+            let (quotient_alt, o_alt) =
+                Float::rational_div_float_prec_round_ref_ref(&x, &y, prec, rm);
+            assert!(quotient_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&quotient_alt),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(o_alt, o);
+
+            if quotient.is_finite() && y.is_finite() && y != 0u32 {
+                if quotient.is_normal() {
+                    assert_eq!(quotient.get_prec(), Some(prec));
+                }
+                let r_quotient = &x / Rational::exact_from(&y);
+                assert_eq!(quotient.partial_cmp(&r_quotient), Some(o));
+                if o == Ordering::Less {
+                    let mut next = quotient.clone();
+                    next.increment();
+                    assert!(next > r_quotient);
+                } else if o == Ordering::Greater {
+                    let mut next = quotient.clone();
+                    next.decrement();
+                    assert!(next < r_quotient);
+                }
+                match (r_quotient >= 0u32, rm) {
+                    (_, RoundingMode::Floor)
+                    | (true, RoundingMode::Down)
+                    | (false, RoundingMode::Up) => {
+                        assert_ne!(o, Ordering::Greater)
+                    }
+                    (_, RoundingMode::Ceiling)
+                    | (true, RoundingMode::Up)
+                    | (false, RoundingMode::Down) => {
+                        assert_ne!(o, Ordering::Less)
+                    }
+                    (_, RoundingMode::Exact) => assert_eq!(o, Ordering::Equal),
+                    _ => {}
+                }
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_quotient, rug_o) = rug_rational_div_float_prec_round(
+                    &rug::Rational::exact_from(&x),
+                    &rug::Float::exact_from(&y),
+                    prec,
+                    rug_rm,
+                );
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_quotient)),
+                    ComparableFloatRef(&quotient)
+                );
+                assert_eq!(rug_o, o);
+            }
+
+            if y.is_finite() && x != 0u32 {
+                let (x_alt, x_o) =
+                    Float::from_rational_prec_round_ref(&x, 4096, RoundingMode::Floor);
+                if x_o == Ordering::Equal {
+                    let (quotient_alt, o_alt) = x_alt.div_prec_round_val_ref(&y, prec, rm);
+                    assert_eq!(
+                        ComparableFloatRef(&quotient_alt),
+                        ComparableFloatRef(&quotient)
+                    );
+                    assert_eq!(o_alt, o);
+                }
+            }
+
+            if o == Ordering::Equal {
+                for rm in exhaustive_rounding_modes() {
+                    let (s, oo) = Float::rational_div_float_prec_round_ref_ref(&x, &y, prec, rm);
+                    assert_eq!(
+                        ComparableFloat(s.abs_negative_zero()),
+                        ComparableFloat(quotient.abs_negative_zero_ref())
+                    );
+                    assert_eq!(oo, Ordering::Equal);
+                }
+            } else {
+                assert_panic!(Float::rational_div_float_prec_round_ref_ref(
+                    &x,
+                    &y,
+                    prec,
+                    RoundingMode::Exact
+                ));
+            }
+        },
+    );
+}
+
+#[test]
+fn rational_div_float_prec_properties() {
+    float_rational_unsigned_triple_gen_var_1().test_properties(|(y, x, prec)| {
+        let (quotient, o) = Float::rational_div_float_prec(x.clone(), y.clone(), prec);
+        assert!(quotient.is_valid());
+
+        let (quotient_alt, o_alt) = Float::rational_div_float_prec_val_ref(x.clone(), &y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = Float::rational_div_float_prec_ref_val(&x, y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = Float::rational_div_float_prec_ref_ref(&x, &y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) =
+            Float::rational_div_float_prec_round_ref_ref(&x, &y, prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        if quotient.is_finite() && y.is_finite() && y != 0u32 {
+            let r_quotient = &x / Rational::exact_from(&y);
+            assert_eq!(quotient.partial_cmp(&r_quotient), Some(o));
+        }
+
+        let (rug_quotient, rug_o) = rug_rational_div_float_prec_round(
+            &rug::Rational::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn rational_div_float_round_properties_helper(x: Rational, y: Float) {
+    for rm in exhaustive_rounding_modes() {
+        let prec = y.significant_bits();
+        let (_, floor_o) =
+            Float::rational_div_float_prec_round_ref_ref(&x, &y, prec, RoundingMode::Floor);
+        if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+            assert_panic!(Float::rational_div_float_round_ref_ref(&x, &y, rm));
+            continue;
+        }
+
+        let (quotient, o) = Float::rational_div_float_round(x.clone(), y.clone(), rm);
+        assert!(quotient.is_valid());
+
+        let (quotient_alt, o_alt) = Float::rational_div_float_round_val_ref(x.clone(), &y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = Float::rational_div_float_round_ref_val(&x, y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = Float::rational_div_float_round_ref_ref(&x, &y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = Float::rational_div_float_prec_round_ref_ref(&x, &y, prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) = rug_rational_div_float_prec_round(
+                &rug::Rational::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    }
+}
+
+#[test]
+fn rational_div_float_round_properties() {
+    float_rational_pair_gen().test_properties(|(y, x)| {
+        rational_div_float_round_properties_helper(x, y);
+    });
+
+    float_rational_pair_gen_var_1().test_properties(|(y, x)| {
+        rational_div_float_round_properties_helper(x, y);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn rational_div_float_properties_helper(x: Rational, y: Float) {
+    let quotient = x.clone() / y.clone();
+    assert!(quotient.is_valid());
+
+    let quotient_alt = x.clone() / &y;
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let quotient_alt = &x / y.clone();
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let quotient_alt = &x / &y;
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let (quotient_alt, _) = Float::rational_div_float_round_ref_ref(&x, &y, RoundingMode::Nearest);
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let (rug_quotient, _) = rug_rational_div_float_prec_round(
+        &rug::Rational::exact_from(&x),
+        &rug::Float::exact_from(&y),
+        y.significant_bits(),
+        rug::float::Round::Nearest,
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_quotient)),
+        ComparableFloatRef(&quotient)
+    );
+}
+
+#[test]
+fn rational_div_float_properties() {
+    float_rational_pair_gen().test_properties(|(y, x)| {
+        rational_div_float_properties_helper(x, y);
+    });
+
+    float_rational_pair_gen_var_1().test_properties(|(y, x)| {
+        rational_div_float_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        if x.is_nan() || x == 0u32 {
+            assert!((Rational::ZERO / &x).is_nan());
+        } else {
+            assert_eq!(
+                ComparableFloat(Rational::ZERO / &x),
+                ComparableFloat(if x.is_sign_positive() {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                })
+            );
+        }
+        if x.is_finite() {
+            let (quotient, o) = Float::rational_div_float_prec_round_val_ref(
+                Rational::ONE,
+                &x,
+                1,
+                RoundingMode::Floor,
+            );
+            let (quotient_alt, o_alt) = x.reciprocal_prec_round_ref(1, RoundingMode::Floor);
+            assert_eq!(ComparableFloat(quotient), ComparableFloat(quotient_alt));
+            assert_eq!(o, o_alt);
+        }
+    });
+}
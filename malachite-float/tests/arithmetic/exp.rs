@@ -0,0 +1,2049 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{CountOnes, SignificantBits};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_5,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_exp_prec_round(x: &rug::Float, prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.exp_ref(), rm)
+}
+
+// MPFR, with its default exponent range, overflows or underflows for larger inputs, and
+// Malachite's results are only compared against it when it doesn't.
+fn in_mpfr_range(x: &Float) -> bool {
+    x.get_exponent().map_or(true, |e| e <= 24)
+}
+
+#[test]
+fn test_exp_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_prec(prec);
+        assert!(exp.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+
+        let (exp_alt, o_alt) = x.exp_prec_ref(prec);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_prec_assign(prec);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            let (rug_exp, rug_o) = rug_exp_prec_round(
+                &rug::Float::exact_from(&x),
+                prec,
+                rug::float::Round::Nearest,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp)),
+                ComparableFloatRef(&exp)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Ordering::Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.2", "0x0.4#1", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "0.3677",
+        "0x0.5e2#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.3678794411714423215955237701614",
+        "0x0.5e2d58d8b3bcdf1abadec78290#100",
+        Ordering::Less,
+    );
+    test("0.5", "0x0.8#1", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("0.5", "0x0.8#1", 10, "1.648", "0x1.a60#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.648721270700128146848650787814",
+        "0x1.a61298e1e069bc972dfefab6e#100",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "2.0e53",
+        "0x2.0E+44#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "2.619e53",
+        "0x2.bcE+44#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "2.619517318749062676188981025375e53",
+        "0x2.bc22a5f3502327274223b3e68E+44#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "23.16",
+        "0x17.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "23.14069263277926617181157008936",
+        "0x17.24046eb09338d2991a30893e#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "15.16",
+        "0xf.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "15.15426224147926199898921968819",
+        "0xf.277dbaf2293cdcf6ff773788#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "0.2432",
+        "0x0.3e4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "0.2431167344342141873020545086561",
+        "0x0.3e3ce5f781592d8fd235460a64#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "1.395612425086089502804107856543",
+        "0x1.6546db1ba2d12f2e20f2f4f98#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "1.395612425086089528628125319603",
+        "0x1.6546db1ba2d1310a7f6c6f280#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "0.03",
+        "0x0.08#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "0.04315",
+        "0x0.0b0c#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "0.04315930926145259798459209453519",
+        "0x0.0b0c7d0dcbbf5eacdfe7f9da0b#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "3.039",
+        "0x3.0a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "3.038391079040129213394497645783",
+        "0x3.09d3ff6cef7d52f4831306c48#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn exp_prec_fail() {
+    assert_panic!(Float::NAN.exp_prec(0));
+    assert_panic!(Float::NAN.exp_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.exp_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_exp_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_round(rm);
+        assert!(exp.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+
+        let (exp_alt, o_alt) = x.exp_round_ref(rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_round_assign(rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_exp, rug_o) =
+                    rug_exp_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_exp)),
+                    ComparableFloatRef(&exp)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "2.6e53",
+        "0x2.b8E+44#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "2.63e53",
+        "0x2.c0E+44#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "2.6e53",
+        "0x2.b8E+44#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "2.63e53",
+        "0x2.c0E+44#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "2.63e53",
+        "0x2.c0E+44#7",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "23.140692632779263",
+        "0x17.24046eb09338#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "23.140692632779263",
+        "0x17.24046eb09338#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "15.154262241479261",
+        "0xf.277dbaf2293c8#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "15.154262241479262",
+        "0xf.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "15.154262241479261",
+        "0xf.277dbaf2293c8#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "15.154262241479262",
+        "0xf.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "15.154262241479262",
+        "0xf.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "0.24311673443421417",
+        "0x0.3e3ce5f781592c#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "0.24311673443421419",
+        "0x0.3e3ce5f781592e#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "0.24311673443421417",
+        "0x0.3e3ce5f781592c#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "0.24311673443421419",
+        "0x0.3e3ce5f781592e#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "0.24311673443421419",
+        "0x0.3e3ce5f781592e#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "1.3956124250860893",
+        "0x1.6546db1ba2d12#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "1.3956124250860893",
+        "0x1.6546db1ba2d12#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "1.395612425086089528628125319602586837597906515199406982617516",
+        "0x1.6546db1ba2d1310a7f6c6f27f69e8540872869a64c7e4f8c7c#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "1.395612425086089528628125319602586837597906515199406982617517",
+        "0x1.6546db1ba2d1310a7f6c6f27f69e8540872869a64c7e4f8c7e#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "1.395612425086089528628125319602586837597906515199406982617516",
+        "0x1.6546db1ba2d1310a7f6c6f27f69e8540872869a64c7e4f8c7c#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "1.395612425086089528628125319602586837597906515199406982617517",
+        "0x1.6546db1ba2d1310a7f6c6f27f69e8540872869a64c7e4f8c7e#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "1.395612425086089528628125319602586837597906515199406982617517",
+        "0x1.6546db1ba2d1310a7f6c6f27f69e8540872869a64c7e4f8c7e#200",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "0.04315930926145259798459209453517597115968278907",
+        "0x0.0b0c7d0dcbbf5eacdfe7f9da0aada1bb7930bd8#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "0.04315930926145259798459209453517597115968278912",
+        "0x0.0b0c7d0dcbbf5eacdfe7f9da0aada1bb7930bdc#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "0.04315930926145259798459209453517597115968278907",
+        "0x0.0b0c7d0dcbbf5eacdfe7f9da0aada1bb7930bd8#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "0.04315930926145259798459209453517597115968278912",
+        "0x0.0b0c7d0dcbbf5eacdfe7f9da0aada1bb7930bdc#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "0.04315930926145259798459209453517597115968278912",
+        "0x0.0b0c7d0dcbbf5eacdfe7f9da0aada1bb7930bdc#150",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "3.035",
+        "0x3.09#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "3.039",
+        "0x3.0a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "3.035",
+        "0x3.09#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "3.039",
+        "0x3.0a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "3.039",
+        "0x3.0a#10",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn exp_round_fail() {
+    assert_panic!(Float::from(3u32).exp_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).exp_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.exp_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_exp_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_prec_round(prec, rm);
+        assert!(exp.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_prec_round_assign(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_exp, rug_o) = rug_exp_prec_round(&rug::Float::exact_from(&x), prec, rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_exp)),
+                    ComparableFloatRef(&exp)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "2.715",
+        "0x2.b7#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "2.718281828459045235360287471354",
+        "0x2.b7e151628aed2a6abf715880c#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.3677",
+        "0x0.5e2#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.3682",
+        "0x0.5e4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.3677",
+        "0x0.5e2#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.3678794411714423215955237701614",
+        "0x0.5e2d58d8b3bcdf1abadec78290#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.3678794411714423215955237701618",
+        "0x0.5e2d58d8b3bcdf1abadec78298#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.3678794411714423215955237701614",
+        "0x0.5e2d58d8b3bcdf1abadec78290#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "23.12",
+        "0x17.20#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "23.16",
+        "0x17.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "23.16",
+        "0x17.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "23.14069263277926617181157008936",
+        "0x17.24046eb09338d2991a30893e#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "23.14069263277926617181157008938",
+        "0x17.24046eb09338d2991a308940#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "23.14069263277926617181157008936",
+        "0x17.24046eb09338d2991a30893e#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "0.2429",
+        "0x0.3e3#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.2432",
+        "0x0.3e4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "0.2432",
+        "0x0.3e4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "0.2431167344342141873020545086561",
+        "0x0.3e3ce5f781592d8fd235460a64#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.2431167344342141873020545086563",
+        "0x0.3e3ce5f781592d8fd235460a68#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "0.2431167344342141873020545086561",
+        "0x0.3e3ce5f781592d8fd235460a64#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "1.395",
+        "0x1.650#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "1.395612425086089528628125319601",
+        "0x1.6546db1ba2d1310a7f6c6f27e#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "1.395612425086089528628125319603",
+        "0x1.6546db1ba2d1310a7f6c6f280#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "1.395612425086089528628125319603",
+        "0x1.6546db1ba2d1310a7f6c6f280#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "3.035",
+        "0x3.09#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "3.039",
+        "0x3.0a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "3.039",
+        "0x3.0a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "3.038391079040129213394497645783",
+        "0x3.09d3ff6cef7d52f4831306c48#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "3.038391079040129213394497645786",
+        "0x3.09d3ff6cef7d52f4831306c4c#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "3.038391079040129213394497645783",
+        "0x3.09d3ff6cef7d52f4831306c48#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn exp_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).exp_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).exp_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.exp_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).exp_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).exp_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.exp_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_exp_prec_round_overflow_and_underflow() {
+    // $e^x$ is treated as overflowing for $x \geq 2^{62}$ and as underflowing for
+    // $x \leq -2^{62}$. Rounding toward zero gives the largest finite `Float` with the target
+    // precision.
+    let x = Float::power_of_2(62u64);
+    for rm in exhaustive_rounding_modes() {
+        if rm == RoundingMode::Exact {
+            assert_panic!(x.exp_prec_round_ref(10, rm));
+            continue;
+        }
+        let (y, o) = x.exp_prec_round_ref(10, rm);
+        assert!(y.is_valid());
+        let (y_alt, o_alt) = x.clone().exp_prec_round(10, rm);
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&y));
+        assert_eq!(o_alt, o);
+        if rm == RoundingMode::Floor || rm == RoundingMode::Down {
+            assert_eq!(y.get_exponent(), Some(i64::MAX));
+            assert_eq!(y.get_prec(), Some(10));
+            assert_eq!(y.to_significand().unwrap().count_ones(), 10);
+            assert_eq!(o, Ordering::Less);
+        } else {
+            assert_eq!(ComparableFloat(y), ComparableFloat(Float::INFINITY));
+            assert_eq!(o, Ordering::Greater);
+        }
+    }
+
+    // Rounding away from zero gives the smallest positive `Float` with the target precision.
+    let x = -x;
+    for rm in exhaustive_rounding_modes() {
+        if rm == RoundingMode::Exact {
+            assert_panic!(x.exp_prec_round_ref(10, rm));
+            continue;
+        }
+        let (y, o) = x.exp_prec_round_ref(10, rm);
+        assert!(y.is_valid());
+        let (y_alt, o_alt) = x.clone().exp_prec_round(10, rm);
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&y));
+        assert_eq!(o_alt, o);
+        if rm == RoundingMode::Ceiling || rm == RoundingMode::Up {
+            assert_eq!(y.get_exponent(), Some(i64::MIN));
+            assert_eq!(y.get_prec(), Some(10));
+            assert_eq!(y.to_significand().unwrap().count_ones(), 1);
+            assert_eq!(o, Ordering::Greater);
+        } else {
+            assert_eq!(ComparableFloat(y), ComparableFloat(Float::ZERO));
+            assert_eq!(o, Ordering::Less);
+        }
+    }
+}
+
+#[test]
+fn exp_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (exp, o) = x.clone().exp_prec_round(prec, rm);
+        assert!(exp.is_valid());
+
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && exp.is_normal() {
+            assert_eq!(exp.get_prec(), Some(prec));
+            // $e^x$ is positive, and is at least 1 if $x$ is positive and at most 1 otherwise.
+            assert!(exp > 0u32 && if x > 0u32 { exp >= 1u32 } else { exp <= 1u32 });
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if exp > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if exp > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.exp_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.exp_prec_round_ref(prec, RoundingMode::Ceiling);
+                if ceiling.is_infinite() {
+                    // On overflow, `floor` is the largest finite `Float`.
+                    assert_eq!(floor.get_exponent(), Some(i64::MAX));
+                } else if floor == 0u32 {
+                    // On underflow, `ceiling` is the smallest positive `Float`.
+                    assert_eq!(ceiling.get_exponent(), Some(i64::MIN));
+                } else if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if in_mpfr_range(&x) {
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_exp, rug_o) =
+                    rug_exp_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_exp)),
+                    ComparableFloatRef(&exp)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.exp_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(exp.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.exp_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn exp_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (exp, o) = x.clone().exp_prec(prec);
+        assert!(exp.is_valid());
+
+        let (exp_alt, o_alt) = x.exp_prec_ref(prec);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        if in_mpfr_range(&x) {
+            let (rug_exp, rug_o) = rug_exp_prec_round(
+                &rug::Float::exact_from(&x),
+                prec,
+                rug::float::Round::Nearest,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp)),
+                ComparableFloatRef(&exp)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn exp_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.exp_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.exp_round_ref(rm));
+                continue;
+            }
+
+            let (exp, o) = x.clone().exp_round(rm);
+            assert!(exp.is_valid());
+
+            let (exp_alt, o_alt) = x.exp_round_ref(rm);
+            assert!(exp_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.exp_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+            assert_eq!(o_alt, o);
+
+            let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+            assert_eq!(o_alt, o);
+
+            if in_mpfr_range(&x) {
+                if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                    let (rug_exp, rug_o) =
+                        rug_exp_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                    assert_eq!(
+                        ComparableFloatRef(&Float::from(&rug_exp)),
+                        ComparableFloatRef(&exp)
+                    );
+                    assert_eq!(rug_o, o);
+                }
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (exp, o) = x.exp_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(exp), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::INFINITY);
+    test_special(Float::NEGATIVE_INFINITY, Float::ZERO);
+    test_special(Float::ZERO, Float::ONE);
+    test_special(Float::NEGATIVE_ZERO, Float::ONE);
+}
@@ -0,0 +1,2380 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{CountOnes, SignificantBits};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_7,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_exp10_prec_round(
+    x: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.exp10_ref(), rm)
+}
+
+// MPFR, with its default exponent range, overflows or underflows for larger inputs, and
+// Malachite's results are only compared against it when it doesn't.
+fn in_mpfr_range(x: &Float) -> bool {
+    x.get_exponent().map_or(true, |e| e <= 24)
+}
+
+#[test]
+fn test_exp10_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp10, o) = x.clone().exp10_prec(prec);
+        assert!(exp10.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp10.to_string(), out);
+        assert_eq!(to_hex_string(&exp10), out_hex);
+
+        let (exp10_alt, o_alt) = x.exp10_prec_ref(prec);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp10_alt = x.clone();
+        let o_alt = exp10_alt.exp10_prec_assign(prec);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            let (rug_exp10, rug_o) = rug_exp10_prec_round(
+                &rug::Float::exact_from(&x),
+                prec,
+                rug::float::Round::Nearest,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp10)),
+                ComparableFloatRef(&exp10)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Ordering::Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "8.0", "0x8.0#1", Ordering::Less);
+    test("1.0", "0x1.0#1", 10, "10.0", "0xa.00#10", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "10.0",
+        "0xa.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.1", "0x0.2#1", Ordering::Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "0.09998",
+        "0x0.1998#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.1",
+        "0x0.1999999999999999999999999a#100",
+        Ordering::Greater,
+    );
+    test("0.5", "0x0.8#1", 1, "4.0", "0x4.0#1", Ordering::Greater);
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        "3.164",
+        "0x3.2a#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "3.162277660168379331998893544431",
+        "0x3.298b075b4b6a5240945790618#100",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "1.0e123",
+        "0x2.0E+102#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.001e123",
+        "0x1.838E+102#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "9.999999999999999999999999999997e122",
+        "0x1.83425a5f872f126e00a5ad62cE+102#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1386.0",
+        "0x56a.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1385.455731367010698462705619402",
+        "0x569.74aacf951283c59a6deee40#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "5.0e2",
+        "0x2.0E+2#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "523.0",
+        "0x20b.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "522.7352996704364809010943192324",
+        "0x20a.bc3c996548c57e66a44d548#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "0.03",
+        "0x0.08#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "0.03851",
+        "0x0.09dc#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "0.03852888470032201757182031492201",
+        "0x0.09dd076bbd3eb0af3c759931ad#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "2.156",
+        "0x2.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "2.154434690031883629966623784704",
+        "0x2.278908270e09d2b3fdba57a80#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "2.156",
+        "0x2.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "2.154434690031883721759293566518",
+        "0x2.278908270e09d951445ae49bc#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "0.0005",
+        "0x0.002#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "0.00072",
+        "0x0.002f3#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "0.0007196856730011520199287864249634",
+        "0x0.002f2a526dcefdf7473078287568#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "12.92",
+        "0xc.ec#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "12.92195204543633211400528910001",
+        "0xc.ec050c9ba11d3e7e99b92e16#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-3.0", "-0x3.0#2", 1, "0.001", "0x0.004#1", Ordering::Less);
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        "0.000999",
+        "0x0.00418#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        "0.001000000000000000000000000000001",
+        "0x0.004189374bc6a7ef9db22d0e5608#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn exp10_prec_fail() {
+    assert_panic!(Float::NAN.exp10_prec(0));
+    assert_panic!(Float::NAN.exp10_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.exp10_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_exp10_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp10, o) = x.clone().exp10_round(rm);
+        assert!(exp10.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp10.to_string(), out);
+        assert_eq!(to_hex_string(&exp10), out_hex);
+
+        let (exp10_alt, o_alt) = x.exp10_round_ref(rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp10_alt = x.clone();
+        let o_alt = exp10_alt.exp10_round_assign(rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_exp10, rug_o) =
+                    rug_exp10_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_exp10)),
+                    ComparableFloatRef(&exp10)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "9.9e122",
+        "0x1.80E+102#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "1.0e123",
+        "0x1.84E+102#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "9.9e122",
+        "0x1.80E+102#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "1.0e123",
+        "0x1.84E+102#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "1.0e123",
+        "0x1.84E+102#7",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1385.4557313670107",
+        "0x569.74aacf95128#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1385.4557313670109",
+        "0x569.74aacf9512c#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1385.4557313670107",
+        "0x569.74aacf95128#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1385.4557313670109",
+        "0x569.74aacf9512c#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1385.4557313670107",
+        "0x569.74aacf95128#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "522.7352996704365",
+        "0x20a.bc3c996548c#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "522.7352996704366",
+        "0x20a.bc3c996548e#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "522.7352996704365",
+        "0x20a.bc3c996548c#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "522.7352996704366",
+        "0x20a.bc3c996548e#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "522.7352996704365",
+        "0x20a.bc3c996548c#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "0.038528884700322015",
+        "0x0.09dd076bbd3eb08#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "0.038528884700322022",
+        "0x0.09dd076bbd3eb10#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "0.038528884700322015",
+        "0x0.09dd076bbd3eb08#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "0.038528884700322022",
+        "0x0.09dd076bbd3eb10#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "0.038528884700322015",
+        "0x0.09dd076bbd3eb08#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "2.1544346900318834",
+        "0x2.278908270e09c#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "2.1544346900318838",
+        "0x2.278908270e09e#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "2.1544346900318834",
+        "0x2.278908270e09c#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "2.1544346900318838",
+        "0x2.278908270e09e#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "2.1544346900318838",
+        "0x2.278908270e09e#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "2.154434690031883721759293566519350495259344942192108582489235",
+        "0x2.278908270e09d951445ae49bd41233e7fa91197db36756b158#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "2.154434690031883721759293566519350495259344942192108582489238",
+        "0x2.278908270e09d951445ae49bd41233e7fa91197db36756b15c#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "2.154434690031883721759293566519350495259344942192108582489235",
+        "0x2.278908270e09d951445ae49bd41233e7fa91197db36756b158#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "2.154434690031883721759293566519350495259344942192108582489238",
+        "0x2.278908270e09d951445ae49bd41233e7fa91197db36756b15c#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "2.154434690031883721759293566519350495259344942192108582489235",
+        "0x2.278908270e09d951445ae49bd41233e7fa91197db36756b158#200",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "0.000719685673001152019928786424963456939222985243",
+        "0x0.002f2a526dcefdf747307828756823261a5e26c9#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "0.0007196856730011520199287864249634569392229852436",
+        "0x0.002f2a526dcefdf747307828756823261a5e26ca#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "0.000719685673001152019928786424963456939222985243",
+        "0x0.002f2a526dcefdf747307828756823261a5e26c9#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "0.0007196856730011520199287864249634569392229852436",
+        "0x0.002f2a526dcefdf747307828756823261a5e26ca#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "0.0007196856730011520199287864249634569392229852436",
+        "0x0.002f2a526dcefdf747307828756823261a5e26ca#150",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "12.92",
+        "0xc.ec#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "12.94",
+        "0xc.f0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "12.92",
+        "0xc.ec#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "12.94",
+        "0xc.f0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "12.92",
+        "0xc.ec#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "8.0e2",
+        "0x3.0E+2#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.0e3",
+        "0x4.0E+2#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "8.0e2",
+        "0x3.0E+2#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.0e3",
+        "0x4.0E+2#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "1.0e3",
+        "0x4.0E+2#2",
+        Ordering::Greater,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Floor,
+        "0.00098",
+        "0x0.004#2",
+        Ordering::Less,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Ceiling,
+        "0.0015",
+        "0x0.006#2",
+        Ordering::Greater,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Down,
+        "0.00098",
+        "0x0.004#2",
+        Ordering::Less,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Up,
+        "0.0015",
+        "0x0.006#2",
+        Ordering::Greater,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Nearest,
+        "0.00098",
+        "0x0.004#2",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn exp10_round_fail() {
+    assert_panic!(Float::from(3u32).exp10_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).exp10_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.exp10_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_exp10_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp10, o) = x.clone().exp10_prec_round(prec, rm);
+        assert!(exp10.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp10.to_string(), out);
+        assert_eq!(to_hex_string(&exp10), out_hex);
+
+        let (exp10_alt, o_alt) = x.exp10_prec_round_ref(prec, rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp10_alt = x.clone();
+        let o_alt = exp10_alt.exp10_prec_round_assign(prec, rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_exp10, rug_o) =
+                    rug_exp10_prec_round(&rug::Float::exact_from(&x), prec, rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_exp10)),
+                    ComparableFloatRef(&exp10)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "10.0",
+        "0xa.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "10.0",
+        "0xa.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "10.0",
+        "0xa.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "10.0",
+        "0xa.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.09998",
+        "0x0.1998#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.1001",
+        "0x0.19a0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.09998",
+        "0x0.1998#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.0999999999999999999999999999999",
+        "0x0.19999999999999999999999998#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.1999999999999999999999999a#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.1999999999999999999999999a#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e3",
+        "0x8.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1384.0",
+        "0x568.0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1386.0",
+        "0x56a.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1386.0",
+        "0x56a.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "1385.455731367010698462705619402",
+        "0x569.74aacf951283c59a6deee40#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "1385.455731367010698462705619404",
+        "0x569.74aacf951283c59a6deee48#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "1385.455731367010698462705619402",
+        "0x569.74aacf951283c59a6deee40#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "0.03",
+        "0x0.08#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "0.03",
+        "0x0.08#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "0.03851",
+        "0x0.09dc#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.03857",
+        "0x0.09e0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "0.03851",
+        "0x0.09dc#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "0.03852888470032201757182031492196",
+        "0x0.09dd076bbd3eb0af3c759931ac#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.03852888470032201757182031492201",
+        "0x0.09dd076bbd3eb0af3c759931ad#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "0.03852888470032201757182031492201",
+        "0x0.09dd076bbd3eb0af3c759931ad#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "2.152",
+        "0x2.27#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "2.156",
+        "0x2.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "2.156",
+        "0x2.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "2.154434690031883721759293566518",
+        "0x2.278908270e09d951445ae49bc#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "2.154434690031883721759293566522",
+        "0x2.278908270e09d951445ae49c0#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "2.154434690031883721759293566518",
+        "0x2.278908270e09d951445ae49bc#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "12.92",
+        "0xc.ec#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "12.94",
+        "0xc.f0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "12.92",
+        "0xc.ec#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "12.92195204543633211400528910001",
+        "0xc.ec050c9ba11d3e7e99b92e16#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "12.92195204543633211400528910003",
+        "0xc.ec050c9ba11d3e7e99b92e17#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "12.92195204543633211400528910001",
+        "0xc.ec050c9ba11d3e7e99b92e16#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "5.0e2",
+        "0x2.0E+2#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Exact,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "0.001",
+        "0x0.004#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "0.002",
+        "0x0.008#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "0.001",
+        "0x0.004#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "0.000999",
+        "0x0.00418#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "0.001001",
+        "0x0.0041a#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "0.000999",
+        "0x0.00418#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "0.000999999999999999999999999999999",
+        "0x0.004189374bc6a7ef9db22d0e5600#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "0.001000000000000000000000000000001",
+        "0x0.004189374bc6a7ef9db22d0e5608#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "0.001000000000000000000000000000001",
+        "0x0.004189374bc6a7ef9db22d0e5608#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn exp10_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).exp10_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).exp10_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.exp10_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).exp10_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).exp10_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.exp10_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_exp10_prec_round_overflow_and_underflow() {
+    // $10^x$ is treated as overflowing for $x \geq 2^{61}$ and as underflowing for
+    // $x \leq -2^{61}$. Rounding toward zero gives the largest finite `Float` with the target
+    // precision.
+    let x = Float::power_of_2(61u64);
+    for rm in exhaustive_rounding_modes() {
+        if rm == RoundingMode::Exact {
+            assert_panic!(x.exp10_prec_round_ref(10, rm));
+            continue;
+        }
+        let (y, o) = x.exp10_prec_round_ref(10, rm);
+        assert!(y.is_valid());
+        let (y_alt, o_alt) = x.clone().exp10_prec_round(10, rm);
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&y));
+        assert_eq!(o_alt, o);
+        if rm == RoundingMode::Floor || rm == RoundingMode::Down {
+            assert_eq!(y.get_exponent(), Some(i64::MAX));
+            assert_eq!(y.get_prec(), Some(10));
+            assert_eq!(y.to_significand().unwrap().count_ones(), 10);
+            assert_eq!(o, Ordering::Less);
+        } else {
+            assert_eq!(ComparableFloat(y), ComparableFloat(Float::INFINITY));
+            assert_eq!(o, Ordering::Greater);
+        }
+    }
+
+    // Rounding away from zero gives the smallest positive `Float` with the target precision.
+    let x = -x;
+    for rm in exhaustive_rounding_modes() {
+        if rm == RoundingMode::Exact {
+            assert_panic!(x.exp10_prec_round_ref(10, rm));
+            continue;
+        }
+        let (y, o) = x.exp10_prec_round_ref(10, rm);
+        assert!(y.is_valid());
+        let (y_alt, o_alt) = x.clone().exp10_prec_round(10, rm);
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&y));
+        assert_eq!(o_alt, o);
+        if rm == RoundingMode::Ceiling || rm == RoundingMode::Up {
+            assert_eq!(y.get_exponent(), Some(i64::MIN));
+            assert_eq!(y.get_prec(), Some(10));
+            assert_eq!(y.to_significand().unwrap().count_ones(), 1);
+            assert_eq!(o, Ordering::Greater);
+        } else {
+            assert_eq!(ComparableFloat(y), ComparableFloat(Float::ZERO));
+            assert_eq!(o, Ordering::Less);
+        }
+    }
+}
+
+#[test]
+fn exp10_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_7().test_properties(|(x, prec, rm)| {
+        let (exp10, o) = x.clone().exp10_prec_round(prec, rm);
+        assert!(exp10.is_valid());
+
+        let (exp10_alt, o_alt) = x.exp10_prec_round_ref(prec, rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp10_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && exp10.is_normal() {
+            assert_eq!(exp10.get_prec(), Some(prec));
+            // $10^x$ is positive, and is at least 1 if $x$ is positive and at most 1 otherwise.
+            assert!(
+                exp10 > 0u32
+                    && if x > 0u32 {
+                        exp10 >= 1u32
+                    } else {
+                        exp10 <= 1u32
+                    }
+            );
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if exp10 > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if exp10 > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.exp10_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.exp10_prec_round_ref(prec, RoundingMode::Ceiling);
+                if ceiling.is_infinite() {
+                    // On overflow, `floor` is the largest finite `Float`.
+                    assert_eq!(floor.get_exponent(), Some(i64::MAX));
+                } else if floor == 0u32 {
+                    // On underflow, `ceiling` is the smallest positive `Float`.
+                    assert_eq!(ceiling.get_exponent(), Some(i64::MIN));
+                } else if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if in_mpfr_range(&x) {
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_exp10, rug_o) =
+                    rug_exp10_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_exp10)),
+                    ComparableFloatRef(&exp10)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.exp10_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(exp10.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.exp10_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn exp10_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (exp10, o) = x.clone().exp10_prec(prec);
+        assert!(exp10.is_valid());
+
+        let (exp10_alt, o_alt) = x.exp10_prec_ref(prec);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp10_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        let (exp10_alt, o_alt) = x.exp10_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        if in_mpfr_range(&x) {
+            let (rug_exp10, rug_o) = rug_exp10_prec_round(
+                &rug::Float::exact_from(&x),
+                prec,
+                rug::float::Round::Nearest,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp10)),
+                ComparableFloatRef(&exp10)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn exp10_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.exp10_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.exp10_round_ref(rm));
+                continue;
+            }
+
+            let (exp10, o) = x.clone().exp10_round(rm);
+            assert!(exp10.is_valid());
+
+            let (exp10_alt, o_alt) = x.exp10_round_ref(rm);
+            assert!(exp10_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.exp10_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp10));
+            assert_eq!(o_alt, o);
+
+            let (exp10_alt, o_alt) = x.exp10_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+            assert_eq!(o_alt, o);
+
+            if in_mpfr_range(&x) {
+                if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                    let (rug_exp10, rug_o) =
+                        rug_exp10_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                    assert_eq!(
+                        ComparableFloatRef(&Float::from(&rug_exp10)),
+                        ComparableFloatRef(&exp10)
+                    );
+                    assert_eq!(rug_o, o);
+                }
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (exp10, o) = x.exp10_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(exp10), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::INFINITY);
+    test_special(Float::NEGATIVE_INFINITY, Float::ZERO);
+    test_special(Float::ZERO, Float::ONE);
+    test_special(Float::NEGATIVE_ZERO, Float::ONE);
+}
@@ -0,0 +1,2445 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{CountOnes, SignificantBits};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_6,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_exp2_prec_round(x: &rug::Float, prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.exp2_ref(), rm)
+}
+
+// MPFR, with its default exponent range, overflows or underflows for larger inputs, and
+// Malachite's results are only compared against it when it doesn't.
+fn in_mpfr_range(x: &Float) -> bool {
+    x.get_exponent().map_or(true, |e| e <= 24)
+}
+
+#[test]
+fn test_exp2_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp2, o) = x.clone().exp2_prec(prec);
+        assert!(exp2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp2.to_string(), out);
+        assert_eq!(to_hex_string(&exp2), out_hex);
+
+        let (exp2_alt, o_alt) = x.exp2_prec_ref(prec);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp2_alt = x.clone();
+        let o_alt = exp2_alt.exp2_prec_assign(prec);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            let (rug_exp2, rug_o) = rug_exp2_prec_round(
+                &rug::Float::exact_from(&x),
+                prec,
+                rug::float::Round::Nearest,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp2)),
+                ComparableFloatRef(&exp2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Ordering::Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test("1.0", "0x1.0#1", 10, "2.0", "0x2.00#10", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.5", "0x0.8#1", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 10, "0.5", "0x0.800#10", Ordering::Equal);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("0.5", "0x0.8#1", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test("0.5", "0x0.8#1", 10, "1.414", "0x1.6a0#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "1.0e37",
+        "0x8.0E+30#1",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.063e37",
+        "0x8.00E+30#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.063382396627932698323045648224e37",
+        "0x8.000000000000000000000000E+30#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "8.83",
+        "0x8.d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "8.82497782707628687473894169658",
+        "0x8.d331bf3337c170dfcdee149f#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "6.58",
+        "0x6.94#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "6.580885991017920311416449878895",
+        "0x6.94b4f1be206373370bd63ee70#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "0.375",
+        "0x0.600#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "0.3752142272464817485304892728879",
+        "0x0.600e0a23047e3cf618ae71c068#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "1.26",
+        "0x1.428#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "1.259921049894873148607716059939",
+        "0x1.428a2f98d728acf826cc8664c#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "1.26",
+        "0x1.428#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "1.259921049894873164767210607278",
+        "0x1.428a2f98d728ae223ddab715c#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "0.1132",
+        "0x0.1cf8#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "0.1132154580329883339492716091518",
+        "0x0.1cfbb031a741a49ef5da021f0a#100",
+        Ordering::Less,
+    );
+    test("1.111", "0x1.1c8#10", 1, "2.0", "0x2.0#1", Ordering::Less);
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "2.16",
+        "0x2.29#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "2.16044443293733576234453848297",
+        "0x2.2912e2e84a86fdfd1be3a07f8#100",
+        Ordering::Less,
+    );
+    test("3.0", "0x3.0#2", 1, "8.0", "0x8.0#1", Ordering::Equal);
+    test("3.0", "0x3.0#2", 10, "8.0", "0x8.00#10", Ordering::Equal);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-3.0", "-0x3.0#2", 1, "0.1", "0x0.2#1", Ordering::Equal);
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        "0.125",
+        "0x0.200#10",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        "0.125",
+        "0x0.20000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn exp2_prec_fail() {
+    assert_panic!(Float::NAN.exp2_prec(0));
+    assert_panic!(Float::NAN.exp2_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.exp2_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_exp2_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp2, o) = x.clone().exp2_round(rm);
+        assert!(exp2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp2.to_string(), out);
+        assert_eq!(to_hex_string(&exp2), out_hex);
+
+        let (exp2_alt, o_alt) = x.exp2_round_ref(rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp2_alt = x.clone();
+        let o_alt = exp2_alt.exp2_round_assign(rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_exp2, rug_o) =
+                    rug_exp2_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_exp2)),
+                    ComparableFloatRef(&exp2)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "1.06e37",
+        "0x8.0E+30#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "1.06e37",
+        "0x8.0E+30#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "1.06e37",
+        "0x8.0E+30#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "1.06e37",
+        "0x8.0E+30#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "1.06e37",
+        "0x8.0E+30#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Exact,
+        "1.06e37",
+        "0x8.0E+30#7",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "8.824977827076285",
+        "0x8.d331bf3337c10#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "8.824977827076287",
+        "0x8.d331bf3337c18#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "8.824977827076285",
+        "0x8.d331bf3337c10#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "8.824977827076287",
+        "0x8.d331bf3337c18#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "8.824977827076287",
+        "0x8.d331bf3337c18#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "6.5808859910179196",
+        "0x6.94b4f1be20634#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "6.5808859910179205",
+        "0x6.94b4f1be20638#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "6.5808859910179196",
+        "0x6.94b4f1be20634#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "6.5808859910179205",
+        "0x6.94b4f1be20638#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "6.5808859910179205",
+        "0x6.94b4f1be20638#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "0.37521422724648174",
+        "0x0.600e0a23047e3c#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "0.37521422724648179",
+        "0x0.600e0a23047e40#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "0.37521422724648174",
+        "0x0.600e0a23047e3c#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "0.37521422724648179",
+        "0x0.600e0a23047e40#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "0.37521422724648174",
+        "0x0.600e0a23047e3c#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "1.259921049894873",
+        "0x1.428a2f98d728a#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "1.2599210498948732",
+        "0x1.428a2f98d728b#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "1.259921049894873",
+        "0x1.428a2f98d728a#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "1.2599210498948732",
+        "0x1.428a2f98d728b#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "1.2599210498948732",
+        "0x1.428a2f98d728b#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "1.259921049894873164767210607278228350570251464701507980081974",
+        "0x1.428a2f98d728ae223ddab715be250d0c288f10291631fbc060#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "1.259921049894873164767210607278228350570251464701507980081975",
+        "0x1.428a2f98d728ae223ddab715be250d0c288f10291631fbc062#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "1.259921049894873164767210607278228350570251464701507980081974",
+        "0x1.428a2f98d728ae223ddab715be250d0c288f10291631fbc060#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "1.259921049894873164767210607278228350570251464701507980081975",
+        "0x1.428a2f98d728ae223ddab715be250d0c288f10291631fbc062#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "1.259921049894873164767210607278228350570251464701507980081975",
+        "0x1.428a2f98d728ae223ddab715be250d0c288f10291631fbc062#200",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "0.1132154580329883339492716091518878984625557352",
+        "0x0.1cfbb031a741a49ef5da021f0af0874ce2e41f0#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "0.11321545803298833394927160915188789846255573526",
+        "0x0.1cfbb031a741a49ef5da021f0af0874ce2e41f8#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "0.1132154580329883339492716091518878984625557352",
+        "0x0.1cfbb031a741a49ef5da021f0af0874ce2e41f0#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "0.11321545803298833394927160915188789846255573526",
+        "0x0.1cfbb031a741a49ef5da021f0af0874ce2e41f8#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "0.11321545803298833394927160915188789846255573526",
+        "0x0.1cfbb031a741a49ef5da021f0af0874ce2e41f8#150",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "2.16",
+        "0x2.29#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "2.164",
+        "0x2.2a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "2.16",
+        "0x2.29#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "2.164",
+        "0x2.2a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "2.16",
+        "0x2.29#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Exact,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Floor,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Down,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Up,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Exact,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn exp2_round_fail() {
+    assert_panic!(Float::from(0.5f32).exp2_round(RoundingMode::Exact));
+    assert_panic!(Float::from(0.5f32).exp2_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(0.5f32);
+        x.exp2_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_exp2_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp2, o) = x.clone().exp2_prec_round(prec, rm);
+        assert!(exp2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp2.to_string(), out);
+        assert_eq!(to_hex_string(&exp2), out_hex);
+
+        let (exp2_alt, o_alt) = x.exp2_prec_round_ref(prec, rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp2_alt = x.clone();
+        let o_alt = exp2_alt.exp2_prec_round_assign(prec, rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_exp2, rug_o) = rug_exp2_prec_round(&rug::Float::exact_from(&x), prec, rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_exp2)),
+                    ComparableFloatRef(&exp2)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "8.81",
+        "0x8.d0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "8.83",
+        "0x8.d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "8.83",
+        "0x8.d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "8.82497782707628687473894169658",
+        "0x8.d331bf3337c170dfcdee149f#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "8.82497782707628687473894169659",
+        "0x8.d331bf3337c170dfcdee14a0#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "8.82497782707628687473894169658",
+        "0x8.d331bf3337c170dfcdee149f#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "0.375",
+        "0x0.600#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.3755",
+        "0x0.602#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "0.375",
+        "0x0.600#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "0.3752142272464817485304892728879",
+        "0x0.600e0a23047e3cf618ae71c068#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.3752142272464817485304892728883",
+        "0x0.600e0a23047e3cf618ae71c070#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "0.3752142272464817485304892728879",
+        "0x0.600e0a23047e3cf618ae71c068#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "1.26",
+        "0x1.428#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "1.262",
+        "0x1.430#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "1.26",
+        "0x1.428#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "1.259921049894873164767210607277",
+        "0x1.428a2f98d728ae223ddab715a#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "1.259921049894873164767210607278",
+        "0x1.428a2f98d728ae223ddab715c#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "1.259921049894873164767210607278",
+        "0x1.428a2f98d728ae223ddab715c#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "2.16",
+        "0x2.29#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "2.164",
+        "0x2.2a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "2.16",
+        "0x2.29#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "2.16044443293733576234453848297",
+        "0x2.2912e2e84a86fdfd1be3a07f8#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "2.160444432937335762344538482973",
+        "0x2.2912e2e84a86fdfd1be3a07fc#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "2.16044443293733576234453848297",
+        "0x2.2912e2e84a86fdfd1be3a07f8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Exact,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Exact,
+        "8.0",
+        "0x8.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        1,
+        RoundingMode::Exact,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "0.125",
+        "0x0.200#10",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "0.125",
+        "0x0.200#10",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "0.125",
+        "0x0.200#10",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Exact,
+        "0.125",
+        "0x0.200#10",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "0.125",
+        "0x0.20000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "0.125",
+        "0x0.20000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "0.125",
+        "0x0.20000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "0.125",
+        "0x0.20000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn exp2_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).exp2_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).exp2_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.exp2_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(0.5f32).exp2_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(0.5f32).exp2_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(0.5f32);
+        x.exp2_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_exp2_prec_round_overflow_and_underflow() {
+    // $2^x$ is treated as overflowing for $x \geq 2^{62}$ and as underflowing for
+    // $x \leq -2^{62}$. Rounding toward zero gives the largest finite `Float` with the target
+    // precision.
+    let x = Float::power_of_2(62u64);
+    for rm in exhaustive_rounding_modes() {
+        if rm == RoundingMode::Exact {
+            assert_panic!(x.exp2_prec_round_ref(10, rm));
+            continue;
+        }
+        let (y, o) = x.exp2_prec_round_ref(10, rm);
+        assert!(y.is_valid());
+        let (y_alt, o_alt) = x.clone().exp2_prec_round(10, rm);
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&y));
+        assert_eq!(o_alt, o);
+        if rm == RoundingMode::Floor || rm == RoundingMode::Down {
+            assert_eq!(y.get_exponent(), Some(i64::MAX));
+            assert_eq!(y.get_prec(), Some(10));
+            assert_eq!(y.to_significand().unwrap().count_ones(), 10);
+            assert_eq!(o, Ordering::Less);
+        } else {
+            assert_eq!(ComparableFloat(y), ComparableFloat(Float::INFINITY));
+            assert_eq!(o, Ordering::Greater);
+        }
+    }
+
+    // Rounding away from zero gives the smallest positive `Float` with the target precision.
+    let x = -x;
+    for rm in exhaustive_rounding_modes() {
+        if rm == RoundingMode::Exact {
+            assert_panic!(x.exp2_prec_round_ref(10, rm));
+            continue;
+        }
+        let (y, o) = x.exp2_prec_round_ref(10, rm);
+        assert!(y.is_valid());
+        let (y_alt, o_alt) = x.clone().exp2_prec_round(10, rm);
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&y));
+        assert_eq!(o_alt, o);
+        if rm == RoundingMode::Ceiling || rm == RoundingMode::Up {
+            assert_eq!(y.get_exponent(), Some(i64::MIN));
+            assert_eq!(y.get_prec(), Some(10));
+            assert_eq!(y.to_significand().unwrap().count_ones(), 1);
+            assert_eq!(o, Ordering::Greater);
+        } else {
+            assert_eq!(ComparableFloat(y), ComparableFloat(Float::ZERO));
+            assert_eq!(o, Ordering::Less);
+        }
+    }
+}
+
+#[test]
+fn exp2_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_6().test_properties(|(x, prec, rm)| {
+        let (exp2, o) = x.clone().exp2_prec_round(prec, rm);
+        assert!(exp2.is_valid());
+
+        let (exp2_alt, o_alt) = x.exp2_prec_round_ref(prec, rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp2_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && exp2.is_normal() {
+            assert_eq!(exp2.get_prec(), Some(prec));
+            // $2^x$ is positive, and is at least 1 if $x$ is positive and at most 1 otherwise.
+            assert!(exp2 > 0u32 && if x > 0u32 { exp2 >= 1u32 } else { exp2 <= 1u32 });
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if exp2 > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if exp2 > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.exp2_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.exp2_prec_round_ref(prec, RoundingMode::Ceiling);
+                if ceiling.is_infinite() {
+                    // On overflow, `floor` is the largest finite `Float`.
+                    assert_eq!(floor.get_exponent(), Some(i64::MAX));
+                } else if floor == 0u32 {
+                    // On underflow, `ceiling` is the smallest positive `Float`.
+                    assert_eq!(ceiling.get_exponent(), Some(i64::MIN));
+                } else if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if in_mpfr_range(&x) {
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_exp2, rug_o) =
+                    rug_exp2_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_exp2)),
+                    ComparableFloatRef(&exp2)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.exp2_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(exp2.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.exp2_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn exp2_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (exp2, o) = x.clone().exp2_prec(prec);
+        assert!(exp2.is_valid());
+
+        let (exp2_alt, o_alt) = x.exp2_prec_ref(prec);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp2_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        let (exp2_alt, o_alt) = x.exp2_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        if in_mpfr_range(&x) {
+            let (rug_exp2, rug_o) = rug_exp2_prec_round(
+                &rug::Float::exact_from(&x),
+                prec,
+                rug::float::Round::Nearest,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp2)),
+                ComparableFloatRef(&exp2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn exp2_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.exp2_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.exp2_round_ref(rm));
+                continue;
+            }
+
+            let (exp2, o) = x.clone().exp2_round(rm);
+            assert!(exp2.is_valid());
+
+            let (exp2_alt, o_alt) = x.exp2_round_ref(rm);
+            assert!(exp2_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.exp2_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp2));
+            assert_eq!(o_alt, o);
+
+            let (exp2_alt, o_alt) = x.exp2_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+            assert_eq!(o_alt, o);
+
+            if in_mpfr_range(&x) {
+                if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                    let (rug_exp2, rug_o) =
+                        rug_exp2_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                    assert_eq!(
+                        ComparableFloatRef(&Float::from(&rug_exp2)),
+                        ComparableFloatRef(&exp2)
+                    );
+                    assert_eq!(rug_o, o);
+                }
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (exp2, o) = x.exp2_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(exp2), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::INFINITY);
+    test_special(Float::NEGATIVE_INFINITY, Float::ZERO);
+    test_special(Float::ZERO, Float::ONE);
+    test_special(Float::NEGATIVE_ZERO, Float::ONE);
+}
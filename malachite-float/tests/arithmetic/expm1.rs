@@ -0,0 +1,2016 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{CountOnes, SignificantBits};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_8,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_expm1_prec_round(
+    x: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.exp_m1_ref(), rm)
+}
+
+// MPFR, with its default exponent range, overflows or underflows for larger inputs, and
+// Malachite's results are only compared against it when it doesn't.
+fn in_mpfr_range(x: &Float) -> bool {
+    x.get_exponent().map_or(true, |e| e <= 24)
+}
+
+#[test]
+fn test_expm1_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (expm1, o) = x.clone().expm1_prec(prec);
+        assert!(expm1.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(expm1.to_string(), out);
+        assert_eq!(to_hex_string(&expm1), out_hex);
+
+        let (expm1_alt, o_alt) = x.expm1_prec_ref(prec);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut expm1_alt = x.clone();
+        let o_alt = expm1_alt.expm1_prec_assign(prec);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            let (rug_expm1, rug_o) = rug_expm1_prec_round(
+                &rug::Float::exact_from(&x),
+                prec,
+                rug::float::Round::Nearest,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_expm1)),
+                ComparableFloatRef(&expm1)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.718281828459045235360287471353",
+        "0x1.b7e151628aed2a6abf715880a#100",
+        Ordering::Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-0.5", "-0x0.8#1", Ordering::Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-0.632",
+        "-0x0.a1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.6321205588285576784044762298386",
+        "-0x0.a1d2a7274c4320e54521387d7#100",
+        Ordering::Less,
+    );
+    test("0.5", "0x0.8#1", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("0.5", "0x0.8#1", 10, "0.648", "0x0.a60#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.648721270700128146848650787814",
+        "0x0.a61298e1e069bc972dfefab6e#100",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "2.0e53",
+        "0x2.0E+44#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "2.619e53",
+        "0x2.bcE+44#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "2.619517318749062676188981025375e53",
+        "0x2.bc22a5f3502327274223b3e68E+44#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "22.16",
+        "0x16.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "22.14069263277926617181157008936",
+        "0x16.24046eb09338d2991a30893e#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "14.16",
+        "0xe.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "14.15426224147926199898921968819",
+        "0xe.277dbaf2293cdcf6ff773788#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "-0.757",
+        "-0x0.c1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "-0.756883265565785812697945491344",
+        "-0x0.c1c31a087ea6d2702dcab9f5a#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "0.3955",
+        "0x0.654#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "0.3956124250860895028041078565437",
+        "0x0.6546db1ba2d12f2e20f2f4f988#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "0.3955",
+        "0x0.654#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "0.3956124250860895286281253196027",
+        "0x0.6546db1ba2d1310a7f6c6f27f8#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "-0.957",
+        "-0x0.f50#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "-0.9568406907385474020154079054646",
+        "-0x0.f4f382f23440a15320180625f#100",
+        Ordering::Greater,
+    );
+    test("1.111", "0x1.1c8#10", 1, "2.0", "0x2.0#1", Ordering::Less);
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "2.039",
+        "0x2.0a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "2.038391079040129213394497645783",
+        "0x2.09d3ff6cef7d52f4831306c48#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn expm1_prec_fail() {
+    assert_panic!(Float::NAN.expm1_prec(0));
+    assert_panic!(Float::NAN.expm1_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.expm1_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_expm1_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (expm1, o) = x.clone().expm1_round(rm);
+        assert!(expm1.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(expm1.to_string(), out);
+        assert_eq!(to_hex_string(&expm1), out_hex);
+
+        let (expm1_alt, o_alt) = x.expm1_round_ref(rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut expm1_alt = x.clone();
+        let o_alt = expm1_alt.expm1_round_assign(rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_expm1, rug_o) =
+                    rug_expm1_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_expm1)),
+                    ComparableFloatRef(&expm1)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "2.6e53",
+        "0x2.b8E+44#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "2.63e53",
+        "0x2.c0E+44#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "2.6e53",
+        "0x2.b8E+44#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "2.63e53",
+        "0x2.c0E+44#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "2.63e53",
+        "0x2.c0E+44#7",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "22.140692632779263",
+        "0x16.24046eb09338#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "22.140692632779263",
+        "0x16.24046eb09338#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "14.154262241479261",
+        "0xe.277dbaf2293c8#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "14.154262241479262",
+        "0xe.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "14.154262241479261",
+        "0xe.277dbaf2293c8#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "14.154262241479262",
+        "0xe.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "14.154262241479262",
+        "0xe.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "-0.7568832655657859",
+        "-0x0.c1c31a087ea6d8#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "-0.7568832655657858",
+        "-0x0.c1c31a087ea6d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "-0.7568832655657858",
+        "-0x0.c1c31a087ea6d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "-0.7568832655657859",
+        "-0x0.c1c31a087ea6d8#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "-0.7568832655657858",
+        "-0x0.c1c31a087ea6d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "0.39561242508608946",
+        "0x0.6546db1ba2d12c#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "0.39561242508608951",
+        "0x0.6546db1ba2d130#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "0.39561242508608946",
+        "0x0.6546db1ba2d12c#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "0.39561242508608951",
+        "0x0.6546db1ba2d130#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "0.39561242508608951",
+        "0x0.6546db1ba2d130#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "0.3956124250860895286281253196025868375979065151994069826175166",
+        "0x0.6546db1ba2d1310a7f6c6f27f69e8540872869a64c7e4f8c7d8#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "0.3956124250860895286281253196025868375979065151994069826175169",
+        "0x0.6546db1ba2d1310a7f6c6f27f69e8540872869a64c7e4f8c7e0#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "0.3956124250860895286281253196025868375979065151994069826175166",
+        "0x0.6546db1ba2d1310a7f6c6f27f69e8540872869a64c7e4f8c7d8#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "0.3956124250860895286281253196025868375979065151994069826175169",
+        "0x0.6546db1ba2d1310a7f6c6f27f69e8540872869a64c7e4f8c7e0#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "0.3956124250860895286281253196025868375979065151994069826175169",
+        "0x0.6546db1ba2d1310a7f6c6f27f69e8540872869a64c7e4f8c7e0#200",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "-0.956840690738547402015407905464824028840317211",
+        "-0x0.f4f382f23440a15320180625f5525e4486cf44#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "-0.9568406907385474020154079054648240288403172105",
+        "-0x0.f4f382f23440a15320180625f5525e4486cf40#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "-0.9568406907385474020154079054648240288403172105",
+        "-0x0.f4f382f23440a15320180625f5525e4486cf40#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "-0.956840690738547402015407905464824028840317211",
+        "-0x0.f4f382f23440a15320180625f5525e4486cf44#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "-0.956840690738547402015407905464824028840317211",
+        "-0x0.f4f382f23440a15320180625f5525e4486cf44#150",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "2.035",
+        "0x2.09#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "2.039",
+        "0x2.0a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "2.035",
+        "0x2.09#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "2.039",
+        "0x2.0a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "2.039",
+        "0x2.0a#10",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn expm1_round_fail() {
+    assert_panic!(Float::from(3u32).expm1_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).expm1_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.expm1_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_expm1_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (expm1, o) = x.clone().expm1_prec_round(prec, rm);
+        assert!(expm1.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(expm1.to_string(), out);
+        assert_eq!(to_hex_string(&expm1), out_hex);
+
+        let (expm1_alt, o_alt) = x.expm1_prec_round_ref(prec, rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut expm1_alt = x.clone();
+        let o_alt = expm1_alt.expm1_prec_round_assign(prec, rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if in_mpfr_range(&x) {
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_expm1, rug_o) =
+                    rug_expm1_prec_round(&rug::Float::exact_from(&x), prec, rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_expm1)),
+                    ComparableFloatRef(&expm1)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.717",
+        "0x1.b78#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.718281828459045235360287471351",
+        "0x1.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.718281828459045235360287471353",
+        "0x1.b7e151628aed2a6abf715880a#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.718281828459045235360287471353",
+        "0x1.b7e151628aed2a6abf715880a#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.633",
+        "-0x0.a20#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.632",
+        "-0x0.a1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.632",
+        "-0x0.a1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "-0.6321205588285576784044762298386",
+        "-0x0.a1d2a7274c4320e54521387d7#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "-0.632120558828557678404476229838",
+        "-0x0.a1d2a7274c4320e54521387d6#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "-0.6321205588285576784044762298386",
+        "-0x0.a1d2a7274c4320e54521387d7#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "22.12",
+        "0x16.20#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "22.16",
+        "0x16.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "22.16",
+        "0x16.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "22.14069263277926617181157008936",
+        "0x16.24046eb09338d2991a30893e#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "22.14069263277926617181157008938",
+        "0x16.24046eb09338d2991a308940#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "22.14069263277926617181157008936",
+        "0x16.24046eb09338d2991a30893e#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "-0.758",
+        "-0x0.c20#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.757",
+        "-0x0.c1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.757",
+        "-0x0.c1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "-0.756883265565785812697945491344",
+        "-0x0.c1c31a087ea6d2702dcab9f5a#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "-0.7568832655657858126979454913433",
+        "-0x0.c1c31a087ea6d2702dcab9f59#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "-0.756883265565785812697945491344",
+        "-0x0.c1c31a087ea6d2702dcab9f5a#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "0.3955",
+        "0x0.654#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "0.396",
+        "0x0.656#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "0.3955",
+        "0x0.654#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "0.3956124250860895286281253196023",
+        "0x0.6546db1ba2d1310a7f6c6f27f0#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "0.3956124250860895286281253196027",
+        "0x0.6546db1ba2d1310a7f6c6f27f8#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "0.3956124250860895286281253196027",
+        "0x0.6546db1ba2d1310a7f6c6f27f8#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "2.035",
+        "0x2.09#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "2.039",
+        "0x2.0a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "2.039",
+        "0x2.0a#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "2.038391079040129213394497645783",
+        "0x2.09d3ff6cef7d52f4831306c48#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "2.038391079040129213394497645786",
+        "0x2.09d3ff6cef7d52f4831306c4c#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "2.038391079040129213394497645783",
+        "0x2.09d3ff6cef7d52f4831306c48#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn expm1_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).expm1_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).expm1_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.expm1_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).expm1_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).expm1_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.expm1_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_expm1_prec_round_overflow() {
+    // $e^x-1$ is treated as overflowing for $x \geq 2^{62}$. Rounding toward zero gives the
+    // largest finite `Float` with the target precision.
+    let x = Float::power_of_2(62u64);
+    for rm in exhaustive_rounding_modes() {
+        if rm == RoundingMode::Exact {
+            assert_panic!(x.expm1_prec_round_ref(10, rm));
+            continue;
+        }
+        let (y, o) = x.expm1_prec_round_ref(10, rm);
+        assert!(y.is_valid());
+        let (y_alt, o_alt) = x.clone().expm1_prec_round(10, rm);
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&y));
+        assert_eq!(o_alt, o);
+        if rm == RoundingMode::Floor || rm == RoundingMode::Down {
+            assert_eq!(y.get_exponent(), Some(i64::MAX));
+            assert_eq!(y.get_prec(), Some(10));
+            assert_eq!(y.to_significand().unwrap().count_ones(), 10);
+            assert_eq!(o, Ordering::Less);
+        } else {
+            assert_eq!(ComparableFloat(y), ComparableFloat(Float::INFINITY));
+            assert_eq!(o, Ordering::Greater);
+        }
+    }
+}
+
+#[test]
+fn expm1_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_8().test_properties(|(x, prec, rm)| {
+        let (expm1, o) = x.clone().expm1_prec_round(prec, rm);
+        assert!(expm1.is_valid());
+
+        let (expm1_alt, o_alt) = x.expm1_prec_round_ref(prec, rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.expm1_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && expm1.is_normal() {
+            assert_eq!(expm1.get_prec(), Some(prec));
+            // $e^x-1$ is at least -1, and has the same sign as $x$.
+            assert!(expm1 >= -1i32 && (x > 0u32) == (expm1 > 0u32));
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if expm1 > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if expm1 > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.expm1_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.expm1_prec_round_ref(prec, RoundingMode::Ceiling);
+                if ceiling.is_infinite() {
+                    // On overflow, `floor` is the largest finite `Float`.
+                    assert_eq!(floor.get_exponent(), Some(i64::MAX));
+                } else if floor == 0u32 {
+                    // On underflow, `ceiling` is the smallest positive `Float`.
+                    assert_eq!(ceiling.get_exponent(), Some(i64::MIN));
+                } else if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if in_mpfr_range(&x) {
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_expm1, rug_o) =
+                    rug_expm1_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_expm1)),
+                    ComparableFloatRef(&expm1)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.expm1_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(expm1.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.expm1_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn expm1_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (expm1, o) = x.clone().expm1_prec(prec);
+        assert!(expm1.is_valid());
+
+        let (expm1_alt, o_alt) = x.expm1_prec_ref(prec);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.expm1_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        let (expm1_alt, o_alt) = x.expm1_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        if in_mpfr_range(&x) {
+            let (rug_expm1, rug_o) = rug_expm1_prec_round(
+                &rug::Float::exact_from(&x),
+                prec,
+                rug::float::Round::Nearest,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_expm1)),
+                ComparableFloatRef(&expm1)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn expm1_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.expm1_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.expm1_round_ref(rm));
+                continue;
+            }
+
+            let (expm1, o) = x.clone().expm1_round(rm);
+            assert!(expm1.is_valid());
+
+            let (expm1_alt, o_alt) = x.expm1_round_ref(rm);
+            assert!(expm1_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.expm1_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&expm1));
+            assert_eq!(o_alt, o);
+
+            let (expm1_alt, o_alt) = x.expm1_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+            assert_eq!(o_alt, o);
+
+            if in_mpfr_range(&x) {
+                if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                    let (rug_expm1, rug_o) =
+                        rug_expm1_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                    assert_eq!(
+                        ComparableFloatRef(&Float::from(&rug_expm1)),
+                        ComparableFloatRef(&expm1)
+                    );
+                    assert_eq!(rug_o, o);
+                }
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (expm1, o) = x.expm1_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(expm1), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::INFINITY);
+    test_special(Float::NEGATIVE_INFINITY, Float::NEGATIVE_ONE);
+    test_special(Float::ZERO, Float::ZERO);
+    test_special(Float::NEGATIVE_ZERO, Float::NEGATIVE_ZERO);
+}
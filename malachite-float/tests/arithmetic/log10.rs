@@ -0,0 +1,2451 @@
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_11,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_log10_prec_round(
+    x: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.log10_ref(), rm)
+}
+
+#[test]
+fn test_log10_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (log10, o) = x.clone().log10_prec(prec);
+        assert!(log10.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(log10.to_string(), out);
+        assert_eq!(to_hex_string(&log10), out_hex);
+
+        let (log10_alt, o_alt) = x.log10_prec_ref(prec);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut log10_alt = x.clone();
+        let o_alt = log10_alt.log10_prec_assign(prec);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_log10, rug_o) = rug_log10_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_log10)),
+            ComparableFloatRef(&log10)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "-Infinity", "-Infinity", Ordering::Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.5", "0x0.8#1", 1, "-0.2", "-0x0.4#1", Ordering::Greater);
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        "-0.3013",
+        "-0x0.4d2#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "-0.3010299956639811952137388947246",
+        "-0x0.4d104d427de7fbcc47c4acd608#100",
+        Ordering::Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "2.0", "0x2.0#1", Ordering::Less);
+    test("123.0", "0x7b.0#7", 10, "2.09", "0x2.17#10", Ordering::Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "2.089905111439397931804439753222",
+        "0x2.17040579601d77164f7c690d4#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "0.4971",
+        "0x0.7f4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "0.4971498726941338374217231245522",
+        "0x0.7f4536cc6e8eeacae4696b9e08#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.4341",
+        "0x0.6f2#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.4342944819032518045543160246293",
+        "0x0.6f2dec549b9437208b105fe9b0#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "-0.4771",
+        "-0x0.7a2#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "-0.4771212547196624614032145670873",
+        "-0x0.7a249e593f57f5dfc3be610ed8#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "30.09",
+        "0x1e.18#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "30.10299956639811952137388947245",
+        "0x1e.1a5e2df92e9e5bcc08d3839a#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "-30.09",
+        "-0x1e.18#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "-30.10299956639811952137388947245",
+        "-0x1e.1a5e2df92e9e5bcc08d3839a#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "-0.4771",
+        "-0x0.7a2#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "-0.4771212547196624372950279032552",
+        "-0x0.7a249e593f57f4230c0d0ea088#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test("1.111", "0x1.1c8#10", 1, "0.03", "0x0.08#1", Ordering::Less);
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "0.04584",
+        "0x0.0bbc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "0.04584230541924041536340547150975",
+        "0x0.0bbc52428c8379aa2889569df9#100",
+        Ordering::Less,
+    );
+    test("1.0e3", "0x3e8.0#7", 1, "4.0", "0x4.0#1", Ordering::Greater);
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        10,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        100,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("100.0", "0x64.0#5", 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test("100.0", "0x64.0#5", 10, "2.0", "0x2.00#10", Ordering::Equal);
+    test(
+        "100.0",
+        "0x64.0#5",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn log10_prec_fail() {
+    assert_panic!(Float::NAN.log10_prec(0));
+    assert_panic!(Float::NAN.log10_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.log10_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_log10_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (log10, o) = x.clone().log10_round(rm);
+        assert!(log10.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(log10.to_string(), out);
+        assert_eq!(to_hex_string(&log10), out_hex);
+
+        let (log10_alt, o_alt) = x.log10_round_ref(rm);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut log10_alt = x.clone();
+        let o_alt = log10_alt.log10_round_assign(rm);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_log10, rug_o) =
+                rug_log10_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_log10)),
+                ComparableFloatRef(&log10)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "2.06",
+        "0x2.10#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "2.09",
+        "0x2.18#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "2.06",
+        "0x2.10#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "2.09",
+        "0x2.18#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "2.09",
+        "0x2.18#7",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "0.4971498726941338",
+        "0x0.7f4536cc6e8ee8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "0.49714987269413385",
+        "0x0.7f4536cc6e8eec#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "0.4971498726941338",
+        "0x0.7f4536cc6e8ee8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "0.49714987269413385",
+        "0x0.7f4536cc6e8eec#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "0.49714987269413385",
+        "0x0.7f4536cc6e8eec#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "0.43429448190325176",
+        "0x0.6f2dec549b9434#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "0.43429448190325182",
+        "0x0.6f2dec549b9438#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "0.43429448190325176",
+        "0x0.6f2dec549b9434#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "0.43429448190325182",
+        "0x0.6f2dec549b9438#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "0.43429448190325182",
+        "0x0.6f2dec549b9438#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "-0.47712125471966249",
+        "-0x0.7a249e593f57f8#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "-0.47712125471966244",
+        "-0x0.7a249e593f57f4#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "-0.47712125471966244",
+        "-0x0.7a249e593f57f4#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "-0.47712125471966249",
+        "-0x0.7a249e593f57f8#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "-0.47712125471966244",
+        "-0x0.7a249e593f57f4#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "-3.0e1",
+        "-0x2.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "-0.4771212547196624372950279032551153092001288641906958648298658",
+        "-0x0.7a249e593f57f4230c0d0ea08689076384571ab306dbee15e80#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "-0.4771212547196624372950279032551153092001288641906958648298655",
+        "-0x0.7a249e593f57f4230c0d0ea08689076384571ab306dbee15e78#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "-0.4771212547196624372950279032551153092001288641906958648298655",
+        "-0x0.7a249e593f57f4230c0d0ea08689076384571ab306dbee15e78#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "-0.4771212547196624372950279032551153092001288641906958648298658",
+        "-0x0.7a249e593f57f4230c0d0ea08689076384571ab306dbee15e80#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "-0.4771212547196624372950279032551153092001288641906958648298655",
+        "-0x0.7a249e593f57f4230c0d0ea08689076384571ab306dbee15e78#200",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "0.04584",
+        "0x0.0bbc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "0.0459",
+        "0x0.0bc0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "0.04584",
+        "0x0.0bbc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "0.0459",
+        "0x0.0bc0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "0.04584",
+        "0x0.0bbc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        RoundingMode::Down,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        RoundingMode::Up,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#5",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn log10_round_fail() {
+    assert_panic!(Float::from(3u32).log10_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).log10_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.log10_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_log10_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (log10, o) = x.clone().log10_prec_round(prec, rm);
+        assert!(log10.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(log10.to_string(), out);
+        assert_eq!(to_hex_string(&log10), out_hex);
+
+        let (log10_alt, o_alt) = x.log10_prec_round_ref(prec, rm);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut log10_alt = x.clone();
+        let o_alt = log10_alt.log10_prec_round_assign(prec, rm);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10), ComparableFloatRef(&log10_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_log10, rug_o) = rug_log10_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_log10)),
+                ComparableFloatRef(&log10)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "0.4971",
+        "0x0.7f4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.4976",
+        "0x0.7f6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "0.4971",
+        "0x0.7f4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "0.4971498726941338374217231245522",
+        "0x0.7f4536cc6e8eeacae4696b9e08#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.4971498726941338374217231245526",
+        "0x0.7f4536cc6e8eeacae4696b9e10#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "0.4971498726941338374217231245522",
+        "0x0.7f4536cc6e8eeacae4696b9e08#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "-0.4775",
+        "-0x0.7a4#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "-0.4771",
+        "-0x0.7a2#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "-0.4771",
+        "-0x0.7a2#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "-0.4771212547196624372950279032552",
+        "-0x0.7a249e593f57f4230c0d0ea088#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "-0.4771212547196624372950279032548",
+        "-0x0.7a249e593f57f4230c0d0ea080#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "-0.4771212547196624372950279032552",
+        "-0x0.7a249e593f57f4230c0d0ea088#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "0.03",
+        "0x0.08#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "0.03",
+        "0x0.08#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "0.04584",
+        "0x0.0bbc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.0459",
+        "0x0.0bc0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "0.04584",
+        "0x0.0bbc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "0.04584230541924041536340547150975",
+        "0x0.0bbc52428c8379aa2889569df9#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "0.0458423054192404153634054715098",
+        "0x0.0bbc52428c8379aa2889569dfa#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "0.04584230541924041536340547150975",
+        "0x0.0bbc52428c8379aa2889569df9#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        10,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        10,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        10,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        100,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        100,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        100,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e3",
+        "0x3e8.0#7",
+        100,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        1,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        10,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        100,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        100,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        100,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#5",
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn log10_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).log10_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).log10_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.log10_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).log10_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).log10_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.log10_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn log10_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_11().test_properties(|(x, prec, rm)| {
+        let (log10, o) = x.clone().log10_prec_round(prec, rm);
+        assert!(log10.is_valid());
+
+        let (log10_alt, o_alt) = x.log10_prec_round_ref(prec, rm);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10_alt), ComparableFloatRef(&log10));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.log10_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&log10));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && log10.is_normal() {
+            assert_eq!(log10.get_prec(), Some(prec));
+            // $\log_{10} x$ is positive exactly when $x$ is greater than 1.
+            assert!((x > 1u32) == (log10 > 0u32));
+            match rm {
+                RoundingMode::Floor => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling => assert_ne!(o, Ordering::Less),
+                RoundingMode::Down => {
+                    assert_ne!(
+                        o,
+                        if log10 > 0u32 {
+                            Ordering::Greater
+                        } else {
+                            Ordering::Less
+                        }
+                    );
+                }
+                RoundingMode::Up => {
+                    assert_ne!(
+                        o,
+                        if log10 > 0u32 {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    );
+                }
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+            if o != Ordering::Equal {
+                // The exact result lies strictly between two adjacent `Float`s.
+                let (mut floor, _) = x.log10_prec_round_ref(prec, RoundingMode::Floor);
+                let (mut ceiling, _) = x.log10_prec_round_ref(prec, RoundingMode::Ceiling);
+                if floor > 0u32 {
+                    floor.increment();
+                    assert_eq!(floor, ceiling);
+                } else {
+                    ceiling.decrement();
+                    assert_eq!(ceiling, floor);
+                }
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_log10, rug_o) =
+                rug_log10_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_log10)),
+                ComparableFloatRef(&log10)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.log10_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(log10.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.log10_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn log10_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (log10, o) = x.clone().log10_prec(prec);
+        assert!(log10.is_valid());
+
+        let (log10_alt, o_alt) = x.log10_prec_ref(prec);
+        assert!(log10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&log10_alt), ComparableFloatRef(&log10));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.log10_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&log10));
+        assert_eq!(o_alt, o);
+
+        let (log10_alt, o_alt) = x.log10_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&log10_alt), ComparableFloatRef(&log10));
+        assert_eq!(o_alt, o);
+
+        let (rug_log10, rug_o) = rug_log10_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_log10)),
+            ComparableFloatRef(&log10)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn log10_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.log10_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.log10_round_ref(rm));
+                continue;
+            }
+
+            let (log10, o) = x.clone().log10_round(rm);
+            assert!(log10.is_valid());
+
+            let (log10_alt, o_alt) = x.log10_round_ref(rm);
+            assert!(log10_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&log10_alt), ComparableFloatRef(&log10));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.log10_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&log10));
+            assert_eq!(o_alt, o);
+
+            let (log10_alt, o_alt) = x.log10_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&log10_alt), ComparableFloatRef(&log10));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_log10, rug_o) =
+                    rug_log10_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_log10)),
+                    ComparableFloatRef(&log10)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (log10, o) = x.log10_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(log10), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::INFINITY);
+    test_special(Float::NEGATIVE_INFINITY, Float::NAN);
+    test_special(Float::ZERO, Float::NEGATIVE_INFINITY);
+    test_special(Float::NEGATIVE_ZERO, Float::NEGATIVE_INFINITY);
+    test_special(Float::ONE, Float::ZERO);
+    test_special(Float::NEGATIVE_ONE, Float::NAN);
+}
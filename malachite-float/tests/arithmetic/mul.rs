@@ -0,0 +1,9035 @@
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_unsigned_rounding_mode_quadruple_gen_var_3, float_float_unsigned_triple_gen_var_1,
+    float_gen, float_integer_pair_gen, float_natural_pair_gen, float_pair_gen,
+    float_pair_gen_var_1, float_rational_pair_gen, float_rational_pair_gen_var_1,
+    float_rational_unsigned_rounding_mode_quadruple_gen_var_3,
+    float_rational_unsigned_triple_gen_var_1, float_triple_gen,
+    float_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn rug_mul_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x * y, rm)
+}
+
+fn rug_mul_rational_prec_round(
+    x: &rug::Float,
+    y: &rug::Rational,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x * y, rm)
+}
+
+#[test]
+fn test_mul() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let product = x.clone() * y.clone();
+        assert!(product.is_valid());
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let product_alt = x.clone() * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let product_alt = &x * y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let product_alt = &x * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+
+        let mut product_alt = x.clone();
+        product_alt *= y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let mut product_alt = x.clone();
+        product_alt *= &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+
+        let prec = max(x.significant_bits(), y.significant_bits());
+        let (rug_product, _) = rug_mul_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_product)),
+            ComparableFloatRef(&product)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", "Infinity", "Infinity",
+    );
+    test("Infinity", "Infinity", "0.0", "0x0.0", "NaN", "NaN");
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("-Infinity", "-Infinity", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "123.0", "0x7b.0#7", "0.0", "0x0.0");
+    test("123.0", "0x7b.0#7", "-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "Infinity", "Infinity", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test("123.0", "0x7b.0#7", "1.0", "0x1.0#1", "123.0", "0x7b.0#7");
+    test("123.0", "0x7b.0#7", "0.5", "0x0.8#1", "61.5", "0x3d.8#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-4.442882938158366",
+        "-0x4.7160c6b758b90#53",
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "4.2255020007607644e29",
+        "0x5.5555555555554E+24#53",
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.4782796245465248e-30",
+        "0x3.243f6a8885a30E-25#53",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "-1.047619047619047619047619047619047619047619047352133625842893",
+        "-0x1.0c30c30c30c30c30c30c30c30c30c30c30c30aaaaaaaaaaaac#200",
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        "-3.492745535714285714285714285714285714285714284",
+        "-0x3.7e24924924924924924924924924924924924#150",
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        "1.234",
+        "0x1.3c0#10",
+    );
+}
+
+#[test]
+fn test_mul_prec() {
+    let test = |s, s_hex, t, t_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (product, o) = x.clone().mul_prec(y.clone(), prec);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_prec_val_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_prec_ref_val(y.clone(), prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_prec_ref_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_assign(y.clone(), prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_assign_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_product, rug_o) = rug_mul_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_product)),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        "61.5",
+        "0x3d.8#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        "61.5",
+        "0x3d.800000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "8.55",
+        "0x8.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "8.53973422267356627840667135511",
+        "0x8.8a2c05a2ea3a1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "-4.445",
+        "-0x4.72#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "-4.442882938158366377531646279377",
+        "-0x4.7160c6b758b913de903fc9488#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "3.0e29",
+        "0x4.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "4.228e29",
+        "0x5.56E+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "422550200076076443709319675904.0",
+        "0x5555555555555400000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "3.0e-30",
+        "0x4.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "2.478e-30",
+        "0x3.24E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "2.478279624546524760487026782061e-30",
+        "0x3.243f6a8885a30000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "-1.047",
+        "-0x1.0c0#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "-1.047619047619047619047619047618",
+        "-0x1.0c30c30c30c30c30c30c30c30#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "-3.492",
+        "-0x3.7e#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "-3.492745535714285714285714285714",
+        "-0x3.7e24924924924924924924924#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "1.235050201416015625",
+        "0x1.3c2c400000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn mul_prec_fail() {
+    assert_panic!(Float::ONE.mul_prec(Float::TWO, 0));
+    assert_panic!(Float::ONE.mul_prec_val_ref(&Float::TWO, 0));
+    assert_panic!(Float::ONE.mul_prec_ref_val(Float::TWO, 0));
+    assert_panic!(Float::ONE.mul_prec_ref_ref(&Float::TWO, 0));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_prec_assign(Float::TWO, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_prec_assign_ref(&Float::TWO, 0)
+    });
+}
+
+#[test]
+fn test_mul_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (product, o) = x.clone().mul_round(y.clone(), rm);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_round_val_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_round_ref_val(y.clone(), rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_round_ref_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_round_assign(y.clone(), rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_round_assign_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_product, rug_o) = rug_mul_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                max(x.significant_bits(), y.significant_bits()),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_product)),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "61.5",
+        "0x3d.8#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "61.5",
+        "0x3d.8#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "61.5",
+        "0x3d.8#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "61.5",
+        "0x3d.8#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "61.5",
+        "0x3d.8#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Exact,
+        "61.5",
+        "0x3d.8#7",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "8.539734222673568",
+        "0x8.8a2c05a2ea3a8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "8.539734222673568",
+        "0x8.8a2c05a2ea3a8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "-4.442882938158367",
+        "-0x4.7160c6b758b94#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "-4.442882938158366",
+        "-0x4.7160c6b758b90#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "-4.442882938158366",
+        "-0x4.7160c6b758b90#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "-4.442882938158367",
+        "-0x4.7160c6b758b94#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "-4.442882938158366",
+        "-0x4.7160c6b758b90#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "4.2255020007607644e29",
+        "0x5.5555555555554E+24#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "4.2255020007607644e29",
+        "0x5.5555555555554E+24#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "4.2255020007607644e29",
+        "0x5.5555555555554E+24#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "4.2255020007607644e29",
+        "0x5.5555555555554E+24#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "4.2255020007607644e29",
+        "0x5.5555555555554E+24#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Exact,
+        "4.2255020007607644e29",
+        "0x5.5555555555554E+24#53",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "2.4782796245465248e-30",
+        "0x3.243f6a8885a30E-25#53",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "2.4782796245465248e-30",
+        "0x3.243f6a8885a30E-25#53",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "2.4782796245465248e-30",
+        "0x3.243f6a8885a30E-25#53",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "2.4782796245465248e-30",
+        "0x3.243f6a8885a30E-25#53",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "2.4782796245465248e-30",
+        "0x3.243f6a8885a30E-25#53",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "2.4782796245465248e-30",
+        "0x3.243f6a8885a30E-25#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "-1.047619047619047619047619047619047619047619047352133625842893",
+        "-0x1.0c30c30c30c30c30c30c30c30c30c30c30c30aaaaaaaaaaaac#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "-1.047619047619047619047619047619047619047619047352133625842892",
+        "-0x1.0c30c30c30c30c30c30c30c30c30c30c30c30aaaaaaaaaaaaa#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "-1.047619047619047619047619047619047619047619047352133625842892",
+        "-0x1.0c30c30c30c30c30c30c30c30c30c30c30c30aaaaaaaaaaaaa#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "-1.047619047619047619047619047619047619047619047352133625842893",
+        "-0x1.0c30c30c30c30c30c30c30c30c30c30c30c30aaaaaaaaaaaac#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "-1.047619047619047619047619047619047619047619047352133625842893",
+        "-0x1.0c30c30c30c30c30c30c30c30c30c30c30c30aaaaaaaaaaaac#200",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "-3.492745535714285714285714285714285714285714287",
+        "-0x3.7e24924924924924924924924924924924925#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "-3.492745535714285714285714285714285714285714284",
+        "-0x3.7e24924924924924924924924924924924924#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "-3.492745535714285714285714285714285714285714284",
+        "-0x3.7e24924924924924924924924924924924924#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "-3.492745535714285714285714285714285714285714287",
+        "-0x3.7e24924924924924924924924924924924925#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "-3.492745535714285714285714285714285714285714284",
+        "-0x3.7e24924924924924924924924924924924924#150",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "1.236",
+        "0x1.3c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "1.236",
+        "0x1.3c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn mul_round_fail() {
+    let third = Float::from(1.0 / 3.0);
+    assert_panic!(third.clone().mul_round(third.clone(), RoundingMode::Exact));
+    assert_panic!(third.clone().mul_round_val_ref(&third, RoundingMode::Exact));
+    assert_panic!(third.mul_round_ref_val(third.clone(), RoundingMode::Exact));
+    assert_panic!(third.mul_round_ref_ref(&third, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = third.clone();
+        x.mul_round_assign(third.clone(), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = third.clone();
+        x.mul_round_assign_ref(&third, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_mul_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (product, o) = x.clone().mul_prec_round(y.clone(), prec, rm);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_prec_round_val_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_round_assign(y.clone(), prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_round_assign_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_product, rug_o) = rug_mul_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_product)),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Floor,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Ceiling,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Nearest,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "61.5",
+        "0x3d.8#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "61.5",
+        "0x3d.8#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "61.5",
+        "0x3d.8#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Exact,
+        "61.5",
+        "0x3d.8#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Floor,
+        "61.5",
+        "0x3d.800000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Ceiling,
+        "61.5",
+        "0x3d.800000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Nearest,
+        "61.5",
+        "0x3d.800000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Exact,
+        "61.5",
+        "0x3d.800000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "8.53",
+        "0x8.88#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "8.55",
+        "0x8.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "8.55",
+        "0x8.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Floor,
+        "8.53973422267356627840667135511",
+        "0x8.8a2c05a2ea3a1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Ceiling,
+        "8.53973422267356627840667135512",
+        "0x8.8a2c05a2ea3a1679e124a69c#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Nearest,
+        "8.53973422267356627840667135511",
+        "0x8.8a2c05a2ea3a1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "-8.0",
+        "-0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "-4.445",
+        "-0x4.72#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "-4.44",
+        "-0x4.70#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "-4.445",
+        "-0x4.72#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "-4.442882938158366377531646279377",
+        "-0x4.7160c6b758b913de903fc9488#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "-4.44288293815836637753164627937",
+        "-0x4.7160c6b758b913de903fc9480#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "-4.442882938158366377531646279377",
+        "-0x4.7160c6b758b913de903fc9488#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Floor,
+        "3.0e29",
+        "0x4.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Ceiling,
+        "6.0e29",
+        "0x8.0E+24#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Nearest,
+        "3.0e29",
+        "0x4.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "4.221e29",
+        "0x5.54E+24#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "4.228e29",
+        "0x5.56E+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "4.228e29",
+        "0x5.56E+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Floor,
+        "422550200076076443709319675904.0",
+        "0x5555555555555400000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Ceiling,
+        "422550200076076443709319675904.0",
+        "0x5555555555555400000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Nearest,
+        "422550200076076443709319675904.0",
+        "0x5555555555555400000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Exact,
+        "422550200076076443709319675904.0",
+        "0x5555555555555400000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "3.0e-30",
+        "0x4.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "3.0e-30",
+        "0x4.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "2.478e-30",
+        "0x3.24E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "2.481e-30",
+        "0x3.25E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "2.478e-30",
+        "0x3.24E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "2.478279624546524760487026782061e-30",
+        "0x3.243f6a8885a30000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "2.478279624546524760487026782061e-30",
+        "0x3.243f6a8885a30000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "2.478279624546524760487026782061e-30",
+        "0x3.243f6a8885a30000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "2.478279624546524760487026782061e-30",
+        "0x3.243f6a8885a30000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Exact,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Exact,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Floor,
+        "-1.049",
+        "-0x1.0c8#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Ceiling,
+        "-1.047",
+        "-0x1.0c0#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Nearest,
+        "-1.047",
+        "-0x1.0c0#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Floor,
+        "-1.04761904761904761904761904762",
+        "-0x1.0c30c30c30c30c30c30c30c32#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Ceiling,
+        "-1.047619047619047619047619047618",
+        "-0x1.0c30c30c30c30c30c30c30c30#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Nearest,
+        "-1.047619047619047619047619047618",
+        "-0x1.0c30c30c30c30c30c30c30c30#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "-3.496",
+        "-0x3.7f#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "-3.492",
+        "-0x3.7e#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "-3.492",
+        "-0x3.7e#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "-3.492745535714285714285714285717",
+        "-0x3.7e24924924924924924924928#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "-3.492745535714285714285714285714",
+        "-0x3.7e24924924924924924924924#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "-3.492745535714285714285714285714",
+        "-0x3.7e24924924924924924924924#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "1.236",
+        "0x1.3c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "1.234",
+        "0x1.3c0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "1.235050201416015625",
+        "0x1.3c2c400000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "1.235050201416015625",
+        "0x1.3c2c400000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "1.235050201416015625",
+        "0x1.3c2c400000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Exact,
+        "1.235050201416015625",
+        "0x1.3c2c400000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn mul_prec_round_fail() {
+    assert_panic!(Float::ONE.mul_prec_round(Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.mul_prec_round_val_ref(&Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.mul_prec_round_ref_val(Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.mul_prec_round_ref_ref(&Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_prec_round_assign(Float::TWO, 0, RoundingMode::Floor)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_prec_round_assign_ref(&Float::TWO, 0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::ONE.mul_prec_round(Float::from(1.0e-100), 1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.mul_prec_round_val_ref(
+        &Float::from(1.0e-100),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.mul_prec_round_ref_val(Float::from(1.0e-100), 1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.mul_prec_round_ref_ref(
+        &Float::from(1.0e-100),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_prec_round_assign(Float::from(1.0e-100), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_prec_round_assign_ref(&Float::from(1.0e-100), 1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_mul_rational() {
+    let test = |s, s_hex, t, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let product = x.clone() * y.clone();
+        assert!(product.is_valid());
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let product_alt = x.clone() * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let product_alt = &x * y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let product_alt = &x * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+
+        let product_alt = y.clone() * x.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let product_alt = y.clone() * &x;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let product_alt = &y * x.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let product_alt = &y * &x;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+
+        let mut product_alt = x.clone();
+        product_alt *= y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let mut product_alt = x.clone();
+        product_alt *= &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+
+        let (rug_product, _) = rug_mul_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::from(&y),
+            x.significant_bits(),
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_product)),
+            ComparableFloatRef(&product)
+        );
+    };
+    test("NaN", "NaN", "1/3", "NaN", "NaN");
+    test("Infinity", "Infinity", "1/3", "Infinity", "Infinity");
+    test("Infinity", "Infinity", "-22/7", "-Infinity", "-Infinity");
+    test("Infinity", "Infinity", "0", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "1/3", "-Infinity", "-Infinity");
+    test("0.0", "0x0.0", "1/3", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "1/3", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "-22/7", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "0", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "0", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1/3", "0.2", "0x0.4#1");
+    test("1.0", "0x1.0#1", "123", "1.0e2", "0x8.0E+1#1");
+    test("123.0", "0x7b.0#7", "-1", "-123.0", "-0x7b.0#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        "1.0471975511965976",
+        "0x1.0c152382d7365#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        "-9.873576911282207",
+        "-0x9.dfa2bc887f6e0#53",
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        "-0.47140452079103173",
+        "-0x0.78adf777fbe99c#53",
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        "1.0",
+        "0x1.00000000000000000000000000000000000000000000000000#200",
+    );
+    test("1.111", "0x1.1c8#10", "9/10", "1.0", "0x1.000#10");
+    test("1.0e30", "0x1.0E+25#1", "1/3", "3.0e29", "0x4.0E+24#1");
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        "-3.0e-30",
+        "-0x4.0E-25#1",
+    );
+}
+
+#[test]
+fn test_mul_rational_prec() {
+    let test = |s, s_hex, t, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (product, o) = x.clone().mul_rational_prec(y.clone(), prec);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_rational_prec_val_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_rational_prec_ref_val(y.clone(), prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_rational_prec_ref_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_rational_prec_assign(y.clone(), prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_rational_prec_assign_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_product, rug_o) = rug_mul_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_product)),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "1/3", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", "1/3", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", "1/3", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", "1/3", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", "1/3", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", "1/3", 100, "0.0", "0x0.0", Ordering::Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", "0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", "0", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", "0", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", "0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", "0", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", "0", 100, "0.0", "0x0.0", Ordering::Equal);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", "1/3", 1, "0.2", "0x0.4#1", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        10,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        100,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        1,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        10,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        100,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        1,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        10,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        100,
+        "-123.0",
+        "-0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        "1.047",
+        "0x1.0c0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        100,
+        "1.047197551196597705332654489515",
+        "0x1.0c152382d7365555555555556#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        "-8.0",
+        "-0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        "-9.88",
+        "-0x9.e0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        100,
+        "-9.87357691128220693599359947257",
+        "-0x9.dfa2bc887f6e000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        "-0.4712",
+        "-0x0.78a#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        100,
+        "-0.4714045207910317151582072862461",
+        "-0x0.78adf777fbe99aaaaaaaaaaaa8#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        100,
+        "1.000195312499999999999999999999",
+        "0x1.000cccccccccccccccccccccc#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        "3.0e29",
+        "0x4.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        "4.228e29",
+        "0x5.56E+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        100,
+        "422550200076076467165567735125.5",
+        "0x5555555555555555555555555.8#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        1,
+        "-3.0e-30",
+        "-0x4.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        10,
+        "-2.481e-30",
+        "-0x3.25E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        100,
+        "-2.479277130694608531294004062318e-30",
+        "-0x3.2492492492492492492492494E-25#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn mul_rational_prec_fail() {
+    assert_panic!(Float::ONE.mul_rational_prec(Rational::ONE, 0));
+    assert_panic!(Float::ONE.mul_rational_prec_val_ref(&Rational::ONE, 0));
+    assert_panic!(Float::ONE.mul_rational_prec_ref_val(Rational::ONE, 0));
+    assert_panic!(Float::ONE.mul_rational_prec_ref_ref(&Rational::ONE, 0));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_rational_prec_assign(Rational::ONE, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_rational_prec_assign_ref(&Rational::ONE, 0)
+    });
+}
+
+#[test]
+fn test_mul_rational_round() {
+    let test = |s, s_hex, t, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (product, o) = x.clone().mul_rational_round(y.clone(), rm);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_rational_round_val_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_rational_round_ref_val(y.clone(), rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_rational_round_ref_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_rational_round_assign(y.clone(), rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_rational_round_assign_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_product, rug_o) = rug_mul_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::from(&y),
+                x.significant_bits(),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_product)),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1/3",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-22/7",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Floor,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Ceiling,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Down,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Up,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Nearest,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        RoundingMode::Exact,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        RoundingMode::Floor,
+        "1.0471975511965976",
+        "0x1.0c152382d7365#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        RoundingMode::Ceiling,
+        "1.0471975511965979",
+        "0x1.0c152382d7366#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        RoundingMode::Down,
+        "1.0471975511965976",
+        "0x1.0c152382d7365#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        RoundingMode::Up,
+        "1.0471975511965979",
+        "0x1.0c152382d7366#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        RoundingMode::Nearest,
+        "1.0471975511965976",
+        "0x1.0c152382d7365#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Floor,
+        "-9.873576911282207",
+        "-0x9.dfa2bc887f6e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "-9.873576911282207",
+        "-0x9.dfa2bc887f6e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Down,
+        "-9.873576911282207",
+        "-0x9.dfa2bc887f6e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Up,
+        "-9.873576911282207",
+        "-0x9.dfa2bc887f6e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Nearest,
+        "-9.873576911282207",
+        "-0x9.dfa2bc887f6e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Exact,
+        "-9.873576911282207",
+        "-0x9.dfa2bc887f6e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Floor,
+        "-0.47140452079103173",
+        "-0x0.78adf777fbe99c#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Ceiling,
+        "-0.47140452079103168",
+        "-0x0.78adf777fbe998#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Down,
+        "-0.47140452079103168",
+        "-0x0.78adf777fbe998#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Up,
+        "-0.47140452079103173",
+        "-0x0.78adf777fbe99c#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Nearest,
+        "-0.47140452079103173",
+        "-0x0.78adf777fbe99c#53",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.00000000000000000000000000000000000000000000000000#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        RoundingMode::Ceiling,
+        "1.000000000000000000000000000000000000000000000000000000000001",
+        "0x1.00000000000000000000000000000000000000000000000002#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.00000000000000000000000000000000000000000000000000#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        RoundingMode::Up,
+        "1.000000000000000000000000000000000000000000000000000000000001",
+        "0x1.00000000000000000000000000000000000000000000000002#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.00000000000000000000000000000000000000000000000000#200",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        RoundingMode::Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        RoundingMode::Up,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Floor,
+        "3.0e29",
+        "0x4.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Ceiling,
+        "6.0e29",
+        "0x8.0E+24#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Down,
+        "3.0e29",
+        "0x4.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Up,
+        "6.0e29",
+        "0x8.0E+24#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Nearest,
+        "3.0e29",
+        "0x4.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        RoundingMode::Floor,
+        "-3.0e-30",
+        "-0x4.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "-2.0e-30",
+        "-0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        RoundingMode::Down,
+        "-2.0e-30",
+        "-0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        RoundingMode::Up,
+        "-3.0e-30",
+        "-0x4.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        RoundingMode::Nearest,
+        "-3.0e-30",
+        "-0x4.0E-25#1",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn mul_rational_round_fail() {
+    assert_panic!(
+        Float::ONE.mul_rational_round(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    );
+    assert_panic!(Float::ONE
+        .mul_rational_round_val_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!(Float::ONE
+        .mul_rational_round_ref_val(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!(Float::ONE
+        .mul_rational_round_ref_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_rational_round_assign_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_mul_rational_prec_round() {
+    let test = |s, s_hex, t, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (product, o) = x.clone().mul_rational_prec_round(y.clone(), prec, rm);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_rational_prec_round_val_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_rational_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let (product_alt, o_alt) = x.mul_rational_prec_round_ref_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_rational_prec_round_assign(y.clone(), prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_rational_prec_round_assign_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_product, rug_o) = rug_mul_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_product)),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1/3",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        1,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1/3",
+        100,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        1,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        1,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        1,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        10,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        10,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        10,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        100,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        100,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        100,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-22/7",
+        100,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "0.333",
+        "0x0.554#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "0.3333333333333333333333333333331",
+        "0x0.55555555555555555555555550#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        1,
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        1,
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        10,
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        10,
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        10,
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        10,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        100,
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        100,
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        100,
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123",
+        100,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        1,
+        RoundingMode::Floor,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        1,
+        RoundingMode::Ceiling,
+        "-6.0e1",
+        "-0x4.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        1,
+        RoundingMode::Nearest,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        10,
+        RoundingMode::Floor,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        10,
+        RoundingMode::Ceiling,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        10,
+        RoundingMode::Nearest,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        10,
+        RoundingMode::Exact,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        100,
+        RoundingMode::Floor,
+        "-123.0",
+        "-0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        100,
+        RoundingMode::Ceiling,
+        "-123.0",
+        "-0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        100,
+        RoundingMode::Nearest,
+        "-123.0",
+        "-0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1",
+        100,
+        RoundingMode::Exact,
+        "-123.0",
+        "-0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "1.047",
+        "0x1.0c0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "1.049",
+        "0x1.0c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "1.047",
+        "0x1.0c0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "1.047197551196597705332654489514",
+        "0x1.0c152382d7365555555555554#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "1.047197551196597705332654489515",
+        "0x1.0c152382d7365555555555556#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "1.047197551196597705332654489515",
+        "0x1.0c152382d7365555555555556#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Floor,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Ceiling,
+        "-8.0",
+        "-0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Nearest,
+        "-8.0",
+        "-0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Floor,
+        "-9.88",
+        "-0x9.e0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Ceiling,
+        "-9.86",
+        "-0x9.dc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Nearest,
+        "-9.88",
+        "-0x9.e0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        100,
+        RoundingMode::Floor,
+        "-9.87357691128220693599359947257",
+        "-0x9.dfa2bc887f6e000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        100,
+        RoundingMode::Ceiling,
+        "-9.87357691128220693599359947257",
+        "-0x9.dfa2bc887f6e000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        100,
+        RoundingMode::Nearest,
+        "-9.87357691128220693599359947257",
+        "-0x9.dfa2bc887f6e000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        100,
+        RoundingMode::Exact,
+        "-9.87357691128220693599359947257",
+        "-0x9.dfa2bc887f6e000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "-0.4717",
+        "-0x0.78c#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "-0.4712",
+        "-0x0.78a#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "-0.4712",
+        "-0x0.78a#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "-0.4714045207910317151582072862465",
+        "-0x0.78adf777fbe99aaaaaaaaaaab0#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "-0.4714045207910317151582072862461",
+        "-0x0.78adf777fbe99aaaaaaaaaaaa8#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "-0.4714045207910317151582072862461",
+        "-0x0.78adf777fbe99aaaaaaaaaaaa8#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        10,
+        RoundingMode::Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        100,
+        RoundingMode::Ceiling,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        10,
+        RoundingMode::Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        100,
+        RoundingMode::Floor,
+        "1.000195312499999999999999999999",
+        "0x1.000cccccccccccccccccccccc#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        100,
+        RoundingMode::Ceiling,
+        "1.000195312500000000000000000001",
+        "0x1.000ccccccccccccccccccccce#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        "9/10",
+        100,
+        RoundingMode::Nearest,
+        "1.000195312499999999999999999999",
+        "0x1.000cccccccccccccccccccccc#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "3.0e29",
+        "0x4.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "6.0e29",
+        "0x8.0E+24#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "3.0e29",
+        "0x4.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "4.221e29",
+        "0x5.54E+24#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "4.228e29",
+        "0x5.56E+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "4.228e29",
+        "0x5.56E+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        100,
+        RoundingMode::Floor,
+        "422550200076076467165567735125.0",
+        "0x5555555555555555555555555.0#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        100,
+        RoundingMode::Ceiling,
+        "422550200076076467165567735125.5",
+        "0x5555555555555555555555555.8#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        100,
+        RoundingMode::Nearest,
+        "422550200076076467165567735125.5",
+        "0x5555555555555555555555555.8#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        1,
+        RoundingMode::Floor,
+        "-3.0e-30",
+        "-0x4.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        1,
+        RoundingMode::Ceiling,
+        "-2.0e-30",
+        "-0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        1,
+        RoundingMode::Nearest,
+        "-3.0e-30",
+        "-0x4.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        10,
+        RoundingMode::Floor,
+        "-2.481e-30",
+        "-0x3.25E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        10,
+        RoundingMode::Ceiling,
+        "-2.478e-30",
+        "-0x3.24E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        10,
+        RoundingMode::Nearest,
+        "-2.481e-30",
+        "-0x3.25E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        100,
+        RoundingMode::Floor,
+        "-2.479277130694608531294004062318e-30",
+        "-0x3.2492492492492492492492494E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        100,
+        RoundingMode::Ceiling,
+        "-2.479277130694608531294004062316e-30",
+        "-0x3.2492492492492492492492490E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "-22/7",
+        100,
+        RoundingMode::Nearest,
+        "-2.479277130694608531294004062318e-30",
+        "-0x3.2492492492492492492492494E-25#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn mul_rational_prec_round_fail() {
+    assert_panic!(Float::ONE.mul_rational_prec_round(Rational::ONE, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.mul_rational_prec_round_val_ref(
+        &Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::ONE.mul_rational_prec_round_ref_val(
+        Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::ONE.mul_rational_prec_round_ref_ref(
+        &Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_rational_prec_round_assign(Rational::ONE, 0, RoundingMode::Floor)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_rational_prec_round_assign_ref(&Rational::ONE, 0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::ONE.mul_rational_prec_round(
+        Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.mul_rational_prec_round_val_ref(
+        &Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.mul_rational_prec_round_ref_val(
+        Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.mul_rational_prec_round_ref_ref(
+        &Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_rational_prec_round_assign(Rational::from_unsigneds(1u8, 3), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_rational_prec_round_assign_ref(
+            &Rational::from_unsigneds(1u8, 3),
+            1,
+            RoundingMode::Exact,
+        )
+    });
+}
+
+#[test]
+fn mul_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_3().test_properties(|(x, y, prec, rm)| {
+        let (product, o) = x.clone().mul_prec_round(y.clone(), prec, rm);
+        assert!(product.is_valid());
+
+        let (product_alt, o_alt) = x.clone().mul_prec_round_val_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_prec_round_assign(y.clone(), prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_prec_round_assign_ref(&y, prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        if product.is_finite() && x.is_finite() && y.is_finite() {
+            if product.is_normal() {
+                assert_eq!(product.get_prec(), Some(prec));
+            }
+            let r_product = Rational::exact_from(&x) * Rational::exact_from(&y);
+            assert_eq!(product.partial_cmp(&r_product), Some(o));
+            if o == Ordering::Less {
+                let mut next = product.clone();
+                next.increment();
+                assert!(next > r_product);
+            } else if o == Ordering::Greater {
+                let mut next = product.clone();
+                next.decrement();
+                assert!(next < r_product);
+            }
+            match (r_product >= 0u32, rm) {
+                (_, RoundingMode::Floor)
+                | (true, RoundingMode::Down)
+                | (false, RoundingMode::Up) => {
+                    assert_ne!(o, Ordering::Greater)
+                }
+                (_, RoundingMode::Ceiling)
+                | (true, RoundingMode::Up)
+                | (false, RoundingMode::Down) => {
+                    assert_ne!(o, Ordering::Less)
+                }
+                (_, RoundingMode::Exact) => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_product, rug_o) = rug_mul_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_product)),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (product_alt, o_alt) = y.mul_prec_round_ref_ref(&x, prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (mut product_alt, mut o_alt) = (-&x).mul_prec_round_val_ref(&y, prec, -rm);
+        product_alt.neg_assign();
+        o_alt = o_alt.reverse();
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (mut product_alt, mut o_alt) = x.mul_prec_round_ref_val(-&y, prec, -rm);
+        product_alt.neg_assign();
+        o_alt = o_alt.reverse();
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.mul_prec_round_ref_ref(&y, prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(product.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.mul_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+        }
+    });
+
+    float_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (product, o) = x.mul_prec_round_ref_val(Float::NAN, prec, rm);
+        assert!(product.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (product, o) = Float::NAN.mul_prec_round_val_ref(&x, prec, rm);
+        assert!(product.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        if x.is_nan() || x == 0u32 {
+            assert!(x
+                .mul_prec_round_ref_val(Float::INFINITY, prec, rm)
+                .0
+                .is_nan());
+            assert!(Float::NEGATIVE_INFINITY
+                .mul_prec_round_val_ref(&x, prec, rm)
+                .0
+                .is_nan());
+        } else if x > 0u32 {
+            assert_eq!(
+                x.mul_prec_round_ref_val(Float::INFINITY, prec, rm),
+                (Float::INFINITY, Ordering::Equal)
+            );
+            assert_eq!(
+                Float::NEGATIVE_INFINITY.mul_prec_round_val_ref(&x, prec, rm),
+                (Float::NEGATIVE_INFINITY, Ordering::Equal)
+            );
+        } else {
+            assert_eq!(
+                x.mul_prec_round_ref_val(Float::INFINITY, prec, rm),
+                (Float::NEGATIVE_INFINITY, Ordering::Equal)
+            );
+            assert_eq!(
+                Float::NEGATIVE_INFINITY.mul_prec_round_val_ref(&x, prec, rm),
+                (Float::INFINITY, Ordering::Equal)
+            );
+        }
+        if x.is_finite() {
+            let (product, o) = x.mul_prec_round_ref_val(Float::ZERO, prec, rm);
+            assert_eq!(
+                ComparableFloat(product),
+                ComparableFloat(if x.is_sign_positive() {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                })
+            );
+            assert_eq!(o, Ordering::Equal);
+        }
+
+        let (product, o) = x.mul_prec_round_ref_val(Float::ONE, prec, rm);
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.set_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(product), ComparableFloat(product_alt));
+        assert_eq!(o, o_alt);
+
+        let (product, o) = Float::NEGATIVE_ONE.mul_prec_round_val_ref(&x, prec, -rm);
+        let mut product_alt = -&x;
+        let o_alt = product_alt.set_prec_round(prec, -rm);
+        assert_eq!(ComparableFloat(product), ComparableFloat(product_alt));
+        assert_eq!(o, o_alt);
+    });
+}
+
+#[test]
+fn mul_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (product, o) = x.clone().mul_prec(y.clone(), prec);
+        assert!(product.is_valid());
+
+        let (product_alt, o_alt) = x.clone().mul_prec_val_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_prec_ref_val(y.clone(), prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_prec_ref_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        if product.is_finite() && x.is_finite() && y.is_finite() {
+            if product.is_normal() {
+                assert_eq!(product.get_prec(), Some(prec));
+            }
+            let r_product = Rational::exact_from(&x) * Rational::exact_from(&y);
+            assert_eq!(product.partial_cmp(&r_product), Some(o));
+            if o == Ordering::Less {
+                let mut next = product.clone();
+                next.increment();
+                assert!(next > r_product);
+            } else if o == Ordering::Greater {
+                let mut next = product.clone();
+                next.decrement();
+                assert!(next < r_product);
+            }
+        }
+
+        let (rug_product, rug_o) = rug_mul_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_product)),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(rug_o, o);
+
+        let (product_alt, o_alt) = y.mul_prec_ref_ref(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = (-&x).mul_prec(-&y, prec);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn mul_round_properties_helper(x: Float, y: Float) {
+    for rm in exhaustive_rounding_modes() {
+        let prec = max(x.significant_bits(), y.significant_bits());
+        let (_, floor_o) = x.mul_prec_round_ref_ref(&y, prec, RoundingMode::Floor);
+        if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+            assert_panic!(x.mul_round_ref_ref(&y, rm));
+            continue;
+        }
+
+        let (product, o) = x.clone().mul_round(y.clone(), rm);
+        assert!(product.is_valid());
+
+        let (product_alt, o_alt) = x.clone().mul_round_val_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_round_ref_val(y.clone(), rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_round_ref_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_product, rug_o) = rug_mul_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_product)),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (product_alt, o_alt) = y.mul_round_ref_ref(&x, rm);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+    }
+}
+
+#[test]
+fn mul_round_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        mul_round_properties_helper(x, y);
+    });
+
+    float_pair_gen_var_1().test_properties(|(x, y)| {
+        mul_round_properties_helper(x, y);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn mul_properties_helper(x: Float, y: Float) {
+    let product = x.clone() * y.clone();
+    assert!(product.is_valid());
+
+    let product_alt = x.clone() * &y;
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let product_alt = &x * y.clone();
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let product_alt = &x * &y;
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let mut x_alt = x.clone();
+    x_alt *= y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+
+    let mut x_alt = x.clone();
+    x_alt *= &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+
+    let (product_alt, _) = x.mul_round_ref_ref(&y, RoundingMode::Nearest);
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let (rug_product, _) = rug_mul_prec_round(
+        &rug::Float::exact_from(&x),
+        &rug::Float::exact_from(&y),
+        max(x.significant_bits(), y.significant_bits()),
+        rug::float::Round::Nearest,
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_product)),
+        ComparableFloatRef(&product)
+    );
+
+    let product_alt = &y * &x;
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&-(-&x * &y)),
+        ComparableFloatRef(&product)
+    );
+    assert_eq!(
+        ComparableFloatRef(&-(&x * -&y)),
+        ComparableFloatRef(&product)
+    );
+}
+
+#[test]
+fn mul_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        mul_properties_helper(x, y);
+    });
+
+    float_pair_gen_var_1().test_properties(|(x, y)| {
+        mul_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        assert!((&x * Float::NAN).is_nan());
+        assert!((Float::NAN * &x).is_nan());
+        if x.is_nan() || x == 0u32 {
+            assert!((&x * Float::INFINITY).is_nan());
+            assert!((Float::NEGATIVE_INFINITY * &x).is_nan());
+        } else if x > 0u32 {
+            assert_eq!(&x * Float::INFINITY, Float::INFINITY);
+            assert_eq!(Float::NEGATIVE_INFINITY * &x, Float::NEGATIVE_INFINITY);
+        } else {
+            assert_eq!(&x * Float::INFINITY, Float::NEGATIVE_INFINITY);
+            assert_eq!(Float::NEGATIVE_INFINITY * &x, Float::INFINITY);
+        }
+        if x.is_finite() {
+            assert_eq!(
+                ComparableFloat(&x * Float::ZERO),
+                ComparableFloat(if x.is_sign_positive() {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                })
+            );
+            assert_eq!(
+                ComparableFloat(Float::NEGATIVE_ZERO * &x),
+                ComparableFloat(if x.is_sign_positive() {
+                    Float::NEGATIVE_ZERO
+                } else {
+                    Float::ZERO
+                })
+            );
+        }
+        assert_eq!(
+            ComparableFloatRef(&(&x * Float::ONE)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(
+            ComparableFloat(Float::NEGATIVE_ONE * &x),
+            ComparableFloat(-&x)
+        );
+    });
+
+    float_triple_gen().test_properties(|(x, y, z)| {
+        if x.is_finite() && y.is_finite() && z.is_finite() {
+            // A product whose precision is the sum of the factors' precisions is exact, so
+            // multiplication with enough precision is associative.
+            let prec = x.significant_bits() + y.significant_bits() + z.significant_bits();
+            let (xy, o) = x.mul_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+            assert_eq!(o, Ordering::Equal);
+            let (yz, o) = y.mul_prec_round_ref_ref(&z, prec, RoundingMode::Nearest);
+            assert_eq!(o, Ordering::Equal);
+            let (xy_z, o) = xy.mul_prec_round(z, prec, RoundingMode::Nearest);
+            assert_eq!(o, Ordering::Equal);
+            let (x_yz, o) = x.mul_prec_round(yz, prec, RoundingMode::Nearest);
+            assert_eq!(o, Ordering::Equal);
+            assert_eq!(ComparableFloat(xy_z), ComparableFloat(x_yz));
+        }
+    });
+}
+
+#[test]
+fn mul_rational_prec_round_properties() {
+    float_rational_unsigned_rounding_mode_quadruple_gen_var_3().test_properties(
+        |(x, y, prec, rm)| {
+            let (product, o) = x.clone().mul_rational_prec_round(y.clone(), prec, rm);
+            assert!(product.is_valid());
+
+            let (product_alt, o_alt) = x.clone().mul_rational_prec_round_val_ref(&y, prec, rm);
+            assert!(product_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&product_alt),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(o_alt, o);
+
+            let (product_alt, o_alt) = x.mul_rational_prec_round_ref_val(y.clone(), prec, rm);
+            assert!(product_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&product_alt),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(o_alt, o);
+
+            let (product_alt, o_alt) = x.mul_rational_prec_round_ref_ref(&y, prec, rm);
+            assert!(product_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&product_alt),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.mul_rational_prec_round_assign(y.clone(), prec, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.mul_rational_prec_round_assign_ref(&y, prec, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+            assert_eq!(o_alt, o);
+
+            if product.is_finite() && x.is_finite() {
+                if product.is_normal() {
+                    assert_eq!(product.get_prec(), Some(prec));
+                }
+                let r_product = Rational::exact_from(&x) * &y;
+                assert_eq!(product.partial_cmp(&r_product), Some(o));
+                if o == Ordering::Less {
+                    let mut next = product.clone();
+                    next.increment();
+                    assert!(next > r_product);
+                } else if o == Ordering::Greater {
+                    let mut next = product.clone();
+                    next.decrement();
+                    assert!(next < r_product);
+                }
+                match (r_product >= 0u32, rm) {
+                    (_, RoundingMode::Floor)
+                    | (true, RoundingMode::Down)
+                    | (false, RoundingMode::Up) => {
+                        assert_ne!(o, Ordering::Greater)
+                    }
+                    (_, RoundingMode::Ceiling)
+                    | (true, RoundingMode::Up)
+                    | (false, RoundingMode::Down) => {
+                        assert_ne!(o, Ordering::Less)
+                    }
+                    (_, RoundingMode::Exact) => assert_eq!(o, Ordering::Equal),
+                    _ => {}
+                }
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_product, rug_o) = rug_mul_rational_prec_round(
+                    &rug::Float::exact_from(&x),
+                    &rug::Rational::exact_from(&y),
+                    prec,
+                    rug_rm,
+                );
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_product)),
+                    ComparableFloatRef(&product)
+                );
+                assert_eq!(rug_o, o);
+            }
+
+            if x.is_finite() && y != 0u32 {
+                let (y_alt, y_o) =
+                    Float::from_rational_prec_round_ref(&y, 4096, RoundingMode::Floor);
+                if y_o == Ordering::Equal {
+                    let (product_alt, o_alt) = x.mul_prec_round_ref_val(y_alt, prec, rm);
+                    assert_eq!(
+                        ComparableFloatRef(&product_alt),
+                        ComparableFloatRef(&product)
+                    );
+                    assert_eq!(o_alt, o);
+                }
+            }
+
+            if o == Ordering::Equal {
+                for rm in exhaustive_rounding_modes() {
+                    let (s, oo) = x.mul_rational_prec_round_ref_ref(&y, prec, rm);
+                    assert_eq!(
+                        ComparableFloat(s.abs_negative_zero()),
+                        ComparableFloat(product.abs_negative_zero_ref())
+                    );
+                    assert_eq!(oo, Ordering::Equal);
+                }
+            } else {
+                assert_panic!(x.mul_rational_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+            }
+        },
+    );
+}
+
+#[test]
+fn mul_rational_prec_properties() {
+    float_rational_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (product, o) = x.clone().mul_rational_prec(y.clone(), prec);
+        assert!(product.is_valid());
+
+        let (product_alt, o_alt) = x.clone().mul_rational_prec_val_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_rational_prec_ref_val(y.clone(), prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_rational_prec_ref_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_rational_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_rational_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) =
+            x.mul_rational_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        if product.is_finite() && x.is_finite() {
+            let r_product = Rational::exact_from(&x) * &y;
+            assert_eq!(product.partial_cmp(&r_product), Some(o));
+        }
+
+        let (rug_product, rug_o) = rug_mul_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_product)),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn mul_rational_round_properties_helper(x: Float, y: Rational) {
+    for rm in exhaustive_rounding_modes() {
+        let prec = x.significant_bits();
+        let (_, floor_o) = x.mul_rational_prec_round_ref_ref(&y, prec, RoundingMode::Floor);
+        if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+            assert_panic!(x.mul_rational_round_ref_ref(&y, rm));
+            continue;
+        }
+
+        let (product, o) = x.clone().mul_rational_round(y.clone(), rm);
+        assert!(product.is_valid());
+
+        let (product_alt, o_alt) = x.clone().mul_rational_round_val_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_rational_round_ref_val(y.clone(), rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_rational_round_ref_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_rational_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_rational_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_rational_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_product, rug_o) = rug_mul_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_product)),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(rug_o, o);
+        }
+    }
+}
+
+#[test]
+fn mul_rational_round_properties() {
+    float_rational_pair_gen().test_properties(|(x, y)| {
+        mul_rational_round_properties_helper(x, y);
+    });
+
+    float_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        mul_rational_round_properties_helper(x, y);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn mul_rational_properties_helper(x: Float, y: Rational) {
+    let product = x.clone() * y.clone();
+    assert!(product.is_valid());
+
+    let product_alt = x.clone() * &y;
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let product_alt = &x * y.clone();
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let product_alt = &x * &y;
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let product_alt = y.clone() * x.clone();
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let product_alt = y.clone() * &x;
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let product_alt = &y * x.clone();
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let product_alt = &y * &x;
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let mut x_alt = x.clone();
+    x_alt *= y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+
+    let mut x_alt = x.clone();
+    x_alt *= &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+
+    let (product_alt, _) = x.mul_rational_round_ref_ref(&y, RoundingMode::Nearest);
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let (rug_product, _) = rug_mul_rational_prec_round(
+        &rug::Float::exact_from(&x),
+        &rug::Rational::exact_from(&y),
+        x.significant_bits(),
+        rug::float::Round::Nearest,
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_product)),
+        ComparableFloatRef(&product)
+    );
+}
+
+#[test]
+fn mul_rational_properties() {
+    float_rational_pair_gen().test_properties(|(x, y)| {
+        mul_rational_properties_helper(x, y);
+    });
+
+    float_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        mul_rational_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        if x.is_finite() {
+            assert_eq!(
+                ComparableFloat(&x * Rational::ZERO),
+                ComparableFloat(if x.is_sign_positive() {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                })
+            );
+            assert_eq!(
+                ComparableFloat(Rational::ZERO * &x),
+                ComparableFloat(if x.is_sign_positive() {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                })
+            );
+        } else {
+            assert!((&x * Rational::ZERO).is_nan());
+        }
+        assert_eq!(
+            ComparableFloatRef(&(&x * Rational::ONE)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(
+            ComparableFloat(Rational::NEGATIVE_ONE * &x),
+            ComparableFloat(-&x)
+        );
+    });
+
+    rational_gen().test_properties(|x| {
+        assert!((x.clone() * Float::NAN).is_nan());
+        assert!((Float::NAN * x.clone()).is_nan());
+    });
+}
+
+#[test]
+fn mul_natural_properties() {
+    float_natural_pair_gen().test_properties(|(x, y)| {
+        let product = x.clone() * y.clone();
+        assert!(product.is_valid());
+
+        let product_alt = x.clone() * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = &x * y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = &x * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = y.clone() * x.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = y.clone() * &x;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = &y * x.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = &y * &x;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let mut x_alt = x.clone();
+        x_alt *= y.clone();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+
+        let mut x_alt = x.clone();
+        x_alt *= &y;
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+
+        let (product_alt, _) =
+            x.mul_rational_round_ref_val(Rational::from(&y), RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+    });
+}
+
+#[test]
+fn mul_integer_properties() {
+    float_integer_pair_gen().test_properties(|(x, y)| {
+        let product = x.clone() * y.clone();
+        assert!(product.is_valid());
+
+        let product_alt = x.clone() * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = &x * y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = &x * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = y.clone() * x.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = y.clone() * &x;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = &y * x.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let product_alt = &y * &x;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+
+        let mut x_alt = x.clone();
+        x_alt *= y.clone();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+
+        let mut x_alt = x.clone();
+        x_alt *= &y;
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+
+        let (product_alt, _) =
+            x.mul_rational_round_ref_val(Rational::from(&y), RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+    });
+}
@@ -0,0 +1,398 @@
+use malachite_base::num::arithmetic::traits::{AddMul, AddMulAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_unsigned_rounding_mode_quadruple_gen_var_1,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_3, float_triple_gen,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+
+fn rug_mul_add_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    z: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.mul_add_ref(y, z), rm)
+}
+
+#[test]
+fn test_mul_add_prec_round() {
+    let test = |s_hex, t_hex, u_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        let y = parse_hex_string(t_hex);
+        let z = parse_hex_string(u_hex);
+
+        let (r, o) = x.clone().mul_add_prec_round(y.clone(), z.clone(), prec, rm);
+        assert!(r.is_valid());
+        assert_eq!(o, o_out);
+        assert_eq!(r.to_string(), out);
+        assert_eq!(to_hex_string(&r), out_hex);
+
+        let (r_alt, o_alt) = x.mul_add_prec_round_ref_ref_ref(&y, &z, prec, rm);
+        assert!(r_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&r), ComparableFloatRef(&r_alt));
+        assert_eq!(o_alt, o_out);
+
+        if rm == RoundingMode::Nearest {
+            let (r_alt, o_alt) = x.clone().mul_add_prec(y.clone(), z.clone(), prec);
+            assert!(r_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&r), ComparableFloatRef(&r_alt));
+            assert_eq!(o_alt, o_out);
+            let (r_alt, o_alt) = x.mul_add_prec_ref_ref_ref(&y, &z, prec);
+            assert!(r_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&r), ComparableFloatRef(&r_alt));
+            assert_eq!(o_alt, o_out);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_r, rug_o) = rug_mul_add_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&z),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_r)),
+                ComparableFloatRef(&r)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "0x1.0#1",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "0x0.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "0x1.0#1",
+        "-Infinity",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "-0x1.0#1",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0x0.0",
+        "-0x1.0#1",
+        "0x0.0",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0x0.0",
+        "-0x1.0#1",
+        "0x0.0",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0x0.0",
+        "0x1.0#1",
+        "-0x0.0",
+        10,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0x0.0",
+        "0x0.0",
+        "0x1.8#3",
+        10,
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    // - pi * e + 1
+    test(
+        "0x3.243f6a8885a30#53",
+        "0x2.b7e151628aed2#53",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "9.55",
+        "0x9.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0x3.243f6a8885a30#53",
+        "0x2.b7e151628aed2#53",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "9.53",
+        "0x9.88#10",
+        Ordering::Less,
+    );
+    test(
+        "0x3.243f6a8885a30#53",
+        "0x2.b7e151628aed2#53",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "9.53973422267356627840667135511",
+        "0x9.8a2c05a2ea3a1679e124a69b#100",
+        Ordering::Less,
+    );
+    // - 0.1 * 10 - 1, exact
+    test(
+        "0x0.1999999999999a#53",
+        "0xa.0#3",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "5.55e-17",
+        "0x4.00E-14#10",
+        Ordering::Equal,
+    );
+    // - (1 + 2^-200)(1 - 2^-200) - 1 = -2^-400
+    test(
+        "0x1.00000000000000000000000000000000000000000000000001#201",
+        "0x0.ffffffffffffffffffffffffffffffffffffffffffffffffff#200",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-3.873e-121",
+        "-0x1.000E-100#10",
+        Ordering::Equal,
+    );
+    // - z is far below the rounding position of xy
+    test(
+        "0x1.0E+1000#1",
+        "0x1.0E+1000#1",
+        "0x1.0E-1000#1",
+        10,
+        RoundingMode::Nearest,
+        "1.738e2408",
+        "0x1.000E+2000#10",
+        Ordering::Less,
+    );
+    test(
+        "0x1.0E+1000#1",
+        "0x1.0E+1000#1",
+        "0x1.0E-1000#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.741e2408",
+        "0x1.008E+2000#10",
+        Ordering::Greater,
+    );
+    test(
+        "0x1.0E+1000#1",
+        "0x1.0E+1000#1",
+        "-0x1.0E-1000#1",
+        10,
+        RoundingMode::Nearest,
+        "1.738e2408",
+        "0x1.000E+2000#10",
+        Ordering::Greater,
+    );
+    test(
+        "0x1.0E+1000#1",
+        "0x1.0E+1000#1",
+        "-0x1.0E-1000#1",
+        10,
+        RoundingMode::Floor,
+        "1.736e2408",
+        "0xf.fcE+1999#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn mul_add_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).mul_add_prec_round(
+        Float::one_prec(1),
+        Float::one_prec(1),
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::from(3).mul_add_prec_round(
+        Float::from(3),
+        Float::one_prec(1),
+        2,
+        RoundingMode::Exact
+    ));
+}
+
+#[test]
+fn test_add_mul() {
+    let test = |s_hex, t_hex, u_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        let y = parse_hex_string(t_hex);
+        let z = parse_hex_string(u_hex);
+
+        let r = x.clone().add_mul(y.clone(), z.clone());
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), out);
+        assert_eq!(to_hex_string(&r), out_hex);
+
+        let r_alt = (&x).add_mul(&y, &z);
+        assert!(r_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&r), ComparableFloatRef(&r_alt));
+
+        let mut r_alt = x.clone();
+        r_alt.add_mul_assign(y.clone(), z.clone());
+        assert!(r_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&r), ComparableFloatRef(&r_alt));
+    };
+    test("NaN", "0x1.0#1", "0x1.0#1", "NaN", "NaN");
+    test("0x1.0#1", "Infinity", "-0x0.0", "NaN", "NaN");
+    test("0x0.0", "-0x1.0#1", "0x0.0", "0.0", "0x0.0");
+    test("-0x0.0", "-0x1.0#1", "0x0.0", "-0.0", "-0x0.0");
+    test(
+        "-0x1.0#1",
+        "0x0.1999999999999a#53",
+        "0xa.0#3",
+        "5.551115123125783e-17",
+        "0x4.0000000000000E-14#53",
+    );
+    test(
+        "0x1.0#1",
+        "0x3.243f6a8885a30#53",
+        "0x2.b7e151628aed2#53",
+        "9.539734222673566",
+        "0x9.8a2c05a2ea3a0#53",
+    );
+}
+
+#[test]
+fn mul_add_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_1().test_properties(|(x, y, prec, rm)| {
+        // x * 1 + y is x + y
+        let (r, o) = x.mul_add_prec_round_ref_ref_ref(&Float::ONE, &y, prec, rm);
+        assert!(r.is_valid());
+        let (r_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(ComparableFloatRef(&r), ComparableFloatRef(&r_alt));
+        assert_eq!(o, o_alt);
+
+        let (r_alt, o_alt) = x
+            .clone()
+            .mul_add_prec_round(Float::ONE, y.clone(), prec, rm);
+        assert_eq!(ComparableFloatRef(&r), ComparableFloatRef(&r_alt));
+        assert_eq!(o, o_alt);
+    });
+
+    float_float_unsigned_rounding_mode_quadruple_gen_var_3().test_properties(|(x, y, prec, rm)| {
+        // x * y + 0 is x * y, unless x * y is zero
+        let (r, o) = x.mul_add_prec_round_ref_ref_ref(&y, &Float::ZERO, prec, rm);
+        assert!(r.is_valid());
+        let (r_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, rm);
+        if r_alt != 0u32 {
+            assert_eq!(ComparableFloatRef(&r), ComparableFloatRef(&r_alt));
+            assert_eq!(o, o_alt);
+        }
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_r, rug_o) = rug_mul_add_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&Float::ZERO),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_r)),
+                ComparableFloatRef(&r)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    float_triple_gen().test_properties(|(x, y, z)| {
+        let r = (&z).add_mul(&x, &y);
+        assert!(r.is_valid());
+        let prec = max(
+            x.significant_bits(),
+            max(y.significant_bits(), z.significant_bits()),
+        );
+        let (r_alt, o) = x.mul_add_prec_ref_ref_ref(&y, &z, prec);
+        assert_eq!(ComparableFloatRef(&r), ComparableFloatRef(&r_alt));
+        if r.is_finite() && x.is_finite() && y.is_finite() && z.is_finite() {
+            let exact =
+                Rational::exact_from(&x) * Rational::exact_from(&y) + Rational::exact_from(&z);
+            assert_eq!(r.partial_cmp(&exact), Some(o));
+            if r != 0u32 {
+                assert_eq!(
+                    ComparableFloat(r),
+                    ComparableFloat(Float::from_rational_prec(exact, prec).0)
+                );
+            }
+        }
+    });
+}
@@ -0,0 +1,2972 @@
+use malachite_base::num::arithmetic::traits::{
+    IsPowerOf2, NegAssign, Reciprocal, ReciprocalAssign,
+};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_2,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_reciprocal_prec_round(
+    x: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.recip_ref(), rm)
+}
+
+#[test]
+fn test_reciprocal() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let reciprocal = x.clone().reciprocal();
+        assert!(reciprocal.is_valid());
+
+        assert_eq!(reciprocal.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal), out_hex);
+
+        let reciprocal_alt = (&x).reciprocal();
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal),
+            ComparableFloatRef(&reciprocal_alt)
+        );
+
+        let mut reciprocal_alt = x.clone();
+        reciprocal_alt.reciprocal_assign();
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal),
+            ComparableFloatRef(&reciprocal_alt)
+        );
+
+        let (rug_reciprocal, _) = rug_reciprocal_prec_round(
+            &rug::Float::exact_from(&x),
+            x.significant_bits(),
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal)),
+            ComparableFloatRef(&reciprocal)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "Infinity", "Infinity");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test("0.5", "0x0.8#1", "2.0", "0x2.0#1");
+    test("123.0", "0x7b.0#7", "0.0082", "0x0.0218#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "0.31830988618379069",
+        "0x0.517cc1b727220c#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.36787944117144233",
+        "0x0.5e2d58d8b3bce0#53",
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-0.7071067811865475",
+        "-0x0.b504f333f9de60#53",
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "3.0",
+        "0x3.0000000000000#53",
+    );
+    test("1.0e30", "0x1.0E+25#1", "8.0e-31", "0x1.0E-25#1");
+    test("8.0e-31", "0x1.0E-25#1", "1.0e30", "0x1.0E+25#1");
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "3.0",
+        "0x3.00000000000000000000000000000000000000000000000000#200",
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "-0.3181818181818181818181818181818181818181818183",
+        "-0x0.51745d1745d1745d1745d1745d1745d1745d18#150",
+    );
+    test("1.111", "0x1.1c8#10", "0.899", "0x0.e64#10");
+}
+
+#[test]
+fn test_reciprocal_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal, o) = x.clone().reciprocal_prec(prec);
+        assert!(reciprocal.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(reciprocal.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal), out_hex);
+
+        let (reciprocal_alt, o_alt) = x.reciprocal_prec_ref(prec);
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal),
+            ComparableFloatRef(&reciprocal_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_alt = x.clone();
+        let o_alt = reciprocal_alt.reciprocal_prec_assign(prec);
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal),
+            ComparableFloatRef(&reciprocal_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_reciprocal, rug_o) = rug_reciprocal_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal)),
+            ComparableFloatRef(&reciprocal)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("Infinity", "Infinity", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("Infinity", "Infinity", 100, "0.0", "0x0.0", Ordering::Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 1, "Infinity", "Infinity", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "Infinity", "Infinity", Ordering::Equal);
+    test("0.0", "0x0.0", 100, "Infinity", "Infinity", Ordering::Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("1.0", "0x1.0#1", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Ordering::Equal);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("0.5", "0x0.8#1", 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test("0.5", "0x0.8#1", 10, "2.0", "0x2.00#10", Ordering::Equal);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("123.0", "0x7b.0#7", 1, "0.008", "0x0.02#1", Ordering::Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "0.00813",
+        "0x0.0215#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "0.00813008130081300813008130081301",
+        "0x0.0214d0214d0214d0214d0214d04#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "0.3184",
+        "0x0.518#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "0.3183098861837906839460338500859",
+        "0x0.517cc1b727220b79e27534dae8#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.3677",
+        "0x0.5e2#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.3678794411714423411602269253923",
+        "0x0.5e2d58d8b3bce083a29193a888#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "-0.707",
+        "-0x0.b50#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "-0.7071067811865474760643777948406",
+        "-0x0.b504f333f9de6108b2fb1366f#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "3.000000000000000166533453693773",
+        "0x3.0000000000000c00000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "-0.3184",
+        "-0x0.518#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "-0.318181818181818181818181818182",
+        "-0x0.51745d1745d1745d1745d17460#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "0.899",
+        "0x0.e64#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "0.8998242530755711775043936731104",
+        "0x0.e65ae1db1b71d3e89ac50fbf3#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn reciprocal_prec_fail() {
+    assert_panic!(Float::NAN.reciprocal_prec(0));
+    assert_panic!(Float::NAN.reciprocal_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.reciprocal_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_reciprocal_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal, o) = x.clone().reciprocal_round(rm);
+        assert!(reciprocal.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(reciprocal.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal), out_hex);
+
+        let (reciprocal_alt, o_alt) = x.reciprocal_round_ref(rm);
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal),
+            ComparableFloatRef(&reciprocal_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_alt = x.clone();
+        let o_alt = reciprocal_alt.reciprocal_round_assign(rm);
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal),
+            ComparableFloatRef(&reciprocal_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal, rug_o) =
+                rug_reciprocal_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal)),
+                ComparableFloatRef(&reciprocal)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "0.0081",
+        "0x0.0210#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "0.0082",
+        "0x0.0218#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "0.0081",
+        "0x0.0210#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "0.0082",
+        "0x0.0218#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "0.0082",
+        "0x0.0218#7",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "0.31830988618379064",
+        "0x0.517cc1b7272208#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "0.31830988618379069",
+        "0x0.517cc1b727220c#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "0.31830988618379064",
+        "0x0.517cc1b7272208#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "0.31830988618379069",
+        "0x0.517cc1b727220c#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "0.31830988618379069",
+        "0x0.517cc1b727220c#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "0.36787944117144233",
+        "0x0.5e2d58d8b3bce0#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "0.36787944117144239",
+        "0x0.5e2d58d8b3bce4#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "0.36787944117144233",
+        "0x0.5e2d58d8b3bce0#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "0.36787944117144239",
+        "0x0.5e2d58d8b3bce4#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "0.36787944117144233",
+        "0x0.5e2d58d8b3bce0#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "-0.7071067811865476",
+        "-0x0.b504f333f9de68#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "-0.7071067811865475",
+        "-0x0.b504f333f9de60#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "-0.7071067811865475",
+        "-0x0.b504f333f9de60#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "-0.7071067811865476",
+        "-0x0.b504f333f9de68#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "-0.7071067811865475",
+        "-0x0.b504f333f9de60#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "3.0000000000000004",
+        "0x3.0000000000002#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "3.0",
+        "0x3.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "3.0000000000000004",
+        "0x3.0000000000002#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Exact,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "2.999999999999999999999999999999999999999999999999999999999998",
+        "0x2.fffffffffffffffffffffffffffffffffffffffffffffffffc#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.00000000000000000000000000000000000000000000000000#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "2.999999999999999999999999999999999999999999999999999999999998",
+        "0x2.fffffffffffffffffffffffffffffffffffffffffffffffffc#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "3.0",
+        "0x3.00000000000000000000000000000000000000000000000000#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00000000000000000000000000000000000000000000000000#200",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "-0.3181818181818181818181818181818181818181818183",
+        "-0x0.51745d1745d1745d1745d1745d1745d1745d18#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "-0.318181818181818181818181818181818181818181818",
+        "-0x0.51745d1745d1745d1745d1745d1745d1745d16#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "-0.318181818181818181818181818181818181818181818",
+        "-0x0.51745d1745d1745d1745d1745d1745d1745d16#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "-0.3181818181818181818181818181818181818181818183",
+        "-0x0.51745d1745d1745d1745d1745d1745d1745d18#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "-0.3181818181818181818181818181818181818181818183",
+        "-0x0.51745d1745d1745d1745d1745d1745d1745d18#150",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "0.899",
+        "0x0.e64#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "0.9",
+        "0x0.e68#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "0.899",
+        "0x0.e64#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "0.9",
+        "0x0.e68#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "0.899",
+        "0x0.e64#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn reciprocal_round_fail() {
+    assert_panic!(Float::from(3u32).reciprocal_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).reciprocal_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.reciprocal_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_reciprocal_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal, o) = x.clone().reciprocal_prec_round(prec, rm);
+        assert!(reciprocal.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(reciprocal.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal), out_hex);
+
+        let (reciprocal_alt, o_alt) = x.reciprocal_prec_round_ref(prec, rm);
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal),
+            ComparableFloatRef(&reciprocal_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_alt = x.clone();
+        let o_alt = reciprocal_alt.reciprocal_prec_round_assign(prec, rm);
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal),
+            ComparableFloatRef(&reciprocal_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal, rug_o) =
+                rug_reciprocal_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal)),
+                ComparableFloatRef(&reciprocal)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Floor,
+        "0.008",
+        "0x0.02#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Ceiling,
+        "0.02",
+        "0x0.04#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Nearest,
+        "0.008",
+        "0x0.02#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Floor,
+        "0.00812",
+        "0x0.0214#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "0.00813",
+        "0x0.0215#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Nearest,
+        "0.00813",
+        "0x0.0215#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        RoundingMode::Floor,
+        "0.008130081300813008130081300813",
+        "0x0.0214d0214d0214d0214d0214d00#100",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        RoundingMode::Ceiling,
+        "0.00813008130081300813008130081301",
+        "0x0.0214d0214d0214d0214d0214d04#100",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        RoundingMode::Nearest,
+        "0.00813008130081300813008130081301",
+        "0x0.0214d0214d0214d0214d0214d04#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "0.3179",
+        "0x0.516#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.3184",
+        "0x0.518#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "0.3184",
+        "0x0.518#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "0.3183098861837906839460338500859",
+        "0x0.517cc1b727220b79e27534dae8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.3183098861837906839460338500863",
+        "0x0.517cc1b727220b79e27534daf0#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "0.3183098861837906839460338500859",
+        "0x0.517cc1b727220b79e27534dae8#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "0.3677",
+        "0x0.5e2#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.3682",
+        "0x0.5e4#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "0.3677",
+        "0x0.5e2#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Floor,
+        "0.3678794411714423411602269253919",
+        "0x0.5e2d58d8b3bce083a29193a880#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.3678794411714423411602269253923",
+        "0x0.5e2d58d8b3bce083a29193a888#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Nearest,
+        "0.3678794411714423411602269253923",
+        "0x0.5e2d58d8b3bce083a29193a888#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "-0.708",
+        "-0x0.b54#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.707",
+        "-0x0.b50#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.707",
+        "-0x0.b50#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "-0.7071067811865474760643777948406",
+        "-0x0.b504f333f9de6108b2fb1366f#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "-0.70710678118654747606437779484",
+        "-0x0.b504f333f9de6108b2fb1366e#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "-0.7071067811865474760643777948406",
+        "-0x0.b504f333f9de6108b2fb1366f#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        RoundingMode::Ceiling,
+        "3.004",
+        "0x3.01#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        RoundingMode::Floor,
+        "3.000000000000000166533453693773",
+        "0x3.0000000000000c00000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        RoundingMode::Ceiling,
+        "3.000000000000000166533453693777",
+        "0x3.0000000000000c00000000004#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        RoundingMode::Nearest,
+        "3.000000000000000166533453693773",
+        "0x3.0000000000000c00000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Exact,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Exact,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Floor,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Ceiling,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Nearest,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Exact,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Exact,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Exact,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "2.996",
+        "0x2.ff#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "2.999999999999999999999999999997",
+        "0x2.ffffffffffffffffffffffffc#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Floor,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Ceiling,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        RoundingMode::Nearest,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Floor,
+        "-0.3184",
+        "-0x0.518#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Ceiling,
+        "-0.3179",
+        "-0x0.516#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        RoundingMode::Nearest,
+        "-0.3184",
+        "-0x0.518#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Floor,
+        "-0.318181818181818181818181818182",
+        "-0x0.51745d1745d1745d1745d17460#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Ceiling,
+        "-0.3181818181818181818181818181816",
+        "-0x0.51745d1745d1745d1745d17458#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        RoundingMode::Nearest,
+        "-0.318181818181818181818181818182",
+        "-0x0.51745d1745d1745d1745d17460#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "0.899",
+        "0x0.e64#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.9",
+        "0x0.e68#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "0.899",
+        "0x0.e64#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "0.8998242530755711775043936731104",
+        "0x0.e65ae1db1b71d3e89ac50fbf3#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "0.899824253075571177504393673111",
+        "0x0.e65ae1db1b71d3e89ac50fbf4#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "0.8998242530755711775043936731104",
+        "0x0.e65ae1db1b71d3e89ac50fbf3#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn reciprocal_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).reciprocal_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).reciprocal_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.reciprocal_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).reciprocal_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).reciprocal_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.reciprocal_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn reciprocal_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_2().test_properties(|(x, prec, rm)| {
+        let (reciprocal, o) = x.clone().reciprocal_prec_round(prec, rm);
+        assert!(reciprocal.is_valid());
+
+        let (reciprocal_alt, o_alt) = x.reciprocal_prec_round_ref(prec, rm);
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_alt),
+            ComparableFloatRef(&reciprocal)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.reciprocal_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&reciprocal));
+        assert_eq!(o_alt, o);
+
+        if reciprocal.is_finite() && x.is_finite() && x != 0u32 {
+            if reciprocal.is_normal() {
+                assert_eq!(reciprocal.get_prec(), Some(prec));
+            }
+            let r_reciprocal = Rational::exact_from(&x).reciprocal();
+            assert_eq!(reciprocal.partial_cmp(&r_reciprocal), Some(o));
+            if o == Ordering::Less {
+                let mut next = reciprocal.clone();
+                next.increment();
+                assert!(next > r_reciprocal);
+            } else if o == Ordering::Greater {
+                let mut next = reciprocal.clone();
+                next.decrement();
+                assert!(next < r_reciprocal);
+            }
+            match (r_reciprocal >= 0u32, rm) {
+                (_, RoundingMode::Floor)
+                | (true, RoundingMode::Down)
+                | (false, RoundingMode::Up) => {
+                    assert_ne!(o, Ordering::Greater)
+                }
+                (_, RoundingMode::Ceiling)
+                | (true, RoundingMode::Up)
+                | (false, RoundingMode::Down) => {
+                    assert_ne!(o, Ordering::Less)
+                }
+                (_, RoundingMode::Exact) => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal, rug_o) =
+                rug_reciprocal_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal)),
+                ComparableFloatRef(&reciprocal)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (reciprocal_alt, o_alt) = Float::ONE.div_prec_round_val_ref(&x, prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_alt),
+            ComparableFloatRef(&reciprocal)
+        );
+        assert_eq!(o_alt, o);
+
+        let (mut reciprocal_alt, mut o_alt) = (-&x).reciprocal_prec_round(prec, -rm);
+        reciprocal_alt.neg_assign();
+        o_alt = o_alt.reverse();
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_alt),
+            ComparableFloatRef(&reciprocal)
+        );
+        assert_eq!(o_alt, o);
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (r, oo) = x.reciprocal_prec_round_ref(prec, rm);
+                assert_eq!(
+                    ComparableFloat(r.abs_negative_zero()),
+                    ComparableFloat(reciprocal.abs_negative_zero_ref())
+                );
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.reciprocal_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn reciprocal_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (reciprocal, o) = x.clone().reciprocal_prec(prec);
+        assert!(reciprocal.is_valid());
+
+        let (reciprocal_alt, o_alt) = x.reciprocal_prec_ref(prec);
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_alt),
+            ComparableFloatRef(&reciprocal)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.reciprocal_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&reciprocal));
+        assert_eq!(o_alt, o);
+
+        let (reciprocal_alt, o_alt) = x.reciprocal_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_alt),
+            ComparableFloatRef(&reciprocal)
+        );
+        assert_eq!(o_alt, o);
+
+        if reciprocal.is_finite() && x.is_finite() && x != 0u32 {
+            let r_reciprocal = Rational::exact_from(&x).reciprocal();
+            assert_eq!(reciprocal.partial_cmp(&r_reciprocal), Some(o));
+        }
+
+        let (rug_reciprocal, rug_o) = rug_reciprocal_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal)),
+            ComparableFloatRef(&reciprocal)
+        );
+        assert_eq!(rug_o, o);
+
+        let (mut reciprocal_alt, mut o_alt) = (-&x).reciprocal_prec(prec);
+        reciprocal_alt.neg_assign();
+        o_alt = o_alt.reverse();
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_alt),
+            ComparableFloatRef(&reciprocal)
+        );
+        assert_eq!(o_alt, o);
+    });
+}
+
+#[test]
+fn reciprocal_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.reciprocal_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.reciprocal_round_ref(rm));
+                continue;
+            }
+
+            let (reciprocal, o) = x.clone().reciprocal_round(rm);
+            assert!(reciprocal.is_valid());
+
+            let (reciprocal_alt, o_alt) = x.reciprocal_round_ref(rm);
+            assert!(reciprocal_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&reciprocal_alt),
+                ComparableFloatRef(&reciprocal)
+            );
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.reciprocal_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&reciprocal));
+            assert_eq!(o_alt, o);
+
+            let (reciprocal_alt, o_alt) = x.reciprocal_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&reciprocal_alt),
+                ComparableFloatRef(&reciprocal)
+            );
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_reciprocal, rug_o) =
+                    rug_reciprocal_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_reciprocal)),
+                    ComparableFloatRef(&reciprocal)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+}
+
+#[test]
+fn reciprocal_properties() {
+    float_gen().test_properties(|x| {
+        let reciprocal = x.clone().reciprocal();
+        assert!(reciprocal.is_valid());
+
+        let reciprocal_alt = (&x).reciprocal();
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_alt),
+            ComparableFloatRef(&reciprocal)
+        );
+
+        let mut x_alt = x.clone();
+        x_alt.reciprocal_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&reciprocal));
+
+        let (reciprocal_alt, _) = x.reciprocal_round_ref(RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_alt),
+            ComparableFloatRef(&reciprocal)
+        );
+
+        let (rug_reciprocal, _) = rug_reciprocal_prec_round(
+            &rug::Float::exact_from(&x),
+            x.significant_bits(),
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal)),
+            ComparableFloatRef(&reciprocal)
+        );
+
+        assert_eq!(
+            ComparableFloatRef(&-(-&x).reciprocal()),
+            ComparableFloatRef(&reciprocal)
+        );
+
+        if x.is_finite() && x.is_power_of_2() {
+            // The reciprocal of a power of 2 is exact, so reciprocating twice gives back the
+            // original value.
+            assert_eq!(
+                ComparableFloatRef(&(&reciprocal).reciprocal()),
+                ComparableFloatRef(&x)
+            );
+        }
+    });
+
+    assert!(Float::NAN.reciprocal().is_nan());
+    assert_eq!(
+        ComparableFloat(Float::INFINITY.reciprocal()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_INFINITY.reciprocal()),
+        ComparableFloat(Float::NEGATIVE_ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::ZERO.reciprocal()),
+        ComparableFloat(Float::INFINITY)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ZERO.reciprocal()),
+        ComparableFloat(Float::NEGATIVE_INFINITY)
+    );
+    assert_eq!(
+        ComparableFloat(Float::ONE.reciprocal()),
+        ComparableFloat(Float::ONE)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ONE.reciprocal()),
+        ComparableFloat(Float::NEGATIVE_ONE)
+    );
+}
@@ -0,0 +1,3137 @@
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_4,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_reciprocal_sqrt_prec_round(
+    x: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.recip_sqrt_ref(), rm)
+}
+
+// Returns $y^2x$, which is less than, equal to, or greater than 1 exactly when $y$ is less than,
+// equal to, or greater than $1/\sqrt{x}$.
+fn square_times(y: &Float, x: &Rational) -> Rational {
+    let y = Rational::exact_from(y);
+    &y * &y * x
+}
+
+#[test]
+fn test_reciprocal_sqrt_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec(prec);
+        assert!(reciprocal_sqrt.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(reciprocal_sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal_sqrt), out_hex);
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_ref(prec);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_sqrt_alt = x.clone();
+        let o_alt = reciprocal_sqrt_alt.reciprocal_sqrt_prec_assign(prec);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_reciprocal_sqrt, rug_o) = rug_reciprocal_sqrt_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("Infinity", "Infinity", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("Infinity", "Infinity", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "Infinity", "Infinity", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "Infinity", "Infinity", Ordering::Equal);
+    test("0.0", "0x0.0", 100, "Infinity", "Infinity", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "Infinity", "Infinity", Ordering::Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("1.0", "0x1.0#1", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.5", "0x0.8#1", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test("0.5", "0x0.8#1", 10, "1.414", "0x1.6a0#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Ordering::Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "0.06", "0x0.1#1", Ordering::Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "0.0902",
+        "0x0.1718#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "0.0901669634667432289597691065253",
+        "0x0.17152e9f44f01eea106bb2b278#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "0.564",
+        "0x0.908#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "0.564189583547756297944619165494",
+        "0x0.906eba8214db69584b8553dd8#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.606",
+        "0x0.9b4#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.6065306597126334397321706584727",
+        "0x0.9b4597e37cb0511d5a89eae2c#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "1.732",
+        "0x1.bb8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "1.732050807568877341601513501094",
+        "0x1.bb67ae8584caaab1f4d1387a0#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "8.88e-16",
+        "0x4.00E-13#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "8.88178419700125232338905334473e-16",
+        "0x4.0000000000000000000000000E-13#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.126e15",
+        "0x4.00E+12#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1125899906842624.0",
+        "0x4000000000000.0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "1.732",
+        "0x1.bb8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "1.732050807568877293527446341506",
+        "0x1.bb67ae8584caa73b25742d708#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "0.948",
+        "0x0.f2c#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "0.948590666766003542416066871839",
+        "0x0.f2d6d6830d027e5c7ca6da615#100",
+        Ordering::Greater,
+    );
+    test("4.0", "0x4.0#3", 1, "0.5", "0x0.8#1", Ordering::Equal);
+    test("4.0", "0x4.0#3", 10, "0.5", "0x0.800#10", Ordering::Equal);
+    test(
+        "4.0",
+        "0x4.0#3",
+        100,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("9.0", "0x9.0#4", 1, "0.2", "0x0.4#1", Ordering::Less);
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test("0.2", "0x0.4#1", 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test("0.2", "0x0.4#1", 10, "2.0", "0x2.00#10", Ordering::Equal);
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("2.2", "0x2.4#4", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test(
+        "2.2",
+        "0x2.4#4",
+        10,
+        "0.667",
+        "0x0.aac#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        100,
+        "0.666666666666666666666666666667",
+        "0x0.aaaaaaaaaaaaaaaaaaaaaaaab#100",
+        Ordering::Greater,
+    );
+    test("1.56", "0x1.9#5", 1, "1.0", "0x1.0#1", Ordering::Greater);
+    test("1.56", "0x1.9#5", 10, "0.8", "0x0.ccc#10", Ordering::Less);
+    test(
+        "1.56",
+        "0x1.9#5",
+        100,
+        "0.8",
+        "0x0.ccccccccccccccccccccccccd#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn reciprocal_sqrt_prec_fail() {
+    assert_panic!(Float::NAN.reciprocal_sqrt_prec(0));
+    assert_panic!(Float::NAN.reciprocal_sqrt_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.reciprocal_sqrt_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_reciprocal_sqrt_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_round(rm);
+        assert!(reciprocal_sqrt.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(reciprocal_sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal_sqrt), out_hex);
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_round_ref(rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_sqrt_alt = x.clone();
+        let o_alt = reciprocal_sqrt_alt.reciprocal_sqrt_round_assign(rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal_sqrt, rug_o) = rug_reciprocal_sqrt_prec_round(
+                &rug::Float::exact_from(&x),
+                x.significant_bits(),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+                ComparableFloatRef(&reciprocal_sqrt)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "0.09",
+        "0x0.170#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "0.091",
+        "0x0.174#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "0.09",
+        "0x0.170#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "0.091",
+        "0x0.174#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "0.09",
+        "0x0.170#7",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "0.5641895835477563",
+        "0x0.906eba8214db68#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "0.5641895835477564",
+        "0x0.906eba8214db70#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "0.5641895835477563",
+        "0x0.906eba8214db68#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "0.5641895835477564",
+        "0x0.906eba8214db70#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "0.5641895835477563",
+        "0x0.906eba8214db68#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "0.6065306597126334",
+        "0x0.9b4597e37cb050#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "0.6065306597126335",
+        "0x0.9b4597e37cb058#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "0.6065306597126334",
+        "0x0.9b4597e37cb050#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "0.6065306597126335",
+        "0x0.9b4597e37cb058#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "0.6065306597126334",
+        "0x0.9b4597e37cb050#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "1.7320508075688772",
+        "0x1.bb67ae8584caa#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "1.7320508075688774",
+        "0x1.bb67ae8584cab#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "1.7320508075688772",
+        "0x1.bb67ae8584caa#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "1.7320508075688774",
+        "0x1.bb67ae8584cab#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "1.7320508075688774",
+        "0x1.bb67ae8584cab#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Exact,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "1.732050807568877293527446341505872366942805253810380628055806",
+        "0x1.bb67ae8584caa73b25742d7078b83b8925d834cc53da4798c6#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "1.732050807568877293527446341505872366942805253810380628055808",
+        "0x1.bb67ae8584caa73b25742d7078b83b8925d834cc53da4798c8#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "1.732050807568877293527446341505872366942805253810380628055806",
+        "0x1.bb67ae8584caa73b25742d7078b83b8925d834cc53da4798c6#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "1.732050807568877293527446341505872366942805253810380628055808",
+        "0x1.bb67ae8584caa73b25742d7078b83b8925d834cc53da4798c8#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "1.732050807568877293527446341505872366942805253810380628055806",
+        "0x1.bb67ae8584caa73b25742d7078b83b8925d834cc53da4798c6#200",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "0.948",
+        "0x0.f2c#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "0.949",
+        "0x0.f30#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "0.948",
+        "0x0.f2c#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "0.949",
+        "0x0.f30#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "0.948",
+        "0x0.f2c#10",
+        Ordering::Less,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8#3",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Floor,
+        "0.31",
+        "0x0.50#4",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Ceiling,
+        "0.34",
+        "0x0.58#4",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Down,
+        "0.31",
+        "0x0.50#4",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Up,
+        "0.34",
+        "0x0.58#4",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Nearest,
+        "0.34",
+        "0x0.58#4",
+        Ordering::Greater,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        RoundingMode::Floor,
+        "0.62",
+        "0x0.a#4",
+        Ordering::Less,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        RoundingMode::Ceiling,
+        "0.7",
+        "0x0.b#4",
+        Ordering::Greater,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        RoundingMode::Down,
+        "0.62",
+        "0x0.a#4",
+        Ordering::Less,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        RoundingMode::Up,
+        "0.7",
+        "0x0.b#4",
+        Ordering::Greater,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        RoundingMode::Nearest,
+        "0.7",
+        "0x0.b#4",
+        Ordering::Greater,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        RoundingMode::Floor,
+        "0.78",
+        "0x0.c8#5",
+        Ordering::Less,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        RoundingMode::Ceiling,
+        "0.81",
+        "0x0.d0#5",
+        Ordering::Greater,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        RoundingMode::Down,
+        "0.78",
+        "0x0.c8#5",
+        Ordering::Less,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        RoundingMode::Up,
+        "0.81",
+        "0x0.d0#5",
+        Ordering::Greater,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        RoundingMode::Nearest,
+        "0.81",
+        "0x0.d0#5",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn reciprocal_sqrt_round_fail() {
+    assert_panic!(Float::from(3u32).reciprocal_sqrt_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).reciprocal_sqrt_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.reciprocal_sqrt_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_reciprocal_sqrt_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec_round(prec, rm);
+        assert!(reciprocal_sqrt.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(reciprocal_sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal_sqrt), out_hex);
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_round_ref(prec, rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_sqrt_alt = x.clone();
+        let o_alt = reciprocal_sqrt_alt.reciprocal_sqrt_prec_round_assign(prec, rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal_sqrt, rug_o) =
+                rug_reciprocal_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+                ComparableFloatRef(&reciprocal_sqrt)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "1.414",
+        "0x1.6a0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.416",
+        "0x1.6a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "1.414",
+        "0x1.6a0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Floor,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.414213562373095048801688724211",
+        "0x1.6a09e667f3bcc908b2fb13670#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Nearest,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "0.563",
+        "0x0.904#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.564",
+        "0x0.908#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "0.564",
+        "0x0.908#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "0.564189583547756297944619165494",
+        "0x0.906eba8214db69584b8553dd8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.564189583547756297944619165495",
+        "0x0.906eba8214db69584b8553dd9#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "0.564189583547756297944619165494",
+        "0x0.906eba8214db69584b8553dd8#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "0.606",
+        "0x0.9b4#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.607",
+        "0x0.9b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "0.606",
+        "0x0.9b4#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Floor,
+        "0.606530659712633439732170658472",
+        "0x0.9b4597e37cb0511d5a89eae2b#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.6065306597126334397321706584727",
+        "0x0.9b4597e37cb0511d5a89eae2c#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Nearest,
+        "0.6065306597126334397321706584727",
+        "0x0.9b4597e37cb0511d5a89eae2c#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        RoundingMode::Floor,
+        "1.73",
+        "0x1.bb0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.732",
+        "0x1.bb8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        RoundingMode::Nearest,
+        "1.732",
+        "0x1.bb8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        RoundingMode::Floor,
+        "1.732050807568877341601513501094",
+        "0x1.bb67ae8584caaab1f4d1387a0#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        RoundingMode::Ceiling,
+        "1.732050807568877341601513501096",
+        "0x1.bb67ae8584caaab1f4d1387a2#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        RoundingMode::Nearest,
+        "1.732050807568877341601513501094",
+        "0x1.bb67ae8584caaab1f4d1387a0#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "1.73",
+        "0x1.bb0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "1.732",
+        "0x1.bb8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "1.732",
+        "0x1.bb8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "1.732050807568877293527446341505",
+        "0x1.bb67ae8584caa73b25742d706#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "1.732050807568877293527446341506",
+        "0x1.bb67ae8584caa73b25742d708#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "1.732050807568877293527446341506",
+        "0x1.bb67ae8584caa73b25742d708#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "0.948",
+        "0x0.f2c#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.949",
+        "0x0.f30#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "0.948",
+        "0x0.f2c#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "0.9485906667660035424160668718384",
+        "0x0.f2d6d6830d027e5c7ca6da614#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "0.948590666766003542416066871839",
+        "0x0.f2d6d6830d027e5c7ca6da615#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "0.948590666766003542416066871839",
+        "0x0.f2d6d6830d027e5c7ca6da615#100",
+        Ordering::Greater,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        100,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        100,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        100,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        100,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Floor,
+        "0.333",
+        "0x0.554#10",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Ceiling,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Nearest,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        RoundingMode::Floor,
+        "0.3333333333333333333333333333331",
+        "0x0.55555555555555555555555550#100",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        RoundingMode::Ceiling,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        RoundingMode::Nearest,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        1,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        10,
+        RoundingMode::Floor,
+        "0.666",
+        "0x0.aa8#10",
+        Ordering::Less,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        10,
+        RoundingMode::Ceiling,
+        "0.667",
+        "0x0.aac#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        10,
+        RoundingMode::Nearest,
+        "0.667",
+        "0x0.aac#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        100,
+        RoundingMode::Floor,
+        "0.666666666666666666666666666666",
+        "0x0.aaaaaaaaaaaaaaaaaaaaaaaaa#100",
+        Ordering::Less,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        100,
+        RoundingMode::Ceiling,
+        "0.666666666666666666666666666667",
+        "0x0.aaaaaaaaaaaaaaaaaaaaaaaab#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        100,
+        RoundingMode::Nearest,
+        "0.666666666666666666666666666667",
+        "0x0.aaaaaaaaaaaaaaaaaaaaaaaab#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        10,
+        RoundingMode::Floor,
+        "0.8",
+        "0x0.ccc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        10,
+        RoundingMode::Ceiling,
+        "0.801",
+        "0x0.cd0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        10,
+        RoundingMode::Nearest,
+        "0.8",
+        "0x0.ccc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        100,
+        RoundingMode::Floor,
+        "0.7999999999999999999999999999994",
+        "0x0.ccccccccccccccccccccccccc#100",
+        Ordering::Less,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        100,
+        RoundingMode::Ceiling,
+        "0.8",
+        "0x0.ccccccccccccccccccccccccd#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        100,
+        RoundingMode::Nearest,
+        "0.8",
+        "0x0.ccccccccccccccccccccccccd#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn reciprocal_sqrt_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).reciprocal_sqrt_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).reciprocal_sqrt_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.reciprocal_sqrt_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).reciprocal_sqrt_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).reciprocal_sqrt_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.reciprocal_sqrt_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn reciprocal_sqrt_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_4().test_properties(|(x, prec, rm)| {
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec_round(prec, rm);
+        assert!(reciprocal_sqrt.is_valid());
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_round_ref(prec, rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.reciprocal_sqrt_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&x_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x > 0u32 {
+            if reciprocal_sqrt.is_normal() {
+                assert_eq!(reciprocal_sqrt.get_prec(), Some(prec));
+            }
+            let r_x = Rational::exact_from(&x);
+            assert_eq!(
+                square_times(&reciprocal_sqrt, &r_x).partial_cmp(&1u32),
+                Some(o)
+            );
+            if o == Ordering::Less {
+                let mut next = reciprocal_sqrt.clone();
+                next.increment();
+                assert!(square_times(&next, &r_x) > 1u32);
+            } else if o == Ordering::Greater {
+                let mut next = reciprocal_sqrt.clone();
+                next.decrement();
+                assert!(square_times(&next, &r_x) < 1u32);
+            }
+            match rm {
+                RoundingMode::Floor | RoundingMode::Down => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling | RoundingMode::Up => assert_ne!(o, Ordering::Less),
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+        } else {
+            assert_eq!(o, Ordering::Equal);
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal_sqrt, rug_o) =
+                rug_reciprocal_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+                ComparableFloatRef(&reciprocal_sqrt)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.reciprocal_sqrt_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(reciprocal_sqrt.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.reciprocal_sqrt_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn reciprocal_sqrt_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec(prec);
+        assert!(reciprocal_sqrt.is_valid());
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_ref(prec);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.reciprocal_sqrt_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&x_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        let (reciprocal_sqrt_alt, o_alt) =
+            x.reciprocal_sqrt_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x > 0u32 {
+            assert_eq!(
+                square_times(&reciprocal_sqrt, &Rational::exact_from(&x)).partial_cmp(&1u32),
+                Some(o)
+            );
+        }
+
+        let (rug_reciprocal_sqrt, rug_o) = rug_reciprocal_sqrt_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn reciprocal_sqrt_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.reciprocal_sqrt_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.reciprocal_sqrt_round_ref(rm));
+                continue;
+            }
+
+            let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_round(rm);
+            assert!(reciprocal_sqrt.is_valid());
+
+            let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_round_ref(rm);
+            assert!(reciprocal_sqrt_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&reciprocal_sqrt_alt),
+                ComparableFloatRef(&reciprocal_sqrt)
+            );
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.reciprocal_sqrt_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(
+                ComparableFloatRef(&x_alt),
+                ComparableFloatRef(&reciprocal_sqrt)
+            );
+            assert_eq!(o_alt, o);
+
+            let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&reciprocal_sqrt_alt),
+                ComparableFloatRef(&reciprocal_sqrt)
+            );
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_reciprocal_sqrt, rug_o) =
+                    rug_reciprocal_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+                    ComparableFloatRef(&reciprocal_sqrt)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    let test_special = |x: Float, out: Float| {
+        let (reciprocal_sqrt, o) = x.reciprocal_sqrt_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(reciprocal_sqrt), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::ZERO);
+    test_special(Float::NEGATIVE_INFINITY, Float::NAN);
+    test_special(Float::ZERO, Float::INFINITY);
+    test_special(Float::NEGATIVE_ZERO, Float::INFINITY);
+    test_special(Float::ONE, Float::ONE);
+    test_special(Float::NEGATIVE_ONE, Float::NAN);
+}
@@ -0,0 +1,5119 @@
+use malachite_base::num::arithmetic::traits::{NegAssign, Parity, Pow};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_unsigned_pair_gen_var_1, float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1,
+    float_unsigned_unsigned_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_root_prec_round(
+    x: &rug::Float,
+    n: u64,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.root_ref(u32::exact_from(n)), rm)
+}
+
+fn power(x: &Float, n: u64) -> Rational {
+    Rational::exact_from(x).pow(n)
+}
+
+#[test]
+fn test_root_prec() {
+    let test = |s, s_hex, n, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (root, o) = x.clone().root_prec(n, prec);
+        assert!(root.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(root.to_string(), out);
+        assert_eq!(to_hex_string(&root), out_hex);
+
+        let (root_alt, o_alt) = x.root_prec_ref(n, prec);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut root_alt = x.clone();
+        let o_alt = root_alt.root_prec_assign(n, prec);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_root, rug_o) = rug_root_prec_round(
+            &rug::Float::exact_from(&x),
+            n,
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_root)),
+            ComparableFloatRef(&root)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 3, 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 3, 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 3, 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 3, 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 3, 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 3, 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 3, 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 3, 10, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 3, 100, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 3, 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("0.5", "0x0.8#1", 3, 1, "1.0", "0x1.0#1", Ordering::Greater);
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        10,
+        "0.794",
+        "0x0.cb4#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        100,
+        "0.793700525984099737375852819636",
+        "0x0.cb2ff529eb71e41582cccd5a2#100",
+        Ordering::Greater,
+    );
+    test("123.0", "0x7b.0#7", 3, 1, "4.0", "0x4.0#1", Ordering::Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        "4.977",
+        "0x4.fa#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        100,
+        "4.973189833268590415650083382858",
+        "0x4.f922f80ab033aed5d40a38d88#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        10,
+        "1.465",
+        "0x1.770#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        100,
+        "1.464591887561523243989373053169",
+        "0x1.76ef7e73104b7750833131288#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        100,
+        "1.395612425086089503887463243268",
+        "0x1.6546db1ba2d12f421cf34179a#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        10,
+        "-1.123",
+        "-0x1.1f8#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        100,
+        "-1.122462048309373007009985675446",
+        "-0x1.1f59ac3c7d6bff43af2976076#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        10,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        100,
+        "0.693361274350634692013591421994",
+        "0x0.b1801fdda61baa516090d8ed3#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        1,
+        "9.0e9",
+        "0x2.0E+8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        10,
+        "1.082e10",
+        "0x2.85E+8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        100,
+        "10822639409.68092896148637802281",
+        "0x285145f31.ae515c447bb56e2b8#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        "1.0e-10",
+        "0x8.0E-9#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        "9.24e-11",
+        "0x6.5aE-9#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        100,
+        "9.23989021666464090086348377676e-11",
+        "0x6.597fa94f5b8f20ac16666ad10E-9#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        10,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        100,
+        "0.6933612743506347048433522747863",
+        "0x0.b1801fdda61bab3e0b6600756#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        3,
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        3,
+        10,
+        "-1.465",
+        "-0x1.770#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        3,
+        100,
+        "-1.4647883603394576039568974119",
+        "-0x1.76fc5eb7382cd16400e76a900#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        10,
+        "1.035",
+        "0x1.090#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        100,
+        "1.035811595522686426583554838127",
+        "0x1.092af2df9667983817a78cef4#100",
+        Ordering::Greater,
+    );
+    test("8.0", "0x8.0#4", 3, 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test("8.0", "0x8.0#4", 3, 10, "2.0", "0x2.00#10", Ordering::Equal);
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        10,
+        "-3.0",
+        "-0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        100,
+        "-3.0",
+        "-0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("0.1", "0x0.2#1", 3, 1, "0.5", "0x0.8#1", Ordering::Equal);
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        10,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        100,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("3.38", "0x3.6#6", 3, 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        10,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        100,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn root_prec_fail() {
+    assert_panic!(Float::NAN.root_prec(3, 0));
+    assert_panic!(Float::NAN.root_prec_ref(3, 0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.root_prec_assign(3, 0)
+    });
+}
+
+#[test]
+fn test_root_round() {
+    let test = |s, s_hex, n, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (root, o) = x.clone().root_round(n, rm);
+        assert!(root.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(root.to_string(), out);
+        assert_eq!(to_hex_string(&root), out_hex);
+
+        let (root_alt, o_alt) = x.root_round_ref(n, rm);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut root_alt = x.clone();
+        let o_alt = root_alt.root_round_assign(n, rm);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_root, rug_o) =
+                rug_root_prec_round(&rug::Float::exact_from(&x), n, x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_root)),
+                ComparableFloatRef(&root)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Down,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Up,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Exact,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Down,
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Up,
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Exact,
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Down,
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Up,
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Exact,
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Floor,
+        "4.0",
+        "0x4.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Down,
+        "4.0",
+        "0x4.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Exact,
+        "4.0",
+        "0x4.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Floor,
+        "9.0",
+        "0x9.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Ceiling,
+        "9.0",
+        "0x9.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Down,
+        "9.0",
+        "0x9.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Up,
+        "9.0",
+        "0x9.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Nearest,
+        "9.0",
+        "0x9.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Exact,
+        "9.0",
+        "0x9.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        2,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        2,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        2,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        2,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        2,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        2,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        2,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        2,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        2,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        2,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        2,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        2,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        2,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        2,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        2,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        2,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        2,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        2,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Floor,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Ceiling,
+        "1.7724538509055161",
+        "0x1.c5bf891b4ef6b#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Down,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Up,
+        "1.7724538509055161",
+        "0x1.c5bf891b4ef6b#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Nearest,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        2,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        2,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        2,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        2,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        2,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        2,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        2,
+        RoundingMode::Floor,
+        "0.577350269189625764509148780501957455647601751270126876018602",
+        "0x0.93cd3a2c8198e2690c7c0f257d92be830c9d66eec69e17dd97#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        2,
+        RoundingMode::Ceiling,
+        "0.5773502691896257645091487805019574556476017512701268760186025",
+        "0x0.93cd3a2c8198e2690c7c0f257d92be830c9d66eec69e17dd98#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        2,
+        RoundingMode::Down,
+        "0.577350269189625764509148780501957455647601751270126876018602",
+        "0x0.93cd3a2c8198e2690c7c0f257d92be830c9d66eec69e17dd97#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        2,
+        RoundingMode::Up,
+        "0.5773502691896257645091487805019574556476017512701268760186025",
+        "0x0.93cd3a2c8198e2690c7c0f257d92be830c9d66eec69e17dd98#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        2,
+        RoundingMode::Nearest,
+        "0.5773502691896257645091487805019574556476017512701268760186025",
+        "0x0.93cd3a2c8198e2690c7c0f257d92be830c9d66eec69e17dd98#200",
+        Ordering::Greater,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        2,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        2,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        2,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        2,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        2,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        2,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        2,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        2,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        2,
+        RoundingMode::Down,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        2,
+        RoundingMode::Up,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        2,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        2,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        RoundingMode::Floor,
+        "4.94",
+        "0x4.f#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        RoundingMode::Ceiling,
+        "5.0",
+        "0x5.0#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        RoundingMode::Down,
+        "4.94",
+        "0x4.f#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        RoundingMode::Up,
+        "5.0",
+        "0x5.0#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        RoundingMode::Nearest,
+        "5.0",
+        "0x5.0#7",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Floor,
+        "1.4645918875615231",
+        "0x1.76ef7e73104b7#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Ceiling,
+        "1.4645918875615234",
+        "0x1.76ef7e73104b8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Down,
+        "1.4645918875615231",
+        "0x1.76ef7e73104b7#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Up,
+        "1.4645918875615234",
+        "0x1.76ef7e73104b8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Nearest,
+        "1.4645918875615231",
+        "0x1.76ef7e73104b7#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        RoundingMode::Floor,
+        "1.3956124250860893",
+        "0x1.6546db1ba2d12#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        RoundingMode::Ceiling,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        RoundingMode::Down,
+        "1.3956124250860893",
+        "0x1.6546db1ba2d12#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        RoundingMode::Up,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        RoundingMode::Nearest,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        RoundingMode::Floor,
+        "-1.122462048309373",
+        "-0x1.1f59ac3c7d6c0#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        RoundingMode::Ceiling,
+        "-1.1224620483093728",
+        "-0x1.1f59ac3c7d6bf#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        RoundingMode::Down,
+        "-1.1224620483093728",
+        "-0x1.1f59ac3c7d6bf#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        RoundingMode::Up,
+        "-1.122462048309373",
+        "-0x1.1f59ac3c7d6c0#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        RoundingMode::Nearest,
+        "-1.122462048309373",
+        "-0x1.1f59ac3c7d6c0#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        RoundingMode::Floor,
+        "0.6933612743506347",
+        "0x0.b1801fdda61ba8#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        RoundingMode::Ceiling,
+        "0.6933612743506348",
+        "0x0.b1801fdda61bb0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        RoundingMode::Down,
+        "0.6933612743506347",
+        "0x0.b1801fdda61ba8#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        RoundingMode::Up,
+        "0.6933612743506348",
+        "0x0.b1801fdda61bb0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        RoundingMode::Nearest,
+        "0.6933612743506347",
+        "0x0.b1801fdda61ba8#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        RoundingMode::Floor,
+        "9.0e9",
+        "0x2.0E+8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        RoundingMode::Ceiling,
+        "2.0e10",
+        "0x4.0E+8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        RoundingMode::Down,
+        "9.0e9",
+        "0x2.0E+8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        RoundingMode::Up,
+        "2.0e10",
+        "0x4.0E+8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        RoundingMode::Nearest,
+        "9.0e9",
+        "0x2.0E+8#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        RoundingMode::Floor,
+        "6.0e-11",
+        "0x4.0E-9#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        RoundingMode::Ceiling,
+        "1.0e-10",
+        "0x8.0E-9#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        RoundingMode::Down,
+        "6.0e-11",
+        "0x4.0E-9#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        RoundingMode::Up,
+        "1.0e-10",
+        "0x8.0E-9#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        RoundingMode::Nearest,
+        "1.0e-10",
+        "0x8.0E-9#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        RoundingMode::Floor,
+        "0.6933612743506347048433522747859617954459351134577540365658637",
+        "0x0.b1801fdda61bab3e0b66007558c3dfdd76c6839bb9d2dde585#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        RoundingMode::Ceiling,
+        "0.6933612743506347048433522747859617954459351134577540365658644",
+        "0x0.b1801fdda61bab3e0b66007558c3dfdd76c6839bb9d2dde586#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        RoundingMode::Down,
+        "0.6933612743506347048433522747859617954459351134577540365658637",
+        "0x0.b1801fdda61bab3e0b66007558c3dfdd76c6839bb9d2dde585#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        RoundingMode::Up,
+        "0.6933612743506347048433522747859617954459351134577540365658644",
+        "0x0.b1801fdda61bab3e0b66007558c3dfdd76c6839bb9d2dde586#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        RoundingMode::Nearest,
+        "0.6933612743506347048433522747859617954459351134577540365658637",
+        "0x0.b1801fdda61bab3e0b66007558c3dfdd76c6839bb9d2dde585#200",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        3,
+        RoundingMode::Floor,
+        "-1.464788360339457603956897411900418560290323538",
+        "-0x1.76fc5eb7382cd16400e76a9000df77de2d8170#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        3,
+        RoundingMode::Ceiling,
+        "-1.464788360339457603956897411900418560290323537",
+        "-0x1.76fc5eb7382cd16400e76a9000df77de2d8168#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        3,
+        RoundingMode::Down,
+        "-1.464788360339457603956897411900418560290323537",
+        "-0x1.76fc5eb7382cd16400e76a9000df77de2d8168#150",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        3,
+        RoundingMode::Up,
+        "-1.464788360339457603956897411900418560290323538",
+        "-0x1.76fc5eb7382cd16400e76a9000df77de2d8170#150",
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        3,
+        RoundingMode::Nearest,
+        "-1.464788360339457603956897411900418560290323537",
+        "-0x1.76fc5eb7382cd16400e76a9000df77de2d8168#150",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        RoundingMode::Floor,
+        "1.035",
+        "0x1.090#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        RoundingMode::Ceiling,
+        "1.037",
+        "0x1.098#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        RoundingMode::Down,
+        "1.035",
+        "0x1.090#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        RoundingMode::Up,
+        "1.037",
+        "0x1.098#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        RoundingMode::Nearest,
+        "1.035",
+        "0x1.090#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        RoundingMode::Floor,
+        "-3.0",
+        "-0x3.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        RoundingMode::Ceiling,
+        "-3.0",
+        "-0x3.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        RoundingMode::Down,
+        "-3.0",
+        "-0x3.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        RoundingMode::Up,
+        "-3.0",
+        "-0x3.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        RoundingMode::Nearest,
+        "-3.0",
+        "-0x3.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        RoundingMode::Exact,
+        "-3.0",
+        "-0x3.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        RoundingMode::Floor,
+        "1.5",
+        "0x1.80#6",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.80#6",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        RoundingMode::Down,
+        "1.5",
+        "0x1.80#6",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        RoundingMode::Up,
+        "1.5",
+        "0x1.80#6",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.80#6",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        RoundingMode::Exact,
+        "1.5",
+        "0x1.80#6",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.1212823532318632",
+        "0x1.1f0c5c3cb6784#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.1212823532318634",
+        "0x1.1f0c5c3cb6785#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1.1212823532318632",
+        "0x1.1f0c5c3cb6784#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1.1212823532318634",
+        "0x1.1f0c5c3cb6785#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.1212823532318632",
+        "0x1.1f0c5c3cb6784#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "0.8959584598407621945273070245756476816936885353775916257396521",
+        "0x0.e55d889b9731c1fcbf7a29bce9919480cf66e63d4427e5ddd2#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "0.8959584598407621945273070245756476816936885353775916257396528",
+        "0x0.e55d889b9731c1fcbf7a29bce9919480cf66e63d4427e5ddd3#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Down,
+        "0.8959584598407621945273070245756476816936885353775916257396521",
+        "0x0.e55d889b9731c1fcbf7a29bce9919480cf66e63d4427e5ddd2#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Up,
+        "0.8959584598407621945273070245756476816936885353775916257396528",
+        "0x0.e55d889b9731c1fcbf7a29bce9919480cf66e63d4427e5ddd3#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "0.8959584598407621945273070245756476816936885353775916257396521",
+        "0x0.e55d889b9731c1fcbf7a29bce9919480cf66e63d4427e5ddd2#200",
+        Ordering::Less,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Less,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Ceiling,
+        "1.2",
+        "0x1.4#3",
+        Ordering::Greater,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Less,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Up,
+        "1.2",
+        "0x1.4#3",
+        Ordering::Greater,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Nearest,
+        "1.2",
+        "0x1.4#3",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Floor,
+        "1.1",
+        "0x1.2#4",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Ceiling,
+        "1.2",
+        "0x1.4#4",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Down,
+        "1.1",
+        "0x1.2#4",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Up,
+        "1.2",
+        "0x1.4#4",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Nearest,
+        "1.2",
+        "0x1.4#4",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn root_round_fail() {
+    assert_panic!(Float::from(3u32).root_round(3, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).root_round_ref(3, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.root_round_assign(3, RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_root_prec_round() {
+    let test = |s, s_hex, n, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (root, o) = x.clone().root_prec_round(n, prec, rm);
+        assert!(root.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(root.to_string(), out);
+        assert_eq!(to_hex_string(&root), out_hex);
+
+        let (root_alt, o_alt) = x.root_prec_round_ref(n, prec, rm);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut root_alt = x.clone();
+        let o_alt = root_alt.root_prec_round_assign(n, prec, rm);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_root, rug_o) = rug_root_prec_round(&rug::Float::exact_from(&x), n, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_root)),
+                ComparableFloatRef(&root)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        3,
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        1,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        1,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        100,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        100,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        10,
+        RoundingMode::Floor,
+        "0.793",
+        "0x0.cb0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "0.794",
+        "0x0.cb4#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "0.794",
+        "0x0.cb4#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        100,
+        RoundingMode::Floor,
+        "0.7937005259840997373758528196354",
+        "0x0.cb2ff529eb71e41582cccd5a1#100",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "0.793700525984099737375852819636",
+        "0x0.cb2ff529eb71e41582cccd5a2#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "0.793700525984099737375852819636",
+        "0x0.cb2ff529eb71e41582cccd5a2#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Floor,
+        "1.463",
+        "0x1.768#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "1.465",
+        "0x1.770#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "1.465",
+        "0x1.770#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        100,
+        RoundingMode::Floor,
+        "1.464591887561523243989373053167",
+        "0x1.76ef7e73104b7750833131286#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "1.464591887561523243989373053169",
+        "0x1.76ef7e73104b7750833131288#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "1.464591887561523243989373053169",
+        "0x1.76ef7e73104b7750833131288#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        RoundingMode::Floor,
+        "1.395",
+        "0x1.650#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        100,
+        RoundingMode::Floor,
+        "1.395612425086089503887463243266",
+        "0x1.6546db1ba2d12f421cf341798#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "1.395612425086089503887463243268",
+        "0x1.6546db1ba2d12f421cf34179a#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "1.395612425086089503887463243268",
+        "0x1.6546db1ba2d12f421cf34179a#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        1,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        10,
+        RoundingMode::Floor,
+        "-1.123",
+        "-0x1.1f8#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "-1.121",
+        "-0x1.1f0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "-1.123",
+        "-0x1.1f8#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        100,
+        RoundingMode::Floor,
+        "-1.122462048309373007009985675447",
+        "-0x1.1f59ac3c7d6bff43af2976078#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "-1.122462048309373007009985675446",
+        "-0x1.1f59ac3c7d6bff43af2976076#100",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "-1.122462048309373007009985675446",
+        "-0x1.1f59ac3c7d6bff43af2976076#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        10,
+        RoundingMode::Floor,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "0.694",
+        "0x0.b1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        100,
+        RoundingMode::Floor,
+        "0.693361274350634692013591421994",
+        "0x0.b1801fdda61baa516090d8ed3#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "0.6933612743506346920135914219946",
+        "0x0.b1801fdda61baa516090d8ed4#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "0.693361274350634692013591421994",
+        "0x0.b1801fdda61baa516090d8ed3#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        10,
+        RoundingMode::Floor,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "0.694",
+        "0x0.b1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        100,
+        RoundingMode::Floor,
+        "0.6933612743506347048433522747855",
+        "0x0.b1801fdda61bab3e0b6600755#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "0.6933612743506347048433522747863",
+        "0x0.b1801fdda61bab3e0b6600756#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "0.6933612743506347048433522747863",
+        "0x0.b1801fdda61bab3e0b6600756#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        10,
+        RoundingMode::Floor,
+        "1.035",
+        "0x1.090#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "1.037",
+        "0x1.098#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "1.035",
+        "0x1.090#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        100,
+        RoundingMode::Floor,
+        "1.035811595522686426583554838125",
+        "0x1.092af2df9667983817a78cef2#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "1.035811595522686426583554838127",
+        "0x1.092af2df9667983817a78cef4#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "1.035811595522686426583554838127",
+        "0x1.092af2df9667983817a78cef4#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        1,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        10,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        100,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#4",
+        3,
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        1,
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        10,
+        RoundingMode::Floor,
+        "-3.0",
+        "-0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "-3.0",
+        "-0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "-3.0",
+        "-0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        10,
+        RoundingMode::Exact,
+        "-3.0",
+        "-0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        100,
+        RoundingMode::Floor,
+        "-3.0",
+        "-0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "-3.0",
+        "-0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "-3.0",
+        "-0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-27.0",
+        "-0x1b.0#5",
+        3,
+        100,
+        RoundingMode::Exact,
+        "-3.0",
+        "-0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        1,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        10,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        10,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        100,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        "0x0.2#1",
+        3,
+        100,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        10,
+        RoundingMode::Floor,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        10,
+        RoundingMode::Exact,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        100,
+        RoundingMode::Floor,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.38",
+        "0x3.6#6",
+        3,
+        100,
+        RoundingMode::Exact,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn root_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).root_prec_round(3, 0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).root_prec_round_ref(3, 0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.root_prec_round_assign(3, 0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).root_prec_round(3, 1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).root_prec_round_ref(3, 1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.root_prec_round_assign(3, 1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_root_prec_round_large_n() {
+    // When `n` is larger than the precisions of the input and output, the root is computed without
+    // raising anything to the `n`th power.
+    let test = |s, s_hex, n: u64, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (root, o) = x.clone().root_prec_round(n, prec, rm);
+        assert!(root.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(root.to_string(), out);
+        assert_eq!(to_hex_string(&root), out_hex);
+
+        let (root_alt, o_alt) = x.root_prec_round_ref(n, prec, rm);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let (Ok(rm), Ok(_)) = (rug_round_try_from_rounding_mode(rm), u32::try_from(n)) {
+            let (rug_root, rug_o) = rug_root_prec_round(&rug::Float::exact_from(&x), n, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_root)),
+                ComparableFloatRef(&root)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    for rm in [RoundingMode::Floor, RoundingMode::Down, RoundingMode::Nearest] {
+        test(
+            "2.0",
+            "0x2.0#1",
+            u64::MAX,
+            10,
+            rm,
+            "1.0",
+            "0x1.000#10",
+            Ordering::Less,
+        );
+    }
+    for rm in [RoundingMode::Ceiling, RoundingMode::Up] {
+        test(
+            "2.0",
+            "0x2.0#1",
+            u64::MAX,
+            10,
+            rm,
+            "1.002",
+            "0x1.008#10",
+            Ordering::Greater,
+        );
+    }
+    for rm in [RoundingMode::Floor, RoundingMode::Down] {
+        test(
+            "0.5",
+            "0x0.8#1",
+            u64::MAX,
+            10,
+            rm,
+            "0.999",
+            "0x0.ffc#10",
+            Ordering::Less,
+        );
+    }
+    for rm in [RoundingMode::Ceiling, RoundingMode::Up, RoundingMode::Nearest] {
+        test(
+            "0.5",
+            "0x0.8#1",
+            u64::MAX,
+            10,
+            rm,
+            "1.0",
+            "0x1.000#10",
+            Ordering::Greater,
+        );
+    }
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        u64::MAX,
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        u64::MAX,
+        10,
+        RoundingMode::Floor,
+        "-1.002",
+        "-0x1.008#10",
+        Ordering::Less,
+    );
+    for rm in exhaustive_rounding_modes() {
+        test(
+            "1.0",
+            "0x1.0#1",
+            u64::MAX,
+            10,
+            rm,
+            "1.0",
+            "0x1.000#10",
+            Ordering::Equal,
+        );
+        test(
+            "-1.0",
+            "-0x1.0#1",
+            u64::MAX,
+            10,
+            rm,
+            "-1.0",
+            "-0x1.000#10",
+            Ordering::Equal,
+        );
+    }
+    // 2^3000 is an exact 1000th power
+    for rm in exhaustive_rounding_modes() {
+        test(
+            "1.0e903",
+            "0x1.0E+750#1",
+            1000,
+            10,
+            rm,
+            "8.0",
+            "0x8.00#10",
+            Ordering::Equal,
+        );
+    }
+    test(
+        "3.0",
+        "0x3.0#2",
+        1000,
+        10,
+        RoundingMode::Nearest,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1000,
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+
+    assert_panic!(Float::from(2u32).root_prec_round(u64::MAX, 10, RoundingMode::Exact));
+}
+
+#[test]
+fn root_prec_round_properties() {
+    float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1().test_properties(
+        |(x, n, prec, rm)| {
+            let (root, o) = x.clone().root_prec_round(n, prec, rm);
+            assert!(root.is_valid());
+
+            let (root_alt, o_alt) = x.root_prec_round_ref(n, prec, rm);
+            assert!(root_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.root_prec_round_assign(n, prec, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&root));
+            assert_eq!(o_alt, o);
+
+            if x.is_finite() && x != 0u32 && (x > 0u32 || n.odd()) {
+                if root.is_normal() {
+                    assert_eq!(root.get_prec(), Some(prec));
+                }
+                // $y \mapsto y^n$ is increasing on the domain of the $n$th root, so comparing the
+                // $n$th power of the result with `x` gives the `Ordering`.
+                let r_x = Rational::exact_from(&x);
+                assert_eq!(power(&root, n).partial_cmp(&r_x), Some(o));
+                if o == Ordering::Less {
+                    let mut next = root.clone();
+                    next.increment();
+                    assert!(power(&next, n) > r_x);
+                } else if o == Ordering::Greater {
+                    let mut next = root.clone();
+                    next.decrement();
+                    assert!(power(&next, n) < r_x);
+                }
+                match (x > 0u32, rm) {
+                    (_, RoundingMode::Floor)
+                    | (true, RoundingMode::Down)
+                    | (false, RoundingMode::Up) => {
+                        assert_ne!(o, Ordering::Greater)
+                    }
+                    (_, RoundingMode::Ceiling)
+                    | (true, RoundingMode::Up)
+                    | (false, RoundingMode::Down) => {
+                        assert_ne!(o, Ordering::Less)
+                    }
+                    (_, RoundingMode::Exact) => assert_eq!(o, Ordering::Equal),
+                    _ => {}
+                }
+            } else {
+                assert_eq!(o, Ordering::Equal);
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_root, rug_o) =
+                    rug_root_prec_round(&rug::Float::exact_from(&x), n, prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_root)),
+                    ComparableFloatRef(&root)
+                );
+                assert_eq!(rug_o, o);
+            }
+
+            if n.odd() {
+                let (mut root_alt, mut o_alt) = (-&x).root_prec_round(n, prec, -rm);
+                root_alt.neg_assign();
+                o_alt = o_alt.reverse();
+                assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+                assert_eq!(o_alt, o);
+            }
+
+            if n == 1 {
+                let mut root_alt = x.clone();
+                let o_alt = root_alt.set_prec_round(prec, rm);
+                assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+                assert_eq!(o_alt, o);
+            } else if n == 2 {
+                let (root_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+                assert_eq!(
+                    ComparableFloat(root_alt.abs_negative_zero()),
+                    ComparableFloat(root.clone())
+                );
+                assert_eq!(o_alt, o);
+            }
+
+            if o == Ordering::Equal {
+                for rm in exhaustive_rounding_modes() {
+                    let (r, oo) = x.root_prec_round_ref(n, prec, rm);
+                    assert_eq!(ComparableFloat(r), ComparableFloat(root.clone()));
+                    assert_eq!(oo, Ordering::Equal);
+                }
+            } else {
+                assert_panic!(x.root_prec_round_ref(n, prec, RoundingMode::Exact));
+            }
+        },
+    );
+}
+
+#[test]
+fn root_prec_properties() {
+    float_unsigned_unsigned_triple_gen_var_1().test_properties(|(x, n, prec)| {
+        let (root, o) = x.clone().root_prec(n, prec);
+        assert!(root.is_valid());
+
+        let (root_alt, o_alt) = x.root_prec_ref(n, prec);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.root_prec_assign(n, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&root));
+        assert_eq!(o_alt, o);
+
+        let (root_alt, o_alt) = x.root_prec_round_ref(n, prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x != 0u32 && (x > 0u32 || n.odd()) {
+            assert_eq!(
+                power(&root, n).partial_cmp(&Rational::exact_from(&x)),
+                Some(o)
+            );
+        }
+
+        let (rug_root, rug_o) = rug_root_prec_round(
+            &rug::Float::exact_from(&x),
+            n,
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_root)),
+            ComparableFloatRef(&root)
+        );
+        assert_eq!(rug_o, o);
+
+        if x.is_finite() && x > 0u32 && n <= 16 {
+            // The `n`th power of a `Float` with precision `p` is exactly representable with
+            // precision `np`, and its `n`th root is the original `Float`.
+            let r_power = power(&x, n);
+            let power_prec = x.significant_bits() * n;
+            let (x_power, o) =
+                Float::from_rational_prec_round(r_power, power_prec, RoundingMode::Exact);
+            assert_eq!(o, Ordering::Equal);
+            let (root_alt, o) = x_power.root_prec(n, x.significant_bits());
+            assert_eq!(o, Ordering::Equal);
+            assert_eq!(root_alt, x);
+        }
+    });
+}
+
+#[test]
+fn root_round_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, n)| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.root_prec_round_ref(n, prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.root_round_ref(n, rm));
+                continue;
+            }
+
+            let (root, o) = x.clone().root_round(n, rm);
+            assert!(root.is_valid());
+
+            let (root_alt, o_alt) = x.root_round_ref(n, rm);
+            assert!(root_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.root_round_assign(n, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&root));
+            assert_eq!(o_alt, o);
+
+            let (root_alt, o_alt) = x.root_prec_round_ref(n, prec, rm);
+            assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_root, rug_o) =
+                    rug_root_prec_round(&rug::Float::exact_from(&x), n, prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_root)),
+                    ComparableFloatRef(&root)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    let test_special = |x: Float, n: u64, out: Float| {
+        let (root, o) = x.root_round(n, RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(root), ComparableFloat(out));
+        assert_eq!(o, Ordering::Equal);
+    };
+    test_special(Float::ONE, 0, Float::NAN);
+    test_special(Float::ZERO, 0, Float::NAN);
+    test_special(Float::NAN, 3, Float::NAN);
+    test_special(Float::INFINITY, 2, Float::INFINITY);
+    test_special(Float::INFINITY, 3, Float::INFINITY);
+    test_special(Float::NEGATIVE_INFINITY, 2, Float::NAN);
+    test_special(Float::NEGATIVE_INFINITY, 3, Float::NEGATIVE_INFINITY);
+    test_special(Float::ZERO, 2, Float::ZERO);
+    test_special(Float::ZERO, 3, Float::ZERO);
+    test_special(Float::NEGATIVE_ZERO, 2, Float::ZERO);
+    test_special(Float::NEGATIVE_ZERO, 3, Float::NEGATIVE_ZERO);
+    test_special(Float::NEGATIVE_ONE, 2, Float::NAN);
+    test_special(Float::NEGATIVE_ONE, 3, Float::NEGATIVE_ONE);
+}
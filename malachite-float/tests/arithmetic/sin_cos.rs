@@ -0,0 +1,2884 @@
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_25,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use rug::ops::AssignRound;
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_sin_cos_prec_round(
+    x: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, rug::Float, Ordering, Ordering) {
+    let prec = u32::exact_from(prec);
+    let (mut sin, mut cos) = (rug::Float::new(prec), rug::Float::new(prec));
+    let (o_sin, o_cos) = (&mut sin, &mut cos).assign_round(x.sin_cos_ref(), rm);
+    (sin, cos, o_sin, o_cos)
+}
+
+#[test]
+fn test_sin_cos_prec() {
+    let test = |s,
+                s_hex,
+                prec,
+                sin_out: &str,
+                sin_hex: &str,
+                cos_out: &str,
+                cos_hex: &str,
+                o_sin_out,
+                o_cos_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_prec(prec);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+        assert_eq!(o_sin, o_sin_out);
+        assert_eq!(o_cos, o_cos_out);
+
+        assert_eq!(sin.to_string(), sin_out);
+        assert_eq!(to_hex_string(&sin), sin_hex);
+        assert_eq!(cos.to_string(), cos_out);
+        assert_eq!(to_hex_string(&cos), cos_hex);
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_ref(prec);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_sin_alt, o_sin_out);
+        assert_eq!(o_cos_alt, o_cos_out);
+
+        let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) = rug_sin_cos_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o_sin, o_sin);
+        assert_eq!(rug_o_cos, o_cos);
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.842",
+        "0x0.d78#10",
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-0.842",
+        "-0x0.d78#10",
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1,
+        "0.5",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        "0.4795",
+        "0x0.7ac#10",
+        "0.878",
+        "0x0.e0c#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.4794255386042030002732879352157",
+        "0x0.7abba1d12c17bfa1d92f0d93f8#100",
+        "0.877582561890372716116281582604",
+        "0x0.e0a94032dbea7cedbddd9da30#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "-0.46",
+        "-0x0.75c#10",
+        "-0.888",
+        "-0x0.e34#10",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "-0.4599034906895912512924357152934",
+        "-0x0.75bc3c33d3fa1892981f9fcdf8#100",
+        "-0.887968906691855428978322569443",
+        "-0x0.e351ee261b3cad7894677374e#100",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.4106",
+        "0x0.692#10",
+        "-0.912",
+        "-0x0.e98#10",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.4107812905029088272805394916102",
+        "0x0.6928f67084c77a976544888ac8#100",
+        "-0.9117339147869650385092477381996",
+        "-0x0.e96764d2aa0034ce60b62d755#100",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        "0.1",
+        "0x0.2#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "-0.987",
+        "-0x0.fcc#10",
+        "0.156",
+        "0x0.27f#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "-0.9877659459927355421446684488834",
+        "-0x0.fcde3aa2242c543a529094fdb#100",
+        "0.155943694765374377964416729388",
+        "0x0.27ebed0d08e166a8f789eb7528#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "0.3271",
+        "0x0.53c#10",
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "0.3271946967961522266881281006328",
+        "0x0.53c3081a2a03196eb92b740be8#100",
+        "0.9449569463147376704426021063145",
+        "0x0.f1e8b2cc8cc165db184e519cd#100",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "-0.872",
+        "-0x0.df4#10",
+        "0.4893",
+        "0x0.7d4#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "-0.8721836054182673097807197782135",
+        "-0x0.df476cbd60fac5f54ce60f245#100",
+        "0.4891786569747214499057893087514",
+        "0x0.7d3acffd9b8db34b71d86c7ff0#100",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "0.2",
+        "0x0.4#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "0.3271",
+        "0x0.53c#10",
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "0.3271946967961522441733440852675",
+        "0x0.53c3081a2a031ab144c484c790#100",
+        "0.944956946314737664388284007676",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "0.001",
+        "0x0.004#1",
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "0.001265",
+        "0x0.0052e#10",
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "0.001264488930377353400360350475647",
+        "0x0.0052de9a9a24d90da7cdffe30130#100",
+        "-0.9999992005335529032683357396563",
+        "-0x0.fffff29651586c28bc01f9fb2#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        "1.0",
+        "0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "0.8965",
+        "0x0.e58#10",
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "0.896288461614839760936648977547",
+        "0x0.e573291e6afab7473e6ba4310#100",
+        "0.4434715251017858896674117237529",
+        "0x0.7187599104f847ef392b3b1268#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        "-2.0e-15",
+        "-0x8.0E-13#1",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        "-2.449e-15",
+        "-0xb.08E-13#10",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        100,
+        "-0.999999999999999999999999999997",
+        "-0x0.ffffffffffffffffffffffffc#100",
+        "-2.449912578931294712152146931693e-15",
+        "-0xb.088e90c77fd12ea79f98632aE-13#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn sin_cos_prec_fail() {
+    assert_panic!(Float::NAN.sin_cos_prec(0));
+    assert_panic!(Float::NAN.sin_cos_prec_ref(0));
+}
+
+#[test]
+fn test_sin_cos_round() {
+    let test = |s,
+                s_hex,
+                rm,
+                sin_out: &str,
+                sin_hex: &str,
+                cos_out: &str,
+                cos_hex: &str,
+                o_sin_out,
+                o_cos_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_round(rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+        assert_eq!(o_sin, o_sin_out);
+        assert_eq!(o_cos, o_cos_out);
+
+        assert_eq!(sin.to_string(), sin_out);
+        assert_eq!(to_hex_string(&sin), sin_hex);
+        assert_eq!(cos.to_string(), cos_out);
+        assert_eq!(to_hex_string(&cos), cos_hex);
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_round_ref(rm);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_sin_alt, o_sin_out);
+        assert_eq!(o_cos_alt, o_cos_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+                rug_sin_cos_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o_sin, o_sin);
+            assert_eq!(rug_o_cos, o_cos);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "-0.461",
+        "-0x0.76#7",
+        "-0.89",
+        "-0x0.e4#7",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "-0.457",
+        "-0x0.75#7",
+        "-0.883",
+        "-0x0.e2#7",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "-0.457",
+        "-0x0.75#7",
+        "-0.883",
+        "-0x0.e2#7",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "-0.461",
+        "-0x0.76#7",
+        "-0.89",
+        "-0x0.e4#7",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "-0.461",
+        "-0x0.76#7",
+        "-0.89",
+        "-0x0.e4#7",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "0.41078129050290879",
+        "0x0.6928f67084c778#53",
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        "-0.911733914786965",
+        "-0x0.e96764d2aa0030#53",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "0.41078129050290879",
+        "0x0.6928f67084c778#53",
+        "-0.911733914786965",
+        "-0x0.e96764d2aa0030#53",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "-0.9877659459927356",
+        "-0x0.fcde3aa2242c58#53",
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "-0.9877659459927355",
+        "-0x0.fcde3aa2242c50#53",
+        "0.1559436947653744",
+        "0x0.27ebed0d08e168#53",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "-0.9877659459927355",
+        "-0x0.fcde3aa2242c50#53",
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "-0.9877659459927356",
+        "-0x0.fcde3aa2242c58#53",
+        "0.1559436947653744",
+        "0x0.27ebed0d08e168#53",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "-0.9877659459927356",
+        "-0x0.fcde3aa2242c58#53",
+        "0.15594369476537437",
+        "0x0.27ebed0d08e166#53",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "0.32719469679615221",
+        "0x0.53c3081a2a0318#53",
+        "0.9449569463147376",
+        "0x0.f1e8b2cc8cc160#53",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "0.32719469679615226",
+        "0x0.53c3081a2a031c#53",
+        "0.9449569463147377",
+        "0x0.f1e8b2cc8cc168#53",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "0.32719469679615221",
+        "0x0.53c3081a2a0318#53",
+        "0.9449569463147376",
+        "0x0.f1e8b2cc8cc160#53",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "0.32719469679615226",
+        "0x0.53c3081a2a031c#53",
+        "0.9449569463147377",
+        "0x0.f1e8b2cc8cc168#53",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "0.32719469679615221",
+        "0x0.53c3081a2a0318#53",
+        "0.9449569463147377",
+        "0x0.f1e8b2cc8cc168#53",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        "0.2",
+        "0x0.4#1",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "0.3271946967961522441733440852676206060643014068937597915900562",
+        "0x0.53c3081a2a031ab144c484c791da121ff8dcc688098d4740778#200",
+        "0.9449569463147376643882840076758806078458526995651407376776453",
+        "0x0.f1e8b2cc8cc1656b6998d964d0f06c45113a7686fcc7f1d25d#200",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "0.3271946967961522441733440852676206060643014068937597915900565",
+        "0x0.53c3081a2a031ab144c484c791da121ff8dcc688098d4740780#200",
+        "0.944956946314737664388284007675880607845852699565140737677646",
+        "0x0.f1e8b2cc8cc1656b6998d964d0f06c45113a7686fcc7f1d25e#200",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "0.3271946967961522441733440852676206060643014068937597915900562",
+        "0x0.53c3081a2a031ab144c484c791da121ff8dcc688098d4740778#200",
+        "0.9449569463147376643882840076758806078458526995651407376776453",
+        "0x0.f1e8b2cc8cc1656b6998d964d0f06c45113a7686fcc7f1d25d#200",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "0.3271946967961522441733440852676206060643014068937597915900565",
+        "0x0.53c3081a2a031ab144c484c791da121ff8dcc688098d4740780#200",
+        "0.944956946314737664388284007675880607845852699565140737677646",
+        "0x0.f1e8b2cc8cc1656b6998d964d0f06c45113a7686fcc7f1d25e#200",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "0.3271946967961522441733440852676206060643014068937597915900562",
+        "0x0.53c3081a2a031ab144c484c791da121ff8dcc688098d4740778#200",
+        "0.944956946314737664388284007675880607845852699565140737677646",
+        "0x0.f1e8b2cc8cc1656b6998d964d0f06c45113a7686fcc7f1d25e#200",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "0.00126448893037735340036035047564593249177391059",
+        "0x0.0052de9a9a24d90da7cdffe3012c45dd3a10b27a#150",
+        "-0.999999200533552903268335739656574951575828821",
+        "-0x0.fffff29651586c28bc01f9fb24c20d43542f88#150",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "0.001264488930377353400360350475645932491773910591",
+        "0x0.0052de9a9a24d90da7cdffe3012c45dd3a10b27c#150",
+        "-0.9999992005335529032683357396565749515758288204",
+        "-0x0.fffff29651586c28bc01f9fb24c20d43542f84#150",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "0.00126448893037735340036035047564593249177391059",
+        "0x0.0052de9a9a24d90da7cdffe3012c45dd3a10b27a#150",
+        "-0.9999992005335529032683357396565749515758288204",
+        "-0x0.fffff29651586c28bc01f9fb24c20d43542f84#150",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "0.001264488930377353400360350475645932491773910591",
+        "0x0.0052de9a9a24d90da7cdffe3012c45dd3a10b27c#150",
+        "-0.999999200533552903268335739656574951575828821",
+        "-0x0.fffff29651586c28bc01f9fb24c20d43542f88#150",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "0.00126448893037735340036035047564593249177391059",
+        "0x0.0052de9a9a24d90da7cdffe3012c45dd3a10b27a#150",
+        "-0.9999992005335529032683357396565749515758288204",
+        "-0x0.fffff29651586c28bc01f9fb24c20d43542f84#150",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "0.8955",
+        "0x0.e54#10",
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "0.8965",
+        "0x0.e58#10",
+        "0.4438",
+        "0x0.71a#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "0.8955",
+        "0x0.e54#10",
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "0.8965",
+        "0x0.e58#10",
+        "0.4438",
+        "0x0.71a#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "0.8965",
+        "0x0.e58#10",
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        "-2.449912578931295e-15",
+        "-0xb.088e90c77fd18E-13#53",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        RoundingMode::Ceiling,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        "-2.4499125789312946e-15",
+        "-0xb.088e90c77fd10E-13#53",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        RoundingMode::Down,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        "-2.4499125789312946e-15",
+        "-0xb.088e90c77fd10E-13#53",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        "-2.449912578931295e-15",
+        "-0xb.088e90c77fd18E-13#53",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "17.27875959474386",
+        "0x11.475cc9eedf00#53",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        "-2.4499125789312946e-15",
+        "-0xb.088e90c77fd10E-13#53",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn sin_cos_round_fail() {
+    assert_panic!(Float::from(3u32).sin_cos_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).sin_cos_round_ref(RoundingMode::Exact));
+}
+
+#[test]
+fn test_sin_cos_prec_round() {
+    let test = |s,
+                s_hex,
+                prec,
+                rm,
+                sin_out: &str,
+                sin_hex: &str,
+                cos_out: &str,
+                cos_hex: &str,
+                o_sin_out,
+                o_cos_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_prec_round(prec, rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+        assert_eq!(o_sin, o_sin_out);
+        assert_eq!(o_cos, o_cos_out);
+
+        assert_eq!(sin.to_string(), sin_out);
+        assert_eq!(to_hex_string(&sin), sin_hex);
+        assert_eq!(cos.to_string(), cos_out);
+        assert_eq!(to_hex_string(&cos), cos_hex);
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_round_ref(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_sin_alt, o_sin_out);
+        assert_eq!(o_cos_alt, o_cos_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+                rug_sin_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o_sin, o_sin);
+            assert_eq!(rug_o_cos, o_cos);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.841",
+        "0x0.d74#10",
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.842",
+        "0x0.d78#10",
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.842",
+        "0x0.d78#10",
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.841470984807896506652502321631",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.842",
+        "-0x0.d78#10",
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.841",
+        "-0x0.d74#10",
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.842",
+        "-0x0.d78#10",
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "-0.841470984807896506652502321631",
+        "-0x0.d76aa47848677020c6e9e909d#100",
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e-16",
+        "0x1.0E-13#1",
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "1.224646799147353177226065932277e-16",
+        "0x8.d313198a2e03707344a40939E-14#100",
+        "-0.999999999999999999999999999999",
+        "-0x0.fffffffffffffffffffffffff#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        "0.1",
+        "0x0.2#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        "0.2",
+        "0x0.4#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        "0.1",
+        "0x0.2#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "-0.988",
+        "-0x0.fd0#10",
+        "0.1558",
+        "0x0.27e#10",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.987",
+        "-0x0.fcc#10",
+        "0.156",
+        "0x0.27f#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.987",
+        "-0x0.fcc#10",
+        "0.156",
+        "0x0.27f#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "-0.987765945992735542144668448884",
+        "-0x0.fcde3aa2242c543a529094fdc#100",
+        "0.1559436947653743779644167293878",
+        "0x0.27ebed0d08e166a8f789eb7524#100",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "-0.9877659459927355421446684488834",
+        "-0x0.fcde3aa2242c543a529094fdb#100",
+        "0.155943694765374377964416729388",
+        "0x0.27ebed0d08e166a8f789eb7528#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "-0.9877659459927355421446684488834",
+        "-0x0.fcde3aa2242c543a529094fdb#100",
+        "0.155943694765374377964416729388",
+        "0x0.27ebed0d08e166a8f789eb7528#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "0.3271",
+        "0x0.53c#10",
+        "0.944",
+        "0x0.f1c#10",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "0.3276",
+        "0x0.53e#10",
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "0.3271",
+        "0x0.53c#10",
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Less,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "0.3271946967961522441733440852675",
+        "0x0.53c3081a2a031ab144c484c790#100",
+        "0.944956946314737664388284007676",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "0.3271946967961522441733440852679",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        "0.9449569463147376643882840076766",
+        "0x0.f1e8b2cc8cc1656b6998d964e#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "0.3271946967961522441733440852675",
+        "0x0.53c3081a2a031ab144c484c790#100",
+        "0.944956946314737664388284007676",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "0.8955",
+        "0x0.e54#10",
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.8965",
+        "0x0.e58#10",
+        "0.4438",
+        "0x0.71a#10",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "0.8965",
+        "0x0.e58#10",
+        "0.4434",
+        "0x0.718#10",
+        Ordering::Greater,
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "0.896288461614839760936648977546",
+        "0x0.e573291e6afab7473e6ba430f#100",
+        "0.4434715251017858896674117237525",
+        "0x0.7187599104f847ef392b3b1260#100",
+        Ordering::Less,
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "0.896288461614839760936648977547",
+        "0x0.e573291e6afab7473e6ba4310#100",
+        "0.4434715251017858896674117237529",
+        "0x0.7187599104f847ef392b3b1268#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "0.896288461614839760936648977547",
+        "0x0.e573291e6afab7473e6ba4310#100",
+        "0.4434715251017858896674117237529",
+        "0x0.7187599104f847ef392b3b1268#100",
+        Ordering::Greater,
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn sin_cos_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).sin_cos_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).sin_cos_prec_round_ref(0, RoundingMode::Floor));
+
+    assert_panic!(Float::from(3u32).sin_cos_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).sin_cos_prec_round_ref(1, RoundingMode::Exact));
+}
+
+#[test]
+fn sin_cos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_25().test_properties(|(x, prec, rm)| {
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_prec_round(prec, rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_round_ref(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        // The results agree with those of the separate functions.
+        let (sin_alt, o_sin_alt) = x.sin_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(o_sin_alt, o_sin);
+        let (cos_alt, o_cos_alt) = x.cos_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_cos_alt, o_cos);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+                rug_sin_cos_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o_sin, o_sin);
+            assert_eq!(rug_o_cos, o_cos);
+        }
+
+        if o_sin == Ordering::Equal && o_cos == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, c, oo_sin, oo_cos) = x.sin_cos_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(sin.clone()));
+                assert_eq!(ComparableFloat(c), ComparableFloat(cos.clone()));
+                assert_eq!(oo_sin, Ordering::Equal);
+                assert_eq!(oo_cos, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.sin_cos_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn sin_cos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_prec(prec);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_ref(prec);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) =
+            x.sin_cos_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        if x.is_finite() {
+            // $\sin(-x) = -\sin x$ and $\cos(-x) = \cos x$.
+            let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = (-&x).sin_cos_prec(prec);
+            assert_eq!(ComparableFloat(-sin_alt), ComparableFloat(sin.clone()));
+            assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+            assert_eq!(o_sin_alt.reverse(), o_sin);
+            assert_eq!(o_cos_alt, o_cos);
+        }
+
+        let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) = rug_sin_cos_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o_sin, o_sin);
+        assert_eq!(rug_o_cos, o_cos);
+    });
+}
+
+#[test]
+fn sin_cos_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, _, floor_o_sin, floor_o_cos) =
+                x.sin_cos_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact
+                && (floor_o_sin != Ordering::Equal || floor_o_cos != Ordering::Equal)
+            {
+                assert_panic!(x.sin_cos_round_ref(rm));
+                continue;
+            }
+
+            let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_round(rm);
+            assert!(sin.is_valid());
+            assert!(cos.is_valid());
+
+            let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_round_ref(rm);
+            assert!(sin_alt.is_valid());
+            assert!(cos_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+            assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+            assert_eq!(o_sin_alt, o_sin);
+            assert_eq!(o_cos_alt, o_cos);
+
+            let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+            assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+            assert_eq!(o_sin_alt, o_sin);
+            assert_eq!(o_cos_alt, o_cos);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+                    rug_sin_cos_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_sin)),
+                    ComparableFloatRef(&sin)
+                );
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_cos)),
+                    ComparableFloatRef(&cos)
+                );
+                assert_eq!(rug_o_sin, o_sin);
+                assert_eq!(rug_o_cos, o_cos);
+            }
+        }
+    });
+
+    let test_special = |x: Float, sin_out: Float, cos_out: Float| {
+        let (sin, cos, o_sin, o_cos) = x.sin_cos_round(RoundingMode::Nearest);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(sin_out));
+        assert_eq!(ComparableFloat(cos), ComparableFloat(cos_out));
+        assert_eq!(o_sin, Ordering::Equal);
+        assert_eq!(o_cos, Ordering::Equal);
+    };
+    test_special(Float::NAN, Float::NAN, Float::NAN);
+    test_special(Float::INFINITY, Float::NAN, Float::NAN);
+    test_special(Float::NEGATIVE_INFINITY, Float::NAN, Float::NAN);
+    test_special(Float::ZERO, Float::ZERO, Float::ONE);
+    test_special(Float::NEGATIVE_ZERO, Float::NEGATIVE_ZERO, Float::ONE);
+}
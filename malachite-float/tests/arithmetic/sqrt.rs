@@ -0,0 +1,3287 @@
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_3,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_sqrt_prec_round(x: &rug::Float, prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.sqrt_ref(), rm)
+}
+
+fn square(x: &Float) -> Rational {
+    let x = Rational::exact_from(x);
+    &x * &x
+}
+
+#[test]
+fn test_sqrt() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let sqrt = x.clone().sqrt();
+        assert!(sqrt.is_valid());
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+
+        let sqrt_alt = (&x).sqrt();
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+
+        let mut sqrt_alt = x.clone();
+        sqrt_alt.sqrt_assign();
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+
+        let (rug_sqrt, _) = rug_sqrt_prec_round(
+            &rug::Float::exact_from(&x),
+            x.significant_bits(),
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt)),
+            ComparableFloatRef(&sqrt)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test("0.5", "0x0.8#1", "0.5", "0x0.8#1");
+    test("123.0", "0x7b.0#7", "11.1", "0xb.2#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.6487212707001282",
+        "0x1.a61298e1e069c#53",
+    );
+    test("-1.4142135623730951", "-0x1.6a09e667f3bcd#53", "NaN", "NaN");
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "0.5773502691896257",
+        "0x0.93cd3a2c8198e0#53",
+    );
+    test("1.0e30", "0x1.0E+25#1", "1.0e15", "0x4.0E+12#1");
+    test("8.0e-31", "0x1.0E-25#1", "9.0e-16", "0x4.0E-13#1");
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        "0.5773502691896257645091487805019574556476017512701268760186025",
+        "0x0.93cd3a2c8198e2690c7c0f257d92be830c9d66eec69e17dd98#200",
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        "NaN",
+        "NaN",
+    );
+    test("1.111", "0x1.1c8#10", "1.055", "0x1.0e0#10");
+    test("4.0", "0x4.0#3", "2.0", "0x2.0#3");
+    test("9.0", "0x9.0#4", "3.0", "0x3.0#4");
+    test("0.2", "0x0.4#1", "0.5", "0x0.8#1");
+    test("2.2", "0x2.4#4", "1.5", "0x1.8#4");
+    test("1.56", "0x1.9#5", "1.25", "0x1.4#5");
+}
+
+#[test]
+fn test_sqrt_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sqrt, o) = x.clone().sqrt_prec(prec);
+        assert!(sqrt.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_ref(prec);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sqrt_alt = x.clone();
+        let o_alt = sqrt_alt.sqrt_prec_assign(prec);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_sqrt, rug_o) = rug_sqrt_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt)),
+            ComparableFloatRef(&sqrt)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("1.0", "0x1.0#1", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Ordering::Equal);
+    test("0.5", "0x0.8#1", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("0.5", "0x0.8#1", 10, "0.707", "0x0.b50#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.7071067811865475244008443621046",
+        "0x0.b504f333f9de6484597d89b37#100",
+        Ordering::Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "8.0", "0x8.0#1", Ordering::Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "11.09",
+        "0xb.18#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "11.09053650640941716205160010262",
+        "0xb.172d66861f5eda75e3bedbc0#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.771",
+        "0x1.c58#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.77245385090551599275151910314",
+        "0x1.c5bf891b4ef6a7fc7dc11ccfa#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.648",
+        "0x1.a60#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.648721270700128103007192640211",
+        "0x1.a61298e1e069b96e729041524#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        "0.577350269189625748484459727306",
+        "0x0.93cd3a2c8198e1417207b6225#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "1.126e15",
+        "0x4.00E+12#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "1125899906842624.0",
+        "0x4000000000000.0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "8.88e-16",
+        "0x4.00E-13#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "8.88178419700125232338905334473e-16",
+        "0x4.0000000000000000000000000E-13#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        "0.577350269189625764509148780502",
+        "0x0.93cd3a2c8198e2690c7c0f258#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test("1.111", "0x1.1c8#10", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        "1.055",
+        "0x1.0e0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        "1.054195487089562530536605566555",
+        "0x1.0ddfc164a3f5456dc8876db12#100",
+        Ordering::Less,
+    );
+    test("4.0", "0x4.0#3", 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test("4.0", "0x4.0#3", 10, "2.0", "0x2.00#10", Ordering::Equal);
+    test(
+        "4.0",
+        "0x4.0#3",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("9.0", "0x9.0#4", 1, "4.0", "0x4.0#1", Ordering::Greater);
+    test("9.0", "0x9.0#4", 10, "3.0", "0x3.00#10", Ordering::Equal);
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("0.2", "0x0.4#1", 1, "0.5", "0x0.8#1", Ordering::Equal);
+    test("0.2", "0x0.4#1", 10, "0.5", "0x0.800#10", Ordering::Equal);
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("2.2", "0x2.4#4", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("2.2", "0x2.4#4", 10, "1.5", "0x1.800#10", Ordering::Equal);
+    test(
+        "2.2",
+        "0x2.4#4",
+        100,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("1.56", "0x1.9#5", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test("1.56", "0x1.9#5", 10, "1.25", "0x1.400#10", Ordering::Equal);
+    test(
+        "1.56",
+        "0x1.9#5",
+        100,
+        "1.25",
+        "0x1.4000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn sqrt_prec_fail() {
+    assert_panic!(Float::NAN.sqrt_prec(0));
+    assert_panic!(Float::NAN.sqrt_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.sqrt_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_sqrt_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sqrt, o) = x.clone().sqrt_round(rm);
+        assert!(sqrt.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+
+        let (sqrt_alt, o_alt) = x.sqrt_round_ref(rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sqrt_alt = x.clone();
+        let o_alt = sqrt_alt.sqrt_round_assign(rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sqrt, rug_o) =
+                rug_sqrt_prec_round(&rug::Float::exact_from(&x), x.significant_bits(), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sqrt)),
+                ComparableFloatRef(&sqrt)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "11.0",
+        "0xb.0#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "11.1",
+        "0xb.2#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "11.0",
+        "0xb.0#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "11.1",
+        "0xb.2#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "11.1",
+        "0xb.2#7",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.7724538509055161",
+        "0x1.c5bf891b4ef6b#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.7724538509055161",
+        "0x1.c5bf891b4ef6b#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "1.648721270700128",
+        "0x1.a61298e1e069b#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "1.6487212707001282",
+        "0x1.a61298e1e069c#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "1.648721270700128",
+        "0x1.a61298e1e069b#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "1.6487212707001282",
+        "0x1.a61298e1e069c#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "1.6487212707001282",
+        "0x1.a61298e1e069c#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Floor,
+        "0.5773502691896257",
+        "0x0.93cd3a2c8198e0#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Ceiling,
+        "0.5773502691896258",
+        "0x0.93cd3a2c8198e8#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Down,
+        "0.5773502691896257",
+        "0x0.93cd3a2c8198e0#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Up,
+        "0.5773502691896258",
+        "0x0.93cd3a2c8198e8#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        RoundingMode::Nearest,
+        "0.5773502691896257",
+        "0x0.93cd3a2c8198e0#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Exact,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Floor,
+        "0.577350269189625764509148780501957455647601751270126876018602",
+        "0x0.93cd3a2c8198e2690c7c0f257d92be830c9d66eec69e17dd97#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Ceiling,
+        "0.5773502691896257645091487805019574556476017512701268760186025",
+        "0x0.93cd3a2c8198e2690c7c0f257d92be830c9d66eec69e17dd98#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Down,
+        "0.577350269189625764509148780501957455647601751270126876018602",
+        "0x0.93cd3a2c8198e2690c7c0f257d92be830c9d66eec69e17dd97#200",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Up,
+        "0.5773502691896257645091487805019574556476017512701268760186025",
+        "0x0.93cd3a2c8198e2690c7c0f257d92be830c9d66eec69e17dd98#200",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        RoundingMode::Nearest,
+        "0.5773502691896257645091487805019574556476017512701268760186025",
+        "0x0.93cd3a2c8198e2690c7c0f257d92be830c9d66eec69e17dd98#200",
+        Ordering::Greater,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.142857142857142857142857142857142857142857142",
+        "-0x3.2492492492492492492492492492492492492#150",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Floor,
+        "1.053",
+        "0x1.0d8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Ceiling,
+        "1.055",
+        "0x1.0e0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Down,
+        "1.053",
+        "0x1.0d8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Up,
+        "1.055",
+        "0x1.0e0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        RoundingMode::Nearest,
+        "1.055",
+        "0x1.0e0#10",
+        Ordering::Greater,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Down,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Up,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.0#4",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        RoundingMode::Floor,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        RoundingMode::Down,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        RoundingMode::Up,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        RoundingMode::Exact,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        RoundingMode::Floor,
+        "1.25",
+        "0x1.4#5",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        RoundingMode::Ceiling,
+        "1.25",
+        "0x1.4#5",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        RoundingMode::Down,
+        "1.25",
+        "0x1.4#5",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        RoundingMode::Up,
+        "1.25",
+        "0x1.4#5",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        RoundingMode::Nearest,
+        "1.25",
+        "0x1.4#5",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        RoundingMode::Exact,
+        "1.25",
+        "0x1.4#5",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn sqrt_round_fail() {
+    assert_panic!(Float::from(3u32).sqrt_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).sqrt_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.sqrt_round_assign(RoundingMode::Exact);
+    });
+}
+
+#[test]
+fn test_sqrt_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sqrt, o) = x.clone().sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sqrt_alt = x.clone();
+        let o_alt = sqrt_alt.sqrt_prec_round_assign(prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sqrt, rug_o) = rug_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sqrt)),
+                ComparableFloatRef(&sqrt)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "0.707",
+        "0x0.b50#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.708",
+        "0x0.b54#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "0.707",
+        "0x0.b50#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Floor,
+        "0.7071067811865475244008443621046",
+        "0x0.b504f333f9de6484597d89b37#100",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.7071067811865475244008443621054",
+        "0x0.b504f333f9de6484597d89b38#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Nearest,
+        "0.7071067811865475244008443621046",
+        "0x0.b504f333f9de6484597d89b37#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.771",
+        "0x1.c58#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.773",
+        "0x1.c60#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.771",
+        "0x1.c58#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "1.772453850905515992751519103138",
+        "0x1.c5bf891b4ef6a7fc7dc11ccf8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "1.77245385090551599275151910314",
+        "0x1.c5bf891b4ef6a7fc7dc11ccfa#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "1.77245385090551599275151910314",
+        "0x1.c5bf891b4ef6a7fc7dc11ccfa#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "1.648",
+        "0x1.a60#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.65",
+        "0x1.a68#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "1.648",
+        "0x1.a60#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Floor,
+        "1.648721270700128103007192640211",
+        "0x1.a61298e1e069b96e729041524#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Ceiling,
+        "1.648721270700128103007192640213",
+        "0x1.a61298e1e069b96e729041526#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Nearest,
+        "1.648721270700128103007192640211",
+        "0x1.a61298e1e069b96e729041524#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        RoundingMode::Floor,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.578",
+        "0x0.940#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        10,
+        RoundingMode::Nearest,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        RoundingMode::Floor,
+        "0.577350269189625748484459727305",
+        "0x0.93cd3a2c8198e1417207b6224#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.577350269189625748484459727306",
+        "0x0.93cd3a2c8198e1417207b6225#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        100,
+        RoundingMode::Nearest,
+        "0.577350269189625748484459727306",
+        "0x0.93cd3a2c8198e1417207b6225#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Floor,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Ceiling,
+        "0.578",
+        "0x0.940#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        10,
+        RoundingMode::Nearest,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Floor,
+        "0.577350269189625764509148780501",
+        "0x0.93cd3a2c8198e2690c7c0f257#100",
+        Ordering::Less,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Ceiling,
+        "0.577350269189625764509148780502",
+        "0x0.93cd3a2c8198e2690c7c0f258#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.3333333333333333333333333333333333333333333333333333333333334",
+        "0x0.555555555555555555555555555555555555555555555555558#200",
+        100,
+        RoundingMode::Nearest,
+        "0.577350269189625764509148780502",
+        "0x0.93cd3a2c8198e2690c7c0f258#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Floor,
+        "1.053",
+        "0x1.0d8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Ceiling,
+        "1.055",
+        "0x1.0e0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        10,
+        RoundingMode::Nearest,
+        "1.055",
+        "0x1.0e0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Floor,
+        "1.054195487089562530536605566555",
+        "0x1.0ddfc164a3f5456dc8876db12#100",
+        Ordering::Less,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Ceiling,
+        "1.054195487089562530536605566557",
+        "0x1.0ddfc164a3f5456dc8876db14#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.111",
+        "0x1.1c8#10",
+        100,
+        RoundingMode::Nearest,
+        "1.054195487089562530536605566555",
+        "0x1.0ddfc164a3f5456dc8876db12#100",
+        Ordering::Less,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        1,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        10,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        100,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        100,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        100,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#3",
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        1,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        10,
+        RoundingMode::Floor,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        10,
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        10,
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        10,
+        RoundingMode::Exact,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        100,
+        RoundingMode::Floor,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        100,
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        100,
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.2",
+        "0x2.4#4",
+        100,
+        RoundingMode::Exact,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        10,
+        RoundingMode::Floor,
+        "1.25",
+        "0x1.400#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        10,
+        RoundingMode::Ceiling,
+        "1.25",
+        "0x1.400#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        10,
+        RoundingMode::Nearest,
+        "1.25",
+        "0x1.400#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        10,
+        RoundingMode::Exact,
+        "1.25",
+        "0x1.400#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        100,
+        RoundingMode::Floor,
+        "1.25",
+        "0x1.4000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        100,
+        RoundingMode::Ceiling,
+        "1.25",
+        "0x1.4000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        100,
+        RoundingMode::Nearest,
+        "1.25",
+        "0x1.4000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.56",
+        "0x1.9#5",
+        100,
+        RoundingMode::Exact,
+        "1.25",
+        "0x1.4000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn sqrt_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).sqrt_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).sqrt_prec_round_ref(0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.sqrt_prec_round_assign(0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::from(3u32).sqrt_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).sqrt_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.sqrt_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn sqrt_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_3().test_properties(|(x, prec, rm)| {
+        let (sqrt, o) = x.clone().sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_valid());
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sqrt_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x > 0u32 {
+            if sqrt.is_normal() {
+                assert_eq!(sqrt.get_prec(), Some(prec));
+            }
+            let r_x = Rational::exact_from(&x);
+            assert_eq!(square(&sqrt).partial_cmp(&r_x), Some(o));
+            if o == Ordering::Less {
+                let mut next = sqrt.clone();
+                next.increment();
+                assert!(square(&next) > r_x);
+            } else if o == Ordering::Greater {
+                let mut next = sqrt.clone();
+                next.decrement();
+                assert!(square(&next) < r_x);
+            }
+            match rm {
+                RoundingMode::Floor | RoundingMode::Down => assert_ne!(o, Ordering::Greater),
+                RoundingMode::Ceiling | RoundingMode::Up => assert_ne!(o, Ordering::Less),
+                RoundingMode::Exact => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+        } else {
+            assert_eq!(o, Ordering::Equal);
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sqrt, rug_o) = rug_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sqrt)),
+                ComparableFloatRef(&sqrt)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        // `root` with an even `n` maps -0.0 to 0.0, while `sqrt` leaves it alone.
+        let (sqrt_alt, o_alt) = x.root_prec_round_ref(2, prec, rm);
+        assert_eq!(
+            ComparableFloat(sqrt_alt),
+            ComparableFloat(sqrt.abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt, o);
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.sqrt_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(sqrt.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.sqrt_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn sqrt_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (sqrt, o) = x.clone().sqrt_prec(prec);
+        assert!(sqrt.is_valid());
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_ref(prec);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sqrt_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x > 0u32 {
+            assert_eq!(
+                square(&sqrt).partial_cmp(&Rational::exact_from(&x)),
+                Some(o)
+            );
+        }
+
+        let (rug_sqrt, rug_o) = rug_sqrt_prec_round(
+            &rug::Float::exact_from(&x),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt)),
+            ComparableFloatRef(&sqrt)
+        );
+        assert_eq!(rug_o, o);
+
+        if x.is_finite() && x != 0u32 {
+            // The square of a `Float` with precision `p` is exactly representable with precision
+            // `2p`, and its square root is the absolute value of the original `Float`.
+            let (square, o) = x.mul_prec_ref_ref(&x, x.significant_bits() << 1);
+            assert_eq!(o, Ordering::Equal);
+            let (sqrt_alt, o) = square.sqrt_prec(x.significant_bits());
+            assert_eq!(o, Ordering::Equal);
+            assert_eq!(sqrt_alt, if x > 0u32 { x.clone() } else { -&x });
+        }
+    });
+}
+
+#[test]
+fn sqrt_round_properties() {
+    float_gen().test_properties(|x| {
+        for rm in exhaustive_rounding_modes() {
+            let prec = x.significant_bits();
+            let (_, floor_o) = x.sqrt_prec_round_ref(prec, RoundingMode::Floor);
+            if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+                assert_panic!(x.sqrt_round_ref(rm));
+                continue;
+            }
+
+            let (sqrt, o) = x.clone().sqrt_round(rm);
+            assert!(sqrt.is_valid());
+
+            let (sqrt_alt, o_alt) = x.sqrt_round_ref(rm);
+            assert!(sqrt_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.sqrt_round_assign(rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+            assert_eq!(o_alt, o);
+
+            let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_sqrt, rug_o) =
+                    rug_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_sqrt)),
+                    ComparableFloatRef(&sqrt)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+}
+
+#[test]
+fn sqrt_properties() {
+    float_gen().test_properties(|x| {
+        let sqrt = x.clone().sqrt();
+        assert!(sqrt.is_valid());
+
+        let sqrt_alt = (&x).sqrt();
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+
+        let mut x_alt = x.clone();
+        x_alt.sqrt_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+
+        let (sqrt_alt, _) = x.sqrt_round_ref(RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+
+        let (rug_sqrt, _) = rug_sqrt_prec_round(
+            &rug::Float::exact_from(&x),
+            x.significant_bits(),
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt)),
+            ComparableFloatRef(&sqrt)
+        );
+
+        if x < 0u32 {
+            assert!(sqrt.is_nan());
+        } else if !x.is_nan() {
+            assert!(sqrt >= 0u32);
+        }
+    });
+
+    assert!(Float::NAN.sqrt().is_nan());
+    assert_eq!(
+        ComparableFloat(Float::INFINITY.sqrt()),
+        ComparableFloat(Float::INFINITY)
+    );
+    assert!(Float::NEGATIVE_INFINITY.sqrt().is_nan());
+    assert_eq!(
+        ComparableFloat(Float::ZERO.sqrt()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ZERO.sqrt()),
+        ComparableFloat(Float::NEGATIVE_ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::ONE.sqrt()),
+        ComparableFloat(Float::ONE)
+    );
+    assert!(Float::NEGATIVE_ONE.sqrt().is_nan());
+}
@@ -0,0 +1,8262 @@
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_unsigned_rounding_mode_quadruple_gen_var_2, float_float_unsigned_triple_gen_var_1,
+    float_gen, float_pair_gen, float_pair_gen_var_1, float_rational_pair_gen,
+    float_rational_pair_gen_var_1, float_rational_unsigned_rounding_mode_quadruple_gen_var_2,
+    float_rational_unsigned_triple_gen_var_1, float_triple_gen,
+    float_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn rug_sub_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x - y, rm)
+}
+
+fn rug_sub_rational_prec_round(
+    x: &rug::Float,
+    y: &rug::Rational,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x - y, rm)
+}
+
+#[test]
+fn test_sub() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let diff = x.clone() - y.clone();
+        assert!(diff.is_valid());
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let diff_alt = x.clone() - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        let diff_alt = &x - y.clone();
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        let diff_alt = &x - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+
+        let mut diff_alt = x.clone();
+        diff_alt -= y.clone();
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        let mut diff_alt = x.clone();
+        diff_alt -= &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+
+        let prec = max(x.significant_bits(), y.significant_bits());
+        let (rug_diff, _) = rug_sub_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_diff)),
+            ComparableFloatRef(&diff)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity", "NaN", "NaN");
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", "Infinity", "Infinity",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "123.0", "0x7b.0#7", "-123.0", "-0x7b.0#7");
+    test("123.0", "0x7b.0#7", "-0.0", "-0x0.0", "123.0", "0x7b.0#7");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "2.0", "0x2.0#1");
+    test("123.0", "0x7b.0#7", "1.0", "0x1.0#1", "122.0", "0x7a.0#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "4.555806215962888",
+        "0x4.8e4950f0795fc#53",
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "-1.2676506002282294e30",
+        "-0x1.0000000000000E+25#53",
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+    );
+    test("1.0", "0x1.0#1", "8.0e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+}
+
+#[test]
+fn test_sub_prec() {
+    let test = |s, s_hex, t, t_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (diff, o) = x.clone().sub_prec(y.clone(), prec);
+        assert!(diff.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let (diff_alt, o_alt) = x.clone().sub_prec_val_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_prec_ref_val(y.clone(), prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_prec_ref_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_prec_assign(y.clone(), prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_prec_assign_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_diff, rug_o) = rug_sub_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_diff)),
+            ComparableFloatRef(&diff)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "-123.0",
+        "-0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        100,
+        "123.0",
+        "0x7b.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "122.0",
+        "0x7a.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "122.0",
+        "0x7a.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        "4.555",
+        "0x4.8e#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        100,
+        "4.555806215962888261472585327283",
+        "0x4.8e4950f0795fd000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "-1.0e30",
+        "-0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "-1.268e30",
+        "-0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "-1267650600228229401496703205376.0",
+        "-0x10000000000000000000000000.0#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "-3.141592653589793115997963468544",
+        "-0x3.243f6a8885a30000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "0.999999999999999999999999999999",
+        "0x0.fffffffffffffffffffffffff#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn sub_prec_fail() {
+    assert_panic!(Float::ONE.sub_prec(Float::TWO, 0));
+    assert_panic!(Float::ONE.sub_prec_val_ref(&Float::TWO, 0));
+    assert_panic!(Float::ONE.sub_prec_ref_val(Float::TWO, 0));
+    assert_panic!(Float::ONE.sub_prec_ref_ref(&Float::TWO, 0));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_prec_assign(Float::TWO, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_prec_assign_ref(&Float::TWO, 0)
+    });
+}
+
+#[test]
+fn test_sub_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (diff, o) = x.clone().sub_round(y.clone(), rm);
+        assert!(diff.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let (diff_alt, o_alt) = x.clone().sub_round_val_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_round_ref_val(y.clone(), rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_round_ref_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_round_assign(y.clone(), rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_round_assign_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_diff, rug_o) = rug_sub_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                max(x.significant_bits(), y.significant_bits()),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_diff)),
+                ComparableFloatRef(&diff)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Floor,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Ceiling,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Down,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Up,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Nearest,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        RoundingMode::Exact,
+        "-123.0",
+        "-0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Down,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Up,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "122.0",
+        "0x7a.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "122.0",
+        "0x7a.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "122.0",
+        "0x7a.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "122.0",
+        "0x7a.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "122.0",
+        "0x7a.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "122.0",
+        "0x7a.0#7",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Exact,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Floor,
+        "4.555806215962888",
+        "0x4.8e4950f0795fc#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Ceiling,
+        "4.555806215962889",
+        "0x4.8e4950f079600#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Down,
+        "4.555806215962888",
+        "0x4.8e4950f0795fc#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Up,
+        "4.555806215962889",
+        "0x4.8e4950f079600#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        RoundingMode::Nearest,
+        "4.555806215962888",
+        "0x4.8e4950f0795fc#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "-1.2676506002282294e30",
+        "-0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "-1.2676506002282293e30",
+        "-0xf.ffffffffffff8E+24#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "-1.2676506002282293e30",
+        "-0xf.ffffffffffff8E+24#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "-1.2676506002282294e30",
+        "-0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "-1.2676506002282294e30",
+        "-0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-3.1415926535897927",
+        "-0x3.243f6a8885a2e#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-3.1415926535897927",
+        "-0x3.243f6a8885a2e#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn sub_round_fail() {
+    assert_panic!(Float::from(3u32).sub_round(Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).sub_round_val_ref(&Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).sub_round_ref_val(Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).sub_round_ref_ref(&Float::from(1.0e-100), RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.sub_round_assign(Float::from(1.0e-100), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.sub_round_assign_ref(&Float::from(1.0e-100), RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_sub_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (diff, o) = x.clone().sub_prec_round(y.clone(), prec, rm);
+        assert!(diff.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let (diff_alt, o_alt) = x.clone().sub_prec_round_val_ref(&y, prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_prec_round_ref_ref(&y, prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_prec_round_assign(y.clone(), prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_prec_round_assign_ref(&y, prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_diff, rug_o) = rug_sub_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_diff)),
+                ComparableFloatRef(&diff)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Floor,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Ceiling,
+        "-6.0e1",
+        "-0x4.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Down,
+        "-6.0e1",
+        "-0x4.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Up,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        RoundingMode::Nearest,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Floor,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Down,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Up,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Nearest,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        RoundingMode::Exact,
+        "-123.0",
+        "-0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Floor,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Down,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Up,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "122.0",
+        "0x7a.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "122.0",
+        "0x7a.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "122.0",
+        "0x7a.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "122.0",
+        "0x7a.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "122.0",
+        "0x7a.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "122.0",
+        "0x7a.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "0.4229",
+        "0x0.6c4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "0.4229",
+        "0x0.6c4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Floor,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Down,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Up,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Floor,
+        "4.555",
+        "0x4.8e#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Ceiling,
+        "4.56",
+        "0x4.90#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Down,
+        "4.555",
+        "0x4.8e#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Up,
+        "4.56",
+        "0x4.90#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        RoundingMode::Nearest,
+        "4.555",
+        "0x4.8e#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0e30",
+        "-0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Ceiling,
+        "-6.0e29",
+        "-0x8.0E+24#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Down,
+        "-6.0e29",
+        "-0x8.0E+24#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Up,
+        "-1.0e30",
+        "-0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        RoundingMode::Nearest,
+        "-1.0e30",
+        "-0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "-1.268e30",
+        "-0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.266e30",
+        "-0xf.fcE+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "-1.266e30",
+        "-0xf.fcE+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "-1.268e30",
+        "-0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.268e30",
+        "-0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Up,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-3.145",
+        "-0x3.25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "-3.145",
+        "-0x3.25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "0.999",
+        "0x0.ffc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "0.999",
+        "0x0.ffc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn sub_prec_round_fail() {
+    assert_panic!(Float::ONE.sub_prec_round(Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.sub_prec_round_val_ref(&Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.sub_prec_round_ref_val(Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.sub_prec_round_ref_ref(&Float::TWO, 0, RoundingMode::Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_prec_round_assign(Float::TWO, 0, RoundingMode::Floor)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_prec_round_assign_ref(&Float::TWO, 0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::ONE.sub_prec_round(Float::from(1.0e-100), 1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.sub_prec_round_val_ref(
+        &Float::from(1.0e-100),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.sub_prec_round_ref_val(Float::from(1.0e-100), 1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.sub_prec_round_ref_ref(
+        &Float::from(1.0e-100),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_prec_round_assign(Float::from(1.0e-100), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_prec_round_assign_ref(&Float::from(1.0e-100), 1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_sub_rational() {
+    let test = |s, s_hex, t, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let diff = x.clone() - y.clone();
+        assert!(diff.is_valid());
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let diff_alt = x.clone() - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        let diff_alt = &x - y.clone();
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        let diff_alt = &x - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+
+        let diff_alt = -(y.clone() - x.clone());
+        assert!(diff_alt.is_valid());
+        assert_eq!(
+            ComparableFloat(diff_alt.abs_negative_zero()),
+            ComparableFloat(diff.abs_negative_zero_ref())
+        );
+        let diff_alt = -(y.clone() - &x);
+        assert!(diff_alt.is_valid());
+        assert_eq!(
+            ComparableFloat(diff_alt.abs_negative_zero()),
+            ComparableFloat(diff.abs_negative_zero_ref())
+        );
+        let diff_alt = -(&y - x.clone());
+        assert!(diff_alt.is_valid());
+        assert_eq!(
+            ComparableFloat(diff_alt.abs_negative_zero()),
+            ComparableFloat(diff.abs_negative_zero_ref())
+        );
+        let diff_alt = -(&y - &x);
+        assert!(diff_alt.is_valid());
+        assert_eq!(
+            ComparableFloat(diff_alt.abs_negative_zero()),
+            ComparableFloat(diff.abs_negative_zero_ref())
+        );
+
+        let mut diff_alt = x.clone();
+        diff_alt -= y.clone();
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        let mut diff_alt = x.clone();
+        diff_alt -= &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+
+        let (rug_diff, _) = rug_sub_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::from(&y),
+            x.significant_bits(),
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_diff)),
+            ComparableFloatRef(&diff)
+        );
+    };
+    test("NaN", "NaN", "123", "NaN", "NaN");
+    test("Infinity", "Infinity", "-123", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "0", "-Infinity", "-Infinity");
+    test("0.0", "0x0.0", "0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "0", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "1/3", "-0.2", "-0x0.4#1");
+    test("-0.0", "-0x0.0", "-22/7", "4.0", "0x4.0#1");
+    test("1.0", "0x1.0#1", "0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "1", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "-1", "2.0", "0x2.0#1");
+    test("123.0", "0x7b.0#7", "1/3", "123.0", "0x7b.0#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        "6.284449796446936",
+        "0x6.48d1b3ad17ec4#53",
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        "-1.7475468957064284",
+        "-0x1.bf5f3bbd49122#53",
+    );
+    test("1.0e30", "0x1.0E+25#1", "1/3", "1.0e30", "0x1.0E+25#1");
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        "-1.0e9",
+        "-0x4.0E+7#1",
+    );
+}
+
+#[test]
+fn test_sub_rational_prec() {
+    let test = |s, s_hex, t, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (diff, o) = x.clone().sub_rational_prec(y.clone(), prec);
+        assert!(diff.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let (diff_alt, o_alt) = x.clone().sub_rational_prec_val_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_rational_prec_ref_val(y.clone(), prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_rational_prec_ref_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_rational_prec_assign(y.clone(), prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_rational_prec_assign_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_diff, rug_o) = rug_sub_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_diff)),
+            ComparableFloatRef(&diff)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "123", 1, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", "123", 10, "NaN", "NaN", Ordering::Equal);
+    test("NaN", "NaN", "123", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        100,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", "0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", "0", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", "0", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", "0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", "0", 10, "-0.0", "-0x0.0", Ordering::Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        "-0.3335",
+        "-0x0.556#10",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        100,
+        "-0.3333333333333333333333333333335",
+        "-0x0.55555555555555555555555558#100",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        100,
+        "3.142857142857142857142857142858",
+        "0x3.2492492492492492492492494#100",
+        Ordering::Greater,
+    );
+    test("1.0", "0x1.0#1", "0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.0#1", "1", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", "1", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", "1", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", "-1", 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        "122.6",
+        "0x7a.a#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        100,
+        "122.6666666666666666666666666666",
+        "0x7a.aaaaaaaaaaaaaaaaaaaaaaa8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        "6.28",
+        "0x6.48#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        100,
+        "6.2844497964469359731408206114",
+        "0x6.48d1b3ad17ec2492492492490#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        "-1.748",
+        "-0x1.bf8#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        100,
+        "-1.747546895706428478807955192073",
+        "-0x1.bf5f3bbd49122555555555556#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        100,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        "-1.0e9",
+        "-0x4.0E+7#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        "-1.0e9",
+        "-0x3.baE+7#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        100,
+        "-1000000000.0",
+        "-0x3b9aca00.000000000000000000#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn sub_rational_prec_fail() {
+    assert_panic!(Float::ONE.sub_rational_prec(Rational::ONE, 0));
+    assert_panic!(Float::ONE.sub_rational_prec_val_ref(&Rational::ONE, 0));
+    assert_panic!(Float::ONE.sub_rational_prec_ref_val(Rational::ONE, 0));
+    assert_panic!(Float::ONE.sub_rational_prec_ref_ref(&Rational::ONE, 0));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_rational_prec_assign(Rational::ONE, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_rational_prec_assign_ref(&Rational::ONE, 0)
+    });
+}
+
+#[test]
+fn test_sub_rational_round() {
+    let test = |s, s_hex, t, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (diff, o) = x.clone().sub_rational_round(y.clone(), rm);
+        assert!(diff.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let (diff_alt, o_alt) = x.clone().sub_rational_round_val_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_rational_round_ref_val(y.clone(), rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_rational_round_ref_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_rational_round_assign(y.clone(), rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_rational_round_assign_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_diff, rug_o) = rug_sub_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::from(&y),
+                x.significant_bits(),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_diff)),
+                ComparableFloatRef(&diff)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Floor,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Ceiling,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Down,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Up,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        RoundingMode::Nearest,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        RoundingMode::Floor,
+        "122.0",
+        "0x7a.0#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        RoundingMode::Ceiling,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        RoundingMode::Down,
+        "122.0",
+        "0x7a.0#7",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        RoundingMode::Up,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        RoundingMode::Nearest,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Floor,
+        "6.2844497964469355",
+        "0x6.48d1b3ad17ec0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Ceiling,
+        "6.284449796446936",
+        "0x6.48d1b3ad17ec4#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Down,
+        "6.2844497964469355",
+        "0x6.48d1b3ad17ec0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Up,
+        "6.284449796446936",
+        "0x6.48d1b3ad17ec4#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        RoundingMode::Nearest,
+        "6.284449796446936",
+        "0x6.48d1b3ad17ec4#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Floor,
+        "-1.7475468957064286",
+        "-0x1.bf5f3bbd49123#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Ceiling,
+        "-1.7475468957064284",
+        "-0x1.bf5f3bbd49122#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Down,
+        "-1.7475468957064284",
+        "-0x1.bf5f3bbd49122#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Up,
+        "-1.7475468957064286",
+        "-0x1.bf5f3bbd49123#53",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        RoundingMode::Nearest,
+        "-1.7475468957064284",
+        "-0x1.bf5f3bbd49122#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Floor,
+        "6.0e29",
+        "0x8.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Ceiling,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Down,
+        "6.0e29",
+        "0x8.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Up,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        RoundingMode::Nearest,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        RoundingMode::Floor,
+        "-1.0e9",
+        "-0x4.0E+7#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        RoundingMode::Ceiling,
+        "-5.0e8",
+        "-0x2.0E+7#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        RoundingMode::Down,
+        "-5.0e8",
+        "-0x2.0E+7#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        RoundingMode::Up,
+        "-1.0e9",
+        "-0x4.0E+7#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        RoundingMode::Nearest,
+        "-1.0e9",
+        "-0x4.0E+7#1",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn sub_rational_round_fail() {
+    assert_panic!(
+        Float::ONE.sub_rational_round(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    );
+    assert_panic!(Float::ONE
+        .sub_rational_round_val_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!(Float::ONE
+        .sub_rational_round_ref_val(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!(Float::ONE
+        .sub_rational_round_ref_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_rational_round_assign(Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_rational_round_assign_ref(&Rational::from_unsigneds(1u8, 3), RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn test_sub_rational_prec_round() {
+    let test = |s, s_hex, t, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = Rational::from_str(t).unwrap();
+
+        let (diff, o) = x.clone().sub_rational_prec_round(y.clone(), prec, rm);
+        assert!(diff.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let (diff_alt, o_alt) = x.clone().sub_rational_prec_round_val_ref(&y, prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_rational_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let (diff_alt, o_alt) = x.sub_rational_prec_round_ref_ref(&y, prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_rational_prec_round_assign(y.clone(), prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_rational_prec_round_assign_ref(&y, prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff), ComparableFloatRef(&diff_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_diff, rug_o) = rug_sub_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_diff)),
+                ComparableFloatRef(&diff)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        1,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "123",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Ceiling,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Down,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Up,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Down,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Up,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Nearest,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Down,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Up,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        RoundingMode::Down,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        RoundingMode::Up,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "-0.3335",
+        "-0x0.556#10",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "-0.333",
+        "-0x0.554#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        RoundingMode::Down,
+        "-0.333",
+        "-0x0.554#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        RoundingMode::Up,
+        "-0.3335",
+        "-0x0.556#10",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "-0.3335",
+        "-0x0.556#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        RoundingMode::Floor,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        RoundingMode::Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        RoundingMode::Down,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        RoundingMode::Up,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-22/7",
+        10,
+        RoundingMode::Nearest,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        1,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        1,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        1,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "122.6",
+        "0x7a.a#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "122.8",
+        "0x7a.c#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        RoundingMode::Down,
+        "122.6",
+        "0x7a.a#10",
+        Ordering::Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        RoundingMode::Up,
+        "122.8",
+        "0x7a.c#10",
+        Ordering::Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "122.6",
+        "0x7a.a#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Floor,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Down,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Up,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Floor,
+        "6.28",
+        "0x6.48#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Ceiling,
+        "6.29",
+        "0x6.4a#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Down,
+        "6.28",
+        "0x6.48#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Up,
+        "6.29",
+        "0x6.4a#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-22/7",
+        10,
+        RoundingMode::Nearest,
+        "6.28",
+        "0x6.48#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "-1.748",
+        "-0x1.bf8#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "-1.746",
+        "-0x1.bf0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Down,
+        "-1.746",
+        "-0x1.bf0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Up,
+        "-1.748",
+        "-0x1.bf8#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "-1.748",
+        "-0x1.bf8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Floor,
+        "6.0e29",
+        "0x8.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Down,
+        "6.0e29",
+        "0x8.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Up,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        1,
+        RoundingMode::Nearest,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Floor,
+        "1.266e30",
+        "0xf.fcE+24#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Ceiling,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Down,
+        "1.266e30",
+        "0xf.fcE+24#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Up,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1/3",
+        10,
+        RoundingMode::Nearest,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        RoundingMode::Floor,
+        "-1.0e9",
+        "-0x4.0E+7#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        RoundingMode::Ceiling,
+        "-5.0e8",
+        "-0x2.0E+7#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        RoundingMode::Down,
+        "-5.0e8",
+        "-0x2.0E+7#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        RoundingMode::Up,
+        "-1.0e9",
+        "-0x4.0E+7#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        1,
+        RoundingMode::Nearest,
+        "-1.0e9",
+        "-0x4.0E+7#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        RoundingMode::Floor,
+        "-1.0e9",
+        "-0x3.baE+7#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        RoundingMode::Ceiling,
+        "-9.99e8",
+        "-0x3.b9E+7#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        RoundingMode::Down,
+        "-9.99e8",
+        "-0x3.b9E+7#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        RoundingMode::Up,
+        "-1.0e9",
+        "-0x3.baE+7#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1000000000",
+        10,
+        RoundingMode::Nearest,
+        "-1.0e9",
+        "-0x3.baE+7#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn sub_rational_prec_round_fail() {
+    assert_panic!(Float::ONE.sub_rational_prec_round(Rational::ONE, 0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.sub_rational_prec_round_val_ref(
+        &Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::ONE.sub_rational_prec_round_ref_val(
+        Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::ONE.sub_rational_prec_round_ref_ref(
+        &Rational::ONE,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_rational_prec_round_assign(Rational::ONE, 0, RoundingMode::Floor)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_rational_prec_round_assign_ref(&Rational::ONE, 0, RoundingMode::Floor)
+    });
+
+    assert_panic!(Float::ONE.sub_rational_prec_round(
+        Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.sub_rational_prec_round_val_ref(
+        &Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.sub_rational_prec_round_ref_val(
+        Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::ONE.sub_rational_prec_round_ref_ref(
+        &Rational::from_unsigneds(1u8, 3),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_rational_prec_round_assign(Rational::from_unsigneds(1u8, 3), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_rational_prec_round_assign_ref(
+            &Rational::from_unsigneds(1u8, 3),
+            1,
+            RoundingMode::Exact,
+        )
+    });
+}
+
+#[test]
+fn sub_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_2().test_properties(|(x, y, prec, rm)| {
+        let (diff, o) = x.clone().sub_prec_round(y.clone(), prec, rm);
+        assert!(diff.is_valid());
+
+        let (diff_alt, o_alt) = x.clone().sub_prec_round_val_ref(&y, prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_prec_round_ref_ref(&y, prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_prec_round_assign(y.clone(), prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_prec_round_assign_ref(&y, prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        if diff.is_finite() && x.is_finite() && y.is_finite() {
+            if diff.is_normal() {
+                assert_eq!(diff.get_prec(), Some(prec));
+            }
+            let r_diff = Rational::exact_from(&x) - Rational::exact_from(&y);
+            assert_eq!(diff.partial_cmp(&r_diff), Some(o));
+            if o == Ordering::Less {
+                let mut next = diff.clone();
+                next.increment();
+                assert!(next > r_diff);
+            } else if o == Ordering::Greater {
+                let mut next = diff.clone();
+                next.decrement();
+                assert!(next < r_diff);
+            }
+            match (r_diff >= 0u32, rm) {
+                (_, RoundingMode::Floor)
+                | (true, RoundingMode::Down)
+                | (false, RoundingMode::Up) => {
+                    assert_ne!(o, Ordering::Greater)
+                }
+                (_, RoundingMode::Ceiling)
+                | (true, RoundingMode::Up)
+                | (false, RoundingMode::Down) => {
+                    assert_ne!(o, Ordering::Less)
+                }
+                (_, RoundingMode::Exact) => assert_eq!(o, Ordering::Equal),
+                _ => {}
+            }
+        }
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_diff, rug_o) = rug_sub_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_diff)),
+                ComparableFloatRef(&diff)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (diff_alt, o_alt) = x.add_prec_round_ref_val(-&y, prec, rm);
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (mut diff_alt, mut o_alt) = y.sub_prec_round_ref_ref(&x, prec, -rm);
+        diff_alt.neg_assign();
+        o_alt = o_alt.reverse();
+        assert_eq!(
+            ComparableFloat(diff_alt.abs_negative_zero()),
+            ComparableFloat(diff.abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt, o);
+
+        let (mut diff_alt, mut o_alt) = (-&x).sub_prec_round(-&y, prec, -rm);
+        diff_alt.neg_assign();
+        o_alt = o_alt.reverse();
+        if diff_alt.is_zero() && x.is_finite() && y.is_finite() {
+            // The sign of an exact zero difference depends on the rounding mode, so it is not symmetric
+            // under negation.
+            assert!(diff.is_zero());
+        } else {
+            assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+            assert_eq!(o_alt, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.sub_prec_round_ref_ref(&y, prec, rm);
+                assert_eq!(
+                    ComparableFloat(s.abs_negative_zero()),
+                    ComparableFloat(diff.abs_negative_zero_ref())
+                );
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.sub_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+        }
+    });
+
+    float_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (diff, o) = x.sub_prec_round_ref_val(Float::NAN, prec, rm);
+        assert!(diff.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (diff, o) = Float::NAN.sub_prec_round_val_ref(&x, prec, rm);
+        assert!(diff.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        if !x.is_nan() {
+            if x != Float::INFINITY {
+                assert_eq!(
+                    x.sub_prec_round_ref_val(Float::INFINITY, prec, rm),
+                    (Float::NEGATIVE_INFINITY, Ordering::Equal)
+                );
+                assert_eq!(
+                    Float::INFINITY.sub_prec_round_val_ref(&x, prec, rm),
+                    (Float::INFINITY, Ordering::Equal)
+                );
+            }
+            if x != Float::NEGATIVE_INFINITY {
+                assert_eq!(
+                    x.sub_prec_round_ref_val(Float::NEGATIVE_INFINITY, prec, rm),
+                    (Float::INFINITY, Ordering::Equal)
+                );
+                assert_eq!(
+                    Float::NEGATIVE_INFINITY.sub_prec_round_val_ref(&x, prec, rm),
+                    (Float::NEGATIVE_INFINITY, Ordering::Equal)
+                );
+            }
+        }
+        if rm != RoundingMode::Floor || !x.is_positive_zero() {
+            let (diff, o) = x.sub_prec_round_ref_val(Float::ZERO, prec, rm);
+            let mut diff_alt = x.clone();
+            let o_alt = diff_alt.set_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(diff), ComparableFloat(diff_alt));
+            assert_eq!(o, o_alt);
+        }
+        if !x.is_negative_zero() {
+            let (diff, o) = x.sub_prec_round_ref_val(Float::NEGATIVE_ZERO, prec, rm);
+            let mut diff_alt = x.clone();
+            let o_alt = diff_alt.set_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(diff), ComparableFloat(diff_alt));
+            assert_eq!(o, o_alt);
+        }
+    });
+}
+
+#[test]
+fn sub_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (diff, o) = x.clone().sub_prec(y.clone(), prec);
+        assert!(diff.is_valid());
+
+        let (diff_alt, o_alt) = x.clone().sub_prec_val_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_prec_ref_val(y.clone(), prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_prec_ref_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        if diff.is_finite() && x.is_finite() && y.is_finite() {
+            if diff.is_normal() {
+                assert_eq!(diff.get_prec(), Some(prec));
+            }
+            let r_diff = Rational::exact_from(&x) - Rational::exact_from(&y);
+            assert_eq!(diff.partial_cmp(&r_diff), Some(o));
+            if o == Ordering::Less {
+                let mut next = diff.clone();
+                next.increment();
+                assert!(next > r_diff);
+            } else if o == Ordering::Greater {
+                let mut next = diff.clone();
+                next.decrement();
+                assert!(next < r_diff);
+            }
+        }
+
+        let (rug_diff, rug_o) = rug_sub_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_diff)),
+            ComparableFloatRef(&diff)
+        );
+        assert_eq!(rug_o, o);
+
+        let (diff_alt, o_alt) = x.add_prec_ref_val(-&y, prec);
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (mut diff_alt, mut o_alt) = y.sub_prec_ref_ref(&x, prec);
+        diff_alt.neg_assign();
+        o_alt = o_alt.reverse();
+        assert_eq!(
+            ComparableFloat(diff_alt.abs_negative_zero()),
+            ComparableFloat(diff.abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt, o);
+
+        if (x != 0u32 && y != 0u32) || (x.is_sign_positive() && y.is_sign_positive()) {
+            let (mut diff_alt, mut o_alt) = (-&x).sub_prec(-&y, prec);
+            diff_alt.neg_assign();
+            o_alt = o_alt.reverse();
+            assert_eq!(
+                ComparableFloat(diff_alt.abs_negative_zero()),
+                ComparableFloat(diff.abs_negative_zero_ref())
+            );
+            assert_eq!(o_alt, o);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sub_round_properties_helper(x: Float, y: Float) {
+    for rm in exhaustive_rounding_modes() {
+        let prec = max(x.significant_bits(), y.significant_bits());
+        let (_, floor_o) = x.sub_prec_round_ref_ref(&y, prec, RoundingMode::Floor);
+        if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+            assert_panic!(x.sub_round_ref_ref(&y, rm));
+            continue;
+        }
+
+        let (diff, o) = x.clone().sub_round(y.clone(), rm);
+        assert!(diff.is_valid());
+
+        let (diff_alt, o_alt) = x.clone().sub_round_val_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_round_ref_val(y.clone(), rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_round_ref_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_diff, rug_o) = rug_sub_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_diff)),
+                ComparableFloatRef(&diff)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (diff_alt, o_alt) = x.add_round_ref_val(-&y, rm);
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (mut diff_alt, mut o_alt) = y.sub_round_ref_ref(&x, -rm);
+        diff_alt.neg_assign();
+        o_alt = o_alt.reverse();
+        assert_eq!(
+            ComparableFloat(diff_alt.abs_negative_zero()),
+            ComparableFloat(diff.abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt, o);
+    }
+}
+
+#[test]
+fn sub_round_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        sub_round_properties_helper(x, y);
+    });
+
+    float_pair_gen_var_1().test_properties(|(x, y)| {
+        sub_round_properties_helper(x, y);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sub_properties_helper(x: Float, y: Float) {
+    let diff = x.clone() - y.clone();
+    assert!(diff.is_valid());
+
+    let diff_alt = x.clone() - &y;
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+
+    let diff_alt = &x - y.clone();
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+
+    let diff_alt = &x - &y;
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+
+    let mut x_alt = x.clone();
+    x_alt -= y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+
+    let mut x_alt = x.clone();
+    x_alt -= &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+
+    let (diff_alt, _) = x.sub_round_ref_ref(&y, RoundingMode::Nearest);
+    assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+
+    let (rug_diff, _) = rug_sub_prec_round(
+        &rug::Float::exact_from(&x),
+        &rug::Float::exact_from(&y),
+        max(x.significant_bits(), y.significant_bits()),
+        rug::float::Round::Nearest,
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_diff)),
+        ComparableFloatRef(&diff)
+    );
+
+    let diff_alt = &x + -&y;
+    assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+
+    let diff_alt = -(&y - &x);
+    assert_eq!(
+        ComparableFloat(diff_alt.abs_negative_zero()),
+        ComparableFloat(diff.abs_negative_zero_ref())
+    );
+}
+
+#[test]
+fn sub_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        sub_properties_helper(x, y);
+    });
+
+    float_pair_gen_var_1().test_properties(|(x, y)| {
+        sub_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        assert!((&x - Float::NAN).is_nan());
+        assert!((Float::NAN - &x).is_nan());
+        if !x.is_nan() {
+            if x != Float::INFINITY {
+                assert_eq!(&x - Float::INFINITY, Float::NEGATIVE_INFINITY);
+                assert_eq!(Float::INFINITY - &x, Float::INFINITY);
+            }
+            if x != Float::NEGATIVE_INFINITY {
+                assert_eq!(&x - Float::NEGATIVE_INFINITY, Float::INFINITY);
+                assert_eq!(Float::NEGATIVE_INFINITY - &x, Float::NEGATIVE_INFINITY);
+            }
+        }
+        assert_eq!(
+            ComparableFloatRef(&(&x - Float::ZERO)),
+            ComparableFloatRef(&x)
+        );
+        if !x.is_negative_zero() {
+            assert_eq!(
+                ComparableFloatRef(&(&x - Float::NEGATIVE_ZERO)),
+                ComparableFloatRef(&x)
+            );
+        }
+        if !x.is_positive_zero() {
+            assert_eq!(ComparableFloat(Float::ZERO - &x), ComparableFloat(-&x));
+        }
+        if x.is_finite() {
+            assert_eq!(&x - &x, 0u32);
+            assert!((&x - &x).is_sign_positive());
+        }
+    });
+
+    float_triple_gen().test_properties(|(x, y, z)| {
+        if x.is_finite() && y.is_finite() && z.is_finite() {
+            // When every step is exact, (x - y) - z == x - (y + z).
+            let prec = 4096;
+            let (xy, o) = x.sub_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+            let (yz, o_alt) = y.add_prec_round_ref_ref(&z, prec, RoundingMode::Nearest);
+            if o == Ordering::Equal && o_alt == Ordering::Equal {
+                let (xy_z, o) = xy.sub_prec_round(z, prec, RoundingMode::Nearest);
+                let (x_yz, o_alt) = x.sub_prec_round(yz, prec, RoundingMode::Nearest);
+                if o == Ordering::Equal && o_alt == Ordering::Equal {
+                    assert_eq!(xy_z, x_yz);
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn sub_rational_prec_round_properties() {
+    float_rational_unsigned_rounding_mode_quadruple_gen_var_2().test_properties(
+        |(x, y, prec, rm)| {
+            let (diff, o) = x.clone().sub_rational_prec_round(y.clone(), prec, rm);
+            assert!(diff.is_valid());
+
+            let (diff_alt, o_alt) = x.clone().sub_rational_prec_round_val_ref(&y, prec, rm);
+            assert!(diff_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+            assert_eq!(o_alt, o);
+
+            let (diff_alt, o_alt) = x.sub_rational_prec_round_ref_val(y.clone(), prec, rm);
+            assert!(diff_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+            assert_eq!(o_alt, o);
+
+            let (diff_alt, o_alt) = x.sub_rational_prec_round_ref_ref(&y, prec, rm);
+            assert!(diff_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.sub_rational_prec_round_assign(y.clone(), prec, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.sub_rational_prec_round_assign_ref(&y, prec, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+            assert_eq!(o_alt, o);
+
+            if diff.is_finite() && x.is_finite() {
+                if diff.is_normal() {
+                    assert_eq!(diff.get_prec(), Some(prec));
+                }
+                let r_diff = Rational::exact_from(&x) - &y;
+                assert_eq!(diff.partial_cmp(&r_diff), Some(o));
+                if o == Ordering::Less {
+                    let mut next = diff.clone();
+                    next.increment();
+                    assert!(next > r_diff);
+                } else if o == Ordering::Greater {
+                    let mut next = diff.clone();
+                    next.decrement();
+                    assert!(next < r_diff);
+                }
+                match (r_diff >= 0u32, rm) {
+                    (_, RoundingMode::Floor)
+                    | (true, RoundingMode::Down)
+                    | (false, RoundingMode::Up) => {
+                        assert_ne!(o, Ordering::Greater)
+                    }
+                    (_, RoundingMode::Ceiling)
+                    | (true, RoundingMode::Up)
+                    | (false, RoundingMode::Down) => {
+                        assert_ne!(o, Ordering::Less)
+                    }
+                    (_, RoundingMode::Exact) => assert_eq!(o, Ordering::Equal),
+                    _ => {}
+                }
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_diff, rug_o) = rug_sub_rational_prec_round(
+                    &rug::Float::exact_from(&x),
+                    &rug::Rational::exact_from(&y),
+                    prec,
+                    rug_rm,
+                );
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_diff)),
+                    ComparableFloatRef(&diff)
+                );
+                assert_eq!(rug_o, o);
+            }
+
+            if x.is_finite() && y != 0u32 {
+                let (diff_alt, o_alt) = x.sub_prec_round_ref_val(
+                    Float::from_rational_prec_round_ref(&y, 4096, RoundingMode::Floor).0,
+                    prec,
+                    rm,
+                );
+                if Rational::exact_from(
+                    &Float::from_rational_prec_round_ref(&y, 4096, RoundingMode::Floor).0,
+                ) == y
+                {
+                    assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+                    assert_eq!(o_alt, o);
+                }
+            }
+
+            let (diff_alt, o_alt) = x.add_rational_prec_round_ref_val(-&y, prec, rm);
+            assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+            assert_eq!(o_alt, o);
+
+            if o == Ordering::Equal {
+                for rm in exhaustive_rounding_modes() {
+                    let (s, oo) = x.sub_rational_prec_round_ref_ref(&y, prec, rm);
+                    assert_eq!(
+                        ComparableFloat(s.abs_negative_zero()),
+                        ComparableFloat(diff.abs_negative_zero_ref())
+                    );
+                    assert_eq!(oo, Ordering::Equal);
+                }
+            } else {
+                assert_panic!(x.sub_rational_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+            }
+        },
+    );
+}
+
+#[test]
+fn sub_rational_prec_properties() {
+    float_rational_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (diff, o) = x.clone().sub_rational_prec(y.clone(), prec);
+        assert!(diff.is_valid());
+
+        let (diff_alt, o_alt) = x.clone().sub_rational_prec_val_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_rational_prec_ref_val(y.clone(), prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_rational_prec_ref_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_rational_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_rational_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_rational_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        if diff.is_finite() && x.is_finite() {
+            let r_diff = Rational::exact_from(&x) - &y;
+            assert_eq!(diff.partial_cmp(&r_diff), Some(o));
+        }
+
+        let (rug_diff, rug_o) = rug_sub_rational_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Rational::exact_from(&y),
+            prec,
+            rug::float::Round::Nearest,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_diff)),
+            ComparableFloatRef(&diff)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sub_rational_round_properties_helper(x: Float, y: Rational) {
+    for rm in exhaustive_rounding_modes() {
+        let prec = x.significant_bits();
+        let (_, floor_o) = x.sub_rational_prec_round_ref_ref(&y, prec, RoundingMode::Floor);
+        if rm == RoundingMode::Exact && floor_o != Ordering::Equal {
+            assert_panic!(x.sub_rational_round_ref_ref(&y, rm));
+            continue;
+        }
+
+        let (diff, o) = x.clone().sub_rational_round(y.clone(), rm);
+        assert!(diff.is_valid());
+
+        let (diff_alt, o_alt) = x.clone().sub_rational_round_val_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_rational_round_ref_val(y.clone(), rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_rational_round_ref_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_rational_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_rational_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        let (diff_alt, o_alt) = x.sub_rational_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_diff, rug_o) = rug_sub_rational_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Rational::exact_from(&y),
+                prec,
+                rug_rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_diff)),
+                ComparableFloatRef(&diff)
+            );
+            assert_eq!(rug_o, o);
+        }
+    }
+}
+
+#[test]
+fn sub_rational_round_properties() {
+    float_rational_pair_gen().test_properties(|(x, y)| {
+        sub_rational_round_properties_helper(x, y);
+    });
+
+    float_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        sub_rational_round_properties_helper(x, y);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sub_rational_properties_helper(x: Float, y: Rational) {
+    let diff = x.clone() - y.clone();
+    assert!(diff.is_valid());
+
+    let diff_alt = x.clone() - &y;
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+
+    let diff_alt = &x - y.clone();
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+
+    let diff_alt = &x - &y;
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+
+    let diff_alt = -(y.clone() - x.clone());
+    assert!(diff_alt.is_valid());
+    assert_eq!(
+        ComparableFloat(diff_alt.abs_negative_zero()),
+        ComparableFloat(diff.abs_negative_zero_ref())
+    );
+
+    let diff_alt = -(y.clone() - &x);
+    assert!(diff_alt.is_valid());
+    assert_eq!(
+        ComparableFloat(diff_alt.abs_negative_zero()),
+        ComparableFloat(diff.abs_negative_zero_ref())
+    );
+
+    let diff_alt = -(&y - x.clone());
+    assert!(diff_alt.is_valid());
+    assert_eq!(
+        ComparableFloat(diff_alt.abs_negative_zero()),
+        ComparableFloat(diff.abs_negative_zero_ref())
+    );
+
+    let diff_alt = -(&y - &x);
+    assert!(diff_alt.is_valid());
+    assert_eq!(
+        ComparableFloat(diff_alt.abs_negative_zero()),
+        ComparableFloat(diff.abs_negative_zero_ref())
+    );
+
+    let mut x_alt = x.clone();
+    x_alt -= y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+
+    let mut x_alt = x.clone();
+    x_alt -= &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&diff));
+
+    let (diff_alt, _) = x.sub_rational_round_ref_ref(&y, RoundingMode::Nearest);
+    assert_eq!(ComparableFloatRef(&diff_alt), ComparableFloatRef(&diff));
+
+    let (rug_diff, _) = rug_sub_rational_prec_round(
+        &rug::Float::exact_from(&x),
+        &rug::Rational::exact_from(&y),
+        x.significant_bits(),
+        rug::float::Round::Nearest,
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_diff)),
+        ComparableFloatRef(&diff)
+    );
+}
+
+#[test]
+fn sub_rational_properties() {
+    float_rational_pair_gen().test_properties(|(x, y)| {
+        sub_rational_properties_helper(x, y);
+    });
+
+    float_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        sub_rational_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        assert_eq!(
+            ComparableFloatRef(&(&x - Rational::ZERO)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(ComparableFloat(Rational::ZERO - &x), ComparableFloat(-&x));
+        if x.is_finite() {
+            assert_eq!(&x - Rational::exact_from(&x), 0u32);
+        }
+    });
+}
@@ -0,0 +1,364 @@
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_unsigned_rounding_mode_quadruple_gen_var_1,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_3, float_triple_gen,
+    float_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_sum_prec_round(
+    xs: &[rug::Float],
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), rug::Float::sum(xs.iter()), rm)
+}
+
+fn rug_dot_prec_round(
+    xs: &[rug::Float],
+    ys: &[rug::Float],
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(
+        u32::exact_from(prec),
+        rug::Float::dot(xs.iter().zip(ys.iter())),
+        rm,
+    )
+}
+
+#[test]
+fn test_sum_prec_round() {
+    let test = |xs: &[&str], prec, rm, out: &str, out_hex: &str, o_out| {
+        let xs: Vec<Float> = xs.iter().map(|s| parse_hex_string(s)).collect();
+
+        let (s, o) = Float::sum_prec_round(&xs, prec, rm);
+        assert!(s.is_valid());
+        assert_eq!(o, o_out);
+        assert_eq!(s.to_string(), out);
+        assert_eq!(to_hex_string(&s), out_hex);
+
+        if rm == RoundingMode::Nearest {
+            let (s_alt, o_alt) = Float::sum_prec(&xs, prec);
+            assert!(s_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&s), ComparableFloatRef(&s_alt));
+            assert_eq!(o_alt, o_out);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let rug_xs: Vec<rug::Float> = xs.iter().map(rug::Float::exact_from).collect();
+            let (rug_s, rug_o) = rug_sum_prec_round(&rug_xs, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_s)),
+                ComparableFloatRef(&s)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        &[],
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        &[],
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        &["NaN", "0x1.0#1"],
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        &["Infinity", "0x1.0#1", "-Infinity"],
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        &["Infinity", "-0x1.0#1"],
+        10,
+        RoundingMode::Nearest,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        &["-0x0.0", "-0x0.0"],
+        10,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        &["0x0.0", "-0x0.0"],
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        &["0x0.0", "-0x0.0"],
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        &["0x1.0#1", "-0x1.0#1", "-0x0.0"],
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        &["0x1.0#1", "-0x1.0#1", "-0x0.0"],
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    // - total cancellation of the largest terms
+    test(
+        &["0x1.0E+100#1", "0x1.0#1", "-0x1.0E+100#1"],
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    // - the result is determined by a term far below the others
+    test(
+        &["0x1.0#1", "0x1.0E-1000#1", "-0x1.0#1", "0x1.0E-100000#1"],
+        10,
+        RoundingMode::Nearest,
+        "7.59e-1205",
+        "0x1.000E-1000#10",
+        Ordering::Less,
+    );
+    test(
+        &["0x1.0#1", "0x1.0E-1000#1", "-0x1.0#1", "0x1.0E-100000#1"],
+        10,
+        RoundingMode::Ceiling,
+        "7.6e-1205",
+        "0x1.008E-1000#10",
+        Ordering::Greater,
+    );
+    test(
+        &["0x1.0#1", "-0x1.0E-1000#1", "-0x1.0E-10000#1"],
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        &["0x1.0#1", "-0x1.0E-1000#1", "-0x1.0E-10000#1"],
+        10,
+        RoundingMode::Floor,
+        "0.999",
+        "0x0.ffc#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn sum_prec_round_fail() {
+    assert_panic!(Float::sum_prec_round(&[Float::ONE], 0, RoundingMode::Floor));
+    assert_panic!(Float::sum_prec_round(
+        &[Float::ONE, Float::from(0.5), Float::from(0.25)],
+        2,
+        RoundingMode::Exact
+    ));
+}
+
+#[test]
+fn test_dot_prec_round() {
+    let test = |xs: &[&str], ys: &[&str], prec, rm, out: &str, out_hex: &str, o_out| {
+        let xs: Vec<Float> = xs.iter().map(|s| parse_hex_string(s)).collect();
+        let ys: Vec<Float> = ys.iter().map(|s| parse_hex_string(s)).collect();
+
+        let (d, o) = Float::dot_prec_round(&xs, &ys, prec, rm);
+        assert!(d.is_valid());
+        assert_eq!(o, o_out);
+        assert_eq!(d.to_string(), out);
+        assert_eq!(to_hex_string(&d), out_hex);
+
+        if rm == RoundingMode::Nearest {
+            let (d_alt, o_alt) = Float::dot_prec(&xs, &ys, prec);
+            assert!(d_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&d), ComparableFloatRef(&d_alt));
+            assert_eq!(o_alt, o_out);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let rug_xs: Vec<rug::Float> = xs.iter().map(rug::Float::exact_from).collect();
+            let rug_ys: Vec<rug::Float> = ys.iter().map(rug::Float::exact_from).collect();
+            let (rug_d, rug_o) = rug_dot_prec_round(&rug_xs, &rug_ys, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_d)),
+                ComparableFloatRef(&d)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        &[],
+        &[],
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        &["Infinity"],
+        &["0x0.0"],
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        &["0x0.0", "-0x0.0"],
+        &["-0x1.0#1", "0x1.0#1"],
+        10,
+        RoundingMode::Nearest,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        &["0x1.0#1", "0x0.1999999999999a#53"],
+        &["0x1.0#1", "0x0.1999999999999a#53"],
+        10,
+        RoundingMode::Floor,
+        "1.01",
+        "0x1.028#10",
+        Ordering::Less,
+    );
+    test(
+        &["0x1.0#1", "0x0.1999999999999a#53"],
+        &["0x1.0#1", "0x0.1999999999999a#53"],
+        10,
+        RoundingMode::Ceiling,
+        "1.012",
+        "0x1.030#10",
+        Ordering::Greater,
+    );
+    // - (1 + 2^-30)(1 - 2^-30) - 1 = -2^-60
+    test(
+        &["0x1.00000004#31", "-0x1.0#1"],
+        &["0x0.fffffffc#30", "0x1.0#1"],
+        10,
+        RoundingMode::Nearest,
+        "-8.67e-19",
+        "-0x1.000E-15#10",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn dot_prec_round_fail() {
+    assert_panic!(Float::dot_prec_round(
+        &[Float::ONE],
+        &[Float::ONE, Float::ONE],
+        10,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::dot_prec_round(
+        &[Float::ONE],
+        &[Float::ONE],
+        0,
+        RoundingMode::Floor
+    ));
+}
+
+#[test]
+fn sum_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (s, o) = Float::sum_prec_round([&x], prec, rm);
+        assert!(s.is_valid());
+        let mut s_alt = x.clone();
+        let o_alt = s_alt.set_prec_round(prec, rm);
+        assert_eq!(ComparableFloatRef(&s), ComparableFloatRef(&s_alt));
+        assert_eq!(o, o_alt);
+    });
+
+    float_float_unsigned_rounding_mode_quadruple_gen_var_1().test_properties(|(x, y, prec, rm)| {
+        let (s, o) = Float::sum_prec_round([&x, &y], prec, rm);
+        assert!(s.is_valid());
+        let (s_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(ComparableFloatRef(&s), ComparableFloatRef(&s_alt));
+        assert_eq!(o, o_alt);
+    });
+
+    float_triple_gen().test_properties(|(x, y, z)| {
+        let prec = 20;
+        let (s, o) = Float::sum_prec([&x, &y, &z], prec);
+        assert!(s.is_valid());
+        let (s_alt, o_alt) = Float::sum_prec([&z, &x, &y], prec);
+        assert_eq!(ComparableFloatRef(&s), ComparableFloatRef(&s_alt));
+        assert_eq!(o, o_alt);
+        if s.is_finite() && x.is_finite() && y.is_finite() && z.is_finite() {
+            let exact =
+                Rational::exact_from(&x) + Rational::exact_from(&y) + Rational::exact_from(&z);
+            assert_eq!(s.partial_cmp(&exact), Some(o));
+            if s != 0u32 {
+                assert_eq!(
+                    ComparableFloat(s),
+                    ComparableFloat(Float::from_rational_prec(exact, prec).0)
+                );
+            }
+        }
+    });
+}
+
+#[test]
+fn dot_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_3().test_properties(|(x, y, prec, rm)| {
+        let (d, o) = Float::dot_prec_round([&x], [&y], prec, rm);
+        assert!(d.is_valid());
+        let (d_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(ComparableFloatRef(&d), ComparableFloatRef(&d_alt));
+        assert_eq!(o, o_alt);
+    });
+
+    float_triple_gen().test_properties(|(x, y, z)| {
+        let (d, o) = Float::dot_prec([&x, &Float::ONE], [&y, &z], 20);
+        let (d_alt, o_alt) = x.mul_add_prec_ref_ref_ref(&y, &z, 20);
+        assert_eq!(ComparableFloatRef(&d), ComparableFloatRef(&d_alt));
+        assert_eq!(o, o_alt);
+
+        let (d, o) = Float::dot_prec([&x, &y, &z], [&Float::ONE, &Float::ONE, &Float::ONE], 20);
+        let (d_alt, o_alt) = Float::sum_prec([&x, &y, &z], 20);
+        assert_eq!(ComparableFloatRef(&d), ComparableFloatRef(&d_alt));
+        assert_eq!(o, o_alt);
+    });
+}
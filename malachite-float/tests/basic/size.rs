@@ -4,6 +4,6 @@ use std::mem::size_of;
 #[test]
 fn test_size() {
     if size_of::<usize>() == 8 {
-        assert_eq!(size_of::<Float>(), 48);
+        assert_eq!(size_of::<Float>(), 64);
     }
 }
@@ -0,0 +1,260 @@
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::{ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_catalan_prec_round(prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), rug::float::Constant::Catalan, rm)
+}
+
+#[test]
+fn test_catalan_prec() {
+    let test = |prec, out: &str, out_hex: &str, o_out| {
+        let (catalan, o) = Float::catalan_prec(prec);
+        assert!(catalan.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(catalan.to_string(), out);
+        assert_eq!(to_hex_string(&catalan), out_hex);
+
+        let (rug_catalan, rug_o) = rug_catalan_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_catalan)),
+            ComparableFloatRef(&catalan)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(1, "1.0", "0x1.0#1", Ordering::Greater);
+    test(2, "1.0", "0x1.0#2", Ordering::Greater);
+    test(3, "0.9", "0x0.e#3", Ordering::Less);
+    test(4, "0.94", "0x0.f#4", Ordering::Greater);
+    test(5, "0.91", "0x0.e8#5", Ordering::Less);
+    test(6, "0.92", "0x0.ec#6", Ordering::Greater);
+    test(7, "0.914", "0x0.ea#7", Ordering::Less);
+    test(8, "0.914", "0x0.ea#8", Ordering::Less);
+    test(9, "0.916", "0x0.ea8#9", Ordering::Greater);
+    test(10, "0.916", "0x0.ea8#10", Ordering::Greater);
+    test(
+        100,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn catalan_prec_fail() {
+    Float::catalan_prec(0);
+}
+
+#[test]
+fn test_catalan_prec_round() {
+    let test = |prec, rm, out: &str, out_hex: &str, o_out| {
+        let (catalan, o) = Float::catalan_prec_round(prec, rm);
+        assert!(catalan.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(catalan.to_string(), out);
+        assert_eq!(to_hex_string(&catalan), out_hex);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_catalan, rug_o) = rug_catalan_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_catalan)),
+                ComparableFloatRef(&catalan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(1, RoundingMode::Floor, "0.5", "0x0.8#1", Ordering::Less);
+    test(
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(1, RoundingMode::Down, "0.5", "0x0.8#1", Ordering::Less);
+    test(1, RoundingMode::Up, "1.0", "0x1.0#1", Ordering::Greater);
+    test(
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+
+    test(2, RoundingMode::Floor, "0.8", "0x0.c#2", Ordering::Less);
+    test(
+        2,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Down, "0.8", "0x0.c#2", Ordering::Less);
+    test(2, RoundingMode::Up, "1.0", "0x1.0#2", Ordering::Greater);
+    test(
+        2,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Greater,
+    );
+
+    test(3, RoundingMode::Floor, "0.9", "0x0.e#3", Ordering::Less);
+    test(
+        3,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Down, "0.9", "0x0.e#3", Ordering::Less);
+    test(3, RoundingMode::Up, "1.0", "0x1.0#3", Ordering::Greater);
+    test(3, RoundingMode::Nearest, "0.9", "0x0.e#3", Ordering::Less);
+
+    test(
+        10,
+        RoundingMode::Floor,
+        "0.915",
+        "0x0.ea4#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Ceiling,
+        "0.916",
+        "0x0.ea8#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Down,
+        "0.915",
+        "0x0.ea4#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Up,
+        "0.916",
+        "0x0.ea8#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Nearest,
+        "0.916",
+        "0x0.ea8#10",
+        Ordering::Greater,
+    );
+
+    test(
+        100,
+        RoundingMode::Floor,
+        "0.915965594177219015054603514932",
+        "0x0.ea7cb89f409ae845215822e37#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Ceiling,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Down,
+        "0.915965594177219015054603514932",
+        "0x0.ea7cb89f409ae845215822e37#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Up,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Nearest,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn catalan_prec_round_fail() {
+    assert_panic!(Float::catalan_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::catalan_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::catalan_prec_round(1000, RoundingMode::Exact));
+}
+
+#[test]
+fn catalan_prec_round_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                assert_panic!(Float::catalan_prec_round(prec, rm));
+                continue;
+            }
+            let (catalan, o) = Float::catalan_prec_round(prec, rm);
+            assert!(catalan.is_valid());
+            assert_eq!(catalan.get_prec(), Some(prec));
+            assert!(catalan >= 0.5 && catalan <= 1u32);
+            match rm {
+                RoundingMode::Floor | RoundingMode::Down => assert_eq!(o, Ordering::Less),
+                RoundingMode::Ceiling | RoundingMode::Up => assert_eq!(o, Ordering::Greater),
+                _ => assert_ne!(o, Ordering::Equal),
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_catalan, rug_o) = rug_catalan_prec_round(prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_catalan)),
+                    ComparableFloatRef(&catalan)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+
+        // The constant lies strictly between two adjacent `Float`s.
+        let (mut floor, _) = Float::catalan_prec_round(prec, RoundingMode::Floor);
+        let (ceiling, _) = Float::catalan_prec_round(prec, RoundingMode::Ceiling);
+        floor.increment();
+        assert_eq!(floor, ceiling);
+    });
+}
+
+#[test]
+fn catalan_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (catalan, o) = Float::catalan_prec(prec);
+        assert!(catalan.is_valid());
+        assert_eq!(catalan.get_prec(), Some(prec));
+        assert_ne!(o, Ordering::Equal);
+
+        let (catalan_alt, o_alt) = Float::catalan_prec_round(prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&catalan_alt),
+            ComparableFloatRef(&catalan)
+        );
+        assert_eq!(o_alt, o);
+
+        let (rug_catalan, rug_o) = rug_catalan_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_catalan)),
+            ComparableFloatRef(&catalan)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
@@ -0,0 +1,249 @@
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::{ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_e_prec_round(prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(
+        u32::exact_from(prec),
+        rug::Float::with_val(1, 1).exp_ref(),
+        rm,
+    )
+}
+
+#[test]
+fn test_e_prec() {
+    let test = |prec, out: &str, out_hex: &str, o_out| {
+        let (e, o) = Float::e_prec(prec);
+        assert!(e.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(e.to_string(), out);
+        assert_eq!(to_hex_string(&e), out_hex);
+
+        let (rug_e, rug_o) = rug_e_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_e)),
+            ComparableFloatRef(&e)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(1, "2.0", "0x2.0#1", Ordering::Less);
+    test(2, "3.0", "0x3.0#2", Ordering::Greater);
+    test(3, "2.5", "0x2.8#3", Ordering::Less);
+    test(4, "2.8", "0x2.c#4", Ordering::Greater);
+    test(5, "2.8", "0x2.c#5", Ordering::Greater);
+    test(6, "2.7", "0x2.b#6", Ordering::Less);
+    test(7, "2.72", "0x2.b8#7", Ordering::Greater);
+    test(8, "2.72", "0x2.b8#8", Ordering::Greater);
+    test(9, "2.72", "0x2.b8#9", Ordering::Greater);
+    test(10, "2.719", "0x2.b8#10", Ordering::Greater);
+    test(
+        100,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn e_prec_fail() {
+    Float::e_prec(0);
+}
+
+#[test]
+fn test_e_prec_round() {
+    let test = |prec, rm, out: &str, out_hex: &str, o_out| {
+        let (e, o) = Float::e_prec_round(prec, rm);
+        assert!(e.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(e.to_string(), out);
+        assert_eq!(to_hex_string(&e), out_hex);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_e, rug_o) = rug_e_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_e)),
+                ComparableFloatRef(&e)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(1, RoundingMode::Floor, "2.0", "0x2.0#1", Ordering::Less);
+    test(
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(1, RoundingMode::Down, "2.0", "0x2.0#1", Ordering::Less);
+    test(1, RoundingMode::Up, "4.0", "0x4.0#1", Ordering::Greater);
+    test(1, RoundingMode::Nearest, "2.0", "0x2.0#1", Ordering::Less);
+
+    test(2, RoundingMode::Floor, "2.0", "0x2.0#2", Ordering::Less);
+    test(
+        2,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Down, "2.0", "0x2.0#2", Ordering::Less);
+    test(2, RoundingMode::Up, "3.0", "0x3.0#2", Ordering::Greater);
+    test(
+        2,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Greater,
+    );
+
+    test(3, RoundingMode::Floor, "2.5", "0x2.8#3", Ordering::Less);
+    test(
+        3,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0#3",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Down, "2.5", "0x2.8#3", Ordering::Less);
+    test(3, RoundingMode::Up, "3.0", "0x3.0#3", Ordering::Greater);
+    test(3, RoundingMode::Nearest, "2.5", "0x2.8#3", Ordering::Less);
+
+    test(
+        10,
+        RoundingMode::Floor,
+        "2.715",
+        "0x2.b7#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Ceiling,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(10, RoundingMode::Down, "2.715", "0x2.b7#10", Ordering::Less);
+    test(
+        10,
+        RoundingMode::Up,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Nearest,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+
+    test(
+        100,
+        RoundingMode::Floor,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Ceiling,
+        "2.718281828459045235360287471354",
+        "0x2.b7e151628aed2a6abf715880c#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Down,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Up,
+        "2.718281828459045235360287471354",
+        "0x2.b7e151628aed2a6abf715880c#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Nearest,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn e_prec_round_fail() {
+    assert_panic!(Float::e_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::e_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::e_prec_round(1000, RoundingMode::Exact));
+}
+
+#[test]
+fn e_prec_round_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                assert_panic!(Float::e_prec_round(prec, rm));
+                continue;
+            }
+            let (e, o) = Float::e_prec_round(prec, rm);
+            assert!(e.is_valid());
+            assert_eq!(e.get_prec(), Some(prec));
+            assert!((2u32..=4u32).contains(&e));
+            match rm {
+                RoundingMode::Floor | RoundingMode::Down => assert_eq!(o, Ordering::Less),
+                RoundingMode::Ceiling | RoundingMode::Up => assert_eq!(o, Ordering::Greater),
+                _ => assert_ne!(o, Ordering::Equal),
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_e, rug_o) = rug_e_prec_round(prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_e)),
+                    ComparableFloatRef(&e)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+
+        // The constant lies strictly between two adjacent `Float`s.
+        let (mut floor, _) = Float::e_prec_round(prec, RoundingMode::Floor);
+        let (ceiling, _) = Float::e_prec_round(prec, RoundingMode::Ceiling);
+        floor.increment();
+        assert_eq!(floor, ceiling);
+    });
+}
+
+#[test]
+fn e_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (e, o) = Float::e_prec(prec);
+        assert!(e.is_valid());
+        assert_eq!(e.get_prec(), Some(prec));
+        assert_ne!(o, Ordering::Equal);
+
+        let (e_alt, o_alt) = Float::e_prec_round(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&e_alt), ComparableFloatRef(&e));
+        assert_eq!(o_alt, o);
+
+        let (rug_e, rug_o) = rug_e_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_e)),
+            ComparableFloatRef(&e)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
@@ -0,0 +1,251 @@
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::{ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_euler_gamma_prec_round(prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), rug::float::Constant::Euler, rm)
+}
+
+#[test]
+fn test_euler_gamma_prec() {
+    let test = |prec, out: &str, out_hex: &str, o_out| {
+        let (gamma, o) = Float::euler_gamma_prec(prec);
+        assert!(gamma.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+
+        let (rug_gamma, rug_o) = rug_euler_gamma_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(1, "0.5", "0x0.8#1", Ordering::Less);
+    test(2, "0.5", "0x0.8#2", Ordering::Less);
+    test(3, "0.6", "0x0.a#3", Ordering::Greater);
+    test(4, "0.56", "0x0.9#4", Ordering::Less);
+    test(5, "0.56", "0x0.90#5", Ordering::Less);
+    test(6, "0.58", "0x0.94#6", Ordering::Greater);
+    test(7, "0.58", "0x0.94#7", Ordering::Greater);
+    test(8, "0.578", "0x0.94#8", Ordering::Greater);
+    test(9, "0.578", "0x0.940#9", Ordering::Greater);
+    test(10, "0.577", "0x0.93c#10", Ordering::Less);
+    test(
+        100,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn euler_gamma_prec_fail() {
+    Float::euler_gamma_prec(0);
+}
+
+#[test]
+fn test_euler_gamma_prec_round() {
+    let test = |prec, rm, out: &str, out_hex: &str, o_out| {
+        let (gamma, o) = Float::euler_gamma_prec_round(prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_gamma, rug_o) = rug_euler_gamma_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(1, RoundingMode::Floor, "0.5", "0x0.8#1", Ordering::Less);
+    test(
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(1, RoundingMode::Down, "0.5", "0x0.8#1", Ordering::Less);
+    test(1, RoundingMode::Up, "1.0", "0x1.0#1", Ordering::Greater);
+    test(1, RoundingMode::Nearest, "0.5", "0x0.8#1", Ordering::Less);
+
+    test(2, RoundingMode::Floor, "0.5", "0x0.8#2", Ordering::Less);
+    test(
+        2,
+        RoundingMode::Ceiling,
+        "0.8",
+        "0x0.c#2",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Down, "0.5", "0x0.8#2", Ordering::Less);
+    test(2, RoundingMode::Up, "0.8", "0x0.c#2", Ordering::Greater);
+    test(2, RoundingMode::Nearest, "0.5", "0x0.8#2", Ordering::Less);
+
+    test(3, RoundingMode::Floor, "0.5", "0x0.8#3", Ordering::Less);
+    test(
+        3,
+        RoundingMode::Ceiling,
+        "0.6",
+        "0x0.a#3",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Down, "0.5", "0x0.8#3", Ordering::Less);
+    test(3, RoundingMode::Up, "0.6", "0x0.a#3", Ordering::Greater);
+    test(
+        3,
+        RoundingMode::Nearest,
+        "0.6",
+        "0x0.a#3",
+        Ordering::Greater,
+    );
+
+    test(
+        10,
+        RoundingMode::Floor,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Ceiling,
+        "0.578",
+        "0x0.940#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Down,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Up,
+        "0.578",
+        "0x0.940#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Nearest,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+
+    test(
+        100,
+        RoundingMode::Floor,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Ceiling,
+        "0.577215664901532860606512090083",
+        "0x0.93c467e37db0c7a4d1be3f811#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Down,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Up,
+        "0.577215664901532860606512090083",
+        "0x0.93c467e37db0c7a4d1be3f811#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Nearest,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn euler_gamma_prec_round_fail() {
+    assert_panic!(Float::euler_gamma_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::euler_gamma_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::euler_gamma_prec_round(1000, RoundingMode::Exact));
+}
+
+#[test]
+fn euler_gamma_prec_round_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                assert_panic!(Float::euler_gamma_prec_round(prec, rm));
+                continue;
+            }
+            let (gamma, o) = Float::euler_gamma_prec_round(prec, rm);
+            assert!(gamma.is_valid());
+            assert_eq!(gamma.get_prec(), Some(prec));
+            assert!(gamma >= 0.5 && gamma <= 1u32);
+            match rm {
+                RoundingMode::Floor | RoundingMode::Down => assert_eq!(o, Ordering::Less),
+                RoundingMode::Ceiling | RoundingMode::Up => assert_eq!(o, Ordering::Greater),
+                _ => assert_ne!(o, Ordering::Equal),
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_gamma, rug_o) = rug_euler_gamma_prec_round(prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_gamma)),
+                    ComparableFloatRef(&gamma)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+
+        // The constant lies strictly between two adjacent `Float`s.
+        let (mut floor, _) = Float::euler_gamma_prec_round(prec, RoundingMode::Floor);
+        let (ceiling, _) = Float::euler_gamma_prec_round(prec, RoundingMode::Ceiling);
+        floor.increment();
+        assert_eq!(floor, ceiling);
+    });
+}
+
+#[test]
+fn euler_gamma_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (gamma, o) = Float::euler_gamma_prec(prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.get_prec(), Some(prec));
+        assert_ne!(o, Ordering::Equal);
+
+        let (gamma_alt, o_alt) = Float::euler_gamma_prec_round(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+        assert_eq!(o_alt, o);
+
+        let (rug_gamma, rug_o) = rug_euler_gamma_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
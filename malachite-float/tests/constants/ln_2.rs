@@ -0,0 +1,257 @@
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::{ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_ln_2_prec_round(prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), rug::float::Constant::Log2, rm)
+}
+
+#[test]
+fn test_ln_2_prec() {
+    let test = |prec, out: &str, out_hex: &str, o_out| {
+        let (ln_2, o) = Float::ln_2_prec(prec);
+        assert!(ln_2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(ln_2.to_string(), out);
+        assert_eq!(to_hex_string(&ln_2), out_hex);
+
+        let (rug_ln_2, rug_o) = rug_ln_2_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln_2)),
+            ComparableFloatRef(&ln_2)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(1, "0.5", "0x0.8#1", Ordering::Less);
+    test(2, "0.8", "0x0.c#2", Ordering::Greater);
+    test(3, "0.8", "0x0.c#3", Ordering::Greater);
+    test(4, "0.7", "0x0.b#4", Ordering::Less);
+    test(5, "0.69", "0x0.b0#5", Ordering::Less);
+    test(6, "0.69", "0x0.b0#6", Ordering::Less);
+    test(7, "0.695", "0x0.b2#7", Ordering::Greater);
+    test(8, "0.691", "0x0.b1#8", Ordering::Less);
+    test(9, "0.693", "0x0.b18#9", Ordering::Greater);
+    test(10, "0.693", "0x0.b18#10", Ordering::Greater);
+    test(
+        100,
+        "0.693147180559945309417232121458",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn ln_2_prec_fail() {
+    Float::ln_2_prec(0);
+}
+
+#[test]
+fn test_ln_2_prec_round() {
+    let test = |prec, rm, out: &str, out_hex: &str, o_out| {
+        let (ln_2, o) = Float::ln_2_prec_round(prec, rm);
+        assert!(ln_2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(ln_2.to_string(), out);
+        assert_eq!(to_hex_string(&ln_2), out_hex);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln_2, rug_o) = rug_ln_2_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln_2)),
+                ComparableFloatRef(&ln_2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(1, RoundingMode::Floor, "0.5", "0x0.8#1", Ordering::Less);
+    test(
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(1, RoundingMode::Down, "0.5", "0x0.8#1", Ordering::Less);
+    test(1, RoundingMode::Up, "1.0", "0x1.0#1", Ordering::Greater);
+    test(1, RoundingMode::Nearest, "0.5", "0x0.8#1", Ordering::Less);
+
+    test(2, RoundingMode::Floor, "0.5", "0x0.8#2", Ordering::Less);
+    test(
+        2,
+        RoundingMode::Ceiling,
+        "0.8",
+        "0x0.c#2",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Down, "0.5", "0x0.8#2", Ordering::Less);
+    test(2, RoundingMode::Up, "0.8", "0x0.c#2", Ordering::Greater);
+    test(
+        2,
+        RoundingMode::Nearest,
+        "0.8",
+        "0x0.c#2",
+        Ordering::Greater,
+    );
+
+    test(3, RoundingMode::Floor, "0.6", "0x0.a#3", Ordering::Less);
+    test(
+        3,
+        RoundingMode::Ceiling,
+        "0.8",
+        "0x0.c#3",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Down, "0.6", "0x0.a#3", Ordering::Less);
+    test(3, RoundingMode::Up, "0.8", "0x0.c#3", Ordering::Greater);
+    test(
+        3,
+        RoundingMode::Nearest,
+        "0.8",
+        "0x0.c#3",
+        Ordering::Greater,
+    );
+
+    test(
+        10,
+        RoundingMode::Floor,
+        "0.692",
+        "0x0.b14#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Ceiling,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Down,
+        "0.692",
+        "0x0.b14#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Up,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Nearest,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Greater,
+    );
+
+    test(
+        100,
+        RoundingMode::Floor,
+        "0.693147180559945309417232121458",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Ceiling,
+        "0.693147180559945309417232121459",
+        "0x0.b17217f7d1cf79abc9e3b3981#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Down,
+        "0.693147180559945309417232121458",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Up,
+        "0.693147180559945309417232121459",
+        "0x0.b17217f7d1cf79abc9e3b3981#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Nearest,
+        "0.693147180559945309417232121458",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn ln_2_prec_round_fail() {
+    assert_panic!(Float::ln_2_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ln_2_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ln_2_prec_round(1000, RoundingMode::Exact));
+}
+
+#[test]
+fn ln_2_prec_round_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                assert_panic!(Float::ln_2_prec_round(prec, rm));
+                continue;
+            }
+            let (ln_2, o) = Float::ln_2_prec_round(prec, rm);
+            assert!(ln_2.is_valid());
+            assert_eq!(ln_2.get_prec(), Some(prec));
+            assert!(ln_2 >= 0.5 && ln_2 <= 1u32);
+            match rm {
+                RoundingMode::Floor | RoundingMode::Down => assert_eq!(o, Ordering::Less),
+                RoundingMode::Ceiling | RoundingMode::Up => assert_eq!(o, Ordering::Greater),
+                _ => assert_ne!(o, Ordering::Equal),
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_ln_2, rug_o) = rug_ln_2_prec_round(prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_ln_2)),
+                    ComparableFloatRef(&ln_2)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+
+        // The constant lies strictly between two adjacent `Float`s.
+        let (mut floor, _) = Float::ln_2_prec_round(prec, RoundingMode::Floor);
+        let (ceiling, _) = Float::ln_2_prec_round(prec, RoundingMode::Ceiling);
+        floor.increment();
+        assert_eq!(floor, ceiling);
+    });
+}
+
+#[test]
+fn ln_2_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (ln_2, o) = Float::ln_2_prec(prec);
+        assert!(ln_2.is_valid());
+        assert_eq!(ln_2.get_prec(), Some(prec));
+        assert_ne!(o, Ordering::Equal);
+
+        let (ln_2_alt, o_alt) = Float::ln_2_prec_round(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&ln_2_alt), ComparableFloatRef(&ln_2));
+        assert_eq!(o_alt, o);
+
+        let (rug_ln_2, rug_o) = rug_ln_2_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln_2)),
+            ComparableFloatRef(&ln_2)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
@@ -0,0 +1,245 @@
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::{ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_pi_prec_round(prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), rug::float::Constant::Pi, rm)
+}
+
+#[test]
+fn test_pi_prec() {
+    let test = |prec, out: &str, out_hex: &str, o_out| {
+        let (pi, o) = Float::pi_prec(prec);
+        assert!(pi.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(pi.to_string(), out);
+        assert_eq!(to_hex_string(&pi), out_hex);
+
+        let (rug_pi, rug_o) = rug_pi_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_pi)),
+            ComparableFloatRef(&pi)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(1, "4.0", "0x4.0#1", Ordering::Greater);
+    test(2, "3.0", "0x3.0#2", Ordering::Less);
+    test(3, "3.0", "0x3.0#3", Ordering::Less);
+    test(4, "3.2", "0x3.4#4", Ordering::Greater);
+    test(5, "3.1", "0x3.2#5", Ordering::Less);
+    test(6, "3.12", "0x3.2#6", Ordering::Less);
+    test(7, "3.16", "0x3.28#7", Ordering::Greater);
+    test(8, "3.14", "0x3.24#8", Ordering::Less);
+    test(9, "3.14", "0x3.24#9", Ordering::Less);
+    test(10, "3.141", "0x3.24#10", Ordering::Less);
+    test(
+        100,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn pi_prec_fail() {
+    Float::pi_prec(0);
+}
+
+#[test]
+fn test_pi_prec_round() {
+    let test = |prec, rm, out: &str, out_hex: &str, o_out| {
+        let (pi, o) = Float::pi_prec_round(prec, rm);
+        assert!(pi.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(pi.to_string(), out);
+        assert_eq!(to_hex_string(&pi), out_hex);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_pi, rug_o) = rug_pi_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_pi)),
+                ComparableFloatRef(&pi)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(1, RoundingMode::Floor, "2.0", "0x2.0#1", Ordering::Less);
+    test(
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(1, RoundingMode::Down, "2.0", "0x2.0#1", Ordering::Less);
+    test(1, RoundingMode::Up, "4.0", "0x4.0#1", Ordering::Greater);
+    test(
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+
+    test(2, RoundingMode::Floor, "3.0", "0x3.0#2", Ordering::Less);
+    test(
+        2,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#2",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Down, "3.0", "0x3.0#2", Ordering::Less);
+    test(2, RoundingMode::Up, "4.0", "0x4.0#2", Ordering::Greater);
+    test(2, RoundingMode::Nearest, "3.0", "0x3.0#2", Ordering::Less);
+
+    test(3, RoundingMode::Floor, "3.0", "0x3.0#3", Ordering::Less);
+    test(
+        3,
+        RoundingMode::Ceiling,
+        "3.5",
+        "0x3.8#3",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Down, "3.0", "0x3.0#3", Ordering::Less);
+    test(3, RoundingMode::Up, "3.5", "0x3.8#3", Ordering::Greater);
+    test(3, RoundingMode::Nearest, "3.0", "0x3.0#3", Ordering::Less);
+
+    test(
+        10,
+        RoundingMode::Floor,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(10, RoundingMode::Down, "3.141", "0x3.24#10", Ordering::Less);
+    test(
+        10,
+        RoundingMode::Up,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Nearest,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+
+    test(
+        100,
+        RoundingMode::Floor,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Ceiling,
+        "3.141592653589793238462643383282",
+        "0x3.243f6a8885a308d313198a2e4#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Down,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Up,
+        "3.141592653589793238462643383282",
+        "0x3.243f6a8885a308d313198a2e4#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Nearest,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn pi_prec_round_fail() {
+    assert_panic!(Float::pi_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::pi_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::pi_prec_round(1000, RoundingMode::Exact));
+}
+
+#[test]
+fn pi_prec_round_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                assert_panic!(Float::pi_prec_round(prec, rm));
+                continue;
+            }
+            let (pi, o) = Float::pi_prec_round(prec, rm);
+            assert!(pi.is_valid());
+            assert_eq!(pi.get_prec(), Some(prec));
+            assert!((2u32..=4u32).contains(&pi));
+            match rm {
+                RoundingMode::Floor | RoundingMode::Down => assert_eq!(o, Ordering::Less),
+                RoundingMode::Ceiling | RoundingMode::Up => assert_eq!(o, Ordering::Greater),
+                _ => assert_ne!(o, Ordering::Equal),
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_pi, rug_o) = rug_pi_prec_round(prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_pi)),
+                    ComparableFloatRef(&pi)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+
+        // The constant lies strictly between two adjacent `Float`s.
+        let (mut floor, _) = Float::pi_prec_round(prec, RoundingMode::Floor);
+        let (ceiling, _) = Float::pi_prec_round(prec, RoundingMode::Ceiling);
+        floor.increment();
+        assert_eq!(floor, ceiling);
+    });
+}
+
+#[test]
+fn pi_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (pi, o) = Float::pi_prec(prec);
+        assert!(pi.is_valid());
+        assert_eq!(pi.get_prec(), Some(prec));
+        assert_ne!(o, Ordering::Equal);
+
+        let (pi_alt, o_alt) = Float::pi_prec_round(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&pi_alt), ComparableFloatRef(&pi));
+        assert_eq!(o_alt, o);
+
+        let (rug_pi, rug_o) = rug_pi_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_pi)),
+            ComparableFloatRef(&pi)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
@@ -0,0 +1,261 @@
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::{ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+fn rug_sqrt_2_prec_round(prec: u64, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(
+        u32::exact_from(prec),
+        rug::Float::with_val(1, 2).sqrt_ref(),
+        rm,
+    )
+}
+
+#[test]
+fn test_sqrt_2_prec() {
+    let test = |prec, out: &str, out_hex: &str, o_out| {
+        let (sqrt_2, o) = Float::sqrt_2_prec(prec);
+        assert!(sqrt_2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sqrt_2.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt_2), out_hex);
+
+        let (rug_sqrt_2, rug_o) = rug_sqrt_2_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt_2)),
+            ComparableFloatRef(&sqrt_2)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(1, "1.0", "0x1.0#1", Ordering::Less);
+    test(2, "1.5", "0x1.8#2", Ordering::Greater);
+    test(3, "1.5", "0x1.8#3", Ordering::Greater);
+    test(4, "1.4", "0x1.6#4", Ordering::Less);
+    test(5, "1.44", "0x1.7#5", Ordering::Greater);
+    test(6, "1.41", "0x1.68#6", Ordering::Less);
+    test(7, "1.42", "0x1.6c#7", Ordering::Greater);
+    test(8, "1.414", "0x1.6a#8", Ordering::Less);
+    test(9, "1.414", "0x1.6a#9", Ordering::Less);
+    test(10, "1.414", "0x1.6a0#10", Ordering::Less);
+    test(
+        100,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn sqrt_2_prec_fail() {
+    Float::sqrt_2_prec(0);
+}
+
+#[test]
+fn test_sqrt_2_prec_round() {
+    let test = |prec, rm, out: &str, out_hex: &str, o_out| {
+        let (sqrt_2, o) = Float::sqrt_2_prec_round(prec, rm);
+        assert!(sqrt_2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sqrt_2.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt_2), out_hex);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sqrt_2, rug_o) = rug_sqrt_2_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sqrt_2)),
+                ComparableFloatRef(&sqrt_2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(1, RoundingMode::Floor, "1.0", "0x1.0#1", Ordering::Less);
+    test(
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(1, RoundingMode::Down, "1.0", "0x1.0#1", Ordering::Less);
+    test(1, RoundingMode::Up, "2.0", "0x2.0#1", Ordering::Greater);
+    test(1, RoundingMode::Nearest, "1.0", "0x1.0#1", Ordering::Less);
+
+    test(2, RoundingMode::Floor, "1.0", "0x1.0#2", Ordering::Less);
+    test(
+        2,
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Down, "1.0", "0x1.0#2", Ordering::Less);
+    test(2, RoundingMode::Up, "1.5", "0x1.8#2", Ordering::Greater);
+    test(
+        2,
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Greater,
+    );
+
+    test(3, RoundingMode::Floor, "1.2", "0x1.4#3", Ordering::Less);
+    test(
+        3,
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.8#3",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Down, "1.2", "0x1.4#3", Ordering::Less);
+    test(3, RoundingMode::Up, "1.5", "0x1.8#3", Ordering::Greater);
+    test(
+        3,
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.8#3",
+        Ordering::Greater,
+    );
+
+    test(
+        10,
+        RoundingMode::Floor,
+        "1.414",
+        "0x1.6a0#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Ceiling,
+        "1.416",
+        "0x1.6a8#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Down,
+        "1.414",
+        "0x1.6a0#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Up,
+        "1.416",
+        "0x1.6a8#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Nearest,
+        "1.414",
+        "0x1.6a0#10",
+        Ordering::Less,
+    );
+
+    test(
+        100,
+        RoundingMode::Floor,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Ceiling,
+        "1.414213562373095048801688724211",
+        "0x1.6a09e667f3bcc908b2fb13670#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Down,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Up,
+        "1.414213562373095048801688724211",
+        "0x1.6a09e667f3bcc908b2fb13670#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Nearest,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn sqrt_2_prec_round_fail() {
+    assert_panic!(Float::sqrt_2_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::sqrt_2_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::sqrt_2_prec_round(1000, RoundingMode::Exact));
+}
+
+#[test]
+fn sqrt_2_prec_round_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                assert_panic!(Float::sqrt_2_prec_round(prec, rm));
+                continue;
+            }
+            let (sqrt_2, o) = Float::sqrt_2_prec_round(prec, rm);
+            assert!(sqrt_2.is_valid());
+            assert_eq!(sqrt_2.get_prec(), Some(prec));
+            assert!((1u32..=2u32).contains(&sqrt_2));
+            match rm {
+                RoundingMode::Floor | RoundingMode::Down => assert_eq!(o, Ordering::Less),
+                RoundingMode::Ceiling | RoundingMode::Up => assert_eq!(o, Ordering::Greater),
+                _ => assert_ne!(o, Ordering::Equal),
+            }
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_sqrt_2, rug_o) = rug_sqrt_2_prec_round(prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_sqrt_2)),
+                    ComparableFloatRef(&sqrt_2)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+
+        // The constant lies strictly between two adjacent `Float`s.
+        let (mut floor, _) = Float::sqrt_2_prec_round(prec, RoundingMode::Floor);
+        let (ceiling, _) = Float::sqrt_2_prec_round(prec, RoundingMode::Ceiling);
+        floor.increment();
+        assert_eq!(floor, ceiling);
+    });
+}
+
+#[test]
+fn sqrt_2_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (sqrt_2, o) = Float::sqrt_2_prec(prec);
+        assert!(sqrt_2.is_valid());
+        assert_eq!(sqrt_2.get_prec(), Some(prec));
+        assert_ne!(o, Ordering::Equal);
+
+        let (sqrt_2_alt, o_alt) = Float::sqrt_2_prec_round(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&sqrt_2_alt), ComparableFloatRef(&sqrt_2));
+        assert_eq!(o_alt, o);
+
+        let (rug_sqrt_2, rug_o) = rug_sqrt_2_prec_round(prec, rug::float::Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt_2)),
+            ComparableFloatRef(&sqrt_2)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
@@ -0,0 +1,92 @@
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::test_util::generators::string_gen;
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_serde() {
+    let test = |x: Float, out| {
+        assert_eq!(serde_json::to_string(&x).unwrap(), out);
+        let y = serde_json::from_str::<Float>(out).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(ComparableFloat(y), ComparableFloat(x));
+    };
+    test(Float::NAN, "\"NaN\"");
+    test(Float::INFINITY, "\"Infinity\"");
+    test(Float::NEGATIVE_INFINITY, "\"-Infinity\"");
+    test(Float::ZERO, "\"0x0.0\"");
+    test(Float::NEGATIVE_ZERO, "\"-0x0.0\"");
+    test(Float::from(1.5), "\"0x1.8000000000000#53\"");
+    test(Float::from(-123.0), "\"-0x7b.000000000000#53\"");
+    test(
+        Float::from_rational_prec(Rational::from_str("1/3").unwrap(), 10).0,
+        "\"0x0.556#10\"",
+    );
+
+    // Strings must be in the hexadecimal format, with a precision
+    assert!(serde_json::from_str::<Float>("\"abc\"").is_err());
+    assert!(serde_json::from_str::<Float>("\"0x1.8\"").is_err());
+}
+
+#[test]
+fn test_serde_binary() {
+    let test = |x: Float, out: &[u8]| {
+        assert_eq!(bincode::serialize(&x).unwrap(), out);
+        let y = bincode::deserialize::<Float>(out).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(ComparableFloat(y), ComparableFloat(x));
+    };
+    test(Float::NAN, &[0, 0, 0, 0]);
+    test(Float::INFINITY, &[1, 0, 0, 0, 1]);
+    test(Float::NEGATIVE_INFINITY, &[1, 0, 0, 0, 0]);
+    test(Float::ZERO, &[2, 0, 0, 0, 1]);
+    test(Float::NEGATIVE_ZERO, &[2, 0, 0, 0, 0]);
+    test(
+        Float::from(1.5),
+        &[
+            3, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 24,
+        ],
+    );
+    test(
+        Float::from_rational_prec(Rational::from_str("-1/3").unwrap(), 10).0,
+        &[
+            3, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255, 10, 0, 0, 0, 0, 0, 0, 0, 2, 0,
+            0, 0, 0, 0, 0, 0, 171, 2,
+        ],
+    );
+
+    // The significand must have exactly `precision` significant bits
+    assert!(bincode::deserialize::<Float>(&[
+        3, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255, 11, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0,
+        0, 0, 0, 0, 171, 2,
+    ])
+    .is_err());
+    assert!(bincode::deserialize::<Float>(&[
+        3, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0,
+    ])
+    .is_err());
+}
+
+#[test]
+fn serde_properties() {
+    float_gen().test_properties(|x| {
+        let s = serde_json::to_string(&x).unwrap();
+        assert_eq!(s, format!("\"{:#x}\"", ComparableFloatRef(&x)));
+        let y = serde_json::from_str::<Float>(&s).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(ComparableFloatRef(&y), ComparableFloatRef(&x));
+
+        let bytes = bincode::serialize(&x).unwrap();
+        let y = bincode::deserialize::<Float>(&bytes).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(ComparableFloat(y), ComparableFloat(x));
+    });
+
+    string_gen().test_properties(|s| {
+        let _x: Result<Float, _> = serde_json::from_str(&s);
+    });
+}
@@ -0,0 +1,669 @@
+use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::traits::{ExactFrom, FromSciString};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::{
+    string_from_sci_string_options_pair_gen_var_2, string_from_sci_string_options_pair_gen_var_3,
+    string_gen_var_14, string_gen_var_15,
+};
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_from_sci_string_prec_round() {
+    let test = |s, base, prec, rm, out: &str, out_hex: &str, out_o| {
+        let mut options = FromSciStringOptions::default();
+        options.set_base(base);
+        let (x, o) = Float::from_sci_string_prec_round(s, options, prec, rm).unwrap();
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, out_o);
+
+        if rm == RoundingMode::Nearest {
+            let (x_alt, o_alt) = Float::from_sci_string_prec(s, options, prec).unwrap();
+            assert_eq!(ComparableFloat(x_alt), ComparableFloat(x.clone()));
+            assert_eq!(o_alt, o);
+        }
+
+        if base == 10 {
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_x, rug_o) = rug::Float::with_val_round(
+                    u32::exact_from(prec),
+                    rug::Float::parse(s).unwrap(),
+                    rm,
+                );
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_x)),
+                    ComparableFloatRef(&x)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    };
+    test(
+        "0",
+        10,
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0",
+        10,
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.000e5",
+        10,
+        1,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        10,
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        10,
+        10,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        10,
+        10,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1",
+        10,
+        1,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "123",
+        10,
+        10,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "+123.000",
+        10,
+        7,
+        RoundingMode::Exact,
+        "123.0",
+        "0x7b.0#7",
+        Ordering::Equal,
+    );
+
+    test(
+        "3.14159",
+        10,
+        10,
+        RoundingMode::Floor,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "3.14159",
+        10,
+        10,
+        RoundingMode::Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.14159",
+        10,
+        10,
+        RoundingMode::Down,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "3.14159",
+        10,
+        10,
+        RoundingMode::Up,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.14159",
+        10,
+        10,
+        RoundingMode::Nearest,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+
+    test(
+        "-3.14159",
+        10,
+        10,
+        RoundingMode::Floor,
+        "-3.145",
+        "-0x3.25#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.14159",
+        10,
+        10,
+        RoundingMode::Ceiling,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.14159",
+        10,
+        10,
+        RoundingMode::Down,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.14159",
+        10,
+        10,
+        RoundingMode::Up,
+        "-3.145",
+        "-0x3.25#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.14159",
+        10,
+        10,
+        RoundingMode::Nearest,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+
+    test(
+        "3.14159e-20",
+        10,
+        20,
+        RoundingMode::Nearest,
+        "3.141591e-20",
+        "0x9.45b8E-17#20",
+        Ordering::Greater,
+    );
+    test(
+        "0.1",
+        10,
+        1,
+        RoundingMode::Floor,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.1",
+        10,
+        1,
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.1",
+        10,
+        1,
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.1",
+        10,
+        100,
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.1999999999999999999999999a#100",
+        Ordering::Greater,
+    );
+    test(
+        "1e100",
+        10,
+        10,
+        RoundingMode::Nearest,
+        "9.996e99",
+        "0x1.248E+83#10",
+        Ordering::Less,
+    );
+    test(
+        "1.5e-300",
+        10,
+        53,
+        RoundingMode::Nearest,
+        "1.5000000000000001e-300",
+        "0x1.01297d23ab683E-249#53",
+        Ordering::Greater,
+    );
+    test(
+        "-1.25",
+        10,
+        3,
+        RoundingMode::Exact,
+        "-1.2",
+        "-0x1.4#3",
+        Ordering::Equal,
+    );
+    // Ties are broken by rounding to even
+    test(
+        "12.5",
+        10,
+        3,
+        RoundingMode::Nearest,
+        "12.0",
+        "0xc.0#3",
+        Ordering::Less,
+    );
+    test(
+        "13.5",
+        10,
+        3,
+        RoundingMode::Nearest,
+        "14.0",
+        "0xe.0#3",
+        Ordering::Greater,
+    );
+
+    test(
+        "1.1e3",
+        2,
+        2,
+        RoundingMode::Exact,
+        "1.0e1",
+        "0xc.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "-1.1e3",
+        2,
+        10,
+        RoundingMode::Exact,
+        "-12.0",
+        "-0xc.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "ff.8",
+        16,
+        8,
+        RoundingMode::Down,
+        "255.0",
+        "0xff.0#8",
+        Ordering::Less,
+    );
+    test(
+        "ff.8",
+        16,
+        8,
+        RoundingMode::Up,
+        "256.0",
+        "0x100.0#8",
+        Ordering::Greater,
+    );
+    test(
+        "ff.8",
+        16,
+        8,
+        RoundingMode::Nearest,
+        "256.0",
+        "0x100.0#8",
+        Ordering::Greater,
+    );
+    test(
+        "FF.8",
+        16,
+        9,
+        RoundingMode::Exact,
+        "255.5",
+        "0xff.8#9",
+        Ordering::Equal,
+    );
+    test(
+        "1e+1",
+        16,
+        10,
+        RoundingMode::Nearest,
+        "16.0",
+        "0x10.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1e1",
+        16,
+        10,
+        RoundingMode::Nearest,
+        "481.0",
+        "0x1e1.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "z.z",
+        36,
+        10,
+        RoundingMode::Nearest,
+        "36.0",
+        "0x24.0#10",
+        Ordering::Greater,
+    );
+
+    let test_none = |s, base, prec, rm| {
+        let mut options = FromSciStringOptions::default();
+        options.set_base(base);
+        assert!(Float::from_sci_string_prec_round(s, options, prec, rm).is_none());
+    };
+    test_none("", 10, 10, RoundingMode::Floor);
+    test_none("+", 10, 10, RoundingMode::Floor);
+    test_none("-", 10, 10, RoundingMode::Floor);
+    test_none("10e", 10, 10, RoundingMode::Floor);
+    test_none("++1", 10, 10, RoundingMode::Floor);
+    test_none("--1", 10, 10, RoundingMode::Floor);
+    test_none("1.0.0", 10, 10, RoundingMode::Floor);
+    test_none("1e0.1", 10, 10, RoundingMode::Floor);
+    test_none("0.000a", 10, 10, RoundingMode::Floor);
+    test_none("nan", 10, 10, RoundingMode::Floor);
+    test_none("-NaN", 10, 10, RoundingMode::Floor);
+    test_none("inf", 10, 10, RoundingMode::Floor);
+    test_none("12", 2, 10, RoundingMode::Floor);
+    test_none("1/2", 10, 10, RoundingMode::Floor);
+}
+
+#[test]
+fn from_sci_string_prec_round_fail() {
+    let options = FromSciStringOptions::default();
+    assert_panic!(Float::from_sci_string_prec_round(
+        "1",
+        options,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::from_sci_string_prec_round(
+        "0.1",
+        options,
+        10,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::from_sci_string_prec_round(
+        "3.14159",
+        options,
+        1000,
+        RoundingMode::Exact
+    ));
+}
+
+#[test]
+fn from_sci_string_prec_fail() {
+    assert_panic!(Float::from_sci_string_prec(
+        "1",
+        FromSciStringOptions::default(),
+        0
+    ));
+}
+
+#[test]
+fn test_from_sci_string() {
+    let test = |s, out: Option<(&str, &str)>| {
+        let x = Float::from_sci_string(s);
+        if let Some(x) = &x {
+            assert!(x.is_valid());
+        }
+        assert_eq!(
+            x.as_ref().map(|x| (x.to_string(), to_hex_string(x))),
+            out.map(|(out, out_hex)| (out.to_string(), out_hex.to_string()))
+        );
+
+        let x_alt = Float::from_sci_string_with_options(s, FromSciStringOptions::default());
+        assert_eq!(x_alt.map(ComparableFloat), x.clone().map(ComparableFloat));
+
+        let x_alt = Float::from_str(s).ok();
+        assert_eq!(x_alt.map(ComparableFloat), x.map(ComparableFloat));
+    };
+    test("0", Some(("0.0", "0x0.0")));
+    test("-0.000", Some(("-0.0", "-0x0.0")));
+    test("0e10", Some(("0.0", "0x0.0")));
+    test("NaN", Some(("NaN", "NaN")));
+    test("Infinity", Some(("Infinity", "Infinity")));
+    test("-Infinity", Some(("-Infinity", "-Infinity")));
+    test("1", Some(("1.0", "0x1.0#5")));
+    test("123", Some(("123.0", "0x7b.0#11")));
+    test("00123", Some(("123.0", "0x7b.0#11")));
+    test("123.000", Some(("123.0", "0x7b.0000#21")));
+    test("-123", Some(("-123.0", "-0x7b.0#11")));
+    test("3.14159", Some(("3.14159", "0x3.243f4#21")));
+    test("1.5e-20", Some(("1.5e-20", "0x4.70E-17#8")));
+    test("0.1", Some(("0.1", "0x0.1a#5")));
+    test("-0.1", Some(("-0.1", "-0x0.1a#5")));
+    test("1e100", Some(("9.8e99", "0x1.2E+83#5")));
+    test(
+        "0.3333333333333333",
+        Some(("0.3333333333333333", "0x0.55555555555553#55")),
+    );
+    test(
+        "2.718281828459045",
+        Some(("2.718281828459045", "0x2.b7e151628aed18#55")),
+    );
+
+    test("", None);
+    test("+", None);
+    test("-", None);
+    test("10e", None);
+    test("++1", None);
+    test("1.0.0", None);
+    test("1e++1", None);
+    test("0.00ae-10", None);
+    test("0e10000000000000000000000000000", None);
+    test("1/2", None);
+    test("infinity", None);
+}
+
+#[test]
+fn test_from_sci_string_with_options() {
+    let test = |s, base, rm, out: Option<(&str, &str)>| {
+        let mut options = FromSciStringOptions::default();
+        options.set_base(base);
+        options.set_rounding_mode(rm);
+        let x = Float::from_sci_string_with_options(s, options);
+        if let Some(x) = &x {
+            assert!(x.is_valid());
+        }
+        assert_eq!(
+            x.as_ref().map(|x| (x.to_string(), to_hex_string(x))),
+            out.map(|(out, out_hex)| (out.to_string(), out_hex.to_string()))
+        );
+    };
+    // For tests with the default options, see `test_from_sci_string`
+    test("1.1e3", 2, RoundingMode::Nearest, Some(("12.0", "0xc.0#4")));
+    test(
+        "-1.1e3",
+        2,
+        RoundingMode::Nearest,
+        Some(("-12.0", "-0xc.0#4")),
+    );
+    test(
+        "ff.8",
+        16,
+        RoundingMode::Nearest,
+        Some(("255.5", "0xff.80#14")),
+    );
+    test("z", 36, RoundingMode::Nearest, Some(("35.0", "0x23.0#7")));
+    test("0.1", 10, RoundingMode::Floor, Some(("0.098", "0x0.19#5")));
+    test("0.1", 10, RoundingMode::Ceiling, Some(("0.1", "0x0.1a#5")));
+    test("0.5", 10, RoundingMode::Exact, Some(("0.5", "0x0.80#5")));
+    test("0.1", 10, RoundingMode::Exact, None);
+    test("2", 2, RoundingMode::Nearest, None);
+}
+
+fn from_sci_string_prec_round_helper(s: &str, options: FromSciStringOptions) {
+    let q = Rational::from_sci_string_with_options(s, options);
+    for prec in [1, 10, 100] {
+        for rm in exhaustive_rounding_modes() {
+            if rm == RoundingMode::Exact {
+                if let Some((x, o)) =
+                    Float::from_sci_string_prec_round(s, options, prec, RoundingMode::Floor)
+                {
+                    if o == Ordering::Equal {
+                        let (x_alt, o_alt) =
+                            Float::from_sci_string_prec_round(s, options, prec, rm).unwrap();
+                        assert_eq!(ComparableFloat(x_alt), ComparableFloat(x));
+                        assert_eq!(o_alt, Ordering::Equal);
+                    }
+                }
+                continue;
+            }
+            let result = Float::from_sci_string_prec_round(s, options, prec, rm);
+            if let Some((x, o)) = result {
+                assert!(x.is_valid());
+                if !x.is_finite() {
+                    assert!(q.is_none());
+                    assert_eq!(o, Ordering::Equal);
+                    continue;
+                }
+                let q = q.as_ref().unwrap();
+                assert_eq!(x.partial_cmp(q), Some(o));
+                let (x_alt, o_alt) = Float::from_rational_prec_round_ref(q, prec, rm);
+                if *q == 0u32 {
+                    assert_eq!(x, 0u32);
+                    assert_eq!(x.is_sign_negative(), s.starts_with('-'));
+                } else {
+                    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
+                    assert_eq!(x.get_prec(), Some(prec));
+                }
+                assert_eq!(o_alt, o);
+
+                if options.get_base() == 10 {
+                    if let (Ok(rug_rm), Ok(parsed)) =
+                        (rug_round_try_from_rounding_mode(rm), rug::Float::parse(s))
+                    {
+                        let (rug_x, rug_o) =
+                            rug::Float::with_val_round(u32::exact_from(prec), parsed, rug_rm);
+                        assert_eq!(
+                            ComparableFloatRef(&Float::from(&rug_x)),
+                            ComparableFloatRef(&x)
+                        );
+                        assert_eq!(rug_o, o);
+                    }
+                }
+            } else {
+                assert!(q.is_none());
+            }
+        }
+    }
+}
+
+#[test]
+fn from_sci_string_prec_round_properties() {
+    let options = FromSciStringOptions::default();
+    string_gen_var_14().test_properties(|s| {
+        from_sci_string_prec_round_helper(&s, options);
+    });
+
+    string_gen_var_15().test_properties(|s| {
+        from_sci_string_prec_round_helper(&s, options);
+    });
+
+    string_from_sci_string_options_pair_gen_var_3().test_properties(|(s, options)| {
+        from_sci_string_prec_round_helper(&s, options);
+    });
+}
+
+fn from_sci_string_with_options_helper(s: &str, options: FromSciStringOptions) {
+    if let Some(x) = Float::from_sci_string_with_options(s, options) {
+        assert!(x.is_valid());
+        if x.is_finite() {
+            let q = Rational::from_sci_string_with_options(s, options).unwrap();
+            if x == 0u32 {
+                assert_eq!(q, 0u32);
+            } else {
+                let prec = x.get_prec().unwrap();
+                let (x_alt, o) =
+                    Float::from_rational_prec_round(q, prec, options.get_rounding_mode());
+                assert_eq!(ComparableFloat(x_alt), ComparableFloat(x));
+                if options.get_rounding_mode() == RoundingMode::Exact {
+                    assert_eq!(o, Ordering::Equal);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn from_sci_string_properties() {
+    string_gen_var_14().test_properties(|s| {
+        let x = Float::from_sci_string(&s);
+        assert_eq!(
+            Float::from_str(&s).ok().map(ComparableFloat),
+            x.clone().map(ComparableFloat)
+        );
+        from_sci_string_with_options_helper(&s, FromSciStringOptions::default());
+    });
+
+    string_gen_var_15().test_properties(|s| {
+        from_sci_string_with_options_helper(&s, FromSciStringOptions::default());
+    });
+}
+
+#[test]
+fn from_sci_string_with_options_properties() {
+    string_from_sci_string_options_pair_gen_var_2().test_properties(|(s, options)| {
+        from_sci_string_with_options_helper(&s, options);
+    });
+
+    string_from_sci_string_options_pair_gen_var_3().test_properties(|(s, options)| {
+        from_sci_string_with_options_helper(&s, options);
+    });
+}
@@ -0,0 +1,149 @@
+use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::traits::{FromSciString, FromStringBase};
+use malachite_base::test_util::generators::{
+    string_gen_var_14, string_unsigned_pair_gen_var_1, string_unsigned_pair_gen_var_2,
+};
+use malachite_float::test_util::common::to_hex_string;
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::{ComparableFloat, Float};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_from_str() {
+    let test = |s, out: &str, out_hex: &str| {
+        let x = Float::from_str(s).unwrap();
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+    };
+    test("0", "0.0", "0x0.0");
+    test("-0.0", "-0.0", "-0x0.0");
+    test("NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "-Infinity");
+    test("1", "1.0", "0x1.0#5");
+    test("123.0", "123.0", "0x7b.00#15");
+    test("-3.14159e-20", "-3.141591e-20", "-0x9.45b80E-17#21");
+    test("0.1", "0.1", "0x0.1a#5");
+    test("1e100", "9.8e99", "0x1.2E+83#5");
+
+    let test_err = |s| {
+        assert!(Float::from_str(s).is_err());
+    };
+    test_err("");
+    test_err("-");
+    test_err("1/2");
+    test_err("0x1.8#3");
+    test_err("1.0.0");
+    test_err("inf");
+}
+
+#[test]
+fn test_from_string_base() {
+    let test = |base, s, out: Option<(&str, &str)>| {
+        let x = Float::from_string_base(base, s);
+        if let Some(x) = &x {
+            assert!(x.is_valid());
+        }
+        assert_eq!(
+            x.as_ref().map(|x| (x.to_string(), to_hex_string(x))),
+            out.map(|(out, out_hex)| (out.to_string(), out_hex.to_string()))
+        );
+    };
+    test(16, "0x0.0", Some(("0.0", "0x0.0")));
+    test(16, "-0x0.0", Some(("-0.0", "-0x0.0")));
+    test(16, "NaN", Some(("NaN", "NaN")));
+    test(16, "Infinity", Some(("Infinity", "Infinity")));
+    test(16, "-Infinity", Some(("-Infinity", "-Infinity")));
+    test(16, "0x1.8#3", Some(("1.5", "0x1.8#3")));
+    test(16, "-0x1.8#3", Some(("-1.5", "-0x1.8#3")));
+    test(
+        16,
+        "0x1.8#100",
+        Some(("1.5", "0x1.8000000000000000000000000#100")),
+    );
+    test(
+        16,
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Some((
+            "3.141592653589793238462643383279",
+            "0x3.243f6a8885a308d313198a2e0#100",
+        )),
+    );
+    test(16, "0x1.0E+100#1", Some(("3.0e120", "0x1.0E+100#1")));
+    test(16, "ff.8", Some(("255.5", "0xff.80#14")));
+    test(10, "0.25", Some(("0.25", "0x0.400#8")));
+    test(
+        10,
+        "-3.14159e-20",
+        Some(("-3.141591e-20", "-0x9.45b80E-17#21")),
+    );
+    test(2, "-1.1e3", Some(("-12.0", "-0xc.0#4")));
+    test(36, "z", Some(("35.0", "0x23.0#7")));
+
+    test(16, "0x1.8", None);
+    test(16, "0x1.8#", None);
+    test(16, "0x1.8#0", None);
+    test(16, "0x1.8#1", None);
+    test(16, "0x1.8#-3", None);
+    test(16, "--0x1.8#3", None);
+    test(16, "0xg#3", None);
+    test(10, "0x1.8#3", None);
+    test(10, "ff", None);
+    test(2, "2", None);
+}
+
+#[test]
+fn from_string_base_fail() {
+    assert_panic!(Float::from_string_base(1, "0"));
+    assert_panic!(Float::from_string_base(37, "0"));
+}
+
+#[test]
+fn from_str_properties() {
+    string_gen_var_14().test_properties(|s| {
+        let x = Float::from_str(&s);
+        assert_eq!(
+            x.clone().ok().map(ComparableFloat),
+            Float::from_sci_string(&s).map(ComparableFloat)
+        );
+        assert_eq!(
+            x.ok().map(ComparableFloat),
+            Float::from_string_base(10, &s).map(ComparableFloat)
+        );
+    });
+}
+
+fn from_string_base_helper(base: u8, s: &str) {
+    let x = Float::from_string_base(base, s);
+    if let Some(x) = &x {
+        assert!(x.is_valid());
+    }
+    if base != 16 || !s.strip_prefix('-').unwrap_or(s).starts_with("0x") {
+        let mut options = FromSciStringOptions::default();
+        options.set_base(base);
+        assert_eq!(
+            x.map(ComparableFloat),
+            Float::from_sci_string_with_options(s, options).map(ComparableFloat)
+        );
+    }
+}
+
+#[test]
+fn from_string_base_properties() {
+    string_unsigned_pair_gen_var_1().test_properties(|(s, base)| {
+        from_string_base_helper(base, &s);
+    });
+
+    string_unsigned_pair_gen_var_2().test_properties(|(s, base)| {
+        from_string_base_helper(base, &s);
+    });
+
+    float_gen().test_properties(|x| {
+        assert_eq!(
+            ComparableFloat(Float::from_string_base(16, &to_hex_string(&x)).unwrap()),
+            ComparableFloat(x)
+        );
+    });
+}
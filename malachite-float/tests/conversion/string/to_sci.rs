@@ -0,0 +1,173 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::string::options::ToSciOptions;
+use malachite_base::num::conversion::traits::{ExactFrom, ToSci};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::Float;
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+pub fn test_to_sci() {
+    let test = |x: Float, out: &str| {
+        assert_eq!(x.to_sci().to_string(), out);
+        assert_eq!(
+            x.to_sci_with_options(ToSciOptions::default()).to_string(),
+            out
+        );
+    };
+    test(Float::NAN, "NaN");
+    test(Float::INFINITY, "Infinity");
+    test(Float::NEGATIVE_INFINITY, "-Infinity");
+    test(Float::ZERO, "0");
+    test(Float::NEGATIVE_ZERO, "-0");
+    test(Float::from(1.0), "1");
+    test(Float::from(-1.0), "-1");
+    test(Float::from(0.1), "0.1");
+    test(Float::from(-0.1), "-0.1");
+    test(Float::from(123.25), "123.25");
+    test(Float::from(1.0e16), "1e16");
+    test(Float::from(1.0e-7), "1e-7");
+    test(Float::from(std::f64::consts::PI), "3.141592653589793");
+    test(Float::from(u64::MAX), "1.844674407370955e19");
+    test(Float::from(f32::MAX), "3.402823466385289e38");
+    test(
+        Float::from(f64::MIN_POSITIVE_SUBNORMAL),
+        "4.940656458412465e-324",
+    );
+    test(Float::from_str("0.1").unwrap(), "0.1015625");
+    test(Float::pi_prec(100).0, "3.141592653589793");
+    test(Float::power_of_2(1000000i64), "9.900656229295898e301029");
+    test(-Float::power_of_2(1000000i64), "-9.900656229295898e301029");
+    test(Float::power_of_2(-1000000i64), "1.01003405919803e-301030");
+}
+
+#[test]
+pub fn test_to_sci_with_options() {
+    let test = |x: &Float, options: ToSciOptions, out: &str| {
+        assert!(x.fmt_sci_valid(options));
+        assert_eq!(x.to_sci_with_options(options).to_string(), out);
+    };
+    let pi = Float::from(std::f64::consts::PI);
+    let neg_pi = -&pi;
+    let test_pi = |base, rm, out_p5: &str, out_neg_s3: &str| {
+        let mut options = ToSciOptions::default();
+        options.set_base(base);
+        options.set_rounding_mode(rm);
+        options.set_precision(5);
+        test(&pi, options, out_p5);
+        options.set_scale(3);
+        test(&neg_pi, options, out_neg_s3);
+    };
+    test_pi(2, RoundingMode::Floor, "11.001", "-11.01");
+    test_pi(2, RoundingMode::Ceiling, "11.01", "-11.001");
+    test_pi(2, RoundingMode::Nearest, "11.001", "-11.001");
+    test_pi(3, RoundingMode::Floor, "10.01", "-10.011");
+    test_pi(3, RoundingMode::Ceiling, "10.011", "-10.01");
+    test_pi(3, RoundingMode::Nearest, "10.011", "-10.011");
+    test_pi(8, RoundingMode::Floor, "3.1103", "-3.111");
+    test_pi(8, RoundingMode::Ceiling, "3.1104", "-3.11");
+    test_pi(8, RoundingMode::Nearest, "3.1104", "-3.11");
+    test_pi(10, RoundingMode::Floor, "3.1415", "-3.142");
+    test_pi(10, RoundingMode::Ceiling, "3.1416", "-3.141");
+    test_pi(10, RoundingMode::Nearest, "3.1416", "-3.142");
+    test_pi(16, RoundingMode::Floor, "3.243f", "-3.244");
+    test_pi(16, RoundingMode::Ceiling, "3.244", "-3.243");
+    test_pi(16, RoundingMode::Nearest, "3.243f", "-3.244");
+    test_pi(36, RoundingMode::Floor, "3.53i5", "-3.53j");
+    test_pi(36, RoundingMode::Ceiling, "3.53i6", "-3.53i");
+    test_pi(36, RoundingMode::Nearest, "3.53i5", "-3.53i");
+
+    let mut options = ToSciOptions::default();
+    options.set_size_complete();
+    options.set_base(2);
+    test(&Float::from(123.25), options, "1111011.01");
+    options.set_base(16);
+    test(&Float::from(1.0e-3), options, "0.004189374bc6a7f");
+    options.set_base(10);
+    test(&Float::from(1.0e20), options, "100000000000000000000");
+    options.set_base(3);
+    assert!(!Float::from(0.75).fmt_sci_valid(options));
+
+    let mut options = ToSciOptions::default();
+    options.set_scale(2);
+    test(&Float::from(0.001), options, "0");
+    test(&Float::from(0.004), options, "0");
+    test(&Float::from(0.005), options, "0.01");
+    test(&Float::from(0.006), options, "0.01");
+    test(&Float::from(0.996), options, "1");
+    test(&Float::from(9.999), options, "10");
+
+    let mut options = ToSciOptions::default();
+    options.set_precision(3);
+    options.set_include_trailing_zeros(true);
+    test(&Float::ZERO, options, "0.00");
+    test(&Float::NEGATIVE_ZERO, options, "-0.00");
+    test(&Float::from(1.0), options, "1.00");
+    test(&Float::from(9.999), options, "10.0");
+    test(&Float::from(1.0e-10), options, "1.00e-10");
+    test(&Float::from(123456.0), options, "1.23e5");
+}
+
+#[test]
+pub fn to_sci_with_options_fail() {
+    let mut options = ToSciOptions::default();
+    options.set_rounding_mode(RoundingMode::Exact);
+    options.set_precision(2);
+    assert_panic!(Float::from(123).to_sci_with_options(options).to_string());
+    let mut options = ToSciOptions::default();
+    options.set_base(3);
+    options.set_size_complete();
+    assert_panic!(Float::from(0.75).to_sci_with_options(options).to_string());
+}
+
+#[test]
+fn to_sci_properties() {
+    float_gen().test_properties(|x| {
+        assert!(x.fmt_sci_valid(ToSciOptions::default()));
+        let s = x.to_sci().to_string();
+        assert_eq!(
+            x.to_sci_with_options(ToSciOptions::default()).to_string(),
+            s
+        );
+        if x.is_finite() && x != 0u32 {
+            assert_eq!(s, Rational::exact_from(&x).to_sci().to_string());
+        }
+    });
+}
+
+#[test]
+fn to_sci_with_options_properties() {
+    float_gen().test_properties(|x| {
+        if !x.is_finite() || x == 0u32 {
+            return;
+        }
+        let q = Rational::exact_from(&x);
+        for base in [2, 3, 10, 16, 36] {
+            for rm in exhaustive_rounding_modes() {
+                for size in 0..5 {
+                    let mut options = ToSciOptions::default();
+                    options.set_base(base);
+                    options.set_rounding_mode(rm);
+                    match size {
+                        0 => options.set_size_complete(),
+                        1 | 2 => options.set_precision(size * 4 - 3),
+                        _ => options.set_scale(size * 2 - 6),
+                    }
+                    let valid = x.fmt_sci_valid(options);
+                    assert_eq!(valid, q.fmt_sci_valid(options));
+                    if valid {
+                        assert_eq!(
+                            x.to_sci_with_options(options).to_string(),
+                            q.to_sci_with_options(options).to_string()
+                        );
+                    }
+                }
+            }
+        }
+    });
+}
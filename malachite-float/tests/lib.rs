@@ -43,10 +43,42 @@ extern crate malachite_base;
 
 pub mod arithmetic {
     pub mod abs;
+    pub mod acos;
+    pub mod acosh;
+    pub mod add;
+    pub mod asin;
+    pub mod asinh;
+    pub mod atan;
+    pub mod atan2;
+    pub mod atanh;
+    pub mod cos;
+    pub mod cosh;
+    pub mod div;
+    pub mod exp;
+    pub mod exp10;
+    pub mod exp2;
+    pub mod expm1;
     pub mod is_power_of_2;
+    pub mod ln;
+    pub mod ln_1p;
+    pub mod log10;
+    pub mod log2;
+    pub mod mul;
+    pub mod mul_add;
     pub mod neg;
     pub mod power_of_2;
+    pub mod reciprocal;
+    pub mod reciprocal_sqrt;
+    pub mod root;
     pub mod sign;
+    pub mod sin;
+    pub mod sin_cos;
+    pub mod sinh;
+    pub mod sqrt;
+    pub mod sub;
+    pub mod sum;
+    pub mod tan;
+    pub mod tanh;
 }
 
 pub mod basic {
@@ -79,6 +111,14 @@ pub mod comparison {
     pub mod partial_eq_primitive_int;
     pub mod partial_eq_rational;
 }
+pub mod constants {
+    pub mod catalan;
+    pub mod e;
+    pub mod euler_gamma;
+    pub mod ln_2;
+    pub mod pi;
+    pub mod sqrt_2;
+}
 pub mod conversion {
     pub mod clone;
     pub mod from_integer;
@@ -92,6 +132,13 @@ pub mod conversion {
     pub mod primitive_float_from_float;
     pub mod primitive_int_from_float;
     pub mod rational_from_float;
+    #[cfg(feature = "serde")]
+    pub mod serde;
+    pub mod string {
+        pub mod from_sci_string;
+        pub mod from_string;
+        pub mod to_sci;
+    }
 }
 pub mod exhaustive {
     pub mod exhaustive_finite_floats;
@@ -0,0 +1,42 @@
+use crate::fixed::{IBig, UBig};
+use crate::natural::arithmetic::neg::limbs_neg_in_place;
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+
+impl<const N: usize> UnsignedAbs for IBig<N> {
+    type Output = UBig<N>;
+
+    /// Takes the absolute value of an [`IBig`], returning a [`UBig`] of the same width.
+    ///
+    /// Unlike taking the absolute value as an [`IBig`], this never overflows.
+    ///
+    /// $f(x) = |x|$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Min;
+    /// use malachite_base::num::arithmetic::traits::{PowerOf2, UnsignedAbs};
+    /// use malachite_nz::fixed::{IBig, UBig};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(IBig::<2>::from(-123).unsigned_abs(), UBig::from(123u32));
+    /// assert_eq!(
+    ///     Natural::from(IBig::<2>::MIN.unsigned_abs()),
+    ///     Natural::power_of_2(IBig::<2>::WIDTH - 1)
+    /// );
+    /// ```
+    #[inline]
+    fn unsigned_abs(self) -> UBig<N> {
+        let mut xs = self.0;
+        if self.is_negative() {
+            limbs_neg_in_place(&mut xs);
+        }
+        UBig(xs)
+    }
+}
@@ -0,0 +1,307 @@
+use crate::fixed::{IBig, UBig};
+use crate::natural::arithmetic::add::limbs_add_same_length_to_out;
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedAdd, OverflowingAdd, OverflowingAddAssign, SaturatingAdd, SaturatingAddAssign,
+    WrappingAdd, WrappingAddAssign,
+};
+use std::ops::{Add, AddAssign};
+
+impl<const N: usize> OverflowingAdd<UBig<N>> for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Adds two [`UBig`]s.
+    ///
+    /// Returns a tuple containing the result and a boolean indicating whether an arithmetic
+    /// overflow occurred. If an overflow occurred, then the wrapped value is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::arithmetic::traits::OverflowingAdd;
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(
+    ///     UBig::<2>::from(123u32).overflowing_add(UBig::from(456u32)),
+    ///     (UBig::from(579u32), false)
+    /// );
+    /// assert_eq!(UBig::<2>::MAX.overflowing_add(UBig::ONE), (UBig::ZERO, true));
+    /// ```
+    fn overflowing_add(self, other: UBig<N>) -> (UBig<N>, bool) {
+        let mut out = [0; N];
+        let carry = limbs_add_same_length_to_out(&mut out, &self.0, &other.0);
+        (UBig(out), carry)
+    }
+}
+
+impl<const N: usize> OverflowingAdd<IBig<N>> for IBig<N> {
+    type Output = IBig<N>;
+
+    /// Adds two [`IBig`]s.
+    ///
+    /// Returns a tuple containing the result and a boolean indicating whether an arithmetic
+    /// overflow occurred. If an overflow occurred, then the wrapped value is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::{Max, Min};
+    /// use malachite_base::num::arithmetic::traits::OverflowingAdd;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!(
+    ///     IBig::<2>::from(123).overflowing_add(IBig::from(-456)),
+    ///     (IBig::from(-333), false)
+    /// );
+    /// assert_eq!(IBig::<2>::MAX.overflowing_add(IBig::ONE), (IBig::MIN, true));
+    /// ```
+    fn overflowing_add(self, other: IBig<N>) -> (IBig<N>, bool) {
+        let mut out = [0; N];
+        limbs_add_same_length_to_out(&mut out, &self.0, &other.0);
+        let out = IBig(out);
+        let x_negative = self.is_negative();
+        (
+            out,
+            x_negative == other.is_negative() && out.is_negative() != x_negative,
+        )
+    }
+}
+
+macro_rules! impl_add {
+    ($t: ident, $saturated: expr) => {
+        impl<const N: usize> Add<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Adds two [`", stringify!($t), "`]s.")]
+            ///
+            /// $f(x, y) = x + y$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::basic::traits::{One, Two};
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!((", stringify!($t), "::<2>::ONE + ", stringify!($t),
+                        "::TWO).to_string(), \"3\");"
+                    )]
+            /// ```
+            #[inline]
+            fn add(self, other: $t<N>) -> $t<N> {
+                let (sum, overflow) = self.overflowing_add(other);
+                assert!(!overflow, "attempt to add with overflow");
+                sum
+            }
+        }
+
+        impl<const N: usize> AddAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Adds a [`", stringify!($t), "`] to a [`", stringify!($t),
+                        "`] in place.")]
+            ///
+            /// $x \gets x + y$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable.
+            #[inline]
+            fn add_assign(&mut self, other: $t<N>) {
+                *self = *self + other;
+            }
+        }
+
+        impl<const N: usize> OverflowingAddAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Adds a [`", stringify!($t), "`] to a [`", stringify!($t),
+                        "`] in place.")]
+            ///
+            /// Returns a boolean indicating whether an arithmetic overflow occurred. If an overflow
+            /// occurred, then the wrapped value is assigned.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn overflowing_add_assign(&mut self, other: $t<N>) -> bool {
+                let overflow;
+                (*self, overflow) = self.overflowing_add(other);
+                overflow
+            }
+        }
+
+        impl<const N: usize> WrappingAdd<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Adds two [`", stringify!($t), "`]s, wrapping around at the ",
+                        "boundary of the type.")]
+            ///
+            /// $f(x, y) = z$, where $z \equiv x + y \mod 2^{NW}$ and $W$ is the width of a limb.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::comparison::traits::{Max, Min};
+            /// use malachite_base::num::arithmetic::traits::WrappingAdd;
+            /// use malachite_base::num::basic::traits::One;
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!(", stringify!($t), "::<2>::MAX.wrapping_add(", stringify!($t),
+                        "::ONE), ", stringify!($t), "::MIN);"
+                    )]
+            /// ```
+            #[inline]
+            fn wrapping_add(self, other: $t<N>) -> $t<N> {
+                self.overflowing_add(other).0
+            }
+        }
+
+        impl<const N: usize> WrappingAddAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Adds a [`", stringify!($t), "`] to a [`", stringify!($t),
+                        "`] in place, wrapping around at the boundary of the type.")]
+            ///
+            /// $x \gets z$, where $z \equiv x + y \mod 2^{NW}$ and $W$ is the width of a limb.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn wrapping_add_assign(&mut self, other: $t<N>) {
+                *self = self.overflowing_add(other).0;
+            }
+        }
+
+        impl<const N: usize> CheckedAdd<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Adds two [`", stringify!($t), "`]s, returning `None` if the ",
+                        "result is not representable.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::comparison::traits::Max;
+            /// use malachite_base::num::arithmetic::traits::CheckedAdd;
+            /// use malachite_base::num::basic::traits::One;
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!(", stringify!($t), "::<2>::MAX.checked_add(", stringify!($t),
+                        "::ONE), None);"
+                    )]
+            /// ```
+            #[inline]
+            fn checked_add(self, other: $t<N>) -> Option<$t<N>> {
+                match self.overflowing_add(other) {
+                    (sum, false) => Some(sum),
+                    _ => None,
+                }
+            }
+        }
+
+        impl<const N: usize> SaturatingAdd<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Adds two [`", stringify!($t), "`]s, saturating at the numeric ",
+                        "bounds instead of overflowing.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::comparison::traits::Max;
+            /// use malachite_base::num::arithmetic::traits::SaturatingAdd;
+            /// use malachite_base::num::basic::traits::One;
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!(", stringify!($t), "::<2>::MAX.saturating_add(", stringify!($t),
+                        "::ONE), ", stringify!($t), "::MAX);"
+                    )]
+            /// ```
+            #[inline]
+            fn saturating_add(self, other: $t<N>) -> $t<N> {
+                match self.overflowing_add(other) {
+                    (sum, false) => sum,
+                    _ => $saturated(self),
+                }
+            }
+        }
+
+        impl<const N: usize> SaturatingAddAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Adds a [`", stringify!($t), "`] to a [`", stringify!($t),
+                        "`] in place, saturating at the numeric bounds instead of overflowing.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn saturating_add_assign(&mut self, other: $t<N>) {
+                *self = self.saturating_add(other);
+            }
+        }
+    };
+}
+impl_add!(UBig, |_| UBig::MAX);
+// Adding two `IBig`s can only overflow if both have the same sign, so the sign of the first
+// determines the bound.
+impl_add!(IBig, |x: IBig<N>| if x.is_negative() {
+    IBig::MIN
+} else {
+    IBig::MAX
+});
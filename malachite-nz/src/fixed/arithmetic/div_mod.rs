@@ -0,0 +1,582 @@
+use crate::fixed::{with_scratch, IBig, UBig};
+use crate::natural::arithmetic::div_mod::{
+    limbs_div_limb_to_out_mod, limbs_div_mod_by_two_limb_normalized, limbs_div_mod_schoolbook,
+    limbs_two_limb_inverse_helper,
+};
+use crate::natural::arithmetic::shl::limbs_shl_to_out;
+use crate::natural::arithmetic::shr::limbs_shr_to_out;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    CheckedDiv, DivAssignMod, DivAssignRem, DivMod, DivRem, UnsignedAbs, WrappingNeg,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::LeadingZeros;
+use malachite_base::slices::{slice_set_zero, slice_trailing_zeros};
+use std::ops::{Div, DivAssign, Rem, RemAssign};
+
+// Interpreting `ns` and `ds` as the limbs (in ascending order) of two numbers, writes the limbs of
+// their quotient to `qs` and the limbs of their remainder to `rs`. `qs` must be as long as `ns`,
+// and `rs` must be as long as `ds`. Neither input needs to be normalized; trailing zero limbs are
+// ignored.
+//
+// # Worst-case complexity
+// $T(n, d) = O(d(n - d + 1))$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `ns.len()`, and $d$ is `ds.len()`.
+//
+// # Panics
+// Panics if `ds` is zero.
+pub(crate) fn limbs_fixed_div_mod(qs: &mut [Limb], rs: &mut [Limb], ns: &[Limb], ds: &[Limb]) {
+    let d_len = ds.len() - slice_trailing_zeros(ds);
+    if d_len == 0 {
+        panic!("division by zero");
+    }
+    let n_len = ns.len() - slice_trailing_zeros(ns);
+    slice_set_zero(qs);
+    slice_set_zero(rs);
+    if n_len < d_len {
+        rs[..n_len].copy_from_slice(&ns[..n_len]);
+        return;
+    }
+    if d_len == 1 {
+        let d = ds[0];
+        if n_len == 1 {
+            qs[0] = ns[0] / d;
+            rs[0] = ns[0] % d;
+        } else {
+            rs[0] = limbs_div_limb_to_out_mod(qs, &ns[..n_len], d);
+        }
+        return;
+    }
+    // Normalize so that the highest bit of the divisor is set. The numerator gets an extra limb to
+    // hold the bits shifted out of it.
+    let bits = LeadingZeros::leading_zeros(ds[d_len - 1]);
+    with_scratch(n_len + 1 + d_len, |scratch| {
+        let (ns_shifted, ds_shifted) = scratch.split_at_mut(n_len + 1);
+        if bits == 0 {
+            ns_shifted[..n_len].copy_from_slice(&ns[..n_len]);
+            ds_shifted.copy_from_slice(&ds[..d_len]);
+        } else {
+            ns_shifted[n_len] = limbs_shl_to_out(ns_shifted, &ns[..n_len], bits);
+            limbs_shl_to_out(ds_shifted, &ds[..d_len], bits);
+        }
+        // The extra numerator limb is smaller than the highest divisor limb, so the highest
+        // quotient limb returned by these functions is always zero.
+        if d_len == 2 {
+            limbs_div_mod_by_two_limb_normalized(qs, ns_shifted, ds_shifted);
+        } else {
+            let d_inv = limbs_two_limb_inverse_helper(ds_shifted[d_len - 1], ds_shifted[d_len - 2]);
+            limbs_div_mod_schoolbook(qs, ns_shifted, ds_shifted, d_inv);
+        }
+        if bits == 0 {
+            rs[..d_len].copy_from_slice(&ns_shifted[..d_len]);
+        } else {
+            limbs_shr_to_out(rs, &ns_shifted[..d_len], bits);
+        }
+    });
+}
+
+// Negates `x` if `negative` is true and reinterprets it as an `IBig`.
+fn ibig_from_sign_and_abs<const N: usize>(negative: bool, x: UBig<N>) -> IBig<N> {
+    let x = IBig(x.0);
+    if negative {
+        x.wrapping_neg()
+    } else {
+        x
+    }
+}
+
+// Returns the truncated quotient and the remainder of two `IBig`s, along with whether the quotient
+// overflowed, which only happens when the minimum value is divided by -1.
+fn ibig_overflowing_div_rem<const N: usize>(x: IBig<N>, y: IBig<N>) -> (IBig<N>, IBig<N>, bool) {
+    let x_negative = x.is_negative();
+    let q_negative = x_negative != y.is_negative();
+    let (q, r) = x.unsigned_abs().div_rem(y.unsigned_abs());
+    let q = ibig_from_sign_and_abs(q_negative, q);
+    (
+        q,
+        ibig_from_sign_and_abs(x_negative, r),
+        !q_negative && q.is_negative(),
+    )
+}
+
+impl<const N: usize> DivMod<UBig<N>> for UBig<N> {
+    type DivOutput = UBig<N>;
+    type ModOutput = UBig<N>;
+
+    /// Divides a [`UBig`] by another [`UBig`], returning the quotient and remainder. The quotient
+    /// is rounded towards negative infinity.
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq r < y$.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \left \lfloor \frac{x}{y} \right \rfloor, \space
+    /// x - y\left \lfloor \frac{x}{y} \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// // 2 * 10 + 3 = 23
+    /// assert_eq!(
+    ///     UBig::<2>::from(23u32).div_mod(UBig::from(10u32)).to_debug_string(),
+    ///     "(2, 3)"
+    /// );
+    /// ```
+    fn div_mod(self, other: UBig<N>) -> (UBig<N>, UBig<N>) {
+        let mut q = UBig::ZERO;
+        let mut r = UBig::ZERO;
+        limbs_fixed_div_mod(&mut q.0, &mut r.0, &self.0, &other.0);
+        (q, r)
+    }
+}
+
+impl<const N: usize> DivAssignMod<UBig<N>> for UBig<N> {
+    type ModOutput = UBig<N>;
+
+    /// Divides a [`UBig`] by another [`UBig`] in place, returning the remainder. The quotient is
+    /// rounded towards negative infinity.
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq r < y$.
+    ///
+    /// $$
+    /// f(x, y) = x - y\left \lfloor \frac{x}{y} \right \rfloor,
+    /// $$
+    /// $$
+    /// x \gets \left \lfloor \frac{x}{y} \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_assign_mod(&mut self, other: UBig<N>) -> UBig<N> {
+        let r;
+        (*self, r) = self.div_mod(other);
+        r
+    }
+}
+
+impl<const N: usize> DivRem<UBig<N>> for UBig<N> {
+    type DivOutput = UBig<N>;
+    type RemOutput = UBig<N>;
+
+    /// Divides a [`UBig`] by another [`UBig`], returning the quotient and remainder. The quotient
+    /// is rounded towards zero.
+    ///
+    /// For [`UBig`]s, `div_rem` is equivalent to
+    /// [`div_mod`](malachite_base::num::arithmetic::traits::DivMod::div_mod).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// // 2 * 10 + 3 = 23
+    /// assert_eq!(
+    ///     UBig::<2>::from(23u32).div_rem(UBig::from(10u32)).to_debug_string(),
+    ///     "(2, 3)"
+    /// );
+    /// ```
+    #[inline]
+    fn div_rem(self, other: UBig<N>) -> (UBig<N>, UBig<N>) {
+        self.div_mod(other)
+    }
+}
+
+impl<const N: usize> DivAssignRem<UBig<N>> for UBig<N> {
+    type RemOutput = UBig<N>;
+
+    /// Divides a [`UBig`] by another [`UBig`] in place, returning the remainder. The quotient is
+    /// rounded towards zero.
+    ///
+    /// For [`UBig`]s, `div_assign_rem` is equivalent to
+    /// [`div_assign_mod`](malachite_base::num::arithmetic::traits::DivAssignMod::div_assign_mod).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_assign_rem(&mut self, other: UBig<N>) -> UBig<N> {
+        self.div_assign_mod(other)
+    }
+}
+
+impl<const N: usize> DivMod<IBig<N>> for IBig<N> {
+    type DivOutput = IBig<N>;
+    type ModOutput = IBig<N>;
+
+    /// Divides an [`IBig`] by another [`IBig`], returning the quotient and remainder. The quotient
+    /// is rounded towards negative infinity, and the remainder has the same sign as the second
+    /// [`IBig`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \left \lfloor \frac{x}{y} \right \rfloor, \space
+    /// x - y\left \lfloor \frac{x}{y} \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `self` is the minimum value and `other` is -1.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// // 2 * 10 + 3 = 23
+    /// assert_eq!(IBig::<2>::from(23).div_mod(IBig::from(10)).to_debug_string(), "(2, 3)");
+    ///
+    /// // -3 * -10 + -7 = 23
+    /// assert_eq!(IBig::<2>::from(23).div_mod(IBig::from(-10)).to_debug_string(), "(-3, -7)");
+    ///
+    /// // -3 * 10 + 7 = -23
+    /// assert_eq!(IBig::<2>::from(-23).div_mod(IBig::from(10)).to_debug_string(), "(-3, 7)");
+    ///
+    /// // 2 * -10 + -3 = -23
+    /// assert_eq!(IBig::<2>::from(-23).div_mod(IBig::from(-10)).to_debug_string(), "(2, -3)");
+    /// ```
+    fn div_mod(self, other: IBig<N>) -> (IBig<N>, IBig<N>) {
+        let (q, r) = self.div_rem(other);
+        if r != IBig::ZERO && self.is_negative() != other.is_negative() {
+            // The truncated quotient is non-positive here, so decrementing it cannot overflow.
+            (q - IBig::ONE, r + other)
+        } else {
+            (q, r)
+        }
+    }
+}
+
+impl<const N: usize> DivAssignMod<IBig<N>> for IBig<N> {
+    type ModOutput = IBig<N>;
+
+    /// Divides an [`IBig`] by another [`IBig`] in place, returning the remainder. The quotient is
+    /// rounded towards negative infinity, and the remainder has the same sign as the second
+    /// [`IBig`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `self` is the minimum value and `other` is -1.
+    #[inline]
+    fn div_assign_mod(&mut self, other: IBig<N>) -> IBig<N> {
+        let r;
+        (*self, r) = self.div_mod(other);
+        r
+    }
+}
+
+impl<const N: usize> DivRem<IBig<N>> for IBig<N> {
+    type DivOutput = IBig<N>;
+    type RemOutput = IBig<N>;
+
+    /// Divides an [`IBig`] by another [`IBig`], returning the quotient and remainder. The quotient
+    /// is rounded towards zero and the remainder has the same sign as the first [`IBig`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = \left ( \operatorname{sgn}(xy) \left \lfloor \left | \frac{x}{y} \right |
+    /// \right \rfloor, \space
+    /// x - y \operatorname{sgn}(xy) \left \lfloor \left | \frac{x}{y} \right | \right \rfloor
+    /// \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `self` is the minimum value and `other` is -1.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRem;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// // 2 * 10 + 3 = 23
+    /// assert_eq!(IBig::<2>::from(23).div_rem(IBig::from(10)).to_debug_string(), "(2, 3)");
+    ///
+    /// // -2 * -10 + 3 = 23
+    /// assert_eq!(IBig::<2>::from(23).div_rem(IBig::from(-10)).to_debug_string(), "(-2, 3)");
+    ///
+    /// // -2 * 10 + -3 = -23
+    /// assert_eq!(IBig::<2>::from(-23).div_rem(IBig::from(10)).to_debug_string(), "(-2, -3)");
+    ///
+    /// // 2 * -10 + -3 = -23
+    /// assert_eq!(IBig::<2>::from(-23).div_rem(IBig::from(-10)).to_debug_string(), "(2, -3)");
+    /// ```
+    fn div_rem(self, other: IBig<N>) -> (IBig<N>, IBig<N>) {
+        let (q, r, overflow) = ibig_overflowing_div_rem(self, other);
+        assert!(!overflow, "attempt to divide with overflow");
+        (q, r)
+    }
+}
+
+impl<const N: usize> DivAssignRem<IBig<N>> for IBig<N> {
+    type RemOutput = IBig<N>;
+
+    /// Divides an [`IBig`] by another [`IBig`] in place, returning the remainder. The quotient is
+    /// rounded towards zero and the remainder has the same sign as the first [`IBig`].
+    ///
+    /// The quotient and remainder satisfy $x = qy + r$ and $0 \leq |r| < |y|$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `self` is the minimum value and `other` is -1.
+    #[inline]
+    fn div_assign_rem(&mut self, other: IBig<N>) -> IBig<N> {
+        let r;
+        (*self, r) = self.div_rem(other);
+        r
+    }
+}
+
+macro_rules! impl_div_and_rem {
+    ($t: ident, $div_example: expr, $rem_example: expr) => {
+        impl<const N: usize> Div<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Divides a [`", stringify!($t), "`] by another [`", stringify!($t),
+                "`]. The quotient is rounded towards zero.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, or if the quotient is not representable.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = $div_example]
+            /// ```
+            #[inline]
+            fn div(self, other: $t<N>) -> $t<N> {
+                self.div_rem(other).0
+            }
+        }
+
+        impl<const N: usize> DivAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Divides a [`", stringify!($t), "`] by another [`", stringify!($t),
+                "`] in place. The quotient is rounded towards zero.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, or if the quotient is not representable.
+            #[inline]
+            fn div_assign(&mut self, other: $t<N>) {
+                *self = self.div_rem(other).0;
+            }
+        }
+
+        impl<const N: usize> Rem<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Finds the remainder when a [`", stringify!($t),
+                "`] is divided by another [`", stringify!($t), "`]. The remainder has the same ",
+                "sign as the first number.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, or if the quotient is not representable.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = $rem_example]
+            /// ```
+            #[inline]
+            fn rem(self, other: $t<N>) -> $t<N> {
+                self.div_rem(other).1
+            }
+        }
+
+        impl<const N: usize> RemAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Finds the remainder when a [`", stringify!($t),
+                "`] is divided by another [`", stringify!($t), "`], in place. The remainder has ",
+                "the same sign as the first number.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, or if the quotient is not representable.
+            #[inline]
+            fn rem_assign(&mut self, other: $t<N>) {
+                *self = self.div_rem(other).1;
+            }
+        }
+    };
+}
+impl_div_and_rem!(
+    UBig,
+    concat!(
+        "use malachite_nz::fixed::UBig;\n\n",
+        "assert_eq!(UBig::<2>::from(23u32) / UBig::from(10u32), UBig::from(2u32));"
+    ),
+    concat!(
+        "use malachite_nz::fixed::UBig;\n\n",
+        "assert_eq!(UBig::<2>::from(23u32) % UBig::from(10u32), UBig::from(3u32));"
+    )
+);
+impl_div_and_rem!(
+    IBig,
+    concat!(
+        "use malachite_nz::fixed::IBig;\n\n",
+        "assert_eq!(IBig::<2>::from(-23) / IBig::from(10), IBig::from(-2));"
+    ),
+    concat!(
+        "use malachite_nz::fixed::IBig;\n\n",
+        "assert_eq!(IBig::<2>::from(-23) % IBig::from(10), IBig::from(-3));"
+    )
+);
+
+impl<const N: usize> CheckedDiv<UBig<N>> for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Divides a [`UBig`] by another [`UBig`], returning `None` if the divisor is zero. The
+    /// quotient is rounded towards zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CheckedDiv;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(UBig::<2>::from(23u32).checked_div(UBig::from(10u32)), Some(UBig::from(2u32)));
+    /// assert_eq!(UBig::<2>::from(23u32).checked_div(UBig::ZERO), None);
+    /// ```
+    #[inline]
+    fn checked_div(self, other: UBig<N>) -> Option<UBig<N>> {
+        if other == UBig::ZERO {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+}
+
+impl<const N: usize> CheckedDiv<IBig<N>> for IBig<N> {
+    type Output = IBig<N>;
+
+    /// Divides an [`IBig`] by another [`IBig`], returning `None` if the divisor is zero or if the
+    /// quotient is not representable. The quotient is rounded towards zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Min;
+    /// use malachite_base::num::arithmetic::traits::CheckedDiv;
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!(IBig::<2>::from(-23).checked_div(IBig::from(10)), Some(IBig::from(-2)));
+    /// assert_eq!(IBig::<2>::from(-23).checked_div(IBig::ZERO), None);
+    /// assert_eq!(IBig::<2>::MIN.checked_div(IBig::NEGATIVE_ONE), None);
+    /// ```
+    fn checked_div(self, other: IBig<N>) -> Option<IBig<N>> {
+        if other == IBig::ZERO {
+            return None;
+        }
+        match ibig_overflowing_div_rem(self, other) {
+            (q, _, false) => Some(q),
+            _ => None,
+        }
+    }
+}
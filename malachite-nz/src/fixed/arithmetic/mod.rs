@@ -0,0 +1,28 @@
+/// Implementations of [`UnsignedAbs`](malachite_base::num::arithmetic::traits::UnsignedAbs) for
+/// [`IBig`](crate::fixed::IBig)s.
+pub mod abs;
+/// Addition of [`UBig`](crate::fixed::UBig)s and of [`IBig`](crate::fixed::IBig)s, with the
+/// checked, overflowing, saturating, and wrapping variants.
+pub mod add;
+/// Division of [`UBig`](crate::fixed::UBig)s and of [`IBig`](crate::fixed::IBig)s, returning
+/// quotients, remainders, or both.
+pub mod div_mod;
+/// Addition of [`UBig`](crate::fixed::UBig)s modulo a [`UBig`](crate::fixed::UBig).
+pub mod mod_add;
+/// Multiplication of [`UBig`](crate::fixed::UBig)s modulo a [`UBig`](crate::fixed::UBig).
+pub mod mod_mul;
+/// Negation of a [`UBig`](crate::fixed::UBig) modulo a [`UBig`](crate::fixed::UBig).
+pub mod mod_neg;
+/// Raising a [`UBig`](crate::fixed::UBig) to a power modulo a [`UBig`](crate::fixed::UBig).
+pub mod mod_pow;
+/// Subtraction of [`UBig`](crate::fixed::UBig)s modulo a [`UBig`](crate::fixed::UBig).
+pub mod mod_sub;
+/// Multiplication of [`UBig`](crate::fixed::UBig)s and of [`IBig`](crate::fixed::IBig)s, with
+/// the checked, overflowing, saturating, and wrapping variants.
+pub mod mul;
+/// Negation of [`UBig`](crate::fixed::UBig)s and of [`IBig`](crate::fixed::IBig)s, with the
+/// checked, overflowing, saturating, and wrapping variants.
+pub mod neg;
+/// Subtraction of [`UBig`](crate::fixed::UBig)s and of [`IBig`](crate::fixed::IBig)s, with the
+/// checked, overflowing, saturating, and wrapping variants.
+pub mod sub;
@@ -0,0 +1,87 @@
+use crate::fixed::UBig;
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModAddAssign, OverflowingAdd, WrappingSubAssign,
+};
+
+impl<const N: usize> ModAdd<UBig<N>, UBig<N>> for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Adds two [`UBig`]s modulo a third [`UBig`] $m$. The inputs must be already reduced modulo
+    /// $m$.
+    ///
+    /// $f(x, y, m) = z$, where $x, y, z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// The sum is allowed to exceed the width of the type; it never overflows.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `other` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::arithmetic::traits::ModAdd;
+    /// use malachite_base::num::basic::traits::{One, Two, Zero};
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(
+    ///     UBig::<2>::ZERO.mod_add(UBig::from(3u32), UBig::from(5u32)),
+    ///     UBig::from(3u32)
+    /// );
+    /// assert_eq!(
+    ///     UBig::<2>::from(7u32).mod_add(UBig::from(5u32), UBig::from(10u32)),
+    ///     UBig::from(2u32)
+    /// );
+    /// let m = UBig::<2>::MAX;
+    /// assert_eq!((m - UBig::ONE).mod_add(m - UBig::ONE, m), m - UBig::TWO);
+    /// ```
+    #[inline]
+    fn mod_add(mut self, other: UBig<N>, m: UBig<N>) -> UBig<N> {
+        self.mod_add_assign(other, m);
+        self
+    }
+}
+
+impl<const N: usize> ModAddAssign<UBig<N>, UBig<N>> for UBig<N> {
+    /// Adds two [`UBig`]s modulo a third [`UBig`] $m$, in place. The inputs must be already
+    /// reduced modulo $m$.
+    ///
+    /// $x \gets z$, where $x, y, z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `other` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModAddAssign;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// let mut x = UBig::<2>::from(7u32);
+    /// x.mod_add_assign(UBig::from(5u32), UBig::from(10u32));
+    /// assert_eq!(x, UBig::from(2u32));
+    /// ```
+    fn mod_add_assign(&mut self, other: UBig<N>, m: UBig<N>) {
+        assert!(*self < m, "self must be reduced mod m, but {self} >= {m}");
+        assert!(other < m, "other must be reduced mod m, but {other} >= {m}");
+        let carry;
+        (*self, carry) = self.overflowing_add(other);
+        // If the sum overflowed, its true value is at least 2^(NW) > m, and subtracting m wraps
+        // back around to the correct result.
+        if carry || *self >= m {
+            self.wrapping_sub_assign(m);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::fixed::arithmetic::div_mod::limbs_fixed_div_mod;
+use crate::fixed::{with_scratch, UBig};
+use crate::natural::arithmetic::mul::limbs_mul_greater_to_out_basecase;
+use malachite_base::num::arithmetic::traits::{ModMul, ModMulAssign};
+
+impl<const N: usize> ModMul<UBig<N>, UBig<N>> for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Multiplies two [`UBig`]s modulo a third [`UBig`] $m$. The inputs must be already reduced
+    /// modulo $m$.
+    ///
+    /// $f(x, y, m) = z$, where $x, y, z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// The full product is computed before it is reduced, so it never overflows.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `other` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_base::num::basic::traits::{One, Two};
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(
+    ///     UBig::<2>::from(3u32).mod_mul(UBig::from(4u32), UBig::from(15u32)),
+    ///     UBig::from(12u32)
+    /// );
+    /// assert_eq!(
+    ///     UBig::<2>::from(7u32).mod_mul(UBig::from(6u32), UBig::from(10u32)),
+    ///     UBig::from(2u32)
+    /// );
+    /// // (-2) * (-2) = 4 mod 2^(NW) - 1
+    /// let m = UBig::<2>::MAX;
+    /// assert_eq!(
+    ///     (m - UBig::TWO).mod_mul(m - UBig::TWO, m),
+    ///     UBig::TWO + UBig::TWO
+    /// );
+    /// ```
+    #[inline]
+    fn mod_mul(mut self, other: UBig<N>, m: UBig<N>) -> UBig<N> {
+        self.mod_mul_assign(other, m);
+        self
+    }
+}
+
+impl<const N: usize> ModMulAssign<UBig<N>, UBig<N>> for UBig<N> {
+    /// Multiplies two [`UBig`]s modulo a third [`UBig`] $m$, in place. The inputs must be already
+    /// reduced modulo $m$.
+    ///
+    /// $x \gets z$, where $x, y, z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `other` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMulAssign;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// let mut x = UBig::<2>::from(7u32);
+    /// x.mod_mul_assign(UBig::from(6u32), UBig::from(10u32));
+    /// assert_eq!(x, UBig::from(2u32));
+    /// ```
+    fn mod_mul_assign(&mut self, other: UBig<N>, m: UBig<N>) {
+        assert!(*self < m, "self must be reduced mod m, but {self} >= {m}");
+        assert!(other < m, "other must be reduced mod m, but {other} >= {m}");
+        let xs = self.0;
+        with_scratch(N << 2, |scratch| {
+            let (product, qs) = scratch.split_at_mut(N << 1);
+            limbs_mul_greater_to_out_basecase(product, &xs, &other.0);
+            limbs_fixed_div_mod(qs, &mut self.0, product, &m.0);
+        });
+    }
+}
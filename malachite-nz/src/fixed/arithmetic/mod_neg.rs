@@ -0,0 +1,70 @@
+use crate::fixed::UBig;
+use malachite_base::num::arithmetic::traits::{ModNeg, ModNegAssign};
+use malachite_base::num::basic::traits::Zero;
+
+impl<const N: usize> ModNeg<UBig<N>> for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Negates a [`UBig`] modulo another [`UBig`] $m$. The input must be already reduced modulo
+    /// $m$.
+    ///
+    /// $f(x, m) = y$, where $x, y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(UBig::<2>::ZERO.mod_neg(UBig::from(5u32)), UBig::ZERO);
+    /// assert_eq!(UBig::<2>::from(7u32).mod_neg(UBig::from(10u32)), UBig::from(3u32));
+    /// ```
+    #[inline]
+    fn mod_neg(mut self, m: UBig<N>) -> UBig<N> {
+        self.mod_neg_assign(m);
+        self
+    }
+}
+
+impl<const N: usize> ModNegAssign<UBig<N>> for UBig<N> {
+    /// Negates a [`UBig`] modulo another [`UBig`] $m$, in place. The input must be already
+    /// reduced modulo $m$.
+    ///
+    /// $x \gets y$, where $x, y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNegAssign;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// let mut x = UBig::<2>::from(7u32);
+    /// x.mod_neg_assign(UBig::from(10u32));
+    /// assert_eq!(x, UBig::from(3u32));
+    /// ```
+    fn mod_neg_assign(&mut self, m: UBig<N>) {
+        assert!(*self < m, "self must be reduced mod m, but {self} >= {m}");
+        if *self != UBig::ZERO {
+            *self = m - *self;
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::fixed::UBig;
+use malachite_base::num::arithmetic::traits::{ModMulAssign, ModPow, ModPowAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// Raises `x` to the power whose bits are given by `exp_bit`, from bit `exp_bits - 1` down to bit
+// 0, modulo `m`, using left-to-right binary exponentiation.
+fn mod_pow_helper<const N: usize, F: Fn(u64) -> bool>(
+    x: UBig<N>,
+    exp_bits: u64,
+    exp_bit: F,
+    m: UBig<N>,
+) -> UBig<N> {
+    assert!(x < m, "self must be reduced mod m, but {x} >= {m}");
+    let mut out = if m == UBig::ONE {
+        UBig::ZERO
+    } else {
+        UBig::ONE
+    };
+    for i in (0..exp_bits).rev() {
+        out.mod_mul_assign(out, m);
+        if exp_bit(i) {
+            out.mod_mul_assign(x, m);
+        }
+    }
+    out
+}
+
+impl<const N: usize> ModPow<u64, UBig<N>> for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Raises a [`UBig`] to a [`u64`] power modulo another [`UBig`] $m$. The base must be already
+    /// reduced modulo $m$.
+    ///
+    /// $f(x, n, m) = y$, where $x, y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `N`, and $k$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(UBig::<2>::from(4u32).mod_pow(13, UBig::from(497u32)), UBig::from(445u32));
+    /// assert_eq!(UBig::<2>::from(10u32).mod_pow(1000, UBig::from(30u32)), UBig::from(10u32));
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: u64, m: UBig<N>) -> UBig<N> {
+        mod_pow_helper(self, exp.significant_bits(), |i| exp.get_bit(i), m)
+    }
+}
+
+impl<const N: usize> ModPowAssign<u64, UBig<N>> for UBig<N> {
+    /// Raises a [`UBig`] to a [`u64`] power modulo another [`UBig`] $m$, in place. The base must
+    /// be already reduced modulo $m$.
+    ///
+    /// $x \gets y$, where $x, y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `N`, and $k$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// let mut x = UBig::<2>::from(4u32);
+    /// x.mod_pow_assign(13, UBig::from(497u32));
+    /// assert_eq!(x, UBig::from(445u32));
+    /// ```
+    #[inline]
+    fn mod_pow_assign(&mut self, exp: u64, m: UBig<N>) {
+        *self = self.mod_pow(exp, m);
+    }
+}
+
+impl<const N: usize> ModPow<UBig<N>, UBig<N>> for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Raises a [`UBig`] to a [`UBig`] power modulo another [`UBig`] $m$. The base must be already
+    /// reduced modulo $m$.
+    ///
+    /// $f(x, n, m) = y$, where $x, y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(
+    ///     UBig::<2>::from(4u32).mod_pow(UBig::from(13u32), UBig::from(497u32)),
+    ///     UBig::from(445u32)
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: UBig<N>, m: UBig<N>) -> UBig<N> {
+        mod_pow_helper(self, exp.significant_bits(), |i| exp.get_bit(i), m)
+    }
+}
+
+impl<const N: usize> ModPowAssign<UBig<N>, UBig<N>> for UBig<N> {
+    /// Raises a [`UBig`] to a [`UBig`] power modulo another [`UBig`] $m$, in place. The base must
+    /// be already reduced modulo $m$.
+    ///
+    /// $x \gets y$, where $x, y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// let mut x = UBig::<2>::from(4u32);
+    /// x.mod_pow_assign(UBig::from(13u32), UBig::from(497u32));
+    /// assert_eq!(x, UBig::from(445u32));
+    /// ```
+    #[inline]
+    fn mod_pow_assign(&mut self, exp: UBig<N>, m: UBig<N>) {
+        *self = self.mod_pow(exp, m);
+    }
+}
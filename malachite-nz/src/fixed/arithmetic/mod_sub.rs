@@ -0,0 +1,79 @@
+use crate::fixed::UBig;
+use malachite_base::num::arithmetic::traits::{
+    ModSub, ModSubAssign, OverflowingSub, WrappingAddAssign,
+};
+
+impl<const N: usize> ModSub<UBig<N>, UBig<N>> for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Subtracts two [`UBig`]s modulo a third [`UBig`] $m$. The inputs must be already reduced
+    /// modulo $m$.
+    ///
+    /// $f(x, y, m) = z$, where $x, y, z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `other` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(
+    ///     UBig::<2>::from(4u32).mod_sub(UBig::from(3u32), UBig::from(5u32)),
+    ///     UBig::from(1u32)
+    /// );
+    /// assert_eq!(
+    ///     UBig::<2>::from(7u32).mod_sub(UBig::from(9u32), UBig::from(10u32)),
+    ///     UBig::from(8u32)
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sub(mut self, other: UBig<N>, m: UBig<N>) -> UBig<N> {
+        self.mod_sub_assign(other, m);
+        self
+    }
+}
+
+impl<const N: usize> ModSubAssign<UBig<N>, UBig<N>> for UBig<N> {
+    /// Subtracts two [`UBig`]s modulo a third [`UBig`] $m$, in place. The inputs must be already
+    /// reduced modulo $m$.
+    ///
+    /// $x \gets z$, where $x, y, z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `other` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSubAssign;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// let mut x = UBig::<2>::from(7u32);
+    /// x.mod_sub_assign(UBig::from(9u32), UBig::from(10u32));
+    /// assert_eq!(x, UBig::from(8u32));
+    /// ```
+    fn mod_sub_assign(&mut self, other: UBig<N>, m: UBig<N>) {
+        assert!(*self < m, "self must be reduced mod m, but {self} >= {m}");
+        assert!(other < m, "other must be reduced mod m, but {other} >= {m}");
+        let borrow;
+        (*self, borrow) = self.overflowing_sub(other);
+        if borrow {
+            self.wrapping_add_assign(m);
+        }
+    }
+}
@@ -0,0 +1,347 @@
+use crate::fixed::{with_scratch, IBig, UBig};
+use crate::natural::arithmetic::mul::limbs_mul_greater_to_out_basecase;
+use crate::natural::arithmetic::neg::limbs_neg_in_place;
+use crate::platform::Limb;
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedMul, OverflowingMul, OverflowingMulAssign, SaturatingMul, SaturatingMulAssign,
+    WrappingMul, WrappingMulAssign,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::slices::slice_test_zero;
+use std::ops::{Mul, MulAssign};
+
+// Multiplies two `N`-limb numbers, writing the low `N` limbs of the product to `out`, and returns
+// the result of calling `f` on the high `N` limbs of the product.
+pub(crate) fn limbs_fixed_mul<const N: usize, T, F: FnOnce(&[Limb]) -> T>(
+    out: &mut [Limb; N],
+    xs: &[Limb; N],
+    ys: &[Limb; N],
+    f: F,
+) -> T {
+    with_scratch(N << 1, |product| {
+        limbs_mul_greater_to_out_basecase(product, xs, ys);
+        let (product_lo, product_hi) = product.split_at(N);
+        out.copy_from_slice(product_lo);
+        f(product_hi)
+    })
+}
+
+impl<const N: usize> OverflowingMul<UBig<N>> for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Multiplies two [`UBig`]s.
+    ///
+    /// Returns a tuple containing the result and a boolean indicating whether an arithmetic
+    /// overflow occurred. If an overflow occurred, then the wrapped value is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::arithmetic::traits::OverflowingMul;
+    /// use malachite_base::num::basic::traits::{One, Two};
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(
+    ///     UBig::<2>::from(123u32).overflowing_mul(UBig::from(456u32)),
+    ///     (UBig::from(56088u32), false)
+    /// );
+    /// assert_eq!(UBig::<2>::MAX.overflowing_mul(UBig::TWO), (UBig::MAX - UBig::ONE, true));
+    /// ```
+    fn overflowing_mul(self, other: UBig<N>) -> (UBig<N>, bool) {
+        let mut out = [0; N];
+        let overflow = limbs_fixed_mul(&mut out, &self.0, &other.0, |hi| !slice_test_zero(hi));
+        (UBig(out), overflow)
+    }
+}
+
+impl<const N: usize> OverflowingMul<IBig<N>> for IBig<N> {
+    type Output = IBig<N>;
+
+    /// Multiplies two [`IBig`]s.
+    ///
+    /// Returns a tuple containing the result and a boolean indicating whether an arithmetic
+    /// overflow occurred. If an overflow occurred, then the wrapped value is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::{Max, Min};
+    /// use malachite_base::num::arithmetic::traits::OverflowingMul;
+    /// use malachite_base::num::basic::traits::{NegativeOne, Two};
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!(
+    ///     IBig::<2>::from(123).overflowing_mul(IBig::from(-456)),
+    ///     (IBig::from(-56088), false)
+    /// );
+    /// assert_eq!(IBig::<2>::MIN.overflowing_mul(IBig::NEGATIVE_ONE), (IBig::MIN, true));
+    /// assert_eq!(IBig::<2>::MAX.overflowing_mul(IBig::TWO), (IBig::from(-2), true));
+    /// ```
+    fn overflowing_mul(self, other: IBig<N>) -> (IBig<N>, bool) {
+        // Multiply the absolute values, then fix up the sign.
+        let x_negative = self.is_negative();
+        let y_negative = other.is_negative();
+        let mut xs = self.0;
+        if x_negative {
+            limbs_neg_in_place(&mut xs);
+        }
+        let mut ys = other.0;
+        if y_negative {
+            limbs_neg_in_place(&mut ys);
+        }
+        let mut out = [0; N];
+        let high_zero = limbs_fixed_mul(&mut out, &xs, &ys, slice_test_zero);
+        let negative = x_negative != y_negative;
+        // The absolute value of the product fits if it is less than 2^(NW - 1), or if it equals
+        // 2^(NW - 1) and the product is negative.
+        let overflow = !high_zero
+            || IBig(out).is_negative()
+                && !(negative
+                    && out[N - 1] == 1 << (Limb::WIDTH - 1)
+                    && slice_test_zero(&out[..N - 1]));
+        if negative {
+            limbs_neg_in_place(&mut out);
+        }
+        (IBig(out), overflow)
+    }
+}
+
+macro_rules! impl_mul {
+    ($t: ident, $saturated: expr) => {
+        impl<const N: usize> Mul<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Multiplies two [`", stringify!($t), "`]s.")]
+            ///
+            /// $f(x, y) = xy$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::basic::traits::{One, Two};
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!((", stringify!($t), "::<2>::TWO * ", stringify!($t),
+                        "::TWO).to_string(), \"4\");"
+                    )]
+            /// ```
+            #[inline]
+            fn mul(self, other: $t<N>) -> $t<N> {
+                let (product, overflow) = self.overflowing_mul(other);
+                assert!(!overflow, "attempt to multiply with overflow");
+                product
+            }
+        }
+
+        impl<const N: usize> MulAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Multiplies a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`] in place.")]
+            ///
+            /// $x \gets xy$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable.
+            #[inline]
+            fn mul_assign(&mut self, other: $t<N>) {
+                *self = *self * other;
+            }
+        }
+
+        impl<const N: usize> OverflowingMulAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Multiplies a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`] in place.")]
+            ///
+            /// Returns a boolean indicating whether an arithmetic overflow occurred. If an overflow
+            /// occurred, then the wrapped value is assigned.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn overflowing_mul_assign(&mut self, other: $t<N>) -> bool {
+                let overflow;
+                (*self, overflow) = self.overflowing_mul(other);
+                overflow
+            }
+        }
+
+        impl<const N: usize> WrappingMul<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Multiplies two [`", stringify!($t), "`]s, wrapping around at the ",
+                        "boundary of the type.")]
+            ///
+            /// $f(x, y) = z$, where $z \equiv xy \mod 2^{NW}$ and $W$ is the width of a limb.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::comparison::traits::Max;
+            /// use malachite_base::num::arithmetic::traits::WrappingMul;
+            /// use malachite_base::num::basic::traits::One;
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!(", stringify!($t), "::<2>::MAX.wrapping_mul(", stringify!($t),
+                        "::MAX), ", stringify!($t), "::ONE);"
+                    )]
+            /// ```
+            #[inline]
+            fn wrapping_mul(self, other: $t<N>) -> $t<N> {
+                self.overflowing_mul(other).0
+            }
+        }
+
+        impl<const N: usize> WrappingMulAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Multiplies a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`] in place, wrapping around at the boundary of the type.")]
+            ///
+            /// $x \gets z$, where $z \equiv xy \mod 2^{NW}$ and $W$ is the width of a limb.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn wrapping_mul_assign(&mut self, other: $t<N>) {
+                *self = self.overflowing_mul(other).0;
+            }
+        }
+
+        impl<const N: usize> CheckedMul<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Multiplies two [`", stringify!($t), "`]s, returning `None` if the ",
+                        "result is not representable.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::comparison::traits::Max;
+            /// use malachite_base::num::arithmetic::traits::CheckedMul;
+            /// use malachite_base::num::basic::traits::Two;
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!(", stringify!($t), "::<2>::MAX.checked_mul(", stringify!($t),
+                        "::TWO), None);"
+                    )]
+            /// ```
+            #[inline]
+            fn checked_mul(self, other: $t<N>) -> Option<$t<N>> {
+                match self.overflowing_mul(other) {
+                    (product, false) => Some(product),
+                    _ => None,
+                }
+            }
+        }
+
+        impl<const N: usize> SaturatingMul<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Multiplies two [`", stringify!($t), "`]s, saturating at the numeric ",
+                        "bounds instead of overflowing.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::comparison::traits::Max;
+            /// use malachite_base::num::arithmetic::traits::SaturatingMul;
+            /// use malachite_base::num::basic::traits::Two;
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!(", stringify!($t), "::<2>::MAX.saturating_mul(", stringify!($t),
+                        "::TWO), ", stringify!($t), "::MAX);"
+                    )]
+            /// ```
+            #[inline]
+            fn saturating_mul(self, other: $t<N>) -> $t<N> {
+                match self.overflowing_mul(other) {
+                    (product, false) => product,
+                    _ => $saturated((self, other)),
+                }
+            }
+        }
+
+        impl<const N: usize> SaturatingMulAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Multiplies a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`] in place, saturating at the numeric bounds instead of overflowing.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn saturating_mul_assign(&mut self, other: $t<N>) {
+                *self = self.saturating_mul(other);
+            }
+        }
+    };
+}
+impl_mul!(UBig, |_| UBig::MAX);
+// Multiplying two `IBig`s saturates to the minimum exactly when their signs differ.
+impl_mul!(
+    IBig,
+    |(x, y): (IBig<N>, IBig<N>)| if x.is_negative() != y.is_negative() {
+        IBig::MIN
+    } else {
+        IBig::MAX
+    }
+);
@@ -0,0 +1,282 @@
+use crate::fixed::{IBig, UBig};
+use crate::natural::arithmetic::neg::limbs_neg_in_place;
+use malachite_base::comparison::traits::Max;
+use malachite_base::num::arithmetic::traits::{
+    CheckedNeg, NegAssign, OverflowingNeg, OverflowingNegAssign, SaturatingNeg,
+    SaturatingNegAssign, WrappingNeg, WrappingNegAssign,
+};
+use std::ops::Neg;
+
+impl<const N: usize> OverflowingNeg for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Negates a [`UBig`].
+    ///
+    /// Returns a tuple containing the result and a boolean indicating whether an arithmetic
+    /// overflow occurred. If an overflow occurred, then the wrapped value is returned. Negating
+    /// any nonzero [`UBig`] overflows.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::arithmetic::traits::OverflowingNeg;
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(UBig::<2>::ZERO.overflowing_neg(), (UBig::ZERO, false));
+    /// assert_eq!(UBig::<2>::ONE.overflowing_neg(), (UBig::MAX, true));
+    /// ```
+    #[inline]
+    fn overflowing_neg(mut self) -> (UBig<N>, bool) {
+        let nonzero = limbs_neg_in_place(&mut self.0);
+        (self, nonzero)
+    }
+}
+
+impl<const N: usize> OverflowingNeg for IBig<N> {
+    type Output = IBig<N>;
+
+    /// Negates an [`IBig`].
+    ///
+    /// Returns a tuple containing the result and a boolean indicating whether an arithmetic
+    /// overflow occurred. If an overflow occurred, then the wrapped value is returned. Only
+    /// negating the minimum value overflows.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Min;
+    /// use malachite_base::num::arithmetic::traits::OverflowingNeg;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!(IBig::<2>::from(123).overflowing_neg(), (IBig::from(-123), false));
+    /// assert_eq!(IBig::<2>::MIN.overflowing_neg(), (IBig::MIN, true));
+    /// ```
+    #[inline]
+    fn overflowing_neg(mut self) -> (IBig<N>, bool) {
+        let x_negative = self.is_negative();
+        limbs_neg_in_place(&mut self.0);
+        // Only the minimum value is negative both before and after negation.
+        (self, x_negative && self.is_negative())
+    }
+}
+
+macro_rules! impl_neg_common {
+    ($t: ident) => {
+        impl<const N: usize> OverflowingNegAssign for $t<N> {
+            #[doc = concat!("Negates a [`", stringify!($t), "`] in place.")]
+            ///
+            /// Returns a boolean indicating whether an arithmetic overflow occurred. If an
+            /// overflow occurred, then the wrapped value is assigned.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn overflowing_neg_assign(&mut self) -> bool {
+                let overflow;
+                (*self, overflow) = self.overflowing_neg();
+                overflow
+            }
+        }
+
+        impl<const N: usize> WrappingNeg for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Negates a [`", stringify!($t), "`], wrapping around at the ",
+                "boundary of the type.")]
+            ///
+            /// $f(x) = y$, where $y \equiv -x \mod 2^{NW}$ and $W$ is the width of a limb.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::comparison::traits::{Max, Min};
+            /// use malachite_base::num::arithmetic::traits::WrappingNeg;
+            /// use malachite_base::num::basic::traits::One;
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                "assert_eq!(", stringify!($t), "::<2>::MAX.wrapping_neg(), ", stringify!($t),
+                "::MIN + ", stringify!($t), "::ONE);"
+            )]
+            /// ```
+            #[inline]
+            fn wrapping_neg(self) -> $t<N> {
+                self.overflowing_neg().0
+            }
+        }
+
+        impl<const N: usize> WrappingNegAssign for $t<N> {
+            #[doc = concat!("Negates a [`", stringify!($t), "`] in place, wrapping around at ",
+                "the boundary of the type.")]
+            ///
+            /// $x \gets y$, where $y \equiv -x \mod 2^{NW}$ and $W$ is the width of a limb.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn wrapping_neg_assign(&mut self) {
+                limbs_neg_in_place(&mut self.0);
+            }
+        }
+
+        impl<const N: usize> CheckedNeg for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Negates a [`", stringify!($t), "`], returning `None` if the ",
+                "result is not representable.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::arithmetic::traits::CheckedNeg;
+            /// use malachite_base::num::basic::traits::{One, Zero};
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                "assert_eq!(", stringify!($t), "::<2>::ZERO.checked_neg(), Some(",
+                stringify!($t), "::ZERO));"
+            )]
+            /// ```
+            #[inline]
+            fn checked_neg(self) -> Option<$t<N>> {
+                match self.overflowing_neg() {
+                    (negative, false) => Some(negative),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+impl_neg_common!(UBig);
+impl_neg_common!(IBig);
+
+impl<const N: usize> Neg for IBig<N> {
+    type Output = IBig<N>;
+
+    /// Negates an [`IBig`].
+    ///
+    /// $f(x) = -x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` is the minimum value.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!((-IBig::<2>::from(123)).to_string(), "-123");
+    /// assert_eq!((-IBig::<2>::from(-123)).to_string(), "123");
+    /// ```
+    #[inline]
+    fn neg(self) -> IBig<N> {
+        let (negative, overflow) = self.overflowing_neg();
+        assert!(!overflow, "attempt to negate with overflow");
+        negative
+    }
+}
+
+impl<const N: usize> NegAssign for IBig<N> {
+    /// Negates an [`IBig`] in place.
+    ///
+    /// $x \gets -x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Panics
+    /// Panics if `self` is the minimum value.
+    #[inline]
+    fn neg_assign(&mut self) {
+        *self = -*self;
+    }
+}
+
+impl<const N: usize> SaturatingNeg for IBig<N> {
+    type Output = IBig<N>;
+
+    /// Negates an [`IBig`], saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::{Max, Min};
+    /// use malachite_base::num::arithmetic::traits::SaturatingNeg;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!(IBig::<2>::from(-123).saturating_neg(), IBig::from(123));
+    /// assert_eq!(IBig::<2>::MIN.saturating_neg(), IBig::MAX);
+    /// ```
+    #[inline]
+    fn saturating_neg(self) -> IBig<N> {
+        match self.overflowing_neg() {
+            (negative, false) => negative,
+            _ => IBig::MAX,
+        }
+    }
+}
+
+impl<const N: usize> SaturatingNegAssign for IBig<N> {
+    /// Negates an [`IBig`] in place, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    #[inline]
+    fn saturating_neg_assign(&mut self) {
+        *self = self.saturating_neg();
+    }
+}
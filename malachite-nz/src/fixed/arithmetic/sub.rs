@@ -0,0 +1,308 @@
+use crate::fixed::{IBig, UBig};
+use crate::natural::arithmetic::sub::limbs_sub_same_length_to_out;
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedSub, OverflowingSub, OverflowingSubAssign, SaturatingSub, SaturatingSubAssign,
+    WrappingSub, WrappingSubAssign,
+};
+use std::ops::{Sub, SubAssign};
+
+impl<const N: usize> OverflowingSub<UBig<N>> for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Subtracts a [`UBig`] by another [`UBig`].
+    ///
+    /// Returns a tuple containing the result and a boolean indicating whether an arithmetic
+    /// overflow occurred. If an overflow occurred, then the wrapped value is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::arithmetic::traits::OverflowingSub;
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(
+    ///     UBig::<2>::from(456u32).overflowing_sub(UBig::from(123u32)),
+    ///     (UBig::from(333u32), false)
+    /// );
+    /// assert_eq!(UBig::<2>::ZERO.overflowing_sub(UBig::ONE), (UBig::MAX, true));
+    /// ```
+    fn overflowing_sub(self, other: UBig<N>) -> (UBig<N>, bool) {
+        let mut out = [0; N];
+        let borrow = limbs_sub_same_length_to_out(&mut out, &self.0, &other.0);
+        (UBig(out), borrow)
+    }
+}
+
+impl<const N: usize> OverflowingSub<IBig<N>> for IBig<N> {
+    type Output = IBig<N>;
+
+    /// Subtracts an [`IBig`] by another [`IBig`].
+    ///
+    /// Returns a tuple containing the result and a boolean indicating whether an arithmetic
+    /// overflow occurred. If an overflow occurred, then the wrapped value is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::{Max, Min};
+    /// use malachite_base::num::arithmetic::traits::OverflowingSub;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!(
+    ///     IBig::<2>::from(123).overflowing_sub(IBig::from(456)),
+    ///     (IBig::from(-333), false)
+    /// );
+    /// assert_eq!(IBig::<2>::MIN.overflowing_sub(IBig::ONE), (IBig::MAX, true));
+    /// ```
+    fn overflowing_sub(self, other: IBig<N>) -> (IBig<N>, bool) {
+        let mut out = [0; N];
+        limbs_sub_same_length_to_out(&mut out, &self.0, &other.0);
+        let out = IBig(out);
+        let x_negative = self.is_negative();
+        (
+            out,
+            x_negative != other.is_negative() && out.is_negative() != x_negative,
+        )
+    }
+}
+
+macro_rules! impl_sub {
+    ($t: ident, $saturated: expr) => {
+        impl<const N: usize> Sub<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Subtracts a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`].")]
+            ///
+            /// $f(x, y) = x - y$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::num::basic::traits::{One, Two};
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!((", stringify!($t), "::<2>::TWO - ", stringify!($t),
+                        "::ONE).to_string(), \"1\");"
+                    )]
+            /// ```
+            #[inline]
+            fn sub(self, other: $t<N>) -> $t<N> {
+                let (difference, overflow) = self.overflowing_sub(other);
+                assert!(!overflow, "attempt to subtract with overflow");
+                difference
+            }
+        }
+
+        impl<const N: usize> SubAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Subtracts a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`] in place.")]
+            ///
+            /// $x \gets x - y$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable.
+            #[inline]
+            fn sub_assign(&mut self, other: $t<N>) {
+                *self = *self - other;
+            }
+        }
+
+        impl<const N: usize> OverflowingSubAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Subtracts a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`] in place.")]
+            ///
+            /// Returns a boolean indicating whether an arithmetic overflow occurred. If an overflow
+            /// occurred, then the wrapped value is assigned.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn overflowing_sub_assign(&mut self, other: $t<N>) -> bool {
+                let overflow;
+                (*self, overflow) = self.overflowing_sub(other);
+                overflow
+            }
+        }
+
+        impl<const N: usize> WrappingSub<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Subtracts a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`], wrapping around at the boundary of the type.")]
+            ///
+            /// $f(x, y) = z$, where $z \equiv x - y \mod 2^{NW}$ and $W$ is the width of a limb.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::comparison::traits::{Max, Min};
+            /// use malachite_base::num::arithmetic::traits::WrappingSub;
+            /// use malachite_base::num::basic::traits::One;
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!(", stringify!($t), "::<2>::MIN.wrapping_sub(", stringify!($t),
+                        "::ONE), ", stringify!($t), "::MAX);"
+                    )]
+            /// ```
+            #[inline]
+            fn wrapping_sub(self, other: $t<N>) -> $t<N> {
+                self.overflowing_sub(other).0
+            }
+        }
+
+        impl<const N: usize> WrappingSubAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Subtracts a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`] in place, wrapping around at the boundary of the type.")]
+            ///
+            /// $x \gets z$, where $z \equiv x - y \mod 2^{NW}$ and $W$ is the width of a limb.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn wrapping_sub_assign(&mut self, other: $t<N>) {
+                *self = self.overflowing_sub(other).0;
+            }
+        }
+
+        impl<const N: usize> CheckedSub<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Subtracts a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`], returning `None` if the result is not representable.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::comparison::traits::Min;
+            /// use malachite_base::num::arithmetic::traits::CheckedSub;
+            /// use malachite_base::num::basic::traits::One;
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!(", stringify!($t), "::<2>::MIN.checked_sub(", stringify!($t),
+                        "::ONE), None);"
+                    )]
+            /// ```
+            #[inline]
+            fn checked_sub(self, other: $t<N>) -> Option<$t<N>> {
+                match self.overflowing_sub(other) {
+                    (difference, false) => Some(difference),
+                    _ => None,
+                }
+            }
+        }
+
+        impl<const N: usize> SaturatingSub<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Subtracts a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`], saturating at the numeric bounds instead of overflowing.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_base::comparison::traits::Min;
+            /// use malachite_base::num::arithmetic::traits::SaturatingSub;
+            /// use malachite_base::num::basic::traits::One;
+            #[doc = concat!("use malachite_nz::fixed::", stringify!($t), ";")]
+            ///
+            #[doc = concat!(
+                        "assert_eq!(", stringify!($t), "::<2>::MIN.saturating_sub(", stringify!($t),
+                        "::ONE), ", stringify!($t), "::MIN);"
+                    )]
+            /// ```
+            #[inline]
+            fn saturating_sub(self, other: $t<N>) -> $t<N> {
+                match self.overflowing_sub(other) {
+                    (difference, false) => difference,
+                    _ => $saturated(self),
+                }
+            }
+        }
+
+        impl<const N: usize> SaturatingSubAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Subtracts a [`", stringify!($t), "`] by another [`", stringify!($t),
+                        "`] in place, saturating at the numeric bounds instead of overflowing.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn saturating_sub_assign(&mut self, other: $t<N>) {
+                *self = self.saturating_sub(other);
+            }
+        }
+    };
+}
+impl_sub!(UBig, |_| UBig::MIN);
+// Subtracting two `IBig`s can only overflow if they have different signs, so the sign of the first
+// determines the bound.
+impl_sub!(IBig, |x: IBig<N>| if x.is_negative() {
+    IBig::MIN
+} else {
+    IBig::MAX
+});
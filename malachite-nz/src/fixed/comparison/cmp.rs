@@ -0,0 +1,76 @@
+use crate::fixed::{IBig, UBig};
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use std::cmp::Ordering;
+
+impl<const N: usize> PartialOrd for UBig<N> {
+    /// Compares two [`UBig`]s.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &UBig<N>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for UBig<N> {
+    /// Compares two [`UBig`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert!(UBig::<2>::from(123u32) > UBig::from(122u32));
+    /// assert!(UBig::<2>::ZERO < UBig::MAX);
+    /// ```
+    fn cmp(&self, other: &UBig<N>) -> Ordering {
+        limbs_cmp_same_length(&self.0, &other.0)
+    }
+}
+
+impl<const N: usize> PartialOrd for IBig<N> {
+    /// Compares two [`IBig`]s.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &IBig<N>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for IBig<N> {
+    /// Compares two [`IBig`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::{Max, Min};
+    /// use malachite_base::num::basic::traits::NegativeOne;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert!(IBig::<2>::from(123) > IBig::from(-123));
+    /// assert!(IBig::<2>::NEGATIVE_ONE > IBig::MIN);
+    /// assert!(IBig::<2>::NEGATIVE_ONE < IBig::MAX);
+    /// ```
+    fn cmp(&self, other: &IBig<N>) -> Ordering {
+        // Two's complement values with the same sign are ordered like their limbs.
+        other
+            .is_negative()
+            .cmp(&self.is_negative())
+            .then_with(|| limbs_cmp_same_length(&self.0, &other.0))
+    }
+}
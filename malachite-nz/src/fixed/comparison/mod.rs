@@ -0,0 +1,2 @@
+/// Comparison of [`UBig`](crate::fixed::UBig)s and of [`IBig`](crate::fixed::IBig)s.
+pub mod cmp;
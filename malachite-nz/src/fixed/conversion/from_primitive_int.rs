@@ -0,0 +1,49 @@
+use crate::fixed::{IBig, UBig};
+use crate::platform::Limb;
+use malachite_base::num::conversion::traits::WrappingFrom;
+
+impl<const N: usize> From<u32> for UBig<N> {
+    /// Converts a [`u32`] to a [`UBig`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(UBig::<2>::from(123u32).to_string(), "123");
+    /// ```
+    #[inline]
+    fn from(u: u32) -> UBig<N> {
+        UBig::from_limb(Limb::from(u))
+    }
+}
+
+impl<const N: usize> From<i32> for IBig<N> {
+    /// Converts an [`i32`] to an [`IBig`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!(IBig::<2>::from(123).to_string(), "123");
+    /// assert_eq!(IBig::<2>::from(-123).to_string(), "-123");
+    /// ```
+    fn from(i: i32) -> IBig<N> {
+        let mut xs = [if i < 0 { Limb::MAX } else { 0 }; N];
+        xs[0] = Limb::wrapping_from(i);
+        IBig(xs)
+    }
+}
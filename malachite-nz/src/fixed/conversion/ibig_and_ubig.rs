@@ -0,0 +1,110 @@
+use crate::fixed::{IBig, UBig};
+use malachite_base::num::conversion::traits::WrappingFrom;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UBigFromIBigError;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IBigFromUBigError;
+
+impl<const N: usize> TryFrom<IBig<N>> for UBig<N> {
+    type Error = UBigFromIBigError;
+
+    /// Converts an [`IBig`] to a [`UBig`] of the same width, returning an error if the [`IBig`] is
+    /// negative.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::{IBig, UBig};
+    ///
+    /// assert_eq!(UBig::try_from(IBig::<2>::from(123)).unwrap().to_string(), "123");
+    /// assert!(UBig::try_from(IBig::<2>::from(-123)).is_err());
+    /// ```
+    #[inline]
+    fn try_from(value: IBig<N>) -> Result<UBig<N>, UBigFromIBigError> {
+        if value.is_negative() {
+            Err(UBigFromIBigError)
+        } else {
+            Ok(UBig(value.0))
+        }
+    }
+}
+
+impl<const N: usize> WrappingFrom<IBig<N>> for UBig<N> {
+    /// Converts an [`IBig`] to a [`UBig`] of the same width, wrapping modulo $2^{NW}$, where $W$
+    /// is the width of a limb. The bits are reinterpreted, as with an `as` cast between primitive
+    /// integers.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::basic::traits::NegativeOne;
+    /// use malachite_base::num::conversion::traits::WrappingFrom;
+    /// use malachite_nz::fixed::{IBig, UBig};
+    ///
+    /// assert_eq!(UBig::wrapping_from(IBig::<2>::from(123)).to_string(), "123");
+    /// assert_eq!(UBig::wrapping_from(IBig::<2>::NEGATIVE_ONE), UBig::MAX);
+    /// ```
+    #[inline]
+    fn wrapping_from(value: IBig<N>) -> UBig<N> {
+        UBig(value.0)
+    }
+}
+
+impl<const N: usize> TryFrom<UBig<N>> for IBig<N> {
+    type Error = IBigFromUBigError;
+
+    /// Converts a [`UBig`] to an [`IBig`] of the same width, returning an error if the [`UBig`] is
+    /// too large.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_nz::fixed::{IBig, UBig};
+    ///
+    /// assert_eq!(IBig::try_from(UBig::<2>::from(123u32)).unwrap().to_string(), "123");
+    /// assert!(IBig::try_from(UBig::<2>::MAX).is_err());
+    /// ```
+    #[inline]
+    fn try_from(value: UBig<N>) -> Result<IBig<N>, IBigFromUBigError> {
+        let x = IBig(value.0);
+        if x.is_negative() {
+            Err(IBigFromUBigError)
+        } else {
+            Ok(x)
+        }
+    }
+}
+
+impl<const N: usize> WrappingFrom<UBig<N>> for IBig<N> {
+    /// Converts a [`UBig`] to an [`IBig`] of the same width, wrapping modulo $2^{NW}$, where $W$
+    /// is the width of a limb. The bits are reinterpreted, as with an `as` cast between primitive
+    /// integers.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::basic::traits::NegativeOne;
+    /// use malachite_base::num::conversion::traits::WrappingFrom;
+    /// use malachite_nz::fixed::{IBig, UBig};
+    ///
+    /// assert_eq!(IBig::wrapping_from(UBig::<2>::from(123u32)).to_string(), "123");
+    /// assert_eq!(IBig::wrapping_from(UBig::<2>::MAX), IBig::NEGATIVE_ONE);
+    /// ```
+    #[inline]
+    fn wrapping_from(value: UBig<N>) -> IBig<N> {
+        IBig(value.0)
+    }
+}
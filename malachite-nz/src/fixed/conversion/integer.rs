@@ -0,0 +1,133 @@
+use crate::fixed::IBig;
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::IsPowerOf2;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, WrappingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IBigFromIntegerError;
+
+impl<const N: usize> From<IBig<N>> for Integer {
+    /// Converts an [`IBig`] to an [`Integer`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Min;
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_nz::fixed::IBig;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(IBig::<2>::from(-123)), -123);
+    /// assert_eq!(
+    ///     Integer::from(IBig::<2>::MIN),
+    ///     -Integer::power_of_2(IBig::<2>::WIDTH - 1)
+    /// );
+    /// ```
+    #[inline]
+    fn from(x: IBig<N>) -> Integer {
+        Integer::from_twos_complement_limbs_asc(&x.0)
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a Integer> for IBig<N> {
+    type Error = IBigFromIntegerError;
+
+    /// Converts an [`Integer`] to an [`IBig`], returning an error if the [`Integer`] is out of
+    /// range.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::fixed::IBig;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(IBig::<1>::try_from(&Integer::from(-123)).unwrap().to_string(), "-123");
+    /// assert!(IBig::<1>::try_from(&-Integer::from(10u32).pow(20)).is_err());
+    /// assert!(IBig::<3>::try_from(&-Integer::from(10u32).pow(20)).is_ok());
+    /// ```
+    fn try_from(value: &'a Integer) -> Result<IBig<N>, IBigFromIntegerError> {
+        if IBig::<N>::convertible_from(value) {
+            Ok(IBig::wrapping_from(value))
+        } else {
+            Err(IBigFromIntegerError)
+        }
+    }
+}
+
+impl<'a, const N: usize> WrappingFrom<&'a Integer> for IBig<N> {
+    /// Converts an [`Integer`] to an [`IBig`], wrapping modulo $2^{NW}$, where $W$ is the width of
+    /// a limb.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::conversion::traits::WrappingFrom;
+    /// use malachite_nz::fixed::IBig;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(IBig::<1>::wrapping_from(&Integer::from(-123)).to_string(), "-123");
+    /// assert_eq!(
+    ///     IBig::<1>::wrapping_from(&(Integer::power_of_2(IBig::<1>::WIDTH) - Integer::from(5u32)))
+    ///         .to_string(),
+    ///     "-5"
+    /// );
+    /// ```
+    fn wrapping_from(value: &'a Integer) -> IBig<N> {
+        let limbs = value.twos_complement_limbs();
+        let mut xs = [0; N];
+        for (i, x) in xs.iter_mut().enumerate() {
+            *x = limbs.get(u64::wrapping_from(i));
+        }
+        IBig(xs)
+    }
+}
+
+impl<'a, const N: usize> ConvertibleFrom<&'a Integer> for IBig<N> {
+    /// Determines whether an [`Integer`] can be converted to an [`IBig`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::conversion::traits::ConvertibleFrom;
+    /// use malachite_nz::fixed::IBig;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let width = IBig::<1>::WIDTH;
+    /// assert!(IBig::<1>::convertible_from(&Integer::from(-123)));
+    /// assert!(!IBig::<1>::convertible_from(&Integer::power_of_2(width - 1)));
+    /// assert!(IBig::<1>::convertible_from(&-Integer::power_of_2(width - 1)));
+    /// ```
+    fn convertible_from(value: &'a Integer) -> bool {
+        let abs = value.unsigned_abs_ref();
+        let bits = abs.significant_bits();
+        bits < IBig::<N>::WIDTH || *value < 0 && bits == IBig::<N>::WIDTH && abs.is_power_of_2()
+    }
+}
@@ -0,0 +1,103 @@
+use crate::fixed::{IBig, UBig};
+use crate::platform::Limb;
+
+impl<const N: usize> UBig<N> {
+    /// Creates a [`UBig`] from its [limbs](crate#limbs), in ascending order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::integers::PrimitiveInt;
+    /// use malachite_nz::fixed::UBig;
+    /// use malachite_nz::platform::Limb;
+    ///
+    /// if Limb::WIDTH == u32::WIDTH {
+    ///     // 10^12 = 232 * 2^32 + 3567587328
+    ///     assert_eq!(UBig::from_limbs_asc([3567587328, 232]).to_string(), "1000000000000");
+    /// }
+    /// ```
+    #[inline]
+    pub const fn from_limbs_asc(xs: [Limb; N]) -> UBig<N> {
+        UBig(xs)
+    }
+
+    /// Returns the [limbs](crate#limbs) of a [`UBig`], in ascending order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::integers::PrimitiveInt;
+    /// use malachite_nz::fixed::UBig;
+    /// use malachite_nz::platform::Limb;
+    /// use std::str::FromStr;
+    ///
+    /// if Limb::WIDTH == u32::WIDTH {
+    ///     // 10^12 = 232 * 2^32 + 3567587328
+    ///     assert_eq!(
+    ///         UBig::<3>::from_str("1000000000000").unwrap().to_limbs_asc(),
+    ///         [3567587328, 232, 0]
+    ///     );
+    /// }
+    /// ```
+    #[inline]
+    pub const fn to_limbs_asc(self) -> [Limb; N] {
+        self.0
+    }
+}
+
+impl<const N: usize> IBig<N> {
+    /// Creates an [`IBig`] from its [limbs](crate#limbs), in ascending order. The limbs are
+    /// interpreted in two's complement, so the value is negative exactly when the highest bit of
+    /// the last limb is set.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::integers::PrimitiveInt;
+    /// use malachite_nz::fixed::IBig;
+    /// use malachite_nz::platform::Limb;
+    ///
+    /// if Limb::WIDTH == u32::WIDTH {
+    ///     // -10^12 = -233 * 2^32 + 727379968
+    ///     assert_eq!(
+    ///         IBig::from_twos_complement_limbs_asc([727379968, 4294967063]).to_string(),
+    ///         "-1000000000000"
+    ///     );
+    /// }
+    /// ```
+    #[inline]
+    pub const fn from_twos_complement_limbs_asc(xs: [Limb; N]) -> IBig<N> {
+        IBig(xs)
+    }
+
+    /// Returns the [limbs](crate#limbs) of an [`IBig`] in two's complement, in ascending order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::integers::PrimitiveInt;
+    /// use malachite_nz::fixed::IBig;
+    /// use malachite_nz::platform::Limb;
+    /// use std::str::FromStr;
+    ///
+    /// if Limb::WIDTH == u32::WIDTH {
+    ///     // -10^12 = -233 * 2^32 + 727379968
+    ///     assert_eq!(
+    ///         IBig::<3>::from_str("-1000000000000").unwrap().to_twos_complement_limbs_asc(),
+    ///         [727379968, 4294967063, 4294967295]
+    ///     );
+    /// }
+    /// ```
+    #[inline]
+    pub const fn to_twos_complement_limbs_asc(self) -> [Limb; N] {
+        self.0
+    }
+}
@@ -0,0 +1,18 @@
+/// Implementations of traits for converting a primitive integer to a
+/// [`UBig`](crate::fixed::UBig) or an [`IBig`](crate::fixed::IBig).
+pub mod from_primitive_int;
+/// Conversions between [`UBig`](crate::fixed::UBig)s and [`IBig`](crate::fixed::IBig)s of the
+/// same width.
+pub mod ibig_and_ubig;
+/// Conversions between [`IBig`](crate::fixed::IBig)s and [`Integer`](crate::integer::Integer)s.
+pub mod integer;
+/// Functions for constructing a [`UBig`](crate::fixed::UBig) or an
+/// [`IBig`](crate::fixed::IBig) from limbs, and for extracting its limbs.
+pub mod limbs;
+/// Conversions between [`UBig`](crate::fixed::UBig)s and [`Natural`](crate::natural::Natural)s.
+pub mod natural;
+/// Implementations of [`Display`](std::fmt::Display), [`Debug`](std::fmt::Debug), and
+/// [`FromStr`](std::str::FromStr) for [`UBig`](crate::fixed::UBig)s and
+/// [`IBig`](crate::fixed::IBig)s, and of the hexadecimal, octal, and binary formatting traits for
+/// [`UBig`](crate::fixed::UBig)s.
+pub mod string;
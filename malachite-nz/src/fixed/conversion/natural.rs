@@ -0,0 +1,120 @@
+use crate::fixed::UBig;
+use crate::natural::Natural;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, WrappingFrom};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UBigFromNaturalError;
+
+impl<const N: usize> From<UBig<N>> for Natural {
+    /// Converts a [`UBig`] to a [`Natural`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_nz::fixed::UBig;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::platform::Limb;
+    ///
+    /// assert_eq!(Natural::from(UBig::<2>::from(123u32)), 123);
+    /// assert_eq!(Natural::from(UBig::<4>::MAX), Natural::from_limbs_asc(&[Limb::MAX; 4]));
+    /// ```
+    #[inline]
+    fn from(x: UBig<N>) -> Natural {
+        Natural::from_limbs_asc(&x.0)
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a Natural> for UBig<N> {
+    type Error = UBigFromNaturalError;
+
+    /// Converts a [`Natural`] to a [`UBig`], returning an error if the [`Natural`] is too large.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::fixed::UBig;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(UBig::<1>::try_from(&Natural::from(123u32)).unwrap().to_string(), "123");
+    /// assert!(UBig::<1>::try_from(&Natural::from(10u32).pow(20)).is_err());
+    /// assert!(UBig::<3>::try_from(&Natural::from(10u32).pow(20)).is_ok());
+    /// ```
+    fn try_from(value: &'a Natural) -> Result<UBig<N>, UBigFromNaturalError> {
+        if UBig::<N>::convertible_from(value) {
+            Ok(UBig::wrapping_from(value))
+        } else {
+            Err(UBigFromNaturalError)
+        }
+    }
+}
+
+impl<'a, const N: usize> WrappingFrom<&'a Natural> for UBig<N> {
+    /// Converts a [`Natural`] to a [`UBig`], wrapping modulo $2^{NW}$, where $W$ is the width of a
+    /// limb.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::conversion::traits::WrappingFrom;
+    /// use malachite_nz::fixed::UBig;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(UBig::<1>::wrapping_from(&Natural::from(123u32)).to_string(), "123");
+    /// assert_eq!(
+    ///     UBig::<1>::wrapping_from(&(Natural::power_of_2(UBig::<1>::WIDTH) + Natural::from(5u32)))
+    ///         .to_string(),
+    ///     "5"
+    /// );
+    /// ```
+    fn wrapping_from(value: &'a Natural) -> UBig<N> {
+        let limbs = value.limbs();
+        let mut xs = [0; N];
+        for (i, x) in xs.iter_mut().enumerate() {
+            *x = limbs[i];
+        }
+        UBig(xs)
+    }
+}
+
+impl<'a, const N: usize> ConvertibleFrom<&'a Natural> for UBig<N> {
+    /// Determines whether a [`Natural`] can be converted to a [`UBig`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::conversion::traits::ConvertibleFrom;
+    /// use malachite_nz::fixed::UBig;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(UBig::<1>::convertible_from(&Natural::from(123u32)));
+    /// assert!(!UBig::<1>::convertible_from(&Natural::from(10u32).pow(20)));
+    /// ```
+    #[inline]
+    fn convertible_from(value: &'a Natural) -> bool {
+        value.limb_count() <= u64::wrapping_from(N)
+    }
+}
@@ -0,0 +1,214 @@
+use crate::fixed::{IBig, UBig};
+use crate::integer::Integer;
+use crate::natural::Natural;
+use std::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, Result, UpperHex};
+use std::str::FromStr;
+
+impl<const N: usize> Display for UBig<N> {
+    /// Converts a [`UBig`] to a [`String`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(UBig::<2>::ZERO.to_string(), "0");
+    /// assert_eq!(UBig::<2>::from(123u32).to_string(), "123");
+    /// assert_eq!(format!("{:05}", UBig::<2>::from(123u32)), "00123");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(&Natural::from(*self), f)
+    }
+}
+
+impl<const N: usize> Debug for UBig<N> {
+    /// Converts a [`UBig`] to a [`String`].
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(UBig::<2>::from(123u32).to_debug_string(), "123");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+macro_rules! impl_radix_fmt {
+    ($trait: ident, $name: expr, $example: expr) => {
+        impl<const N: usize> $trait for UBig<N> {
+            #[doc = concat!("Converts a [`UBig`] to a ", $name, " [`String`].")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            /// use malachite_nz::fixed::UBig;
+            ///
+            #[doc = $example]
+            /// ```
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                $trait::fmt(&Natural::from(*self), f)
+            }
+        }
+    };
+}
+impl_radix_fmt!(
+    Binary,
+    "binary",
+    "assert_eq!(format!(\"{:#b}\", UBig::<2>::from(123u32)), \"0b1111011\");"
+);
+impl_radix_fmt!(
+    Octal,
+    "octal",
+    "assert_eq!(format!(\"{:#o}\", UBig::<2>::from(123u32)), \"0o173\");"
+);
+impl_radix_fmt!(
+    LowerHex,
+    "hexadecimal",
+    "assert_eq!(format!(\"{:#x}\", UBig::<2>::from(123u32)), \"0x7b\");"
+);
+impl_radix_fmt!(
+    UpperHex,
+    "hexadecimal",
+    "assert_eq!(format!(\"{:#X}\", UBig::<2>::from(123u32)), \"0x7B\");"
+);
+
+impl<const N: usize> FromStr for UBig<N> {
+    type Err = ();
+
+    /// Converts a string to a [`UBig`].
+    ///
+    /// If the string does not represent a valid [`UBig`], an `Err` is returned. To be valid, the
+    /// string must be nonempty, only contain the [`char`]s `'0'` through `'9'`, and represent a
+    /// value less than $2^{NW}$, where $W$ is the width of a limb. Leading zeros are allowed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::UBig;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(UBig::<2>::from_str("123456").unwrap().to_string(), "123456");
+    /// assert!(UBig::<1>::from_str("100000000000000000000").is_err());
+    /// assert!(UBig::<2>::from_str("-5").is_err());
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> std::result::Result<UBig<N>, ()> {
+        UBig::try_from(&Natural::from_str(s)?).map_err(|_| ())
+    }
+}
+
+impl<const N: usize> Display for IBig<N> {
+    /// Converts an [`IBig`] to a [`String`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!(IBig::<2>::ZERO.to_string(), "0");
+    /// assert_eq!(IBig::<2>::from(123).to_string(), "123");
+    /// assert_eq!(IBig::<2>::from(-123).to_string(), "-123");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(&Integer::from(*self), f)
+    }
+}
+
+impl<const N: usize> Debug for IBig<N> {
+    /// Converts an [`IBig`] to a [`String`].
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!(IBig::<2>::from(-123).to_debug_string(), "-123");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<const N: usize> FromStr for IBig<N> {
+    type Err = ();
+
+    /// Converts a string to an [`IBig`].
+    ///
+    /// If the string does not represent a valid [`IBig`], an `Err` is returned. To be valid, the
+    /// string must be nonempty, only contain the [`char`]s `'0'` through `'9'`, except for an
+    /// optional leading `'-'`, and represent a value in $[-2^{NW-1}, 2^{NW-1})$, where $W$ is the
+    /// width of a limb. Leading zeros are allowed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::IBig;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(IBig::<2>::from_str("-123456").unwrap().to_string(), "-123456");
+    /// assert!(IBig::<1>::from_str("-100000000000000000000").is_err());
+    /// assert!(IBig::<2>::from_str("--5").is_err());
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> std::result::Result<IBig<N>, ()> {
+        IBig::try_from(&Integer::from_str(s)?).map_err(|_| ())
+    }
+}
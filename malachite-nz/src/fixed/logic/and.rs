@@ -0,0 +1,59 @@
+use crate::fixed::{IBig, UBig};
+use std::ops::{BitAnd, BitAndAssign};
+
+macro_rules! impl_and {
+    ($t: ident, $example: expr) => {
+        impl<const N: usize> BitAnd<$t<N>> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Takes the bitwise and of two [`", stringify!($t), "`]s.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = $example]
+            /// ```
+            #[inline]
+            fn bitand(mut self, other: $t<N>) -> $t<N> {
+                self &= other;
+                self
+            }
+        }
+
+        impl<const N: usize> BitAndAssign<$t<N>> for $t<N> {
+            #[doc = concat!("Takes the bitwise and of two [`", stringify!($t), "`]s, in place.")]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            fn bitand_assign(&mut self, other: $t<N>) {
+                for (x, y) in self.0.iter_mut().zip(other.0.iter()) {
+                    *x &= y;
+                }
+            }
+        }
+    };
+}
+impl_and!(
+    UBig,
+    concat!(
+        "use malachite_nz::fixed::UBig;\n\n",
+        "assert_eq!((UBig::<2>::from(123u32) & UBig::from(456u32)).to_string(), \"72\");"
+    )
+);
+impl_and!(
+    IBig,
+    concat!(
+        "use malachite_nz::fixed::IBig;\n\n",
+        "assert_eq!((IBig::<2>::from(-123) & IBig::from(456)).to_string(), \"384\");"
+    )
+);
@@ -0,0 +1,207 @@
+use crate::fixed::{IBig, UBig};
+use crate::platform::Limb;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::BitAccess;
+
+// Returns the index of the limb containing bit `index`, together with a mask selecting that bit.
+// Assumes that `index` is less than the width.
+fn limb_index_and_mask(index: u64) -> (usize, Limb) {
+    (
+        usize::exact_from(index >> Limb::LOG_WIDTH),
+        1 << (index & Limb::WIDTH_MASK),
+    )
+}
+
+impl<const N: usize> BitAccess for UBig<N> {
+    /// Determines whether the $i$th bit of a [`UBig`], or the coefficient of $2^i$ in its binary
+    /// expansion, is 0 or 1.
+    ///
+    /// `false` means 0 and `true` means 1. Getting bits beyond the width is allowed; those bits are
+    /// false.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::logic::traits::BitAccess;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(UBig::<2>::from(123u32).get_bit(2), false);
+    /// assert_eq!(UBig::<2>::from(123u32).get_bit(3), true);
+    /// assert_eq!(UBig::<2>::from(123u32).get_bit(1000), false);
+    /// ```
+    fn get_bit(&self, index: u64) -> bool {
+        if index >= UBig::<N>::WIDTH {
+            return false;
+        }
+        let (i, mask) = limb_index_and_mask(index);
+        self.0[i] & mask != 0
+    }
+
+    /// Sets the $i$th bit of a [`UBig`], or the coefficient of $2^i$ in its binary expansion, to
+    /// 1.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if $i \geq NW$, where $W$ is the width of a limb.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::num::logic::traits::BitAccess;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// let mut x = UBig::<2>::ZERO;
+    /// x.set_bit(2);
+    /// x.set_bit(5);
+    /// x.set_bit(6);
+    /// assert_eq!(x.to_string(), "100");
+    /// ```
+    fn set_bit(&mut self, index: u64) {
+        if index >= UBig::<N>::WIDTH {
+            panic!(
+                "Cannot set bit {} in non-negative value of width {}",
+                index,
+                UBig::<N>::WIDTH
+            );
+        }
+        let (i, mask) = limb_index_and_mask(index);
+        self.0[i] |= mask;
+    }
+
+    /// Sets the $i$th bit of a [`UBig`], or the coefficient of $2^i$ in its binary expansion, to
+    /// 0.
+    ///
+    /// Clearing bits beyond the width is allowed; since those bits are already false, clearing
+    /// them does nothing.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::logic::traits::BitAccess;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// let mut x = UBig::<2>::from(127u32);
+    /// x.clear_bit(0);
+    /// x.clear_bit(1);
+    /// x.clear_bit(3);
+    /// x.clear_bit(4);
+    /// assert_eq!(x.to_string(), "100");
+    /// ```
+    fn clear_bit(&mut self, index: u64) {
+        if index < UBig::<N>::WIDTH {
+            let (i, mask) = limb_index_and_mask(index);
+            self.0[i] &= !mask;
+        }
+    }
+}
+
+impl<const N: usize> BitAccess for IBig<N> {
+    /// Determines whether the $i$th bit of an [`IBig`], or the coefficient of $2^i$ in its binary
+    /// expansion, is 0 or 1.
+    ///
+    /// Negative numbers are represented in two's complement. Getting bits beyond the width is
+    /// allowed; those bits are false for non-negative numbers and true for negative numbers.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::logic::traits::BitAccess;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!(IBig::<2>::from(123).get_bit(2), false);
+    /// assert_eq!(IBig::<2>::from(123).get_bit(1000), false);
+    /// assert_eq!(IBig::<2>::from(-123).get_bit(2), true);
+    /// assert_eq!(IBig::<2>::from(-123).get_bit(1000), true);
+    /// ```
+    fn get_bit(&self, index: u64) -> bool {
+        if index >= IBig::<N>::WIDTH {
+            return self.is_negative();
+        }
+        let (i, mask) = limb_index_and_mask(index);
+        self.0[i] & mask != 0
+    }
+
+    /// Sets the $i$th bit of an [`IBig`], or the coefficient of $2^i$ in its binary expansion, to
+    /// 1.
+    ///
+    /// Negative numbers are represented in two's complement. Setting bits beyond the width is
+    /// allowed for negative numbers; since those bits are already true, setting them does
+    /// nothing.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if $i \geq NW$, where $W$ is the width of a limb, and `self` is non-negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::logic::traits::BitAccess;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// let mut x = IBig::<2>::from(-0x100);
+    /// x.set_bit(2);
+    /// x.set_bit(5);
+    /// x.set_bit(6);
+    /// assert_eq!(x.to_string(), "-156");
+    /// ```
+    fn set_bit(&mut self, index: u64) {
+        if index < IBig::<N>::WIDTH {
+            let (i, mask) = limb_index_and_mask(index);
+            self.0[i] |= mask;
+        } else if !self.is_negative() {
+            panic!(
+                "Cannot set bit {} in non-negative value of width {}",
+                index,
+                IBig::<N>::WIDTH
+            );
+        }
+    }
+
+    /// Sets the $i$th bit of an [`IBig`], or the coefficient of $2^i$ in its binary expansion, to
+    /// 0.
+    ///
+    /// Negative numbers are represented in two's complement. Clearing bits beyond the width is
+    /// allowed for non-negative numbers; since those bits are already false, clearing them does
+    /// nothing.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if $i \geq NW$, where $W$ is the width of a limb, and `self` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::logic::traits::BitAccess;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// let mut x = IBig::<2>::from(-1);
+    /// x.clear_bit(0);
+    /// x.clear_bit(1);
+    /// x.clear_bit(3);
+    /// x.clear_bit(4);
+    /// assert_eq!(x.to_string(), "-28");
+    /// ```
+    fn clear_bit(&mut self, index: u64) {
+        if index < IBig::<N>::WIDTH {
+            let (i, mask) = limb_index_and_mask(index);
+            self.0[i] &= !mask;
+        } else if self.is_negative() {
+            panic!(
+                "Cannot clear bit {} in negative value of width {}",
+                index,
+                IBig::<N>::WIDTH
+            );
+        }
+    }
+}
@@ -0,0 +1,18 @@
+/// Bitwise and of [`UBig`](crate::fixed::UBig)s or of [`IBig`](crate::fixed::IBig)s.
+pub mod and;
+/// Implementations of [`BitAccess`](malachite_base::num::logic::traits::BitAccess), a trait for
+/// getting and setting individual bits of a number.
+pub mod bit_access;
+/// Bitwise negation of a [`UBig`](crate::fixed::UBig) or an [`IBig`](crate::fixed::IBig).
+pub mod not;
+/// Bitwise or of [`UBig`](crate::fixed::UBig)s or of [`IBig`](crate::fixed::IBig)s.
+pub mod or;
+/// Left-shifting a [`UBig`](crate::fixed::UBig) or an [`IBig`](crate::fixed::IBig).
+pub mod shl;
+/// Right-shifting a [`UBig`](crate::fixed::UBig) or an [`IBig`](crate::fixed::IBig).
+pub mod shr;
+/// Implementations of [`SignificantBits`](malachite_base::num::logic::traits::SignificantBits),
+/// a trait for determining how many significant bits a number has.
+pub mod significant_bits;
+/// Bitwise xor of [`UBig`](crate::fixed::UBig)s or of [`IBig`](crate::fixed::IBig)s.
+pub mod xor;
@@ -0,0 +1,61 @@
+use crate::fixed::{IBig, UBig};
+use crate::natural::logic::not::limbs_not_in_place;
+use std::ops::Not;
+
+impl<const N: usize> Not for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Returns the bitwise negation of a [`UBig`].
+    ///
+    /// $f(n) = 2^{NW} - n - 1$, where $W$ is the width of a limb.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(!UBig::<2>::ZERO, UBig::MAX);
+    /// assert_eq!(!UBig::<2>::MAX, UBig::ZERO);
+    /// ```
+    #[inline]
+    fn not(mut self) -> UBig<N> {
+        limbs_not_in_place(&mut self.0);
+        self
+    }
+}
+
+impl<const N: usize> Not for IBig<N> {
+    type Output = IBig<N>;
+
+    /// Returns the bitwise negation of an [`IBig`].
+    ///
+    /// $f(n) = -n - 1$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!((!IBig::<2>::from(123)).to_string(), "-124");
+    /// assert_eq!((!IBig::<2>::from(-123)).to_string(), "122");
+    /// ```
+    #[inline]
+    fn not(mut self) -> IBig<N> {
+        limbs_not_in_place(&mut self.0);
+        self
+    }
+}
@@ -0,0 +1,91 @@
+use crate::fixed::{IBig, UBig};
+use crate::natural::arithmetic::shl::limbs_shl_to_out;
+use crate::platform::Limb;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+use std::ops::{Shl, ShlAssign};
+
+// Shifts the limbs of a fixed-width number left by `bits`, discarding the bits that are shifted
+// past the highest limb.
+pub(crate) fn limbs_fixed_shl<const N: usize>(xs: &[Limb; N], bits: u64) -> [Limb; N] {
+    let mut out = [0; N];
+    let limbs = bits >> Limb::LOG_WIDTH;
+    if limbs >= u64::wrapping_from(N) {
+        return out;
+    }
+    let limbs = usize::exact_from(limbs);
+    let small_bits = bits & Limb::WIDTH_MASK;
+    if small_bits == 0 {
+        out[limbs..].copy_from_slice(&xs[..N - limbs]);
+    } else {
+        limbs_shl_to_out(&mut out[limbs..], &xs[..N - limbs], small_bits);
+    }
+    out
+}
+
+macro_rules! impl_shl {
+    ($t: ident, $example: expr) => {
+        impl<const N: usize> Shl<u64> for $t<N> {
+            type Output = $t<N>;
+
+            #[doc = concat!("Left-shifts a [`", stringify!($t), "`] (multiplies it by a power of 2).")]
+            ///
+            /// Bits shifted past the highest bit are discarded, so the result is $2^kx$ modulo
+            /// $2^{NW}$, where $W$ is the width of a limb. Unlike with primitive integers, shifting
+            /// by the width or more is allowed and yields zero.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            ///
+            /// # Examples
+            /// ```
+            #[doc = $example]
+            /// ```
+            #[inline]
+            fn shl(self, bits: u64) -> $t<N> {
+                $t(limbs_fixed_shl(&self.0, bits))
+            }
+        }
+
+        impl<const N: usize> ShlAssign<u64> for $t<N> {
+            #[doc = concat!(
+                "Left-shifts a [`",
+                stringify!($t),
+                "`] (multiplies it by a power of 2), in place."
+            )]
+            ///
+            /// See the documentation for the [`Shl`] implementation.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+            #[inline]
+            fn shl_assign(&mut self, bits: u64) {
+                self.0 = limbs_fixed_shl(&self.0, bits);
+            }
+        }
+    };
+}
+impl_shl!(
+    UBig,
+    concat!(
+        "use malachite_base::num::basic::traits::Zero;\n",
+        "use malachite_nz::fixed::UBig;\n\n",
+        "assert_eq!((UBig::<2>::from(123u32) << 10).to_string(), \"125952\");\n",
+        "assert_eq!(UBig::<2>::from(123u32) << UBig::<2>::WIDTH, UBig::ZERO);"
+    )
+);
+impl_shl!(
+    IBig,
+    concat!(
+        "use malachite_nz::fixed::IBig;\n\n",
+        "assert_eq!((IBig::<2>::from(-123) << 10).to_string(), \"-125952\");"
+    )
+);
@@ -0,0 +1,121 @@
+use crate::fixed::{IBig, UBig};
+use crate::natural::arithmetic::shr::limbs_shr_to_out;
+use crate::platform::Limb;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+use std::ops::{Shr, ShrAssign};
+
+// Shifts the limbs of a fixed-width number right by `bits`, filling the vacated high bits with the
+// bits of `fill`, which should be either 0 or `Limb::MAX`.
+fn limbs_fixed_shr<const N: usize>(xs: &[Limb; N], bits: u64, fill: Limb) -> [Limb; N] {
+    let mut out = [fill; N];
+    let limbs = bits >> Limb::LOG_WIDTH;
+    if limbs >= u64::wrapping_from(N) {
+        return out;
+    }
+    let limbs = usize::exact_from(limbs);
+    let small_bits = bits & Limb::WIDTH_MASK;
+    if small_bits == 0 {
+        out[..N - limbs].copy_from_slice(&xs[limbs..]);
+    } else {
+        limbs_shr_to_out(&mut out, &xs[limbs..], small_bits);
+        out[N - limbs - 1] |= fill << (Limb::WIDTH - small_bits);
+    }
+    out
+}
+
+impl<const N: usize> Shr<u64> for UBig<N> {
+    type Output = UBig<N>;
+
+    /// Right-shifts a [`UBig`] (divides it by a power of 2 and takes the floor).
+    ///
+    /// $f(x, k) = \lfloor x/2^k \rfloor$. Unlike with primitive integers, shifting by the width
+    /// or more is allowed and yields zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!((UBig::<2>::from(125952u32) >> 10).to_string(), "123");
+    /// assert_eq!(UBig::<2>::MAX >> (UBig::<2>::WIDTH - 1), UBig::ONE);
+    /// assert_eq!(UBig::<2>::MAX >> UBig::<2>::WIDTH, UBig::ZERO);
+    /// ```
+    #[inline]
+    fn shr(self, bits: u64) -> UBig<N> {
+        UBig(limbs_fixed_shr(&self.0, bits, 0))
+    }
+}
+
+impl<const N: usize> ShrAssign<u64> for UBig<N> {
+    /// Right-shifts a [`UBig`] (divides it by a power of 2 and takes the floor), in place.
+    ///
+    /// See the documentation for the [`Shr`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    #[inline]
+    fn shr_assign(&mut self, bits: u64) {
+        self.0 = limbs_fixed_shr(&self.0, bits, 0);
+    }
+}
+
+impl<const N: usize> Shr<u64> for IBig<N> {
+    type Output = IBig<N>;
+
+    /// Right-shifts an [`IBig`] (divides it by a power of 2 and takes the floor).
+    ///
+    /// $f(x, k) = \lfloor x/2^k \rfloor$. The shift is arithmetic, so the sign is preserved.
+    /// Unlike with primitive integers, shifting by the width or more is allowed and yields 0 or
+    /// -1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::NegativeOne;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!((IBig::<2>::from(-125952) >> 10).to_string(), "-123");
+    /// assert_eq!((IBig::<2>::from(-125953) >> 10).to_string(), "-124");
+    /// assert_eq!(IBig::<2>::from(-5) >> IBig::<2>::WIDTH, IBig::NEGATIVE_ONE);
+    /// ```
+    #[inline]
+    fn shr(self, bits: u64) -> IBig<N> {
+        IBig(limbs_fixed_shr(&self.0, bits, self.sign_limb()))
+    }
+}
+
+impl<const N: usize> ShrAssign<u64> for IBig<N> {
+    /// Right-shifts an [`IBig`] (divides it by a power of 2 and takes the floor), in place.
+    ///
+    /// See the documentation for the [`Shr`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    #[inline]
+    fn shr_assign(&mut self, bits: u64) {
+        self.0 = limbs_fixed_shr(&self.0, bits, self.sign_limb());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::fixed::{IBig, UBig};
+use crate::natural::logic::significant_bits::limbs_significant_bits;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::slices::slice_trailing_zeros;
+
+fn limbs_fixed_significant_bits(xs: &[Limb]) -> u64 {
+    let len = xs.len() - slice_trailing_zeros(xs);
+    if len == 0 {
+        0
+    } else {
+        limbs_significant_bits(&xs[..len])
+    }
+}
+
+impl<const N: usize> SignificantBits for UBig<N> {
+    /// Returns the number of significant bits of a [`UBig`].
+    ///
+    /// $$
+    /// f(n) = \\begin{cases}
+    ///     0 & \text{if} \\quad n = 0, \\\\
+    ///     \lfloor \log_2 n \rfloor + 1 & \text{if} \\quad n > 0.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Max;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::num::logic::traits::SignificantBits;
+    /// use malachite_nz::fixed::UBig;
+    ///
+    /// assert_eq!(UBig::<2>::ZERO.significant_bits(), 0);
+    /// assert_eq!(UBig::<2>::from(100u32).significant_bits(), 7);
+    /// assert_eq!(UBig::<2>::MAX.significant_bits(), UBig::<2>::WIDTH);
+    /// ```
+    #[inline]
+    fn significant_bits(self) -> u64 {
+        limbs_fixed_significant_bits(&self.0)
+    }
+}
+
+impl<const N: usize> SignificantBits for IBig<N> {
+    /// Returns the number of significant bits of the absolute value of an [`IBig`].
+    ///
+    /// $$
+    /// f(n) = \\begin{cases}
+    ///     0 & \text{if} \\quad n = 0, \\\\
+    ///     \lfloor \log_2 |n| \rfloor + 1 & \text{if} \\quad n \neq 0.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::comparison::traits::Min;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::num::logic::traits::SignificantBits;
+    /// use malachite_nz::fixed::IBig;
+    ///
+    /// assert_eq!(IBig::<2>::ZERO.significant_bits(), 0);
+    /// assert_eq!(IBig::<2>::from(-100).significant_bits(), 7);
+    /// assert_eq!(IBig::<2>::MIN.significant_bits(), IBig::<2>::WIDTH);
+    /// ```
+    #[inline]
+    fn significant_bits(self) -> u64 {
+        self.unsigned_abs().significant_bits()
+    }
+}
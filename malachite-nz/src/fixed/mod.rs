@@ -0,0 +1,172 @@
+use crate::platform::Limb;
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::named::Named;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+
+/// An unsigned integer made up of `N` [`Limb`](crate#limbs)s.
+///
+/// Unlike a [`Natural`](crate::natural::Natural), a `UBig` has a fixed width of $NW$ bits, where
+/// $W$ is the width of a limb, and is stored inline as an array. Arithmetic is implemented using
+/// the same limb algorithms as [`Natural`](crate::natural::Natural) and does not allocate unless
+/// `N` is greater than 128 (8192 bits with 64-bit limbs). The arithmetic operators panic on
+/// overflow; the wrapping, overflowing, checked, and saturating traits from `malachite-base`
+/// provide the other behaviors, just as they do for primitive integers.
+///
+/// `N` must be positive.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct UBig<const N: usize>(pub(crate) [Limb; N]);
+
+/// A signed integer made up of `N` [`Limb`](crate#limbs)s, stored in two's complement.
+///
+/// An `IBig` has a fixed width of $NW$ bits, where $W$ is the width of a limb, and is stored
+/// inline as an array. It relates to [`UBig`] the way that a primitive signed integer relates to
+/// the unsigned integer of the same width.
+///
+/// `N` must be positive.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct IBig<const N: usize>(pub(crate) [Limb; N]);
+
+/// The constant 0.
+impl<const N: usize> Zero for UBig<N> {
+    const ZERO: UBig<N> = UBig([0; N]);
+}
+
+/// The constant 1.
+impl<const N: usize> One for UBig<N> {
+    const ONE: UBig<N> = UBig::from_limb(1);
+}
+
+/// The constant 2.
+impl<const N: usize> Two for UBig<N> {
+    const TWO: UBig<N> = UBig::from_limb(2);
+}
+
+/// The minimum value of a [`UBig`], 0.
+impl<const N: usize> Min for UBig<N> {
+    const MIN: UBig<N> = UBig([0; N]);
+}
+
+/// The maximum value of a [`UBig`], $2^{NW}-1$.
+impl<const N: usize> Max for UBig<N> {
+    const MAX: UBig<N> = UBig([Limb::MAX; N]);
+}
+
+impl<const N: usize> Default for UBig<N> {
+    /// The default value of a [`UBig`], 0.
+    fn default() -> UBig<N> {
+        UBig::ZERO
+    }
+}
+
+impl<const N: usize> Named for UBig<N> {
+    /// The name of this type, `"UBig"`.
+    const NAME: &'static str = "UBig";
+}
+
+impl<const N: usize> UBig<N> {
+    /// The width of a [`UBig`], in bits.
+    pub const WIDTH: u64 = (N as u64) << Limb::LOG_WIDTH;
+
+    const fn from_limb(x: Limb) -> UBig<N> {
+        let mut xs = [0; N];
+        xs[0] = x;
+        UBig(xs)
+    }
+}
+
+/// The constant 0.
+impl<const N: usize> Zero for IBig<N> {
+    const ZERO: IBig<N> = IBig([0; N]);
+}
+
+/// The constant 1.
+impl<const N: usize> One for IBig<N> {
+    const ONE: IBig<N> = IBig(UBig::<N>::ONE.0);
+}
+
+/// The constant 2.
+impl<const N: usize> Two for IBig<N> {
+    const TWO: IBig<N> = IBig(UBig::<N>::TWO.0);
+}
+
+/// The constant -1.
+impl<const N: usize> NegativeOne for IBig<N> {
+    const NEGATIVE_ONE: IBig<N> = IBig([Limb::MAX; N]);
+}
+
+/// The minimum value of an [`IBig`], $-2^{NW-1}$.
+impl<const N: usize> Min for IBig<N> {
+    const MIN: IBig<N> = IBig::with_highest_limb(0, 1 << (Limb::WIDTH - 1));
+}
+
+/// The maximum value of an [`IBig`], $2^{NW-1}-1$.
+impl<const N: usize> Max for IBig<N> {
+    const MAX: IBig<N> = IBig::with_highest_limb(Limb::MAX, Limb::MAX >> 1);
+}
+
+impl<const N: usize> Default for IBig<N> {
+    /// The default value of an [`IBig`], 0.
+    fn default() -> IBig<N> {
+        IBig::ZERO
+    }
+}
+
+impl<const N: usize> Named for IBig<N> {
+    /// The name of this type, `"IBig"`.
+    const NAME: &'static str = "IBig";
+}
+
+impl<const N: usize> IBig<N> {
+    /// The width of an [`IBig`], in bits.
+    pub const WIDTH: u64 = (N as u64) << Limb::LOG_WIDTH;
+
+    const fn with_highest_limb(fill: Limb, highest: Limb) -> IBig<N> {
+        let mut xs = [fill; N];
+        xs[N - 1] = highest;
+        IBig(xs)
+    }
+
+    // Returns whether an `IBig` is negative, which is the case exactly when its highest bit is
+    // set.
+    #[inline]
+    pub(crate) const fn is_negative(&self) -> bool {
+        self.0[N - 1] >> (Limb::WIDTH - 1) != 0
+    }
+
+    // Returns the limb that sign-extends an `IBig`: 0 if it is non-negative and `Limb::MAX` if it
+    // is negative.
+    #[inline]
+    pub(crate) const fn sign_limb(&self) -> Limb {
+        if self.is_negative() {
+            Limb::MAX
+        } else {
+            0
+        }
+    }
+}
+
+// Calls `f` with a zeroed slice of `len` limbs. Buffers of up to 512 limbs live on the stack. The
+// largest buffer requested is 4 * N limbs, by modular multiplication, so fixed-width arithmetic
+// only touches the heap when N is greater than 128.
+pub(crate) fn with_scratch<T, F: FnOnce(&mut [Limb]) -> T>(len: usize, f: F) -> T {
+    if len <= 32 {
+        f(&mut [0; 32][..len])
+    } else if len <= 128 {
+        f(&mut [0; 128][..len])
+    } else if len <= 512 {
+        f(&mut [0; 512][..len])
+    } else {
+        f(&mut vec![0; len])
+    }
+}
+
+/// Arithmetic on [`UBig`]s and [`IBig`]s.
+pub mod arithmetic;
+/// Comparison of [`UBig`]s and of [`IBig`]s.
+pub mod comparison;
+/// Conversions between [`UBig`]s, [`IBig`]s, limbs, [`Natural`](crate::natural::Natural)s, and
+/// [`Integer`](crate::integer::Integer)s.
+pub mod conversion;
+/// Bitwise operations and shifts on [`UBig`]s and [`IBig`]s.
+pub mod logic;
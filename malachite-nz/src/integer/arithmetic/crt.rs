@@ -0,0 +1,121 @@
+use crate::integer::Integer;
+use crate::natural::arithmetic::crt::{crt_coprime_helper, crt_helper};
+use crate::natural::Natural;
+
+// Reduces each residue modulo its modulus, so that the result is nonnegative.
+fn reduce_residues(residues: &[Integer], moduli: &[Natural]) -> Vec<Natural> {
+    assert_eq!(
+        residues.len(),
+        moduli.len(),
+        "There must be as many residues as moduli"
+    );
+    residues
+        .iter()
+        .zip(moduli)
+        .map(|(a, m)| {
+            assert_ne!(*m, 0u32, "The moduli must be positive");
+            let r = a.unsigned_abs_ref() % m;
+            if *a < 0u32 && r != 0u32 {
+                m - r
+            } else {
+                r
+            }
+        })
+        .collect()
+}
+
+// Converts a solution in [0, m) to the equivalent solution in (-m / 2, m / 2].
+fn balance(x: Natural, m: &Natural) -> Integer {
+    if x > (m >> 1u32) {
+        Integer::from(x) - Integer::from(m)
+    } else {
+        Integer::from(x)
+    }
+}
+
+impl Integer {
+    /// Finds the [`Integer`] of smallest absolute value that is congruent to each of a list of
+    /// residues modulo the corresponding modulus, or returns `None` if there is no such
+    /// [`Integer`].
+    ///
+    /// The moduli need not be pairwise coprime; the congruences are combined one at a time, and
+    /// `None` is returned if they are inconsistent. The residues need not be reduced. If there are
+    /// no congruences, 0 is returned. If there are two solutions of smallest absolute value, the
+    /// positive one is returned.
+    ///
+    /// $f((a_1, \ldots, a_k), (m_1, \ldots, m_k)) = x$, where $-L < 2x \leq L$, $L =
+    /// \operatorname{lcm}(m_1, \ldots, m_k)$, and $x \equiv a_i \mod m_i$ for all $i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of bits in the
+    /// residues and moduli, and $k$ is `moduli.len()`.
+    ///
+    /// # Panics
+    /// Panics if `residues` and `moduli` have different lengths, or if any modulus is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let moduli = [Natural::from(3u32), Natural::from(5u32), Natural::from(7u32)];
+    /// let residues = [Integer::from(2), Integer::from(3), Integer::from(2)];
+    /// assert_eq!(Integer::crt(&residues, &moduli), Some(Integer::from(23)));
+    ///
+    /// let residues = [Integer::from(-1), Integer::from(-1), Integer::from(-1)];
+    /// assert_eq!(Integer::crt(&residues, &moduli), Some(Integer::from(-1)));
+    ///
+    /// let moduli = [Natural::from(6u32), Natural::from(10u32)];
+    /// let residues = [Integer::from(5), Integer::from(4)];
+    /// assert_eq!(Integer::crt(&residues, &moduli), None);
+    /// ```
+    pub fn crt(residues: &[Integer], moduli: &[Natural]) -> Option<Integer> {
+        crt_helper(&reduce_residues(residues, moduli), moduli).map(|(x, m)| balance(x, &m))
+    }
+
+    /// Finds the [`Integer`] of smallest absolute value that is congruent to each of a list of
+    /// residues modulo the corresponding modulus, where the moduli are pairwise coprime.
+    ///
+    /// This uses a subproduct tree, so it is faster than [`Integer::crt`] when there are many
+    /// moduli. This is the reconstruction step of multi-modular algorithms: if the absolute value
+    /// of the result of a computation is known to be less than half the product of the moduli, it
+    /// can be recovered from its residues. The residues need not be reduced. If there are no
+    /// congruences, 0 is returned. If there are two solutions of smallest absolute value, the
+    /// positive one is returned.
+    ///
+    /// $f((a_1, \ldots, a_k), (m_1, \ldots, m_k)) = x$, where $-M < 2x \leq M$, $M =
+    /// \prod_{i=1}^k m_i$, and $x \equiv a_i \mod m_i$ for all $i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// residues and moduli.
+    ///
+    /// # Panics
+    /// Panics if `residues` and `moduli` have different lengths, if any modulus is zero, or if the
+    /// moduli are not pairwise coprime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let moduli = [Natural::from(3u32), Natural::from(5u32), Natural::from(7u32)];
+    /// let residues = [Integer::from(2), Integer::from(3), Integer::from(2)];
+    /// assert_eq!(Integer::crt_coprime(&residues, &moduli), Integer::from(23));
+    ///
+    /// let residues = [Integer::from(1), Integer::from(0), Integer::from(0)];
+    /// assert_eq!(Integer::crt_coprime(&residues, &moduli), Integer::from(-35));
+    /// ```
+    pub fn crt_coprime(residues: &[Integer], moduli: &[Natural]) -> Integer {
+        let (x, m) = crt_coprime_helper(&reduce_residues(residues, moduli), moduli);
+        balance(x, &m)
+    }
+}
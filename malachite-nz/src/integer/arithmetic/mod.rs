@@ -11,6 +11,9 @@ pub mod add_mul;
 /// [`BinomialCoefficient`](malachite_base::num::arithmetic::traits::BinomialCoefficient), a trait
 /// for computing the binomial coefficient of two numbers.
 pub mod binomial_coefficient;
+/// Reconstruction of [`Integer`](super::Integer)s from their residues using the Chinese remainder
+/// theorem.
+pub mod crt;
 /// Division of [`Integer`](super::Integer)s.
 pub mod div;
 /// Implementations of [`DivExact`](malachite_base::num::arithmetic::traits::DivExact) and
@@ -55,6 +58,18 @@ pub mod extended_gcd;
 /// [`KroneckerSymbol`](malachite_base::num::arithmetic::traits::KroneckerSymbol), traits for
 /// computing the Legendre, Jacobi, and Kronecker symbols of two numbers.
 pub mod kronecker_symbol;
+/// Implementations of [`ModAdd`](malachite_base::num::arithmetic::traits::ModAdd), a trait for
+/// adding two numbers modulo another number.
+pub mod mod_add;
+/// Implementations of [`ModInverse`](malachite_base::num::arithmetic::traits::ModInverse), a trait
+/// for finding the multiplicative inverse of a number modulo another number.
+pub mod mod_inverse;
+/// Implementations of [`ModMul`](malachite_base::num::arithmetic::traits::ModMul), a trait for
+/// multiplying two numbers modulo another number.
+pub mod mod_mul;
+/// Implementations of [`ModNeg`](malachite_base::num::arithmetic::traits::ModNeg), a trait for
+/// negating a number modulo another number.
+pub mod mod_neg;
 /// Implementations of traits for finding the remainder of two numbers, subject to various rounding
 /// rules.
 ///
@@ -67,6 +82,9 @@ pub mod kronecker_symbol;
 ///
 /// The [`Rem`](std::ops::Rem) trait in the standard library rounds towards 0.
 pub mod mod_op;
+/// Implementations of [`ModPow`](malachite_base::num::arithmetic::traits::ModPow), a trait for
+/// raising a number to a power modulo another number.
+pub mod mod_pow;
 /// Implementations of traits for finding the remainder of a number divided by $2^k$, subject to
 /// various rounding rules.
 ///
@@ -78,6 +96,12 @@ pub mod mod_op;
 /// | towards 0 | [`RemPowerOf2`](malachite_base::num::arithmetic::traits::RemPowerOf2) | [`RemPowerOf2Assign`](malachite_base::num::arithmetic::traits::RemPowerOf2Assign)       |
 /// | towards $\infty$  | [`CeilingModPowerOf2`](malachite_base::num::arithmetic::traits::CeilingModPowerOf2) | [`CeilingModPowerOf2Assign`](malachite_base::num::arithmetic::traits::CeilingModPowerOf2Assign) |
 pub mod mod_power_of_2;
+/// Implementations of [`ModSquare`](malachite_base::num::arithmetic::traits::ModSquare), a trait
+/// for squaring a number modulo another number.
+pub mod mod_square;
+/// Implementations of [`ModSub`](malachite_base::num::arithmetic::traits::ModSub), a trait for
+/// subtracting two numbers modulo another number.
+pub mod mod_sub;
 /// Multiplication of [`Integer`](super::Integer)s.
 pub mod mul;
 /// Negation of an [`Integer`](super::Integer).
@@ -97,8 +121,10 @@ pub mod power_of_2;
 /// The traits are [`FloorRoot`](malachite_base::num::arithmetic::traits::FloorRoot),
 /// [`FloorRootAssign`](malachite_base::num::arithmetic::traits::FloorRootAssign),
 /// [`CeilingRoot`](malachite_base::num::arithmetic::traits::CeilingRoot),
-/// [`CeilingRootAssign`](malachite_base::num::arithmetic::traits::CeilingRootAssign), and
-/// [`CheckedRoot`](malachite_base::num::arithmetic::traits::CheckedRoot).
+/// [`CeilingRootAssign`](malachite_base::num::arithmetic::traits::CeilingRootAssign),
+/// [`CheckedRoot`](malachite_base::num::arithmetic::traits::CheckedRoot),
+/// [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower), and
+/// [`PerfectPowerDecomposition`](malachite_base::num::arithmetic::traits::PerfectPowerDecomposition).
 pub mod root;
 /// Implementations of
 /// [`RoundToMultiple`](malachite_base::num::arithmetic::traits::RoundToMultiple) and
@@ -675,8 +701,9 @@ pub mod sign;
 /// The traits are [`FloorSqrt`](malachite_base::num::arithmetic::traits::FloorSqrt),
 /// [`FloorSqrtAssign`](malachite_base::num::arithmetic::traits::FloorSqrtAssign),
 /// [`CeilingSqrt`](malachite_base::num::arithmetic::traits::CeilingSqrt),
-/// [`CeilingSqrtAssign`](malachite_base::num::arithmetic::traits::CeilingSqrtAssign), and
-/// [`CheckedSqrt`](malachite_base::num::arithmetic::traits::CheckedSqrt).
+/// [`CeilingSqrtAssign`](malachite_base::num::arithmetic::traits::CeilingSqrtAssign),
+/// [`CheckedSqrt`](malachite_base::num::arithmetic::traits::CheckedSqrt), and
+/// [`IsSquare`](malachite_base::num::arithmetic::traits::IsSquare).
 pub mod sqrt;
 /// Implementations of [`Square`](malachite_base::num::arithmetic::traits::Square) and
 /// [`SquareAssign`](malachite_base::num::arithmetic::traits::SquareAssign), traits for squaring a
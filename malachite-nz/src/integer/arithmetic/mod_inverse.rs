@@ -0,0 +1,161 @@
+use crate::integer::arithmetic::mod_op::{integer_mod_natural, integer_ref_mod_natural};
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::ModInverse;
+use malachite_base::num::basic::traits::Zero;
+
+// Inverts a `Natural` that has already been reduced modulo `m`. Unlike `Natural::mod_inverse`, this
+// accepts 0, which is invertible only modulo 1.
+pub(crate) fn mod_inverse_reduced(x: Natural, m: &Natural) -> Option<Natural> {
+    if *m == 1u32 {
+        Some(Natural::ZERO)
+    } else if x == 0u32 {
+        None
+    } else {
+        x.mod_inverse(m)
+    }
+}
+
+impl ModInverse<Natural> for Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. Both
+    /// arguments are taken by value.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative. Returns `None` if $x$ and $m$
+    /// are not coprime.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_inverse(Natural::from(10u32)), Some(Natural::from(3u32)));
+    /// assert_eq!(Integer::from(-4).mod_inverse(Natural::from(10u32)), None);
+    /// ```
+    fn mod_inverse(self, m: Natural) -> Option<Natural> {
+        mod_inverse_reduced(integer_mod_natural(self, &m), &m)
+    }
+}
+
+impl<'a> ModInverse<&'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. The
+    /// [`Integer`] is taken by value and the [`Natural`] by reference.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative. Returns `None` if $x$ and $m$
+    /// are not coprime.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_inverse(&Natural::from(10u32)), Some(Natural::from(3u32)));
+    /// assert_eq!(Integer::from(-4).mod_inverse(&Natural::from(10u32)), None);
+    /// ```
+    fn mod_inverse(self, m: &'a Natural) -> Option<Natural> {
+        mod_inverse_reduced(integer_mod_natural(self, m), m)
+    }
+}
+
+impl<'a> ModInverse<Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. The
+    /// [`Natural`] is taken by value and the [`Integer`] by reference.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative. Returns `None` if $x$ and $m$
+    /// are not coprime.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_inverse(Natural::from(10u32)), Some(Natural::from(3u32)));
+    /// assert_eq!((&Integer::from(-4)).mod_inverse(Natural::from(10u32)), None);
+    /// ```
+    fn mod_inverse(self, m: Natural) -> Option<Natural> {
+        mod_inverse_reduced(integer_ref_mod_natural(self, &m), &m)
+    }
+}
+
+impl<'a, 'b> ModInverse<&'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. Both
+    /// arguments are taken by reference.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative. Returns `None` if $x$ and $m$
+    /// are not coprime.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_inverse(&Natural::from(10u32)), Some(Natural::from(3u32)));
+    /// assert_eq!((&Integer::from(-4)).mod_inverse(&Natural::from(10u32)), None);
+    /// ```
+    fn mod_inverse(self, m: &'b Natural) -> Option<Natural> {
+        mod_inverse_reduced(integer_ref_mod_natural(self, m), m)
+    }
+}
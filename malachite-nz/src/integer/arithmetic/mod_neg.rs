@@ -0,0 +1,142 @@
+use crate::integer::arithmetic::mod_op::{integer_mod_natural, integer_ref_mod_natural};
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::ModNeg;
+
+impl ModNeg<Natural> for Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$. Both arguments are taken by value.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative; the output is always reduced.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_neg(Natural::from(7u32)), 3);
+    /// assert_eq!(Integer::from(10).mod_neg(Natural::from(7u32)), 4);
+    /// ```
+    fn mod_neg(self, m: Natural) -> Natural {
+        integer_mod_natural(self, &m).mod_neg(m)
+    }
+}
+
+impl<'a> ModNeg<&'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$. The [`Integer`] is taken by value and the
+    /// [`Natural`] by reference.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative; the output is always reduced.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_neg(&Natural::from(7u32)), 3);
+    /// assert_eq!(Integer::from(10).mod_neg(&Natural::from(7u32)), 4);
+    /// ```
+    fn mod_neg(self, m: &'a Natural) -> Natural {
+        integer_mod_natural(self, m).mod_neg(m)
+    }
+}
+
+impl<'a> ModNeg<Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$. The [`Natural`] is taken by value and the
+    /// [`Integer`] by reference.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative; the output is always reduced.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_neg(Natural::from(7u32)), 3);
+    /// assert_eq!((&Integer::from(10)).mod_neg(Natural::from(7u32)), 4);
+    /// ```
+    fn mod_neg(self, m: Natural) -> Natural {
+        integer_ref_mod_natural(self, &m).mod_neg(m)
+    }
+}
+
+impl<'a, 'b> ModNeg<&'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$. Both arguments are taken by reference.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative; the output is always reduced.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_neg(&Natural::from(7u32)), 3);
+    /// assert_eq!((&Integer::from(10)).mod_neg(&Natural::from(7u32)), 4);
+    /// ```
+    fn mod_neg(self, m: &'b Natural) -> Natural {
+        integer_ref_mod_natural(self, m).mod_neg(m)
+    }
+}
@@ -1,9 +1,32 @@
 use crate::integer::Integer;
+use crate::natural::Natural;
 use malachite_base::num::arithmetic::traits::{
     CeilingMod, CeilingModAssign, Mod, ModAssign, NegMod, NegModAssign,
 };
 use std::ops::{Rem, RemAssign};
 
+// Reduces an `Integer` modulo a positive `Natural`, taking it by value and returning a result in
+// [0, m).
+pub(crate) fn integer_mod_natural(x: Integer, m: &Natural) -> Natural {
+    let r = x.abs % m;
+    if x.sign || r == 0u32 {
+        r
+    } else {
+        m - r
+    }
+}
+
+// Reduces an `Integer` modulo a positive `Natural`, taking it by reference and returning a result
+// in [0, m).
+pub(crate) fn integer_ref_mod_natural(x: &Integer, m: &Natural) -> Natural {
+    let r = &x.abs % m;
+    if x.sign || r == 0u32 {
+        r
+    } else {
+        m - r
+    }
+}
+
 impl Mod<Integer> for Integer {
     type Output = Integer;
 
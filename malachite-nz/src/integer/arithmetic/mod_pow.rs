@@ -0,0 +1,341 @@
+use crate::integer::arithmetic::mod_inverse::mod_inverse_reduced;
+use crate::integer::arithmetic::mod_op::{integer_mod_natural, integer_ref_mod_natural};
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::ModPow;
+
+impl ModPow<Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. All three arguments
+    /// are taken by value.
+    ///
+    /// The base need not be reduced modulo $m$, and may be negative. If the exponent is negative,
+    /// the result is a power of the multiplicative inverse of the base.
+    ///
+    /// $f(x, n, m) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0, or if `exp` is negative and `self` is not invertible modulo $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-2).mod_pow(Integer::from(3), Natural::from(7u32)), 6);
+    /// assert_eq!(Integer::from(3).mod_pow(Integer::from(-2), Natural::from(10u32)), 9);
+    /// ```
+    fn mod_pow(self, exp: Integer, m: Natural) -> Natural {
+        let x = integer_mod_natural(self, &m);
+        let x = if exp.sign {
+            x
+        } else {
+            mod_inverse_reduced(x, &m).expect("The base must be invertible modulo m")
+        };
+        x.mod_pow(exp.abs, m)
+    }
+}
+
+impl<'a> ModPow<Integer, &'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base and the
+    /// exponent are taken by value and the modulus by reference.
+    ///
+    /// The base need not be reduced modulo $m$, and may be negative. If the exponent is negative,
+    /// the result is a power of the multiplicative inverse of the base.
+    ///
+    /// $f(x, n, m) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0, or if `exp` is negative and `self` is not invertible modulo $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-2).mod_pow(Integer::from(3), &Natural::from(7u32)), 6);
+    /// assert_eq!(Integer::from(3).mod_pow(Integer::from(-2), &Natural::from(10u32)), 9);
+    /// ```
+    fn mod_pow(self, exp: Integer, m: &'a Natural) -> Natural {
+        let x = integer_mod_natural(self, m);
+        let x = if exp.sign {
+            x
+        } else {
+            mod_inverse_reduced(x, m).expect("The base must be invertible modulo m")
+        };
+        x.mod_pow(exp.abs, m)
+    }
+}
+
+impl<'a> ModPow<&'a Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base and the
+    /// modulus are taken by value and the exponent by reference.
+    ///
+    /// The base need not be reduced modulo $m$, and may be negative. If the exponent is negative,
+    /// the result is a power of the multiplicative inverse of the base.
+    ///
+    /// $f(x, n, m) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0, or if `exp` is negative and `self` is not invertible modulo $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-2).mod_pow(&Integer::from(3), Natural::from(7u32)), 6);
+    /// assert_eq!(Integer::from(3).mod_pow(&Integer::from(-2), Natural::from(10u32)), 9);
+    /// ```
+    fn mod_pow(self, exp: &'a Integer, m: Natural) -> Natural {
+        let x = integer_mod_natural(self, &m);
+        let x = if exp.sign {
+            x
+        } else {
+            mod_inverse_reduced(x, &m).expect("The base must be invertible modulo m")
+        };
+        x.mod_pow(&exp.abs, m)
+    }
+}
+
+impl<'a, 'b> ModPow<&'a Integer, &'b Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base is taken by
+    /// value and the exponent and the modulus by reference.
+    ///
+    /// The base need not be reduced modulo $m$, and may be negative. If the exponent is negative,
+    /// the result is a power of the multiplicative inverse of the base.
+    ///
+    /// $f(x, n, m) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0, or if `exp` is negative and `self` is not invertible modulo $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-2).mod_pow(&Integer::from(3), &Natural::from(7u32)), 6);
+    /// assert_eq!(Integer::from(3).mod_pow(&Integer::from(-2), &Natural::from(10u32)), 9);
+    /// ```
+    fn mod_pow(self, exp: &'a Integer, m: &'b Natural) -> Natural {
+        let x = integer_mod_natural(self, m);
+        let x = if exp.sign {
+            x
+        } else {
+            mod_inverse_reduced(x, m).expect("The base must be invertible modulo m")
+        };
+        x.mod_pow(&exp.abs, m)
+    }
+}
+
+impl<'a> ModPow<Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The exponent and the
+    /// modulus are taken by value and the base by reference.
+    ///
+    /// The base need not be reduced modulo $m$, and may be negative. If the exponent is negative,
+    /// the result is a power of the multiplicative inverse of the base.
+    ///
+    /// $f(x, n, m) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0, or if `exp` is negative and `self` is not invertible modulo $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-2)).mod_pow(Integer::from(3), Natural::from(7u32)), 6);
+    /// assert_eq!((&Integer::from(3)).mod_pow(Integer::from(-2), Natural::from(10u32)), 9);
+    /// ```
+    fn mod_pow(self, exp: Integer, m: Natural) -> Natural {
+        let x = integer_ref_mod_natural(self, &m);
+        let x = if exp.sign {
+            x
+        } else {
+            mod_inverse_reduced(x, &m).expect("The base must be invertible modulo m")
+        };
+        x.mod_pow(exp.abs, m)
+    }
+}
+
+impl<'a, 'b> ModPow<Integer, &'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The exponent is
+    /// taken by value and the base and the modulus by reference.
+    ///
+    /// The base need not be reduced modulo $m$, and may be negative. If the exponent is negative,
+    /// the result is a power of the multiplicative inverse of the base.
+    ///
+    /// $f(x, n, m) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0, or if `exp` is negative and `self` is not invertible modulo $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-2)).mod_pow(Integer::from(3), &Natural::from(7u32)), 6);
+    /// assert_eq!((&Integer::from(3)).mod_pow(Integer::from(-2), &Natural::from(10u32)), 9);
+    /// ```
+    fn mod_pow(self, exp: Integer, m: &'b Natural) -> Natural {
+        let x = integer_ref_mod_natural(self, m);
+        let x = if exp.sign {
+            x
+        } else {
+            mod_inverse_reduced(x, m).expect("The base must be invertible modulo m")
+        };
+        x.mod_pow(exp.abs, m)
+    }
+}
+
+impl<'a, 'b> ModPow<&'b Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The modulus is taken
+    /// by value and the base and the exponent by reference.
+    ///
+    /// The base need not be reduced modulo $m$, and may be negative. If the exponent is negative,
+    /// the result is a power of the multiplicative inverse of the base.
+    ///
+    /// $f(x, n, m) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0, or if `exp` is negative and `self` is not invertible modulo $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-2)).mod_pow(&Integer::from(3), Natural::from(7u32)), 6);
+    /// assert_eq!((&Integer::from(3)).mod_pow(&Integer::from(-2), Natural::from(10u32)), 9);
+    /// ```
+    fn mod_pow(self, exp: &'b Integer, m: Natural) -> Natural {
+        let x = integer_ref_mod_natural(self, &m);
+        let x = if exp.sign {
+            x
+        } else {
+            mod_inverse_reduced(x, &m).expect("The base must be invertible modulo m")
+        };
+        x.mod_pow(&exp.abs, m)
+    }
+}
+
+impl<'a, 'b, 'c> ModPow<&'b Integer, &'c Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. All three arguments
+    /// are taken by reference.
+    ///
+    /// The base need not be reduced modulo $m$, and may be negative. If the exponent is negative,
+    /// the result is a power of the multiplicative inverse of the base.
+    ///
+    /// $f(x, n, m) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0, or if `exp` is negative and `self` is not invertible modulo $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-2)).mod_pow(&Integer::from(3), &Natural::from(7u32)), 6);
+    /// assert_eq!((&Integer::from(3)).mod_pow(&Integer::from(-2), &Natural::from(10u32)), 9);
+    /// ```
+    fn mod_pow(self, exp: &'b Integer, m: &'c Natural) -> Natural {
+        let x = integer_ref_mod_natural(self, m);
+        let x = if exp.sign {
+            x
+        } else {
+            mod_inverse_reduced(x, m).expect("The base must be invertible modulo m")
+        };
+        x.mod_pow(&exp.abs, m)
+    }
+}
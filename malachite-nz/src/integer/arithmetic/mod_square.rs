@@ -0,0 +1,142 @@
+use crate::integer::arithmetic::mod_op::{integer_mod_natural, integer_ref_mod_natural};
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::ModSquare;
+
+impl ModSquare<Natural> for Integer {
+    type Output = Natural;
+
+    /// Squares an [`Integer`] modulo a [`Natural`] $m$. Both arguments are taken by value.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative; the output is always reduced.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_square(Natural::from(7u32)), 2);
+    /// assert_eq!(Integer::from(-10).mod_square(Natural::from(13u32)), 9);
+    /// ```
+    fn mod_square(self, m: Natural) -> Natural {
+        integer_mod_natural(self, &m).mod_square(m)
+    }
+}
+
+impl<'a> ModSquare<&'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Squares an [`Integer`] modulo a [`Natural`] $m$. The [`Integer`] is taken by value and the
+    /// [`Natural`] by reference.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative; the output is always reduced.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_square(&Natural::from(7u32)), 2);
+    /// assert_eq!(Integer::from(-10).mod_square(&Natural::from(13u32)), 9);
+    /// ```
+    fn mod_square(self, m: &'a Natural) -> Natural {
+        integer_mod_natural(self, m).mod_square(m)
+    }
+}
+
+impl<'a> ModSquare<Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Squares an [`Integer`] modulo a [`Natural`] $m$. The [`Natural`] is taken by value and the
+    /// [`Integer`] by reference.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative; the output is always reduced.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_square(Natural::from(7u32)), 2);
+    /// assert_eq!((&Integer::from(-10)).mod_square(Natural::from(13u32)), 9);
+    /// ```
+    fn mod_square(self, m: Natural) -> Natural {
+        integer_ref_mod_natural(self, &m).mod_square(m)
+    }
+}
+
+impl<'a, 'b> ModSquare<&'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Squares an [`Integer`] modulo a [`Natural`] $m$. Both arguments are taken by reference.
+    ///
+    /// The input need not be reduced modulo $m$, and may be negative; the output is always reduced.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_square(&Natural::from(7u32)), 2);
+    /// assert_eq!((&Integer::from(-10)).mod_square(&Natural::from(13u32)), 9);
+    /// ```
+    fn mod_square(self, m: &'b Natural) -> Natural {
+        integer_ref_mod_natural(self, m).mod_square(m)
+    }
+}
@@ -0,0 +1,291 @@
+use crate::integer::arithmetic::mod_op::{integer_mod_natural, integer_ref_mod_natural};
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::ModSub;
+
+impl ModSub<Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Subtracts two [`Integer`]s modulo a [`Natural`] $m$. All three arguments are taken by value.
+    ///
+    /// The inputs need not be reduced modulo $m$, and may be negative; the output is always
+    /// reduced.
+    ///
+    /// $f(x, y, m) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(Integer::from(5), Natural::from(7u32)), 6);
+    /// assert_eq!(Integer::from(10).mod_sub(Integer::from(-20), Natural::from(7u32)), 2);
+    /// ```
+    fn mod_sub(self, other: Integer, m: Natural) -> Natural {
+        integer_mod_natural(self, &m).mod_sub(integer_mod_natural(other, &m), m)
+    }
+}
+
+impl<'a> ModSub<Integer, &'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Subtracts two [`Integer`]s modulo a [`Natural`] $m$. Both [`Integer`]s are taken by value
+    /// and the [`Natural`] by reference.
+    ///
+    /// The inputs need not be reduced modulo $m$, and may be negative; the output is always
+    /// reduced.
+    ///
+    /// $f(x, y, m) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(Integer::from(5), &Natural::from(7u32)), 6);
+    /// assert_eq!(Integer::from(10).mod_sub(Integer::from(-20), &Natural::from(7u32)), 2);
+    /// ```
+    fn mod_sub(self, other: Integer, m: &'a Natural) -> Natural {
+        integer_mod_natural(self, m).mod_sub(integer_mod_natural(other, m), m)
+    }
+}
+
+impl<'a> ModSub<&'a Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Subtracts two [`Integer`]s modulo a [`Natural`] $m$. The first [`Integer`] and the
+    /// [`Natural`] are taken by value and the second [`Integer`] by reference.
+    ///
+    /// The inputs need not be reduced modulo $m$, and may be negative; the output is always
+    /// reduced.
+    ///
+    /// $f(x, y, m) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(&Integer::from(5), Natural::from(7u32)), 6);
+    /// assert_eq!(Integer::from(10).mod_sub(&Integer::from(-20), Natural::from(7u32)), 2);
+    /// ```
+    fn mod_sub(self, other: &'a Integer, m: Natural) -> Natural {
+        integer_mod_natural(self, &m).mod_sub(integer_ref_mod_natural(other, &m), m)
+    }
+}
+
+impl<'a, 'b> ModSub<&'a Integer, &'b Natural> for Integer {
+    type Output = Natural;
+
+    /// Subtracts two [`Integer`]s modulo a [`Natural`] $m$. The first [`Integer`] is taken by value
+    /// and the second [`Integer`] and the [`Natural`] by reference.
+    ///
+    /// The inputs need not be reduced modulo $m$, and may be negative; the output is always
+    /// reduced.
+    ///
+    /// $f(x, y, m) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(&Integer::from(5), &Natural::from(7u32)), 6);
+    /// assert_eq!(Integer::from(10).mod_sub(&Integer::from(-20), &Natural::from(7u32)), 2);
+    /// ```
+    fn mod_sub(self, other: &'a Integer, m: &'b Natural) -> Natural {
+        integer_mod_natural(self, m).mod_sub(integer_ref_mod_natural(other, m), m)
+    }
+}
+
+impl<'a> ModSub<Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Subtracts two [`Integer`]s modulo a [`Natural`] $m$. The second [`Integer`] and the
+    /// [`Natural`] are taken by value and the first [`Integer`] by reference.
+    ///
+    /// The inputs need not be reduced modulo $m$, and may be negative; the output is always
+    /// reduced.
+    ///
+    /// $f(x, y, m) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(Integer::from(5), Natural::from(7u32)), 6);
+    /// assert_eq!((&Integer::from(10)).mod_sub(Integer::from(-20), Natural::from(7u32)), 2);
+    /// ```
+    fn mod_sub(self, other: Integer, m: Natural) -> Natural {
+        integer_ref_mod_natural(self, &m).mod_sub(integer_mod_natural(other, &m), m)
+    }
+}
+
+impl<'a, 'b> ModSub<Integer, &'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Subtracts two [`Integer`]s modulo a [`Natural`] $m$. The second [`Integer`] is taken by
+    /// value and the first [`Integer`] and the [`Natural`] by reference.
+    ///
+    /// The inputs need not be reduced modulo $m$, and may be negative; the output is always
+    /// reduced.
+    ///
+    /// $f(x, y, m) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(Integer::from(5), &Natural::from(7u32)), 6);
+    /// assert_eq!((&Integer::from(10)).mod_sub(Integer::from(-20), &Natural::from(7u32)), 2);
+    /// ```
+    fn mod_sub(self, other: Integer, m: &'b Natural) -> Natural {
+        integer_ref_mod_natural(self, m).mod_sub(integer_mod_natural(other, m), m)
+    }
+}
+
+impl<'a, 'b> ModSub<&'b Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Subtracts two [`Integer`]s modulo a [`Natural`] $m$. The [`Natural`] is taken by value and
+    /// both [`Integer`]s by reference.
+    ///
+    /// The inputs need not be reduced modulo $m$, and may be negative; the output is always
+    /// reduced.
+    ///
+    /// $f(x, y, m) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(&Integer::from(5), Natural::from(7u32)), 6);
+    /// assert_eq!((&Integer::from(10)).mod_sub(&Integer::from(-20), Natural::from(7u32)), 2);
+    /// ```
+    fn mod_sub(self, other: &'b Integer, m: Natural) -> Natural {
+        integer_ref_mod_natural(self, &m).mod_sub(integer_ref_mod_natural(other, &m), m)
+    }
+}
+
+impl<'a, 'b, 'c> ModSub<&'b Integer, &'c Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Subtracts two [`Integer`]s modulo a [`Natural`] $m$. All three arguments are taken by
+    /// reference.
+    ///
+    /// The inputs need not be reduced modulo $m$, and may be negative; the output is always
+    /// reduced.
+    ///
+    /// $f(x, y, m) = z$, where $z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(&Integer::from(5), &Natural::from(7u32)), 6);
+    /// assert_eq!((&Integer::from(10)).mod_sub(&Integer::from(-20), &Natural::from(7u32)), 2);
+    /// ```
+    fn mod_sub(self, other: &'b Integer, m: &'c Natural) -> Natural {
+        integer_ref_mod_natural(self, m).mod_sub(integer_ref_mod_natural(other, m), m)
+    }
+}
@@ -1,7 +1,8 @@
 use crate::integer::Integer;
 use crate::natural::Natural;
 use malachite_base::num::arithmetic::traits::{
-    CeilingRoot, CeilingRootAssign, CheckedRoot, FloorRoot, FloorRootAssign, Parity, UnsignedAbs,
+    CeilingRoot, CeilingRootAssign, CheckedRoot, FloorRoot, FloorRootAssign, IsPerfectPower,
+    Parity, PerfectPowerDecomposition, Pow, UnsignedAbs,
 };
 use std::ops::Neg;
 
@@ -360,3 +361,155 @@ impl<'a> CheckedRoot<u64> for &'a Integer {
         }
     }
 }
+
+impl IsPerfectPower for Integer {
+    /// Determines whether an [`Integer`] is a perfect power; that is, whether it is equal to $a^k$
+    /// for some integers $a$ and $k \geq 2$.
+    ///
+    /// 0, 1, and -1 are considered perfect powers. A negative [`Integer`] can only be an odd
+    /// power.
+    ///
+    /// $f(x) = (\exists a \in \Z, k \in \N : k \geq 2 \land x = a^k)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{IsPerfectPower, Pow};
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-1).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(1000).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-1000).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-16).is_perfect_power(), false);
+    /// assert_eq!(Integer::from(-10).pow(33).is_perfect_power(), true);
+    /// ```
+    fn is_perfect_power(&self) -> bool {
+        if *self >= -1 {
+            self.unsigned_abs_ref().is_perfect_power()
+        } else {
+            let exp = self.unsigned_abs_ref().perfect_power_decomposition().1;
+            exp >> exp.trailing_zeros() != 1
+        }
+    }
+}
+
+impl PerfectPowerDecomposition for Integer {
+    type Output = Integer;
+
+    /// Writes an [`Integer`] as $a^k$, where $k$ is as large as possible, and returns $(a, k)$.
+    /// The [`Integer`] is taken by value.
+    ///
+    /// If the [`Integer`] is not a perfect power, $k$ is 1. Since 0, 1, and -1 are $k$th powers
+    /// for infinitely many $k$, they are returned with an exponent of 1. If the [`Integer`] is
+    /// negative, $k$ is odd.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{PerfectPowerDecomposition, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-1).perfect_power_decomposition().to_debug_string(), "(-1, 1)");
+    /// assert_eq!(Integer::from(1000).perfect_power_decomposition().to_debug_string(), "(10, 3)");
+    /// assert_eq!(
+    ///     Integer::from(-1000).perfect_power_decomposition().to_debug_string(),
+    ///     "(-10, 3)"
+    /// );
+    /// assert_eq!(Integer::from(-16).perfect_power_decomposition().to_debug_string(), "(-16, 1)");
+    /// assert_eq!(
+    ///     Integer::from(-10).pow(30).perfect_power_decomposition().to_debug_string(),
+    ///     "(10, 30)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-10).pow(33).perfect_power_decomposition().to_debug_string(),
+    ///     "(-10, 33)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-2).pow(40).perfect_power_decomposition().to_debug_string(),
+    ///     "(2, 40)"
+    /// );
+    /// assert_eq!(
+    ///     (-Integer::from(2).pow(40)).perfect_power_decomposition().to_debug_string(),
+    ///     "(-256, 5)"
+    /// );
+    /// ```
+    #[inline]
+    fn perfect_power_decomposition(self) -> (Integer, u64) {
+        (&self).perfect_power_decomposition()
+    }
+}
+
+impl<'a> PerfectPowerDecomposition for &'a Integer {
+    type Output = Integer;
+
+    /// Writes an [`Integer`] as $a^k$, where $k$ is as large as possible, and returns $(a, k)$.
+    /// The [`Integer`] is taken by reference.
+    ///
+    /// If the [`Integer`] is not a perfect power, $k$ is 1. Since 0, 1, and -1 are $k$th powers
+    /// for infinitely many $k$, they are returned with an exponent of 1. If the [`Integer`] is
+    /// negative, $k$ is odd.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{PerfectPowerDecomposition, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-1)).perfect_power_decomposition().to_debug_string(), "(-1, 1)");
+    /// assert_eq!(
+    ///     (&Integer::from(1000)).perfect_power_decomposition().to_debug_string(),
+    ///     "(10, 3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-1000)).perfect_power_decomposition().to_debug_string(),
+    ///     "(-10, 3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-16)).perfect_power_decomposition().to_debug_string(),
+    ///     "(-16, 1)"
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-10).pow(33)).perfect_power_decomposition().to_debug_string(),
+    ///     "(-10, 33)"
+    /// );
+    /// assert_eq!(
+    ///     (&-Integer::from(2).pow(40)).perfect_power_decomposition().to_debug_string(),
+    ///     "(-256, 5)"
+    /// );
+    /// ```
+    fn perfect_power_decomposition(self) -> (Integer, u64) {
+        let (base, exp) = self.unsigned_abs_ref().perfect_power_decomposition();
+        if *self >= 0 {
+            (Integer::from(base), exp)
+        } else {
+            // -a^k = (-a^(2^s))^m, where k = 2^s * m and m is odd.
+            let zeros = exp.trailing_zeros();
+            let odd_exp = exp >> zeros;
+            if odd_exp == 1 {
+                (self.clone(), 1)
+            } else {
+                (-base.pow(1 << zeros), odd_exp)
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::integer::Integer;
 use crate::natural::Natural;
 use malachite_base::num::arithmetic::traits::{
-    CeilingSqrt, CeilingSqrtAssign, CheckedSqrt, FloorSqrt, FloorSqrtAssign, UnsignedAbs,
+    CeilingSqrt, CeilingSqrtAssign, CheckedSqrt, FloorSqrt, FloorSqrtAssign, IsSquare, UnsignedAbs,
 };
 
 impl FloorSqrt for Integer {
@@ -342,3 +342,32 @@ impl<'a> CheckedSqrt for &'a Integer {
         }
     }
 }
+
+impl IsSquare for Integer {
+    /// Determines whether an [`Integer`] is a perfect square.
+    ///
+    /// $f(x) = (\exists k \in \N : x = k^2)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{IsSquare, Pow};
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(0).is_square(), true);
+    /// assert_eq!(Integer::from(99).is_square(), false);
+    /// assert_eq!(Integer::from(100).is_square(), true);
+    /// assert_eq!(Integer::from(-100).is_square(), false);
+    /// assert_eq!(Integer::from(10).pow(30).is_square(), true);
+    /// ```
+    #[inline]
+    fn is_square(&self) -> bool {
+        *self >= 0 && self.unsigned_abs_ref().is_square()
+    }
+}
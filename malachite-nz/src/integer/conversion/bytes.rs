@@ -0,0 +1,317 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::{VecFromOtherTypeSlice, WrappingFrom};
+
+impl Integer {
+    /// Returns the bytes of an [`Integer`] in two's complement, in little-endian order, so that
+    /// less-significant bytes have lower indices in the output vector.
+    ///
+    /// The most significant bit of the bytes indicates the sign; if the bit is zero, the
+    /// [`Integer`] is non-negative, and if the bit is one it is negative. There are no trailing
+    /// zero bytes if the [`Integer`] is positive or trailing `u8::MAX` bytes if the [`Integer`] is
+    /// negative, except as necessary to include the correct sign bit. Zero is a special case: it
+    /// produces an empty vector.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(Integer::ZERO.to_twos_complement_bytes_le().is_empty());
+    /// assert_eq!(Integer::from(123).to_twos_complement_bytes_le(), &[123]);
+    /// assert_eq!(Integer::from(128).to_twos_complement_bytes_le(), &[128, 0]);
+    /// assert_eq!(Integer::from(-1).to_twos_complement_bytes_le(), &[255]);
+    /// assert_eq!(Integer::from(-128).to_twos_complement_bytes_le(), &[128]);
+    /// assert_eq!(Integer::from(-129).to_twos_complement_bytes_le(), &[127, 255]);
+    /// assert_eq!(Integer::from(-0x1234).to_twos_complement_bytes_le(), &[0xcc, 0xed]);
+    /// ```
+    pub fn to_twos_complement_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = u8::vec_from_other_type_slice(&self.to_twos_complement_limbs_asc());
+        let extension = if self.sign { 0 } else { u8::MAX };
+        while let [.., previous, last] = *bytes.as_slice() {
+            if last != extension || previous.get_highest_bit() == self.sign {
+                break;
+            }
+            bytes.pop();
+        }
+        bytes
+    }
+
+    /// Returns the bytes of an [`Integer`] in two's complement, in big-endian order, so that
+    /// less-significant bytes have higher indices in the output vector.
+    ///
+    /// The most significant bit of the bytes indicates the sign; if the bit is zero, the
+    /// [`Integer`] is non-negative, and if the bit is one it is negative. There are no leading
+    /// zero bytes if the [`Integer`] is positive or leading `u8::MAX` bytes if the [`Integer`] is
+    /// negative, except as necessary to include the correct sign bit. Zero is a special case: it
+    /// produces an empty vector.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(Integer::ZERO.to_twos_complement_bytes_be().is_empty());
+    /// assert_eq!(Integer::from(123).to_twos_complement_bytes_be(), &[123]);
+    /// assert_eq!(Integer::from(128).to_twos_complement_bytes_be(), &[0, 128]);
+    /// assert_eq!(Integer::from(-1).to_twos_complement_bytes_be(), &[255]);
+    /// assert_eq!(Integer::from(-128).to_twos_complement_bytes_be(), &[128]);
+    /// assert_eq!(Integer::from(-129).to_twos_complement_bytes_be(), &[255, 127]);
+    /// assert_eq!(Integer::from(-0x1234).to_twos_complement_bytes_be(), &[0xed, 0xcc]);
+    /// ```
+    pub fn to_twos_complement_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_twos_complement_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Returns the bytes of an [`Integer`] in two's complement, in little-endian order,
+    /// sign-extended to exactly `len` bytes. If the [`Integer`] doesn't fit in `len` bytes, `None`
+    /// is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(123).to_twos_complement_bytes_le_padded(2).unwrap(), &[123, 0]);
+    /// assert_eq!(Integer::from(-1).to_twos_complement_bytes_le_padded(2).unwrap(), &[255, 255]);
+    /// assert_eq!(Integer::from(128).to_twos_complement_bytes_le_padded(1), None);
+    /// assert_eq!(Integer::from(-128).to_twos_complement_bytes_le_padded(1).unwrap(), &[128]);
+    /// assert_eq!(Integer::from(-129).to_twos_complement_bytes_le_padded(1), None);
+    /// ```
+    pub fn to_twos_complement_bytes_le_padded(&self, len: usize) -> Option<Vec<u8>> {
+        let mut bytes = self.to_twos_complement_bytes_le();
+        if bytes.len() > len {
+            None
+        } else {
+            bytes.resize(len, if self.sign { 0 } else { u8::MAX });
+            Some(bytes)
+        }
+    }
+
+    /// Returns the bytes of an [`Integer`] in two's complement, in big-endian order, sign-extended
+    /// to exactly `len` bytes. If the [`Integer`] doesn't fit in `len` bytes, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(123).to_twos_complement_bytes_be_padded(2).unwrap(), &[0, 123]);
+    /// assert_eq!(Integer::from(-1).to_twos_complement_bytes_be_padded(2).unwrap(), &[255, 255]);
+    /// assert_eq!(Integer::from(128).to_twos_complement_bytes_be_padded(1), None);
+    /// assert_eq!(Integer::from(-128).to_twos_complement_bytes_be_padded(1).unwrap(), &[128]);
+    /// assert_eq!(Integer::from(-129).to_twos_complement_bytes_be_padded(1), None);
+    /// ```
+    pub fn to_twos_complement_bytes_be_padded(&self, len: usize) -> Option<Vec<u8>> {
+        let mut bytes = self.to_twos_complement_bytes_le_padded(len)?;
+        bytes.reverse();
+        Some(bytes)
+    }
+
+    /// Converts a slice of bytes in two's complement and little-endian order to an [`Integer`].
+    /// Less-significant bytes have lower indices in the input slice.
+    ///
+    /// The most significant bit of the bytes indicates the sign; if the bit is zero, the
+    /// [`Integer`] is non-negative, and if the bit is one it is negative. If the slice is empty,
+    /// zero is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[]), 0);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[123]), 123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[128]), -128);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[128, 0]), 128);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[255, 255, 255]), -1);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[0xcc, 0xed]), -0x1234);
+    /// ```
+    pub fn from_twos_complement_bytes_le(bytes: &[u8]) -> Integer {
+        match bytes.last() {
+            None => Integer::ZERO,
+            Some(last) => {
+                let mut bytes = bytes.to_vec();
+                // Sign-extend to a whole number of limbs
+                let limb_bytes = usize::wrapping_from(Limb::WIDTH >> 3);
+                let remainder = bytes.len() % limb_bytes;
+                if remainder != 0 {
+                    let extension = if last.get_highest_bit() { u8::MAX } else { 0 };
+                    bytes.resize(bytes.len() + limb_bytes - remainder, extension);
+                }
+                Integer::from_owned_twos_complement_limbs_asc(Limb::vec_from_other_type_slice(
+                    &bytes,
+                ))
+            }
+        }
+    }
+
+    /// Converts a slice of bytes in two's complement and big-endian order to an [`Integer`].
+    /// Less-significant bytes have higher indices in the input slice.
+    ///
+    /// The most significant bit of the bytes indicates the sign; if the bit is zero, the
+    /// [`Integer`] is non-negative, and if the bit is one it is negative. If the slice is empty,
+    /// zero is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[]), 0);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[123]), 123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[128]), -128);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[0, 128]), 128);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[255, 255, 255]), -1);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[0xed, 0xcc]), -0x1234);
+    /// ```
+    pub fn from_twos_complement_bytes_be(bytes: &[u8]) -> Integer {
+        let bytes: Vec<u8> = bytes.iter().rev().copied().collect();
+        Integer::from_twos_complement_bytes_le(&bytes)
+    }
+
+    /// Returns the sign of an [`Integer`] and the bytes of its absolute value in little-endian
+    /// order. The sign is `true` if the [`Integer`] is non-negative.
+    ///
+    /// See [`Natural::to_bytes_le`] for details about the bytes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::ZERO.to_sign_and_bytes_le(), (true, vec![]));
+    /// assert_eq!(Integer::from(0x1234).to_sign_and_bytes_le(), (true, vec![0x34, 0x12]));
+    /// assert_eq!(Integer::from(-0x1234).to_sign_and_bytes_le(), (false, vec![0x34, 0x12]));
+    /// ```
+    pub fn to_sign_and_bytes_le(&self) -> (bool, Vec<u8>) {
+        (self.sign, self.abs.to_bytes_le())
+    }
+
+    /// Returns the sign of an [`Integer`] and the bytes of its absolute value in big-endian
+    /// order. The sign is `true` if the [`Integer`] is non-negative.
+    ///
+    /// See [`Natural::to_bytes_be`] for details about the bytes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::ZERO.to_sign_and_bytes_be(), (true, vec![]));
+    /// assert_eq!(Integer::from(0x1234).to_sign_and_bytes_be(), (true, vec![0x12, 0x34]));
+    /// assert_eq!(Integer::from(-0x1234).to_sign_and_bytes_be(), (false, vec![0x12, 0x34]));
+    /// ```
+    pub fn to_sign_and_bytes_be(&self) -> (bool, Vec<u8>) {
+        (self.sign, self.abs.to_bytes_be())
+    }
+
+    /// Converts a sign and the bytes of an absolute value, in little-endian order, to an
+    /// [`Integer`]. The sign indicates whether the [`Integer`] should be non-negative; if the
+    /// absolute value is zero, the [`Integer`] is zero regardless of the sign.
+    ///
+    /// Trailing zero bytes are allowed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_sign_and_bytes_le(false, &[]), 0);
+    /// assert_eq!(Integer::from_sign_and_bytes_le(true, &[0x34, 0x12]), 0x1234);
+    /// assert_eq!(Integer::from_sign_and_bytes_le(false, &[0x34, 0x12, 0]), -0x1234);
+    /// ```
+    pub fn from_sign_and_bytes_le(sign: bool, bytes: &[u8]) -> Integer {
+        Integer::from_sign_and_abs(sign, Natural::from_bytes_le(bytes))
+    }
+
+    /// Converts a sign and the bytes of an absolute value, in big-endian order, to an
+    /// [`Integer`]. The sign indicates whether the [`Integer`] should be non-negative; if the
+    /// absolute value is zero, the [`Integer`] is zero regardless of the sign.
+    ///
+    /// Leading zero bytes are allowed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_sign_and_bytes_be(false, &[]), 0);
+    /// assert_eq!(Integer::from_sign_and_bytes_be(true, &[0x12, 0x34]), 0x1234);
+    /// assert_eq!(Integer::from_sign_and_bytes_be(false, &[0, 0x12, 0x34]), -0x1234);
+    /// ```
+    pub fn from_sign_and_bytes_be(sign: bool, bytes: &[u8]) -> Integer {
+        Integer::from_sign_and_abs(sign, Natural::from_bytes_be(bytes))
+    }
+}
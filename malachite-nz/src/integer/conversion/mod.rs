@@ -1,3 +1,6 @@
+/// Functions for converting [`Integer`](crate::integer::Integer)s to and from bytes, either in
+/// two's complement or as a sign and the bytes of the absolute value.
+pub mod bytes;
 /// An implementation of the [`From`] trait for converting a [`bool`] to an
 /// [`Integer`](crate::integer::Integer).
 pub mod from_bool;
@@ -180,6 +183,14 @@ pub mod is_integer;
 /// [`ConvertibleFrom`](malachite_base::num::conversion::traits::ConvertibleFrom), and
 /// [`SaturatingFrom`](malachite_base::num::conversion::traits::SaturatingFrom).
 pub mod natural_from_integer;
+/// Implementations of traits from [num-traits](https://docs.rs/num-traits) and
+/// [num-integer](https://docs.rs/num-integer), such as [`Num`](::num_traits::Num) and
+/// [`Integer`](::num_integer::Integer), for [`Integer`](crate::integer::Integer). This allows
+/// [`Integer`](crate::integer::Integer)s to be used in generic code written against those traits.
+///
+/// These implementations are only available when the `enable_num_traits` feature is enabled.
+#[cfg(feature = "enable_num_traits")]
+pub mod num_traits;
 /// Implementations of traits for converting an [`Integer`](crate::integer::Integer) to a primitive
 /// float.
 ///
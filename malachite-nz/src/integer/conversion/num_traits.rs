@@ -0,0 +1,389 @@
+use crate::integer::Integer;
+use crate::natural::conversion::num_traits::FromStrRadixError;
+use malachite_base::num::arithmetic::traits::{
+    Abs, CeilingRoot, DivMod, DivRem, DivRound, DivisibleBy, FloorRoot, FloorSqrt, Gcd, Lcm, Mod,
+    Parity, Pow, Sign,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::{FromStringBase, RoundingFrom, WrappingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::Ordering;
+
+impl num_traits::Zero for Integer {
+    /// Returns zero. See [`Zero::ZERO`].
+    #[inline]
+    fn zero() -> Integer {
+        Integer::ZERO
+    }
+
+    /// Determines whether an [`Integer`] is zero.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == 0u32
+    }
+}
+
+impl num_traits::One for Integer {
+    /// Returns one. See [`One::ONE`].
+    #[inline]
+    fn one() -> Integer {
+        Integer::ONE
+    }
+
+    /// Determines whether an [`Integer`] is one.
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == 1u32
+    }
+}
+
+impl num_traits::Num for Integer {
+    type FromStrRadixErr = FromStrRadixError;
+
+    /// Converts a string in the given radix to an [`Integer`]. See
+    /// [`FromStringBase::from_string_base`].
+    ///
+    /// Returns [`FromStrRadixError::InvalidRadix`] if `radix` is less than 2 or greater than 36,
+    /// and [`FromStrRadixError::InvalidString`] if `s` is not a valid representation of an
+    /// [`Integer`] in that radix.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Integer, FromStrRadixError> {
+        if !(2..=36).contains(&radix) {
+            return Err(FromStrRadixError::InvalidRadix);
+        }
+        Integer::from_string_base(u8::wrapping_from(radix), s)
+            .ok_or(FromStrRadixError::InvalidString)
+    }
+}
+
+impl num_traits::Signed for Integer {
+    /// Returns the absolute value of an [`Integer`]. See [`Abs::abs`].
+    #[inline]
+    fn abs(&self) -> Integer {
+        Abs::abs(self)
+    }
+
+    /// Returns the difference of two [`Integer`]s if it is positive, and zero otherwise.
+    #[inline]
+    fn abs_sub(&self, other: &Integer) -> Integer {
+        if self <= other {
+            Integer::ZERO
+        } else {
+            self - other
+        }
+    }
+
+    /// Returns 1, 0, or -1, depending on whether an [`Integer`] is positive, zero, or negative.
+    #[inline]
+    fn signum(&self) -> Integer {
+        match self.sign() {
+            Ordering::Less => Integer::NEGATIVE_ONE,
+            Ordering::Equal => Integer::ZERO,
+            Ordering::Greater => Integer::ONE,
+        }
+    }
+
+    /// Determines whether an [`Integer`] is greater than zero.
+    #[inline]
+    fn is_positive(&self) -> bool {
+        *self > 0u32
+    }
+
+    /// Determines whether an [`Integer`] is less than zero.
+    #[inline]
+    fn is_negative(&self) -> bool {
+        *self < 0u32
+    }
+}
+
+macro_rules! impl_pow {
+    ($t: ident) => {
+        impl num_traits::Pow<$t> for Integer {
+            type Output = Integer;
+
+            /// Raises an [`Integer`] to a power, taking it by value. See [`Pow::pow`].
+            #[inline]
+            fn pow(self, exp: $t) -> Integer {
+                Pow::pow(self, u64::from(exp))
+            }
+        }
+
+        impl<'a> num_traits::Pow<$t> for &'a Integer {
+            type Output = Integer;
+
+            /// Raises an [`Integer`] to a power, taking it by reference. See [`Pow::pow`].
+            #[inline]
+            fn pow(self, exp: $t) -> Integer {
+                Pow::pow(self, u64::from(exp))
+            }
+        }
+    };
+}
+impl_pow!(u8);
+impl_pow!(u16);
+impl_pow!(u32);
+impl_pow!(u64);
+
+impl num_traits::ToPrimitive for Integer {
+    /// Converts an [`Integer`] to an [`i64`], returning `None` if it is out of range.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self).ok()
+    }
+
+    /// Converts an [`Integer`] to an [`i128`], returning `None` if it is out of range.
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self).ok()
+    }
+
+    /// Converts an [`Integer`] to a [`u64`], returning `None` if it is out of range.
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self).ok()
+    }
+
+    /// Converts an [`Integer`] to a [`u128`], returning `None` if it is out of range.
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self).ok()
+    }
+
+    /// Converts an [`Integer`] to the nearest [`f32`]. An [`Integer`] whose absolute value is
+    /// greater than the maximum finite [`f32`] is converted to the maximum or minimum finite
+    /// [`f32`].
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f32::rounding_from(self, RoundingMode::Nearest).0)
+    }
+
+    /// Converts an [`Integer`] to the nearest [`f64`]. An [`Integer`] whose absolute value is
+    /// greater than the maximum finite [`f64`] is converted to the maximum or minimum finite
+    /// [`f64`].
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f64::rounding_from(self, RoundingMode::Nearest).0)
+    }
+}
+
+impl num_traits::FromPrimitive for Integer {
+    /// Converts an [`i64`] to an [`Integer`].
+    #[inline]
+    fn from_i64(n: i64) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    /// Converts an [`i128`] to an [`Integer`].
+    #[inline]
+    fn from_i128(n: i128) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    /// Converts a [`u64`] to an [`Integer`].
+    #[inline]
+    fn from_u64(n: u64) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    /// Converts a [`u128`] to an [`Integer`].
+    #[inline]
+    fn from_u128(n: u128) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    /// Converts an [`f32`] to an [`Integer`], rounding toward zero. Returns `None` if the [`f32`]
+    /// is NaN or infinite.
+    #[inline]
+    fn from_f32(n: f32) -> Option<Integer> {
+        num_traits::FromPrimitive::from_f64(f64::from(n))
+    }
+
+    /// Converts an [`f64`] to an [`Integer`], rounding toward zero. Returns `None` if the [`f64`]
+    /// is NaN or infinite.
+    fn from_f64(n: f64) -> Option<Integer> {
+        if n.is_finite() {
+            Some(Integer::rounding_from(n, RoundingMode::Down).0)
+        } else {
+            None
+        }
+    }
+}
+
+impl num_traits::CheckedAdd for Integer {
+    /// Adds two [`Integer`]s. The result is never `None`.
+    #[inline]
+    fn checked_add(&self, other: &Integer) -> Option<Integer> {
+        Some(self + other)
+    }
+}
+
+impl num_traits::CheckedSub for Integer {
+    /// Subtracts an [`Integer`] from another [`Integer`]. The result is never `None`.
+    #[inline]
+    fn checked_sub(&self, other: &Integer) -> Option<Integer> {
+        Some(self - other)
+    }
+}
+
+impl num_traits::CheckedMul for Integer {
+    /// Multiplies two [`Integer`]s. The result is never `None`.
+    #[inline]
+    fn checked_mul(&self, other: &Integer) -> Option<Integer> {
+        Some(self * other)
+    }
+}
+
+impl num_traits::CheckedDiv for Integer {
+    /// Divides an [`Integer`] by another [`Integer`], returning `None` if the divisor is zero. The
+    /// quotient is rounded toward zero.
+    #[inline]
+    fn checked_div(&self, other: &Integer) -> Option<Integer> {
+        if *other == 0u32 {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+}
+
+impl num_traits::CheckedRem for Integer {
+    /// Computes the remainder of dividing an [`Integer`] by another [`Integer`], returning `None`
+    /// if the divisor is zero. The quotient is rounded toward zero, so the remainder has the same
+    /// sign as the dividend.
+    #[inline]
+    fn checked_rem(&self, other: &Integer) -> Option<Integer> {
+        if *other == 0u32 {
+            None
+        } else {
+            Some(self % other)
+        }
+    }
+}
+
+impl num_traits::CheckedNeg for Integer {
+    /// Negates an [`Integer`]. The result is never `None`.
+    #[inline]
+    fn checked_neg(&self) -> Option<Integer> {
+        Some(-self)
+    }
+}
+
+impl num_integer::Integer for Integer {
+    /// Divides an [`Integer`] by another [`Integer`], rounding the quotient toward negative
+    /// infinity.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_floor(&self, other: &Integer) -> Integer {
+        self.div_round(other, RoundingMode::Floor).0
+    }
+
+    /// Computes the remainder of dividing an [`Integer`] by another [`Integer`], with the quotient
+    /// rounded toward negative infinity. The remainder has the same sign as the divisor. See
+    /// [`Mod::mod_op`].
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn mod_floor(&self, other: &Integer) -> Integer {
+        self.mod_op(other)
+    }
+
+    /// Divides an [`Integer`] by another [`Integer`], returning the quotient and remainder. The
+    /// quotient is rounded toward negative infinity. See [`DivMod::div_mod`].
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_mod_floor(&self, other: &Integer) -> (Integer, Integer) {
+        self.div_mod(other)
+    }
+
+    /// Divides an [`Integer`] by another [`Integer`], rounding the quotient toward positive
+    /// infinity.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_ceil(&self, other: &Integer) -> Integer {
+        self.div_round(other, RoundingMode::Ceiling).0
+    }
+
+    /// Computes the GCD (greatest common divisor) of two [`Integer`]s. The GCD is always
+    /// non-negative.
+    #[inline]
+    fn gcd(&self, other: &Integer) -> Integer {
+        Integer::from(Gcd::gcd(self.unsigned_abs_ref(), other.unsigned_abs_ref()))
+    }
+
+    /// Computes the LCM (least common multiple) of two [`Integer`]s. The LCM is always
+    /// non-negative.
+    #[inline]
+    fn lcm(&self, other: &Integer) -> Integer {
+        Integer::from(Lcm::lcm(self.unsigned_abs_ref(), other.unsigned_abs_ref()))
+    }
+
+    /// Determines whether an [`Integer`] is divisible by another [`Integer`].
+    #[inline]
+    fn divides(&self, other: &Integer) -> bool {
+        self.divisible_by(other)
+    }
+
+    /// Determines whether an [`Integer`] is divisible by another [`Integer`]. Zero is a multiple
+    /// of every [`Integer`], including zero. See [`DivisibleBy::divisible_by`].
+    #[inline]
+    fn is_multiple_of(&self, other: &Integer) -> bool {
+        self.divisible_by(other)
+    }
+
+    /// Determines whether an [`Integer`] is even.
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.even()
+    }
+
+    /// Determines whether an [`Integer`] is odd.
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.odd()
+    }
+
+    /// Divides an [`Integer`] by another [`Integer`], returning the quotient and remainder. The
+    /// quotient is rounded toward zero. See [`DivRem::div_rem`].
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_rem(&self, other: &Integer) -> (Integer, Integer) {
+        DivRem::div_rem(self, other)
+    }
+}
+
+impl num_integer::Roots for Integer {
+    /// Returns the `n`th root of an [`Integer`], rounded toward zero.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero, or if `n` is even and the [`Integer`] is negative.
+    fn nth_root(&self, n: u32) -> Integer {
+        if *self >= 0u32 {
+            self.floor_root(u64::from(n))
+        } else {
+            self.ceiling_root(u64::from(n))
+        }
+    }
+
+    /// Returns the floor of the square root of an [`Integer`]. See [`FloorSqrt::floor_sqrt`].
+    ///
+    /// # Panics
+    /// Panics if the [`Integer`] is negative.
+    #[inline]
+    fn sqrt(&self) -> Integer {
+        self.floor_sqrt()
+    }
+
+    /// Returns the cube root of an [`Integer`], rounded toward zero.
+    #[inline]
+    fn cbrt(&self) -> Integer {
+        num_integer::Roots::nth_root(self, 3)
+    }
+}
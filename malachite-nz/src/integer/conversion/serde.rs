@@ -1,11 +1,17 @@
 use crate::integer::{Integer, SerdeInteger};
+#[cfg(feature = "serde")]
+use crate::natural::conversion::serde::BytesVisitor;
 use crate::natural::Natural;
 use malachite_base::num::conversion::traits::FromStringBase;
+#[cfg(feature = "serde")]
+use serde::de::Error;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::convert::TryFrom;
 
-impl From<Integer> for SerdeInteger {
+impl<'a> From<&'a Integer> for SerdeInteger {
     #[inline]
-    fn from(x: Integer) -> SerdeInteger {
+    fn from(x: &'a Integer) -> SerdeInteger {
         SerdeInteger(format!("{x:#x}"))
     }
 }
@@ -41,3 +47,61 @@ impl TryFrom<SerdeInteger> for Integer {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+impl Serialize for Integer {
+    /// Serializes an [`Integer`].
+    ///
+    /// Human-readable formats, like JSON, get a hexadecimal string such as `"-0x64"`. Binary
+    /// formats get a length-prefixed byte array consisting of a sign byte (0 if the [`Integer`] is
+    /// non-negative and 1 if it is negative), followed by the bytes of its absolute value in
+    /// ascending order, with no trailing zeros.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            SerdeInteger::from(self).serialize(serializer)
+        } else {
+            let abs_bytes = self.abs.to_bytes_le();
+            let mut bytes = Vec::with_capacity(abs_bytes.len() + 1);
+            bytes.push(u8::from(!self.sign));
+            bytes.extend_from_slice(&abs_bytes);
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Integer {
+    /// Deserializes an [`Integer`] from the representation produced by its [`Serialize`]
+    /// implementation.
+    ///
+    /// In binary formats, trailing zero bytes are accepted and ignored, and a negative zero is
+    /// deserialized as zero. An empty byte array, or a sign byte other than 0 or 1, is an error.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the length of the input.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Integer, D::Error> {
+        if deserializer.is_human_readable() {
+            Integer::try_from(SerdeInteger::deserialize(deserializer)?).map_err(D::Error::custom)
+        } else {
+            let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+            match bytes.split_first() {
+                Some((&sign, abs)) if sign < 2 => {
+                    Ok(Integer::from_sign_and_bytes_le(sign == 0, abs))
+                }
+                Some((&sign, _)) => Err(D::Error::custom(format!("Invalid sign byte {sign}"))),
+                None => Err(D::Error::custom("Missing sign byte")),
+            }
+        }
+    }
+}
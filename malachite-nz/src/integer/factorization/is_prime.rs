@@ -0,0 +1,46 @@
+use crate::integer::Integer;
+use malachite_base::num::factorization::traits::IsPrime;
+
+impl IsPrime for Integer {
+    /// Determines whether an [`Integer`] is prime. Negative [`Integer`]s are never prime.
+    ///
+    /// Small factors are removed by trial division, and then the Baillie-PSW test is applied: a
+    /// strong Fermat test to base 2, followed by a strong Lucas test. This is known to be correct
+    /// for all numbers less than $2^{64}$. For larger numbers, no counterexample is known.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    #[inline]
+    fn is_prime(&self) -> bool {
+        *self > 0u32 && self.unsigned_abs_ref().is_prime()
+    }
+
+    /// Determines whether an [`Integer`] is probably prime, running `reps` additional strong
+    /// Fermat tests after the Baillie-PSW test. Negative [`Integer`]s are never prime.
+    ///
+    /// The additional tests use the odd primes $3, 5, 7, \ldots$ as bases. Since the Baillie-PSW
+    /// test is known to be correct for all numbers less than $2^{64}$, the additional tests are
+    /// only run for larger numbers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `reps`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_probable_prime).
+    #[inline]
+    fn is_probable_prime(&self, reps: u64) -> bool {
+        *self > 0u32 && self.unsigned_abs_ref().is_probable_prime(reps)
+    }
+}
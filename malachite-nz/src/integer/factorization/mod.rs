@@ -0,0 +1,27 @@
+/// An implementation of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime), a trait
+/// for testing whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::IsPrime;
+/// use malachite_nz::integer::Integer;
+///
+/// assert_eq!(Integer::from(0).is_prime(), false);
+/// assert_eq!(Integer::from(1).is_prime(), false);
+/// assert_eq!(Integer::from(97).is_prime(), true);
+/// assert_eq!(Integer::from(-97).is_prime(), false);
+/// assert_eq!((Integer::from(2).pow(127) - Integer::from(1)).is_prime(), true);
+/// ```
+///
+/// # is_probable_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::IsPrime;
+/// use malachite_nz::integer::Integer;
+///
+/// assert_eq!(Integer::from(10).is_probable_prime(5), false);
+/// assert_eq!(Integer::from(-7).is_probable_prime(5), false);
+/// assert_eq!((Integer::from(2).pow(521) - Integer::from(1)).is_probable_prime(10), true);
+/// ```
+pub mod is_prime;
@@ -199,7 +199,7 @@ impl Natural {
     fn neg_get_bits_owned(self, start: u64, end: u64) -> Natural {
         Natural::from_owned_limbs_asc(match self {
             Natural(Small(small)) => limbs_neg_limb_get_bits(small, start, end),
-            Natural(Large(limbs)) => limbs_vec_neg_get_bits(limbs, start, end),
+            Natural(Large(limbs)) => limbs_vec_neg_get_bits(limbs.into_vec(), start, end),
         })
     }
 
@@ -226,8 +226,12 @@ impl Natural {
         }
         let limbs = self.promote_in_place();
         match *bits {
-            Natural(Small(small_bits)) => limbs_neg_assign_bits(limbs, start, end, &[small_bits]),
-            Natural(Large(ref bits_limbs)) => limbs_neg_assign_bits(limbs, start, end, bits_limbs),
+            Natural(Small(small_bits)) => {
+                limbs_neg_assign_bits(limbs.as_mut_vec(), start, end, &[small_bits])
+            }
+            Natural(Large(ref bits_limbs)) => {
+                limbs_neg_assign_bits(limbs.as_mut_vec(), start, end, bits_limbs)
+            }
         }
         self.trim();
     }
@@ -6,6 +6,7 @@ use crate::natural::arithmetic::sub::{
     limbs_sub, limbs_sub_greater_in_place_left, limbs_sub_greater_to_out, limbs_sub_limb,
     limbs_sub_limb_in_place, limbs_sub_limb_to_out, limbs_vec_sub_in_place_right,
 };
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::logic::not::limbs_not_in_place;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
@@ -988,13 +989,13 @@ impl Natural {
             Natural(Small(ref mut small)) => {
                 let result = small.wrapping_neg() ^ other;
                 if result == 0 {
-                    *self = Natural(Large(vec![0, 1]));
+                    *self = Natural(Large(LimbVec::from_slice(&[0, 1])));
                 } else {
                     *small = result.wrapping_neg();
                 }
             }
             Natural(Large(ref mut limbs)) => {
-                limbs_vec_neg_xor_limb_in_place(limbs, other);
+                limbs_vec_neg_xor_limb_in_place(limbs.as_mut_vec(), other);
                 self.trim();
             }
         }
@@ -1006,7 +1007,7 @@ impl Natural {
             Natural(Small(ref small)) => {
                 let result = small.wrapping_neg() ^ other;
                 Natural(if result == 0 {
-                    Large(vec![0, 1])
+                    Large(LimbVec::from_slice(&[0, 1]))
                 } else {
                     Small(result.wrapping_neg())
                 })
@@ -1022,13 +1023,13 @@ impl Natural {
             Natural(Small(ref mut small)) => {
                 let result = *small ^ other;
                 if result == 0 {
-                    *self = Natural(Large(vec![0, 1]))
+                    *self = Natural(Large(LimbVec::from_slice(&[0, 1])))
                 } else {
                     *small = result.wrapping_neg();
                 }
             }
             Natural(Large(ref mut limbs)) => {
-                limbs_vec_pos_xor_limb_neg_in_place(limbs, other);
+                limbs_vec_pos_xor_limb_neg_in_place(limbs.as_mut_vec(), other);
                 self.trim();
             }
         }
@@ -1039,12 +1040,12 @@ impl Natural {
             Natural(Small(small)) => {
                 let result = small ^ other;
                 if result == 0 {
-                    Large(vec![0, 1])
+                    Large(LimbVec::from_slice(&[0, 1]))
                 } else {
                     Small(result.wrapping_neg())
                 }
             }
-            Natural(Large(ref limbs)) => Large(limbs_pos_xor_limb_neg(limbs, other)),
+            Natural(Large(ref limbs)) => Large(limbs_pos_xor_limb_neg(limbs, other).into()),
         })
     }
 
@@ -1075,7 +1076,7 @@ impl Natural {
             }
             (_, Natural(Small(y))) => self.xor_assign_pos_limb_neg(y.wrapping_neg()),
             (Natural(Large(ref mut xs)), Natural(Large(ys))) => {
-                if limbs_xor_pos_neg_in_place_either(xs, ys) {
+                if limbs_xor_pos_neg_in_place_either(xs.as_mut_vec(), ys.as_mut_vec()) {
                     *self = other;
                 }
                 self.trim();
@@ -1088,7 +1089,7 @@ impl Natural {
             (Natural(Small(x)), _) => *self = other.xor_neg_limb_pos(*x),
             (_, Natural(Small(y))) => self.xor_assign_pos_limb_neg(y.wrapping_neg()),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_xor_pos_neg_in_place_left(xs, ys);
+                limbs_xor_pos_neg_in_place_left(xs.as_mut_vec(), ys);
                 self.trim();
             }
         }
@@ -1104,7 +1105,7 @@ impl Natural {
             (Natural(Small(x)), _) => *self = other.xor_pos_limb_neg(x.wrapping_neg()),
             (_, Natural(Small(y))) => self.xor_assign_neg_limb_pos(*y),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_xor_pos_neg_in_place_right(ys, xs);
+                limbs_xor_pos_neg_in_place_right(ys, xs.as_mut_vec());
                 self.trim();
             }
         }
@@ -1138,7 +1139,7 @@ impl Natural {
             (Natural(Small(x)), _) => *self = other.xor_neg_limb_neg(x.wrapping_neg()),
             (_, Natural(Small(y))) => self.xor_assign_neg_limb_neg(y.wrapping_neg()),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_xor_neg_neg_in_place_left(xs, ys);
+                limbs_xor_neg_neg_in_place_left(xs.as_mut_vec(), ys);
                 self.trim();
             }
         }
@@ -7,11 +7,6 @@ use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
 /// Any `Integer` whose absolute value is small enough to fit into a [`Limb`](crate#limbs) is
 /// represented inline. Only integers outside this range incur the costs of heap-allocation.
 #[derive(Clone, Hash, Eq, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(
-    feature = "serde",
-    serde(try_from = "SerdeInteger", into = "SerdeInteger")
-)]
 pub struct Integer {
     // whether the `Integer` is non-negative
     pub(crate) sign: bool,
@@ -108,6 +103,8 @@ pub mod comparison;
 pub mod conversion;
 /// Iterators that generate [`Integer`]s without repetition.
 pub mod exhaustive;
+/// Traits for primality testing.
+pub mod factorization;
 /// Traits for logic and bit manipulation.
 pub mod logic;
 #[cfg(feature = "random")]
@@ -28,8 +28,9 @@
 //!   [Schönhage-Strassen (FFT) multiplication](https://en.wikipedia.org/wiki/Schonhage-Strassen_algorithm),
 //!   depending on the input size.
 //! - Small numbers are also handled efficiently. Any [`Natural`](natural::Natural) smaller than
-//!   $2^{64}$ does not use any allocated memory, and working with such numbers is almost as fast
-//!   as working with primitive integers. As a result, Malachite does not provide implementations
+//!   $2^{256}$ does not use any allocated memory, and working with such numbers is much faster
+//!   than working with heap-allocated ones; those smaller than $2^{64}$ are almost as fast as
+//!   primitive integers. As a result, Malachite does not provide implementations
 //!   for _e.g._ adding a [`Natural`](natural::Natural) to a [`u64`], since the [`u64`] can be
 //!   converted to a [`Natural`](natural::Natural) very cheaply.
 //! - Malachite handles memory intelligently. Consider the problem of adding a 1000-bit
@@ -148,6 +149,10 @@ extern crate malachite_base;
 #[cfg(feature = "serde")]
 #[macro_use]
 extern crate serde;
+#[cfg(feature = "enable_num_traits")]
+extern crate num_integer;
+#[cfg(feature = "enable_num_traits")]
+extern crate num_traits;
 
 #[cfg(feature = "test_build")]
 extern crate num;
@@ -174,6 +179,9 @@ extern crate embed_doc_image;
 /// [`Natural`](natural::Natural), a type representing arbitrarily large non-negative integers.
 #[macro_use]
 pub mod natural;
+/// [`UBig`](fixed::UBig) and [`IBig`](fixed::IBig), fixed-width integer types that are stored
+/// inline rather than on the heap.
+pub mod fixed;
 /// [`Integer`](integer::Integer), a type representing integers with arbitrarily large absolute
 /// values.
 pub mod integer;
@@ -1,12 +1,14 @@
 use crate::natural::arithmetic::shl::{limbs_shl, limbs_vec_shl_in_place};
+use crate::natural::limb_vec::{LimbVec, INLINE_LIMB_COUNT};
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
 use malachite_base::num::arithmetic::traits::OverflowingAddAssign;
 use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
-use std::iter::Sum;
-use std::ops::{Add, AddAssign};
+use std::cmp::max;
+use std::iter::{once, Sum};
+use std::ops::{Add, AddAssign, DerefMut};
 
 // Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
 // limbs of the sum of the `Natural` and a `Limb`.
@@ -193,6 +195,27 @@ pub_crate_test! {limbs_add(xs: &[Limb], ys: &[Limb]) -> Vec<Limb> {
     }
 }}
 
+// Interpreting two slices of `Limb`s as the limbs (in ascending order) of two `Natural`s, returns
+// the limbs of the sum of the `Natural`s as a `LimbVec`. This is the same as `limbs_add`, except
+// that if the sum is short enough, no memory is allocated for it.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), ys.len())`.
+fn limbs_add_limb_vec(xs: &[Limb], ys: &[Limb]) -> LimbVec {
+    let len = max(xs.len(), ys.len());
+    let mut out = LimbVec::zeroed(len + 1);
+    if limbs_add_to_out(&mut out, xs, ys) {
+        out[len] = 1;
+    } else {
+        out.truncate(len);
+    }
+    out
+}
+
 // Interpreting two equal-length slices of `Limb`s as the limbs (in ascending order) of two
 // `Natural`s, writes the `xs.len()` least-significant limbs of the sum of the `Natural`s to an
 // output slice. The output must be at least as long as one of the input slices. Returns whether
@@ -429,7 +452,8 @@ pub_test! {limbs_slice_add_in_place_either(xs: &mut [Limb], ys: &mut [Limb]) ->
 // Interpreting two `Vec`s of `Limb`s as the limbs (in ascending order) of two `Natural`s, writes
 // the limbs of the sum of the `Natural`s to the longer slice (or the first one, if they are
 // equally long). Returns a `bool` which is `false` when the output is to the first `Vec` and
-// `true` when it's to the second `Vec`.
+// `true` when it's to the second `Vec`. Any growable sequence of limbs, such as the `LimbVec` of a
+// `Natural`, may be used in place of a `Vec`.
 //
 // # Worst-case complexity
 // $T(n) = O(n)$
@@ -440,15 +464,18 @@ pub_test! {limbs_slice_add_in_place_either(xs: &mut [Limb], ys: &mut [Limb]) ->
 //
 // This is equivalent to `mpz_add` from `mpz/aors.h`, GMP 6.2.1, where both inputs are non-negative
 // and the output is written to the longer input.
-pub_test! {limbs_vec_add_in_place_either(xs: &mut Vec<Limb>, ys: &mut Vec<Limb>) -> bool {
+pub_test! {limbs_vec_add_in_place_either<V: DerefMut<Target = [Limb]> + Extend<Limb>>(
+    xs: &mut V,
+    ys: &mut V,
+) -> bool {
     if xs.len() >= ys.len() {
         if limbs_slice_add_greater_in_place_left(xs, ys) {
-            xs.push(1);
+            xs.extend(once(1));
         }
         false
     } else {
         if limbs_slice_add_greater_in_place_left(ys, xs) {
-            ys.push(1);
+            ys.extend(once(1));
         }
         true
     }
@@ -523,9 +550,11 @@ impl Natural {
             (x, 0) => x.clone(),
             (Natural(Small(small)), other) => match small.overflowing_add(other) {
                 (sum, false) => Natural::from(sum),
-                (sum, true) => Natural(Large(vec![sum, 1])),
+                (sum, true) => Natural(Large(LimbVec::from_slice(&[sum, 1]))),
             },
-            (Natural(Large(ref limbs)), other) => Natural(Large(limbs_add_limb(limbs, other))),
+            (Natural(Large(ref limbs)), other) => {
+                Natural(Large(limbs_add_limb(limbs, other).into()))
+            }
         }
     }
 
@@ -536,13 +565,15 @@ impl Natural {
             (&mut Natural(Small(ref mut small)), other) => {
                 let (sum, overflow) = small.overflowing_add(other);
                 if overflow {
-                    *self = Natural(Large(vec![sum, 1]));
+                    *self = Natural(Large(LimbVec::from_slice(&[sum, 1])));
                 } else {
                     *small = sum;
                 }
             }
             (&mut Natural(Large(ref mut limbs)), other) => {
-                limbs_vec_add_limb_in_place(limbs, other);
+                if limbs_slice_add_limb_in_place(limbs, other) {
+                    limbs.push(1);
+                }
             }
         }
     }
@@ -553,7 +584,7 @@ impl Natural {
             *self += Natural::from(y);
             return;
         }
-        let xs = self.promote_in_place();
+        let xs = self.promote_in_place().as_mut_vec();
         if xs.len() <= i {
             xs.resize(i + 1, 0);
         }
@@ -711,7 +742,9 @@ impl<'a, 'b> Add<&'a Natural> for &'b Natural {
         match (self, other) {
             (x, &Natural(Small(y))) => x.add_limb_ref(y),
             (&Natural(Small(x)), y) => y.add_limb_ref(x),
-            (&Natural(Large(ref xs)), &Natural(Large(ref ys))) => Natural(Large(limbs_add(xs, ys))),
+            (&Natural(Large(ref xs)), &Natural(Large(ref ys))) => {
+                Natural::from_limb_vec(limbs_add_limb_vec(xs, ys))
+            }
         }
     }
 }
@@ -792,7 +825,15 @@ impl<'a> AddAssign<&'a Natural> for Natural {
             (x, &Natural(Small(y))) => x.add_assign_limb(y),
             (&mut Natural(Small(x)), y) => *self = y.add_limb_ref(x),
             (&mut Natural(Large(ref mut xs)), &Natural(Large(ref ys))) => {
-                limbs_vec_add_in_place_left(xs, ys);
+                if xs.len() >= ys.len() {
+                    if limbs_slice_add_greater_in_place_left(xs, ys) {
+                        xs.push(1);
+                    }
+                } else if ys.len() < INLINE_LIMB_COUNT {
+                    *xs = limbs_add_limb_vec(xs, ys);
+                } else {
+                    limbs_vec_add_in_place_left(xs.as_mut_vec(), ys);
+                }
             }
         }
     }
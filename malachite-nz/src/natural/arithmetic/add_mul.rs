@@ -301,7 +301,7 @@ impl Natural {
             (x, y, 1) => x + y,
             (x, &Natural::ONE, z) => x + Natural::from(z),
             (Natural(Large(ref xs)), Natural(Large(ref ys)), z) => {
-                Natural(Large(limbs_add_mul_limb(xs, ys, z)))
+                Natural(Large(limbs_add_mul_limb(xs, ys, z).into()))
             }
             (x, y, z) => x + y * Natural::from(z),
         }
@@ -313,7 +313,7 @@ impl Natural {
             (x, _, 1) => *x += y,
             (x, &mut Natural::ONE, z) => *x += Natural::from(z),
             (Natural(Large(ref mut xs)), Natural(Large(ref mut ys)), z) => {
-                if limbs_vec_add_mul_limb_in_place_either(xs, ys, z) {
+                if limbs_vec_add_mul_limb_in_place_either(xs.as_mut_vec(), ys.as_mut_vec(), z) {
                     *self = y;
                 }
             }
@@ -327,7 +327,7 @@ impl Natural {
             (x, y, 1) => *x += y,
             (x, &Natural::ONE, z) => *x += Natural::from(z),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), z) => {
-                limbs_vec_add_mul_limb_in_place_left(xs, ys, z);
+                limbs_vec_add_mul_limb_in_place_left(xs.as_mut_vec(), ys, z);
             }
             (x, y, z) => *x += y * Natural::from(z),
         }
@@ -507,7 +507,7 @@ impl<'a, 'b, 'c> AddMul<&'a Natural, &'b Natural> for &'c Natural {
             (x, Natural(Small(y)), z) => x.add_mul_limb_ref_ref(z, *y),
             (x, y, Natural(Small(z))) => x.add_mul_limb_ref_ref(y, *z),
             (Natural(Large(ref xs)), Natural(Large(ref ys)), Natural(Large(ref zs))) => {
-                Natural(Large(limbs_add_mul(xs, ys, zs)))
+                Natural(Large(limbs_add_mul(xs, ys, zs).into()))
             }
         }
     }
@@ -546,7 +546,7 @@ impl AddMulAssign<Natural, Natural> for Natural {
             (_, Natural(Small(y)), _) => self.add_mul_assign_limb(z, *y),
             (_, _, Natural(Small(z))) => self.add_mul_assign_limb(y, *z),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), Natural(Large(ref zs))) => {
-                limbs_add_mul_in_place_left(xs, ys, zs)
+                limbs_add_mul_in_place_left(xs.as_mut_vec(), ys, zs)
             }
         }
     }
@@ -585,7 +585,7 @@ impl<'a> AddMulAssign<Natural, &'a Natural> for Natural {
             (_, Natural(Small(y)), _) => self.add_mul_assign_limb_ref(z, *y),
             (_, _, Natural(Small(z))) => self.add_mul_assign_limb(y, *z),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), Natural(Large(ref zs))) => {
-                limbs_add_mul_in_place_left(xs, ys, zs)
+                limbs_add_mul_in_place_left(xs.as_mut_vec(), ys, zs)
             }
         }
     }
@@ -624,7 +624,7 @@ impl<'a> AddMulAssign<&'a Natural, Natural> for Natural {
             (_, Natural(Small(y)), _) => self.add_mul_assign_limb(z, *y),
             (_, _, Natural(Small(z))) => self.add_mul_assign_limb_ref(y, *z),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), Natural(Large(ref zs))) => {
-                limbs_add_mul_in_place_left(xs, ys, zs)
+                limbs_add_mul_in_place_left(xs.as_mut_vec(), ys, zs)
             }
         }
     }
@@ -663,7 +663,7 @@ impl<'a, 'b> AddMulAssign<&'a Natural, &'b Natural> for Natural {
             (_, Natural(Small(y)), _) => self.add_mul_assign_limb_ref(z, *y),
             (_, _, Natural(Small(z))) => self.add_mul_assign_limb_ref(y, *z),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys)), Natural(Large(ref zs))) => {
-                limbs_add_mul_in_place_left(xs, ys, zs)
+                limbs_add_mul_in_place_left(xs.as_mut_vec(), ys, zs)
             }
         }
     }
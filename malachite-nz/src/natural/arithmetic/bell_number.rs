@@ -0,0 +1,51 @@
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::One;
+
+impl Natural {
+    /// Computes a Bell number.
+    ///
+    /// $B_n$ is the number of ways to partition a set of $n$ elements into nonempty subsets.
+    ///
+    /// $$
+    /// B_n = \sum_{k=0}^n S(n, k).
+    /// $$
+    ///
+    /// where $S(n, k)$ is a Stirling number of the second kind. The computation uses the Bell
+    /// triangle.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::bell_number(0), 1);
+    /// assert_eq!(Natural::bell_number(5), 52);
+    /// assert_eq!(Natural::bell_number(10), 115975);
+    /// assert_eq!(
+    ///     Natural::bell_number(50).to_string(),
+    ///     "185724268771078270438257767181908917499221852770"
+    /// );
+    /// ```
+    pub fn bell_number(n: u64) -> Natural {
+        // Each row of the Bell triangle starts with the last element of the previous row, and
+        // each subsequent element is the sum of the element to its left and the element above
+        // that one. The first element of row i is B_i.
+        let mut row = vec![Natural::ONE];
+        for _ in 0..n {
+            let mut next = Vec::with_capacity(row.len() + 1);
+            next.push(row.last().unwrap().clone());
+            for x in &row {
+                let y = next.last().unwrap() + x;
+                next.push(y);
+            }
+            row = next;
+        }
+        row.swap_remove(0)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, DivExact};
+
+impl Natural {
+    /// Computes a Catalan number.
+    ///
+    /// $C_n$ is, among many other things, the number of binary trees with $n$ internal nodes and
+    /// the number of ways to correctly match $n$ pairs of parentheses.
+    ///
+    /// $$
+    /// C_n = \frac{1}{n+1}\binom{2n}{n}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::catalan_number(0), 1);
+    /// assert_eq!(Natural::catalan_number(5), 42);
+    /// assert_eq!(Natural::catalan_number(10), 16796);
+    /// assert_eq!(
+    ///     Natural::catalan_number(100).to_string(),
+    ///     "896519947090131496687170070074100632420837521538745909320"
+    /// );
+    /// ```
+    pub fn catalan_number(n: u64) -> Natural {
+        Natural::binomial_coefficient(Natural::from(n) << 1u32, Natural::from(n))
+            .div_exact(Natural::from(n + 1))
+    }
+}
@@ -107,7 +107,8 @@ impl Natural {
             }),
             (_, Natural(Small(_))) => true,
             (&mut Natural(Large(ref mut xs)), &Natural(Large(ref ys))) => {
-                let borrow = xs.len() > ys.len() || limbs_vec_sub_in_place_right(ys, xs);
+                let borrow =
+                    xs.len() > ys.len() || limbs_vec_sub_in_place_right(ys, xs.as_mut_vec());
                 if !borrow {
                     self.trim();
                 }
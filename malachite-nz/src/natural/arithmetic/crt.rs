@@ -0,0 +1,223 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivisibleBy, Gcd, ModInverse, ModMul, NegMod, Square,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+
+// Reduces each residue modulo its modulus, checking that the inputs are valid.
+fn reduce_residues(residues: &[Natural], moduli: &[Natural]) -> Vec<Natural> {
+    assert_eq!(
+        residues.len(),
+        moduli.len(),
+        "There must be as many residues as moduli"
+    );
+    residues
+        .iter()
+        .zip(moduli)
+        .map(|(a, m)| {
+            assert_ne!(*m, 0u32, "The moduli must be positive");
+            a % m
+        })
+        .collect()
+}
+
+// Combines x = a mod m and x = b mod n into a single congruence x = c mod lcm(m, n), returning
+// `None` if the two congruences are inconsistent. `a` must be less than `m`, and `b` less than `n`.
+fn crt_combine(a: Natural, m: Natural, b: &Natural, n: &Natural) -> Option<(Natural, Natural)> {
+    let g = (&m).gcd(n);
+    let (diff, diff_negative) = if *b >= a {
+        (b - &a, false)
+    } else {
+        (&a - b, true)
+    };
+    if !(&diff).divisible_by(&g) {
+        return None;
+    }
+    // Solve m * t = b - a mod n by dividing through by g
+    let n_g = n.div_exact(&g);
+    if n_g == 1u32 {
+        return Some((a, m));
+    }
+    let inverse = ((&m).div_exact(&g) % &n_g).mod_inverse(&n_g).unwrap();
+    let mut d = diff.div_exact(g) % &n_g;
+    if diff_negative {
+        d = d.neg_mod(&n_g);
+    }
+    let t = d.mod_mul(inverse, &n_g);
+    let c = a + &m * t;
+    Some((c, m * n_g))
+}
+
+// Returns the solution of the congruences, together with the least common multiple of the moduli.
+pub(crate) fn crt_helper(residues: &[Natural], moduli: &[Natural]) -> Option<(Natural, Natural)> {
+    let residues = reduce_residues(residues, moduli);
+    let mut x = Natural::ZERO;
+    let mut m = Natural::ONE;
+    for (a, n) in residues.iter().zip(moduli) {
+        (x, m) = crt_combine(x, m, a, n)?;
+    }
+    Some((x, m))
+}
+
+// Builds a subproduct tree from the moduli. The first level contains the moduli themselves, and
+// each subsequent level contains the products of adjacent pairs from the previous level; if a level
+// has odd length, its last element is carried up unchanged. The last level contains the product of
+// all the moduli.
+//
+// `limbs_product` isn't used here: it only accepts single-limb factors and only returns the final
+// product, whereas the moduli are arbitrary `Natural`s and every intermediate level is needed.
+fn subproduct_tree(moduli: &[Natural]) -> Vec<Vec<Natural>> {
+    let mut levels = vec![moduli.to_vec()];
+    while levels.last().unwrap().len() > 1 {
+        let next = levels
+            .last()
+            .unwrap()
+            .chunks(2)
+            .map(|pair| {
+                if pair.len() == 2 {
+                    &pair[0] * &pair[1]
+                } else {
+                    pair[0].clone()
+                }
+            })
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+// Returns the solution of the congruences, together with the product of the moduli, which must be
+// pairwise coprime.
+//
+// With M the product of the moduli, the solution is the sum of a_i * s_i * (M / m_i), where s_i is
+// the inverse of M / m_i modulo m_i. The values (M / m_i) mod m_i are found by reducing M modulo the
+// squares of the nodes of a subproduct tree, and the sum is accumulated by going back up the tree.
+pub(crate) fn crt_coprime_helper(residues: &[Natural], moduli: &[Natural]) -> (Natural, Natural) {
+    let residues = reduce_residues(residues, moduli);
+    if residues.is_empty() {
+        return (Natural::ZERO, Natural::ONE);
+    }
+    let levels = subproduct_tree(moduli);
+    let m = levels.last().unwrap()[0].clone();
+    // After this loop, `rems[i]` is M mod m_i ^ 2
+    let mut rems = vec![m.clone()];
+    for level in levels.iter().rev().skip(1) {
+        rems = level
+            .iter()
+            .enumerate()
+            .map(|(i, p)| &rems[i >> 1] % p.square())
+            .collect();
+    }
+    let mut values: Vec<Natural> = residues
+        .into_iter()
+        .zip(moduli)
+        .zip(rems)
+        .map(|((a, m_i), r)| {
+            if *m_i == 1u32 {
+                return Natural::ZERO;
+            }
+            // Since m_i divides M, r / m_i = (M / m_i) mod m_i
+            let c = r.div_exact(m_i);
+            let inverse = if c == 0u32 { None } else { c.mod_inverse(m_i) }
+                .expect("The moduli must be pairwise coprime");
+            a.mod_mul(inverse, m_i)
+        })
+        .collect();
+    for level in &levels[..levels.len() - 1] {
+        values = values
+            .chunks(2)
+            .zip(level.chunks(2))
+            .map(|(vs, ps)| {
+                if vs.len() == 2 {
+                    &vs[0] * &ps[1] + &vs[1] * &ps[0]
+                } else {
+                    vs[0].clone()
+                }
+            })
+            .collect();
+    }
+    let x = values.pop().unwrap() % &m;
+    (x, m)
+}
+
+impl Natural {
+    /// Finds the smallest [`Natural`] that is congruent to each of a list of residues modulo the
+    /// corresponding modulus, or returns `None` if there is no such [`Natural`].
+    ///
+    /// The moduli need not be pairwise coprime; the congruences are combined one at a time, and
+    /// `None` is returned if they are inconsistent. The residues need not be reduced. If there are
+    /// no congruences, 0 is returned.
+    ///
+    /// $f((a_1, \ldots, a_k), (m_1, \ldots, m_k)) = x$, where $0 \leq x < \operatorname{lcm}(m_1,
+    /// \ldots, m_k)$ and $x \equiv a_i \mod m_i$ for all $i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of bits in the
+    /// residues and moduli, and $k$ is `moduli.len()`.
+    ///
+    /// # Panics
+    /// Panics if `residues` and `moduli` have different lengths, or if any modulus is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let moduli = [Natural::from(3u32), Natural::from(5u32), Natural::from(7u32)];
+    /// let residues = [Natural::from(2u32), Natural::from(3u32), Natural::from(2u32)];
+    /// assert_eq!(Natural::crt(&residues, &moduli), Some(Natural::from(23u32)));
+    ///
+    /// let moduli = [Natural::from(6u32), Natural::from(10u32)];
+    /// let residues = [Natural::from(5u32), Natural::from(3u32)];
+    /// assert_eq!(Natural::crt(&residues, &moduli), Some(Natural::from(23u32)));
+    ///
+    /// let residues = [Natural::from(5u32), Natural::from(4u32)];
+    /// assert_eq!(Natural::crt(&residues, &moduli), None);
+    /// ```
+    pub fn crt(residues: &[Natural], moduli: &[Natural]) -> Option<Natural> {
+        crt_helper(residues, moduli).map(|(x, _)| x)
+    }
+
+    /// Finds the smallest [`Natural`] that is congruent to each of a list of residues modulo the
+    /// corresponding modulus, where the moduli are pairwise coprime.
+    ///
+    /// This uses a subproduct tree, so it is faster than [`Natural::crt`] when there are many
+    /// moduli. The residues need not be reduced. If there are no congruences, 0 is returned.
+    ///
+    /// $f((a_1, \ldots, a_k), (m_1, \ldots, m_k)) = x$, where $0 \leq x < \prod_{i=1}^k m_i$ and
+    /// $x \equiv a_i \mod m_i$ for all $i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// residues and moduli.
+    ///
+    /// # Panics
+    /// Panics if `residues` and `moduli` have different lengths, if any modulus is zero, or if the
+    /// moduli are not pairwise coprime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let moduli = [Natural::from(3u32), Natural::from(5u32), Natural::from(7u32)];
+    /// let residues = [Natural::from(2u32), Natural::from(3u32), Natural::from(2u32)];
+    /// assert_eq!(Natural::crt_coprime(&residues, &moduli), Natural::from(23u32));
+    ///
+    /// let moduli = [Natural::from(1000000007u32), Natural::from(998244353u32)];
+    /// let residues = [Natural::from(1u32), Natural::from(2u32)];
+    /// assert_eq!(
+    ///     Natural::crt_coprime(&residues, &moduli).to_string(),
+    ///     "993328913953302350"
+    /// );
+    /// ```
+    pub fn crt_coprime(residues: &[Natural], moduli: &[Natural]) -> Natural {
+        crt_coprime_helper(residues, moduli).0
+    }
+}
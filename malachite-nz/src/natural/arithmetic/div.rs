@@ -24,6 +24,7 @@ use crate::natural::arithmetic::sub::{
 };
 use crate::natural::arithmetic::sub_mul::limbs_sub_mul_limb_same_length_in_place_left;
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::limb_vec::{LimbVec, INLINE_LIMB_COUNT};
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{
@@ -42,7 +43,6 @@ use malachite_base::num::logic::traits::LeadingZeros;
 use malachite_base::slices::{slice_move_left, slice_set_zero};
 use std::cmp::Ordering;
 use std::iter::once;
-use std::mem::swap;
 use std::ops::{Div, DivAssign};
 
 // Divide an number by a divisor of B - 1, where B is the limb base.
@@ -1728,6 +1728,30 @@ pub_test! {limbs_div(ns: &[Limb], ds: &[Limb]) -> Vec<Limb> {
     qs
 }}
 
+// Interpreting two slices of `Limb`s, `ns` and `ds`, as the limbs (in ascending order) of two
+// `Natural`s, divides them, returning the quotient as a `LimbVec`. This is the same as
+// `limbs_div`, except that if the quotient is short enough, no memory is allocated for it.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log \log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `ns.len()`.
+//
+// # Panics
+// Panics if `ns` is shorter than `ds`, `ds` has length less than 2, or the most-significant limb
+// of `ds` is zero.
+fn limbs_div_limb_vec(ns: &[Limb], ds: &[Limb]) -> LimbVec {
+    let q_len = ns.len() - ds.len() + 1;
+    if q_len > INLINE_LIMB_COUNT {
+        return LimbVec::from(limbs_div(ns, ds));
+    }
+    let mut qs = LimbVec::zeroed(q_len);
+    limbs_div_to_out_ref_ref(&mut qs, ns, ds);
+    qs
+}
+
 // Interpreting two slices of `Limb`s, `ns` and `ds`, as the limbs (in ascending order) of two
 // `Natural`s, divides them, writing the `ns.len() - ds.len() + 1` limbs of the quotient to `qs`.
 //
@@ -2100,9 +2124,9 @@ impl<'a> Div<Natural> for &'a Natural {
                 if ns_len < ds_len {
                     Natural::ZERO
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_to_out_ref_val(&mut qs, ns, ds);
-                    Natural::from_owned_limbs_asc(qs)
+                    Natural::from_limb_vec(qs)
                 }
             }
         }
@@ -2153,7 +2177,7 @@ impl<'a, 'b> Div<&'b Natural> for &'a Natural {
                 if ns.len() < ds.len() {
                     Natural::ZERO
                 } else {
-                    Natural::from_owned_limbs_asc(limbs_div(ns, ds))
+                    Natural::from_limb_vec(limbs_div_limb_vec(ns, ds))
                 }
             }
         }
@@ -2205,9 +2229,9 @@ impl DivAssign<Natural> for Natural {
                 if ns_len < ds_len {
                     *self = Natural::ZERO;
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_to_out(&mut qs, ns, ds);
-                    swap(&mut qs, ns);
+                    *ns = qs;
                     self.trim();
                 }
             }
@@ -2260,9 +2284,9 @@ impl<'a> DivAssign<&'a Natural> for Natural {
                 if ns_len < ds_len {
                     *self = Natural::ZERO;
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_to_out_val_ref(&mut qs, ns, ds);
-                    swap(&mut qs, ns);
+                    *ns = qs;
                     self.trim();
                 }
             }
@@ -2327,9 +2351,9 @@ impl CheckedDiv<Natural> for Natural {
                 Some(if ns_len < ds_len {
                     Natural::ZERO
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_to_out(&mut qs, &mut ns, ds);
-                    Natural::from_owned_limbs_asc(qs)
+                    Natural::from_limb_vec(qs)
                 })
             }
         }
@@ -2393,9 +2417,9 @@ impl<'a> CheckedDiv<&'a Natural> for Natural {
                 Some(if ns_len < ds_len {
                     Natural::ZERO
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_to_out_val_ref(&mut qs, &mut ns, ds);
-                    Natural::from_owned_limbs_asc(qs)
+                    Natural::from_limb_vec(qs)
                 })
             }
         }
@@ -2455,9 +2479,9 @@ impl<'a> CheckedDiv<Natural> for &'a Natural {
                 Some(if ns_len < ds_len {
                     Natural::ZERO
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_to_out_ref_val(&mut qs, ns, ds);
-                    Natural::from_owned_limbs_asc(qs)
+                    Natural::from_limb_vec(qs)
                 })
             }
         }
@@ -2514,7 +2538,7 @@ impl<'a, 'b> CheckedDiv<&'b Natural> for &'a Natural {
             (&Natural(Large(ref ns)), &Natural(Large(ref ds))) => Some(if ns.len() < ds.len() {
                 Natural::ZERO
             } else {
-                Natural::from_owned_limbs_asc(limbs_div(ns, ds))
+                Natural::from_limb_vec(limbs_div_limb_vec(ns, ds))
             }),
         }
     }
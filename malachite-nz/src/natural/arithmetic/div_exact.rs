@@ -27,6 +27,7 @@ use crate::natural::arithmetic::sub::{
 };
 use crate::natural::arithmetic::sub_mul::limbs_sub_mul_limb_same_length_in_place_left;
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{
@@ -45,7 +46,6 @@ use malachite_base::num::logic::traits::TrailingZeros;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::slices::{slice_leading_zeros, slice_set_zero, slice_test_zero};
 use std::cmp::{max, min, Ordering};
-use std::mem::swap;
 
 const INVERT_LIMB_TABLE_LOG_SIZE: u64 = 7;
 
@@ -2127,9 +2127,9 @@ impl DivExactAssign<Natural> for Natural {
                 if ns_len < ds_len {
                     panic!("division not exact");
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_exact_to_out(&mut qs, ns, ds);
-                    swap(&mut qs, ns);
+                    *ns = qs;
                     self.trim();
                 }
             }
@@ -2193,9 +2193,9 @@ impl<'a> DivExactAssign<&'a Natural> for Natural {
                 if ns_len < ds_len {
                     panic!("division not exact");
                 } else {
-                    let mut qs = vec![0; ns_len - ds_len + 1];
+                    let mut qs = LimbVec::zeroed(ns_len - ds_len + 1);
                     limbs_div_exact_to_out_val_ref(&mut qs, ns, ds);
-                    swap(&mut qs, ns);
+                    *ns = qs;
                     self.trim();
                 }
             }
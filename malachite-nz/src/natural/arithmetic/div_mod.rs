@@ -25,6 +25,7 @@ use crate::natural::arithmetic::sub::{
 };
 use crate::natural::arithmetic::sub_mul::limbs_sub_mul_limb_same_length_in_place_left;
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::limb_vec::{LimbVec, INLINE_LIMB_COUNT};
 use crate::natural::logic::not::limbs_not_to_out;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
@@ -1803,6 +1804,34 @@ pub_test! {limbs_div_mod(ns: &[Limb], ds: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
     (qs, rs)
 }}
 
+// Interpreting two slices of `Limb`s, `ns` and `ds`, as the limbs (in ascending order) of two
+// `Natural`s, divides them, returning the quotient and remainder as `LimbVec`s. This is the same as
+// `limbs_div_mod`, except that if the quotient and remainder are short enough, no memory is
+// allocated for them.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `ns.len()`.
+//
+// # Panics
+// Panics if `ns` is shorter than `ds`, `ds` has length less than 2, or the most-significant limb
+// of `ds` is zero.
+fn limbs_div_mod_limb_vecs(ns: &[Limb], ds: &[Limb]) -> (LimbVec, LimbVec) {
+    let d_len = ds.len();
+    let q_len = ns.len() - d_len + 1;
+    if q_len > INLINE_LIMB_COUNT || d_len > INLINE_LIMB_COUNT {
+        let (qs, rs) = limbs_div_mod(ns, ds);
+        return (LimbVec::from(qs), LimbVec::from(rs));
+    }
+    let mut qs = LimbVec::zeroed(q_len);
+    let mut rs = LimbVec::zeroed(d_len);
+    limbs_div_mod_to_out(&mut qs, &mut rs, ns, ds);
+    (qs, rs)
+}
+
 // Interpreting two slices of `Limb`s, `ns` and `ds`, as the limbs (in ascending order) of two
 // `Natural`s, divides them, writing the `ns.len() - ds.len() + 1` limbs of the quotient to `qs`
 // and the `ds.len()` limbs of the remainder to `rs`.
@@ -2050,10 +2079,10 @@ impl<'a> DivMod<Natural> for &'a Natural {
                 if ns.len() < ds.len() {
                     (Natural::ZERO, self.clone())
                 } else {
-                    let (qs, mut rs) = limbs_div_mod(ns, ds);
-                    swap(&mut rs, ds);
+                    let (qs, rs) = limbs_div_mod_limb_vecs(ns, ds);
+                    *ds = rs;
                     other.trim();
-                    (Natural::from_owned_limbs_asc(qs), other)
+                    (Natural::from_limb_vec(qs), other)
                 }
             }
         }
@@ -2120,11 +2149,8 @@ impl<'a, 'b> DivMod<&'b Natural> for &'a Natural {
                 if ns.len() < ds.len() {
                     (Natural::ZERO, self.clone())
                 } else {
-                    let (qs, rs) = limbs_div_mod(ns, ds);
-                    (
-                        Natural::from_owned_limbs_asc(qs),
-                        Natural::from_owned_limbs_asc(rs),
-                    )
+                    let (qs, rs) = limbs_div_mod_limb_vecs(ns, ds);
+                    (Natural::from_limb_vec(qs), Natural::from_limb_vec(rs))
                 }
             }
         }
@@ -2193,9 +2219,9 @@ impl DivAssignMod<Natural> for Natural {
                     swap(self, &mut r);
                     r
                 } else {
-                    let (mut qs, mut rs) = limbs_div_mod(ns, ds);
-                    swap(&mut qs, ns);
-                    swap(&mut rs, ds);
+                    let (qs, rs) = limbs_div_mod_limb_vecs(ns, ds);
+                    *ns = qs;
+                    *ds = rs;
                     self.trim();
                     other.trim();
                     other
@@ -2270,10 +2296,10 @@ impl<'a> DivAssignMod<&'a Natural> for Natural {
                     swap(self, &mut r);
                     r
                 } else {
-                    let (mut qs, rs) = limbs_div_mod(ns, ds);
-                    swap(&mut qs, ns);
+                    let (qs, rs) = limbs_div_mod_limb_vecs(ns, ds);
+                    *ns = qs;
                     self.trim();
-                    Natural::from_owned_limbs_asc(rs)
+                    Natural::from_limb_vec(rs)
                 }
             }
         }
@@ -0,0 +1,288 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CheckedFibonacci, Fibonacci, FibonacciMod, ModAdd, ModShl, ModSquare, ModSub, Parity, Square,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// Returns (F_n, F_{n - 1}), where F_{-1} = 1.
+//
+// For large n, this uses the doubling formulas
+// - F_{2k - 1} = F_k^2 + F_{k - 1}^2,
+// - F_{2k + 1} = 4F_k^2 - F_{k - 1}^2 + 2(-1)^k,
+// - F_{2k} = F_{2k + 1} - F_{2k - 1},
+// which only need two squarings per bit of n.
+//
+// This is equivalent to `mpz_fib2_ui` from `mpz/fib2_ui.c`, GMP 6.2.1.
+fn fibonacci2_helper(n: u64) -> (Natural, Natural) {
+    if let Some((f, f_prev)) = u128::checked_fibonacci2(n) {
+        return (Natural::from(f), Natural::from(f_prev));
+    }
+    let k = n >> 1;
+    let (f, f_prev) = fibonacci2_helper(k);
+    let f_squared = f.square();
+    let f_prev_squared = f_prev.square();
+    let f_2k_minus_1 = &f_squared + &f_prev_squared;
+    let mut f_2k_plus_1 = (f_squared << 2) - f_prev_squared;
+    if k.even() {
+        f_2k_plus_1 += Natural::TWO;
+    } else {
+        f_2k_plus_1 -= Natural::TWO;
+    }
+    let f_2k = &f_2k_plus_1 - &f_2k_minus_1;
+    if n.odd() {
+        (f_2k_plus_1, f_2k)
+    } else {
+        (f_2k, f_2k_minus_1)
+    }
+}
+
+fn fibonacci_mod_helper(n: u64, m: &Natural) -> Natural {
+    assert_ne!(*m, 0, "Modulus must be positive");
+    if n == 0 || *m == 1 {
+        return Natural::ZERO;
+    }
+    // Start with (F_1, F_0) and use the same doubling formulas as `fibonacci2_helper`, reducing
+    // after every operation.
+    let two = Natural::TWO % m;
+    let mut f = Natural::ONE;
+    let mut f_prev = Natural::ZERO;
+    let mut k_odd = true;
+    for i in (0..n.significant_bits() - 1).rev() {
+        let f_squared = f.mod_square(m);
+        let f_prev_squared = f_prev.mod_square(m);
+        let f_2k_minus_1 = (&f_squared).mod_add(&f_prev_squared, m);
+        let mut f_2k_plus_1 = f_squared.mod_shl(2u64, m).mod_sub(f_prev_squared, m);
+        if k_odd {
+            f_2k_plus_1 = f_2k_plus_1.mod_sub(&two, m);
+        } else {
+            f_2k_plus_1 = f_2k_plus_1.mod_add(&two, m);
+        }
+        let f_2k = (&f_2k_plus_1).mod_sub(&f_2k_minus_1, m);
+        k_odd = n.get_bit(i);
+        if k_odd {
+            f = f_2k_plus_1;
+            f_prev = f_2k;
+        } else {
+            f = f_2k;
+            f_prev = f_2k_minus_1;
+        }
+    }
+    f
+}
+
+impl Fibonacci for Natural {
+    /// Computes a Fibonacci number.
+    ///
+    /// $$
+    /// f(n) = F_n,
+    /// $$
+    /// where $F_0 = 0$, $F_1 = 1$, and $F_n = F_{n-1} + F_{n-2}$.
+    ///
+    /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt 5)/2$ is the golden ratio.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Fibonacci;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci(0), 0);
+    /// assert_eq!(Natural::fibonacci(1), 1);
+    /// assert_eq!(Natural::fibonacci(2), 1);
+    /// assert_eq!(Natural::fibonacci(3), 2);
+    /// assert_eq!(Natural::fibonacci(4), 3);
+    /// assert_eq!(Natural::fibonacci(5), 5);
+    /// assert_eq!(Natural::fibonacci(100).to_string(), "354224848179261915075");
+    /// assert_eq!(
+    ///     Natural::fibonacci(300).to_string(),
+    ///     "222232244629420445529739893461909967206666939096499764990979600"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `mpz_fib_ui` from `mpz/fib_ui.c`, GMP 6.2.1.
+    fn fibonacci(n: u64) -> Natural {
+        if let Some(f) = u128::checked_fibonacci(n) {
+            return Natural::from(f);
+        }
+        // With k = floor(n / 2), F_{2k} = F_k(F_k + 2F_{k - 1}) and
+        // F_{2k + 1} = (2F_k + F_{k - 1})(2F_k - F_{k - 1}) + 2(-1)^k.
+        let k = n >> 1;
+        let (f, f_prev) = fibonacci2_helper(k);
+        if n.even() {
+            let x = &f + (f_prev << 1);
+            f * x
+        } else {
+            let f_doubled = f << 1;
+            let mut x = (&f_doubled + &f_prev) * (f_doubled - f_prev);
+            if k.even() {
+                x += Natural::TWO;
+            } else {
+                x -= Natural::TWO;
+            }
+            x
+        }
+    }
+
+    /// Computes two consecutive Fibonacci numbers, $F_n$ and $F_{n-1}$.
+    ///
+    /// $F_{-1}$ is taken to be 1, so that $F_1 = F_0 + F_{-1}$.
+    ///
+    /// $f(n) = (F_n, F_{n-1})$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Fibonacci;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci2(0).to_debug_string(), "(0, 1)");
+    /// assert_eq!(Natural::fibonacci2(1).to_debug_string(), "(1, 0)");
+    /// assert_eq!(Natural::fibonacci2(5).to_debug_string(), "(5, 3)");
+    /// assert_eq!(
+    ///     Natural::fibonacci2(100).to_debug_string(),
+    ///     "(354224848179261915075, 218922995834555169026)"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `mpz_fib2_ui` from `mpz/fib2_ui.c`, GMP 6.2.1.
+    #[inline]
+    fn fibonacci2(n: u64) -> (Natural, Natural) {
+        fibonacci2_helper(n)
+    }
+
+    /// Computes a Lucas number.
+    ///
+    /// $$
+    /// f(n) = L_n,
+    /// $$
+    /// where $L_0 = 2$, $L_1 = 1$, and $L_n = L_{n-1} + L_{n-2}$.
+    ///
+    /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt 5)/2$ is the golden ratio.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Fibonacci;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::lucas(0), 2);
+    /// assert_eq!(Natural::lucas(1), 1);
+    /// assert_eq!(Natural::lucas(2), 3);
+    /// assert_eq!(Natural::lucas(3), 4);
+    /// assert_eq!(Natural::lucas(4), 7);
+    /// assert_eq!(Natural::lucas(5), 11);
+    /// assert_eq!(Natural::lucas(100).to_string(), "792070839848372253127");
+    /// assert_eq!(
+    ///     Natural::lucas(300).to_string(),
+    ///     "496926405783746676393791436882468230898067489522034699520200002"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `mpz_lucnum_ui` from `mpz/lucnum_ui.c`, GMP 6.2.1.
+    fn lucas(n: u64) -> Natural {
+        if let Some(l) = u128::checked_lucas(n) {
+            return Natural::from(l);
+        }
+        // L_n = F_n + 2F_{n - 1}
+        let (f, f_prev) = fibonacci2_helper(n);
+        f + (f_prev << 1)
+    }
+}
+
+impl FibonacciMod<Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes a Fibonacci number modulo a [`Natural`]. The [`Natural`] is taken by value.
+    ///
+    /// $f(n, m) = F_n \bmod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(m \log n \log m \log\log m)$
+    ///
+    /// $M(m) = O(m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $m$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{FibonacciMod, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci_mod(10, Natural::from(7u32)), 6);
+    /// assert_eq!(
+    ///     Natural::fibonacci_mod(1000, Natural::from(100000000000000000039u128)),
+    ///     22923194251203333989u128
+    /// );
+    /// assert_eq!(
+    ///     Natural::fibonacci_mod(1000000000000000000, Natural::from(10u32).pow(30))
+    ///         .to_string(),
+    ///     "123436395041183788299560546875"
+    /// );
+    /// ```
+    #[inline]
+    fn fibonacci_mod(n: u64, m: Natural) -> Natural {
+        fibonacci_mod_helper(n, &m)
+    }
+}
+
+impl<'a> FibonacciMod<&'a Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes a Fibonacci number modulo a [`Natural`]. The [`Natural`] is taken by reference.
+    ///
+    /// $f(n, m) = F_n \bmod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(m \log n \log m \log\log m)$
+    ///
+    /// $M(m) = O(m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $m$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{FibonacciMod, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci_mod(10, &Natural::from(7u32)), 6);
+    /// assert_eq!(
+    ///     Natural::fibonacci_mod(1000, &Natural::from(100000000000000000039u128)),
+    ///     22923194251203333989u128
+    /// );
+    /// assert_eq!(
+    ///     Natural::fibonacci_mod(1000000000000000000, &Natural::from(10u32).pow(30))
+    ///         .to_string(),
+    ///     "123436395041183788299560546875"
+    /// );
+    /// ```
+    #[inline]
+    fn fibonacci_mod(n: u64, m: &'a Natural) -> Natural {
+        fibonacci_mod_helper(n, m)
+    }
+}
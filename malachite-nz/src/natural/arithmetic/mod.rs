@@ -4,10 +4,14 @@ pub mod add;
 /// [`AddMulAssign`](malachite_base::num::arithmetic::traits::AddMulAssign), traits for adding a
 /// number and the product of two other numbers.
 pub mod add_mul;
+/// A function for computing Bell numbers.
+pub mod bell_number;
 /// Implementations of
 /// [`BinomialCoefficient`](malachite_base::num::arithmetic::traits::BinomialCoefficient), a trait
 /// for computing the binomial coefficient of two numbers.
 pub mod binomial_coefficient;
+/// A function for computing Catalan numbers.
+pub mod catalan_number;
 /// Implementations of [`CheckedSub`](malachite_base::num::arithmetic::traits::CheckedSub), a trait
 /// for subtracting two numbers and checking whether the result is representable.
 pub mod checked_sub;
@@ -18,6 +22,9 @@ pub mod checked_sub_mul;
 /// Implementations of [`CoprimeWith`](malachite_base::num::arithmetic::traits::CoprimeWith), a
 /// trait for determining whether two numbers are coprime.
 pub mod coprime_with;
+/// Reconstruction of [`Natural`](super::Natural)s from their residues using the Chinese remainder
+/// theorem.
+pub mod crt;
 /// Division of [`Natural`](super::Natural)s.
 pub mod div;
 /// Implementations of [`DivExact`](malachite_base::num::arithmetic::traits::DivExact) and
@@ -63,6 +70,10 @@ pub mod eq_mod_power_of_2;
 /// [`Multifactorial`](malachite_base::num::arithmetic::traits::Multifactorial), and
 /// [`Subfactorial`](malachite_base::num::arithmetic::traits::Subfactorial).
 pub mod factorial;
+/// Implementations of [`Fibonacci`](malachite_base::num::arithmetic::traits::Fibonacci) and
+/// [`FibonacciMod`](malachite_base::num::arithmetic::traits::FibonacciMod), traits for computing
+/// Fibonacci and Lucas numbers.
+pub mod fibonacci;
 /// Implementations of [`Gcd`](malachite_base::num::arithmetic::traits::Gcd) and
 /// [`GcdAssign`](malachite_base::num::arithmetic::traits::GcdAssign), traits for computing the GCD
 /// (greatest common divisor) of two numbers.
@@ -478,6 +489,11 @@ pub mod mod_shl;
 /// assert_eq!(x, 7436663564915145u64);
 /// ```
 pub mod mod_shr;
+/// Implementations of [`ModSqrt`](malachite_base::num::arithmetic::traits::ModSqrt),
+/// [`ModPrimePowerSqrt`](malachite_base::num::arithmetic::traits::ModPrimePowerSqrt), and
+/// [`ModFactoredSqrt`](malachite_base::num::arithmetic::traits::ModFactoredSqrt), traits for
+/// finding square roots modulo a prime, a prime power, or a number with known factorization.
+pub mod mod_sqrt;
 /// Implementations of traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](malachite_base::num::arithmetic::traits::ModSquare),
@@ -490,6 +506,10 @@ pub mod mod_square;
 /// [`ModSubAssign`](malachite_base::num::arithmetic::traits::ModSubAssign), traits for subtracting
 /// two numbers modulo another number.
 pub mod mod_sub;
+/// [`MontgomeryContext`](montgomery::MontgomeryContext), a type for performing many modular
+/// multiplications and exponentiations with the same odd modulus, keeping values in Montgomery form
+/// between operations.
+pub mod montgomery;
 /// Multiplication of [`Natural`](super::Natural)s.
 pub mod mul;
 /// Negation of a [`Natural`](super::Natural), returning an [`Integer`](crate::integer::Integer).
@@ -501,6 +521,8 @@ pub mod next_power_of_2;
 /// Implementations of [`Parity`](malachite_base::num::arithmetic::traits::Parity), a trait for
 /// determining whether a number is even or odd.
 pub mod parity;
+/// A function for computing partition numbers.
+pub mod partition_number;
 /// Implementations of [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
 /// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign), traits for raising a number
 /// to a power.
@@ -518,8 +540,10 @@ pub mod primorial;
 /// [`CeilingRoot`](malachite_base::num::arithmetic::traits::CeilingRoot),
 /// [`CeilingRootAssign`](malachite_base::num::arithmetic::traits::CeilingRootAssign),
 /// [`CheckedRoot`](malachite_base::num::arithmetic::traits::CheckedRoot),
-/// [`RootRem`](malachite_base::num::arithmetic::traits::RootRem), and
-/// [`RootAssignRem`](malachite_base::num::arithmetic::traits::RootAssignRem).
+/// [`RootRem`](malachite_base::num::arithmetic::traits::RootRem),
+/// [`RootAssignRem`](malachite_base::num::arithmetic::traits::RootAssignRem),
+/// [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower), and
+/// [`PerfectPowerDecomposition`](malachite_base::num::arithmetic::traits::PerfectPowerDecomposition).
 pub mod root;
 /// Implementations of
 /// [`RoundToMultiple`](malachite_base::num::arithmetic::traits::RoundToMultiple) and
@@ -1072,13 +1096,16 @@ pub mod sign;
 /// [`CeilingSqrt`](malachite_base::num::arithmetic::traits::CeilingSqrt),
 /// [`CeilingSqrtAssign`](malachite_base::num::arithmetic::traits::CeilingSqrtAssign),
 /// [`CheckedSqrt`](malachite_base::num::arithmetic::traits::CheckedSqrt),
-/// [`SqrtRem`](malachite_base::num::arithmetic::traits::SqrtRem), and
-/// [`SqrtAssignRem`](malachite_base::num::arithmetic::traits::SqrtAssignRem).
+/// [`SqrtRem`](malachite_base::num::arithmetic::traits::SqrtRem),
+/// [`SqrtAssignRem`](malachite_base::num::arithmetic::traits::SqrtAssignRem), and
+/// [`IsSquare`](malachite_base::num::arithmetic::traits::IsSquare).
 pub mod sqrt;
 /// Implementations of [`Square`](malachite_base::num::arithmetic::traits::Square) and
 /// [`SquareAssign`](malachite_base::num::arithmetic::traits::SquareAssign), traits for squaring a
 /// number.
 pub mod square;
+/// Functions for computing Stirling numbers of the first and second kinds.
+pub mod stirling;
 /// Subtraction of [`Natural`](super::Natural)s.
 pub mod sub;
 /// Implementations of [`SubMul`](malachite_base::num::arithmetic::traits::SubMul) and
@@ -1,4 +1,5 @@
 use crate::natural::arithmetic::div_mod::limbs_div_mod_by_two_limb_normalized;
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{DoubleLimb, Limb};
@@ -199,14 +200,14 @@ impl Natural {
             (&mut Natural(Large(ref mut xs)), &Natural(Small(y)), &Natural(Large(ref ms))) => {
                 let (r_1, r_0) =
                     limbs_mod_mul_two_limbs(xs[1], xs[0], 0, y, ms[1], ms[0], inv_2, inv_1, inv_0);
-                *xs = vec![r_0, r_1];
+                *xs = LimbVec::from_slice(&[r_0, r_1]);
                 self.trim();
             }
             (&mut Natural(Large(ref mut xs)), &Natural(Large(ref ys)), &Natural(Large(ref ms))) => {
                 let (r_1, r_0) = limbs_mod_mul_two_limbs(
                     xs[1], xs[0], ys[1], ys[0], ms[1], ms[0], inv_2, inv_1, inv_0,
                 );
-                *xs = vec![r_0, r_1];
+                *xs = LimbVec::from_slice(&[r_0, r_1]);
                 self.trim();
             }
             _ => unreachable!(),
@@ -24,6 +24,7 @@ use crate::natural::arithmetic::sub::{
 };
 use crate::natural::arithmetic::sub_mul::limbs_sub_mul_limb_same_length_in_place_left;
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{
@@ -42,7 +43,6 @@ use malachite_base::num::conversion::traits::{JoinHalves, SplitInHalf};
 use malachite_base::num::logic::traits::LeadingZeros;
 use malachite_base::slices::{slice_move_left, slice_set_zero};
 use std::cmp::Ordering;
-use std::mem::swap;
 use std::ops::{Rem, RemAssign};
 
 // # Worst-case complexity
@@ -1447,7 +1447,7 @@ impl Natural {
         }
     }
 
-    fn rem_limb_ref(&self, other: Limb) -> Limb {
+    pub(crate) fn rem_limb_ref(&self, other: Limb) -> Limb {
         match (self, other) {
             (_, 0) => panic!("division by zero"),
             (Natural(Small(small)), other) => small % other,
@@ -2032,9 +2032,9 @@ impl<'a> RemAssign<&'a Natural> for Natural {
             (Natural(Small(_)), _) => {}
             (&mut Natural(Large(ref mut ns)), Natural(Large(ref ds))) => {
                 if ns.len() >= ds.len() {
-                    let mut rs = vec![0; ds.len()];
+                    let mut rs = LimbVec::zeroed(ds.len());
                     limbs_mod_to_out(&mut rs, ns, ds);
-                    swap(&mut rs, ns);
+                    *ns = rs;
                     self.trim();
                 }
             }
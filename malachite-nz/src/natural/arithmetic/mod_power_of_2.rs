@@ -233,7 +233,7 @@ impl ModPowerOf2Assign for Natural {
         match *self {
             Natural(Small(ref mut small)) => small.mod_power_of_2_assign(pow),
             Natural(Large(ref mut limbs)) => {
-                limbs_vec_mod_power_of_2_in_place(limbs, pow);
+                limbs_vec_mod_power_of_2_in_place(limbs.as_mut_vec(), pow);
                 self.trim();
             }
         }
@@ -474,7 +474,7 @@ impl NegModPowerOf2Assign for Natural {
             *self = Natural::from(Limb::wrapping_from(&*self).neg_mod_power_of_2(pow));
         } else {
             let limbs = self.promote_in_place();
-            limbs_neg_mod_power_of_2_in_place(limbs, pow);
+            limbs_neg_mod_power_of_2_in_place(limbs.as_mut_vec(), pow);
             self.trim();
         }
     }
@@ -97,7 +97,7 @@ impl ModPowerOf2Inverse for Natural {
             (Natural(Large(mut xs)), pow) => {
                 let len =
                     usize::exact_from(pow.shr_round(Limb::LOG_WIDTH, RoundingMode::Ceiling).0);
-                xs.resize(len, 0);
+                xs.as_mut_vec().resize(len, 0);
                 mod_power_of_2_inverse_helper(&xs, pow)
             }
         }
@@ -155,7 +155,7 @@ impl<'a> ModPowerOf2Inverse for &'a Natural {
                 let len =
                     usize::exact_from(pow.shr_round(Limb::LOG_WIDTH, RoundingMode::Ceiling).0);
                 let mut xs = xs.clone();
-                xs.resize(len, 0);
+                xs.as_mut_vec().resize(len, 0);
                 mod_power_of_2_inverse_helper(&xs, pow)
             }
         }
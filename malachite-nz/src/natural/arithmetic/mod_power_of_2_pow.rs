@@ -360,12 +360,12 @@ impl<'a> ModPowerOf2PowAssign<&'a Natural> for Natural {
             }
             (_, Natural(Small(e))) => {
                 let xs = self.promote_in_place();
-                limbs_mod_power_of_2_pow(xs, &[*e], pow);
+                limbs_mod_power_of_2_pow(xs.as_mut_vec(), &[*e], pow);
                 self.trim();
             }
             (_, Natural(Large(ref es))) => {
                 let xs = self.promote_in_place();
-                limbs_mod_power_of_2_pow(xs, es, pow);
+                limbs_mod_power_of_2_pow(xs.as_mut_vec(), es, pow);
                 self.trim();
             }
         }
@@ -0,0 +1,665 @@
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    DivMod, JacobiSymbol, ModAdd, ModFactoredSqrt, ModInverse, ModMul, ModPow, ModPowerOf2,
+    ModPrimePowerSqrt, ModSqrt, ModSquare, ModSub, Parity, Pow, Square,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use std::cmp::min;
+
+// Finds a square root of `x` modulo `p` using Cipolla's algorithm, where `p` is an odd prime and
+// `x` is a nonzero quadratic residue modulo `p`. Returns `None` if `p` turns out not to be prime.
+fn mod_sqrt_cipolla(x: &Natural, p: &Natural) -> Option<Natural> {
+    // Find a such that w = a ^ 2 - x is a quadratic nonresidue
+    let mut a = Natural::ONE;
+    let w = loop {
+        let w = (&a).mod_square(p).mod_sub(x, p);
+        match (&w).jacobi_symbol(p) {
+            -1 => break w,
+            0 => return Some(a),
+            _ => {}
+        }
+        a += Natural::ONE;
+        if a == *p {
+            return None;
+        }
+    };
+    // Compute (a + ω) ^ ((p + 1) / 2) in F_p(ω), where ω ^ 2 = w. The result lies in F_p.
+    let exp = (p >> 1u32) + Natural::ONE;
+    let mut u = Natural::ONE;
+    let mut v = Natural::ZERO;
+    for i in (0..exp.significant_bits()).rev() {
+        let uv = (&u).mod_mul(&v, p);
+        u = (&u)
+            .mod_square(p)
+            .mod_add((&v).mod_square(p).mod_mul(&w, p), p);
+        v = (&uv).mod_add(&uv, p);
+        if exp.get_bit(i) {
+            let new_u = (&u).mod_mul(&a, p).mod_add((&v).mod_mul(&w, p), p);
+            v = u.mod_add((&v).mod_mul(&a, p), p);
+            u = new_u;
+        }
+    }
+    Some(u)
+}
+
+// Finds a square root of `x` modulo `p` using the Tonelli-Shanks algorithm, where `p` is an odd
+// prime, `x` is a nonzero quadratic residue modulo `p`, and `s` is the number of trailing zeros of
+// `p - 1`. Returns `None` if `p` turns out not to be prime.
+fn mod_sqrt_tonelli_shanks(x: &Natural, p: &Natural, s: u64) -> Option<Natural> {
+    let q = p >> s;
+    let mut z = Natural::TWO;
+    loop {
+        match (&z).jacobi_symbol(p) {
+            -1 => break,
+            0 => return None,
+            _ => {}
+        }
+        z += Natural::ONE;
+    }
+    let mut c = z.mod_pow(&q, p);
+    let mut r = x.mod_pow((&q >> 1u32) + Natural::ONE, p);
+    let mut t = x.mod_pow(q, p);
+    let mut m = s;
+    while t != 1u32 {
+        // Find the least i such that t ^ (2 ^ i) = 1
+        let mut i = 0;
+        let mut t_pow = t.clone();
+        while t_pow != 1u32 {
+            t_pow = t_pow.mod_square(p);
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+        let mut b = c;
+        for _ in i + 1..m {
+            b = b.mod_square(p);
+        }
+        r = r.mod_mul(&b, p);
+        c = b.mod_square(p);
+        t = t.mod_mul(&c, p);
+        m = i;
+    }
+    Some(r)
+}
+
+fn mod_sqrt_prime(x: &Natural, p: &Natural) -> Option<Natural> {
+    assert!(x < p, "self must be reduced mod p, but {x} >= {p}");
+    if let (Natural(Small(x)), Natural(Small(p))) = (x, p) {
+        return x.mod_sqrt(*p).map(Natural::from);
+    }
+    if *x == 0u32 || *p == 2u32 {
+        return Some(x.clone());
+    }
+    if x.jacobi_symbol(p) == -1 {
+        return None;
+    }
+    let s = (p - Natural::ONE).trailing_zeros().unwrap();
+    let r = match s {
+        // p = 3 mod 4: x ^ ((p + 1) / 4)
+        1 => x.mod_pow((p >> 2u32) + Natural::ONE, p),
+        // p = 5 mod 8: Atkin's algorithm
+        2 => {
+            let x_2 = x.mod_add(x, p);
+            let v = (&x_2).mod_pow(p >> 3u32, p);
+            let i = x_2.mod_mul((&v).mod_square(p), p);
+            x.mod_mul(v, p).mod_mul(i.mod_sub(Natural::ONE, p), p)
+        }
+        // Cipolla's algorithm is faster than Tonelli-Shanks when s(s - 1) > 8n + 20, where n is the
+        // bit length of p
+        _ if s * (s - 1) > (p.significant_bits() << 3) + 20 => mod_sqrt_cipolla(x, p)?,
+        _ => mod_sqrt_tonelli_shanks(x, p, s)?,
+    };
+    // If p is not prime, r may not be a square root
+    if (&r).mod_square(p) == *x {
+        let neg = p - &r;
+        Some(min(r, neg))
+    } else {
+        None
+    }
+}
+
+// Finds a square root of `x` modulo `2 ^ k`, where `x` is odd and less than `2 ^ k`.
+fn mod_power_of_2_sqrt_odd(x: &Natural, k: u64) -> Option<Natural> {
+    match k {
+        1 => Some(Natural::ONE),
+        2 => {
+            if x.mod_power_of_2(2) == 1u32 {
+                Some(Natural::ONE)
+            } else {
+                None
+            }
+        }
+        _ => {
+            if x.mod_power_of_2(3) != 1u32 {
+                return None;
+            }
+            // If r ^ 2 = x mod 2 ^ i, then either r or r + 2 ^ (i - 1) is a square root of x mod
+            // 2 ^ (i + 1)
+            let mut r = Natural::ONE;
+            for i in 3..k {
+                if (&r).square().mod_power_of_2(i + 1) != x.mod_power_of_2(i + 1) {
+                    r.set_bit(i - 1);
+                }
+            }
+            Some(r)
+        }
+    }
+}
+
+// Finds a square root of `x` modulo `p ^ k`, where `p` is an odd prime and `x` is less than `p ^ k`
+// and not divisible by `p`. The square root modulo `p` is lifted using Hensel's lemma, doubling the
+// exponent at each step.
+fn mod_odd_prime_power_sqrt_unit(x: &Natural, p: &Natural, k: u64) -> Option<Natural> {
+    let mut r = mod_sqrt_prime(&(x % p), p)?;
+    let mut j = 1;
+    while j < k {
+        j = min(j << 1, k);
+        let m = p.pow(j);
+        let f = (&r).mod_square(&m).mod_sub(x % &m, &m);
+        let inverse = (&r).mod_add(&r, &m).mod_inverse(&m)?;
+        r = r.mod_sub(f.mod_mul(inverse, &m), &m);
+    }
+    Some(r)
+}
+
+fn mod_prime_power_sqrt(x: &Natural, p: &Natural, k: u64) -> Option<Natural> {
+    let m = p.pow(k);
+    assert!(*x < m, "self must be reduced mod p^k, but {x} >= {m}");
+    if *x == 0u32 {
+        return Some(Natural::ZERO);
+    }
+    // Write x = p ^ e * u, where u is not divisible by p. Since x < p ^ k, e < k.
+    let (e, u) = if *p == 2u32 {
+        let e = x.trailing_zeros().unwrap();
+        (e, x >> e)
+    } else {
+        let mut e = 0;
+        let mut u = x.clone();
+        loop {
+            let (q, r) = (&u).div_mod(p);
+            if r != 0u32 {
+                break;
+            }
+            u = q;
+            e += 1;
+        }
+        (e, u)
+    };
+    if e.odd() {
+        return None;
+    }
+    // If y ^ 2 = u mod p ^ (k - e), then (p ^ (e / 2) * y) ^ 2 = x mod p ^ k
+    let y = if *p == 2u32 {
+        mod_power_of_2_sqrt_odd(&u, k - e)?
+    } else {
+        mod_odd_prime_power_sqrt_unit(&u, p, k - e)?
+    };
+    let r = y * p.pow(e >> 1);
+    let neg = m - &r;
+    Some(min(r, neg))
+}
+
+fn mod_factored_sqrt(x: &Natural, factors: &[(Natural, u64)]) -> Option<Natural> {
+    let m: Natural = factors.iter().map(|(p, k)| p.pow(*k)).product();
+    assert!(*x < m, "self must be reduced mod m, but {x} >= {m}");
+    // Combine the square roots modulo each prime power using the Chinese remainder theorem
+    let mut r = Natural::ZERO;
+    let mut m = Natural::ONE;
+    for (p, k) in factors {
+        if *k == 0 {
+            continue;
+        }
+        let q = p.pow(*k);
+        let r_q = mod_prime_power_sqrt(&(x % &q), p, *k)?;
+        let d = r_q
+            .mod_sub(&r % &q, &q)
+            .mod_mul((&m % &q).mod_inverse(&q).unwrap(), &q);
+        r += &m * d;
+        m *= q;
+    }
+    let neg = m - &r;
+    Some(min(r, neg))
+}
+
+impl ModSqrt<Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime $p$. The [`Natural`] must be already
+    /// reduced modulo $p$. Both [`Natural`]s are taken by value.
+    ///
+    /// Returns `None` if `self` is not a quadratic residue modulo $p$. Otherwise, of the two
+    /// square roots $r$ and $p - r$, the smaller one is returned. If $p$ is not prime, `None` may
+    /// be returned even if a square root exists.
+    ///
+    /// $f(x, p) = y$, where $x, y < p$, $y \leq p - y$, and $y^2 \equiv x \mod p$.
+    ///
+    /// The square root is found using the Tonelli-Shanks algorithm, or Cipolla's algorithm if $p -
+    /// 1$ is divisible by a large power of 2.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`, or if `p` is even and not 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_sqrt(Natural::from(7u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(Natural::from(3u32).mod_sqrt(Natural::from(7u32)), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: Natural) -> Option<Natural> {
+        mod_sqrt_prime(&self, &p)
+    }
+}
+
+impl<'a> ModSqrt<&'a Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime $p$. The [`Natural`] must be already
+    /// reduced modulo $p$. The first [`Natural`] is taken by value and the second by reference.
+    ///
+    /// Returns `None` if `self` is not a quadratic residue modulo $p$. Otherwise, of the two
+    /// square roots $r$ and $p - r$, the smaller one is returned. If $p$ is not prime, `None` may
+    /// be returned even if a square root exists.
+    ///
+    /// $f(x, p) = y$, where $x, y < p$, $y \leq p - y$, and $y^2 \equiv x \mod p$.
+    ///
+    /// The square root is found using the Tonelli-Shanks algorithm, or Cipolla's algorithm if $p -
+    /// 1$ is divisible by a large power of 2.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`, or if `p` is even and not 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_sqrt(&Natural::from(7u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(Natural::from(3u32).mod_sqrt(&Natural::from(7u32)), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: &'a Natural) -> Option<Natural> {
+        mod_sqrt_prime(&self, p)
+    }
+}
+
+impl<'a> ModSqrt<Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime $p$. The [`Natural`] must be already
+    /// reduced modulo $p$. The first [`Natural`] is taken by reference and the second by value.
+    ///
+    /// Returns `None` if `self` is not a quadratic residue modulo $p$. Otherwise, of the two
+    /// square roots $r$ and $p - r$, the smaller one is returned. If $p$ is not prime, `None` may
+    /// be returned even if a square root exists.
+    ///
+    /// $f(x, p) = y$, where $x, y < p$, $y \leq p - y$, and $y^2 \equiv x \mod p$.
+    ///
+    /// The square root is found using the Tonelli-Shanks algorithm, or Cipolla's algorithm if $p -
+    /// 1$ is divisible by a large power of 2.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`, or if `p` is even and not 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_sqrt(Natural::from(7u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!((&Natural::from(3u32)).mod_sqrt(Natural::from(7u32)), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: Natural) -> Option<Natural> {
+        mod_sqrt_prime(self, &p)
+    }
+}
+
+impl<'a, 'b> ModSqrt<&'b Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime $p$. The [`Natural`] must be already
+    /// reduced modulo $p$. Both [`Natural`]s are taken by reference.
+    ///
+    /// Returns `None` if `self` is not a quadratic residue modulo $p$. Otherwise, of the two
+    /// square roots $r$ and $p - r$, the smaller one is returned. If $p$ is not prime, `None` may
+    /// be returned even if a square root exists.
+    ///
+    /// $f(x, p) = y$, where $x, y < p$, $y \leq p - y$, and $y^2 \equiv x \mod p$.
+    ///
+    /// The square root is found using the Tonelli-Shanks algorithm, or Cipolla's algorithm if $p -
+    /// 1$ is divisible by a large power of 2.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`, or if `p` is even and not 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_sqrt(&Natural::from(7u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!((&Natural::from(3u32)).mod_sqrt(&Natural::from(7u32)), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: &'b Natural) -> Option<Natural> {
+        mod_sqrt_prime(self, p)
+    }
+}
+
+impl ModPrimePowerSqrt<Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime power $p^k$. The [`Natural`] must be
+    /// already reduced modulo $p^k$. Both [`Natural`]s are taken by value.
+    ///
+    /// Returns `None` if `self` is not a square modulo $p^k$. Otherwise, a square root $r$ with $r
+    /// \leq p^k - r$ is returned. If $p$ is not prime, `None` may be returned even if a square
+    /// root exists.
+    ///
+    /// $f(x, p, k) = y$, where $x, y < p^k$, $y \leq p^k - y$, and $y^2 \equiv x \mod p^k$.
+    ///
+    /// A square root modulo $p$ is lifted to a square root modulo $p^k$ using Hensel's lemma.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `k * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $p^k$, or if `p` is even and not 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_prime_power_sqrt(Natural::from(7u32), 2),
+    ///     Some(Natural::from(10u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_prime_power_sqrt(Natural::from(7u32), 2),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: Natural, k: u64) -> Option<Natural> {
+        mod_prime_power_sqrt(&self, &p, k)
+    }
+}
+
+impl<'a> ModPrimePowerSqrt<&'a Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime power $p^k$. The [`Natural`] must be
+    /// already reduced modulo $p^k$. The first [`Natural`] is taken by value and the second by
+    /// reference.
+    ///
+    /// Returns `None` if `self` is not a square modulo $p^k$. Otherwise, a square root $r$ with $r
+    /// \leq p^k - r$ is returned. If $p$ is not prime, `None` may be returned even if a square
+    /// root exists.
+    ///
+    /// $f(x, p, k) = y$, where $x, y < p^k$, $y \leq p^k - y$, and $y^2 \equiv x \mod p^k$.
+    ///
+    /// A square root modulo $p$ is lifted to a square root modulo $p^k$ using Hensel's lemma.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `k * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $p^k$, or if `p` is even and not 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_prime_power_sqrt(&Natural::from(7u32), 2),
+    ///     Some(Natural::from(10u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_prime_power_sqrt(&Natural::from(7u32), 2),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: &'a Natural, k: u64) -> Option<Natural> {
+        mod_prime_power_sqrt(&self, p, k)
+    }
+}
+
+impl<'a> ModPrimePowerSqrt<Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime power $p^k$. The [`Natural`] must be
+    /// already reduced modulo $p^k$. The first [`Natural`] is taken by reference and the second by
+    /// value.
+    ///
+    /// Returns `None` if `self` is not a square modulo $p^k$. Otherwise, a square root $r$ with $r
+    /// \leq p^k - r$ is returned. If $p$ is not prime, `None` may be returned even if a square
+    /// root exists.
+    ///
+    /// $f(x, p, k) = y$, where $x, y < p^k$, $y \leq p^k - y$, and $y^2 \equiv x \mod p^k$.
+    ///
+    /// A square root modulo $p$ is lifted to a square root modulo $p^k$ using Hensel's lemma.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `k * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $p^k$, or if `p` is even and not 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_prime_power_sqrt(Natural::from(7u32), 2),
+    ///     Some(Natural::from(10u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).mod_prime_power_sqrt(Natural::from(7u32), 2),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: Natural, k: u64) -> Option<Natural> {
+        mod_prime_power_sqrt(self, &p, k)
+    }
+}
+
+impl<'a, 'b> ModPrimePowerSqrt<&'b Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo a prime power $p^k$. The [`Natural`] must be
+    /// already reduced modulo $p^k$. Both [`Natural`]s are taken by reference.
+    ///
+    /// Returns `None` if `self` is not a square modulo $p^k$. Otherwise, a square root $r$ with $r
+    /// \leq p^k - r$ is returned. If $p$ is not prime, `None` may be returned even if a square
+    /// root exists.
+    ///
+    /// $f(x, p, k) = y$, where $x, y < p^k$, $y \leq p^k - y$, and $y^2 \equiv x \mod p^k$.
+    ///
+    /// A square root modulo $p$ is lifted to a square root modulo $p^k$ using Hensel's lemma.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `k * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $p^k$, or if `p` is even and not 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_prime_power_sqrt(&Natural::from(7u32), 2),
+    ///     Some(Natural::from(10u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).mod_prime_power_sqrt(&Natural::from(7u32), 2),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: &'b Natural, k: u64) -> Option<Natural> {
+        mod_prime_power_sqrt(self, p, k)
+    }
+}
+
+impl ModFactoredSqrt<Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo $m$, given the factorization of $m$. The
+    /// [`Natural`] must be already reduced modulo $m$, and is taken by value.
+    ///
+    /// The factorization is given as a list of distinct primes and their exponents, as returned
+    /// by [`factor`](malachite_base::num::factorization::traits::Factor::factor). Returns `None`
+    /// if `self` is not a square modulo $m$. Otherwise, a square root $r$ with $r \leq m - r$ is
+    /// returned.
+    ///
+    /// $f(x, ((p_1, k_1), \ldots, (p_n, k_n))) = y$, where $m = \prod_{i=1}^n p_i^{k_i}$, $x, y <
+    /// m$, $y \leq m - y$, and $y^2 \equiv x \mod m$.
+    ///
+    /// Square roots modulo each prime power are found with
+    /// [`mod_prime_power_sqrt`](ModPrimePowerSqrt::mod_prime_power_sqrt) and combined using the
+    /// Chinese remainder theorem.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $m$, or if the primes are not distinct.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModFactoredSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// // 100 = 2^2 * 5^2
+    /// let factors = [(Natural::from(2u32), 2), (Natural::from(5u32), 2)];
+    /// assert_eq!(
+    ///     Natural::from(41u32).mod_factored_sqrt(&factors),
+    ///     Some(Natural::from(29u32))
+    /// );
+    /// assert_eq!(Natural::from(2u32).mod_factored_sqrt(&factors), None);
+    /// ```
+    #[inline]
+    fn mod_factored_sqrt(self, factors: &[(Natural, u64)]) -> Option<Natural> {
+        mod_factored_sqrt(&self, factors)
+    }
+}
+
+impl<'a> ModFactoredSqrt<Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo $m$, given the factorization of $m$. The
+    /// [`Natural`] must be already reduced modulo $m$, and is taken by reference.
+    ///
+    /// The factorization is given as a list of distinct primes and their exponents, as returned
+    /// by [`factor`](malachite_base::num::factorization::traits::Factor::factor). Returns `None`
+    /// if `self` is not a square modulo $m$. Otherwise, a square root $r$ with $r \leq m - r$ is
+    /// returned.
+    ///
+    /// $f(x, ((p_1, k_1), \ldots, (p_n, k_n))) = y$, where $m = \prod_{i=1}^n p_i^{k_i}$, $x, y <
+    /// m$, $y \leq m - y$, and $y^2 \equiv x \mod m$.
+    ///
+    /// Square roots modulo each prime power are found with
+    /// [`mod_prime_power_sqrt`](ModPrimePowerSqrt::mod_prime_power_sqrt) and combined using the
+    /// Chinese remainder theorem.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $m$, or if the primes are not distinct.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModFactoredSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// // 100 = 2^2 * 5^2
+    /// let factors = [(Natural::from(2u32), 2), (Natural::from(5u32), 2)];
+    /// assert_eq!(
+    ///     (&Natural::from(41u32)).mod_factored_sqrt(&factors),
+    ///     Some(Natural::from(29u32))
+    /// );
+    /// assert_eq!((&Natural::from(2u32)).mod_factored_sqrt(&factors), None);
+    /// ```
+    #[inline]
+    fn mod_factored_sqrt(self, factors: &[(Natural, u64)]) -> Option<Natural> {
+        mod_factored_sqrt(self, factors)
+    }
+}
@@ -0,0 +1,433 @@
+use crate::natural::arithmetic::div_exact::{
+    limbs_modular_invert, limbs_modular_invert_limb, limbs_modular_invert_scratch_len,
+};
+use crate::natural::arithmetic::mod_pow::{
+    get_bits, get_window_size, limbs_redc, limbs_redc_limb, REDC_1_TO_REDC_N_THRESHOLD,
+};
+use crate::natural::arithmetic::mul::{
+    limbs_mul_same_length_to_out, limbs_mul_same_length_to_out_scratch_len,
+};
+use crate::natural::arithmetic::square::{limbs_square_to_out, limbs_square_to_out_scratch_len};
+use crate::natural::arithmetic::sub::limbs_sub_same_length_in_place_left;
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::logic::bit_access::limbs_get_bit;
+use crate::natural::logic::significant_bits::limbs_significant_bits;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{Parity, PowerOf2, Square};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::TrailingZeros;
+use std::cmp::Ordering;
+
+/// Precomputed data for multiplying [`Natural`]s modulo a fixed odd modulus $m$ using Montgomery
+/// reduction.
+///
+/// If $m$ has $n$ limbs, let $R = B^n$, where $B$ is $2^W$ and $W$ is the width of a
+/// [`Limb`](crate#limbs). The Montgomery form of $x$ is $xR \bmod m$. Multiplying two numbers in
+/// Montgomery form, and reducing, gives the Montgomery form of their product without any division
+/// by $m$. A computation that performs many multiplications modulo the same $m$ can convert its
+/// inputs to Montgomery form once, stay in Montgomery form between operations, and convert the
+/// results back at the end.
+///
+/// All values passed to and returned from the methods of this type are [`Natural`]s less than $m$.
+/// Since the Montgomery form of $x$ is a different number from $x$, it is up to the caller to keep
+/// track of which values are in Montgomery form.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MontgomeryContext {
+    modulus: Natural,
+    // The limbs of the modulus
+    ms: Vec<Limb>,
+    // R mod m
+    r: Natural,
+    // R^2 mod m
+    r_squared: Natural,
+    // -m^(-1) mod B
+    m_inv: Limb,
+    // m^(-1) mod R, used for reduction when m is large; empty otherwise
+    is: Vec<Limb>,
+}
+
+impl MontgomeryContext {
+    /// Creates a [`MontgomeryContext`] for an odd modulus $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is even.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(1000000007u32));
+    /// assert_eq!(*ctx.modulus(), 1000000007u32);
+    /// ```
+    pub fn new(m: Natural) -> MontgomeryContext {
+        assert!(m.odd(), "The modulus must be odd, but it is {m}");
+        let ms = m.to_limbs_asc();
+        let n = ms.len();
+        let m_inv = limbs_modular_invert_limb(ms[0]).wrapping_neg();
+        let is = if n < REDC_1_TO_REDC_N_THRESHOLD {
+            Vec::new()
+        } else {
+            let mut is = vec![0; n];
+            let mut scratch = vec![0; limbs_modular_invert_scratch_len(n)];
+            limbs_modular_invert(&mut is, &ms, &mut scratch);
+            is
+        };
+        let r = Natural::power_of_2(u64::exact_from(n) << Limb::LOG_WIDTH) % &m;
+        let r_squared = (&r).square() % &m;
+        MontgomeryContext {
+            modulus: m,
+            ms,
+            r,
+            r_squared,
+            m_inv,
+            is,
+        }
+    }
+
+    /// Returns the modulus $m$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// assert_eq!(*ctx.modulus(), 101);
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> &Natural {
+        &self.modulus
+    }
+
+    /// Returns $R \bmod m$, which is the Montgomery form of 1.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// assert_eq!(ctx.from_montgomery(ctx.r()), 1);
+    /// ```
+    #[inline]
+    pub const fn r(&self) -> &Natural {
+        &self.r
+    }
+
+    /// Returns $R^2 \bmod m$, which is the Montgomery form of $R$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// assert_eq!(ctx.from_montgomery(ctx.r_squared()), *ctx.r());
+    /// ```
+    #[inline]
+    pub const fn r_squared(&self) -> &Natural {
+        &self.r_squared
+    }
+
+    /// Returns $-m^{-1} \bmod B$, where $B$ is $2^W$ and $W$ is the width of a
+    /// [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// assert_eq!(ctx.m_inv().wrapping_mul(101).wrapping_add(1), 0);
+    /// ```
+    #[inline]
+    pub const fn m_inv(&self) -> Limb {
+        self.m_inv
+    }
+
+    // Returns the limbs of `x`, padded with zeros to the length of the modulus.
+    fn to_padded_limbs(&self, x: &Natural) -> Vec<Limb> {
+        assert!(
+            *x < self.modulus,
+            "x must be reduced mod m, but {x} >= {}",
+            self.modulus
+        );
+        let mut xs = x.to_limbs_asc();
+        xs.resize(self.ms.len(), 0);
+        xs
+    }
+
+    // Given the limbs of some $x < mR$ in `xs`, which must have twice as many limbs as $m$, writes
+    // the limbs of $xR^{-1} \bmod m$ to `out`. `xs` is used as scratch space.
+    fn reduce(&self, out: &mut [Limb], xs: &mut [Limb]) {
+        if self.is.is_empty() {
+            limbs_redc_limb(out, xs, &self.ms, self.m_inv);
+        } else {
+            limbs_redc(out, xs, &self.ms, &self.is);
+        }
+        if limbs_cmp_same_length(out, &self.ms) != Ordering::Less {
+            limbs_sub_same_length_in_place_left(out, &self.ms);
+        }
+    }
+
+    // Multiplies two slices with the same length as the modulus, which represent numbers in
+    // Montgomery form, and returns their product in Montgomery form.
+    fn mul_limbs(&self, xs: &[Limb], ys: &[Limb]) -> Natural {
+        let n = self.ms.len();
+        let mut product = vec![0; n << 1];
+        let mut mul_scratch = vec![0; limbs_mul_same_length_to_out_scratch_len(n)];
+        limbs_mul_same_length_to_out(&mut product, xs, ys, &mut mul_scratch);
+        let mut out = vec![0; n];
+        self.reduce(&mut out, &mut product);
+        Natural::from_owned_limbs_asc(out)
+    }
+
+    /// Converts a [`Natural`] to Montgomery form. The input must be already reduced modulo $m$.
+    ///
+    /// $f(x) = xR \bmod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(5u32));
+    /// assert_eq!(ctx.from_montgomery(&x), 5);
+    /// ```
+    pub fn to_montgomery(&self, x: &Natural) -> Natural {
+        let xs = self.to_padded_limbs(x);
+        let r_squared = self.to_padded_limbs(&self.r_squared);
+        self.mul_limbs(&xs, &r_squared)
+    }
+
+    /// Converts a [`Natural`] from Montgomery form. The input must be already reduced modulo $m$.
+    ///
+    /// $f(x) = xR^{-1} \bmod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(5u32));
+    /// let y = ctx.to_montgomery(&Natural::from(7u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.mul(&x, &y)), 35);
+    /// ```
+    pub fn from_montgomery(&self, x: &Natural) -> Natural {
+        let n = self.ms.len();
+        let mut xs = self.to_padded_limbs(x);
+        xs.resize(n << 1, 0);
+        let mut out = vec![0; n];
+        self.reduce(&mut out, &mut xs);
+        Natural::from_owned_limbs_asc(out)
+    }
+
+    /// Multiplies two [`Natural`]s in Montgomery form, returning their product in Montgomery form.
+    /// The inputs must be already reduced modulo $m$.
+    ///
+    /// $f(x, y) = xyR^{-1} \bmod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` are greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(1000000007u32));
+    /// let x = ctx.to_montgomery(&Natural::from(123456u32));
+    /// let y = ctx.to_montgomery(&Natural::from(654321u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.mul(&x, &y)), 779852816);
+    /// ```
+    pub fn mul(&self, x: &Natural, y: &Natural) -> Natural {
+        let xs = self.to_padded_limbs(x);
+        let ys = self.to_padded_limbs(y);
+        self.mul_limbs(&xs, &ys)
+    }
+
+    /// Squares a [`Natural`] in Montgomery form, returning the result in Montgomery form. The
+    /// input must be already reduced modulo $m$.
+    ///
+    /// $f(x) = x^2R^{-1} \bmod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(1000000007u32));
+    /// let x = ctx.to_montgomery(&Natural::from(123456u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.square(&x)), 241383831);
+    /// ```
+    pub fn square(&self, x: &Natural) -> Natural {
+        let n = self.ms.len();
+        let xs = self.to_padded_limbs(x);
+        let mut product = vec![0; n << 1];
+        let mut square_scratch = vec![0; limbs_square_to_out_scratch_len(n)];
+        limbs_square_to_out(&mut product, &xs, &mut square_scratch);
+        let mut out = vec![0; n];
+        self.reduce(&mut out, &mut product);
+        Natural::from_owned_limbs_asc(out)
+    }
+
+    /// Raises a [`Natural`] in Montgomery form to a [`Natural`] power, returning the result in
+    /// Montgomery form. The base must be already reduced modulo $m$.
+    ///
+    /// This uses sliding-window exponentiation, and all intermediate values stay in Montgomery
+    /// form.
+    ///
+    /// $f(x, e) = x^eR^{1-e} \bmod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus().significant_bits()`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(1000000007u32));
+    /// let x = ctx.to_montgomery(&Natural::from(2u32));
+    /// assert_eq!(
+    ///     ctx.from_montgomery(&ctx.pow(&x, &Natural::from(1000000u32))),
+    ///     235042059
+    /// );
+    /// assert_eq!(ctx.from_montgomery(&ctx.pow(&x, &Natural::from(1000000006u32))), 1);
+    /// ```
+    pub fn pow(&self, x: &Natural, exp: &Natural) -> Natural {
+        let xs = self.to_padded_limbs(x);
+        if *exp == 0u32 {
+            return self.r.clone();
+        } else if *exp == 1u32 {
+            return x.clone();
+        }
+        let n = self.ms.len();
+        let es = exp.to_limbs_asc();
+        let width = limbs_significant_bits(&es);
+        let window_size = get_window_size(width);
+        let mut product = vec![0; n << 1];
+        let mut mul_scratch = vec![0; limbs_mul_same_length_to_out_scratch_len(n)];
+        let mut square_scratch = vec![0; limbs_square_to_out_scratch_len(n)];
+        let mut out = vec![0; n];
+        // Store x ^ 2 at `out`.
+        limbs_square_to_out(&mut product, &xs, &mut square_scratch);
+        self.reduce(&mut out, &mut product);
+        // Precompute odd powers of x and put them in `powers`.
+        let mut powers = vec![0; n << (window_size - 1)];
+        let mut powers: Vec<&mut [Limb]> = powers.chunks_mut(n).collect();
+        powers[0].copy_from_slice(&xs);
+        for i in 1..usize::power_of_2(window_size - 1) {
+            let (powers_lo, powers_hi) = powers.split_at_mut(i);
+            limbs_mul_same_length_to_out(&mut product, powers_lo[i - 1], &out, &mut mul_scratch);
+            self.reduce(powers_hi[0], &mut product);
+        }
+        let exp_bits = get_bits(&es, width, window_size);
+        let trailing_zeros = TrailingZeros::trailing_zeros(Limb::exact_from(exp_bits));
+        let mut bit_index = width - window_size + trailing_zeros;
+        out.copy_from_slice(powers[exp_bits >> trailing_zeros >> 1]);
+        'outer: while bit_index != 0 {
+            while !limbs_get_bit(&es, bit_index - 1) {
+                limbs_square_to_out(&mut product, &out, &mut square_scratch);
+                self.reduce(&mut out, &mut product);
+                bit_index -= 1;
+                if bit_index == 0 {
+                    break 'outer;
+                }
+            }
+            // The next bit of the exponent is 1. Now extract the largest block of bits <=
+            // window_size, and such that the least significant bit is 1.
+            let exp_bits = get_bits(&es, bit_index, window_size);
+            let mut this_window_size = window_size;
+            if bit_index < window_size {
+                this_window_size -= window_size - bit_index;
+                bit_index = 0;
+            } else {
+                bit_index -= window_size;
+            }
+            let trailing_zeros = TrailingZeros::trailing_zeros(Limb::exact_from(exp_bits));
+            bit_index += trailing_zeros;
+            for _ in 0..this_window_size - trailing_zeros {
+                limbs_square_to_out(&mut product, &out, &mut square_scratch);
+                self.reduce(&mut out, &mut product);
+            }
+            limbs_mul_same_length_to_out(
+                &mut product,
+                &out,
+                powers[exp_bits >> trailing_zeros >> 1],
+                &mut mul_scratch,
+            );
+            self.reduce(&mut out, &mut product);
+        }
+        Natural::from_owned_limbs_asc(out)
+    }
+}
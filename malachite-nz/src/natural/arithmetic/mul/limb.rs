@@ -1,9 +1,12 @@
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{DoubleLimb, Limb};
 use malachite_base::num::arithmetic::traits::XMulYToZZ;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::SplitInHalf;
+use std::iter::once;
+use std::ops::DerefMut;
 
 // Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
 // limbs of the product of the `Natural` and a `Limb`.
@@ -124,7 +127,8 @@ pub_crate_test! {limbs_slice_mul_limb_in_place(xs: &mut [Limb], y: Limb) -> Limb
 }}
 
 // Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
-// limbs of the product of the `Natural` and a `Limb` to the input `Vec`.
+// limbs of the product of the `Natural` and a `Limb` to the input `Vec`. Any growable sequence of
+// limbs, such as the `LimbVec` of a `Natural`, may be used in place of a `Vec`.
 //
 // # Worst-case complexity
 // $T(n) = O(n)$
@@ -135,10 +139,13 @@ pub_crate_test! {limbs_slice_mul_limb_in_place(xs: &mut [Limb], y: Limb) -> Limb
 //
 // This is equivalent to `mpn_mul_1` from `mpn/generic/mul_1.c`, GMP 6.2.1, where the `rp == up`
 // and instead of returning the carry, it is appended to `rp`.
-pub_test! {limbs_vec_mul_limb_in_place(xs: &mut Vec<Limb>, y: Limb) {
+pub_test! {limbs_vec_mul_limb_in_place<V: DerefMut<Target = [Limb]> + Extend<Limb>>(
+    xs: &mut V,
+    y: Limb,
+) {
     let carry = limbs_slice_mul_limb_in_place(xs, y);
     if carry != 0 {
-        xs.push(carry);
+        xs.extend(once(carry));
     }
 }}
 
@@ -153,7 +160,7 @@ impl Natural {
                 if upper == 0 {
                     *small = lower;
                 } else {
-                    *self = Natural(Large(vec![lower, upper]));
+                    *self = Natural(Large(LimbVec::from_slice(&[lower, upper])));
                 }
             }
             (&mut Natural(Large(ref mut limbs)), other) => {
@@ -172,10 +179,12 @@ impl Natural {
                 if upper == 0 {
                     Small(lower)
                 } else {
-                    Large(vec![lower, upper])
+                    Large(LimbVec::from_slice(&[lower, upper]))
                 }
             }),
-            (Natural(Large(ref limbs)), other) => Natural(Large(limbs_mul_limb(limbs, other))),
+            (Natural(Large(ref limbs)), other) => {
+                Natural(Large(limbs_mul_limb(limbs, other).into()))
+            }
         }
     }
 }
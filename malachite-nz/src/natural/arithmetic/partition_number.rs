@@ -0,0 +1,67 @@
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Zero};
+
+impl Natural {
+    /// Computes a partition number.
+    ///
+    /// $p(n)$ is the number of ways to write $n$ as a sum of positive integers, disregarding the
+    /// order of the summands.
+    ///
+    /// The computation uses Euler's pentagonal number theorem:
+    /// $$
+    /// p(n) = \sum_{k \neq 0} (-1)^{k+1} p\left (n - \frac{k(3k-1)}{2} \right ),
+    /// $$
+    /// where the sum is over all nonzero integers $k$ and $p(m) = 0$ for $m < 0$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n^{3/2})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Panics
+    /// Panics if `n` does not fit in a `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::partition_number(0), 1);
+    /// assert_eq!(Natural::partition_number(5), 7);
+    /// assert_eq!(Natural::partition_number(100), 190569292);
+    /// assert_eq!(
+    ///     Natural::partition_number(1000).to_string(),
+    ///     "24061467864032622473692149727991"
+    /// );
+    /// ```
+    pub fn partition_number(n: u64) -> Natural {
+        let n = usize::try_from(n).unwrap();
+        let mut ps = Vec::with_capacity(n + 1);
+        ps.push(Natural::ONE);
+        for m in 1..=n {
+            // Sum the positive and negative terms separately, since the result is nonnegative.
+            let mut positive = Natural::ZERO;
+            let mut negative = Natural::ZERO;
+            for k in 1.. {
+                // The generalized pentagonal numbers k(3k - 1) / 2 and k(3k + 1) / 2
+                let g = (k * (3 * k - 1)) >> 1;
+                if g > m {
+                    break;
+                }
+                let sum = if k & 1 == 1 {
+                    &mut positive
+                } else {
+                    &mut negative
+                };
+                *sum += &ps[m - g];
+                let g = g + k;
+                if g <= m {
+                    *sum += &ps[m - g];
+                }
+            }
+            ps.push(positive - negative);
+        }
+        ps.swap_remove(n)
+    }
+}
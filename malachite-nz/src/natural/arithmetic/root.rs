@@ -1,4 +1,5 @@
 use crate::natural::arithmetic::div::{limbs_div_limb_to_out, limbs_div_to_out};
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
 use crate::natural::arithmetic::mul::limb::limbs_slice_mul_limb_in_place;
 use crate::natural::arithmetic::mul::{
     limbs_mul_greater_to_out, limbs_mul_greater_to_out_scratch_len,
@@ -6,6 +7,7 @@ use crate::natural::arithmetic::mul::{
 use crate::natural::arithmetic::pow::limbs_pow;
 use crate::natural::arithmetic::shl::limbs_slice_shl_in_place;
 use crate::natural::arithmetic::shr::limbs_shr_to_out;
+use crate::natural::arithmetic::sqrt::limbs_could_be_square;
 use crate::natural::arithmetic::sub::{
     limbs_sub_greater_in_place_left, limbs_sub_greater_to_out, limbs_sub_limb_in_place,
     limbs_sub_limb_to_out,
@@ -16,13 +18,15 @@ use crate::natural::Natural;
 use crate::platform::Limb;
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
-    CeilingRoot, CeilingRootAssign, CeilingSqrt, CheckedRoot, CheckedSqrt, DivMod, DivRound,
-    FloorRoot, FloorRootAssign, FloorSqrt, ModPowerOf2Assign, PowerOf2, RootAssignRem, RootRem,
-    SqrtRem,
+    CeilingRoot, CeilingRootAssign, CeilingSqrt, CheckedRoot, CheckedSqrt, DivExact,
+    DivExactAssign, DivMod, DivRound, DivisibleBy, FloorRoot, FloorRootAssign, FloorSqrt, Gcd,
+    IsPerfectPower, ModPow, ModPowerOf2Assign, PerfectPowerDecomposition, PowerOf2, RootAssignRem,
+    RootRem, SqrtRem,
 };
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
 use malachite_base::num::logic::traits::{LeadingZeros, LowMask, SignificantBits};
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::slices::{slice_set_zero, slice_trailing_zeros};
@@ -1106,3 +1110,270 @@ impl RootAssignRem<u64> for Natural {
         rem
     }
 }
+
+// The odd primes less than 64.
+const SMALL_ODD_PRIMES: [Limb; 17] =
+    [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61];
+
+// The number of primes $q \equiv 1 \pmod p$ used to sieve candidate exponents $p$.
+const POWER_SIEVE_TESTS: usize = 4;
+
+fn natural_mod_limb(x: &Natural, d: Limb) -> Limb {
+    match *x {
+        Natural(Small(small)) => small % d,
+        Natural(Large(ref limbs)) => limbs_mod_limb(limbs, d),
+    }
+}
+
+// Returns `false` if $x$ is certainly not a perfect $p$th power, where $p$ is an odd prime.
+//
+// If $q$ is a prime with $q \equiv 1 \pmod p$ and $q \nmid x$, then $x$ can only be a $p$th power
+// if $x^{(q-1)/p} \equiv 1 \pmod q$. A number that is not a $p$th power passes each such test
+// with probability about $1/p$.
+#[allow(clippy::useless_conversion)]
+fn could_be_odd_prime_power(x: &Natural, p: u64) -> bool {
+    let mut tests = 0;
+    let mut q = 1u64;
+    while tests < POWER_SIEVE_TESTS {
+        q = match q.checked_add(p << 1) {
+            Some(q) if q <= u64::from(Limb::MAX) => q,
+            _ => break,
+        };
+        if !q.is_prime() {
+            continue;
+        }
+        tests += 1;
+        let r = u64::from(natural_mod_limb(x, Limb::wrapping_from(q)));
+        if r != 0 && r.mod_pow((q - 1) / p, q) != 1 {
+            return false;
+        }
+    }
+    true
+}
+
+// Given a `Natural` $x > 1$, returns the smallest prime $p \geq$ `min_p` for which $x$ is a
+// perfect $p$th power, together with the $p$th root of $x$. Returns `None` if there is no such
+// prime.
+//
+// Candidate exponents are narrowed down before any roots are taken: an exponent must divide the
+// multiplicity of every prime factor of $x$, so small prime factors are found by trial division,
+// and the remaining candidates are sieved with `could_be_odd_prime_power`.
+fn smallest_prime_root(x: &Natural, min_p: u64) -> Option<(Natural, u64)> {
+    let bits = x.significant_bits();
+    // `exp_gcd` is the gcd of the multiplicities of the small primes dividing x, or 0 if no small
+    // prime divides x.
+    let mut exp_gcd = x.trailing_zeros().unwrap();
+    for &q in &SMALL_ODD_PRIMES {
+        if exp_gcd == 1 {
+            return None;
+        }
+        if natural_mod_limb(x, q) == 0 {
+            let q = Natural::from(q);
+            let mut y = x.div_exact(&q);
+            let mut multiplicity = 1;
+            while (&y).divisible_by(&q) {
+                y.div_exact_assign(&q);
+                multiplicity += 1;
+            }
+            exp_gcd = exp_gcd.gcd(multiplicity);
+        }
+    }
+    if exp_gcd == 1 {
+        return None;
+    }
+    // If $x = a^p$ with $a \geq 2$, then $x \geq 2^p$. If no prime less than 64 divides $x$, then
+    // $a \geq 67 > 2^6$, so $x \geq 2^{6p}$.
+    let max_p = if exp_gcd == 0 {
+        (bits - 1) / 6
+    } else {
+        bits - 1
+    };
+    for p in u64::primes() {
+        if p > max_p {
+            break;
+        }
+        if p < min_p || (exp_gcd != 0 && !exp_gcd.divisible_by(p)) {
+            continue;
+        }
+        let root = if p == 2 {
+            match *x {
+                Natural(Large(ref limbs)) if !limbs_could_be_square(limbs) => continue,
+                _ => x.checked_sqrt(),
+            }
+        } else if could_be_odd_prime_power(x, p) {
+            x.checked_root(p)
+        } else {
+            continue;
+        };
+        if let Some(root) = root {
+            return Some((root, p));
+        }
+    }
+    None
+}
+
+impl IsPerfectPower for Natural {
+    /// Determines whether a [`Natural`] is a perfect power; that is, whether it is equal to $a^k$
+    /// for some integers $a$ and $k \geq 2$.
+    ///
+    /// 0 and 1 are considered perfect powers. Most numbers that are not perfect powers are
+    /// rejected by looking at their residues modulo small primes, without computing any roots.
+    ///
+    /// $f(x) = (\exists a, k \in \N : k \geq 2 \land x = a^k)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{IsPerfectPower, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(0u32).is_perfect_power(), true);
+    /// assert_eq!(Natural::from(1u32).is_perfect_power(), true);
+    /// assert_eq!(Natural::from(12u32).is_perfect_power(), false);
+    /// assert_eq!(Natural::from(1000u32).is_perfect_power(), true);
+    /// assert_eq!(Natural::from(10u32).pow(30).is_perfect_power(), true);
+    /// assert_eq!(Natural::from(3u32).pow(101).is_perfect_power(), true);
+    /// assert_eq!((Natural::from(3u32).pow(101) + Natural::from(1u32)).is_perfect_power(), false);
+    /// ```
+    fn is_perfect_power(&self) -> bool {
+        match *self {
+            Natural(Small(small)) => small.is_perfect_power(),
+            Natural(Large(_)) => smallest_prime_root(self, 2).is_some(),
+        }
+    }
+}
+
+// Given a `Natural` $x$ that does not fit in a single limb, returns $(a, k)$ such that $x = a^k$
+// and $k$ is as large as possible, or `None` if $k = 1$.
+//
+// Prime roots are taken, smallest prime first, for as long as possible. Once a prime $p$ has been
+// ruled out, it never needs to be tried again, since a root of $x$ that is a $p$th power would
+// make $x$ a $p$th power too.
+fn perfect_power_decomposition_large(x: &Natural) -> Option<(Natural, u64)> {
+    let (mut base, mut exp) = smallest_prime_root(x, 2)?;
+    let mut min_p = exp;
+    loop {
+        if let Natural(Small(small)) = base {
+            let (small_base, small_exp) = small.perfect_power_decomposition();
+            return Some((Natural::from(small_base), exp * small_exp));
+        }
+        match smallest_prime_root(&base, min_p) {
+            Some((root, p)) => {
+                base = root;
+                exp *= p;
+                min_p = p;
+            }
+            None => return Some((base, exp)),
+        }
+    }
+}
+
+impl PerfectPowerDecomposition for Natural {
+    type Output = Natural;
+
+    /// Writes a [`Natural`] as $a^k$, where $k$ is as large as possible, and returns $(a, k)$. The
+    /// [`Natural`] is taken by value.
+    ///
+    /// If the [`Natural`] is not a perfect power, $k$ is 1. Since 0 and 1 are $k$th powers for
+    /// every $k$, they are returned with an exponent of 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{PerfectPowerDecomposition, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(0u32).perfect_power_decomposition().to_debug_string(), "(0, 1)");
+    /// assert_eq!(Natural::from(12u32).perfect_power_decomposition().to_debug_string(), "(12, 1)");
+    /// assert_eq!(
+    ///     Natural::from(1000u32).perfect_power_decomposition().to_debug_string(),
+    ///     "(10, 3)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(30).perfect_power_decomposition().to_debug_string(),
+    ///     "(10, 30)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(36u32).pow(35).perfect_power_decomposition().to_debug_string(),
+    ///     "(6, 70)"
+    /// );
+    /// ```
+    fn perfect_power_decomposition(self) -> (Natural, u64) {
+        match self {
+            Natural(Small(small)) => {
+                let (base, exp) = small.perfect_power_decomposition();
+                (Natural::from(base), exp)
+            }
+            Natural(Large(_)) => perfect_power_decomposition_large(&self).unwrap_or((self, 1)),
+        }
+    }
+}
+
+impl<'a> PerfectPowerDecomposition for &'a Natural {
+    type Output = Natural;
+
+    /// Writes a [`Natural`] as $a^k$, where $k$ is as large as possible, and returns $(a, k)$. The
+    /// [`Natural`] is taken by reference.
+    ///
+    /// If the [`Natural`] is not a perfect power, $k$ is 1. Since 0 and 1 are $k$th powers for
+    /// every $k$, they are returned with an exponent of 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{PerfectPowerDecomposition, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(0u32)).perfect_power_decomposition().to_debug_string(),
+    ///     "(0, 1)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(12u32)).perfect_power_decomposition().to_debug_string(),
+    ///     "(12, 1)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(1000u32)).perfect_power_decomposition().to_debug_string(),
+    ///     "(10, 3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(10u32).pow(30)).perfect_power_decomposition().to_debug_string(),
+    ///     "(10, 30)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(36u32).pow(35)).perfect_power_decomposition().to_debug_string(),
+    ///     "(6, 70)"
+    /// );
+    /// ```
+    fn perfect_power_decomposition(self) -> (Natural, u64) {
+        match *self {
+            Natural(Small(small)) => {
+                let (base, exp) = small.perfect_power_decomposition();
+                (Natural::from(base), exp)
+            }
+            Natural(Large(_)) => {
+                perfect_power_decomposition_large(self).unwrap_or_else(|| (self.clone(), 1))
+            }
+        }
+    }
+}
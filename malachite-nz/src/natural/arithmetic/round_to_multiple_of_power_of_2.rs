@@ -657,7 +657,9 @@ impl RoundToMultipleOfPowerOf2Assign<u64> for Natural {
                 o
             }
             (Natural(Large(ref mut limbs)), pow) => {
-                if let Some(o) = limbs_round_to_multiple_of_power_of_2_in_place(limbs, pow, rm) {
+                if let Some(o) =
+                    limbs_round_to_multiple_of_power_of_2_in_place(limbs.as_mut_vec(), pow, rm)
+                {
                     self.trim();
                     o
                 } else {
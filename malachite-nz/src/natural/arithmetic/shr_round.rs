@@ -375,7 +375,8 @@ where
         (_, bits) if bits == T::ZERO => Ordering::Equal,
         (Natural(Small(ref mut small)), bits) => small.shr_round_assign(bits, rm),
         (Natural(Large(ref mut limbs)), bits) => {
-            let (b, o) = limbs_vec_shr_round_in_place(limbs, u64::exact_from(bits), rm);
+            let (b, o) =
+                limbs_vec_shr_round_in_place(limbs.as_mut_vec(), u64::exact_from(bits), rm);
             if !b {
                 panic!("Right shift is not exact.");
             }
@@ -10,6 +10,7 @@ use crate::natural::arithmetic::div::{
 use crate::natural::arithmetic::div_mod::{
     limbs_div_limb_to_out_mod, limbs_div_mod_qs_to_out_rs_to_ns, limbs_two_limb_inverse_helper,
 };
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
 use crate::natural::arithmetic::mul::{
     limbs_mul_greater_to_out, limbs_mul_greater_to_out_scratch_len,
 };
@@ -26,8 +27,9 @@ use crate::natural::Natural;
 use crate::platform::{Limb, SignedLimb, DC_DIVAPPR_Q_THRESHOLD, MU_DIVAPPR_Q_THRESHOLD};
 use malachite_base::num::arithmetic::sqrt::sqrt_rem_newton;
 use malachite_base::num::arithmetic::traits::{
-    CeilingSqrt, CeilingSqrtAssign, CheckedSqrt, FloorSqrt, FloorSqrtAssign, ModPowerOf2, Parity,
-    ShrRound, SqrtAssignRem, SqrtRem, Square, WrappingAddAssign, WrappingSquare, WrappingSubAssign,
+    CeilingSqrt, CeilingSqrtAssign, CheckedSqrt, FloorSqrt, FloorSqrtAssign, IsSquare, ModPowerOf2,
+    Parity, ShrRound, SqrtAssignRem, SqrtRem, Square, WrappingAddAssign, WrappingSquare,
+    WrappingSubAssign,
 };
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::traits::ExactFrom;
@@ -417,6 +419,40 @@ pub_test! {limbs_sqrt_to_out(out: &mut [Limb], xs: &[Limb]) {
     }
 }}
 
+// Bit $r$ of each mask is set if and only if $r$ is a square modulo the corresponding number.
+const SQUARES_MOD_64: u64 = 0x202021202030213;
+const SQUARES_MOD_63: u64 = 0x402483012450293;
+const SQUARES_MOD_65: u128 = 0x1218a019866014613;
+const SQUARES_MOD_11: u64 = 0x23b;
+
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns
+// `false` if the `Natural` is certainly not a perfect square. If `true` is returned, the `Natural`
+// may or may not be a perfect square.
+//
+// The residues mod 64 and mod $63 \cdot 65 \cdot 11 = 45045$ are checked; fewer than 1% of
+// non-squares pass.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if the length of `xs` is less than 2.
+pub_crate_test! {
+#[allow(clippy::useless_conversion)]
+limbs_could_be_square(xs: &[Limb]) -> bool {
+    if !SQUARES_MOD_64.get_bit(u64::from(xs[0]) & 63) {
+        return false;
+    }
+    let r = u64::from(limbs_mod_limb(xs, 45045));
+    SQUARES_MOD_63.get_bit(r % 63)
+        && SQUARES_MOD_65.get_bit(r % 65)
+        && SQUARES_MOD_11.get_bit(r % 11)
+}}
+
 // Computes the square root and remainder of a `Natural`.
 //
 // Let $n$ be `xs.len()` and $x$ be the `Natural` whose limbs are `xs`. Let $s$ be the `Natural`
@@ -938,6 +974,43 @@ impl<'a> CheckedSqrt for &'a Natural {
     }
 }
 
+impl IsSquare for Natural {
+    /// Determines whether a [`Natural`] is a perfect square.
+    ///
+    /// Most non-squares are rejected by looking at their residues modulo a few small numbers,
+    /// without computing a square root.
+    ///
+    /// $f(x) = (\exists k \in \N : x = k^2)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{IsSquare, Pow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(0u32).is_square(), true);
+    /// assert_eq!(Natural::from(99u32).is_square(), false);
+    /// assert_eq!(Natural::from(100u32).is_square(), true);
+    /// assert_eq!(Natural::from(10u32).pow(30).is_square(), true);
+    /// assert_eq!(Natural::from(10u32).pow(31).is_square(), false);
+    /// assert_eq!((Natural::from(10u32).pow(30) + Natural::from(1u32)).is_square(), false);
+    /// ```
+    fn is_square(&self) -> bool {
+        match *self {
+            Natural(Small(small)) => small.is_square(),
+            Natural(Large(ref limbs)) => {
+                limbs_could_be_square(limbs) && self.checked_sqrt().is_some()
+            }
+        }
+    }
+}
+
 impl SqrtRem for Natural {
     type SqrtOutput = Natural;
     type RemOutput = Natural;
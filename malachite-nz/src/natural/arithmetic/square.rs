@@ -28,6 +28,7 @@ use crate::natural::arithmetic::sub::{
     limbs_sub_limb_in_place, limbs_sub_same_length_in_place_left, limbs_sub_same_length_to_out,
 };
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{
@@ -1064,7 +1065,7 @@ impl<'a> Square for &'a Natural {
                 if upper == 0 {
                     Small(lower)
                 } else {
-                    Large(vec![lower, upper])
+                    Large(LimbVec::from_slice(&[lower, upper]))
                 }
             }),
             Natural(Large(ref xs)) => Natural::from_owned_limbs_asc(limbs_square(xs)),
@@ -1108,11 +1109,11 @@ impl SquareAssign for Natural {
                 if upper == 0 {
                     *x = lower;
                 } else {
-                    *self = Natural(Large(vec![lower, upper]));
+                    *self = Natural(Large(LimbVec::from_slice(&[lower, upper])));
                 }
             }
             Natural(Large(ref mut xs)) => {
-                *xs = limbs_square(xs);
+                *xs = limbs_square(xs).into();
                 self.trim();
             }
         }
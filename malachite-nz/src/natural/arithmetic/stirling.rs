@@ -0,0 +1,121 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, Factorial, ModPowerOf2, Parity, Pow, PowerOf2,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// Returns $(x + a)(x + a + 1) \cdots (x + b - 1)$ evaluated at $x = 2^w$ and reduced modulo
+// $2^{kw}$, using binary splitting so that the expensive multiplications are balanced. If every
+// coefficient of the polynomial is less than $2^w$, the result holds the coefficients of $x^0$
+// through $x^{k-1}$ in consecutive $w$-bit slots.
+fn rising_factorial_kronecker(a: u64, b: u64, w: u64, k: u64) -> Natural {
+    if b - a == 1 {
+        (Natural::power_of_2(w) + Natural::from(a)).mod_power_of_2(k * w)
+    } else {
+        let mid = a + ((b - a) >> 1);
+        (rising_factorial_kronecker(a, mid, w, k) * rising_factorial_kronecker(mid, b, w, k))
+            .mod_power_of_2(k * w)
+    }
+}
+
+impl Natural {
+    /// Computes an unsigned Stirling number of the first kind.
+    ///
+    /// $c(n, k)$ is the number of permutations of $n$ elements with exactly $k$ cycles. The signed
+    /// Stirling number of the first kind is $(-1)^{n-k}c(n, k)$.
+    ///
+    /// $$
+    /// \sum_{k=0}^n c(n, k)x^k = x(x + 1)(x + 2) \cdots (x + n - 1).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(M(nk \log n) \log n)$
+    ///
+    /// $M(n, k) = O(nk \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $M(n)$ is the time needed to multiply two
+    /// $n$-bit [`Natural`]s.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::stirling_first(0, 0), 1);
+    /// assert_eq!(Natural::stirling_first(4, 2), 11);
+    /// assert_eq!(Natural::stirling_first(10, 3), 1172700);
+    /// assert_eq!(
+    ///     Natural::stirling_first(30, 10).to_string(),
+    ///     "215760462268683520394805979744"
+    /// );
+    /// ```
+    pub fn stirling_first(n: u64, k: u64) -> Natural {
+        if k > n {
+            Natural::ZERO
+        } else if k == n {
+            Natural::ONE
+        } else if k == 0 {
+            Natural::ZERO
+        } else {
+            // The factor for i = 0 is x, which just shifts the coefficients, so we compute the
+            // coefficient of x^(k - 1) in (x + 1)(x + 2)...(x + n - 1) using Kronecker
+            // substitution. The coefficients sum to n!, so each of them fits in w bits.
+            let w = Natural::factorial(n).significant_bits();
+            (rising_factorial_kronecker(1, n, w, k) >> ((k - 1) * w)).mod_power_of_2(w)
+        }
+    }
+
+    /// Computes a Stirling number of the second kind.
+    ///
+    /// $S(n, k)$ is the number of ways to partition a set of $n$ elements into exactly $k$
+    /// nonempty subsets.
+    ///
+    /// $$
+    /// S(n, k) = \frac{1}{k!}\sum_{j=0}^k (-1)^{k-j}\binom{k}{j}j^n.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(k M(n \log k) \log n)$
+    ///
+    /// $M(n, k) = O(n \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $M(n)$ is the time needed to multiply two
+    /// $n$-bit [`Natural`]s.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::stirling_second(0, 0), 1);
+    /// assert_eq!(Natural::stirling_second(4, 2), 7);
+    /// assert_eq!(Natural::stirling_second(10, 3), 9330);
+    /// assert_eq!(
+    ///     Natural::stirling_second(30, 10).to_string(),
+    ///     "173373343599189364594756"
+    /// );
+    /// ```
+    pub fn stirling_second(n: u64, k: u64) -> Natural {
+        if k > n {
+            Natural::ZERO
+        } else if k == n {
+            Natural::ONE
+        } else if k == 0 {
+            Natural::ZERO
+        } else {
+            // Sum the positive and negative terms separately, since the result is nonnegative.
+            let mut positive = Natural::ZERO;
+            let mut negative = Natural::ZERO;
+            let mut binomial = Natural::ONE;
+            for j in 0..=k {
+                let term = &binomial * Natural::from(j).pow(n);
+                if (k - j).even() {
+                    positive += term;
+                } else {
+                    negative += term;
+                }
+                binomial = (binomial * Natural::from(k - j)).div_exact(Natural::from(j + 1));
+            }
+            (positive - negative).div_exact(Natural::factorial(k))
+        }
+    }
+}
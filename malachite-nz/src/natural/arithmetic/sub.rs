@@ -519,7 +519,7 @@ impl Natural {
             *self -= Natural::from(y);
             return;
         }
-        let xs = self.promote_in_place();
+        let xs = self.promote_in_place().as_mut_vec();
         if xs.len() <= i {
             xs.resize(i + 1, 0);
         }
@@ -0,0 +1,396 @@
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::ModPowerOf2;
+use malachite_base::num::conversion::traits::{
+    ExactFrom, PowerOf2Digits, VecFromOtherType, VecFromOtherTypeSlice,
+};
+use malachite_base::slices::slice_trailing_zeros;
+
+/// The order of bytes within a word, or of words within a sequence of words.
+///
+/// [`Endianness::Little`] puts the least-significant byte or word first, and [`Endianness::Big`]
+/// puts the most-significant byte or word first.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+// Reverses the order of bytes within each `word_size`-byte word, and/or the order of the words,
+// converting between little-endian byte and word order and the requested orders.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+fn reorder_words(
+    bytes: &mut [u8],
+    word_size: usize,
+    word_order: Endianness,
+    byte_order: Endianness,
+) {
+    if byte_order == Endianness::Big {
+        for word in bytes.chunks_exact_mut(word_size) {
+            word.reverse();
+        }
+    }
+    if word_order == Endianness::Big {
+        // Reversing all the bytes reverses the words and the bytes within each word; reversing
+        // each word again restores the byte order.
+        bytes.reverse();
+        for word in bytes.chunks_exact_mut(word_size) {
+            word.reverse();
+        }
+    }
+}
+
+impl Natural {
+    /// Returns the bytes of a [`Natural`] in little-endian order, so that less-significant bytes
+    /// have lower indices in the output vector.
+    ///
+    /// There are no trailing zero bytes; in particular, zero produces an empty vector. The output
+    /// does not depend on the width of a [limb](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.to_bytes_le().is_empty());
+    /// assert_eq!(Natural::from(123u32).to_bytes_le(), &[123]);
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_le(), &[0x34, 0x12]);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(12).to_bytes_le(),
+    ///     &[0x00, 0x10, 0xa5, 0xd4, 0xe8]
+    /// );
+    /// ```
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = match *self {
+            Natural(Small(small)) => u8::vec_from_other_type(small),
+            Natural(Large(ref limbs)) => u8::vec_from_other_type_slice(limbs),
+        };
+        bytes.truncate(bytes.len() - slice_trailing_zeros(&bytes));
+        bytes
+    }
+
+    /// Returns the bytes of a [`Natural`] in big-endian order, so that less-significant bytes
+    /// have higher indices in the output vector.
+    ///
+    /// There are no leading zero bytes; in particular, zero produces an empty vector. The output
+    /// does not depend on the width of a [limb](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.to_bytes_be().is_empty());
+    /// assert_eq!(Natural::from(123u32).to_bytes_be(), &[123]);
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_be(), &[0x12, 0x34]);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(12).to_bytes_be(),
+    ///     &[0xe8, 0xd4, 0xa5, 0x10, 0x00]
+    /// );
+    /// ```
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Returns the bytes of a [`Natural`] in little-endian order, padded with zeros to exactly
+    /// `len` bytes. If the [`Natural`] doesn't fit in `len` bytes, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ZERO.to_bytes_le_padded(2).unwrap(), &[0, 0]);
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_le_padded(4).unwrap(), &[0x34, 0x12, 0, 0]);
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_le_padded(2).unwrap(), &[0x34, 0x12]);
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_le_padded(1), None);
+    /// ```
+    pub fn to_bytes_le_padded(&self, len: usize) -> Option<Vec<u8>> {
+        let mut bytes = self.to_bytes_le();
+        if bytes.len() > len {
+            None
+        } else {
+            bytes.resize(len, 0);
+            Some(bytes)
+        }
+    }
+
+    /// Returns the bytes of a [`Natural`] in big-endian order, padded with zeros to exactly `len`
+    /// bytes. If the [`Natural`] doesn't fit in `len` bytes, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `len`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ZERO.to_bytes_be_padded(2).unwrap(), &[0, 0]);
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_be_padded(4).unwrap(), &[0, 0, 0x12, 0x34]);
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_be_padded(2).unwrap(), &[0x12, 0x34]);
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_be_padded(1), None);
+    /// ```
+    pub fn to_bytes_be_padded(&self, len: usize) -> Option<Vec<u8>> {
+        let mut bytes = self.to_bytes_le_padded(len)?;
+        bytes.reverse();
+        Some(bytes)
+    }
+
+    /// Converts a slice of bytes in little-endian order to a [`Natural`]. Less-significant bytes
+    /// have lower indices in the input slice.
+    ///
+    /// Trailing zero bytes are allowed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_bytes_le(&[]), 0);
+    /// assert_eq!(Natural::from_bytes_le(&[123]), 123);
+    /// assert_eq!(Natural::from_bytes_le(&[0x34, 0x12, 0, 0]), 0x1234);
+    /// assert_eq!(
+    ///     Natural::from_bytes_le(&[0x00, 0x10, 0xa5, 0xd4, 0xe8]),
+    ///     Natural::from(10u32).pow(12)
+    /// );
+    /// ```
+    pub fn from_bytes_le(bytes: &[u8]) -> Natural {
+        Natural::from_owned_limbs_asc(Limb::vec_from_other_type_slice(bytes))
+    }
+
+    /// Converts a slice of bytes in big-endian order to a [`Natural`]. Less-significant bytes have
+    /// higher indices in the input slice.
+    ///
+    /// Leading zero bytes are allowed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_bytes_be(&[]), 0);
+    /// assert_eq!(Natural::from_bytes_be(&[123]), 123);
+    /// assert_eq!(Natural::from_bytes_be(&[0, 0, 0x12, 0x34]), 0x1234);
+    /// assert_eq!(
+    ///     Natural::from_bytes_be(&[0xe8, 0xd4, 0xa5, 0x10, 0x00]),
+    ///     Natural::from(10u32).pow(12)
+    /// );
+    /// ```
+    pub fn from_bytes_be(bytes: &[u8]) -> Natural {
+        let bytes: Vec<u8> = bytes.iter().rev().copied().collect();
+        Natural::from_bytes_le(&bytes)
+    }
+
+    /// Writes a [`Natural`] as a sequence of words of `word_size` bytes each, in the manner of
+    /// GMP's `mpz_export`.
+    ///
+    /// Each word holds $8w - k$ bits of the [`Natural`], where $w$ is `word_size` and $k$ is
+    /// `nails`; the $k$ most significant bits of each word, the "nail bits", are zero. The words
+    /// are ordered according to `word_order`, and the bytes within each word according to
+    /// `byte_order`. As few words as possible are used; in particular, zero produces an empty
+    /// vector.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `word_size` is zero or if `nails` is greater than or equal to `8 * word_size`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::conversion::bytes::Endianness;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = Natural::from(0x123456u32);
+    /// assert_eq!(
+    ///     x.export_words(2, Endianness::Little, Endianness::Little, 0),
+    ///     &[0x56, 0x34, 0x12, 0x00]
+    /// );
+    /// assert_eq!(
+    ///     x.export_words(2, Endianness::Big, Endianness::Little, 0),
+    ///     &[0x12, 0x00, 0x56, 0x34]
+    /// );
+    /// assert_eq!(
+    ///     x.export_words(2, Endianness::Big, Endianness::Big, 0),
+    ///     &[0x00, 0x12, 0x34, 0x56]
+    /// );
+    /// // 0x123456 = 0b100100011010001010110, split into 7-bit digits
+    /// assert_eq!(
+    ///     x.export_words(1, Endianness::Little, Endianness::Little, 1),
+    ///     &[0x56, 0x68, 0x48]
+    /// );
+    /// ```
+    pub fn export_words(
+        &self,
+        word_size: usize,
+        word_order: Endianness,
+        byte_order: Endianness,
+        nails: u64,
+    ) -> Vec<u8> {
+        assert_ne!(word_size, 0);
+        let word_bits = u64::exact_from(word_size) << 3;
+        assert!(nails < word_bits);
+        let mut bytes = if nails == 0 {
+            let mut bytes = self.to_bytes_le();
+            let remainder = bytes.len() % word_size;
+            if remainder != 0 {
+                bytes.resize(bytes.len() + word_size - remainder, 0);
+            }
+            bytes
+        } else {
+            let digits: Vec<Natural> = self.to_power_of_2_digits_asc(word_bits - nails);
+            let mut bytes = Vec::with_capacity(digits.len() * word_size);
+            for digit in digits {
+                bytes.extend(digit.to_bytes_le_padded(word_size).unwrap());
+            }
+            bytes
+        };
+        reorder_words(&mut bytes, word_size, word_order, byte_order);
+        bytes
+    }
+
+    /// Converts a sequence of words of `word_size` bytes each to a [`Natural`], in the manner of
+    /// GMP's `mpz_import`.
+    ///
+    /// Each word holds $8w - k$ bits of the [`Natural`], where $w$ is `word_size` and $k$ is
+    /// `nails`; the $k$ most significant bits of each word, the "nail bits", are ignored. The
+    /// words are ordered according to `word_order`, and the bytes within each word according to
+    /// `byte_order`. This function is the inverse of
+    /// [`export_words`](Self::export_words).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bytes.len()`.
+    ///
+    /// # Panics
+    /// Panics if `word_size` is zero, if `nails` is greater than or equal to `8 * word_size`, or
+    /// if the length of `bytes` is not a multiple of `word_size`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::conversion::bytes::Endianness;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::import_words(
+    ///         &[0x56, 0x34, 0x12, 0x00],
+    ///         2,
+    ///         Endianness::Little,
+    ///         Endianness::Little,
+    ///         0
+    ///     ),
+    ///     0x123456
+    /// );
+    /// assert_eq!(
+    ///     Natural::import_words(
+    ///         &[0x00, 0x12, 0x34, 0x56],
+    ///         2,
+    ///         Endianness::Big,
+    ///         Endianness::Big,
+    ///         0
+    ///     ),
+    ///     0x123456
+    /// );
+    /// // The nail bits are ignored
+    /// assert_eq!(
+    ///     Natural::import_words(
+    ///         &[0xd6, 0xe8, 0x48],
+    ///         1,
+    ///         Endianness::Little,
+    ///         Endianness::Little,
+    ///         1
+    ///     ),
+    ///     0x123456
+    /// );
+    /// ```
+    pub fn import_words(
+        bytes: &[u8],
+        word_size: usize,
+        word_order: Endianness,
+        byte_order: Endianness,
+        nails: u64,
+    ) -> Natural {
+        assert_ne!(word_size, 0);
+        let word_bits = u64::exact_from(word_size) << 3;
+        assert!(nails < word_bits);
+        assert_eq!(bytes.len() % word_size, 0);
+        let mut bytes = bytes.to_vec();
+        // Reordering twice restores the original order, so the same function converts to
+        // little-endian order.
+        reorder_words(&mut bytes, word_size, word_order, byte_order);
+        if nails == 0 {
+            Natural::from_bytes_le(&bytes)
+        } else {
+            let digit_bits = word_bits - nails;
+            Natural::from_power_of_2_digits_asc(
+                digit_bits,
+                bytes
+                    .chunks_exact(word_size)
+                    .map(|word| Natural::from_bytes_le(word).mod_power_of_2(digit_bits)),
+            )
+            .unwrap()
+        }
+    }
+}
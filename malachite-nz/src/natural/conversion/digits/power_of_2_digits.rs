@@ -362,7 +362,7 @@ impl PowerOf2Digits<Natural> for Natural {
         } else {
             let mut digit = Natural::ZERO;
             let mut remaining_digit_bits = log_base;
-            for &limb in limbs {
+            for &limb in limbs.iter() {
                 let mut limb = limb;
                 let mut remaining_limb_bits = Limb::WIDTH;
                 while remaining_limb_bits != 0 {
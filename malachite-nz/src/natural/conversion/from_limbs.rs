@@ -1,3 +1,4 @@
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
@@ -55,7 +56,7 @@ impl Natural {
         match significant_length {
             0 => Natural::ZERO,
             1 => Natural(Small(xs[0])),
-            _ => Natural(Large(xs[..significant_length].to_vec())),
+            _ => Natural(Large(LimbVec::from_slice(&xs[..significant_length]))),
         }
     }
 
@@ -130,7 +131,7 @@ impl Natural {
             1 => Natural(Small(xs[0])),
             _ => {
                 xs.truncate(significant_length);
-                Natural(Large(xs))
+                Natural(Large(xs.into()))
             }
         }
     }
@@ -1,3 +1,7 @@
+/// Functions for converting [`Natural`](crate::natural::Natural)s to and from bytes, in
+/// little-endian or big-endian order, or as sequences of words in the manner of GMP's `mpz_import`
+/// and `mpz_export`.
+pub mod bytes;
 /// Implementations of traits for working with the digits of [`Natural`](crate::natural::Natural)s.
 pub mod digits;
 /// An implementation of the [`From`] trait for converting a [`bool`] to a
@@ -261,6 +265,14 @@ pub mod limb_count;
 /// test(0.5, 1, None);
 /// ```
 pub mod mantissa_and_exponent;
+/// Implementations of traits from [num-traits](https://docs.rs/num-traits) and
+/// [num-integer](https://docs.rs/num-integer), such as [`Num`](::num_traits::Num) and
+/// [`Integer`](::num_integer::Integer), for [`Natural`](crate::natural::Natural). This allows
+/// [`Natural`](crate::natural::Natural)s to be used in generic code written against those traits.
+///
+/// These implementations are only available when the `enable_num_traits` feature is enabled.
+#[cfg(feature = "enable_num_traits")]
+pub mod num_traits;
 /// Implementations of traits for converting a [`Natural`](crate::natural::Natural) to a primitive
 /// float.
 ///
@@ -0,0 +1,328 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CheckedSub, DivRem, DivRound, DivisibleBy, FloorRoot, FloorSqrt, Gcd, Lcm, Parity, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{FromStringBase, RoundingFrom, WrappingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+
+impl num_traits::Zero for Natural {
+    /// Returns zero. See [`Zero::ZERO`].
+    #[inline]
+    fn zero() -> Natural {
+        Natural::ZERO
+    }
+
+    /// Determines whether a [`Natural`] is zero.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == 0u32
+    }
+}
+
+impl num_traits::One for Natural {
+    /// Returns one. See [`One::ONE`].
+    #[inline]
+    fn one() -> Natural {
+        Natural::ONE
+    }
+
+    /// Determines whether a [`Natural`] is one.
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == 1u32
+    }
+}
+
+/// The error returned by the implementations of
+/// [`Num::from_str_radix`](num_traits::Num::from_str_radix) for [`Natural`],
+/// [`Integer`](crate::integer::Integer), and `Rational`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FromStrRadixError {
+    /// The radix was less than 2 or greater than 36.
+    InvalidRadix,
+    /// The string did not represent a number in the given radix.
+    InvalidString,
+}
+
+impl num_traits::Num for Natural {
+    type FromStrRadixErr = FromStrRadixError;
+
+    /// Converts a string in the given radix to a [`Natural`]. See
+    /// [`FromStringBase::from_string_base`].
+    ///
+    /// Returns [`FromStrRadixError::InvalidRadix`] if `radix` is less than 2 or greater than 36,
+    /// and [`FromStrRadixError::InvalidString`] if `s` is not a valid representation of a
+    /// [`Natural`] in that radix.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Natural, FromStrRadixError> {
+        if !(2..=36).contains(&radix) {
+            return Err(FromStrRadixError::InvalidRadix);
+        }
+        Natural::from_string_base(u8::wrapping_from(radix), s)
+            .ok_or(FromStrRadixError::InvalidString)
+    }
+}
+
+impl num_traits::Unsigned for Natural {}
+
+macro_rules! impl_pow {
+    ($t: ident) => {
+        impl num_traits::Pow<$t> for Natural {
+            type Output = Natural;
+
+            /// Raises a [`Natural`] to a power, taking it by value. See [`Pow::pow`].
+            #[inline]
+            fn pow(self, exp: $t) -> Natural {
+                Pow::pow(self, u64::from(exp))
+            }
+        }
+
+        impl<'a> num_traits::Pow<$t> for &'a Natural {
+            type Output = Natural;
+
+            /// Raises a [`Natural`] to a power, taking it by reference. See [`Pow::pow`].
+            #[inline]
+            fn pow(self, exp: $t) -> Natural {
+                Pow::pow(self, u64::from(exp))
+            }
+        }
+    };
+}
+impl_pow!(u8);
+impl_pow!(u16);
+impl_pow!(u32);
+impl_pow!(u64);
+
+impl num_traits::ToPrimitive for Natural {
+    /// Converts a [`Natural`] to an [`i64`], returning `None` if it is too large.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self).ok()
+    }
+
+    /// Converts a [`Natural`] to an [`i128`], returning `None` if it is too large.
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self).ok()
+    }
+
+    /// Converts a [`Natural`] to a [`u64`], returning `None` if it is too large.
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self).ok()
+    }
+
+    /// Converts a [`Natural`] to a [`u128`], returning `None` if it is too large.
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self).ok()
+    }
+
+    /// Converts a [`Natural`] to the nearest [`f32`]. A [`Natural`] greater than the maximum
+    /// finite [`f32`] is converted to the maximum finite [`f32`].
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f32::rounding_from(self, RoundingMode::Nearest).0)
+    }
+
+    /// Converts a [`Natural`] to the nearest [`f64`]. A [`Natural`] greater than the maximum
+    /// finite [`f64`] is converted to the maximum finite [`f64`].
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f64::rounding_from(self, RoundingMode::Nearest).0)
+    }
+}
+
+impl num_traits::FromPrimitive for Natural {
+    /// Converts an [`i64`] to a [`Natural`], returning `None` if it is negative.
+    #[inline]
+    fn from_i64(n: i64) -> Option<Natural> {
+        Natural::try_from(n).ok()
+    }
+
+    /// Converts an [`i128`] to a [`Natural`], returning `None` if it is negative.
+    #[inline]
+    fn from_i128(n: i128) -> Option<Natural> {
+        Natural::try_from(n).ok()
+    }
+
+    /// Converts a [`u64`] to a [`Natural`].
+    #[inline]
+    fn from_u64(n: u64) -> Option<Natural> {
+        Some(Natural::from(n))
+    }
+
+    /// Converts a [`u128`] to a [`Natural`].
+    #[inline]
+    fn from_u128(n: u128) -> Option<Natural> {
+        Some(Natural::from(n))
+    }
+
+    /// Converts an [`f32`] to a [`Natural`], rounding toward zero. Returns `None` if the [`f32`]
+    /// is NaN, infinite, or less than or equal to -1.
+    #[inline]
+    fn from_f32(n: f32) -> Option<Natural> {
+        num_traits::FromPrimitive::from_f64(f64::from(n))
+    }
+
+    /// Converts an [`f64`] to a [`Natural`], rounding toward zero. Returns `None` if the [`f64`]
+    /// is NaN, infinite, or less than or equal to -1.
+    fn from_f64(n: f64) -> Option<Natural> {
+        if n.is_finite() && n > -1.0 {
+            Some(Natural::rounding_from(n, RoundingMode::Down).0)
+        } else {
+            None
+        }
+    }
+}
+
+impl num_traits::CheckedAdd for Natural {
+    /// Adds two [`Natural`]s. The result is never `None`.
+    #[inline]
+    fn checked_add(&self, other: &Natural) -> Option<Natural> {
+        Some(self + other)
+    }
+}
+
+impl num_traits::CheckedSub for Natural {
+    /// Subtracts a [`Natural`] from another [`Natural`], returning `None` if the result would be
+    /// negative. See [`CheckedSub::checked_sub`].
+    #[inline]
+    fn checked_sub(&self, other: &Natural) -> Option<Natural> {
+        CheckedSub::checked_sub(self, other)
+    }
+}
+
+impl num_traits::CheckedMul for Natural {
+    /// Multiplies two [`Natural`]s. The result is never `None`.
+    #[inline]
+    fn checked_mul(&self, other: &Natural) -> Option<Natural> {
+        Some(self * other)
+    }
+}
+
+impl num_traits::CheckedDiv for Natural {
+    /// Divides a [`Natural`] by another [`Natural`], returning `None` if the divisor is zero. The
+    /// quotient is rounded down.
+    #[inline]
+    fn checked_div(&self, other: &Natural) -> Option<Natural> {
+        if *other == 0u32 {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+}
+
+impl num_traits::CheckedRem for Natural {
+    /// Computes the remainder of dividing a [`Natural`] by another [`Natural`], returning `None`
+    /// if the divisor is zero.
+    #[inline]
+    fn checked_rem(&self, other: &Natural) -> Option<Natural> {
+        if *other == 0u32 {
+            None
+        } else {
+            Some(self % other)
+        }
+    }
+}
+
+impl num_integer::Integer for Natural {
+    /// Divides a [`Natural`] by another [`Natural`], rounding the quotient down.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_floor(&self, other: &Natural) -> Natural {
+        self / other
+    }
+
+    /// Computes the remainder of dividing a [`Natural`] by another [`Natural`], with the quotient
+    /// rounded down.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn mod_floor(&self, other: &Natural) -> Natural {
+        self % other
+    }
+
+    /// Divides a [`Natural`] by another [`Natural`], rounding the quotient up.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_ceil(&self, other: &Natural) -> Natural {
+        self.div_round(other, RoundingMode::Ceiling).0
+    }
+
+    /// Computes the GCD (greatest common divisor) of two [`Natural`]s. See [`Gcd::gcd`].
+    #[inline]
+    fn gcd(&self, other: &Natural) -> Natural {
+        Gcd::gcd(self, other)
+    }
+
+    /// Computes the LCM (least common multiple) of two [`Natural`]s. See [`Lcm::lcm`].
+    #[inline]
+    fn lcm(&self, other: &Natural) -> Natural {
+        Lcm::lcm(self, other)
+    }
+
+    /// Determines whether a [`Natural`] is divisible by another [`Natural`].
+    #[inline]
+    fn divides(&self, other: &Natural) -> bool {
+        self.divisible_by(other)
+    }
+
+    /// Determines whether a [`Natural`] is divisible by another [`Natural`]. Zero is a multiple of
+    /// every [`Natural`], including zero. See [`DivisibleBy::divisible_by`].
+    #[inline]
+    fn is_multiple_of(&self, other: &Natural) -> bool {
+        self.divisible_by(other)
+    }
+
+    /// Determines whether a [`Natural`] is even.
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.even()
+    }
+
+    /// Determines whether a [`Natural`] is odd.
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.odd()
+    }
+
+    /// Divides a [`Natural`] by another [`Natural`], returning the quotient and remainder. The
+    /// quotient is rounded down. See [`DivRem::div_rem`].
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    #[inline]
+    fn div_rem(&self, other: &Natural) -> (Natural, Natural) {
+        DivRem::div_rem(self, other)
+    }
+}
+
+impl num_integer::Roots for Natural {
+    /// Returns the floor of the `n`th root of a [`Natural`]. See [`FloorRoot::floor_root`].
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    #[inline]
+    fn nth_root(&self, n: u32) -> Natural {
+        self.floor_root(u64::from(n))
+    }
+
+    /// Returns the floor of the square root of a [`Natural`]. See [`FloorSqrt::floor_sqrt`].
+    #[inline]
+    fn sqrt(&self) -> Natural {
+        self.floor_sqrt()
+    }
+
+    /// Returns the floor of the cube root of a [`Natural`]. See [`FloorRoot::floor_root`].
+    #[inline]
+    fn cbrt(&self) -> Natural {
+        self.floor_root(3)
+    }
+}
@@ -1,10 +1,16 @@
 use crate::natural::{Natural, SerdeNatural};
 use malachite_base::num::conversion::traits::FromStringBase;
+#[cfg(feature = "serde")]
+use serde::de::{Error, SeqAccess, Visitor};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::convert::TryFrom;
+#[cfg(feature = "serde")]
+use std::fmt::{self, Formatter};
 
-impl From<Natural> for SerdeNatural {
+impl<'a> From<&'a Natural> for SerdeNatural {
     #[inline]
-    fn from(x: Natural) -> SerdeNatural {
+    fn from(x: &'a Natural) -> SerdeNatural {
         SerdeNatural(format!("{x:#x}"))
     }
 }
@@ -22,3 +28,83 @@ impl TryFrom<SerdeNatural> for Natural {
         }
     }
 }
+
+// Collects the bytes of a binary-encoded `Natural` or `Integer`, whether the format presents them
+// as a byte string or as a sequence of `u8`s.
+#[cfg(feature = "serde")]
+pub(crate) struct BytesVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a byte array")
+    }
+
+    #[inline]
+    fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(bytes.to_vec())
+    }
+
+    #[inline]
+    fn visit_byte_buf<E: Error>(self, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(bytes)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Natural {
+    /// Serializes a [`Natural`].
+    ///
+    /// Human-readable formats, like JSON, get a hexadecimal string such as `"0x64"`. Binary
+    /// formats get the bytes of the [`Natural`] in ascending order, with no trailing zeros, as a
+    /// length-prefixed byte array. The byte array is the same whether Malachite is compiled with
+    /// 32- or 64-bit limbs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            SerdeNatural::from(self).serialize(serializer)
+        } else {
+            serializer.serialize_bytes(&self.to_bytes_le())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Natural {
+    /// Deserializes a [`Natural`] from the representation produced by its [`Serialize`]
+    /// implementation.
+    ///
+    /// In binary formats, trailing zero bytes are accepted and ignored.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the length of the input.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Natural, D::Error> {
+        if deserializer.is_human_readable() {
+            Natural::try_from(SerdeNatural::deserialize(deserializer)?).map_err(D::Error::custom)
+        } else {
+            Ok(Natural::from_bytes_le(
+                &deserializer.deserialize_bytes(BytesVisitor)?,
+            ))
+        }
+    }
+}
@@ -0,0 +1,372 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CheckedRoot, DivisibleBy, FloorLogBase2, FloorSqrt, Gcd, ModAdd, ModInverse, ModMul,
+    ModMulAssign, ModSquare, ModSub,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{Factor, IsPrime, Primes};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use std::cmp::min;
+
+// Factors less than this limit are found by trial division.
+const TRIAL_DIVISION_LIMIT: u64 = 10000;
+
+const RHO_BATCH_SIZE: u64 = 128;
+
+// Pollard's rho algorithm is only run until this many iterations have been performed, after which
+// ECM takes over.
+const RHO_MAX_ITERATIONS: u64 = 1 << 16;
+
+// Pairs of stage 1 bounds and curve counts used by ECM. The stage 2 bound is always 100 times the
+// stage 1 bound. Once all the levels are exhausted, the last level is repeated with the stage 1
+// bound doubling each time.
+const ECM_LEVELS: [(u64, u64); 6] =
+    [(2000, 25), (11000, 90), (50000, 300), (250000, 700), (1000000, 1800), (3000000, 5100)];
+
+const ECM_STAGE_2_FACTOR: u64 = 100;
+
+// Finds a nontrivial factor of `n` using Brent's variant of Pollard's rho algorithm, iterating
+// x -> x ^ 2 + c. `n` must be odd and composite, and `c` must be less than `n`. Returns `None` if
+// the sequence cycles modulo `n` before a factor is found, or if no factor is found within
+// `RHO_MAX_ITERATIONS` iterations.
+fn pollard_brent_rho(n: &Natural, c: &Natural) -> Option<Natural> {
+    let f = |x: &Natural| x.mod_square(n).mod_add(c, n);
+    let mut x;
+    let mut y = Natural::TWO;
+    let mut ys = y.clone();
+    let mut q = Natural::ONE;
+    let mut g = Natural::ONE;
+    let mut r = 1u64;
+    loop {
+        x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+        let mut k = 0;
+        while k < r && g == 1u32 {
+            ys = y.clone();
+            // Accumulate a batch of differences, so that only one gcd needs to be computed
+            for _ in 0..min(RHO_BATCH_SIZE, r - k) {
+                y = f(&y);
+                q.mod_mul_assign((&x).mod_sub(&y, n), n);
+            }
+            g = (&q).gcd(n);
+            k += RHO_BATCH_SIZE;
+        }
+        if g != 1u32 {
+            break;
+        }
+        r <<= 1;
+        if r > RHO_MAX_ITERATIONS {
+            return None;
+        }
+    }
+    if g == *n {
+        // The batch overshot; step through it one difference at a time
+        loop {
+            ys = f(&ys);
+            g = (&x).mod_sub(&ys, n).gcd(n);
+            if g != 1u32 {
+                break;
+            }
+        }
+    }
+    if g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+// A point on a Montgomery curve By ^ 2 = x ^ 3 + Ax ^ 2 + x, in projective coordinates with the
+// y-coordinate dropped.
+#[derive(Clone)]
+struct MontgomeryPoint {
+    x: Natural,
+    z: Natural,
+}
+
+// A Montgomery curve modulo `n`, represented by (A + 2) / 4.
+struct MontgomeryCurve<'a> {
+    n: &'a Natural,
+    a_24: Natural,
+}
+
+impl<'a> MontgomeryCurve<'a> {
+    // Constructs a curve and a point on it from a parameter `sigma`, using Suyama's
+    // parametrization. If the construction fails because some number is not invertible modulo
+    // `n`, the gcd that caused the failure is returned instead.
+    fn suyama(
+        n: &'a Natural,
+        sigma: u64,
+    ) -> Result<(MontgomeryCurve<'a>, MontgomeryPoint), Natural> {
+        let sigma = Natural::from(sigma) % n;
+        let u = (&sigma).mod_square(n).mod_sub(Natural::from(5u32) % n, n);
+        let v = (&sigma).mod_mul(Natural::from(4u32) % n, n);
+        let u_cubed = (&u).mod_square(n).mod_mul(&u, n);
+        let v_cubed = (&v).mod_square(n).mod_mul(&v, n);
+        let v_minus_u = (&v).mod_sub(&u, n);
+        let numerator = (&v_minus_u)
+            .mod_square(n)
+            .mod_mul(&v_minus_u, n)
+            .mod_mul((&u).mod_add(&u, n).mod_add(&u, n).mod_add(&v, n), n);
+        let denominator = (&u_cubed)
+            .mod_mul(&v, n)
+            .mod_mul(Natural::from(16u32) % n, n);
+        if denominator == 0u32 {
+            return Err(n.clone());
+        }
+        match (&denominator).mod_inverse(n) {
+            Some(inverse) => Ok((
+                MontgomeryCurve {
+                    n,
+                    a_24: numerator.mod_mul(inverse, n),
+                },
+                MontgomeryPoint {
+                    x: u_cubed,
+                    z: v_cubed,
+                },
+            )),
+            None => Err(denominator.gcd(n)),
+        }
+    }
+
+    fn double(&self, p: &MontgomeryPoint) -> MontgomeryPoint {
+        let n = self.n;
+        let sum_squared = (&p.x).mod_add(&p.z, n).mod_square(n);
+        let difference_squared = (&p.x).mod_sub(&p.z, n).mod_square(n);
+        let t = (&sum_squared).mod_sub(&difference_squared, n);
+        MontgomeryPoint {
+            x: sum_squared.mod_mul(&difference_squared, n),
+            z: (&self.a_24)
+                .mod_mul(&t, n)
+                .mod_add(difference_squared, n)
+                .mod_mul(t, n),
+        }
+    }
+
+    // Returns p + q, given p - q.
+    fn add(
+        &self,
+        p: &MontgomeryPoint,
+        q: &MontgomeryPoint,
+        difference: &MontgomeryPoint,
+    ) -> MontgomeryPoint {
+        let n = self.n;
+        let u = (&p.x).mod_sub(&p.z, n).mod_mul((&q.x).mod_add(&q.z, n), n);
+        let v = (&p.x).mod_add(&p.z, n).mod_mul((&q.x).mod_sub(&q.z, n), n);
+        MontgomeryPoint {
+            x: (&difference.z).mod_mul((&u).mod_add(&v, n).mod_square(n), n),
+            z: (&difference.x).mod_mul(u.mod_sub(v, n).mod_square(n), n),
+        }
+    }
+
+    // Computes kp using the Montgomery ladder. `k` must be positive.
+    fn multiply(&self, p: &MontgomeryPoint, k: u64) -> MontgomeryPoint {
+        let mut r_0 = p.clone();
+        let mut r_1 = self.double(p);
+        for i in (0..k.significant_bits() - 1).rev() {
+            if k.get_bit(i) {
+                r_0 = self.add(&r_0, &r_1, p);
+                r_1 = self.double(&r_1);
+            } else {
+                r_1 = self.add(&r_0, &r_1, p);
+                r_0 = self.double(&r_0);
+            }
+        }
+        r_0
+    }
+}
+
+// Runs one curve of Lenstra's elliptic curve method on `n`, with stage 1 bound `b_1` and stage 2
+// bound `b_2`. `n` must be odd, composite, and not a perfect power. Returns a nontrivial factor of
+// `n`, or `None` if the curve fails.
+//
+// Stage 2 is the standard continuation: for each prime q in (b_1, b_2], it checks whether qQ is
+// the identity modulo some factor of `n`, where Q is the output of stage 1. This follows Algorithm
+// 7.4.4 of Crandall and Pomerance, "Prime Numbers: A Computational Perspective", 2nd edition.
+fn ecm_curve(n: &Natural, sigma: u64, b_1: u64, b_2: u64) -> Option<Natural> {
+    let (curve, mut q) = match MontgomeryCurve::suyama(n, sigma) {
+        Ok(curve_and_point) => curve_and_point,
+        Err(g) => return if g == *n { None } else { Some(g) },
+    };
+    // Stage 1: multiply by every prime power less than or equal to b_1
+    for p in u64::primes_less_than_or_equal_to(&b_1) {
+        let mut p_power = p;
+        while p_power <= b_1 / p {
+            p_power *= p;
+        }
+        q = curve.multiply(&q, p_power);
+    }
+    let g = (&q.z).gcd(n);
+    if g == *n {
+        return None;
+    } else if g != 1u32 {
+        return Some(g);
+    }
+    // Stage 2: precompute S_d = 2dQ for 1 <= d <= D
+    let d_max = (b_2.floor_sqrt() >> 1).max(2);
+    let mut s = Vec::with_capacity(usize::exact_from(d_max));
+    s.push(curve.double(&q));
+    s.push(curve.double(&s[0]));
+    for d in 2..usize::exact_from(d_max) {
+        let next = curve.add(&s[d - 1], &s[0], &s[d - 2]);
+        s.push(next);
+    }
+    let betas = s
+        .iter()
+        .map(|p| (&p.x).mod_mul(&p.z, n))
+        .collect::<Vec<_>>();
+    let step = d_max << 1;
+    let mut r = b_1 - (b_1 + 1) % 2;
+    let mut r_point = curve.multiply(&q, r);
+    let mut t_point = curve.multiply(&q, r - step);
+    let r_0 = r;
+    let mut primes = u64::primes_less_than_or_equal_to(&(b_2 + step))
+        .skip_while(|&p| p <= r_0)
+        .peekable();
+    let mut g = Natural::ONE;
+    while r < b_2 {
+        let alpha = (&r_point.x).mod_mul(&r_point.z, n);
+        while let Some(&p) = primes.peek() {
+            if p > r + step {
+                break;
+            }
+            primes.next();
+            let i = usize::exact_from(((p - r) >> 1) - 1);
+            let term = (&r_point.x)
+                .mod_sub(&s[i].x, n)
+                .mod_mul((&r_point.z).mod_add(&s[i].z, n), n)
+                .mod_sub(&alpha, n)
+                .mod_add(&betas[i], n);
+            g.mod_mul_assign(term, n);
+        }
+        let next = curve.add(&r_point, &s[s.len() - 1], &t_point);
+        t_point = r_point;
+        r_point = next;
+        r += step;
+    }
+    let g = g.gcd(n);
+    if g == 1u32 || g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+// Finds a nontrivial factor of `n`, which must be odd, composite, not a perfect power, and have no
+// factors less than `TRIAL_DIVISION_LIMIT`.
+fn find_factor(n: &Natural) -> Natural {
+    if let Some(d) = pollard_brent_rho(n, &Natural::ONE) {
+        return d;
+    }
+    let mut sigma = 6;
+    let mut levels = ECM_LEVELS.iter().copied().chain((1..).map(|i| {
+        (
+            ECM_LEVELS[ECM_LEVELS.len() - 1].0 << i,
+            ECM_LEVELS[ECM_LEVELS.len() - 1].1,
+        )
+    }));
+    loop {
+        let (b_1, curves) = levels.next().unwrap();
+        for _ in 0..curves {
+            if let Some(d) = ecm_curve(n, sigma, b_1, b_1 * ECM_STAGE_2_FACTOR) {
+                return d;
+            }
+            sigma += 1;
+        }
+    }
+}
+
+// Pushes the prime factors of `n`, each with multiplicity `multiplicity`, onto `factors`. `n` must
+// have no factors less than `TRIAL_DIVISION_LIMIT`.
+fn split(n: Natural, multiplicity: u64, factors: &mut Vec<(Natural, u64)>) {
+    if n == 1u32 {
+        return;
+    } else if let Ok(small) = u64::try_from(&n) {
+        factors.extend(
+            small
+                .factor()
+                .into_iter()
+                .map(|(p, e)| (Natural::from(p), e * multiplicity)),
+        );
+        return;
+    } else if n.is_prime() {
+        factors.push((n, multiplicity));
+        return;
+    }
+    // Every prime factor of n is at least `TRIAL_DIVISION_LIMIT`, which is greater than 2 ^ 13, so
+    // if n is a kth power it has more than 13 * k bits
+    let max_exponent = n.significant_bits() / TRIAL_DIVISION_LIMIT.floor_log_base_2();
+    for k in u64::primes_less_than_or_equal_to(&max_exponent) {
+        if let Some(root) = (&n).checked_root(k) {
+            split(root, multiplicity * k, factors);
+            return;
+        }
+    }
+    let d = find_factor(&n);
+    let e = n / &d;
+    split(d, multiplicity, factors);
+    split(e, multiplicity, factors);
+}
+
+impl Factor for Natural {
+    /// Returns the prime factorization of a [`Natural`], as a list of pairs of primes and
+    /// exponents, sorted by prime.
+    ///
+    /// Factors less than 10000 are found by trial division. Perfect powers are detected and their
+    /// roots factored. Any remaining composite cofactor is split using Brent's variant of Pollard's
+    /// rho algorithm, and if that fails to find a factor quickly, Lenstra's elliptic curve method
+    /// with a stage 2 continuation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(\exp((\sqrt{2} + o(1)) \sqrt{\log p \log\log p}) n^2)$
+    ///
+    /// $M(n) = O(n \sqrt{\log p})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $p$ is
+    /// the second-largest prime factor of `self`. The time complexity is heuristic.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    fn factor(&self) -> Vec<(Natural, u64)> {
+        assert_ne!(*self, 0u32, "Cannot factor 0");
+        let mut n = self.clone();
+        let mut factors = Vec::new();
+        for p in u64::primes_less_than(&TRIAL_DIVISION_LIMIT) {
+            if let Ok(small) = u64::try_from(&n) {
+                // All remaining factors are at least p
+                factors.extend(
+                    small
+                        .factor()
+                        .into_iter()
+                        .map(|(p, e)| (Natural::from(p), e)),
+                );
+                return factors;
+            }
+            let p = Natural::from(p);
+            let mut exponent = 0;
+            while (&n).divisible_by(&p) {
+                n /= &p;
+                exponent += 1;
+            }
+            if exponent != 0 {
+                factors.push((p, exponent));
+            }
+        }
+        let mut large_factors = Vec::new();
+        split(n, 1, &mut large_factors);
+        large_factors.sort_unstable();
+        for (p, e) in large_factors {
+            match factors.last_mut() {
+                Some((q, exponent)) if *q == p => *exponent += e,
+                _ => factors.push((p, e)),
+            }
+        }
+        factors
+    }
+}
@@ -0,0 +1,187 @@
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    CheckedSqrt, DivisibleBy, JacobiSymbol, ModAdd, ModMul, ModMulAssign, ModPow, ModSquare,
+    ModSquareAssign, ModSub, Parity,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// Numbers that are too large to fit in a `u64` are first checked for divisibility by the odd primes
+// less than this limit.
+const TRIAL_DIVISION_LIMIT: u64 = 1000;
+
+// Returns (x / 2) mod n, where x < n and n is odd.
+fn mod_half(x: Natural, n: &Natural) -> Natural {
+    if x.even() {
+        x >> 1
+    } else {
+        (x + n) >> 1
+    }
+}
+
+// Determines whether `n` is a strong probable prime to base `base`, where `n` is odd and greater
+// than `base`.
+fn is_strong_probable_prime(n: &Natural, base: Natural) -> bool {
+    let n_minus_1 = n - Natural::ONE;
+    let s = n_minus_1.trailing_zeros().unwrap();
+    let mut x = base.mod_pow(&n_minus_1 >> s, n);
+    if x == 1u32 || x == n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x.mod_square_assign(n);
+        if x == n_minus_1 {
+            return true;
+        } else if x == 1u32 {
+            return false;
+        }
+    }
+    false
+}
+
+// Determines whether `n` is a strong Lucas probable prime, with parameters chosen using Selfridge's
+// method A. `n` must be odd, not a perfect square, and greater than `TRIAL_DIVISION_LIMIT`.
+fn is_strong_lucas_probable_prime(n: &Natural) -> bool {
+    // Find the first D in 5, -7, 9, -11, ... with Jacobi symbol (D / n) = -1.
+    let mut d_abs = 5u64;
+    let mut d_negative = false;
+    let d = loop {
+        let d = Natural::from(d_abs);
+        let d = if d_negative { n - d } else { d };
+        match (&d).jacobi_symbol(n) {
+            -1 => break d,
+            // n shares a factor with D but is larger than D, so it is composite
+            0 => return false,
+            _ => {}
+        }
+        d_abs += 2;
+        d_negative = !d_negative;
+    };
+    // P = 1 and Q = (1 - D) / 4
+    let q = if d_negative {
+        Natural::from((d_abs + 1) >> 2)
+    } else {
+        n - Natural::from((d_abs - 1) >> 2)
+    };
+    // n + 1 = k * 2 ^ s, with k odd
+    let n_plus_1 = n + Natural::ONE;
+    let s = n_plus_1.trailing_zeros().unwrap();
+    let k = n_plus_1 >> s;
+    // Compute U_k, V_k, and Q ^ k, starting from U_1 = 1, V_1 = P = 1.
+    let mut u = Natural::ONE;
+    let mut v = Natural::ONE;
+    let mut q_k = q.clone();
+    for i in (0..k.significant_bits() - 1).rev() {
+        u.mod_mul_assign(&v, n);
+        v = v.mod_square(n).mod_sub((&q_k).mod_add(&q_k, n), n);
+        q_k.mod_square_assign(n);
+        if k.get_bit(i) {
+            let new_u = mod_half((&u).mod_add(&v, n), n);
+            v = mod_half((&d).mod_mul(u, n).mod_add(v, n), n);
+            u = new_u;
+            q_k.mod_mul_assign(&q, n);
+        }
+    }
+    if u == 0u32 || v == 0u32 {
+        return true;
+    }
+    for _ in 1..s {
+        v = v.mod_square(n).mod_sub((&q_k).mod_add(&q_k, n), n);
+        if v == 0u32 {
+            return true;
+        }
+        q_k.mod_square_assign(n);
+    }
+    false
+}
+
+// Determines whether `n` is divisible by an odd prime less than `TRIAL_DIVISION_LIMIT`. The primes
+// are grouped so that each group's product fits in a `Limb`, and `n` is reduced once per group.
+fn has_small_odd_factor(n: &Natural) -> bool {
+    let group_divides = |product: Limb, primes: &[Limb]| {
+        let r = n.rem_limb_ref(product);
+        primes.iter().any(|&p| r.divisible_by(p))
+    };
+    let mut product: Limb = 1;
+    let mut primes = Vec::new();
+    for p in Limb::primes_less_than(&Limb::wrapping_from(TRIAL_DIVISION_LIMIT)).skip(1) {
+        if let Some(new_product) = product.checked_mul(p) {
+            product = new_product;
+        } else {
+            if group_divides(product, &primes) {
+                return true;
+            }
+            product = p;
+            primes.clear();
+        }
+        primes.push(p);
+    }
+    group_divides(product, &primes)
+}
+
+// Applies the Baillie-PSW test to `n`, which must be odd and greater than `TRIAL_DIVISION_LIMIT`.
+pub(crate) fn is_baillie_psw_probable_prime(n: &Natural) -> bool {
+    is_strong_probable_prime(n, Natural::TWO)
+        && n.checked_sqrt().is_none()
+        && is_strong_lucas_probable_prime(n)
+}
+
+impl IsPrime for Natural {
+    /// Determines whether a [`Natural`] is prime.
+    ///
+    /// Small factors are removed by trial division, and then the Baillie-PSW test is applied: a
+    /// strong Fermat test to base 2, followed by a strong Lucas test. This is known to be correct
+    /// for all numbers less than $2^{64}$. For larger numbers it is probabilistic, although no
+    /// counterexample is known.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    fn is_prime(&self) -> bool {
+        if let Ok(x) = u64::try_from(self) {
+            return x.is_prime();
+        } else if self.even() {
+            return false;
+        }
+        !has_small_odd_factor(self) && is_baillie_psw_probable_prime(self)
+    }
+
+    /// Determines whether a [`Natural`] is probably prime, running `reps` additional strong Fermat
+    /// tests after the Baillie-PSW test.
+    ///
+    /// The bases are fixed rather than random, as they are in GMP: the additional tests use the odd
+    /// primes $3, 5, 7, \ldots$, so the result is reproducible, but a composite number that passes
+    /// them could be constructed deliberately. Since the Baillie-PSW test is known to be correct
+    /// for all numbers less than $2^{64}$, the additional tests are only run for larger numbers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `reps`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_probable_prime).
+    fn is_probable_prime(&self, reps: u64) -> bool {
+        if !self.is_prime() {
+            return false;
+        } else if *self <= u64::MAX {
+            return true;
+        }
+        u64::primes()
+            .skip(1)
+            .take(usize::wrapping_from(reps))
+            .all(|p| is_strong_probable_prime(self, Natural::from(p)))
+    }
+}
@@ -1,3 +1,83 @@
+/// An implementation of [`Factor`](malachite_base::num::factorization::traits::Factor), a trait
+/// for computing the prime factorization of a number.
+///
+/// # factor
+/// ```
+/// use malachite_base::num::arithmetic::traits::{Factorial, Pow};
+/// use malachite_base::num::factorization::traits::Factor;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).factor().to_debug_string(), "[]");
+/// assert_eq!(Natural::from(360u32).factor().to_debug_string(), "[(2, 3), (3, 2), (5, 1)]");
+/// assert_eq!(
+///     Natural::factorial(30).factor().to_debug_string(),
+///     "[(2, 26), (3, 14), (5, 7), (7, 4), (11, 2), (13, 2), (17, 1), (19, 1), (23, 1), (29, 1)]"
+/// );
+/// assert_eq!(
+///     (Natural::from(2u32).pow(128) + Natural::from(1u32)).factor().to_debug_string(),
+///     "[(59649589127497217, 1), (5704689200685129054721, 1)]"
+/// );
+/// ```
+pub mod factor;
+/// An implementation of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime), a trait
+/// for testing whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::IsPrime;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(0u32).is_prime(), false);
+/// assert_eq!(Natural::from(1u32).is_prime(), false);
+/// assert_eq!(Natural::from(2u32).is_prime(), true);
+/// assert_eq!(Natural::from(97u32).is_prime(), true);
+/// assert_eq!(Natural::from(3825123056546413051u64).is_prime(), false);
+/// assert_eq!((Natural::from(2u32).pow(127) - Natural::from(1u32)).is_prime(), true);
+/// assert_eq!((Natural::from(2u32).pow(128) + Natural::from(1u32)).is_prime(), false);
+/// ```
+///
+/// # is_probable_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::IsPrime;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(10u32).is_probable_prime(5), false);
+/// assert_eq!(Natural::from(65521u32).is_probable_prime(5), true);
+/// assert_eq!((Natural::from(2u32).pow(521) - Natural::from(1u32)).is_probable_prime(10), true);
+/// assert_eq!((Natural::from(2u32).pow(523) - Natural::from(1u32)).is_probable_prime(10), false);
+/// ```
+pub mod is_prime;
+/// Implementations of [`NextPrime`](malachite_base::num::factorization::traits::NextPrime) and
+/// [`PrevPrime`](malachite_base::num::factorization::traits::PrevPrime), traits for finding the
+/// primes adjacent to a number.
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::NextPrime;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(0u32).next_prime(), 2);
+/// assert_eq!(Natural::from(100u32).next_prime(), 101);
+/// assert_eq!(Natural::from(u64::MAX).next_prime().to_string(), "18446744073709551629");
+/// assert_eq!(Natural::from(10u32).pow(30).next_prime().to_string(), "1000000000000000000000000000057");
+/// ```
+///
+/// # prev_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::PrevPrime;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(3u32).prev_prime(), 2);
+/// assert_eq!(Natural::from(100u32).prev_prime(), 97);
+/// assert_eq!(Natural::from(2u32).pow(64).prev_prime(), 18446744073709551557u64);
+/// assert_eq!(Natural::from(10u32).pow(30).prev_prime().to_string(), "999999999999999999999999999989");
+/// ```
+pub mod next_prime;
 /// An implementation of [`Primes`](malachite_base::num::factorization::traits::Primes), a trait
 /// for generating prime numbers.
 ///
@@ -0,0 +1,127 @@
+use crate::natural::factorization::is_prime::is_baillie_psw_probable_prime;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+use malachite_base::num::factorization::traits::{NextPrime, PrevPrime, Primes};
+use malachite_base::num::logic::traits::SignificantBits;
+use std::cmp::max;
+
+// The largest prime that fits in a `u64`. Numbers below this are handled by the `u64`
+// implementations.
+const LARGEST_U64_PRIME: u64 = 18446744073709551557;
+
+// Candidates are sieved by the odd primes less than this limit before being tested for primality.
+pub(crate) const SIEVE_LIMIT: u64 = 1 << 14;
+
+// Marks the composite numbers among `start`, `start + 2`, ..., `start + 2 * (len - 1)`, where
+// `start` is odd and greater than `SIEVE_LIMIT`. `composite[i]` is set if `start + 2 * i` is
+// divisible by one of `sieve_primes`.
+fn sieve_window(start: &Natural, sieve_primes: &[Limb], composite: &mut [bool]) {
+    composite.fill(false);
+    let len = composite.len();
+    for &p in sieve_primes {
+        // Find the smallest i with start + 2 * i = 0 mod p, using 1 / 2 = (p + 1) / 2 mod p. Since
+        // p < 2 ^ 14, the product can't overflow.
+        let r = start.rem_limb_ref(p);
+        let i = usize::exact_from((p - r) % p * ((p + 1) >> 1) % p);
+        let p = usize::exact_from(p);
+        for j in (i..len).step_by(p) {
+            composite[j] = true;
+        }
+    }
+}
+
+pub(crate) fn sieve_primes() -> Vec<Limb> {
+    Limb::primes_less_than(&Limb::wrapping_from(SIEVE_LIMIT))
+        .skip(1)
+        .collect()
+}
+
+// The number of odd candidates sieved at a time. This is roughly twice the expected gap between
+// primes near `n`.
+fn window_len(n: &Natural) -> usize {
+    usize::exact_from(max(n.significant_bits(), 64) << 1)
+}
+
+impl NextPrime for Natural {
+    /// Finds the smallest prime greater than a [`Natural`].
+    ///
+    /// Windows of odd numbers greater than `self` are sieved by small primes, and the survivors
+    /// are tested in order using the Baillie-PSW test. The result is known to be correct if it is
+    /// less than $2^{64}$; for larger results, no counterexample is known.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::next_prime#next_prime).
+    fn next_prime(&self) -> Natural {
+        if *self < LARGEST_U64_PRIME {
+            return Natural::from(u64::exact_from(self).next_prime());
+        }
+        let sieve_primes = sieve_primes();
+        let mut composite = vec![false; window_len(self)];
+        // Start at the smallest odd number greater than `self`
+        let mut start = (self + Natural::ONE) | Natural::ONE;
+        loop {
+            sieve_window(&start, &sieve_primes, &mut composite);
+            let mut candidate = start.clone();
+            for &c in &composite {
+                if !c && is_baillie_psw_probable_prime(&candidate) {
+                    return candidate;
+                }
+                candidate += Natural::TWO;
+            }
+            start = candidate;
+        }
+    }
+}
+
+impl PrevPrime for Natural {
+    /// Finds the largest prime less than a [`Natural`].
+    ///
+    /// Windows of odd numbers less than `self` are sieved by small primes, and the survivors are
+    /// tested in order using the Baillie-PSW test. The result is known to be correct if it is
+    /// less than $2^{64}$; for larger results, no counterexample is known.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is less than or equal to 2.
+    ///
+    /// # Examples
+    /// See [here](super::next_prime#prev_prime).
+    fn prev_prime(&self) -> Natural {
+        if let Ok(n) = u64::try_from(self) {
+            return Natural::from(n.prev_prime());
+        }
+        let sieve_primes = sieve_primes();
+        let len = window_len(self);
+        let mut composite = vec![false; len];
+        let window_width = Natural::from(len - 1) << 1;
+        // Start at the largest odd number less than `self`
+        let mut end = (self - Natural::TWO) | Natural::ONE;
+        loop {
+            let start = &end - &window_width;
+            sieve_window(&start, &sieve_primes, &mut composite);
+            let mut candidate = end;
+            for &c in composite.iter().rev() {
+                if !c && is_baillie_psw_probable_prime(&candidate) {
+                    return candidate;
+                }
+                candidate -= Natural::TWO;
+            }
+            end = candidate;
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::platform::Limb;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+
+// The number of limbs that a `LimbVec` can hold without allocating; that is, 256 bits' worth.
+pub(crate) const INLINE_LIMB_COUNT: usize = (256 >> Limb::LOG_WIDTH) as usize;
+
+// The limbs of a `Large` `Natural`, in ascending order.
+//
+// Up to `INLINE_LIMB_COUNT` limbs are stored inline, so that `Natural`s of up to 256 bits don't
+// need to allocate. Longer sequences are stored in a `Vec`. A short sequence may also be stored in
+// a `Vec`, for example after an operation that needed a growable buffer, so the representation is
+// not unique; equality and hashing only depend on the limbs themselves. The length of an inline
+// sequence is stored in a `u8`, which keeps a `Natural` at 40 bytes on 64-bit platforms.
+pub(crate) enum LimbVec {
+    Inline(u8, [Limb; INLINE_LIMB_COUNT]),
+    Heap(Vec<Limb>),
+}
+
+impl LimbVec {
+    // Copies a slice of limbs into a `LimbVec`, storing it inline if it is short enough.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    pub(crate) fn from_slice(xs: &[Limb]) -> LimbVec {
+        let len = xs.len();
+        if len <= INLINE_LIMB_COUNT {
+            let mut inline = [0; INLINE_LIMB_COUNT];
+            inline[..len].copy_from_slice(xs);
+            LimbVec::Inline(u8::wrapping_from(len), inline)
+        } else {
+            LimbVec::Heap(xs.to_vec())
+        }
+    }
+
+    // Creates a `LimbVec` containing `len` zero limbs, storing them inline if there are few enough.
+    // This is for use as the output of `_to_out` functions.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `len`.
+    pub(crate) fn zeroed(len: usize) -> LimbVec {
+        if len <= INLINE_LIMB_COUNT {
+            LimbVec::Inline(u8::wrapping_from(len), [0; INLINE_LIMB_COUNT])
+        } else {
+            LimbVec::Heap(vec![0; len])
+        }
+    }
+
+    // Moves inline limbs into a `Vec`, if necessary, and returns a reference to it. This is for
+    // algorithms that need to grow their output.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `self.len()`.
+    pub(crate) fn as_mut_vec(&mut self) -> &mut Vec<Limb> {
+        if let LimbVec::Inline(len, xs) = self {
+            let mut vec = Vec::with_capacity(INLINE_LIMB_COUNT << 1);
+            vec.extend_from_slice(&xs[..usize::from(*len)]);
+            *self = LimbVec::Heap(vec);
+        }
+        if let LimbVec::Heap(xs) = self {
+            xs
+        } else {
+            unreachable!()
+        }
+    }
+
+    // Converts a `LimbVec` into a `Vec`.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `self.len()`.
+    pub(crate) fn into_vec(self) -> Vec<Limb> {
+        match self {
+            LimbVec::Inline(len, xs) => xs[..usize::from(len)].to_vec(),
+            LimbVec::Heap(xs) => xs,
+        }
+    }
+
+    // Appends a limb to a `LimbVec`, moving the limbs into a `Vec` if there is no room for it
+    // inline.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `self.len()`.
+    pub(crate) fn push(&mut self, x: Limb) {
+        match self {
+            LimbVec::Inline(len, xs) if usize::from(*len) < INLINE_LIMB_COUNT => {
+                xs[usize::from(*len)] = x;
+                *len += 1;
+            }
+            _ => self.as_mut_vec().push(x),
+        }
+    }
+
+    // If the limbs are stored in a `Vec` but are short enough to be stored inline, moves them
+    // inline and frees the `Vec`.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(1)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `self.len()`.
+    pub(crate) fn shrink_to_inline(&mut self) {
+        if let LimbVec::Heap(xs) = self {
+            if xs.len() <= INLINE_LIMB_COUNT {
+                *self = LimbVec::from_slice(xs);
+            }
+        }
+    }
+
+    // Shortens a `LimbVec` to `len` limbs. If `len` is greater than the current length, this has
+    // no effect.
+    //
+    // # Worst-case complexity
+    // Constant time and additional memory.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        match self {
+            LimbVec::Inline(old_len, _) => {
+                if len < usize::from(*old_len) {
+                    *old_len = u8::wrapping_from(len);
+                }
+            }
+            LimbVec::Heap(xs) => xs.truncate(len),
+        }
+    }
+}
+
+impl From<Vec<Limb>> for LimbVec {
+    // Converts a `Vec` of limbs to a `LimbVec`. If the `Vec` is short enough, its limbs are moved
+    // inline and it is freed; otherwise it is reused.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(1)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    fn from(xs: Vec<Limb>) -> LimbVec {
+        if xs.len() <= INLINE_LIMB_COUNT {
+            LimbVec::from_slice(&xs)
+        } else {
+            LimbVec::Heap(xs)
+        }
+    }
+}
+
+impl Extend<Limb> for LimbVec {
+    fn extend<I: IntoIterator<Item = Limb>>(&mut self, xs: I) {
+        for x in xs {
+            self.push(x);
+        }
+    }
+}
+
+impl Default for LimbVec {
+    fn default() -> LimbVec {
+        LimbVec::Inline(0, [0; INLINE_LIMB_COUNT])
+    }
+}
+
+impl Clone for LimbVec {
+    // Clones a `LimbVec`. The clone is stored inline whenever it is short enough, even if the
+    // original isn't.
+    fn clone(&self) -> LimbVec {
+        LimbVec::from_slice(self)
+    }
+}
+
+impl Deref for LimbVec {
+    type Target = [Limb];
+
+    fn deref(&self) -> &[Limb] {
+        match self {
+            LimbVec::Inline(len, xs) => &xs[..usize::from(*len)],
+            LimbVec::Heap(xs) => xs,
+        }
+    }
+}
+
+impl DerefMut for LimbVec {
+    fn deref_mut(&mut self) -> &mut [Limb] {
+        match self {
+            LimbVec::Inline(len, xs) => &mut xs[..usize::from(*len)],
+            LimbVec::Heap(xs) => xs,
+        }
+    }
+}
+
+impl PartialEq for LimbVec {
+    fn eq(&self, other: &LimbVec) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for LimbVec {}
+
+impl Hash for LimbVec {
+    // Hashes the limbs the same way that a `Vec` of them would be hashed, regardless of how they
+    // are stored.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
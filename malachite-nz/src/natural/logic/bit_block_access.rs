@@ -260,7 +260,7 @@ impl BitBlockAccess for Natural {
         match self {
             Natural(Small(small)) => Natural(Small(small.get_bits(start, end))),
             Natural(Large(limbs)) => {
-                Natural::from_owned_limbs_asc(limbs_vec_get_bits(limbs, start, end))
+                Natural::from_owned_limbs_asc(limbs_vec_get_bits(limbs.into_vec(), start, end))
             }
         }
     }
@@ -341,8 +341,12 @@ impl BitBlockAccess for Natural {
         }
         let limbs = self.promote_in_place();
         match *bits {
-            Natural(Small(small_bits)) => limbs_assign_bits(limbs, start, end, &[small_bits]),
-            Natural(Large(ref bits_limbs)) => limbs_assign_bits(limbs, start, end, bits_limbs),
+            Natural(Small(small_bits)) => {
+                limbs_assign_bits(limbs.as_mut_vec(), start, end, &[small_bits])
+            }
+            Natural(Large(ref bits_limbs)) => {
+                limbs_assign_bits(limbs.as_mut_vec(), start, end, bits_limbs)
+            }
         }
         self.trim();
     }
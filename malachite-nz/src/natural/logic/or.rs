@@ -233,7 +233,7 @@ impl Natural {
     fn or_limb_ref(&self, other: Limb) -> Natural {
         Natural(match *self {
             Natural(Small(small)) => Small(small | other),
-            Natural(Large(ref limbs)) => Large(limbs_or_limb(limbs, other)),
+            Natural(Large(ref limbs)) => Large(limbs_or_limb(limbs, other).into()),
         })
     }
 
@@ -386,7 +386,9 @@ impl<'a, 'b> BitOr<&'a Natural> for &'b Natural {
         match (self, other) {
             (x, &Natural(Small(y))) => x.or_limb_ref(y),
             (&Natural(Small(x)), y) => y.or_limb_ref(x),
-            (&Natural(Large(ref xs)), &Natural(Large(ref ys))) => Natural(Large(limbs_or(xs, ys))),
+            (&Natural(Large(ref xs)), &Natural(Large(ref ys))) => {
+                Natural(Large(limbs_or(xs, ys).into()))
+            }
         }
     }
 }
@@ -456,7 +458,7 @@ impl<'a> BitOrAssign<&'a Natural> for Natural {
             (_, Natural(Small(y))) => self.or_assign_limb(*y),
             (Natural(Small(ref mut x)), _) => *self = other.or_limb_ref(*x),
             (Natural(Large(ref mut xs)), Natural(Large(ref ys))) => {
-                limbs_or_in_place_left(xs, ys);
+                limbs_or_in_place_left(xs.as_mut_vec(), ys);
             }
         }
     }
@@ -1,3 +1,4 @@
+use crate::natural::limb_vec::LimbVec;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::platform::Limb;
 #[cfg(feature = "doc-images")]
@@ -11,22 +12,21 @@ use malachite_base::slices::slice_trailing_zeros;
 
 /// A natural (non-negative) integer.
 ///
-/// Any `Natural` small enough to fit into a [`Limb`](crate#limbs) is represented inline. Only
-/// `Natural`s outside this range incur the costs of heap-allocation. Here's a diagram of a slice
-/// of `Natural`s (using 32-bit limbs) containing the first 8 values of
+/// Any `Natural` small enough to fit into a [`Limb`](crate#limbs) is represented inline, and the
+/// limbs of any `Natural` less than $2^{256}$ are also stored inline. Only `Natural`s outside this
+/// range incur the costs of heap-allocation. Here's a diagram of a slice of `Natural`s (using
+/// 64-bit limbs) containing the first 10 values of
 /// [Sylvester's sequence](https://oeis.org/A000058):
 ///
 /// ![Natural memory layout][natural-mem-layout]
+///
+/// Making room for the inline limbs means that a `Natural` takes up 40 bytes on 64-bit platforms.
+/// Up to version 0.4.4, when every multi-limb `Natural` was stored on the heap, it took up 24.
 #[cfg_attr(
     feature = "doc-images",
     embed_doc_image("natural-mem-layout", "images/natural-mem-layout.svg")
 )]
 #[derive(Clone, Eq, Hash, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
-#[cfg_attr(
-    feature = "serde",
-    serde(try_from = "SerdeNatural", into = "SerdeNatural")
-)]
 pub struct Natural(pub(crate) InnerNatural);
 
 // We want to limit the visibility of the `Small` and `Large` constructors to within this crate. To
@@ -34,7 +34,7 @@ pub struct Natural(pub(crate) InnerNatural);
 #[derive(Clone, Eq, Hash, PartialEq)]
 pub(crate) enum InnerNatural {
     Small(Limb),
-    Large(Vec<Limb>),
+    Large(LimbVec),
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -42,21 +42,24 @@ pub(crate) enum InnerNatural {
 pub(crate) struct SerdeNatural(String);
 
 impl Natural {
-    // If a `Natural` is `Large` but is small enough to be `Small`, make it `Small`.
+    // If a `Natural` is `Large` but is small enough to be `Small`, make it `Small`. If it is
+    // `Large` and its limbs are stored in a `Vec`, but they are few enough to be stored inline,
+    // move them inline.
     fn demote_if_small(&mut self) {
-        if let Natural(Large(ref limbs)) = self {
+        if let Natural(Large(ref mut limbs)) = self {
             match limbs.len() {
                 0 => *self = Natural::ZERO,
                 1 => *self = Natural(Small(limbs[0])),
-                _ => {}
+                _ => limbs.shrink_to_inline(),
             }
         }
     }
 
-    // If a `Natural` is `Small`, make it `Large`. Return a reference to the `Limb` vector.
-    pub(crate) fn promote_in_place(&mut self) -> &mut Vec<Limb> {
+    // If a `Natural` is `Small`, make it `Large`, storing its limb inline. Return a reference to
+    // the limbs.
+    pub(crate) fn promote_in_place(&mut self) -> &mut LimbVec {
         if let Natural(Small(x)) = self {
-            *self = Natural(Large(vec![*x]));
+            *self = Natural(Large(LimbVec::from_slice(&[*x])));
         }
         if let Natural(Large(ref mut xs)) = self {
             xs
@@ -65,6 +68,13 @@ impl Natural {
         }
     }
 
+    // Converts a `LimbVec` to a `Natural`, removing any leading zero limbs.
+    pub(crate) fn from_limb_vec(xs: LimbVec) -> Natural {
+        let mut x = Natural(Large(xs));
+        x.trim();
+        x
+    }
+
     pub(crate) fn trim(&mut self) {
         if let Natural(Large(ref mut limbs)) = *self {
             let trailing_zero_count = slice_trailing_zeros(limbs);
@@ -135,6 +145,7 @@ pub mod conversion;
 pub mod exhaustive;
 /// Traits for generating primes, primality testing, and factorization (TODO!)
 pub mod factorization;
+pub(crate) mod limb_vec;
 /// Traits for logic and bit manipulation.
 pub mod logic;
 #[cfg(feature = "random")]
@@ -1,6 +1,8 @@
 use crate::natural::arithmetic::mod_power_of_2::limbs_slice_mod_power_of_2_in_place;
+use crate::natural::factorization::next_prime::{sieve_primes, SIEVE_LIMIT};
 use crate::natural::logic::bit_access::limbs_slice_set_bit;
 use crate::natural::Natural;
+use crate::platform::Limb;
 use itertools::Itertools;
 use malachite_base::num::arithmetic::traits::{
     CeilingLogBase2, PowerOf2, RoundToMultipleOfPowerOf2, ShrRound,
@@ -8,6 +10,7 @@ use malachite_base::num::arithmetic::traits::{
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::IsPrime;
 #[cfg(feature = "32_bit_limbs")]
 use malachite_base::num::iterators::iterator_to_bit_chunks;
 use malachite_base::num::logic::traits::{BitAccess, LowMask, SignificantBits};
@@ -1295,3 +1298,189 @@ pub fn striped_random_natural_range_to_infinity(
         ),
     }
 }
+
+// Determines whether `x` is divisible by one of the sieve primes or, if `safe` is true, whether
+// `(x - 1) / 2` is. The primes are grouped so that each group's product fits in a `Limb`, and `x`
+// is reduced once per group. `x` must be odd, and `(x - 1) / 2` must be greater than every sieve
+// prime.
+fn sieved_out(x: &Natural, sieve_groups: &[(Limb, Vec<Limb>)], safe: bool) -> bool {
+    sieve_groups.iter().any(|(product, primes)| {
+        let r = x.rem_limb_ref(*product);
+        primes.iter().any(|&p| {
+            let r = r % p;
+            // (x - 1) / 2 is divisible by p exactly when x = 1 mod p
+            r == 0 || safe && r == 1
+        })
+    })
+}
+
+// Groups the odd primes used to sieve random candidates with a given bit length, pairing each
+// group with its product. Candidates that are too small to be sieved safely are not sieved at all.
+fn sieve_groups_for_bits(bits: u64) -> Vec<(Limb, Vec<Limb>)> {
+    let mut groups = Vec::new();
+    if bits <= SIEVE_LIMIT.significant_bits() {
+        return groups;
+    }
+    let mut product: Limb = 1;
+    let mut primes = Vec::new();
+    for p in sieve_primes() {
+        if let Some(new_product) = product.checked_mul(p) {
+            product = new_product;
+        } else {
+            groups.push((product, primes));
+            product = p;
+            primes = Vec::new();
+        }
+        primes.push(p);
+    }
+    groups.push((product, primes));
+    groups
+}
+
+/// Uniformly generates random prime [`Natural`]s with a given bit length.
+#[derive(Clone, Debug)]
+pub struct RandomPrimesWithBits {
+    bits: u64,
+    limbs: RandomPrimitiveInts<u64>,
+    sieve_groups: Vec<(Limb, Vec<Limb>)>,
+}
+
+impl Iterator for RandomPrimesWithBits {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        loop {
+            let mut x = get_random_natural_with_bits(&mut self.limbs, self.bits);
+            if self.bits > 2 {
+                // All primes with more than 2 bits are odd
+                x.set_bit(0);
+            }
+            if !sieved_out(&x, &self.sieve_groups, false) && x.is_prime() {
+                return Some(x);
+            }
+        }
+    }
+}
+
+/// Uniformly generates random prime [`Natural`]s with a given bit length.
+///
+/// Odd [`Natural`]s with the given bit length are generated until one is found to be prime, so
+/// every prime with bit length $b$ is equally likely to be generated. Candidates with a small prime
+/// factor are rejected by trial division before the Baillie-PSW test is run.
+///
+/// $$
+/// P(p) = \\begin{cases}
+///     \frac{1}{\pi(2^b) - \pi(2^{b-1})} & \text{if} \\quad 2^{b-1} \leq p < 2^b
+///         \\ \text{and} \\ p \\ \text{is prime}, \\\\
+///     0 & \\text{otherwise}.
+/// \\end{cases}
+/// $$
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^3 \log n \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+///
+/// # Panics
+/// Panics if `bits` is less than 2.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::random_primes_with_bits;
+///
+/// assert_eq!(
+///     prefix_to_string(random_primes_with_bits(EXAMPLE_SEED, 10), 10),
+///     "[881, 599, 727, 641, 647, 797, 839, 647, 599, 593, ...]"
+/// );
+/// assert_eq!(
+///     prefix_to_string(random_primes_with_bits(EXAMPLE_SEED, 100), 3),
+///     "[834112884250571316017711689193, 1096064561690550858427390195543, \
+///     1247194096045807604465004471959, ...]"
+/// );
+/// ```
+pub fn random_primes_with_bits(seed: Seed, bits: u64) -> RandomPrimesWithBits {
+    assert!(bits >= 2, "There are no primes with fewer than 2 bits");
+    RandomPrimesWithBits {
+        bits,
+        limbs: random_primitive_ints(seed),
+        sieve_groups: sieve_groups_for_bits(bits),
+    }
+}
+
+/// Uniformly generates random safe prime [`Natural`]s with a given bit length.
+#[derive(Clone, Debug)]
+pub struct RandomSafePrimesWithBits {
+    bits: u64,
+    limbs: RandomPrimitiveInts<u64>,
+    sieve_groups: Vec<(Limb, Vec<Limb>)>,
+}
+
+impl Iterator for RandomSafePrimesWithBits {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        loop {
+            let mut x = get_random_natural_with_bits(&mut self.limbs, self.bits);
+            if self.bits > 3 {
+                // All safe primes greater than 7 are 3 mod 4
+                x.set_bit(0);
+                x.set_bit(1);
+            }
+            if !sieved_out(&x, &self.sieve_groups, true) && (&x >> 1u32).is_prime() && x.is_prime()
+            {
+                return Some(x);
+            }
+        }
+    }
+}
+
+/// Uniformly generates random safe prime [`Natural`]s with a given bit length.
+///
+/// A safe prime is a prime $p$ such that $(p - 1) / 2$ is also prime. [`Natural`]s with the given
+/// bit length are generated until a safe prime is found, so every safe prime with bit length $b$
+/// is equally likely to be generated. Candidates for which $p$ or $(p - 1) / 2$ has a small prime
+/// factor are rejected by trial division, so the Baillie-PSW tests only run on a small fraction of
+/// them.
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^4 \log n \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+///
+/// # Panics
+/// Panics if `bits` is less than 3.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::random_safe_primes_with_bits;
+///
+/// assert_eq!(
+///     prefix_to_string(random_safe_primes_with_bits(EXAMPLE_SEED, 10), 10),
+///     "[863, 839, 563, 563, 839, 1019, 887, 863, 1019, 839, ...]"
+/// );
+/// assert_eq!(
+///     prefix_to_string(random_safe_primes_with_bits(EXAMPLE_SEED, 100), 3),
+///     "[781785490480340982599183009627, 1143417078396099228848584894439, \
+///     1239807572827793062962537484139, ...]"
+/// );
+/// ```
+pub fn random_safe_primes_with_bits(seed: Seed, bits: u64) -> RandomSafePrimesWithBits {
+    assert!(bits >= 3, "There are no safe primes with fewer than 3 bits");
+    RandomSafePrimesWithBits {
+        bits,
+        limbs: random_primitive_ints(seed),
+        sieve_groups: sieve_groups_for_bits(bits),
+    }
+}
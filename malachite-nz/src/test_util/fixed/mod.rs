@@ -0,0 +1,7 @@
+use crate::platform::Limb;
+use malachite_base::num::basic::integers::PrimitiveInt;
+
+// The numbers of limbs in 128- and 256-bit `UBig`s and `IBig`s. Unit tests use these so that the
+// same expected values work for both 32- and 64-bit limbs.
+pub const LIMBS_128: usize = (128 >> Limb::LOG_WIDTH) as usize;
+pub const LIMBS_256: usize = (256 >> Limb::LOG_WIDTH) as usize;
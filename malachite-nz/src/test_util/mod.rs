@@ -1,6 +1,7 @@
 pub mod bench;
 pub mod common;
 pub mod extra_variadic;
+pub mod fixed;
 pub mod generators;
 pub mod integer;
 pub mod natural;
@@ -0,0 +1,6 @@
+use crate::natural::Natural;
+use crate::test_util::natural::arithmetic::stirling::stirling_second_naive;
+
+pub fn bell_number_naive(n: u64) -> Natural {
+    (0..=n).map(|k| stirling_second_naive(n, k)).sum()
+}
@@ -0,0 +1,14 @@
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+
+pub fn catalan_number_naive(n: u64) -> Natural {
+    // C_{i + 1} = sum_{j = 0}^i C_j * C_{i - j}
+    let n = usize::exact_from(n);
+    let mut cs = vec![Natural::ONE];
+    for _ in 0..n {
+        let c = cs.iter().zip(cs.iter().rev()).map(|(x, y)| x * y).sum();
+        cs.push(c);
+    }
+    cs.swap_remove(n)
+}
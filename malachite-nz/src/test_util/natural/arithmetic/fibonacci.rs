@@ -0,0 +1,30 @@
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use std::mem::swap;
+
+fn lucas_sequence_naive(n: u64, mut x: Natural, mut y: Natural) -> Natural {
+    for _ in 0..n {
+        x += &y;
+        swap(&mut x, &mut y);
+    }
+    x
+}
+
+pub fn fibonacci_naive(n: u64) -> Natural {
+    lucas_sequence_naive(n, Natural::ZERO, Natural::ONE)
+}
+
+pub fn lucas_naive(n: u64) -> Natural {
+    lucas_sequence_naive(n, Natural::TWO, Natural::ONE)
+}
+
+pub fn fibonacci_mod_naive(n: u64, m: &Natural) -> Natural {
+    let mut x = Natural::ZERO;
+    let mut y = Natural::ONE % m;
+    for _ in 0..n {
+        x += &y;
+        x %= m;
+        swap(&mut x, &mut y);
+    }
+    x
+}
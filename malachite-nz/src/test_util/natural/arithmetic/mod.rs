@@ -1,5 +1,7 @@
 pub mod add;
+pub mod bell_number;
 pub mod binomial_coefficient;
+pub mod catalan_number;
 pub mod checked_sub;
 pub mod div;
 pub mod div_exact;
@@ -8,6 +10,7 @@ pub mod divisible_by;
 pub mod eq_mod;
 pub mod extended_gcd;
 pub mod factorial;
+pub mod fibonacci;
 pub mod gcd;
 pub mod kronecker_symbol;
 pub mod log_base;
@@ -20,9 +23,11 @@ pub mod mod_power_of_2_pow;
 pub mod mod_power_of_2_square;
 pub mod mul;
 pub mod neg;
+pub mod partition_number;
 pub mod pow;
 pub mod primorial;
 pub mod root;
 pub mod sqrt;
 pub mod square;
+pub mod stirling;
 pub mod sub;
@@ -0,0 +1,18 @@
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+pub fn partition_number_naive(n: u64) -> Natural {
+    // After processing part size k, ps[i] is the number of partitions of i into parts no larger
+    // than k
+    let n = usize::exact_from(n);
+    let mut ps = vec![Natural::ZERO; n + 1];
+    ps[0] = Natural::ONE;
+    for k in 1..=n {
+        for i in k..=n {
+            let p = &ps[i] + &ps[i - k];
+            ps[i] = p;
+        }
+    }
+    ps.swap_remove(n)
+}
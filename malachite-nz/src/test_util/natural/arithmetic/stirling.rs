@@ -0,0 +1,37 @@
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+pub fn stirling_first_naive(n: u64, k: u64) -> Natural {
+    // c(i + 1, j) = i * c(i, j) + c(i, j - 1)
+    let k = usize::exact_from(k);
+    let mut row = vec![Natural::ZERO; k + 1];
+    row[0] = Natural::ONE;
+    for i in 0..n {
+        for j in (0..=k).rev() {
+            let mut c = Natural::from(i) * &row[j];
+            if j != 0 {
+                c += &row[j - 1];
+            }
+            row[j] = c;
+        }
+    }
+    row.swap_remove(k)
+}
+
+pub fn stirling_second_naive(n: u64, k: u64) -> Natural {
+    // S(i + 1, j) = j * S(i, j) + S(i, j - 1)
+    let k = usize::exact_from(k);
+    let mut row = vec![Natural::ZERO; k + 1];
+    row[0] = Natural::ONE;
+    for _ in 0..n {
+        for j in (0..=k).rev() {
+            let mut s = Natural::from(j) * &row[j];
+            if j != 0 {
+                s += &row[j - 1];
+            }
+            row[j] = s;
+        }
+    }
+    row.swap_remove(k)
+}
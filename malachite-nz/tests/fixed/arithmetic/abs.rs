@@ -0,0 +1,49 @@
+use malachite_base::comparison::traits::Min;
+use malachite_base::num::arithmetic::traits::{PowerOf2, UnsignedAbs, WrappingNeg};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::integer_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_unsigned_abs() {
+    let test = |s, out| {
+        let u = IBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!(u.unsigned_abs(), UBig::<LIMBS_128>::from_str(out).unwrap());
+    };
+    test("0", "0");
+    test("123", "123");
+    test("-123", "123");
+    test(
+        "170141183460469231731687303715884105727",
+        "170141183460469231731687303715884105727",
+    );
+    test(
+        "-170141183460469231731687303715884105728",
+        "170141183460469231731687303715884105728",
+    );
+}
+
+fn unsigned_abs_properties_helper<const N: usize>() {
+    integer_gen().test_properties(|x| {
+        let u = IBig::<N>::wrapping_from(&x);
+        let abs = u.unsigned_abs();
+        assert_eq!(Natural::from(abs), Integer::from(u).unsigned_abs());
+        assert_eq!(u.wrapping_neg().unsigned_abs(), abs);
+        assert!(Natural::from(abs) <= Natural::power_of_2(IBig::<N>::WIDTH - 1));
+    });
+    assert_eq!(
+        Natural::from(IBig::<N>::MIN.unsigned_abs()),
+        Natural::power_of_2(IBig::<N>::WIDTH - 1)
+    );
+}
+
+#[test]
+fn unsigned_abs_properties() {
+    unsigned_abs_properties_helper::<1>();
+    unsigned_abs_properties_helper::<2>();
+    unsigned_abs_properties_helper::<4>();
+}
@@ -0,0 +1,154 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedAdd, ModPowerOf2, OverflowingAdd, OverflowingAddAssign, SaturatingAdd,
+    SaturatingAddAssign, WrappingAdd, WrappingAddAssign,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{integer_pair_gen, natural_pair_gen};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_add_ubig() {
+    let test = |s, t, out, overflow| {
+        let u = UBig::<LIMBS_128>::from_str(s).unwrap();
+        let v = UBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = UBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.overflowing_add(v), (out, overflow));
+        assert_eq!(u.wrapping_add(v), out);
+        let mut n = u;
+        assert_eq!(n.overflowing_add_assign(v), overflow);
+        assert_eq!(n, out);
+        let mut n = u;
+        n.wrapping_add_assign(v);
+        assert_eq!(n, out);
+        if overflow {
+            assert_eq!(u.checked_add(v), None);
+            assert_eq!(u.saturating_add(v), UBig::MAX);
+        } else {
+            assert_eq!(u + v, out);
+            let mut n = u;
+            n += v;
+            assert_eq!(n, out);
+            assert_eq!(u.checked_add(v), Some(out));
+            assert_eq!(u.saturating_add(v), out);
+        }
+    };
+    test("0", "0", "0", false);
+    test("123", "456", "579", false);
+    test("18446744073709551615", "1", "18446744073709551616", false);
+    test(
+        "340282366920938463463374607431768211455",
+        "0",
+        "340282366920938463463374607431768211455",
+        false,
+    );
+    test("340282366920938463463374607431768211455", "1", "0", true);
+    test(
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211454",
+        true,
+    );
+}
+
+#[test]
+fn test_add_ibig() {
+    let test = |s, t, out, overflow| {
+        let u = IBig::<LIMBS_128>::from_str(s).unwrap();
+        let v = IBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = IBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.overflowing_add(v), (out, overflow));
+        assert_eq!(u.wrapping_add(v), out);
+        let mut n = u;
+        assert_eq!(n.overflowing_add_assign(v), overflow);
+        assert_eq!(n, out);
+        if !overflow {
+            assert_eq!(u + v, out);
+            assert_eq!(u.checked_add(v), Some(out));
+            assert_eq!(u.saturating_add(v), out);
+        } else {
+            assert_eq!(u.checked_add(v), None);
+        }
+    };
+    test("0", "0", "0", false);
+    test("123", "-456", "-333", false);
+    test("-123", "-456", "-579", false);
+    test(
+        "170141183460469231731687303715884105727",
+        "1",
+        "-170141183460469231731687303715884105728",
+        true,
+    );
+    test(
+        "-170141183460469231731687303715884105728",
+        "-1",
+        "170141183460469231731687303715884105727",
+        true,
+    );
+    test(
+        "-170141183460469231731687303715884105728",
+        "170141183460469231731687303715884105727",
+        "-1",
+        false,
+    );
+    assert_eq!(IBig::<LIMBS_128>::MAX.saturating_add(IBig::ONE), IBig::MAX);
+    let mut n = IBig::<LIMBS_128>::MIN;
+    n.saturating_add_assign(IBig::MIN);
+    assert_eq!(n, IBig::MIN);
+}
+
+#[test]
+fn add_fail() {
+    assert_panic!(UBig::<LIMBS_128>::MAX + UBig::ONE);
+    assert_panic!(IBig::<LIMBS_128>::MAX + IBig::ONE);
+    assert_panic!({
+        let mut x = IBig::<LIMBS_128>::MIN;
+        x += IBig::MIN;
+    });
+}
+
+fn add_properties_helper<const N: usize>() {
+    natural_pair_gen().test_properties(|(x, y)| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let v = UBig::<N>::wrapping_from(&y);
+        let sum = Natural::from(u) + Natural::from(v);
+        let (out, overflow) = u.overflowing_add(v);
+        assert_eq!(Natural::from(out), (&sum).mod_power_of_2(UBig::<N>::WIDTH));
+        assert_eq!(overflow, sum > Natural::from(UBig::<N>::MAX));
+        assert_eq!(u.wrapping_add(v), out);
+        assert_eq!(v.overflowing_add(u), (out, overflow));
+        assert_eq!(u.checked_add(v).is_none(), overflow);
+        assert!(u.saturating_add(v) >= u);
+        assert_eq!(out.wrapping_add(UBig::ZERO), out);
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        let u = IBig::<N>::wrapping_from(&x);
+        let v = IBig::<N>::wrapping_from(&y);
+        let sum = Integer::from(u) + Integer::from(v);
+        let (out, overflow) = u.overflowing_add(v);
+        assert_eq!(out, IBig::<N>::wrapping_from(&sum));
+        assert_eq!(overflow, Integer::from(out) != sum);
+        assert_eq!(v.overflowing_add(u), (out, overflow));
+        assert_eq!(u.checked_add(v).is_none(), overflow);
+        let saturated = u.saturating_add(v);
+        if overflow {
+            assert!(saturated == IBig::MAX || saturated == IBig::MIN);
+        } else {
+            assert_eq!(saturated, out);
+        }
+    });
+}
+
+#[test]
+fn add_properties() {
+    add_properties_helper::<1>();
+    add_properties_helper::<2>();
+    add_properties_helper::<4>();
+}
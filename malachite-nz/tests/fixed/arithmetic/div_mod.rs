@@ -0,0 +1,212 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedDiv, DivAssignMod, DivAssignRem, DivMod, DivRem, WrappingAdd, WrappingMul,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::{LIMBS_128, LIMBS_256};
+use malachite_nz::test_util::generators::{integer_pair_gen_var_1, natural_pair_gen_var_5};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_div_mod_ubig() {
+    let test = |s, t, q_out, r_out| {
+        let u = UBig::<LIMBS_256>::from_str(s).unwrap();
+        let v = UBig::<LIMBS_256>::from_str(t).unwrap();
+        let q_out = UBig::<LIMBS_256>::from_str(q_out).unwrap();
+        let r_out = UBig::<LIMBS_256>::from_str(r_out).unwrap();
+        assert_eq!(u.div_mod(v), (q_out, r_out));
+        assert_eq!(u.div_rem(v), (q_out, r_out));
+        let mut n = u;
+        assert_eq!(n.div_assign_mod(v), r_out);
+        assert_eq!(n, q_out);
+        let mut n = u;
+        assert_eq!(n.div_assign_rem(v), r_out);
+        assert_eq!(n, q_out);
+        assert_eq!(u / v, q_out);
+        assert_eq!(u % v, r_out);
+        let mut n = u;
+        n /= v;
+        assert_eq!(n, q_out);
+        let mut n = u;
+        n %= v;
+        assert_eq!(n, r_out);
+        assert_eq!(u.checked_div(v), Some(q_out));
+    };
+    test("0", "1", "0", "0");
+    test("0", "123", "0", "0");
+    test("1", "1", "1", "0");
+    test("123", "1", "123", "0");
+    test("123", "123", "1", "0");
+    test("123", "456", "0", "123");
+    test("456", "123", "3", "87");
+    test("4294967295", "1", "4294967295", "0");
+    test("4294967295", "4294967295", "1", "0");
+    test("1000000000000", "1", "1000000000000", "0");
+    test("1000000000000", "3", "333333333333", "1");
+    test("1000000000000", "123", "8130081300", "100");
+    test("1000000000000", "4294967295", "232", "3567587560");
+    test(
+        "1000000000000000000000000",
+        "1000000000000",
+        "1000000000000",
+        "0",
+    );
+    test(
+        "1000000000000000000000000",
+        "1234567890987",
+        "810000006723",
+        "530068894399",
+    );
+    test(
+        "340282366920938463463374607431768211455",
+        "18446744073709551615",
+        "18446744073709551617",
+        "0",
+    );
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "340282366920938463463374607431768211457",
+        "340282366920938463463374607431768211455",
+        "0",
+    );
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "6277101735386680763835789423207666416102355444464034512895",
+        "18446744073709551616",
+        "18446744073709551615",
+    );
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "1",
+        "0",
+    );
+    test(
+        "57896044618658097711785492504343953926634992332820282019728792003956564819968",
+        "3",
+        "19298681539552699237261830834781317975544997444273427339909597334652188273322",
+        "2",
+    );
+}
+
+#[test]
+fn div_mod_ubig_fail() {
+    assert_panic!(UBig::<LIMBS_128>::ONE.div_mod(UBig::ZERO));
+    assert_panic!(UBig::<LIMBS_128>::ONE / UBig::ZERO);
+    assert_panic!(UBig::<LIMBS_128>::ONE % UBig::ZERO);
+    assert_eq!(UBig::<LIMBS_128>::ONE.checked_div(UBig::ZERO), None);
+}
+
+#[test]
+fn test_div_mod_ibig() {
+    let test = |s, t, q_mod, r_mod, q_rem, r_rem| {
+        let u = IBig::<LIMBS_128>::from_str(s).unwrap();
+        let v = IBig::<LIMBS_128>::from_str(t).unwrap();
+        let q_mod = IBig::<LIMBS_128>::from_str(q_mod).unwrap();
+        let r_mod = IBig::<LIMBS_128>::from_str(r_mod).unwrap();
+        let q_rem = IBig::<LIMBS_128>::from_str(q_rem).unwrap();
+        let r_rem = IBig::<LIMBS_128>::from_str(r_rem).unwrap();
+        assert_eq!(u.div_mod(v), (q_mod, r_mod));
+        let mut n = u;
+        assert_eq!(n.div_assign_mod(v), r_mod);
+        assert_eq!(n, q_mod);
+        assert_eq!(u.div_rem(v), (q_rem, r_rem));
+        let mut n = u;
+        assert_eq!(n.div_assign_rem(v), r_rem);
+        assert_eq!(n, q_rem);
+        assert_eq!(u / v, q_rem);
+        assert_eq!(u % v, r_rem);
+        assert_eq!(u.checked_div(v), Some(q_rem));
+    };
+    test("0", "1", "0", "0", "0", "0");
+    test("23", "10", "2", "3", "2", "3");
+    test("23", "-10", "-3", "-7", "-2", "3");
+    test("-23", "10", "-3", "7", "-2", "-3");
+    test("-23", "-10", "2", "-3", "2", "-3");
+    test("-20", "10", "-2", "0", "-2", "0");
+    test(
+        "-170141183460469231731687303715884105728",
+        "1",
+        "-170141183460469231731687303715884105728",
+        "0",
+        "-170141183460469231731687303715884105728",
+        "0",
+    );
+    test(
+        "-170141183460469231731687303715884105728",
+        "-170141183460469231731687303715884105728",
+        "1",
+        "0",
+        "1",
+        "0",
+    );
+    test(
+        "170141183460469231731687303715884105727",
+        "-170141183460469231731687303715884105728",
+        "-1",
+        "-1",
+        "0",
+        "170141183460469231731687303715884105727",
+    );
+}
+
+#[test]
+fn div_mod_ibig_fail() {
+    assert_panic!(IBig::<LIMBS_128>::ONE.div_mod(IBig::ZERO));
+    assert_panic!(IBig::<LIMBS_128>::ONE.div_rem(IBig::ZERO));
+    assert_panic!(IBig::<LIMBS_128>::MIN / IBig::NEGATIVE_ONE);
+    assert_panic!(IBig::<LIMBS_128>::MIN.div_mod(IBig::NEGATIVE_ONE));
+    assert_eq!(IBig::<LIMBS_128>::ONE.checked_div(IBig::ZERO), None);
+    assert_eq!(IBig::<LIMBS_128>::MIN.checked_div(IBig::NEGATIVE_ONE), None);
+}
+
+fn div_mod_properties_helper<const N: usize>() {
+    natural_pair_gen_var_5().test_properties(|(x, y)| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let v = UBig::<N>::wrapping_from(&y);
+        if v == UBig::ZERO {
+            return;
+        }
+        let (q, r) = u.div_mod(v);
+        let (q_alt, r_alt) = Natural::from(u).div_mod(Natural::from(v));
+        assert_eq!(Natural::from(q), q_alt);
+        assert_eq!(Natural::from(r), r_alt);
+        assert!(r < v);
+        assert_eq!(q.wrapping_mul(v).wrapping_add(r), u);
+        assert_eq!(u.div_rem(v), (q, r));
+        assert_eq!(u.checked_div(v), Some(q));
+        assert_eq!(u.div_mod(UBig::ONE), (u, UBig::ZERO));
+        assert_eq!(v.div_mod(v), (UBig::ONE, UBig::ZERO));
+        assert!(UBig::<N>::MAX.div_mod(v).0 >= q);
+    });
+
+    integer_pair_gen_var_1().test_properties(|(x, y)| {
+        let u = IBig::<N>::wrapping_from(&x);
+        let v = IBig::<N>::wrapping_from(&y);
+        if v == IBig::ZERO || u == IBig::MIN && v == IBig::NEGATIVE_ONE {
+            return;
+        }
+        let (q, r) = u.div_mod(v);
+        let (q_alt, r_alt) = Integer::from(u).div_mod(Integer::from(v));
+        assert_eq!(Integer::from(q), q_alt);
+        assert_eq!(Integer::from(r), r_alt);
+        let (q, r) = u.div_rem(v);
+        let (q_alt, r_alt) = Integer::from(u).div_rem(Integer::from(v));
+        assert_eq!(Integer::from(q), q_alt);
+        assert_eq!(Integer::from(r), r_alt);
+        assert_eq!(u.checked_div(v), Some(q));
+    });
+}
+
+#[test]
+fn div_mod_properties() {
+    div_mod_properties_helper::<1>();
+    div_mod_properties_helper::<2>();
+    div_mod_properties_helper::<3>();
+    div_mod_properties_helper::<4>();
+}
@@ -0,0 +1,83 @@
+use malachite_base::comparison::traits::Max;
+use malachite_base::num::arithmetic::traits::{ModAdd, ModAddAssign, ModNeg, ModSub};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::UBig;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::natural_triple_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_add() {
+    let test = |r, s, t, out| {
+        let u = UBig::<LIMBS_128>::from_str(r).unwrap();
+        let v = UBig::<LIMBS_128>::from_str(s).unwrap();
+        let m = UBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = UBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.mod_add(v, m), out);
+        let mut n = u;
+        n.mod_add_assign(v, m);
+        assert_eq!(n, out);
+    };
+    test("0", "0", "1", "0");
+    test("0", "0", "32", "0");
+    test("0", "2", "32", "2");
+    test("10", "14", "16", "8");
+    test("0", "123", "128", "123");
+    test("123", "0", "128", "123");
+    test("123", "456", "512", "67");
+    test(
+        "340282366920938463463374607431768211454",
+        "340282366920938463463374607431768211454",
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211453",
+    );
+    test(
+        "340282366920938463463374607431768211454",
+        "1",
+        "340282366920938463463374607431768211455",
+        "0",
+    );
+}
+
+#[test]
+fn mod_add_fail() {
+    assert_panic!(UBig::<LIMBS_128>::ZERO.mod_add(UBig::ZERO, UBig::ZERO));
+    assert_panic!(UBig::<LIMBS_128>::from(123u32).mod_add(UBig::from(200u32), UBig::from(200u32)));
+    assert_panic!({
+        let mut x = UBig::<LIMBS_128>::from(200u32);
+        x.mod_add_assign(UBig::from(123u32), UBig::from(200u32));
+    });
+}
+
+fn mod_add_properties_helper<const N: usize>() {
+    natural_triple_gen().test_properties(|(x, y, m)| {
+        let m = UBig::<N>::wrapping_from(&m);
+        if m == UBig::ZERO {
+            return;
+        }
+        let u = UBig::<N>::wrapping_from(&x) % m;
+        let v = UBig::<N>::wrapping_from(&y) % m;
+        let sum = u.mod_add(v, m);
+        assert!(sum < m);
+        assert_eq!(
+            Natural::from(sum),
+            (Natural::from(u) + Natural::from(v)) % Natural::from(m)
+        );
+        assert_eq!(v.mod_add(u, m), sum);
+        assert_eq!(sum.mod_sub(v, m), u);
+        assert_eq!(u.mod_add(v.mod_neg(m), m), u.mod_sub(v, m));
+    });
+
+    let m = UBig::<N>::MAX;
+    assert_eq!((m - UBig::ONE).mod_add(m - UBig::ONE, m), m - UBig::TWO);
+}
+
+#[test]
+fn mod_add_properties() {
+    mod_add_properties_helper::<1>();
+    mod_add_properties_helper::<2>();
+    mod_add_properties_helper::<4>();
+}
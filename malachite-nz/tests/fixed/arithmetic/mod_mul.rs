@@ -0,0 +1,96 @@
+use malachite_base::comparison::traits::Max;
+use malachite_base::num::arithmetic::traits::{ModMul, ModMulAssign, ModNeg};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::UBig;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::natural_triple_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_mul() {
+    let test = |r, s, t, out| {
+        let u = UBig::<LIMBS_128>::from_str(r).unwrap();
+        let v = UBig::<LIMBS_128>::from_str(s).unwrap();
+        let m = UBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = UBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.mod_mul(v, m), out);
+        let mut n = u;
+        n.mod_mul_assign(v, m);
+        assert_eq!(n, out);
+    };
+    test("0", "0", "1", "0");
+    test("1", "0", "32", "0");
+    test("1", "2", "32", "2");
+    test("3", "4", "15", "12");
+    test("7", "6", "10", "2");
+    test("10", "14", "16", "12");
+    test("1", "123", "128", "123");
+    test("123", "1", "128", "123");
+    test("123", "456", "512", "280");
+    test(
+        "18446744073709551615",
+        "18446744073709551615",
+        "18446744073709551616",
+        "1",
+    );
+    test(
+        "340282366920938463463374607431768211453",
+        "340282366920938463463374607431768211453",
+        "340282366920938463463374607431768211455",
+        "4",
+    );
+    test(
+        "170141183460469231731687303715884105728",
+        "170141183460469231731687303715884105728",
+        "340282366920938463463374607431768211297",
+        "255211775190703847597530955573826164793",
+    );
+}
+
+#[test]
+fn mod_mul_fail() {
+    assert_panic!(UBig::<LIMBS_128>::ZERO.mod_mul(UBig::ZERO, UBig::ZERO));
+    assert_panic!(UBig::<LIMBS_128>::from(123u32).mod_mul(UBig::from(200u32), UBig::from(200u32)));
+    assert_panic!({
+        let mut x = UBig::<LIMBS_128>::from(200u32);
+        x.mod_mul_assign(UBig::from(123u32), UBig::from(200u32));
+    });
+}
+
+fn mod_mul_properties_helper<const N: usize>() {
+    natural_triple_gen().test_properties(|(x, y, m)| {
+        let m = UBig::<N>::wrapping_from(&m);
+        if m == UBig::ZERO {
+            return;
+        }
+        let u = UBig::<N>::wrapping_from(&x) % m;
+        let v = UBig::<N>::wrapping_from(&y) % m;
+        let product = u.mod_mul(v, m);
+        assert!(product < m);
+        assert_eq!(
+            Natural::from(product),
+            Natural::from(u) * Natural::from(v) % Natural::from(m)
+        );
+        assert_eq!(v.mod_mul(u, m), product);
+        assert_eq!(u.mod_neg(m).mod_mul(v, m), product.mod_neg(m));
+        if m != UBig::ONE {
+            assert_eq!(u.mod_mul(UBig::ONE, m), u);
+        }
+    });
+
+    let m = UBig::<N>::MAX;
+    assert_eq!(
+        (m - UBig::TWO).mod_mul(m - UBig::TWO, m),
+        UBig::TWO + UBig::TWO
+    );
+}
+
+#[test]
+fn mod_mul_properties() {
+    mod_mul_properties_helper::<1>();
+    mod_mul_properties_helper::<2>();
+    mod_mul_properties_helper::<4>();
+}
@@ -0,0 +1,64 @@
+use malachite_base::num::arithmetic::traits::{ModAdd, ModNeg, ModNegAssign};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::UBig;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::natural_pair_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_neg() {
+    let test = |r, s, out| {
+        let u = UBig::<LIMBS_128>::from_str(r).unwrap();
+        let m = UBig::<LIMBS_128>::from_str(s).unwrap();
+        let out = UBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.mod_neg(m), out);
+        let mut n = u;
+        n.mod_neg_assign(m);
+        assert_eq!(n, out);
+    };
+    test("0", "1", "0");
+    test("0", "5", "0");
+    test("7", "10", "3");
+    test("100", "101", "1");
+    test(
+        "1",
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211454",
+    );
+}
+
+#[test]
+fn mod_neg_fail() {
+    assert_panic!(UBig::<LIMBS_128>::ZERO.mod_neg(UBig::ZERO));
+    assert_panic!(UBig::<LIMBS_128>::from(30u32).mod_neg(UBig::from(3u32)));
+    assert_panic!({
+        let mut x = UBig::<LIMBS_128>::from(3u32);
+        x.mod_neg_assign(UBig::from(3u32));
+    });
+}
+
+fn mod_neg_properties_helper<const N: usize>() {
+    natural_pair_gen().test_properties(|(x, m)| {
+        let m = UBig::<N>::wrapping_from(&m);
+        if m == UBig::ZERO {
+            return;
+        }
+        let u = UBig::<N>::wrapping_from(&x) % m;
+        let neg = u.mod_neg(m);
+        assert!(neg < m);
+        let m_alt = Natural::from(m);
+        assert_eq!(Natural::from(neg), (&m_alt - Natural::from(u)) % &m_alt);
+        assert_eq!(neg.mod_neg(m), u);
+        assert_eq!(u.mod_add(neg, m), UBig::ZERO);
+    });
+}
+
+#[test]
+fn mod_neg_properties() {
+    mod_neg_properties_helper::<1>();
+    mod_neg_properties_helper::<2>();
+    mod_neg_properties_helper::<4>();
+}
@@ -0,0 +1,97 @@
+use malachite_base::comparison::traits::Max;
+use malachite_base::num::arithmetic::traits::{ModMul, ModPow, ModPowAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::UBig;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{natural_triple_gen, natural_unsigned_pair_gen_var_4};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_pow() {
+    let test = |r, exp: u64, t, out| {
+        let u = UBig::<LIMBS_128>::from_str(r).unwrap();
+        let m = UBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = UBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.mod_pow(exp, m), out);
+        let mut n = u;
+        n.mod_pow_assign(exp, m);
+        assert_eq!(n, out);
+        let exp = UBig::<LIMBS_128>::from(u32::try_from(exp).unwrap());
+        assert_eq!(u.mod_pow(exp, m), out);
+        let mut n = u;
+        n.mod_pow_assign(exp, m);
+        assert_eq!(n, out);
+    };
+    test("0", 0, "1", "0");
+    test("0", 0, "10", "1");
+    test("0", 1, "10", "0");
+    test("2", 10, "10", "4");
+    test("4", 13, "497", "445");
+    test("10", 1000, "30", "10");
+    test("2", 340, "341", "1");
+    test("5", 5, "19", "9");
+    test(
+        "2",
+        126,
+        "340282366920938463463374607431768211455",
+        "85070591730234615865843651857942052864",
+    );
+    test(
+        "3",
+        1000,
+        "340282366920938463463374607431768211297",
+        "41607535425614603799440823781571772106",
+    );
+}
+
+#[test]
+fn mod_pow_fail() {
+    assert_panic!(UBig::<LIMBS_128>::ZERO.mod_pow(10, UBig::ZERO));
+    assert_panic!(UBig::<LIMBS_128>::from(30u32).mod_pow(10, UBig::ONE));
+    assert_panic!({
+        let mut x = UBig::<LIMBS_128>::from(30u32);
+        x.mod_pow_assign(UBig::ONE, UBig::from(30u32));
+    });
+}
+
+fn mod_pow_properties_helper<const N: usize>() {
+    natural_triple_gen().test_properties(|(x, exp, m)| {
+        let m = UBig::<N>::wrapping_from(&m);
+        if m == UBig::ZERO {
+            return;
+        }
+        let u = UBig::<N>::wrapping_from(&x) % m;
+        let exp = UBig::<N>::wrapping_from(&exp);
+        let power = u.mod_pow(exp, m);
+        assert!(power < m);
+        assert_eq!(
+            Natural::from(power),
+            Natural::from(u).mod_pow(Natural::from(exp), Natural::from(m))
+        );
+    });
+
+    natural_unsigned_pair_gen_var_4::<u64>().test_properties(|(x, exp)| {
+        let m = UBig::<N>::MAX;
+        let u = UBig::<N>::wrapping_from(&x) % m;
+        let power = u.mod_pow(exp, m);
+        assert_eq!(
+            Natural::from(power),
+            Natural::from(u).mod_pow(Natural::from(exp), Natural::from(m))
+        );
+        assert_eq!(power.mod_mul(u, m), u.mod_pow(exp + 1, m));
+        assert_eq!(
+            u.mod_pow(UBig::<N>::wrapping_from(&Natural::from(exp)), m),
+            power
+        );
+    });
+}
+
+#[test]
+fn mod_pow_properties() {
+    mod_pow_properties_helper::<1>();
+    mod_pow_properties_helper::<2>();
+    mod_pow_properties_helper::<4>();
+}
@@ -0,0 +1,79 @@
+use malachite_base::comparison::traits::Max;
+use malachite_base::num::arithmetic::traits::{ModAdd, ModNeg, ModSub, ModSubAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::UBig;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::natural_triple_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_sub() {
+    let test = |r, s, t, out| {
+        let u = UBig::<LIMBS_128>::from_str(r).unwrap();
+        let v = UBig::<LIMBS_128>::from_str(s).unwrap();
+        let m = UBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = UBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.mod_sub(v, m), out);
+        let mut n = u;
+        n.mod_sub_assign(v, m);
+        assert_eq!(n, out);
+    };
+    test("0", "0", "1", "0");
+    test("0", "0", "32", "0");
+    test("0", "27", "32", "5");
+    test("10", "2", "16", "8");
+    test("2", "10", "16", "8");
+    test("0", "5", "128", "123");
+    test("123", "0", "128", "123");
+    test("123", "56", "512", "67");
+    test("56", "123", "512", "445");
+    test(
+        "0",
+        "340282366920938463463374607431768211454",
+        "340282366920938463463374607431768211455",
+        "1",
+    );
+}
+
+#[test]
+fn mod_sub_fail() {
+    assert_panic!(UBig::<LIMBS_128>::ZERO.mod_sub(UBig::ZERO, UBig::ZERO));
+    assert_panic!(UBig::<LIMBS_128>::from(123u32).mod_sub(UBig::from(200u32), UBig::from(200u32)));
+    assert_panic!({
+        let mut x = UBig::<LIMBS_128>::from(200u32);
+        x.mod_sub_assign(UBig::from(123u32), UBig::from(200u32));
+    });
+}
+
+fn mod_sub_properties_helper<const N: usize>() {
+    natural_triple_gen().test_properties(|(x, y, m)| {
+        let m = UBig::<N>::wrapping_from(&m);
+        if m == UBig::ZERO {
+            return;
+        }
+        let u = UBig::<N>::wrapping_from(&x) % m;
+        let v = UBig::<N>::wrapping_from(&y) % m;
+        let difference = u.mod_sub(v, m);
+        assert!(difference < m);
+        let m_alt = Natural::from(m);
+        assert_eq!(
+            Natural::from(difference),
+            (Natural::from(u) + &m_alt - Natural::from(v)) % m_alt
+        );
+        assert_eq!(difference.mod_add(v, m), u);
+        assert_eq!(v.mod_sub(u, m), difference.mod_neg(m));
+    });
+
+    let m = UBig::<N>::MAX;
+    assert_eq!(UBig::ZERO.mod_sub(m - UBig::ONE, m), UBig::ONE);
+}
+
+#[test]
+fn mod_sub_properties() {
+    mod_sub_properties_helper::<1>();
+    mod_sub_properties_helper::<2>();
+    mod_sub_properties_helper::<4>();
+}
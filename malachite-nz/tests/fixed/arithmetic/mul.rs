@@ -0,0 +1,187 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedMul, ModPowerOf2, OverflowingMul, OverflowingMulAssign, SaturatingMul,
+    SaturatingMulAssign, WrappingMul, WrappingMulAssign,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{
+    integer_pair_gen, integer_triple_gen, natural_pair_gen, natural_triple_gen,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mul_ubig() {
+    let test = |s, t, out, overflow| {
+        let u = UBig::<LIMBS_128>::from_str(s).unwrap();
+        let v = UBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = UBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.overflowing_mul(v), (out, overflow));
+        assert_eq!(u.wrapping_mul(v), out);
+        let mut n = u;
+        assert_eq!(n.overflowing_mul_assign(v), overflow);
+        assert_eq!(n, out);
+        let mut n = u;
+        n.wrapping_mul_assign(v);
+        assert_eq!(n, out);
+        if overflow {
+            assert_eq!(u.checked_mul(v), None);
+            assert_eq!(u.saturating_mul(v), UBig::MAX);
+        } else {
+            assert_eq!(u * v, out);
+            let mut n = u;
+            n *= v;
+            assert_eq!(n, out);
+            assert_eq!(u.checked_mul(v), Some(out));
+            assert_eq!(u.saturating_mul(v), out);
+        }
+    };
+    test("0", "0", "0", false);
+    test("123", "456", "56088", false);
+    test(
+        "18446744073709551615",
+        "18446744073709551615",
+        "340282366920938463426481119284349108225",
+        false,
+    );
+    test("18446744073709551616", "18446744073709551616", "0", true);
+    test(
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211455",
+        "1",
+        true,
+    );
+}
+
+#[test]
+fn test_mul_ibig() {
+    let test = |s, t, out, overflow| {
+        let u = IBig::<LIMBS_128>::from_str(s).unwrap();
+        let v = IBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = IBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.overflowing_mul(v), (out, overflow));
+        assert_eq!(u.wrapping_mul(v), out);
+        let mut n = u;
+        assert_eq!(n.overflowing_mul_assign(v), overflow);
+        assert_eq!(n, out);
+        if !overflow {
+            assert_eq!(u * v, out);
+            assert_eq!(u.checked_mul(v), Some(out));
+            assert_eq!(u.saturating_mul(v), out);
+        } else {
+            assert_eq!(u.checked_mul(v), None);
+        }
+    };
+    test("0", "0", "0", false);
+    test("123", "-456", "-56088", false);
+    test("-123", "-456", "56088", false);
+    test(
+        "-85070591730234615865843651857942052864",
+        "2",
+        "-170141183460469231731687303715884105728",
+        false,
+    );
+    test(
+        "85070591730234615865843651857942052864",
+        "2",
+        "-170141183460469231731687303715884105728",
+        true,
+    );
+    test(
+        "-170141183460469231731687303715884105728",
+        "-1",
+        "-170141183460469231731687303715884105728",
+        true,
+    );
+    test(
+        "-170141183460469231731687303715884105728",
+        "1",
+        "-170141183460469231731687303715884105728",
+        false,
+    );
+    assert_eq!(IBig::<LIMBS_128>::MAX.saturating_mul(IBig::TWO), IBig::MAX);
+    assert_eq!(
+        IBig::<LIMBS_128>::MIN.saturating_mul(IBig::NEGATIVE_ONE),
+        IBig::MAX
+    );
+    let mut n = IBig::<LIMBS_128>::MIN;
+    n.saturating_mul_assign(IBig::TWO);
+    assert_eq!(n, IBig::MIN);
+}
+
+#[test]
+fn mul_fail() {
+    assert_panic!(UBig::<LIMBS_128>::MAX * UBig::TWO);
+    assert_panic!(IBig::<LIMBS_128>::MIN * IBig::NEGATIVE_ONE);
+    assert_panic!({
+        let mut x = IBig::<LIMBS_128>::MAX;
+        x *= IBig::MAX;
+    });
+}
+
+fn mul_properties_helper<const N: usize>() {
+    natural_pair_gen().test_properties(|(x, y)| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let v = UBig::<N>::wrapping_from(&y);
+        let product = Natural::from(u) * Natural::from(v);
+        let (out, overflow) = u.overflowing_mul(v);
+        assert_eq!(
+            Natural::from(out),
+            (&product).mod_power_of_2(UBig::<N>::WIDTH)
+        );
+        assert_eq!(overflow, product > Natural::from(UBig::<N>::MAX));
+        assert_eq!(v.overflowing_mul(u), (out, overflow));
+        assert_eq!(u.wrapping_mul(UBig::ONE), u);
+        assert_eq!(u.wrapping_mul(UBig::ZERO), UBig::ZERO);
+        assert_eq!(u.checked_mul(v).is_none(), overflow);
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        let u = IBig::<N>::wrapping_from(&x);
+        let v = IBig::<N>::wrapping_from(&y);
+        let product = Integer::from(u) * Integer::from(v);
+        let (out, overflow) = u.overflowing_mul(v);
+        assert_eq!(out, IBig::<N>::wrapping_from(&product));
+        assert_eq!(overflow, Integer::from(out) != product);
+        assert_eq!(v.overflowing_mul(u), (out, overflow));
+        assert_eq!(u.checked_mul(v).is_none(), overflow);
+        let saturated = u.saturating_mul(v);
+        if overflow {
+            assert_eq!(saturated, if product < 0 { IBig::MIN } else { IBig::MAX });
+        } else {
+            assert_eq!(saturated, out);
+        }
+    });
+
+    natural_triple_gen().test_properties(|(x, y, z)| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let v = UBig::<N>::wrapping_from(&y);
+        let w = UBig::<N>::wrapping_from(&z);
+        assert_eq!(
+            u.wrapping_mul(v).wrapping_mul(w),
+            u.wrapping_mul(v.wrapping_mul(w))
+        );
+    });
+
+    integer_triple_gen().test_properties(|(x, y, z)| {
+        let u = IBig::<N>::wrapping_from(&x);
+        let v = IBig::<N>::wrapping_from(&y);
+        let w = IBig::<N>::wrapping_from(&z);
+        assert_eq!(
+            u.wrapping_mul(v).wrapping_mul(w),
+            u.wrapping_mul(v.wrapping_mul(w))
+        );
+    });
+}
+
+#[test]
+fn mul_properties() {
+    mul_properties_helper::<1>();
+    mul_properties_helper::<2>();
+    mul_properties_helper::<4>();
+}
@@ -0,0 +1,119 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedNeg, NegAssign, OverflowingNeg, OverflowingNegAssign, SaturatingNeg,
+    SaturatingNegAssign, WrappingAdd, WrappingMul, WrappingNeg, WrappingNegAssign,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_neg_ubig() {
+    let test = |s, out, overflow| {
+        let u = UBig::<LIMBS_128>::from_str(s).unwrap();
+        let out = UBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.overflowing_neg(), (out, overflow));
+        assert_eq!(u.wrapping_neg(), out);
+        let mut n = u;
+        assert_eq!(n.overflowing_neg_assign(), overflow);
+        assert_eq!(n, out);
+        let mut n = u;
+        n.wrapping_neg_assign();
+        assert_eq!(n, out);
+        assert_eq!(u.checked_neg(), if overflow { None } else { Some(out) });
+    };
+    test("0", "0", false);
+    test("1", "340282366920938463463374607431768211455", true);
+    test("340282366920938463463374607431768211455", "1", true);
+    test(
+        "18446744073709551616",
+        "340282366920938463444927863358058659840",
+        true,
+    );
+}
+
+#[test]
+fn test_neg_ibig() {
+    let test = |s, out, overflow| {
+        let u = IBig::<LIMBS_128>::from_str(s).unwrap();
+        let out = IBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.overflowing_neg(), (out, overflow));
+        assert_eq!(u.wrapping_neg(), out);
+        let mut n = u;
+        assert_eq!(n.overflowing_neg_assign(), overflow);
+        assert_eq!(n, out);
+        assert_eq!(u.checked_neg(), if overflow { None } else { Some(out) });
+        if !overflow {
+            assert_eq!(-u, out);
+            let mut n = u;
+            n.neg_assign();
+            assert_eq!(n, out);
+            assert_eq!(u.saturating_neg(), out);
+        }
+    };
+    test("0", "0", false);
+    test("123", "-123", false);
+    test("-123", "123", false);
+    test(
+        "170141183460469231731687303715884105727",
+        "-170141183460469231731687303715884105727",
+        false,
+    );
+    test(
+        "-170141183460469231731687303715884105728",
+        "-170141183460469231731687303715884105728",
+        true,
+    );
+    assert_eq!(IBig::<LIMBS_128>::MIN.saturating_neg(), IBig::MAX);
+    let mut n = IBig::<LIMBS_128>::MIN;
+    n.saturating_neg_assign();
+    assert_eq!(n, IBig::MAX);
+}
+
+#[test]
+fn neg_fail() {
+    assert_panic!(-IBig::<LIMBS_128>::MIN);
+    assert_panic!({
+        let mut x = IBig::<LIMBS_128>::MIN;
+        x.neg_assign();
+    });
+}
+
+fn neg_properties_helper<const N: usize>() {
+    natural_gen().test_properties(|x| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let (out, overflow) = u.overflowing_neg();
+        assert_eq!(overflow, u != UBig::ZERO);
+        assert_eq!(out.wrapping_add(u), UBig::ZERO);
+        assert_eq!(out.wrapping_neg(), u);
+        if overflow {
+            assert_eq!(
+                Natural::from(out),
+                Natural::from(UBig::<N>::MAX) + Natural::ONE - Natural::from(u)
+            );
+        }
+    });
+
+    integer_gen().test_properties(|x| {
+        let u = IBig::<N>::wrapping_from(&x);
+        let (out, overflow) = u.overflowing_neg();
+        assert_eq!(overflow, u == IBig::MIN);
+        assert_eq!(out, IBig::<N>::wrapping_from(&-Integer::from(u)));
+        assert_eq!(out.wrapping_neg(), u);
+        assert_eq!(out.wrapping_add(u), IBig::ZERO);
+        assert_eq!(u.wrapping_neg(), u.wrapping_mul(IBig::NEGATIVE_ONE));
+    });
+}
+
+#[test]
+fn neg_properties() {
+    neg_properties_helper::<1>();
+    neg_properties_helper::<2>();
+    neg_properties_helper::<4>();
+}
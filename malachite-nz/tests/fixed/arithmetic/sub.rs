@@ -0,0 +1,153 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{
+    CheckedSub, ModPowerOf2, OverflowingSub, OverflowingSubAssign, SaturatingSub,
+    SaturatingSubAssign, WrappingAdd, WrappingNeg, WrappingSub, WrappingSubAssign,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{integer_pair_gen, natural_pair_gen};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_sub_ubig() {
+    let test = |s, t, out, overflow| {
+        let u = UBig::<LIMBS_128>::from_str(s).unwrap();
+        let v = UBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = UBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.overflowing_sub(v), (out, overflow));
+        assert_eq!(u.wrapping_sub(v), out);
+        let mut n = u;
+        assert_eq!(n.overflowing_sub_assign(v), overflow);
+        assert_eq!(n, out);
+        let mut n = u;
+        n.wrapping_sub_assign(v);
+        assert_eq!(n, out);
+        if overflow {
+            assert_eq!(u.checked_sub(v), None);
+            assert_eq!(u.saturating_sub(v), UBig::ZERO);
+        } else {
+            assert_eq!(u - v, out);
+            let mut n = u;
+            n -= v;
+            assert_eq!(n, out);
+            assert_eq!(u.checked_sub(v), Some(out));
+            assert_eq!(u.saturating_sub(v), out);
+        }
+    };
+    test("0", "0", "0", false);
+    test("456", "123", "333", false);
+    test("18446744073709551616", "1", "18446744073709551615", false);
+    test("0", "1", "340282366920938463463374607431768211455", true);
+    test(
+        "123",
+        "456",
+        "340282366920938463463374607431768211123",
+        true,
+    );
+}
+
+#[test]
+fn test_sub_ibig() {
+    let test = |s, t, out, overflow| {
+        let u = IBig::<LIMBS_128>::from_str(s).unwrap();
+        let v = IBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = IBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u.overflowing_sub(v), (out, overflow));
+        assert_eq!(u.wrapping_sub(v), out);
+        let mut n = u;
+        assert_eq!(n.overflowing_sub_assign(v), overflow);
+        assert_eq!(n, out);
+        if !overflow {
+            assert_eq!(u - v, out);
+            assert_eq!(u.checked_sub(v), Some(out));
+            assert_eq!(u.saturating_sub(v), out);
+        } else {
+            assert_eq!(u.checked_sub(v), None);
+        }
+    };
+    test("0", "0", "0", false);
+    test("123", "456", "-333", false);
+    test("-123", "-456", "333", false);
+    test(
+        "-170141183460469231731687303715884105728",
+        "1",
+        "170141183460469231731687303715884105727",
+        true,
+    );
+    test(
+        "0",
+        "-170141183460469231731687303715884105728",
+        "-170141183460469231731687303715884105728",
+        true,
+    );
+    test(
+        "-1",
+        "-170141183460469231731687303715884105728",
+        "170141183460469231731687303715884105727",
+        false,
+    );
+    assert_eq!(IBig::<LIMBS_128>::MIN.saturating_sub(IBig::ONE), IBig::MIN);
+    let mut n = IBig::<LIMBS_128>::ZERO;
+    n.saturating_sub_assign(IBig::MIN);
+    assert_eq!(n, IBig::MAX);
+}
+
+#[test]
+fn sub_fail() {
+    assert_panic!(UBig::<LIMBS_128>::ZERO - UBig::ONE);
+    assert_panic!(IBig::<LIMBS_128>::MIN - IBig::ONE);
+    assert_panic!({
+        let mut x = UBig::<LIMBS_128>::ONE;
+        x -= UBig::MAX;
+    });
+}
+
+fn sub_properties_helper<const N: usize>() {
+    natural_pair_gen().test_properties(|(x, y)| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let v = UBig::<N>::wrapping_from(&y);
+        let (out, overflow) = u.overflowing_sub(v);
+        assert_eq!(overflow, u < v);
+        if !overflow {
+            assert_eq!(Natural::from(out), Natural::from(u) - Natural::from(v));
+        }
+        assert_eq!(
+            Natural::from(out),
+            (Natural::from(u) + Natural::from(UBig::<N>::MAX) + Natural::ONE - Natural::from(v))
+                .mod_power_of_2(UBig::<N>::WIDTH)
+        );
+        assert_eq!(out.wrapping_add(v), u);
+        assert_eq!(v.wrapping_sub(u), out.wrapping_neg());
+        assert_eq!(u.checked_sub(v).is_none(), overflow);
+        assert!(u.saturating_sub(v) <= u);
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        let u = IBig::<N>::wrapping_from(&x);
+        let v = IBig::<N>::wrapping_from(&y);
+        let difference = Integer::from(u) - Integer::from(v);
+        let (out, overflow) = u.overflowing_sub(v);
+        assert_eq!(out, IBig::<N>::wrapping_from(&difference));
+        assert_eq!(overflow, Integer::from(out) != difference);
+        assert_eq!(out.wrapping_add(v), u);
+        assert_eq!(u.checked_sub(v).is_none(), overflow);
+        let saturated = u.saturating_sub(v);
+        if overflow {
+            assert!(saturated == IBig::MAX || saturated == IBig::MIN);
+        } else {
+            assert_eq!(saturated, out);
+        }
+    });
+}
+
+#[test]
+fn sub_properties() {
+    sub_properties_helper::<1>();
+    sub_properties_helper::<2>();
+    sub_properties_helper::<4>();
+}
@@ -0,0 +1,96 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::basic::traits::{NegativeOne, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{integer_pair_gen, natural_pair_gen};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[test]
+fn test_cmp_ubig() {
+    let strings = &[
+        "0",
+        "1",
+        "2",
+        "123",
+        "999999999999",
+        "1000000000000",
+        "18446744073709551616",
+        "340282366920938463463374607431768211455",
+    ];
+    let xs = strings
+        .iter()
+        .map(|s| UBig::<LIMBS_128>::from_str(s).unwrap())
+        .collect::<Vec<_>>();
+    for (i, x) in xs.iter().enumerate() {
+        for (j, y) in xs.iter().enumerate() {
+            assert_eq!(x.cmp(y), i.cmp(&j));
+            assert_eq!(x.partial_cmp(y), Some(i.cmp(&j)));
+        }
+    }
+}
+
+#[test]
+fn test_cmp_ibig() {
+    let strings = &[
+        "-170141183460469231731687303715884105728",
+        "-18446744073709551616",
+        "-1000000000000",
+        "-123",
+        "-1",
+        "0",
+        "1",
+        "123",
+        "1000000000000",
+        "18446744073709551616",
+        "170141183460469231731687303715884105727",
+    ];
+    let xs = strings
+        .iter()
+        .map(|s| IBig::<LIMBS_128>::from_str(s).unwrap())
+        .collect::<Vec<_>>();
+    for (i, x) in xs.iter().enumerate() {
+        for (j, y) in xs.iter().enumerate() {
+            assert_eq!(x.cmp(y), i.cmp(&j));
+            assert_eq!(x.partial_cmp(y), Some(i.cmp(&j)));
+        }
+    }
+}
+
+fn cmp_properties_helper<const N: usize>() {
+    natural_pair_gen().test_properties(|(x, y)| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let v = UBig::<N>::wrapping_from(&y);
+        let ord = u.cmp(&v);
+        assert_eq!(Natural::from(u).cmp(&Natural::from(v)), ord);
+        assert_eq!(v.cmp(&u), ord.reverse());
+        assert_eq!(u == v, ord == Ordering::Equal);
+        assert!(UBig::MIN <= u);
+        assert!(u <= UBig::MAX);
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        let u = IBig::<N>::wrapping_from(&x);
+        let v = IBig::<N>::wrapping_from(&y);
+        let ord = u.cmp(&v);
+        assert_eq!(Integer::from(u).cmp(&Integer::from(v)), ord);
+        assert_eq!(v.cmp(&u), ord.reverse());
+        assert_eq!(u == v, ord == Ordering::Equal);
+        assert!(IBig::MIN <= u);
+        assert!(u <= IBig::MAX);
+    });
+
+    assert!(IBig::<N>::NEGATIVE_ONE < IBig::ZERO);
+    assert!(IBig::<N>::MIN < IBig::NEGATIVE_ONE);
+    assert!(UBig::<N>::ZERO < UBig::MAX);
+}
+
+#[test]
+fn cmp_properties() {
+    cmp_properties_helper::<1>();
+    cmp_properties_helper::<2>();
+    cmp_properties_helper::<4>();
+}
@@ -0,0 +1,55 @@
+use malachite_base::num::basic::traits::{NegativeOne, Zero};
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen};
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+
+#[test]
+fn test_from_u32() {
+    let test = |u: u32, out| {
+        let x = UBig::<LIMBS_128>::from(u);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(UBig::<1>::from(u).to_string(), out);
+    };
+    test(0, "0");
+    test(123, "123");
+    test(u32::MAX, "4294967295");
+}
+
+#[test]
+fn test_from_i32() {
+    let test = |i: i32, out| {
+        let x = IBig::<LIMBS_128>::from(i);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(IBig::<1>::from(i).to_string(), out);
+    };
+    test(0, "0");
+    test(123, "123");
+    test(-123, "-123");
+    test(i32::MIN, "-2147483648");
+    test(i32::MAX, "2147483647");
+}
+
+fn from_primitive_int_properties_helper<const N: usize>() {
+    unsigned_gen::<u32>().test_properties(|u| {
+        let x = UBig::<N>::from(u);
+        assert_eq!(Natural::from(x), u);
+    });
+
+    signed_gen::<i32>().test_properties(|i| {
+        let x = IBig::<N>::from(i);
+        assert_eq!(Integer::from(x), i);
+        assert_eq!(x < IBig::ZERO, i < 0);
+    });
+
+    assert_eq!(UBig::<N>::from(0u32), UBig::ZERO);
+    assert_eq!(IBig::<N>::from(-1), IBig::NEGATIVE_ONE);
+}
+
+#[test]
+fn from_primitive_int_properties() {
+    from_primitive_int_properties_helper::<1>();
+    from_primitive_int_properties_helper::<2>();
+    from_primitive_int_properties_helper::<4>();
+}
@@ -0,0 +1,98 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::basic::traits::{NegativeOne, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_ubig_from_ibig() {
+    let test = |s, out: Option<&str>, out_wrapped| {
+        let x = IBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!(
+            UBig::try_from(x).ok(),
+            out.map(|s| UBig::<LIMBS_128>::from_str(s).unwrap())
+        );
+        assert_eq!(UBig::wrapping_from(x).to_string(), out_wrapped);
+    };
+    test("0", Some("0"), "0");
+    test("123", Some("123"), "123");
+    test("-1", None, "340282366920938463463374607431768211455");
+    test(
+        "-170141183460469231731687303715884105728",
+        None,
+        "170141183460469231731687303715884105728",
+    );
+    test(
+        "170141183460469231731687303715884105727",
+        Some("170141183460469231731687303715884105727"),
+        "170141183460469231731687303715884105727",
+    );
+}
+
+#[test]
+fn test_ibig_from_ubig() {
+    let test = |s, out: Option<&str>, out_wrapped| {
+        let x = UBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!(
+            IBig::try_from(x).ok(),
+            out.map(|s| IBig::<LIMBS_128>::from_str(s).unwrap())
+        );
+        assert_eq!(IBig::wrapping_from(x).to_string(), out_wrapped);
+    };
+    test("0", Some("0"), "0");
+    test("123", Some("123"), "123");
+    test(
+        "170141183460469231731687303715884105727",
+        Some("170141183460469231731687303715884105727"),
+        "170141183460469231731687303715884105727",
+    );
+    test(
+        "170141183460469231731687303715884105728",
+        None,
+        "-170141183460469231731687303715884105728",
+    );
+    test("340282366920938463463374607431768211455", None, "-1");
+}
+
+fn ibig_and_ubig_properties_helper<const N: usize>() {
+    natural_gen().test_properties(|x| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let i = IBig::<N>::wrapping_from(u);
+        assert_eq!(UBig::wrapping_from(i), u);
+        assert_eq!(
+            IBig::try_from(u).is_ok(),
+            u <= UBig::wrapping_from(IBig::<N>::MAX)
+        );
+        if let Ok(i) = IBig::try_from(u) {
+            assert_eq!(Integer::from(i), Natural::from(u));
+        }
+    });
+
+    integer_gen().test_properties(|x| {
+        let i = IBig::<N>::wrapping_from(&x);
+        let u = UBig::<N>::wrapping_from(i);
+        assert_eq!(IBig::wrapping_from(u), i);
+        assert_eq!(UBig::try_from(i).is_ok(), i >= IBig::ZERO);
+        if let Ok(u) = UBig::try_from(i) {
+            assert_eq!(Integer::from(i), Natural::from(u));
+        }
+    });
+
+    assert_eq!(UBig::wrapping_from(IBig::<N>::NEGATIVE_ONE), UBig::MAX);
+    assert_eq!(IBig::wrapping_from(UBig::<N>::MAX), IBig::NEGATIVE_ONE);
+    assert_eq!(
+        IBig::wrapping_from(UBig::wrapping_from(IBig::<N>::MAX) + UBig::from(1u32)),
+        IBig::MIN
+    );
+}
+
+#[test]
+fn ibig_and_ubig_properties() {
+    ibig_and_ubig_properties_helper::<1>();
+    ibig_and_ubig_properties_helper::<2>();
+    ibig_and_ubig_properties_helper::<4>();
+}
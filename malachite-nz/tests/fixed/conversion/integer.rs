@@ -0,0 +1,102 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::arithmetic::traits::{PowerOf2, Sign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{ConvertibleFrom, WrappingFrom};
+use malachite_nz::fixed::IBig;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::integer_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_integer_from_ibig() {
+    let test = |s| {
+        let x = IBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!(Integer::from(x).to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("-123");
+    test("-1000000000000");
+    test("-18446744073709551616");
+    test("170141183460469231731687303715884105727");
+    test("-170141183460469231731687303715884105728");
+}
+
+#[test]
+fn test_ibig_from_integer() {
+    let test = |s, out: Option<&str>, out_wrapped| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(
+            IBig::<LIMBS_128>::try_from(&x)
+                .ok()
+                .map(|i| i.to_string())
+                .as_deref(),
+            out
+        );
+        assert_eq!(IBig::<LIMBS_128>::convertible_from(&x), out.is_some());
+        assert_eq!(
+            IBig::<LIMBS_128>::wrapping_from(&x).to_string(),
+            out_wrapped
+        );
+    };
+    test("0", Some("0"), "0");
+    test("-123", Some("-123"), "-123");
+    test(
+        "170141183460469231731687303715884105727",
+        Some("170141183460469231731687303715884105727"),
+        "170141183460469231731687303715884105727",
+    );
+    test(
+        "-170141183460469231731687303715884105728",
+        Some("-170141183460469231731687303715884105728"),
+        "-170141183460469231731687303715884105728",
+    );
+    test(
+        "170141183460469231731687303715884105728",
+        None,
+        "-170141183460469231731687303715884105728",
+    );
+    test(
+        "-170141183460469231731687303715884105729",
+        None,
+        "170141183460469231731687303715884105727",
+    );
+    test("-340282366920938463463374607431768211457", None, "-1");
+}
+
+fn integer_properties_helper<const N: usize>() {
+    integer_gen().test_properties(|x| {
+        let i = IBig::<N>::wrapping_from(&x);
+        let modulus = Integer::power_of_2(IBig::<N>::WIDTH);
+        let y = Integer::from(i);
+        assert_eq!((&x - &y) % &modulus, 0);
+        assert!(y >= Integer::from(IBig::<N>::MIN));
+        assert!(y <= Integer::from(IBig::<N>::MAX));
+        assert_eq!(i.cmp(&IBig::ZERO), y.sign());
+        let convertible = IBig::<N>::convertible_from(&x);
+        assert_eq!(
+            convertible,
+            x >= Integer::from(IBig::<N>::MIN) && x <= Integer::from(IBig::<N>::MAX)
+        );
+        assert_eq!(IBig::<N>::try_from(&x).is_ok(), convertible);
+        if convertible {
+            assert_eq!(IBig::<N>::try_from(&x).unwrap(), i);
+            assert_eq!(y, x);
+        }
+    });
+
+    let max = Integer::from(IBig::<N>::MAX);
+    assert_eq!(
+        max,
+        Integer::power_of_2(IBig::<N>::WIDTH - 1) - Integer::ONE
+    );
+    assert_eq!(Integer::from(IBig::<N>::MIN), -(max + Integer::ONE));
+}
+
+#[test]
+fn integer_properties() {
+    integer_properties_helper::<1>();
+    integer_properties_helper::<2>();
+    integer_properties_helper::<4>();
+}
@@ -0,0 +1,78 @@
+#[cfg(feature = "32_bit_limbs")]
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+#[cfg(feature = "32_bit_limbs")]
+use std::str::FromStr;
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_ubig_limbs_asc() {
+    let test = |xs: [u32; 3], out| {
+        let x = UBig::from_limbs_asc(xs);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_limbs_asc(), xs);
+        assert_eq!(UBig::<3>::from_str(out).unwrap(), x);
+    };
+    test([0, 0, 0], "0");
+    test([123, 0, 0], "123");
+    test([3567587328, 232, 0], "1000000000000");
+    test([1, 2, 3], "55340232229718589441");
+    test([u32::MAX; 3], "79228162514264337593543950335");
+    assert_eq!(UBig::<3>::MAX.to_limbs_asc(), [u32::MAX; 3]);
+}
+
+#[cfg(feature = "32_bit_limbs")]
+#[test]
+fn test_ibig_twos_complement_limbs_asc() {
+    let test = |xs: [u32; 2], out| {
+        let x = IBig::from_twos_complement_limbs_asc(xs);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_twos_complement_limbs_asc(), xs);
+        assert_eq!(IBig::<2>::from_str(out).unwrap(), x);
+    };
+    test([0, 0], "0");
+    test([123, 0], "123");
+    test([4294967173, u32::MAX], "-123");
+    test([3567587328, 232], "1000000000000");
+    test([727379968, 4294967063], "-1000000000000");
+    test([0, 0x80000000], "-9223372036854775808");
+    test([u32::MAX, 0x7fffffff], "9223372036854775807");
+    assert_eq!(
+        IBig::<2>::MIN.to_twos_complement_limbs_asc(),
+        [0, 0x80000000]
+    );
+    assert_eq!(
+        IBig::<2>::MAX.to_twos_complement_limbs_asc(),
+        [u32::MAX, 0x7fffffff]
+    );
+}
+
+fn limbs_properties_helper<const N: usize>() {
+    natural_gen().test_properties(|x| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let xs = u.to_limbs_asc();
+        assert_eq!(UBig::from_limbs_asc(xs), u);
+        assert_eq!(Natural::from_limbs_asc(&xs), Natural::from(u));
+    });
+
+    integer_gen().test_properties(|x| {
+        let i = IBig::<N>::wrapping_from(&x);
+        let xs = i.to_twos_complement_limbs_asc();
+        assert_eq!(IBig::from_twos_complement_limbs_asc(xs), i);
+        assert_eq!(
+            Integer::from_twos_complement_limbs_asc(&xs),
+            Integer::from(i)
+        );
+    });
+}
+
+#[test]
+fn limbs_properties() {
+    limbs_properties_helper::<1>();
+    limbs_properties_helper::<2>();
+    limbs_properties_helper::<4>();
+}
@@ -0,0 +1,80 @@
+use malachite_base::comparison::traits::Max;
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, WrappingFrom};
+use malachite_nz::fixed::UBig;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_natural_from_ubig() {
+    let test = |s| {
+        let x = UBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!(Natural::from(x).to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("1000000000000");
+    test("18446744073709551616");
+    test("340282366920938463463374607431768211455");
+}
+
+#[test]
+fn test_ubig_from_natural() {
+    let test = |s, out: Option<&str>, out_wrapped| {
+        let x = Natural::from_str(s).unwrap();
+        assert_eq!(
+            UBig::<LIMBS_128>::try_from(&x)
+                .ok()
+                .map(|u| u.to_string())
+                .as_deref(),
+            out
+        );
+        assert_eq!(UBig::<LIMBS_128>::convertible_from(&x), out.is_some());
+        assert_eq!(
+            UBig::<LIMBS_128>::wrapping_from(&x).to_string(),
+            out_wrapped
+        );
+    };
+    test("0", Some("0"), "0");
+    test("123", Some("123"), "123");
+    test("1000000000000", Some("1000000000000"), "1000000000000");
+    test(
+        "340282366920938463463374607431768211455",
+        Some("340282366920938463463374607431768211455"),
+        "340282366920938463463374607431768211455",
+    );
+    test("340282366920938463463374607431768211456", None, "0");
+    test(
+        "1000000000000000000000000000000000000000000",
+        None,
+        "250405986282794344605403365464994742272",
+    );
+}
+
+fn natural_properties_helper<const N: usize>() {
+    natural_gen().test_properties(|x| {
+        let u = UBig::<N>::wrapping_from(&x);
+        assert_eq!(Natural::from(u), &x % Natural::power_of_2(UBig::<N>::WIDTH));
+        let convertible = UBig::<N>::convertible_from(&x);
+        assert_eq!(convertible, x <= Natural::from(UBig::<N>::MAX));
+        assert_eq!(UBig::<N>::try_from(&x).is_ok(), convertible);
+        if convertible {
+            assert_eq!(UBig::<N>::try_from(&x).unwrap(), u);
+            assert_eq!(Natural::from(u), x);
+        }
+    });
+
+    let max = Natural::from(UBig::<N>::MAX);
+    assert_eq!(max, Natural::power_of_2(UBig::<N>::WIDTH) - Natural::ONE);
+    assert!(UBig::<N>::try_from(&(max + Natural::ONE)).is_err());
+}
+
+#[test]
+fn natural_properties() {
+    natural_properties_helper::<1>();
+    natural_properties_helper::<2>();
+    natural_properties_helper::<4>();
+}
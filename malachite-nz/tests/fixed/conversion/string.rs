@@ -0,0 +1,138 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_ubig_to_string() {
+    let test = |s, binary, octal, hex: &str| {
+        let x = UBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!(x.to_string(), s);
+        assert_eq!(x.to_debug_string(), s);
+        assert_eq!(format!("{x:b}"), binary);
+        assert_eq!(format!("{x:o}"), octal);
+        assert_eq!(format!("{x:x}"), hex);
+        assert_eq!(format!("{x:X}"), hex.to_uppercase());
+    };
+    test("0", "0", "0", "0");
+    test("123", "1111011", "173", "7b");
+    test(
+        "1000000000000",
+        "1110100011010100101001010001000000000000",
+        "16432451210000",
+        "e8d4a51000",
+    );
+    test(
+        "340282366920938463463374607431768211455",
+        "1111111111111111111111111111111111111111111111111111111111111111\
+        1111111111111111111111111111111111111111111111111111111111111111",
+        "3777777777777777777777777777777777777777777",
+        "ffffffffffffffffffffffffffffffff",
+    );
+    assert_eq!(format!("{:#x}", UBig::<LIMBS_128>::from(255u32)), "0xff");
+    assert_eq!(
+        format!("{:08}", UBig::<LIMBS_128>::from(255u32)),
+        "00000255"
+    );
+}
+
+#[test]
+fn test_ibig_to_string() {
+    let test = |s| {
+        let x = IBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!(x.to_string(), s);
+        assert_eq!(x.to_debug_string(), s);
+    };
+    test("0");
+    test("123");
+    test("-123");
+    test("-1000000000000");
+    test("170141183460469231731687303715884105727");
+    test("-170141183460469231731687303715884105728");
+}
+
+#[test]
+fn test_from_str() {
+    let test_ubig = |s, out: Option<&str>| {
+        assert_eq!(
+            UBig::<LIMBS_128>::from_str(s)
+                .ok()
+                .map(|x| x.to_string())
+                .as_deref(),
+            out
+        );
+    };
+    test_ubig("0", Some("0"));
+    test_ubig("00123", Some("123"));
+    test_ubig(
+        "340282366920938463463374607431768211455",
+        Some("340282366920938463463374607431768211455"),
+    );
+    test_ubig("340282366920938463463374607431768211456", None);
+    test_ubig("", None);
+    test_ubig("-1", None);
+    test_ubig("12a", None);
+
+    let test_ibig = |s, out: Option<&str>| {
+        assert_eq!(
+            IBig::<LIMBS_128>::from_str(s)
+                .ok()
+                .map(|x| x.to_string())
+                .as_deref(),
+            out
+        );
+    };
+    test_ibig("0", Some("0"));
+    test_ibig("-00123", Some("-123"));
+    test_ibig(
+        "-170141183460469231731687303715884105728",
+        Some("-170141183460469231731687303715884105728"),
+    );
+    test_ibig("170141183460469231731687303715884105728", None);
+    test_ibig("-170141183460469231731687303715884105729", None);
+    test_ibig("", None);
+    test_ibig("-", None);
+    test_ibig("--1", None);
+}
+
+fn string_properties_helper<const N: usize>() {
+    natural_gen().test_properties(|x| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let n = Natural::from(u);
+        let s = u.to_string();
+        assert_eq!(s, n.to_string());
+        assert_eq!(UBig::<N>::from_str(&s).unwrap(), u);
+        assert_eq!(format!("{u:b}"), format!("{n:b}"));
+        assert_eq!(format!("{u:#o}"), format!("{n:#o}"));
+        assert_eq!(format!("{u:x}"), format!("{n:x}"));
+        assert_eq!(format!("{u:#X}"), format!("{n:#X}"));
+    });
+
+    integer_gen().test_properties(|x| {
+        let i = IBig::<N>::wrapping_from(&x);
+        let s = i.to_string();
+        assert_eq!(s, Integer::from(i).to_string());
+        assert_eq!(IBig::<N>::from_str(&s).unwrap(), i);
+    });
+
+    assert_eq!(
+        UBig::<N>::from_str(&UBig::<N>::MAX.to_string()).unwrap(),
+        UBig::MAX
+    );
+    assert_eq!(
+        IBig::<N>::from_str(&IBig::<N>::MIN.to_string()).unwrap(),
+        IBig::MIN
+    );
+}
+
+#[test]
+fn string_properties() {
+    string_properties_helper::<1>();
+    string_properties_helper::<2>();
+    string_properties_helper::<4>();
+}
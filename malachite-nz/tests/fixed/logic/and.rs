@@ -0,0 +1,84 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::basic::traits::{NegativeOne, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{integer_pair_gen, natural_pair_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_and_ubig() {
+    let test = |s, t, out| {
+        let u = UBig::<LIMBS_128>::from_str(s).unwrap();
+        let v = UBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = UBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u & v, out);
+        let mut n = u;
+        n &= v;
+        assert_eq!(n, out);
+    };
+    test("0", "123", "0");
+    test("123", "456", "72");
+    test("1000000000000", "999999999999", "999999995904");
+    test(
+        "340282366920938463463374607431768211455",
+        "18446744073709551616",
+        "18446744073709551616",
+    );
+}
+
+#[test]
+fn test_and_ibig() {
+    let test = |s, t, out| {
+        let u = IBig::<LIMBS_128>::from_str(s).unwrap();
+        let v = IBig::<LIMBS_128>::from_str(t).unwrap();
+        let out = IBig::<LIMBS_128>::from_str(out).unwrap();
+        assert_eq!(u & v, out);
+        let mut n = u;
+        n &= v;
+        assert_eq!(n, out);
+    };
+    test("-123", "456", "384");
+    test("-123", "-456", "-512");
+    test("1000000000000", "-1", "1000000000000");
+    test(
+        "-170141183460469231731687303715884105728",
+        "-1",
+        "-170141183460469231731687303715884105728",
+    );
+}
+
+fn and_properties_helper<const N: usize>() {
+    natural_pair_gen().test_properties(|(x, y)| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let v = UBig::<N>::wrapping_from(&y);
+        let result = u & v;
+        assert_eq!(Natural::from(result), Natural::from(u) & Natural::from(v));
+        assert_eq!(v & u, result);
+        let mut n = u;
+        n &= v;
+        assert_eq!(n, result);
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        let u = IBig::<N>::wrapping_from(&x);
+        let v = IBig::<N>::wrapping_from(&y);
+        let result = u & v;
+        assert_eq!(Integer::from(result), Integer::from(u) & Integer::from(v));
+        assert_eq!(IBig::<N>::wrapping_from(&(x & y)), result);
+        assert_eq!(v & u, result);
+    });
+
+    assert_eq!(UBig::<N>::MAX & UBig::ZERO, UBig::ZERO);
+    assert_eq!(IBig::<N>::MIN & IBig::NEGATIVE_ONE, IBig::MIN);
+    assert_eq!(IBig::<N>::MIN & IBig::MAX, IBig::ZERO);
+}
+
+#[test]
+fn and_properties() {
+    and_properties_helper::<1>();
+    and_properties_helper::<2>();
+    and_properties_helper::<4>();
+}
@@ -0,0 +1,159 @@
+use malachite_base::comparison::traits::Max;
+use malachite_base::num::basic::traits::{NegativeOne, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::logic::traits::BitAccess;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{
+    integer_unsigned_pair_gen_var_2, natural_unsigned_pair_gen_var_4,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_bit_access_ubig() {
+    let test = |s, index, get, set, clear, flip| {
+        let u = UBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!(u.get_bit(index), get);
+        let mut n = u;
+        n.set_bit(index);
+        assert_eq!(n.to_string(), set);
+        let mut n = u;
+        n.clear_bit(index);
+        assert_eq!(n.to_string(), clear);
+        let mut n = u;
+        n.flip_bit(index);
+        assert_eq!(n.to_string(), flip);
+    };
+    test("0", 0, false, "1", "0", "1");
+    test("123", 2, false, "127", "123", "127");
+    test("123", 3, true, "123", "115", "115");
+    test(
+        "1000000000000",
+        100,
+        false,
+        "1267650600228229402496703205376",
+        "1000000000000",
+        "1267650600228229402496703205376",
+    );
+    test(
+        "340282366920938463463374607431768211455",
+        127,
+        true,
+        "340282366920938463463374607431768211455",
+        "170141183460469231731687303715884105727",
+        "170141183460469231731687303715884105727",
+    );
+    assert!(!UBig::<LIMBS_128>::MAX.get_bit(128));
+    let mut n = UBig::<LIMBS_128>::MAX;
+    n.clear_bit(1000);
+    assert_eq!(n, UBig::MAX);
+}
+
+#[test]
+fn test_bit_access_ibig() {
+    let test = |s, index, get, set, clear| {
+        let i = IBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!(i.get_bit(index), get);
+        let mut n = i;
+        n.set_bit(index);
+        assert_eq!(n.to_string(), set);
+        let mut n = i;
+        n.clear_bit(index);
+        assert_eq!(n.to_string(), clear);
+    };
+    test("0", 0, false, "1", "0");
+    test("-123", 2, true, "-123", "-127");
+    test("-123", 0, true, "-123", "-124");
+    test("-123", 1, false, "-121", "-123");
+    test(
+        "0",
+        127,
+        false,
+        "-170141183460469231731687303715884105728",
+        "0",
+    );
+    test(
+        "-1",
+        127,
+        true,
+        "-1",
+        "170141183460469231731687303715884105727",
+    );
+    assert!(IBig::<LIMBS_128>::NEGATIVE_ONE.get_bit(1000));
+    let mut n = IBig::<LIMBS_128>::NEGATIVE_ONE;
+    n.set_bit(1000);
+    assert_eq!(n, IBig::NEGATIVE_ONE);
+    let mut n = IBig::<LIMBS_128>::from(123);
+    n.clear_bit(1000);
+    assert_eq!(n, IBig::from(123));
+}
+
+#[test]
+fn bit_access_fail() {
+    assert_panic!({
+        let mut n = UBig::<LIMBS_128>::ZERO;
+        n.set_bit(128);
+    });
+    assert_panic!({
+        let mut n = IBig::<LIMBS_128>::ZERO;
+        n.set_bit(128);
+    });
+    assert_panic!({
+        let mut n = IBig::<LIMBS_128>::NEGATIVE_ONE;
+        n.clear_bit(128);
+    });
+}
+
+fn bit_access_properties_helper<const N: usize>() {
+    natural_unsigned_pair_gen_var_4::<u64>().test_properties(|(x, index)| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let index = index % UBig::<N>::WIDTH;
+        assert_eq!(u.get_bit(index), Natural::from(u).get_bit(index));
+        let mut set = u;
+        set.set_bit(index);
+        assert!(set.get_bit(index));
+        let mut alt = Natural::from(u);
+        alt.set_bit(index);
+        assert_eq!(Natural::from(set), alt);
+        let mut clear = u;
+        clear.clear_bit(index);
+        assert!(!clear.get_bit(index));
+        let mut alt = Natural::from(u);
+        alt.clear_bit(index);
+        assert_eq!(Natural::from(clear), alt);
+        assert!(!u.get_bit(index + UBig::<N>::WIDTH));
+    });
+
+    integer_unsigned_pair_gen_var_2::<u64>().test_properties(|(x, index)| {
+        let i = IBig::<N>::wrapping_from(&x);
+        assert_eq!(i.get_bit(index), Integer::from(i).get_bit(index));
+        if index < IBig::<N>::WIDTH {
+            let mut set = i;
+            set.set_bit(index);
+            assert!(set.get_bit(index));
+            let mut alt = Integer::from(i);
+            alt.set_bit(index);
+            assert_eq!(set, IBig::wrapping_from(&alt));
+            let mut clear = i;
+            clear.clear_bit(index);
+            assert!(!clear.get_bit(index));
+            let mut alt = Integer::from(i);
+            alt.clear_bit(index);
+            assert_eq!(clear, IBig::wrapping_from(&alt));
+        }
+        assert_eq!(i.get_bit(index + IBig::<N>::WIDTH), i < IBig::ZERO);
+    });
+
+    assert!(UBig::<N>::MAX.get_bit(UBig::<N>::WIDTH - 1));
+    assert!(IBig::<N>::NEGATIVE_ONE.get_bit(IBig::<N>::WIDTH));
+}
+
+#[test]
+fn bit_access_properties() {
+    bit_access_properties_helper::<1>();
+    bit_access_properties_helper::<2>();
+    bit_access_properties_helper::<4>();
+}
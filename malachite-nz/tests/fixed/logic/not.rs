@@ -0,0 +1,64 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::basic::traits::{NegativeOne, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_not() {
+    let test_ubig = |s, out| {
+        let u = UBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!((!u).to_string(), out);
+    };
+    test_ubig("0", "340282366920938463463374607431768211455");
+    test_ubig("123", "340282366920938463463374607431768211332");
+    test_ubig("340282366920938463463374607431768211455", "0");
+
+    let test_ibig = |s, out| {
+        let i = IBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!((!i).to_string(), out);
+    };
+    test_ibig("0", "-1");
+    test_ibig("123", "-124");
+    test_ibig("-123", "122");
+    test_ibig(
+        "-170141183460469231731687303715884105728",
+        "170141183460469231731687303715884105727",
+    );
+}
+
+fn not_properties_helper<const N: usize>() {
+    natural_gen().test_properties(|x| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let not = !u;
+        assert_eq!(!not, u);
+        assert_eq!(
+            Natural::from(not),
+            Natural::from(UBig::<N>::MAX) - Natural::from(u)
+        );
+        assert_eq!(u & not, UBig::ZERO);
+    });
+
+    integer_gen().test_properties(|x| {
+        let i = IBig::<N>::wrapping_from(&x);
+        let not = !i;
+        assert_eq!(!not, i);
+        assert_eq!(Integer::from(not), !Integer::from(i));
+        assert_eq!(IBig::<N>::wrapping_from(&!x), not);
+    });
+
+    assert_eq!(!UBig::<N>::ZERO, UBig::MAX);
+    assert_eq!(!IBig::<N>::ZERO, IBig::NEGATIVE_ONE);
+    assert_eq!(!IBig::<N>::MIN, IBig::MAX);
+}
+
+#[test]
+fn not_properties() {
+    not_properties_helper::<1>();
+    not_properties_helper::<2>();
+    not_properties_helper::<4>();
+}
@@ -0,0 +1,87 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{
+    integer_unsigned_pair_gen_var_2, natural_unsigned_pair_gen_var_4,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_shl_ubig() {
+    let test = |s, bits: u64, out| {
+        let u = UBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!((u << bits).to_string(), out);
+        let mut n = u;
+        n <<= bits;
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", 10, "0");
+    test("123", 0, "123");
+    test("123", 1, "246");
+    test("123", 100, "155921023828072216384094494261248");
+    test("1000000000000", 64, "18446744073709551616000000000000");
+    test("1", 127, "170141183460469231731687303715884105728");
+    test("3", 127, "170141183460469231731687303715884105728");
+    test("1", 128, "0");
+    test("123", 1000, "0");
+}
+
+#[test]
+fn test_shl_ibig() {
+    let test = |s, bits: u64, out| {
+        let i = IBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!((i << bits).to_string(), out);
+        let mut n = i;
+        n <<= bits;
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", 10, "0");
+    test("-123", 0, "-123");
+    test("-123", 1, "-246");
+    test("-123", 100, "-155921023828072216384094494261248");
+    test("1", 127, "-170141183460469231731687303715884105728");
+    test("-1", 127, "-170141183460469231731687303715884105728");
+    test("-1", 128, "0");
+}
+
+fn shl_properties_helper<const N: usize>() {
+    natural_unsigned_pair_gen_var_4::<u64>().test_properties(|(x, bits)| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let shifted = u << bits;
+        assert_eq!(
+            Natural::from(shifted),
+            (Natural::from(u) << bits) % Natural::power_of_2(UBig::<N>::WIDTH)
+        );
+        let mut n = u;
+        n <<= bits;
+        assert_eq!(n, shifted);
+        assert_eq!(u << (bits + UBig::<N>::WIDTH), UBig::ZERO);
+    });
+
+    integer_unsigned_pair_gen_var_2::<u64>().test_properties(|(x, bits)| {
+        let i = IBig::<N>::wrapping_from(&x);
+        let shifted = i << bits;
+        assert_eq!(
+            IBig::<N>::wrapping_from(&(Integer::from(i) << bits)),
+            shifted
+        );
+        assert_eq!(IBig::<N>::wrapping_from(&(x << bits)), shifted);
+        let mut n = i;
+        n <<= bits;
+        assert_eq!(n, shifted);
+    });
+
+    assert_eq!(UBig::<N>::ONE << (UBig::<N>::WIDTH - 1) << 1, UBig::ZERO);
+    assert_eq!(IBig::<N>::ONE << IBig::<N>::WIDTH, IBig::ZERO);
+}
+
+#[test]
+fn shl_properties() {
+    shl_properties_helper::<1>();
+    shl_properties_helper::<2>();
+    shl_properties_helper::<4>();
+}
@@ -0,0 +1,96 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::basic::traits::{NegativeOne, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{
+    integer_unsigned_pair_gen_var_2, natural_unsigned_pair_gen_var_4,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_shr_ubig() {
+    let test = |s, bits: u64, out| {
+        let u = UBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!((u >> bits).to_string(), out);
+        let mut n = u;
+        n >>= bits;
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", 10, "0");
+    test("123", 0, "123");
+    test("245", 1, "122");
+    test("155921023828072216384094494261248", 100, "123");
+    test("18446744073709551616000000000000", 64, "1000000000000");
+    test("340282366920938463463374607431768211455", 127, "1");
+    test("340282366920938463463374607431768211455", 128, "0");
+    test("123", 1000, "0");
+}
+
+#[test]
+fn test_shr_ibig() {
+    let test = |s, bits: u64, out| {
+        let i = IBig::<LIMBS_128>::from_str(s).unwrap();
+        assert_eq!((i >> bits).to_string(), out);
+        let mut n = i;
+        n >>= bits;
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", 10, "0");
+    test("-123", 0, "-123");
+    test("-245", 1, "-123");
+    test("-155921023828072216384094494261248", 100, "-123");
+    test("-155921023828072216384094494261249", 100, "-124");
+    test("-170141183460469231731687303715884105728", 127, "-1");
+    test(
+        "-170141183460469231731687303715884105728",
+        64,
+        "-9223372036854775808",
+    );
+    test("-1", 1000, "-1");
+    test("170141183460469231731687303715884105727", 126, "1");
+    test("170141183460469231731687303715884105727", 1000, "0");
+}
+
+fn shr_properties_helper<const N: usize>() {
+    natural_unsigned_pair_gen_var_4::<u64>().test_properties(|(x, bits)| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let shifted = u >> bits;
+        assert_eq!(Natural::from(shifted), Natural::from(u) >> bits);
+        let mut n = u;
+        n >>= bits;
+        assert_eq!(n, shifted);
+        assert!(shifted <= u);
+        assert_eq!(u >> (bits + UBig::<N>::WIDTH), UBig::ZERO);
+    });
+
+    integer_unsigned_pair_gen_var_2::<u64>().test_properties(|(x, bits)| {
+        let i = IBig::<N>::wrapping_from(&x);
+        let shifted = i >> bits;
+        assert_eq!(Integer::from(shifted), Integer::from(i) >> bits);
+        let mut n = i;
+        n >>= bits;
+        assert_eq!(n, shifted);
+        assert_eq!(
+            i >> (bits + IBig::<N>::WIDTH),
+            if i < IBig::ZERO {
+                IBig::NEGATIVE_ONE
+            } else {
+                IBig::ZERO
+            }
+        );
+    });
+
+    assert_eq!(UBig::<N>::MAX >> (UBig::<N>::WIDTH - 1), UBig::from(1u32));
+    assert_eq!(IBig::<N>::MIN >> (IBig::<N>::WIDTH - 1), IBig::NEGATIVE_ONE);
+    assert_eq!(IBig::<N>::MAX >> (IBig::<N>::WIDTH - 1), IBig::ZERO);
+}
+
+#[test]
+fn shr_properties() {
+    shr_properties_helper::<1>();
+    shr_properties_helper::<2>();
+    shr_properties_helper::<4>();
+}
@@ -0,0 +1,67 @@
+use malachite_base::comparison::traits::{Max, Min};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::fixed::{IBig, UBig};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::fixed::LIMBS_128;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_significant_bits() {
+    let test_ubig = |s, out| {
+        assert_eq!(
+            UBig::<LIMBS_128>::from_str(s).unwrap().significant_bits(),
+            out
+        );
+    };
+    test_ubig("0", 0);
+    test_ubig("100", 7);
+    test_ubig("1000000000000", 40);
+    test_ubig("18446744073709551616", 65);
+    test_ubig("340282366920938463463374607431768211455", 128);
+
+    let test_ibig = |s, out| {
+        assert_eq!(
+            IBig::<LIMBS_128>::from_str(s).unwrap().significant_bits(),
+            out
+        );
+    };
+    test_ibig("0", 0);
+    test_ibig("-100", 7);
+    test_ibig("-1000000000000", 40);
+    test_ibig("170141183460469231731687303715884105727", 127);
+    test_ibig("-170141183460469231731687303715884105728", 128);
+}
+
+fn significant_bits_properties_helper<const N: usize>() {
+    natural_gen().test_properties(|x| {
+        let u = UBig::<N>::wrapping_from(&x);
+        let bits = u.significant_bits();
+        assert_eq!(bits, Natural::from(u).significant_bits());
+        assert!(bits <= UBig::<N>::WIDTH);
+        assert_eq!(bits == 0, u == UBig::ZERO);
+    });
+
+    integer_gen().test_properties(|x| {
+        let i = IBig::<N>::wrapping_from(&x);
+        let bits = i.significant_bits();
+        assert_eq!(bits, Integer::from(i).significant_bits());
+        assert!(bits <= IBig::<N>::WIDTH);
+    });
+
+    assert_eq!(UBig::<N>::MAX.significant_bits(), UBig::<N>::WIDTH);
+    assert_eq!(UBig::<N>::ONE.significant_bits(), 1);
+    assert_eq!(IBig::<N>::MIN.significant_bits(), IBig::<N>::WIDTH);
+    assert_eq!(IBig::<N>::MAX.significant_bits(), IBig::<N>::WIDTH - 1);
+    assert_eq!(IBig::<N>::NEGATIVE_ONE.significant_bits(), 1);
+}
+
+#[test]
+fn significant_bits_properties() {
+    significant_bits_properties_helper::<1>();
+    significant_bits_properties_helper::<2>();
+    significant_bits_properties_helper::<4>();
+}
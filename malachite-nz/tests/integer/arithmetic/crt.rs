@@ -0,0 +1,131 @@
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Mod};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_natural_pair_gen, integer_vec_gen};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn parse_integers(xs: &[&str]) -> Vec<Integer> {
+    xs.iter().map(|x| Integer::from_str(x).unwrap()).collect()
+}
+
+fn parse_naturals(xs: &[&str]) -> Vec<Natural> {
+    xs.iter().map(|x| Natural::from_str(x).unwrap()).collect()
+}
+
+#[test]
+fn test_crt() {
+    let test = |residues: &[&str], moduli: &[&str], out| {
+        let residues = parse_integers(residues);
+        let moduli = parse_naturals(moduli);
+        let x = Integer::crt(&residues, &moduli);
+        assert!(x.as_ref().map_or(true, Integer::is_valid));
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test(&[], &[], "Some(0)");
+    test(&["-5"], &["1"], "Some(0)");
+    test(&["5"], &["3"], "Some(-1)");
+    test(&["-5"], &["3"], "Some(1)");
+    test(&["3"], &["6"], "Some(3)");
+    test(&["-3"], &["6"], "Some(3)");
+    test(&["2", "3", "2"], &["3", "5", "7"], "Some(23)");
+    test(&["-1", "-1", "-1"], &["3", "5", "7"], "Some(-1)");
+    test(&["5", "3"], &["6", "10"], "Some(-7)");
+    test(&["5", "-6"], &["6", "10"], "None");
+    test(&["-1", "-1", "-1"], &["4", "6", "8"], "Some(-1)");
+    test(
+        &["-1", "-2"],
+        &["1000000007", "998244353"],
+        "Some(4915446034408121)",
+    );
+}
+
+#[test]
+fn crt_fail() {
+    assert_panic!(Integer::crt(&[Integer::ONE], &[]));
+    assert_panic!(Integer::crt(&[Integer::ONE], &[Natural::ZERO]));
+}
+
+#[test]
+fn test_crt_coprime() {
+    let test = |residues: &[&str], moduli: &[&str], out| {
+        let residues = parse_integers(residues);
+        let moduli = parse_naturals(moduli);
+        let x = Integer::crt_coprime(&residues, &moduli);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test(&[], &[], "0");
+    test(&["-5"], &["1"], "0");
+    test(&["5"], &["3"], "-1");
+    test(&["3"], &["6"], "3");
+    test(&["2", "3", "2"], &["3", "5", "7"], "23");
+    test(&["1", "0", "0"], &["3", "5", "7"], "-35");
+    test(&["-1", "0", "0"], &["3", "5", "7"], "35");
+    test(
+        &["-1", "-2"],
+        &["1000000007", "998244353"],
+        "4915446034408121",
+    );
+}
+
+#[test]
+fn crt_coprime_fail() {
+    assert_panic!(Integer::crt_coprime(&[Integer::ONE], &[]));
+    assert_panic!(Integer::crt_coprime(&[Integer::ONE], &[Natural::ZERO]));
+    assert_panic!(Integer::crt_coprime(
+        &[Integer::ONE, Integer::ONE],
+        &[Natural::from(6u32), Natural::from(10u32)]
+    ));
+}
+
+#[test]
+fn crt_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        let mut product = Natural::ONE;
+        let mut moduli = Vec::with_capacity(xs.len());
+        for x in &xs {
+            let m = x.unsigned_abs_ref() * &product + Natural::ONE;
+            product *= &m;
+            moduli.push(m);
+        }
+        let x = Integer::crt_coprime(&xs, &moduli);
+        assert!(x.is_valid());
+        let product = Integer::from(product);
+        assert!(-&product < Integer::from(2) * &x);
+        assert!(Integer::from(2) * &x <= product);
+        for (r, m) in xs.iter().zip(&moduli) {
+            assert!((&x - r).divisible_by(Integer::from(m)));
+        }
+        assert_eq!(Integer::crt(&xs, &moduli).as_ref(), Some(&x));
+
+        let naturals: Vec<Natural> = xs
+            .iter()
+            .zip(&moduli)
+            .map(|(r, m)| Natural::exact_from(r.mod_op(Integer::from(m))))
+            .collect();
+        assert_eq!(
+            Integer::from(Natural::crt_coprime(&naturals, &moduli)),
+            (&x).mod_op(&product)
+        );
+
+        let negated: Vec<Integer> = xs.iter().map(|r| -r).collect();
+        let y = Integer::crt_coprime(&negated, &moduli);
+        if Integer::from(2) * &x != product {
+            assert_eq!(y, -x);
+        }
+    });
+
+    integer_natural_pair_gen().test_properties(|(x, y)| {
+        let m = y + Natural::ONE;
+        let r = Integer::crt_coprime(&[x.clone()], &[m.clone()]);
+        assert_eq!(Integer::crt(&[x.clone()], &[m.clone()]).as_ref(), Some(&r));
+        assert!((x - &r).divisible_by(Integer::from(&m)));
+        let m = Integer::from(m);
+        assert!(-&m < Integer::from(2) * &r);
+        assert!(Integer::from(2) * &r <= m);
+    });
+}
@@ -0,0 +1,115 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModAdd, ModSub};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_integer_natural_triple_gen, natural_triple_gen_var_3,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_add() {
+    let test = |r, s, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let v = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let result = u.clone().mod_add(v.clone(), m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = u.clone().mod_add(v.clone(), &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = u.clone().mod_add(&v, m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = u.clone().mod_add(&v, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_add(v.clone(), m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_add(v.clone(), &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_add(&v, m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_add(&v, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("5", "-3", "1", "0");
+    test("-3", "5", "7", "2");
+    test("-10", "-20", "7", "5");
+    test("123", "-456", "789", "456");
+    test(
+        "-1000000000000000000000",
+        "999999999999",
+        "1000000007",
+        "999944006",
+    );
+    test(
+        "-340282366920938463463374607431768211455",
+        "1",
+        "340282366920938463463374607431768211456",
+        "2",
+    );
+    test(
+        "170141183460469231731687303715884105727",
+        "-170141183460469231731687303715884105728",
+        "170141183460469231731687303715884105727",
+        "170141183460469231731687303715884105726",
+    );
+}
+
+#[test]
+fn mod_add_fail() {
+    assert_panic!(Integer::ONE.mod_add(Integer::ONE, Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_add(Integer::ONE, &Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_add(&Integer::ONE, Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_add(&Integer::ONE, &Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_add(Integer::ONE, Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_add(Integer::ONE, &Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_add(&Integer::ONE, Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_add(&Integer::ONE, &Natural::ZERO));
+}
+
+#[test]
+fn mod_add_properties() {
+    integer_integer_natural_triple_gen().test_properties(|(x, y, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let result = (&x).mod_add(&y, &m);
+        assert!(result.is_valid());
+        assert_eq!(x.clone().mod_add(y.clone(), m.clone()), result);
+        assert_eq!(x.clone().mod_add(y.clone(), &m), result);
+        assert_eq!(x.clone().mod_add(&y, m.clone()), result);
+        assert_eq!(x.clone().mod_add(&y, &m), result);
+        assert_eq!((&x).mod_add(y.clone(), m.clone()), result);
+        assert_eq!((&x).mod_add(y.clone(), &m), result);
+        assert_eq!((&x).mod_add(&y, m.clone()), result);
+
+        assert!(result < m);
+        assert_eq!(Integer::from(&result), (&x + &y).mod_op(Integer::from(&m)));
+        assert_eq!((&y).mod_add(&x, &m), result);
+        assert_eq!((&x).mod_sub(-&y, &m), result);
+    });
+
+    natural_triple_gen_var_3().test_properties(|(x, y, m)| {
+        assert_eq!(
+            Integer::from(&x).mod_add(Integer::from(&y), &m),
+            x.mod_add(y, m)
+        );
+    });
+}
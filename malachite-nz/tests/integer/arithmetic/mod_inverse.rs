@@ -0,0 +1,81 @@
+use malachite_base::num::arithmetic::traits::{CoprimeWith, ModInverse, ModMul};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_natural_pair_gen, natural_pair_gen_var_11};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_inverse() {
+    let test = |s, t, out| {
+        let u = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let result = u.clone().mod_inverse(m.clone());
+        assert!(result.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(result.to_debug_string(), out);
+
+        let result = u.clone().mod_inverse(&m);
+        assert!(result.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(result.to_debug_string(), out);
+
+        let result = (&u).mod_inverse(m.clone());
+        assert!(result.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(result.to_debug_string(), out);
+
+        let result = (&u).mod_inverse(&m);
+        assert!(result.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(result.to_debug_string(), out);
+    };
+    test("0", "1", "Some(0)");
+    test("-5", "1", "Some(0)");
+    test("0", "7", "None");
+    test("-3", "10", "Some(3)");
+    test("-4", "10", "None");
+    test("10", "7", "Some(5)");
+    test("-1000000000000000000000", "1000000007", "Some(84551021)");
+    test("-2", "340282366920938463463374607431768211456", "None");
+    test(
+        "-3",
+        "340282366920938463463374607431768211456",
+        "Some(113427455640312821154458202477256070485)",
+    );
+}
+
+#[test]
+fn mod_inverse_fail() {
+    assert_panic!(Integer::ONE.mod_inverse(Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_inverse(&Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_inverse(Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_inverse(&Natural::ZERO));
+}
+
+#[test]
+fn mod_inverse_properties() {
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let result = (&x).mod_inverse(&m);
+        assert!(result.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(x.clone().mod_inverse(m.clone()), result);
+        assert_eq!(x.clone().mod_inverse(&m), result);
+        assert_eq!((&x).mod_inverse(m.clone()), result);
+
+        assert_eq!(result.is_some(), x.unsigned_abs_ref().coprime_with(&m));
+        if let Some(inverse) = result {
+            assert!(inverse < m);
+            assert_eq!((&x).mod_mul(Integer::from(&inverse), &m), Natural::ONE % &m);
+            assert_eq!(
+                Integer::from(inverse).mod_inverse(&m),
+                Some((&x).mod_mul(Integer::ONE, &m))
+            );
+        }
+    });
+
+    natural_pair_gen_var_11().test_properties(|(x, m)| {
+        assert_eq!(Integer::from(&x).mod_inverse(&m), x.mod_inverse(m));
+    });
+}
@@ -0,0 +1,115 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModMul};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_integer_natural_triple_gen, natural_triple_gen_var_3,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_mul() {
+    let test = |r, s, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let v = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let result = u.clone().mod_mul(v.clone(), m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = u.clone().mod_mul(v.clone(), &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = u.clone().mod_mul(&v, m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = u.clone().mod_mul(&v, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_mul(v.clone(), m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_mul(v.clone(), &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_mul(&v, m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_mul(&v, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("5", "-3", "1", "0");
+    test("-3", "5", "7", "6");
+    test("-10", "-20", "7", "4");
+    test("123", "-456", "789", "720");
+    test(
+        "-1000000000000000000000",
+        "999999999999",
+        "1000000007",
+        "343049000",
+    );
+    test(
+        "-340282366920938463463374607431768211455",
+        "1",
+        "340282366920938463463374607431768211456",
+        "1",
+    );
+    test(
+        "170141183460469231731687303715884105727",
+        "-170141183460469231731687303715884105728",
+        "170141183460469231731687303715884105727",
+        "0",
+    );
+}
+
+#[test]
+fn mod_mul_fail() {
+    assert_panic!(Integer::ONE.mod_mul(Integer::ONE, Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_mul(Integer::ONE, &Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_mul(&Integer::ONE, Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_mul(&Integer::ONE, &Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_mul(Integer::ONE, Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_mul(Integer::ONE, &Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_mul(&Integer::ONE, Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_mul(&Integer::ONE, &Natural::ZERO));
+}
+
+#[test]
+fn mod_mul_properties() {
+    integer_integer_natural_triple_gen().test_properties(|(x, y, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let result = (&x).mod_mul(&y, &m);
+        assert!(result.is_valid());
+        assert_eq!(x.clone().mod_mul(y.clone(), m.clone()), result);
+        assert_eq!(x.clone().mod_mul(y.clone(), &m), result);
+        assert_eq!(x.clone().mod_mul(&y, m.clone()), result);
+        assert_eq!(x.clone().mod_mul(&y, &m), result);
+        assert_eq!((&x).mod_mul(y.clone(), m.clone()), result);
+        assert_eq!((&x).mod_mul(y.clone(), &m), result);
+        assert_eq!((&x).mod_mul(&y, m.clone()), result);
+
+        assert!(result < m);
+        assert_eq!(Integer::from(&result), (&x * &y).mod_op(Integer::from(&m)));
+        assert_eq!((&y).mod_mul(&x, &m), result);
+        assert_eq!((-&x).mod_mul(-&y, &m), result);
+    });
+
+    natural_triple_gen_var_3().test_properties(|(x, y, m)| {
+        assert_eq!(
+            Integer::from(&x).mod_mul(Integer::from(&y), &m),
+            x.mod_mul(y, m)
+        );
+    });
+}
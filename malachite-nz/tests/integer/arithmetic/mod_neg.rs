@@ -0,0 +1,76 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModNeg};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_natural_pair_gen, natural_pair_gen_var_8};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_neg() {
+    let test = |s, t, out| {
+        let u = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let result = u.clone().mod_neg(m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = u.clone().mod_neg(&m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_neg(m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_neg(&m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+    };
+    test("0", "1", "0");
+    test("5", "1", "0");
+    test("-3", "7", "3");
+    test("10", "7", "4");
+    test("-10", "13", "10");
+    test("123456789", "1000", "211");
+    test("-1000000000000000000000", "1000000007", "49000");
+    test(
+        "-340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211456",
+        "340282366920938463463374607431768211455",
+    );
+}
+
+#[test]
+fn mod_neg_fail() {
+    assert_panic!(Integer::ONE.mod_neg(Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_neg(&Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_neg(Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_neg(&Natural::ZERO));
+}
+
+#[test]
+fn mod_neg_properties() {
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let result = (&x).mod_neg(&m);
+        assert!(result.is_valid());
+        assert_eq!(x.clone().mod_neg(m.clone()), result);
+        assert_eq!(x.clone().mod_neg(&m), result);
+        assert_eq!((&x).mod_neg(m.clone()), result);
+
+        assert!(result < m);
+        assert_eq!(Integer::from(&result), (-&x).mod_op(Integer::from(&m)));
+        assert_eq!(
+            Integer::from(Integer::from(result).mod_neg(&m)),
+            x.mod_op(Integer::from(&m))
+        );
+    });
+
+    natural_pair_gen_var_8().test_properties(|(x, m)| {
+        assert_eq!(Integer::from(&x).mod_neg(&m), x.mod_neg(m));
+    });
+}
@@ -0,0 +1,140 @@
+use malachite_base::num::arithmetic::traits::{ModInverse, ModMul, ModPow, ModSquare};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_integer_natural_triple_gen, integer_natural_pair_gen, natural_triple_gen_var_5,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_pow() {
+    let test = |r, s, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let v = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let result = u.clone().mod_pow(v.clone(), m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = u.clone().mod_pow(v.clone(), &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = u.clone().mod_pow(&v, m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = u.clone().mod_pow(&v, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_pow(v.clone(), m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_pow(v.clone(), &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_pow(&v, m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_pow(&v, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("-5", "-1", "1", "0");
+    test("0", "0", "7", "1");
+    test("-2", "3", "7", "6");
+    test("3", "-2", "10", "9");
+    test("-3", "-1", "10", "3");
+    test("-7", "-100", "1000000007", "107959084");
+    test("2", "-1", "1000000007", "500000004");
+    test(
+        "-123456789",
+        "1000000",
+        "340282366920938463463374607431768211456",
+        "332333423920871058156108689643215325953",
+    );
+    test(
+        "-3",
+        "-1",
+        "340282366920938463463374607431768211456",
+        "113427455640312821154458202477256070485",
+    );
+}
+
+#[test]
+fn mod_pow_fail() {
+    assert_panic!(Integer::ONE.mod_pow(Integer::ONE, Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_pow(Integer::ONE, &Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_pow(&Integer::ONE, Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_pow(&Integer::ONE, &Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_pow(Integer::ONE, Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_pow(Integer::ONE, &Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_pow(&Integer::ONE, Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_pow(&Integer::ONE, &Natural::ZERO));
+    assert_panic!(Integer::from(2).mod_pow(Integer::NEGATIVE_ONE, Natural::from(4u32)));
+    assert_panic!(Integer::from(2).mod_pow(Integer::NEGATIVE_ONE, &Natural::from(4u32)));
+    assert_panic!(Integer::from(2).mod_pow(&Integer::NEGATIVE_ONE, Natural::from(4u32)));
+    assert_panic!(Integer::from(2).mod_pow(&Integer::NEGATIVE_ONE, &Natural::from(4u32)));
+    assert_panic!((&Integer::from(2)).mod_pow(Integer::NEGATIVE_ONE, Natural::from(4u32)));
+    assert_panic!((&Integer::from(2)).mod_pow(Integer::NEGATIVE_ONE, &Natural::from(4u32)));
+    assert_panic!((&Integer::from(2)).mod_pow(&Integer::NEGATIVE_ONE, Natural::from(4u32)));
+    assert_panic!((&Integer::from(2)).mod_pow(&Integer::NEGATIVE_ONE, &Natural::from(4u32)));
+}
+
+#[test]
+fn mod_pow_properties() {
+    integer_integer_natural_triple_gen().test_properties(|(x, exp, m)| {
+        if m == 0u32 || (exp < 0u32 && (&x).mod_inverse(&m).is_none()) {
+            return;
+        }
+        let result = (&x).mod_pow(&exp, &m);
+        assert!(result.is_valid());
+        assert_eq!(x.clone().mod_pow(exp.clone(), m.clone()), result);
+        assert_eq!(x.clone().mod_pow(exp.clone(), &m), result);
+        assert_eq!(x.clone().mod_pow(&exp, m.clone()), result);
+        assert_eq!(x.clone().mod_pow(&exp, &m), result);
+        assert_eq!((&x).mod_pow(exp.clone(), m.clone()), result);
+        assert_eq!((&x).mod_pow(exp.clone(), &m), result);
+        assert_eq!((&x).mod_pow(&exp, m.clone()), result);
+
+        assert!(result < m);
+        if exp < 0u32 {
+            let inverse = Integer::from((&x).mod_inverse(&m).unwrap());
+            assert_eq!(inverse.mod_pow(-&exp, &m), result);
+            assert_eq!(
+                Integer::from((&x).mod_pow(-exp, &m)).mod_inverse(&m),
+                Some(result)
+            );
+        }
+    });
+
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        if m == 0u32 {
+            return;
+        }
+        assert_eq!((&x).mod_pow(Integer::ZERO, &m), Natural::ONE % &m);
+        assert_eq!(
+            (&x).mod_pow(Integer::ONE, &m),
+            (&x).mod_mul(Integer::ONE, &m)
+        );
+        assert_eq!((&x).mod_pow(Integer::from(2), &m), (&x).mod_square(&m));
+        if let Some(inverse) = (&x).mod_inverse(&m) {
+            assert_eq!((&x).mod_pow(Integer::NEGATIVE_ONE, &m), inverse);
+        }
+    });
+
+    natural_triple_gen_var_5().test_properties(|(x, exp, m)| {
+        assert_eq!(
+            Integer::from(&x).mod_pow(Integer::from(&exp), &m),
+            x.mod_pow(exp, m)
+        );
+    });
+}
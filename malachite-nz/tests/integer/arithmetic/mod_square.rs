@@ -0,0 +1,74 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModMul, ModSquare};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_natural_pair_gen, natural_pair_gen_var_8};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_square() {
+    let test = |s, t, out| {
+        let u = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let result = u.clone().mod_square(m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = u.clone().mod_square(&m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_square(m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&u).mod_square(&m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+    };
+    test("0", "1", "0");
+    test("5", "1", "0");
+    test("-3", "7", "2");
+    test("10", "7", "2");
+    test("-10", "13", "9");
+    test("123456789", "1000", "521");
+    test("-1000000000000000000000", "1000000007", "400999986");
+    test(
+        "-340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211456",
+        "1",
+    );
+}
+
+#[test]
+fn mod_square_fail() {
+    assert_panic!(Integer::ONE.mod_square(Natural::ZERO));
+    assert_panic!(Integer::ONE.mod_square(&Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_square(Natural::ZERO));
+    assert_panic!((&Integer::ONE).mod_square(&Natural::ZERO));
+}
+
+#[test]
+fn mod_square_properties() {
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let result = (&x).mod_square(&m);
+        assert!(result.is_valid());
+        assert_eq!(x.clone().mod_square(m.clone()), result);
+        assert_eq!(x.clone().mod_square(&m), result);
+        assert_eq!((&x).mod_square(m.clone()), result);
+
+        assert!(result < m);
+        assert_eq!(Integer::from(&result), (&x * &x).mod_op(Integer::from(&m)));
+        assert_eq!((&x).mod_mul(&x, &m), result);
+        assert_eq!((-x).mod_square(&m), result);
+    });
+
+    natural_pair_gen_var_8().test_properties(|(x, m)| {
+        assert_eq!(Integer::from(&x).mod_square(&m), x.mod_square(m));
+    });
+}
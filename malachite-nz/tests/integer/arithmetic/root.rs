@@ -1,6 +1,6 @@
 use malachite_base::num::arithmetic::traits::{
     CeilingRoot, CeilingRootAssign, CeilingSqrt, CheckedRoot, CheckedSqrt, FloorRoot,
-    FloorRootAssign, FloorSqrt, Parity, Pow,
+    FloorRootAssign, FloorSqrt, IsPerfectPower, Parity, PerfectPowerDecomposition, Pow,
 };
 use malachite_base::num::basic::traits::{NegativeOne, One};
 use malachite_base::num::conversion::traits::ExactFrom;
@@ -288,6 +288,56 @@ fn checked_root_ref_fail() {
     assert_panic!((&Integer::NEGATIVE_ONE).checked_root(100));
 }
 
+#[test]
+fn test_is_perfect_power() {
+    let test = |s, out| {
+        let n = Integer::from_str(s).unwrap();
+        assert_eq!(n.is_perfect_power(), out);
+    };
+    test("0", true);
+    test("1", true);
+    test("-1", true);
+    test("-2", false);
+    test("-8", true);
+    test("16", true);
+    test("-16", false);
+    test("1000", true);
+    test("-1000", true);
+    test("-18446744073709551616", false);
+    test("-1000000000000000000000000000000", true);
+    test("-1000000000000000000000000000000000", true);
+}
+
+#[test]
+fn test_perfect_power_decomposition() {
+    let test = |s, base, exp| {
+        let n = Integer::from_str(s).unwrap();
+        let (actual_base, actual_exp) = n.clone().perfect_power_decomposition();
+        assert!(actual_base.is_valid());
+        assert_eq!(actual_base.to_string(), base);
+        assert_eq!(actual_exp, exp);
+
+        let (actual_base, actual_exp) = (&n).perfect_power_decomposition();
+        assert!(actual_base.is_valid());
+        assert_eq!(actual_base.to_string(), base);
+        assert_eq!(actual_exp, exp);
+    };
+    test("0", "0", 1);
+    test("1", "1", 1);
+    test("-1", "-1", 1);
+    test("-2", "-2", 1);
+    test("-8", "-2", 3);
+    test("16", "2", 4);
+    test("-16", "-16", 1);
+    test("1000", "10", 3);
+    test("-1000", "-10", 3);
+    test("-1099511627776", "-256", 5);
+    test("-18446744073709551616", "-18446744073709551616", 1);
+    test("1000000000000000000000000000000", "10", 30);
+    test("-1000000000000000000000000000000", "-100", 15);
+    test("-1000000000000000000000000000000000", "-10", 33);
+}
+
 #[test]
 fn floor_cbrt_properties() {
     integer_gen().test_properties(|n| {
@@ -541,3 +591,45 @@ fn checked_root_properties() {
         );
     });
 }
+
+#[test]
+fn perfect_power_decomposition_properties() {
+    integer_gen().test_properties(|n| {
+        let (base, exp) = n.clone().perfect_power_decomposition();
+        assert!(base.is_valid());
+        let (base_alt, exp_alt) = (&n).perfect_power_decomposition();
+        assert!(base_alt.is_valid());
+        assert_eq!(base_alt, base);
+        assert_eq!(exp_alt, exp);
+
+        assert_ne!(exp, 0);
+        assert_eq!((&base).pow(exp), n);
+        let trivial = (-1..=1).contains(&n);
+        assert_eq!(n.is_perfect_power(), exp > 1 || trivial);
+        if n < 0 {
+            assert!(exp.odd());
+            assert!(base < 0);
+        }
+        if exp > 1 && n > 0 {
+            assert!(!base.is_perfect_power());
+        }
+    });
+
+    natural_gen().test_properties(|n| {
+        let (base, exp) = (&n).perfect_power_decomposition();
+        assert_eq!(
+            (Integer::from(base), exp),
+            Integer::from(&n).perfect_power_decomposition()
+        );
+        assert_eq!(n.is_perfect_power(), Integer::from(n).is_perfect_power());
+    });
+
+    signed_gen::<SignedLimb>().test_properties(|i| {
+        let (base, exp) = i.perfect_power_decomposition();
+        assert_eq!(
+            (Integer::from(base), exp),
+            Integer::from(i).perfect_power_decomposition()
+        );
+        assert_eq!(i.is_perfect_power(), Integer::from(i).is_perfect_power());
+    });
+}
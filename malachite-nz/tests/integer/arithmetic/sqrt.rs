@@ -1,12 +1,12 @@
 use malachite_base::num::arithmetic::traits::{
     CeilingRoot, CeilingSqrt, CeilingSqrtAssign, CheckedRoot, CheckedSqrt, FloorRoot, FloorSqrt,
-    FloorSqrtAssign, Square,
+    FloorSqrtAssign, IsSquare, Square,
 };
 use malachite_base::num::basic::traits::{NegativeOne, One};
-use malachite_base::test_util::generators::signed_gen_var_2;
+use malachite_base::test_util::generators::{signed_gen, signed_gen_var_2};
 use malachite_nz::integer::Integer;
 use malachite_nz::platform::SignedLimb;
-use malachite_nz::test_util::generators::{integer_gen_var_4, natural_gen};
+use malachite_nz::test_util::generators::{integer_gen, integer_gen_var_4, natural_gen};
 use num::BigInt;
 use std::panic::catch_unwind;
 use std::str::FromStr;
@@ -121,6 +121,23 @@ pub fn checked_sqrt_fail() {
     assert_panic!(Integer::NEGATIVE_ONE.checked_sqrt());
 }
 
+#[test]
+fn test_is_square() {
+    let test = |s, out| {
+        let n = Integer::from_str(s).unwrap();
+        assert_eq!(n.is_square(), out);
+    };
+    test("0", true);
+    test("1", true);
+    test("-1", false);
+    test("99", false);
+    test("100", true);
+    test("-100", false);
+    test("10000000000000000000000000000000000000000", true);
+    test("-10000000000000000000000000000000000000000", false);
+    test("100000000000000000000000000000000000000000", false);
+}
+
 #[test]
 fn floor_sqrt_properties() {
     integer_gen_var_4().test_properties(|n| {
@@ -211,3 +228,23 @@ fn checked_sqrt_properties() {
         );
     });
 }
+
+#[test]
+fn is_square_properties() {
+    integer_gen().test_properties(|n| {
+        let is_square = n.is_square();
+        assert_eq!(is_square, n >= 0 && (&n).checked_sqrt().is_some());
+        assert!((&n).square().is_square());
+        if n != 0 {
+            assert!(!(-(&n).square()).is_square());
+        }
+    });
+
+    natural_gen().test_properties(|n| {
+        assert_eq!(n.is_square(), Integer::from(n).is_square());
+    });
+
+    signed_gen::<SignedLimb>().test_properties(|i| {
+        assert_eq!(i.is_square(), Integer::from(i).is_square());
+    });
+}
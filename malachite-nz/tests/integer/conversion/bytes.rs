@@ -0,0 +1,226 @@
+use itertools::Itertools;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{bool_gen, signed_gen, unsigned_vec_gen};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_gen, integer_unsigned_pair_gen_var_2};
+use std::str::FromStr;
+
+#[test]
+fn test_to_twos_complement_bytes() {
+    let test = |n, out_le: &[u8], out_be: &[u8]| {
+        let x = Integer::from_str(n).unwrap();
+        assert_eq!(x.to_twos_complement_bytes_le(), out_le);
+        assert_eq!(x.to_twos_complement_bytes_be(), out_be);
+        assert_eq!(Integer::from_twos_complement_bytes_le(out_le), x);
+        assert_eq!(Integer::from_twos_complement_bytes_be(out_be), x);
+    };
+    test("0", &[], &[]);
+    test("123", &[123], &[123]);
+    test("127", &[127], &[127]);
+    test("128", &[128, 0], &[0, 128]);
+    test("255", &[255, 0], &[0, 255]);
+    test("256", &[0, 1], &[1, 0]);
+    test("-1", &[255], &[255]);
+    test("-128", &[128], &[128]);
+    test("-129", &[127, 255], &[255, 127]);
+    test("-256", &[0, 255], &[255, 0]);
+    test("-257", &[255, 254], &[254, 255]);
+    test(
+        "1000000000000",
+        &[0, 16, 165, 212, 232, 0],
+        &[0, 232, 212, 165, 16, 0],
+    );
+    test(
+        "-1000000000000",
+        &[0, 240, 90, 43, 23, 255],
+        &[255, 23, 43, 90, 240, 0],
+    );
+    test(
+        "9223372036854775807",
+        &[255, 255, 255, 255, 255, 255, 255, 127],
+        &[127, 255, 255, 255, 255, 255, 255, 255],
+    );
+    test(
+        "9223372036854775808",
+        &[0, 0, 0, 0, 0, 0, 0, 128, 0],
+        &[0, 128, 0, 0, 0, 0, 0, 0, 0],
+    );
+    test(
+        "-9223372036854775808",
+        &[0, 0, 0, 0, 0, 0, 0, 128],
+        &[128, 0, 0, 0, 0, 0, 0, 0],
+    );
+    test(
+        "-9223372036854775809",
+        &[255, 255, 255, 255, 255, 255, 255, 127, 255],
+        &[255, 127, 255, 255, 255, 255, 255, 255, 255],
+    );
+}
+
+#[test]
+fn test_from_twos_complement_bytes() {
+    let test = |xs: &[u8], out| {
+        assert_eq!(Integer::from_twos_complement_bytes_le(xs).to_string(), out);
+        let mut ys = xs.to_vec();
+        ys.reverse();
+        assert_eq!(Integer::from_twos_complement_bytes_be(&ys).to_string(), out);
+    };
+    test(&[], "0");
+    test(&[0, 0, 0], "0");
+    test(&[255, 255, 255], "-1");
+    test(&[128, 255, 255, 255, 255, 255, 255, 255, 255], "-128");
+    test(&[128, 0, 0, 0, 0, 0, 0, 0, 0], "128");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 1], "18446744073709551616");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 255], "-18446744073709551616");
+}
+
+#[test]
+fn test_to_twos_complement_bytes_padded() {
+    let test = |n, len, out_le: Option<&[u8]>, out_be: Option<&[u8]>| {
+        let x = Integer::from_str(n).unwrap();
+        assert_eq!(x.to_twos_complement_bytes_le_padded(len).as_deref(), out_le);
+        assert_eq!(x.to_twos_complement_bytes_be_padded(len).as_deref(), out_be);
+    };
+    test("0", 0, Some(&[]), Some(&[]));
+    test("0", 2, Some(&[0, 0]), Some(&[0, 0]));
+    test("-1", 0, None, None);
+    test("-1", 3, Some(&[255, 255, 255]), Some(&[255, 255, 255]));
+    test("127", 1, Some(&[127]), Some(&[127]));
+    test("128", 1, None, None);
+    test("128", 2, Some(&[128, 0]), Some(&[0, 128]));
+    test("-128", 1, Some(&[128]), Some(&[128]));
+    test("-129", 1, None, None);
+    test("-129", 3, Some(&[127, 255, 255]), Some(&[255, 255, 127]));
+}
+
+#[test]
+fn test_sign_and_bytes() {
+    let test = |n, sign, out_le: &[u8], out_be: &[u8]| {
+        let x = Integer::from_str(n).unwrap();
+        assert_eq!(x.to_sign_and_bytes_le(), (sign, out_le.to_vec()));
+        assert_eq!(x.to_sign_and_bytes_be(), (sign, out_be.to_vec()));
+        assert_eq!(Integer::from_sign_and_bytes_le(sign, out_le), x);
+        assert_eq!(Integer::from_sign_and_bytes_be(sign, out_be), x);
+    };
+    test("0", true, &[], &[]);
+    test("123", true, &[123], &[123]);
+    test("-123", false, &[123], &[123]);
+    test("-256", false, &[0, 1], &[1, 0]);
+    test(
+        "1000000000000",
+        true,
+        &[0, 16, 165, 212, 232],
+        &[232, 212, 165, 16, 0],
+    );
+    test(
+        "-1000000000000",
+        false,
+        &[0, 16, 165, 212, 232],
+        &[232, 212, 165, 16, 0],
+    );
+
+    assert_eq!(Integer::from_sign_and_bytes_le(false, &[]), 0);
+    assert_eq!(Integer::from_sign_and_bytes_be(false, &[0, 0]), 0);
+}
+
+#[test]
+fn twos_complement_bytes_properties() {
+    integer_gen().test_properties(|x| {
+        let bytes_le = x.to_twos_complement_bytes_le();
+        assert_eq!(Integer::from_twos_complement_bytes_le(&bytes_le), x);
+        if x != 0 {
+            let last = *bytes_le.last().unwrap();
+            assert_eq!(last >> 7 == 1, x < 0);
+            assert!(x
+                .to_twos_complement_bytes_le_padded(bytes_le.len() - 1)
+                .is_none());
+        }
+        let bytes_be = x.to_twos_complement_bytes_be();
+        assert_eq!(bytes_be.iter().rev().copied().collect_vec(), bytes_le);
+        assert_eq!(Integer::from_twos_complement_bytes_be(&bytes_be), x);
+        assert_eq!(
+            x.to_twos_complement_bytes_le_padded(bytes_le.len())
+                .unwrap(),
+            bytes_le
+        );
+        assert_eq!(
+            x.to_twos_complement_bytes_be_padded(bytes_be.len())
+                .unwrap(),
+            bytes_be
+        );
+
+        let neg_bytes = (-&x).to_twos_complement_bytes_le();
+        assert!(neg_bytes.len().abs_diff(bytes_le.len()) <= 1);
+    });
+
+    integer_unsigned_pair_gen_var_2::<u8>().test_properties(|(x, len)| {
+        let len = usize::from(len);
+        let bytes_le = x.to_twos_complement_bytes_le();
+        match x.to_twos_complement_bytes_le_padded(len) {
+            Some(padded) => {
+                assert_eq!(padded.len(), len);
+                assert_eq!(Integer::from_twos_complement_bytes_le(&padded), x);
+            }
+            None => assert!(bytes_le.len() > len),
+        }
+        match x.to_twos_complement_bytes_be_padded(len) {
+            Some(padded) => {
+                assert_eq!(padded.len(), len);
+                assert_eq!(Integer::from_twos_complement_bytes_be(&padded), x);
+            }
+            None => assert!(bytes_le.len() > len),
+        }
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |xs| {
+        let x = Integer::from_twos_complement_bytes_le(&xs);
+        let bytes = x.to_twos_complement_bytes_le();
+        assert!(bytes.len() <= xs.len());
+        assert_eq!(x.to_twos_complement_bytes_le_padded(xs.len()).unwrap(), xs);
+        let mut ys = xs.clone();
+        ys.reverse();
+        assert_eq!(Integer::from_twos_complement_bytes_be(&ys), x);
+    });
+
+    signed_gen::<i64>().test_properties(|i| {
+        let x = Integer::from(i);
+        assert_eq!(
+            x.to_twos_complement_bytes_le_padded(8).unwrap(),
+            i.to_le_bytes()
+        );
+        assert_eq!(
+            x.to_twos_complement_bytes_be_padded(8).unwrap(),
+            i.to_be_bytes()
+        );
+        assert_eq!(Integer::from_twos_complement_bytes_le(&i.to_le_bytes()), i);
+        assert_eq!(Integer::from_twos_complement_bytes_be(&i.to_be_bytes()), i);
+    });
+}
+
+#[test]
+fn sign_and_bytes_properties() {
+    integer_gen().test_properties(|x| {
+        let (sign, bytes_le) = x.to_sign_and_bytes_le();
+        assert_eq!(sign, x >= 0);
+        assert_eq!(bytes_le, x.unsigned_abs_ref().to_bytes_le());
+        assert_eq!(Integer::from_sign_and_bytes_le(sign, &bytes_le), x);
+        let (sign_be, bytes_be) = x.to_sign_and_bytes_be();
+        assert_eq!(sign_be, sign);
+        assert_eq!(bytes_be, x.unsigned_abs_ref().to_bytes_be());
+        assert_eq!(Integer::from_sign_and_bytes_be(sign, &bytes_be), x);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    bool_gen().test_properties(|sign| {
+        assert_eq!(Integer::from_sign_and_bytes_le(sign, &[]), 0);
+    });
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |xs| {
+        let n = Natural::from_bytes_le(&xs);
+        assert_eq!(Integer::from_sign_and_bytes_le(true, &xs), n);
+        assert_eq!(Integer::from_sign_and_bytes_le(false, &xs), -&n);
+    });
+}
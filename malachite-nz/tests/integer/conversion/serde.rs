@@ -1,7 +1,7 @@
 use malachite_base::strings::string_is_subset;
 use malachite_base::test_util::generators::{string_gen, string_gen_var_9};
 use malachite_nz::integer::Integer;
-use malachite_nz::test_util::generators::integer_gen;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
 use std::str::FromStr;
 
 #[test]
@@ -46,12 +46,60 @@ fn test_serde() {
     );
 }
 
+#[test]
+fn test_serde_binary() {
+    let test = |n, out: &[u8]| {
+        assert_eq!(
+            bincode::serialize(&Integer::from_str(n).unwrap()).unwrap(),
+            out
+        );
+        assert_eq!(bincode::deserialize::<Integer>(out).unwrap().to_string(), n);
+    };
+    test("0", &[1, 0, 0, 0, 0, 0, 0, 0, 0]);
+    test("100", &[2, 0, 0, 0, 0, 0, 0, 0, 0, 100]);
+    test("-100", &[2, 0, 0, 0, 0, 0, 0, 0, 1, 100]);
+    test(
+        "1000000000000",
+        &[6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 165, 212, 232],
+    );
+    test(
+        "-1000000000000",
+        &[6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 16, 165, 212, 232],
+    );
+    test(
+        "-18446744073709551616",
+        &[10, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    );
+
+    // negative zero is zero
+    assert_eq!(
+        bincode::deserialize::<Integer>(&[1, 0, 0, 0, 0, 0, 0, 0, 1]).unwrap(),
+        0
+    );
+    // the sign byte is missing
+    assert!(bincode::deserialize::<Integer>(&[0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+    // the sign byte is invalid
+    assert!(bincode::deserialize::<Integer>(&[2, 0, 0, 0, 0, 0, 0, 0, 2, 100]).is_err());
+}
+
 #[test]
 fn serde_properties() {
     integer_gen().test_properties(|x| {
         let s = serde_json::to_string(&x).unwrap();
         assert_eq!(serde_json::from_str::<Integer>(&s).unwrap(), x);
         assert!(string_is_subset(&s, "\"-0123456789abcdefx"));
+
+        let bytes = bincode::serialize(&x).unwrap();
+        assert_eq!(bincode::deserialize::<Integer>(&bytes).unwrap(), x);
+        assert_eq!(bincode::serialize(&-&x).unwrap().len(), bytes.len());
+    });
+
+    natural_gen().test_properties(|x| {
+        let n_bytes = bincode::serialize(&x).unwrap();
+        let i_bytes = bincode::serialize(&Integer::from(x)).unwrap();
+        assert_eq!(i_bytes.len(), n_bytes.len() + 1);
+        assert_eq!(i_bytes[8], 0);
+        assert_eq!(i_bytes[9..], n_bytes[8..]);
     });
 
     string_gen().test_properties(|s| {
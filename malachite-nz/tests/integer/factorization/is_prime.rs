@@ -0,0 +1,52 @@
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_is_prime() {
+    let test = |s, out| {
+        let n = Integer::from_str(s).unwrap();
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.is_probable_prime(10), out);
+    };
+    test("0", false);
+    test("1", false);
+    test("-1", false);
+    test("2", true);
+    test("-2", false);
+    test("97", true);
+    test("-97", false);
+    test("3825123056546413051", false);
+    test("18446744073709551629", true);
+    test("-18446744073709551629", false);
+    test("170141183460469231731687303715884105727", true);
+    test("-170141183460469231731687303715884105727", false);
+}
+
+#[test]
+fn is_prime_properties() {
+    integer_gen().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(n.is_probable_prime(2), is_prime);
+        if n < 0u32 {
+            assert!(!is_prime);
+        } else {
+            assert_eq!((&n).unsigned_abs().is_prime(), is_prime);
+        }
+    });
+
+    natural_gen().test_properties(|n| {
+        assert_eq!(Integer::from(&n).is_prime(), n.is_prime());
+        assert!(!(-Integer::from(n)).is_prime());
+    });
+
+    signed_gen::<i64>().test_properties(|n| {
+        assert_eq!(
+            Integer::from(n).is_prime(),
+            n > 0 && n.unsigned_abs().is_prime()
+        );
+    });
+}
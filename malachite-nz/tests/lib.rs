@@ -45,12 +45,49 @@ extern crate malachite_nz;
 extern crate num;
 extern crate rug;
 
+pub mod fixed {
+    pub mod arithmetic {
+        pub mod abs;
+        pub mod add;
+        pub mod div_mod;
+        pub mod mod_add;
+        pub mod mod_mul;
+        pub mod mod_neg;
+        pub mod mod_pow;
+        pub mod mod_sub;
+        pub mod mul;
+        pub mod neg;
+        pub mod sub;
+    }
+    pub mod comparison {
+        pub mod cmp;
+    }
+    pub mod conversion {
+        pub mod from_primitive_int;
+        pub mod ibig_and_ubig;
+        pub mod integer;
+        pub mod limbs;
+        pub mod natural;
+        pub mod string;
+    }
+    pub mod logic {
+        pub mod and;
+        pub mod bit_access;
+        pub mod not;
+        pub mod or;
+        pub mod shl;
+        pub mod shr;
+        pub mod significant_bits;
+        pub mod xor;
+    }
+}
 pub mod integer {
     pub mod arithmetic {
         pub mod abs;
         pub mod add;
         pub mod add_mul;
         pub mod binomial_coefficient;
+        pub mod crt;
         pub mod div;
         pub mod div_exact;
         pub mod div_mod;
@@ -61,8 +98,15 @@ pub mod integer {
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod kronecker_symbol;
+        pub mod mod_add;
+        pub mod mod_inverse;
+        pub mod mod_mul;
+        pub mod mod_neg;
         pub mod mod_op;
+        pub mod mod_pow;
         pub mod mod_power_of_2;
+        pub mod mod_square;
+        pub mod mod_sub;
         pub mod mul;
         pub mod neg;
         pub mod parity;
@@ -104,6 +148,7 @@ pub mod integer {
         pub mod partial_eq_primitive_int;
     }
     pub mod conversion {
+        pub mod bytes;
         pub mod clone;
         pub mod from_bool;
         pub mod from_natural;
@@ -139,6 +184,9 @@ pub mod integer {
         pub mod integer_increasing_range;
         pub mod integer_increasing_range_to_infinity;
     }
+    pub mod factorization {
+        pub mod is_prime;
+    }
     pub mod logic {
         pub mod and;
         pub mod assign_bit;
@@ -190,10 +238,13 @@ pub mod natural {
     pub mod arithmetic {
         pub mod add;
         pub mod add_mul;
+        pub mod bell_number;
         pub mod binomial_coefficient;
+        pub mod catalan_number;
         pub mod checked_sub;
         pub mod checked_sub_mul;
         pub mod coprime_with;
+        pub mod crt;
         pub mod div;
         pub mod div_exact;
         pub mod div_mod;
@@ -204,6 +255,7 @@ pub mod natural {
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod factorial;
+        pub mod fibonacci;
         pub mod gcd;
         pub mod is_power_of_2;
         pub mod kronecker_symbol;
@@ -231,12 +283,15 @@ pub mod natural {
         pub mod mod_power_of_2_sub;
         pub mod mod_shl;
         pub mod mod_shr;
+        pub mod mod_sqrt;
         pub mod mod_square;
         pub mod mod_sub;
+        pub mod montgomery;
         pub mod mul;
         pub mod neg;
         pub mod next_power_of_2;
         pub mod parity;
+        pub mod partition_number;
         pub mod pow;
         pub mod power_of_2;
         pub mod primorial;
@@ -252,6 +307,7 @@ pub mod natural {
         pub mod sign;
         pub mod sqrt;
         pub mod square;
+        pub mod stirling;
         pub mod sub;
         pub mod sub_mul;
     }
@@ -274,6 +330,7 @@ pub mod natural {
         pub mod partial_eq_primitive_int;
     }
     pub mod conversion {
+        pub mod bytes;
         pub mod clone;
         pub mod digits {
             pub mod from_digits;
@@ -311,6 +368,9 @@ pub mod natural {
         pub mod exhaustive_positive_naturals;
     }
     pub mod factorization {
+        pub mod factor;
+        pub mod is_prime;
+        pub mod next_prime;
         pub mod primes;
     }
     pub mod logic {
@@ -348,6 +408,8 @@ pub mod natural {
         pub mod random_naturals;
         pub mod random_naturals_less_than;
         pub mod random_positive_naturals;
+        pub mod random_primes_with_bits;
+        pub mod random_safe_primes_with_bits;
         pub mod striped_random_natural_inclusive_range;
         pub mod striped_random_natural_range;
         pub mod striped_random_natural_range_to_infinity;
@@ -0,0 +1,42 @@
+use malachite_base::num::arithmetic::traits::EqMod;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::bell_number::bell_number_naive;
+
+#[test]
+fn test_bell_number() {
+    fn test(n: u64, out: &str) {
+        let b = Natural::bell_number(n);
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+        assert_eq!(bell_number_naive(n).to_string(), out);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "5");
+    test(4, "15");
+    test(5, "52");
+    test(6, "203");
+    test(7, "877");
+    test(
+        100,
+        "47585391276764833658790768841387207826363669686825611466616334637559114497892442622672724\
+        044217756306953557882560751",
+    );
+}
+
+#[test]
+fn bell_number_properties() {
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        let b = Natural::bell_number(n);
+        assert!(b.is_valid());
+        assert_ne!(b, 0u32);
+        // Touchard's congruence: B_{n + p} = B_n + B_{n + 1} mod p for prime p
+        let b_1 = Natural::bell_number(n + 1);
+        let sum = &b + &b_1;
+        assert!(Natural::bell_number(n + 2).eq_mod(&sum, Natural::from(2u32)));
+        assert!(Natural::bell_number(n + 3).eq_mod(sum, Natural::from(3u32)));
+    });
+}
@@ -0,0 +1,41 @@
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::catalan_number::catalan_number_naive;
+
+#[test]
+fn test_catalan_number() {
+    fn test(n: u64, out: &str) {
+        let c = Natural::catalan_number(n);
+        assert!(c.is_valid());
+        assert_eq!(c.to_string(), out);
+        assert_eq!(catalan_number_naive(n).to_string(), out);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "5");
+    test(4, "14");
+    test(5, "42");
+    test(6, "132");
+    test(7, "429");
+    test(
+        200,
+        "51220149321101707946754169313632829232443246458247586186492069440757876802314407262854027\
+        6213813397768975366156750120",
+    );
+}
+
+#[test]
+fn catalan_number_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(TINY_LIMIT, |n| {
+        let c = Natural::catalan_number(n);
+        assert!(c.is_valid());
+        assert_ne!(c, 0u32);
+        // (n + 2) C_{n + 1} = 2(2n + 1) C_n
+        assert_eq!(
+            Natural::from(n + 2) * Natural::catalan_number(n + 1),
+            Natural::from((n << 2) + 2) * c
+        );
+    });
+}
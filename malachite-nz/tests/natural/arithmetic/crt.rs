@@ -0,0 +1,135 @@
+use malachite_base::num::arithmetic::traits::Lcm;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_pair_gen, natural_vec_gen};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn parse(xs: &[&str]) -> Vec<Natural> {
+    xs.iter().map(|x| Natural::from_str(x).unwrap()).collect()
+}
+
+// Builds pairwise coprime moduli from arbitrary `Natural`s: each modulus is 1 more than a multiple
+// of the product of the previous ones.
+fn coprime_moduli(xs: &[Natural]) -> (Vec<Natural>, Natural) {
+    let mut product = Natural::ONE;
+    let mut moduli = Vec::with_capacity(xs.len());
+    for x in xs {
+        let m = x * &product + Natural::ONE;
+        product *= &m;
+        moduli.push(m);
+    }
+    (moduli, product)
+}
+
+#[test]
+fn test_crt() {
+    let test = |residues: &[&str], moduli: &[&str], out| {
+        let residues = parse(residues);
+        let moduli = parse(moduli);
+        let x = Natural::crt(&residues, &moduli);
+        assert!(x.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test(&[], &[], "Some(0)");
+    test(&["5"], &["1"], "Some(0)");
+    test(&["5"], &["3"], "Some(2)");
+    test(&["2", "3", "2"], &["3", "5", "7"], "Some(23)");
+    test(&["5", "3"], &["6", "10"], "Some(23)");
+    test(&["5", "4"], &["6", "10"], "None");
+    test(&["1", "1", "1"], &["4", "6", "8"], "Some(1)");
+    test(&["3", "11"], &["4", "4"], "Some(3)");
+    test(&["3", "2"], &["4", "4"], "None");
+    test(&["7", "0"], &["12", "18"], "None");
+    test(&["7", "1"], &["12", "18"], "Some(19)");
+    test(
+        &["1", "2"],
+        &["1000000007", "998244353"],
+        "Some(993328913953302350)",
+    );
+    test(
+        &["123456789012345678901234567890", "98765432109876543210"],
+        &["1000000000000000000000", "999999999999999999999"],
+        "Some(86419753208641975320012345678901234567890)",
+    );
+}
+
+#[test]
+fn crt_fail() {
+    assert_panic!(Natural::crt(&[Natural::ONE], &[]));
+    assert_panic!(Natural::crt(&[Natural::ONE], &[Natural::ZERO]));
+}
+
+#[test]
+fn test_crt_coprime() {
+    let test = |residues: &[&str], moduli: &[&str], out| {
+        let residues = parse(residues);
+        let moduli = parse(moduli);
+        let x = Natural::crt_coprime(&residues, &moduli);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test(&[], &[], "0");
+    test(&["5"], &["1"], "0");
+    test(&["5"], &["3"], "2");
+    test(&["2", "3", "2"], &["3", "5", "7"], "23");
+    test(&["2", "3", "2"], &["3", "1", "7"], "2");
+    test(&["1", "0", "0"], &["3", "5", "7"], "70");
+    test(
+        &["1", "2"],
+        &["1000000007", "998244353"],
+        "993328913953302350",
+    );
+    test(
+        &["123456789012345678901234567890", "98765432109876543210"],
+        &["1000000000000000000000", "999999999999999999999"],
+        "86419753208641975320012345678901234567890",
+    );
+}
+
+#[test]
+fn crt_coprime_fail() {
+    assert_panic!(Natural::crt_coprime(&[Natural::ONE], &[]));
+    assert_panic!(Natural::crt_coprime(&[Natural::ONE], &[Natural::ZERO]));
+    assert_panic!(Natural::crt_coprime(
+        &[Natural::ONE, Natural::ONE],
+        &[Natural::from(6u32), Natural::from(10u32)]
+    ));
+}
+
+#[test]
+fn crt_properties() {
+    natural_vec_gen().test_properties(|xs| {
+        let (moduli, product) = coprime_moduli(&xs);
+        let x = Natural::crt_coprime(&xs, &moduli);
+        assert!(x.is_valid());
+        assert!(x < product);
+        for (r, m) in xs.iter().zip(&moduli) {
+            assert_eq!(&x % m, r % m);
+        }
+        assert_eq!(Natural::crt(&xs, &moduli), Some(x));
+    });
+
+    natural_vec_gen().test_properties(|xs| {
+        let moduli: Vec<Natural> = xs.iter().map(|x| x + Natural::ONE).collect();
+        let lcm = moduli.iter().fold(Natural::ONE, Lcm::lcm);
+        if let Some(x) = Natural::crt(&xs, &moduli) {
+            assert!(x.is_valid());
+            assert!(x < lcm);
+            for (r, m) in xs.iter().zip(&moduli) {
+                assert_eq!(&x % m, r % m);
+            }
+        }
+        let target: Natural = xs.iter().sum();
+        let residues: Vec<Natural> = moduli.iter().map(|m| &target % m).collect();
+        assert_eq!(Natural::crt(&residues, &moduli), Some(target % lcm));
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        let m = y + Natural::ONE;
+        let r = &x % &m;
+        assert_eq!(Natural::crt(&[x.clone()], &[m.clone()]), Some(r.clone()));
+        assert_eq!(Natural::crt_coprime(&[x], &[m]), r);
+    });
+}
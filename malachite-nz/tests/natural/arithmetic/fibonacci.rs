@@ -0,0 +1,215 @@
+use malachite_base::num::arithmetic::traits::{Fibonacci, FibonacciMod, Parity};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_unsigned_pair_gen_var_8;
+use malachite_nz::test_util::natural::arithmetic::fibonacci::{
+    fibonacci_mod_naive, fibonacci_naive, lucas_naive,
+};
+use rug::Complete;
+use std::str::FromStr;
+
+#[test]
+fn test_fibonacci() {
+    fn test(n: u64, out: &str) {
+        let f = Natural::fibonacci(n);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+        assert_eq!(fibonacci_naive(n).to_string(), out);
+        assert_eq!(
+            rug::Integer::fibonacci(u32::exact_from(n))
+                .complete()
+                .to_string(),
+            out
+        );
+    }
+    test(0, "0");
+    test(1, "1");
+    test(2, "1");
+    test(3, "2");
+    test(4, "3");
+    test(5, "5");
+    test(6, "8");
+    test(7, "13");
+    test(100, "354224848179261915075");
+    test(200, "280571172992510140037611932413038677189525");
+    test(
+        500,
+        "13942322456169788013972438287040728395007025658769730726410896294832557162286329069155765\
+        8876222521294125",
+    );
+}
+
+#[test]
+fn test_fibonacci2() {
+    fn test(n: u64, out: &str) {
+        let (f, f_prev) = Natural::fibonacci2(n);
+        assert!(f.is_valid());
+        assert!(f_prev.is_valid());
+        assert_eq!((&f, &f_prev).to_debug_string(), out);
+        let (rug_f, rug_f_prev) =
+            <(rug::Integer, rug::Integer)>::from(rug::Integer::fibonacci_2(u32::exact_from(n)));
+        assert_eq!(
+            (
+                Natural::exact_from(&rug_f),
+                Natural::exact_from(&rug_f_prev)
+            )
+                .to_debug_string(),
+            out
+        );
+    }
+    test(0, "(0, 1)");
+    test(1, "(1, 0)");
+    test(2, "(1, 1)");
+    test(3, "(2, 1)");
+    test(4, "(3, 2)");
+    test(5, "(5, 3)");
+    test(100, "(354224848179261915075, 218922995834555169026)");
+    test(
+        200,
+        "(280571172992510140037611932413038677189525, \
+        173402521172797813159685037284371942044301)",
+    );
+}
+
+#[test]
+fn test_lucas() {
+    fn test(n: u64, out: &str) {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        assert_eq!(l.to_string(), out);
+        assert_eq!(lucas_naive(n).to_string(), out);
+        assert_eq!(
+            rug::Integer::lucas(u32::exact_from(n))
+                .complete()
+                .to_string(),
+            out
+        );
+    }
+    test(0, "2");
+    test(1, "1");
+    test(2, "3");
+    test(3, "4");
+    test(4, "7");
+    test(5, "11");
+    test(6, "18");
+    test(7, "29");
+    test(100, "792070839848372253127");
+    test(200, "627376215338105766356982006981782561278127");
+    test(
+        500,
+        "31175980776217478160530100720173686014195239323981907391316876988862368385451047611847431\
+        5229371415703127",
+    );
+}
+
+#[test]
+fn test_fibonacci_mod() {
+    fn test(n: u64, m: &str, out: &str) {
+        let m = Natural::from_str(m).unwrap();
+        let f = Natural::fibonacci_mod(n, &m);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+
+        let f_alt = Natural::fibonacci_mod(n, m.clone());
+        assert!(f_alt.is_valid());
+        assert_eq!(f_alt, f);
+
+        if n < 100000 {
+            assert_eq!(fibonacci_mod_naive(n, &m).to_string(), out);
+        }
+    }
+    test(0, "1", "0");
+    test(5, "1", "0");
+    test(0, "10", "0");
+    test(1, "10", "1");
+    test(10, "7", "6");
+    test(100, "1000000007", "687995182");
+    test(1000, "100000000000000000039", "22923194251203333989");
+    test(
+        12345,
+        "10000000000000000000000000000000000000001",
+        "8493799066800529077858138101694994998202",
+    );
+    test(
+        1000000,
+        "170141183460469231731687303715884105727",
+        "137640370289385123615933572233439085100",
+    );
+}
+
+#[test]
+#[should_panic]
+fn fibonacci_mod_fail() {
+    Natural::fibonacci_mod(10, Natural::from(0u32));
+}
+
+#[test]
+#[should_panic]
+fn fibonacci_mod_ref_fail() {
+    Natural::fibonacci_mod(10, &Natural::from(0u32));
+}
+
+#[test]
+fn fibonacci_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(TINY_LIMIT, |n| {
+        let f = Natural::fibonacci(n);
+        assert!(f.is_valid());
+        assert_eq!(fibonacci_naive(n), f);
+        assert_eq!(
+            Natural::exact_from(&rug::Integer::fibonacci(u32::exact_from(n)).complete()),
+            f
+        );
+        let (f_alt, f_prev) = Natural::fibonacci2(n);
+        assert_eq!(f_alt, f);
+        let f_next = &f + &f_prev;
+        assert_eq!(Natural::fibonacci(n + 1), f_next);
+        // Cassini's identity: F_{n - 1}F_{n + 1} - F_n^2 = (-1)^n
+        if n.even() {
+            assert_eq!(f_prev * f_next, &f * &f + Natural::ONE);
+        } else {
+            assert_eq!(f_prev * f_next + Natural::ONE, &f * &f);
+        }
+    });
+}
+
+#[test]
+fn lucas_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(TINY_LIMIT, |n| {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        assert_eq!(lucas_naive(n), l);
+        assert_eq!(
+            Natural::exact_from(&rug::Integer::lucas(u32::exact_from(n)).complete()),
+            l
+        );
+        // F_{2n} = F_n L_n
+        assert_eq!(Natural::fibonacci(n) * &l, Natural::fibonacci(n << 1));
+        // L_n^2 - 2(-1)^n = L_{2n}
+        let l_squared = &l * &l;
+        if n.even() {
+            assert_eq!(l_squared, Natural::lucas(n << 1) + Natural::TWO);
+        } else {
+            assert_eq!(l_squared + Natural::TWO, Natural::lucas(n << 1));
+        }
+    });
+}
+
+#[test]
+fn fibonacci_mod_properties() {
+    natural_unsigned_pair_gen_var_8::<u64>().test_properties(|(m, n)| {
+        let f = Natural::fibonacci_mod(n, &m);
+        assert!(f.is_valid());
+        assert!(f < m);
+
+        let f_alt = Natural::fibonacci_mod(n, m.clone());
+        assert!(f_alt.is_valid());
+        assert_eq!(f_alt, f);
+
+        assert_eq!(fibonacci_mod_naive(n, &m), f);
+        assert_eq!(Natural::fibonacci(n) % &m, f);
+    });
+}
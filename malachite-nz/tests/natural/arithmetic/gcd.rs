@@ -1,5 +1,5 @@
 use malachite_base::num::arithmetic::traits::{
-    CoprimeWith, DivExact, DivisibleBy, Gcd, GcdAssign, Lcm, ModPowerOf2,
+    CoprimeWith, DivExact, DivisibleBy, Gcd, GcdAssign, Lcm, ModPowerOf2, Pow,
 };
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
@@ -12885,6 +12885,12 @@ fn test_gcd() {
     test("12", "90", "6");
     test("12345678987654321", "98765432123456789", "1");
     test("12345678987654321", "98765432123456827", "37");
+
+    // m_lens[1][0] == 0 in limbs_half_gcd_matrix_apply
+    let x = Natural::from(3u32).pow(230001) + Natural::from(5u32).pow(23000);
+    let y = Natural::from(3u32).pow(230000);
+    assert_eq!((&x).gcd(&y), 1);
+    assert_eq!(y.gcd(x), 1);
 }
 
 #[test]
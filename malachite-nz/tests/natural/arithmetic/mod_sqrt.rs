@@ -0,0 +1,274 @@
+use malachite_base::num::arithmetic::traits::{
+    LegendreSymbol, ModFactoredSqrt, ModPrimePowerSqrt, ModSqrt, ModSquare, Pow,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::{Factor, NextPrime};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_pair_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_sqrt() {
+    let test = |s, t, out| {
+        let x = Natural::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        let result = x.clone().mod_sqrt(p.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |n| n.is_valid()));
+
+        let result = x.clone().mod_sqrt(&p);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |n| n.is_valid()));
+
+        let result = (&x).mod_sqrt(p.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |n| n.is_valid()));
+
+        let result = (&x).mod_sqrt(&p);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |n| n.is_valid()));
+    };
+    test("0", "2", "Some(0)");
+    test("1", "2", "Some(1)");
+    test("0", "7", "Some(0)");
+    test("2", "7", "Some(3)");
+    test("3", "7", "None");
+    // 2^127 - 1
+    test(
+        "2",
+        "170141183460469231731687303715884105727",
+        "Some(18446744073709551616)",
+    );
+    test("3", "170141183460469231731687303715884105727", "None");
+    // 2^255 - 19
+    test(
+        "4",
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        "Some(2)",
+    );
+    test(
+        "2",
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        "None",
+    );
+    // 2^224 - 2^96 + 1
+    test(
+        "2",
+        "26959946667150639794667015087019630673557916260026308143510066298881",
+        "Some(11530978453080176508409676669917297614893691613623558510871677887308)",
+    );
+    test(
+        "11",
+        "26959946667150639794667015087019630673557916260026308143510066298881",
+        "None",
+    );
+    // (2^188 + 1) * 2^12 + 1
+    test(
+        "2",
+        "1606938044258990275541962092341162602522202993782792835731457",
+        "Some(442722294033987021286318896821861064657242573876244615335860)",
+    );
+    test(
+        "3",
+        "1606938044258990275541962092341162602522202993782792835731457",
+        "None",
+    );
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    assert_panic!(Natural::ZERO.mod_sqrt(Natural::ZERO));
+    assert_panic!(Natural::from(7u32).mod_sqrt(Natural::from(7u32)));
+    assert_panic!(Natural::from(3u32).mod_sqrt(Natural::from(10u32)));
+    assert_panic!(Natural::ZERO.mod_sqrt(&Natural::ZERO));
+    assert_panic!(Natural::from(7u32).mod_sqrt(&Natural::from(7u32)));
+    assert_panic!(Natural::from(3u32).mod_sqrt(&Natural::from(10u32)));
+    assert_panic!((&Natural::ZERO).mod_sqrt(Natural::ZERO));
+    assert_panic!((&Natural::from(7u32)).mod_sqrt(Natural::from(7u32)));
+    assert_panic!((&Natural::from(3u32)).mod_sqrt(Natural::from(10u32)));
+    assert_panic!((&Natural::ZERO).mod_sqrt(&Natural::ZERO));
+    assert_panic!((&Natural::from(7u32)).mod_sqrt(&Natural::from(7u32)));
+    assert_panic!((&Natural::from(3u32)).mod_sqrt(&Natural::from(10u32)));
+}
+
+#[test]
+fn test_mod_prime_power_sqrt() {
+    let test = |s, t, k: u64, out| {
+        let x = Natural::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        let result = x.clone().mod_prime_power_sqrt(p.clone(), k);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |n| n.is_valid()));
+
+        let result = x.clone().mod_prime_power_sqrt(&p, k);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |n| n.is_valid()));
+
+        let result = (&x).mod_prime_power_sqrt(p.clone(), k);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |n| n.is_valid()));
+
+        let result = (&x).mod_prime_power_sqrt(&p, k);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |n| n.is_valid()));
+    };
+    test("0", "2", 0, "Some(0)");
+    test("2", "7", 2, "Some(10)");
+    test("3", "7", 2, "None");
+    test("7", "7", 2, "None");
+    test("17", "2", 7, "Some(41)");
+    test("36", "3", 4, "Some(21)");
+    test(
+        "17",
+        "2",
+        200,
+        "Some(511266638840850274495046747546751271659868338649645911844585)",
+    );
+    test(
+        "2",
+        "7",
+        100,
+        "Some(184803548658820523509188598274732918323927994210374255806287874881056356289085490227\
+        )",
+    );
+    test(
+        "15241578750190521",
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        3,
+        "Some(123456789)",
+    );
+    test(
+        "2",
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        3,
+        "None",
+    );
+}
+
+#[test]
+fn mod_prime_power_sqrt_fail() {
+    assert_panic!(Natural::from(49u32).mod_prime_power_sqrt(Natural::from(7u32), 2));
+    assert_panic!(Natural::ONE.mod_prime_power_sqrt(Natural::TWO, 0));
+    assert_panic!(Natural::from(49u32).mod_prime_power_sqrt(&Natural::from(7u32), 2));
+    assert_panic!(Natural::ONE.mod_prime_power_sqrt(&Natural::TWO, 0));
+    assert_panic!((&Natural::from(49u32)).mod_prime_power_sqrt(Natural::from(7u32), 2));
+    assert_panic!((&Natural::ONE).mod_prime_power_sqrt(Natural::TWO, 0));
+    assert_panic!((&Natural::from(49u32)).mod_prime_power_sqrt(&Natural::from(7u32), 2));
+    assert_panic!((&Natural::ONE).mod_prime_power_sqrt(&Natural::TWO, 0));
+}
+
+#[test]
+fn test_mod_factored_sqrt() {
+    let test = |s, factors: &[(&str, u64)], out| {
+        let x = Natural::from_str(s).unwrap();
+        let factors: Vec<(Natural, u64)> = factors
+            .iter()
+            .map(|&(p, k)| (Natural::from_str(p).unwrap(), k))
+            .collect();
+
+        let result = x.clone().mod_factored_sqrt(&factors);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |n| n.is_valid()));
+
+        let result = (&x).mod_factored_sqrt(&factors);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |n| n.is_valid()));
+    };
+    test("0", &[], "Some(0)");
+    test("41", &[("2", 2), ("5", 2)], "Some(29)");
+    test("2", &[("2", 2), ("5", 2)], "None");
+    test("4", &[("3", 1), ("5", 1), ("7", 1)], "Some(37)");
+    let factors = [
+        ("170141183460469231731687303715884105727", 1),
+        (
+            "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+            2,
+        ),
+        ("2", 10),
+    ];
+    test("4", &factors, "Some(2)");
+    test("17", &factors, "None");
+}
+
+#[test]
+fn mod_factored_sqrt_fail() {
+    let factors = [(Natural::TWO, 1), (Natural::from(3u32), 1)];
+    assert_panic!(Natural::from(6u32).mod_factored_sqrt(&factors));
+    assert_panic!((&Natural::from(6u32)).mod_factored_sqrt(&factors));
+    let factors = [(Natural::from(3u32), 1), (Natural::from(3u32), 1)];
+    assert_panic!(Natural::ONE.mod_factored_sqrt(&factors));
+    assert_panic!((&Natural::ONE).mod_factored_sqrt(&factors));
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    natural_pair_gen().test_properties(|(x, p)| {
+        let p = p.next_prime();
+        let x = x % &p;
+        let sqrt_val_val = x.clone().mod_sqrt(p.clone());
+        let sqrt_val_ref = x.clone().mod_sqrt(&p);
+        let sqrt_ref_val = (&x).mod_sqrt(p.clone());
+        let sqrt = (&x).mod_sqrt(&p);
+        assert!(sqrt.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(sqrt_val_val, sqrt);
+        assert_eq!(sqrt_val_ref, sqrt);
+        assert_eq!(sqrt_ref_val, sqrt);
+
+        assert_eq!(sqrt.is_some(), p == 2u32 || (&x).legendre_symbol(&p) != -1);
+        if let Some(sqrt) = &sqrt {
+            assert!(*sqrt <= &p - sqrt);
+            assert_eq!(sqrt.mod_square(&p), x);
+        }
+        assert_eq!((&x).mod_prime_power_sqrt(&p, 1), sqrt);
+        assert_eq!((&x).mod_factored_sqrt(&[(p.clone(), 1)]), sqrt);
+
+        let square = (&x).mod_square(&p);
+        let sqrt = square.mod_sqrt(&p).unwrap();
+        assert!(sqrt == x || sqrt == p - x);
+    });
+
+    natural_pair_gen().test_properties(|(x, p)| {
+        let p = p.next_prime();
+        let m = (&p).pow(3);
+        let x = x % &m;
+        let square = (&x).mod_square(&m);
+        let sqrt = (&square).mod_prime_power_sqrt(&p, 3).unwrap();
+        assert!(sqrt.is_valid());
+        assert!(sqrt <= &m - &sqrt);
+        assert_eq!(sqrt.mod_square(&m), square);
+    });
+
+    unsigned_pair_gen_var_27::<Limb>().test_properties(|(x, m)| {
+        if m == 0 {
+            return;
+        }
+        let x = x % m;
+        let factors = m.factor();
+        let n_factors: Vec<(Natural, u64)> = factors
+            .iter()
+            .map(|&(p, k)| (Natural::from(p), k))
+            .collect();
+        assert_eq!(
+            Natural::from(x).mod_factored_sqrt(&n_factors),
+            x.mod_factored_sqrt(&factors).map(Natural::from)
+        );
+        if let &[(p, k)] = factors.as_slice() {
+            assert_eq!(
+                Natural::from(x).mod_prime_power_sqrt(Natural::from(p), k),
+                x.mod_prime_power_sqrt(p, k).map(Natural::from)
+            );
+            if k == 1 {
+                assert_eq!(
+                    Natural::from(x).mod_sqrt(Natural::from(p)),
+                    x.mod_sqrt(p).map(Natural::from)
+                );
+            }
+        }
+    });
+}
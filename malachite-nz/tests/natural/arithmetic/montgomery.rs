@@ -0,0 +1,205 @@
+use malachite_base::num::arithmetic::traits::{ModMul, ModPow, ModSquare, Parity, Pow, PowerOf2};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{
+    natural_gen_var_2, natural_triple_gen_var_3, natural_triple_gen_var_5,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn check_context(ctx: &MontgomeryContext) {
+    let m = ctx.modulus();
+    let bits = u64::exact_from(m.to_limbs_asc().len()) << Limb::LOG_WIDTH;
+    assert_eq!(*ctx.r(), Natural::power_of_2(bits) % m);
+    assert_eq!(*ctx.r_squared(), Natural::power_of_2(bits << 1) % m);
+    assert_eq!(
+        ctx.m_inv()
+            .wrapping_mul(m.to_limbs_asc()[0])
+            .wrapping_add(1),
+        0
+    );
+    assert_eq!(ctx.from_montgomery(ctx.r()), Natural::ONE % m);
+}
+
+#[test]
+fn test_montgomery_context() {
+    let test = |s| {
+        let ctx = MontgomeryContext::new(Natural::from_str(s).unwrap());
+        assert_eq!(ctx.modulus().to_string(), s);
+        check_context(&ctx);
+    };
+    test("1");
+    test("3");
+    test("101");
+    test("1000000007");
+    test("340282366920938463463374607431768211455");
+    test("1267650600228229401496703205653");
+}
+
+#[test]
+fn montgomery_context_fail() {
+    assert_panic!(MontgomeryContext::new(Natural::ZERO));
+    assert_panic!(MontgomeryContext::new(Natural::TWO));
+    assert_panic!(MontgomeryContext::new(Natural::from(1000000u32)));
+}
+
+#[test]
+fn test_montgomery_mul_and_square() {
+    let test = |s, t, u, mul_out, square_out| {
+        let x = Natural::from_str(s).unwrap();
+        let y = Natural::from_str(t).unwrap();
+        let ctx = MontgomeryContext::new(Natural::from_str(u).unwrap());
+        let x_m = ctx.to_montgomery(&x);
+        let y_m = ctx.to_montgomery(&y);
+        assert!(x_m.is_valid());
+        assert_eq!(ctx.from_montgomery(&x_m), x);
+        assert_eq!(ctx.from_montgomery(&y_m), y);
+
+        let product = ctx.mul(&x_m, &y_m);
+        assert!(product.is_valid());
+        assert_eq!(ctx.from_montgomery(&product).to_string(), mul_out);
+
+        let square = ctx.square(&x_m);
+        assert!(square.is_valid());
+        assert_eq!(ctx.from_montgomery(&square).to_string(), square_out);
+    };
+    test("0", "0", "1", "0", "0");
+    test("0", "5", "7", "0", "0");
+    test("3", "5", "7", "1", "2");
+    test("123456", "654321", "1000000007", "779852816", "241383831");
+    test(
+        "123456789012345678901234567890",
+        "987654321098765432109876543210",
+        "1267650600228229401496703205653",
+        "206204641768903422400632321155",
+        "419719767930936708108893553436",
+    );
+    test(
+        "340282366920938463463374607431768211454",
+        "340282366920938463463374607431768211454",
+        "340282366920938463463374607431768211455",
+        "1",
+        "1",
+    );
+}
+
+#[test]
+fn montgomery_mul_and_square_fail() {
+    let ctx = MontgomeryContext::new(Natural::from(7u32));
+    assert_panic!(ctx.to_montgomery(&Natural::from(7u32)));
+    assert_panic!(ctx.from_montgomery(&Natural::from(7u32)));
+    assert_panic!(ctx.mul(&Natural::from(7u32), &Natural::ONE));
+    assert_panic!(ctx.mul(&Natural::ONE, &Natural::from(7u32)));
+    assert_panic!(ctx.square(&Natural::from(7u32)));
+}
+
+#[test]
+fn test_montgomery_pow() {
+    let test = |s, t, u, out| {
+        let x = Natural::from_str(s).unwrap();
+        let exp = Natural::from_str(t).unwrap();
+        let ctx = MontgomeryContext::new(Natural::from_str(u).unwrap());
+        let result = ctx.pow(&ctx.to_montgomery(&x), &exp);
+        assert!(result.is_valid());
+        assert_eq!(ctx.from_montgomery(&result).to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("0", "0", "7", "1");
+    test("0", "5", "7", "0");
+    test("3", "0", "7", "1");
+    test("3", "1", "7", "3");
+    test("3", "2", "7", "2");
+    test("2", "1000000", "1000000007", "235042059");
+    test("2", "1000000006", "1000000007", "1");
+    test(
+        "123456789012345678901234567890",
+        "987654321098765432109876543210",
+        "1267650600228229401496703205653",
+        "15252171254647064617701542368",
+    );
+    test(
+        "3",
+        "340282366920938463463374607431768211454",
+        "340282366920938463463374607431768211455",
+        "216434416826713267302305937111878741529",
+    );
+}
+
+#[test]
+fn montgomery_pow_fail() {
+    let ctx = MontgomeryContext::new(Natural::from(7u32));
+    assert_panic!(ctx.pow(&Natural::from(7u32), &Natural::TWO));
+}
+
+#[test]
+fn test_montgomery_large_modulus() {
+    // Large enough that reduction uses a full-width inverse rather than a single limb
+    let m = Natural::from(3u32).pow(5000);
+    let ctx = MontgomeryContext::new(m.clone());
+    check_context(&ctx);
+    let x = Natural::from(2u32).pow(7000) + Natural::from(12345u32);
+    let y = Natural::from(5u32).pow(3000);
+    let x_m = ctx.to_montgomery(&x);
+    let y_m = ctx.to_montgomery(&y);
+    assert_eq!(ctx.from_montgomery(&x_m), x);
+    assert_eq!(
+        ctx.from_montgomery(&ctx.mul(&x_m, &y_m)),
+        (&x).mod_mul(&y, &m)
+    );
+    assert_eq!(ctx.from_montgomery(&ctx.square(&x_m)), (&x).mod_square(&m));
+    let exp = Natural::from(7u32).pow(300);
+    assert_eq!(
+        ctx.from_montgomery(&ctx.pow(&x_m, &exp)),
+        (&x).mod_pow(&exp, &m)
+    );
+}
+
+#[test]
+fn montgomery_properties() {
+    natural_gen_var_2().test_properties(|m| {
+        let m = if m.even() { m - Natural::ONE } else { m };
+        check_context(&MontgomeryContext::new(m));
+    });
+
+    natural_triple_gen_var_3().test_properties(|(x, y, m)| {
+        if m.even() {
+            return;
+        }
+        let ctx = MontgomeryContext::new(m.clone());
+        let x_m = ctx.to_montgomery(&x);
+        let y_m = ctx.to_montgomery(&y);
+        assert!(x_m.is_valid());
+        assert!(x_m < m);
+        assert_eq!(ctx.from_montgomery(&x_m), x);
+        assert_eq!(ctx.mul(&x_m, ctx.r()), x_m);
+        assert_eq!(ctx.mul(&x, ctx.r_squared()), x_m);
+
+        let product = ctx.mul(&x_m, &y_m);
+        assert!(product.is_valid());
+        assert_eq!(ctx.mul(&y_m, &x_m), product);
+        assert_eq!(ctx.from_montgomery(&product), (&x).mod_mul(&y, &m));
+        assert_eq!(ctx.to_montgomery(&(&x).mod_mul(&y, &m)), product);
+
+        let square = ctx.square(&x_m);
+        assert!(square.is_valid());
+        assert_eq!(ctx.mul(&x_m, &x_m), square);
+        assert_eq!(ctx.from_montgomery(&square), x.mod_square(&m));
+    });
+
+    natural_triple_gen_var_5().test_properties(|(x, exp, m)| {
+        if m.even() {
+            return;
+        }
+        let ctx = MontgomeryContext::new(m.clone());
+        let x_m = ctx.to_montgomery(&x);
+        let result = ctx.pow(&x_m, &exp);
+        assert!(result.is_valid());
+        assert!(result < m);
+        assert_eq!(ctx.from_montgomery(&result), (&x).mod_pow(&exp, &m));
+        assert_eq!(ctx.mul(&result, &x_m), ctx.pow(&x_m, &(exp + Natural::ONE)));
+    });
+}
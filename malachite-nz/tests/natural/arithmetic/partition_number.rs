@@ -0,0 +1,35 @@
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::partition_number::partition_number_naive;
+
+#[test]
+fn test_partition_number() {
+    fn test(n: u64, out: &str) {
+        let p = Natural::partition_number(n);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(partition_number_naive(n).to_string(), out);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "3");
+    test(4, "5");
+    test(5, "7");
+    test(6, "11");
+    test(7, "15");
+    test(200, "3972999029388");
+    test(2000, "4720819175619413888601432406799959512200344166");
+}
+
+#[test]
+fn partition_number_properties() {
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        let p = Natural::partition_number(n);
+        assert!(p.is_valid());
+        assert_eq!(partition_number_naive(n), p);
+        assert_ne!(p, 0u32);
+        assert!(Natural::partition_number(n + 1) >= p);
+    });
+}
@@ -1,6 +1,7 @@
 use malachite_base::num::arithmetic::traits::{
-    CeilingRoot, CeilingRootAssign, CeilingSqrt, CheckedRoot, CheckedSqrt, FloorRoot,
-    FloorRootAssign, FloorSqrt, Pow, RootAssignRem, RootRem, SqrtRem,
+    CeilingRoot, CeilingRootAssign, CeilingSqrt, CheckedRoot, CheckedSqrt, DivisibleBy, FloorRoot,
+    FloorRootAssign, FloorSqrt, IsPerfectPower, IsSquare, Parity, PerfectPowerDecomposition, Pow,
+    RootAssignRem, RootRem, SqrtRem,
 };
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
@@ -12,7 +13,9 @@ use malachite_base::test_util::generators::{
 use malachite_nz::natural::arithmetic::root::{limbs_floor_root, limbs_root_rem};
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
-use malachite_nz::test_util::generators::{natural_gen, natural_unsigned_pair_gen_var_7};
+use malachite_nz::test_util::generators::{
+    natural_gen, natural_unsigned_pair_gen_var_7, natural_unsigned_pair_gen_var_8,
+};
 use malachite_nz::test_util::natural::arithmetic::root::{
     ceiling_root_binary, checked_root_binary, floor_root_binary, root_rem_binary,
 };
@@ -470,6 +473,74 @@ fn root_assign_rem_fail() {
     x.root_assign_rem(0);
 }
 
+#[test]
+fn test_is_perfect_power() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        assert_eq!(n.is_perfect_power(), out);
+    };
+    test("0", true);
+    test("1", true);
+    test("2", false);
+    test("4", true);
+    test("8", true);
+    test("1000", true);
+    test("1024", true);
+    test("123456789", false);
+    test("1000000000000000000000000000000", true);
+    test("18446744073709551616", true);
+    test("18446744073709551617", false);
+    test("1546132562196033993109383389296863818106322566003", true);
+    test("1546132562196033993109383389296863818106322566004", false);
+}
+
+#[test]
+fn test_perfect_power_decomposition() {
+    let test = |s, base, exp| {
+        let n = Natural::from_str(s).unwrap();
+        let (actual_base, actual_exp) = n.clone().perfect_power_decomposition();
+        assert!(actual_base.is_valid());
+        assert_eq!(actual_base.to_string(), base);
+        assert_eq!(actual_exp, exp);
+
+        let (actual_base, actual_exp) = (&n).perfect_power_decomposition();
+        assert!(actual_base.is_valid());
+        assert_eq!(actual_base.to_string(), base);
+        assert_eq!(actual_exp, exp);
+    };
+    test("0", "0", 1);
+    test("1", "1", 1);
+    test("2", "2", 1);
+    test("4", "2", 2);
+    test("8", "2", 3);
+    test("1000", "10", 3);
+    test("1024", "2", 10);
+    test("123456789", "123456789", 1);
+    test("1000000000000000000000000000000", "10", 30);
+    test("18446744073709551616", "2", 64);
+    test("18446744073709551617", "18446744073709551617", 1);
+    test(
+        "1546132562196033993109383389296863818106322566003",
+        "3",
+        101,
+    );
+    test(
+        "1546132562196033993109383389296863818106322566004",
+        "1546132562196033993109383389296863818106322566004",
+        1,
+    );
+    test(
+        "2955204414547681244658707659790455381671329323051646976",
+        "6",
+        70,
+    );
+    test(
+        "653318623500070906096690267158057820537143710472954871543071966369497141477376",
+        "6",
+        100,
+    );
+}
+
 #[test]
 fn limbs_floor_root_properties() {
     let mut config = GenConfig::new();
@@ -773,3 +844,49 @@ fn root_rem_properties() {
         );
     });
 }
+
+#[test]
+fn perfect_power_decomposition_properties() {
+    natural_gen().test_properties(|n| {
+        let (base, exp) = n.clone().perfect_power_decomposition();
+        assert!(base.is_valid());
+        let (base_alt, exp_alt) = (&n).perfect_power_decomposition();
+        assert!(base_alt.is_valid());
+        assert_eq!(base_alt, base);
+        assert_eq!(exp_alt, exp);
+
+        assert_ne!(exp, 0);
+        assert_eq!((&base).pow(exp), n);
+        let is_perfect_power = n.is_perfect_power();
+        assert_eq!(is_perfect_power, exp > 1 || n <= 1u32);
+        if exp > 1 {
+            assert!(!base.is_perfect_power());
+        }
+        if n > 1u32 {
+            assert_eq!(n.is_square(), exp.even());
+        }
+    });
+
+    natural_unsigned_pair_gen_var_8::<u64>().test_properties(|(n, exp)| {
+        let power = (&n).pow(exp);
+        let (base, power_exp) = (&power).perfect_power_decomposition();
+        if n == 1u32 {
+            assert_eq!(power_exp, 1);
+        } else {
+            assert!(power_exp.divisible_by(exp));
+            assert_eq!(base.pow(power_exp / exp), n);
+        }
+        if exp > 1 {
+            assert!(power.is_perfect_power());
+        }
+    });
+
+    unsigned_gen::<Limb>().test_properties(|u| {
+        let (base, exp) = u.perfect_power_decomposition();
+        assert_eq!(
+            (Natural::from(base), exp),
+            Natural::from(u).perfect_power_decomposition()
+        );
+        assert_eq!(u.is_perfect_power(), Natural::from(u).is_perfect_power());
+    });
+}
@@ -2,7 +2,7 @@
 use malachite_base::num::arithmetic::traits::Parity;
 use malachite_base::num::arithmetic::traits::{
     CeilingRoot, CeilingSqrt, CeilingSqrtAssign, CheckedRoot, CheckedSqrt, FloorRoot, FloorSqrt,
-    FloorSqrtAssign, RootRem, ShrRound, SqrtAssignRem, SqrtRem, Square,
+    FloorSqrtAssign, IsSquare, RootRem, ShrRound, SqrtAssignRem, SqrtRem, Square,
 };
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::One;
@@ -17,9 +17,9 @@ use malachite_base::test_util::generators::{
     unsigned_vec_pair_gen_var_4, unsigned_vec_pair_gen_var_5, unsigned_vec_triple_gen_var_28,
 };
 use malachite_nz::natural::arithmetic::sqrt::{
-    limbs_ceiling_sqrt, limbs_checked_sqrt, limbs_floor_sqrt, limbs_sqrt_helper, limbs_sqrt_rem,
-    limbs_sqrt_rem_helper, limbs_sqrt_rem_helper_scratch_len, limbs_sqrt_rem_to_out,
-    limbs_sqrt_to_out, sqrt_rem_2_newton,
+    limbs_ceiling_sqrt, limbs_checked_sqrt, limbs_could_be_square, limbs_floor_sqrt,
+    limbs_sqrt_helper, limbs_sqrt_rem, limbs_sqrt_rem_helper, limbs_sqrt_rem_helper_scratch_len,
+    limbs_sqrt_rem_to_out, limbs_sqrt_to_out, sqrt_rem_2_newton,
 };
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::{DoubleLimb, Limb};
@@ -657,6 +657,26 @@ fn test_sqrt_rem() {
     );
 }
 
+#[test]
+fn test_is_square() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        assert_eq!(n.is_square(), out);
+    };
+    test("0", true);
+    test("1", true);
+    test("2", false);
+    test("4", true);
+    test("99", false);
+    test("100", true);
+    test("152415765279683", false);
+    test("152415765279684", true);
+    test("18446744073709551616", true);
+    test("18446744073709551617", false);
+    test("10000000000000000000000000000000000000000", true);
+    test("100000000000000000000000000000000000000000", false);
+}
+
 #[test]
 fn sqrt_rem_2_newton_properties() {
     unsigned_pair_gen_var_31().test_properties(|(n_hi, n_lo)| {
@@ -916,3 +936,23 @@ fn sqrt_rem_properties() {
         );
     });
 }
+
+#[test]
+fn is_square_properties() {
+    natural_gen().test_properties(|n| {
+        let is_square = n.is_square();
+        assert_eq!(is_square, (&n).checked_sqrt().is_some());
+        let xs = n.to_limbs_asc();
+        if xs.len() > 1 && is_square {
+            assert!(limbs_could_be_square(&xs));
+        }
+        assert!((&n).square().is_square());
+        if n != 0u32 {
+            assert!(!((&n).square() + &n).is_square());
+        }
+    });
+
+    unsigned_gen::<Limb>().test_properties(|u| {
+        assert_eq!(u.is_square(), Natural::from(u).is_square());
+    });
+}
@@ -0,0 +1,97 @@
+use malachite_base::num::arithmetic::traits::BinomialCoefficient;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_28;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::stirling::{
+    stirling_first_naive, stirling_second_naive,
+};
+
+#[test]
+fn test_stirling_first() {
+    fn test(n: u64, k: u64, out: &str) {
+        let c = Natural::stirling_first(n, k);
+        assert!(c.is_valid());
+        assert_eq!(c.to_string(), out);
+        assert_eq!(stirling_first_naive(n, k).to_string(), out);
+    }
+    test(0, 0, "1");
+    test(0, 1, "0");
+    test(1, 0, "0");
+    test(1, 1, "1");
+    test(2, 1, "1");
+    test(3, 1, "2");
+    test(3, 2, "3");
+    test(4, 5, "0");
+    test(5, 3, "35");
+    test(10, 5, "269325");
+    test(20, 1, "121645100408832000");
+    test(20, 19, "190");
+    test(
+        100,
+        50,
+        "31832227823529643847443541207296860641756094393970550637175786687692271130718363821987396\
+        97421125692626030268475",
+    );
+}
+
+#[test]
+fn test_stirling_second() {
+    fn test(n: u64, k: u64, out: &str) {
+        let s = Natural::stirling_second(n, k);
+        assert!(s.is_valid());
+        assert_eq!(s.to_string(), out);
+        assert_eq!(stirling_second_naive(n, k).to_string(), out);
+    }
+    test(0, 0, "1");
+    test(0, 1, "0");
+    test(1, 0, "0");
+    test(1, 1, "1");
+    test(2, 1, "1");
+    test(3, 1, "1");
+    test(3, 2, "3");
+    test(4, 5, "0");
+    test(5, 3, "25");
+    test(10, 5, "42525");
+    test(20, 1, "1");
+    test(20, 19, "190");
+    test(
+        100,
+        50,
+        "43098323700936634042151430154725869594352028961434061391244174113128031905885378314559826\
+        1659992013900",
+    );
+}
+
+#[test]
+fn stirling_first_properties() {
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties(|(n, k)| {
+        let c = Natural::stirling_first(n, k);
+        assert!(c.is_valid());
+        assert_eq!(stirling_first_naive(n, k), c);
+        assert_eq!(c == 0u32, k > n || (k == 0 && n != 0));
+        assert!(c >= Natural::stirling_second(n, k));
+        assert_eq!(
+            Natural::stirling_first(n + 1, k + 1),
+            Natural::from(n) * Natural::stirling_first(n, k + 1) + c
+        );
+    });
+}
+
+#[test]
+fn stirling_second_properties() {
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties(|(n, k)| {
+        let s = Natural::stirling_second(n, k);
+        assert!(s.is_valid());
+        assert_eq!(stirling_second_naive(n, k), s);
+        assert_eq!(s == 0u32, k > n || (k == 0 && n != 0));
+        assert_eq!(
+            Natural::stirling_second(n + 1, k + 1),
+            Natural::from(k + 1) * Natural::stirling_second(n, k + 1) + s
+        );
+        if n != 0 {
+            assert_eq!(
+                Natural::stirling_second(n, n - 1),
+                Natural::binomial_coefficient(Natural::from(n), Natural::from(2u32))
+            );
+        }
+    });
+}
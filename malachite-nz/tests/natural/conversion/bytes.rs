@@ -0,0 +1,264 @@
+use itertools::Itertools;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::slices::slice_trailing_zeros;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::natural::conversion::bytes::Endianness;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_unsigned_pair_gen_var_4};
+use std::str::FromStr;
+
+#[test]
+fn test_to_bytes() {
+    let test = |n, out_le: &[u8], out_be: &[u8]| {
+        let x = Natural::from_str(n).unwrap();
+        assert_eq!(x.to_bytes_le(), out_le);
+        assert_eq!(x.to_bytes_be(), out_be);
+        assert_eq!(Natural::from_bytes_le(out_le), x);
+        assert_eq!(Natural::from_bytes_be(out_be), x);
+    };
+    test("0", &[], &[]);
+    test("123", &[123], &[123]);
+    test("256", &[0, 1], &[1, 0]);
+    test(
+        "1000000000000",
+        &[0, 16, 165, 212, 232],
+        &[232, 212, 165, 16, 0],
+    );
+    test(
+        "1000000000000000000000000000000",
+        &[0, 0, 0, 64, 234, 237, 116, 70, 208, 156, 44, 159, 12],
+        &[12, 159, 44, 156, 208, 70, 116, 237, 234, 64, 0, 0, 0],
+    );
+    test(
+        "340282366920938463463374607431768211455",
+        &[255; 16],
+        &[255; 16],
+    );
+}
+
+#[test]
+fn test_from_bytes() {
+    let test = |xs: &[u8], out| {
+        assert_eq!(Natural::from_bytes_le(xs).to_string(), out);
+        let mut ys = xs.to_vec();
+        ys.reverse();
+        assert_eq!(Natural::from_bytes_be(&ys).to_string(), out);
+    };
+    test(&[], "0");
+    test(&[0, 0, 0], "0");
+    test(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0], "1");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 1], "18446744073709551616");
+}
+
+#[test]
+fn test_to_bytes_padded() {
+    let test = |n, len, out_le: Option<&[u8]>, out_be: Option<&[u8]>| {
+        let x = Natural::from_str(n).unwrap();
+        assert_eq!(x.to_bytes_le_padded(len).as_deref(), out_le);
+        assert_eq!(x.to_bytes_be_padded(len).as_deref(), out_be);
+    };
+    test("0", 0, Some(&[]), Some(&[]));
+    test("0", 3, Some(&[0, 0, 0]), Some(&[0, 0, 0]));
+    test("123", 0, None, None);
+    test("123", 1, Some(&[123]), Some(&[123]));
+    test("123", 4, Some(&[123, 0, 0, 0]), Some(&[0, 0, 0, 123]));
+    test("256", 1, None, None);
+    test("256", 2, Some(&[0, 1]), Some(&[1, 0]));
+}
+
+#[test]
+fn test_export_words() {
+    let test = |n, word_size, word_order, byte_order, nails, out: &[u8]| {
+        let x = Natural::from_str(n).unwrap();
+        assert_eq!(
+            x.export_words(word_size, word_order, byte_order, nails),
+            out
+        );
+        assert_eq!(
+            Natural::import_words(out, word_size, word_order, byte_order, nails),
+            x
+        );
+    };
+    test("0", 1, Endianness::Little, Endianness::Little, 0, &[]);
+    test("0", 4, Endianness::Big, Endianness::Big, 3, &[]);
+    test(
+        "1193046",
+        2,
+        Endianness::Little,
+        Endianness::Little,
+        0,
+        &[0x56, 0x34, 0x12, 0x00],
+    );
+    test(
+        "1193046",
+        2,
+        Endianness::Little,
+        Endianness::Big,
+        0,
+        &[0x34, 0x56, 0x00, 0x12],
+    );
+    test(
+        "1193046",
+        2,
+        Endianness::Big,
+        Endianness::Little,
+        0,
+        &[0x12, 0x00, 0x56, 0x34],
+    );
+    test(
+        "1193046",
+        2,
+        Endianness::Big,
+        Endianness::Big,
+        0,
+        &[0x00, 0x12, 0x34, 0x56],
+    );
+    test(
+        "1193046",
+        1,
+        Endianness::Little,
+        Endianness::Little,
+        1,
+        &[0x56, 0x68, 0x48],
+    );
+    test(
+        "1193046",
+        2,
+        Endianness::Big,
+        Endianness::Big,
+        4,
+        &[0x01, 0x23, 0x04, 0x56],
+    );
+    test(
+        "1193046",
+        3,
+        Endianness::Little,
+        Endianness::Big,
+        0,
+        &[0x12, 0x34, 0x56],
+    );
+}
+
+#[test]
+fn test_import_words_ignores_nails() {
+    assert_eq!(
+        Natural::import_words(
+            &[0xd6, 0xe8, 0x48],
+            1,
+            Endianness::Little,
+            Endianness::Little,
+            1
+        ),
+        0x123456
+    );
+    assert_eq!(
+        Natural::import_words(&[0xff, 0xff], 2, Endianness::Big, Endianness::Big, 15),
+        1
+    );
+}
+
+#[test]
+#[should_panic]
+fn export_words_fail_1() {
+    Natural::from(10u32).export_words(0, Endianness::Little, Endianness::Little, 0);
+}
+
+#[test]
+#[should_panic]
+fn export_words_fail_2() {
+    Natural::from(10u32).export_words(2, Endianness::Little, Endianness::Little, 16);
+}
+
+#[test]
+#[should_panic]
+fn import_words_fail_1() {
+    Natural::import_words(&[], 0, Endianness::Little, Endianness::Little, 0);
+}
+
+#[test]
+#[should_panic]
+fn import_words_fail_2() {
+    Natural::import_words(&[1, 2], 1, Endianness::Little, Endianness::Little, 8);
+}
+
+#[test]
+#[should_panic]
+fn import_words_fail_3() {
+    Natural::import_words(&[1, 2, 3], 2, Endianness::Little, Endianness::Little, 0);
+}
+
+const ENDIANNESSES: [Endianness; 2] = [Endianness::Little, Endianness::Big];
+
+#[test]
+fn bytes_properties() {
+    natural_gen().test_properties(|x| {
+        let bytes_le = x.to_bytes_le();
+        assert_eq!(
+            bytes_le.len(),
+            usize::try_from((x.significant_bits() + 7) >> 3).unwrap()
+        );
+        assert_ne!(bytes_le.last(), Some(&0));
+        assert_eq!(Natural::from_bytes_le(&bytes_le), x);
+        let bytes_be = x.to_bytes_be();
+        assert_eq!(bytes_be.iter().rev().copied().collect_vec(), bytes_le);
+        assert_eq!(Natural::from_bytes_be(&bytes_be), x);
+        assert_eq!(x.to_bytes_le_padded(bytes_le.len()).unwrap(), bytes_le);
+        assert_eq!(x.to_bytes_be_padded(bytes_be.len()).unwrap(), bytes_be);
+        if x != 0 {
+            assert!(x.to_bytes_le_padded(bytes_le.len() - 1).is_none());
+            assert!(x.to_bytes_be_padded(bytes_be.len() - 1).is_none());
+        }
+        for word_order in ENDIANNESSES {
+            for byte_order in ENDIANNESSES {
+                for word_size in 1..10 {
+                    for nails in [0, 1, 7] {
+                        let words = x.export_words(word_size, word_order, byte_order, nails);
+                        assert_eq!(words.len() % word_size, 0);
+                        assert_eq!(
+                            Natural::import_words(&words, word_size, word_order, byte_order, nails),
+                            x
+                        );
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            x.export_words(1, Endianness::Little, Endianness::Little, 0),
+            bytes_le
+        );
+        assert_eq!(
+            x.export_words(1, Endianness::Big, Endianness::Big, 0),
+            bytes_be
+        );
+    });
+
+    natural_unsigned_pair_gen_var_4::<u8>().test_properties(|(x, len)| {
+        let len = usize::from(len);
+        let bytes_le = x.to_bytes_le();
+        match x.to_bytes_le_padded(len) {
+            Some(padded) => {
+                assert_eq!(padded.len(), len);
+                assert_eq!(Natural::from_bytes_le(&padded), x);
+            }
+            None => assert!(bytes_le.len() > len),
+        }
+        match x.to_bytes_be_padded(len) {
+            Some(padded) => {
+                assert_eq!(padded.len(), len);
+                assert_eq!(Natural::from_bytes_be(&padded), x);
+            }
+            None => assert!(bytes_le.len() > len),
+        }
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    unsigned_vec_gen::<u8>().test_properties_with_config(&config, |xs| {
+        let x = Natural::from_bytes_le(&xs);
+        assert_eq!(x.to_bytes_le(), &xs[..xs.len() - slice_trailing_zeros(&xs)]);
+        let mut ys = xs.clone();
+        ys.reverse();
+        assert_eq!(Natural::from_bytes_be(&ys), x);
+    });
+}
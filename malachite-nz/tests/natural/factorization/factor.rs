@@ -0,0 +1,115 @@
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, Factorial, Pow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_28};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn factor_check(n: &Natural) -> Vec<(Natural, u64)> {
+    let factors = n.factor();
+    assert!(factors.iter().all(|(p, _)| p.is_valid()));
+    assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+    let mut product = Natural::ONE;
+    for (p, e) in &factors {
+        assert!(p.is_prime());
+        assert_ne!(*e, 0);
+        product *= p.pow(*e);
+    }
+    assert_eq!(product, *n);
+    factors
+}
+
+#[test]
+fn test_factor() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        assert_eq!(factor_check(&n).to_debug_string(), out);
+    };
+    test("1", "[]");
+    test("2", "[(2, 1)]");
+    test("360", "[(2, 3), (3, 2), (5, 1)]");
+    test(
+        "18446744073709551615",
+        "[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]",
+    );
+    test("18446744073709551617", "[(274177, 1), (67280421310721, 1)]");
+    test("18446744073709551629", "[(18446744073709551629, 1)]");
+    test(
+        "340282366920938463463374607431768211457",
+        "[(59649589127497217, 1), (5704689200685129054721, 1)]",
+    );
+    test(
+        "1000000000000000000000000000228000000000000000000000000019494000000000000000000000000740772\
+        000000000000000000000010556001",
+        "[(1000000000000000000000000000057, 4)]",
+    );
+    test(
+        "7000000086569037000000457579",
+        "[(1000000012367, 1), (7000000000000037, 1)]",
+    );
+    test(
+        "7000000000000087073037000000000000460243",
+        "[(1000000000000012439, 1), (7000000000000000000037, 1)]",
+    );
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639937",
+        "[(1238926361552897, 1), (93461639715357977769163558199606896584051237541638188580280321, \
+        1)]",
+    );
+
+    // 10007 ^ 13 has 173 bits, fewer than 14 * 13
+    assert_eq!(
+        Natural::from(10007u32).pow(13).factor().to_debug_string(),
+        "[(10007, 13)]"
+    );
+    assert_eq!(
+        Natural::factorial(30).factor().to_debug_string(),
+        "[(2, 26), (3, 14), (5, 7), (7, 4), (11, 2), (13, 2), (17, 1), (19, 1), (23, 1), (29, 1)]"
+    );
+    assert_eq!(
+        Natural::binomial_coefficient(Natural::from(100u32), Natural::from(50u32))
+            .factor()
+            .to_debug_string(),
+        "[(2, 3), (3, 4), (11, 1), (13, 1), (17, 1), (19, 1), (29, 1), (31, 1), (53, 1), (59, 1), \
+        (61, 1), (67, 1), (71, 1), (73, 1), (79, 1), (83, 1), (89, 1), (97, 1)]"
+    );
+}
+
+#[test]
+fn factor_fail() {
+    assert_panic!(Natural::ZERO.factor());
+}
+
+#[test]
+fn factor_properties() {
+    natural_gen_var_2().test_properties(|n| {
+        if n.significant_bits() <= 72 {
+            let factors = factor_check(&n);
+            assert_eq!(n.is_prime(), factors.len() == 1 && factors[0].1 == 1);
+        }
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(
+            Natural::from(n).factor(),
+            n.factor()
+                .into_iter()
+                .map(|(p, e)| (Natural::from(p), e))
+                .collect::<Vec<_>>()
+        );
+    });
+
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties(|(n, k)| {
+        factor_check(&Natural::factorial(n % 500));
+        // Choose k <= n, so that the binomial coefficient is nonzero
+        let n = n % 1000;
+        factor_check(&Natural::binomial_coefficient(
+            Natural::from(n),
+            Natural::from(k % (n + 1)),
+        ));
+    });
+}
@@ -0,0 +1,97 @@
+use malachite_base::num::arithmetic::traits::{Parity, Pow, Square};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_gen_var_9};
+use rug::integer::IsPrime as RugIsPrime;
+use std::str::FromStr;
+
+#[test]
+fn test_is_prime() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.is_probable_prime(0), out);
+        assert_eq!(n.is_probable_prime(10), out);
+        assert_eq!(
+            rug::Integer::from_str(s).unwrap().is_probably_prime(30) != RugIsPrime::No,
+            out
+        );
+    };
+    test("0", false);
+    test("1", false);
+    test("2", true);
+    test("3", true);
+    test("4", false);
+    test("97", true);
+    test("561", false);
+    test("2047", false);
+    test("5459", false);
+    test("3825123056546413051", false);
+    test("18446744073709551557", true);
+    test("18446744073709551615", false);
+    test("18446744073709551617", false);
+    test("18446744073709551629", true);
+    test("318665857834031151167461", false);
+    test("3317044064679887385961981", false);
+    test("170141183460469231731687303715884105727", true);
+    test("340282366920938463463374607431768211297", true);
+    test("340282366920938463463374607431768211457", false);
+    test(
+        "6864797660130609714981900799081393217269435300143305409394463459185543183397656052122559\
+        640661454554977296311391480858037121987999716643812574028291115057151",
+        true,
+    );
+    test(
+        "2745919064052243885992760319632557286907774120057322163763785383674217273359062420849023\
+        8564265818217091895127556312143344788851197706657502293611316460228607",
+        false,
+    );
+}
+
+#[test]
+fn test_is_prime_mersenne() {
+    let exponents = [2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127, 521, 607];
+    for p in u64::primes_less_than(&700) {
+        let n = Natural::TWO.pow(p) - Natural::ONE;
+        assert_eq!(n.is_prime(), exponents.contains(&p));
+    }
+}
+
+#[test]
+fn is_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(
+            rug::Integer::from(&n).is_probably_prime(30) != RugIsPrime::No,
+            is_prime
+        );
+        assert_eq!(n.is_probable_prime(2), is_prime);
+        if is_prime && n != 2u32 {
+            assert!(n.odd());
+        }
+        if n > 1u32 {
+            assert!(!(&n).square().is_prime());
+            assert!(!(&n * Natural::from(3u32)).is_prime());
+        }
+    });
+
+    natural_gen_var_9().test_properties(|n| {
+        assert_eq!(
+            n.is_prime(),
+            Natural::primes_less_than_or_equal_to(&n).last().as_ref() == Some(&n)
+        );
+    });
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        assert_eq!(Natural::from(n).is_prime(), n.is_prime());
+    });
+
+    unsigned_gen::<u128>().test_properties(|n| {
+        let x = Natural::from(n);
+        assert_eq!(x.is_prime(), n.is_prime());
+        assert_eq!(u128::exact_from(&x), n);
+    });
+}
@@ -0,0 +1,146 @@
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrevPrime, Primes};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_gen_var_9};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_next_prime() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        let p = n.next_prime();
+        assert_eq!(p.to_string(), out);
+        assert_eq!(
+            rug::Integer::from_str(s).unwrap().next_prime().to_string(),
+            out
+        );
+    };
+    test("0", "2");
+    test("1", "2");
+    test("2", "3");
+    test("3", "5");
+    test("100", "101");
+    test("1000000", "1000003");
+    test("18446744073709551556", "18446744073709551557");
+    test("18446744073709551557", "18446744073709551629");
+    test("18446744073709551615", "18446744073709551629");
+    test("18446744073709551629", "18446744073709551653");
+    test(
+        "1000000000000000000000000000000",
+        "1000000000000000000000000000057",
+    );
+    test(
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211507",
+    );
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+        "115792089237316195423570985008687907853269984665640564039457584007913129640233",
+    );
+}
+
+#[test]
+fn test_prev_prime() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        let p = n.prev_prime();
+        assert_eq!(p.to_string(), out);
+        assert_eq!(
+            rug::Integer::from_str(s).unwrap().prev_prime().to_string(),
+            out
+        );
+    };
+    test("3", "2");
+    test("4", "3");
+    test("100", "97");
+    test("1000000", "999983");
+    test("18446744073709551615", "18446744073709551557");
+    test("18446744073709551616", "18446744073709551557");
+    test("18446744073709551629", "18446744073709551557");
+    test("18446744073709551630", "18446744073709551629");
+    test(
+        "1000000000000000000000000000000",
+        "999999999999999999999999999989",
+    );
+    test(
+        "340282366920938463463374607431768211456",
+        "340282366920938463463374607431768211297",
+    );
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639747",
+    );
+}
+
+#[test]
+fn prev_prime_fail() {
+    assert_panic!(Natural::ZERO.prev_prime());
+    assert_panic!(Natural::from(1u32).prev_prime());
+    assert_panic!(Natural::TWO.prev_prime());
+}
+
+#[test]
+fn test_next_prime_powers_of_2() {
+    // The smallest prime greater than 2 ^ k is 2 ^ k + d
+    let test = |k: u64, d: u32| {
+        let n = Natural::TWO.pow(k);
+        assert_eq!(n.next_prime() - &n, d);
+    };
+    test(64, 13);
+    test(128, 51);
+    test(256, 297);
+    test(512, 75);
+    test(1024, 643);
+}
+
+#[test]
+fn next_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let p = n.next_prime();
+        assert!(p > n);
+        assert!(p.is_prime());
+        assert_eq!(Natural::exact_from(&rug::Integer::from(&n).next_prime()), p);
+        if p > 2u32 {
+            assert!(p.prev_prime() <= n);
+        }
+    });
+
+    natural_gen_var_9().test_properties(|n| {
+        assert_eq!(Some(n.next_prime()), Natural::primes().find(|p| *p > n));
+    });
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        assert_eq!(Natural::from(n).next_prime(), u128::from(n).next_prime());
+    });
+}
+
+#[test]
+fn prev_prime_properties() {
+    natural_gen().test_properties(|n| {
+        if n <= 2u32 {
+            return;
+        }
+        let p = n.prev_prime();
+        assert!(p < n);
+        assert!(p.is_prime());
+        assert_eq!(Natural::exact_from(&rug::Integer::from(&n).prev_prime()), p);
+        assert!(p.next_prime() >= n);
+    });
+
+    natural_gen_var_9().test_properties(|n| {
+        if n <= 2u32 {
+            return;
+        }
+        assert_eq!(Some(n.prev_prime()), Natural::primes_less_than(&n).last());
+    });
+
+    unsigned_gen::<u128>().test_properties(|n| {
+        if n > 2 {
+            assert_eq!(Natural::from(n).prev_prime(), n.prev_prime());
+        }
+    });
+}
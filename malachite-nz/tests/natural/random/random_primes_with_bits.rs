@@ -0,0 +1,158 @@
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_base::test_util::stats::moments::MomentStats;
+use malachite_nz::natural::random::random_primes_with_bits;
+use malachite_nz::test_util::natural::random::random_naturals_helper_helper;
+
+fn random_primes_with_bits_helper(
+    bits: u64,
+    expected_values: &[&str],
+    expected_common_values: &[(&str, usize)],
+    expected_sample_median: (&str, Option<&str>),
+    expected_sample_moment_stats: MomentStats,
+) {
+    random_naturals_helper_helper(
+        random_primes_with_bits(EXAMPLE_SEED, bits),
+        expected_values,
+        expected_common_values,
+        expected_sample_median,
+        expected_sample_moment_stats,
+    );
+}
+
+#[test]
+fn test_random_primes_with_bits() {
+    let values = &[
+        "3", "3", "3", "3", "3", "3", "2", "2", "2", "3", "2", "3", "2", "3", "2", "2", "3", "3",
+        "3", "3",
+    ];
+    let common_values = &[("2", 500399), ("3", 499601)];
+    let sample_median = ("2", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(2.4996009999998368),
+        standard_deviation: NiceFloat(0.5000000907990857),
+        skewness: NiceFloat(0.0015960005081696673),
+        excess_kurtosis: NiceFloat(-1.9999974527823994),
+    };
+    random_primes_with_bits_helper(2, values, common_values, sample_median, sample_moment_stats);
+    let values = &[
+        "5", "7", "5", "5", "7", "5", "7", "5", "7", "7", "5", "7", "7", "5", "7", "7", "7", "5",
+        "7", "7",
+    ];
+    let common_values = &[("7", 501268), ("5", 498732)];
+    let sample_median = ("7", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(6.002536000000046),
+        standard_deviation: NiceFloat(0.9999972843456104),
+        skewness: NiceFloat(-0.0050720163098450805),
+        excess_kurtosis: NiceFloat(-1.9999742746505949),
+    };
+    random_primes_with_bits_helper(3, values, common_values, sample_median, sample_moment_stats);
+    let values = &[
+        "881", "599", "727", "641", "647", "797", "839", "647", "599", "593", "857", "829", "691",
+        "613", "683", "929", "733", "683", "751", "593",
+    ];
+    let common_values = &[
+        ("727", 13711),
+        ("547", 13544),
+        ("643", 13539),
+        ("607", 13536),
+        ("661", 13473),
+        ("691", 13469),
+        ("1013", 13468),
+        ("911", 13455),
+        ("653", 13452),
+        ("733", 13445),
+    ];
+    let sample_median = ("761", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(768.4881879999868),
+        standard_deviation: NiceFloat(147.09336870483202),
+        skewness: NiceFloat(0.07555985068738975),
+        excess_kurtosis: NiceFloat(-1.2178202402134548),
+    };
+    random_primes_with_bits_helper(
+        10,
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+    let values = &[
+        "2381873899",
+        "3441745031",
+        "3156494423",
+        "3008075069",
+        "2243260091",
+        "3173155549",
+        "3262431643",
+        "3664058753",
+        "3607270493",
+        "3327300287",
+        "3930973621",
+        "2202830711",
+        "3826648093",
+        "3828906973",
+        "2811406673",
+        "3094419479",
+        "2169730993",
+        "3567094697",
+        "3236393221",
+        "3208413667",
+    ];
+    let common_values = &[
+        ("2230511267", 3),
+        ("2250019589", 3),
+        ("2397039223", 3),
+        ("2588854003", 3),
+        ("3095176771", 3),
+        ("3098455757", 3),
+        ("3104483159", 3),
+        ("3185192477", 3),
+        ("3369877939", 3),
+        ("3511148653", 3),
+    ];
+    let sample_median = ("3212589439", Some("3212592059"));
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(3215825953.9623976),
+        standard_deviation: NiceFloat(619784738.1331611),
+        skewness: NiceFloat(0.010582302543576028),
+        excess_kurtosis: NiceFloat(-1.1997837200576094),
+    };
+    random_primes_with_bits_helper(
+        32,
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+}
+
+#[test]
+fn test_random_primes_with_bits_large() {
+    let xs = random_primes_with_bits(EXAMPLE_SEED, 256)
+        .take(5)
+        .collect::<Vec<_>>();
+    for x in &xs {
+        assert_eq!(x.significant_bits(), 256);
+        assert!(x.is_prime());
+    }
+    assert_eq!(
+        xs.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        &[
+            "90940578488510162533659490724030586846246437681175877305173649005623339367449",
+            "67990936465117988210392646564976218942490357059543934313137551374763765079983",
+            "87540175601457768086766371658159478292670441305532749023405437691639766510303",
+            "77466830683435135031724370220411805237627946237487253677783708503971360214379",
+            "93024543056590674406566586230078693727335088244691638716199323794781973767741",
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn random_primes_with_bits_fail() {
+    random_primes_with_bits(EXAMPLE_SEED, 1);
+}
@@ -0,0 +1,151 @@
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_base::test_util::stats::moments::MomentStats;
+use malachite_nz::natural::random::random_safe_primes_with_bits;
+use malachite_nz::test_util::natural::random::random_naturals_helper_helper;
+
+fn random_safe_primes_with_bits_helper(
+    bits: u64,
+    expected_values: &[&str],
+    expected_common_values: &[(&str, usize)],
+    expected_sample_median: (&str, Option<&str>),
+    expected_sample_moment_stats: MomentStats,
+) {
+    random_naturals_helper_helper(
+        random_safe_primes_with_bits(EXAMPLE_SEED, bits),
+        expected_values,
+        expected_common_values,
+        expected_sample_median,
+        expected_sample_moment_stats,
+    );
+}
+
+#[test]
+fn test_random_safe_primes_with_bits() {
+    let values = &[
+        "5", "7", "5", "5", "7", "5", "7", "7", "5", "7", "5", "7", "7", "5", "5", "5", "7", "7",
+        "5", "5",
+    ];
+    let common_values = &[("7", 500476), ("5", 499524)];
+    let sample_median = ("7", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(6.000951999999925),
+        standard_deviation: NiceFloat(1.0000000468480454),
+        skewness: NiceFloat(-0.0019040008628020426),
+        excess_kurtosis: NiceFloat(-1.9999963747807399),
+    };
+    random_safe_primes_with_bits_helper(
+        3,
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+    let values = &[
+        "23", "23", "23", "23", "23", "23", "23", "23", "23", "23", "23", "23", "23", "23", "23",
+        "23", "23", "23", "23", "23",
+    ];
+    let common_values = &[("23", 1000000)];
+    let sample_median = ("23", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(23.0),
+        standard_deviation: NiceFloat(0.0),
+        skewness: NiceFloat(f64::NAN),
+        excess_kurtosis: NiceFloat(f64::NAN),
+    };
+    random_safe_primes_with_bits_helper(
+        5,
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+    let values = &[
+        "863", "839", "563", "563", "839", "1019", "887", "863", "1019", "839", "1019", "587",
+        "1019", "1019", "887", "1019", "563", "983", "983", "839",
+    ];
+    let common_values = &[
+        ("839", 125464),
+        ("983", 125300),
+        ("719", 125192),
+        ("563", 125021),
+        ("887", 124975),
+        ("587", 124789),
+        ("1019", 124652),
+        ("863", 124607),
+    ];
+    let sample_median = ("839", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(807.4942640000129),
+        standard_deviation: NiceFloat(158.9183458199188),
+        skewness: NiceFloat(-0.32562241222992255),
+        excess_kurtosis: NiceFloat(-1.2377092592504657),
+    };
+    random_safe_primes_with_bits_helper(
+        10,
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+    let values = &[
+        "63719", "34703", "51287", "41507", "61703", "53783", "36083", "43427", "59063", "39983",
+        "42767", "49019", "61703", "36887", "44699", "42443", "41543", "47507", "57719", "39419",
+    ];
+    let common_values = &[
+        ("51599", 5408),
+        ("33647", 5346),
+        ("36467", 5344),
+        ("34319", 5338),
+        ("51047", 5338),
+        ("40823", 5320),
+        ("38603", 5319),
+        ("45707", 5319),
+        ("36083", 5313),
+        ("56003", 5307),
+    ];
+    let sample_median = ("47507", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(48437.063587998826),
+        standard_deviation: NiceFloat(9324.95534157985),
+        skewness: NiceFloat(0.1409929619492136),
+        excess_kurtosis: NiceFloat(-1.1707055082753435),
+    };
+    random_safe_primes_with_bits_helper(
+        16,
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+}
+
+#[test]
+fn test_random_safe_primes_with_bits_large() {
+    let xs = random_safe_primes_with_bits(EXAMPLE_SEED, 128)
+        .take(5)
+        .collect::<Vec<_>>();
+    for x in &xs {
+        assert_eq!(x.significant_bits(), 128);
+        assert!(x.is_prime());
+        assert!((x >> 1u32).is_prime());
+    }
+    assert_eq!(
+        xs.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        &[
+            "327046585580438051957751563860503001283",
+            "254784675657323854056903435199811849927",
+            "188218996120914246459822068173070612243",
+            "225613498437018723114275695057016906303",
+            "313627171211384456654445474197866857539",
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn random_safe_primes_with_bits_fail() {
+    random_safe_primes_with_bits(EXAMPLE_SEED, 2);
+}
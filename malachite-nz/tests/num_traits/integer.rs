@@ -0,0 +1,369 @@
+use malachite_base::num::arithmetic::traits::{
+    Abs, CeilingRoot, DivMod, DivRem, DivRound, DivisibleBy, FloorRoot, Gcd, Lcm, Mod, Parity, Pow,
+    Sign,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::{RoundingFrom, ToStringBase};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::{primitive_float_gen, signed_gen};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::conversion::num_traits::FromStrRadixError;
+use malachite_nz::test_util::generators::{
+    integer_gen, integer_gen_var_4, integer_pair_gen, integer_pair_gen_var_1,
+    integer_unsigned_pair_gen_var_2, integer_unsigned_pair_gen_var_3,
+};
+use num_integer::Roots;
+use num_traits::{FromPrimitive, Num, Signed, ToPrimitive};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[test]
+fn test_zero_and_one() {
+    assert_eq!(<Integer as num_traits::Zero>::zero(), 0);
+    assert_eq!(<Integer as num_traits::One>::one(), 1);
+    assert!(num_traits::Zero::is_zero(&Integer::ZERO));
+    assert!(!num_traits::Zero::is_zero(&Integer::NEGATIVE_ONE));
+    assert!(num_traits::One::is_one(&Integer::ONE));
+    assert!(!num_traits::One::is_one(&Integer::NEGATIVE_ONE));
+}
+
+#[test]
+fn test_from_str_radix() {
+    let test = |s, radix, out: Option<&str>| {
+        assert_eq!(
+            Integer::from_str_radix(s, radix)
+                .ok()
+                .map(|n| n.to_string()),
+            out.map(ToString::to_string)
+        );
+    };
+    test("0", 10, Some("0"));
+    test("-0", 10, Some("0"));
+    test("123", 10, Some("123"));
+    test("-123", 10, Some("-123"));
+    test("-ff", 16, Some("-255"));
+    test("zz", 36, Some("1295"));
+    test("", 10, None);
+    test("-", 10, None);
+    test("--1", 10, None);
+    test("12", 2, None);
+}
+
+#[test]
+fn test_from_str_radix_error() {
+    let test = |s, radix, out| {
+        assert_eq!(Integer::from_str_radix(s, radix), Err(out));
+    };
+    test("1", 0, FromStrRadixError::InvalidRadix);
+    test("1", 1, FromStrRadixError::InvalidRadix);
+    test("1", 37, FromStrRadixError::InvalidRadix);
+    test("1", 256, FromStrRadixError::InvalidRadix);
+    test("1", u32::MAX, FromStrRadixError::InvalidRadix);
+    test("", 10, FromStrRadixError::InvalidString);
+    test("-12", 2, FromStrRadixError::InvalidString);
+}
+
+#[test]
+fn test_signed() {
+    let test = |s, abs, signum, is_positive, is_negative| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(Signed::abs(&x).to_string(), abs);
+        assert_eq!(x.signum().to_string(), signum);
+        assert_eq!(x.is_positive(), is_positive);
+        assert_eq!(x.is_negative(), is_negative);
+    };
+    test("0", "0", "0", false, false);
+    test("123", "123", "1", true, false);
+    test("-123", "123", "-1", false, true);
+    test(
+        "-1000000000000000000000",
+        "1000000000000000000000",
+        "-1",
+        false,
+        true,
+    );
+
+    let test_abs_sub = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+        assert_eq!(x.abs_sub(&y).to_string(), out);
+    };
+    test_abs_sub("10", "3", "7");
+    test_abs_sub("3", "10", "0");
+    test_abs_sub("-3", "-10", "7");
+    test_abs_sub("-10", "-3", "0");
+}
+
+#[test]
+fn test_to_primitive() {
+    let test = |s, i: Option<i64>, u: Option<u64>, f: f64| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(x.to_i64(), i);
+        assert_eq!(x.to_u64(), u);
+        assert_eq!(x.to_f64(), Some(f));
+    };
+    test("0", Some(0), Some(0), 0.0);
+    test("-123", Some(-123), None, -123.0);
+    test(
+        "-9223372036854775808",
+        Some(i64::MIN),
+        None,
+        -9.223372036854776e18,
+    );
+    test("-9223372036854775809", None, None, -9.223372036854776e18);
+    test(
+        "18446744073709551615",
+        None,
+        Some(u64::MAX),
+        1.8446744073709552e19,
+    );
+    let x = -Integer::from(10u32).pow(400);
+    assert_eq!(x.to_i128(), None);
+    assert_eq!(x.to_f64(), Some(-f64::MAX));
+}
+
+#[test]
+fn test_from_primitive() {
+    assert_eq!(Integer::from_i64(-123).unwrap(), -123);
+    assert_eq!(Integer::from_i128(i128::MIN).unwrap(), i128::MIN);
+    assert_eq!(Integer::from_u128(u128::MAX).unwrap(), u128::MAX);
+    assert_eq!(Integer::from_f64(-1.5).unwrap(), -1);
+    assert_eq!(Integer::from_f64(-0.5).unwrap(), 0);
+    assert_eq!(Integer::from_f64(2.5).unwrap(), 2);
+    assert_eq!(Integer::from_f64(f64::NAN), None);
+    assert_eq!(Integer::from_f64(f64::NEG_INFINITY), None);
+    assert_eq!(
+        Integer::from_f32(-1.0e20).unwrap(),
+        -100000002004087734272i128
+    );
+}
+
+#[test]
+fn test_checked_ops() {
+    let x = Integer::from(-10);
+    let y = Integer::from(3);
+    assert_eq!(num_traits::CheckedAdd::checked_add(&x, &y).unwrap(), -7);
+    assert_eq!(num_traits::CheckedSub::checked_sub(&y, &x).unwrap(), 13);
+    assert_eq!(num_traits::CheckedMul::checked_mul(&x, &y).unwrap(), -30);
+    assert_eq!(num_traits::CheckedDiv::checked_div(&x, &y).unwrap(), -3);
+    assert_eq!(
+        num_traits::CheckedDiv::checked_div(&x, &Integer::ZERO),
+        None
+    );
+    assert_eq!(num_traits::CheckedRem::checked_rem(&x, &y).unwrap(), -1);
+    assert_eq!(
+        num_traits::CheckedRem::checked_rem(&x, &Integer::ZERO),
+        None
+    );
+    assert_eq!(num_traits::CheckedNeg::checked_neg(&x).unwrap(), 10);
+}
+
+#[test]
+fn test_num_integer() {
+    let test = |s, t, div_floor, mod_floor, div_ceil, div_rem: (&str, &str), gcd, lcm| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+        assert_eq!(
+            num_integer::Integer::div_floor(&x, &y).to_string(),
+            div_floor
+        );
+        assert_eq!(
+            num_integer::Integer::mod_floor(&x, &y).to_string(),
+            mod_floor
+        );
+        let (q, r) = num_integer::Integer::div_mod_floor(&x, &y);
+        assert_eq!(
+            (q.to_string().as_str(), r.to_string().as_str()),
+            (div_floor, mod_floor)
+        );
+        assert_eq!(num_integer::Integer::div_ceil(&x, &y).to_string(), div_ceil);
+        let (q, r) = num_integer::Integer::div_rem(&x, &y);
+        assert_eq!((q.to_string().as_str(), r.to_string().as_str()), div_rem);
+        assert_eq!(num_integer::Integer::gcd(&x, &y).to_string(), gcd);
+        assert_eq!(num_integer::Integer::lcm(&x, &y).to_string(), lcm);
+    };
+    test("23", "10", "2", "3", "3", ("2", "3"), "1", "230");
+    test("23", "-10", "-3", "-7", "-2", ("-2", "3"), "1", "230");
+    test("-23", "10", "-3", "7", "-2", ("-2", "-3"), "1", "230");
+    test("-23", "-10", "2", "-3", "3", ("2", "-3"), "1", "230");
+    test("-12", "8", "-2", "4", "-1", ("-1", "-4"), "4", "24");
+    test("0", "-5", "0", "0", "0", ("0", "0"), "5", "0");
+    assert!(num_integer::Integer::is_even(&Integer::from(-4)));
+    assert!(num_integer::Integer::is_odd(&Integer::from(-3)));
+    assert!(num_integer::Integer::is_multiple_of(
+        &Integer::from(-12),
+        &Integer::from(4)
+    ));
+    assert!(!num_integer::Integer::is_multiple_of(
+        &Integer::from(-12),
+        &Integer::from(5)
+    ));
+}
+
+#[test]
+fn test_roots() {
+    let test = |s, n, out| {
+        assert_eq!(Integer::from_str(s).unwrap().nth_root(n).to_string(), out);
+    };
+    test("0", 1, "0");
+    test("100", 2, "10");
+    test("99", 2, "9");
+    test("-1000", 3, "-10");
+    test("-999", 3, "-9");
+    test("-1001", 3, "-10");
+    test("-1000000000000000000000", 7, "-1000");
+    assert_eq!(Integer::from(99).sqrt(), 9);
+    assert_eq!(Integer::from(-999).cbrt(), -9);
+}
+
+#[test]
+#[should_panic]
+fn nth_root_fail_1() {
+    Integer::from(10).nth_root(0);
+}
+
+#[test]
+#[should_panic]
+fn nth_root_fail_2() {
+    Integer::from(-10).nth_root(2);
+}
+
+#[test]
+#[should_panic]
+fn sqrt_fail() {
+    Integer::from(-10).sqrt();
+}
+
+#[test]
+fn num_traits_properties() {
+    integer_gen().test_properties(|x| {
+        assert_eq!(num_traits::Zero::is_zero(&x), x == 0);
+        assert_eq!(num_traits::One::is_one(&x), x == 1);
+        for radix in [2, 10, 16, 36] {
+            assert_eq!(
+                Integer::from_str_radix(&x.to_string_base(radix), u32::from(radix)).unwrap(),
+                x
+            );
+        }
+        assert_eq!(Signed::abs(&x), Abs::abs(&x));
+        assert_eq!(
+            x.signum(),
+            match x.sign() {
+                Ordering::Less => -1,
+                Ordering::Equal => 0,
+                Ordering::Greater => 1,
+            }
+        );
+        assert_eq!(x.is_positive(), x > 0);
+        assert_eq!(x.is_negative(), x < 0);
+        assert_eq!(x.to_i64(), i64::try_from(&x).ok());
+        assert_eq!(x.to_u64(), u64::try_from(&x).ok());
+        assert_eq!(x.to_i128(), i128::try_from(&x).ok());
+        assert_eq!(
+            x.to_f64(),
+            Some(f64::rounding_from(&x, RoundingMode::Nearest).0)
+        );
+        assert_eq!(num_traits::CheckedNeg::checked_neg(&x).unwrap(), -&x);
+        assert_eq!(num_integer::Integer::is_even(&x), x.even());
+        assert_ne!(
+            num_integer::Integer::is_even(&x),
+            num_integer::Integer::is_odd(&x)
+        );
+        assert_eq!(x.cbrt(), -(-&x).cbrt());
+    });
+
+    integer_gen_var_4().test_properties(|x| {
+        let sqrt = x.sqrt();
+        assert!(&sqrt * &sqrt <= x);
+        assert_eq!(x.nth_root(2), x.sqrt());
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(
+            num_traits::CheckedAdd::checked_add(&x, &y).unwrap(),
+            &x + &y
+        );
+        assert_eq!(
+            num_traits::CheckedSub::checked_sub(&x, &y).unwrap(),
+            &x - &y
+        );
+        assert_eq!(
+            num_traits::CheckedMul::checked_mul(&x, &y).unwrap(),
+            &x * &y
+        );
+        let gcd = num_integer::Integer::gcd(&x, &y);
+        assert!(gcd >= 0);
+        assert_eq!(
+            gcd,
+            Integer::from(x.unsigned_abs_ref().gcd(y.unsigned_abs_ref()))
+        );
+        let lcm = num_integer::Integer::lcm(&x, &y);
+        assert!(lcm >= 0);
+        assert_eq!(
+            lcm,
+            Integer::from(x.unsigned_abs_ref().lcm(y.unsigned_abs_ref()))
+        );
+        assert_eq!(
+            num_integer::Integer::is_multiple_of(&x, &y),
+            (&x).divisible_by(&y)
+        );
+        let abs_sub = x.abs_sub(&y);
+        assert!(abs_sub >= 0);
+        assert_eq!(abs_sub, if x > y { &x - &y } else { Integer::ZERO });
+    });
+
+    integer_pair_gen_var_1().test_properties(|(x, y)| {
+        assert_eq!(num_integer::Integer::div_rem(&x, &y), (&x).div_rem(&y));
+        assert_eq!(
+            num_integer::Integer::div_mod_floor(&x, &y),
+            (&x).div_mod(&y)
+        );
+        assert_eq!(
+            num_integer::Integer::div_floor(&x, &y),
+            (&x).div_round(&y, RoundingMode::Floor).0
+        );
+        assert_eq!(num_integer::Integer::mod_floor(&x, &y), (&x).mod_op(&y));
+        assert_eq!(
+            num_integer::Integer::div_ceil(&x, &y),
+            (&x).div_round(&y, RoundingMode::Ceiling).0
+        );
+        assert_eq!(
+            num_traits::CheckedDiv::checked_div(&x, &y).unwrap(),
+            &x / &y
+        );
+        assert_eq!(
+            num_traits::CheckedRem::checked_rem(&x, &y).unwrap(),
+            &x % &y
+        );
+    });
+
+    integer_unsigned_pair_gen_var_3::<u32>().test_properties(|(x, n)| {
+        let root = x.nth_root(n);
+        if x >= 0 {
+            assert_eq!(root, (&x).floor_root(u64::from(n)));
+        } else {
+            assert_eq!(root, (&x).ceiling_root(u64::from(n)));
+        }
+        if n.odd() {
+            assert_eq!(-(-&x).nth_root(n), root);
+        }
+    });
+
+    integer_unsigned_pair_gen_var_2::<u64>().test_properties(|(x, n)| {
+        assert_eq!(num_traits::Pow::pow(&x, n), (&x).pow(n));
+    });
+
+    signed_gen::<i64>().test_properties(|i| {
+        let x = Integer::from_i64(i).unwrap();
+        assert_eq!(x, i);
+        assert_eq!(x.to_i64(), Some(i));
+    });
+
+    primitive_float_gen::<f64>().test_properties(|f| {
+        let x = Integer::from_f64(f);
+        assert_eq!(x.is_some(), f.is_finite());
+        if let Some(x) = x {
+            assert_eq!(x, Integer::rounding_from(f.trunc(), RoundingMode::Exact).0);
+        }
+    });
+}
@@ -0,0 +1,301 @@
+use malachite_base::num::arithmetic::traits::{
+    CheckedSub, DivRem, DivRound, DivisibleBy, FloorRoot, FloorSqrt, Gcd, Lcm, Parity, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{RoundingFrom, ToStringBase};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::{primitive_float_gen, signed_gen, unsigned_gen};
+use malachite_nz::natural::conversion::num_traits::FromStrRadixError;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    natural_gen, natural_pair_gen, natural_pair_gen_var_5, natural_unsigned_pair_gen_var_4,
+    natural_unsigned_pair_gen_var_7,
+};
+use num_integer::Roots;
+use num_traits::{FromPrimitive, Num, ToPrimitive};
+use std::str::FromStr;
+
+#[test]
+fn test_zero_and_one() {
+    assert_eq!(<Natural as num_traits::Zero>::zero(), 0);
+    assert_eq!(<Natural as num_traits::One>::one(), 1);
+    assert!(num_traits::Zero::is_zero(&Natural::ZERO));
+    assert!(!num_traits::Zero::is_zero(&Natural::ONE));
+    assert!(num_traits::One::is_one(&Natural::ONE));
+    assert!(!num_traits::One::is_one(&Natural::from(2u32)));
+}
+
+#[test]
+fn test_from_str_radix() {
+    let test = |s, radix, out: Option<&str>| {
+        assert_eq!(
+            Natural::from_str_radix(s, radix)
+                .ok()
+                .map(|n| n.to_string()),
+            out.map(ToString::to_string)
+        );
+    };
+    test("0", 10, Some("0"));
+    test("123", 10, Some("123"));
+    test("ff", 16, Some("255"));
+    test("FF", 16, Some("255"));
+    test("1000000000000000000000000", 2, Some("16777216"));
+    test("zz", 36, Some("1295"));
+    test("", 10, None);
+    test("-1", 10, None);
+    test("12", 2, None);
+    test("1.0", 10, None);
+}
+
+#[test]
+fn test_from_str_radix_error() {
+    let test = |s, radix, out| {
+        assert_eq!(Natural::from_str_radix(s, radix), Err(out));
+    };
+    test("1", 0, FromStrRadixError::InvalidRadix);
+    test("1", 1, FromStrRadixError::InvalidRadix);
+    test("1", 37, FromStrRadixError::InvalidRadix);
+    test("1", 256, FromStrRadixError::InvalidRadix);
+    test("1", u32::MAX, FromStrRadixError::InvalidRadix);
+    test("", 10, FromStrRadixError::InvalidString);
+    test("12", 2, FromStrRadixError::InvalidString);
+}
+
+#[test]
+fn test_to_primitive() {
+    let test = |s, i: Option<i64>, u: Option<u64>, i_128: Option<i128>, f: f64| {
+        let x = Natural::from_str(s).unwrap();
+        assert_eq!(x.to_i64(), i);
+        assert_eq!(x.to_u64(), u);
+        assert_eq!(x.to_i128(), i_128);
+        assert_eq!(x.to_f64(), Some(f));
+    };
+    test("0", Some(0), Some(0), Some(0), 0.0);
+    test("123", Some(123), Some(123), Some(123), 123.0);
+    test(
+        "9223372036854775807",
+        Some(i64::MAX),
+        Some(9223372036854775807),
+        Some(9223372036854775807),
+        9.223372036854776e18,
+    );
+    test(
+        "9223372036854775808",
+        None,
+        Some(9223372036854775808),
+        Some(9223372036854775808),
+        9.223372036854776e18,
+    );
+    test(
+        "18446744073709551616",
+        None,
+        None,
+        Some(18446744073709551616),
+        1.8446744073709552e19,
+    );
+    let x = Natural::from(10u32).pow(400);
+    assert_eq!(x.to_u128(), None);
+    assert_eq!(x.to_f64(), Some(f64::MAX));
+    assert_eq!(x.to_f32(), Some(f32::MAX));
+}
+
+#[test]
+fn test_from_primitive() {
+    assert_eq!(Natural::from_i64(123).unwrap(), 123);
+    assert_eq!(Natural::from_i64(-1), None);
+    assert_eq!(Natural::from_i128(-1), None);
+    assert_eq!(Natural::from_u64(u64::MAX).unwrap(), u64::MAX);
+    assert_eq!(Natural::from_u128(u128::MAX).unwrap(), u128::MAX);
+    assert_eq!(Natural::from_f64(0.0).unwrap(), 0);
+    assert_eq!(Natural::from_f64(-0.0).unwrap(), 0);
+    assert_eq!(Natural::from_f64(1.5).unwrap(), 1);
+    assert_eq!(Natural::from_f64(-0.5).unwrap(), 0);
+    assert_eq!(Natural::from_f64(-1.0), None);
+    assert_eq!(Natural::from_f64(f64::NAN), None);
+    assert_eq!(Natural::from_f64(f64::INFINITY), None);
+    assert_eq!(
+        Natural::from_f32(1.0e20).unwrap(),
+        100000002004087734272u128
+    );
+}
+
+#[test]
+fn test_checked_ops() {
+    let x = Natural::from(10u32);
+    let y = Natural::from(3u32);
+    assert_eq!(num_traits::CheckedAdd::checked_add(&x, &y).unwrap(), 13);
+    assert_eq!(num_traits::CheckedSub::checked_sub(&x, &y).unwrap(), 7);
+    assert_eq!(num_traits::CheckedSub::checked_sub(&y, &x), None);
+    assert_eq!(num_traits::CheckedMul::checked_mul(&x, &y).unwrap(), 30);
+    assert_eq!(num_traits::CheckedDiv::checked_div(&x, &y).unwrap(), 3);
+    assert_eq!(
+        num_traits::CheckedDiv::checked_div(&x, &Natural::ZERO),
+        None
+    );
+    assert_eq!(num_traits::CheckedRem::checked_rem(&x, &y).unwrap(), 1);
+    assert_eq!(
+        num_traits::CheckedRem::checked_rem(&x, &Natural::ZERO),
+        None
+    );
+}
+
+#[test]
+fn test_num_integer() {
+    let test = |s, t, div_floor, mod_floor, div_ceil, gcd, lcm, is_multiple_of| {
+        let x = Natural::from_str(s).unwrap();
+        let y = Natural::from_str(t).unwrap();
+        assert_eq!(
+            num_integer::Integer::div_floor(&x, &y).to_string(),
+            div_floor
+        );
+        assert_eq!(
+            num_integer::Integer::mod_floor(&x, &y).to_string(),
+            mod_floor
+        );
+        assert_eq!(num_integer::Integer::div_ceil(&x, &y).to_string(), div_ceil);
+        assert_eq!(num_integer::Integer::gcd(&x, &y).to_string(), gcd);
+        assert_eq!(num_integer::Integer::lcm(&x, &y).to_string(), lcm);
+        assert_eq!(num_integer::Integer::is_multiple_of(&x, &y), is_multiple_of);
+        let (q, r) = num_integer::Integer::div_rem(&x, &y);
+        assert_eq!(q.to_string(), div_floor);
+        assert_eq!(r.to_string(), mod_floor);
+    };
+    test("0", "1", "0", "0", "0", "1", "0", true);
+    test("10", "3", "3", "1", "4", "1", "30", false);
+    test("12", "4", "3", "0", "3", "4", "12", true);
+    test(
+        "1000000000000000000000",
+        "7",
+        "142857142857142857142",
+        "6",
+        "142857142857142857143",
+        "1",
+        "7000000000000000000000",
+        false,
+    );
+    assert!(num_integer::Integer::is_even(&Natural::ZERO));
+    assert!(num_integer::Integer::is_odd(&Natural::ONE));
+    assert!(num_integer::Integer::is_multiple_of(
+        &Natural::ZERO,
+        &Natural::ZERO
+    ));
+}
+
+#[test]
+fn test_roots() {
+    let test = |s, n, out| {
+        assert_eq!(Natural::from_str(s).unwrap().nth_root(n).to_string(), out);
+    };
+    test("0", 1, "0");
+    test("0", 5, "0");
+    test("100", 1, "100");
+    test("100", 2, "10");
+    test("99", 2, "9");
+    test("1000", 3, "10");
+    test("999", 3, "9");
+    test("1000000000000000000000", 7, "1000");
+    assert_eq!(Natural::from(99u32).sqrt(), 9);
+    assert_eq!(Natural::from(1000u32).cbrt(), 10);
+}
+
+#[test]
+#[should_panic]
+fn nth_root_fail() {
+    Natural::from(10u32).nth_root(0);
+}
+
+#[test]
+fn num_traits_properties() {
+    natural_gen().test_properties(|x| {
+        assert_eq!(num_traits::Zero::is_zero(&x), x == 0);
+        assert_eq!(num_traits::One::is_one(&x), x == 1);
+        for radix in [2, 10, 16, 36] {
+            assert_eq!(
+                Natural::from_str_radix(&x.to_string_base(radix), u32::from(radix)).unwrap(),
+                x
+            );
+        }
+        assert_eq!(x.to_i64(), i64::try_from(&x).ok());
+        assert_eq!(x.to_u64(), u64::try_from(&x).ok());
+        assert_eq!(x.to_u128(), u128::try_from(&x).ok());
+        assert_eq!(
+            x.to_f64(),
+            Some(f64::rounding_from(&x, RoundingMode::Nearest).0)
+        );
+        if let Some(u) = x.to_u64() {
+            assert_eq!(Natural::from_u64(u).unwrap(), x);
+        }
+        assert_ne!(
+            num_integer::Integer::is_even(&x),
+            num_integer::Integer::is_odd(&x)
+        );
+        assert_eq!(num_integer::Integer::is_even(&x), x.even());
+        assert_eq!(x.sqrt(), (&x).floor_sqrt());
+        assert_eq!(x.cbrt(), (&x).floor_root(3));
+        assert_eq!(num_traits::Pow::pow(&x, 2u32), (&x).pow(2));
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(
+            num_traits::CheckedAdd::checked_add(&x, &y).unwrap(),
+            &x + &y
+        );
+        assert_eq!(
+            num_traits::CheckedSub::checked_sub(&x, &y),
+            CheckedSub::checked_sub(&x, &y)
+        );
+        assert_eq!(
+            num_traits::CheckedMul::checked_mul(&x, &y).unwrap(),
+            &x * &y
+        );
+        assert_eq!(num_integer::Integer::gcd(&x, &y), (&x).gcd(&y));
+        assert_eq!(num_integer::Integer::lcm(&x, &y), (&x).lcm(&y));
+        assert_eq!(
+            num_integer::Integer::is_multiple_of(&x, &y),
+            (&x).divisible_by(&y)
+        );
+        assert_eq!(
+            num_traits::CheckedDiv::checked_div(&x, &y).is_none(),
+            y == 0
+        );
+    });
+
+    natural_pair_gen_var_5().test_properties(|(x, y)| {
+        let (q, r) = num_integer::Integer::div_rem(&x, &y);
+        assert_eq!((q.clone(), r.clone()), (&x).div_rem(&y));
+        assert_eq!(num_integer::Integer::div_floor(&x, &y), q);
+        assert_eq!(num_integer::Integer::mod_floor(&x, &y), r);
+        assert_eq!(
+            num_integer::Integer::div_ceil(&x, &y),
+            (&x).div_round(&y, RoundingMode::Ceiling).0
+        );
+        assert_eq!(num_traits::CheckedDiv::checked_div(&x, &y).unwrap(), q);
+        assert_eq!(num_traits::CheckedRem::checked_rem(&x, &y).unwrap(), r);
+    });
+
+    natural_unsigned_pair_gen_var_7::<u32>().test_properties(|(x, n)| {
+        assert_eq!(x.nth_root(n), (&x).floor_root(u64::from(n)));
+    });
+
+    natural_unsigned_pair_gen_var_4::<u64>().test_properties(|(x, n)| {
+        assert_eq!(num_traits::Pow::pow(&x, n), (&x).pow(n));
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        let x = Natural::from_u64(u).unwrap();
+        assert_eq!(x, u);
+        assert_eq!(x.to_u64(), Some(u));
+    });
+
+    signed_gen::<i64>().test_properties(|i| {
+        assert_eq!(Natural::from_i64(i), Natural::try_from(i).ok());
+    });
+
+    primitive_float_gen::<f64>().test_properties(|f| {
+        let x = Natural::from_f64(f);
+        assert_eq!(x.is_some(), f.is_finite() && f > -1.0);
+        if let Some(x) = x {
+            assert_eq!(x, Natural::rounding_from(f.trunc(), RoundingMode::Exact).0);
+        }
+    });
+}
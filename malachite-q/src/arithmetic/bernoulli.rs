@@ -0,0 +1,78 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Parity, PowerOf2};
+use malachite_base::num::basic::traits::{One, OneHalf, Zero};
+use malachite_nz::natural::Natural;
+
+// Returns the tangent number T_m, the coefficient of x^(2m - 1) / (2m - 1)! in tan(x). This uses
+// the in-place triangle algorithm of Brent and Harvey, which only needs additions and small
+// multiplications.
+fn tangent_number(m: usize) -> Natural {
+    let mut ts = Vec::with_capacity(m + 1);
+    ts.push(Natural::ZERO);
+    ts.push(Natural::ONE);
+    for k in 2..=m {
+        let t = Natural::from(k - 1) * &ts[k - 1];
+        ts.push(t);
+    }
+    for k in 2..=m {
+        for j in k..=m {
+            let t = Natural::from(j - k) * &ts[j - 1] + Natural::from(j - k + 2) * &ts[j];
+            ts[j] = t;
+        }
+    }
+    ts.swap_remove(m)
+}
+
+impl Rational {
+    /// Computes a Bernoulli number.
+    ///
+    /// The Bernoulli numbers are defined by
+    /// $$
+    /// \frac{x}{e^x - 1} = \sum_{n=0}^\infty B_n \frac{x^n}{n!},
+    /// $$
+    /// so that $B_1 = -1/2$. $B_n = 0$ for all odd $n > 1$. For even $n = 2m > 0$, the computation
+    /// uses the tangent numbers $T_m$:
+    /// $$
+    /// B_{2m} = (-1)^{m-1}\frac{2mT_m}{4^m(4^m - 1)}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Panics
+    /// Panics if `n` does not fit in a `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::bernoulli(0), 1);
+    /// assert_eq!(Rational::bernoulli(1).to_string(), "-1/2");
+    /// assert_eq!(Rational::bernoulli(2).to_string(), "1/6");
+    /// assert_eq!(Rational::bernoulli(3), 0);
+    /// assert_eq!(Rational::bernoulli(12).to_string(), "-691/2730");
+    /// assert_eq!(
+    ///     Rational::bernoulli(100).to_string(),
+    ///     "-94598037819122125295227433069493721872702841533066936133385696204311395415197247711/\
+    ///     33330"
+    /// );
+    /// ```
+    pub fn bernoulli(n: u64) -> Rational {
+        match n {
+            0 => Rational::ONE,
+            1 => -Rational::ONE_HALF,
+            n if n.odd() => Rational::ZERO,
+            n => {
+                let m = n >> 1;
+                let numerator = Natural::from(n) * tangent_number(usize::try_from(m).unwrap());
+                let power = Natural::power_of_2(n);
+                let denominator = (&power - Natural::ONE) * power;
+                Rational::from_sign_and_naturals(m.odd(), numerator, denominator)
+            }
+        }
+    }
+}
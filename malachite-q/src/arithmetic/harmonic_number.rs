@@ -0,0 +1,52 @@
+use crate::Rational;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::Natural;
+
+// Returns (p, q) such that p / q = 1 / a + 1 / (a + 1) + ... + 1 / (b - 1), using binary splitting
+// so that the expensive multiplications are balanced. The fraction is not necessarily reduced.
+fn harmonic_sum(a: u64, b: u64) -> (Natural, Natural) {
+    if b - a == 1 {
+        (Natural::ONE, Natural::from(a))
+    } else {
+        let mid = a + ((b - a) >> 1);
+        let (p_1, q_1) = harmonic_sum(a, mid);
+        let (p_2, q_2) = harmonic_sum(mid, b);
+        (p_1 * &q_2 + p_2 * &q_1, q_1 * q_2)
+    }
+}
+
+impl Rational {
+    /// Computes a harmonic number.
+    ///
+    /// $$
+    /// H_n = \sum_{k=1}^n \frac{1}{k}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::harmonic_number(0), 0);
+    /// assert_eq!(Rational::harmonic_number(1), 1);
+    /// assert_eq!(Rational::harmonic_number(10).to_string(), "7381/2520");
+    /// assert_eq!(
+    ///     Rational::harmonic_number(30).to_string(),
+    ///     "9304682830147/2329089562800"
+    /// );
+    /// ```
+    pub fn harmonic_number(n: u64) -> Rational {
+        if n == 0 {
+            Rational::ZERO
+        } else {
+            let (p, q) = harmonic_sum(1, n + 1);
+            Rational::from_naturals(p, q)
+        }
+    }
+}
@@ -6,6 +6,8 @@ pub mod add;
 /// [`ApproximateAssign`](traits::ApproximateAssign), traits for approximating a
 /// [`Rational`](super::Rational) by a [`Rational`](super::Rational) with a bounded denominator.
 pub mod approximate;
+/// A function for computing Bernoulli numbers.
+pub mod bernoulli;
 /// Implementations of [`Ceiling`](malachite_base::num::arithmetic::traits::Ceiling) and
 /// [`CeilingAssign`](malachite_base::num::arithmetic::traits::CeilingAssign), traits for taking
 /// the ceiling of a number.
@@ -19,6 +21,8 @@ pub mod div;
 /// [`FloorAssign`](malachite_base::num::arithmetic::traits::FloorAssign), traits for taking the
 /// floor of a number.
 pub mod floor;
+/// A function for computing harmonic numbers.
+pub mod harmonic_number;
 /// An implementation of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a
 /// trait for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
@@ -61,6 +65,8 @@ pub mod power_of_2;
 /// [`ReciprocalAssign`](malachite_base::num::arithmetic::traits::ReciprocalAssign), traits for
 /// computing the reciprocal of a number.
 pub mod reciprocal;
+/// Remainders of [`Rational`](super::Rational) division, with the quotient rounded toward zero.
+pub mod rem;
 /// Implementations of [`CheckedRoot`](malachite_base::num::arithmetic::traits::CheckedRoot), a
 /// trait for computing the root of a number, if the number is a perfect power.
 pub mod root;
@@ -0,0 +1,343 @@
+use crate::Rational;
+use malachite_nz::natural::Natural;
+use std::ops::{Rem, RemAssign};
+
+// Computes the remainder of x / y, rounding the quotient toward zero. If x = a / b and y = c / d,
+// then the remainder is ((ad) mod (bc)) / (bd), with the sign of x.
+//
+// # Worst-case complexity
+// $T(n) = O(n (\log n)^2 \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is
+// `max(x.significant_bits(), y.significant_bits())`.
+fn rem_helper(
+    sign: bool,
+    x_numerator: &Natural,
+    x_denominator: &Natural,
+    y: &Rational,
+) -> Rational {
+    if *y == 0u32 {
+        panic!("division by zero");
+    }
+    Rational::from_sign_and_naturals(
+        sign,
+        (x_numerator * &y.denominator) % (&y.numerator * x_denominator),
+        x_denominator * &y.denominator,
+    )
+}
+
+impl Rem<Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by value and returning just the
+    /// remainder. The quotient is rounded toward zero, so the remainder has the same sign as the
+    /// first [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$, where
+    /// $q$ is an integer, and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 6 * 1/2 + 1/7 = 22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(22, 7) % Rational::from_signeds(1, 2)).to_string(),
+    ///     "1/7"
+    /// );
+    ///
+    /// // -6 * -1/2 + 1/7 = 22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(22, 7) % Rational::from_signeds(-1, 2)).to_string(),
+    ///     "1/7"
+    /// );
+    ///
+    /// // -6 * 1/2 + -1/7 = -22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-22, 7) % Rational::from_signeds(1, 2)).to_string(),
+    ///     "-1/7"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: Rational) -> Rational {
+        rem_helper(self.sign, &self.numerator, &self.denominator, &other)
+    }
+}
+
+impl<'a> Rem<&'a Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by value and the second by
+    /// reference and returning just the remainder. The quotient is rounded toward zero, so the
+    /// remainder has the same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$, where
+    /// $q$ is an integer, and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 6 * 1/2 + 1/7 = 22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(22, 7) % &Rational::from_signeds(1, 2)).to_string(),
+    ///     "1/7"
+    /// );
+    ///
+    /// // -6 * -1/2 + 1/7 = 22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(22, 7) % &Rational::from_signeds(-1, 2)).to_string(),
+    ///     "1/7"
+    /// );
+    ///
+    /// // -6 * 1/2 + -1/7 = -22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-22, 7) % &Rational::from_signeds(1, 2)).to_string(),
+    ///     "-1/7"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: &'a Rational) -> Rational {
+        rem_helper(self.sign, &self.numerator, &self.denominator, other)
+    }
+}
+
+impl<'a> Rem<Rational> for &'a Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by reference and the second
+    /// by value and returning just the remainder. The quotient is rounded toward zero, so the
+    /// remainder has the same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$, where
+    /// $q$ is an integer, and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 6 * 1/2 + 1/7 = 22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(22, 7) % Rational::from_signeds(1, 2)).to_string(),
+    ///     "1/7"
+    /// );
+    ///
+    /// // -6 * -1/2 + 1/7 = 22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(22, 7) % Rational::from_signeds(-1, 2)).to_string(),
+    ///     "1/7"
+    /// );
+    ///
+    /// // -6 * 1/2 + -1/7 = -22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-22, 7) % Rational::from_signeds(1, 2)).to_string(),
+    ///     "-1/7"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: Rational) -> Rational {
+        rem_helper(self.sign, &self.numerator, &self.denominator, &other)
+    }
+}
+
+impl<'a, 'b> Rem<&'b Rational> for &'a Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by reference and returning just
+    /// the remainder. The quotient is rounded toward zero, so the remainder has the same sign as
+    /// the first [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$, where
+    /// $q$ is an integer, and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 6 * 1/2 + 1/7 = 22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(22, 7) % &Rational::from_signeds(1, 2)).to_string(),
+    ///     "1/7"
+    /// );
+    ///
+    /// // -6 * -1/2 + 1/7 = 22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(22, 7) % &Rational::from_signeds(-1, 2)).to_string(),
+    ///     "1/7"
+    /// );
+    ///
+    /// // -6 * 1/2 + -1/7 = -22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-22, 7) % &Rational::from_signeds(1, 2)).to_string(),
+    ///     "-1/7"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: &'b Rational) -> Rational {
+        rem_helper(self.sign, &self.numerator, &self.denominator, other)
+    }
+}
+
+impl RemAssign<Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`], taking the second [`Rational`] by value and
+    /// replacing the first by the remainder. The quotient is rounded toward zero, so the remainder
+    /// has the same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$, where
+    /// $q$ is an integer, and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// x \gets x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 6 * 1/2 + 1/7 = 22/7
+    /// let mut x = Rational::from_signeds(22, 7);
+    /// x %= Rational::from_signeds(1, 2);
+    /// assert_eq!(x.to_string(), "1/7");
+    ///
+    /// // -6 * -1/2 + 1/7 = 22/7
+    /// let mut x = Rational::from_signeds(22, 7);
+    /// x %= Rational::from_signeds(-1, 2);
+    /// assert_eq!(x.to_string(), "1/7");
+    ///
+    /// // -6 * 1/2 + -1/7 = -22/7
+    /// let mut x = Rational::from_signeds(-22, 7);
+    /// x %= Rational::from_signeds(1, 2);
+    /// assert_eq!(x.to_string(), "-1/7");
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: Rational) {
+        *self = rem_helper(self.sign, &self.numerator, &self.denominator, &other);
+    }
+}
+
+impl<'a> RemAssign<&'a Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`], taking the second [`Rational`] by reference
+    /// and replacing the first by the remainder. The quotient is rounded toward zero, so the
+    /// remainder has the same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qy + r$, where
+    /// $q$ is an integer, and $0 \leq |r| < |y|$.
+    ///
+    /// $$
+    /// x \gets x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 6 * 1/2 + 1/7 = 22/7
+    /// let mut x = Rational::from_signeds(22, 7);
+    /// x %= &Rational::from_signeds(1, 2);
+    /// assert_eq!(x.to_string(), "1/7");
+    ///
+    /// // -6 * -1/2 + 1/7 = 22/7
+    /// let mut x = Rational::from_signeds(22, 7);
+    /// x %= &Rational::from_signeds(-1, 2);
+    /// assert_eq!(x.to_string(), "1/7");
+    ///
+    /// // -6 * 1/2 + -1/7 = -22/7
+    /// let mut x = Rational::from_signeds(-22, 7);
+    /// x %= &Rational::from_signeds(1, 2);
+    /// assert_eq!(x.to_string(), "-1/7");
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: &'a Rational) {
+        *self = rem_helper(self.sign, &self.numerator, &self.denominator, other);
+    }
+}
@@ -138,6 +138,14 @@ pub mod mutate_numerator_and_denominator;
 /// [`ConvertibleFrom`](malachite_base::num::conversion::traits::ConvertibleFrom), and
 /// [`RoundingFrom`](malachite_base::num::conversion::traits::RoundingFrom).
 pub mod natural_from_rational;
+/// Implementations of traits from [num-traits](https://docs.rs/num-traits), such as
+/// [`Num`](::num_traits::Num) and [`Signed`](::num_traits::Signed), for
+/// [`Rational`](crate::Rational). This allows [`Rational`](crate::Rational)s to be used in generic
+/// code written against those traits.
+///
+/// These implementations are only available when the `enable_num_traits` feature is enabled.
+#[cfg(feature = "enable_num_traits")]
+pub mod num_traits;
 /// Functions and implementations of traits for converting a [`Rational`](crate::Rational) to a
 /// primitive float.
 ///
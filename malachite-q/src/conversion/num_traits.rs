@@ -0,0 +1,305 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Abs, Pow, Sign};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::{FromStringBase, RoundingFrom, WrappingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::conversion::num_traits::FromStrRadixError;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+
+impl num_traits::Zero for Rational {
+    /// Returns zero. See [`Zero::ZERO`].
+    #[inline]
+    fn zero() -> Rational {
+        Rational::ZERO
+    }
+
+    /// Determines whether a [`Rational`] is zero.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == 0u32
+    }
+}
+
+impl num_traits::One for Rational {
+    /// Returns one. See [`One::ONE`].
+    #[inline]
+    fn one() -> Rational {
+        Rational::ONE
+    }
+
+    /// Determines whether a [`Rational`] is one.
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == 1u32
+    }
+}
+
+impl num_traits::Num for Rational {
+    type FromStrRadixErr = FromStrRadixError;
+
+    /// Converts a string in the given radix to a [`Rational`].
+    ///
+    /// The string may be an integer, like `"-ff"`, or a fraction, like `"-ff/10"`. The numerator
+    /// and denominator do not need to be in lowest terms, but the denominator must be nonzero. A
+    /// negative sign is only allowed at the 0th position of the string.
+    ///
+    /// Returns [`FromStrRadixError::InvalidRadix`] if `radix` is less than 2 or greater than 36,
+    /// and [`FromStrRadixError::InvalidString`] if `s` is not a valid representation of a
+    /// [`Rational`] in that radix.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Rational, FromStrRadixError> {
+        if !(2..=36).contains(&radix) {
+            return Err(FromStrRadixError::InvalidRadix);
+        }
+        let radix = u8::wrapping_from(radix);
+        let (abs_string, sign) = match s.strip_prefix('-') {
+            Some(abs_string) => (abs_string, false),
+            None => (s, true),
+        };
+        let (numerator, denominator) = match abs_string.split_once('/') {
+            Some((numerator, denominator)) => (
+                Natural::from_string_base(radix, numerator)
+                    .ok_or(FromStrRadixError::InvalidString)?,
+                Natural::from_string_base(radix, denominator)
+                    .ok_or(FromStrRadixError::InvalidString)?,
+            ),
+            None => (
+                Natural::from_string_base(radix, abs_string)
+                    .ok_or(FromStrRadixError::InvalidString)?,
+                Natural::ONE,
+            ),
+        };
+        if denominator == 0u32 {
+            Err(FromStrRadixError::InvalidString)
+        } else {
+            Ok(Rational::from_sign_and_naturals(
+                sign,
+                numerator,
+                denominator,
+            ))
+        }
+    }
+}
+
+impl num_traits::Signed for Rational {
+    /// Returns the absolute value of a [`Rational`]. See [`Abs::abs`].
+    #[inline]
+    fn abs(&self) -> Rational {
+        Abs::abs(self)
+    }
+
+    /// Returns the difference of two [`Rational`]s if it is positive, and zero otherwise.
+    #[inline]
+    fn abs_sub(&self, other: &Rational) -> Rational {
+        if self <= other {
+            Rational::ZERO
+        } else {
+            self - other
+        }
+    }
+
+    /// Returns 1, 0, or -1, depending on whether a [`Rational`] is positive, zero, or negative.
+    #[inline]
+    fn signum(&self) -> Rational {
+        match self.sign() {
+            Ordering::Less => Rational::NEGATIVE_ONE,
+            Ordering::Equal => Rational::ZERO,
+            Ordering::Greater => Rational::ONE,
+        }
+    }
+
+    /// Determines whether a [`Rational`] is greater than zero.
+    #[inline]
+    fn is_positive(&self) -> bool {
+        *self > 0u32
+    }
+
+    /// Determines whether a [`Rational`] is less than zero.
+    #[inline]
+    fn is_negative(&self) -> bool {
+        *self < 0u32
+    }
+}
+
+macro_rules! impl_pow {
+    ($t: ident, $u: ident) => {
+        impl num_traits::Pow<$t> for Rational {
+            type Output = Rational;
+
+            /// Raises a [`Rational`] to a power, taking it by value. See [`Pow::pow`].
+            ///
+            /// # Panics
+            /// Panics if the [`Rational`] is zero and the exponent is negative.
+            #[inline]
+            fn pow(self, exp: $t) -> Rational {
+                Pow::pow(self, $u::from(exp))
+            }
+        }
+
+        impl<'a> num_traits::Pow<$t> for &'a Rational {
+            type Output = Rational;
+
+            /// Raises a [`Rational`] to a power, taking it by reference. See [`Pow::pow`].
+            ///
+            /// # Panics
+            /// Panics if the [`Rational`] is zero and the exponent is negative.
+            #[inline]
+            fn pow(self, exp: $t) -> Rational {
+                Pow::pow(self, $u::from(exp))
+            }
+        }
+    };
+}
+impl_pow!(u8, u64);
+impl_pow!(u16, u64);
+impl_pow!(u32, u64);
+impl_pow!(u64, u64);
+impl_pow!(i8, i64);
+impl_pow!(i16, i64);
+impl_pow!(i32, i64);
+impl_pow!(i64, i64);
+
+impl num_traits::ToPrimitive for Rational {
+    /// Converts a [`Rational`] to an [`i64`], rounding toward zero. Returns `None` if the rounded
+    /// value is out of range.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(&Integer::rounding_from(self, RoundingMode::Down).0).ok()
+    }
+
+    /// Converts a [`Rational`] to an [`i128`], rounding toward zero. Returns `None` if the
+    /// rounded value is out of range.
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(&Integer::rounding_from(self, RoundingMode::Down).0).ok()
+    }
+
+    /// Converts a [`Rational`] to a [`u64`], rounding toward zero. Returns `None` if the rounded
+    /// value is out of range.
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(&Integer::rounding_from(self, RoundingMode::Down).0).ok()
+    }
+
+    /// Converts a [`Rational`] to a [`u128`], rounding toward zero. Returns `None` if the rounded
+    /// value is out of range.
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(&Integer::rounding_from(self, RoundingMode::Down).0).ok()
+    }
+
+    /// Converts a [`Rational`] to the nearest [`f32`]. A [`Rational`] whose absolute value is
+    /// greater than the maximum finite [`f32`] is converted to the maximum or minimum finite
+    /// [`f32`].
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f32::rounding_from(self, RoundingMode::Nearest).0)
+    }
+
+    /// Converts a [`Rational`] to the nearest [`f64`]. A [`Rational`] whose absolute value is
+    /// greater than the maximum finite [`f64`] is converted to the maximum or minimum finite
+    /// [`f64`].
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f64::rounding_from(self, RoundingMode::Nearest).0)
+    }
+}
+
+impl num_traits::FromPrimitive for Rational {
+    /// Converts an [`i64`] to a [`Rational`].
+    #[inline]
+    fn from_i64(n: i64) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    /// Converts an [`i128`] to a [`Rational`].
+    #[inline]
+    fn from_i128(n: i128) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    /// Converts a [`u64`] to a [`Rational`].
+    #[inline]
+    fn from_u64(n: u64) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    /// Converts a [`u128`] to a [`Rational`].
+    #[inline]
+    fn from_u128(n: u128) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    /// Converts an [`f32`] to a [`Rational`] exactly. Returns `None` if the [`f32`] is NaN or
+    /// infinite.
+    #[inline]
+    fn from_f32(n: f32) -> Option<Rational> {
+        Rational::try_from(n).ok()
+    }
+
+    /// Converts an [`f64`] to a [`Rational`] exactly. Returns `None` if the [`f64`] is NaN or
+    /// infinite.
+    #[inline]
+    fn from_f64(n: f64) -> Option<Rational> {
+        Rational::try_from(n).ok()
+    }
+}
+
+impl num_traits::CheckedAdd for Rational {
+    /// Adds two [`Rational`]s. The result is never `None`.
+    #[inline]
+    fn checked_add(&self, other: &Rational) -> Option<Rational> {
+        Some(self + other)
+    }
+}
+
+impl num_traits::CheckedSub for Rational {
+    /// Subtracts a [`Rational`] from another [`Rational`]. The result is never `None`.
+    #[inline]
+    fn checked_sub(&self, other: &Rational) -> Option<Rational> {
+        Some(self - other)
+    }
+}
+
+impl num_traits::CheckedMul for Rational {
+    /// Multiplies two [`Rational`]s. The result is never `None`.
+    #[inline]
+    fn checked_mul(&self, other: &Rational) -> Option<Rational> {
+        Some(self * other)
+    }
+}
+
+impl num_traits::CheckedDiv for Rational {
+    /// Divides a [`Rational`] by another [`Rational`], returning `None` if the divisor is zero.
+    #[inline]
+    fn checked_div(&self, other: &Rational) -> Option<Rational> {
+        if *other == 0u32 {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+}
+
+impl num_traits::CheckedRem for Rational {
+    /// Computes the remainder of dividing a [`Rational`] by another [`Rational`], returning `None`
+    /// if the divisor is zero. The quotient is rounded toward zero, so the remainder has the same
+    /// sign as the dividend.
+    #[inline]
+    fn checked_rem(&self, other: &Rational) -> Option<Rational> {
+        if *other == 0u32 {
+            None
+        } else {
+            Some(self % other)
+        }
+    }
+}
+
+impl num_traits::CheckedNeg for Rational {
+    /// Negates a [`Rational`]. The result is never `None`.
+    #[inline]
+    fn checked_neg(&self) -> Option<Rational> {
+        Some(-self)
+    }
+}
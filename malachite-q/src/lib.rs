@@ -99,6 +99,8 @@ extern crate malachite_nz;
 #[cfg(feature = "serde")]
 #[macro_use]
 extern crate serde;
+#[cfg(feature = "enable_num_traits")]
+extern crate num_traits;
 
 #[cfg(feature = "test_build")]
 extern crate itertools;
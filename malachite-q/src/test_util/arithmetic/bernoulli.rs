@@ -0,0 +1,22 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::BinomialCoefficient;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+
+pub fn bernoulli_naive(n: u64) -> Rational {
+    // sum_{k = 0}^m C(m + 1, k) B_k = 0 for m > 0
+    let n = usize::exact_from(n);
+    let mut bs = vec![Rational::ONE];
+    for m in 1..=n {
+        let mut sum = Rational::ZERO;
+        for (k, b) in bs.iter().enumerate() {
+            sum += Rational::from(Natural::binomial_coefficient(
+                Natural::from(m + 1),
+                Natural::from(k),
+            )) * b;
+        }
+        bs.push(-sum / Rational::from(m + 1));
+    }
+    bs.swap_remove(n)
+}
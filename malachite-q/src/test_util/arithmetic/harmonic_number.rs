@@ -0,0 +1,6 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::Reciprocal;
+
+pub fn harmonic_number_naive(n: u64) -> Rational {
+    (1..=n).map(|k| Rational::from(k).reciprocal()).sum()
+}
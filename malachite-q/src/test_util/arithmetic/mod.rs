@@ -1,6 +1,8 @@
 pub mod add;
 pub mod approximate;
+pub mod bernoulli;
 pub mod div;
+pub mod harmonic_number;
 pub mod mul;
 pub mod sign;
 pub mod simplest_rational_in_interval;
@@ -0,0 +1,60 @@
+use malachite_base::num::arithmetic::traits::{Parity, Sign};
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_q::test_util::arithmetic::bernoulli::bernoulli_naive;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+
+#[test]
+fn test_bernoulli() {
+    fn test(n: u64, out: &str) {
+        let b = Rational::bernoulli(n);
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+        assert_eq!(bernoulli_naive(n).to_string(), out);
+    }
+    test(0, "1");
+    test(1, "-1/2");
+    test(2, "1/6");
+    test(3, "0");
+    test(4, "-1/30");
+    test(5, "0");
+    test(6, "1/42");
+    test(8, "-1/30");
+    test(10, "5/66");
+    test(12, "-691/2730");
+    test(14, "7/6");
+    test(16, "-3617/510");
+    test(18, "43867/798");
+    test(20, "-174611/330");
+    test(60, "-1215233140483755572040304994079820246041491/56786730");
+}
+
+#[test]
+fn bernoulli_properties() {
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        let b = Rational::bernoulli(n);
+        assert!(b.is_valid());
+        if n > 1 && n.odd() {
+            assert_eq!(b, 0u32);
+        } else if n > 1 {
+            // The sign of B_n alternates, and by the von Staudt-Clausen theorem the denominator
+            // of B_n is the product of the primes p such that p - 1 divides n.
+            assert_eq!(
+                b.sign(),
+                if (n >> 1).odd() {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                }
+            );
+            let denominator: Natural = u64::primes_less_than_or_equal_to(&(n + 1))
+                .filter(|&p| n % (p - 1) == 0)
+                .map(Natural::from)
+                .product();
+            assert_eq!(*b.denominator_ref(), denominator);
+        }
+    });
+}
@@ -0,0 +1,39 @@
+use malachite_base::num::arithmetic::traits::Reciprocal;
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_q::test_util::arithmetic::harmonic_number::harmonic_number_naive;
+use malachite_q::Rational;
+
+#[test]
+fn test_harmonic_number() {
+    fn test(n: u64, out: &str) {
+        let h = Rational::harmonic_number(n);
+        assert!(h.is_valid());
+        assert_eq!(h.to_string(), out);
+        assert_eq!(harmonic_number_naive(n).to_string(), out);
+    }
+    test(0, "0");
+    test(1, "1");
+    test(2, "3/2");
+    test(3, "11/6");
+    test(4, "25/12");
+    test(5, "137/60");
+    test(10, "7381/2520");
+    test(
+        100,
+        "14466636279520351160221518043104131447711/2788815009188499086581352357412492142272",
+    );
+}
+
+#[test]
+fn harmonic_number_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(TINY_LIMIT, |n| {
+        let h = Rational::harmonic_number(n);
+        assert!(h.is_valid());
+        assert_eq!(harmonic_number_naive(n), h);
+        assert_eq!(
+            Rational::harmonic_number(n + 1) - h,
+            Rational::from(n + 1).reciprocal()
+        );
+    });
+}
@@ -0,0 +1,146 @@
+use malachite_base::num::arithmetic::traits::{Abs, Sign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_pair_gen_var_1;
+use malachite_q::test_util::generators::{
+    rational_gen, rational_gen_var_1, rational_pair_gen_var_1,
+};
+use malachite_q::Rational;
+use num::BigRational;
+use std::str::FromStr;
+
+#[test]
+fn test_rem() {
+    let test = |s, t, out| {
+        let u = Rational::from_str(s).unwrap();
+        let v = Rational::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n %= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n %= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() % v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u % v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() % &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u % &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = BigRational::from_str(s).unwrap() % BigRational::from_str(t).unwrap();
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "1/123", "0");
+    test("0", "-1/123", "0");
+    test("1", "1/123", "0");
+    test("1", "-1/123", "0");
+    test("123", "1", "0");
+    test("123", "10", "3");
+    test("123", "-10", "3");
+    test("-123", "10", "-3");
+    test("-123", "-10", "-3");
+    test("22/7", "1/2", "1/7");
+    test("22/7", "-1/2", "1/7");
+    test("-22/7", "1/2", "-1/7");
+    test("-22/7", "-1/2", "-1/7");
+    test("22/7", "2/3", "10/21");
+    test("1/3", "1/2", "1/3");
+    test("-1/3", "1/2", "-1/3");
+    test("4/5", "4/5", "0");
+    test("4/5", "-4/5", "0");
+}
+
+#[test]
+#[should_panic]
+#[allow(unused_must_use, clippy::unnecessary_operation)]
+fn rem_fail() {
+    Rational::ONE % Rational::ZERO;
+}
+
+#[test]
+#[should_panic]
+fn rem_assign_fail() {
+    let mut x = Rational::ONE;
+    x %= Rational::ZERO;
+}
+
+#[allow(clippy::eq_op)]
+#[test]
+fn rem_properties() {
+    rational_pair_gen_var_1().test_properties(|(x, y)| {
+        let mut mut_x = x.clone();
+        mut_x %= &y;
+        assert!(mut_x.is_valid());
+        let remainder = mut_x;
+
+        let mut mut_x = x.clone();
+        mut_x %= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, remainder);
+
+        let remainder_alt = &x % &y;
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = x.clone() % &y;
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = &x % y.clone();
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let remainder_alt = x.clone() % y.clone();
+        assert!(remainder_alt.is_valid());
+        assert_eq!(remainder_alt, remainder);
+
+        let quotient = Integer::rounding_from(&x / &y, RoundingMode::Down).0;
+        assert_eq!(&x - Rational::from(quotient) * &y, remainder);
+        assert!((&remainder).abs() < (&y).abs());
+        assert!(remainder == 0u32 || remainder.sign() == x.sign());
+
+        assert_eq!(&x % -&y, remainder);
+        assert_eq!(-&x % &y, -&remainder);
+
+        assert_eq!(
+            Rational::from(&(BigRational::from(&x) % BigRational::from(&y))),
+            remainder
+        );
+    });
+
+    rational_gen().test_properties(|x| {
+        assert_eq!(
+            &x % Rational::ONE,
+            &x - Rational::from(Integer::rounding_from(&x, RoundingMode::Down).0)
+        );
+    });
+
+    rational_gen_var_1().test_properties(|x| {
+        assert_eq!(&x % &x, 0u32);
+        assert_eq!(&x % -&x, 0u32);
+        assert_eq!(Rational::ZERO % &x, 0u32);
+    });
+
+    integer_pair_gen_var_1().test_properties(|(x, y)| {
+        assert_eq!(
+            Rational::from(&x) % Rational::from(&y),
+            Rational::from(x % y)
+        );
+    });
+}
@@ -55,12 +55,46 @@ fn test_serde() {
     test("-22/7", "{\"s\":false,\"n\":\"0x16\",\"d\":\"0x7\"}");
 }
 
+#[test]
+fn test_serde_binary() {
+    let test = |n, out: &[u8]| {
+        assert_eq!(
+            bincode::serialize(&Rational::from_str(n).unwrap()).unwrap(),
+            out
+        );
+        assert_eq!(
+            bincode::deserialize::<Rational>(out).unwrap().to_string(),
+            n
+        );
+    };
+    test("0", &[1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1]);
+    test(
+        "100",
+        &[1, 1, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 0, 0, 0, 0, 1],
+    );
+    test(
+        "22/7",
+        &[1, 1, 0, 0, 0, 0, 0, 0, 0, 22, 1, 0, 0, 0, 0, 0, 0, 0, 7],
+    );
+    test(
+        "-22/7",
+        &[0, 1, 0, 0, 0, 0, 0, 0, 0, 22, 1, 0, 0, 0, 0, 0, 0, 0, 7],
+    );
+    test(
+        "-1000000000000/7",
+        &[0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 16, 165, 212, 232, 1, 0, 0, 0, 0, 0, 0, 0, 7],
+    );
+}
+
 #[test]
 fn serde_properties() {
     rational_gen().test_properties(|x| {
         let s = serde_json::to_string(&x).unwrap();
         assert_eq!(serde_json::from_str::<Rational>(&s).unwrap(), x);
         assert!(string_is_subset(&s, "\",-/0123456789:abcdeflnrstux{}"));
+
+        let bytes = bincode::serialize(&x).unwrap();
+        assert_eq!(bincode::deserialize::<Rational>(&bytes).unwrap(), x);
     });
 
     string_gen().test_properties(|s| {
@@ -50,10 +50,12 @@ pub mod arithmetic {
     pub mod abs;
     pub mod add;
     pub mod approximate;
+    pub mod bernoulli;
     pub mod ceiling;
     pub mod denominators_in_closed_interval;
     pub mod div;
     pub mod floor;
+    pub mod harmonic_number;
     pub mod is_power_of_2;
     pub mod log_base;
     pub mod log_base_2;
@@ -64,6 +66,7 @@ pub mod arithmetic {
     pub mod pow;
     pub mod power_of_2;
     pub mod reciprocal;
+    pub mod rem;
     pub mod root;
     pub mod round_to_multiple;
     pub mod round_to_multiple_of_power_of_2;
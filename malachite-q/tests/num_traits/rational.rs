@@ -0,0 +1,295 @@
+use malachite_base::num::arithmetic::traits::{Abs, Pow, Sign};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::{RoundingFrom, ToStringBase};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::{primitive_float_gen, signed_gen};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::conversion::num_traits::FromStrRadixError;
+use malachite_q::test_util::generators::{
+    rational_gen, rational_pair_gen, rational_pair_gen_var_1, rational_signed_pair_gen_var_2,
+    rational_unsigned_pair_gen_var_1,
+};
+use malachite_q::Rational;
+use num_traits::{FromPrimitive, Num, Signed, ToPrimitive};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[test]
+fn test_zero_and_one() {
+    assert_eq!(<Rational as num_traits::Zero>::zero(), 0);
+    assert_eq!(<Rational as num_traits::One>::one(), 1);
+    assert!(num_traits::Zero::is_zero(&Rational::ZERO));
+    assert!(!num_traits::Zero::is_zero(&Rational::NEGATIVE_ONE));
+    assert!(num_traits::One::is_one(&Rational::ONE));
+    assert!(!num_traits::One::is_one(&Rational::from_signeds(1, 2)));
+}
+
+#[test]
+fn test_from_str_radix() {
+    let test = |s, radix, out: Option<&str>| {
+        assert_eq!(
+            Rational::from_str_radix(s, radix)
+                .ok()
+                .map(|n| n.to_string()),
+            out.map(ToString::to_string)
+        );
+    };
+    test("0", 10, Some("0"));
+    test("-0", 10, Some("0"));
+    test("123", 10, Some("123"));
+    test("-123", 10, Some("-123"));
+    test("22/7", 10, Some("22/7"));
+    test("-22/7", 10, Some("-22/7"));
+    test("6/4", 10, Some("3/2"));
+    test("-ff/10", 16, Some("-255/16"));
+    test("101/11", 2, Some("5/3"));
+    test("zz/z", 36, Some("37"));
+    test("", 10, None);
+    test("-", 10, None);
+    test("/", 10, None);
+    test("1/", 10, None);
+    test("/1", 10, None);
+    test("1/0", 10, None);
+    test("1/-2", 10, None);
+    test("--1", 10, None);
+    test("1/2/3", 10, None);
+    test("12", 2, None);
+}
+
+#[test]
+fn test_from_str_radix_error() {
+    let test = |s, radix, out| {
+        assert_eq!(Rational::from_str_radix(s, radix), Err(out));
+    };
+    test("1", 0, FromStrRadixError::InvalidRadix);
+    test("1", 1, FromStrRadixError::InvalidRadix);
+    test("1", 37, FromStrRadixError::InvalidRadix);
+    test("1", 256, FromStrRadixError::InvalidRadix);
+    test("1", u32::MAX, FromStrRadixError::InvalidRadix);
+    test("", 10, FromStrRadixError::InvalidString);
+    test("1/0", 2, FromStrRadixError::InvalidString);
+}
+
+#[test]
+fn test_signed() {
+    let test = |s, abs, signum, is_positive, is_negative| {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(Signed::abs(&x).to_string(), abs);
+        assert_eq!(x.signum().to_string(), signum);
+        assert_eq!(x.is_positive(), is_positive);
+        assert_eq!(x.is_negative(), is_negative);
+    };
+    test("0", "0", "0", false, false);
+    test("22/7", "22/7", "1", true, false);
+    test("-22/7", "22/7", "-1", false, true);
+
+    let test_abs_sub = |s, t, out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = Rational::from_str(t).unwrap();
+        assert_eq!(x.abs_sub(&y).to_string(), out);
+    };
+    test_abs_sub("1/2", "1/3", "1/6");
+    test_abs_sub("1/3", "1/2", "0");
+    test_abs_sub("-1/3", "-1/2", "1/6");
+}
+
+#[test]
+fn test_to_primitive() {
+    let test = |s, i: Option<i64>, u: Option<u64>, f: f64| {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(x.to_i64(), i);
+        assert_eq!(x.to_u64(), u);
+        assert_eq!(x.to_f64(), Some(f));
+    };
+    test("0", Some(0), Some(0), 0.0);
+    test("22/7", Some(3), Some(3), 3.142857142857143);
+    test("-22/7", Some(-3), None, -3.142857142857143);
+    test("-1/2", Some(0), Some(0), -0.5);
+    test(
+        "18446744073709551615",
+        None,
+        Some(u64::MAX),
+        1.8446744073709552e19,
+    );
+    test(
+        "-9223372036854775809/2",
+        Some(-4611686018427387904),
+        None,
+        -4.611686018427388e18,
+    );
+}
+
+#[test]
+fn test_from_primitive() {
+    assert_eq!(Rational::from_i64(-123).unwrap(), -123);
+    assert_eq!(Rational::from_i128(i128::MIN).unwrap(), i128::MIN);
+    assert_eq!(Rational::from_u128(u128::MAX).unwrap(), u128::MAX);
+    assert_eq!(Rational::from_f64(-1.5).unwrap().to_string(), "-3/2");
+    assert_eq!(
+        Rational::from_f64(0.1).unwrap().to_string(),
+        "3602879701896397/36028797018963968"
+    );
+    assert_eq!(
+        Rational::from_f32(0.1).unwrap().to_string(),
+        "13421773/134217728"
+    );
+    assert_eq!(Rational::from_f64(f64::NAN), None);
+    assert_eq!(Rational::from_f64(f64::INFINITY), None);
+}
+
+#[test]
+fn test_checked_ops() {
+    let x = Rational::from_signeds(-22, 7);
+    let y = Rational::from_signeds(1, 2);
+    assert_eq!(
+        num_traits::CheckedAdd::checked_add(&x, &y)
+            .unwrap()
+            .to_string(),
+        "-37/14"
+    );
+    assert_eq!(
+        num_traits::CheckedSub::checked_sub(&y, &x)
+            .unwrap()
+            .to_string(),
+        "51/14"
+    );
+    assert_eq!(
+        num_traits::CheckedMul::checked_mul(&x, &y)
+            .unwrap()
+            .to_string(),
+        "-11/7"
+    );
+    assert_eq!(
+        num_traits::CheckedDiv::checked_div(&x, &y)
+            .unwrap()
+            .to_string(),
+        "-44/7"
+    );
+    assert_eq!(
+        num_traits::CheckedDiv::checked_div(&x, &Rational::ZERO),
+        None
+    );
+    assert_eq!(
+        num_traits::CheckedRem::checked_rem(&x, &y)
+            .unwrap()
+            .to_string(),
+        "-1/7"
+    );
+    assert_eq!(
+        num_traits::CheckedRem::checked_rem(&x, &Rational::ZERO),
+        None
+    );
+    assert_eq!(
+        num_traits::CheckedNeg::checked_neg(&x).unwrap().to_string(),
+        "22/7"
+    );
+}
+
+#[test]
+fn test_pow() {
+    let x = Rational::from_signeds(-2, 3);
+    assert_eq!(num_traits::Pow::pow(&x, 3u8).to_string(), "-8/27");
+    assert_eq!(num_traits::Pow::pow(&x, 0u16).to_string(), "1");
+    assert_eq!(num_traits::Pow::pow(&x, -2i32).to_string(), "9/4");
+    assert_eq!(num_traits::Pow::pow(x, -3i64).to_string(), "-27/8");
+}
+
+#[test]
+#[should_panic]
+fn pow_fail() {
+    num_traits::Pow::pow(Rational::ZERO, -1i8);
+}
+
+#[test]
+fn num_traits_properties() {
+    rational_gen().test_properties(|x| {
+        assert_eq!(num_traits::Zero::is_zero(&x), x == 0);
+        assert_eq!(num_traits::One::is_one(&x), x == 1);
+        for radix in [2, 10, 16, 36] {
+            let s = if *x.denominator_ref() == 1u32 {
+                x.numerator_ref().to_string_base(radix)
+            } else {
+                format!(
+                    "{}/{}",
+                    x.numerator_ref().to_string_base(radix),
+                    x.denominator_ref().to_string_base(radix)
+                )
+            };
+            let s = if x < 0u32 { format!("-{s}") } else { s };
+            assert_eq!(Rational::from_str_radix(&s, u32::from(radix)).unwrap(), x);
+        }
+        assert_eq!(Signed::abs(&x), Abs::abs(&x));
+        assert_eq!(
+            x.signum(),
+            match x.sign() {
+                Ordering::Less => -1,
+                Ordering::Equal => 0,
+                Ordering::Greater => 1,
+            }
+        );
+        assert_eq!(x.is_positive(), x > 0u32);
+        assert_eq!(x.is_negative(), x < 0u32);
+        let truncated = Integer::rounding_from(&x, RoundingMode::Down).0;
+        assert_eq!(x.to_i64(), i64::try_from(&truncated).ok());
+        assert_eq!(x.to_u64(), u64::try_from(&truncated).ok());
+        assert_eq!(x.to_i128(), i128::try_from(&truncated).ok());
+        assert_eq!(x.to_u128(), u128::try_from(&truncated).ok());
+        assert_eq!(
+            x.to_f64(),
+            Some(f64::rounding_from(&x, RoundingMode::Nearest).0)
+        );
+        assert_eq!(num_traits::CheckedNeg::checked_neg(&x).unwrap(), -&x);
+    });
+
+    rational_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(
+            num_traits::CheckedAdd::checked_add(&x, &y).unwrap(),
+            &x + &y
+        );
+        assert_eq!(
+            num_traits::CheckedSub::checked_sub(&x, &y).unwrap(),
+            &x - &y
+        );
+        assert_eq!(
+            num_traits::CheckedMul::checked_mul(&x, &y).unwrap(),
+            &x * &y
+        );
+        let abs_sub = x.abs_sub(&y);
+        assert!(abs_sub >= 0u32);
+        assert_eq!(abs_sub, if x > y { &x - &y } else { Rational::ZERO });
+    });
+
+    rational_pair_gen_var_1().test_properties(|(x, y)| {
+        assert_eq!(
+            num_traits::CheckedDiv::checked_div(&x, &y).unwrap(),
+            &x / &y
+        );
+        assert_eq!(
+            num_traits::CheckedRem::checked_rem(&x, &y).unwrap(),
+            &x % &y
+        );
+    });
+
+    rational_unsigned_pair_gen_var_1::<u64>().test_properties(|(x, exp)| {
+        assert_eq!(num_traits::Pow::pow(&x, exp), (&x).pow(exp));
+    });
+
+    rational_signed_pair_gen_var_2::<i64>().test_properties(|(x, exp)| {
+        assert_eq!(num_traits::Pow::pow(&x, exp), (&x).pow(exp));
+    });
+
+    signed_gen::<i64>().test_properties(|i| {
+        let x = Rational::from_i64(i).unwrap();
+        assert_eq!(x, i);
+        assert_eq!(x.to_i64(), Some(i));
+    });
+
+    primitive_float_gen::<f64>().test_properties(|f| {
+        let x = Rational::from_f64(f);
+        assert_eq!(x.is_some(), f.is_finite());
+        if let Some(x) = x {
+            assert_eq!(x, Rational::try_from(f).unwrap());
+            assert_eq!(x.to_f64(), Some(f));
+        }
+    });
+}